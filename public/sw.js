@@ -25,11 +25,15 @@ self.addEventListener('push', function(event) {
         }
     }
 
+    // Buttons like "Mark watered"; the request each one makes is kept in the notification's data
+    const actions = Array.isArray(data.actions) ? data.actions : [];
+
     const options = {
         body: data.body,
         icon: '/pkg/favicon.ico',
         badge: '/pkg/favicon.ico',
-        data: { url: data.url || '/' },
+        data: { url: data.url || '/', actions: actions },
+        actions: actions.map(function(a) { return { action: a.action, title: a.title }; }),
         vibrate: [100, 50, 100],
     };
 
@@ -38,23 +42,56 @@ self.addEventListener('push', function(event) {
     );
 });
 
+// Focus an open tab showing `url`, or open one
+function openApp(url) {
+    return clients.matchAll({ type: 'window', includeUncontrolled: true }).then(function(clientList) {
+        for (const client of clientList) {
+            if (client.url.includes(url) && 'focus' in client) {
+                return client.focus();
+            }
+        }
+        if (clients.openWindow) {
+            return clients.openWindow(url);
+        }
+    });
+}
+
+// Carry out a notification button's request with the session cookie, then confirm. If it can't be
+// done here (signed out, no access, offline), open the app so it can be done there.
+function runAction(action, url) {
+    return fetch(action.endpoint, {
+        method: 'POST',
+        credentials: 'same-origin',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(action.body || {}),
+    }).then(function(response) {
+        if (!response.ok) {
+            throw new Error('HTTP ' + response.status);
+        }
+        return response.json();
+    }).then(function(result) {
+        console.log('[SW] Action done:', action.action);
+        return self.registration.showNotification('Velamen', {
+            body: result.message || 'Done',
+            icon: '/pkg/favicon.ico',
+            badge: '/pkg/favicon.ico',
+            data: { url: url },
+            tag: 'push-action',
+        });
+    }).catch(function(e) {
+        console.log('[SW] Action failed, opening the app:', e);
+        return openApp(url);
+    });
+}
+
 self.addEventListener('notificationclick', function(event) {
     event.notification.close();
 
-    const url = event.notification.data && event.notification.data.url
-        ? event.notification.data.url
-        : '/';
+    const data = event.notification.data || {};
+    const url = data.url || '/';
+    const action = event.action && Array.isArray(data.actions)
+        ? data.actions.find(function(a) { return a.action === event.action; })
+        : null;
 
-    event.waitUntil(
-        clients.matchAll({ type: 'window', includeUncontrolled: true }).then(function(clientList) {
-            for (const client of clientList) {
-                if (client.url.includes(url) && 'focus' in client) {
-                    return client.focus();
-                }
-            }
-            if (clients.openWindow) {
-                return clients.openWindow(url);
-            }
-        })
-    );
+    event.waitUntil(action ? runAction(action, url) : openApp(url));
 });
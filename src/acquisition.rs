@@ -0,0 +1,183 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::Orchid;
+
+/// A nursery, shop or grower plants are bought from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vendor {
+    /// The vendor record ID.
+    pub id: String,
+    /// Display name.
+    pub name: String,
+    /// Website or shop link, if recorded.
+    pub website: Option<String>,
+    /// When the vendor was added.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Spend and plant count for one group in the collection-value summary.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpendGroup {
+    /// The vendor name, source label or year the group is for.
+    pub label: String,
+    /// Plants in the group.
+    pub plants: u32,
+    /// Total paid for the group's priced plants.
+    pub total: f64,
+}
+
+/// What the collection cost, broken down by vendor, source and year.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CollectionValue {
+    /// Total paid across plants with a price.
+    pub total_spent: f64,
+    /// Plants with a price recorded, including free ones.
+    pub priced_plants: u32,
+    /// Plants without a price.
+    pub unpriced_plants: u32,
+    /// The most paid for a single plant, with its name.
+    pub most_expensive: Option<(String, f64)>,
+    /// By vendor, biggest spend first. Plants without a vendor are left out.
+    pub by_vendor: Vec<SpendGroup>,
+    /// By acquisition source, biggest spend first. Plants without a source are left out.
+    pub by_source: Vec<SpendGroup>,
+    /// By year acquired, newest first. Plants without a date are left out.
+    pub by_year: Vec<SpendGroup>,
+}
+
+impl CollectionValue {
+    /// Average price of the priced plants, or None if none have a price.
+    pub fn average_price(&self) -> Option<f64> {
+        (self.priced_plants > 0).then(|| self.total_spent / self.priced_plants as f64)
+    }
+}
+
+/// Formats an acquisition date for an `<input type="date">`, or "" when unset.
+pub fn date_input_value(at: Option<DateTime<Utc>>) -> String {
+    at.map(|t| t.date_naive().format("%Y-%m-%d").to_string()).unwrap_or_default()
+}
+
+/// Parses a date input's `YYYY-MM-DD` value as midnight UTC; None for an empty or malformed value.
+pub fn parse_date_input(raw: &str) -> Option<DateTime<Utc>> {
+    NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d").ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|d| d.and_utc())
+}
+
+/// Parses a price input; None for an empty, negative or non-numeric value.
+pub fn parse_price_input(raw: &str) -> Option<f64> {
+    raw.trim().parse::<f64>().ok().filter(|p| p.is_finite() && *p >= 0.0)
+}
+
+fn add_to(groups: &mut Vec<SpendGroup>, label: String, price: Option<f64>) {
+    let group = match groups.iter().position(|g| g.label == label) {
+        Some(i) => &mut groups[i],
+        None => {
+            groups.push(SpendGroup { label, plants: 0, total: 0.0 });
+            groups.last_mut().expect("just pushed")
+        }
+    };
+    group.plants += 1;
+    group.total += price.unwrap_or(0.0);
+}
+
+fn by_spend(groups: &mut [SpendGroup]) {
+    groups.sort_by(|a, b| b.total.total_cmp(&a.total).then_with(|| a.label.cmp(&b.label)));
+}
+
+/// Summarizes what the collection cost. Vendors the plants point at but that aren't in `vendors`
+/// (deleted since) are grouped as "Unknown vendor".
+pub fn collection_value(orchids: &[Orchid], vendors: &[Vendor]) -> CollectionValue {
+    let mut value = CollectionValue::default();
+
+    for o in orchids {
+        match o.acquisition_price {
+            Some(price) => {
+                value.total_spent += price;
+                value.priced_plants += 1;
+                if value.most_expensive.as_ref().is_none_or(|(_, top)| price > *top) {
+                    value.most_expensive = Some((o.name.clone(), price));
+                }
+            }
+            None => value.unpriced_plants += 1,
+        }
+
+        if let Some(vendor_id) = &o.vendor_id {
+            let name = vendors.iter()
+                .find(|v| v.id == *vendor_id)
+                .map(|v| v.name.clone())
+                .unwrap_or_else(|| "Unknown vendor".to_string());
+            add_to(&mut value.by_vendor, name, o.acquisition_price);
+        }
+        if let Some(source) = o.acquisition_source {
+            add_to(&mut value.by_source, source.label().to_string(), o.acquisition_price);
+        }
+        if let Some(at) = o.acquired_at {
+            add_to(&mut value.by_year, at.year().to_string(), o.acquisition_price);
+        }
+    }
+
+    by_spend(&mut value.by_vendor);
+    by_spend(&mut value.by_source);
+    value.by_year.sort_by(|a, b| b.label.cmp(&a.label));
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::orchid::AcquisitionSource;
+    use crate::test_helpers::test_orchid;
+
+    fn vendor(id: &str, name: &str) -> Vendor {
+        Vendor { id: id.into(), name: name.into(), website: None, created_at: Utc::now() }
+    }
+
+    #[test]
+    fn test_collection_value_groups_spend() {
+        let bought = |name: &str, price: f64, vendor: &str, year: i32| Orchid {
+            name: name.into(),
+            acquisition_price: Some(price),
+            acquisition_source: Some(AcquisitionSource::Nursery),
+            vendor_id: Some(vendor.into()),
+            acquired_at: Some(Utc.with_ymd_and_hms(year, 5, 1, 0, 0, 0).unwrap()),
+            ..test_orchid()
+        };
+        let orchids = vec![
+            bought("A", 30.0, "vendor:sky", 2024),
+            bought("B", 120.0, "vendor:sky", 2025),
+            bought("C", 20.0, "vendor:gone", 2025),
+            Orchid { name: "Gift".into(), acquisition_price: Some(0.0), acquisition_source: Some(AcquisitionSource::Gift), ..test_orchid() },
+            Orchid { name: "Old".into(), ..test_orchid() },
+        ];
+        let value = collection_value(&orchids, &[vendor("vendor:sky", "Sky Orchids")]);
+
+        assert_eq!(value.total_spent, 170.0);
+        assert_eq!((value.priced_plants, value.unpriced_plants), (4, 1));
+        assert_eq!(value.average_price(), Some(42.5));
+        assert_eq!(value.most_expensive, Some(("B".to_string(), 120.0)));
+        assert_eq!(value.by_vendor.iter().map(|g| (g.label.as_str(), g.plants)).collect::<Vec<_>>(), vec![("Sky Orchids", 2), ("Unknown vendor", 1)]);
+        assert_eq!(value.by_source[0].label, "Nursery");
+        assert_eq!(value.by_year.iter().map(|g| g.label.as_str()).collect::<Vec<_>>(), vec!["2025", "2024"]);
+    }
+
+    #[test]
+    fn test_form_inputs_roundtrip() {
+        let at = parse_date_input("2024-03-09").unwrap();
+        assert_eq!(at, Utc.with_ymd_and_hms(2024, 3, 9, 0, 0, 0).unwrap());
+        assert_eq!(date_input_value(Some(at)), "2024-03-09");
+        assert_eq!(date_input_value(None), "");
+        assert_eq!(parse_date_input(""), None);
+        assert_eq!(parse_price_input(" 24.50 "), Some(24.5));
+        assert_eq!(parse_price_input("-3"), None);
+        assert_eq!(parse_price_input("free"), None);
+    }
+
+    #[test]
+    fn test_empty_collection_has_no_average() {
+        let value = collection_value(&[], &[]);
+        assert_eq!(value.average_price(), None);
+        assert!(value.most_expensive.is_none());
+    }
+}
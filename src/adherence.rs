@@ -0,0 +1,233 @@
+use chrono::{DateTime, Datelike, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::{Hemisphere, Orchid};
+
+/// How far back adherence is measured, in days.
+pub const ADHERENCE_WINDOW_DAYS: i64 = 90;
+
+/// A journal event that matters for adherence, oldest first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CareEvent {
+    /// A watering that restarted the countdown (mistings of non-misted plants don't count).
+    Watered(DateTime<Utc>),
+    /// A watering deliberately put off with the "Skip" action.
+    Skipped(DateTime<Utc>),
+}
+
+impl CareEvent {
+    fn at(&self) -> DateTime<Utc> {
+        match self {
+            CareEvent::Watered(t) | CareEvent::Skipped(t) => *t,
+        }
+    }
+}
+
+/// One plant's on-time and late waterings over the window.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlantAdherence {
+    /// The plant's record ID.
+    pub orchid_id: String,
+    /// The plant's display name.
+    pub name: String,
+    /// Waterings that came within the schedule plus the grace period.
+    pub on_time: u32,
+    /// Waterings that came later than that.
+    pub late: u32,
+    /// Average days past due across the late waterings, 0 if none were late.
+    pub average_days_late: f64,
+}
+
+impl PlantAdherence {
+    /// Share of waterings that were on time, 0-100, or None with nothing to judge.
+    pub fn on_time_percent(&self) -> Option<f64> {
+        on_time_percent(self.on_time, self.late)
+    }
+}
+
+/// Watering adherence for the whole collection.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdherenceStats {
+    /// On-time waterings across every plant.
+    pub on_time: u32,
+    /// Late waterings across every plant.
+    pub late: u32,
+    /// Plants with at least one judged watering, least punctual first.
+    pub plants: Vec<PlantAdherence>,
+}
+
+impl AdherenceStats {
+    /// Share of all waterings that were on time, 0-100, or None with nothing to judge.
+    pub fn on_time_percent(&self) -> Option<f64> {
+        on_time_percent(self.on_time, self.late)
+    }
+}
+
+fn on_time_percent(on_time: u32, late: u32) -> Option<f64> {
+    let total = on_time + late;
+    (total > 0).then(|| on_time as f64 / total as f64 * 100.0)
+}
+
+/// Judges each watering in the window against the interval scheduled after the watering before it.
+///
+/// The schedule is the plant's seasonal frequency in the month of the earlier watering, plus
+/// `grace_days`. A watering with no earlier one on record can't be judged and is left out, and
+/// a gap that contains a skip counts as on time: the grower checked the plant and chose to wait.
+pub fn plant_adherence(
+    orchid: &Orchid,
+    events: &[CareEvent],
+    hemisphere: &Hemisphere,
+    grace_days: u32,
+    now: DateTime<Utc>,
+) -> PlantAdherence {
+    plant_adherence_since(orchid, events, hemisphere, grace_days, now - Duration::days(ADHERENCE_WINDOW_DAYS))
+}
+
+/// `plant_adherence` judging only the waterings from `since` on, for windows other than the last 90 days.
+pub fn plant_adherence_since(
+    orchid: &Orchid,
+    events: &[CareEvent],
+    hemisphere: &Hemisphere,
+    grace_days: u32,
+    since: DateTime<Utc>,
+) -> PlantAdherence {
+    let mut on_time = 0;
+    let mut late = 0;
+    let mut days_late_total = 0;
+    let mut previous: Option<DateTime<Utc>> = None;
+    let mut skipped_since_previous = false;
+
+    for event in events {
+        match *event {
+            CareEvent::Skipped(_) => skipped_since_previous = true,
+            CareEvent::Watered(at) => {
+                if let Some(prev) = previous.filter(|_| at >= since) {
+                    let scheduled = orchid.water_frequency_in_month(hemisphere, prev.month()) as i64;
+                    let days_past_due = (at - prev).num_days() - scheduled;
+                    if skipped_since_previous || days_past_due <= grace_days as i64 {
+                        on_time += 1;
+                    } else {
+                        late += 1;
+                        days_late_total += days_past_due;
+                    }
+                }
+                previous = Some(at);
+                skipped_since_previous = false;
+            }
+        }
+    }
+
+    PlantAdherence {
+        orchid_id: orchid.id.clone(),
+        name: orchid.name.clone(),
+        on_time,
+        late,
+        average_days_late: if late > 0 { days_late_total as f64 / late as f64 } else { 0.0 },
+    }
+}
+
+/// Adherence for every plant with events, keyed by orchid ID, and the collection totals.
+///
+/// `events_for` returns a plant's events oldest first. Plants with no judged watering are left out.
+pub fn collection_adherence<'a>(
+    orchids: &[Orchid],
+    events_for: impl Fn(&str) -> &'a [CareEvent],
+    hemisphere: &Hemisphere,
+    default_grace_days: u32,
+    now: DateTime<Utc>,
+) -> AdherenceStats {
+    collection_adherence_since(orchids, events_for, hemisphere, default_grace_days, now - Duration::days(ADHERENCE_WINDOW_DAYS))
+}
+
+/// `collection_adherence` judging only the waterings from `since` on. Leave later events out of
+/// `events_for` to end the window early, as the monthly report does.
+pub fn collection_adherence_since<'a>(
+    orchids: &[Orchid],
+    events_for: impl Fn(&str) -> &'a [CareEvent],
+    hemisphere: &Hemisphere,
+    default_grace_days: u32,
+    since: DateTime<Utc>,
+) -> AdherenceStats {
+    let mut plants: Vec<PlantAdherence> = orchids.iter()
+        .map(|o| {
+            let mut events = events_for(&o.id).to_vec();
+            events.sort_by_key(CareEvent::at);
+            plant_adherence_since(o, &events, hemisphere, o.grace_days(default_grace_days), since)
+        })
+        .filter(|p| p.on_time + p.late > 0)
+        .collect();
+    plants.sort_by(|a, b| {
+        a.on_time_percent().unwrap_or(100.0).total_cmp(&b.on_time_percent().unwrap_or(100.0))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    AdherenceStats {
+        on_time: plants.iter().map(|p| p.on_time).sum(),
+        late: plants.iter().map(|p| p.late).sum(),
+        plants,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    fn days_ago(now: DateTime<Utc>, d: i64) -> DateTime<Utc> {
+        now - Duration::days(d)
+    }
+
+    #[test]
+    fn test_plant_adherence_counts_late_waterings() {
+        let now = Utc::now();
+        let orchid = Orchid { water_frequency_days: 7, ..test_orchid() };
+        let events = [
+            CareEvent::Watered(days_ago(now, 40)),
+            CareEvent::Watered(days_ago(now, 33)),
+            CareEvent::Watered(days_ago(now, 20)),
+            CareEvent::Watered(days_ago(now, 12)),
+        ];
+        let stats = plant_adherence(&orchid, &events, &Hemisphere::Northern, 0, now);
+        assert_eq!((stats.on_time, stats.late), (1, 2));
+        assert_eq!(stats.average_days_late, 3.5);
+
+        // A day of grace turns the one-day-late watering into an on-time one
+        let stats = plant_adherence(&orchid, &events, &Hemisphere::Northern, 1, now);
+        assert_eq!((stats.on_time, stats.late), (2, 1));
+    }
+
+    #[test]
+    fn test_skip_and_window_edges() {
+        let now = Utc::now();
+        let orchid = Orchid { water_frequency_days: 7, ..test_orchid() };
+        let events = [
+            CareEvent::Watered(days_ago(now, 120)),
+            CareEvent::Watered(days_ago(now, 100)),
+            CareEvent::Watered(days_ago(now, 80)),
+            CareEvent::Skipped(days_ago(now, 73)),
+            CareEvent::Watered(days_ago(now, 60)),
+        ];
+        // The watering 100 days ago is outside the window; the one 80 days ago is judged against it
+        let stats = plant_adherence(&orchid, &events, &Hemisphere::Northern, 0, now);
+        assert_eq!((stats.on_time, stats.late), (1, 1));
+        assert_eq!(stats.on_time_percent(), Some(50.0));
+    }
+
+    #[test]
+    fn test_collection_adherence_sorts_least_punctual_first() {
+        let now = Utc::now();
+        let steady = Orchid { id: "orchid:a".into(), name: "Steady".into(), water_frequency_days: 7, ..test_orchid() };
+        let neglected = Orchid { id: "orchid:b".into(), name: "Neglected".into(), water_frequency_days: 7, ..test_orchid() };
+        let unwatered = Orchid { id: "orchid:c".into(), name: "New".into(), ..test_orchid() };
+        let a = [CareEvent::Watered(days_ago(now, 14)), CareEvent::Watered(days_ago(now, 7))];
+        let b = [CareEvent::Watered(days_ago(now, 30)), CareEvent::Watered(days_ago(now, 10))];
+        let stats = collection_adherence(
+            &[steady, neglected, unwatered],
+            |id| match id { "orchid:a" => &a[..], "orchid:b" => &b[..], _ => &[] },
+            &Hemisphere::Northern,
+            0,
+            now,
+        );
+        assert_eq!(stats.plants.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), vec!["Neglected", "Steady"]);
+        assert_eq!((stats.on_time, stats.late), (1, 1));
+    }
+}
@@ -0,0 +1,494 @@
+use async_trait::async_trait;
+use crate::config::config;
+
+/// An AI service that can answer a prompt, optionally about a photo.
+///
+/// Responses come back as plain text with any markdown code fences removed, since every caller asks for bare JSON.
+#[async_trait]
+pub trait AiProvider: Send + Sync {
+    /// Short name used in logs, e.g. "Gemini".
+    fn name(&self) -> &'static str;
+
+    /// Answer `prompt` about a base64-encoded JPEG.
+    async fn vision(&self, prompt: &str, image_base64: &str) -> Result<String, String>;
+
+    /// Answer a text-only `prompt`.
+    async fn text(&self, prompt: &str) -> Result<String, String>;
+}
+
+/// The providers this server knows how to call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProviderKind {
+    Gemini,
+    Claude,
+    OpenAi,
+    Ollama,
+}
+
+impl ProviderKind {
+    /// Parses one entry of `AI_PROVIDERS`; unknown names are None.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "gemini" | "google" => Some(ProviderKind::Gemini),
+            "claude" | "anthropic" => Some(ProviderKind::Claude),
+            "openai" => Some(ProviderKind::OpenAi),
+            "ollama" | "local" => Some(ProviderKind::Ollama),
+            _ => None,
+        }
+    }
+}
+
+/// The order providers are tried in when `AI_PROVIDERS` is empty.
+const DEFAULT_ORDER: &[ProviderKind] = &[ProviderKind::Gemini, ProviderKind::Claude, ProviderKind::OpenAi, ProviderKind::Ollama];
+
+/// The providers named in a comma-separated `AI_PROVIDERS` value, in order and without repeats.
+/// An empty value means every provider, Gemini first.
+pub fn parse_provider_order(s: &str) -> Vec<ProviderKind> {
+    if s.trim().is_empty() {
+        return DEFAULT_ORDER.to_vec();
+    }
+    let mut order = Vec::new();
+    for name in s.split(',').filter(|n| !n.trim().is_empty()) {
+        match ProviderKind::parse(name) {
+            Some(kind) if !order.contains(&kind) => order.push(kind),
+            Some(_) => {}
+            None => tracing::warn!("Ignoring unknown AI provider '{}' in AI_PROVIDERS", name.trim()),
+        }
+    }
+    order
+}
+
+/// The configured providers in the order they should be tried. Providers without credentials
+/// (or, for Ollama, without a URL) are skipped, so only services the operator set up ever see a photo.
+pub fn providers() -> Vec<Box<dyn AiProvider>> {
+    let cfg = config();
+    parse_provider_order(&cfg.ai_providers)
+        .into_iter()
+        .filter_map(|kind| -> Option<Box<dyn AiProvider>> {
+            match kind {
+                ProviderKind::Gemini if !cfg.gemini_api_key.is_empty() => Some(Box::new(Gemini {
+                    api_key: cfg.gemini_api_key.clone(),
+                    model: cfg.gemini_model.clone(),
+                })),
+                ProviderKind::Claude if !cfg.claude_api_key.is_empty() => Some(Box::new(Claude {
+                    api_key: cfg.claude_api_key.clone(),
+                    model: cfg.claude_model.clone(),
+                })),
+                ProviderKind::OpenAi if !cfg.openai_api_key.is_empty() => Some(Box::new(OpenAi {
+                    api_key: cfg.openai_api_key.clone(),
+                    model: cfg.openai_model.clone(),
+                    base_url: cfg.openai_base_url.trim_end_matches('/').to_string(),
+                })),
+                ProviderKind::Ollama if !cfg.ollama_url.is_empty() => Some(Box::new(Ollama {
+                    url: cfg.ollama_url.trim_end_matches('/').to_string(),
+                    model: cfg.ollama_model.clone(),
+                })),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+const NOT_CONFIGURED: &str = "No AI providers configured. Set GEMINI_API_KEY, CLAUDE_API_KEY, OPENAI_API_KEY and/or OLLAMA_URL in your .env file.";
+
+/// Ask the configured providers about a photo, falling back to the next one when a call fails.
+pub async fn vision(prompt: &str, image_base64: &str) -> Result<String, String> {
+    let providers = providers();
+    let mut last_err = NOT_CONFIGURED.to_string();
+    for (i, provider) in providers.iter().enumerate() {
+        match provider.vision(prompt, image_base64).await {
+            Ok(text) => return Ok(text),
+            Err(e) => {
+                if let Some(next) = providers.get(i + 1) {
+                    tracing::warn!("{} failed ({}), falling back to {}", provider.name(), e, next.name());
+                }
+                last_err = e;
+            }
+        }
+    }
+    if providers.len() > 1 {
+        return Err(format!("AI analysis failed (all providers). Last error: {}", last_err));
+    }
+    Err(last_err)
+}
+
+/// Ask the configured providers a text-only question, falling back to the next one when a call fails.
+pub async fn text(prompt: &str) -> Result<String, String> {
+    let providers = providers();
+    let mut last_err = NOT_CONFIGURED.to_string();
+    for (i, provider) in providers.iter().enumerate() {
+        match provider.text(prompt).await {
+            Ok(text) => return Ok(text),
+            Err(e) => {
+                if let Some(next) = providers.get(i + 1) {
+                    tracing::warn!("{} text failed ({}), falling back to {}", provider.name(), e, next.name());
+                }
+                last_err = e;
+            }
+        }
+    }
+    Err(last_err)
+}
+
+/// Removes markdown code fences models like to wrap JSON in.
+fn strip_fences(s: &str) -> String {
+    s.replace("```json", "").replace("```", "").trim().to_string()
+}
+
+/// POSTs a JSON body and returns the JSON reply, turning HTTP failures into `"{label} ..."` messages.
+async fn post_json(
+    label: &str,
+    request: reqwest::RequestBuilder,
+    body: &serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    let resp = request
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| format!("{} network error: {}", label, e))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("{} API error: {} {}", label, status, body));
+    }
+
+    resp.json().await.map_err(|e| format!("{} parse error: {}", label, e))
+}
+
+// ── Gemini ──────────────────────────────────────────────────────────
+
+/// Google Gemini's `generateContent` API.
+pub struct Gemini {
+    pub api_key: String,
+    pub model: String,
+}
+
+impl Gemini {
+    async fn generate(&self, parts: serde_json::Value) -> Result<String, String> {
+        let url = format!(
+            "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
+            self.model
+        );
+        let request = reqwest::Client::new().post(&url).header("x-goog-api-key", &self.api_key);
+        let json = post_json("Gemini", request, &serde_json::json!({ "contents": [{ "parts": parts }] })).await?;
+        extract_gemini_text(&json)
+    }
+}
+
+#[async_trait]
+impl AiProvider for Gemini {
+    fn name(&self) -> &'static str {
+        "Gemini"
+    }
+
+    async fn vision(&self, prompt: &str, image_base64: &str) -> Result<String, String> {
+        self.generate(serde_json::json!([
+            { "text": prompt },
+            { "inline_data": { "mime_type": "image/jpeg", "data": image_base64 } }
+        ])).await
+    }
+
+    async fn text(&self, prompt: &str) -> Result<String, String> {
+        self.generate(serde_json::json!([{ "text": prompt }])).await
+    }
+}
+
+/// Extract text from a Gemini API response.
+fn extract_gemini_text(json: &serde_json::Value) -> Result<String, String> {
+    json.get("candidates")
+        .and_then(|c| c.get(0))
+        .and_then(|c| c.get("content"))
+        .and_then(|c| c.get("parts"))
+        .and_then(|p| p.get(0))
+        .and_then(|p| p.get("text"))
+        .and_then(|t| t.as_str())
+        .map(strip_fences)
+        .ok_or_else(|| "Could not extract text from Gemini response".to_string())
+}
+
+// ── Claude ──────────────────────────────────────────────────────────
+
+/// Anthropic's Messages API.
+pub struct Claude {
+    pub api_key: String,
+    pub model: String,
+}
+
+impl Claude {
+    async fn messages(&self, content: serde_json::Value, max_tokens: u32) -> Result<String, String> {
+        let body = serde_json::json!({
+            "model": self.model,
+            "max_tokens": max_tokens,
+            "messages": [{ "role": "user", "content": content }]
+        });
+        let request = reqwest::Client::new()
+            .post("https://api.anthropic.com/v1/messages")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json");
+        let json = post_json("Claude", request, &body).await?;
+        extract_claude_text(&json)
+    }
+}
+
+#[async_trait]
+impl AiProvider for Claude {
+    fn name(&self) -> &'static str {
+        "Claude"
+    }
+
+    async fn vision(&self, prompt: &str, image_base64: &str) -> Result<String, String> {
+        self.messages(serde_json::json!([
+            {
+                "type": "image",
+                "source": { "type": "base64", "media_type": "image/jpeg", "data": image_base64 }
+            },
+            { "type": "text", "text": prompt }
+        ]), 4096).await
+    }
+
+    async fn text(&self, prompt: &str) -> Result<String, String> {
+        self.messages(serde_json::json!(prompt), 1024).await
+    }
+}
+
+/// Extract text from a Claude Messages API response.
+fn extract_claude_text(json: &serde_json::Value) -> Result<String, String> {
+    json.get("content")
+        .and_then(|c| c.get(0))
+        .and_then(|c| c.get("text"))
+        .and_then(|t| t.as_str())
+        .map(strip_fences)
+        .ok_or_else(|| "Could not extract text from Claude response".to_string())
+}
+
+// ── OpenAI ──────────────────────────────────────────────────────────
+
+/// OpenAI's Chat Completions API, or any service that speaks it at `base_url`.
+pub struct OpenAi {
+    pub api_key: String,
+    pub model: String,
+    pub base_url: String,
+}
+
+impl OpenAi {
+    async fn chat(&self, content: serde_json::Value) -> Result<String, String> {
+        let body = serde_json::json!({
+            "model": self.model,
+            "messages": [{ "role": "user", "content": content }]
+        });
+        let request = reqwest::Client::new()
+            .post(format!("{}/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key);
+        let json = post_json("OpenAI", request, &body).await?;
+        extract_openai_text(&json)
+    }
+}
+
+#[async_trait]
+impl AiProvider for OpenAi {
+    fn name(&self) -> &'static str {
+        "OpenAI"
+    }
+
+    async fn vision(&self, prompt: &str, image_base64: &str) -> Result<String, String> {
+        self.chat(serde_json::json!([
+            { "type": "text", "text": prompt },
+            { "type": "image_url", "image_url": { "url": format!("data:image/jpeg;base64,{}", image_base64) } }
+        ])).await
+    }
+
+    async fn text(&self, prompt: &str) -> Result<String, String> {
+        self.chat(serde_json::json!(prompt)).await
+    }
+}
+
+/// Extract text from an OpenAI Chat Completions response.
+fn extract_openai_text(json: &serde_json::Value) -> Result<String, String> {
+    json.get("choices")
+        .and_then(|c| c.get(0))
+        .and_then(|c| c.get("message"))
+        .and_then(|m| m.get("content"))
+        .and_then(|t| t.as_str())
+        .map(strip_fences)
+        .ok_or_else(|| "Could not extract text from OpenAI response".to_string())
+}
+
+// ── Ollama ──────────────────────────────────────────────────────────
+
+/// A local Ollama server, for photos that shouldn't leave the network.
+pub struct Ollama {
+    pub url: String,
+    pub model: String,
+}
+
+impl Ollama {
+    async fn generate(&self, prompt: &str, images: &[&str]) -> Result<String, String> {
+        let body = serde_json::json!({
+            "model": self.model,
+            "prompt": prompt,
+            "images": images,
+            "stream": false
+        });
+        let request = reqwest::Client::new().post(format!("{}/api/generate", self.url));
+        let json = post_json("Ollama", request, &body).await?;
+        extract_ollama_text(&json)
+    }
+}
+
+#[async_trait]
+impl AiProvider for Ollama {
+    fn name(&self) -> &'static str {
+        "Ollama"
+    }
+
+    async fn vision(&self, prompt: &str, image_base64: &str) -> Result<String, String> {
+        self.generate(prompt, &[image_base64]).await
+    }
+
+    async fn text(&self, prompt: &str) -> Result<String, String> {
+        self.generate(prompt, &[]).await
+    }
+}
+
+/// Extract text from an Ollama `/api/generate` response.
+fn extract_ollama_text(json: &serde_json::Value) -> Result<String, String> {
+    json.get("response")
+        .and_then(|t| t.as_str())
+        .map(strip_fences)
+        .ok_or_else(|| "Could not extract text from Ollama response".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── parse_provider_order ────────────────────────────────────────
+
+    #[test]
+    fn test_parse_provider_order() {
+        assert_eq!(parse_provider_order(""), DEFAULT_ORDER.to_vec());
+        assert_eq!(parse_provider_order(" Ollama "), vec![ProviderKind::Ollama]);
+        assert_eq!(
+            parse_provider_order("anthropic, openai, bogus, claude"),
+            vec![ProviderKind::Claude, ProviderKind::OpenAi]
+        );
+    }
+
+    // ── extract_gemini_text ─────────────────────────────────────────
+
+    #[test]
+    fn test_extract_gemini_text_valid_response() {
+        let json = serde_json::json!({
+            "candidates": [{
+                "content": {
+                    "parts": [{ "text": "{\"species_name\": \"Phalaenopsis bellina\"}" }]
+                }
+            }]
+        });
+        let result = extract_gemini_text(&json);
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("Phalaenopsis bellina"));
+    }
+
+    #[test]
+    fn test_extract_gemini_text_strips_markdown_fences() {
+        let json = serde_json::json!({
+            "candidates": [{
+                "content": {
+                    "parts": [{ "text": "```json\n{\"species_name\": \"Dendrobium\"}\n```" }]
+                }
+            }]
+        });
+        let result = extract_gemini_text(&json).unwrap();
+        assert!(!result.contains("```"));
+        assert!(result.contains("Dendrobium"));
+    }
+
+    #[test]
+    fn test_extract_gemini_text_missing_candidates() {
+        let json = serde_json::json!({});
+        assert!(extract_gemini_text(&json).is_err());
+    }
+
+    #[test]
+    fn test_extract_gemini_text_empty_candidates() {
+        let json = serde_json::json!({ "candidates": [] });
+        assert!(extract_gemini_text(&json).is_err());
+    }
+
+    #[test]
+    fn test_extract_gemini_text_missing_parts() {
+        let json = serde_json::json!({
+            "candidates": [{ "content": {} }]
+        });
+        assert!(extract_gemini_text(&json).is_err());
+    }
+
+    // ── extract_claude_text ─────────────────────────────────────────
+
+    #[test]
+    fn test_extract_claude_text_valid_response() {
+        let json = serde_json::json!({
+            "content": [{
+                "type": "text",
+                "text": "{\"species_name\": \"Oncidium sharry baby\"}"
+            }]
+        });
+        let result = extract_claude_text(&json);
+        assert!(result.is_ok());
+        assert!(result.unwrap().contains("Oncidium sharry baby"));
+    }
+
+    #[test]
+    fn test_extract_claude_text_strips_markdown_fences() {
+        let json = serde_json::json!({
+            "content": [{
+                "type": "text",
+                "text": "```json\n{\"species_name\": \"Cattleya\"}\n```"
+            }]
+        });
+        let result = extract_claude_text(&json).unwrap();
+        assert!(!result.contains("```"));
+        assert!(result.contains("Cattleya"));
+    }
+
+    #[test]
+    fn test_extract_claude_text_missing_content() {
+        let json = serde_json::json!({});
+        assert!(extract_claude_text(&json).is_err());
+    }
+
+    #[test]
+    fn test_extract_claude_text_empty_content() {
+        let json = serde_json::json!({ "content": [] });
+        assert!(extract_claude_text(&json).is_err());
+    }
+
+    #[test]
+    fn test_extract_claude_text_missing_text_field() {
+        let json = serde_json::json!({
+            "content": [{ "type": "text" }]
+        });
+        assert!(extract_claude_text(&json).is_err());
+    }
+
+    // ── extract_openai_text / extract_ollama_text ───────────────────
+
+    #[test]
+    fn test_extract_openai_text() {
+        let json = serde_json::json!({
+            "choices": [{ "message": { "role": "assistant", "content": "```json\n{\"species_name\": \"Vanda\"}\n```" } }]
+        });
+        assert_eq!(extract_openai_text(&json).unwrap(), "{\"species_name\": \"Vanda\"}");
+        assert!(extract_openai_text(&serde_json::json!({ "choices": [] })).is_err());
+    }
+
+    #[test]
+    fn test_extract_ollama_text() {
+        let json = serde_json::json!({ "model": "llava", "response": "{\"species_name\": \"Masdevallia\"}", "done": true });
+        assert_eq!(extract_ollama_text(&json).unwrap(), "{\"species_name\": \"Masdevallia\"}");
+        assert!(extract_ollama_text(&serde_json::json!({ "done": true })).is_err());
+    }
+}
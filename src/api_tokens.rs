@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::household::HouseholdRole;
+
+/// Prefix on every personal API token, so leaked tokens are recognisable in logs and secret scanners.
+pub const TOKEN_PREFIX: &str = "vlm_";
+
+/// How many characters of a token (after the prefix) are kept for display.
+pub const DISPLAY_PREFIX_LEN: usize = 6;
+
+/// Maximum number of tokens a user can hold at once.
+pub const MAX_TOKENS: usize = 20;
+
+/// What a personal API token may be used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ApiScope {
+    /// Read plants, journals, and climate data.
+    Read,
+    /// Log care and edit plants.
+    Write,
+    /// Upload images via `/api/images/upload`.
+    Upload,
+    /// Post climate readings via `/api/zones/{zone_id}/readings`, and nothing else.
+    Ingest,
+}
+
+impl ApiScope {
+    /// All scopes, in the order the settings panel lists them.
+    pub const ALL: [ApiScope; 4] = [ApiScope::Read, ApiScope::Write, ApiScope::Upload, ApiScope::Ingest];
+
+    /// Stable key stored in `api_token.scopes`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::Read => "read",
+            ApiScope::Write => "write",
+            ApiScope::Upload => "images:upload",
+            ApiScope::Ingest => "climate:ingest",
+        }
+    }
+
+    /// Parses a stored scope key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == key)
+    }
+
+    /// One-line description for the settings panel.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ApiScope::Read => "Read plants, journals and climate data",
+            ApiScope::Write => "Log care and edit plants",
+            ApiScope::Upload => "Upload photos",
+            ApiScope::Ingest => "Post climate readings for HTTP push zones",
+        }
+    }
+
+    /// The most a token with `scopes` may do through server functions, or `None` if it can't call them at all.
+    ///
+    /// Tokens never reach owner-only actions (zones, members, deleting plants); those need a signed-in session.
+    pub fn max_role(scopes: &[ApiScope]) -> Option<HouseholdRole> {
+        if scopes.contains(&ApiScope::Write) {
+            Some(HouseholdRole::Caretaker)
+        } else if scopes.contains(&ApiScope::Read) {
+            Some(HouseholdRole::Viewer)
+        } else {
+            None
+        }
+    }
+}
+
+/// Common scope combinations offered when creating a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiTokenPreset {
+    /// Dashboards and exports: read only.
+    ReadOnly,
+    /// Sensor scripts: post climate readings only.
+    ClimateIngest,
+    /// Every scope.
+    Full,
+}
+
+impl ApiTokenPreset {
+    /// All presets, in the order the settings panel lists them.
+    pub const ALL: [ApiTokenPreset; 3] = [ApiTokenPreset::ReadOnly, ApiTokenPreset::ClimateIngest, ApiTokenPreset::Full];
+
+    /// Button label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ApiTokenPreset::ReadOnly => "Read-only",
+            ApiTokenPreset::ClimateIngest => "Climate ingest only",
+            ApiTokenPreset::Full => "Full access",
+        }
+    }
+
+    /// The scopes the preset grants.
+    pub fn scopes(&self) -> Vec<ApiScope> {
+        match self {
+            ApiTokenPreset::ReadOnly => vec![ApiScope::Read],
+            ApiTokenPreset::ClimateIngest => vec![ApiScope::Ingest],
+            ApiTokenPreset::Full => ApiScope::ALL.to_vec(),
+        }
+    }
+
+    /// The preset granting exactly `scopes`, if any (order doesn't matter).
+    pub fn matching(scopes: &[ApiScope]) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| {
+            let preset = p.scopes();
+            preset.len() == scopes.len() && preset.iter().all(|s| scopes.contains(s))
+        })
+    }
+}
+
+/// A client-facing summary of one personal API token. The secret itself is never included.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiTokenInfo {
+    /// The token record ID.
+    pub id: String,
+    /// User-chosen label (e.g. "Home Assistant").
+    pub name: String,
+    /// The first few characters, shown so users can tell tokens apart.
+    pub prefix: String,
+    /// What the token may do.
+    pub scopes: Vec<ApiScope>,
+    /// When the token was created.
+    pub created_at: DateTime<Utc>,
+    /// When the token was last used.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// A freshly created token: its summary plus the secret, which is shown exactly once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewApiToken {
+    /// The stored summary.
+    pub info: ApiTokenInfo,
+    /// The full token to copy into the client.
+    pub token: String,
+}
+
+/// Extracts the token from an `Authorization: Bearer vlm_...` header value.
+pub fn parse_bearer(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && token.starts_with(TOKEN_PREFIX)).then_some(token)
+}
+
+/// The display prefix for a full token, e.g. "vlm_a1B2c3".
+pub fn display_prefix(token: &str) -> String {
+    token.chars().take(TOKEN_PREFIX.len() + DISPLAY_PREFIX_LEN).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bearer() {
+        assert_eq!(parse_bearer("Bearer vlm_abc"), Some("vlm_abc"));
+        assert_eq!(parse_bearer("bearer  vlm_abc "), Some("vlm_abc"));
+        assert_eq!(parse_bearer("Basic vlm_abc"), None);
+        assert_eq!(parse_bearer("Bearer abc"), None);
+        assert_eq!(parse_bearer("vlm_abc"), None);
+    }
+
+    #[test]
+    fn test_scope_key_roundtrip() {
+        for scope in ApiScope::ALL {
+            assert_eq!(ApiScope::from_key(scope.as_str()), Some(scope));
+        }
+        assert_eq!(ApiScope::from_key("admin"), None);
+    }
+
+    #[test]
+    fn test_max_role_caps_tokens_below_owner() {
+        assert_eq!(ApiScope::max_role(&[ApiScope::Ingest]), None);
+        assert_eq!(ApiScope::max_role(&[ApiScope::Upload, ApiScope::Read]), Some(HouseholdRole::Viewer));
+        assert_eq!(ApiScope::max_role(&ApiScope::ALL), Some(HouseholdRole::Caretaker));
+    }
+
+    #[test]
+    fn test_preset_matching() {
+        for preset in ApiTokenPreset::ALL {
+            assert_eq!(ApiTokenPreset::matching(&preset.scopes()), Some(preset));
+        }
+        let mut full = ApiScope::ALL.to_vec();
+        full.reverse();
+        assert_eq!(ApiTokenPreset::matching(&full), Some(ApiTokenPreset::Full));
+        assert_eq!(ApiTokenPreset::matching(&[ApiScope::Read, ApiScope::Upload]), None);
+    }
+
+    #[test]
+    fn test_display_prefix() {
+        assert_eq!(display_prefix("vlm_abcdefghijkl"), "vlm_abcdef");
+    }
+}
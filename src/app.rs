@@ -5,11 +5,15 @@ use leptos_router::path;
 
 use crate::pages::home::HomePage;
 use crate::pages::login::LoginPage;
+use crate::pages::forgot_password::ForgotPasswordPage;
+use crate::pages::reset_password::ResetPasswordPage;
+use crate::pages::join_household::JoinHouseholdPage;
 use crate::pages::onboarding::OnboardingPage;
 use crate::pages::public_collection::PublicCollectionPage;
 use crate::pages::register::RegisterPage;
 use crate::pages::cookie_policy::CookiePolicyPage;
 use crate::pages::account_delete::AccountDeletePage;
+use crate::pages::admin::AdminPage;
 use crate::pages::terms_of_service::TermsOfServicePage;
 use crate::components::cookie_consent::CookieConsent;
 use crate::components::global_footer::GlobalFooter;
@@ -50,11 +54,15 @@ pub fn App() -> impl IntoView {
             <Routes fallback=|| "Page not found.">
                 <Route path=path!("/") view=HomePage />
                 <Route path=path!("/login") view=LoginPage />
+                <Route path=path!("/forgot-password") view=ForgotPasswordPage />
+                <Route path=path!("/reset-password") view=ResetPasswordPage />
+                <Route path=path!("/join") view=JoinHouseholdPage />
                 <Route path=path!("/register") view=RegisterPage />
                 <Route path=path!("/onboarding") view=OnboardingPage />
                 <Route path=path!("/u/:username") view=PublicCollectionPage />
                 <Route path=path!("/cookie-policy") view=CookiePolicyPage />
                 <Route path=path!("/account/delete") view=AccountDeletePage />
+                <Route path=path!("/admin") view=AdminPage />
                 <Route path=path!("/terms") view=TermsOfServicePage />
             </Routes>
         </Router>
@@ -1,4 +1,5 @@
 use crate::error::AppError;
+use crate::household::HouseholdRole;
 use crate::server_fns::auth::UserInfo;
 use leptos::prelude::*;
 
@@ -38,6 +39,201 @@ pub async fn require_auth() -> Result<String, ServerFnError> {
     }
 }
 
+/// The collection a signed-in user is working in, and what they may do there
+#[derive(Debug, Clone)]
+pub struct CollectionAccess {
+    /// The signed-in user.
+    pub user_id: String,
+    /// The user whose collection is being accessed; scope `owner` queries by this.
+    /// Same as `user_id` unless the user is a member of someone else's household.
+    pub owner_id: String,
+    /// The signed-in user's role in that collection.
+    pub role: HouseholdRole,
+}
+
+/// Resolve which collection `user_id` works in: their household's, or their own
+pub async fn collection_access(user_id: &str) -> Result<CollectionAccess, ServerFnError> {
+    use crate::error::internal_error;
+    use crate::db::db;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct MembershipRow {
+        owner: surrealdb::types::RecordId,
+        role: String,
+    }
+
+    let record_id = surrealdb::types::RecordId::parse_simple(user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    let mut response = db()
+        .query("SELECT household.owner AS owner, role FROM household_member WHERE user = $user LIMIT 1")
+        .bind(("user", record_id))
+        .await
+        .map_err(|e| internal_error("Household membership query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Household membership query error", err_msg));
+    }
+
+    let row: Option<MembershipRow> = response.take(0)
+        .map_err(|e| internal_error("Household membership parse failed", e))?;
+
+    Ok(match row {
+        Some(m) => CollectionAccess {
+            user_id: user_id.to_string(),
+            owner_id: crate::server_fns::auth::record_id_to_string(&m.owner),
+            // Unknown roles get the least access
+            role: HouseholdRole::from_key(&m.role).unwrap_or(HouseholdRole::Viewer),
+        },
+        None => CollectionAccess {
+            user_id: user_id.to_string(),
+            owner_id: user_id.to_string(),
+            role: HouseholdRole::Owner,
+        },
+    })
+}
+
+/// Require a signed-in user with at least `min` access to the collection they work in
+///
+/// Without a session, a personal API token is accepted instead; its scopes cap the role
+/// (see `ApiScope::max_role`), so a read-only or ingest-only token can't change anything.
+pub async fn require_role(min: HouseholdRole) -> Result<CollectionAccess, ServerFnError> {
+    let access = match get_session_user().await? {
+        Some(user) => collection_access(&user.id).await?,
+        None => token_access().await?,
+    };
+    if !access.role.allows(min) {
+        return Err(ServerFnError::new(format!(
+            "Your household role ({}) doesn't allow this",
+            access.role.label()
+        )));
+    }
+    Ok(access)
+}
+
+/// The user and scopes behind a presented personal API token
+#[derive(Debug, Clone)]
+pub struct ApiTokenGrant {
+    /// The user who created the token.
+    pub user_id: String,
+    /// What the token may do; unknown stored keys are dropped.
+    pub scopes: Vec<crate::api_tokens::ApiScope>,
+}
+
+/// Resolve the `Authorization: Bearer` personal API token in `headers`, recording its use
+///
+/// Returns `Ok(None)` when no token is presented. A malformed or unknown token is an error.
+pub async fn resolve_bearer(headers: &axum::http::HeaderMap) -> Result<Option<ApiTokenGrant>, AppError> {
+    use crate::api_tokens::{parse_bearer, ApiScope};
+    use crate::crypto::hash_token;
+    use crate::db::db;
+    use crate::server_fns::auth::record_id_to_string;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct TokenRow {
+        id: surrealdb::types::RecordId,
+        owner: surrealdb::types::RecordId,
+        scopes: Vec<String>,
+    }
+
+    let Some(header) = headers.get(axum::http::header::AUTHORIZATION) else {
+        return Ok(None);
+    };
+    let token = header.to_str().ok()
+        .and_then(parse_bearer)
+        .ok_or_else(|| AppError::Auth("Malformed API token".into()))?;
+
+    let mut resp = db()
+        .query("SELECT id, owner, scopes FROM api_token WHERE token_hash = $hash AND owner.disabled != true LIMIT 1")
+        .bind(("hash", hash_token(token)))
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(AppError::Database(err_msg));
+    }
+    let row: Option<TokenRow> = resp.take(0)
+        .map_err(|e| AppError::Database(e.to_string()))?;
+    let row = row.ok_or_else(|| AppError::Auth("Invalid API token".into()))?;
+
+    if let Err(e) = db()
+        .query("UPDATE $id SET last_used_at = time::now()")
+        .bind(("id", row.id))
+        .await
+    {
+        tracing::warn!("Failed to record API token use: {}", e);
+    }
+
+    Ok(Some(ApiTokenGrant {
+        user_id: record_id_to_string(&row.owner),
+        scopes: row.scopes.iter().filter_map(|s| ApiScope::from_key(s)).collect(),
+    }))
+}
+
+/// The collection access granted by the request's API token, with the role capped by its scopes
+async fn token_access() -> Result<CollectionAccess, ServerFnError> {
+    use crate::api_tokens::ApiScope;
+    use leptos_axum::extract;
+
+    let headers: axum::http::HeaderMap = extract().await?;
+    let grant = resolve_bearer(&headers).await
+        .map_err(|e| match e {
+            AppError::Database(msg) => crate::error::internal_error("API token lookup failed", msg),
+            other => ServerFnError::new(other.to_string()),
+        })?
+        .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
+    let cap = ApiScope::max_role(&grant.scopes)
+        .ok_or_else(|| ServerFnError::new("This API token can't call the API; it is limited to its other scopes"))?;
+
+    let mut access = collection_access(&grant.user_id).await?;
+    access.role = access.role.min(cap);
+    Ok(access)
+}
+
+/// Resolve the user behind an `Authorization: Bearer` personal API token that grants `scope`
+///
+/// Returns `Ok(None)` when no token is presented, so callers can fall back to the session.
+/// A token that is unknown or lacks the scope is an error.
+pub async fn authenticate_bearer(
+    headers: &axum::http::HeaderMap,
+    scope: crate::api_tokens::ApiScope,
+) -> Result<Option<String>, AppError> {
+    let Some(grant) = resolve_bearer(headers).await? else {
+        return Ok(None);
+    };
+    if !grant.scopes.contains(&scope) {
+        return Err(AppError::Auth(format!("API token lacks the '{}' scope", scope.as_str())));
+    }
+    Ok(Some(grant.user_id))
+}
+
+/// The HTTP status for a bearer token that `authenticate_bearer` didn't accept
+///
+/// A token that couldn't be looked up is a server error rather than a rejected token.
+pub fn bearer_rejection_status(e: &AppError) -> axum::http::StatusCode {
+    match e {
+        AppError::Database(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+        _ => axum::http::StatusCode::UNAUTHORIZED,
+    }
+}
+
+/// Require a signed-in instance admin, returning their user ID
+pub async fn require_admin() -> Result<String, ServerFnError> {
+    let user = get_session_user().await?
+        .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
+    if !user.is_admin {
+        return Err(ServerFnError::new("Only instance admins can do this"));
+    }
+    Ok(user.id)
+}
+
 /// Create a session for the given user_id (store in tower-sessions)
 pub async fn create_session(user_id: &str) -> Result<(), ServerFnError> {
     use crate::error::internal_error;
@@ -47,6 +243,20 @@ pub async fn create_session(user_id: &str) -> Result<(), ServerFnError> {
     let session: Session = extract().await?;
     session.insert("user_id", user_id).await
         .map_err(|e| internal_error("Session insert failed", e))?;
+
+    // Device details shown in the active sessions list
+    let headers: axum::http::HeaderMap = extract().await?;
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+    let user_agent = header("user-agent").unwrap_or_default();
+    let ip = header("x-forwarded-for")
+        .and_then(|v| v.split(',').next().map(|s| s.trim().to_string()))
+        .or_else(|| header("x-real-ip"))
+        .unwrap_or_default();
+    let signed_in_at = chrono::Utc::now().timestamp();
+    for (key, value) in [("user_agent", serde_json::json!(user_agent)), ("ip", serde_json::json!(ip)), ("signed_in_at", serde_json::json!(signed_in_at))] {
+        session.insert(key, value).await
+            .map_err(|e| internal_error("Session insert failed", e))?;
+    }
     Ok(())
 }
 
@@ -83,7 +293,8 @@ pub async fn get_session_user() -> Result<Option<UserInfo>, ServerFnError> {
         .map_err(|e| internal_error("User ID parse failed", e))?;
 
     let mut response = db()
-        .query("SELECT id, username, email FROM user WHERE id = $id LIMIT 1")
+        // Disabled accounts lose access immediately, even with a live session
+        .query("SELECT id, username, email, is_admin FROM user WHERE id = $id AND disabled != true LIMIT 1")
         .bind(("id", record_id))
         .await
         .map_err(|e| internal_error("Session user query failed", e))?;
@@ -99,3 +310,80 @@ pub async fn get_session_user() -> Result<Option<UserInfo>, ServerFnError> {
 
     Ok(row.map(|r| r.into_user_info()))
 }
+
+/// Session key holding in-flight passkey registration state.
+const PASSKEY_REG_KEY: &str = "passkey_registration";
+/// Session key holding in-flight passkey authentication state.
+const PASSKEY_AUTH_KEY: &str = "passkey_authentication";
+
+/// The shared WebAuthn relying party, built from `WEBAUTHN_RP_ID` / `WEBAUTHN_RP_ORIGIN`
+pub fn webauthn() -> Result<&'static webauthn_rs::Webauthn, AppError> {
+    use std::sync::OnceLock;
+    use webauthn_rs::prelude::{Url, WebauthnBuilder};
+
+    static WEBAUTHN: OnceLock<webauthn_rs::Webauthn> = OnceLock::new();
+    if let Some(w) = WEBAUTHN.get() {
+        return Ok(w);
+    }
+
+    let cfg = crate::config::config();
+    let origin = Url::parse(&cfg.webauthn_rp_origin)
+        .map_err(|e| AppError::Auth(format!("Invalid WEBAUTHN_RP_ORIGIN: {}", e)))?;
+    let built = WebauthnBuilder::new(&cfg.webauthn_rp_id, &origin)
+        .and_then(|b| b.rp_name("Velamen").build())
+        .map_err(|e| AppError::Auth(format!("WebAuthn config error: {}", e)))?;
+    Ok(WEBAUTHN.get_or_init(|| built))
+}
+
+/// Stash passkey registration state in the session until the browser responds
+pub async fn store_passkey_registration(
+    state: &webauthn_rs::prelude::PasskeyRegistration,
+) -> Result<(), ServerFnError> {
+    use crate::error::internal_error;
+    use leptos_axum::extract;
+    use tower_sessions::Session;
+
+    let session: Session = extract().await?;
+    session.insert(PASSKEY_REG_KEY, state).await
+        .map_err(|e| internal_error("Session insert failed", e))?;
+    Ok(())
+}
+
+/// Take (and clear) the pending passkey registration state
+pub async fn take_passkey_registration() -> Result<webauthn_rs::prelude::PasskeyRegistration, ServerFnError> {
+    use crate::error::internal_error;
+    use leptos_axum::extract;
+    use tower_sessions::Session;
+
+    let session: Session = extract().await?;
+    session.remove(PASSKEY_REG_KEY).await
+        .map_err(|e| internal_error("Session read failed", e))?
+        .ok_or_else(|| ServerFnError::new("No passkey registration in progress"))
+}
+
+/// Stash passkey authentication state (with the user it was issued for) in the session
+pub async fn store_passkey_authentication(
+    user_id: &str,
+    state: &webauthn_rs::prelude::PasskeyAuthentication,
+) -> Result<(), ServerFnError> {
+    use crate::error::internal_error;
+    use leptos_axum::extract;
+    use tower_sessions::Session;
+
+    let session: Session = extract().await?;
+    session.insert(PASSKEY_AUTH_KEY, (user_id, state)).await
+        .map_err(|e| internal_error("Session insert failed", e))?;
+    Ok(())
+}
+
+/// Take (and clear) the pending passkey authentication state
+pub async fn take_passkey_authentication() -> Result<(String, webauthn_rs::prelude::PasskeyAuthentication), ServerFnError> {
+    use crate::error::internal_error;
+    use leptos_axum::extract;
+    use tower_sessions::Session;
+
+    let session: Session = extract().await?;
+    session.remove(PASSKEY_AUTH_KEY).await
+        .map_err(|e| internal_error("Session read failed", e))?
+        .ok_or_else(|| ServerFnError::new("No passkey sign-in in progress"))
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Longest award title accepted, matching the schema assertion.
+pub const MAX_AWARD_TITLE_LEN: usize = 100;
+/// Longest show name accepted, matching the schema assertion.
+pub const MAX_SHOW_NAME_LEN: usize = 200;
+/// Highest judging score accepted.
+pub const MAX_AWARD_POINTS: u32 = 100;
+
+/// What kind of recognition an award is, from most to least prestigious.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AwardKind {
+    /// An American Orchid Society award (HCC, AM, FCC, CCM, JC and so on).
+    Aos,
+    /// An award from another judging system, such as the RHS or JOGA.
+    Judging,
+    /// A ribbon or placing in a show class.
+    Ribbon,
+}
+
+impl AwardKind {
+    /// Every kind, in the order shown in pickers.
+    pub const ALL: [AwardKind; 3] = [AwardKind::Aos, AwardKind::Judging, AwardKind::Ribbon];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AwardKind::Aos => "Aos",
+            AwardKind::Judging => "Judging",
+            AwardKind::Ribbon => "Ribbon",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+
+    /// Display label for pickers and the awards list.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AwardKind::Aos => "AOS award",
+            AwardKind::Judging => "Judging award",
+            AwardKind::Ribbon => "Show ribbon",
+        }
+    }
+
+    /// Example title shown as the picker's placeholder.
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            AwardKind::Aos => "e.g. HCC/AOS",
+            AwardKind::Judging => "e.g. AM/RHS",
+            AwardKind::Ribbon => "e.g. 1st place, Phalaenopsis class",
+        }
+    }
+}
+
+/// One award won by a plant.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Award {
+    /// The award record ID.
+    pub id: String,
+    /// The plant that won it.
+    pub orchid_id: String,
+    /// AOS, other judging, or a show ribbon.
+    pub kind: AwardKind,
+    /// The award as written on the certificate or ribbon, such as "HCC/AOS" or "Best in Show".
+    pub title: String,
+    /// The show or judging centre where it was given.
+    pub show_name: Option<String>,
+    /// The judges' score, for point-scored awards.
+    pub points: Option<u32>,
+    /// When it was awarded.
+    pub awarded_at: DateTime<Utc>,
+    /// Photo of the plant on the bench or of the certificate.
+    pub image_filename: Option<String>,
+}
+
+/// What an orchid card shows for a plant's awards.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AwardBadge {
+    /// The most prestigious award's title; the latest wins a tie.
+    pub top_title: String,
+    /// How many awards the plant has.
+    pub count: u32,
+}
+
+/// Picks each plant's badge: its highest kind of award, then the most recent.
+pub fn award_badges(awards: &[Award]) -> HashMap<String, AwardBadge> {
+    let mut best: HashMap<&str, (&Award, u32)> = HashMap::new();
+    for award in awards {
+        best.entry(award.orchid_id.as_str())
+            .and_modify(|(top, count)| {
+                *count += 1;
+                if (award.kind, std::cmp::Reverse(award.awarded_at)) < (top.kind, std::cmp::Reverse(top.awarded_at)) {
+                    *top = award;
+                }
+            })
+            .or_insert((award, 1));
+    }
+    best.into_iter()
+        .map(|(id, (top, count))| (id.to_string(), AwardBadge { top_title: top.title.clone(), count }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn award(orchid: &str, kind: AwardKind, title: &str, days_ago: i64) -> Award {
+        Award {
+            id: format!("award:{}", title),
+            orchid_id: orchid.into(),
+            kind,
+            title: title.into(),
+            show_name: None,
+            points: None,
+            awarded_at: Utc::now() - Duration::days(days_ago),
+            image_filename: None,
+        }
+    }
+
+    #[test]
+    fn test_badge_prefers_aos_then_latest() {
+        let awards = vec![
+            award("orchid:a", AwardKind::Ribbon, "Blue ribbon", 1),
+            award("orchid:a", AwardKind::Aos, "HCC/AOS", 400),
+            award("orchid:a", AwardKind::Aos, "AM/AOS", 30),
+            award("orchid:b", AwardKind::Ribbon, "2nd place", 10),
+        ];
+        let badges = award_badges(&awards);
+        assert_eq!(badges["orchid:a"], AwardBadge { top_title: "AM/AOS".into(), count: 3 });
+        assert_eq!(badges["orchid:b"].top_title, "2nd place");
+        assert!(!badges.contains_key("orchid:c"));
+    }
+
+    #[test]
+    fn test_kind_roundtrip() {
+        for k in AwardKind::ALL {
+            assert_eq!(AwardKind::parse(k.as_str()), Some(k));
+        }
+        assert_eq!(AwardKind::parse("Trophy"), None);
+    }
+}
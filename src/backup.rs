@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Format version written to every backup; bump when a breaking change is made.
+pub const BACKUP_VERSION: u32 = 1;
+
+/// The tables `Command::Import` restores, by export name, in an order that creates what a row
+/// links to before the row. Preferences and devices hold credentials encrypted with the source
+/// instance's key, and accounts, households, sessions and push subscriptions belong to the
+/// instance, so those are exported but never imported.
+pub const RESTORE_TABLES: &[(&str, &str)] = &[
+    ("zones", "growing_zone"),
+    ("zone_goals", "zone_goal"),
+    ("zone_energy", "zone_energy"),
+    ("vendors", "vendor"),
+    ("event_types", "event_type"),
+    ("note_templates", "note_template"),
+    ("orchids", "orchid"),
+    ("orchid_revisions", "orchid_revision"),
+    ("journal", "log_entry"),
+    ("propagations", "propagation"),
+    ("awards", "award"),
+    ("treatments", "treatment"),
+    ("reminders", "reminder"),
+    ("species_aliases", "species_alias"),
+    ("species_photos", "species_photo"),
+    ("water_sources", "water_source"),
+    ("water_readings", "water_reading"),
+    ("climate_readings", "climate_reading"),
+    ("zone_dli", "zone_dli"),
+    ("climate_summaries", "climate_reading_summary"),
+    ("alerts", "alert"),
+    ("care_rules", "care_rule"),
+    ("monthly_reports", "monthly_report"),
+];
+
+/// Columns never restored because they hold credentials encrypted with the source instance's key.
+/// Exports leave them out; backups written before that still carry them.
+pub const CREDENTIAL_COLUMNS: &[&str] = &["data_source_config"];
+
+/// A user's data as one JSON document: every exported table's rows, keyed by export name
+/// (`orchids`, `journal`, ...). Record IDs and links are `table:key` strings, datetimes RFC 3339.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Backup {
+    /// `BACKUP_VERSION` at export time.
+    pub version: u32,
+    /// When the backup was written.
+    pub exported_at: DateTime<Utc>,
+    /// The account it was taken from.
+    pub username: String,
+    /// Rows per table.
+    pub tables: BTreeMap<String, Vec<serde_json::Value>>,
+}
+
+impl Backup {
+    /// Reads a backup written by `Command::Export --format json`.
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let backup: Backup = serde_json::from_str(text).map_err(|e| format!("Not a backup file: {}", e))?;
+        if backup.version > BACKUP_VERSION {
+            return Err(format!(
+                "Backup version {} is newer than this server understands ({})",
+                backup.version, BACKUP_VERSION
+            ));
+        }
+        Ok(backup)
+    }
+}
+
+/// How a column is restored, from its `DEFINE FIELD` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// A record link, exported as a `table:key` string.
+    Record,
+    /// A datetime, exported as an RFC 3339 string.
+    Datetime,
+    /// Anything JSON carries as-is.
+    Plain,
+}
+
+/// Top-level field types from `INFO FOR TABLE`'s `fields` map (name to `DEFINE FIELD` statement).
+/// Nested fields (`climate.temperature`) are left out; none of them are links or datetimes.
+pub fn field_kinds(fields: &serde_json::Map<String, serde_json::Value>) -> BTreeMap<String, FieldKind> {
+    fields
+        .iter()
+        .filter(|(name, _)| !name.contains('.') && !name.contains('['))
+        .map(|(name, definition)| {
+            let definition = definition.as_str().unwrap_or_default();
+            let ty = definition
+                .split_once(" TYPE ")
+                .map(|(_, rest)| rest.split_whitespace().next().unwrap_or_default())
+                .unwrap_or_default();
+            let ty = ty.strip_prefix("option<").unwrap_or(ty);
+            let kind = if ty.starts_with("record") {
+                FieldKind::Record
+            } else if ty.starts_with("datetime") {
+                FieldKind::Datetime
+            } else {
+                FieldKind::Plain
+            };
+            (name.clone(), kind)
+        })
+        .collect()
+}
+
+/// The statement restoring `$rows` into `table`, keeping each row's ID (so links between restored
+/// rows still hold) and giving `owner` fields to `$owner`. Only `fields` are written; a JSON null
+/// becomes NONE, links go through `type::record` and datetimes through `<datetime>`.
+pub fn restore_query(table: &str, fields: &[(String, FieldKind)], keep_ids: bool) -> String {
+    let mut sets = Vec::with_capacity(fields.len());
+    for (name, kind) in fields {
+        let value = format!("$r.`{}`", name);
+        sets.push(match kind {
+            _ if name == "owner" => "owner = $owner".to_string(),
+            FieldKind::Record => format!("`{}` = IF {} THEN type::record({}) END", name, value, value),
+            FieldKind::Datetime => format!("`{}` = IF {} THEN <datetime> {} END", name, value, value),
+            FieldKind::Plain => format!("`{}` = IF {} = NULL THEN NONE ELSE {} END", name, value, value),
+        });
+    }
+    let target = if keep_ids { "type::record($r.id)".to_string() } else { format!("`{}`", table) };
+    format!(
+        "BEGIN TRANSACTION; FOR $r IN $rows {{ CREATE {} SET {}; }}; COMMIT TRANSACTION;",
+        target,
+        sets.join(", ")
+    )
+}
+
+/// The columns of `rows` that the table's schema defines, in name order (always including
+/// `owner` when the table has one), plus whether every row has an ID to keep. Columns the schema
+/// doesn't know (computed in the export) are returned separately; `CREDENTIAL_COLUMNS` are
+/// dropped.
+pub fn restore_columns(
+    rows: &[serde_json::Value],
+    kinds: &BTreeMap<String, FieldKind>,
+) -> (Vec<(String, FieldKind)>, Vec<String>, bool) {
+    let mut seen = std::collections::BTreeSet::new();
+    for row in rows {
+        if let Some(object) = row.as_object() {
+            seen.extend(object.keys().filter(|k| k.as_str() != "id" && !CREDENTIAL_COLUMNS.contains(&k.as_str())).cloned());
+        }
+    }
+    if kinds.contains_key("owner") {
+        seen.insert("owner".to_string());
+    }
+    let known = kinds.iter()
+        .filter(|(name, _)| seen.contains(*name))
+        .map(|(name, kind)| (name.clone(), *kind))
+        .collect();
+    let unknown = seen.into_iter().filter(|name| !kinds.contains_key(name)).collect();
+    let keep_ids = !rows.is_empty() && rows.iter().all(|r| r.get("id").and_then(|id| id.as_str()).is_some());
+    (known, unknown, keep_ids)
+}
+
+/// Writes rows as CSV: one column per field seen in any row (`id` first), strings as-is and
+/// everything else as JSON. A string that would read back as something else (`"42"`, `true`, or
+/// empty) is written as a JSON string, so `rows_from_csv` gives back exactly the same rows.
+pub fn rows_to_csv(rows: &[serde_json::Value]) -> String {
+    let mut columns: Vec<String> = Vec::new();
+    for row in rows {
+        for key in row.as_object().into_iter().flat_map(|o| o.keys()) {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+    columns.sort_by_key(|c| (c != "id", c.clone()));
+
+    let mut out = csv_line(columns.iter().map(String::as_str));
+    for row in rows {
+        let cells: Vec<String> = columns.iter()
+            .map(|c| match row.get(c) {
+                None | Some(serde_json::Value::Null) => String::new(),
+                Some(serde_json::Value::String(s)) if cell_value(s) == Some(serde_json::Value::String(s.clone())) => s.clone(),
+                Some(value) => value.to_string(),
+            })
+            .collect();
+        out.push_str(&csv_line(cells.iter().map(String::as_str)));
+    }
+    out
+}
+
+/// Reads rows written by `rows_to_csv`. Empty cells are left out of the row.
+pub fn rows_from_csv(text: &str) -> Result<Vec<serde_json::Value>, String> {
+    let (headers, records) = crate::import::csv::parse_csv(text)?;
+    Ok(records
+        .into_iter()
+        .map(|record| {
+            let object: serde_json::Map<String, serde_json::Value> = headers.iter()
+                .zip(record)
+                .filter_map(|(h, cell)| cell_value(&cell).map(|v| (h.clone(), v)))
+                .collect();
+            serde_json::Value::Object(object)
+        })
+        .collect())
+}
+
+/// What a CSV cell holds: nothing when empty, JSON when it parses as JSON, otherwise the text.
+fn cell_value(cell: &str) -> Option<serde_json::Value> {
+    if cell.is_empty() {
+        return None;
+    }
+    Some(serde_json::from_str(cell).unwrap_or_else(|_| serde_json::Value::String(cell.to_string())))
+}
+
+/// One CSV record, quoting fields that need it.
+fn csv_line<'a>(fields: impl Iterator<Item = &'a str>) -> String {
+    let mut line = fields
+        .map(|f| {
+            if f.contains([',', ';', '\t', '"', '\n', '\r']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_csv_round_trip_keeps_types() {
+        let rows = vec![
+            json!({ "id": "orchid:a1", "name": "Kingie", "water_frequency_days": 7, "notes": "Line one,\n\"quoted\"", "zone": "growing_zone:z" }),
+            json!({ "id": "orchid:b2", "name": "42", "archived": true, "notes": "", "climate": { "temperature": 21.5 }, "zone": null }),
+        ];
+        let csv = rows_to_csv(&rows);
+        assert!(csv.starts_with("id,"));
+        let back = rows_from_csv(&csv).unwrap();
+        assert_eq!(back[0], rows[0]);
+        // null reads back as missing, which restores as NONE either way
+        assert_eq!(back[1], json!({ "id": "orchid:b2", "name": "42", "archived": true, "notes": "", "climate": { "temperature": 21.5 } }));
+    }
+
+    #[test]
+    fn test_restore_query_casts_links_and_datetimes() {
+        let info = json!({
+            "owner": "DEFINE FIELD owner ON log_entry TYPE record<user> PERMISSIONS FULL",
+            "orchid": "DEFINE FIELD orchid ON log_entry TYPE record<orchid> PERMISSIONS FULL",
+            "timestamp": "DEFINE FIELD timestamp ON log_entry TYPE datetime DEFAULT time::now() PERMISSIONS FULL",
+            "snoozed_until": "DEFINE FIELD snoozed_until ON log_entry TYPE option<datetime> PERMISSIONS FULL",
+            "note": "DEFINE FIELD note ON log_entry TYPE string DEFAULT '' PERMISSIONS FULL",
+            "climate.temperature": "DEFINE FIELD climate.temperature ON log_entry TYPE float PERMISSIONS FULL",
+        });
+        let kinds = field_kinds(info.as_object().unwrap());
+        assert_eq!(kinds.get("snoozed_until"), Some(&FieldKind::Datetime));
+        assert!(!kinds.contains_key("climate.temperature"));
+
+        let rows = vec![json!({ "id": "log_entry:1", "orchid": "orchid:a", "timestamp": "2026-01-02T03:04:05Z", "note": "Watered", "orchid_name": "Kingie" })];
+        let (fields, unknown, keep_ids) = restore_columns(&rows, &kinds);
+        assert_eq!(unknown, vec!["orchid_name".to_string()]);
+        assert!(keep_ids);
+        assert_eq!(
+            restore_query("log_entry", &fields, keep_ids),
+            "BEGIN TRANSACTION; FOR $r IN $rows { CREATE type::record($r.id) SET \
+             `note` = IF $r.`note` = NULL THEN NONE ELSE $r.`note` END, \
+             `orchid` = IF $r.`orchid` THEN type::record($r.`orchid`) END, \
+             owner = $owner, \
+             `timestamp` = IF $r.`timestamp` THEN <datetime> $r.`timestamp` END; }; COMMIT TRANSACTION;",
+        );
+
+        let zone_kinds = BTreeMap::from([
+            ("name".to_string(), FieldKind::Plain),
+            ("data_source_config".to_string(), FieldKind::Plain),
+        ]);
+        let zones = vec![json!({ "id": "growing_zone:a", "name": "Tent", "data_source_config": "enc:abc" })];
+        let (fields, unknown, _) = restore_columns(&zones, &zone_kinds);
+        assert_eq!(fields, vec![("name".to_string(), FieldKind::Plain)]);
+        assert!(unknown.is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{DateTime, Datelike, Utc};
+use crate::orchid::LogEntry;
+
+/// The journal event type that marks a plant in flower.
+pub const BLOOM_EVENT: &str = "Flowering";
+
+/// Longest gap between two 'Flowering' entries that still counts as the same bloom, in days.
+/// Growers log a spike opening and then photos every week or two; a longer silence means the flowers fell.
+pub const BLOOM_GAP_DAYS: i64 = 45;
+
+/// One flowering, derived from a run of 'Flowering' journal entries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BloomEvent {
+    /// The first 'Flowering' entry of the run.
+    pub started_at: DateTime<Utc>,
+    /// The last 'Flowering' entry of the run; equal to `started_at` for a single entry.
+    pub last_seen_at: DateTime<Utc>,
+    /// How many entries the run has.
+    pub entries: u32,
+}
+
+impl BloomEvent {
+    /// Days from the first to the last entry. None for a single entry, whose length isn't known.
+    pub fn duration_days(&self) -> Option<i64> {
+        (self.entries > 1).then(|| (self.last_seen_at - self.started_at).num_days())
+    }
+}
+
+/// The bloom history card's numbers for one plant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BloomStats {
+    /// Every bloom, oldest first.
+    pub blooms: Vec<BloomEvent>,
+    /// Mean length of the blooms logged more than once, in days.
+    pub average_duration_days: Option<f64>,
+    /// How many blooms started in each month, January first.
+    pub onset_months: [u32; 12],
+    /// Each year with a bloom, oldest first, and the months the plant was in flower.
+    pub years: Vec<(i32, [bool; 12])>,
+}
+
+/// Groups a plant's 'Flowering' entries into blooms, splitting wherever entries are more than `BLOOM_GAP_DAYS` apart.
+pub fn bloom_events(entries: &[LogEntry]) -> Vec<BloomEvent> {
+    let mut times: Vec<DateTime<Utc>> = entries.iter()
+        .filter(|e| e.event_type.as_deref() == Some(BLOOM_EVENT))
+        .map(|e| e.timestamp)
+        .collect();
+    times.sort();
+
+    let mut blooms: Vec<BloomEvent> = Vec::new();
+    for t in times {
+        match blooms.last_mut() {
+            Some(b) if (t - b.last_seen_at).num_days() <= BLOOM_GAP_DAYS => {
+                b.last_seen_at = t;
+                b.entries += 1;
+            }
+            _ => blooms.push(BloomEvent { started_at: t, last_seen_at: t, entries: 1 }),
+        }
+    }
+    blooms
+}
+
+/// Bloom count, average length, onset months and the year-by-month chart from a plant's journal.
+pub fn bloom_stats(entries: &[LogEntry]) -> BloomStats {
+    let blooms = bloom_events(entries);
+
+    let durations: Vec<i64> = blooms.iter().filter_map(BloomEvent::duration_days).collect();
+    let average_duration_days = (!durations.is_empty())
+        .then(|| durations.iter().sum::<i64>() as f64 / durations.len() as f64);
+
+    let mut onset_months = [0u32; 12];
+    let mut years: Vec<(i32, [bool; 12])> = Vec::new();
+    for b in &blooms {
+        onset_months[b.started_at.month0() as usize] += 1;
+
+        // Mark every month the bloom touched, carrying over into the next year if it spans New Year
+        let (mut year, mut month0) = (b.started_at.year(), b.started_at.month0());
+        let end = (b.last_seen_at.year(), b.last_seen_at.month0());
+        loop {
+            let row = match years.iter().position(|(y, _)| *y == year) {
+                Some(i) => i,
+                None => {
+                    years.push((year, [false; 12]));
+                    years.len() - 1
+                }
+            };
+            years[row].1[month0 as usize] = true;
+            if (year, month0) >= end {
+                break;
+            }
+            month0 += 1;
+            if month0 == 12 {
+                month0 = 0;
+                year += 1;
+            }
+        }
+    }
+    years.sort_by_key(|(y, _)| *y);
+
+    BloomStats { blooms, average_duration_days, onset_months, years }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(y: i32, m: u32, d: u32, event: &str) -> LogEntry {
+        LogEntry {
+            id: format!("log_entry:{}{}{}", y, m, d),
+            timestamp: Utc.with_ymd_and_hms(y, m, d, 9, 0, 0).unwrap(),
+            note: String::new(),
+            image_filename: None,
+            event_type: Some(event.into()),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
+        }
+    }
+
+    #[test]
+    fn test_entries_split_into_blooms_on_long_gaps() {
+        let entries = vec![
+            entry(2024, 2, 1, "Flowering"),
+            entry(2024, 2, 20, "Flowering"),
+            entry(2024, 3, 25, "Flowering"),
+            entry(2024, 3, 1, "Watered"),
+            entry(2025, 1, 10, "Flowering"),
+        ];
+        let blooms = bloom_events(&entries);
+        assert_eq!(blooms.len(), 2);
+        assert_eq!(blooms[0].entries, 3);
+        assert_eq!(blooms[0].duration_days(), Some(53));
+        assert_eq!(blooms[1].duration_days(), None);
+    }
+
+    #[test]
+    fn test_stats_average_onsets_and_years() {
+        let entries = vec![
+            entry(2023, 12, 10, "Flowering"),
+            entry(2024, 1, 19, "Flowering"),
+            entry(2024, 11, 30, "Flowering"),
+            entry(2024, 12, 30, "Flowering"),
+        ];
+        let stats = bloom_stats(&entries);
+        assert_eq!(stats.blooms.len(), 2);
+        assert_eq!(stats.average_duration_days, Some(35.0));
+        assert_eq!(stats.onset_months[10], 1);
+        assert_eq!(stats.onset_months[11], 1);
+        assert_eq!(stats.years.iter().map(|(y, _)| *y).collect::<Vec<_>>(), vec![2023, 2024]);
+        assert!(stats.years[0].1[11]);
+        assert!(stats.years[1].1[0] && stats.years[1].1[10] && stats.years[1].1[11]);
+        assert!(!stats.years[1].1[5]);
+    }
+
+    #[test]
+    fn test_no_flowering_entries() {
+        let stats = bloom_stats(&[entry(2024, 5, 1, "Watered")]);
+        assert!(stats.blooms.is_empty());
+        assert_eq!(stats.average_duration_days, None);
+        assert!(stats.years.is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::care_rules::{CareRule, RuleTrigger, MAX_RULES};
+use crate::goals::ZoneGoal;
+use crate::orchid::{LightRequirement, LocationType};
+
+/// Format version written to every blueprint; bump when a breaking field change is made.
+pub const BLUEPRINT_VERSION: u32 = 1;
+
+/// Maximum size of an uploaded blueprint file, in bytes.
+pub const MAX_BLUEPRINT_BYTES: usize = 512 * 1024;
+
+/// Maximum zones or devices a blueprint may carry.
+pub const MAX_BLUEPRINT_ITEMS: usize = 100;
+
+/// Config keys that hold credentials or account identity and are never exported.
+const CREDENTIAL_KEYS: &[&str] = &["token", "password", "email", "username", "api_key", "apikey", "secret", "access_token", "refresh_token"];
+
+/// A shareable snapshot of a collection's environment setup: zones, devices, climate goals
+/// and automation rules, with no plant data and no credentials.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Blueprint {
+    /// `BLUEPRINT_VERSION` at export time.
+    pub version: u32,
+    /// When the blueprint was exported.
+    pub exported_at: DateTime<Utc>,
+    /// Hardware devices, with credentials removed from their config.
+    #[serde(default)]
+    pub devices: Vec<BlueprintDevice>,
+    /// Growing zones with their data source and climate goal.
+    #[serde(default)]
+    pub zones: Vec<BlueprintZone>,
+    /// Automation rules. A climate trigger's `zone_id` holds the zone *name*, since record IDs
+    /// don't carry over between instances. Rules watching a single plant are left out.
+    #[serde(default)]
+    pub rules: Vec<CareRule>,
+}
+
+/// A hardware device in a blueprint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlueprintDevice {
+    /// Device name; zones refer to devices by this.
+    pub name: String,
+    /// Device type (e.g., "tempest").
+    pub device_type: String,
+    /// JSON config with credential fields removed.
+    #[serde(default)]
+    pub config: String,
+}
+
+/// A growing zone in a blueprint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlueprintZone {
+    /// Zone name; unique within a collection.
+    pub name: String,
+    /// Light level.
+    pub light_level: LightRequirement,
+    /// Indoor or outdoor.
+    pub location_type: LocationType,
+    /// Text description of typical temperature range.
+    #[serde(default)]
+    pub temperature_range: String,
+    /// Text description of typical humidity.
+    #[serde(default)]
+    pub humidity: String,
+    /// Notes about the zone.
+    #[serde(default)]
+    pub description: String,
+    /// Display order.
+    #[serde(default)]
+    pub sort_order: i32,
+    /// Climate data source type, if any.
+    #[serde(default)]
+    pub data_source_type: Option<String>,
+    /// Data source config with credential fields removed.
+    #[serde(default)]
+    pub data_source_config: String,
+    /// Name of the linked hardware device, if any.
+    #[serde(default)]
+    pub device: Option<String>,
+    /// Port or station number on the linked device.
+    #[serde(default)]
+    pub hardware_port: Option<i32>,
+    /// Temperature and humidity goal; `zone_id` is ignored.
+    #[serde(default)]
+    pub goal: Option<ZoneGoal>,
+}
+
+/// What a blueprint import created or skipped.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlueprintImportSummary {
+    /// Devices created.
+    pub devices_created: usize,
+    /// Zones created.
+    pub zones_created: usize,
+    /// Zone goals set.
+    pub goals_set: usize,
+    /// Rules created.
+    pub rules_created: usize,
+    /// Items skipped, with the reason.
+    pub warnings: Vec<String>,
+}
+
+/// Removes credential fields from a JSON config string, at any depth.
+///
+/// Configs that aren't JSON objects are dropped entirely, since there's no way to tell which
+/// parts are secret.
+pub fn strip_credentials(config: &str) -> String {
+    fn strip(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|k, _| !CREDENTIAL_KEYS.contains(&k.to_ascii_lowercase().as_str()));
+                map.values_mut().for_each(strip);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(strip),
+            _ => {}
+        }
+    }
+
+    match serde_json::from_str::<serde_json::Value>(config) {
+        Ok(mut value @ serde_json::Value::Object(_)) => {
+            strip(&mut value);
+            value.to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+impl Blueprint {
+    /// Parses and validates an uploaded blueprint file.
+    pub fn parse(json: &str) -> Result<Self, String> {
+        if json.len() > MAX_BLUEPRINT_BYTES {
+            return Err("Blueprint file is too large (max 512 KB)".into());
+        }
+        let blueprint: Blueprint = serde_json::from_str(json)
+            .map_err(|e| format!("This doesn't look like a blueprint file: {}", e))?;
+        blueprint.validate()?;
+        Ok(blueprint)
+    }
+
+    /// Checks the version and item counts, and that rules and zones refer to items in the blueprint.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.version == 0 || self.version > BLUEPRINT_VERSION {
+            return Err(format!("Unsupported blueprint version {}", self.version));
+        }
+        if self.zones.len() > MAX_BLUEPRINT_ITEMS || self.devices.len() > MAX_BLUEPRINT_ITEMS {
+            return Err(format!("A blueprint can hold at most {} zones and {} devices", MAX_BLUEPRINT_ITEMS, MAX_BLUEPRINT_ITEMS));
+        }
+        if self.rules.len() > MAX_RULES {
+            return Err(format!("A blueprint can hold at most {} rules", MAX_RULES));
+        }
+        for zone in &self.zones {
+            if let Some(device) = &zone.device
+                && !self.devices.iter().any(|d| d.name == *device) {
+                return Err(format!("Zone \"{}\" uses device \"{}\", which isn't in the blueprint", zone.name, device));
+            }
+        }
+        for rule in &self.rules {
+            if let RuleTrigger::Climate { zone_id: Some(zone), .. } = &rule.trigger
+                && !self.zones.iter().any(|z| z.name == *zone) {
+                return Err(format!("Rule \"{}\" watches zone \"{}\", which isn't in the blueprint", rule.name, zone));
+            }
+            if let RuleTrigger::Climate { orchid_id: Some(_), .. } = &rule.trigger {
+                return Err(format!("Rule \"{}\" watches a single plant, which blueprints can't include", rule.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// A short description for the import preview, e.g. "3 zones, 1 device, 2 rules".
+    pub fn describe(&self) -> String {
+        let count = |n: usize, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+        [
+            count(self.zones.len(), "zone", "zones"),
+            count(self.devices.len(), "device", "devices"),
+            count(self.rules.len(), "rule", "rules"),
+        ].join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::care_rules::{ClimateMetric, Comparison, RuleAction};
+
+    fn zone(name: &str) -> BlueprintZone {
+        BlueprintZone {
+            name: name.into(),
+            light_level: LightRequirement::Medium,
+            location_type: LocationType::Indoor,
+            temperature_range: String::new(),
+            humidity: String::new(),
+            description: String::new(),
+            sort_order: 0,
+            data_source_type: None,
+            data_source_config: String::new(),
+            device: None,
+            hardware_port: None,
+            goal: None,
+        }
+    }
+
+    fn blueprint() -> Blueprint {
+        Blueprint {
+            version: BLUEPRINT_VERSION,
+            exported_at: Utc::now(),
+            devices: vec![],
+            zones: vec![zone("Cabinet")],
+            rules: vec![],
+        }
+    }
+
+    #[test]
+    fn test_strip_credentials_removes_secrets() {
+        let stripped = strip_credentials(r#"{"station_id":"123","token":"abc","nested":{"Password":"x","port":2}}"#);
+        let value: serde_json::Value = serde_json::from_str(&stripped).unwrap();
+        assert_eq!(value["station_id"], "123");
+        assert!(value.get("token").is_none());
+        assert!(value["nested"].get("Password").is_none());
+        assert_eq!(value["nested"]["port"], 2);
+    }
+
+    #[test]
+    fn test_strip_credentials_drops_non_objects() {
+        assert_eq!(strip_credentials(""), "");
+        assert_eq!(strip_credentials("not json"), "");
+        assert_eq!(strip_credentials("[1,2]"), "");
+    }
+
+    #[test]
+    fn test_validate_rejects_dangling_references() {
+        let mut bp = blueprint();
+        bp.zones[0].device = Some("Tent controller".into());
+        assert!(bp.validate().is_err());
+
+        let mut bp = blueprint();
+        bp.rules.push(CareRule {
+            id: String::new(),
+            name: "Heat".into(),
+            enabled: true,
+            trigger: RuleTrigger::Climate {
+                zone_id: Some("Greenhouse".into()),
+                orchid_id: None,
+                metric: ClimateMetric::Temperature,
+                comparison: Comparison::Above,
+                threshold: 32.0,
+                for_minutes: 60,
+            },
+            actions: vec![RuleAction::Alert { severity: "warning".into() }],
+            last_fired_at: None,
+        });
+        assert!(bp.validate().is_err());
+        bp.zones.push(zone("Greenhouse"));
+        assert!(bp.validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_rejects_future_versions() {
+        let mut bp = blueprint();
+        bp.version = BLUEPRINT_VERSION + 1;
+        let json = serde_json::to_string(&bp).unwrap();
+        assert!(Blueprint::parse(&json).is_err());
+        assert!(Blueprint::parse(&serde_json::to_string(&blueprint()).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(blueprint().describe(), "1 zone, 0 devices, 0 rules");
+    }
+}
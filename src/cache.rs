@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Entries a cache holds before it sweeps out the expired ones on insert.
+const SWEEP_AT: usize = 256;
+
+/// A small in-memory cache whose entries expire `ttl` after they're stored. Server functions keep
+/// one in a `LazyLock` static per hot read path, keyed by user or zone, and call `invalidate` from
+/// every write to the rows behind it, so the TTL only bounds how stale a change made outside this
+/// process (another instance, a CLI command) can look.
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    /// An empty cache whose entries live for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, entries: Mutex::new(HashMap::new()) }
+    }
+
+    /// The value stored for `key`, if it hasn't expired.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let entries = self.entries.lock().ok()?;
+        entries.get(key)
+            .filter(|(stored_at, _)| stored_at.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Stores `value` for `key`, replacing what was there.
+    pub fn insert(&self, key: K, value: V) {
+        if let Ok(mut entries) = self.entries.lock() {
+            if entries.len() >= SWEEP_AT {
+                entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+            }
+            entries.insert(key, (Instant::now(), value));
+        }
+    }
+
+    /// Forgets `key`, so the next read goes to the database.
+    pub fn invalidate(&self, key: &K) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+
+    /// Forgets everything, for writes that can't easily say which keys they touched.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_expire_and_invalidate() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        cache.insert("user:a".to_string(), vec![1, 2]);
+        cache.insert("user:b".to_string(), vec![3]);
+        assert_eq!(cache.get(&"user:a".to_string()), Some(vec![1, 2]));
+
+        cache.invalidate(&"user:a".to_string());
+        assert_eq!(cache.get(&"user:a".to_string()), None);
+        assert_eq!(cache.get(&"user:b".to_string()), Some(vec![3]));
+        cache.clear();
+        assert_eq!(cache.get(&"user:b".to_string()), None);
+
+        let expired = TtlCache::new(Duration::ZERO);
+        expired.insert(1, "stale");
+        assert_eq!(expired.get(&1), None);
+    }
+}
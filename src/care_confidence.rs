@@ -0,0 +1,238 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::light::parse_lux_range;
+use crate::orchid::{LogEntry, Orchid, PotType};
+
+/// A photo newer than this many days counts as recent.
+pub const RECENT_PHOTO_DAYS: i64 = 90;
+
+/// One piece of plant data the smarter features depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConfidenceFactor {
+    /// Minimum and maximum temperature tolerances.
+    TemperatureRange,
+    /// Minimum and maximum humidity.
+    HumidityRange,
+    /// Measured PAR or a lux range.
+    LightLevel,
+    /// Rest or bloom season months.
+    SeasonalMonths,
+    /// Native habitat coordinates.
+    NativeHabitat,
+    /// Pot medium and pot type.
+    PotSetup,
+    /// A journal photo from the last `RECENT_PHOTO_DAYS` days.
+    RecentPhoto,
+    /// The growing zone the plant lives in.
+    Placement,
+}
+
+impl ConfidenceFactor {
+    /// Every factor, most impactful first.
+    pub const ALL: [ConfidenceFactor; 8] = [
+        ConfidenceFactor::TemperatureRange,
+        ConfidenceFactor::HumidityRange,
+        ConfidenceFactor::LightLevel,
+        ConfidenceFactor::SeasonalMonths,
+        ConfidenceFactor::NativeHabitat,
+        ConfidenceFactor::PotSetup,
+        ConfidenceFactor::RecentPhoto,
+        ConfidenceFactor::Placement,
+    ];
+
+    /// Points this factor adds to the score; all factors sum to 100.
+    pub fn weight(&self) -> u32 {
+        match self {
+            ConfidenceFactor::TemperatureRange => 20,
+            ConfidenceFactor::HumidityRange
+            | ConfidenceFactor::LightLevel
+            | ConfidenceFactor::SeasonalMonths => 15,
+            ConfidenceFactor::NativeHabitat
+            | ConfidenceFactor::PotSetup
+            | ConfidenceFactor::RecentPhoto => 10,
+            ConfidenceFactor::Placement => 5,
+        }
+    }
+
+    /// Short name of the missing data.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfidenceFactor::TemperatureRange => "Temperature range",
+            ConfidenceFactor::HumidityRange => "Humidity range",
+            ConfidenceFactor::LightLevel => "Light level",
+            ConfidenceFactor::SeasonalMonths => "Rest and bloom months",
+            ConfidenceFactor::NativeHabitat => "Native habitat location",
+            ConfidenceFactor::PotSetup => "Pot medium and type",
+            ConfidenceFactor::RecentPhoto => "Recent photo",
+            ConfidenceFactor::Placement => "Growing zone",
+        }
+    }
+
+    /// What filling it in unlocks, shown as the prompt.
+    pub fn prompt(&self) -> &'static str {
+        match self {
+            ConfidenceFactor::TemperatureRange => "Add min/max temperatures so climate alerts can warn you before it gets too hot or cold.",
+            ConfidenceFactor::HumidityRange => "Add a humidity range so dry-air alerts and VPD advice fit this plant.",
+            ConfidenceFactor::LightLevel => "Add PAR or a lux range so light sensors can tell you if it's too dim or too bright.",
+            ConfidenceFactor::SeasonalMonths => "Set rest and bloom months so watering and fertilizer adjust through the year.",
+            ConfidenceFactor::NativeHabitat => "Identify the species with the scanner to add its native habitat and compare your conditions with its weather.",
+            ConfidenceFactor::PotSetup => "Record the pot medium and type so watering intervals account for how fast it dries.",
+            ConfidenceFactor::RecentPhoto => "Take a photo so you can track growth and spot problems early.",
+            ConfidenceFactor::Placement => "Assign a growing zone so the plant gets that zone's live climate.",
+        }
+    }
+
+    /// Whether the factor can be filled in from the plant's edit form.
+    /// Photos come from the journal and native coordinates from species identification.
+    pub fn is_editable(&self) -> bool {
+        !matches!(self, ConfidenceFactor::RecentPhoto | ConfidenceFactor::NativeHabitat)
+    }
+
+    /// Whether `orchid` (with its newest photo at `last_photo_at`) has this data as of `now`.
+    fn is_present(&self, orchid: &Orchid, last_photo_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        match self {
+            ConfidenceFactor::TemperatureRange => orchid.temp_min.is_some() && orchid.temp_max.is_some(),
+            ConfidenceFactor::HumidityRange => orchid.humidity_min.is_some(),
+            ConfidenceFactor::LightLevel => orchid.par_ppfd.is_some() || parse_lux_range(&orchid.light_lux).is_some(),
+            ConfidenceFactor::SeasonalMonths => {
+                (orchid.rest_start_month.is_some() && orchid.rest_end_month.is_some())
+                    || (orchid.bloom_start_month.is_some() && orchid.bloom_end_month.is_some())
+            }
+            ConfidenceFactor::NativeHabitat => orchid.native_latitude.is_some() && orchid.native_longitude.is_some(),
+            // Mounted plants have no medium
+            ConfidenceFactor::PotSetup => match orchid.pot_type {
+                Some(PotType::Mounted) => true,
+                Some(PotType::Unknown) | None => false,
+                Some(_) => orchid.pot_medium.is_some(),
+            },
+            ConfidenceFactor::RecentPhoto => last_photo_at
+                .is_some_and(|at| now - at <= Duration::days(RECENT_PHOTO_DAYS)),
+            ConfidenceFactor::Placement => !orchid.placement.trim().is_empty(),
+        }
+    }
+}
+
+/// How much the app's advice for a plant can be trusted, in broad terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfidenceLevel {
+    /// Below 50: most advice falls back to generic defaults.
+    Low,
+    /// 50 to 79.
+    Fair,
+    /// 80 and above.
+    High,
+}
+
+impl ConfidenceLevel {
+    /// The level for a 0–100 score.
+    pub fn from_score(score: u32) -> Self {
+        match score {
+            0..=49 => ConfidenceLevel::Low,
+            50..=79 => ConfidenceLevel::Fair,
+            _ => ConfidenceLevel::High,
+        }
+    }
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfidenceLevel::Low => "Low",
+            ConfidenceLevel::Fair => "Fair",
+            ConfidenceLevel::High => "High",
+        }
+    }
+}
+
+/// **What is it?**
+/// A plant's care confidence: a 0–100 completeness score and the data still missing.
+///
+/// **Why does it exist?**
+/// It exists because climate alerts, seasonal schedules, and light checks quietly fall back to defaults when a plant's profile is thin; the score makes that visible and says what to fill in.
+///
+/// **How should it be used?**
+/// Build with `assess` and show the score with the first few `missing` prompts, which are ordered by impact.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareConfidence {
+    /// Sum of the weights of the factors present, 0–100.
+    pub score: u32,
+    /// The broad level for `score`.
+    pub level: ConfidenceLevel,
+    /// Factors not yet filled in, most impactful first.
+    pub missing: Vec<ConfidenceFactor>,
+}
+
+/// The newest photo timestamp among a plant's journal entries.
+pub fn last_photo_at(entries: &[LogEntry]) -> Option<DateTime<Utc>> {
+    entries.iter()
+        .filter(|e| e.image_filename.as_deref().is_some_and(|f| !f.is_empty()))
+        .map(|e| e.timestamp)
+        .max()
+}
+
+/// **What is it?**
+/// A pure function that scores how complete a plant's care profile is.
+///
+/// **Why does it exist?**
+/// It exists so the score is computed one way, testable without a database or UI.
+///
+/// **How should it be used?**
+/// Call with the plant, its newest photo time (see `last_photo_at`), and the current time.
+pub fn assess(orchid: &Orchid, last_photo_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> CareConfidence {
+    let (present, missing): (Vec<ConfidenceFactor>, Vec<ConfidenceFactor>) = ConfidenceFactor::ALL
+        .into_iter()
+        .partition(|f| f.is_present(orchid, last_photo_at, now));
+    let score = present.iter().map(|f| f.weight()).sum();
+    CareConfidence {
+        score,
+        level: ConfidenceLevel::from_score(score),
+        missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchid::PotMedium;
+    use crate::test_helpers::test_orchid;
+
+    #[test]
+    fn test_weights_sum_to_100() {
+        assert_eq!(ConfidenceFactor::ALL.iter().map(|f| f.weight()).sum::<u32>(), 100);
+    }
+
+    #[test]
+    fn test_minimal_plant_is_low_with_ordered_prompts() {
+        let now = Utc::now();
+        let result = assess(&test_orchid(), None, now);
+        // Only the placement is set
+        assert_eq!(result.score, 5);
+        assert_eq!(result.level, ConfidenceLevel::Low);
+        assert_eq!(result.missing.first(), Some(&ConfidenceFactor::TemperatureRange));
+        assert!(!result.missing.contains(&ConfidenceFactor::Placement));
+    }
+
+    #[test]
+    fn test_complete_plant_scores_100() {
+        let now = Utc::now();
+        let orchid = Orchid {
+            temp_min: Some(15.0),
+            temp_max: Some(30.0),
+            humidity_min: Some(50.0),
+            light_lux: "10,000-20,000".into(),
+            rest_start_month: Some(11),
+            rest_end_month: Some(2),
+            native_latitude: Some(14.5),
+            native_longitude: Some(121.0),
+            pot_medium: Some(PotMedium::Bark),
+            pot_type: Some(PotType::Clay),
+            ..test_orchid()
+        };
+        let result = assess(&orchid, Some(now - Duration::days(10)), now);
+        assert_eq!(result.score, 100);
+        assert_eq!(result.level, ConfidenceLevel::High);
+        assert!(result.missing.is_empty());
+
+        let stale = assess(&orchid, Some(now - Duration::days(RECENT_PHOTO_DAYS + 1)), now);
+        assert_eq!(stale.missing, vec![ConfidenceFactor::RecentPhoto]);
+    }
+}
@@ -0,0 +1,544 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::{ClimateReading, Hemisphere, Orchid, SeasonalPhase};
+
+/// Maximum number of automation rules per collection.
+pub const MAX_RULES: usize = 50;
+
+/// Longest a climate condition can be required to hold, in minutes (one week).
+pub const MAX_SUSTAIN_MINUTES: u32 = 7 * 24 * 60;
+
+/// Minimum hours between two firings of the same climate rule.
+pub const RULE_COOLDOWN_HOURS: i64 = 12;
+
+/// Longest suggestion text a rule can carry.
+pub const MAX_SUGGESTION_LEN: usize = 200;
+
+/// Which zone reading a climate trigger watches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClimateMetric {
+    /// Air temperature in Celsius.
+    Temperature,
+    /// Relative humidity percentage.
+    Humidity,
+    /// Vapor pressure deficit in kPa.
+    Vpd,
+}
+
+impl ClimateMetric {
+    /// All metrics, in the order the rule builder lists them.
+    pub const ALL: [ClimateMetric; 3] = [ClimateMetric::Temperature, ClimateMetric::Humidity, ClimateMetric::Vpd];
+
+    /// Stable key used by the rule builder's select.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClimateMetric::Temperature => "temperature",
+            ClimateMetric::Humidity => "humidity",
+            ClimateMetric::Vpd => "vpd",
+        }
+    }
+
+    /// Parses a key from `as_str`.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == key)
+    }
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClimateMetric::Temperature => "Temperature",
+            ClimateMetric::Humidity => "Humidity",
+            ClimateMetric::Vpd => "VPD",
+        }
+    }
+
+    /// Unit suffix for thresholds (temperatures are stored in Celsius).
+    pub fn unit(&self) -> &'static str {
+        match self {
+            ClimateMetric::Temperature => "C",
+            ClimateMetric::Humidity => "%",
+            ClimateMetric::Vpd => " kPa",
+        }
+    }
+
+    /// The metric's value in a reading, if the reading has it.
+    pub fn value_of(&self, reading: &ClimateReading) -> Option<f64> {
+        match self {
+            ClimateMetric::Temperature => Some(reading.temperature),
+            ClimateMetric::Humidity => Some(reading.humidity),
+            ClimateMetric::Vpd => reading.vpd,
+        }
+    }
+}
+
+/// Direction of a threshold comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    /// Strictly greater than the threshold.
+    Above,
+    /// Strictly less than the threshold.
+    Below,
+}
+
+impl Comparison {
+    /// Whether `value` is on the triggering side of `threshold`.
+    pub fn matches(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::Above => value > threshold,
+            Comparison::Below => value < threshold,
+        }
+    }
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Comparison::Above => "above",
+            Comparison::Below => "below",
+        }
+    }
+}
+
+/// What makes a rule fire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RuleTrigger {
+    /// A zone reading stays past a threshold for a sustained period. Checked after each climate poll.
+    Climate {
+        /// Zone record ID to watch, or `None` for every zone.
+        zone_id: Option<String>,
+        /// Plant record ID to watch instead of a zone: the rule follows the zone the plant is placed in, and alerts name the plant.
+        #[serde(default)]
+        orchid_id: Option<String>,
+        /// Which reading to compare.
+        metric: ClimateMetric,
+        /// Direction of the comparison.
+        comparison: Comparison,
+        /// Threshold in the metric's unit.
+        threshold: f64,
+        /// How long every reading must be past the threshold, in minutes. 0 fires on a single reading.
+        for_minutes: u32,
+    },
+    /// A plant's seasonal phase changes to `phase`. Checked by the daily scheduling job.
+    PhaseEntered {
+        /// The phase being entered (Rest, Active, or Blooming).
+        phase: SeasonalPhase,
+    },
+}
+
+/// What a rule does when it fires.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RuleAction {
+    /// Create an alert (and push notification for warning/critical).
+    Alert {
+        /// "info", "warning", or "critical".
+        severity: String,
+    },
+    /// Suggest a care step for each affected plant.
+    SuggestCare {
+        /// The suggestion, e.g. "Mist an extra time today".
+        suggestion: String,
+        /// Only suggest for plants whose minimum humidity is at least this (e.g. 70 for high-humidity plants).
+        #[serde(default)]
+        min_plant_humidity: Option<f64>,
+    },
+    /// Hold fertilizer reminders while the plant is in the trigger's phase.
+    PauseFertilizer,
+}
+
+/// **What is it?**
+/// A user-defined "when this happens, do that" automation rule for the collection.
+///
+/// **Why does it exist?**
+/// It exists so growers can encode their own routines ("when the greenhouse tops 32°C for 2 hours, remind me to mist the high-humidity plants") instead of relying only on the built-in alerts.
+///
+/// **How should it be used?**
+/// Build and edit rules in the settings rule builder, persist them with `save_care_rule`, and let the climate poller and daily job evaluate them. Use `fertilizer_paused` when showing fertilizer due dates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareRule {
+    /// The rule's record ID (empty for a new rule).
+    pub id: String,
+    /// Short name shown in the rule list and in alerts.
+    pub name: String,
+    /// Disabled rules are kept but never evaluated.
+    pub enabled: bool,
+    /// What makes the rule fire.
+    pub trigger: RuleTrigger,
+    /// What happens when it fires, in order.
+    pub actions: Vec<RuleAction>,
+    /// When the rule last fired, if ever.
+    #[serde(default)]
+    pub last_fired_at: Option<DateTime<Utc>>,
+}
+
+impl CareRule {
+    /// Checks the rule is complete and within limits.
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() || name.len() > 100 {
+            return Err("Rule name must be 1-100 characters".into());
+        }
+        if self.actions.is_empty() {
+            return Err("Choose at least one action".into());
+        }
+        match &self.trigger {
+            RuleTrigger::Climate { zone_id, orchid_id, metric, threshold, for_minutes, .. } => {
+                if zone_id.is_some() && orchid_id.is_some() {
+                    return Err("Watch a zone or a plant, not both".into());
+                }
+                if !threshold.is_finite() {
+                    return Err("Enter a threshold".into());
+                }
+                if *metric == ClimateMetric::Humidity && !(0.0..=100.0).contains(threshold) {
+                    return Err("Humidity thresholds must be between 0 and 100%".into());
+                }
+                if *for_minutes > MAX_SUSTAIN_MINUTES {
+                    return Err("Conditions can be required to hold for at most 7 days".into());
+                }
+                if self.actions.contains(&RuleAction::PauseFertilizer) {
+                    return Err("Pausing fertilizer only works with a seasonal phase trigger".into());
+                }
+            }
+            RuleTrigger::PhaseEntered { phase } => {
+                if *phase == SeasonalPhase::Unknown {
+                    return Err("Choose a seasonal phase".into());
+                }
+            }
+        }
+        for action in &self.actions {
+            match action {
+                RuleAction::Alert { severity } => {
+                    if !matches!(severity.as_str(), "info" | "warning" | "critical") {
+                        return Err("Alert severity must be info, warning, or critical".into());
+                    }
+                }
+                RuleAction::SuggestCare { suggestion, min_plant_humidity } => {
+                    let s = suggestion.trim();
+                    if s.is_empty() || s.len() > MAX_SUGGESTION_LEN {
+                        return Err(format!("Suggestions must be 1-{} characters", MAX_SUGGESTION_LEN));
+                    }
+                    if let Some(h) = min_plant_humidity
+                        && !(0.0..=100.0).contains(h)
+                    {
+                        return Err("Plant humidity filter must be between 0 and 100%".into());
+                    }
+                }
+                RuleAction::PauseFertilizer => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// One-line summary of the trigger, e.g. "Temperature above 32C for 2h in Greenhouse".
+    ///
+    /// `name_of` resolves a zone or plant record ID to its name.
+    pub fn describe_trigger(&self, name_of: impl Fn(&str) -> Option<String>) -> String {
+        match &self.trigger {
+            RuleTrigger::Climate { zone_id, orchid_id, metric, comparison, threshold, for_minutes } => {
+                let place = match (orchid_id.as_deref(), zone_id.as_deref()) {
+                    (Some(id), _) => format!("where {} grows", name_of(id).unwrap_or_else(|| "a deleted plant".into())),
+                    (None, Some(id)) => format!("in {}", name_of(id).unwrap_or_else(|| "a deleted zone".into())),
+                    (None, None) => "in any zone".into(),
+                };
+                let held = if *for_minutes == 0 { String::new() } else { format!(" for {}", format_minutes(*for_minutes)) };
+                format!("{} {} {}{}{} {}", metric.label(), comparison.label(), threshold, metric.unit(), held, place)
+            }
+            RuleTrigger::PhaseEntered { phase } => format!("A plant enters its {} phase", phase),
+        }
+    }
+
+    /// Whether the rule may fire again at `now`, given its cooldown.
+    pub fn is_cooled_down(&self, now: DateTime<Utc>) -> bool {
+        self.last_fired_at
+            .map(|t| now - t >= Duration::hours(RULE_COOLDOWN_HOURS))
+            .unwrap_or(true)
+    }
+}
+
+/// Formats a duration in minutes as "45m", "2h", or "1h 30m".
+pub fn format_minutes(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+/// **What is it?**
+/// A pure function that decides whether a climate trigger's condition has held for its full duration.
+///
+/// **Why does it exist?**
+/// It exists so a single hot reading doesn't fire a "for 2 hours" rule, while keeping the check side-effect free and testable.
+///
+/// **How should it be used?**
+/// Pass one zone's readings ordered oldest first (covering at least `for_minutes` plus one polling interval) and the current time. Returns the latest value when the condition held throughout, otherwise `None`.
+pub fn condition_held(
+    readings: &[ClimateReading],
+    metric: ClimateMetric,
+    comparison: Comparison,
+    threshold: f64,
+    for_minutes: u32,
+    now: DateTime<Utc>,
+) -> Option<f64> {
+    let values: Vec<(DateTime<Utc>, f64)> = readings.iter()
+        .filter_map(|r| metric.value_of(r).map(|v| (r.recorded_at, v)))
+        .collect();
+    let (_, latest) = *values.last()?;
+    if !comparison.matches(latest, threshold) {
+        return None;
+    }
+
+    // Walk back to the start of the current unbroken run of matching readings
+    let run_start = values.iter().rev()
+        .take_while(|(_, v)| comparison.matches(*v, threshold))
+        .last()
+        .map(|(t, _)| *t)?;
+
+    (now - run_start >= Duration::minutes(for_minutes as i64)).then_some(latest)
+}
+
+/// **What is it?**
+/// A pure function that decides whether a plant has just entered `phase`.
+///
+/// **Why does it exist?**
+/// It exists so phase rules fire once at the start of a rest or bloom period rather than every day of it.
+///
+/// **How should it be used?**
+/// Call from the daily scheduling job for each plant and phase rule; fire the rule's actions when it returns true.
+pub fn entered_phase(orchid: &Orchid, phase: &SeasonalPhase, hemisphere: &Hemisphere, month: u32) -> bool {
+    let previous = if month == 1 { 12 } else { month - 1 };
+    orchid.phase_in_month(hemisphere, month) == *phase
+        && orchid.phase_in_month(hemisphere, previous) != *phase
+}
+
+/// **What is it?**
+/// A pure function that decides whether the owner's own rules take over a plant's built-in range alert for one metric.
+///
+/// **Why does it exist?**
+/// It exists because cabinets have very different safe ranges: once a grower sets an alert rule for a zone or plant, the generic plant-range alert for the same reading would only repeat it with the wrong threshold.
+///
+/// **How should it be used?**
+/// Call from the alert check for each plant, passing the zone it grows in. Only enabled climate rules that create an alert and are scoped to that plant or zone count; "any zone" rules add to the built-in checks instead.
+pub fn replaces_builtin_check(rules: &[CareRule], orchid_id: &str, zone_id: &str, metric: ClimateMetric) -> bool {
+    rules.iter().any(|r| {
+        r.enabled
+            && r.actions.iter().any(|a| matches!(a, RuleAction::Alert { .. }))
+            && match &r.trigger {
+                RuleTrigger::Climate { zone_id: z, orchid_id: o, metric: m, .. } => {
+                    *m == metric && (o.as_deref() == Some(orchid_id) || (o.is_none() && z.as_deref() == Some(zone_id)))
+                }
+                RuleTrigger::PhaseEntered { .. } => false,
+            }
+    })
+}
+
+/// Whether a plant's minimum humidity qualifies it for a suggestion's humidity filter.
+pub fn suggestion_applies(min_plant_humidity: Option<f64>, plant_humidity_min: Option<f64>) -> bool {
+    match min_plant_humidity {
+        None => true,
+        Some(filter) => plant_humidity_min.is_some_and(|h| h >= filter),
+    }
+}
+
+/// **What is it?**
+/// A pure function that finds the enabled rule pausing fertilizer for a plant right now.
+///
+/// **Why does it exist?**
+/// It exists so "when a plant enters Rest, pause fertilizer" rules hold fertilizer reminders for the whole phase.
+///
+/// **How should it be used?**
+/// Call wherever fertilizer due dates are shown; when it returns a rule, show the fertilizer as paused by that rule instead of due.
+pub fn fertilizer_paused<'a>(rules: &'a [CareRule], orchid: &Orchid, hemisphere: &Hemisphere) -> Option<&'a CareRule> {
+    let phase = orchid.current_phase(hemisphere);
+    rules.iter().find(|r| {
+        r.enabled
+            && r.actions.contains(&RuleAction::PauseFertilizer)
+            && matches!(&r.trigger, RuleTrigger::PhaseEntered { phase: p } if *p == phase)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    fn reading(minutes_ago: i64, temperature: f64, now: DateTime<Utc>) -> ClimateReading {
+        ClimateReading {
+            id: String::new(),
+            zone_id: "growing_zone:gh".into(),
+            zone_name: "Greenhouse".into(),
+            temperature,
+            humidity: 60.0,
+            vpd: None,
+            dew_point: None,
+            precipitation: None,
+            uv_index: None,
+            solar_radiation: None,
+            wind_speed: None,
+            wind_gust: None,
+            lux: None,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
+            source: None,
+            recorded_at: now - Duration::minutes(minutes_ago),
+        }
+    }
+
+    fn heat_rule() -> CareRule {
+        CareRule {
+            id: String::new(),
+            name: "Heat wave".into(),
+            enabled: true,
+            trigger: RuleTrigger::Climate {
+                zone_id: None,
+                orchid_id: None,
+                metric: ClimateMetric::Temperature,
+                comparison: Comparison::Above,
+                threshold: 32.0,
+                for_minutes: 120,
+            },
+            actions: vec![RuleAction::Alert { severity: "warning".into() }],
+            last_fired_at: None,
+        }
+    }
+
+    #[test]
+    fn test_condition_held_for_full_duration() {
+        let now = Utc::now();
+        let readings: Vec<_> = [(180, 30.0), (150, 33.0), (120, 33.5), (60, 34.0), (0, 33.0)]
+            .into_iter().map(|(m, t)| reading(m, t, now)).collect();
+        assert_eq!(condition_held(&readings, ClimateMetric::Temperature, Comparison::Above, 32.0, 120, now), Some(33.0));
+    }
+
+    #[test]
+    fn test_condition_not_held_long_enough() {
+        let now = Utc::now();
+        let readings: Vec<_> = [(150, 33.0), (90, 31.0), (60, 34.0), (0, 33.0)]
+            .into_iter().map(|(m, t)| reading(m, t, now)).collect();
+        assert_eq!(condition_held(&readings, ClimateMetric::Temperature, Comparison::Above, 32.0, 120, now), None);
+        // A zero-minute rule fires on the latest reading alone
+        assert_eq!(condition_held(&readings, ClimateMetric::Temperature, Comparison::Above, 32.0, 0, now), Some(33.0));
+    }
+
+    #[test]
+    fn test_condition_requires_latest_reading_to_match() {
+        let now = Utc::now();
+        let readings: Vec<_> = [(180, 34.0), (60, 34.0), (0, 31.0)]
+            .into_iter().map(|(m, t)| reading(m, t, now)).collect();
+        assert_eq!(condition_held(&readings, ClimateMetric::Temperature, Comparison::Above, 32.0, 120, now), None);
+        assert_eq!(condition_held(&[], ClimateMetric::Temperature, Comparison::Above, 32.0, 0, now), None);
+    }
+
+    #[test]
+    fn test_validate_rejects_fertilizer_pause_on_climate_trigger() {
+        let mut rule = heat_rule();
+        assert!(rule.validate().is_ok());
+        rule.actions.push(RuleAction::PauseFertilizer);
+        assert!(rule.validate().is_err());
+        rule.actions.clear();
+        assert!(rule.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_zone_and_plant_scope_together() {
+        let mut rule = heat_rule();
+        if let RuleTrigger::Climate { ref mut zone_id, ref mut orchid_id, .. } = rule.trigger {
+            *zone_id = Some("growing_zone:gh".into());
+            *orchid_id = Some("orchid:1".into());
+        }
+        assert!(rule.validate().is_err());
+    }
+
+    #[test]
+    fn test_describe_plant_scoped_trigger() {
+        let mut rule = heat_rule();
+        if let RuleTrigger::Climate { ref mut orchid_id, .. } = rule.trigger {
+            *orchid_id = Some("orchid:1".into());
+        }
+        assert_eq!(rule.describe_trigger(|_| Some("Sweetheart".into())), "Temperature above 32C for 2h where Sweetheart grows");
+        assert_eq!(heat_rule().describe_trigger(|_| None), "Temperature above 32C for 2h in any zone");
+    }
+
+    #[test]
+    fn test_scoped_alert_rules_replace_builtin_checks() {
+        let scoped = |zone: Option<&str>, orchid: Option<&str>| {
+            let mut rule = heat_rule();
+            if let RuleTrigger::Climate { ref mut zone_id, ref mut orchid_id, .. } = rule.trigger {
+                *zone_id = zone.map(Into::into);
+                *orchid_id = orchid.map(Into::into);
+            }
+            rule
+        };
+        let temp = ClimateMetric::Temperature;
+
+        // "Any zone" rules add to the built-in checks
+        assert!(!replaces_builtin_check(&[heat_rule()], "orchid:1", "growing_zone:gh", temp));
+        assert!(replaces_builtin_check(&[scoped(Some("growing_zone:gh"), None)], "orchid:1", "growing_zone:gh", temp));
+        assert!(!replaces_builtin_check(&[scoped(Some("growing_zone:gh"), None)], "orchid:1", "growing_zone:gh", ClimateMetric::Humidity));
+        assert!(replaces_builtin_check(&[scoped(None, Some("orchid:1"))], "orchid:1", "growing_zone:gh", temp));
+        assert!(!replaces_builtin_check(&[scoped(None, Some("orchid:2"))], "orchid:1", "growing_zone:gh", temp));
+
+        // Disabled rules and rules that only suggest care leave the built-in alert in place
+        let mut disabled = scoped(Some("growing_zone:gh"), None);
+        disabled.enabled = false;
+        let mut suggest_only = scoped(Some("growing_zone:gh"), None);
+        suggest_only.actions = vec![RuleAction::SuggestCare { suggestion: "Open the vents".into(), min_plant_humidity: None }];
+        assert!(!replaces_builtin_check(&[disabled, suggest_only], "orchid:1", "growing_zone:gh", temp));
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let now = Utc::now();
+        let mut rule = heat_rule();
+        assert!(rule.is_cooled_down(now));
+        rule.last_fired_at = Some(now - Duration::hours(1));
+        assert!(!rule.is_cooled_down(now));
+        rule.last_fired_at = Some(now - Duration::hours(RULE_COOLDOWN_HOURS));
+        assert!(rule.is_cooled_down(now));
+    }
+
+    #[test]
+    fn test_fertilizer_paused_during_rest() {
+        let mut orchid = test_orchid();
+        orchid.rest_start_month = Some(1);
+        orchid.rest_end_month = Some(12);
+        let rule = CareRule {
+            id: "care_rule:1".into(),
+            name: "Rest feeding".into(),
+            enabled: true,
+            trigger: RuleTrigger::PhaseEntered { phase: SeasonalPhase::Rest },
+            actions: vec![RuleAction::PauseFertilizer],
+            last_fired_at: None,
+        };
+        let rules = vec![rule.clone()];
+        assert_eq!(fertilizer_paused(&rules, &orchid, &Hemisphere::Northern).map(|r| r.name.as_str()), Some("Rest feeding"));
+
+        let disabled = vec![CareRule { enabled: false, ..rule }];
+        assert!(fertilizer_paused(&disabled, &orchid, &Hemisphere::Northern).is_none());
+    }
+
+    #[test]
+    fn test_entered_phase_only_in_first_month() {
+        let mut orchid = test_orchid();
+        orchid.rest_start_month = Some(11);
+        orchid.rest_end_month = Some(2);
+        let rest = SeasonalPhase::Rest;
+        assert!(entered_phase(&orchid, &rest, &Hemisphere::Northern, 11));
+        assert!(!entered_phase(&orchid, &rest, &Hemisphere::Northern, 12));
+        assert!(!entered_phase(&orchid, &rest, &Hemisphere::Northern, 10));
+    }
+
+    #[test]
+    fn test_suggestion_humidity_filter() {
+        assert!(suggestion_applies(None, None));
+        assert!(suggestion_applies(Some(70.0), Some(75.0)));
+        assert!(!suggestion_applies(Some(70.0), Some(50.0)));
+        assert!(!suggestion_applies(Some(70.0), None));
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(120), "2h");
+        assert_eq!(format_minutes(90), "1h 30m");
+    }
+}
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+use crate::components::scanner::AnalysisResult;
+use crate::orchid::{LightRequirement, Orchid};
+
+/// Longest watering interval a tag can set, in days.
+const MAX_TAG_WATER_DAYS: u32 = 60;
+
+/// Care instructions read off a grower's plant tag or care card. Every field is optional:
+/// tags print whatever the grower chose to, and an unreadable field is left out rather than guessed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CareTag {
+    /// The name printed on the tag.
+    #[serde(default)]
+    pub species_name: Option<String>,
+    /// Lowest temperature the tag allows.
+    #[serde(default)]
+    pub temp_min: Option<f64>,
+    /// Highest temperature the tag allows.
+    #[serde(default)]
+    pub temp_max: Option<f64>,
+    /// Whether the temperatures are in Fahrenheit; `normalize` converts them to Celsius.
+    #[serde(default)]
+    pub fahrenheit: bool,
+    /// Light level the tag asks for.
+    #[serde(default)]
+    pub light: Option<LightRequirement>,
+    /// Days between waterings.
+    #[serde(default)]
+    pub water_every_days: Option<u32>,
+    /// Lowest humidity the tag asks for, in percent.
+    #[serde(default)]
+    pub humidity_min: Option<f64>,
+    /// Highest humidity the tag asks for, in percent.
+    #[serde(default)]
+    pub humidity_max: Option<f64>,
+    /// First month of the bloom season (1-12).
+    #[serde(default)]
+    pub bloom_start_month: Option<u32>,
+    /// Last month of the bloom season (1-12).
+    #[serde(default)]
+    pub bloom_end_month: Option<u32>,
+}
+
+impl CareTag {
+    /// Tidies what the model read: temperatures in Celsius with the lower one first, humidity within 0-100,
+    /// and impossible months or intervals dropped. A bloom season needs both ends.
+    pub fn normalize(mut self) -> Self {
+        if self.fahrenheit {
+            self.temp_min = self.temp_min.map(|f| (f - 32.0) * 5.0 / 9.0);
+            self.temp_max = self.temp_max.map(|f| (f - 32.0) * 5.0 / 9.0);
+            self.fahrenheit = false;
+        }
+        self.temp_min = self.temp_min.filter(|t| t.is_finite()).map(|t| (t * 10.0).round() / 10.0);
+        self.temp_max = self.temp_max.filter(|t| t.is_finite()).map(|t| (t * 10.0).round() / 10.0);
+        if let (Some(lo), Some(hi)) = (self.temp_min, self.temp_max)
+            && lo > hi
+        {
+            (self.temp_min, self.temp_max) = (Some(hi), Some(lo));
+        }
+        self.humidity_min = self.humidity_min.filter(|h| (0.0..=100.0).contains(h));
+        self.humidity_max = self.humidity_max.filter(|h| (0.0..=100.0).contains(h));
+        self.water_every_days = self.water_every_days.filter(|d| (1..=MAX_TAG_WATER_DAYS).contains(d));
+        self.species_name = self.species_name.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        let valid = |m: &u32| (1..=12).contains(m);
+        if !(self.bloom_start_month.is_some_and(|m| valid(&m)) && self.bloom_end_month.is_some_and(|m| valid(&m))) {
+            self.bloom_start_month = None;
+            self.bloom_end_month = None;
+        }
+        self
+    }
+
+    /// Whether the tag carried any care instructions, as opposed to just a name.
+    pub fn has_care(&self) -> bool {
+        self.temp_min.is_some()
+            || self.temp_max.is_some()
+            || self.light.is_some()
+            || self.water_every_days.is_some()
+            || self.humidity_min.is_some()
+            || self.humidity_max.is_some()
+            || self.bloom_start_month.is_some()
+    }
+
+    /// Overwrites the AI's estimates in `result` with what the tag says; the grower knows their own plant best.
+    pub fn apply_to(&self, result: &mut AnalysisResult) {
+        if self.temp_min.is_some() {
+            result.temp_min = self.temp_min;
+        }
+        if self.temp_max.is_some() {
+            result.temp_max = self.temp_max;
+        }
+        if let (Some(lo), Some(hi)) = (result.temp_min, result.temp_max)
+            && (self.temp_min.is_some() || self.temp_max.is_some())
+        {
+            result.temp_range = format!("{}-{}C", lo.round(), hi.round());
+        }
+        if let Some(light) = &self.light {
+            result.light_req = light.clone();
+        }
+        if let Some(days) = self.water_every_days {
+            result.water_freq = days;
+        }
+        if self.humidity_min.is_some() {
+            result.humidity_min = self.humidity_min;
+        }
+        if self.humidity_max.is_some() {
+            result.humidity_max = self.humidity_max;
+        }
+        if self.bloom_start_month.is_some() {
+            result.bloom_start_month = self.bloom_start_month;
+            result.bloom_end_month = self.bloom_end_month;
+        }
+    }
+
+    /// Short labels for each field the tag set, for showing what was read.
+    pub fn highlights(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        match (self.temp_min, self.temp_max) {
+            (Some(lo), Some(hi)) => out.push(format!("{}\u{2013}{}\u{00B0}C", lo, hi)),
+            (Some(lo), None) => out.push(format!("Min {}\u{00B0}C", lo)),
+            (None, Some(hi)) => out.push(format!("Max {}\u{00B0}C", hi)),
+            (None, None) => {}
+        }
+        if let Some(light) = &self.light {
+            out.push(light.to_string());
+        }
+        if let Some(days) = self.water_every_days {
+            out.push(format!("Water every {} days", days));
+        }
+        match (self.humidity_min, self.humidity_max) {
+            (Some(lo), Some(hi)) => out.push(format!("{}\u{2013}{}% humidity", lo, hi)),
+            (Some(h), None) | (None, Some(h)) => out.push(format!("{}% humidity", h)),
+            (None, None) => {}
+        }
+        if let (Some(start), Some(end)) = (self.bloom_start_month, self.bloom_end_month) {
+            out.push(format!("Blooms {}\u{2013}{}", Orchid::month_name(start), Orchid::month_name(end)));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchid::FitCategory;
+
+    fn result() -> AnalysisResult {
+        AnalysisResult {
+            species_name: "Phalaenopsis bellina".into(),
+            fit_category: FitCategory::GoodFit,
+            reason: String::new(),
+            already_owned: false,
+            water_freq: 7,
+            light_req: LightRequirement::Medium,
+            temp_range: "18-28C".into(),
+            placement_suggestion: String::new(),
+            conservation_status: None,
+            native_region: None,
+            native_latitude: None,
+            native_longitude: None,
+            temp_min: Some(18.0),
+            temp_max: Some(28.0),
+            humidity_min: Some(50.0),
+            humidity_max: Some(80.0),
+            rest_start_month: None,
+            rest_end_month: None,
+            bloom_start_month: None,
+            bloom_end_month: None,
+            rest_water_multiplier: None,
+            rest_fertilizer_multiplier: None,
+            active_water_multiplier: None,
+            active_fertilizer_multiplier: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_converts_and_validates() {
+        let tag = CareTag {
+            temp_min: Some(86.0),
+            temp_max: Some(59.0),
+            fahrenheit: true,
+            humidity_max: Some(140.0),
+            water_every_days: Some(0),
+            bloom_start_month: Some(3),
+            bloom_end_month: Some(13),
+            ..Default::default()
+        }.normalize();
+        assert_eq!((tag.temp_min, tag.temp_max), (Some(15.0), Some(30.0)));
+        assert_eq!(tag.humidity_max, None);
+        assert_eq!(tag.water_every_days, None);
+        assert_eq!(tag.bloom_start_month, None);
+        assert!(tag.has_care());
+        assert!(!CareTag { species_name: Some("Phal".into()), ..Default::default() }.has_care());
+    }
+
+    #[test]
+    fn test_apply_to_overrides_estimates() {
+        let tag = CareTag {
+            temp_min: Some(12.0),
+            light: Some(LightRequirement::High),
+            water_every_days: Some(5),
+            bloom_start_month: Some(4),
+            bloom_end_month: Some(6),
+            ..Default::default()
+        };
+        let mut r = result();
+        tag.apply_to(&mut r);
+        assert_eq!(r.temp_range, "12-28C");
+        assert_eq!(r.light_req, LightRequirement::High);
+        assert_eq!(r.water_freq, 5);
+        assert_eq!(r.humidity_min, Some(50.0));
+        assert_eq!((r.bloom_start_month, r.bloom_end_month), (Some(4), Some(6)));
+        assert_eq!(tag.highlights(), vec!["Min 12\u{00B0}C", "High Light", "Water every 5 days", "Blooms Apr\u{2013}Jun"]);
+    }
+}
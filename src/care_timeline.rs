@@ -0,0 +1,357 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use crate::orchid::{Hemisphere, Orchid};
+use crate::watering::ClimateSnapshot;
+
+/// How many days ahead the care timeline covers, starting today.
+pub const TIMELINE_DAYS: i64 = 30;
+/// How many days ahead the care forecast calendar covers, starting today.
+pub const FORECAST_DAYS: i64 = 14;
+
+/// Which kind of care a timeline bar represents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CareSpanKind {
+    /// Watering window: from the due day until the grace period runs out.
+    Water,
+    /// Fertilizing due day.
+    Fertilize,
+    /// The seasonal rest period.
+    Rest,
+}
+
+/// One bar on a plant's timeline row, in days from today (both ends inclusive, clipped to the timeline).
+#[derive(Clone, Debug, PartialEq)]
+pub struct CareSpan {
+    /// What kind of care this bar represents.
+    pub kind: CareSpanKind,
+    /// First day of the bar (0 = today).
+    pub start_day: i64,
+    /// Last day of the bar.
+    pub end_day: i64,
+    /// True when the bar starts before today, e.g. an overdue watering or a rest period already under way.
+    pub started_earlier: bool,
+}
+
+/// A plant's upcoming care windows over the next `TIMELINE_DAYS` days.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CareTimelineRow {
+    /// The plant's record ID.
+    pub orchid_id: String,
+    /// The plant's display name.
+    pub name: String,
+    /// The zone the plant lives in.
+    pub placement: String,
+    /// Bars to draw, grouped by kind in the order water, fertilize, rest.
+    pub spans: Vec<CareSpan>,
+}
+
+/// Repeating windows of `length` days, the first due `first_due` days from today (negative = overdue).
+/// After an overdue window, the next one is scheduled as if the care happens today.
+fn recurring_spans(kind: CareSpanKind, first_due: i64, every: i64, length: i64) -> Vec<CareSpan> {
+    let every = every.max(1);
+    let mut spans = Vec::new();
+    let mut due = first_due;
+    while due < TIMELINE_DAYS {
+        // Overdue care is still shown as due today, even past its grace period
+        spans.push(CareSpan {
+            kind,
+            start_day: due.max(0),
+            end_day: (due + length).clamp(0, TIMELINE_DAYS - 1),
+            started_earlier: due < 0,
+        });
+        due = due.max(0) + every;
+    }
+    spans
+}
+
+/// Last calendar day of `month` in `year`.
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let (y, m) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(y, m, 1)?.pred_opt()
+}
+
+/// The plant's rest period(s) overlapping the timeline, adjusted for hemisphere.
+fn rest_spans(orchid: &Orchid, hemisphere: &Hemisphere, today: NaiveDate) -> Vec<CareSpan> {
+    let (Some(rs), Some(re)) = (orchid.rest_start_month, orchid.rest_end_month) else {
+        return Vec::new();
+    };
+    let (start_month, end_month) = (hemisphere.adjust_month(rs), hemisphere.adjust_month(re));
+    let horizon_end = today + chrono::Duration::days(TIMELINE_DAYS - 1);
+
+    // A rest period that wraps the new year (e.g. Nov-Feb) may have started last year
+    (today.year() - 1..=today.year())
+        .filter_map(|year| {
+            let start = NaiveDate::from_ymd_opt(year, start_month, 1)?;
+            let end_year = if end_month < start_month { year + 1 } else { year };
+            let end = last_day_of_month(end_year, end_month)?;
+            (start <= horizon_end && end >= today).then(|| CareSpan {
+                kind: CareSpanKind::Rest,
+                start_day: (start - today).num_days().max(0),
+                end_day: (end - today).num_days().min(TIMELINE_DAYS - 1),
+                started_earlier: start < today,
+            })
+        })
+        .chain(
+            // The next year's period can also begin inside the window
+            std::iter::once(NaiveDate::from_ymd_opt(today.year() + 1, start_month, 1))
+                .flatten()
+                .filter(|start| *start <= horizon_end)
+                .map(|start| CareSpan {
+                    kind: CareSpanKind::Rest,
+                    start_day: (start - today).num_days(),
+                    end_day: TIMELINE_DAYS - 1,
+                    started_earlier: false,
+                }),
+        )
+        .collect()
+}
+
+/// **What is it?**
+/// A function that lays out each plant's watering windows, fertilizer due days, and rest periods over the next `TIMELINE_DAYS` days.
+///
+/// **Why does it exist?**
+/// It exists to give a planning view across the whole collection: the card grid shows one countdown per plant and the Today list only what is due now, so neither answers "which days next month are busy?".
+///
+/// **How should it be used?**
+/// Pass the plants to show (already filtered by zone), the zones' climate snapshots, the user's hemisphere and default grace days, and today's local date. Rows come back sorted by next watering.
+pub fn build_timeline(
+    orchids: &[Orchid],
+    snapshots: &[ClimateSnapshot],
+    hemisphere: &Hemisphere,
+    default_grace_days: u32,
+    today: NaiveDate,
+) -> Vec<CareTimelineRow> {
+    let mut rows: Vec<(i64, CareTimelineRow)> = orchids.iter().map(|orchid| {
+        let snapshot = snapshots.iter().find(|s| s.zone_name == orchid.placement);
+        let water_every = orchid.climate_adjusted_water_frequency(hemisphere, snapshot).adjusted_days as i64;
+        // Never watered counts as due today, matching the Today tab
+        let water_due = orchid.climate_days_until_due(hemisphere, snapshot).unwrap_or(0);
+        let grace = orchid.grace_days(default_grace_days) as i64;
+
+        let mut spans = recurring_spans(CareSpanKind::Water, water_due, water_every, grace);
+        if let Some(fert_every) = orchid.effective_fertilize_frequency(hemisphere) {
+            let fert_due = orchid.fertilize_days_until_due().unwrap_or(0);
+            spans.extend(recurring_spans(CareSpanKind::Fertilize, fert_due, fert_every as i64, 0));
+        }
+        spans.extend(rest_spans(orchid, hemisphere, today));
+
+        (water_due, CareTimelineRow {
+            orchid_id: orchid.id.clone(),
+            name: orchid.name.clone(),
+            placement: orchid.placement.clone(),
+            spans,
+        })
+    }).collect();
+
+    rows.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+    rows.into_iter().map(|(_, row)| row).collect()
+}
+
+/// Which care a forecast entry asks for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForecastTaskKind {
+    /// A watering is due.
+    Water,
+    /// A feeding is due.
+    Fertilize,
+}
+
+/// One plant's care due on a forecast day.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForecastTask {
+    /// The plant's record ID.
+    pub orchid_id: String,
+    /// The plant's display name.
+    pub name: String,
+    /// The zone the plant lives in.
+    pub placement: String,
+    /// What care is due.
+    pub kind: ForecastTaskKind,
+    /// True when the care was already due before today.
+    pub overdue: bool,
+}
+
+/// **What is it?**
+/// One calendar day of the care forecast: every watering and fertilizing due that day.
+///
+/// **Why does it exist?**
+/// It exists so growers can see two weeks ahead which days are busy and plan trips around them; the timeline shows windows per plant, this shows work per day.
+///
+/// **How should it be used?**
+/// Fetch with `server_fns::care_timeline::get_care_forecast`; days come back in order starting today, empty days included. Use `care_while_away` for a trip's date range.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForecastDay {
+    /// The local date.
+    pub date: NaiveDate,
+    /// Care due that day, waterings first.
+    pub tasks: Vec<ForecastTask>,
+}
+
+/// Projects each plant's climate-adjusted watering and fertilizing due dates over the next `FORECAST_DAYS` days.
+///
+/// Overdue care lands on today, and the next round is scheduled as if it is done then, matching the timeline.
+pub fn build_forecast(
+    orchids: &[Orchid],
+    snapshots: &[ClimateSnapshot],
+    hemisphere: &Hemisphere,
+    today: NaiveDate,
+) -> Vec<ForecastDay> {
+    let mut days: Vec<ForecastDay> = (0..FORECAST_DAYS)
+        .map(|offset| ForecastDay { date: today + chrono::Duration::days(offset), tasks: Vec::new() })
+        .collect();
+
+    for orchid in orchids {
+        let snapshot = snapshots.iter().find(|s| s.zone_name == orchid.placement);
+        let water_every = orchid.climate_adjusted_water_frequency(hemisphere, snapshot).adjusted_days as i64;
+        let water_due = orchid.climate_days_until_due(hemisphere, snapshot).unwrap_or(0);
+        let mut spans = recurring_spans(CareSpanKind::Water, water_due, water_every, 0);
+        if let Some(fert_every) = orchid.effective_fertilize_frequency(hemisphere) {
+            let fert_due = orchid.fertilize_days_until_due().unwrap_or(0);
+            spans.extend(recurring_spans(CareSpanKind::Fertilize, fert_due, fert_every as i64, 0));
+        }
+        for span in spans.into_iter().filter(|s| s.start_day < FORECAST_DAYS) {
+            let kind = if span.kind == CareSpanKind::Water { ForecastTaskKind::Water } else { ForecastTaskKind::Fertilize };
+            days[span.start_day as usize].tasks.push(ForecastTask {
+                orchid_id: orchid.id.clone(),
+                name: orchid.name.clone(),
+                placement: orchid.placement.clone(),
+                kind,
+                overdue: span.started_earlier,
+            });
+        }
+    }
+
+    for day in &mut days {
+        day.tasks.sort_by(|a, b| (a.kind != ForecastTaskKind::Water).cmp(&(b.kind != ForecastTaskKind::Water))
+            .then_with(|| a.name.cmp(&b.name)));
+    }
+    days
+}
+
+/// One plant's care that falls inside a trip.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TripCare {
+    /// The plant's record ID.
+    pub orchid_id: String,
+    /// The plant's display name.
+    pub name: String,
+    /// Waterings due while away.
+    pub waterings: usize,
+    /// Fertilizings due while away.
+    pub fertilizings: usize,
+    /// The first day care is due while away.
+    pub first_due: NaiveDate,
+}
+
+/// The plants that need care between `from` and `to` (inclusive), soonest first, so the grower can water ahead or arrange a sitter.
+pub fn care_while_away(days: &[ForecastDay], from: NaiveDate, to: NaiveDate) -> Vec<TripCare> {
+    let mut plants: Vec<TripCare> = Vec::new();
+    for day in days.iter().filter(|d| d.date >= from && d.date <= to) {
+        for task in &day.tasks {
+            let idx = match plants.iter().position(|p| p.orchid_id == task.orchid_id) {
+                Some(idx) => idx,
+                None => {
+                    plants.push(TripCare {
+                        orchid_id: task.orchid_id.clone(),
+                        name: task.name.clone(),
+                        waterings: 0,
+                        fertilizings: 0,
+                        first_due: day.date,
+                    });
+                    plants.len() - 1
+                }
+            };
+            match task.kind {
+                ForecastTaskKind::Water => plants[idx].waterings += 1,
+                ForecastTaskKind::Fertilize => plants[idx].fertilizings += 1,
+            }
+        }
+    }
+    plants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 16).unwrap()
+    }
+
+    #[test]
+    fn test_recurring_spans_reschedule_after_overdue() {
+        let spans = recurring_spans(CareSpanKind::Water, -3, 10, 2);
+        assert_eq!(spans[0], CareSpan { kind: CareSpanKind::Water, start_day: 0, end_day: 0, started_earlier: true });
+        assert_eq!(spans[1].start_day, 10);
+        assert_eq!(spans[2].start_day, 20);
+        assert_eq!(spans.len(), 3);
+    }
+
+    #[test]
+    fn test_recurring_spans_clip_at_horizon() {
+        let spans = recurring_spans(CareSpanKind::Water, 28, 7, 3);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].end_day, TIMELINE_DAYS - 1);
+    }
+
+    #[test]
+    fn test_rest_span_starting_next_month() {
+        let orchid = Orchid { rest_start_month: Some(11), rest_end_month: Some(2), ..test_orchid() };
+        let spans = rest_spans(&orchid, &Hemisphere::Northern, today());
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].start_day, 16);
+        assert_eq!(spans[0].end_day, TIMELINE_DAYS - 1);
+        assert!(!spans[0].started_earlier);
+    }
+
+    #[test]
+    fn test_rest_span_already_under_way() {
+        let orchid = Orchid { rest_start_month: Some(9), rest_end_month: Some(10), ..test_orchid() };
+        let spans = rest_spans(&orchid, &Hemisphere::Northern, today());
+        assert_eq!(spans, vec![CareSpan { kind: CareSpanKind::Rest, start_day: 0, end_day: 15, started_earlier: true }]);
+    }
+
+    #[test]
+    fn test_build_timeline_sorts_by_next_watering() {
+        let later = Orchid {
+            id: "orchid:1".into(), name: "Later".into(), water_frequency_days: 10,
+            last_watered_at: Some(chrono::Utc::now()),
+            ..test_orchid()
+        };
+        let sooner = Orchid {
+            id: "orchid:2".into(), name: "Sooner".into(), water_frequency_days: 10,
+            last_watered_at: Some(chrono::Utc::now() - chrono::Duration::days(9)),
+            ..test_orchid()
+        };
+        let rows = build_timeline(&[later, sooner], &[], &Hemisphere::Northern, 2, today());
+        assert_eq!(rows[0].name, "Sooner");
+        assert_eq!(rows[0].spans[0].kind, CareSpanKind::Water);
+        assert!(rows.iter().all(|r| r.spans.iter().all(|s| s.kind != CareSpanKind::Fertilize)));
+    }
+
+    #[test]
+    fn test_forecast_repeats_waterings_and_summarizes_a_trip() {
+        let orchid = Orchid {
+            id: "orchid:1".into(), name: "Phal".into(), water_frequency_days: 5,
+            last_watered_at: Some(chrono::Utc::now() - chrono::Duration::days(7)),
+            ..test_orchid()
+        };
+        let days = build_forecast(&[orchid], &[], &Hemisphere::Northern, today());
+        assert_eq!(days.len(), FORECAST_DAYS as usize);
+        assert_eq!(days[0].date, today());
+
+        let watering_days: Vec<usize> = days.iter().enumerate()
+            .filter(|(_, d)| d.tasks.iter().any(|t| t.kind == ForecastTaskKind::Water))
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(watering_days, vec![0, 5, 10]);
+        assert!(days[0].tasks[0].overdue);
+        assert!(!days[5].tasks[0].overdue);
+
+        let trip = care_while_away(&days, today() + chrono::Duration::days(3), today() + chrono::Duration::days(12));
+        assert_eq!(trip.len(), 1);
+        assert_eq!((trip[0].waterings, trip[0].first_due), (2, today() + chrono::Duration::days(5)));
+        assert!(care_while_away(&days, today() + chrono::Duration::days(1), today() + chrono::Duration::days(4)).is_empty());
+    }
+}
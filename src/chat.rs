@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::taxonomy::normalize;
+
+/// Longest question the assistant accepts, in characters.
+pub const MAX_CHAT_MESSAGE_LEN: usize = 2000;
+/// Most messages shown in the chat panel.
+pub const CHAT_HISTORY_LIMIT: usize = 100;
+/// Most earlier messages sent to the model with a new question.
+pub const CHAT_CONTEXT_MESSAGES: usize = 12;
+/// Most plants whose journal and profile go into one answer's context.
+pub const MAX_FOCUS_PLANTS: usize = 3;
+
+/// Who wrote a chat message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatRole {
+    /// The grower.
+    User,
+    /// The AI care assistant.
+    Assistant,
+}
+
+impl ChatRole {
+    /// The stored key: "user" or "assistant".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChatRole::User => "user",
+            ChatRole::Assistant => "assistant",
+        }
+    }
+
+    /// Parses a stored key; anything but "user" is the assistant.
+    pub fn from_str_lossy(s: &str) -> Self {
+        if s == "user" { ChatRole::User } else { ChatRole::Assistant }
+    }
+}
+
+/// One message in a grower's conversation with the care assistant.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    /// The stored message's ID.
+    pub id: String,
+    /// Who wrote it.
+    pub role: ChatRole,
+    /// The text.
+    pub content: String,
+    /// When it was written.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Trims a question and checks it isn't empty or too long.
+pub fn validate_question(question: &str) -> Result<String, String> {
+    let question = question.trim();
+    if question.is_empty() {
+        return Err("Type a question first".into());
+    }
+    if question.chars().count() > MAX_CHAT_MESSAGE_LEN {
+        return Err(format!("Questions can be at most {} characters", MAX_CHAT_MESSAGE_LEN));
+    }
+    Ok(question.to_string())
+}
+
+/// `s` folded for matching, with punctuation turned into spaces and padded so whole words can be found with `contains`.
+fn words(s: &str) -> String {
+    let spaced: String = s.chars().map(|c| if c.is_alphanumeric() { c } else { ' ' }).collect();
+    format!(" {} ", normalize(&spaced))
+}
+
+/// Indexes of the plants a question is about, given each plant's (name, species), best match first:
+/// plants named in it, then ones whose species is, then ones whose genus is. At most `MAX_FOCUS_PLANTS`.
+pub fn plants_mentioned<'a>(question: &str, plants: impl IntoIterator<Item = (&'a str, &'a str)>) -> Vec<usize> {
+    let question = words(question);
+    let found = |s: &str| {
+        let needle = words(s);
+        needle.trim().len() >= 3 && question.contains(&needle)
+    };
+    let mut ranked: Vec<(u8, usize)> = plants.into_iter().enumerate()
+        .filter_map(|(i, (name, species))| {
+            let genus = species.split_whitespace().next().unwrap_or_default();
+            if found(name) {
+                Some((0, i))
+            } else if species.split_whitespace().count() > 1 && found(species) {
+                Some((1, i))
+            } else if found(genus) {
+                Some((2, i))
+            } else {
+                None
+            }
+        })
+        .collect();
+    ranked.sort();
+    ranked.into_iter().take(MAX_FOCUS_PLANTS).map(|(_, i)| i).collect()
+}
+
+/// Earlier messages as a transcript for the prompt, oldest first.
+pub fn transcript(history: &[ChatMessage]) -> String {
+    history.iter()
+        .map(|m| format!("{}: {}", if m.role == ChatRole::User { "Grower" } else { "Assistant" }, m.content))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_question() {
+        assert_eq!(validate_question("  Why yellow leaves? ").unwrap(), "Why yellow leaves?");
+        assert!(validate_question("   ").is_err());
+        assert!(validate_question(&"a".repeat(MAX_CHAT_MESSAGE_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_plants_mentioned() {
+        let plants = [
+            ("Big Phal", "Phalaenopsis amabilis"),
+            ("Kingie", "Dendrobium kingianum"),
+            ("Stinky", "Bulbophyllum phalaenopsis"),
+            ("Den", "Dendrobium nobile"),
+        ];
+        let q = "Why is my Dendrobium dropping leaves? Kingie looks fine.";
+        assert_eq!(plants_mentioned(q, plants), vec![1, 3]);
+        // The whole species has to appear; sharing an epithet with the question isn't enough
+        assert_eq!(plants_mentioned("Is my phalaenopsis amabilis thirsty?", plants), vec![0]);
+        assert!(plants_mentioned("When should I repot?", plants).is_empty());
+    }
+}
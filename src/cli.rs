@@ -25,6 +25,15 @@ pub enum Command {
         #[arg(short, long)]
         password: String,
     },
+    /// Grant or remove instance admin rights
+    SetAdmin {
+        /// The username to update
+        #[arg(short, long)]
+        username: String,
+        /// Remove admin rights instead of granting them
+        #[arg(long)]
+        revoke: bool,
+    },
     /// Re-run AI analysis on all plants for a user
     ReprocessPlants {
         /// Username whose plants to reprocess
@@ -40,8 +49,81 @@ pub enum Command {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Write a user's climate history as Parquet files (one per zone per year)
+    ExportClimate {
+        /// Username whose zones to export
+        #[arg(short, long)]
+        user: String,
+        /// Directory to write into, e.g. a mounted object storage bucket
+        #[arg(short, long)]
+        out: std::path::PathBuf,
+    },
+    /// Back up all of a user's data: one JSON file, or a directory of CSV files (one per table)
+    Export {
+        /// Username whose data to export
+        #[arg(short, long)]
+        user: String,
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: BackupFormat,
+        /// File (JSON) or directory (CSV) to write
+        #[arg(short, long)]
+        path: std::path::PathBuf,
+    },
+    /// Restore a backup written by `export` into a user's account, keeping record IDs
+    Import {
+        /// Username to import into; their collection should be empty
+        #[arg(short, long)]
+        user: String,
+        /// A JSON backup file, or a directory of CSV files
+        #[arg(short, long)]
+        path: std::path::PathBuf,
+    },
+    /// Fill an account with sample plants, zones, journal history and climate readings for development and demos
+    Seed {
+        /// Username to seed; created if it doesn't exist, and must have no plants yet
+        #[arg(short, long, default_value = "demo")]
+        user: String,
+        /// Password for the account if it has to be created
+        #[arg(short, long)]
+        password: Option<String>,
+        /// Days of journal history and climate readings to generate (default: 30)
+        #[arg(long, default_value = "30")]
+        days: u32,
+    },
+    /// Check the database, migrations, push keys, image storage, saved devices and AI providers, and print a pass/fail report
+    Doctor,
+    /// Print per-user counts and instance storage: the numbers on the admin page
+    Stats,
+    /// Apply pending schema migrations, or revert applied ones, printing each step
+    Migrate {
+        /// Print what would be applied or reverted without touching the database
+        #[arg(long)]
+        dry_run: bool,
+        /// Revert every applied migration newer than this schema version, using their down scripts
+        #[arg(long)]
+        down_to: Option<u32>,
+    },
+    /// Poll climate sources once, with debug logging, and print every reading stored
+    Poll {
+        /// A zone's record ID (e.g. growing_zone:abc123), or "all" for every polled zone
+        #[arg(default_value = "all")]
+        zone_or_all: String,
+    },
+}
+
+/// File format for `export`; `import` tells them apart by whether the path is a directory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum BackupFormat {
+    /// A single JSON document.
+    Json,
+    /// One CSV file per table.
+    Csv,
 }
 
+/// Rows written per restore statement, so large climate histories aren't sent as one query.
+const IMPORT_BATCH_ROWS: usize = 1000;
+
 /// Executes the reset-password subcommand, hashing and updating the user's password.
 pub async fn run_reset_password(username: &str, password: &str) -> Result<(), Box<dyn std::error::Error>> {
     let hash = hash_password(password)?;
@@ -75,6 +157,29 @@ pub async fn run_reset_password(username: &str, password: &str) -> Result<(), Bo
     Ok(())
 }
 
+/// Executes the set-admin subcommand, granting or removing a user's instance admin rights.
+pub async fn run_set_admin(username: &str, revoke: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut response = db()
+        .query("UPDATE user SET is_admin = $is_admin WHERE username = $username RETURN VALUE id")
+        .bind(("is_admin", !revoke))
+        .bind(("username", username.to_owned()))
+        .await?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(format!("Database error: {}", err_msg).into());
+    }
+
+    let rows: Vec<surrealdb::types::RecordId> = response.take(0)?;
+    if rows.is_empty() {
+        return Err(format!("No user found with username '{}'", username).into());
+    }
+
+    tracing::info!("User '{}' is {} an instance admin", username, if revoke { "no longer" } else { "now" });
+    Ok(())
+}
+
 /// Executes the reprocess-plants subcommand, running AI analysis on a user's orchids.
 pub async fn run_reprocess_plants(
     username: &str,
@@ -183,6 +288,668 @@ pub async fn run_reprocess_plants(
     Ok(())
 }
 
+/// Executes the export-climate subcommand, writing a user's climate history as Parquet files under `out`.
+pub async fn run_export_climate(username: &str, out: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    let owner = user_by_username(username).await?;
+
+    let files = crate::climate::parquet_export::export_climate_parquet(owner).await?;
+    for (name, bytes) in &files {
+        let path = out.join(name);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, bytes).await?;
+    }
+
+    tracing::info!("Wrote {} Parquet files for '{}' to {}", files.len(), username, out.display());
+    Ok(())
+}
+
+/// Look up a user's record ID by username.
+async fn user_by_username(username: &str) -> Result<surrealdb::types::RecordId, Box<dyn std::error::Error>> {
+    Ok(find_user_by_username(username).await?
+        .ok_or_else(|| format!("No user found with username '{}'", username))?)
+}
+
+/// Look up a user's record ID by username; `None` if there's no such user.
+async fn find_user_by_username(username: &str) -> Result<Option<surrealdb::types::RecordId>, Box<dyn std::error::Error>> {
+    let mut response = db()
+        .query("SELECT VALUE id FROM user WHERE username = $username")
+        .bind(("username", username.to_owned()))
+        .await?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(format!("Database error: {}", err_msg).into());
+    }
+    let users: Vec<surrealdb::types::RecordId> = response.take(0)?;
+    Ok(users.into_iter().next())
+}
+
+/// Executes the export subcommand, writing all of a user's data (the same tables as "Download my
+/// data", without photos) as a JSON backup file or a directory of CSV files.
+pub async fn run_export(username: &str, format: BackupFormat, path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::backup::{rows_to_csv, Backup, BACKUP_VERSION};
+
+    let owner = user_by_username(username).await?;
+    let tables = crate::server_fns::auth::export_tables(owner).await?;
+    let tables: Vec<(String, Vec<serde_json::Value>)> = tables.into_iter()
+        .map(|(file, rows)| (file.trim_end_matches(".json").to_string(), rows))
+        .collect();
+    let row_count: usize = tables.iter().map(|(_, rows)| rows.len()).sum();
+
+    match format {
+        BackupFormat::Json => {
+            let backup = Backup {
+                version: BACKUP_VERSION,
+                exported_at: chrono::Utc::now(),
+                username: username.to_owned(),
+                tables: tables.into_iter().collect(),
+            };
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(path, serde_json::to_vec_pretty(&backup)?).await?;
+        }
+        BackupFormat::Csv => {
+            tokio::fs::create_dir_all(path).await?;
+            for (name, rows) in &tables {
+                tokio::fs::write(path.join(format!("{}.csv", name)), rows_to_csv(rows)).await?;
+            }
+        }
+    }
+
+    tracing::info!("Exported {} rows for '{}' to {}", row_count, username, path.display());
+    Ok(())
+}
+
+/// Executes the import subcommand, restoring a backup from `run_export` into a user's account.
+///
+/// Rows keep their record IDs so links between them survive, which means importing into an
+/// instance that still has the same records fails for that table (each table is restored in its
+/// own transactions, and the error is reported). Only the collection tables in `RESTORE_TABLES`
+/// are restored.
+pub async fn run_import(username: &str, path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::backup::{field_kinds, restore_columns, restore_query, rows_from_csv, Backup, RESTORE_TABLES};
+
+    let owner = user_by_username(username).await?;
+
+    let mut tables = std::collections::BTreeMap::new();
+    if tokio::fs::metadata(path).await?.is_dir() {
+        for (name, _) in RESTORE_TABLES {
+            let file = path.join(format!("{}.csv", name));
+            if let Ok(text) = tokio::fs::read_to_string(&file).await {
+                let rows = rows_from_csv(&text).map_err(|e| format!("{}: {}", file.display(), e))?;
+                tables.insert(name.to_string(), rows);
+            }
+        }
+    } else {
+        let backup = Backup::from_json(&tokio::fs::read_to_string(path).await?)?;
+        tracing::info!("Backup of '{}' taken {}", backup.username, backup.exported_at.format("%Y-%m-%d %H:%M UTC"));
+        tables = backup.tables;
+    }
+
+    let mut imported = 0usize;
+    let mut failed = Vec::new();
+    for (name, table) in RESTORE_TABLES {
+        let Some(rows) = tables.remove(*name).filter(|rows| !rows.is_empty()) else {
+            continue;
+        };
+
+        let mut info = db().query(format!("INFO FOR TABLE `{}`", table)).await?;
+        let errors = info.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(format!("Can't read the schema of '{}': {}", table, err_msg).into());
+        }
+        let info: Option<serde_json::Value> = info.take(0)?;
+        let fields = info.as_ref()
+            .and_then(|i| i.get("fields"))
+            .and_then(|f| f.as_object())
+            .ok_or_else(|| format!("Table '{}' has no schema on this server", table))?;
+        let (columns, unknown, keep_ids) = restore_columns(&rows, &field_kinds(fields));
+        if !unknown.is_empty() {
+            tracing::warn!("{}: skipping columns this server doesn't store: {}", name, unknown.join(", "));
+        }
+        let query = restore_query(table, &columns, keep_ids);
+
+        let mut restored = 0usize;
+        for batch in rows.chunks(IMPORT_BATCH_ROWS) {
+            let mut response = db()
+                .query(query.as_str())
+                .bind(("rows", batch.to_vec()))
+                .bind(("owner", owner.clone()))
+                .await?;
+            let errors = response.take_errors();
+            if !errors.is_empty() {
+                let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+                tracing::error!("{}: import stopped after {} of {} rows: {}", name, restored, rows.len(), err_msg);
+                failed.push(name.to_string());
+                break;
+            }
+            restored += batch.len();
+        }
+        tracing::info!("{}: imported {} rows", name, restored);
+        imported += restored;
+    }
+
+    if !failed.is_empty() {
+        return Err(format!("Imported {} rows, but these tables failed: {}", imported, failed.join(", ")).into());
+    }
+    tracing::info!("Imported {} rows into '{}'", imported, username);
+    Ok(())
+}
+
+/// Executes the seed subcommand, filling an account with the sample collection from `demo_data`:
+/// three zones, eight plants (two overdue for water), a journal that follows each plant's
+/// schedule, and a reading every half hour per zone over the last `days` days.
+///
+/// Refuses to touch an account that already has plants, so it can't be run twice by mistake.
+pub async fn run_seed(username: &str, password: Option<&str>, days: u32) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::climate::{calculate_dew_point, calculate_vpd};
+    use crate::demo_data::{demo_journal, demo_reading, DEMO_ORCHIDS, DEMO_ZONES, MAX_SEED_DAYS, READING_INTERVAL_MINUTES};
+
+    if days == 0 || days > MAX_SEED_DAYS {
+        return Err(format!("--days must be between 1 and {}", MAX_SEED_DAYS).into());
+    }
+
+    let owner = match find_user_by_username(username).await? {
+        Some(owner) => owner,
+        None => {
+            let password = password.ok_or_else(|| format!("No user '{}'; pass --password to create it", username))?;
+            let mut response = db()
+                .query("CREATE user SET username = $username, email = $email, password_hash = $hash RETURN VALUE id")
+                .bind(("username", username.to_owned()))
+                .bind(("email", format!("{}@example.com", username)))
+                .bind(("hash", hash_password(password)?))
+                .await?;
+            let errors = response.take_errors();
+            if !errors.is_empty() {
+                let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+                return Err(format!("Database error: {}", err_msg).into());
+            }
+            let created: Vec<surrealdb::types::RecordId> = response.take(0)?;
+            tracing::info!("Created user '{}'", username);
+            created.into_iter().next().ok_or("User was not created")?
+        }
+    };
+
+    let mut existing = db()
+        .query("SELECT VALUE id FROM orchid WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner.clone()))
+        .await?;
+    let errors = existing.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(format!("Database error: {}", err_msg).into());
+    }
+    let existing: Vec<surrealdb::types::RecordId> = existing.take(0)?;
+    if !existing.is_empty() {
+        return Err(format!("'{}' already has plants; seed an empty account", username).into());
+    }
+
+    let now = chrono::Utc::now();
+    let days_ago = |d: u32| (now - chrono::Duration::days(d as i64)).to_rfc3339();
+
+    let mut zone_ids = Vec::with_capacity(DEMO_ZONES.len());
+    for (i, zone) in DEMO_ZONES.iter().enumerate() {
+        let mut response = db()
+            .query(
+                "CREATE growing_zone SET owner = $owner, name = $name, light_level = $light, \
+                 location_type = $location, temperature_range = $temp_range, humidity = $humidity, \
+                 description = $description, sort_order = $sort_order RETURN VALUE id"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("name", zone.name.to_string()))
+            .bind(("light", zone.light_level.as_str().to_string()))
+            .bind(("location", zone.location_type.to_string()))
+            .bind(("temp_range", zone.temperature_range.to_string()))
+            .bind(("humidity", zone.humidity.to_string()))
+            .bind(("description", zone.description.to_string()))
+            .bind(("sort_order", i as i64))
+            .await?;
+        let errors = response.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(format!("Database error: {}", err_msg).into());
+        }
+        let ids: Vec<surrealdb::types::RecordId> = response.take(0)?;
+        zone_ids.push(ids.into_iter().next().ok_or("Zone was not created")?);
+    }
+
+    let mut orchid_ids = Vec::with_capacity(DEMO_ORCHIDS.len());
+    for orchid in &DEMO_ORCHIDS {
+        let (temp_min, temp_max, humidity_min, humidity_max) = orchid.climate;
+        let mut response = db()
+            .query(
+                "CREATE orchid SET owner = $owner, name = $name, species = $species, \
+                 water_frequency_days = $water_days, light_requirement = $light, notes = $notes, \
+                 placement = $placement, temperature_range = $temp_range, \
+                 temp_min = $temp_min, temp_max = $temp_max, humidity_min = $humidity_min, humidity_max = $humidity_max, \
+                 fertilize_frequency_days = $fertilize_days, last_watered_at = <datetime> $last_watered, \
+                 created_at = <datetime> $created RETURN VALUE id"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("name", orchid.name.to_string()))
+            .bind(("species", orchid.species.to_string()))
+            .bind(("water_days", orchid.water_frequency_days as i64))
+            .bind(("light", orchid.light_requirement.as_str().to_string()))
+            .bind(("notes", orchid.notes.to_string()))
+            .bind(("placement", DEMO_ZONES[orchid.zone].name.to_string()))
+            .bind(("temp_range", format!("{:.0}-{:.0}°C", temp_min, temp_max)))
+            .bind(("temp_min", temp_min))
+            .bind(("temp_max", temp_max))
+            .bind(("humidity_min", humidity_min))
+            .bind(("humidity_max", humidity_max))
+            .bind(("fertilize_days", orchid.fertilize_frequency_days.map(|d| d as i64)))
+            .bind(("last_watered", days_ago(orchid.last_watered_days_ago)))
+            .bind(("created", days_ago(days)))
+            .await?;
+        let errors = response.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(format!("Database error: {}", err_msg).into());
+        }
+        let ids: Vec<surrealdb::types::RecordId> = response.take(0)?;
+        orchid_ids.push(ids.into_iter().next().ok_or("Plant was not created")?);
+    }
+
+    // Journal entries are dated so many days before now, and move the plant's care timestamps with them
+    let journal = demo_journal(days);
+    let entries: Vec<serde_json::Value> = journal.iter()
+        .map(|entry| serde_json::json!({
+            "orchid": crate::server_fns::auth::record_id_to_string(&orchid_ids[entry.orchid]),
+            "event_type": entry.event_type,
+            "note": entry.note,
+            "at": days_ago(entry.days_ago),
+        }))
+        .collect();
+    let mut response = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             FOR $r IN $entries { \
+                 CREATE log_entry SET orchid = type::record($r.orchid), owner = $owner, note = $r.note, \
+                     event_type = $r.event_type, timestamp = <datetime> $r.at; \
+                 UPDATE type::record($r.orchid) SET last_fertilized_at = <datetime> $r.at WHERE $r.event_type = 'Fertilized'; \
+                 UPDATE type::record($r.orchid) SET last_repotted_at = <datetime> $r.at WHERE $r.event_type = 'Repotted'; \
+             }; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("entries", entries))
+        .bind(("owner", owner.clone()))
+        .await?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(format!("Database error: {}", err_msg).into());
+    }
+
+    let steps = days as i64 * 24 * 60 / READING_INTERVAL_MINUTES;
+    let mut readings = 0usize;
+    for (zone, zone_id) in DEMO_ZONES.iter().zip(&zone_ids) {
+        let rows: Vec<serde_json::Value> = (0..steps)
+            .map(|step| {
+                let at = now - chrono::Duration::minutes((steps - step) * READING_INTERVAL_MINUTES);
+                let (temperature, humidity) = demo_reading(zone, at);
+                serde_json::json!({
+                    "temperature": temperature,
+                    "humidity": humidity,
+                    "vpd": calculate_vpd(temperature, humidity),
+                    "dew_point": calculate_dew_point(temperature, humidity),
+                    "at": at.to_rfc3339(),
+                })
+            })
+            .collect();
+        for batch in rows.chunks(IMPORT_BATCH_ROWS) {
+            let mut response = db()
+                .query(
+                    "FOR $r IN $rows { \
+                         CREATE climate_reading SET zone = $zone, zone_name = $zone_name, \
+                             temperature = $r.temperature, humidity = $r.humidity, vpd = $r.vpd, \
+                             dew_point = $r.dew_point, source = 'demo', recorded_at = <datetime> $r.at; \
+                     };"
+                )
+                .bind(("rows", batch.to_vec()))
+                .bind(("zone", zone_id.clone()))
+                .bind(("zone_name", zone.name.to_string()))
+                .await?;
+            let errors = response.take_errors();
+            if !errors.is_empty() {
+                let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+                return Err(format!("Database error: {}", err_msg).into());
+            }
+            readings += batch.len();
+        }
+    }
+
+    tracing::info!(
+        "Seeded '{}' with {} zones, {} plants, {} journal entries and {} climate readings",
+        username, zone_ids.len(), orchid_ids.len(), journal.len(), readings
+    );
+    Ok(())
+}
+
+/// Executes the poll subcommand: one polling pass, for every zone or just one, outside the
+/// 30-minute loop.
+///
+/// Polling everything runs the full `poll_all_zones` cycle, alerts and rules included. A single
+/// zone is fetched and stored only, so debugging one sensor doesn't send anyone alerts.
+pub async fn run_poll(zone_or_all: &str) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::climate::poller::{poll_all_zones, poll_zones};
+
+    if zone_or_all == "all" {
+        poll_all_zones().await;
+        return Ok(());
+    }
+
+    let zone = surrealdb::types::RecordId::parse_simple(zone_or_all)
+        .map_err(|e| format!("'{}' is not a zone ID: {}", zone_or_all, e))?;
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct ZoneRow {
+        name: String,
+        #[surreal(default)]
+        data_source_type: Option<String>,
+        #[surreal(default)]
+        device_type: Option<String>,
+    }
+
+    let mut response = db()
+        .query("SELECT name, data_source_type, hardware_device.device_type AS device_type FROM $zone")
+        .bind(("zone", zone.clone()))
+        .await?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(format!("Database error: {}", err_msg).into());
+    }
+    let zones: Vec<ZoneRow> = response.take(0)?;
+    let ZoneRow { name, data_source_type, device_type } = zones.into_iter().next()
+        .ok_or_else(|| format!("No zone {}", zone_or_all))?;
+    match (device_type, data_source_type.as_deref()) {
+        (Some(device), _) => tracing::info!("Polling zone '{}' through its {} device", name, device),
+        (None, Some("http")) => return Err(format!("Zone '{}' receives readings over HTTP ingest; there is nothing to poll", name).into()),
+        (None, Some(source)) => tracing::info!("Polling zone '{}' from its {} source", name, source),
+        (None, None) => return Err(format!("Zone '{}' has no device or data source to poll", name).into()),
+    }
+
+    let (readings, outcome) = poll_zones(Some(&zone)).await;
+    if readings.is_empty() {
+        return Err(format!("No reading came back for '{}'; see the warnings above", name).into());
+    }
+    for reading in &readings {
+        tracing::info!("Fetched {}", reading.describe());
+    }
+    if outcome.held > 0 {
+        return Err(format!(
+            "The database is unreachable; {} readings are held in memory and are lost when this command exits",
+            outcome.held
+        ).into());
+    }
+    if outcome.rejected > 0 {
+        return Err(format!("The database rejected {} of {} readings; see the warnings above", outcome.rejected, readings.len()).into());
+    }
+    tracing::info!("Stored {} readings", outcome.stored);
+    Ok(())
+}
+
+/// Executes the stats subcommand, printing each account's plants, journal entries, photos,
+/// climate readings and image storage, then the instance's storage and largest tables.
+pub async fn run_stats() -> Result<(), Box<dyn std::error::Error>> {
+    use crate::pages::admin::format_bytes;
+
+    let users = crate::server_fns::admin::list_users().await?;
+    let storage = crate::server_fns::admin::storage_usage().await?;
+
+    let width = users.iter().map(|u| u.username.len()).max().unwrap_or(0).max("User".len());
+    println!("{:<width$}  {:>8}  {:>8}  {:>8}  {:>10}  {:>10}", "User", "Plants", "Entries", "Photos", "Readings", "Images");
+    for u in &users {
+        println!(
+            "{:<width$}  {:>8}  {:>8}  {:>8}  {:>10}  {:>10}",
+            u.username, u.plant_count, u.journal_count, u.photo_count, u.reading_count, format_bytes(u.image_bytes)
+        );
+    }
+    println!();
+    println!("Images:   {} in {} files", format_bytes(storage.image_bytes), storage.image_files);
+    match storage.database_bytes {
+        Some(bytes) => println!("Database: {} on disk", format_bytes(bytes)),
+        None => println!("Database: size unknown (set SURREAL_DATA_PATH if SurrealDB's data is on this host)"),
+    }
+    for (table, rows) in &storage.table_rows {
+        println!("  {:<16} {:>10} rows", table, rows);
+    }
+    Ok(())
+}
+
+/// Outcome of one `doctor` check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CheckStatus {
+    /// Working.
+    Pass,
+    /// Not set up, or working with a caveat; doesn't fail the run.
+    Warn,
+    /// Broken.
+    Fail,
+}
+
+/// Prints one line of the doctor report and tallies failures.
+struct Report {
+    failures: usize,
+}
+
+impl Report {
+    fn line(&mut self, status: CheckStatus, check: &str, detail: impl std::fmt::Display) {
+        let tag = match status {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => {
+                self.failures += 1;
+                "FAIL"
+            }
+        };
+        println!("[{}] {}: {}", tag, check, detail);
+    }
+}
+
+/// Executes the doctor subcommand: connects to the database without migrating, then checks
+/// migrations, VAPID keys, the image directory, every saved device (one live test poll each, as
+/// the device form's Test button does) and each configured AI provider (one tiny prompt each).
+///
+/// Runs before the server's own startup, so it works on installs that won't boot. Fails if any
+/// check fails; warnings (push or AI not configured) don't.
+pub async fn run_doctor(cfg: &crate::config::AppConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let mut report = Report { failures: 0 };
+
+    let connected = match crate::db::connect(cfg).await {
+        Ok(()) => {
+            report.line(CheckStatus::Pass, "Database", format!("connected to {} ({}/{})", cfg.surreal_url, cfg.surreal_ns, cfg.surreal_db));
+            true
+        }
+        Err(e) => {
+            report.line(CheckStatus::Fail, "Database", e);
+            false
+        }
+    };
+
+    if connected {
+        match crate::db::migration_status().await {
+            Ok((files, applied)) => {
+                let plan = crate::migration::plan(&files, &applied);
+                let pending: Vec<&str> = plan.pending.iter().map(|f| f.name.as_str()).collect();
+                if !plan.changed.is_empty() {
+                    report.line(CheckStatus::Fail, "Migrations", format!("changed since applied: {}", plan.changed.join(", ")));
+                } else if pending.is_empty() {
+                    report.line(CheckStatus::Pass, "Migrations", format!("all applied (schema version {})", crate::migration::schema_version(&files, &applied)));
+                } else {
+                    report.line(
+                        CheckStatus::Fail,
+                        "Migrations",
+                        format!("{} not applied ({}); run `migrate` or start the server to apply them", pending.len(), pending.join(", ")),
+                    );
+                }
+            }
+            Err(e) => report.line(CheckStatus::Fail, "Migrations", e),
+        }
+    }
+
+    match (cfg.vapid_private_key.is_empty(), cfg.vapid_public_key.is_empty()) {
+        (true, true) => report.line(CheckStatus::Warn, "VAPID keys", "not set; push notifications are off"),
+        (true, false) | (false, true) => report.line(CheckStatus::Fail, "VAPID keys", "set VAPID_PRIVATE_KEY and VAPID_PUBLIC_KEY together"),
+        (false, false) => {
+            use base64::Engine;
+            let public = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(cfg.vapid_public_key.trim_end_matches('='));
+            match (web_push::VapidSignatureBuilder::from_base64_no_sub(&cfg.vapid_private_key), public) {
+                (Err(e), _) => report.line(CheckStatus::Fail, "VAPID keys", format!("private key unreadable: {}", e)),
+                (_, Err(e)) => report.line(CheckStatus::Fail, "VAPID keys", format!("public key unreadable: {}", e)),
+                (Ok(private), Ok(public)) if private.get_public_key() != public => {
+                    report.line(CheckStatus::Fail, "VAPID keys", "public key doesn't belong to the private key; browsers will reject pushes")
+                }
+                (Ok(_), Ok(_)) => report.line(CheckStatus::Pass, "VAPID keys", "key pair matches"),
+            }
+        }
+    }
+
+    let images = std::path::Path::new(&cfg.image_storage_path);
+    let probe = images.join(".doctor-write-test");
+    match tokio::fs::metadata(images).await {
+        Err(e) => report.line(CheckStatus::Fail, "Image directory", format!("{}: {}", images.display(), e)),
+        Ok(meta) if !meta.is_dir() => report.line(CheckStatus::Fail, "Image directory", format!("{} is not a directory", images.display())),
+        Ok(_) => match tokio::fs::write(&probe, b"ok").await {
+            Ok(()) => {
+                let _ = tokio::fs::remove_file(&probe).await;
+                report.line(CheckStatus::Pass, "Image directory", format!("{} is writable", images.display()));
+            }
+            Err(e) => report.line(CheckStatus::Fail, "Image directory", format!("can't write to {}: {}", images.display(), e)),
+        },
+    }
+
+    if connected {
+        #[derive(serde::Deserialize, SurrealValue)]
+        #[surreal(crate = "surrealdb::types")]
+        struct DeviceRow {
+            name: String,
+            device_type: String,
+            config: String,
+            #[surreal(default)]
+            username: Option<String>,
+        }
+
+        let devices: Result<Vec<DeviceRow>, String> = match db()
+            .query("SELECT name, device_type, config, owner.username AS username FROM hardware_device ORDER BY device_type, name")
+            .await
+        {
+            Ok(mut response) => {
+                let errors = response.take_errors();
+                if errors.is_empty() {
+                    response.take(0).map_err(|e| e.to_string())
+                } else {
+                    Err(errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        let devices = match devices {
+            Ok(devices) if devices.is_empty() => {
+                report.line(CheckStatus::Pass, "Devices", "none saved");
+                devices
+            }
+            Ok(devices) => devices,
+            Err(e) => {
+                report.line(CheckStatus::Fail, "Devices", format!("can't list saved devices: {}", e));
+                Vec::new()
+            }
+        };
+        for device in devices {
+            let check = format!("Device '{}' ({}, {})", device.name, device.device_type, device.username.as_deref().unwrap_or("no owner"));
+            let config = crate::crypto::decrypt_or_raw(&device.config);
+            match crate::server_fns::devices::probe_device(&device.device_type, &config).await {
+                Ok(summary) => report.line(CheckStatus::Pass, &check, summary),
+                Err(e) => report.line(CheckStatus::Fail, &check, e),
+            }
+        }
+    }
+
+    let providers = crate::ai::providers();
+    if providers.is_empty() {
+        report.line(CheckStatus::Warn, "AI providers", "none configured; identification and diagnosis are off");
+    }
+    for provider in providers {
+        let check = format!("AI provider {}", provider.name());
+        match provider.text("Reply with the single word OK.").await {
+            Ok(_) => report.line(CheckStatus::Pass, &check, "reachable"),
+            Err(e) => report.line(CheckStatus::Fail, &check, e),
+        }
+    }
+
+    if report.failures > 0 {
+        return Err(format!("{} check{} failed", report.failures, if report.failures == 1 { "" } else { "s" }).into());
+    }
+    println!("All checks passed");
+    Ok(())
+}
+
+/// Executes the migrate subcommand: connects without migrating, prints the schema version and
+/// what would change, then applies pending migrations, or with `down_to` reverts every applied
+/// migration newer than that version, newest first, using their down scripts. `dry_run` stops
+/// after printing. A rollback is refused before anything runs if a migration in its way has no
+/// down script.
+pub async fn run_migrate(cfg: &crate::config::AppConfig, dry_run: bool, down_to: Option<u32>) -> Result<(), Box<dyn std::error::Error>> {
+    crate::db::connect(cfg).await?;
+    let (files, applied) = crate::db::migration_status().await?;
+    println!("Schema version {} ({} of {} migrations applied)", crate::migration::schema_version(&files, &applied), applied.len(), files.len());
+
+    if let Some(target) = down_to {
+        let revert = crate::migration::rollback_plan(&files, &applied, target)?;
+        if revert.is_empty() {
+            println!("Nothing to revert: the schema is at or below version {}", target);
+            return Ok(());
+        }
+        for file in &revert {
+            println!("  revert {} ({})", file.name, file.down.as_deref().unwrap_or_default());
+        }
+        if dry_run {
+            println!("Dry run: {} migration{} would be reverted", revert.len(), if revert.len() == 1 { "" } else { "s" });
+            return Ok(());
+        }
+        for file in &revert {
+            crate::db::revert_migration(file).await?;
+        }
+        println!("Reverted to schema version {}", target);
+        return Ok(());
+    }
+
+    let plan = crate::migration::plan(&files, &applied);
+    for name in &plan.changed {
+        println!("  changed since applied: {}", name);
+    }
+    for file in &plan.unrecorded {
+        println!("  record checksum: {}", file.name);
+    }
+    for file in &plan.pending {
+        println!("  apply {}", file.name);
+    }
+    if !plan.changed.is_empty() {
+        return Err(format!("{} applied migration{} changed on disk; restore the original{} and put the change in a new migration",
+            plan.changed.len(),
+            if plan.changed.len() == 1 { "" } else { "s" },
+            if plan.changed.len() == 1 { "" } else { "s" },
+        ).into());
+    }
+    if plan.pending.is_empty() && plan.unrecorded.is_empty() {
+        println!("Schema is up to date");
+        return Ok(());
+    }
+    if dry_run {
+        println!("Dry run: {} migration{} would be applied", plan.pending.len(), if plan.pending.len() == 1 { "" } else { "s" });
+        return Ok(());
+    }
+
+    crate::db::run_migrations().await?;
+    let (files, applied) = crate::db::migration_status().await?;
+    println!("Migrated to schema version {}", crate::migration::schema_version(&files, &applied));
+    Ok(())
+}
+
 /// Build a climate summary string from DB zone readings (no auth context needed).
 async fn build_climate_summary_for_owner(owner: &surrealdb::types::RecordId) -> String {
     #[derive(serde::Deserialize, SurrealValue)]
@@ -300,7 +1067,8 @@ async fn update_orchid_ai_fields(
                 rest_water_multiplier = $rest_water_multiplier, \
                 rest_fertilizer_multiplier = $rest_fertilizer_multiplier, \
                 active_water_multiplier = $active_water_multiplier, \
-                active_fertilizer_multiplier = $active_fertilizer_multiplier"
+                active_fertilizer_multiplier = $active_fertilizer_multiplier, \
+                updated_at = time::now()"
         )
         .bind(("id", record))
         .bind(("water_freq", result.water_freq))
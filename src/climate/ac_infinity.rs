@@ -110,11 +110,16 @@ pub async fn fetch_ac_infinity_reading(
     let humidity = humidity_raw / 100.0;
     let vpd = vpd_raw.map(|v| v / 100.0);
 
+    let (co2_ppm, substrate_moisture_pct) = accessory_sensor_values(device);
+
     Ok(RawReading {
         temperature_c: temp_c,
         humidity_pct: humidity,
         vpd_kpa: vpd,
         precipitation_mm: None,
+        co2_ppm,
+        substrate_moisture_pct,
+        ..Default::default()
     })
 }
 
@@ -196,6 +201,7 @@ pub async fn fetch_ac_infinity_all_ports(
 
     // Step 4: Iterate ALL ports and build readings map
     let mut readings = HashMap::new();
+    let (co2_ppm, substrate_moisture_pct) = accessory_sensor_values(device);
 
     for port_data in ports {
         let port_id = match port_data.get("portId").and_then(|id| id.as_u64()) {
@@ -225,8 +231,66 @@ pub async fn fetch_ac_infinity_all_ports(
             humidity_pct: humidity,
             vpd_kpa: vpd,
             precipitation_mm: None,
+            co2_ppm,
+            substrate_moisture_pct,
+            ..Default::default()
         });
     }
 
     Ok(readings)
 }
+
+/// AC Infinity `sensorType` codes for accessory sensors plugged into a controller.
+const SENSOR_TYPE_SOIL: u64 = 10;
+const SENSOR_TYPE_CO2: u64 = 11;
+
+/// Extract CO2 (ppm) and substrate moisture (%) from a device's accessory sensors, if any are attached.
+/// Accessory sensors are listed per controller rather than per port, so every port shares them.
+fn accessory_sensor_values(device: &serde_json::Value) -> (Option<f64>, Option<f64>) {
+    let sensors = device.get("sensors")
+        .or_else(|| device.get("deviceInfo").and_then(|info| info.get("sensors")))
+        .and_then(|s| s.as_array());
+    let Some(sensors) = sensors else {
+        return (None, None);
+    };
+
+    let value_of = |sensor_type: u64| {
+        sensors.iter()
+            .find(|s| s.get("sensorType").and_then(|t| t.as_u64()) == Some(sensor_type))
+            .and_then(|s| {
+                let raw = s.get("sensorData").and_then(|v| v.as_f64())?;
+                // Values are sent as integers scaled by 10^(precision - 1), like the port readings
+                let precision = s.get("sensorPrecision").and_then(|p| p.as_u64()).unwrap_or(1);
+                Some(raw / 10f64.powi(precision.saturating_sub(1) as i32))
+            })
+    };
+
+    (value_of(SENSOR_TYPE_CO2), value_of(SENSOR_TYPE_SOIL))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accessory_sensor_values() {
+        let device = serde_json::json!({
+            "devId": "abc",
+            "deviceInfo": {
+                "sensors": [
+                    { "sensorType": 0, "sensorData": 7520, "sensorPrecision": 3 },
+                    { "sensorType": 11, "sensorData": 812, "sensorPrecision": 1 },
+                    { "sensorType": 10, "sensorData": 4350, "sensorPrecision": 3 },
+                ]
+            }
+        });
+        let (co2, moisture) = accessory_sensor_values(&device);
+        assert_eq!(co2, Some(812.0));
+        assert_eq!(moisture, Some(43.5));
+    }
+
+    #[test]
+    fn test_accessory_sensor_values_without_accessories() {
+        assert_eq!(accessory_sensor_values(&serde_json::json!({ "devId": "abc" })), (None, None));
+    }
+}
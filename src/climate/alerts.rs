@@ -1,4 +1,36 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use crate::care_rules::ClimateMetric;
+use crate::orchid::ClimateReading;
+
+/// A plant-range temperature alert turns critical this many degrees past the plant's limit.
+const CRITICAL_TEMP_MARGIN_C: f64 = 5.0;
+
+/// A plant-range humidity alert turns critical this many percentage points past the plant's limit.
+const CRITICAL_HUMIDITY_MARGIN_PCT: f64 = 15.0;
+
+/// A zone whose temperature is within this many degrees of its dew point gets a condensation warning.
+const CONDENSATION_MARGIN_C: f64 = 2.0;
+
+/// At or below this spread the warning becomes critical: water is already forming on cool surfaces.
+const CONDENSATION_CRITICAL_C: f64 = 0.5;
+
+/// Temperatures outside this range (Celsius) are a failing sensor, not a growing space.
+const PLAUSIBLE_TEMP_C: (f64, f64) = (-30.0, 60.0);
+
+/// A temperature change larger than this between readings less than `JUMP_WINDOW_MINUTES` apart is a jump.
+const JUMP_TEMP_C: f64 = 8.0;
+
+/// A humidity change larger than this (percentage points) between close readings is a jump.
+const JUMP_HUMIDITY_PCT: f64 = 30.0;
+
+/// Readings further apart than this are not compared for jumps; conditions can change over a gap.
+const JUMP_WINDOW_MINUTES: i64 = 30;
+
+/// The same temperature and humidity, repeated exactly for this long, means the sensor is stuck.
+const STUCK_HOURS: i64 = 6;
+
+/// How many identical readings in a row it takes before a run can count as stuck.
+const STUCK_MIN_READINGS: usize = 4;
 
 /// **What is it?**
 /// A struct representing a newly generated climate or watering alert before it is persisted to the database.
@@ -8,6 +40,7 @@ use chrono::{DateTime, Utc};
 ///
 /// **How should it be used?**
 /// Instantiate this struct inside `check_alerts` when a condition is violated, then pass the resulting vector to the database insertion logic.
+#[derive(Clone)]
 pub struct NewAlert {
     /// The ID of the user who owns the alert.
     pub owner: surrealdb::types::RecordId,
@@ -23,6 +56,186 @@ pub struct NewAlert {
     pub message: String,
 }
 
+/// Alert types `check_alerts` and `check_condensation` re-evaluate on every run, so their absence means the condition cleared.
+/// These are matched by plant and zone rather than message, since the message carries the latest reading.
+pub const AUTO_RESOLVE_TYPES: [&str; 6] = [
+    "temperature_low",
+    "temperature_high",
+    "humidity_low",
+    "humidity_high",
+    "condensation_risk",
+    "watering_overdue",
+];
+
+/// Alert types that escalate when left alone. Overdue care only gets worse, unlike readings that come and go.
+pub const ESCALATING_TYPES: [&str; 1] = ["watering_overdue"];
+
+/// **What is it?**
+/// An unresolved alert as stored, with the state that decides whether a repeat of its condition should notify again.
+///
+/// **Why does it exist?**
+/// It exists so acknowledgment, snoozing and auto-resolution can be decided by pure functions before touching the database.
+///
+/// **How should it be used?**
+/// Load open alerts for an owner and pass them to `delivery_for` (when storing a new alert) or `cleared_alerts` (after a check run).
+pub struct OpenAlert {
+    /// The alert's record ID.
+    pub id: surrealdb::types::RecordId,
+    /// The owner of the collection.
+    pub owner: surrealdb::types::RecordId,
+    /// The associated orchid, if any.
+    pub orchid: Option<surrealdb::types::RecordId>,
+    /// The associated zone, if any.
+    pub zone: Option<surrealdb::types::RecordId>,
+    /// The type of the alert.
+    pub alert_type: String,
+    /// The current severity.
+    pub severity: String,
+    /// The current message.
+    pub message: String,
+    /// Whether someone acknowledged it.
+    pub acknowledged: bool,
+    /// Hidden and silent until this time, if snoozed.
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// When the household was last notified (the creation time if never re-sent).
+    pub last_notified_at: DateTime<Utc>,
+    /// When it was first raised.
+    pub created_at: DateTime<Utc>,
+    /// When it was last escalated, if ever.
+    pub escalated_at: Option<DateTime<Utc>>,
+}
+
+impl OpenAlert {
+    /// Whether `alert` reports the same ongoing condition.
+    fn same_condition(&self, alert: &NewAlert) -> bool {
+        self.owner == alert.owner
+            && self.alert_type == alert.alert_type
+            && if AUTO_RESOLVE_TYPES.contains(&alert.alert_type.as_str()) {
+                self.orchid == alert.orchid && self.zone == alert.zone
+            } else {
+                // Other alerts never clear on their own, so an acknowledged one is closed
+                !self.acknowledged && self.message == alert.message
+            }
+    }
+}
+
+/// What to do with a freshly raised alert, given any open alert for the same condition.
+#[derive(Debug, PartialEq)]
+pub enum Delivery {
+    /// Store it as a new alert and notify.
+    Create,
+    /// Update the open alert with the latest message, clear its acknowledgment and snooze, and notify again.
+    Renotify(surrealdb::types::RecordId),
+    /// Keep quiet; the open alert is acknowledged, snoozed, or was sent within the dedup window.
+    Suppress(surrealdb::types::RecordId),
+}
+
+/// Rank of a severity string, for spotting escalation.
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "critical" => 2,
+        "warning" => 1,
+        _ => 0,
+    }
+}
+
+/// The severity string for a rank.
+fn severity_name(rank: u8) -> &'static str {
+    match rank {
+        2 => "critical",
+        1 => "warning",
+        _ => "info",
+    }
+}
+
+/// The severity a repeat of an escalating alert should carry instead of the one it was raised with.
+#[derive(Debug, PartialEq)]
+pub struct SeverityOverride {
+    /// The severity to store and notify with.
+    pub severity: &'static str,
+    /// Whether this raises the open alert's severity, rather than keeping an earlier escalation.
+    pub escalated: bool,
+}
+
+/// **What is it?**
+/// A pure function that escalates overdue-care alerts nobody has dealt with: after `after_days` open without
+/// being acknowledged or snoozed, a repeat goes up one severity (info, warning, critical), and again after each further `after_days`.
+///
+/// **Why does it exist?**
+/// It exists so a plant left dry for a week doesn't stay at the quiet severity it was first raised at. Escalating to warning or critical is what makes
+/// `store_and_notify` notify again, and lets the owner's escalation webhook hear about it.
+///
+/// **How should it be used?**
+/// Call from `store_and_notify` before `delivery_for`, with the owner's `escalation_after_days` (`None` turns escalation off; earlier escalations are still kept).
+/// Only `ESCALATING_TYPES` are considered; `None` means store the alert as raised.
+pub fn escalation_for(open: &[OpenAlert], alert: &NewAlert, after_days: Option<u32>, now: DateTime<Utc>) -> Option<SeverityOverride> {
+    if !ESCALATING_TYPES.contains(&alert.alert_type.as_str()) {
+        return None;
+    }
+    let existing = open.iter().find(|o| o.same_condition(alert))?;
+    let current = severity_rank(&existing.severity).max(severity_rank(&alert.severity));
+    let ignored = !existing.acknowledged && !existing.snoozed_until.is_some_and(|until| until > now);
+    let due = after_days.filter(|d| *d > 0).is_some_and(|days| {
+        now - existing.escalated_at.unwrap_or(existing.created_at) >= Duration::days(days as i64)
+    });
+    let rank = if ignored && due { (current + 1).min(2) } else { current };
+    let severity = severity_name(rank);
+    (severity != alert.severity).then_some(SeverityOverride {
+        severity,
+        escalated: rank > severity_rank(&existing.severity),
+    })
+}
+
+/// **What is it?**
+/// A pure function that decides whether a freshly raised alert should notify, given the open alerts it might repeat.
+///
+/// **Why does it exist?**
+/// It exists so the same warning doesn't nag forever: an acknowledged condition stays quiet until it clears, a snoozed one until the snooze ends, and an unacknowledged one re-sends at most once per dedup window.
+///
+/// **How should it be used?**
+/// Call from `store_and_notify` with the owner's open alerts of the same type. Escalating to a higher severity always notifies again.
+pub fn delivery_for(open: &[OpenAlert], alert: &NewAlert, now: DateTime<Utc>, dedup_hours: u32) -> Delivery {
+    let Some(existing) = open.iter().find(|o| o.same_condition(alert)) else {
+        return Delivery::Create;
+    };
+    let escalated = severity_rank(&alert.severity) > severity_rank(&existing.severity);
+    let snoozed = existing.snoozed_until.is_some_and(|until| until > now);
+    let recently_sent = now - existing.last_notified_at < Duration::hours(dedup_hours as i64);
+    if escalated || !(existing.acknowledged || snoozed || recently_sent) {
+        Delivery::Renotify(existing.id.clone())
+    } else {
+        Delivery::Suppress(existing.id.clone())
+    }
+}
+
+/// **What is it?**
+/// A pure function that finds open alerts whose condition a check run no longer raises.
+///
+/// **Why does it exist?**
+/// It exists so a warning resolves itself once the zone recovers or the plant is watered, instead of waiting for someone to dismiss it.
+///
+/// **How should it be used?**
+/// Pass the open alerts of `AUTO_RESOLVE_TYPES`, the alerts the run raised, and the zones and plants it actually checked. Alerts about a zone with no fresh reading are kept, since a silent sensor is not a recovered one.
+pub fn cleared_alerts(
+    open: &[OpenAlert],
+    raised: &[NewAlert],
+    checked_zones: &[surrealdb::types::RecordId],
+    checked_orchids: &[surrealdb::types::RecordId],
+) -> Vec<surrealdb::types::RecordId> {
+    open.iter()
+        .filter(|o| AUTO_RESOLVE_TYPES.contains(&o.alert_type.as_str()))
+        .filter(|o| match (&o.zone, &o.orchid) {
+            (Some(zone), _) => checked_zones.contains(zone),
+            (None, Some(orchid)) => checked_orchids.contains(orchid),
+            (None, None) => false,
+        })
+        .filter(|o| !raised.iter().any(|a| {
+            a.owner == o.owner && a.alert_type == o.alert_type && a.orchid == o.orchid && a.zone == o.zone
+        }))
+        .map(|o| o.id.clone())
+        .collect()
+}
+
 /// **What is it?**
 /// A lightweight representation of an orchid containing only the fields necessary for evaluating climate and watering alerts.
 ///
@@ -44,6 +257,10 @@ pub struct OrchidRequirements {
     pub water_frequency_days: u32,
     /// When the orchid was last watered.
     pub last_watered_at: Option<DateTime<Utc>>,
+    /// While set and in the future, the grower has skipped a watering and no overdue alert fires.
+    pub water_deferred_until: Option<DateTime<Utc>>,
+    /// Days past due before a watering alert fires (plant override, else the owner's default).
+    pub overdue_grace_days: u32,
     /// Minimum temperature requirement in Celsius.
     pub temp_min: Option<f64>,
     /// Maximum temperature requirement in Celsius.
@@ -52,6 +269,8 @@ pub struct OrchidRequirements {
     pub humidity_min: Option<f64>,
     /// Maximum humidity requirement percentage.
     pub humidity_max: Option<f64>,
+    /// Readings whose range checks the owner's own alert rules handle for this plant (see `care_rules::replaces_builtin_check`).
+    pub rule_managed: Vec<ClimateMetric>,
 }
 
 /// **What is it?**
@@ -71,6 +290,12 @@ pub struct ZoneReading {
     pub temperature: f64,
     /// The recorded relative humidity.
     pub humidity: f64,
+    /// The dew point in Celsius (stored with the reading, or calculated for older readings).
+    pub dew_point: f64,
+    /// The zone's owner, for zone-level alerts such as condensation.
+    pub owner: Option<surrealdb::types::RecordId>,
+    /// Whether the zone is outdoors, where dew is weather rather than a cabinet problem.
+    pub outdoor: bool,
 }
 
 /// **What is it?**
@@ -91,11 +316,14 @@ pub fn check_alerts(
         let reading = readings.iter().find(|r| r.zone_name == orchid.placement);
 
         if let Some(reading) = reading {
+            let check_temperature = !orchid.rule_managed.contains(&ClimateMetric::Temperature);
+            let check_humidity = !orchid.rule_managed.contains(&ClimateMetric::Humidity);
+
             // Temperature checks
-            if let Some(temp_min) = orchid.temp_min {
+            if let Some(temp_min) = orchid.temp_min.filter(|_| check_temperature) {
                 let diff = temp_min - reading.temperature;
                 if diff > 0.0 {
-                    let severity = if diff > 5.0 { "critical" } else { "warning" };
+                    let severity = if diff > CRITICAL_TEMP_MARGIN_C { "critical" } else { "warning" };
                     alerts.push(NewAlert {
                         owner: orchid.owner.clone(),
                         orchid: Some(orchid.id.clone()),
@@ -110,10 +338,10 @@ pub fn check_alerts(
                 }
             }
 
-            if let Some(temp_max) = orchid.temp_max {
+            if let Some(temp_max) = orchid.temp_max.filter(|_| check_temperature) {
                 let diff = reading.temperature - temp_max;
                 if diff > 0.0 {
-                    let severity = if diff > 5.0 { "critical" } else { "warning" };
+                    let severity = if diff > CRITICAL_TEMP_MARGIN_C { "critical" } else { "warning" };
                     alerts.push(NewAlert {
                         owner: orchid.owner.clone(),
                         orchid: Some(orchid.id.clone()),
@@ -129,10 +357,10 @@ pub fn check_alerts(
             }
 
             // Humidity checks
-            if let Some(hum_min) = orchid.humidity_min {
+            if let Some(hum_min) = orchid.humidity_min.filter(|_| check_humidity) {
                 let diff = hum_min - reading.humidity;
                 if diff > 0.0 {
-                    let severity = if diff > 15.0 { "critical" } else { "warning" };
+                    let severity = if diff > CRITICAL_HUMIDITY_MARGIN_PCT { "critical" } else { "warning" };
                     alerts.push(NewAlert {
                         owner: orchid.owner.clone(),
                         orchid: Some(orchid.id.clone()),
@@ -147,10 +375,10 @@ pub fn check_alerts(
                 }
             }
 
-            if let Some(hum_max) = orchid.humidity_max {
+            if let Some(hum_max) = orchid.humidity_max.filter(|_| check_humidity) {
                 let diff = reading.humidity - hum_max;
                 if diff > 0.0 {
-                    let severity = if diff > 15.0 { "critical" } else { "warning" };
+                    let severity = if diff > CRITICAL_HUMIDITY_MARGIN_PCT { "critical" } else { "warning" };
                     alerts.push(NewAlert {
                         owner: orchid.owner.clone(),
                         orchid: Some(orchid.id.clone()),
@@ -166,10 +394,11 @@ pub fn check_alerts(
             }
         }
 
-        // Watering overdue check
-        if let Some(last_watered) = orchid.last_watered_at {
+        // Watering overdue check (not while a skipped watering is deferred)
+        let deferred = orchid.water_deferred_until.is_some_and(|until| until > Utc::now());
+        if let Some(last_watered) = orchid.last_watered_at.filter(|_| !deferred) {
             let days_since = (Utc::now() - last_watered).num_days();
-            if days_since > orchid.water_frequency_days as i64 {
+            if days_since > orchid.water_frequency_days as i64 + orchid.overdue_grace_days as i64 {
                 let overdue = days_since - orchid.water_frequency_days as i64;
                 alerts.push(NewAlert {
                     owner: orchid.owner.clone(),
@@ -189,6 +418,270 @@ pub fn check_alerts(
     alerts
 }
 
+/// **What is it?**
+/// A pure function that flags zones whose temperature has come within a couple of degrees of the dew point.
+///
+/// **Why does it exist?**
+/// It exists because enclosed cabinets and terrariums cool overnight while humidity stays high, and water condensing on leaves and crowns is a leading cause of rot.
+///
+/// **How should it be used?**
+/// Call this alongside `check_alerts` with the latest reading per zone. Outdoor zones and zones with no known owner are skipped; one alert is raised per zone, not per plant.
+pub fn check_condensation(readings: &[ZoneReading]) -> Vec<NewAlert> {
+    readings.iter()
+        .filter(|r| !r.outdoor)
+        .filter_map(|r| {
+            let owner = r.owner.clone()?;
+            let spread = r.temperature - r.dew_point;
+            if spread > CONDENSATION_MARGIN_C {
+                return None;
+            }
+            let severity = if spread <= CONDENSATION_CRITICAL_C { "critical" } else { "warning" };
+            Some(NewAlert {
+                owner,
+                orchid: None,
+                zone: Some(r.zone_id.clone()),
+                alert_type: "condensation_risk".into(),
+                severity: severity.into(),
+                message: format!(
+                    "{}: Temperature {:.1}C is within {:.1}C of the dew point ({:.1}C); condensation risk, increase airflow",
+                    r.zone_name, r.temperature, spread.max(0.0), r.dew_point
+                ),
+            })
+        })
+        .collect()
+}
+
+/// **What is it?**
+/// A problem with a zone's sensor stream, found by `screen_readings`.
+///
+/// **Why does it exist?**
+/// It exists so a sensor that fell off its mount, has a dying battery, or froze on one value is reported as a hardware problem instead of being read as real climate.
+///
+/// **How should it be used?**
+/// Turn each fault into a `sensor_health` alert via `describe`; the readings it covers are already left out of `ScreenedReadings::kept`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SensorFault {
+    /// A reading outside physical limits (below -30C or above 60C, humidity outside 0-100%).
+    Implausible {
+        /// When the reading was taken.
+        at: DateTime<Utc>,
+        /// The reported temperature in Celsius.
+        temperature: f64,
+        /// The reported humidity.
+        humidity: f64,
+    },
+    /// An isolated reading far from both its neighbours, or a sudden step between close readings.
+    Jump {
+        /// When the reading after the jump was taken.
+        at: DateTime<Utc>,
+        /// Temperature change in Celsius.
+        temp_delta: f64,
+        /// Humidity change in percentage points.
+        humidity_delta: f64,
+        /// Minutes between the two readings.
+        minutes: i64,
+    },
+    /// The same temperature and humidity reported over and over.
+    Stuck {
+        /// When the repeated value was first reported.
+        since: DateTime<Utc>,
+        /// The repeated temperature in Celsius.
+        temperature: f64,
+        /// The repeated humidity.
+        humidity: f64,
+    },
+}
+
+impl SensorFault {
+    /// A one-line explanation for the alert, prefixed with the zone name.
+    pub fn describe(&self, zone_name: &str) -> String {
+        match self {
+            SensorFault::Implausible { temperature, humidity, .. } => format!(
+                "{}: Sensor reported {:.1}C / {:.0}% RH, which is not physically plausible; check that it is mounted and charged",
+                zone_name, temperature, humidity
+            ),
+            SensorFault::Jump { temp_delta, humidity_delta, minutes, .. } => format!(
+                "{}: Sensor jumped {:+.1}C / {:+.0}% RH in {} minutes; check whether it fell or its battery is low",
+                zone_name, temp_delta, humidity_delta, minutes
+            ),
+            SensorFault::Stuck { temperature, humidity, .. } => format!(
+                "{}: Sensor has reported exactly {:.1}C / {:.0}% RH for over {} hours; it may be frozen or disconnected",
+                zone_name, temperature, humidity, STUCK_HOURS
+            ),
+        }
+    }
+}
+
+/// The readings that passed `screen_readings`, and what was wrong with the rest.
+#[derive(Debug, Clone, Default)]
+pub struct ScreenedReadings {
+    /// Readings safe to average, in their original order.
+    pub kept: Vec<ClimateReading>,
+    /// Faults found in the stream.
+    pub faults: Vec<SensorFault>,
+}
+
+fn is_plausible(r: &ClimateReading) -> bool {
+    (PLAUSIBLE_TEMP_C.0..=PLAUSIBLE_TEMP_C.1).contains(&r.temperature)
+        && (0.0..=100.0).contains(&r.humidity)
+}
+
+fn is_jump(a: &ClimateReading, b: &ClimateReading) -> bool {
+    (b.recorded_at - a.recorded_at).num_minutes().abs() <= JUMP_WINDOW_MINUTES
+        && ((b.temperature - a.temperature).abs() > JUMP_TEMP_C
+            || (b.humidity - a.humidity).abs() > JUMP_HUMIDITY_PCT)
+}
+
+/// **What is it?**
+/// A pure function that screens one zone's sensor stream for implausible values, sudden jumps, and stuck values.
+///
+/// **Why does it exist?**
+/// It exists so bad sensor data raises a sensor health alert instead of skewing the watering algorithm, which averages the last 48 hours of readings.
+///
+/// **How should it be used?**
+/// Pass one zone's readings in any order. Use `kept` wherever readings feed calculations and turn `faults` into alerts. Out-of-range readings and isolated spikes (far from both neighbours, which agree with each other) are dropped; a stuck run keeps only its first reading. A lasting step is reported but kept, since the zone may really have changed.
+pub fn screen_readings(readings: &[ClimateReading]) -> ScreenedReadings {
+    let mut faults = Vec::new();
+    let mut sorted: Vec<&ClimateReading> = readings.iter().collect();
+    sorted.sort_by_key(|r| r.recorded_at);
+
+    let mut plausible = Vec::with_capacity(sorted.len());
+    for r in sorted {
+        if is_plausible(r) {
+            plausible.push(r);
+        } else {
+            faults.push(SensorFault::Implausible { at: r.recorded_at, temperature: r.temperature, humidity: r.humidity });
+        }
+    }
+
+    // Spikes: a reading that jumps away from a neighbour that agrees with the reading on its other side
+    let mut keep = vec![true; plausible.len()];
+    let mut i = 1;
+    while i < plausible.len() {
+        let (prev, cur) = (plausible[i - 1], plausible[i]);
+        if !is_jump(prev, cur) {
+            i += 1;
+            continue;
+        }
+        faults.push(SensorFault::Jump {
+            at: cur.recorded_at,
+            temp_delta: cur.temperature - prev.temperature,
+            humidity_delta: cur.humidity - prev.humidity,
+            minutes: (cur.recorded_at - prev.recorded_at).num_minutes(),
+        });
+        let isolated = plausible.get(i + 1).is_some_and(|next| is_jump(cur, next) && !is_jump(prev, next));
+        if isolated {
+            // Drop the spike; the jump back down is the same fault
+            keep[i] = false;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    let plausible: Vec<&ClimateReading> = plausible.into_iter().zip(keep).filter_map(|(r, k)| k.then_some(r)).collect();
+
+    // Stuck: runs of exactly repeated values spanning STUCK_HOURS or more
+    let mut kept = Vec::with_capacity(plausible.len());
+    let mut start = 0;
+    while start < plausible.len() {
+        let first = plausible[start];
+        let mut end = start + 1;
+        while end < plausible.len()
+            && plausible[end].temperature == first.temperature
+            && plausible[end].humidity == first.humidity
+        {
+            end += 1;
+        }
+        let span = plausible[end - 1].recorded_at - first.recorded_at;
+        if end - start >= STUCK_MIN_READINGS && span >= Duration::hours(STUCK_HOURS) {
+            faults.push(SensorFault::Stuck { since: first.recorded_at, temperature: first.temperature, humidity: first.humidity });
+            kept.push(first.clone());
+        } else {
+            kept.extend(plausible[start..end].iter().map(|r| (*r).clone()));
+        }
+        start = end;
+    }
+
+    ScreenedReadings { kept, faults }
+}
+
+/// **What is it?**
+/// A background check that screens the last day of each zone's readings and raises `sensor_health` alerts.
+///
+/// **Why does it exist?**
+/// It exists so growers hear that a sensor fell, is running out of battery, or froze, rather than silently getting watering advice built on bad numbers.
+///
+/// **How should it be used?**
+/// `check_and_send_alerts` runs it every cycle. Only faults from the last two hours are reported, so an old glitch isn't raised again on every poll.
+pub async fn check_sensor_health() {
+    use crate::db::db;
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::server_fns::climate::ssr_types::ReadingDbRow;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct ZoneOwnerRow {
+        id: surrealdb::types::RecordId,
+        name: String,
+        owner: surrealdb::types::RecordId,
+    }
+
+    let mut resp = match db()
+        .query(
+            "SELECT id, name, owner FROM growing_zone WHERE data_source_type != NONE; \
+             SELECT * FROM climate_reading WHERE recorded_at > time::now() - 24h AND source NOT IN ['manual', 'wizard'];"
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("Sensor health: query failed: {}", e);
+            return;
+        }
+    };
+    let _ = resp.take_errors();
+    let zones: Vec<ZoneOwnerRow> = resp.take(0).unwrap_or_default();
+    let rows: Vec<ReadingDbRow> = resp.take(1).unwrap_or_default();
+
+    let mut by_zone: std::collections::HashMap<String, Vec<ClimateReading>> = std::collections::HashMap::new();
+    for row in rows {
+        let reading = row.into_climate_reading();
+        by_zone.entry(reading.zone_id.clone()).or_default().push(reading);
+    }
+
+    let recent = Utc::now() - Duration::hours(2);
+    let mut alerts = Vec::new();
+    for zone in zones {
+        let Some(readings) = by_zone.get(&record_id_to_string(&zone.id)) else {
+            continue;
+        };
+        for fault in screen_readings(readings).faults {
+            let at = match fault {
+                SensorFault::Implausible { at, .. } | SensorFault::Jump { at, .. } => at,
+                // A stuck run is still current as long as its latest reading is recent
+                SensorFault::Stuck { .. } => readings.iter().map(|r| r.recorded_at).max().unwrap_or(recent),
+            };
+            if at < recent {
+                continue;
+            }
+            alerts.push(NewAlert {
+                owner: zone.owner.clone(),
+                orchid: None,
+                zone: Some(zone.id.clone()),
+                alert_type: "sensor_health".into(),
+                severity: "warning".into(),
+                message: fault.describe(&zone.name),
+            });
+        }
+    }
+
+    if !alerts.is_empty() {
+        tracing::info!("Sensor health: {} faults found", alerts.len());
+        store_and_notify(&alerts, 24).await;
+    }
+}
+
 /// **What is it?**
 /// An asynchronous orchestration function that fetches necessary data, evaluates conditions via `check_alerts`, and persists new alerts while sending push notifications.
 ///
@@ -212,6 +705,8 @@ pub async fn check_and_send_alerts() {
         #[surreal(default)]
         last_watered_at: Option<DateTime<Utc>>,
         #[surreal(default)]
+        water_deferred_until: Option<DateTime<Utc>>,
+        #[surreal(default)]
         temp_min: Option<f64>,
         #[surreal(default)]
         temp_max: Option<f64>,
@@ -219,6 +714,15 @@ pub async fn check_and_send_alerts() {
         humidity_min: Option<f64>,
         #[surreal(default)]
         humidity_max: Option<f64>,
+        #[surreal(default)]
+        overdue_grace_days: Option<u32>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct GraceRow {
+        owner: surrealdb::types::RecordId,
+        overdue_grace_days: u32,
     }
 
     #[derive(serde::Deserialize, SurrealValue)]
@@ -228,20 +732,19 @@ pub async fn check_and_send_alerts() {
         zone_name: String,
         temperature: f64,
         humidity: f64,
+        #[surreal(default)]
+        dew_point: Option<f64>,
+        #[surreal(default)]
+        owner: Option<surrealdb::types::RecordId>,
+        #[surreal(default)]
+        location_type: Option<String>,
     }
 
-    #[derive(serde::Deserialize, SurrealValue)]
-    #[surreal(crate = "surrealdb::types")]
-    struct PushSubRow {
-        owner: surrealdb::types::RecordId,
-        endpoint: String,
-        p256dh: String,
-        auth: String,
-    }
+    check_sensor_health().await;
 
     // 1. Fetch all orchids with structured requirements
     let mut orchid_resp = match db()
-        .query("SELECT id, owner, name, placement, water_frequency_days, last_watered_at, temp_min, temp_max, humidity_min, humidity_max FROM orchid WHERE temp_min IS NOT NULL OR temp_max IS NOT NULL OR humidity_min IS NOT NULL OR humidity_max IS NOT NULL OR last_watered_at IS NOT NULL")
+        .query("SELECT id, owner, name, placement, water_frequency_days, last_watered_at, water_deferred_until, temp_min, temp_max, humidity_min, humidity_max, overdue_grace_days FROM orchid WHERE archived_at = NONE AND (temp_min IS NOT NULL OR temp_max IS NOT NULL OR humidity_min IS NOT NULL OR humidity_max IS NOT NULL OR last_watered_at IS NOT NULL)")
         .await
     {
         Ok(r) => r,
@@ -259,7 +762,7 @@ pub async fn check_and_send_alerts() {
 
     // 2. Get latest readings per zone (fetch recent, deduplicate by zone in Rust)
     let mut reading_resp = match db()
-        .query("SELECT zone, zone_name, temperature, humidity, recorded_at FROM climate_reading WHERE recorded_at > time::now() - 2h ORDER BY recorded_at DESC")
+        .query("SELECT zone, zone_name, temperature, humidity, dew_point, zone.owner AS owner, zone.location_type AS location_type, recorded_at FROM climate_reading WHERE recorded_at > time::now() - 2h ORDER BY recorded_at DESC")
         .await
     {
         Ok(r) => r,
@@ -277,15 +780,52 @@ pub async fn check_and_send_alerts() {
         .filter(|r| seen_zones.insert(format!("{:?}", r.zone)))
         .collect();
 
+    // Owners' default watering grace periods (per-plant overrides take precedence)
+    let grace_rows: Vec<GraceRow> = match db()
+        .query("SELECT owner, overdue_grace_days FROM user_preference WHERE overdue_grace_days > 0")
+        .await
+    {
+        Ok(mut r) => {
+            let _ = r.take_errors();
+            r.take(0).unwrap_or_default()
+        }
+        Err(e) => {
+            tracing::warn!("Alert check: failed to query grace preferences: {}", e);
+            Vec::new()
+        }
+    };
+    let owner_grace: std::collections::HashMap<String, u32> = grace_rows
+        .into_iter()
+        .map(|g| (format!("{:?}", g.owner), g.overdue_grace_days))
+        .collect();
+
+    // Zone- and plant-scoped alert rules take over the built-in range checks they cover
+    let rules_by_owner = super::care_rules::enabled_rules_by_owner().await;
+    let rule_managed = |r: &OrchidRow| -> Vec<ClimateMetric> {
+        let Some((_, rules)) = rules_by_owner.get(&format!("{:?}", r.owner)) else { return Vec::new() };
+        let Some(zone) = reading_rows.iter().find(|z| z.zone_name == r.placement && z.owner.as_ref() == Some(&r.owner)) else {
+            return Vec::new();
+        };
+        let orchid_id = crate::server_fns::auth::record_id_to_string(&r.id);
+        let zone_id = crate::server_fns::auth::record_id_to_string(&zone.zone);
+        [ClimateMetric::Temperature, ClimateMetric::Humidity].into_iter()
+            .filter(|m| crate::care_rules::replaces_builtin_check(rules, &orchid_id, &zone_id, *m))
+            .collect()
+    };
+
     let orchid_reqs: Vec<OrchidRequirements> = orchid_rows
         .into_iter()
         .map(|r| OrchidRequirements {
+            overdue_grace_days: r.overdue_grace_days
+                .unwrap_or_else(|| owner_grace.get(&format!("{:?}", r.owner)).copied().unwrap_or(0)),
+            rule_managed: rule_managed(&r),
             id: r.id,
             owner: r.owner,
             name: r.name,
             placement: r.placement,
             water_frequency_days: r.water_frequency_days,
             last_watered_at: r.last_watered_at,
+            water_deferred_until: r.water_deferred_until,
             temp_min: r.temp_min,
             temp_max: r.temp_max,
             humidity_min: r.humidity_min,
@@ -296,15 +836,24 @@ pub async fn check_and_send_alerts() {
     let zone_readings: Vec<ZoneReading> = reading_rows
         .into_iter()
         .map(|r| ZoneReading {
+            dew_point: r.dew_point.unwrap_or_else(|| super::calculate_dew_point(r.temperature, r.humidity)),
+            outdoor: r.location_type.as_deref() == Some("Outdoor"),
             zone_name: r.zone_name,
             zone_id: r.zone,
             temperature: r.temperature,
             humidity: r.humidity,
+            owner: r.owner,
         })
         .collect();
 
     // 3. Check alerts
-    let new_alerts = check_alerts(&orchid_reqs, &zone_readings);
+    let mut new_alerts = check_alerts(&orchid_reqs, &zone_readings);
+    new_alerts.extend(check_condensation(&zone_readings));
+
+    // Resolve open alerts whose condition has cleared, before deduplicating against them
+    let checked_zones: Vec<_> = zone_readings.iter().map(|z| z.zone_id.clone()).collect();
+    let checked_orchids: Vec<_> = orchid_reqs.iter().map(|o| o.id.clone()).collect();
+    resolve_cleared(&new_alerts, &checked_zones, &checked_orchids).await;
 
     if new_alerts.is_empty() {
         return;
@@ -312,72 +861,506 @@ pub async fn check_and_send_alerts() {
 
     tracing::info!("Alert check: {} new alerts generated", new_alerts.len());
 
-    // 4. Store alerts (with dedup: skip if identical unacknowledged alert from last 6h)
-    for alert in &new_alerts {
-        let mut dup_check = match db()
-            .query(
-                "SELECT count() FROM alert WHERE owner = $owner AND alert_type = $atype AND message = $msg AND acknowledged_at IS NULL AND created_at > time::now() - 6h GROUP ALL"
-            )
+    // 4. Store alerts and push warnings
+    store_and_notify(&new_alerts, 6).await;
+}
+
+#[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct OpenAlertRow {
+    id: surrealdb::types::RecordId,
+    owner: surrealdb::types::RecordId,
+    #[surreal(default)]
+    orchid: Option<surrealdb::types::RecordId>,
+    #[surreal(default)]
+    zone: Option<surrealdb::types::RecordId>,
+    alert_type: String,
+    severity: String,
+    message: String,
+    #[surreal(default)]
+    acknowledged_at: Option<DateTime<Utc>>,
+    #[surreal(default)]
+    snoozed_until: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    #[surreal(default)]
+    last_notified_at: Option<DateTime<Utc>>,
+    #[surreal(default)]
+    escalated_at: Option<DateTime<Utc>>,
+}
+
+impl OpenAlertRow {
+    fn into_open_alert(self) -> OpenAlert {
+        OpenAlert {
+            id: self.id,
+            owner: self.owner,
+            orchid: self.orchid,
+            zone: self.zone,
+            alert_type: self.alert_type,
+            severity: self.severity,
+            message: self.message,
+            acknowledged: self.acknowledged_at.is_some(),
+            snoozed_until: self.snoozed_until,
+            last_notified_at: self.last_notified_at.unwrap_or(self.created_at),
+            created_at: self.created_at,
+            escalated_at: self.escalated_at,
+        }
+    }
+}
+
+/// **What is it?**
+/// An asynchronous helper that resolves open alerts whose condition the latest check run no longer raises.
+///
+/// **Why does it exist?**
+/// It exists so temperature, humidity, condensation and watering warnings clear themselves once conditions recover.
+///
+/// **How should it be used?**
+/// Call from `check_and_send_alerts` after evaluating, with every alert the run raised (stored or not) and the zones and plants it checked.
+async fn resolve_cleared(
+    raised: &[NewAlert],
+    checked_zones: &[surrealdb::types::RecordId],
+    checked_orchids: &[surrealdb::types::RecordId],
+) {
+    use crate::db::db;
+
+    let open: Vec<OpenAlert> = match db()
+        .query("SELECT * FROM alert WHERE resolved_at IS NONE AND alert_type IN $types")
+        .bind(("types", AUTO_RESOLVE_TYPES.map(String::from).to_vec()))
+        .await
+    {
+        Ok(mut r) => {
+            let _ = r.take_errors();
+            let rows: Vec<OpenAlertRow> = r.take(0).unwrap_or_default();
+            rows.into_iter().map(|r| r.into_open_alert()).collect()
+        }
+        Err(e) => {
+            tracing::warn!("Alert check: failed to query open alerts: {}", e);
+            return;
+        }
+    };
+
+    let cleared = cleared_alerts(&open, raised, checked_zones, checked_orchids);
+    if cleared.is_empty() {
+        return;
+    }
+    tracing::info!("Alert check: {} alerts resolved", cleared.len());
+    if let Err(e) = db()
+        .query("UPDATE alert SET resolved_at = time::now() WHERE id IN $ids")
+        .bind(("ids", cleared))
+        .await
+    {
+        tracing::warn!("Alert check: failed to resolve alerts: {}", e);
+    }
+}
+
+/// The notification title for an alert.
+fn alert_title(alert: &NewAlert) -> &'static str {
+    match alert.severity.as_str() {
+        "critical" => "Critical Alert",
+        _ => "Warning",
+    }
+}
+
+/// Posts an alert to the household's Discord or Slack webhook, if the owner set one.
+async fn post_to_webhook(alert: &NewAlert, title: &str) {
+    let Some(url) = crate::server_fns::alerts::alert_webhook_for_owner(&alert.owner).await else {
+        return;
+    };
+    if let Err(e) = crate::push::deliver_webhook(&alert.owner, &url, title, &alert.message).await {
+        tracing::warn!("Webhook notification failed: {}", e);
+    }
+}
+
+/// An owner's escalation settings, as stored on `user_preference`.
+struct EscalationRule {
+    owner: surrealdb::types::RecordId,
+    after_days: Option<u32>,
+    webhook_url: Option<String>,
+}
+
+/// Loads the escalation settings of every owner who set them.
+async fn escalation_rules() -> Vec<EscalationRule> {
+    use crate::db::db;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct RuleRow {
+        owner: surrealdb::types::RecordId,
+        #[surreal(default)]
+        escalation_after_days: Option<i64>,
+        #[surreal(default)]
+        escalation_webhook_url: Option<String>,
+    }
+
+    let mut resp = match db()
+        .query("SELECT owner, escalation_after_days, escalation_webhook_url FROM user_preference \
+                WHERE escalation_after_days > 0 OR escalation_webhook_url != NONE")
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("Alert check: failed to query escalation rules: {}", e);
+            return Vec::new();
+        }
+    };
+    let _ = resp.take_errors();
+    let rows: Vec<RuleRow> = resp.take(0).unwrap_or_default();
+    rows.into_iter()
+        .map(|r| EscalationRule {
+            owner: r.owner,
+            after_days: r.escalation_after_days
+                .filter(|d| *d > 0)
+                .map(|d| d.min(crate::orchid::MAX_ESCALATION_DAYS as i64) as u32),
+            webhook_url: r.escalation_webhook_url.and_then(|u| crate::crypto::decrypt(&u).ok()),
+        })
+        .collect()
+}
+
+/// Pushes an alert to every device in the owner's household.
+async fn push_to_household(alert: &NewAlert, title: &str) {
+    use crate::db::db;
+    use crate::push_action::PushAction;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PushSubRow {
+        owner: surrealdb::types::RecordId,
+        endpoint: String,
+        p256dh: String,
+        auth: String,
+    }
+
+    let mut sub_resp = match db()
+        .query("SELECT owner, endpoint, p256dh, auth FROM push_subscription \
+                WHERE owner = $owner OR owner IN (SELECT VALUE user FROM household_member WHERE household.owner = $owner)")
+        .bind(("owner", alert.owner.clone()))
+        .await
+    {
+        Ok(r) => r,
+        Err(_) => return,
+    };
+    let _ = sub_resp.take_errors();
+    let subs: Vec<PushSubRow> = sub_resp.take(0).unwrap_or_default();
+
+    // Overdue watering can be dealt with from the notification itself
+    let actions: Vec<PushAction> = match &alert.orchid {
+        Some(orchid) if alert.alert_type == "watering_overdue" => {
+            vec![PushAction::MarkWatered { orchid_id: crate::server_fns::auth::record_id_to_string(orchid) }]
+        }
+        _ => Vec::new(),
+    };
+    for sub in subs {
+        let push_sub = crate::push::PushSubscriptionRow {
+            endpoint: sub.endpoint,
+            p256dh: sub.p256dh,
+            auth: sub.auth,
+        };
+        if let Err(e) = crate::push::deliver_push_with_actions(&sub.owner, &push_sub, title, &alert.message, &actions).await {
+            tracing::warn!("Push notification failed: {}", e);
+        }
+    }
+    crate::push::send_ntfy_to_household(&alert.owner, title, &alert.message).await;
+}
+
+/// **What is it?**
+/// An asynchronous helper that persists new alerts, skipping duplicates, and pushes critical and warning alerts to the household's devices and alert webhook.
+///
+/// **Why does it exist?**
+/// It exists so the built-in threshold checks and user care rules share one dedup and notification path.
+///
+/// **How should it be used?**
+/// Call with freshly evaluated alerts and the dedup window in hours. `delivery_for` decides per alert: a repeat of an open alert updates it in place, and only notifies again once the window has passed (or it escalated) and it isn't acknowledged or snoozed.
+/// Overdue care left alone past the owner's escalation period is raised a severity first (see `escalation_for`), and escalations also go to their escalation webhook.
+pub async fn store_and_notify(alerts: &[NewAlert], dedup_hours: u32) {
+    use crate::db::db;
+
+    let now = Utc::now();
+    let rules = if alerts.iter().any(|a| ESCALATING_TYPES.contains(&a.alert_type.as_str())) {
+        escalation_rules().await
+    } else {
+        Vec::new()
+    };
+    for alert in alerts {
+        let open: Vec<OpenAlert> = match db()
+            .query("SELECT * FROM alert WHERE owner = $owner AND alert_type = $atype AND resolved_at IS NONE")
             .bind(("owner", alert.owner.clone()))
             .bind(("atype", alert.alert_type.clone()))
-            .bind(("msg", alert.message.clone()))
             .await
         {
-            Ok(r) => r,
+            Ok(mut r) => {
+                let _ = r.take_errors();
+                let rows: Vec<OpenAlertRow> = r.take(0).unwrap_or_default();
+                rows.into_iter().map(|r| r.into_open_alert()).collect()
+            }
             Err(_) => continue,
         };
-        let _ = dup_check.take_errors();
 
-        #[derive(serde::Deserialize, SurrealValue)]
-        #[surreal(crate = "surrealdb::types")]
-        struct CountRow {
-            count: i64,
-        }
+        let rule = rules.iter().find(|r| r.owner == alert.owner);
+        let escalation = escalation_for(&open, alert, rule.and_then(|r| r.after_days), now);
+        let escalated = escalation.as_ref().is_some_and(|e| e.escalated);
+        let alert = &match escalation {
+            Some(e) => NewAlert { severity: e.severity.into(), ..alert.clone() },
+            None => alert.clone(),
+        };
 
-        let dup: Option<CountRow> = dup_check.take(0).unwrap_or(None);
-        if dup.map(|c| c.count > 0).unwrap_or(false) {
-            continue;
-        }
+        let notify = match delivery_for(&open, alert, now, dedup_hours) {
+            Delivery::Create => {
+                let _ = db()
+                    .query(
+                        "CREATE alert SET owner = $owner, orchid = $orchid, zone = $zone, alert_type = $atype, severity = $severity, message = $msg, last_notified_at = time::now()"
+                    )
+                    .bind(("owner", alert.owner.clone()))
+                    .bind(("orchid", alert.orchid.clone()))
+                    .bind(("zone", alert.zone.clone()))
+                    .bind(("atype", alert.alert_type.clone()))
+                    .bind(("severity", alert.severity.clone()))
+                    .bind(("msg", alert.message.clone()))
+                    .await;
+                true
+            }
+            Delivery::Renotify(id) => {
+                let _ = db()
+                    .query(
+                        "UPDATE $id SET severity = $severity, message = $msg, acknowledged_at = NONE, snoozed_until = NONE, last_notified_at = time::now(), \
+                         escalated_at = IF $escalated THEN time::now() ELSE escalated_at END"
+                    )
+                    .bind(("id", id))
+                    .bind(("severity", alert.severity.clone()))
+                    .bind(("msg", alert.message.clone()))
+                    .bind(("escalated", escalated))
+                    .await;
+                true
+            }
+            Delivery::Suppress(id) => {
+                // Keep the latest reading in the message without notifying
+                let _ = db()
+                    .query("UPDATE $id SET message = $msg")
+                    .bind(("id", id))
+                    .bind(("msg", alert.message.clone()))
+                    .await;
+                false
+            }
+        };
 
-        let _ = db()
-            .query(
-                "CREATE alert SET owner = $owner, orchid = $orchid, zone = $zone, alert_type = $atype, severity = $severity, message = $msg"
-            )
-            .bind(("owner", alert.owner.clone()))
-            .bind(("orchid", alert.orchid.clone()))
-            .bind(("zone", alert.zone.clone()))
-            .bind(("atype", alert.alert_type.clone()))
-            .bind(("severity", alert.severity.clone()))
-            .bind(("msg", alert.message.clone()))
-            .await;
-
-        // 5. For critical/warning alerts, send push notifications
-        if alert.severity == "critical" || alert.severity == "warning" {
-            let mut sub_resp = match db()
-                .query("SELECT owner, endpoint, p256dh, auth FROM push_subscription WHERE owner = $owner")
-                .bind(("owner", alert.owner.clone()))
-                .await
-            {
-                Ok(r) => r,
-                Err(_) => continue,
-            };
-            let _ = sub_resp.take_errors();
-            let subs: Vec<PushSubRow> = sub_resp.take(0).unwrap_or_default();
-
-            for sub in subs {
-                let push_sub = crate::push::PushSubscriptionRow {
-                    endpoint: sub.endpoint,
-                    p256dh: sub.p256dh,
-                    auth: sub.auth,
-                };
-                let title = match alert.severity.as_str() {
-                    "critical" => "Critical Alert",
-                    _ => "Warning",
-                };
-                if let Err(e) = crate::push::send_push(&push_sub, title, &alert.message).await {
-                    tracing::warn!("Push notification failed: {}", e);
+        // For critical/warning alerts, notify everyone in the household: on their devices and in their channel
+        if notify && (alert.severity == "critical" || alert.severity == "warning") {
+            let title = if escalated { format!("Escalated: {}", alert_title(alert)) } else { alert_title(alert).to_string() };
+            push_to_household(alert, &title).await;
+            post_to_webhook(alert, &title).await;
+            if let Some(url) = rule.filter(|_| escalated).and_then(|r| r.webhook_url.as_deref()) {
+                if let Err(e) = crate::push::deliver_webhook(&alert.owner, url, &title, &alert.message).await {
+                    tracing::warn!("Escalation webhook failed: {}", e);
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn reading(minutes: i64, temperature: f64, humidity: f64) -> ClimateReading {
+        ClimateReading {
+            id: format!("climate_reading:{}", minutes),
+            zone_id: "growing_zone:a".into(),
+            zone_name: "Cabinet".into(),
+            temperature,
+            humidity,
+            vpd: None,
+            dew_point: None,
+            precipitation: None,
+            uv_index: None,
+            solar_radiation: None,
+            wind_speed: None,
+            wind_gust: None,
+            lux: None,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
+            source: Some("sensorpush".into()),
+            recorded_at: Utc.with_ymd_and_hms(2026, 1, 10, 0, 0, 0).unwrap() + Duration::minutes(minutes),
+        }
+    }
+
+    #[test]
+    fn test_screen_drops_spikes_and_impossible_values() {
+        let readings = [
+            reading(0, 22.0, 60.0),
+            reading(10, 22.1, 61.0),
+            reading(20, 4.0, 95.0),   // fell onto a cold sill for one sample
+            reading(30, 22.2, 60.0),
+            reading(40, -40.0, 0.0),  // dying battery
+        ];
+        let screened = screen_readings(&readings);
+        assert_eq!(screened.kept.len(), 3);
+        assert!(screened.kept.iter().all(|r| r.temperature > 20.0));
+        assert!(screened.faults.iter().any(|f| matches!(f, SensorFault::Implausible { .. })));
+        assert_eq!(screened.faults.iter().filter(|f| matches!(f, SensorFault::Jump { .. })).count(), 1);
+    }
+
+    #[test]
+    fn test_screen_collapses_stuck_runs() {
+        let mut readings: Vec<_> = (0..=14).map(|i| reading(i * 30, 21.5, 55.0)).collect();
+        readings.push(reading(15 * 30, 21.9, 54.0));
+        let screened = screen_readings(&readings);
+        assert_eq!(screened.kept.len(), 2);
+        assert!(matches!(screened.faults.as_slice(), [SensorFault::Stuck { .. }]));
+
+        // A steady but short run is fine
+        let steady: Vec<_> = (0..4).map(|i| reading(i * 30, 21.5, 55.0)).collect();
+        let screened = screen_readings(&steady);
+        assert_eq!(screened.kept.len(), 4);
+        assert!(screened.faults.is_empty());
+    }
+
+    #[test]
+    fn test_rule_managed_metrics_skip_builtin_range_checks() {
+        let owner = surrealdb::types::RecordId::parse_simple("user:a").unwrap();
+        let zone = ZoneReading {
+            zone_name: "Cabinet".into(),
+            zone_id: surrealdb::types::RecordId::parse_simple("growing_zone:a").unwrap(),
+            temperature: 10.0,
+            humidity: 30.0,
+            dew_point: -6.0,
+            owner: Some(owner.clone()),
+            outdoor: false,
+        };
+        let mut orchid = OrchidRequirements {
+            id: surrealdb::types::RecordId::parse_simple("orchid:a").unwrap(),
+            owner,
+            name: "Sweetheart".into(),
+            placement: "Cabinet".into(),
+            water_frequency_days: 7,
+            last_watered_at: None,
+            water_deferred_until: None,
+            overdue_grace_days: 0,
+            temp_min: Some(18.0),
+            temp_max: None,
+            humidity_min: Some(50.0),
+            humidity_max: None,
+            rule_managed: Vec::new(),
+        };
+        let types = |alerts: Vec<NewAlert>| alerts.into_iter().map(|a| (a.alert_type, a.severity)).collect::<Vec<_>>();
+        assert_eq!(
+            types(check_alerts(std::slice::from_ref(&orchid), std::slice::from_ref(&zone))),
+            vec![("temperature_low".to_string(), "critical".to_string()), ("humidity_low".to_string(), "critical".to_string())],
+        );
+
+        orchid.rule_managed = vec![ClimateMetric::Temperature];
+        assert_eq!(types(check_alerts(&[orchid], &[zone])), vec![("humidity_low".to_string(), "critical".to_string())]);
+    }
+
+    fn rid(s: &str) -> surrealdb::types::RecordId {
+        surrealdb::types::RecordId::parse_simple(s).unwrap()
+    }
+
+    fn humidity_alert(severity: &str, message: &str) -> NewAlert {
+        NewAlert {
+            owner: rid("user:a"),
+            orchid: Some(rid("orchid:a")),
+            zone: Some(rid("growing_zone:a")),
+            alert_type: "humidity_low".into(),
+            severity: severity.into(),
+            message: message.into(),
+        }
+    }
+
+    fn open_alert(alert_type: &str, notified_hours_ago: i64, now: DateTime<Utc>) -> OpenAlert {
+        OpenAlert {
+            id: rid("alert:1"),
+            owner: rid("user:a"),
+            orchid: Some(rid("orchid:a")),
+            zone: Some(rid("growing_zone:a")),
+            alert_type: alert_type.into(),
+            severity: "warning".into(),
+            message: "Sweetheart: Humidity 42% is below minimum 50%".into(),
+            acknowledged: false,
+            snoozed_until: None,
+            last_notified_at: now - Duration::hours(notified_hours_ago),
+            created_at: now - Duration::hours(notified_hours_ago),
+            escalated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_delivery_dedups_by_condition_and_respects_ack_and_snooze() {
+        let now = Utc::now();
+        let repeat = humidity_alert("warning", "Sweetheart: Humidity 40% is below minimum 50%");
+        assert_eq!(delivery_for(&[], &repeat, now, 6), Delivery::Create);
+
+        // Same condition with a newer reading: quiet inside the window, re-sent after it
+        assert_eq!(delivery_for(&[open_alert("humidity_low", 1, now)], &repeat, now, 6), Delivery::Suppress(rid("alert:1")));
+        assert_eq!(delivery_for(&[open_alert("humidity_low", 7, now)], &repeat, now, 6), Delivery::Renotify(rid("alert:1")));
+
+        // Acknowledged stays quiet until it clears; snoozed until the snooze ends
+        let acked = OpenAlert { acknowledged: true, ..open_alert("humidity_low", 30, now) };
+        assert_eq!(delivery_for(&[acked], &repeat, now, 6), Delivery::Suppress(rid("alert:1")));
+        let snoozed = OpenAlert { snoozed_until: Some(now + Duration::hours(2)), ..open_alert("humidity_low", 30, now) };
+        assert_eq!(delivery_for(&[snoozed], &repeat, now, 6), Delivery::Suppress(rid("alert:1")));
+
+        // Escalation always notifies
+        let acked = OpenAlert { acknowledged: true, ..open_alert("humidity_low", 1, now) };
+        let critical = humidity_alert("critical", "Sweetheart: Humidity 30% is below minimum 50%");
+        assert_eq!(delivery_for(&[acked], &critical, now, 6), Delivery::Renotify(rid("alert:1")));
+    }
+
+    #[test]
+    fn test_delivery_for_other_types_matches_message_and_ack_closes() {
+        let now = Utc::now();
+        let mut fault = open_alert("sensor_health", 1, now);
+        fault.message = "Cabinet: sensor repeated the same reading for 6h".into();
+        let alert = NewAlert { alert_type: "sensor_health".into(), message: fault.message.clone(), ..humidity_alert("warning", "") };
+        assert_eq!(delivery_for(std::slice::from_ref(&fault), &alert, now, 24), Delivery::Suppress(rid("alert:1")));
+        let other = NewAlert { message: "Cabinet: temperature jumped 9C in 10 minutes".into(), ..alert };
+        assert_eq!(delivery_for(std::slice::from_ref(&fault), &other, now, 24), Delivery::Create);
+        fault.acknowledged = true;
+        let alert = NewAlert { message: fault.message.clone(), ..other };
+        assert_eq!(delivery_for(&[fault], &alert, now, 24), Delivery::Create);
+    }
+
+    #[test]
+    fn test_escalation_raises_ignored_overdue_care() {
+        let now = Utc::now();
+        let overdue = NewAlert { zone: None, alert_type: "watering_overdue".into(), ..humidity_alert("info", "Kingie: Watering overdue by 5 days") };
+        let open = |days: i64| OpenAlert { zone: None, severity: "info".into(), ..open_alert("watering_overdue", days * 24, now) };
+
+        // Not yet due, turned off, or not an escalating type: stored as raised
+        assert_eq!(escalation_for(&[open(2)], &overdue, Some(3), now), None);
+        assert_eq!(escalation_for(&[open(9)], &overdue, None, now), None);
+        assert_eq!(escalation_for(&[open_alert("humidity_low", 96, now)], &humidity_alert("warning", ""), Some(3), now), None);
+
+        // Three days open and ignored: one step up, which delivery_for re-sends
+        let step = escalation_for(&[open(3)], &overdue, Some(3), now).unwrap();
+        assert_eq!(step, SeverityOverride { severity: "warning", escalated: true });
+        let escalated = NewAlert { severity: step.severity.into(), ..overdue.clone() };
+        assert_eq!(delivery_for(&[open(3)], &escalated, now, 6), Delivery::Renotify(rid("alert:1")));
+
+        // An acknowledged one stays put; an escalated one keeps its severity until the next period is up
+        assert_eq!(escalation_for(&[OpenAlert { acknowledged: true, ..open(9) }], &overdue, Some(3), now), None);
+        let warned = OpenAlert { severity: "warning".into(), escalated_at: Some(now - Duration::days(1)), ..open(9) };
+        assert_eq!(escalation_for(std::slice::from_ref(&warned), &overdue, Some(3), now), Some(SeverityOverride { severity: "warning", escalated: false }));
+        let warned = OpenAlert { escalated_at: Some(now - Duration::days(4)), ..warned };
+        assert_eq!(escalation_for(&[warned], &overdue, Some(3), now), Some(SeverityOverride { severity: "critical", escalated: true }));
+    }
+
+    #[test]
+    fn test_cleared_alerts_only_for_checked_zones() {
+        let now = Utc::now();
+        let open = [open_alert("humidity_low", 1, now)];
+        // Still raised: stays open
+        let raised = [humidity_alert("warning", "")];
+        assert!(cleared_alerts(&open, &raised, &[rid("growing_zone:a")], &[]).is_empty());
+        // Not raised and the zone was checked: resolved
+        assert_eq!(cleared_alerts(&open, &[], &[rid("growing_zone:a")], &[]), vec![rid("alert:1")]);
+        // No fresh reading for the zone: a silent sensor isn't a recovery
+        assert!(cleared_alerts(&open, &[], &[], &[]).is_empty());
+
+        // Watering alerts resolve by plant; other types never auto-resolve
+        let watering = OpenAlert { zone: None, ..open_alert("watering_overdue", 1, now) };
+        assert_eq!(cleared_alerts(&[watering], &[], &[], &[rid("orchid:a")]), vec![rid("alert:1")]);
+        assert!(cleared_alerts(&[open_alert("care_rule", 1, now)], &[], &[rid("growing_zone:a")], &[]).is_empty());
+    }
+}
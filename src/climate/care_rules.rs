@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use chrono::{Datelike, Utc};
+use surrealdb::types::{RecordId, SurrealValue};
+use super::alerts::{store_and_notify, NewAlert};
+use crate::care_rules::{
+    condition_held, entered_phase, suggestion_applies, CareRule, RuleAction, RuleTrigger,
+    RULE_COOLDOWN_HOURS,
+};
+use crate::db::db;
+use crate::orchid::Hemisphere;
+use crate::server_fns::care_rules::ssr_types::CareRuleDbRow;
+
+/// Extra history fetched beyond a rule's duration so the run start before the window is visible.
+const LOOKBACK_MARGIN_MINUTES: u32 = 60;
+
+/// Phase rules fire once per plant per phase entry; the month-long dedup window enforces that.
+const PHASE_DEDUP_HOURS: u32 = 31 * 24;
+
+#[derive(serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct ZoneRow {
+    id: RecordId,
+    name: String,
+}
+
+#[derive(serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct PlantRow {
+    id: RecordId,
+    name: String,
+    placement: String,
+    #[surreal(default)]
+    humidity_min: Option<f64>,
+}
+
+/// Loads every enabled rule, grouped by the owner of the collection it belongs to.
+pub(super) async fn enabled_rules_by_owner() -> HashMap<String, (RecordId, Vec<CareRule>)> {
+    let rows: Vec<CareRuleDbRow> = match db()
+        .query("SELECT * FROM care_rule WHERE enabled = true")
+        .await
+    {
+        Ok(mut r) => {
+            let _ = r.take_errors();
+            r.take(0).unwrap_or_default()
+        }
+        Err(e) => {
+            tracing::warn!("Care rules: failed to query rules: {}", e);
+            return HashMap::new();
+        }
+    };
+
+    let mut by_owner: HashMap<String, (RecordId, Vec<CareRule>)> = HashMap::new();
+    for row in rows {
+        let owner = row.owner.clone();
+        if let Some(rule) = row.into_care_rule() {
+            by_owner.entry(format!("{:?}", owner))
+                .or_insert_with(|| (owner, Vec::new()))
+                .1.push(rule);
+        }
+    }
+    by_owner
+}
+
+/// Records that the given rules fired, starting their cooldown.
+async fn mark_fired(rule_ids: &[String]) {
+    for id in rule_ids {
+        let Ok(record) = RecordId::parse_simple(id) else { continue };
+        if let Err(e) = db()
+            .query("UPDATE $id SET last_fired_at = time::now()")
+            .bind(("id", record))
+            .await
+        {
+            tracing::warn!("Care rules: failed to record firing: {}", e);
+        }
+    }
+}
+
+/// Builds the alerts and suggestions a fired rule produces for one zone or plant.
+///
+/// `orchid` is set for plant-scoped rules, so their alerts are attached to the plant.
+fn fire_actions(
+    rule: &CareRule,
+    owner: &RecordId,
+    zone: Option<&RecordId>,
+    orchid: Option<&RecordId>,
+    headline: &str,
+    plants: &[&PlantRow],
+) -> Vec<NewAlert> {
+    let mut alerts = Vec::new();
+    for action in &rule.actions {
+        match action {
+            RuleAction::Alert { severity } => alerts.push(NewAlert {
+                owner: owner.clone(),
+                orchid: orchid.cloned(),
+                zone: zone.cloned(),
+                alert_type: "care_rule".into(),
+                severity: severity.clone(),
+                message: format!("{}: {}", rule.name, headline),
+            }),
+            RuleAction::SuggestCare { suggestion, min_plant_humidity } => {
+                for plant in plants.iter().filter(|p| suggestion_applies(*min_plant_humidity, p.humidity_min)) {
+                    alerts.push(NewAlert {
+                        owner: owner.clone(),
+                        orchid: Some(plant.id.clone()),
+                        zone: zone.cloned(),
+                        alert_type: "care_suggestion".into(),
+                        severity: "info".into(),
+                        message: format!("{}: {} ({})", plant.name, suggestion, rule.name),
+                    });
+                }
+            }
+            // Applied wherever fertilizer reminders are shown, not as a one-off event
+            RuleAction::PauseFertilizer => {}
+        }
+    }
+    alerts
+}
+
+/// **What is it?**
+/// A background task that evaluates every enabled climate-triggered care rule against recent zone readings.
+///
+/// **Why does it exist?**
+/// It exists so rules like "when zone temp > 32°C for 2h, alert me and suggest extra misting" fire as soon as new readings land.
+///
+/// **How should it be used?**
+/// Call from `poll_all_zones` after readings are stored. Each rule fires at most once per `RULE_COOLDOWN_HOURS`.
+pub async fn evaluate_climate_rules() {
+    use crate::server_fns::climate::ssr_types::ReadingDbRow;
+
+    let now = Utc::now();
+    let mut new_alerts = Vec::new();
+    let mut fired = Vec::new();
+
+    for (_, (owner, rules)) in enabled_rules_by_owner().await {
+        let rules: Vec<&CareRule> = rules.iter()
+            .filter(|r| matches!(r.trigger, RuleTrigger::Climate { .. }) && r.is_cooled_down(now))
+            .collect();
+        let Some(window) = rules.iter()
+            .filter_map(|r| match r.trigger {
+                RuleTrigger::Climate { for_minutes, .. } => Some(for_minutes),
+                _ => None,
+            })
+            .max()
+        else {
+            continue;
+        };
+
+        let mut resp = match db()
+            .query(
+                "SELECT id, name FROM growing_zone WHERE owner = $owner; \
+                 SELECT * FROM climate_reading \
+                    WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $owner) \
+                    AND recorded_at > time::now() - <duration> $window ORDER BY recorded_at ASC; \
+                 SELECT id, name, placement, humidity_min FROM orchid WHERE owner = $owner AND archived_at = NONE;"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("window", format!("{}m", window + LOOKBACK_MARGIN_MINUTES)))
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!("Care rules: failed to query zone data: {}", e);
+                continue;
+            }
+        };
+        let _ = resp.take_errors();
+        let zones: Vec<ZoneRow> = resp.take(0).unwrap_or_default();
+        let readings: Vec<ReadingDbRow> = resp.take(1).unwrap_or_default();
+        let plants: Vec<PlantRow> = resp.take(2).unwrap_or_default();
+
+        let mut readings_by_zone: HashMap<String, Vec<crate::orchid::ClimateReading>> = HashMap::new();
+        for r in readings {
+            let reading = r.into_climate_reading();
+            readings_by_zone.entry(reading.zone_id.clone()).or_default().push(reading);
+        }
+
+        for rule in rules {
+            let RuleTrigger::Climate { ref zone_id, ref orchid_id, metric, comparison, threshold, for_minutes } = rule.trigger else {
+                continue;
+            };
+            // A plant-scoped rule watches whichever zone the plant is placed in now
+            let plant = match orchid_id {
+                Some(id) => match plants.iter().find(|p| crate::server_fns::auth::record_id_to_string(&p.id) == *id) {
+                    Some(p) => Some(p),
+                    None => continue,
+                },
+                None => None,
+            };
+            let mut rule_fired = false;
+            for zone in &zones {
+                let zone_key = crate::server_fns::auth::record_id_to_string(&zone.id);
+                if zone_id.as_ref().is_some_and(|z| *z != zone_key) || plant.is_some_and(|p| p.placement != zone.name) {
+                    continue;
+                }
+                let Some(zone_readings) = readings_by_zone.get(&zone_key) else { continue };
+                let Some(value) = condition_held(zone_readings, metric, comparison, threshold, for_minutes, now) else {
+                    continue;
+                };
+
+                let headline = format!(
+                    "{} {} is {:.1}{} ({})",
+                    zone.name, metric.label().to_lowercase(), value, metric.unit(),
+                    rule.describe_trigger(|id| Some(if id == zone_key { zone.name.clone() } else { plant.map(|p| p.name.clone()).unwrap_or_default() })),
+                );
+                let affected: Vec<&PlantRow> = match plant {
+                    Some(p) => vec![p],
+                    None => plants.iter().filter(|p| p.placement == zone.name).collect(),
+                };
+                new_alerts.extend(fire_actions(rule, &owner, Some(&zone.id), plant.map(|p| &p.id), &headline, &affected));
+                rule_fired = true;
+            }
+            if rule_fired {
+                fired.push(rule.id.clone());
+            }
+        }
+    }
+
+    if fired.is_empty() {
+        return;
+    }
+
+    tracing::info!("Care rules: {} climate rules fired, {} alerts", fired.len(), new_alerts.len());
+    store_and_notify(&new_alerts, RULE_COOLDOWN_HOURS as u32).await;
+    mark_fired(&fired).await;
+}
+
+/// **What is it?**
+/// A daily task that evaluates seasonal-phase care rules, firing them for plants that have just entered the rule's phase.
+///
+/// **Why does it exist?**
+/// It exists so rules like "when a plant enters Rest, remind me to cut back watering" fire at the start of each phase rather than every day of it.
+///
+/// **How should it be used?**
+/// Run from the daily seasonal job. `PauseFertilizer` actions need no scheduling; they are applied wherever fertilizer reminders are shown.
+pub async fn evaluate_phase_rules() {
+    use crate::server_fns::orchids::ssr_types::OrchidDbRow;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        hemisphere: String,
+    }
+
+    let month = Utc::now().month();
+    let mut new_alerts = Vec::new();
+    let mut fired = Vec::new();
+
+    for (_, (owner, rules)) in enabled_rules_by_owner().await {
+        // Rules that only pause fertilizer have nothing to fire
+        let rules: Vec<&CareRule> = rules.iter()
+            .filter(|r| matches!(r.trigger, RuleTrigger::PhaseEntered { .. }))
+            .filter(|r| r.actions.iter().any(|a| *a != RuleAction::PauseFertilizer))
+            .collect();
+        if rules.is_empty() {
+            continue;
+        }
+
+        let mut resp = match db()
+            .query(
+                "SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE AND (rest_start_month IS NOT NULL OR bloom_start_month IS NOT NULL); \
+                 SELECT hemisphere FROM user_preference WHERE owner = $owner LIMIT 1;"
+            )
+            .bind(("owner", owner.clone()))
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!("Care rules: failed to query seasonal plants: {}", e);
+                continue;
+            }
+        };
+        let _ = resp.take_errors();
+        let rows: Vec<OrchidDbRow> = resp.take(0).unwrap_or_default();
+        let pref: Option<PrefRow> = resp.take(1).unwrap_or(None);
+        let hemisphere = Hemisphere::from_code(&pref.map(|p| p.hemisphere).unwrap_or_default());
+
+        let orchids: Vec<crate::orchid::Orchid> = rows.into_iter().map(|r| r.into_orchid()).collect();
+
+        for rule in rules {
+            let RuleTrigger::PhaseEntered { ref phase } = rule.trigger else { continue };
+            let mut rule_fired = false;
+            for orchid in orchids.iter().filter(|o| entered_phase(o, phase, &hemisphere, month)) {
+                let Ok(id) = RecordId::parse_simple(&orchid.id) else { continue };
+                let plant = PlantRow {
+                    id,
+                    name: orchid.name.clone(),
+                    placement: orchid.placement.clone(),
+                    humidity_min: orchid.humidity_min,
+                };
+                let headline = format!("{} entered its {} phase", orchid.name, phase);
+                new_alerts.extend(fire_actions(rule, &owner, None, None, &headline, &[&plant]));
+                rule_fired = true;
+            }
+            if rule_fired {
+                fired.push(rule.id.clone());
+            }
+        }
+    }
+
+    if fired.is_empty() {
+        return;
+    }
+
+    tracing::info!("Care rules: {} phase rules fired, {} alerts", fired.len(), new_alerts.len());
+    store_and_notify(&new_alerts, PHASE_DEDUP_HOURS).await;
+    mark_fired(&fired).await;
+}
@@ -0,0 +1,199 @@
+use std::collections::{BTreeMap, HashSet};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::db::db;
+use crate::light::lux_to_ppfd;
+
+/// How many past days are (re)checked on each run, so a missed run is caught up.
+const LOOKBACK_DAYS: i64 = 7;
+
+/// Gaps between samples longer than this are not integrated; the sensor was offline.
+const MAX_SAMPLE_GAP_SECS: i64 = 2 * 60 * 60;
+
+/// A day needs at least this many hours of light samples to get a DLI.
+const MIN_COVERAGE_HOURS: f64 = 12.0;
+
+/// A day's integrated light and how much of the day the samples covered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DliEstimate {
+    /// Integrated light, in mol/m²/day.
+    pub dli: f64,
+    /// Hours between the samples that were integrated.
+    pub covered_hours: f64,
+}
+
+/// **What is it?**
+/// A pure function that integrates timestamped PPFD samples (µmol/m²/s) into a daily light integral.
+///
+/// **Why does it exist?**
+/// It exists because readings arrive every few minutes to every half hour, so the day's light has to be summed between samples.
+///
+/// **How should it be used?**
+/// Pass one day's samples in any order. Uses the trapezoid rule and skips gaps over two hours; returns `None` with fewer than two samples.
+pub fn integrate_dli(samples: &[(DateTime<Utc>, f64)]) -> Option<DliEstimate> {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by_key(|(at, _)| *at);
+
+    let mut micromoles = 0.0;
+    let mut covered_secs = 0;
+    for pair in sorted.windows(2) {
+        let ((t0, p0), (t1, p1)) = (pair[0], pair[1]);
+        let secs = (t1 - t0).num_seconds();
+        if secs <= 0 || secs > MAX_SAMPLE_GAP_SECS {
+            continue;
+        }
+        micromoles += (p0.max(0.0) + p1.max(0.0)) / 2.0 * secs as f64;
+        covered_secs += secs;
+    }
+
+    (sorted.len() >= 2).then_some(DliEstimate {
+        dli: micromoles / 1_000_000.0,
+        covered_hours: covered_secs as f64 / 3600.0,
+    })
+}
+
+#[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct LightSampleRow {
+    zone: surrealdb::types::RecordId,
+    recorded_at: DateTime<Utc>,
+    #[surreal(default)]
+    ppfd: Option<f64>,
+    #[surreal(default)]
+    lux: Option<f64>,
+}
+
+#[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct RecordedDayRow {
+    zone: surrealdb::types::RecordId,
+    date: String,
+}
+
+/// **What is it?**
+/// A background job that turns the past week's light readings into one stored DLI per zone per UTC day.
+///
+/// **Why does it exist?**
+/// It exists so the dashboard can show daily light integrals without re-reading every sample, and so DLI history survives raw reading compaction.
+///
+/// **How should it be used?**
+/// Run it daily, before `rollups::compact_climate_readings`. Days already stored or with under 12 hours of samples are skipped. Measured PPFD is used when a day has it; otherwise lux is converted and the day is marked estimated.
+pub async fn record_daily_dli() {
+    let today = Utc::now().date_naive();
+    let start = today - Duration::days(LOOKBACK_DAYS);
+    let start_at = start.and_hms_opt(0, 0, 0).map(|d| d.and_utc());
+    let end_at = today.and_hms_opt(0, 0, 0).map(|d| d.and_utc());
+    let (Some(start_at), Some(end_at)) = (start_at, end_at) else {
+        return;
+    };
+
+    let response = db()
+        .query(
+            "SELECT zone, recorded_at, ppfd, lux FROM climate_reading \
+             WHERE recorded_at >= $start AND recorded_at < $end AND (ppfd != NONE OR lux != NONE) \
+             ORDER BY recorded_at ASC; \
+             SELECT zone, date FROM zone_dli WHERE date >= $start_date;"
+        )
+        .bind(("start", start_at))
+        .bind(("end", end_at))
+        .bind(("start_date", start.to_string()))
+        .await;
+
+    let mut response = match response {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("DLI: light reading query failed: {}", e);
+            return;
+        }
+    };
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        tracing::warn!("DLI: light reading query errors: {:?}", errors);
+        return;
+    }
+    let samples: Vec<LightSampleRow> = response.take(0).unwrap_or_default();
+    let recorded: Vec<RecordedDayRow> = response.take(1).unwrap_or_default();
+    let recorded: HashSet<(String, String)> = recorded.into_iter()
+        .map(|r| (crate::server_fns::auth::record_id_to_string(&r.zone), r.date))
+        .collect();
+
+    // Group by zone and day, keeping PPFD and lux samples apart
+    type DaySamples = (Vec<(DateTime<Utc>, f64)>, Vec<(DateTime<Utc>, f64)>);
+    let mut by_day: BTreeMap<(String, NaiveDate), (surrealdb::types::RecordId, DaySamples)> = BTreeMap::new();
+    for row in samples {
+        let key = (crate::server_fns::auth::record_id_to_string(&row.zone), row.recorded_at.date_naive());
+        let (_, (ppfd, lux)) = by_day.entry(key).or_insert_with(|| (row.zone.clone(), Default::default()));
+        if let Some(p) = row.ppfd {
+            ppfd.push((row.recorded_at, p));
+        } else if let Some(l) = row.lux {
+            lux.push((row.recorded_at, lux_to_ppfd(l)));
+        }
+    }
+
+    let mut stored = 0;
+    for ((zone_key, date), (zone, (ppfd, lux))) in by_day {
+        if recorded.contains(&(zone_key, date.to_string())) {
+            continue;
+        }
+        let (estimate, estimated) = match integrate_dli(&ppfd) {
+            Some(e) if e.covered_hours >= MIN_COVERAGE_HOURS => (e, false),
+            _ => match integrate_dli(&lux) {
+                Some(e) if e.covered_hours >= MIN_COVERAGE_HOURS => (e, true),
+                _ => continue,
+            },
+        };
+
+        let result = db()
+            .query(
+                "CREATE zone_dli SET zone = $zone, date = $date, dli = $dli, \
+                 estimated = $estimated, covered_hours = $covered"
+            )
+            .bind(("zone", zone))
+            .bind(("date", date.to_string()))
+            .bind(("dli", estimate.dli))
+            .bind(("estimated", estimated))
+            .bind(("covered", estimate.covered_hours))
+            .await;
+        match result {
+            Ok(mut resp) => {
+                let errors = resp.take_errors();
+                if errors.is_empty() {
+                    stored += 1;
+                } else {
+                    tracing::warn!("DLI: failed to store {}: {:?}", date, errors);
+                }
+            }
+            Err(e) => tracing::warn!("DLI: failed to store {}: {}", date, e),
+        }
+    }
+
+    tracing::info!("DLI: stored {} zone-days", stored);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 6, 1, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_integrate_constant_light() {
+        // 200 µmol/m²/s for 12 hours, sampled every 30 minutes, out of order
+        let mut samples: Vec<_> = (0..=24).map(|i| (at(6, 0) + Duration::minutes(30 * i), 200.0)).collect();
+        samples.reverse();
+        let estimate = integrate_dli(&samples).unwrap();
+        assert!((estimate.dli - 8.64).abs() < 1e-9);
+        assert!((estimate.covered_hours - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_integrate_skips_long_gaps() {
+        let samples = [(at(6, 0), 100.0), (at(6, 30), 100.0), (at(12, 0), 100.0), (at(12, 30), 100.0)];
+        let estimate = integrate_dli(&samples).unwrap();
+        assert!((estimate.covered_hours - 1.0).abs() < 1e-9);
+        assert!((estimate.dli - 0.36).abs() < 1e-9);
+        assert_eq!(integrate_dli(&[(at(6, 0), 100.0)]), None);
+    }
+}
@@ -2,6 +2,9 @@ use crate::db::db;
 use surrealdb::types::SurrealValue;
 use super::open_meteo;
 
+/// Most readings written in one transaction.
+const WRITE_BATCH_SIZE: usize = 50;
+
 /// **What is it?**
 /// A background orchestration task that queries Open-Meteo for the current weather at all unique native coordinates of the user's orchids.
 ///
@@ -20,7 +23,7 @@ pub async fn poll_habitat_weather() {
             "SELECT math::round(native_latitude * 100) / 100 AS lat, \
                     math::round(native_longitude * 100) / 100 AS lon \
              FROM orchid \
-             WHERE native_latitude IS NOT NULL AND native_longitude IS NOT NULL \
+             WHERE native_latitude IS NOT NULL AND native_longitude IS NOT NULL AND archived_at = NONE \
              GROUP BY lat, lon"
         )
         .await
@@ -53,46 +56,49 @@ pub async fn poll_habitat_weather() {
 
     tracing::info!("Habitat poll: fetching weather for {} coordinate pairs", coords.len());
 
-    // 2. Fetch weather for each unique coordinate pair
-    for coord in &coords {
-        match open_meteo::fetch_habitat_weather(&client, coord.lat, coord.lon).await {
-            Ok(reading) => {
-                if let Err(e) = db
-                    .query(
-                        "CREATE habitat_weather SET \
-                         latitude = $lat, longitude = $lon, \
-                         temperature = $temp, humidity = $humidity, \
-                         precipitation = $precip, recorded_at = time::now()"
-                    )
-                    .bind(("lat", coord.lat))
-                    .bind(("lon", coord.lon))
-                    .bind(("temp", reading.temperature_c))
-                    .bind(("humidity", reading.humidity_pct))
-                    .bind(("precip", reading.precipitation_mm))
-                    .await
-                {
+    // 2. Fetch weather for each unique coordinate pair, a few at a time
+    let tasks = coords.into_iter().map(|coord| {
+        let client = client.clone();
+        async move {
+            let result = open_meteo::fetch_habitat_weather(&client, coord.lat, coord.lon).await;
+            // Brief delay before releasing the slot to be respectful
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            match result {
+                Ok(reading) => Some((coord, reading)),
+                Err(e) => {
                     tracing::warn!(
-                        "Habitat poll: failed to store reading for ({}, {}): {}",
+                        "Habitat poll: failed to fetch weather for ({}, {}): {}",
                         coord.lat, coord.lon, e
                     );
-                } else {
-                    tracing::info!(
-                        "Habitat poll: stored reading for ({}, {}): {:.1}C, {:.0}%, {:.1}mm",
-                        coord.lat, coord.lon,
-                        reading.temperature_c, reading.humidity_pct, reading.precipitation_mm
-                    );
+                    None
                 }
             }
-            Err(e) => {
-                tracing::warn!(
-                    "Habitat poll: failed to fetch weather for ({}, {}): {}",
-                    coord.lat, coord.lon, e
-                );
+        }
+    });
+    let readings: Vec<_> = super::join_bounded(tasks).await.into_iter().flatten().collect();
+
+    // Store all readings in batched transactions
+    for batch in readings.chunks(WRITE_BATCH_SIZE) {
+        let mut query = db.query(batch_insert_sql(batch.len()));
+        for (i, (coord, reading)) in batch.iter().enumerate() {
+            query = query
+                .bind((format!("lat_{i}"), coord.lat))
+                .bind((format!("lon_{i}"), coord.lon))
+                .bind((format!("temp_{i}"), reading.temperature_c))
+                .bind((format!("humidity_{i}"), reading.humidity_pct))
+                .bind((format!("precip_{i}"), reading.precipitation_mm));
+        }
+        match query.await {
+            Ok(mut response) => {
+                let errors = response.take_errors();
+                if errors.is_empty() {
+                    tracing::info!("Habitat poll: stored {} readings", batch.len());
+                } else {
+                    tracing::warn!("Habitat poll: failed to store {} readings: {:?}", batch.len(), errors);
+                }
             }
+            Err(e) => tracing::warn!("Habitat poll: failed to store {} readings: {}", batch.len(), e),
         }
-
-        // Brief delay between API calls to be respectful
-        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
     }
 
     // 3. Run compaction
@@ -224,6 +230,21 @@ pub async fn compact_habitat_data() {
     }
 }
 
+/// Builds one transaction creating `count` habitat readings, bound as `$lat_0`, `$lon_0`, ... `$precip_{count-1}`.
+fn batch_insert_sql(count: usize) -> String {
+    let mut sql = String::from("BEGIN TRANSACTION; ");
+    for i in 0..count {
+        sql.push_str(&format!(
+            "CREATE habitat_weather SET \
+             latitude = $lat_{i}, longitude = $lon_{i}, \
+             temperature = $temp_{i}, humidity = $humidity_{i}, \
+             precipitation = $precip_{i}, recorded_at = time::now(); "
+        ));
+    }
+    sql.push_str("COMMIT TRANSACTION;");
+    sql
+}
+
 #[derive(serde::Deserialize, SurrealValue)]
 #[surreal(crate = "surrealdb::types")]
 struct CoordRow {
@@ -25,6 +25,34 @@ pub mod tempest;
 /// **How should it be used?**
 /// Call these functions to parse device states or poll the AC Infinity API for indoor climate data.
 pub mod ac_infinity;
+
+/// **What is it?**
+/// A module for receiving climate readings from an MQTT broker.
+///
+/// **Why does it exist?**
+/// It exists so DIY sensors (ESPHome, Tasmota, Zigbee2MQTT) that publish JSON to a broker can feed zones without a vendor cloud.
+///
+/// **How should it be used?**
+/// Spawn `run_mqtt_ingestion` once at startup; it subscribes to the topics of every `mqtt` hardware device and stores readings for linked zones.
+pub mod mqtt;
+/// **What is it?**
+/// A module for parsing the JSON bodies DIY sensors send.
+///
+/// **Why does it exist?**
+/// It exists so MQTT messages and HTTP ingest requests accept the same payload shapes.
+///
+/// **How should it be used?**
+/// Call `parse_sensor_payload` with the raw message or request body.
+pub mod payload;
+/// **What is it?**
+/// A module for SensorPush wireless sensor integration via the SensorPush cloud API.
+///
+/// **Why does it exist?**
+/// It exists because SensorPush units are among the most common sensors in orchidariums and grow cabinets, and their readings only leave the unit through the vendor's gateway and cloud.
+///
+/// **How should it be used?**
+/// Call `fetch_sensorpush_sensors` to discover sensors when setting up a device; the poller calls `fetch_sensorpush_readings` once per device per cycle.
+pub mod sensorpush;
 /// **What is it?**
 /// A module containing periodic climate polling tasks.
 ///
@@ -53,6 +81,33 @@ pub mod open_meteo;
 /// Run these tasks in the background to periodically update the environmental conditions of outdoor orchid zones.
 pub mod habitat_poller;
 /// **What is it?**
+/// A module that rolls old climate readings up into hourly and daily summaries.
+///
+/// **Why does it exist?**
+/// It exists to keep the raw readings table small enough for fast chart queries, while keeping each zone's long-term min/avg/max history.
+///
+/// **How should it be used?**
+/// Call `compact_climate_readings` once a day from a background task; retention windows come from the server config.
+pub mod rollups;
+/// **What is it?**
+/// A module that integrates light readings into a daily light integral (DLI) per zone.
+///
+/// **Why does it exist?**
+/// It exists because DLI is the standard way growers reason about light, and a single peak reading says little about the whole day.
+///
+/// **How should it be used?**
+/// Run `record_daily_dli` from the daily background task, before readings are compacted.
+pub mod dli;
+/// **What is it?**
+/// A module that encodes a user's climate readings and rollups as Parquet files, one set per zone per year.
+///
+/// **Why does it exist?**
+/// It exists for growers who analyze years of telemetry in pandas, Polars or DuckDB, where JSON exports are slow and untyped.
+///
+/// **How should it be used?**
+/// Call `export_climate_parquet` with the owner's record; the settings download and the `export-climate` CLI command both use it.
+pub mod parquet_export;
+/// **What is it?**
 /// A module for climate alerts checking and management.
 ///
 /// **Why does it exist?**
@@ -70,6 +125,24 @@ pub mod alerts;
 /// **How should it be used?**
 /// Run these checks periodically using forecast data to alert users days in advance of significant seasonal shifts.
 pub mod seasonal_alerts;
+/// **What is it?**
+/// A module for the weekly climate report card push job.
+///
+/// **Why does it exist?**
+/// It exists to summarize how well each zone met its goal ranges over the past week and deliver that to opted-in users.
+///
+/// **How should it be used?**
+/// Spawn `send_weekly_reports` periodically from the server's background tasks.
+pub mod weekly_reports;
+/// **What is it?**
+/// A module that evaluates user-defined care automation rules in the background.
+///
+/// **Why does it exist?**
+/// It exists to run climate-triggered rules after each poll and seasonal-phase rules once a day, turning their actions into alerts and care suggestions.
+///
+/// **How should it be used?**
+/// `poll_all_zones` calls `evaluate_climate_rules`; the daily seasonal task calls `evaluate_phase_rules`.
+pub mod care_rules;
 
 /// **What is it?**
 /// A structure representing a raw climate reading from any data source, before storage.
@@ -79,6 +152,7 @@ pub mod seasonal_alerts;
 ///
 /// **How should it be used?**
 /// Instantiate this struct when parsing incoming data, then pass it to database functions to persist the reading.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct RawReading {
     /// Temperature in Celsius.
     pub temperature_c: f64,
@@ -88,6 +162,46 @@ pub struct RawReading {
     pub vpd_kpa: Option<f64>,
     /// Precipitation in millimeters.
     pub precipitation_mm: Option<f64>,
+    /// UV index (outdoor stations only).
+    pub uv_index: Option<f64>,
+    /// Solar radiation in W/m² (outdoor stations only).
+    pub solar_radiation_wm2: Option<f64>,
+    /// Average wind speed in m/s (outdoor stations only).
+    pub wind_avg_ms: Option<f64>,
+    /// Wind gust speed in m/s (outdoor stations only).
+    pub wind_gust_ms: Option<f64>,
+    /// Illuminance in lux (light sensors, or a Tempest's brightness).
+    pub lux: Option<f64>,
+    /// Photosynthetic photon flux density in µmol/m²/s (quantum/PAR sensors).
+    pub ppfd: Option<f64>,
+    /// CO2 concentration in ppm (CO2 sensors, e.g. AC Infinity CO2 kits).
+    pub co2_ppm: Option<f64>,
+    /// Substrate moisture percentage (capacitive soil/substrate probes).
+    pub substrate_moisture_pct: Option<f64>,
+}
+
+impl RawReading {
+    /// Whether any of the outdoor-only extended metrics (UV, solar, wind) are present.
+    pub fn has_outdoor_metrics(&self) -> bool {
+        self.uv_index.is_some()
+            || self.solar_radiation_wm2.is_some()
+            || self.wind_avg_ms.is_some()
+            || self.wind_gust_ms.is_some()
+    }
+
+    /// Drop the outdoor-only extended metrics, keeping the core climate values.
+    /// Light goes too: a reading with outdoor metrics comes from a weather station, whose light sensor sees open sky.
+    pub fn without_outdoor_metrics(&self) -> RawReading {
+        RawReading {
+            uv_index: None,
+            solar_radiation_wm2: None,
+            wind_avg_ms: None,
+            wind_gust_ms: None,
+            lux: None,
+            ppfd: None,
+            ..self.clone()
+        }
+    }
 }
 
 /// **What is it?**
@@ -103,3 +217,66 @@ pub fn calculate_vpd(temp_c: f64, humidity_pct: f64) -> f64 {
     let actual_pressure = saturation_pressure * (humidity_pct / 100.0);
     saturation_pressure - actual_pressure
 }
+
+/// **What is it?**
+/// A function that calculates the dew point from temperature and humidity.
+///
+/// **Why does it exist?**
+/// It exists because water condenses on leaves, crowns and glass once the air cools to its dew point, which is how rot starts in closed cabinets overnight.
+///
+/// **How should it be used?**
+/// Call this function with a temperature in Celsius and a relative humidity percentage to get the dew point in Celsius, using the same Magnus constants as `calculate_vpd`. Humidity is clamped to at least 1% so dry air doesn't produce a logarithm of zero.
+pub fn calculate_dew_point(temp_c: f64, humidity_pct: f64) -> f64 {
+    let gamma = (humidity_pct.clamp(1.0, 100.0) / 100.0).ln() + (17.27 * temp_c) / (temp_c + 237.3);
+    237.3 * gamma / (17.27 - gamma)
+}
+
+/// How many device or API fetches a poller runs at once.
+const POLL_CONCURRENCY: usize = 4;
+
+/// **What is it?**
+/// A helper that runs independent fetch futures concurrently, at most `POLL_CONCURRENCY` at a time.
+///
+/// **Why does it exist?**
+/// It exists because polling devices one after another makes each cycle as slow as the sum of every vendor API's latency, while unbounded fan-out would hammer those APIs on instances with many zones.
+///
+/// **How should it be used?**
+/// Pass one future per device, zone, or coordinate from a poller. Results come back in completion order; tasks that panic are logged and dropped.
+pub(crate) async fn join_bounded<T, F>(tasks: impl IntoIterator<Item = F>) -> Vec<T>
+where
+    F: std::future::Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+{
+    let limit = std::sync::Arc::new(tokio::sync::Semaphore::new(POLL_CONCURRENCY));
+    let mut set = tokio::task::JoinSet::new();
+    for task in tasks {
+        let limit = limit.clone();
+        set.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            task.await
+        });
+    }
+
+    let mut results = Vec::with_capacity(set.len());
+    while let Some(joined) = set.join_next().await {
+        match joined {
+            Ok(value) => results.push(value),
+            Err(e) => tracing::warn!("Poll task failed: {}", e),
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dew_point() {
+        // Saturated air is at its dew point
+        assert!((calculate_dew_point(18.0, 100.0) - 18.0).abs() < 1e-9);
+        // 25C at 60% RH condenses around 16.7C
+        assert!((calculate_dew_point(25.0, 60.0) - 16.7).abs() < 0.1);
+        assert!(calculate_dew_point(20.0, 0.0).is_finite());
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS, SubscribeFilter};
+use surrealdb::types::{RecordId, SurrealValue};
+use crate::db::db;
+use crate::error::AppError;
+use super::payload::parse_sensor_payload;
+use super::RawReading;
+
+/// Readings from one topic are stored at most this often; DIY sensors often publish every few seconds.
+const MIN_STORE_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// How often the supervisor re-reads MQTT devices to pick up added, edited, or deleted brokers.
+const DEVICE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Pause before polling the event loop again after a connection error (the next poll reconnects).
+const RECONNECT_DELAY: Duration = Duration::from_secs(15);
+
+/// **What is it?**
+/// A struct representing the deserialized configuration for an MQTT broker device.
+///
+/// **Why does it exist?**
+/// It exists to unpack the broker address, credentials, and subscribed topics stored (encrypted) on a `hardware_device` of type `mqtt`.
+///
+/// **How should it be used?**
+/// Deserialize the decrypted device config into this struct. Zones linked to the device pick a topic by 1-based `hardware_port`, like Tempest stations.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct MqttConfig {
+    /// Broker hostname or IP address.
+    pub host: String,
+    /// Broker port (plain TCP).
+    #[serde(default = "default_mqtt_port")]
+    pub port: u16,
+    /// Optional broker username.
+    #[serde(default)]
+    pub username: String,
+    /// Optional broker password.
+    #[serde(default)]
+    pub password: String,
+    /// Topics (or filters with `+`/`#` wildcards), one per sensor.
+    #[serde(default)]
+    pub topics: Vec<String>,
+}
+
+fn default_mqtt_port() -> u16 {
+    1883
+}
+
+impl MqttConfig {
+    /// The 1-based port of the first configured topic filter that matches `topic`.
+    pub fn port_for_topic(&self, topic: &str) -> Option<i32> {
+        self.topics.iter()
+            .position(|filter| rumqttc::matches(topic, filter))
+            .map(|i| i as i32 + 1)
+    }
+
+    /// Connection options for this broker, identified as the given device.
+    fn options(&self, client_id: &str) -> MqttOptions {
+        let mut options = MqttOptions::new(client_id, self.host.clone(), self.port);
+        options.set_keep_alive(Duration::from_secs(30));
+        if !self.username.is_empty() {
+            options.set_credentials(self.username.clone(), self.password.clone());
+        }
+        options
+    }
+
+    fn subscriptions(&self) -> Vec<SubscribeFilter> {
+        self.topics.iter()
+            .map(|t| SubscribeFilter::new(t.clone(), QoS::AtMostOnce))
+            .collect()
+    }
+}
+
+/// **What is it?**
+/// A function that connects to a broker, subscribes to the configured topics, and waits for the first reading.
+///
+/// **Why does it exist?**
+/// It exists to back the "Test" button in device management, so growers find typos in hosts, credentials, or topics before linking zones.
+///
+/// **How should it be used?**
+/// Call from `test_device` with a parsed `MqttConfig`. Returns the topic and reading of the first parseable message, or an error after `timeout`.
+pub async fn wait_for_reading(config: &MqttConfig, timeout: Duration) -> Result<(String, RawReading), AppError> {
+    if config.topics.is_empty() {
+        return Err(AppError::Network("Add at least one topic".into()));
+    }
+    let client_id = format!("orchid-tracker-test-{}", uuid::Uuid::new_v4().simple());
+    let (client, mut eventloop) = AsyncClient::new(config.options(&client_id), 10);
+
+    let result = tokio::time::timeout(timeout, async {
+        loop {
+            match eventloop.poll().await {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    client.try_subscribe_many(config.subscriptions())
+                        .map_err(|e| AppError::Network(format!("MQTT subscribe failed: {}", e)))?;
+                }
+                Ok(Event::Incoming(Packet::Publish(p))) => {
+                    if let Ok(raw) = parse_sensor_payload(&p.payload) {
+                        return Ok((p.topic, raw));
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => return Err(AppError::Network(format!("MQTT connection failed: {}", e))),
+            }
+        }
+    })
+    .await
+    .unwrap_or_else(|_| Err(AppError::Network(format!(
+        "Connected, but no readable message arrived within {}s",
+        timeout.as_secs()
+    ))));
+
+    let _ = client.try_disconnect();
+    result
+}
+
+#[derive(serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct MqttDeviceRow {
+    id: RecordId,
+    config: String,
+}
+
+#[derive(serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct TopicZoneRow {
+    id: RecordId,
+    name: String,
+    #[surreal(default)]
+    location_type: Option<String>,
+}
+
+/// **What is it?**
+/// A long-running supervisor that keeps one MQTT subscription running per `mqtt` hardware device.
+///
+/// **Why does it exist?**
+/// It exists because most DIY greenhouse sensors push readings to a broker instead of a vendor cloud the poller could query; readings need to be received as they are published.
+///
+/// **How should it be used?**
+/// Spawn once at server startup. Every minute it re-reads MQTT devices, starting subscribers for new ones and restarting or stopping them when a device is edited or deleted.
+pub async fn run_mqtt_ingestion() {
+    let mut running: HashMap<String, (String, tokio::task::JoinHandle<()>)> = HashMap::new();
+
+    loop {
+        match load_mqtt_devices().await {
+            Ok(devices) => {
+                let mut seen = Vec::with_capacity(devices.len());
+                for device in devices {
+                    let key = crate::server_fns::auth::record_id_to_string(&device.id);
+                    let config_str = crate::crypto::decrypt_or_raw(&device.config);
+                    seen.push(key.clone());
+
+                    if running.get(&key).is_some_and(|(cfg, task)| *cfg == config_str && !task.is_finished()) {
+                        continue;
+                    }
+                    if let Some((_, task)) = running.remove(&key) {
+                        task.abort();
+                    }
+                    let config: MqttConfig = match serde_json::from_str(&config_str) {
+                        Ok(c) => c,
+                        Err(e) => {
+                            tracing::warn!("MQTT: bad config for device {}: {}", key, e);
+                            continue;
+                        }
+                    };
+                    if config.topics.is_empty() {
+                        continue;
+                    }
+                    tracing::info!("MQTT: subscribing to {} topics on {}:{} for device {}", config.topics.len(), config.host, config.port, key);
+                    let task = tokio::spawn(subscribe_device(device.id, config));
+                    running.insert(key, (config_str, task));
+                }
+
+                running.retain(|key, (_, task)| {
+                    let keep = seen.contains(key);
+                    if !keep {
+                        tracing::info!("MQTT: device {} removed, stopping subscriber", key);
+                        task.abort();
+                    }
+                    keep
+                });
+            }
+            Err(e) => tracing::warn!("MQTT: failed to load devices: {}", e),
+        }
+
+        tokio::time::sleep(DEVICE_REFRESH_INTERVAL).await;
+    }
+}
+
+async fn load_mqtt_devices() -> Result<Vec<MqttDeviceRow>, AppError> {
+    let mut resp = db()
+        .query("SELECT id, config FROM hardware_device WHERE device_type = 'mqtt'")
+        .await
+        .map_err(|e| AppError::Database(e.to_string()))?;
+    let _ = resp.take_errors();
+    resp.take(0).map_err(|e| AppError::Database(e.to_string()))
+}
+
+/// Runs one broker connection until aborted, storing readings for zones linked to the device.
+async fn subscribe_device(device_id: RecordId, config: MqttConfig) {
+    let client_id = format!("orchid-tracker-{}", crate::server_fns::auth::record_id_to_string(&device_id).replace(':', "-"));
+    let (client, mut eventloop) = AsyncClient::new(config.options(&client_id), 10);
+    let mut last_stored: HashMap<i32, Instant> = HashMap::new();
+
+    loop {
+        match eventloop.poll().await {
+            // Clean sessions drop subscriptions, so (re)subscribe on every connect
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                if let Err(e) = client.try_subscribe_many(config.subscriptions()) {
+                    tracing::warn!("MQTT: subscribe failed on {}: {}", config.host, e);
+                }
+            }
+            Ok(Event::Incoming(Packet::Publish(p))) => {
+                let Some(port) = config.port_for_topic(&p.topic) else { continue };
+                if last_stored.get(&port).is_some_and(|at| at.elapsed() < MIN_STORE_INTERVAL) {
+                    continue;
+                }
+                match parse_sensor_payload(&p.payload) {
+                    Ok(raw) => {
+                        store_topic_reading(&device_id, port, &raw).await;
+                        last_stored.insert(port, Instant::now());
+                    }
+                    Err(e) => tracing::debug!("MQTT: ignoring message on '{}': {}", p.topic, e),
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("MQTT: connection to {}:{} failed: {}", config.host, config.port, e);
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        }
+    }
+}
+
+async fn store_topic_reading(device_id: &RecordId, port: i32, raw: &RawReading) {
+    let db = db();
+    let mut resp = match db
+        .query("SELECT id, name, location_type FROM growing_zone WHERE hardware_device = $dev AND hardware_port = $port")
+        .bind(("dev", device_id.clone()))
+        .bind(("port", port))
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("MQTT: failed to query zones for topic #{}: {}", port, e);
+            return;
+        }
+    };
+    let _ = resp.take_errors();
+    let zones: Vec<TopicZoneRow> = resp.take(0).unwrap_or_default();
+    let readings: Vec<_> = zones.iter()
+        .map(|zone| super::poller::PendingReading::for_zone(&zone.id, &zone.name, zone.location_type.as_deref(), raw, "mqtt"))
+        .collect();
+    super::poller::store_readings(&db, &readings).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_for_topic_matches_wildcards() {
+        let config = MqttConfig {
+            host: "broker.local".into(),
+            port: 1883,
+            username: String::new(),
+            password: String::new(),
+            topics: vec!["greenhouse/bench1".into(), "tent/+/climate".into()],
+        };
+        assert_eq!(config.port_for_topic("greenhouse/bench1"), Some(1));
+        assert_eq!(config.port_for_topic("tent/left/climate"), Some(2));
+        assert_eq!(config.port_for_topic("other/topic"), None);
+    }
+}
@@ -1,4 +1,15 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use crate::error::AppError;
+use crate::forecast::{ForecastHour, FORECAST_HOURS};
+
+/// How long a fetched forecast is reused before asking Open-Meteo again.
+const FORECAST_TTL: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+
+/// Forecasts keyed by coordinates rounded to 0.01 degrees (about 1 km), so zones at the same site share one request.
+static FORECAST_CACHE: LazyLock<Mutex<HashMap<(i64, i64), (DateTime<Utc>, Vec<ForecastHour>)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 /// **What is it?**
 /// A data structure representing the parsed response of an Open-Meteo weather API call.
@@ -80,3 +91,118 @@ pub async fn fetch_habitat_weather(
         precipitation_mm: precipitation,
     })
 }
+
+/// **What is it?**
+/// A function that returns the next 48 hours of hourly temperature and precipitation for a coordinate pair, served from an in-memory cache when fresh.
+///
+/// **Why does it exist?**
+/// It exists to back the dashboard forecast card for outdoor zones without calling Open-Meteo on every page load; forecasts only change a few times per hour.
+///
+/// **How should it be used?**
+/// Call from the forecast server function with a zone's configured coordinates. Returns the forecast hours and when they were fetched.
+pub async fn fetch_forecast(
+    client: &reqwest::Client,
+    latitude: f64,
+    longitude: f64,
+) -> Result<(DateTime<Utc>, Vec<ForecastHour>), AppError> {
+    let key = ((latitude * 100.0).round() as i64, (longitude * 100.0).round() as i64);
+    if let Ok(cache) = FORECAST_CACHE.lock() {
+        if let Some((fetched_at, hours)) = cache.get(&key) {
+            let age = (Utc::now() - *fetched_at).to_std().unwrap_or_default();
+            if age < FORECAST_TTL {
+                return Ok((*fetched_at, hours.clone()));
+            }
+        }
+    }
+
+    let url = format!(
+        "https://api.open-meteo.com/v1/forecast?latitude={:.2}&longitude={:.2}\
+         &hourly=temperature_2m,precipitation,precipitation_probability&forecast_hours={}&timezone=UTC",
+        key.0 as f64 / 100.0, key.1 as f64 / 100.0, FORECAST_HOURS
+    );
+
+    let resp = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| AppError::Network(format!("Open-Meteo forecast request failed: {}", e)))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(AppError::Network(format!(
+            "Open-Meteo forecast API error {}: {}",
+            status, body
+        )));
+    }
+
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| AppError::Serialization(format!("Open-Meteo forecast parse error: {}", e)))?;
+
+    let hours = parse_hourly_forecast(&json)?;
+    let fetched_at = Utc::now();
+    if let Ok(mut cache) = FORECAST_CACHE.lock() {
+        cache.retain(|_, (at, _)| (fetched_at - *at).to_std().unwrap_or_default() < FORECAST_TTL);
+        cache.insert(key, (fetched_at, hours.clone()));
+    }
+    Ok((fetched_at, hours))
+}
+
+/// Converts the `hourly` block of an Open-Meteo response (requested with `timezone=UTC`) into forecast hours.
+fn parse_hourly_forecast(json: &serde_json::Value) -> Result<Vec<ForecastHour>, AppError> {
+    let hourly = json
+        .get("hourly")
+        .ok_or_else(|| AppError::Serialization("Missing 'hourly' in Open-Meteo response".into()))?;
+    let series = |name: &str| -> Vec<Option<f64>> {
+        hourly.get(name)
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().map(|v| v.as_f64()).collect())
+            .unwrap_or_default()
+    };
+    let times = hourly.get("time")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| AppError::Serialization("Missing 'hourly.time' in Open-Meteo response".into()))?;
+    let temps = series("temperature_2m");
+    let precip = series("precipitation");
+    let probability = series("precipitation_probability");
+
+    Ok(times.iter().enumerate().filter_map(|(i, t)| {
+        let time = NaiveDateTime::parse_from_str(t.as_str()?, "%Y-%m-%dT%H:%M").ok()?.and_utc();
+        Some(ForecastHour {
+            time,
+            temperature_c: temps.get(i).copied().flatten()?,
+            precipitation_mm: precip.get(i).copied().flatten().unwrap_or(0.0),
+            precipitation_probability: probability.get(i).copied().flatten(),
+        })
+    }).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hourly_forecast() {
+        let json = serde_json::json!({
+            "hourly": {
+                "time": ["2026-06-01T18:00", "2026-06-01T19:00", "2026-06-01T20:00"],
+                "temperature_2m": [21.5, null, 19.0],
+                "precipitation": [0.0, 0.4, null],
+                "precipitation_probability": [10, 40, 80]
+            }
+        });
+        let hours = parse_hourly_forecast(&json).unwrap();
+        assert_eq!(hours.len(), 2);
+        assert_eq!(hours[0].time.to_rfc3339(), "2026-06-01T18:00:00+00:00");
+        assert_eq!(hours[0].temperature_c, 21.5);
+        assert_eq!(hours[1].precipitation_mm, 0.0);
+        assert_eq!(hours[1].precipitation_probability, Some(80.0));
+    }
+
+    #[test]
+    fn test_parse_hourly_forecast_missing_block() {
+        assert!(parse_hourly_forecast(&serde_json::json!({})).is_err());
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use chrono::{DateTime, Datelike, Utc};
+use parquet::basic::Compression;
+use parquet::data_type::{ByteArray, ByteArrayType, DataType, DoubleType, Int64Type};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
+use parquet::schema::parser::parse_message_type;
+use surrealdb::types::{RecordId, SurrealValue};
+use crate::db::db;
+use crate::error::AppError;
+use crate::server_fns::auth::record_id_to_string;
+
+const READINGS_SCHEMA: &str = "
+message climate_reading {
+    REQUIRED INT64 recorded_at (TIMESTAMP(MILLIS, true));
+    REQUIRED DOUBLE temperature_c;
+    REQUIRED DOUBLE humidity_pct;
+    OPTIONAL DOUBLE vpd_kpa;
+    OPTIONAL DOUBLE dew_point_c;
+    OPTIONAL DOUBLE precipitation_mm;
+    OPTIONAL DOUBLE lux;
+    OPTIONAL DOUBLE ppfd;
+    OPTIONAL DOUBLE co2_ppm;
+    OPTIONAL DOUBLE substrate_moisture_pct;
+    OPTIONAL BYTE_ARRAY source (UTF8);
+}
+";
+
+const SUMMARIES_SCHEMA: &str = "
+message climate_summary {
+    REQUIRED BYTE_ARRAY period_type (UTF8);
+    REQUIRED INT64 period_start (TIMESTAMP(MILLIS, true));
+    REQUIRED DOUBLE avg_temperature_c;
+    REQUIRED DOUBLE min_temperature_c;
+    REQUIRED DOUBLE max_temperature_c;
+    REQUIRED DOUBLE avg_humidity_pct;
+    REQUIRED DOUBLE min_humidity_pct;
+    REQUIRED DOUBLE max_humidity_pct;
+    REQUIRED INT64 sample_count;
+}
+";
+
+/// One raw climate reading as exported.
+#[derive(Debug, Clone, serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ReadingRecord {
+    /// The zone the reading belongs to.
+    pub zone: RecordId,
+    /// When the reading was taken.
+    pub recorded_at: DateTime<Utc>,
+    /// Temperature in Celsius.
+    pub temperature: f64,
+    /// Relative humidity percentage.
+    pub humidity: f64,
+    /// Vapor pressure deficit in kPa.
+    #[surreal(default)]
+    pub vpd: Option<f64>,
+    /// Dew point in Celsius.
+    #[surreal(default)]
+    pub dew_point: Option<f64>,
+    /// Precipitation in mm.
+    #[surreal(default)]
+    pub precipitation: Option<f64>,
+    /// Illuminance in lux.
+    #[surreal(default)]
+    pub lux: Option<f64>,
+    /// PPFD in µmol/m²/s.
+    #[surreal(default)]
+    pub ppfd: Option<f64>,
+    /// CO2 in ppm.
+    #[surreal(default)]
+    pub co2_ppm: Option<f64>,
+    /// Substrate moisture percentage.
+    #[surreal(default)]
+    pub substrate_moisture_pct: Option<f64>,
+    /// The device or integration that produced the reading.
+    #[surreal(default)]
+    pub source: Option<String>,
+}
+
+/// One hourly or daily rollup as exported.
+#[derive(Debug, Clone, serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct SummaryRecord {
+    /// The zone the summary belongs to.
+    pub zone: RecordId,
+    /// `hourly` or `daily`.
+    pub period_type: String,
+    /// Start of the summarized period.
+    pub period_start: DateTime<Utc>,
+    /// Mean temperature in Celsius.
+    pub avg_temperature: f64,
+    /// Lowest temperature in Celsius.
+    pub min_temperature: f64,
+    /// Highest temperature in Celsius.
+    pub max_temperature: f64,
+    /// Mean relative humidity.
+    pub avg_humidity: f64,
+    /// Lowest relative humidity.
+    pub min_humidity: f64,
+    /// Highest relative humidity.
+    pub max_humidity: f64,
+    /// Raw readings the period summarizes.
+    pub sample_count: i64,
+}
+
+#[derive(serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct ZoneNameRow {
+    id: RecordId,
+    name: String,
+}
+
+fn parquet_error(e: ParquetError) -> AppError {
+    AppError::Serialization(format!("Parquet write failed: {}", e))
+}
+
+fn next_column<'a>(
+    row_group: &'a mut SerializedRowGroupWriter<'_, Vec<u8>>,
+) -> Result<parquet::file::writer::SerializedColumnWriter<'a>, ParquetError> {
+    row_group.next_column()?
+        .ok_or_else(|| ParquetError::General("more columns written than the schema defines".into()))
+}
+
+/// Writes the next column of a row group, where every row has a value.
+fn write_required<T: DataType>(
+    row_group: &mut SerializedRowGroupWriter<'_, Vec<u8>>,
+    values: &[T::T],
+) -> Result<(), ParquetError> {
+    let mut column = next_column(row_group)?;
+    column.typed::<T>().write_batch(values, None, None)?;
+    column.close()
+}
+
+/// Writes the next column of a row group, where rows may be null.
+fn write_optional<T: DataType>(
+    row_group: &mut SerializedRowGroupWriter<'_, Vec<u8>>,
+    values: Vec<Option<T::T>>,
+) -> Result<(), ParquetError> {
+    let def_levels: Vec<i16> = values.iter().map(|v| i16::from(v.is_some())).collect();
+    let present: Vec<T::T> = values.into_iter().flatten().collect();
+    let mut column = next_column(row_group)?;
+    column.typed::<T>().write_batch(&present, Some(&def_levels), None)?;
+    column.close()
+}
+
+/// Runs `fill` against a single row group of a new Snappy-compressed file with `schema`, returning the file's bytes.
+fn write_file(
+    schema: &str,
+    fill: impl FnOnce(&mut SerializedRowGroupWriter<'_, Vec<u8>>) -> Result<(), ParquetError>,
+) -> Result<Vec<u8>, AppError> {
+    let schema = Arc::new(parse_message_type(schema).map_err(parquet_error)?);
+    let props = Arc::new(WriterProperties::builder().set_compression(Compression::SNAPPY).build());
+    let mut writer = SerializedFileWriter::new(Vec::new(), schema, props).map_err(parquet_error)?;
+    let mut row_group = writer.next_row_group().map_err(parquet_error)?;
+    fill(&mut row_group).map_err(parquet_error)?;
+    row_group.close().map_err(parquet_error)?;
+    writer.into_inner().map_err(parquet_error)
+}
+
+/// **What is it?**
+/// A function that encodes raw climate readings as a Parquet file.
+///
+/// **Why does it exist?**
+/// It exists so years of telemetry can be loaded straight into pandas, Polars, DuckDB or R, which read Parquet columns far faster than JSON.
+///
+/// **How should it be used?**
+/// Pass one zone-year of readings. Columns use SI units named in the column (`temperature_c`, `vpd_kpa`); metrics a sensor doesn't report are null.
+pub fn readings_to_parquet(readings: &[ReadingRecord]) -> Result<Vec<u8>, AppError> {
+    write_file(READINGS_SCHEMA, |rg| {
+        let times: Vec<i64> = readings.iter().map(|r| r.recorded_at.timestamp_millis()).collect();
+        write_required::<Int64Type>(rg, &times)?;
+        write_required::<DoubleType>(rg, &readings.iter().map(|r| r.temperature).collect::<Vec<_>>())?;
+        write_required::<DoubleType>(rg, &readings.iter().map(|r| r.humidity).collect::<Vec<_>>())?;
+        let metrics: [fn(&ReadingRecord) -> Option<f64>; 7] = [
+            |r| r.vpd,
+            |r| r.dew_point,
+            |r| r.precipitation,
+            |r| r.lux,
+            |r| r.ppfd,
+            |r| r.co2_ppm,
+            |r| r.substrate_moisture_pct,
+        ];
+        for metric in metrics {
+            write_optional::<DoubleType>(rg, readings.iter().map(metric).collect())?;
+        }
+        write_optional::<ByteArrayType>(rg, readings.iter()
+            .map(|r| r.source.as_deref().map(ByteArray::from))
+            .collect())
+    })
+}
+
+/// **What is it?**
+/// A function that encodes hourly and daily climate rollups as a Parquet file.
+///
+/// **Why does it exist?**
+/// It exists because raw readings past the retention window only survive as rollups, so a multi-year export needs them too.
+///
+/// **How should it be used?**
+/// Pass one zone-year of summaries; `period_type` tells hourly and daily rows apart.
+pub fn summaries_to_parquet(summaries: &[SummaryRecord]) -> Result<Vec<u8>, AppError> {
+    write_file(SUMMARIES_SCHEMA, |rg| {
+        write_required::<ByteArrayType>(rg, &summaries.iter().map(|s| ByteArray::from(s.period_type.as_str())).collect::<Vec<_>>())?;
+        write_required::<Int64Type>(rg, &summaries.iter().map(|s| s.period_start.timestamp_millis()).collect::<Vec<_>>())?;
+        let metrics: [fn(&SummaryRecord) -> f64; 6] = [
+            |s| s.avg_temperature,
+            |s| s.min_temperature,
+            |s| s.max_temperature,
+            |s| s.avg_humidity,
+            |s| s.min_humidity,
+            |s| s.max_humidity,
+        ];
+        for metric in metrics {
+            write_required::<DoubleType>(rg, &summaries.iter().map(metric).collect::<Vec<_>>())?;
+        }
+        write_required::<Int64Type>(rg, &summaries.iter().map(|s| s.sample_count).collect::<Vec<_>>())
+    })
+}
+
+/// A file-system-safe folder name for a zone: lowercase letters, digits and dashes.
+fn zone_folder(name: &str) -> String {
+    let slug = name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() { "zone".to_string() } else { slug }
+}
+
+/// Groups rows by zone and UTC year, keeping their order.
+fn by_zone_year<T>(rows: Vec<T>, key: impl Fn(&T) -> (String, i32)) -> BTreeMap<(String, i32), Vec<T>> {
+    let mut groups: BTreeMap<(String, i32), Vec<T>> = BTreeMap::new();
+    for row in rows {
+        groups.entry(key(&row)).or_default().push(row);
+    }
+    groups
+}
+
+/// **What is it?**
+/// A function that builds the Parquet export of a user's climate history: one readings file and one rollups file per zone per year.
+///
+/// **Why does it exist?**
+/// It exists so the download in settings and the `export-climate` CLI command produce the same layout.
+///
+/// **How should it be used?**
+/// Pass the owner's user record. Returns `(path, bytes)` pairs such as `greenhouse/2025/readings.parquet`; zip them for download or write them under a directory. Zones whose names collide get a numeric suffix.
+pub async fn export_climate_parquet(owner: RecordId) -> Result<Vec<(String, Vec<u8>)>, AppError> {
+    let mut response = db()
+        .query(
+            "SELECT id, name FROM growing_zone WHERE owner = $owner ORDER BY name ASC; \
+             SELECT zone, recorded_at, temperature, humidity, vpd, dew_point, precipitation, lux, ppfd, co2_ppm, substrate_moisture_pct, source \
+                 FROM climate_reading WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $owner) ORDER BY recorded_at ASC; \
+             SELECT zone, period_type, period_start, avg_temperature, min_temperature, max_temperature, avg_humidity, min_humidity, max_humidity, sample_count \
+                 FROM climate_reading_summary WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $owner) ORDER BY period_start ASC;"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| AppError::Database(format!("Climate export query failed: {}", e)))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(AppError::Database(format!("Climate export query error: {}", err_msg)));
+    }
+
+    let zones: Vec<ZoneNameRow> = response.take(0)
+        .map_err(|e| AppError::Database(format!("Climate export zone parse failed: {}", e)))?;
+    let readings: Vec<ReadingRecord> = response.take(1)
+        .map_err(|e| AppError::Database(format!("Climate export reading parse failed: {}", e)))?;
+    let summaries: Vec<SummaryRecord> = response.take(2)
+        .map_err(|e| AppError::Database(format!("Climate export summary parse failed: {}", e)))?;
+
+    let mut used = HashSet::new();
+    let folders: BTreeMap<String, String> = zones.into_iter()
+        .map(|z| {
+            let base = zone_folder(&z.name);
+            let mut folder = base.clone();
+            let mut n = 2;
+            while !used.insert(folder.clone()) {
+                folder = format!("{}-{}", base, n);
+                n += 1;
+            }
+            (record_id_to_string(&z.id), folder)
+        })
+        .collect();
+
+    let readings = by_zone_year(readings, |r| (record_id_to_string(&r.zone), r.recorded_at.year()));
+    let summaries = by_zone_year(summaries, |s| (record_id_to_string(&s.zone), s.period_start.year()));
+
+    tokio::task::spawn_blocking(move || {
+        let mut files = Vec::new();
+        for ((zone, year), rows) in readings {
+            if let Some(folder) = folders.get(&zone) {
+                files.push((format!("{}/{}/readings.parquet", folder, year), readings_to_parquet(&rows)?));
+            }
+        }
+        for ((zone, year), rows) in summaries {
+            if let Some(folder) = folders.get(&zone) {
+                files.push((format!("{}/{}/summaries.parquet", folder, year), summaries_to_parquet(&rows)?));
+            }
+        }
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(files)
+    })
+    .await
+    .map_err(|e| AppError::Serialization(format!("Climate export task failed: {}", e)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    fn reading(hour: u32, lux: Option<f64>) -> ReadingRecord {
+        ReadingRecord {
+            zone: RecordId::parse_simple("growing_zone:a").unwrap(),
+            recorded_at: Utc.with_ymd_and_hms(2025, 6, 1, hour, 0, 0).unwrap(),
+            temperature: 22.0,
+            humidity: 60.0,
+            vpd: Some(1.06),
+            dew_point: Some(13.9),
+            precipitation: None,
+            lux,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
+            source: Some("sensorpush".into()),
+        }
+    }
+
+    #[test]
+    fn test_readings_round_trip_through_parquet() {
+        let bytes = readings_to_parquet(&[reading(6, Some(12000.0)), reading(7, None), reading(8, Some(18000.0))]).unwrap();
+        assert_eq!(&bytes[..4], b"PAR1");
+
+        let path = std::env::temp_dir().join(format!("orchid-parquet-test-{}.parquet", std::process::id()));
+        std::fs::write(&path, &bytes).unwrap();
+        let reader = SerializedFileReader::try_from(path.as_path()).unwrap();
+        let meta = reader.metadata().file_metadata();
+        assert_eq!(meta.num_rows(), 3);
+        assert_eq!(meta.schema_descr().num_columns(), 11);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_zone_folder() {
+        assert_eq!(zone_folder("Kitchen Window (East)"), "kitchen-window-east");
+        assert_eq!(zone_folder("  "), "zone");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::error::AppError;
+use super::RawReading;
+
+/// Looks up a numeric field by any of `keys`, case-insensitively.
+fn find_number(obj: &serde_json::Map<String, serde_json::Value>, keys: &[&str]) -> Option<f64> {
+    obj.iter()
+        .find(|(k, _)| keys.iter().any(|key| k.eq_ignore_ascii_case(key)))
+        .and_then(|(_, v)| v.as_f64().or_else(|| v.as_str().and_then(|s| s.trim().parse().ok())))
+}
+
+/// Reads temperature/humidity (and light, CO2, and substrate moisture, if present) from one JSON object, if it has both.
+fn reading_from_object(obj: &serde_json::Map<String, serde_json::Value>) -> Option<RawReading> {
+    let temperature_c = find_number(obj, &["temperature", "temperature_c", "temp", "temp_c", "tempc"])
+        .or_else(|| find_number(obj, &["temperature_f", "temp_f", "tempf"]).map(|f| (f - 32.0) * 5.0 / 9.0))?;
+    let humidity_pct = find_number(obj, &["humidity", "humidity_pct", "hum", "rh"])?;
+    let vpd_kpa = find_number(obj, &["vpd", "vpd_kpa"])
+        .or_else(|| Some(super::calculate_vpd(temperature_c, humidity_pct)));
+    let lux = find_number(obj, &["lux", "illuminance", "illuminance_lux"]);
+    let ppfd = find_number(obj, &["ppfd", "par", "ppfd_umol"]);
+    let co2_ppm = find_number(obj, &["co2", "co2_ppm", "eco2", "carbondioxide"]);
+    let substrate_moisture_pct = find_number(obj, &["moisture", "soil_moisture", "substrate_moisture", "substrate_moisture_pct"]);
+    Some(RawReading { temperature_c, humidity_pct, vpd_kpa, lux, ppfd, co2_ppm, substrate_moisture_pct, ..Default::default() })
+}
+
+/// **What is it?**
+/// A function that converts a JSON sensor payload into a `RawReading`.
+///
+/// **Why does it exist?**
+/// It exists because DIY sensors send many slightly different shapes: `{"temperature": 22.4, "humidity": 61}`, `{"temp_f": 72.3, "rh": 61}`, or Tasmota-style `{"AM2301": {"Temperature": 22.4, "Humidity": 61}}`, whether they publish to MQTT or POST to the HTTP ingest endpoint.
+///
+/// **How should it be used?**
+/// Call this with each received payload. Keys are matched case-insensitively; when the top level has no readings, the first nested object with both temperature and humidity is used. VPD is computed when not reported; light, CO2, and moisture are optional.
+pub fn parse_sensor_payload(payload: &[u8]) -> Result<RawReading, AppError> {
+    let json: serde_json::Value = serde_json::from_slice(payload)
+        .map_err(|e| AppError::Serialization(format!("Sensor payload is not JSON: {}", e)))?;
+    let obj = json.as_object()
+        .ok_or_else(|| AppError::Serialization("Sensor payload is not a JSON object".into()))?;
+
+    reading_from_object(obj)
+        .or_else(|| obj.values().filter_map(|v| v.as_object()).find_map(reading_from_object))
+        .ok_or_else(|| AppError::Serialization("Sensor payload has no temperature and humidity".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flat_payload() {
+        let raw = parse_sensor_payload(br#"{"temperature": 22.5, "humidity": 60}"#).unwrap();
+        assert_eq!(raw.temperature_c, 22.5);
+        assert_eq!(raw.humidity_pct, 60.0);
+        assert!(raw.vpd_kpa.is_some());
+        assert_eq!(raw.lux, None);
+    }
+
+    #[test]
+    fn test_parse_fahrenheit_and_reported_vpd() {
+        let raw = parse_sensor_payload(br#"{"TempF": "71.6", "RH": 55, "vpd": 1.1}"#).unwrap();
+        assert!((raw.temperature_c - 22.0).abs() < 1e-9);
+        assert_eq!(raw.vpd_kpa, Some(1.1));
+    }
+
+    #[test]
+    fn test_parse_nested_tasmota_payload() {
+        let raw = parse_sensor_payload(br#"{"Time": "2026-06-01T10:00:00", "AM2301": {"Temperature": 24.1, "Humidity": 70.2}}"#).unwrap();
+        assert_eq!(raw.temperature_c, 24.1);
+        assert_eq!(raw.humidity_pct, 70.2);
+    }
+
+    #[test]
+    fn test_parse_optional_lux() {
+        let raw = parse_sensor_payload(br#"{"temp": 23.0, "humidity": 65, "lux": 12500}"#).unwrap();
+        assert_eq!(raw.lux, Some(12500.0));
+        assert_eq!(raw.ppfd, None);
+    }
+
+    #[test]
+    fn test_parse_optional_ppfd() {
+        let raw = parse_sensor_payload(br#"{"temp": 23.0, "humidity": 65, "PAR": 180.5}"#).unwrap();
+        assert_eq!(raw.ppfd, Some(180.5));
+    }
+
+    #[test]
+    fn test_parse_optional_co2_and_moisture() {
+        let raw = parse_sensor_payload(br#"{"temp": 23.0, "humidity": 65, "CO2": 640, "soil_moisture": "38.5"}"#).unwrap();
+        assert_eq!(raw.co2_ppm, Some(640.0));
+        assert_eq!(raw.substrate_moisture_pct, Some(38.5));
+    }
+
+    #[test]
+    fn test_parse_rejects_incomplete_payloads() {
+        assert!(parse_sensor_payload(b"22.5").is_err());
+        assert!(parse_sensor_payload(br#"{"temperature": 22.5}"#).is_err());
+    }
+}
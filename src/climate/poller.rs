@@ -1,6 +1,21 @@
 use crate::db::db;
+use std::sync::Arc;
 use surrealdb::types::SurrealValue;
-use super::{tempest, ac_infinity, open_meteo};
+use super::{tempest, ac_infinity, open_meteo, sensorpush};
+
+/// Most readings written in one transaction; keeps each request well under SurrealDB's message limits.
+const WRITE_BATCH_SIZE: usize = 50;
+
+/// Most readings held back while the database is unreachable (a day of 30-minute polls for about
+/// a hundred zones); past this the oldest are dropped.
+const MAX_UNSTORED_READINGS: usize = 5000;
+
+/// Readings whose write failed because the database couldn't be reached, oldest first. The next
+/// `store_readings` call writes them ahead of its own.
+static UNSTORED: std::sync::LazyLock<std::sync::Mutex<std::collections::VecDeque<PendingReading>>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::VecDeque::new()));
+
+type Db = surrealdb::Surreal<surrealdb::engine::remote::ws::Client>;
 
 /// **What is it?**
 /// A core orchestration task that fetches fresh climate readings for all active zones and stores them in the database.
@@ -11,27 +26,43 @@ use super::{tempest, ac_infinity, open_meteo};
 /// **How should it be used?**
 /// Spawn this as a recurring job in the main background loop, executing the two-phase approach (hardware-grouped then legacy) periodically.
 pub async fn poll_all_zones() {
+    // Old raw readings are left for `rollups::compact_climate_readings` to summarize and delete
+    poll_zones(None).await;
+
+    tracing::info!("Climate poll completed, checking alerts...");
+
+    // Check condition alerts after storing new readings
+    super::alerts::check_and_send_alerts().await;
+
+    // Then the user's own climate automation rules
+    super::care_rules::evaluate_climate_rules().await;
+}
+
+/// **What is it?**
+/// One fetch-and-store pass over every polled zone, or only `zone`, without the alert checks that follow in `poll_all_zones`.
+///
+/// **Why does it exist?**
+/// It exists so `Command::Poll` can exercise a single integration on demand and show exactly what came back, instead of waiting for the 30-minute loop.
+///
+/// **How should it be used?**
+/// Pass `None` for every zone. A device shared by several zones is still fetched once, but only `zone`'s reading is kept. Returns the readings fetched and what `store_readings` did with them.
+pub(crate) async fn poll_zones(zone: Option<&surrealdb::types::RecordId>) -> (Vec<PendingReading>, StoreOutcome) {
     let db = db();
     let client = reqwest::Client::new();
 
     // ── Phase A: Device-linked zones ──────────────────────────────
-    poll_device_linked_zones(db, &client).await;
+    let mut readings = poll_device_linked_zones(&db, &client, zone).await;
 
     // ── Phase B: Legacy zones (data_source_type set, no hardware_device) ──
-    poll_legacy_zones(db, &client).await;
+    readings.extend(poll_legacy_zones(&db, &client, zone).await);
 
-    // Prune readings older than 30 days
-    if let Err(e) = db
-        .query("DELETE climate_reading WHERE recorded_at < time::now() - 30d")
-        .await
-    {
-        tracing::warn!("Climate poll: failed to prune old readings: {}", e);
+    if let Some(zone) = zone {
+        readings.retain(|r| &r.zone_id == zone);
     }
 
-    tracing::info!("Climate poll completed, checking alerts...");
-
-    // Check condition alerts after storing new readings
-    super::alerts::check_and_send_alerts().await;
+    // One write per batch instead of one round trip per zone
+    let outcome = store_readings(&db, &readings).await;
+    (readings, outcome)
 }
 
 /// **What is it?**
@@ -41,141 +72,214 @@ pub async fn poll_all_zones() {
 /// It exists to optimize network calls, pulling data for multiple sensors simultaneously with a single API request per physical device rather than per zone.
 ///
 /// **How should it be used?**
-/// Call this internally within `poll_all_zones` before processing legacy single-sensor zones.
+/// Call this internally within `poll_all_zones` before processing legacy single-sensor zones. Devices are polled concurrently; the returned readings are not yet stored.
 async fn poll_device_linked_zones(
-    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+    db: &Arc<Db>,
     client: &reqwest::Client,
-) {
-    // Get all hardware devices
+    zone: Option<&surrealdb::types::RecordId>,
+) -> Vec<PendingReading> {
+    // Get all hardware devices, or just the one `zone` is linked to
     let mut dev_response = match db
-        .query("SELECT id, device_type, config FROM hardware_device")
+        .query("SELECT id, device_type, config FROM hardware_device WHERE $zone IS NONE OR id = $zone.hardware_device")
+        .bind(("zone", zone.cloned()))
         .await
     {
         Ok(r) => r,
         Err(e) => {
             tracing::warn!("Climate poll: failed to query hardware devices: {}", e);
-            return;
+            return Vec::new();
         }
     };
 
     let errors = dev_response.take_errors();
     if !errors.is_empty() {
         tracing::debug!("Climate poll: hardware_device query errors (may not exist yet): {:?}", errors);
-        return;
+        return Vec::new();
     }
 
     let devices: Vec<DeviceRow> = match dev_response.take(0) {
         Ok(d) => d,
         Err(e) => {
             tracing::debug!("Climate poll: failed to parse hardware devices: {}", e);
-            return;
+            return Vec::new();
         }
     };
 
     if devices.is_empty() {
         tracing::debug!("Climate poll: no hardware devices configured");
-        return;
+        return Vec::new();
     }
 
-    for device in &devices {
-        // Get zones linked to this device
-        let mut zone_response = match db
-            .query("SELECT id, name, hardware_port FROM growing_zone WHERE hardware_device = $dev_id")
-            .bind(("dev_id", device.id.clone()))
-            .await
-        {
-            Ok(r) => r,
-            Err(e) => {
-                tracing::warn!("Climate poll: failed to query zones for device {:?}: {}", device.id, e);
-                continue;
-            }
-        };
+    let tasks = devices.into_iter()
+        .map(|device| poll_device(db.clone(), client.clone(), device));
+    super::join_bounded(tasks).await.into_iter().flatten().collect()
+}
 
-        let _ = zone_response.take_errors();
-        let linked_zones: Vec<DeviceZoneRow> = match zone_response.take(0) {
-            Ok(z) => z,
-            Err(e) => {
-                tracing::warn!("Climate poll: failed to parse linked zones: {}", e);
-                continue;
-            }
-        };
+/// Fetches one device's readings and maps them onto the zones linked to it.
+async fn poll_device(db: Arc<Db>, client: reqwest::Client, device: DeviceRow) -> Vec<PendingReading> {
+    // MQTT devices push their readings; see `climate::mqtt`
+    if device.device_type == "mqtt" {
+        return Vec::new();
+    }
+
+    // Get zones linked to this device
+    let mut zone_response = match db
+        .query("SELECT id, name, hardware_port, location_type FROM growing_zone WHERE hardware_device = $dev_id")
+        .bind(("dev_id", device.id.clone()))
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("Climate poll: failed to query zones for device {:?}: {}", device.id, e);
+            return Vec::new();
+        }
+    };
 
-        if linked_zones.is_empty() {
-            continue;
+    let _ = zone_response.take_errors();
+    let linked_zones: Vec<DeviceZoneRow> = match zone_response.take(0) {
+        Ok(z) => z,
+        Err(e) => {
+            tracing::warn!("Climate poll: failed to parse linked zones: {}", e);
+            return Vec::new();
         }
+    };
 
-        let config_str = crate::crypto::decrypt_or_raw(&device.config);
+    if linked_zones.is_empty() {
+        return Vec::new();
+    }
 
-        match device.device_type.as_str() {
-            "tempest" => {
-                let config: TempestConfig = match serde_json::from_str(&config_str) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        tracing::warn!("Climate poll: bad tempest config for device {:?}: {}", device.id, e);
-                        continue;
-                    }
-                };
+    let config_str = crate::crypto::decrypt_or_raw(&device.config);
+    let mut readings = Vec::with_capacity(linked_zones.len());
 
-                // One fetch for all linked zones
-                match tempest::fetch_tempest_reading(client, &config.station_id, &config.token).await {
+    match device.device_type.as_str() {
+        "tempest" => {
+            let config: TempestConfig = match serde_json::from_str(&config_str) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Climate poll: bad tempest config for device {:?}: {}", device.id, e);
+                    return Vec::new();
+                }
+            };
+
+            // One fetch per station on the account; zones pick a station by
+            // `hardware_port` (1-based, defaults to the primary station).
+            let stations = config.stations();
+            let mut station_readings: std::collections::HashMap<usize, super::RawReading> =
+                std::collections::HashMap::new();
+            for zone in &linked_zones {
+                let idx = zone.hardware_port.unwrap_or(1).max(1) as usize - 1;
+                if station_readings.contains_key(&idx) {
+                    continue;
+                }
+                let Some(station_id) = stations.get(idx) else {
+                    tracing::warn!(
+                        "Climate poll: no Tempest station #{} on device for zone '{}'",
+                        idx + 1, zone.name
+                    );
+                    continue;
+                };
+                match tempest::fetch_tempest_reading(&client, station_id, &config.token).await {
                     Ok(raw) => {
-                        tracing::info!(
-                            "Climate poll: Tempest device fetch OK, distributing to {} zones",
-                            linked_zones.len()
-                        );
-                        for zone in &linked_zones {
-                            store_reading(db, &zone.id, &zone.name, &raw, "tempest").await;
-                        }
+                        station_readings.insert(idx, raw);
                     }
                     Err(e) => {
-                        tracing::warn!("Climate poll: Tempest fetch failed for device: {}", e);
+                        tracing::warn!("Climate poll: Tempest fetch failed for station {}: {}", station_id, e);
                     }
                 }
             }
-            "ac_infinity" => {
-                let config: AcInfinityConfig = match serde_json::from_str(&config_str) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        tracing::warn!("Climate poll: bad ac_infinity config for device {:?}: {}", device.id, e);
-                        continue;
-                    }
-                };
 
-                // One fetch, all ports
-                match ac_infinity::fetch_ac_infinity_all_ports(
-                    client,
-                    &config.email,
-                    &config.password,
-                    &config.device_id,
-                ).await {
-                    Ok(port_readings) => {
-                        tracing::info!(
-                            "Climate poll: AC Infinity device fetch OK ({} ports), distributing to {} zones",
-                            port_readings.len(),
-                            linked_zones.len()
-                        );
-                        for zone in &linked_zones {
-                            let port = zone.hardware_port.unwrap_or(1) as u32;
-                            if let Some(raw) = port_readings.get(&port) {
-                                store_reading(db, &zone.id, &zone.name, raw, "ac_infinity").await;
-                            } else {
-                                tracing::warn!(
-                                    "Climate poll: no reading for port {} on AC Infinity device for zone '{}'",
-                                    port, zone.name
-                                );
-                            }
+            tracing::info!(
+                "Climate poll: Tempest device fetch OK ({} stations), distributing to {} zones",
+                station_readings.len(),
+                linked_zones.len()
+            );
+            for zone in &linked_zones {
+                let idx = zone.hardware_port.unwrap_or(1).max(1) as usize - 1;
+                if let Some(raw) = station_readings.get(&idx) {
+                    readings.push(PendingReading::for_zone(&zone.id, &zone.name, zone.location_type.as_deref(), raw, "tempest"));
+                }
+            }
+        }
+        "ac_infinity" => {
+            let config: AcInfinityConfig = match serde_json::from_str(&config_str) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Climate poll: bad ac_infinity config for device {:?}: {}", device.id, e);
+                    return Vec::new();
+                }
+            };
+
+            // One fetch, all ports
+            match ac_infinity::fetch_ac_infinity_all_ports(
+                &client,
+                &config.email,
+                &config.password,
+                &config.device_id,
+            ).await {
+                Ok(port_readings) => {
+                    tracing::info!(
+                        "Climate poll: AC Infinity device fetch OK ({} ports), distributing to {} zones",
+                        port_readings.len(),
+                        linked_zones.len()
+                    );
+                    for zone in &linked_zones {
+                        let port = zone.hardware_port.unwrap_or(1) as u32;
+                        if let Some(raw) = port_readings.get(&port) {
+                            readings.push(PendingReading::for_zone(&zone.id, &zone.name, zone.location_type.as_deref(), raw, "ac_infinity"));
+                        } else {
+                            tracing::warn!(
+                                "Climate poll: no reading for port {} on AC Infinity device for zone '{}'",
+                                port, zone.name
+                            );
                         }
                     }
-                    Err(e) => {
-                        tracing::warn!("Climate poll: AC Infinity fetch failed for device: {}", e);
-                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Climate poll: AC Infinity fetch failed for device: {}", e);
                 }
             }
-            other => {
-                tracing::warn!("Climate poll: unknown device type '{}' for device", other);
+        }
+        "sensorpush" => {
+            let config: sensorpush::SensorPushConfig = match serde_json::from_str(&config_str) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Climate poll: bad sensorpush config for device {:?}: {}", device.id, e);
+                    return Vec::new();
+                }
+            };
+
+            // One login and one samples call for every sensor on the account; zones pick a
+            // sensor by `hardware_port` (1-based, in the order sensors were chosen).
+            let sensor_ids = config.sensor_ids();
+            match sensorpush::fetch_sensorpush_readings(&client, &config.email, &config.password, &sensor_ids).await {
+                Ok(sensor_readings) => {
+                    tracing::info!(
+                        "Climate poll: SensorPush device fetch OK ({} sensors), distributing to {} zones",
+                        sensor_readings.len(),
+                        linked_zones.len()
+                    );
+                    for zone in &linked_zones {
+                        let idx = zone.hardware_port.unwrap_or(1).max(1) as usize - 1;
+                        match sensor_ids.get(idx).and_then(|id| sensor_readings.get(id)) {
+                            Some(raw) => readings.push(PendingReading::for_zone(&zone.id, &zone.name, zone.location_type.as_deref(), raw, "sensorpush")),
+                            None => tracing::warn!(
+                                "Climate poll: no SensorPush reading for sensor #{} for zone '{}'",
+                                idx + 1, zone.name
+                            ),
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Climate poll: SensorPush fetch failed for device: {}", e);
+                }
             }
         }
+        other => {
+            tracing::warn!("Climate poll: unknown device type '{}' for device", other);
+        }
     }
+
+    readings
 }
 
 /// **What is it?**
@@ -185,157 +289,317 @@ async fn poll_device_linked_zones(
 /// It exists to maintain backwards compatibility for zones created before the shared hardware feature, ensuring they still receive data from APIs like Open-Meteo.
 ///
 /// **How should it be used?**
-/// Call this internally within `poll_all_zones` after processing the shared hardware devices.
+/// Call this internally within `poll_all_zones` after processing the shared hardware devices. Zones are fetched concurrently; the returned readings are not yet stored.
 async fn poll_legacy_zones(
-    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
+    db: &Db,
     client: &reqwest::Client,
-) {
+    zone: Option<&surrealdb::types::RecordId>,
+) -> Vec<PendingReading> {
+    // HTTP ingest zones push their readings; see `server_fns::ingest`
     let mut response = match db
         .query(
-            "SELECT id, name, data_source_type, data_source_config FROM growing_zone \
-             WHERE data_source_type IS NOT NULL AND hardware_device IS NONE"
+            "SELECT id, name, data_source_type, data_source_config, location_type FROM growing_zone \
+             WHERE data_source_type IS NOT NULL AND data_source_type != 'http' AND hardware_device IS NONE \
+             AND ($zone IS NONE OR id = $zone)"
         )
+        .bind(("zone", zone.cloned()))
         .await
     {
         Ok(r) => r,
         Err(e) => {
             tracing::warn!("Climate poll: failed to query legacy zones: {}", e);
-            return;
+            return Vec::new();
         }
     };
 
     let errors = response.take_errors();
     if !errors.is_empty() {
         tracing::warn!("Climate poll: legacy zone query errors: {:?}", errors);
-        return;
+        return Vec::new();
     }
 
     let zones: Vec<ZoneRow> = match response.take(0) {
         Ok(z) => z,
         Err(e) => {
             tracing::warn!("Climate poll: failed to parse legacy zones: {}", e);
-            return;
+            return Vec::new();
         }
     };
 
     if zones.is_empty() {
         tracing::debug!("Climate poll: no legacy zones with data sources configured");
-        return;
+        return Vec::new();
     }
 
     tracing::info!("Climate poll: polling {} legacy zones", zones.len());
 
-    for zone in &zones {
-        let zone_id = &zone.id;
-        let zone_name = &zone.name;
-        let source_type = match &zone.data_source_type {
-            Some(t) => t.as_str(),
-            None => continue,
-        };
-        let config_str = crate::crypto::decrypt_or_raw(&zone.data_source_config);
+    let tasks = zones.into_iter()
+        .map(|zone| poll_legacy_zone(client.clone(), zone));
+    super::join_bounded(tasks).await.into_iter().flatten().collect()
+}
 
-        let reading = match source_type {
-            "tempest" => {
-                let config: TempestConfig = match serde_json::from_str(&config_str) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        tracing::warn!("Climate poll: bad tempest config for zone '{}': {}", zone_name, e);
-                        continue;
-                    }
-                };
-                tempest::fetch_tempest_reading(client, &config.station_id, &config.token).await
-            }
-            "ac_infinity" => {
-                let config: AcInfinityConfig = match serde_json::from_str(&config_str) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        tracing::warn!("Climate poll: bad ac_infinity config for zone '{}': {}", zone_name, e);
-                        continue;
-                    }
-                };
-                ac_infinity::fetch_ac_infinity_reading(
-                    client,
-                    &config.email,
-                    &config.password,
-                    &config.device_id,
-                    config.port,
-                )
+/// Fetches the reading for one legacy zone from its own data source config.
+async fn poll_legacy_zone(client: reqwest::Client, zone: ZoneRow) -> Option<PendingReading> {
+    let zone_name = &zone.name;
+    let source_type = zone.data_source_type.as_deref()?;
+    let config_str = crate::crypto::decrypt_or_raw(&zone.data_source_config);
+
+    let reading = match source_type {
+        "tempest" => {
+            let config: TempestConfig = match serde_json::from_str(&config_str) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Climate poll: bad tempest config for zone '{}': {}", zone_name, e);
+                    return None;
+                }
+            };
+            tempest::fetch_tempest_reading(&client, &config.station_id, &config.token).await
+        }
+        "ac_infinity" => {
+            let config: AcInfinityConfig = match serde_json::from_str(&config_str) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Climate poll: bad ac_infinity config for zone '{}': {}", zone_name, e);
+                    return None;
+                }
+            };
+            ac_infinity::fetch_ac_infinity_reading(
+                &client,
+                &config.email,
+                &config.password,
+                &config.device_id,
+                config.port,
+            )
+            .await
+        }
+        "weather_api" => {
+            let config: WeatherApiConfig = match serde_json::from_str(&config_str) {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::warn!("Climate poll: bad weather_api config for zone '{}': {}", zone_name, e);
+                    return None;
+                }
+            };
+            open_meteo::fetch_habitat_weather(&client, config.latitude, config.longitude)
                 .await
-            }
-            "weather_api" => {
-                let config: WeatherApiConfig = match serde_json::from_str(&config_str) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        tracing::warn!("Climate poll: bad weather_api config for zone '{}': {}", zone_name, e);
-                        continue;
-                    }
-                };
-                open_meteo::fetch_habitat_weather(client, config.latitude, config.longitude)
-                    .await
-                    .map(|h| super::RawReading {
-                        temperature_c: h.temperature_c,
-                        humidity_pct: h.humidity_pct,
-                        vpd_kpa: Some(super::calculate_vpd(h.temperature_c, h.humidity_pct)),
-                        precipitation_mm: Some(h.precipitation_mm),
-                    })
-            }
-            other => {
-                tracing::warn!("Climate poll: unknown data source type '{}' for zone '{}'", other, zone_name);
-                continue;
-            }
-        };
+                .map(|h| super::RawReading {
+                    temperature_c: h.temperature_c,
+                    humidity_pct: h.humidity_pct,
+                    vpd_kpa: Some(super::calculate_vpd(h.temperature_c, h.humidity_pct)),
+                    precipitation_mm: Some(h.precipitation_mm),
+                    ..Default::default()
+                })
+        }
+        other => {
+            tracing::warn!("Climate poll: unknown data source type '{}' for zone '{}'", other, zone_name);
+            return None;
+        }
+    };
 
-        match reading {
-            Ok(raw) => {
-                store_reading(db, zone_id, zone_name, &raw, source_type).await;
-            }
-            Err(e) => {
-                tracing::warn!("Climate poll: failed to fetch reading for zone '{}': {}", zone_name, e);
-            }
+    match reading {
+        Ok(raw) => Some(PendingReading::for_zone(&zone.id, zone_name, zone.location_type.as_deref(), &raw, source_type)),
+        Err(e) => {
+            tracing::warn!("Climate poll: failed to fetch reading for zone '{}': {}", zone_name, e);
+            None
         }
     }
 }
 
 /// **What is it?**
-/// A shared helper function that inserts a climate reading into the database for a specific zone.
+/// A fetched reading waiting to be written, already tied to its zone and source.
 ///
 /// **Why does it exist?**
-/// It exists to deduplicate the database insertion logic across both Phase A (shared hardware) and Phase B (legacy single sensors).
+/// It exists so pollers can gather readings from many devices first and write them in a few batched transactions instead of one round trip per zone.
 ///
 /// **How should it be used?**
-/// Call this after successfully obtaining a `RawReading` from a data source, providing the target zone ID and name.
-async fn store_reading(
-    db: &surrealdb::Surreal<surrealdb::engine::remote::ws::Client>,
-    zone_id: &surrealdb::types::RecordId,
-    zone_name: &str,
-    raw: &super::RawReading,
-    source: &str,
-) {
-    if let Err(e) = db
-        .query(
-            "CREATE climate_reading SET \
-             zone = $zone_id, zone_name = $zone_name, \
-             temperature = $temp, humidity = $humidity, \
-             vpd = $vpd, precipitation = $precip, \
-             source = $source, recorded_at = time::now()",
+/// Build with `PendingReading::for_zone` as readings arrive, then pass the collected list to `store_readings`.
+#[derive(Clone)]
+pub(crate) struct PendingReading {
+    zone_id: surrealdb::types::RecordId,
+    zone_name: String,
+    raw: super::RawReading,
+    source: String,
+    /// When it was fetched, so a reading written late still lands at the right time.
+    recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PendingReading {
+    /// Ties a reading to a zone, dropping outdoor-only metrics (UV, solar radiation, wind) for zones that are not outdoors.
+    /// Those describe the open sky, not the inside of a greenhouse or windowsill zone that happens to share a weather station.
+    pub(crate) fn for_zone(
+        zone_id: &surrealdb::types::RecordId,
+        zone_name: &str,
+        location_type: Option<&str>,
+        raw: &super::RawReading,
+        source: &str,
+    ) -> Self {
+        let raw = if location_type == Some("Outdoor") || !raw.has_outdoor_metrics() {
+            raw.clone()
+        } else {
+            raw.without_outdoor_metrics()
+        };
+        PendingReading {
+            zone_id: zone_id.clone(),
+            zone_name: zone_name.to_string(),
+            raw,
+            source: source.to_string(),
+            recorded_at: chrono::Utc::now(),
+        }
+    }
+}
+
+impl PendingReading {
+    /// One log line's worth: zone, source and every metric the reading carries.
+    pub(crate) fn describe(&self) -> String {
+        let raw = &self.raw;
+        let mut parts = vec![format!("{:.1}C", raw.temperature_c), format!("{:.1}%", raw.humidity_pct)];
+        let extras = [
+            ("kPa VPD", raw.vpd_kpa),
+            ("mm rain", raw.precipitation_mm),
+            ("UV", raw.uv_index),
+            ("W/m2", raw.solar_radiation_wm2),
+            ("m/s wind", raw.wind_avg_ms),
+            ("m/s gust", raw.wind_gust_ms),
+            ("lux", raw.lux),
+            ("PPFD", raw.ppfd),
+            ("ppm CO2", raw.co2_ppm),
+            ("% substrate", raw.substrate_moisture_pct),
+        ];
+        parts.extend(extras.iter().filter_map(|(unit, value)| value.map(|v| format!("{:.2} {}", v, unit))));
+        format!(
+            "'{}' ({}, {}): {}",
+            self.zone_name,
+            crate::server_fns::auth::record_id_to_string(&self.zone_id),
+            self.source,
+            parts.join(", ")
         )
-        .bind(("zone_id", zone_id.clone()))
-        .bind(("zone_name", zone_name.to_string()))
-        .bind(("temp", raw.temperature_c))
-        .bind(("humidity", raw.humidity_pct))
-        .bind(("vpd", raw.vpd_kpa))
-        .bind(("precip", raw.precipitation_mm))
-        .bind(("source", source.to_string()))
-        .await
-    {
-        tracing::warn!("Climate poll: failed to store reading for zone '{}': {}", zone_name, e);
-    } else {
-        tracing::info!(
-            "Climate poll: stored reading for '{}': {:.1}C, {:.1}%",
-            zone_name,
-            raw.temperature_c,
-            raw.humidity_pct
-        );
+    }
+}
+
+/// Builds one transaction creating `count` climate readings, bound as `$zone_0`, `$temp_0`, ... `$at_{count-1}`.
+fn batch_insert_sql(count: usize) -> String {
+    let mut sql = String::from("BEGIN TRANSACTION; ");
+    for i in 0..count {
+        sql.push_str(&format!(
+            "CREATE climate_reading SET \
+             zone = $zone_{i}, zone_name = $zone_name_{i}, \
+             temperature = $temp_{i}, humidity = $humidity_{i}, \
+             vpd = $vpd_{i}, dew_point = $dew_{i}, precipitation = $precip_{i}, \
+             uv_index = $uv_{i}, solar_radiation = $solar_{i}, \
+             wind_speed = $wind_{i}, wind_gust = $gust_{i}, \
+             lux = $lux_{i}, ppfd = $ppfd_{i}, \
+             co2_ppm = $co2_{i}, substrate_moisture_pct = $moisture_{i}, \
+             source = $source_{i}, recorded_at = $at_{i}; "
+        ));
+    }
+    sql.push_str("COMMIT TRANSACTION;");
+    sql
+}
+
+/// **What is it?**
+/// What one `store_readings` call did with the readings passed to it.
+///
+/// **Why does it exist?**
+/// It exists so callers that report on a write, like the ingest endpoint and `Command::Poll`, can say whether readings were stored, rejected or held instead of assuming success.
+///
+/// **How should it be used?**
+/// Read the counts after `store_readings`; readings held from earlier calls are not counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct StoreOutcome {
+    /// Written to the database.
+    pub stored: usize,
+    /// Rejected by the database and dropped.
+    pub rejected: usize,
+    /// Held for the next call because the database couldn't be reached.
+    pub held: usize,
+}
+
+/// **What is it?**
+/// A shared helper that inserts climate readings into the database in batched transactions.
+///
+/// **Why does it exist?**
+/// It exists to deduplicate the insertion logic across both polling phases, MQTT, and HTTP ingestion, and to cut a cycle's writes from one round trip per zone to one per `WRITE_BATCH_SIZE` readings.
+///
+/// **How should it be used?**
+/// Call this once per cycle with every reading gathered. A batch the database rejects is logged and dropped; when the database can't be reached at all, that batch and the rest are held (up to `MAX_UNSTORED_READINGS`) and written first on the next call, so an outage delays readings instead of losing them. The returned `StoreOutcome` covers only `readings`.
+pub(crate) async fn store_readings(db: &Db, readings: &[PendingReading]) -> StoreOutcome {
+    let held: Vec<PendingReading> = match UNSTORED.lock() {
+        Ok(mut unstored) => unstored.drain(..).collect(),
+        Err(_) => Vec::new(),
+    };
+    if !held.is_empty() {
+        tracing::info!("Climate poll: retrying {} readings held while the database was unreachable", held.len());
+    }
+    let held_count = held.len();
+    let all: Vec<PendingReading> = held.into_iter().chain(readings.iter().cloned()).collect();
+    // How many of `all[start..end]` came from this call rather than an earlier one
+    let own = |start: usize, end: usize| end.saturating_sub(start.max(held_count));
+
+    let mut outcome = StoreOutcome::default();
+    let mut start = 0;
+    let mut batches = all.chunks(WRITE_BATCH_SIZE);
+    while let Some(batch) = batches.next() {
+        let end = start + batch.len();
+        let mut query = db.query(batch_insert_sql(batch.len()));
+        for (i, r) in batch.iter().enumerate() {
+            query = query
+                .bind((format!("zone_{i}"), r.zone_id.clone()))
+                .bind((format!("zone_name_{i}"), r.zone_name.clone()))
+                .bind((format!("temp_{i}"), r.raw.temperature_c))
+                .bind((format!("humidity_{i}"), r.raw.humidity_pct))
+                .bind((format!("vpd_{i}"), r.raw.vpd_kpa))
+                .bind((format!("dew_{i}"), super::calculate_dew_point(r.raw.temperature_c, r.raw.humidity_pct)))
+                .bind((format!("precip_{i}"), r.raw.precipitation_mm))
+                .bind((format!("uv_{i}"), r.raw.uv_index))
+                .bind((format!("solar_{i}"), r.raw.solar_radiation_wm2))
+                .bind((format!("wind_{i}"), r.raw.wind_avg_ms))
+                .bind((format!("gust_{i}"), r.raw.wind_gust_ms))
+                .bind((format!("lux_{i}"), r.raw.lux))
+                .bind((format!("ppfd_{i}"), r.raw.ppfd))
+                .bind((format!("co2_{i}"), r.raw.co2_ppm))
+                .bind((format!("moisture_{i}"), r.raw.substrate_moisture_pct))
+                .bind((format!("source_{i}"), r.source.clone()))
+                .bind((format!("at_{i}"), r.recorded_at));
+        }
+
+        let names = batch.iter().map(|r| r.zone_name.as_str()).collect::<Vec<_>>().join(", ");
+        match query.await {
+            Ok(mut response) => {
+                let errors = response.take_errors();
+                if errors.is_empty() {
+                    for r in batch {
+                        crate::server_fns::climate::invalidate_latest_reading(&crate::server_fns::auth::record_id_to_string(&r.zone_id));
+                    }
+                    tracing::info!("Climate poll: stored {} readings ({})", batch.len(), names);
+                    outcome.stored += own(start, end);
+                } else {
+                    tracing::warn!("Climate poll: failed to store readings for {}: {:?}", names, errors);
+                    outcome.rejected += own(start, end);
+                }
+            }
+            Err(e) => {
+                // The database is down or unreachable: hold this batch and the rest rather than
+                // sending each one into the same failure
+                let unsent: Vec<PendingReading> = batch.iter().chain(batches.flatten()).cloned().collect();
+                tracing::warn!("Climate poll: database unreachable ({}); holding {} readings for the next attempt", e, unsent.len());
+                hold_unstored(unsent);
+                outcome.held += own(start, all.len());
+                return outcome;
+            }
+        }
+        start = end;
+    }
+    outcome
+}
+
+/// Queues readings for the next `store_readings` call, dropping the oldest past `MAX_UNSTORED_READINGS`.
+fn hold_unstored(readings: Vec<PendingReading>) {
+    let Ok(mut unstored) = UNSTORED.lock() else { return };
+    unstored.extend(readings);
+    let overflow = unstored.len().saturating_sub(MAX_UNSTORED_READINGS);
+    if overflow > 0 {
+        unstored.drain(..overflow);
+        tracing::warn!("Climate poll: dropped the {} oldest held readings; the database has been unreachable too long", overflow);
     }
 }
 
@@ -356,6 +620,8 @@ struct DeviceZoneRow {
     name: String,
     #[surreal(default)]
     hardware_port: Option<i32>,
+    #[surreal(default)]
+    location_type: Option<String>,
 }
 
 #[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
@@ -365,6 +631,8 @@ struct ZoneRow {
     name: String,
     data_source_type: Option<String>,
     data_source_config: String,
+    #[surreal(default)]
+    location_type: Option<String>,
 }
 
 /// **What is it?**
@@ -379,10 +647,28 @@ struct ZoneRow {
 pub struct TempestConfig {
     /// The station's unique identifier.
     pub station_id: String,
+    /// Additional stations on the same WeatherFlow account.
+    #[serde(default)]
+    pub station_ids: Vec<String>,
     /// API token for accessing the station data.
     pub token: String,
 }
 
+impl TempestConfig {
+    /// All configured station IDs, primary first, without blanks or duplicates.
+    /// Zones linked to the device select one of these by 1-based `hardware_port`.
+    pub fn stations(&self) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        for id in std::iter::once(&self.station_id).chain(self.station_ids.iter()) {
+            let id = id.trim();
+            if !id.is_empty() && !out.iter().any(|s| s == id) {
+                out.push(id.to_string());
+            }
+        }
+        out
+    }
+}
+
 /// **What is it?**
 /// A struct representing the deserialized configuration for an AC Infinity controller.
 ///
@@ -423,3 +709,34 @@ pub struct WeatherApiConfig {
     /// Longitude coordinate.
     pub longitude: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_insert_sql_binds_each_reading() {
+        let sql = batch_insert_sql(3);
+        assert!(sql.starts_with("BEGIN TRANSACTION;"));
+        assert!(sql.ends_with("COMMIT TRANSACTION;"));
+        assert_eq!(sql.matches("CREATE climate_reading").count(), 3);
+        assert!(sql.contains("$zone_2") && sql.contains("$dew_2") && sql.contains("$source_2") && sql.contains("$at_2"));
+        assert!(!sql.contains("$zone_3"));
+    }
+
+    #[test]
+    fn test_pending_reading_strips_outdoor_metrics_indoors() {
+        let zone = surrealdb::types::RecordId::parse_simple("growing_zone:a").unwrap();
+        let raw = super::super::RawReading { temperature_c: 20.0, humidity_pct: 50.0, uv_index: Some(6.0), lux: Some(40000.0), ..Default::default() };
+        let indoor = PendingReading::for_zone(&zone, "Shelf", Some("Indoor"), &raw, "tempest");
+        assert_eq!(indoor.raw.uv_index, None);
+        assert_eq!(indoor.raw.lux, None);
+        let outdoor = PendingReading::for_zone(&zone, "Patio", Some("Outdoor"), &raw, "tempest");
+        assert_eq!(outdoor.raw.uv_index, Some(6.0));
+        assert_eq!(outdoor.raw.lux, Some(40000.0));
+        // A light sensor's own reading is kept indoors
+        let sensor = super::super::RawReading { temperature_c: 20.0, humidity_pct: 50.0, lux: Some(8000.0), ..Default::default() };
+        let shelf = PendingReading::for_zone(&zone, "Shelf", Some("Indoor"), &sensor, "mqtt");
+        assert_eq!(shelf.raw.lux, Some(8000.0));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::config::config;
+use crate::db::db;
+
+/// Summarizes raw readings older than `$raw_days` into hourly rows and deletes them, in one
+/// transaction so a failed run neither leaves duplicate summaries nor drops unsummarized readings.
+const HOURLY_ROLLUP_SQL: &str = "BEGIN TRANSACTION; \
+     LET $cutoff = time::floor(time::now() - duration::from_days($raw_days), 1h); \
+     LET $groups = (SELECT \
+         zone, zone_name, \
+         time::floor(recorded_at, 1h) AS period_start, \
+         math::mean(temperature) AS avg_temperature, \
+         math::min(temperature) AS min_temperature, \
+         math::max(temperature) AS max_temperature, \
+         math::mean(humidity) AS avg_humidity, \
+         math::min(humidity) AS min_humidity, \
+         math::max(humidity) AS max_humidity, \
+         count() AS sample_count \
+     FROM climate_reading \
+     WHERE recorded_at < $cutoff \
+     GROUP BY zone, zone_name, period_start); \
+     FOR $g IN $groups { \
+         CREATE climate_reading_summary SET \
+             zone = $g.zone, zone_name = $g.zone_name, \
+             period_type = 'hourly', period_start = $g.period_start, \
+             avg_temperature = $g.avg_temperature, \
+             min_temperature = $g.min_temperature, \
+             max_temperature = $g.max_temperature, \
+             avg_humidity = $g.avg_humidity, \
+             min_humidity = $g.min_humidity, \
+             max_humidity = $g.max_humidity, \
+             sample_count = $g.sample_count; \
+     }; \
+     DELETE climate_reading WHERE recorded_at < $cutoff; \
+     COMMIT TRANSACTION;";
+
+/// Folds hourly summaries older than `$hourly_days` into daily rows and deletes them, in one
+/// transaction for the same reason as `HOURLY_ROLLUP_SQL`.
+const DAILY_ROLLUP_SQL: &str = "BEGIN TRANSACTION; \
+     LET $cutoff = time::floor(time::now() - duration::from_days($hourly_days), 1d); \
+     LET $groups = (SELECT \
+         zone, zone_name, \
+         time::floor(period_start, 1d) AS day_start, \
+         math::sum(avg_temperature * sample_count) / math::sum(sample_count) AS avg_temperature, \
+         math::min(min_temperature) AS min_temperature, \
+         math::max(max_temperature) AS max_temperature, \
+         math::sum(avg_humidity * sample_count) / math::sum(sample_count) AS avg_humidity, \
+         math::min(min_humidity) AS min_humidity, \
+         math::max(max_humidity) AS max_humidity, \
+         math::sum(sample_count) AS sample_count \
+     FROM climate_reading_summary \
+     WHERE period_type = 'hourly' AND period_start < $cutoff \
+     GROUP BY zone, zone_name, day_start); \
+     FOR $g IN $groups { \
+         CREATE climate_reading_summary SET \
+             zone = $g.zone, zone_name = $g.zone_name, \
+             period_type = 'daily', period_start = $g.day_start, \
+             avg_temperature = $g.avg_temperature, \
+             min_temperature = $g.min_temperature, \
+             max_temperature = $g.max_temperature, \
+             avg_humidity = $g.avg_humidity, \
+             min_humidity = $g.min_humidity, \
+             max_humidity = $g.max_humidity, \
+             sample_count = $g.sample_count; \
+     }; \
+     DELETE climate_reading_summary WHERE period_type = 'hourly' AND period_start < $cutoff; \
+     COMMIT TRANSACTION;";
+
+/// **What is it?**
+/// A background job that compacts old climate readings into hourly and then daily min/avg/max summaries, and drops data past the retention policy.
+///
+/// **Why does it exist?**
+/// It exists because 30-minute polling adds ~17,500 rows per zone per year, which makes long-range chart queries slow; old data only needs its shape, not every sample.
+///
+/// **How should it be used?**
+/// Run it daily from a background task. Windows come from `CLIMATE_RAW_RETENTION_DAYS`, `CLIMATE_HOURLY_RETENTION_DAYS`, and `CLIMATE_DAILY_RETENTION_DAYS`. Cutoffs are aligned to whole hours/days so a bucket is never summarized twice. This is the only job that deletes raw readings, so they are always summarized first.
+pub async fn compact_climate_readings() {
+    let cfg = config();
+    let db = db();
+
+    // --- Raw → Hourly ---
+    let compact_hourly = db
+        .query(HOURLY_ROLLUP_SQL)
+        .bind(("raw_days", cfg.climate_raw_retention_days as i64))
+        .await;
+
+    match compact_hourly {
+        Ok(mut resp) => {
+            let errors = resp.take_errors();
+            if !errors.is_empty() {
+                tracing::warn!("Climate compact: raw→hourly errors: {:?}", errors);
+            }
+        }
+        Err(e) => tracing::warn!("Climate compact: raw→hourly failed: {}", e),
+    }
+
+    // --- Hourly → Daily ---
+    let compact_daily = db
+        .query(DAILY_ROLLUP_SQL)
+        .bind(("hourly_days", cfg.climate_hourly_retention_days as i64))
+        .await;
+
+    match compact_daily {
+        Ok(mut resp) => {
+            let errors = resp.take_errors();
+            if !errors.is_empty() {
+                tracing::warn!("Climate compact: hourly→daily errors: {:?}", errors);
+            }
+        }
+        Err(e) => tracing::warn!("Climate compact: hourly→daily failed: {}", e),
+    }
+
+    // --- Daily retention (0 = keep forever) ---
+    if cfg.climate_daily_retention_days > 0 {
+        let expire = db
+            .query("DELETE climate_reading_summary WHERE period_type = 'daily' AND period_start < time::now() - duration::from_days($daily_days)")
+            .bind(("daily_days", cfg.climate_daily_retention_days as i64))
+            .await;
+        if let Err(e) = expire {
+            tracing::warn!("Climate compact: daily expiry failed: {}", e);
+        }
+    }
+
+    tracing::info!("Climate compaction completed");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealdb::engine::local::Mem;
+    use surrealdb::Surreal;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct SummaryRow {
+        period_type: String,
+        avg_temperature: f64,
+        min_temperature: f64,
+        max_temperature: f64,
+        sample_count: i64,
+    }
+
+    #[tokio::test]
+    async fn test_hourly_rollup_summarizes_readings_past_the_raw_window() {
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        db.query(include_str!("../../migrations/0003_climate_readings.surql")).await.unwrap();
+        db.query(include_str!("../../migrations/0039_climate_rollups.surql")).await.unwrap();
+
+        let mut resp = db
+            .query(
+                "LET $hour = time::floor(time::now() - 10d, 1h); \
+                 CREATE climate_reading SET zone = growing_zone:a, zone_name = 'A', temperature = 20.0, humidity = 50.0, recorded_at = $hour + 5m; \
+                 CREATE climate_reading SET zone = growing_zone:a, zone_name = 'A', temperature = 24.0, humidity = 70.0, recorded_at = $hour + 35m; \
+                 CREATE climate_reading SET zone = growing_zone:a, zone_name = 'A', temperature = 22.0, humidity = 60.0, recorded_at = time::now() - 1h;"
+            )
+            .await
+            .unwrap();
+        assert!(resp.take_errors().is_empty());
+
+        let mut resp = db.query(HOURLY_ROLLUP_SQL).bind(("raw_days", 7i64)).await.unwrap();
+        let errors = resp.take_errors();
+        assert!(errors.is_empty(), "rollup failed: {:?}", errors);
+
+        let mut resp = db.query("SELECT * FROM climate_reading_summary").await.unwrap();
+        let summaries: Vec<SummaryRow> = resp.take(0).unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].period_type, "hourly");
+        assert_eq!(summaries[0].sample_count, 2);
+        assert_eq!(summaries[0].avg_temperature, 22.0);
+        assert_eq!(summaries[0].min_temperature, 20.0);
+        assert_eq!(summaries[0].max_temperature, 24.0);
+
+        // Only the reading inside the raw window is left
+        let mut resp = db.query("SELECT VALUE temperature FROM climate_reading").await.unwrap();
+        let remaining: Vec<f64> = resp.take(0).unwrap();
+        assert_eq!(remaining, vec![22.0]);
+
+        // A second run finds nothing new to summarize
+        let mut resp = db.query(HOURLY_ROLLUP_SQL).bind(("raw_days", 7i64)).await.unwrap();
+        assert!(resp.take_errors().is_empty());
+        let mut resp = db.query("SELECT * FROM climate_reading_summary").await.unwrap();
+        let summaries: Vec<SummaryRow> = resp.take(0).unwrap();
+        assert_eq!(summaries.len(), 1);
+    }
+}
@@ -40,7 +40,7 @@ pub async fn check_seasonal_alerts() {
 
     // 1. Fetch all orchids with seasonal data
     let mut orchid_resp = match db()
-        .query("SELECT id, owner, name, rest_start_month, rest_end_month, bloom_start_month, bloom_end_month FROM orchid WHERE rest_start_month IS NOT NULL OR bloom_start_month IS NOT NULL")
+        .query("SELECT id, owner, name, rest_start_month, rest_end_month, bloom_start_month, bloom_end_month FROM orchid WHERE archived_at = NONE AND (rest_start_month IS NOT NULL OR bloom_start_month IS NOT NULL)")
         .await
     {
         Ok(r) => r,
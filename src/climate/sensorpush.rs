@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use super::{calculate_vpd, RawReading};
+use crate::error::AppError;
+
+const API_BASE: &str = "https://api.sensorpush.com/api/v1";
+
+/// **What is it?**
+/// A struct representing the deserialized configuration for a SensorPush account device.
+///
+/// **Why does it exist?**
+/// It exists to unpack the cloud login and the sensors chosen during discovery, stored (encrypted) on a `hardware_device` of type `sensorpush`.
+///
+/// **How should it be used?**
+/// Deserialize the decrypted device config into this struct. Zones linked to the device pick a sensor by 1-based `hardware_port`, like Tempest stations.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct SensorPushConfig {
+    /// SensorPush account email.
+    pub email: String,
+    /// SensorPush account password.
+    pub password: String,
+    /// Sensors chosen on this account, in the order zones refer to them.
+    #[serde(default)]
+    pub sensors: Vec<SensorPushSensor>,
+}
+
+/// A sensor on a SensorPush account, as discovered from the cloud API.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SensorPushSensor {
+    /// The sensor ID (e.g. "12345.67890123456789").
+    pub id: String,
+    /// The name given to the sensor in the SensorPush app.
+    #[serde(default)]
+    pub name: String,
+}
+
+impl SensorPushConfig {
+    /// IDs of the configured sensors, in port order.
+    pub fn sensor_ids(&self) -> Vec<String> {
+        self.sensors.iter().map(|s| s.id.clone()).collect()
+    }
+}
+
+/// Logs in with the two-step OAuth flow and returns a short-lived access token.
+async fn access_token(client: &reqwest::Client, email: &str, password: &str) -> Result<String, AppError> {
+    let auth_json = post_json(client, "oauth/authorize", None, serde_json::json!({
+        "email": email,
+        "password": password,
+    })).await?;
+    let authorization = auth_json.get("authorization")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| AppError::Auth("SensorPush login failed: no authorization in response".into()))?;
+
+    let token_json = post_json(client, "oauth/accesstoken", None, serde_json::json!({
+        "authorization": authorization,
+    })).await?;
+    token_json.get("accesstoken")
+        .and_then(|v| v.as_str())
+        .map(String::from)
+        .ok_or_else(|| AppError::Auth("SensorPush login failed: no access token in response".into()))
+}
+
+/// POSTs a JSON body to the SensorPush API, returning the parsed JSON response.
+async fn post_json(
+    client: &reqwest::Client,
+    path: &str,
+    token: Option<&str>,
+    body: serde_json::Value,
+) -> Result<serde_json::Value, AppError> {
+    let mut req = client.post(format!("{}/{}", API_BASE, path)).json(&body);
+    if let Some(token) = token {
+        // The API expects the bare token, without a "Bearer" prefix
+        req = req.header("Authorization", token);
+    }
+
+    let resp = req.send()
+        .await
+        .map_err(|e| AppError::Network(format!("SensorPush {} request failed: {}", path, e)))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(AppError::Auth(format!("SensorPush rejected the login ({})", status)));
+        }
+        return Err(AppError::Network(format!("SensorPush {} API error {}: {}", path, status, body)));
+    }
+
+    resp.json()
+        .await
+        .map_err(|e| AppError::Serialization(format!("SensorPush {} parse error: {}", path, e)))
+}
+
+/// **What is it?**
+/// A function that lists every active sensor on a SensorPush account.
+///
+/// **Why does it exist?**
+/// It exists so users can pick sensors by the names they gave them in the SensorPush app instead of copying long sensor IDs.
+///
+/// **How should it be used?**
+/// Call this from the device form's "Find Sensors" button or the "Test" flow. Returns sensors sorted by name.
+pub async fn fetch_sensorpush_sensors(
+    client: &reqwest::Client,
+    email: &str,
+    password: &str,
+) -> Result<Vec<SensorPushSensor>, AppError> {
+    let token = access_token(client, email, password).await?;
+    let json = post_json(client, "devices/sensors", Some(&token), serde_json::json!({})).await?;
+    Ok(parse_sensors(&json))
+}
+
+/// **What is it?**
+/// A function that fetches the latest sample from several SensorPush sensors in one request.
+///
+/// **Why does it exist?**
+/// It exists so a whole account's sensors cost one login and one samples call per poll, however many zones they feed.
+///
+/// **How should it be used?**
+/// Call this from the device-linked polling phase with the device's configured sensor IDs. Sensors that have not reported are missing from the map.
+pub async fn fetch_sensorpush_readings(
+    client: &reqwest::Client,
+    email: &str,
+    password: &str,
+    sensor_ids: &[String],
+) -> Result<HashMap<String, RawReading>, AppError> {
+    if sensor_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let token = access_token(client, email, password).await?;
+    let json = post_json(client, "samples", Some(&token), serde_json::json!({
+        "limit": 1,
+        "sensors": sensor_ids,
+    })).await?;
+    Ok(parse_samples(&json))
+}
+
+/// Extract active sensors from a `/devices/sensors` response, which maps sensor ID to details.
+fn parse_sensors(json: &serde_json::Value) -> Vec<SensorPushSensor> {
+    let mut sensors: Vec<SensorPushSensor> = json.as_object()
+        .map(|map| {
+            map.iter()
+                .filter(|(_, s)| s.get("active").and_then(|v| v.as_bool()).unwrap_or(true))
+                .map(|(id, s)| SensorPushSensor {
+                    id: s.get("id").and_then(|v| v.as_str()).unwrap_or(id).to_string(),
+                    name: s.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    sensors.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+    sensors
+}
+
+/// Extract the newest sample per sensor from a `/samples` response.
+/// SensorPush reports temperature in Fahrenheit and VPD in kPa; VPD is computed when absent.
+fn parse_samples(json: &serde_json::Value) -> HashMap<String, RawReading> {
+    let Some(sensors) = json.get("sensors").and_then(|s| s.as_object()) else {
+        return HashMap::new();
+    };
+
+    sensors.iter()
+        .filter_map(|(id, samples)| {
+            let sample = samples.as_array()?.first()?;
+            let temp_f = sample.get("temperature").and_then(|v| v.as_f64())?;
+            let humidity = sample.get("humidity").and_then(|v| v.as_f64())?;
+            let temp_c = (temp_f - 32.0) * 5.0 / 9.0;
+            let vpd = sample.get("vpd")
+                .and_then(|v| v.as_f64())
+                .unwrap_or_else(|| calculate_vpd(temp_c, humidity));
+            Some((id.clone(), RawReading {
+                temperature_c: temp_c,
+                humidity_pct: humidity,
+                vpd_kpa: Some(vpd),
+                ..Default::default()
+            }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sensors_skips_inactive_and_sorts_by_name() {
+        let json = serde_json::json!({
+            "111.1": { "id": "111.1", "name": "Orchidarium", "active": true },
+            "222.2": { "id": "222.2", "name": "Old tent", "active": false },
+            "333.3": { "id": "333.3", "name": "Greenhouse", "active": true },
+        });
+        let sensors = parse_sensors(&json);
+        assert_eq!(sensors.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), vec!["333.3", "111.1"]);
+    }
+
+    #[test]
+    fn test_parse_samples_converts_fahrenheit() {
+        let json = serde_json::json!({
+            "sensors": {
+                "111.1": [{ "observed": "2026-10-16T12:00:00.000Z", "temperature": 77.0, "humidity": 70.0, "vpd": 0.95 }],
+                "333.3": [{ "observed": "2026-10-16T12:00:00.000Z", "temperature": 68.0, "humidity": 60.0 }],
+                "444.4": [],
+            },
+            "truncated": false,
+        });
+        let readings = parse_samples(&json);
+        assert_eq!(readings.len(), 2);
+        let a = &readings["111.1"];
+        assert!((a.temperature_c - 25.0).abs() < 0.01);
+        assert_eq!(a.vpd_kpa, Some(0.95));
+        let b = &readings["333.3"];
+        assert!((b.temperature_c - 20.0).abs() < 0.01);
+        assert!(b.vpd_kpa.is_some_and(|v| v > 0.0));
+    }
+
+    #[test]
+    fn test_config_without_sensors() {
+        let config: SensorPushConfig = serde_json::from_str(r#"{"email":"a@b.c","password":"pw"}"#).unwrap();
+        assert!(config.sensor_ids().is_empty());
+    }
+}
@@ -44,44 +44,193 @@ pub async fn fetch_tempest_reading(
         .and_then(|o| o.get(0))
         .ok_or_else(|| AppError::Serialization("No observations in Tempest response".into()))?;
 
-    let (temp_c, humidity) = if obs.is_object() {
+    parse_tempest_observation(obs)
+}
+
+/// **What is it?**
+/// A pure parser that converts a single Tempest observation (`obs[0]`) into a `RawReading`.
+///
+/// **Why does it exist?**
+/// It exists so both Tempest response shapes — named keys and the positional `obs_st` array — are handled in one testable place, including the outdoor-only UV, solar radiation, wind, and brightness metrics.
+///
+/// **How should it be used?**
+/// Call this with the first element of the `obs` array from a station observation response. Temperature and humidity are required; the extended metrics are optional.
+pub fn parse_tempest_observation(obs: &serde_json::Value) -> Result<RawReading, AppError> {
+    let mut reading = if obs.is_object() {
         // Named-key format: {"air_temperature": 12.6, "relative_humidity": 60, ...}
-        let temp = obs.get("air_temperature")
-            .and_then(|v| v.as_f64())
+        let field = |key: &str| obs.get(key).and_then(|v| v.as_f64());
+        let temp = field("air_temperature")
             .ok_or_else(|| AppError::Serialization(
                 "Missing 'air_temperature' in Tempest observation".into(),
             ))?;
-        let hum = obs.get("relative_humidity")
-            .and_then(|v| v.as_f64())
+        let hum = field("relative_humidity")
             .ok_or_else(|| AppError::Serialization(
                 "Missing 'relative_humidity' in Tempest observation".into(),
             ))?;
-        (temp, hum)
+        RawReading {
+            temperature_c: temp,
+            humidity_pct: hum,
+            uv_index: field("uv"),
+            solar_radiation_wm2: field("solar_radiation"),
+            wind_avg_ms: field("wind_avg"),
+            wind_gust_ms: field("wind_gust"),
+            lux: field("brightness"),
+            ..Default::default()
+        }
     } else if let Some(arr) = obs.as_array() {
-        // Positional array format: index 7 = temperature, index 8 = humidity
-        let temp = arr.get(7)
-            .and_then(|v| v.as_f64())
+        // Positional obs_st format: 2 = wind avg, 3 = wind gust, 7 = temperature,
+        // 8 = humidity, 9 = illuminance (lux), 10 = UV index, 11 = solar radiation
+        let field = |idx: usize| arr.get(idx).and_then(|v| v.as_f64());
+        let temp = field(7)
             .ok_or_else(|| AppError::Serialization(format!(
                 "Missing temperature at index 7 (array length={})", arr.len(),
             )))?;
-        let hum = arr.get(8)
-            .and_then(|v| v.as_f64())
+        let hum = field(8)
             .ok_or_else(|| AppError::Serialization(format!(
                 "Missing humidity at index 8 (array length={})", arr.len(),
             )))?;
-        (temp, hum)
+        RawReading {
+            temperature_c: temp,
+            humidity_pct: hum,
+            uv_index: field(10),
+            solar_radiation_wm2: field(11),
+            wind_avg_ms: field(2),
+            wind_gust_ms: field(3),
+            lux: field(9),
+            ..Default::default()
+        }
     } else {
         return Err(AppError::Serialization(format!(
             "Unexpected obs[0] type: {:?}", obs
         )));
     };
 
-    let vpd = calculate_vpd(temp_c, humidity);
+    reading.vpd_kpa = Some(calculate_vpd(reading.temperature_c, reading.humidity_pct));
+    Ok(reading)
+}
+
+/// **What is it?**
+/// A function that lists every station registered to a WeatherFlow account.
+///
+/// **Why does it exist?**
+/// It exists because a single personal access token can cover several stations, and users need to see which station IDs are available when setting up a multi-station device.
+///
+/// **How should it be used?**
+/// Call this from the device "Test Connection" flow with the account token. Returns `(station_id, name)` pairs in the order the API reports them.
+pub async fn fetch_tempest_stations(
+    client: &reqwest::Client,
+    token: &str,
+) -> Result<Vec<(String, String)>, AppError> {
+    let url = format!("https://swd.weatherflow.com/swd/rest/stations?token={}", token);
+
+    let resp = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| AppError::Network(format!("Tempest stations request failed: {}", e)))?;
 
-    Ok(RawReading {
-        temperature_c: temp_c,
-        humidity_pct: humidity,
-        vpd_kpa: Some(vpd),
-        precipitation_mm: None,
-    })
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(AppError::Network(format!(
+            "Tempest stations API error {}: {}",
+            status, body
+        )));
+    }
+
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| AppError::Serialization(format!("Tempest stations parse error: {}", e)))?;
+
+    Ok(parse_tempest_stations(&json))
+}
+
+/// Extract `(station_id, name)` pairs from a `/stations` response body.
+fn parse_tempest_stations(json: &serde_json::Value) -> Vec<(String, String)> {
+    json.get("stations")
+        .and_then(|s| s.as_array())
+        .map(|stations| {
+            stations
+                .iter()
+                .filter_map(|st| {
+                    let id = st.get("station_id").and_then(|v| {
+                        v.as_u64().map(|n| n.to_string()).or_else(|| v.as_str().map(String::from))
+                    })?;
+                    let name = st.get("name").and_then(|v| v.as_str()).unwrap_or_default();
+                    Some((id, name.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_observation_with_extended_metrics() {
+        let obs = serde_json::json!({
+            "air_temperature": 21.5,
+            "relative_humidity": 64.0,
+            "uv": 3.2,
+            "solar_radiation": 412.0,
+            "wind_avg": 1.8,
+            "wind_gust": 4.1,
+            "brightness": 49500
+        });
+        let r = parse_tempest_observation(&obs).unwrap();
+        assert_eq!(r.temperature_c, 21.5);
+        assert_eq!(r.humidity_pct, 64.0);
+        assert_eq!(r.uv_index, Some(3.2));
+        assert_eq!(r.solar_radiation_wm2, Some(412.0));
+        assert_eq!(r.wind_avg_ms, Some(1.8));
+        assert_eq!(r.wind_gust_ms, Some(4.1));
+        assert_eq!(r.lux, Some(49500.0));
+        assert!(r.vpd_kpa.is_some());
+    }
+
+    #[test]
+    fn test_parse_positional_observation() {
+        let obs = serde_json::json!([
+            1700000000, 0.5, 1.2, 3.4, 180, 3, 1012.0, 18.0, 72.0, 25000, 1.5, 210.0, 0.0
+        ]);
+        let r = parse_tempest_observation(&obs).unwrap();
+        assert_eq!(r.temperature_c, 18.0);
+        assert_eq!(r.humidity_pct, 72.0);
+        assert_eq!(r.wind_avg_ms, Some(1.2));
+        assert_eq!(r.wind_gust_ms, Some(3.4));
+        assert_eq!(r.uv_index, Some(1.5));
+        assert_eq!(r.solar_radiation_wm2, Some(210.0));
+        assert_eq!(r.lux, Some(25000.0));
+    }
+
+    #[test]
+    fn test_parse_observation_without_extended_metrics() {
+        let obs = serde_json::json!({"air_temperature": 20.0, "relative_humidity": 50.0});
+        let r = parse_tempest_observation(&obs).unwrap();
+        assert!(!r.has_outdoor_metrics());
+    }
+
+    #[test]
+    fn test_parse_observation_missing_temperature_fails() {
+        let obs = serde_json::json!({"relative_humidity": 50.0});
+        assert!(parse_tempest_observation(&obs).is_err());
+    }
+
+    #[test]
+    fn test_parse_stations_list() {
+        let json = serde_json::json!({
+            "stations": [
+                {"station_id": 101, "name": "Greenhouse"},
+                {"station_id": 202, "name": "Shade House"}
+            ]
+        });
+        let stations = parse_tempest_stations(&json);
+        assert_eq!(stations, vec![
+            ("101".to_string(), "Greenhouse".to_string()),
+            ("202".to_string(), "Shade House".to_string()),
+        ]);
+    }
 }
@@ -0,0 +1,95 @@
+use chrono::{Datelike, Utc, Weekday};
+
+/// **What is it?**
+/// A background task that pushes each opted-in user's weekly climate report cards once per ISO week.
+///
+/// **Why does it exist?**
+/// It exists so growers who set zone goals get a Monday summary (grade and time in range per zone) without opening the dashboard.
+///
+/// **How should it be used?**
+/// Spawn this in the main background loop every few hours. It only sends on Mondays and records the ISO week on `user_preference` so restarts never double-send.
+pub async fn send_weekly_reports() {
+    use crate::db::db;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        owner: surrealdb::types::RecordId,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PushSubRow {
+        endpoint: String,
+        p256dh: String,
+        auth: String,
+    }
+
+    let now = Utc::now();
+    if now.weekday() != Weekday::Mon {
+        return;
+    }
+    let iso = now.iso_week();
+    let week_tag = format!("{}-W{:02}", iso.year(), iso.week());
+
+    // 1. Users who opted in and haven't been sent this week's cards
+    let mut pref_resp = match db()
+        .query("SELECT owner FROM user_preference WHERE weekly_report_push = true AND (weekly_report_sent_week IS NONE OR weekly_report_sent_week != $week)")
+        .bind(("week", week_tag.clone()))
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("Weekly reports: failed to query prefs: {}", e);
+            return;
+        }
+    };
+    let _ = pref_resp.take_errors();
+    let prefs: Vec<PrefRow> = pref_resp.take(0).unwrap_or_default();
+
+    for pref in prefs {
+        // Mark first so a failing push can't cause a resend loop
+        let _ = db()
+            .query("UPDATE user_preference SET weekly_report_sent_week = $week WHERE owner = $owner")
+            .bind(("owner", pref.owner.clone()))
+            .bind(("week", week_tag.clone()))
+            .await;
+
+        let cards = match crate::server_fns::goals::report_cards_for_owner(&pref.owner).await {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!("Weekly reports: failed to build cards: {}", e);
+                continue;
+            }
+        };
+        if cards.is_empty() {
+            continue;
+        }
+
+        let body = cards.iter().map(|c| c.summary_line()).collect::<Vec<_>>().join("\n");
+        crate::push::send_ntfy_to_user(&pref.owner, "Weekly Climate Report", &body).await;
+
+        let mut sub_resp = match db()
+            .query("SELECT endpoint, p256dh, auth FROM push_subscription WHERE owner = $owner")
+            .bind(("owner", pref.owner.clone()))
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        let _ = sub_resp.take_errors();
+        let subs: Vec<PushSubRow> = sub_resp.take(0).unwrap_or_default();
+
+        for sub in subs {
+            let push_sub = crate::push::PushSubscriptionRow {
+                endpoint: sub.endpoint,
+                p256dh: sub.p256dh,
+                auth: sub.auth,
+            };
+            if let Err(e) = crate::push::deliver_push(&pref.owner, &push_sub, "Weekly Climate Report", &body).await {
+                tracing::warn!("Weekly report push failed: {}", e);
+            }
+        }
+    }
+}
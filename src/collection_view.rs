@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use crate::orchid::{GrowingZone, Orchid};
+use crate::species_photos::genus_key;
+
+/// Most saved views a user can keep.
+pub const MAX_SAVED_VIEWS: usize = 12;
+/// Longest saved view name, in characters.
+pub const MAX_VIEW_NAME_LEN: usize = 40;
+/// Longest search a saved view can hold, in characters.
+pub const MAX_VIEW_SEARCH_LEN: usize = 100;
+
+/// The order of the collection grid and table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CollectionSort {
+    /// Newest first, the order plants load in.
+    #[default]
+    RecentlyAdded,
+    /// Most overdue first; plants never watered lead.
+    DueSoonest,
+    /// By name, A to Z.
+    Name,
+    /// By genus, then species and name.
+    Genus,
+    /// By zone, then name; plants with no zone last.
+    Zone,
+}
+
+impl CollectionSort {
+    /// Every sort, in the order the picker lists them.
+    pub const ALL: [CollectionSort; 5] = [
+        CollectionSort::RecentlyAdded,
+        CollectionSort::DueSoonest,
+        CollectionSort::Name,
+        CollectionSort::Genus,
+        CollectionSort::Zone,
+    ];
+
+    /// The picker's option value.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CollectionSort::RecentlyAdded => "recent",
+            CollectionSort::DueSoonest => "due",
+            CollectionSort::Name => "name",
+            CollectionSort::Genus => "genus",
+            CollectionSort::Zone => "zone",
+        }
+    }
+
+    /// Parses a picker value; anything unknown is the default order.
+    pub fn parse(s: &str) -> Self {
+        Self::ALL.into_iter().find(|sort| sort.as_str() == s).unwrap_or_default()
+    }
+
+    /// The picker's option text.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CollectionSort::RecentlyAdded => "Recently added",
+            CollectionSort::DueSoonest => "Due soonest",
+            CollectionSort::Name => "Name",
+            CollectionSort::Genus => "Genus",
+            CollectionSort::Zone => "Zone",
+        }
+    }
+}
+
+/// Which plants the collection shows.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionFilter {
+    /// Search text, matched by name, species or any alias; empty shows everything.
+    #[serde(default)]
+    pub search: String,
+    /// Only plants placed in the zone with this record ID, so a saved view keeps working after
+    /// the zone is renamed.
+    #[serde(default)]
+    pub zone: Option<String>,
+    /// Only plants due for water today or overdue.
+    #[serde(default)]
+    pub needs_water: bool,
+}
+
+impl CollectionFilter {
+    /// Whether the filter shows everything.
+    pub fn is_empty(&self) -> bool {
+        self.search.trim().is_empty() && self.zone.is_none() && !self.needs_water
+    }
+}
+
+/// A named sort and filter the user saved to get back to with one tap.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedView {
+    /// Shown on the view's button.
+    pub name: String,
+    /// The order.
+    #[serde(default)]
+    pub sort: CollectionSort,
+    /// The filter.
+    #[serde(default)]
+    pub filter: CollectionFilter,
+}
+
+/// The plants `filter` lets through, in `sort` order. `search_matches` holds the IDs matching the
+/// filter's search (from `SearchIndex::matching_ids`) and is ignored when the search is empty;
+/// `zones` resolves the filter's zone ID to the name plants are placed under (views saved before
+/// filters held IDs hold the zone name, which still resolves), and a zone that no longer exists
+/// matches nothing; `days_until_due` gives each plant's climate-adjusted days until
+/// watering, `None` if never watered. Ties keep the incoming order, so "Recently added" is the
+/// order plants arrive in.
+pub fn arrange(
+    orchids: Vec<Orchid>,
+    sort: CollectionSort,
+    filter: &CollectionFilter,
+    zones: &[GrowingZone],
+    search_matches: &HashSet<String>,
+    days_until_due: impl Fn(&Orchid) -> Option<i64>,
+) -> Vec<Orchid> {
+    let searching = !filter.search.trim().is_empty();
+    let zone_name = filter.zone.as_ref()
+        .map(|id| zones.iter().find(|z| z.id == *id || z.name == *id).map(|z| z.name.as_str()));
+    let mut shown: Vec<(Orchid, Option<i64>)> = orchids.into_iter()
+        .filter(|o| !searching || search_matches.contains(&o.id))
+        .filter(|o| zone_name.is_none_or(|name| name == Some(o.placement.as_str())))
+        .map(|o| {
+            let due = days_until_due(&o);
+            (o, due)
+        })
+        .filter(|(_, due)| !filter.needs_water || due.is_none_or(|days| days <= 0))
+        .collect();
+
+    let by_name = |a: &Orchid, b: &Orchid| a.name.to_lowercase().cmp(&b.name.to_lowercase());
+    match sort {
+        CollectionSort::RecentlyAdded => {}
+        CollectionSort::DueSoonest => shown.sort_by(|(a, a_due), (b, b_due)| {
+            // Never watered counts as most urgent, matching the Today list
+            let urgency = |due: &Option<i64>| due.unwrap_or(i64::MIN);
+            urgency(a_due).cmp(&urgency(b_due)).then_with(|| by_name(a, b))
+        }),
+        CollectionSort::Name => shown.sort_by(|(a, _), (b, _)| by_name(a, b)),
+        CollectionSort::Genus => shown.sort_by(|(a, _), (b, _)| {
+            genus_key(&a.species).cmp(&genus_key(&b.species))
+                .then_with(|| a.species.to_lowercase().cmp(&b.species.to_lowercase()))
+                .then_with(|| by_name(a, b))
+        }),
+        CollectionSort::Zone => shown.sort_by(|(a, _), (b, _)| {
+            match (a.placement.is_empty(), b.placement.is_empty()) {
+                (false, true) => Ordering::Less,
+                (true, false) => Ordering::Greater,
+                _ => a.placement.to_lowercase().cmp(&b.placement.to_lowercase()),
+            }
+            .then_with(|| by_name(a, b))
+        }),
+    }
+    shown.into_iter().map(|(o, _)| o).collect()
+}
+
+/// Validates and normalizes a saved view list: trims names and searches, and rejects empty or
+/// duplicate names and oversized input.
+pub fn sanitize_saved_views(views: Vec<SavedView>) -> Result<Vec<SavedView>, String> {
+    if views.len() > MAX_SAVED_VIEWS {
+        return Err(format!("At most {} saved views are allowed", MAX_SAVED_VIEWS));
+    }
+    let mut names = HashSet::new();
+    views.into_iter()
+        .map(|view| {
+            let name = view.name.trim().to_string();
+            if name.is_empty() {
+                return Err("Saved views need a name".to_string());
+            }
+            if name.chars().count() > MAX_VIEW_NAME_LEN {
+                return Err(format!("View names must be at most {} characters", MAX_VIEW_NAME_LEN));
+            }
+            if !names.insert(name.to_lowercase()) {
+                return Err(format!("There's already a view called \"{}\"", name));
+            }
+            let search = view.filter.search.trim().to_string();
+            if search.chars().count() > MAX_VIEW_SEARCH_LEN {
+                return Err(format!("A saved search must be at most {} characters", MAX_VIEW_SEARCH_LEN));
+            }
+            let zone = view.filter.zone.map(|z| z.trim().to_string()).filter(|z| !z.is_empty());
+            Ok(SavedView { name, sort: view.sort, filter: CollectionFilter { search, zone, ..view.filter } })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orchid::{LightRequirement, LocationType};
+    use crate::test_helpers::test_orchid;
+
+    fn plant(id: &str, name: &str, species: &str, placement: &str) -> Orchid {
+        Orchid { id: id.into(), name: name.into(), species: species.into(), placement: placement.into(), ..test_orchid() }
+    }
+
+    fn zone(id: &str, name: &str) -> GrowingZone {
+        GrowingZone {
+            id: id.into(),
+            name: name.into(),
+            light_level: LightRequirement::Medium,
+            location_type: LocationType::Indoor,
+            temperature_range: String::new(),
+            humidity: String::new(),
+            description: String::new(),
+            sort_order: 0,
+            data_source_type: None,
+            data_source_config: String::new(),
+            hardware_device_id: None,
+            hardware_port: None,
+        }
+    }
+
+    fn names(orchids: &[Orchid]) -> Vec<&str> {
+        orchids.iter().map(|o| o.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_arrange_sorts_and_filters() {
+        let orchids = vec![
+            plant("orchid:1", "moth", "Phalaenopsis amabilis", "Window"),
+            plant("orchid:2", "Kingie", "Dendrobium kingianum", "Patio"),
+            plant("orchid:3", "Slipper", "Paphiopedilum insigne", ""),
+            plant("orchid:4", "Cattleya", "Cattleya walkeriana", "Window"),
+        ];
+        let due = |o: &Orchid| match o.id.as_str() {
+            "orchid:1" => Some(3),
+            "orchid:2" => Some(-2),
+            "orchid:3" => None,
+            _ => Some(0),
+        };
+        let everything = CollectionFilter::default();
+        let none = HashSet::new();
+        let zones = vec![zone("growing_zone:window", "Window"), zone("growing_zone:patio", "Patio")];
+        let arranged = |sort, filter: &CollectionFilter, matches: &HashSet<String>| arrange(orchids.clone(), sort, filter, &zones, matches, due);
+
+        assert_eq!(names(&arranged(CollectionSort::RecentlyAdded, &everything, &none)), vec!["moth", "Kingie", "Slipper", "Cattleya"]);
+        assert_eq!(names(&arranged(CollectionSort::DueSoonest, &everything, &none)), vec!["Slipper", "Kingie", "Cattleya", "moth"]);
+        assert_eq!(names(&arranged(CollectionSort::Name, &everything, &none)), vec!["Cattleya", "Kingie", "moth", "Slipper"]);
+        assert_eq!(names(&arranged(CollectionSort::Genus, &everything, &none)), vec!["Cattleya", "Kingie", "Slipper", "moth"]);
+        assert_eq!(names(&arranged(CollectionSort::Zone, &everything, &none)), vec!["Kingie", "Cattleya", "moth", "Slipper"]);
+
+        let thirsty_window = CollectionFilter { zone: Some("growing_zone:window".into()), needs_water: true, ..Default::default() };
+        assert_eq!(names(&arranged(CollectionSort::Name, &thirsty_window, &none)), vec!["Cattleya"]);
+        let deleted_zone = CollectionFilter { zone: Some("growing_zone:gone".into()), ..Default::default() };
+        assert!(arranged(CollectionSort::Name, &deleted_zone, &none).is_empty());
+
+        let search = CollectionFilter { search: "phal".into(), ..Default::default() };
+        let matches = HashSet::from(["orchid:1".to_string()]);
+        assert_eq!(names(&arranged(CollectionSort::Name, &search, &matches)), vec!["moth"]);
+    }
+
+    #[test]
+    fn test_sanitize_saved_views() {
+        let view = |name: &str| SavedView { name: name.into(), sort: CollectionSort::DueSoonest, filter: CollectionFilter::default() };
+        let clean = sanitize_saved_views(vec![SavedView {
+            filter: CollectionFilter { search: "  dendrobium ".into(), zone: Some(" ".into()), needs_water: true },
+            ..view("  Thirsty ")
+        }]).unwrap();
+        assert_eq!(clean[0].name, "Thirsty");
+        assert_eq!(clean[0].filter, CollectionFilter { search: "dendrobium".into(), zone: None, needs_water: true });
+
+        assert!(sanitize_saved_views(vec![view("Tent"), view("tent")]).unwrap_err().contains("already"));
+        assert!(sanitize_saved_views(vec![view("   ")]).is_err());
+        assert!(sanitize_saved_views(vec![view(&"x".repeat(MAX_VIEW_NAME_LEN + 1))]).is_err());
+        assert!(sanitize_saved_views((0..=MAX_SAVED_VIEWS).map(|i| view(&i.to_string())).collect()).is_err());
+        assert_eq!(CollectionSort::parse("genus"), CollectionSort::Genus);
+        assert_eq!(CollectionSort::parse("bogus"), CollectionSort::RecentlyAdded);
+    }
+}
@@ -0,0 +1,282 @@
+use leptos::prelude::*;
+use crate::acquisition::{SpendGroup, Vendor};
+use crate::orchid::AcquisitionSource;
+use super::{BTN_DANGER, BTN_SECONDARY};
+
+const SECTION_HEADING: &str = "mt-0 mb-3 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400";
+
+/// The "Where It Came From" section of the add and edit forms: date, price, source and vendor.
+///
+/// Every value is the raw input string; convert with `crate::acquisition::parse_date_input`,
+/// `parse_price_input`, `AcquisitionSource::parse` and a non-empty check on the vendor ID.
+#[component]
+pub fn AcquisitionFields(
+    acquired: ReadSignal<String>, set_acquired: WriteSignal<String>,
+    price: ReadSignal<String>, set_price: WriteSignal<String>,
+    source: ReadSignal<String>, set_source: WriteSignal<String>,
+    vendor: ReadSignal<String>, set_vendor: WriteSignal<String>,
+) -> impl IntoView {
+    view! {
+        <div class="pt-4 mt-4 mb-4 border-t border-stone-200 dark:border-stone-700">
+            <h4 class=SECTION_HEADING>"Where It Came From"</h4>
+            <div class="flex flex-col gap-4 mb-4 sm:flex-row">
+                <div class="flex-1">
+                    <label>"Acquired On:"</label>
+                    <input type="date"
+                        max=chrono::Utc::now().date_naive().to_string()
+                        prop:value=acquired
+                        on:change=move |ev| set_acquired.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="flex-1">
+                    <label>"Price Paid:"</label>
+                    <input type="number" step="0.01" min="0"
+                        prop:value=price
+                        on:input=move |ev| set_price.set(event_target_value(&ev))
+                        placeholder="e.g. 24.50"
+                    />
+                </div>
+                <div class="flex-1">
+                    <label>"Source:"</label>
+                    <select prop:value=source on:change=move |ev| set_source.set(event_target_value(&ev))>
+                        <option value="">"Unknown / Unset"</option>
+                        {AcquisitionSource::ALL.into_iter().map(|s| view! {
+                            <option value=s.as_str()>{s.label()}</option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </div>
+            </div>
+            <VendorPicker vendor=vendor set_vendor=set_vendor />
+        </div>
+    }
+}
+
+/// A vendor select with an inline "add vendor" input, which selects the vendor it creates.
+#[component]
+fn VendorPicker(vendor: ReadSignal<String>, set_vendor: WriteSignal<String>) -> impl IntoView {
+    let vendors = Resource::new(|| (), |_| crate::server_fns::acquisition::get_vendors());
+    let (adding, set_adding) = signal(false);
+    let (new_name, set_new_name) = signal(String::new());
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let add_vendor = move |_| {
+        set_error_msg.set(None);
+        let name = new_name.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::acquisition::create_vendor(name, None).await {
+                Ok(v) => {
+                    set_vendor.set(v.id);
+                    set_new_name.set(String::new());
+                    set_adding.set(false);
+                    vendors.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("acquisition.create_vendor", &format!("Failed to add vendor: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div>
+            <div class="flex justify-between items-center">
+                <label>"Vendor:"</label>
+                <button
+                    type="button"
+                    class="transition-colors focus:outline-none text-[10px] text-primary hover:text-primary-light"
+                    on:click=move |_| set_adding.update(|a| *a = !*a)
+                >
+                    {move || if adding.get() { "Cancel" } else { "+ New Vendor" }}
+                </button>
+            </div>
+            <Suspense fallback=|| view! { <select disabled><option>"Loading vendors\u{2026}"</option></select> }>
+                {move || vendors.get().map(|result| {
+                    let list: Vec<Vendor> = result.unwrap_or_default();
+                    view! {
+                        <select on:change=move |ev| set_vendor.set(event_target_value(&ev))>
+                            <option value="" selected=move || vendor.get().is_empty()>"Unknown / Unset"</option>
+                            {list.into_iter().map(|v| {
+                                let id = v.id.clone();
+                                view! { <option value=v.id selected=move || vendor.get() == id>{v.name}</option> }
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    }
+                })}
+            </Suspense>
+            {move || adding.get().then(|| view! {
+                <div class="flex gap-2 items-center mt-2 animate-fade-in">
+                    <input type="text" maxlength="200" placeholder="Nursery, shop or grower"
+                        prop:value=new_name
+                        on:input=move |ev| set_new_name.set(event_target_value(&ev))
+                    />
+                    <button type="button" class=BTN_SECONDARY
+                        disabled=move || new_name.get().trim().is_empty()
+                        on:click=add_vendor
+                    >"Add"</button>
+                </div>
+            })}
+            {move || error_msg.get().map(|e| view! { <p class="mt-1 mb-0 text-xs text-danger">{e}</p> })}
+        </div>
+    }
+}
+
+/// The Stats tab: what the collection cost, split by vendor, source and year, with the vendor list.
+#[component]
+pub fn CollectionValueCard() -> impl IntoView {
+    let value = Resource::new(|| (), |_| crate::server_fns::acquisition::get_collection_value());
+    let vendors = Resource::new(|| (), |_| crate::server_fns::acquisition::get_vendors());
+
+    view! {
+        <div class="flex flex-col gap-4">
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Adding up the collection\u{2026}"</p> }>
+                {move || value.get().map(|result| match result {
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("acquisition.value", &format!("Failed to load collection value: {}", e), &[]);
+                        view! { <p class="text-sm text-danger">{format!("Couldn't load collection value: {}", e)}</p> }.into_any()
+                    }
+                    Ok(v) if v.priced_plants == 0 => view! {
+                        <p class="text-sm text-stone-500 dark:text-stone-400">
+                            "No prices recorded yet. Add what you paid in a plant's Details tab to see your spend here."
+                        </p>
+                    }.into_any(),
+                    Ok(v) => {
+                        let average = v.average_price().unwrap_or_default();
+                        view! {
+                            <div class="grid grid-cols-2 gap-3 sm:grid-cols-4">
+                                <Stat label="Total Spent" value=format!("{:.2}", v.total_spent) />
+                                <Stat label="Average Plant" value=format!("{:.2}", average) />
+                                <Stat label="Priced Plants" value=v.priced_plants.to_string() />
+                                <Stat label="No Price" value=v.unpriced_plants.to_string() />
+                            </div>
+                            {v.most_expensive.map(|(name, price)| view! {
+                                <p class="m-0 text-sm text-stone-500 dark:text-stone-400">
+                                    "Most expensive: "
+                                    <span class="font-medium text-stone-700 dark:text-stone-200">{name}</span>
+                                    {format!(" ({:.2})", price)}
+                                </p>
+                            })}
+                            <div class="grid gap-4 sm:grid-cols-3">
+                                <SpendList title="By Vendor" groups=v.by_vendor />
+                                <SpendList title="By Source" groups=v.by_source />
+                                <SpendList title="By Year" groups=v.by_year />
+                            </div>
+                        }.into_any()
+                    }
+                })}
+            </Suspense>
+            <Suspense fallback=|| ()>
+                {move || vendors.get().map(|result| {
+                    let list = result.unwrap_or_default();
+                    view! {
+                        <VendorList vendors=list on_deleted=move || {
+                            vendors.refetch();
+                            value.refetch();
+                        } />
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn Stat(label: &'static str, value: String) -> impl IntoView {
+    view! {
+        <div class="p-3 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="text-xs tracking-wide uppercase text-stone-400">{label}</div>
+            <div class="mt-1 text-lg font-semibold text-stone-800 dark:text-stone-100">{value}</div>
+        </div>
+    }
+}
+
+#[component]
+fn SpendList(title: &'static str, groups: Vec<SpendGroup>) -> impl IntoView {
+    view! {
+        <div class="p-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <h4 class=SECTION_HEADING>{title}</h4>
+            {if groups.is_empty() {
+                view! { <p class="m-0 text-sm text-stone-400">"Nothing recorded."</p> }.into_any()
+            } else {
+                view! {
+                    <ul class="p-0 m-0 list-none">
+                        {groups.into_iter().map(|g| view! {
+                            <li class="flex justify-between py-1 text-sm text-stone-600 dark:text-stone-300">
+                                <span>{format!("{} ({})", g.label, g.plants)}</span>
+                                <span class="font-medium">{format!("{:.2}", g.total)}</span>
+                            </li>
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                }.into_any()
+            }}
+        </div>
+    }
+}
+
+/// The collection's vendors, each with a two-step delete.
+#[component]
+fn VendorList(
+    vendors: Vec<Vendor>,
+    on_deleted: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (confirming, set_confirming) = signal(None::<String>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let delete = move |id: String| {
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::acquisition::delete_vendor(id).await {
+                Ok(()) => {
+                    set_confirming.set(None);
+                    on_deleted();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("acquisition.delete_vendor", &format!("Failed to delete vendor: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="p-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <h4 class=SECTION_HEADING>"Vendors"</h4>
+            {if vendors.is_empty() {
+                view! { <p class="m-0 text-sm text-stone-400">"No vendors yet. Add one from a plant's edit form."</p> }.into_any()
+            } else {
+                view! {
+                    <ul class="p-0 m-0 list-none">
+                        {vendors.into_iter().map(|v| {
+                            let id = StoredValue::new(v.id.clone());
+                            view! {
+                                <li class="flex flex-wrap gap-2 justify-between items-center py-1.5 text-sm text-stone-600 dark:text-stone-300">
+                                    <span>
+                                        <span class="font-medium">{v.name}</span>
+                                        {v.website.map(|w| view! { <span class="ml-2 text-xs text-stone-400">{w}</span> })}
+                                    </span>
+                                    {move || if confirming.get().as_deref() == Some(id.get_value().as_str()) {
+                                        view! {
+                                            <span class="flex gap-1 items-center text-xs text-stone-500">
+                                                "Plants keep their price and date."
+                                                <button class=BTN_DANGER on:click=move |_| delete(id.get_value())>"Delete"</button>
+                                                <button class=BTN_SECONDARY on:click=move |_| set_confirming.set(None)>"Cancel"</button>
+                                            </span>
+                                        }.into_any()
+                                    } else {
+                                        view! {
+                                            <button class=BTN_DANGER on:click=move |_| set_confirming.set(Some(id.get_value()))>"Remove"</button>
+                                        }.into_any()
+                                    }}
+                                </li>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                }.into_any()
+            }}
+            {move || error_msg.get().map(|e| view! { <p class="mt-2 mb-0 text-xs text-danger">{e}</p> })}
+        </div>
+    }
+}
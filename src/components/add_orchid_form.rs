@@ -1,6 +1,10 @@
 use super::{BTN_CLOSE, BTN_PRIMARY, MODAL_CONTENT, MODAL_HEADER, MODAL_OVERLAY};
+use crate::acquisition::{parse_date_input, parse_price_input};
+use crate::components::acquisition::AcquisitionFields;
 use crate::components::scanner::AnalysisResult;
-use crate::orchid::{GrowingZone, LightRequirement, Orchid};
+use crate::components::species_search::SpeciesSuggestions;
+use crate::orchid::{AcquisitionSource, GrowingZone, LightRequirement, Orchid};
+use crate::taxonomy::SpeciesSuggestion;
 use leptos::prelude::*;
 
 #[component]
@@ -31,6 +35,10 @@ pub fn AddOrchidForm(
     let (pot_size, set_pot_size) = signal(String::new());
     let (pot_type, set_pot_type) = signal(String::new());
     let (par_ppfd, set_par_ppfd) = signal(String::new());
+    let (acquired, set_acquired) = signal(String::new());
+    let (price, set_price) = signal(String::new());
+    let (source, set_source) = signal(String::new());
+    let (vendor, set_vendor) = signal(String::new());
 
     // Seasonal signals
     let (rest_start_month, set_rest_start_month) = signal::<Option<u32>>(None);
@@ -44,6 +52,30 @@ pub fn AddOrchidForm(
 
     let zones_for_prefill = zones.clone();
 
+    // A known species fills in its genus care defaults and native range
+    let on_pick_species = move |s: SpeciesSuggestion| {
+        if name.get_untracked().trim().is_empty() {
+            set_name.set(s.name.clone());
+        }
+        set_species.set(s.name);
+        if let Some(care) = s.care {
+            set_water_freq.set(care.water_frequency_days.to_string());
+            set_light.set(care.light_requirement.as_str().to_string());
+            set_temp_min.set(care.temp_min.to_string());
+            set_temp_max.set(care.temp_max.to_string());
+            set_humidity_min.set(care.humidity_min.to_string());
+            set_humidity_max.set(care.humidity_max.to_string());
+            if let Some(status) = care.conservation_status.filter(|_| conservation.get_untracked().is_empty()) {
+                set_conservation.set(status);
+            }
+        }
+        if s.native_region.is_some() {
+            set_native_region.set(s.native_region);
+            set_native_latitude.set(s.native_latitude);
+            set_native_longitude.set(s.native_longitude);
+        }
+    };
+
     Effect::new(move |_| {
         if let Some(data) = prefill_data.get() {
             set_name.set(data.species_name.clone());
@@ -199,6 +231,22 @@ pub fn AddOrchidForm(
             active_water_multiplier: active_water_mult.get(),
             active_fertilizer_multiplier: active_fert_mult.get(),
             par_ppfd: par_ppfd.get().parse().ok(),
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: parse_date_input(&acquired.get()),
+            acquisition_price: parse_price_input(&price.get()),
+            acquisition_source: AcquisitionSource::parse(&source.get()),
+            vendor_id: Some(vendor.get()).filter(|v| !v.is_empty()),
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
 
         on_add(new_orchid);
@@ -217,6 +265,10 @@ pub fn AddOrchidForm(
         set_pot_size.set(String::new());
         set_pot_type.set(String::new());
         set_par_ppfd.set(String::new());
+        set_acquired.set(String::new());
+        set_price.set(String::new());
+        set_source.set(String::new());
+        set_vendor.set(String::new());
     };
 
     view! {
@@ -236,13 +288,15 @@ pub fn AddOrchidForm(
                                 required
                             />
                         </div>
-                        <div class="mb-4">
+                        <div class="relative mb-4">
                             <label>"Species:"</label>
                             <input type="text"
                                 on:input=move |ev| set_species.set(event_target_value(&ev))
                                 prop:value=species
+                                autocomplete="off"
                                 required
                             />
+                            <SpeciesSuggestions query=species on_pick=on_pick_species />
                         </div>
                         <div class="mb-4">
                             <label>"Conservation Status (e.g. CITES II):"</label>
@@ -366,6 +420,13 @@ pub fn AddOrchidForm(
                             pot_type=pot_type set_pot_type=set_pot_type
                         />
 
+                        <AcquisitionFields
+                            acquired=acquired set_acquired=set_acquired
+                            price=price set_price=set_price
+                            source=source set_source=set_source
+                            vendor=vendor set_vendor=set_vendor
+                        />
+
                         <div class="mb-4">
                             <label>"Notes:"</label>
                             <textarea
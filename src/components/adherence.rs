@@ -0,0 +1,105 @@
+use leptos::prelude::*;
+use crate::adherence::{AdherenceStats, PlantAdherence, ADHERENCE_WINDOW_DAYS};
+use crate::orchid::Orchid;
+
+/// Plants listed before "Show all" is needed.
+const COLLAPSED_ROWS: usize = 5;
+
+/// Tailwind classes for an on-time percentage.
+fn percent_class(percent: f64) -> &'static str {
+    if percent >= 90.0 {
+        "text-emerald-600 dark:text-emerald-400"
+    } else if percent >= 70.0 {
+        "text-amber-600 dark:text-amber-400"
+    } else {
+        "text-red-600 dark:text-red-400"
+    }
+}
+
+/// How often waterings over the last 90 days were on time, overall and per plant, least punctual first.
+#[component]
+pub fn WateringAdherenceCard(
+    orchids: Memo<Vec<Orchid>>,
+    on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let stats = Resource::new(|| (), |_| crate::server_fns::adherence::get_watering_adherence());
+    let (show_all, set_show_all) = signal(false);
+
+    let select = move |orchid_id: String| {
+        if let Some(o) = orchids.get_untracked().into_iter().find(|o| o.id == orchid_id) {
+            on_select(o);
+        }
+    };
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || stats.get().map(|result| {
+                let stats: AdherenceStats = match result {
+                    Ok(s) => s,
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("adherence.load", &format!("Failed to load watering adherence: {}", _e), &[]);
+                        return ().into_any();
+                    }
+                };
+                let Some(overall) = stats.on_time_percent() else {
+                    return ().into_any();
+                };
+                let plant_count = stats.plants.len();
+                let plants = StoredValue::new(stats.plants);
+                view! {
+                    <div class="p-4 mt-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+                        <div class="flex flex-wrap gap-2 justify-between items-baseline mb-3">
+                            <h2 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">
+                                {format!("Watering on Time \u{00B7} last {} days", ADHERENCE_WINDOW_DAYS)}
+                            </h2>
+                            <div class="text-xs text-stone-500 dark:text-stone-400">
+                                <span class=format!("text-lg font-bold {}", percent_class(overall))>{format!("{:.0}%", overall)}</span>
+                                {format!(" \u{00B7} {} on time, {} late", stats.on_time, stats.late)}
+                            </div>
+                        </div>
+                        <ul class="p-0 m-0 list-none">
+                            {move || plants.with_value(|all| {
+                                let shown = if show_all.get() { all.len() } else { COLLAPSED_ROWS };
+                                all.iter().take(shown).cloned().map(|p| view! { <AdherenceRow plant=p on_select=select /> }).collect::<Vec<_>>()
+                            })}
+                        </ul>
+                        {(plant_count > COLLAPSED_ROWS).then(|| view! {
+                            <button
+                                class="p-0 mt-2 text-xs bg-transparent border-none cursor-pointer text-primary dark:text-primary-light"
+                                on:click=move |_| set_show_all.update(|v| *v = !*v)
+                            >
+                                {move || if show_all.get() { "Show fewer".to_string() } else { format!("Show all {} plants", plant_count) }}
+                            </button>
+                        })}
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+    }
+}
+
+/// One plant's line: name, on-time share as a bar, and how late the late waterings were.
+#[component]
+fn AdherenceRow(
+    plant: PlantAdherence,
+    on_select: impl Fn(String) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let percent = plant.on_time_percent().unwrap_or(100.0);
+    let mut detail = format!("{} of {}", plant.on_time, plant.on_time + plant.late);
+    if plant.late > 0 {
+        detail.push_str(&format!(" \u{00B7} ~{:.0}d late", plant.average_days_late));
+    }
+    let id = plant.orchid_id.clone();
+
+    view! {
+        <li class="flex gap-3 items-center py-1 text-xs cursor-pointer" on:click=move |_| on_select(id.clone())>
+            <span class="w-32 font-medium truncate text-stone-700 dark:text-stone-300">{plant.name}</span>
+            <div class="overflow-hidden flex-1 h-2 rounded-full bg-stone-100 dark:bg-stone-800">
+                <div class="h-full rounded-full bg-primary/70" style=format!("width: {:.0}%", percent)></div>
+            </div>
+            <span class=format!("w-10 text-right font-semibold {}", percent_class(percent))>{format!("{:.0}%", percent)}</span>
+            <span class="w-28 text-right text-stone-400">{detail}</span>
+        </li>
+    }
+}
@@ -0,0 +1,148 @@
+use leptos::prelude::*;
+use crate::api_tokens::{ApiScope, ApiTokenPreset};
+
+/// API token list with create/revoke controls for the settings modal.
+#[component]
+pub fn ApiTokenSettings() -> impl IntoView {
+    let tokens = Resource::new(|| (), |_| crate::server_fns::api_tokens::list_api_tokens());
+    let (name, set_name) = signal(String::new());
+    let scopes = RwSignal::new(vec![ApiScope::Upload]);
+    let (is_creating, set_is_creating) = signal(false);
+    let (new_token, set_new_token) = signal(None::<String>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let create = move |_| {
+        set_is_creating.set(true);
+        set_error_msg.set(None);
+        set_new_token.set(None);
+        let label = name.get_untracked();
+        let chosen = scopes.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::api_tokens::create_api_token(label, chosen).await {
+                Ok(created) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.api_token_created", "API token created", &[]);
+                    set_name.set(String::new());
+                    set_new_token.set(Some(created.token));
+                    tokens.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.api_token_create", &format!("Failed to create API token: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_creating.set(false);
+        });
+    };
+
+    let revoke = move |id: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::api_tokens::revoke_api_token(id.clone()).await {
+                Ok(()) => tokens.refetch(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.api_token_revoke", &format!("Failed to revoke API token: {}", e), &[("token_id", &id)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || tokens.get().map(|result| {
+                let list = result.unwrap_or_default();
+                if list.is_empty() {
+                    return view! { <p class="mt-0 mb-3 text-xs text-stone-400">"No API tokens yet."</p> }.into_any();
+                }
+                view! {
+                    <div class="flex flex-col gap-1.5 mb-3">
+                        {list.into_iter().map(|token| {
+                            let id = token.id.clone();
+                            let used = token.last_used_at
+                                .map(|t| format!("last used {}", super::format_time_ago(&t)))
+                                .unwrap_or_else(|| "never used".into());
+                            let scope_list = match ApiTokenPreset::matching(&token.scopes) {
+                                Some(preset) => preset.label().to_string(),
+                                None => token.scopes.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", "),
+                            };
+                            view! {
+                                <div class="flex justify-between items-center py-2 px-3 text-sm rounded-lg bg-secondary/30">
+                                    <div>
+                                        <div class="font-medium text-stone-700 dark:text-stone-300">
+                                            {token.name}
+                                            <code class="ml-2 text-[11px] text-stone-400">{format!("{}\u{2026}", token.prefix)}</code>
+                                        </div>
+                                        <div class="text-[11px] text-stone-400">{format!("{} \u{00B7} created {} \u{00B7} {}", scope_list, token.created_at.format("%b %d, %Y"), used)}</div>
+                                    </div>
+                                    <button
+                                        class="py-1 px-2 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                        on:click=move |_| revoke(id.clone())
+                                    >"Revoke"</button>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+        <div class="flex flex-wrap gap-1.5 mb-2">
+            {ApiTokenPreset::ALL.into_iter().map(|preset| view! {
+                <button
+                    class=move || if ApiTokenPreset::matching(&scopes.get()) == Some(preset) {
+                        "py-1 px-2 text-xs font-semibold rounded-lg border-none cursor-pointer text-white bg-primary"
+                    } else {
+                        "py-1 px-2 text-xs font-semibold rounded-lg border-none cursor-pointer text-stone-600 bg-stone-100 hover:bg-stone-200 dark:text-stone-300 dark:bg-stone-800 dark:hover:bg-stone-700"
+                    }
+                    on:click=move |_| scopes.set(preset.scopes())
+                >{preset.label()}</button>
+            }).collect::<Vec<_>>()}
+        </div>
+        <div class="flex flex-col gap-1 mb-2">
+            {ApiScope::ALL.into_iter().map(|scope| view! {
+                <label class="flex gap-2 items-center text-xs cursor-pointer text-stone-600 dark:text-stone-400">
+                    <input type="checkbox"
+                        prop:checked=move || scopes.get().contains(&scope)
+                        on:change=move |ev| {
+                            let on = event_target_checked(&ev);
+                            scopes.update(|s| {
+                                s.retain(|x| *x != scope);
+                                if on {
+                                    s.push(scope);
+                                }
+                            });
+                        }
+                    />
+                    <code>{scope.as_str()}</code>
+                    <span class="text-stone-400">{scope.description()}</span>
+                </label>
+            }).collect::<Vec<_>>()}
+        </div>
+        <div class="flex gap-2 items-center">
+            <input type="text"
+                class="flex-1 py-2 px-3 text-sm rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50"
+                placeholder="Token name (e.g. Greenhouse camera)"
+                prop:value=name
+                on:input=move |ev| set_name.set(event_target_value(&ev))
+            />
+            <button
+                class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                disabled=move || is_creating.get() || scopes.with(|s| s.is_empty())
+                on:click=create
+            >
+                {move || if is_creating.get() { "Creating..." } else { "Create Token" }}
+            </button>
+        </div>
+        {move || new_token.get().map(|token| view! {
+            <div class="p-3 mt-3 text-sm rounded-lg bg-primary/5 dark:bg-primary-light/5">
+                <div class="text-xs font-medium text-stone-500 dark:text-stone-400">"Copy this token now. It won't be shown again:"</div>
+                <code class="text-xs break-all text-primary dark:text-primary-light">{token}</code>
+                <div class="mt-1 text-[11px] text-stone-400">"Send it as an Authorization: Bearer header."</div>
+            </div>
+        })}
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
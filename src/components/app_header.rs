@@ -7,6 +7,7 @@ pub fn AppHeader(
     on_toggle_dark: impl Fn() + 'static + Copy + Send + Sync,
     on_add: impl Fn() + 'static + Copy + Send + Sync,
     on_scan: impl Fn() + 'static + Copy + Send + Sync,
+    on_chat: impl Fn() + 'static + Copy + Send + Sync,
     on_settings: impl Fn() + 'static + Copy + Send + Sync,
 ) -> impl IntoView {
     view! {
@@ -25,9 +26,10 @@ pub fn AppHeader(
                     <button class=BTN_GHOST aria-label="Toggle dark mode" title="Toggle dark mode" on:click=move |_| on_toggle_dark()>
                         {move || if dark_mode.get() { "\u{2600}" } else { "\u{263E}" }}
                     </button>
-                    <button class=BTN_GHOST on:click=move |_| on_add()>"Add"</button>
+                    <button class=BTN_GHOST data-tour="add-plant" on:click=move |_| on_add()>"Add"</button>
                     <button class=BTN_GHOST on:click=move |_| on_scan()>"ID Plant"</button>
-                    <button class=BTN_GHOST on:click=move |_| on_settings()>"Settings"</button>
+                    <button class=BTN_GHOST on:click=move |_| on_chat()>"Ask"</button>
+                    <button class=BTN_GHOST data-tour="add-zone" on:click=move |_| on_settings()>"Settings"</button>
                 </div>
             </div>
         </header>
@@ -0,0 +1,183 @@
+use leptos::prelude::*;
+use crate::orchid::{ArchiveReason, Orchid};
+use super::{BTN_DANGER, BTN_SECONDARY};
+
+const INPUT: &str = "py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600";
+
+/// "Died · Mar 3, 2026" for an archived plant, or just the date without a reason; None while it's still in the collection.
+pub fn archive_summary(o: &Orchid) -> Option<String> {
+    let at = o.archived_at?;
+    let date = at.format("%b %-d, %Y").to_string();
+    Some(match o.archive_reason {
+        Some(reason) => format!("{} \u{00B7} {}", reason.label(), date),
+        None => date,
+    })
+}
+
+/// Moves a plant into the archive: a collapsed button that opens a reason and date picker.
+///
+/// The plant keeps its journal, photos and awards; it only stops getting care reminders.
+#[component]
+pub fn ArchivePlantForm(
+    orchid_signal: ReadSignal<Orchid>,
+    on_archived: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (open, set_open) = signal(false);
+    let (reason, set_reason) = signal(ArchiveReason::Died);
+    let (archived_on, set_archived_on) = signal(chrono::Utc::now().date_naive().to_string());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let archive = move |_| {
+        let Some(archived_at) = crate::acquisition::parse_date_input(&archived_on.get_untracked()) else {
+            set_error_msg.set(Some("Pick the date the plant left".into()));
+            return;
+        };
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let id = orchid_signal.get_untracked().id;
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::archive_orchid(id, reason.get_untracked(), archived_at).await {
+                Ok(archived) => on_archived(archived),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("archive.archive_orchid", &format!("Failed to archive plant: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="pt-4 mt-4 border-t border-stone-200 dark:border-stone-700">
+            {move || if open.get() {
+                view! {
+                    <div class="p-3 rounded-lg bg-secondary">
+                        <p class="mt-0 mb-3 text-sm text-stone-500 dark:text-stone-400">
+                            "Archived plants leave your tasks and watering schedule. Their journal stays in the Archive tab, and you can restore them later."
+                        </p>
+                        <div class="flex flex-wrap gap-3 mb-3">
+                            <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                                "Reason"
+                                <select class=INPUT on:change=move |ev| if let Some(r) = ArchiveReason::parse(&event_target_value(&ev)) { set_reason.set(r) }>
+                                    {ArchiveReason::ALL.into_iter().map(|r| view! {
+                                        <option value=r.as_str() selected=move || reason.get() == r>{r.label()}</option>
+                                    }).collect::<Vec<_>>()}
+                                </select>
+                            </label>
+                            <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                                "Date"
+                                <input type="date" class=INPUT required
+                                    max=chrono::Utc::now().date_naive().to_string()
+                                    prop:value=archived_on
+                                    on:change=move |ev| set_archived_on.set(event_target_value(&ev))
+                                />
+                            </label>
+                        </div>
+                        {move || error_msg.get().map(|e| view! { <p class="mt-0 mb-2 text-xs text-danger">{e}</p> })}
+                        <div class="flex gap-2">
+                            <button class=BTN_DANGER disabled=move || is_saving.get() on:click=archive>
+                                {move || if is_saving.get() { "Archiving..." } else { "Archive Plant" }}
+                            </button>
+                            <button class=BTN_SECONDARY on:click=move |_| set_open.set(false)>"Cancel"</button>
+                        </div>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <button class=BTN_SECONDARY on:click=move |_| set_open.set(true)>"No longer in collection\u{2026}"</button>
+                }.into_any()
+            }}
+        </div>
+    }
+}
+
+/// The Archive tab: plants that died or were passed on, each openable to browse its journal.
+#[component]
+pub fn ArchiveView(
+    on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+    on_restored: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let archived = Resource::new(|| (), |_| crate::server_fns::orchids::get_archived_orchids());
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let restore = move |id: String| {
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::restore_orchid(id).await {
+                Ok(orchid) => {
+                    archived.refetch();
+                    on_restored(orchid);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("archive.restore_orchid", &format!("Failed to restore plant: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-3">
+            {move || error_msg.get().map(|e| view! { <p class="m-0 text-sm text-danger">{e}</p> })}
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Loading the archive\u{2026}"</p> }>
+                {move || archived.get().map(|result| match result {
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("archive.load", &format!("Failed to load archived plants: {}", e), &[]);
+                        view! { <p class="text-sm text-danger">{format!("Couldn't load the archive: {}", e)}</p> }.into_any()
+                    }
+                    Ok(list) if list.is_empty() => view! {
+                        <p class="text-sm text-stone-500 dark:text-stone-400">
+                            "No archived plants. When a plant dies or leaves your collection, archive it from its Details tab to keep its journal here."
+                        </p>
+                    }.into_any(),
+                    Ok(list) => view! {
+                        <ul class="p-0 m-0 list-none">
+                            {list.into_iter().map(|o| {
+                                let summary = archive_summary(&o).unwrap_or_default();
+                                let name = o.name.clone();
+                                let species = o.species.clone();
+                                let id = StoredValue::new(o.id.clone());
+                                let orchid = StoredValue::new(o);
+                                view! {
+                                    <li class="flex gap-3 justify-between items-center py-2 border-t first:border-t-0 border-stone-100 dark:border-stone-800">
+                                        <button
+                                            class="flex-1 p-0 min-w-0 text-left bg-transparent border-none cursor-pointer"
+                                            on:click=move |_| on_select(orchid.get_value())
+                                        >
+                                            <div class="text-sm font-semibold text-stone-800 dark:text-stone-100">{name}</div>
+                                            <div class="text-xs italic text-stone-400">{species}</div>
+                                            <div class="text-xs text-stone-500 dark:text-stone-400">{summary}</div>
+                                        </button>
+                                        <button class=BTN_SECONDARY on:click=move |_| restore(id.get_value())>"Restore"</button>
+                                    </li>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::test_helpers::test_orchid;
+
+    #[test]
+    fn test_archive_summary() {
+        assert_eq!(archive_summary(&test_orchid()), None);
+        let sold = Orchid {
+            archived_at: Some(chrono::Utc.with_ymd_and_hms(2026, 3, 3, 0, 0, 0).unwrap()),
+            archive_reason: Some(ArchiveReason::Sold),
+            ..test_orchid()
+        };
+        assert_eq!(archive_summary(&sold).as_deref(), Some("Sold \u{00B7} Mar 3, 2026"));
+    }
+}
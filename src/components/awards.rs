@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use leptos::prelude::*;
+use crate::award::{Award, AwardBadge, AwardKind, MAX_AWARD_POINTS};
+use crate::components::photo_capture::PhotoCapture;
+use crate::orchid::Orchid;
+use crate::thumbnails::{thumbnail_url, ThumbSize};
+use super::{BTN_DANGER, BTN_PRIMARY, BTN_SECONDARY};
+
+const INPUT: &str = "py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600";
+const BADGE: &str = "inline-block py-0.5 px-2 text-xs font-medium text-violet-700 rounded-full border dark:text-violet-300 bg-violet-100/80 border-violet-300/40 dark:bg-violet-900/30 dark:border-violet-700/40";
+
+/// Award badges for the collection grid, keyed by orchid ID, provided as context by the home page.
+#[derive(Clone, Copy)]
+pub struct AwardBadges(pub RwSignal<HashMap<String, AwardBadge>>);
+
+/// Reloads the award badges after an award is added or removed.
+fn refresh_award_badges(ctx: Option<AwardBadges>) {
+    let Some(ctx) = ctx else { return };
+    leptos::task::spawn_local(async move {
+        match crate::server_fns::awards::get_award_badges().await {
+            Ok(badges) => ctx.0.set(badges),
+            Err(_e) => {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("awards.refresh_badges", &format!("Failed to reload award badges: {}", _e), &[]);
+            }
+        }
+    });
+}
+
+/// The award badge on an orchid card: the plant's top award, plus how many more it has.
+#[component]
+pub fn CardAwardBadge(orchid_id: String) -> impl IntoView {
+    let ctx = use_context::<AwardBadges>();
+    move || {
+        let badge = ctx.and_then(|c| c.0.with(|b| b.get(&orchid_id).cloned()))?;
+        let text = match badge.count {
+            1 => format!("\u{1F3C6} {}", badge.top_title),
+            n => format!("\u{1F3C6} {} +{}", badge.top_title, n - 1),
+        };
+        Some(view! { <span class=BADGE>{text}</span> })
+    }
+}
+
+/// A plant's awards and show results, with a form to record a new one.
+#[component]
+pub fn AwardsCard(orchid_signal: ReadSignal<Orchid>) -> impl IntoView {
+    let awards = Resource::new(
+        move || orchid_signal.with(|o| o.id.clone()),
+        crate::server_fns::awards::get_awards,
+    );
+    let (show_form, set_show_form) = signal(false);
+    let badges = use_context::<AwardBadges>();
+
+    let on_changed = move || {
+        awards.refetch();
+        refresh_award_badges(badges);
+    };
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="flex justify-between items-center mb-3">
+                <h4 class="m-0 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400">"Awards & Show Results"</h4>
+                <button class=BTN_SECONDARY on:click=move |_| set_show_form.update(|s| *s = !*s)>
+                    {move || if show_form.get() { "Cancel" } else { "Add Award" }}
+                </button>
+            </div>
+            {move || show_form.get().then(|| view! {
+                <AwardForm orchid_signal=orchid_signal on_saved=move || {
+                    set_show_form.set(false);
+                    on_changed();
+                } />
+            })}
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Loading awards\u{2026}"</p> }>
+                {move || awards.get().map(|result| match result {
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("awards.load", &format!("Failed to load awards: {}", e), &[]);
+                        view! { <p class="m-0 text-sm text-danger">{format!("Couldn't load awards: {}", e)}</p> }.into_any()
+                    }
+                    Ok(list) if list.is_empty() => view! {
+                        <p class="m-0 text-sm text-stone-400">"No awards yet."</p>
+                    }.into_any(),
+                    Ok(list) => view! {
+                        <ul class="p-0 m-0 list-none">
+                            {list.into_iter().map(|a| view! { <AwardRow award=a on_deleted=on_changed /> }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// One award: title, kind, show, score and date, with its photo and a two-step delete.
+#[component]
+fn AwardRow(award: Award, on_deleted: impl Fn() + 'static + Copy + Send + Sync) -> impl IntoView {
+    let (confirming, set_confirming) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let id = StoredValue::new(award.id.clone());
+
+    let delete = move |_| {
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::awards::delete_award(id.get_value()).await {
+                Ok(()) => on_deleted(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("awards.delete", &format!("Failed to delete award: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let mut detail = vec![award.kind.label().to_string()];
+    if let Some(show) = &award.show_name {
+        detail.push(show.clone());
+    }
+    if let Some(points) = award.points {
+        detail.push(format!("{} pts", points));
+    }
+    detail.push(award.awarded_at.format("%b %-d, %Y").to_string());
+
+    view! {
+        <li class="flex gap-3 items-start py-2 border-t first:border-t-0 border-stone-100 dark:border-stone-800">
+            {award.image_filename.map(|f| view! {
+                <a href=format!("/images/{}", f) target="_blank" rel="noopener">
+                    <img src=thumbnail_url(&f, ThumbSize::Small) class="object-cover w-14 h-14 rounded-lg" alt=award.title.clone() loading="lazy" />
+                </a>
+            })}
+            <div class="flex-1 min-w-0">
+                <div class="text-sm font-semibold text-stone-800 dark:text-stone-100">{award.title.clone()}</div>
+                <div class="text-xs text-stone-400">{detail.join(" \u{00B7} ")}</div>
+                {move || error_msg.get().map(|e| view! { <p class="mt-1 mb-0 text-xs text-danger">{e}</p> })}
+            </div>
+            {move || if confirming.get() {
+                view! {
+                    <div class="flex gap-1">
+                        <button class=BTN_DANGER on:click=delete>"Delete"</button>
+                        <button class=BTN_SECONDARY on:click=move |_| set_confirming.set(false)>"Keep"</button>
+                    </div>
+                }.into_any()
+            } else {
+                view! { <button class=BTN_DANGER on:click=move |_| set_confirming.set(true)>"Remove"</button> }.into_any()
+            }}
+        </li>
+    }
+}
+
+/// Records a new award. The photo is staged locally and uploaded on save, like a journal note's.
+#[component]
+fn AwardForm(
+    orchid_signal: ReadSignal<Orchid>,
+    on_saved: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (kind, set_kind) = signal(AwardKind::Aos);
+    let (title, set_title) = signal(String::new());
+    let (show_name, set_show_name) = signal(String::new());
+    let (points, set_points) = signal(String::new());
+    let (awarded_on, set_awarded_on) = signal(chrono::Utc::now().date_naive().to_string());
+    let (staged_photo, set_staged_photo) = signal(None::<String>);
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let clear_staged = std::sync::Arc::new(move || {
+        set_staged_photo.set(None);
+    }) as std::sync::Arc<dyn Fn() + Send + Sync>;
+
+    let save = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let Some(awarded_at) = crate::acquisition::parse_date_input(&awarded_on.get_untracked()) else {
+            set_error_msg.set(Some("Pick the date of the award".into()));
+            return;
+        };
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let orchid_id = orchid_signal.get_untracked().id;
+        let photo_data_url = staged_photo.get_untracked();
+
+        leptos::task::spawn_local(async move {
+            let image_filename = if let Some(_data_url) = photo_data_url {
+                #[cfg(feature = "hydrate")]
+                {
+                    match crate::components::photo_capture::upload_data_url(&_data_url).await {
+                        Ok(fname) => Some(fname),
+                        Err(e) => {
+                            crate::server_fns::telemetry::emit_error("awards.upload_photo", &format!("Photo upload failed: {}", e), &[]);
+                            set_error_msg.set(Some(format!("Photo upload failed: {}", e)));
+                            set_is_saving.set(false);
+                            return;
+                        }
+                    }
+                }
+                #[cfg(not(feature = "hydrate"))]
+                { None }
+            } else {
+                None
+            };
+
+            match crate::server_fns::awards::add_award(
+                orchid_id,
+                kind.get_untracked(),
+                title.get_untracked(),
+                Some(show_name.get_untracked()),
+                points.get_untracked().trim().parse().ok(),
+                awarded_at,
+                image_filename,
+            ).await {
+                Ok(_) => on_saved(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("awards.add", &format!("Failed to add award: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <form class="p-3 mb-3 rounded-lg bg-secondary" on:submit=save>
+            <div class="flex flex-wrap gap-3 mb-3">
+                <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                    "Type"
+                    <select class=INPUT on:change=move |ev| if let Some(k) = AwardKind::parse(&event_target_value(&ev)) { set_kind.set(k) }>
+                        {AwardKind::ALL.into_iter().map(|k| view! {
+                            <option value=k.as_str() selected=move || kind.get() == k>{k.label()}</option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </label>
+                <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[140px]">
+                    "Award"
+                    <input type="text" class=INPUT maxlength="100" required
+                        placeholder=move || kind.get().placeholder()
+                        prop:value=title
+                        on:input=move |ev| set_title.set(event_target_value(&ev))
+                    />
+                </label>
+            </div>
+            <div class="flex flex-wrap gap-3 mb-3">
+                <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[140px]">
+                    "Show or judging centre"
+                    <input type="text" class=INPUT maxlength="200" placeholder="Optional"
+                        prop:value=show_name
+                        on:input=move |ev| set_show_name.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="flex flex-col gap-1 w-20 text-xs text-stone-500 dark:text-stone-400">
+                    "Points"
+                    <input type="number" class=INPUT min="0" max=MAX_AWARD_POINTS.to_string()
+                        prop:value=points
+                        on:input=move |ev| set_points.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                    "Date"
+                    <input type="date" class=INPUT required
+                        max=chrono::Utc::now().date_naive().to_string()
+                        prop:value=awarded_on
+                        on:change=move |ev| set_awarded_on.set(event_target_value(&ev))
+                    />
+                </label>
+            </div>
+            <div class="mb-3">
+                <PhotoCapture
+                    on_photo_ready=move |data_url| set_staged_photo.set(Some(data_url))
+                    on_clear=clear_staged.clone()
+                />
+            </div>
+            {move || error_msg.get().map(|e| view! { <p class="mt-0 mb-2 text-xs text-danger">{e}</p> })}
+            <button type="submit" class=BTN_PRIMARY disabled=move || is_saving.get()>
+                {move || if is_saving.get() { "Saving..." } else { "Save Award" }}
+            </button>
+        </form>
+    }
+}
@@ -0,0 +1,117 @@
+use leptos::prelude::*;
+use crate::bloom::{bloom_stats, BloomStats};
+use crate::orchid::{LogEntry, Orchid};
+
+const SECTION_HEADING: &str = "mt-0 mb-3 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400";
+const MONTH_LETTERS: [&str; 12] = ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"];
+
+/// A plant's bloom history from its journal: how many times it flowered, for how long, when blooms
+/// usually start, and a year-by-month chart. Renders nothing until a 'Flowering' entry is logged.
+#[component]
+pub fn BloomHistoryCard(log_entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
+    let stats = Memo::new(move |_| log_entries.with(|entries| bloom_stats(entries)));
+
+    move || {
+        let s = stats.get();
+        (!s.blooms.is_empty()).then(|| view! {
+            <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+                <h4 class=SECTION_HEADING>"Bloom History"</h4>
+                <BloomSummary stats=s.clone() />
+                <OnsetBars onset_months=s.onset_months />
+                <YearChart years=s.years />
+            </div>
+        })
+    }
+}
+
+#[component]
+fn BloomSummary(stats: BloomStats) -> impl IntoView {
+    let count = stats.blooms.len();
+    let average = stats.average_duration_days
+        .map(|d| format!("{:.0} days", d))
+        .unwrap_or_else(|| "\u{2014}".to_string());
+    let usual_onset = stats.onset_months.iter().enumerate()
+        .max_by_key(|(i, n)| (**n, std::cmp::Reverse(*i)))
+        .map(|(i, _)| Orchid::month_name(i as u32 + 1))
+        .unwrap_or("\u{2014}");
+
+    view! {
+        <div class="grid grid-cols-3 gap-3 mb-4 text-sm">
+            <div>
+                <div class="text-xs text-stone-400">"Bloomings"</div>
+                <div class="font-medium text-stone-700 dark:text-stone-300">{count}</div>
+            </div>
+            <div>
+                <div class="text-xs text-stone-400">"Average Length"</div>
+                <div class="font-medium text-stone-700 dark:text-stone-300">{average}</div>
+            </div>
+            <div>
+                <div class="text-xs text-stone-400">"Usually Starts"</div>
+                <div class="font-medium text-stone-700 dark:text-stone-300">{usual_onset}</div>
+            </div>
+        </div>
+    }
+}
+
+/// How many blooms started in each month, as a row of bars.
+#[component]
+fn OnsetBars(onset_months: [u32; 12]) -> impl IntoView {
+    let max = onset_months.iter().copied().max().unwrap_or(0).max(1);
+    view! {
+        <div class="mb-4">
+            <div class="mb-1 text-xs text-stone-400">"Bloom onset by month"</div>
+            <div class="flex gap-1 items-end h-12">
+                {onset_months.into_iter().enumerate().map(|(i, n)| {
+                    let height = if n == 0 { 4 } else { 8 + n * 40 / max };
+                    let title = format!("{}: {} bloom{}", Orchid::month_name(i as u32 + 1), n, if n == 1 { "" } else { "s" });
+                    view! {
+                        <div
+                            class=if n == 0 { "flex-1 rounded-sm bg-stone-100 dark:bg-stone-800" } else { "flex-1 rounded-sm bg-pink-400 dark:bg-pink-500" }
+                            style=format!("height: {}px", height)
+                            title=title
+                        ></div>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            <MonthLetters />
+        </div>
+    }
+}
+
+/// Each year on its own row, with the months the plant was in flower filled in, so seasons can be compared.
+#[component]
+fn YearChart(years: Vec<(i32, [bool; 12])>) -> impl IntoView {
+    view! {
+        <div>
+            <div class="mb-1 text-xs text-stone-400">"Year over year"</div>
+            {years.into_iter().map(|(year, months)| view! {
+                <div class="flex gap-2 items-center mb-1">
+                    <span class="w-10 text-xs tabular-nums text-stone-500 dark:text-stone-400">{year}</span>
+                    <div class="flex flex-1 gap-1">
+                        {months.into_iter().enumerate().map(|(i, blooming)| view! {
+                            <div
+                                class=if blooming { "flex-1 h-3 rounded-sm bg-pink-400 dark:bg-pink-500" } else { "flex-1 h-3 rounded-sm bg-stone-100 dark:bg-stone-800" }
+                                title=format!("{} {}", Orchid::month_name(i as u32 + 1), year)
+                            ></div>
+                        }).collect::<Vec<_>>()}
+                    </div>
+                </div>
+            }).collect::<Vec<_>>()}
+            <div class="flex gap-2">
+                <span class="w-10"></span>
+                <div class="flex-1"><MonthLetters /></div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn MonthLetters() -> impl IntoView {
+    view! {
+        <div class="flex gap-1 mt-1">
+            {MONTH_LETTERS.into_iter().map(|m| view! {
+                <span class="flex-1 text-center text-stone-400 text-[10px]">{m}</span>
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
@@ -0,0 +1,162 @@
+use leptos::prelude::*;
+use crate::blueprint::Blueprint;
+
+/// Hands the blueprint to the browser as a JSON file download.
+#[cfg(feature = "hydrate")]
+fn trigger_download(blueprint: &Blueprint) -> Result<(), String> {
+    use wasm_bindgen::JsCast;
+
+    let json = serde_json::to_string_pretty(blueprint)
+        .map_err(|e| format!("Couldn't prepare blueprint: {}", e))?;
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let link = document.create_element("a")
+        .map_err(|_| "Couldn't create download link".to_string())?;
+    let href = format!("data:application/json;charset=utf-8,{}", js_sys::encode_uri_component(&json));
+    let _ = link.set_attribute("href", &href);
+    let _ = link.set_attribute("download", &format!("orchid-blueprint-{}.json", blueprint.exported_at.format("%Y-%m-%d")));
+    link.dyn_into::<web_sys::HtmlElement>()
+        .map_err(|_| "Couldn't create download link".to_string())?
+        .click();
+    Ok(())
+}
+
+/// Reads the chosen file as text.
+#[cfg(feature = "hydrate")]
+async fn read_file(file: web_sys::File) -> Result<String, String> {
+    let file = gloo_file::File::from(file);
+    if file.size() as usize > crate::blueprint::MAX_BLUEPRINT_BYTES {
+        return Err("Blueprint file is too large (max 512 KB)".into());
+    }
+    gloo_file::futures::read_as_text(&file).await
+        .map_err(|e| format!("Couldn't read file: {}", e))
+}
+
+/// Export and import of zone, device, goal and rule configuration, without plant data or credentials.
+#[component]
+pub fn BlueprintTransfer(
+    on_imported: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let pending = RwSignal::new(None::<Blueprint>);
+    let is_busy = RwSignal::new(false);
+    let error_msg = RwSignal::new(None::<String>);
+    let result_msg = RwSignal::new(None::<String>);
+    let file_input_ref = NodeRef::<leptos::html::Input>::new();
+    let care_rules = use_context::<crate::components::care_rules::CareRules>();
+
+    let export = move |_| {
+        is_busy.set(true);
+        error_msg.set(None);
+        result_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::blueprint::export_blueprint().await {
+                Ok(_blueprint) => {
+                    #[cfg(feature = "hydrate")]
+                    {
+                        crate::server_fns::telemetry::emit_info("settings.blueprint_export", "Blueprint exported", &[]);
+                        if let Err(e) = trigger_download(&_blueprint) {
+                            error_msg.set(Some(e));
+                        }
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.blueprint_export", &format!("Blueprint export failed: {}", e), &[]);
+                    error_msg.set(Some(e.to_string()));
+                }
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let on_file = move |_| {
+        error_msg.set(None);
+        result_msg.set(None);
+        #[cfg(feature = "hydrate")]
+        {
+            let Some(input) = file_input_ref.get() else { return };
+            let input_el: &web_sys::HtmlInputElement = input.as_ref();
+            let Some(file) = input_el.files().and_then(|f| f.get(0)) else { return };
+            leptos::task::spawn_local(async move {
+                match read_file(file).await.and_then(|text| Blueprint::parse(&text)) {
+                    Ok(blueprint) => pending.set(Some(blueprint)),
+                    Err(e) => {
+                        crate::server_fns::telemetry::emit_warn("settings.blueprint_read", &format!("Blueprint file rejected: {}", e), &[]);
+                        pending.set(None);
+                        error_msg.set(Some(e));
+                    }
+                }
+            });
+        }
+    };
+
+    let do_import = move |_| {
+        let Some(blueprint) = pending.get_untracked() else { return };
+        is_busy.set(true);
+        error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::blueprint::import_blueprint(blueprint).await {
+                Ok(summary) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.blueprint_import", "Blueprint imported", &[
+                        ("zones", &summary.zones_created.to_string()),
+                        ("devices", &summary.devices_created.to_string()),
+                        ("rules", &summary.rules_created.to_string()),
+                    ]);
+                    let mut msg = format!(
+                        "Added {} zones, {} devices, {} goals and {} rules.",
+                        summary.zones_created, summary.devices_created, summary.goals_set, summary.rules_created
+                    );
+                    for warning in &summary.warnings {
+                        msg.push_str(&format!(" {}.", warning));
+                    }
+                    result_msg.set(Some(msg));
+                    pending.set(None);
+                    if let Some(rules) = care_rules
+                        && let Ok(fresh) = crate::server_fns::care_rules::get_care_rules().await {
+                        rules.0.set(fresh);
+                    }
+                    on_imported();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.blueprint_import", &format!("Blueprint import failed: {}", e), &[]);
+                    error_msg.set(Some(e.to_string()));
+                }
+            }
+            is_busy.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-2 items-center mb-2">
+            <button
+                class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20 disabled:opacity-50"
+                disabled=move || is_busy.get()
+                on:click=export
+            >"Export Blueprint"</button>
+            <input type="file" accept=".json,application/json"
+                class="text-xs text-stone-500"
+                node_ref=file_input_ref
+                on:change=on_file
+            />
+        </div>
+        {move || pending.get().map(|blueprint| view! {
+            <div class="flex justify-between items-center p-3 text-sm rounded-lg bg-primary/5 dark:bg-primary-light/5">
+                <span class="text-xs text-stone-600 dark:text-stone-400">{format!("Blueprint from {}: {}", blueprint.exported_at.format("%b %d, %Y"), blueprint.describe())}</span>
+                <button
+                    class="py-1.5 px-3 text-xs font-semibold text-white rounded-lg border-none cursor-pointer bg-primary hover:bg-primary-dark disabled:opacity-50"
+                    disabled=move || is_busy.get()
+                    on:click=do_import
+                >{move || if is_busy.get() { "Importing..." } else { "Import" }}</button>
+            </div>
+        })}
+        {move || result_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-stone-500">{msg}</p>
+        })}
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
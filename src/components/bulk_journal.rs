@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+use leptos::prelude::*;
+use crate::components::event_type_picker::EventTypePicker;
+use crate::components::photo_capture::PhotoCapture;
+use crate::orchid::Orchid;
+use crate::server_fns::orchids::MAX_BULK_LOG_PLANTS;
+use super::{BTN_PRIMARY, BTN_SECONDARY};
+
+const BAR: &str = "fixed inset-x-0 bottom-4 z-30 p-4 mx-auto w-[calc(100%-2rem)] max-w-xl rounded-2xl border shadow-xl bg-surface border-stone-200 dark:bg-stone-900 dark:border-stone-700";
+const LINK_BTN: &str = "p-0 text-xs font-medium bg-transparent border-none cursor-pointer text-primary hover:underline dark:text-primary-light";
+
+/// The floating bar shown while the collection is in select mode: how many plants are picked,
+/// select all / clear, and a journal entry form that writes the same note to every selected plant.
+#[component]
+pub fn BulkJournalBar(
+    selected: RwSignal<HashSet<String>>,
+    /// The plants currently shown, for "Select all".
+    visible: Memo<Vec<Orchid>>,
+    /// Called with the updated plants after the entry is saved.
+    on_logged: Callback<Vec<Orchid>>,
+    /// Leaves select mode.
+    on_done: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let count = Memo::new(move |_| selected.with(HashSet::len));
+    let (show_form, set_show_form) = signal(false);
+
+    let select_all = move |_| {
+        let ids: HashSet<String> = visible.with(|list| list.iter().take(MAX_BULK_LOG_PLANTS).map(|o| o.id.clone()).collect());
+        selected.set(ids);
+    };
+
+    view! {
+        <div class=BAR role="region" aria-label="Selected plants">
+            <div class="flex gap-3 justify-between items-center">
+                <div class="flex gap-3 items-baseline">
+                    <span class="text-sm font-semibold text-stone-700 dark:text-stone-200">
+                        {move || match count.get() {
+                            1 => "1 plant selected".to_string(),
+                            n => format!("{} plants selected", n),
+                        }}
+                    </span>
+                    <button type="button" class=LINK_BTN on:click=select_all>"Select all"</button>
+                    <button type="button" class=LINK_BTN on:click=move |_| selected.update(HashSet::clear)>"Clear"</button>
+                </div>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class=BTN_PRIMARY
+                        disabled=move || count.get() == 0
+                        on:click=move |_| set_show_form.update(|s| *s = !*s)
+                    >
+                        {move || if show_form.get() { "Hide Entry" } else { "Add Entry" }}
+                    </button>
+                    <button type="button" class=BTN_SECONDARY on:click=move |_| on_done()>"Done"</button>
+                </div>
+            </div>
+            {move || (show_form.get() && count.get() > 0).then(|| view! {
+                <BulkEntryForm selected=selected on_saved=move |updated| {
+                    set_show_form.set(false);
+                    on_logged.run(updated);
+                } />
+            })}
+        </div>
+    }
+}
+
+/// The note, photo and event type written to every selected plant. The photo is uploaded once and
+/// shared by all the entries.
+#[component]
+fn BulkEntryForm(
+    selected: RwSignal<HashSet<String>>,
+    on_saved: impl Fn(Vec<Orchid>) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (note, set_note) = signal(String::new());
+    let (event_type, set_event_type) = signal(None::<String>);
+    let (staged_photo, set_staged_photo) = signal(None::<String>);
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let clear_staged = std::sync::Arc::new(move || {
+        set_staged_photo.set(None);
+    }) as std::sync::Arc<dyn Fn() + Send + Sync>;
+
+    let save = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let current_note = note.get_untracked();
+        let photo_data_url = staged_photo.get_untracked();
+        let current_type = event_type.get_untracked();
+        if current_note.trim().is_empty() && photo_data_url.is_none() && current_type.is_none() {
+            set_error_msg.set(Some("Add a note, photo or event type".into()));
+            return;
+        }
+        let ids: Vec<String> = selected.get_untracked().into_iter().collect();
+        if ids.len() > MAX_BULK_LOG_PLANTS {
+            set_error_msg.set(Some(format!("Select at most {} plants", MAX_BULK_LOG_PLANTS)));
+            return;
+        }
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+
+        leptos::task::spawn_local(async move {
+            let image_filename = if let Some(_data_url) = photo_data_url {
+                #[cfg(feature = "hydrate")]
+                {
+                    match crate::components::photo_capture::upload_data_url(&_data_url).await {
+                        Ok(fname) => Some(fname),
+                        Err(e) => {
+                            crate::server_fns::telemetry::emit_error("bulk_journal.upload_photo", &format!("Photo upload failed: {}", e), &[]);
+                            set_error_msg.set(Some(format!("Photo upload failed: {}", e)));
+                            set_is_saving.set(false);
+                            return;
+                        }
+                    }
+                }
+                #[cfg(not(feature = "hydrate"))]
+                { None }
+            } else {
+                None
+            };
+
+            let _count = ids.len().to_string();
+            match crate::server_fns::orchids::add_log_entry_bulk(ids, current_note, image_filename, current_type).await {
+                Ok(updated) => on_saved(updated),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("bulk_journal.add", &format!("Failed to add bulk log entry: {}", e), &[("count", &_count)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <form class="pt-3 mt-3 border-t border-stone-200 dark:border-stone-700" on:submit=save>
+            <div class="mb-3">
+                <EventTypePicker selected=event_type on_select=move |t| set_event_type.set(t) />
+            </div>
+            <div class="mb-3">
+                <textarea
+                    prop:value=note
+                    on:input=move |ev| set_note.set(event_target_value(&ev))
+                    placeholder="e.g. Preventative fungicide spray"
+                    rows="2"
+                    maxlength="5000"
+                    class="py-2 px-3 w-full text-sm bg-white rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600 dark:text-stone-200"
+                ></textarea>
+            </div>
+            <div class="mb-3">
+                <PhotoCapture
+                    on_photo_ready=move |data_url| set_staged_photo.set(Some(data_url))
+                    on_clear=clear_staged.clone()
+                />
+            </div>
+            {move || error_msg.get().map(|e| view! { <p class="mt-0 mb-2 text-xs text-danger">{e}</p> })}
+            <button type="submit" class=BTN_PRIMARY disabled=move || is_saving.get()>
+                {move || if is_saving.get() {
+                    "Saving...".to_string()
+                } else {
+                    match selected.with(HashSet::len) {
+                        1 => "Log for 1 plant".to_string(),
+                        n => format!("Log for {} plants", n),
+                    }
+                }}
+            </button>
+        </form>
+    }
+}
@@ -0,0 +1,338 @@
+use chrono::{Duration, NaiveDate, Utc};
+use leptos::prelude::*;
+use crate::components::browser_utc_offset_minutes;
+use crate::orchid::Orchid;
+use crate::server_fns::orchids::{PhotoAssignment, MAX_BULK_PHOTOS};
+use crate::thumbnails::{thumbnail_url, ThumbSize};
+
+const INPUT_SM: &str = "py-1 px-2 w-full text-xs bg-white rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600 dark:text-stone-200";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Where one photo in the queue has got to.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(not(feature = "hydrate"), allow(dead_code))]
+enum UploadStatus {
+    Waiting,
+    Uploading,
+    /// Uploaded; holds the stored filename.
+    Uploaded(String),
+    Failed(String),
+    /// Its journal entry exists.
+    Saved,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct QueuedPhoto {
+    key: usize,
+    name: String,
+    status: UploadStatus,
+    orchid_id: String,
+    /// `YYYY-MM-DD`, in the browser's time zone.
+    date: String,
+}
+
+/// The photo's date as shown in the browser, from its file timestamp (milliseconds since the epoch).
+#[cfg(feature = "hydrate")]
+fn local_date_from_millis(ms: f64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms as i64)
+        .map(|at| (at + Duration::minutes(browser_utc_offset_minutes() as i64)).date_naive())
+        .unwrap_or_else(|| Utc::now().date_naive())
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+/// Local noon on `date`, so a back-dated entry lands on the picked day in every time zone nearby.
+fn taken_at_for(date: NaiveDate) -> chrono::DateTime<Utc> {
+    date.and_hms_opt(12, 0, 0).unwrap_or_default().and_utc() - Duration::minutes(browser_utc_offset_minutes() as i64)
+}
+
+/// Bulk photo upload for moving a camera roll in: drop many photos (or pick a folder), watch
+/// them upload one by one, give each a plant and date, then save them all as journal entries.
+#[component]
+pub fn BulkPhotoUpload() -> impl IntoView {
+    let orchids = Resource::new(|| (), |_| crate::server_fns::orchids::get_orchids());
+    let queue = RwSignal::new(Vec::<QueuedPhoto>::new());
+    let next_key = StoredValue::new(0usize);
+    let (is_dragging, set_is_dragging) = signal(false);
+    let (is_saving, set_is_saving) = signal(false);
+    let (message, set_message) = signal(None::<String>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let files_ref = NodeRef::<leptos::html::Input>::new();
+    let folder_ref = NodeRef::<leptos::html::Input>::new();
+    // Only used in #[cfg(feature = "hydrate")] blocks
+    let _ = (&next_key, &files_ref, &folder_ref);
+
+    let update = move |key: usize, f: &dyn Fn(&mut QueuedPhoto)| {
+        queue.update(|q| {
+            if let Some(p) = q.iter_mut().find(|p| p.key == key) {
+                f(p);
+            }
+        });
+    };
+
+    // Queue the images among `files`, then resize and upload them one at a time
+    #[cfg(feature = "hydrate")]
+    let add_files = move |files: web_sys::FileList| {
+        let mut batch = Vec::new();
+        for i in 0..files.length() {
+            let Some(file) = files.get(i) else { continue };
+            if !file.type_().starts_with("image/") {
+                continue;
+            }
+            let key = next_key.get_value();
+            next_key.set_value(key + 1);
+            queue.update(|q| q.push(QueuedPhoto {
+                key,
+                name: file.name(),
+                status: UploadStatus::Waiting,
+                orchid_id: String::new(),
+                date: local_date_from_millis(file.last_modified()),
+            }));
+            batch.push((key, file));
+        }
+        set_message.set(None);
+
+        leptos::task::spawn_local(async move {
+            use crate::components::photo_capture::{resize_to_data_url, upload_data_url};
+
+            for (key, file) in batch {
+                update(key, &|p| p.status = UploadStatus::Uploading);
+                let result = match web_sys::Url::create_object_url_with_blob(&file) {
+                    Ok(blob_url) => {
+                        let resized = resize_to_data_url(&blob_url).await;
+                        let _ = web_sys::Url::revoke_object_url(&blob_url);
+                        match resized {
+                            Ok(data_url) => upload_data_url(&data_url).await,
+                            Err(e) => Err(e),
+                        }
+                    }
+                    Err(_) => Err("Failed to read image file".to_string()),
+                };
+                match result {
+                    Ok(filename) => update(key, &|p| p.status = UploadStatus::Uploaded(filename.clone())),
+                    Err(e) => {
+                        crate::server_fns::telemetry::emit_warn("bulk_photos.upload", &format!("Photo upload failed: {}", e), &[]);
+                        update(key, &|p| p.status = UploadStatus::Failed(e.clone()));
+                    }
+                }
+            }
+        });
+    };
+
+    let on_pick = move |_ev: leptos::ev::Event| {
+        #[cfg(feature = "hydrate")]
+        for input in [files_ref.get(), folder_ref.get()].into_iter().flatten() {
+            let input_el: &web_sys::HtmlInputElement = input.as_ref();
+            if let Some(files) = input_el.files()
+                && files.length() > 0
+            {
+                add_files(files);
+                input_el.set_value("");
+            }
+        }
+    };
+
+    let on_drop = move |ev: leptos::ev::DragEvent| {
+        ev.prevent_default();
+        set_is_dragging.set(false);
+        #[cfg(feature = "hydrate")]
+        if let Some(files) = ev.data_transfer().and_then(|dt| dt.files()) {
+            add_files(files);
+        }
+    };
+
+    let assign_all = move |orchid_id: String| {
+        queue.update(|q| {
+            for p in q.iter_mut().filter(|p| p.orchid_id.is_empty() && p.status != UploadStatus::Saved) {
+                p.orchid_id = orchid_id.clone();
+            }
+        });
+    };
+
+    let ready = move || queue.with(|q| q.iter().filter_map(|p| match &p.status {
+        UploadStatus::Uploaded(filename) if !p.orchid_id.is_empty() => {
+            let date = NaiveDate::parse_from_str(&p.date, "%Y-%m-%d").ok()?;
+            Some((p.key, PhotoAssignment {
+                orchid_id: p.orchid_id.clone(),
+                image_filename: filename.clone(),
+                taken_at: taken_at_for(date),
+                note: String::new(),
+            }))
+        }
+        _ => None,
+    }).collect::<Vec<_>>());
+
+    let save = move |_| {
+        let batch = ready();
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            let mut saved = 0;
+            for chunk in batch.chunks(MAX_BULK_PHOTOS) {
+                let photos = chunk.iter().map(|(_, a)| a.clone()).collect::<Vec<_>>();
+                match crate::server_fns::orchids::add_photo_entries(photos).await {
+                    Ok(n) => {
+                        saved += n;
+                        for (key, _) in chunk {
+                            update(*key, &|p| p.status = UploadStatus::Saved);
+                        }
+                    }
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_error("bulk_photos.save", &format!("Saving photo entries failed: {}", e), &[]);
+                        set_error_msg.set(Some(e.to_string()));
+                        break;
+                    }
+                }
+            }
+            set_message.set(Some(format!("Added {} photo{} to plant journals.", saved, if saved == 1 { "" } else { "s" })));
+            set_is_saving.set(false);
+        });
+    };
+
+    let progress = move || queue.with(|q| {
+        let total = q.len();
+        let done = q.iter().filter(|p| !matches!(p.status, UploadStatus::Waiting | UploadStatus::Uploading)).count();
+        (done < total).then(|| format!("Uploading {} of {}\u{2026}", done + 1, total))
+    });
+
+    view! {
+        <div>
+            <div
+                class=move || if is_dragging.get() {
+                    "flex flex-col gap-2 justify-center items-center p-5 text-center rounded-xl border-2 border-dashed transition-colors border-primary-light bg-primary-light/5"
+                } else {
+                    "flex flex-col gap-2 justify-center items-center p-5 text-center rounded-xl border-2 border-dashed transition-colors border-stone-300 dark:border-stone-600"
+                }
+                on:drop=on_drop
+                on:dragover=move |ev: leptos::ev::DragEvent| { ev.prevent_default(); set_is_dragging.set(true); }
+                on:dragleave=move |ev: leptos::ev::DragEvent| { ev.prevent_default(); set_is_dragging.set(false); }
+            >
+                <div class="text-sm text-stone-500 dark:text-stone-400">"Drop photos here, or"</div>
+                <div class="flex gap-2">
+                    <label class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)>
+                        "Choose Photos"
+                        <input node_ref=files_ref type="file" accept="image/jpeg,image/png,image/webp" multiple class="hidden" on:change=on_pick />
+                    </label>
+                    <label class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)>
+                        "Choose Folder"
+                        <input node_ref=folder_ref type="file" prop:webkitdirectory=true multiple class="hidden" on:change=on_pick />
+                    </label>
+                </div>
+            </div>
+
+            {move || progress().map(|text| view! {
+                <p class="mt-2 mb-0 text-xs text-stone-500 dark:text-stone-400">{text}</p>
+            })}
+
+            <Show when=move || queue.with(|q| !q.is_empty())>
+                <Transition fallback=|| ()>
+                    {move || orchids.get().map(|result| {
+                        let mut list: Vec<Orchid> = result.unwrap_or_default();
+                        list.sort_by_key(|o| o.name.to_lowercase());
+                        let list = StoredValue::new(list);
+                        let options = move || list.get_value().into_iter().map(|o| view! {
+                            <option value=o.id.clone()>{o.name}</option>
+                        }).collect::<Vec<_>>();
+                        view! {
+                            <div class="flex gap-2 items-center mt-3 mb-2">
+                                <span class="text-xs text-stone-500 dark:text-stone-400 shrink-0">"Assign unassigned to"</span>
+                                <select class=INPUT_SM
+                                    prop:value=""
+                                    on:change=move |ev| {
+                                        let id = event_target_value(&ev);
+                                        if !id.is_empty() {
+                                            assign_all(id);
+                                        }
+                                    }
+                                >
+                                    <option value="">"Pick a plant\u{2026}"</option>
+                                    {options()}
+                                </select>
+                            </div>
+                            <ul class="overflow-y-auto p-0 m-0 list-none max-h-[420px]">
+                                <For
+                                    each=move || queue.with(|q| q.iter().map(|p| p.key).collect::<Vec<_>>())
+                                    key=|key| *key
+                                    children=move |key| {
+                                        let photo = move || queue.with(|q| q.iter().find(|p| p.key == key).cloned());
+                                        let status = move || photo().map(|p| p.status).unwrap_or(UploadStatus::Waiting);
+                                        view! {
+                                            <li class="flex gap-2 items-center py-1.5 border-b last:border-b-0 border-stone-100 dark:border-stone-800">
+                                                <div class="overflow-hidden w-12 h-12 rounded-lg shrink-0 bg-stone-100 dark:bg-stone-800">
+                                                    {move || match status() {
+                                                        UploadStatus::Uploaded(filename) => view! {
+                                                            <img src=thumbnail_url(&filename, ThumbSize::Small) class="object-cover w-full h-full" alt="" loading="lazy" />
+                                                        }.into_any(),
+                                                        _ => ().into_any(),
+                                                    }}
+                                                </div>
+                                                <div class="flex-1 min-w-0">
+                                                    <div class="text-xs truncate text-stone-600 dark:text-stone-300">{move || photo().map(|p| p.name).unwrap_or_default()}</div>
+                                                    <div class="text-[11px]">
+                                                        {move || match status() {
+                                                            UploadStatus::Waiting => view! { <span class="text-stone-400">"Waiting"</span> }.into_any(),
+                                                            UploadStatus::Uploading => view! { <span class="text-stone-400">"Uploading\u{2026}"</span> }.into_any(),
+                                                            UploadStatus::Uploaded(_) => ().into_any(),
+                                                            UploadStatus::Failed(e) => view! { <span class="text-red-600 dark:text-red-400">{e}</span> }.into_any(),
+                                                            UploadStatus::Saved => view! { <span class="text-primary">"\u{2713} Saved"</span> }.into_any(),
+                                                        }}
+                                                    </div>
+                                                </div>
+                                                <Show when=move || matches!(status(), UploadStatus::Uploaded(_))>
+                                                    <select class=format!("{} max-w-[150px]", INPUT_SM)
+                                                        prop:value=move || photo().map(|p| p.orchid_id).unwrap_or_default()
+                                                        on:change=move |ev| {
+                                                            let id = event_target_value(&ev);
+                                                            update(key, &|p| p.orchid_id = id.clone());
+                                                        }
+                                                    >
+                                                        <option value="">"Plant\u{2026}"</option>
+                                                        {options()}
+                                                    </select>
+                                                    <input type="date" class=format!("{} w-[130px]", INPUT_SM)
+                                                        prop:value=move || photo().map(|p| p.date).unwrap_or_default()
+                                                        on:change=move |ev| {
+                                                            let date = event_target_value(&ev);
+                                                            update(key, &|p| p.date = date.clone());
+                                                        }
+                                                    />
+                                                </Show>
+                                            </li>
+                                        }
+                                    }
+                                />
+                            </ul>
+                        }
+                    })}
+                </Transition>
+
+                <div class="flex gap-2 justify-end items-center mt-3">
+                    <button
+                        class=format!("{} text-stone-600 bg-stone-100 hover:bg-stone-200 dark:bg-stone-800 dark:text-stone-300", BTN_SM)
+                        on:click=move |_| queue.update(|q| q.retain(|p| !matches!(p.status, UploadStatus::Saved | UploadStatus::Failed(_))))
+                    >
+                        "Clear Finished"
+                    </button>
+                    <button
+                        class=format!("{} text-white bg-primary hover:bg-primary-dark disabled:opacity-50", BTN_SM)
+                        disabled=move || is_saving.get() || ready().is_empty()
+                        on:click=save
+                    >
+                        {move || match (is_saving.get(), ready().len()) {
+                            (true, _) => "Saving...".to_string(),
+                            (false, n) => format!("Save {} to Journals", n),
+                        }}
+                    </button>
+                </div>
+            </Show>
+
+            {move || message.get().map(|msg| view! {
+                <p class="mt-2 mb-0 text-xs text-primary">{msg}</p>
+            })}
+            {move || error_msg.get().map(|msg| view! {
+                <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+            })}
+        </div>
+    }
+}
@@ -28,6 +28,8 @@ pub fn OrchidCabinetTable(
     on_delete: impl Fn(String) + 'static + Copy + Send + Sync,
     on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     on_update: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+    /// Marks several plants watered at once; shows a "Water N due" button on each zone when set.
+    on_water_all: Option<Callback<Vec<String>>>,
 ) -> impl IntoView {
     let (drag_target, set_drag_target) = signal::<Option<String>>(None);
 
@@ -65,6 +67,17 @@ pub fn OrchidCabinetTable(
             }
         });
 
+        let due_ids = Memo::new(move |_| {
+            let snaps = climate_snapshots.map(|m| m.get()).unwrap_or_default();
+            let hemi = Hemisphere::from_code(&hemisphere.map(|m| m.get()).unwrap_or_else(|| "N".to_string()));
+            zone_orchids
+                .get()
+                .into_iter()
+                .filter(|o| o.is_climate_due(&hemi, snaps.iter().find(|s| s.zone_name == o.placement)))
+                .map(|o| o.id)
+                .collect::<Vec<_>>()
+        });
+
         let handle_drop = move |ev: leptos::ev::DragEvent| {
             ev.prevent_default();
             set_drag_target.set(None);
@@ -108,7 +121,23 @@ pub fn OrchidCabinetTable(
                 on:dragleave=move |_| set_drag_target.set(None)
                 on:drop=handle_drop
             >
-                <h3 class="pb-2 mt-0 border-b text-primary border-stone-200 dark:border-stone-700">{display_name}</h3>
+                <div class="flex gap-3 justify-between items-center pb-2 border-b border-stone-200 dark:border-stone-700">
+                    <h3 class="m-0 text-primary">{display_name}</h3>
+                    {move || on_water_all.and_then(|water_all| {
+                        let ids = due_ids.get();
+                        (!ids.is_empty()).then(|| {
+                            let count = ids.len();
+                            view! {
+                                <button
+                                    class="py-1.5 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-sky-700 bg-sky-50 hover:bg-sky-100 dark:text-sky-300 dark:bg-sky-900/30 dark:hover:bg-sky-900/50"
+                                    on:click=move |_| water_all.run(ids.clone())
+                                >
+                                    {format!("Water {} due", count)}
+                                </button>
+                            }
+                        })
+                    })}
+                </div>
                 <OrchidTableSection
                     orchids=zone_orchids
                     zones=zones
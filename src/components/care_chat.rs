@@ -0,0 +1,158 @@
+use leptos::prelude::*;
+use crate::chat::{ChatMessage, ChatRole, MAX_CHAT_MESSAGE_LEN};
+use super::{MODAL_OVERLAY, MODAL_CONTENT, MODAL_HEADER, BTN_PRIMARY, BTN_CLOSE, BTN_DANGER};
+
+const CHAT_INPUT: &str = "flex-1 min-w-0 py-2.5 px-3.5 text-sm rounded-xl border outline-none resize-none transition-all duration-200 bg-white/60 border-stone-200/80 placeholder:text-stone-500 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/60 dark:border-stone-600/60 dark:placeholder:text-stone-400 dark:focus:bg-stone-800";
+const BUBBLE_USER: &str = "self-end py-2 px-3.5 max-w-[85%] text-sm text-white whitespace-pre-wrap rounded-2xl rounded-br-sm bg-primary";
+const BUBBLE_ASSISTANT: &str = "self-start py-2 px-3.5 max-w-[85%] text-sm whitespace-pre-wrap rounded-2xl rounded-bl-sm text-stone-700 bg-stone-100 dark:text-stone-200 dark:bg-stone-800";
+
+/// Modal chat with the AI care assistant. Answers are grounded in the grower's own plants, journals and
+/// zone climate, and the conversation is kept per person across sessions.
+#[component]
+pub fn CareChatPanel(
+    on_close: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (messages, set_messages) = signal(Vec::<ChatMessage>::new());
+    let (draft, set_draft) = signal(String::new());
+    let (is_loading, set_is_loading) = signal(true);
+    let (is_sending, set_is_sending) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+    let list_ref = NodeRef::<leptos::html::Div>::new();
+
+    let scroll_to_end = move || {
+        if let Some(el) = list_ref.get_untracked() {
+            el.set_scroll_top(el.scroll_height());
+        }
+    };
+
+    leptos::task::spawn_local(async move {
+        match crate::server_fns::chat::get_chat_history().await {
+            Ok(history) => set_messages.set(history),
+            Err(_e) => {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("chat.load_history", &format!("Failed to load chat history: {}", _e), &[]);
+                set_error.set(Some("Couldn't load earlier messages".into()));
+            }
+        }
+        set_is_loading.set(false);
+        request_animation_frame(scroll_to_end);
+    });
+
+    let send = move || {
+        let question = draft.get_untracked().trim().to_string();
+        if question.is_empty() || is_sending.get_untracked() {
+            return;
+        }
+        // Show the question right away; only the answer comes back from the server
+        set_messages.update(|list| list.push(ChatMessage {
+            id: String::new(),
+            role: ChatRole::User,
+            content: question.clone(),
+            created_at: chrono::Utc::now(),
+        }));
+        set_draft.set(String::new());
+        set_error.set(None);
+        set_is_sending.set(true);
+        request_animation_frame(scroll_to_end);
+
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::chat::send_chat_message(question.clone(), None).await {
+                Ok(answer) => set_messages.update(|list| list.push(answer)),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("chat.send", &format!("Chat message failed: {}", e), &[]);
+                    // Put the question back so it can be retried
+                    set_messages.update(|list| { list.pop(); });
+                    set_draft.set(question);
+                    set_error.set(Some(format!("No answer: {}", e)));
+                }
+            }
+            set_is_sending.set(false);
+            request_animation_frame(scroll_to_end);
+        });
+    };
+
+    let clear = move |_| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::chat::clear_chat_history().await {
+                Ok(()) => {
+                    set_messages.set(Vec::new());
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("chat.clear", &format!("Failed to clear chat: {}", e), &[]);
+                    set_error.set(Some(format!("Couldn't clear: {}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class=MODAL_OVERLAY>
+            <div class=MODAL_CONTENT>
+                <div class=MODAL_HEADER>
+                    <div>
+                        <h2 class="m-0">"Ask About Your Plants"</h2>
+                        <p class="mt-1 mb-0 text-xs text-stone-500 dark:text-stone-400">"Answers use your plants' journals, zone climate and species profiles"</p>
+                    </div>
+                    <div class="flex gap-2 items-center">
+                        {move || (!messages.get().is_empty()).then(|| view! {
+                            <button class=BTN_DANGER disabled=move || is_sending.get() on:click=clear>"Clear"</button>
+                        })}
+                        <button class=BTN_CLOSE on:click=move |_| on_close()>"Close"</button>
+                    </div>
+                </div>
+
+                <div node_ref=list_ref class="flex overflow-y-auto flex-col gap-2.5 mb-4 min-h-[200px] max-h-[50vh]">
+                    {move || if is_loading.get() {
+                        view! { <p class="m-auto text-sm text-stone-400">"Loading..."</p> }.into_any()
+                    } else if messages.get().is_empty() {
+                        view! {
+                            <p class="m-auto max-w-xs text-sm text-center text-stone-400">
+                                "Ask anything, like \u{201C}Why is my Dendrobium dropping leaves?\u{201D} Mention a plant by name or species to bring in its journal."
+                            </p>
+                        }.into_any()
+                    } else {
+                        messages.get().into_iter().map(|m| view! {
+                            <div class=if m.role == ChatRole::User { BUBBLE_USER } else { BUBBLE_ASSISTANT }>{m.content}</div>
+                        }).collect::<Vec<_>>().into_any()
+                    }}
+                    {move || is_sending.get().then(|| view! {
+                        <div class=BUBBLE_ASSISTANT>
+                            <span class="text-stone-400 animate-pulse">"Looking through your collection..."</span>
+                        </div>
+                    })}
+                </div>
+
+                {move || error.get().map(|msg| view! {
+                    <div class="p-2 mb-3 text-xs text-red-700 bg-red-50 rounded-lg dark:text-red-300 dark:bg-red-900/20">{msg}</div>
+                })}
+
+                <div class="flex gap-2 items-end">
+                    <textarea
+                        class=CHAT_INPUT
+                        rows="2"
+                        maxlength=MAX_CHAT_MESSAGE_LEN.to_string()
+                        placeholder="Ask a question about your plants"
+                        aria-label="Question for the care assistant"
+                        prop:value=draft
+                        on:input=move |ev| set_draft.set(event_target_value(&ev))
+                        on:keydown=move |ev| {
+                            // Enter sends; Shift+Enter adds a line
+                            if ev.key() == "Enter" && !ev.shift_key() {
+                                ev.prevent_default();
+                                send();
+                            }
+                        }
+                    ></textarea>
+                    <button
+                        class=BTN_PRIMARY
+                        disabled=move || is_sending.get() || draft.get().trim().is_empty()
+                        on:click=move |_| send()
+                    >"Send"</button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,60 @@
+use leptos::prelude::*;
+use crate::care_confidence::{assess, last_photo_at, ConfidenceLevel};
+use crate::orchid::{LogEntry, Orchid};
+
+/// How many missing-field prompts the card shows at once.
+const MAX_PROMPTS: usize = 3;
+
+/// A plant's care confidence score with prompts for its most impactful missing fields.
+///
+/// `on_edit` opens the edit form; it is omitted in read-only views.
+#[component]
+pub fn CareConfidenceCard(
+    orchid_signal: ReadSignal<Orchid>,
+    log_entries: ReadSignal<Vec<LogEntry>>,
+    #[prop(optional, into)] on_edit: Option<Callback<()>>,
+) -> impl IntoView {
+    view! {
+        {move || {
+            let confidence = log_entries.with(|entries| {
+                assess(&orchid_signal.get(), last_photo_at(entries), chrono::Utc::now())
+            });
+            let (bar, text) = match confidence.level {
+                ConfidenceLevel::Low => ("bg-amber-400", "text-amber-700 dark:text-amber-400"),
+                ConfidenceLevel::Fair => ("bg-sky-400", "text-sky-700 dark:text-sky-400"),
+                ConfidenceLevel::High => ("bg-emerald-500", "text-emerald-700 dark:text-emerald-400"),
+            };
+            let can_edit = on_edit.is_some() && confidence.missing.iter().take(MAX_PROMPTS).any(|f| f.is_editable());
+
+            view! {
+                <div class="p-4 mb-4 rounded-xl border border-stone-200 bg-stone-50 dark:border-stone-700 dark:bg-stone-800/50">
+                    <div class="flex gap-3 justify-between items-baseline mb-2">
+                        <h3 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Care Confidence"</h3>
+                        <span class=format!("text-sm font-semibold tabular-nums {}", text)>
+                            {format!("{} \u{00B7} {}%", confidence.level.label(), confidence.score)}
+                        </span>
+                    </div>
+                    <div class="overflow-hidden h-1.5 rounded-full bg-stone-200 dark:bg-stone-700">
+                        <div class=format!("h-full rounded-full {}", bar) style=format!("width: {}%", confidence.score)></div>
+                    </div>
+                    {(!confidence.missing.is_empty()).then(|| view! {
+                        <ul class="pl-0 mt-3 mb-0 list-none">
+                            {confidence.missing.iter().take(MAX_PROMPTS).map(|f| view! {
+                                <li class="mb-1.5 text-xs leading-relaxed text-stone-600 dark:text-stone-400">
+                                    <span class="font-semibold text-stone-700 dark:text-stone-300">{f.label()}</span>
+                                    {format!(" (+{}): {}", f.weight(), f.prompt())}
+                                </li>
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    })}
+                    {can_edit.then(|| view! {
+                        <button
+                            class="py-1 px-2 mt-1 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                            on:click=move |_| if let Some(cb) = on_edit { cb.run(()) }
+                        >"Fill in details"</button>
+                    })}
+                </div>
+            }
+        }}
+    }
+}
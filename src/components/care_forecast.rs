@@ -0,0 +1,139 @@
+use leptos::prelude::*;
+use chrono::NaiveDate;
+use crate::care_timeline::{care_while_away, ForecastDay, ForecastTaskKind};
+use crate::orchid::Orchid;
+use super::browser_utc_offset_minutes;
+
+const INPUT_SM: &str = "px-2 py-1 text-xs bg-white/80 border border-stone-300/50 rounded-lg outline-none dark:bg-stone-800/80 dark:border-stone-600/50";
+
+/// Two-week calendar of each day's waterings and fertilizings, projected on the server, with a trip planner below.
+#[component]
+pub fn CareForecast(
+    orchids: Memo<Vec<Orchid>>,
+    on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let forecast = Resource::new(|| (), |_| crate::server_fns::care_timeline::get_care_forecast(browser_utc_offset_minutes()));
+    let (away_from, set_away_from) = signal(None::<NaiveDate>);
+    let (away_to, set_away_to) = signal(None::<NaiveDate>);
+
+    let select = move |orchid_id: String| {
+        if let Some(o) = orchids.get_untracked().into_iter().find(|o| o.id == orchid_id) {
+            on_select(o);
+        }
+    };
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="flex flex-wrap gap-2 justify-between items-center mb-3">
+                <h2 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Next 2 Weeks"</h2>
+                <div class="flex flex-wrap gap-3 items-center text-xs text-stone-500 dark:text-stone-400">
+                    <span class="flex gap-1 items-center"><span class="inline-block w-2.5 h-2.5 rounded-sm bg-sky-400/80"></span>"Water"</span>
+                    <span class="flex gap-1 items-center"><span class="inline-block w-2.5 h-2.5 rounded-sm bg-amber-400/80"></span>"Fertilize"</span>
+                </div>
+            </div>
+            <Suspense fallback=|| view! { <p class="py-6 text-sm text-center text-stone-400">"Projecting care dates\u{2026}"</p> }>
+                {move || forecast.get().map(|result| {
+                    let days: Vec<ForecastDay> = match result {
+                        Ok(days) => days,
+                        Err(e) => {
+                            #[cfg(feature = "hydrate")]
+                            crate::server_fns::telemetry::emit_warn("care_forecast.load", &format!("Failed to load care forecast: {}", e), &[]);
+                            return view! { <p class="py-6 text-sm text-center text-danger">{format!("Couldn't load the forecast: {}", e)}</p> }.into_any();
+                        }
+                    };
+                    let first = days.first().map(|d| d.date);
+                    let last = days.last().map(|d| d.date);
+                    let trip_days = days.clone();
+                    view! {
+                        <div class="grid grid-cols-2 gap-2 sm:grid-cols-7">
+                            {days.into_iter().enumerate().map(|(i, day)| {
+                                let away = move || matches!((away_from.get(), away_to.get()), (Some(f), Some(t)) if day.date >= f && day.date <= t);
+                                let label = if i == 0 { "Today".to_string() } else { day.date.format("%a %-d").to_string() };
+                                view! {
+                                    <div class=move || if away() {
+                                        "p-2 rounded-lg border min-h-[88px] border-primary/40 bg-primary/5 dark:border-primary-light/40"
+                                    } else {
+                                        "p-2 rounded-lg border min-h-[88px] border-stone-200 dark:border-stone-700"
+                                    }>
+                                        <div class=if i == 0 { "mb-1 text-[10px] font-bold uppercase text-primary dark:text-primary-light" } else { "mb-1 text-[10px] font-semibold uppercase text-stone-400" }>
+                                            {label}
+                                        </div>
+                                        <ul class="flex flex-col gap-0.5 p-0 m-0 list-none">
+                                            {day.tasks.into_iter().map(|t| {
+                                                let class = match (t.kind, t.overdue) {
+                                                    (ForecastTaskKind::Water, true) => "py-0.5 px-1.5 text-[11px] truncate rounded cursor-pointer bg-danger/15 text-danger",
+                                                    (ForecastTaskKind::Water, false) => "py-0.5 px-1.5 text-[11px] truncate rounded cursor-pointer bg-sky-100 text-sky-800 dark:bg-sky-900/40 dark:text-sky-200",
+                                                    (ForecastTaskKind::Fertilize, _) => "py-0.5 px-1.5 text-[11px] truncate rounded cursor-pointer bg-amber-100 text-amber-800 dark:bg-amber-900/40 dark:text-amber-200",
+                                                };
+                                                let title = format!(
+                                                    "{} \u{00B7} {}{}",
+                                                    if t.kind == ForecastTaskKind::Water { "Water" } else { "Fertilize" },
+                                                    t.placement,
+                                                    if t.overdue { " \u{00B7} overdue" } else { "" },
+                                                );
+                                                let id = t.orchid_id.clone();
+                                                view! { <li class=class title=title on:click=move |_| select(id.clone())>{t.name}</li> }
+                                            }).collect::<Vec<_>>()}
+                                        </ul>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+
+                        // Trip planner
+                        <div class="pt-3 mt-4 border-t border-stone-200 dark:border-stone-700">
+                            <div class="flex flex-wrap gap-3 items-center mb-2 text-xs text-stone-500 dark:text-stone-400">
+                                <span class="font-semibold">"Going away?"</span>
+                                <label class="flex gap-1 items-center">
+                                    "From"
+                                    <input type="date" class=INPUT_SM
+                                        min=first.map(|d| d.to_string()) max=last.map(|d| d.to_string())
+                                        on:change=move |ev| set_away_from.set(event_target_value(&ev).parse().ok())
+                                    />
+                                </label>
+                                <label class="flex gap-1 items-center">
+                                    "Back"
+                                    <input type="date" class=INPUT_SM
+                                        min=first.map(|d| d.to_string()) max=last.map(|d| d.to_string())
+                                        on:change=move |ev| set_away_to.set(event_target_value(&ev).parse().ok())
+                                    />
+                                </label>
+                            </div>
+                            {move || match (away_from.get(), away_to.get()) {
+                                (Some(from), Some(to)) if from <= to => {
+                                    let plants = care_while_away(&trip_days, from, to);
+                                    if plants.is_empty() {
+                                        return view! { <p class="m-0 text-xs text-stone-500">"Nothing falls due while you're away."</p> }.into_any();
+                                    }
+                                    view! {
+                                        <p class="mt-0 mb-1 text-xs text-stone-500">"Water these before you leave, or ask someone to look after them:"</p>
+                                        <ul class="p-0 m-0 list-none">
+                                            {plants.into_iter().map(|p| {
+                                                let mut parts = Vec::new();
+                                                if p.waterings > 0 {
+                                                    parts.push(if p.waterings == 1 { "1 watering".to_string() } else { format!("{} waterings", p.waterings) });
+                                                }
+                                                if p.fertilizings > 0 {
+                                                    parts.push("fertilize".to_string());
+                                                }
+                                                let id = p.orchid_id.clone();
+                                                view! {
+                                                    <li class="flex gap-2 justify-between py-0.5 text-xs cursor-pointer text-stone-700 dark:text-stone-300" on:click=move |_| select(id.clone())>
+                                                        <span class="font-medium">{p.name}</span>
+                                                        <span class="text-stone-400">{format!("{} \u{00B7} from {}", parts.join(", "), p.first_due.format("%a %-d"))}</span>
+                                                    </li>
+                                                }
+                                            }).collect::<Vec<_>>()}
+                                        </ul>
+                                    }.into_any()
+                                }
+                                (Some(_), Some(_)) => view! { <p class="m-0 text-xs text-danger">"The return date is before the departure date."</p> }.into_any(),
+                                _ => view! { <p class="m-0 text-xs text-stone-400">"Pick your dates to see which plants need care while you're gone."</p> }.into_any(),
+                            }}
+                        </div>
+                    }.into_any()
+                })}
+            </Suspense>
+        </div>
+    }
+}
@@ -0,0 +1,402 @@
+use leptos::prelude::*;
+use crate::care_rules::{format_minutes, CareRule, ClimateMetric, Comparison, RuleAction, RuleTrigger};
+use crate::estimation::{c_to_f, f_to_c};
+use crate::orchid::{GrowingZone, Orchid, SeasonalPhase};
+
+const INPUT_SM: &str = "w-full px-2 py-1.5 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-400 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:focus:bg-stone-800";
+const LABEL_SM: &str = "block mb-1 text-[10px] font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// The collection's care automation rules, provided as context by the home page.
+#[derive(Clone, Copy)]
+pub struct CareRules(pub RwSignal<Vec<CareRule>>);
+
+/// Rule summary for the list, with temperatures in the user's unit.
+fn trigger_summary(rule: &CareRule, zones: &[GrowingZone], plants: &[Orchid], unit: &str) -> String {
+    let name_of = |id: &str| zones.iter().find(|z| z.id == id).map(|z| z.name.clone())
+        .or_else(|| plants.iter().find(|p| p.id == id).map(|p| p.name.clone()));
+    match &rule.trigger {
+        RuleTrigger::Climate { metric: ClimateMetric::Temperature, threshold, .. } if unit == "F" => {
+            let celsius = format!("{}{}", threshold, ClimateMetric::Temperature.unit());
+            rule.describe_trigger(name_of).replace(&celsius, &format!("{:.0}F", c_to_f(*threshold)))
+        }
+        _ => rule.describe_trigger(name_of),
+    }
+}
+
+/// Rule summary of what happens when it fires.
+fn actions_summary(rule: &CareRule) -> String {
+    rule.actions.iter().map(|a| match a {
+        RuleAction::Alert { severity } => format!("{} alert", severity),
+        RuleAction::SuggestCare { suggestion, min_plant_humidity: Some(h) } => format!("suggest \u{201C}{}\u{201D} for plants needing {:.0}%+ humidity", suggestion, h),
+        RuleAction::SuggestCare { suggestion, .. } => format!("suggest \u{201C}{}\u{201D}", suggestion),
+        RuleAction::PauseFertilizer => "pause fertilizer".into(),
+    }).collect::<Vec<_>>().join(", ")
+}
+
+/// Care automation rule list and builder for the settings modal.
+#[component]
+pub fn CareRuleSettings(
+    zones: ReadSignal<Vec<GrowingZone>>,
+    temp_unit: ReadSignal<String>,
+) -> impl IntoView {
+    let shared = use_context::<CareRules>();
+    let rules = Resource::new(|| (), |_| crate::server_fns::care_rules::get_care_rules());
+    let plants = Resource::new(|| (), |_| crate::server_fns::orchids::get_orchids());
+    let plant_list = move || plants.get().and_then(|r| r.ok()).unwrap_or_default();
+    let (local_rules, set_local_rules) = signal(Vec::<CareRule>::new());
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let (is_saving, set_is_saving) = signal(false);
+    let (show_builder, set_show_builder) = signal(false);
+
+    // Builder state
+    let (name, set_name) = signal(String::new());
+    let (trigger_kind, set_trigger_kind) = signal("climate".to_string());
+    // A zone or plant record ID, told apart by table; empty watches every zone
+    let (scope_id, set_scope_id) = signal(String::new());
+    let (metric, set_metric) = signal(ClimateMetric::Temperature);
+    let (comparison, set_comparison) = signal(Comparison::Above);
+    let (threshold, set_threshold) = signal(String::new());
+    let (hours, set_hours) = signal("2".to_string());
+    let (phase, set_phase) = signal("Rest".to_string());
+    let (alert_on, set_alert_on) = signal(true);
+    let (severity, set_severity) = signal("warning".to_string());
+    let (suggest_on, set_suggest_on) = signal(false);
+    let (suggestion, set_suggestion) = signal(String::new());
+    let (humidity_filter, set_humidity_filter) = signal(String::new());
+    let (pause_fert, set_pause_fert) = signal(false);
+
+    Effect::new(move |_| {
+        if let Some(Ok(list)) = rules.get() {
+            set_local_rules.set(list);
+        }
+    });
+
+    // Keep the dashboard's copy (used for fertilizer pauses) in sync
+    let publish = move |list: Vec<CareRule>| {
+        if let Some(ctx) = shared {
+            ctx.0.set(list.clone());
+        }
+        set_local_rules.set(list);
+    };
+
+    let reset_builder = move || {
+        set_name.set(String::new());
+        set_threshold.set(String::new());
+        set_suggestion.set(String::new());
+        set_humidity_filter.set(String::new());
+        set_pause_fert.set(false);
+        set_show_builder.set(false);
+    };
+
+    let build_rule = move || -> Result<CareRule, String> {
+        let trigger = if trigger_kind.get_untracked() == "phase" {
+            let phase = match phase.get_untracked().as_str() {
+                "Active" => SeasonalPhase::Active,
+                "Blooming" => SeasonalPhase::Blooming,
+                _ => SeasonalPhase::Rest,
+            };
+            RuleTrigger::PhaseEntered { phase }
+        } else {
+            let metric = metric.get_untracked();
+            let raw = threshold.get_untracked();
+            let value: f64 = raw.trim().parse().map_err(|_| "Enter a number for the threshold".to_string())?;
+            let threshold = if metric == ClimateMetric::Temperature && temp_unit.get_untracked() == "F" { f_to_c(value) } else { value };
+            let hours: f64 = hours.get_untracked().trim().parse().unwrap_or(0.0);
+            let scope = scope_id.get_untracked();
+            let is_plant = scope.starts_with("orchid:");
+            RuleTrigger::Climate {
+                zone_id: (!scope.is_empty() && !is_plant).then(|| scope.clone()),
+                orchid_id: is_plant.then_some(scope),
+                metric,
+                comparison: comparison.get_untracked(),
+                threshold,
+                for_minutes: (hours.max(0.0) * 60.0).round() as u32,
+            }
+        };
+
+        let mut actions = Vec::new();
+        if alert_on.get_untracked() {
+            actions.push(RuleAction::Alert { severity: severity.get_untracked() });
+        }
+        if suggest_on.get_untracked() {
+            let filter = humidity_filter.get_untracked();
+            let min_plant_humidity = if filter.trim().is_empty() {
+                None
+            } else {
+                Some(filter.trim().parse::<f64>().map_err(|_| "Enter a number for the humidity filter".to_string())?)
+            };
+            actions.push(RuleAction::SuggestCare { suggestion: suggestion.get_untracked().trim().to_string(), min_plant_humidity });
+        }
+        if pause_fert.get_untracked() && matches!(trigger, RuleTrigger::PhaseEntered { .. }) {
+            actions.push(RuleAction::PauseFertilizer);
+        }
+
+        let rule = CareRule {
+            id: String::new(),
+            name: name.get_untracked().trim().to_string(),
+            enabled: true,
+            trigger,
+            actions,
+            last_fired_at: None,
+        };
+        rule.validate()?;
+        Ok(rule)
+    };
+
+    let save = move |rule: CareRule| {
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let is_new = rule.id.is_empty();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::care_rules::save_care_rule(rule).await {
+                Ok(saved) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.care_rule_saved", "Care rule saved", &[("rule_id", &saved.id)]);
+                    let mut list = local_rules.get_untracked();
+                    match list.iter_mut().find(|r| r.id == saved.id) {
+                        Some(existing) => *existing = saved,
+                        None => list.push(saved),
+                    }
+                    publish(list);
+                    if is_new {
+                        reset_builder();
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.care_rule_save", &format!("Failed to save care rule: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let create = move |_| match build_rule() {
+        Ok(rule) => save(rule),
+        Err(msg) => set_error_msg.set(Some(msg)),
+    };
+
+    let delete = move |id: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::care_rules::delete_care_rule(id.clone()).await {
+                Ok(()) => {
+                    let list = local_rules.get_untracked().into_iter().filter(|r| r.id != id).collect();
+                    publish(list);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.care_rule_delete", &format!("Failed to delete care rule: {}", e), &[("rule_id", &id)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-1.5 mb-3">
+            {move || {
+                let list = local_rules.get();
+                if list.is_empty() {
+                    return view! { <p class="mt-0 text-xs text-stone-400">"No rules yet."</p> }.into_any();
+                }
+                let zones = zones.get();
+                let plants = plant_list();
+                let unit = temp_unit.get();
+                list.into_iter().map(|rule| {
+                    let summary = trigger_summary(&rule, &zones, &plants, &unit);
+                    let actions = actions_summary(&rule);
+                    let id = rule.id.clone();
+                    let enabled = rule.enabled;
+                    let toggled = CareRule { enabled: !rule.enabled, ..rule.clone() };
+                    view! {
+                        <div class="flex gap-2 justify-between items-start py-2 px-3 text-sm rounded-lg bg-secondary/30">
+                            <div class=if enabled { "" } else { "opacity-50" }>
+                                <div class="font-medium text-stone-700 dark:text-stone-300">{rule.name}</div>
+                                <div class="text-[11px] text-stone-500">{format!("When: {}", summary)}</div>
+                                <div class="text-[11px] text-stone-400">{format!("Then: {}", actions)}</div>
+                            </div>
+                            <div class="flex gap-1 shrink-0">
+                                <button
+                                    class=format!("{} text-stone-600 bg-stone-100 hover:bg-stone-200 dark:text-stone-300 dark:bg-stone-700", BTN_SM)
+                                    disabled=move || is_saving.get()
+                                    on:click=move |_| save(toggled.clone())
+                                >{if enabled { "Disable" } else { "Enable" }}</button>
+                                <button
+                                    class="py-1 px-2 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                    on:click=move |_| delete(id.clone())
+                                >"Delete"</button>
+                            </div>
+                        </div>
+                    }
+                }).collect::<Vec<_>>().into_any()
+            }}
+        </div>
+
+        {move || if show_builder.get() {
+            view! {
+                <div class="p-4 mb-3 rounded-xl border bg-secondary/30 border-stone-200/60 dark:border-stone-700">
+                    <div class="mb-3">
+                        <label class=LABEL_SM>"Rule name"</label>
+                        <input type="text" class=INPUT_SM placeholder="e.g. Heat wave misting"
+                            prop:value=name
+                            on:input=move |ev| set_name.set(event_target_value(&ev))
+                        />
+                    </div>
+
+                    <div class="mb-3">
+                        <label class=LABEL_SM>"When"</label>
+                        <select class=INPUT_SM
+                            prop:value=trigger_kind
+                            on:change=move |ev| set_trigger_kind.set(event_target_value(&ev))
+                        >
+                            <option value="climate">"A zone reading crosses a threshold"</option>
+                            <option value="phase">"A plant enters a seasonal phase"</option>
+                        </select>
+                    </div>
+
+                    {move || if trigger_kind.get() == "phase" {
+                        view! {
+                            <div class="mb-3">
+                                <label class=LABEL_SM>"Phase"</label>
+                                <select class=INPUT_SM
+                                    prop:value=phase
+                                    on:change=move |ev| set_phase.set(event_target_value(&ev))
+                                >
+                                    <option value="Rest">"Rest"</option>
+                                    <option value="Active">"Active growth"</option>
+                                    <option value="Blooming">"Blooming"</option>
+                                </select>
+                            </div>
+                        }.into_any()
+                    } else {
+                        view! {
+                            <div class="grid grid-cols-2 gap-3 mb-3">
+                                <div>
+                                    <label class=LABEL_SM>"Zone or plant"</label>
+                                    <select class=INPUT_SM
+                                        prop:value=scope_id
+                                        on:change=move |ev| set_scope_id.set(event_target_value(&ev))
+                                    >
+                                        <option value="">"Any zone"</option>
+                                        <optgroup label="Zones">
+                                            {move || zones.get().into_iter().map(|z| view! {
+                                                <option value=z.id.clone()>{z.name.clone()}</option>
+                                            }).collect::<Vec<_>>()}
+                                        </optgroup>
+                                        <optgroup label="Plants (follows the plant's zone)">
+                                            {move || plant_list().into_iter().map(|p| view! {
+                                                <option value=p.id.clone()>{p.name.clone()}</option>
+                                            }).collect::<Vec<_>>()}
+                                        </optgroup>
+                                    </select>
+                                </div>
+                                <div>
+                                    <label class=LABEL_SM>"Reading"</label>
+                                    <select class=INPUT_SM
+                                        on:change=move |ev| if let Some(m) = ClimateMetric::from_key(&event_target_value(&ev)) { set_metric.set(m) }
+                                    >
+                                        {ClimateMetric::ALL.iter().map(|m| view! {
+                                            <option value=m.as_str() selected=move || metric.get() == *m>{m.label()}</option>
+                                        }).collect::<Vec<_>>()}
+                                    </select>
+                                </div>
+                                <div>
+                                    <label class=LABEL_SM>
+                                        {move || match metric.get() {
+                                            ClimateMetric::Temperature => format!("Threshold ({})", temp_unit.get()),
+                                            m => format!("Threshold ({})", m.unit().trim()),
+                                        }}
+                                    </label>
+                                    <div class="flex gap-1">
+                                        <select class=INPUT_SM
+                                            on:change=move |ev| set_comparison.set(if event_target_value(&ev) == "below" { Comparison::Below } else { Comparison::Above })
+                                        >
+                                            <option value="above">"Above"</option>
+                                            <option value="below">"Below"</option>
+                                        </select>
+                                        <input type="number" step="0.1" class=INPUT_SM placeholder="32"
+                                            prop:value=threshold
+                                            on:input=move |ev| set_threshold.set(event_target_value(&ev))
+                                        />
+                                    </div>
+                                </div>
+                                <div>
+                                    <label class=LABEL_SM>"For at least (hours)"</label>
+                                    <input type="number" min="0" step="0.5" class=INPUT_SM
+                                        prop:value=hours
+                                        on:input=move |ev| set_hours.set(event_target_value(&ev))
+                                    />
+                                </div>
+                            </div>
+                        }.into_any()
+                    }}
+
+                    <label class=LABEL_SM>"Then"</label>
+                    <div class="flex flex-col gap-2 mb-3 text-sm text-stone-700 dark:text-stone-300">
+                        <div class="flex gap-2 items-center">
+                            <input type="checkbox" prop:checked=alert_on on:change=move |ev| set_alert_on.set(event_target_checked(&ev)) />
+                            <span>"Create an alert"</span>
+                            <select class="py-1 px-2 text-xs rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50"
+                                prop:value=severity
+                                on:change=move |ev| set_severity.set(event_target_value(&ev))
+                            >
+                                <option value="info">"Info"</option>
+                                <option value="warning">"Warning (push)"</option>
+                                <option value="critical">"Critical (push)"</option>
+                            </select>
+                        </div>
+                        <div class="flex gap-2 items-center">
+                            <input type="checkbox" prop:checked=suggest_on on:change=move |ev| set_suggest_on.set(event_target_checked(&ev)) />
+                            <span>"Suggest a care step for affected plants"</span>
+                        </div>
+                        {move || suggest_on.get().then(|| view! {
+                            <div class="grid grid-cols-3 gap-2 pl-6">
+                                <input type="text" class=format!("col-span-2 {}", INPUT_SM) placeholder="e.g. Mist an extra time today"
+                                    prop:value=suggestion
+                                    on:input=move |ev| set_suggestion.set(event_target_value(&ev))
+                                />
+                                <input type="number" min="0" max="100" class=INPUT_SM placeholder="Min humidity %"
+                                    title="Only plants whose minimum humidity is at least this"
+                                    prop:value=humidity_filter
+                                    on:input=move |ev| set_humidity_filter.set(event_target_value(&ev))
+                                />
+                            </div>
+                        })}
+                        {move || (trigger_kind.get() == "phase").then(|| view! {
+                            <div class="flex gap-2 items-center">
+                                <input type="checkbox" prop:checked=pause_fert on:change=move |ev| set_pause_fert.set(event_target_checked(&ev)) />
+                                <span>"Pause fertilizer reminders during this phase"</span>
+                            </div>
+                        })}
+                    </div>
+
+                    <div class="flex gap-2">
+                        <button class=format!("{} text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                            disabled=move || is_saving.get()
+                            on:click=create
+                        >{move || if is_saving.get() { "Saving..." } else { "Save Rule" }}</button>
+                        <button class=format!("{} text-stone-600 bg-stone-100 hover:bg-stone-200 dark:text-stone-300 dark:bg-stone-700", BTN_SM)
+                            on:click=move |_| reset_builder()
+                        >"Cancel"</button>
+                    </div>
+                    <p class="mt-2 mb-0 text-[11px] text-stone-400">
+                        {format!("Climate rules are checked after each sensor poll and fire at most every {}. An alert rule for a specific zone or plant replaces the built-in temperature or humidity range alert for the plants it covers.", format_minutes(crate::care_rules::RULE_COOLDOWN_HOURS as u32 * 60))}
+                    </p>
+                </div>
+            }.into_any()
+        } else {
+            view! {
+                <button
+                    class="flex gap-2 justify-center items-center py-2 w-full text-sm font-medium rounded-xl border border-dashed transition-colors cursor-pointer text-stone-400 border-stone-300 dark:border-stone-600 hover:text-primary hover:border-primary/40"
+                    on:click=move |_| set_show_builder.set(true)
+                >"+  Add Rule"</button>
+            }.into_any()
+        }}
+
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
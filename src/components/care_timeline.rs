@@ -0,0 +1,158 @@
+use leptos::prelude::*;
+use crate::care_timeline::{build_timeline, CareSpan, CareSpanKind, TIMELINE_DAYS};
+use crate::orchid::{GrowingZone, Hemisphere, Orchid};
+use crate::watering::ClimateSnapshot;
+
+/// Grid columns: a name column, then one column per day.
+fn grid_style() -> String {
+    format!("grid-template-columns: 140px repeat({}, minmax(0, 1fr))", TIMELINE_DAYS)
+}
+
+/// Tailwind classes and tooltip text for a bar.
+fn span_style(span: &CareSpan) -> (&'static str, String) {
+    let days = if span.start_day == span.end_day {
+        format!("day {}", span.start_day)
+    } else {
+        format!("days {}-{}", span.start_day, span.end_day)
+    };
+    match span.kind {
+        CareSpanKind::Water if span.started_earlier => (
+            "h-2 rounded-full bg-danger/70 dark:bg-red-500/60",
+            "Watering overdue".to_string(),
+        ),
+        CareSpanKind::Water => (
+            "h-2 rounded-full bg-sky-400/80 dark:bg-sky-500/60",
+            format!("Watering window, {}", days),
+        ),
+        CareSpanKind::Fertilize => (
+            "h-2 rounded-full bg-amber-400/80 dark:bg-amber-500/60",
+            format!("Fertilize, {}", days),
+        ),
+        CareSpanKind::Rest => (
+            "h-1 rounded-full bg-blue-200 dark:bg-blue-800/50",
+            if span.started_earlier { "Resting".to_string() } else { format!("Rest begins, {}", days) },
+        ),
+    }
+}
+
+/// Gantt-style view of each plant's upcoming watering windows, fertilizer days, and rest periods, filterable by zone.
+#[component]
+pub fn CareTimeline(
+    orchids: Memo<Vec<Orchid>>,
+    zones: Memo<Vec<GrowingZone>>,
+    climate_snapshots: Memo<Vec<ClimateSnapshot>>,
+    hemisphere: Memo<String>,
+    /// The user's default watering grace period, in days past due.
+    overdue_grace_days: Memo<u32>,
+    on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (zone_filter, set_zone_filter) = signal(String::new());
+    let today = chrono::Local::now().date_naive();
+
+    let rows = Memo::new(move |_| {
+        let filter = zone_filter.get();
+        let visible: Vec<Orchid> = orchids.get().into_iter()
+            .filter(|o| filter.is_empty() || o.placement == filter)
+            .collect();
+        build_timeline(
+            &visible,
+            &climate_snapshots.get(),
+            &Hemisphere::from_code(&hemisphere.get()),
+            overdue_grace_days.get(),
+            today,
+        )
+    });
+
+    let day_headers = (0..TIMELINE_DAYS).map(|offset| {
+        let date = today + chrono::Duration::days(offset);
+        let label = if offset == 0 { "Today".to_string() } else { date.format("%-d").to_string() };
+        let class = if offset == 0 {
+            "text-center font-semibold text-primary text-[10px] truncate"
+        } else if chrono::Datelike::weekday(&date).number_from_monday() >= 6 {
+            "text-center text-stone-500 dark:text-stone-400 text-[10px]"
+        } else {
+            "text-center text-stone-400 dark:text-stone-500 text-[10px]"
+        };
+        view! { <div class=class title=date.format("%a %b %-d").to_string()>{label}</div> }
+    }).collect::<Vec<_>>();
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="flex flex-wrap gap-2 justify-between items-center mb-3">
+                <h2 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Next 30 Days"</h2>
+                <div class="flex flex-wrap gap-3 items-center text-xs text-stone-500 dark:text-stone-400">
+                    <span class="flex gap-1 items-center"><span class="inline-block w-2.5 h-2.5 rounded-sm bg-sky-400/80"></span>"Water"</span>
+                    <span class="flex gap-1 items-center"><span class="inline-block w-2.5 h-2.5 rounded-sm bg-amber-400/80"></span>"Fertilize"</span>
+                    <span class="flex gap-1 items-center"><span class="inline-block w-2.5 h-2.5 bg-blue-200 rounded-sm dark:bg-blue-800/50"></span>"Rest"</span>
+                    <select
+                        class="py-1 px-2 text-xs rounded-lg border bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50"
+                        aria-label="Filter by zone"
+                        prop:value=zone_filter
+                        on:change=move |ev| set_zone_filter.set(event_target_value(&ev))
+                    >
+                        <option value="">"All zones"</option>
+                        {move || zones.get().into_iter().map(|z| {
+                            let name = z.name.clone();
+                            view! { <option value=name>{z.name}</option> }
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </div>
+            </div>
+
+            <div class="overflow-x-auto">
+                <div class="min-w-[640px]">
+                    <div class="grid gap-px mb-1" style=grid_style()>
+                        <div></div>
+                        {day_headers}
+                    </div>
+
+                    {move || {
+                        let rows = rows.get();
+                        if rows.is_empty() {
+                            return view! {
+                                <p class="py-6 text-sm text-center text-stone-400 dark:text-stone-500">"No plants in this zone."</p>
+                            }.into_any();
+                        }
+                        view! {
+                            <div class="flex flex-col gap-1">
+                                {rows.into_iter().map(|row| {
+                                    let orchid_id = row.orchid_id.clone();
+                                    let select = move |_| {
+                                        if let Some(o) = orchids.get_untracked().into_iter().find(|o| o.id == orchid_id) {
+                                            on_select(o);
+                                        }
+                                    };
+                                    let bars = row.spans.iter().map(|span| {
+                                        let (class, title) = span_style(span);
+                                        // One lane per kind so same-day bars don't overlap
+                                        let lane = match span.kind {
+                                            CareSpanKind::Water => "flex items-center h-2.5 row-start-1",
+                                            CareSpanKind::Fertilize => "flex items-center h-2.5 row-start-2",
+                                            CareSpanKind::Rest => "flex items-center h-2 row-start-3",
+                                        };
+                                        // Grid lines are 1-based and the name column takes the first
+                                        let style = format!("grid-column: {} / {}", span.start_day + 2, span.end_day + 3);
+                                        view! {
+                                            <div class=lane style=style title=title>
+                                                <div class=format!("w-full {}", class)></div>
+                                            </div>
+                                        }
+                                    }).collect::<Vec<_>>();
+                                    view! {
+                                        <div class="grid gap-px items-center py-0.5 rounded cursor-pointer hover:bg-secondary/40" style=grid_style() on:click=select>
+                                            <div class="col-start-1 row-span-3 row-start-1 pr-2 min-w-0">
+                                                <div class="text-xs font-medium truncate text-stone-700 dark:text-stone-300">{row.name}</div>
+                                                <div class="text-[10px] truncate text-stone-400">{row.placement}</div>
+                                            </div>
+                                            {bars}
+                                        </div>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }.into_any()
+                    }}
+                </div>
+            </div>
+        </div>
+    }
+}
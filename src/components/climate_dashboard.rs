@@ -42,8 +42,10 @@ pub fn ClimateDashboard(
                     let name = r.zone_name.clone();
                     let humidity = r.humidity;
                     let vpd = r.vpd;
+                    let dew_point = r.dew_point.map(|d| if u == "F" { format!("{:.1}", d * 9.0 / 5.0 + 32.0) } else { format!("{:.1}", d) });
                     let ago = format_time_ago(&r.recorded_at);
                     let source = r.source.clone();
+                    let extra = (r.has_outdoor_metrics() || r.has_light_metrics() || r.has_co2_or_moisture()).then(|| r.clone());
 
                     view! {
                         <div class="overflow-hidden p-5 pl-6 mx-auto mb-4 rounded-2xl border shadow-sm bg-surface border-stone-200/60 max-w-[700px] climate-card dark:border-stone-700/60">
@@ -77,8 +79,17 @@ pub fn ClimateDashboard(
                                             <span class="font-medium text-[10px] text-primary/50 dark:text-primary-light/50">"kPa"</span>
                                         </div>
                                     })}
+                                    {dew_point.map(|d| view! {
+                                        <div class="w-px h-8 bg-stone-200 dark:bg-stone-700"></div>
+                                        <div class="flex flex-col items-center climate-value-in" style="animation-delay: 0.15s">
+                                            <span class="font-bold tracking-widest uppercase text-[10px] text-stone-500 dark:text-stone-400">"Dew Pt"</span>
+                                            <span class="text-2xl font-display text-primary dark:text-primary-light">{d}</span>
+                                            <span class="font-medium text-[10px] text-primary/50 dark:text-primary-light/50">{temp_unit_label}</span>
+                                        </div>
+                                    })}
                                 </div>
                             </div>
+                            {extra.map(|reading| view! { <ExtraMetricsPanel reading=reading read_only=read_only /> })}
                         </div>
                     }
                 }).collect::<Vec<_>>()
@@ -150,3 +161,164 @@ pub fn ClimateDashboard(
     }.into_any()
 }
 
+
+const METRIC_CHIP_ACTIVE: &str = "py-1 px-2.5 text-[11px] font-semibold rounded-full border-none cursor-pointer transition-colors text-white bg-amber-500 dark:bg-amber-600";
+const METRIC_CHIP_INACTIVE: &str = "py-1 px-2.5 text-[11px] font-medium rounded-full border-none cursor-pointer transition-colors text-stone-500 bg-stone-100 dark:text-stone-400 dark:bg-stone-800 hover:bg-stone-200 dark:hover:bg-stone-700";
+
+/// Metrics beyond temperature and humidity — weather station UV, solar, and wind, plus light,
+/// CO2, and substrate moisture from dedicated sensors — toggleable in the chart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ExtraMetric {
+    Uv,
+    Solar,
+    Wind,
+    Lux,
+    Ppfd,
+    Co2,
+    Moisture,
+}
+
+impl ExtraMetric {
+    const ALL: [ExtraMetric; 7] = [
+        ExtraMetric::Uv, ExtraMetric::Solar, ExtraMetric::Wind, ExtraMetric::Lux,
+        ExtraMetric::Ppfd, ExtraMetric::Co2, ExtraMetric::Moisture,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ExtraMetric::Uv => "UV",
+            ExtraMetric::Solar => "Solar",
+            ExtraMetric::Wind => "Wind",
+            ExtraMetric::Lux => "Light",
+            ExtraMetric::Ppfd => "PPFD",
+            ExtraMetric::Co2 => "CO\u{2082}",
+            ExtraMetric::Moisture => "Substrate",
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            ExtraMetric::Uv => "index",
+            ExtraMetric::Solar => "W/m\u{00B2}",
+            ExtraMetric::Wind => "m/s",
+            ExtraMetric::Lux => "lux",
+            ExtraMetric::Ppfd => "\u{00B5}mol/m\u{00B2}/s",
+            ExtraMetric::Co2 => "ppm",
+            ExtraMetric::Moisture => "% moisture",
+        }
+    }
+
+    fn value(self, r: &ClimateReading) -> Option<f64> {
+        match self {
+            ExtraMetric::Uv => r.uv_index,
+            ExtraMetric::Solar => r.solar_radiation,
+            ExtraMetric::Wind => r.wind_speed,
+            ExtraMetric::Lux => r.lux,
+            ExtraMetric::Ppfd => r.ppfd,
+            ExtraMetric::Co2 => r.co2_ppm,
+            ExtraMetric::Moisture => r.substrate_moisture_pct,
+        }
+    }
+
+    fn format(self, v: f64) -> String {
+        match self {
+            ExtraMetric::Uv | ExtraMetric::Wind => format!("{:.1}", v),
+            ExtraMetric::Solar | ExtraMetric::Lux | ExtraMetric::Ppfd
+            | ExtraMetric::Co2 | ExtraMetric::Moisture => format!("{:.0}", v),
+        }
+    }
+}
+
+/// UV, solar radiation, wind, light, CO2, and substrate moisture for zones that report them, with per-metric chart toggles.
+/// The 24h chart is only loaded for the signed-in owner (history needs auth).
+#[component]
+fn ExtraMetricsPanel(reading: ClimateReading, read_only: bool) -> impl IntoView {
+    let available: Vec<ExtraMetric> = ExtraMetric::ALL.into_iter()
+        .filter(|m| m.value(&reading).is_some())
+        .collect();
+    let (chart_metric, set_chart_metric) = signal(None::<ExtraMetric>);
+    let zone_id = reading.zone_id.clone();
+    let gust = reading.wind_gust;
+
+    let history = Resource::new(
+        move || chart_metric.get().is_some() && !read_only,
+        move |load| {
+            let zid = zone_id.clone();
+            async move {
+                if load {
+                    crate::server_fns::climate::get_zone_history(zid, 24).await.unwrap_or_default()
+                } else {
+                    Vec::new()
+                }
+            }
+        },
+    );
+
+    view! {
+        <div class="flex flex-wrap gap-4 items-center pt-3 mt-3 border-t border-stone-100 dark:border-stone-700/50">
+            {available.iter().map(|&m| {
+                let v = m.value(&reading).unwrap_or_default();
+                let extra = (m == ExtraMetric::Wind)
+                    .then_some(gust)
+                    .flatten()
+                    .map(|g| format!(" (gust {:.1})", g))
+                    .unwrap_or_default();
+                view! {
+                    <div class="flex gap-1.5 items-baseline">
+                        <span class="font-bold tracking-widest uppercase text-[10px] text-stone-500 dark:text-stone-400">{m.label()}</span>
+                        <span class="text-sm font-semibold text-amber-700 dark:text-amber-400">{m.format(v)}</span>
+                        <span class="text-[10px] text-stone-400">{m.unit()}{extra}</span>
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
+            {(!read_only).then(|| view! {
+                <div class="flex gap-1 ml-auto">
+                    {available.iter().map(|&m| view! {
+                        <button
+                            class=move || if chart_metric.get() == Some(m) { METRIC_CHIP_ACTIVE } else { METRIC_CHIP_INACTIVE }
+                            on:click=move |_| set_chart_metric.update(|cur| {
+                                *cur = if *cur == Some(m) { None } else { Some(m) };
+                            })
+                        >
+                            {m.label()}
+                        </button>
+                    }).collect::<Vec<_>>()}
+                </div>
+            })}
+        </div>
+        <Suspense fallback=|| ()>
+            {move || chart_metric.get().map(|m| {
+                let points: Vec<(chrono::DateTime<chrono::Utc>, f64)> = history.get()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|r| m.value(r).map(|v| (r.recorded_at, v)))
+                    .collect();
+                view! { <ExtraMetricChart metric=m points=points /> }
+            })}
+        </Suspense>
+    }
+}
+
+/// Bar chart of one extra metric over the loaded history window.
+#[component]
+fn ExtraMetricChart(metric: ExtraMetric, points: Vec<(chrono::DateTime<chrono::Utc>, f64)>) -> impl IntoView {
+    if points.is_empty() {
+        return view! {
+            <p class="mt-2 mb-0 text-xs italic text-stone-400">"No readings in the last 24 hours."</p>
+        }.into_any();
+    }
+
+    let max = points.iter().map(|(_, v)| *v).fold(0.0_f64, f64::max).max(f64::EPSILON);
+
+    view! {
+        <div class="flex gap-px items-end mt-3 h-14">
+            {points.into_iter().map(|(at, v)| {
+                let height = format!("height: {}px", ((v / max) * 56.0).max(2.0) as u32);
+                let title = format!("{}: {} {}", at.format("%H:%M"), metric.format(v), metric.unit());
+                view! {
+                    <div class="flex-1 rounded-sm bg-amber-400/60 dark:bg-amber-600/40" style=height title=title></div>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+    }.into_any()
+}
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
-use crate::orchid::{ClimateReading, GrowingZone};
+use crate::daily_extremes::{DayStats, ZoneDailyExtremes};
+use crate::orchid::{Alert, ClimateReading, GrowingZone, ALERT_SNOOZE_HOURS};
 use super::{source_badge, format_time_ago};
 
 // ── Grid-aligned Tailwind class constants ────────────────────────────
@@ -28,7 +29,82 @@ const CELL_DESKTOP: &str = "hidden sm:block text-right tabular-nums text-sm";
 /// Mobile-only compact value row.
 const CELL_MOBILE_ROW: &str = "flex sm:hidden gap-3 mt-1 text-sm";
 
+/// Full-width line under a zone row with yesterday's and today's ranges.
+const RANGE_ROW: &str = "flex flex-wrap gap-x-4 gap-y-0.5 mt-1 text-[11px] tabular-nums sm:col-span-5 text-stone-400 dark:text-stone-500";
+
+/// Full-width line under a zone row listing the zone's open alerts.
+const ALERT_ROW: &str = "flex flex-col gap-1 mt-2 sm:col-span-5";
+
+/// Small pill button for acknowledging or snoozing an alert.
+const ALERT_BTN: &str = "py-0.5 px-2 font-semibold rounded-md border-none opacity-70 transition-opacity cursor-pointer text-[11px] bg-black/5 hover:opacity-100 dark:bg-white/10";
+
+/// Formats a Celsius value in the chosen unit, without the unit label.
+fn temp_in_unit(celsius: f64, unit: &str) -> String {
+    if unit == "F" {
+        format!("{:.1}", celsius * 9.0 / 5.0 + 32.0)
+    } else {
+        format!("{:.1}", celsius)
+    }
+}
+
+/// One day's range: "Today 12.4–24.1° avg 18.9 · 55–82% RH".
+fn day_range(label: &'static str, stats: DayStats, unit: String) -> impl IntoView {
+    let degree = if unit == "F" { "\u{00B0}F" } else { "\u{00B0}C" };
+    view! {
+        <span title=format!("{} readings", stats.samples)>
+            <span class="font-semibold uppercase tracking-wide">{label}</span>" "
+            <span class="text-sky-600 dark:text-sky-400" title="Low">{temp_in_unit(stats.min_temperature, &unit)}</span>
+            "\u{2013}"
+            <span class="text-amber-600 dark:text-amber-400" title="High">{temp_in_unit(stats.max_temperature, &unit)}</span>
+            {degree}
+            {format!(" avg {} \u{00B7} {:.0}\u{2013}{:.0}% RH", temp_in_unit(stats.avg_temperature, &unit), stats.min_humidity, stats.max_humidity)}
+        </span>
+    }
+}
+
+/// One open alert under its zone, with "Got it" and snooze buttons.
+fn zone_alert(
+    alert: Alert,
+    on_acknowledge: impl Fn(String) + 'static + Copy + Send + Sync,
+    on_snooze: impl Fn(String, u32) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let class = match alert.severity.as_str() {
+        "critical" => "flex flex-wrap gap-2 justify-between items-center py-1 px-2 text-xs rounded-lg text-red-700 bg-red-50 dark:text-red-300 dark:bg-red-900/20",
+        "warning" => "flex flex-wrap gap-2 justify-between items-center py-1 px-2 text-xs rounded-lg text-amber-700 bg-amber-50 dark:text-amber-300 dark:bg-amber-900/20",
+        _ => "flex flex-wrap gap-2 justify-between items-center py-1 px-2 text-xs rounded-lg text-sky-700 bg-sky-50 dark:text-sky-300 dark:bg-sky-900/20",
+    };
+    let id = alert.id.clone();
+    view! {
+        <div class=class role="alert">
+            <span class="min-w-0">{alert.message}</span>
+            <div class="flex gap-1 items-center">
+                {ALERT_SNOOZE_HOURS.into_iter().map(|hours| {
+                    let id = alert.id.clone();
+                    view! {
+                        <button
+                            class=ALERT_BTN
+                            title=format!("Snooze for {} hour{}", hours, if hours == 1 { "" } else { "s" })
+                            on:click=move |_| on_snooze(id.clone(), hours)
+                        >
+                            {format!("{}h", hours)}
+                        </button>
+                    }
+                }).collect::<Vec<_>>()}
+                <button
+                    class=ALERT_BTN
+                    title="Stop notifying until the condition clears and comes back"
+                    on:click=move |_| on_acknowledge(id.clone())
+                >
+                    "Got it"
+                </button>
+            </div>
+        </div>
+    }
+}
+
 /// Compact one-row-per-zone climate strip for the My Plants tab.
+///
+/// Open alerts for a zone are listed under its row, where they can be acknowledged or snoozed.
 #[component]
 pub fn ClimateStrip(
     readings: Vec<ClimateReading>,
@@ -37,6 +113,8 @@ pub fn ClimateStrip(
     on_show_wizard: impl Fn(GrowingZone) + 'static + Copy + Send + Sync,
     on_zones_changed: impl Fn() + 'static + Copy + Send + Sync,
     temp_unit_str: String,
+    alerts: Signal<Vec<Alert>>,
+    on_alerts_changed: impl Fn() + 'static + Copy + Send + Sync,
 ) -> impl IntoView {
     let zone_ids_with_readings: Vec<String> = readings.iter().map(|r| r.zone_id.clone()).collect();
     let empty_zones: Vec<GrowingZone> = zones.into_iter()
@@ -49,8 +127,41 @@ pub fn ClimateStrip(
 
     let readings = StoredValue::new(readings);
     let empty_zones = StoredValue::new(empty_zones);
+
+    // Yesterday's and today's min/max per zone. Fetched from the browser, which knows the local time zone.
+    let (extremes, set_extremes) = signal(Vec::<ZoneDailyExtremes>::new());
+    Effect::new(move |_| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::climate::get_zone_daily_extremes(super::browser_utc_offset_minutes()).await {
+                Ok(list) => set_extremes.set(list),
+                Err(_e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("climate_strip.daily_extremes", &format!("Failed to load daily min/max: {}", _e), &[]);
+                }
+            }
+        });
+    });
     let temp_unit_stored = StoredValue::new(temp_unit_str);
 
+    let acknowledge = move |id: String| {
+        leptos::task::spawn_local(async move {
+            if let Err(_e) = crate::server_fns::alerts::acknowledge_alert(id.clone()).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_warn("climate_strip.acknowledge_alert", &format!("Failed to acknowledge alert: {}", _e), &[("alert_id", &id)]);
+            }
+            on_alerts_changed();
+        });
+    };
+    let snooze = move |id: String, hours: u32| {
+        leptos::task::spawn_local(async move {
+            if let Err(_e) = crate::server_fns::alerts::snooze_alert(id.clone(), hours).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_warn("climate_strip.snooze_alert", &format!("Failed to snooze alert: {}", _e), &[("alert_id", &id)]);
+            }
+            on_alerts_changed();
+        });
+    };
+
     view! {
         <div class=STRIP_CONTAINER>
             // Left accent bar
@@ -77,6 +188,9 @@ pub fn ClimateStrip(
                     };
 
                     let name = r.zone_name.clone();
+                    let zone_id = r.zone_id.clone();
+                    let alert_zone_id = r.zone_id.clone();
+                    let range_unit = u.clone();
                     let humidity = r.humidity;
                     let vpd = r.vpd;
                     let ago = format_time_ago(&r.recorded_at);
@@ -125,6 +239,29 @@ pub fn ClimateStrip(
                                 })}
                                 <span class="text-xs text-stone-400 dark:text-stone-500">{ago}</span>
                             </div>
+
+                            // Yesterday's and today's ranges, so the overnight low isn't hidden
+                            {move || extremes.with(|list| list.iter().find(|e| e.zone_id == zone_id).cloned()).map(|e| {
+                                let unit = range_unit.clone();
+                                view! {
+                                    <div class=RANGE_ROW>
+                                        {e.yesterday.map(|s| day_range("Yesterday", s, unit.clone()))}
+                                        {e.today.map(|s| day_range("Today", s, unit.clone()))}
+                                    </div>
+                                }
+                            })}
+
+                            // Open alerts for this zone
+                            {move || {
+                                let zone_alerts: Vec<Alert> = alerts.with(|list| {
+                                    list.iter().filter(|a| a.zone_id.as_deref() == Some(alert_zone_id.as_str())).cloned().collect()
+                                });
+                                (!zone_alerts.is_empty()).then(|| view! {
+                                    <div class=ALERT_ROW>
+                                        {zone_alerts.into_iter().map(|a| zone_alert(a, acknowledge, snooze)).collect::<Vec<_>>()}
+                                    </div>
+                                })
+                            }}
                         </div>
                     }
                 }).collect::<Vec<_>>()
@@ -150,6 +287,7 @@ pub fn ClimateStrip(
                                 <div class="flex gap-2 items-center">
                                     <span class="text-xs text-stone-300 dark:text-stone-600">"No data"</span>
                                     <button
+                                        data-tour="estimate-climate"
                                         class="py-1 px-2.5 font-semibold rounded-lg border-none transition-colors cursor-pointer text-[11px] text-accent-dark bg-accent/10 dark:text-accent-light dark:bg-accent/10 dark:hover:bg-accent/20 hover:bg-accent/20"
                                         on:click=move |_| on_show_wizard(zone_for_wizard.clone())
                                     >
@@ -0,0 +1,107 @@
+use leptos::prelude::*;
+
+/// Hands the exported archive to the browser as a file download.
+#[cfg(feature = "hydrate")]
+fn trigger_download(export: &crate::server_fns::auth::DataExport) -> Result<(), String> {
+    use wasm_bindgen::JsCast;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("No document")?;
+    let link = document.create_element("a")
+        .map_err(|_| "Couldn't create download link".to_string())?;
+    let _ = link.set_attribute("href", &format!("data:application/zip;base64,{}", export.zip_base64));
+    let _ = link.set_attribute("download", &export.filename);
+    link.dyn_into::<web_sys::HtmlElement>()
+        .map_err(|_| "Couldn't create download link".to_string())?
+        .click();
+    Ok(())
+}
+
+/// "Download my data" button that exports all of the user's records and photos as a zip.
+#[component]
+pub fn DataExportButton() -> impl IntoView {
+    let (is_exporting, set_is_exporting) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let export = move |_| {
+        set_is_exporting.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::auth::export_my_data().await {
+                Ok(_data) => {
+                    #[cfg(feature = "hydrate")]
+                    {
+                        crate::server_fns::telemetry::emit_info("settings.data_export", "Data export downloaded", &[]);
+                        if let Err(e) = trigger_download(&_data) {
+                            set_error_msg.set(Some(e));
+                        }
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.data_export", &format!("Data export failed: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_exporting.set(false);
+        });
+    };
+
+    view! {
+        <button
+            class="py-2 px-4 w-full text-sm font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20 disabled:opacity-50"
+            disabled=move || is_exporting.get()
+            on:click=export
+        >
+            {move || if is_exporting.get() { "Preparing export..." } else { "Download My Data" }}
+        </button>
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
+
+/// "Download climate data" button that exports zone readings and rollups as zipped Parquet files.
+#[component]
+pub fn ClimateExportButton() -> impl IntoView {
+    let (is_exporting, set_is_exporting) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let export = move |_| {
+        set_is_exporting.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::climate::export_climate_parquet().await {
+                Ok(_data) => {
+                    #[cfg(feature = "hydrate")]
+                    {
+                        crate::server_fns::telemetry::emit_info("settings.climate_export", "Climate Parquet export downloaded", &[]);
+                        if let Err(e) = trigger_download(&_data) {
+                            set_error_msg.set(Some(e));
+                        }
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("settings.climate_export", &format!("Climate export failed: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_exporting.set(false);
+        });
+    };
+
+    view! {
+        <button
+            class="py-2 px-4 w-full text-sm font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20 disabled:opacity-50"
+            disabled=move || is_exporting.get()
+            on:click=export
+        >
+            {move || if is_exporting.get() { "Preparing export..." } else { "Download Climate Data (Parquet)" }}
+        </button>
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
@@ -69,6 +69,8 @@ pub fn DeviceList(
                         let device_type_badge = match device.device_type.as_str() {
                             "tempest" => ("Tempest", "bg-sky-100 text-sky-700 dark:bg-sky-900/30 dark:text-sky-300"),
                             "ac_infinity" => ("AC Infinity", "bg-violet-100 text-violet-700 dark:bg-violet-900/30 dark:text-violet-300"),
+                            "mqtt" => ("MQTT", "bg-emerald-100 text-emerald-700 dark:bg-emerald-900/30 dark:text-emerald-300"),
+                            "sensorpush" => ("SensorPush", "bg-orange-100 text-orange-700 dark:bg-orange-900/30 dark:text-orange-300"),
                             _ => ("Unknown", "bg-stone-100 text-stone-600 dark:bg-stone-800 dark:text-stone-400"),
                         };
 
@@ -211,7 +213,20 @@ fn DeviceForm(
     };
 
     // Tempest fields
-    let (tempest_station, set_tempest_station) = signal(get_str("station_id"));
+    // Multiple stations on one account are entered comma-separated; the first is primary.
+    let init_stations = {
+        let extra: Vec<String> = parsed.as_ref()
+            .and_then(|j| j.get("station_ids"))
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+        std::iter::once(get_str("station_id"))
+            .chain(extra)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let (tempest_station, set_tempest_station) = signal(init_stations);
     let (tempest_token, set_tempest_token) = signal(get_str("token"));
 
     // AC Infinity fields
@@ -219,17 +234,103 @@ fn DeviceForm(
     let (aci_password, set_aci_password) = signal(get_str("password"));
     let (aci_device, set_aci_device) = signal(get_str("device_id"));
 
+    // MQTT fields
+    // One topic per line; zones pick a topic by its position, like Tempest stations.
+    let init_topics = parsed.as_ref()
+        .and_then(|j| j.get("topics"))
+        .and_then(|v| v.as_array())
+        .map(|a| a.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>().join("\n"))
+        .unwrap_or_default();
+    let init_port = parsed.as_ref()
+        .and_then(|j| j.get("port"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(1883)
+        .to_string();
+    let (mqtt_host, set_mqtt_host) = signal(get_str("host"));
+    let (mqtt_port, set_mqtt_port) = signal(init_port);
+    let (mqtt_username, set_mqtt_username) = signal(get_str("username"));
+    let (mqtt_password, set_mqtt_password) = signal(get_str("password"));
+    let (mqtt_topics, set_mqtt_topics) = signal(init_topics);
+
+    // SensorPush fields
+    // Sensors found on the account, and which of them are saved (in port order).
+    let init_sensors = editing.as_ref().map(|d| d.sensorpush_sensors()).unwrap_or_default();
+    let (sp_email, set_sp_email) = signal(get_str("email"));
+    let (sp_password, set_sp_password) = signal(get_str("password"));
+    let (sp_found, set_sp_found) = signal(init_sensors.clone());
+    let sp_selected = RwSignal::new(init_sensors.into_iter().map(|(id, _)| id).collect::<Vec<_>>());
+    let (sp_is_finding, set_sp_is_finding) = signal(false);
+
+    let find_sensors = move |_| {
+        set_sp_is_finding.set(true);
+        set_error_msg.set(None);
+        let email = sp_email.get_untracked();
+        let password = sp_password.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::devices::discover_sensorpush_sensors(email, password).await {
+                Ok(found) => {
+                    // Keep earlier choices that still exist so zone ports don't shift
+                    sp_selected.update(|sel| sel.retain(|id| found.iter().any(|(f, _)| f == id)));
+                    set_sp_found.set(found);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("device_management.find_sensors", &format!("SensorPush discovery failed: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_sp_is_finding.set(false);
+        });
+    };
+
     let build_config_json = move || -> String {
         match device_type.get().as_str() {
-            "tempest" => serde_json::json!({
-                "station_id": tempest_station.get(),
-                "token": tempest_token.get(),
-            }).to_string(),
+            "tempest" => {
+                let stations: Vec<String> = tempest_station.get()
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                let (primary, extra) = stations.split_first()
+                    .map(|(p, rest)| (p.clone(), rest.to_vec()))
+                    .unwrap_or_default();
+                serde_json::json!({
+                    "station_id": primary,
+                    "station_ids": extra,
+                    "token": tempest_token.get(),
+                }).to_string()
+            }
             "ac_infinity" => serde_json::json!({
                 "email": aci_email.get(),
                 "password": aci_password.get(),
                 "device_id": aci_device.get(),
             }).to_string(),
+            "mqtt" => {
+                let topics: Vec<String> = mqtt_topics.get()
+                    .lines()
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                serde_json::json!({
+                    "host": mqtt_host.get().trim(),
+                    "port": mqtt_port.get().trim().parse::<u16>().unwrap_or(1883),
+                    "username": mqtt_username.get(),
+                    "password": mqtt_password.get(),
+                    "topics": topics,
+                }).to_string()
+            }
+            "sensorpush" => {
+                let found = sp_found.get();
+                let sensors: Vec<serde_json::Value> = sp_selected.get().iter()
+                    .filter_map(|id| found.iter().find(|(f, _)| f == id))
+                    .map(|(id, name)| serde_json::json!({ "id": id, "name": name }))
+                    .collect();
+                serde_json::json!({
+                    "email": sp_email.get().trim(),
+                    "password": sp_password.get(),
+                    "sensors": sensors,
+                }).to_string()
+            }
             _ => String::new(),
         }
     };
@@ -288,6 +389,8 @@ fn DeviceForm(
                         <option value="">"Select type..."</option>
                         <option value="tempest">"Tempest Weather Station"</option>
                         <option value="ac_infinity">"AC Infinity Controller"</option>
+                        <option value="mqtt">"MQTT Broker"</option>
+                        <option value="sensorpush">"SensorPush Account"</option>
                     </select>
                 </div>
             })}
@@ -296,12 +399,13 @@ fn DeviceForm(
                 "tempest" => view! {
                     <div class="p-3 mb-3 rounded-lg bg-sky-50/50 dark:bg-sky-900/10">
                         <div class="mb-3">
-                            <label class=LABEL_SM>"Station ID"</label>
+                            <label class=LABEL_SM>"Station IDs"</label>
                             <input type="text" class=INPUT_SM
-                                placeholder="e.g. 12345"
+                                placeholder="e.g. 12345, 67890"
                                 prop:value=tempest_station
                                 on:input=move |ev| set_tempest_station.set(event_target_value(&ev))
                             />
+                            <p class="mt-1 mb-0 text-[11px] text-stone-400">"Separate multiple stations on the same account with commas."</p>
                         </div>
                         <div>
                             <label class=LABEL_SM>"API Token"</label>
@@ -343,6 +447,119 @@ fn DeviceForm(
                         </div>
                     </div>
                 }.into_any(),
+                "mqtt" => view! {
+                    <div class="p-3 mb-3 rounded-lg bg-emerald-50/50 dark:bg-emerald-900/10">
+                        <div class="flex gap-3 mb-3">
+                            <div class="flex-1">
+                                <label class=LABEL_SM>"Broker Host"</label>
+                                <input type="text" class=INPUT_SM
+                                    placeholder="e.g. 192.168.1.20"
+                                    prop:value=mqtt_host
+                                    on:input=move |ev| set_mqtt_host.set(event_target_value(&ev))
+                                />
+                            </div>
+                            <div class="w-24">
+                                <label class=LABEL_SM>"Port"</label>
+                                <input type="number" class=INPUT_SM
+                                    prop:value=mqtt_port
+                                    on:input=move |ev| set_mqtt_port.set(event_target_value(&ev))
+                                />
+                            </div>
+                        </div>
+                        <div class="flex gap-3 mb-3">
+                            <div class="flex-1">
+                                <label class=LABEL_SM>"Username"</label>
+                                <input type="text" class=INPUT_SM
+                                    placeholder="Optional"
+                                    prop:value=mqtt_username
+                                    on:input=move |ev| set_mqtt_username.set(event_target_value(&ev))
+                                />
+                            </div>
+                            <div class="flex-1">
+                                <label class=LABEL_SM>"Password"</label>
+                                <input type="password" class=INPUT_SM
+                                    placeholder="Optional"
+                                    prop:value=mqtt_password
+                                    on:input=move |ev| set_mqtt_password.set(event_target_value(&ev))
+                                />
+                            </div>
+                        </div>
+                        <div>
+                            <label class=LABEL_SM>"Topics"</label>
+                            <textarea class=INPUT_SM rows="3"
+                                placeholder="greenhouse/bench1/climate"
+                                prop:value=mqtt_topics
+                                on:input=move |ev| set_mqtt_topics.set(event_target_value(&ev))
+                            ></textarea>
+                            <p class="mt-1 mb-0 text-[11px] text-stone-400">"One topic per line (+ and # wildcards work). Payloads are JSON with temperature and humidity fields."</p>
+                        </div>
+                    </div>
+                }.into_any(),
+                "sensorpush" => view! {
+                    <div class="p-3 mb-3 rounded-lg bg-orange-50/50 dark:bg-orange-900/10">
+                        <div class="flex gap-3 mb-3">
+                            <div class="flex-1">
+                                <label class=LABEL_SM>"Email"</label>
+                                <input type="email" class=INPUT_SM
+                                    placeholder="SensorPush account email"
+                                    prop:value=sp_email
+                                    on:input=move |ev| set_sp_email.set(event_target_value(&ev))
+                                />
+                            </div>
+                            <div class="flex-1">
+                                <label class=LABEL_SM>"Password"</label>
+                                <input type="password" class=INPUT_SM
+                                    placeholder="Account password"
+                                    prop:value=sp_password
+                                    on:input=move |ev| set_sp_password.set(event_target_value(&ev))
+                                />
+                            </div>
+                        </div>
+                        <div class="flex justify-between items-center mb-2">
+                            <span class=LABEL_SM>"Sensors"</span>
+                            <button
+                                class=format!("{} text-stone-500 bg-stone-100 hover:bg-stone-200 dark:text-stone-400 dark:bg-stone-800 dark:hover:bg-stone-700", BTN_SM)
+                                disabled=move || sp_is_finding.get() || sp_email.get().trim().is_empty()
+                                on:click=find_sensors
+                            >{move || if sp_is_finding.get() { "Searching..." } else { "Find Sensors" }}</button>
+                        </div>
+                        {move || {
+                            let found = sp_found.get();
+                            if found.is_empty() {
+                                return view! {
+                                    <p class="mt-0 mb-0 text-[11px] text-stone-400">"Sensors must be paired with a SensorPush gateway to report to the cloud."</p>
+                                }.into_any();
+                            }
+                            view! {
+                                <div class="flex flex-col gap-1">
+                                    {found.into_iter().map(|(id, name)| {
+                                        let id_check = id.clone();
+                                        let id_toggle = id.clone();
+                                        let label = if name.is_empty() { id.clone() } else { name };
+                                        view! {
+                                            <label class="flex gap-2 items-center text-xs cursor-pointer text-stone-600 dark:text-stone-400">
+                                                <input type="checkbox"
+                                                    prop:checked=move || sp_selected.get().contains(&id_check)
+                                                    on:change=move |ev| {
+                                                        let on = event_target_checked(&ev);
+                                                        sp_selected.update(|sel| {
+                                                            sel.retain(|x| *x != id_toggle);
+                                                            if on {
+                                                                sel.push(id_toggle.clone());
+                                                            }
+                                                        });
+                                                    }
+                                                />
+                                                <span>{label}</span>
+                                                <code class="text-[10px] text-stone-400">{id}</code>
+                                            </label>
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                            }.into_any()
+                        }}
+                    </div>
+                }.into_any(),
                 _ => view! {
                     <p class="mb-3 text-xs text-stone-400">"Select a device type to configure credentials."</p>
                 }.into_any(),
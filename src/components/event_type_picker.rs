@@ -1,5 +1,9 @@
 use leptos::prelude::*;
-use super::event_types::EVENT_TYPES;
+use crate::orchid::{EventTypeDef, LogEntry};
+use super::event_types::{registered_event_types, selectable_event_types};
+
+const CHIP_SELECTED: &str = "py-1.5 px-3 text-xs font-semibold rounded-full border-2 cursor-pointer transition-all ring-2 ring-offset-1";
+const CHIP: &str = "py-1.5 px-3 text-xs font-semibold rounded-full border border-transparent cursor-pointer transition-all hover:border-stone-300";
 
 #[component]
 pub fn EventTypePicker(
@@ -8,37 +12,147 @@ pub fn EventTypePicker(
 ) -> impl IntoView {
     view! {
         <div class="flex flex-wrap gap-2">
-            {EVENT_TYPES.iter().map(|et| {
-                let key = et.key;
-                let label = et.label;
-                let emoji = et.emoji;
-                let bg = et.bg_class;
-                let color = et.color_class;
-
-                view! {
-                    <button
-                        type="button"
-                        class=move || {
-                            let is_selected = selected.get().as_deref() == Some(key);
-                            if is_selected {
-                                format!("py-1.5 px-3 text-xs font-semibold rounded-full border-2 cursor-pointer transition-all ring-2 ring-offset-1 {} {} border-current", bg, color)
-                            } else {
-                                format!("py-1.5 px-3 text-xs font-semibold rounded-full border border-transparent cursor-pointer transition-all hover:border-stone-300 {} {}", bg, color)
-                            }
-                        }
-                        on:click=move |_| {
-                            let current = selected.get_untracked();
-                            if current.as_deref() == Some(key) {
-                                on_select(None);
-                            } else {
-                                on_select(Some(key.to_string()));
-                            }
-                        }
-                    >
-                        {emoji} " " {label}
-                    </button>
-                }
+            {selectable_event_types().into_iter().map(|et| {
+                let label = format!("{} {}", et.emoji, et.label);
+                view! { <TypeChip et=et label=label selected=selected on_select=on_select /> }
             }).collect::<Vec<_>>()}
         </div>
     }.into_any()
 }
+
+/// Chips for the event types that appear in `entries`, with how many entries each has.
+/// Picking one narrows the journal to that type; picking it again shows everything.
+#[component]
+pub fn EventTypeFilter(
+    entries: ReadSignal<Vec<LogEntry>>,
+    selected: ReadSignal<Option<String>>,
+    on_select: impl Fn(Option<String>) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    move || {
+        let counts = entries.with(|list| event_type_counts(list, &registered_event_types()));
+        // Filtering only helps once the journal mixes kinds of entries
+        (counts.len() > 1).then(|| view! {
+            <div class="flex flex-wrap gap-2 mb-4">
+                {counts.into_iter().map(|(et, n)| {
+                    let label = format!("{} {} ({})", et.emoji, et.label, n);
+                    view! { <TypeChip et=et label=label selected=selected on_select=on_select /> }
+                }).collect::<Vec<_>>()}
+            </div>
+        })
+    }
+}
+
+#[component]
+fn TypeChip(
+    et: EventTypeDef,
+    label: String,
+    selected: ReadSignal<Option<String>>,
+    on_select: impl Fn(Option<String>) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let bg = et.bg_class();
+    let color = et.color_class();
+    let key = StoredValue::new(et.key);
+
+    view! {
+        <button
+            type="button"
+            class=move || {
+                let is_selected = key.with_value(|k| selected.get().as_deref() == Some(k.as_str()));
+                if is_selected {
+                    format!("{} {} {} border-current", CHIP_SELECTED, bg, color)
+                } else {
+                    format!("{} {} {}", CHIP, bg, color)
+                }
+            }
+            on:click=move |_| {
+                let current = selected.get_untracked();
+                let key = key.get_value();
+                if current.as_deref() == Some(key.as_str()) {
+                    on_select(None);
+                } else {
+                    on_select(Some(key));
+                }
+            }
+        >
+            {label}
+        </button>
+    }
+}
+
+/// The event types used in `entries` with their entry counts, in registry order.
+/// Keys missing from the registry (a deleted custom type) are listed last under their key.
+pub fn event_type_counts(entries: &[LogEntry], registry: &[EventTypeDef]) -> Vec<(EventTypeDef, u32)> {
+    let mut counts: Vec<(EventTypeDef, u32)> = Vec::new();
+    for key in entries.iter().filter_map(|e| e.event_type.as_deref()) {
+        match counts.iter_mut().find(|(et, _)| et.key == key) {
+            Some((_, n)) => *n += 1,
+            None => {
+                let et = registry.iter().find(|e| e.key == key).cloned().unwrap_or_else(|| EventTypeDef {
+                    key: key.to_string(),
+                    label: key.to_string(),
+                    emoji: "\u{1F4CC}".to_string(),
+                    color: "stone".to_string(),
+                    quick_action: false,
+                    builtin: false,
+                });
+                counts.push((et, 1));
+            }
+        }
+    }
+    let position = |key: &str| registry.iter().position(|e| e.key == key).unwrap_or(usize::MAX);
+    counts.sort_by_key(|(et, _)| position(&et.key));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::event_types::builtin_event_types;
+
+    fn entry(event: Option<&str>) -> LogEntry {
+        LogEntry {
+            id: "log_entry:1".into(),
+            timestamp: chrono::Utc::now(),
+            note: String::new(),
+            image_filename: None,
+            event_type: event.map(String::from),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
+        }
+    }
+
+    #[test]
+    fn test_counts_in_registry_order_with_unknown_last() {
+        let mut registry = builtin_event_types();
+        registry.push(EventTypeDef {
+            key: "Staked".into(),
+            label: "Staked".into(),
+            emoji: "\u{1F4CF}".into(),
+            color: "lime".into(),
+            quick_action: false,
+            builtin: false,
+        });
+        let entries = vec![
+            entry(Some("Gone")),
+            entry(Some("Staked")),
+            entry(Some("Watered")),
+            entry(None),
+            entry(Some("Staked")),
+            entry(Some("Flowering")),
+        ];
+        let counts: Vec<(String, u32)> = event_type_counts(&entries, &registry).into_iter()
+            .map(|(et, n)| (et.key, n))
+            .collect();
+        assert_eq!(counts, vec![
+            ("Flowering".to_string(), 1),
+            ("Watered".to_string(), 1),
+            ("Staked".to_string(), 2),
+            ("Gone".to_string(), 1),
+        ]);
+    }
+}
@@ -1,7 +1,11 @@
+use leptos::prelude::*;
+use crate::orchid::EventTypeDef;
+
 pub struct EventTypeInfo {
     pub key: &'static str,
     pub label: &'static str,
     pub emoji: &'static str,
+    pub color: &'static str,
     pub color_class: &'static str,
     pub bg_class: &'static str,
     pub quick_action: bool,
@@ -12,6 +16,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "Flowering",
         label: "Flowering",
         emoji: "\u{1F338}",
+        color: "pink",
         color_class: "text-pink-600 dark:text-pink-400",
         bg_class: "bg-pink-100 dark:bg-pink-900/30",
         quick_action: true,
@@ -20,6 +25,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "NewGrowth",
         label: "New Growth",
         emoji: "\u{1F331}",
+        color: "emerald",
         color_class: "text-emerald-600 dark:text-emerald-400",
         bg_class: "bg-emerald-100 dark:bg-emerald-900/30",
         quick_action: true,
@@ -28,6 +34,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "Repotted",
         label: "Repotted",
         emoji: "\u{1FAB4}",
+        color: "amber",
         color_class: "text-amber-600 dark:text-amber-400",
         bg_class: "bg-amber-100 dark:bg-amber-900/30",
         quick_action: true,
@@ -36,6 +43,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "Fertilized",
         label: "Fertilized",
         emoji: "\u{2728}",
+        color: "yellow",
         color_class: "text-yellow-600 dark:text-yellow-400",
         bg_class: "bg-yellow-100 dark:bg-yellow-900/30",
         quick_action: true,
@@ -44,6 +52,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "PestTreatment",
         label: "Pest Treatment",
         emoji: "\u{1F41B}",
+        color: "red",
         color_class: "text-red-600 dark:text-red-400",
         bg_class: "bg-red-100 dark:bg-red-900/30",
         quick_action: true,
@@ -52,6 +61,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "Purchased",
         label: "Purchased",
         emoji: "\u{1F3F7}\u{FE0F}",
+        color: "violet",
         color_class: "text-violet-600 dark:text-violet-400",
         bg_class: "bg-violet-100 dark:bg-violet-900/30",
         quick_action: true,
@@ -60,6 +70,7 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "Watered",
         label: "Watered",
         emoji: "\u{1F4A7}",
+        color: "sky",
         color_class: "text-sky-600 dark:text-sky-400",
         bg_class: "bg-sky-100 dark:bg-sky-900/30",
         quick_action: true,
@@ -68,10 +79,51 @@ pub const EVENT_TYPES: &[EventTypeInfo] = &[
         key: "Note",
         label: "Note",
         emoji: "\u{1F4DD}",
+        color: "stone",
         color_class: "text-stone-600 dark:text-stone-400",
         bg_class: "bg-stone-100 dark:bg-stone-800",
         quick_action: true,
     },
+    // Written by the troubleshooting wizard, so not offered as a one-tap action
+    EventTypeInfo {
+        key: "Diagnosis",
+        label: "Diagnosis",
+        emoji: "\u{1FA7A}",
+        color: "orange",
+        color_class: "text-orange-600 dark:text-orange-400",
+        bg_class: "bg-orange-100 dark:bg-orange-900/30",
+        quick_action: false,
+    },
+    // Written by the "Skip" watering action, which asks for a reason
+    EventTypeInfo {
+        key: "Skipped",
+        label: "Skipped",
+        emoji: "\u{23ED}\u{FE0F}",
+        color: "teal",
+        color_class: "text-teal-600 dark:text-teal-400",
+        bg_class: "bg-teal-100 dark:bg-teal-900/30",
+        quick_action: false,
+    },
+    // Semi-hydro only; the quick log adds it for LECA plants rather than for every plant
+    EventTypeInfo {
+        key: "ToppedUp",
+        label: "Reservoir Top-Up",
+        emoji: "\u{1FAD9}",
+        color: "indigo",
+        color_class: "text-indigo-600 dark:text-indigo-400",
+        bg_class: "bg-indigo-100 dark:bg-indigo-900/30",
+        quick_action: false,
+    },
+    // Written by the Growth tab's measurement form, which asks for the numbers
+    EventTypeInfo {
+        key: "Measured",
+        label: "Measured",
+        emoji: "\u{1F4CF}",
+        color: "lime",
+        color_class: "text-lime-600 dark:text-lime-400",
+        bg_class: "bg-lime-100 dark:bg-lime-900/30",
+        quick_action: false,
+    },
 ];
 
 pub fn get_event_info(key: &str) -> Option<&'static EventTypeInfo> {
@@ -82,10 +134,109 @@ pub fn quick_action_types() -> impl Iterator<Item = &'static EventTypeInfo> {
     EVENT_TYPES.iter().filter(|e| e.quick_action)
 }
 
-/// The allowed event type keys, matching the DB ASSERT constraint in migration 0008.
+/// Palette available to event types: (color name, text classes, background classes).
+/// Classes are spelled out literally so Tailwind keeps them in the build.
+pub const EVENT_COLORS: &[(&str, &str, &str)] = &[
+    ("pink", "text-pink-600 dark:text-pink-400", "bg-pink-100 dark:bg-pink-900/30"),
+    ("rose", "text-rose-600 dark:text-rose-400", "bg-rose-100 dark:bg-rose-900/30"),
+    ("red", "text-red-600 dark:text-red-400", "bg-red-100 dark:bg-red-900/30"),
+    ("orange", "text-orange-600 dark:text-orange-400", "bg-orange-100 dark:bg-orange-900/30"),
+    ("amber", "text-amber-600 dark:text-amber-400", "bg-amber-100 dark:bg-amber-900/30"),
+    ("yellow", "text-yellow-600 dark:text-yellow-400", "bg-yellow-100 dark:bg-yellow-900/30"),
+    ("lime", "text-lime-600 dark:text-lime-400", "bg-lime-100 dark:bg-lime-900/30"),
+    ("emerald", "text-emerald-600 dark:text-emerald-400", "bg-emerald-100 dark:bg-emerald-900/30"),
+    ("teal", "text-teal-600 dark:text-teal-400", "bg-teal-100 dark:bg-teal-900/30"),
+    ("sky", "text-sky-600 dark:text-sky-400", "bg-sky-100 dark:bg-sky-900/30"),
+    ("indigo", "text-indigo-600 dark:text-indigo-400", "bg-indigo-100 dark:bg-indigo-900/30"),
+    ("violet", "text-violet-600 dark:text-violet-400", "bg-violet-100 dark:bg-violet-900/30"),
+    ("fuchsia", "text-fuchsia-600 dark:text-fuchsia-400", "bg-fuchsia-100 dark:bg-fuchsia-900/30"),
+    ("stone", "text-stone-600 dark:text-stone-400", "bg-stone-100 dark:bg-stone-800"),
+];
+
+/// Text and background classes for a palette color, falling back to stone.
+pub fn color_classes(color: &str) -> (&'static str, &'static str) {
+    let (_, text, bg) = EVENT_COLORS.iter()
+        .find(|(name, _, _)| *name == color)
+        .unwrap_or(&EVENT_COLORS[EVENT_COLORS.len() - 1]);
+    (text, bg)
+}
+
+impl EventTypeInfo {
+    /// Owned registry form of a built-in event type.
+    pub fn to_def(&self) -> EventTypeDef {
+        EventTypeDef {
+            key: self.key.to_string(),
+            label: self.label.to_string(),
+            emoji: self.emoji.to_string(),
+            color: self.color.to_string(),
+            quick_action: self.quick_action,
+            builtin: true,
+        }
+    }
+}
+
+impl EventTypeDef {
+    /// Tailwind text color classes for this type.
+    pub fn color_class(&self) -> &'static str {
+        color_classes(&self.color).0
+    }
+
+    /// Tailwind background classes for this type.
+    pub fn bg_class(&self) -> &'static str {
+        color_classes(&self.color).1
+    }
+}
+
+/// The built-in event types as registry entries (used until the DB registry loads).
+pub fn builtin_event_types() -> Vec<EventTypeDef> {
+    EVENT_TYPES.iter().map(|et| et.to_def()).collect()
+}
+
+/// The user's event type registry, provided as context by the home page.
+#[derive(Clone, Copy)]
+pub struct EventTypeRegistry(pub RwSignal<Vec<EventTypeDef>>);
+
+/// All event types visible to the current user: the registry from context if
+/// provided, otherwise the built-ins.
+pub fn registered_event_types() -> Vec<EventTypeDef> {
+    use_context::<EventTypeRegistry>()
+        .map(|r| r.0.get_untracked())
+        .filter(|list| !list.is_empty())
+        .unwrap_or_else(builtin_event_types)
+}
+
+/// Event types a grower can tag a journal entry with: their own types plus the built-in quick actions.
+/// Built-ins written by other flows (diagnoses, skips, top-ups) are left out.
+pub fn selectable_event_types() -> Vec<EventTypeDef> {
+    registered_event_types().into_iter().filter(|e| !e.builtin || e.quick_action).collect()
+}
+
+/// Resolve an event type key against the registry, falling back to the built-ins.
+pub fn lookup_event_type(key: &str) -> Option<EventTypeDef> {
+    use_context::<EventTypeRegistry>()
+        .and_then(|r| r.0.with_untracked(|list| list.iter().find(|e| e.key == key).cloned()))
+        .or_else(|| get_event_info(key).map(|e| e.to_def()))
+}
+
+/// Derive a registry key from a user-entered label: "Pest sighting" -> "PestSighting".
+/// Returns `None` when the label has no usable characters.
+pub fn event_type_key_from_label(label: &str) -> Option<String> {
+    let key: String = label
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase()).unwrap_or_default();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect();
+    (!key.is_empty()).then(|| key.chars().take(40).collect())
+}
+
+/// The built-in event type keys, matching the rows seeded in migrations 0020, 0035, 0048 and 0050.
 pub const ALLOWED_EVENT_TYPE_KEYS: &[&str] = &[
     "Flowering", "NewGrowth", "Repotted", "Fertilized",
-    "PestTreatment", "Purchased", "Watered", "Note",
+    "PestTreatment", "Purchased", "Watered", "Note", "Diagnosis", "Skipped", "ToppedUp", "Measured",
 ];
 
 #[cfg(test)]
@@ -94,7 +245,7 @@ mod tests {
 
     #[test]
     fn test_all_event_types_present() {
-        assert_eq!(EVENT_TYPES.len(), 8);
+        assert_eq!(EVENT_TYPES.len(), 12);
     }
 
     #[test]
@@ -167,7 +318,36 @@ mod tests {
     #[test]
     fn test_quick_action_types_count() {
         let count = quick_action_types().count();
-        assert_eq!(count, 8, "All 8 event types should be quick actions");
+        assert_eq!(count, 8, "Every event type except Diagnosis, Skipped and ToppedUp should be a quick action");
+    }
+
+    #[test]
+    fn test_builtin_colors_are_in_palette() {
+        for et in EVENT_TYPES {
+            let (text, bg) = color_classes(et.color);
+            assert_eq!(text, et.color_class, "Palette text mismatch for '{}'", et.key);
+            assert_eq!(bg, et.bg_class, "Palette bg mismatch for '{}'", et.key);
+        }
+    }
+
+    #[test]
+    fn test_color_classes_unknown_falls_back_to_stone() {
+        assert_eq!(color_classes("chartreuse"), color_classes("stone"));
+    }
+
+    #[test]
+    fn test_lookup_without_registry_uses_builtins() {
+        let def = lookup_event_type("Watered").unwrap();
+        assert!(def.builtin);
+        assert_eq!(def.color, "sky");
+        assert!(lookup_event_type("Pollinated").is_none());
+    }
+
+    #[test]
+    fn test_event_type_key_from_label() {
+        assert_eq!(event_type_key_from_label("Pest sighting").as_deref(), Some("PestSighting"));
+        assert_eq!(event_type_key_from_label("  misted! ").as_deref(), Some("Misted"));
+        assert_eq!(event_type_key_from_label("\u{1F41D}"), None);
     }
 
     #[test]
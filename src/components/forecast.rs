@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use crate::estimation::c_to_f;
+use crate::forecast::ZoneForecast;
+
+/// Formats a Celsius temperature in the display unit, e.g. "18°".
+fn format_temp(c: f64, unit: &str) -> String {
+    if unit == "F" {
+        format!("{:.0}\u{00B0}", c_to_f(c))
+    } else {
+        format!("{:.0}\u{00B0}", c)
+    }
+}
+
+/// 48-hour forecast cards for outdoor zones, shown on the My Plants tab below the climate strip.
+#[component]
+pub fn OutdoorForecastCards(unit: Memo<String>) -> impl IntoView {
+    let forecasts = Resource::new(|| (), |_| crate::server_fns::climate::get_outdoor_forecasts());
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || forecasts.get().map(|result| {
+                let forecasts = match result {
+                    Ok(f) => f,
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("forecast.load", &format!("Failed to load forecasts: {}", _e), &[]);
+                        Vec::new()
+                    }
+                };
+                if forecasts.is_empty() {
+                    return view! { <div></div> }.into_any();
+                }
+                view! {
+                    <div class="mb-4">
+                        <h3 class="mt-0 mb-2 text-xs font-bold tracking-widest uppercase text-stone-400 dark:text-stone-500">"Next 48 Hours Outdoors"</h3>
+                        <div class="flex flex-col gap-3">
+                            {forecasts.into_iter().map(|f| view! { <ForecastCard forecast=f unit=unit /> }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+    }
+}
+
+/// One zone's forecast: watering hint, temperature range, and hourly precipitation bars.
+#[component]
+fn ForecastCard(forecast: ZoneForecast, unit: Memo<String>) -> impl IntoView {
+    let now = chrono::Utc::now();
+    let hint = forecast.watering_hint(now);
+    let total_rain = forecast.precipitation_next(now, 48);
+    let range = forecast.temperature_range();
+    let max_rain = forecast.hours.iter().map(|h| h.precipitation_mm).fold(0.0_f64, f64::max).max(1.0);
+    let hours = StoredValue::new(forecast.hours);
+    let updated = crate::components::format_time_ago(&forecast.fetched_at);
+
+    view! {
+        <div class="p-4 rounded-xl border bg-surface/80 border-stone-200/60 dark:border-stone-700/60">
+            <div class="flex flex-wrap gap-2 justify-between items-baseline mb-1">
+                <span class="text-sm font-semibold text-stone-700 dark:text-stone-300">{forecast.zone_name}</span>
+                <span class="text-xs tabular-nums text-stone-500 dark:text-stone-400">
+                    {move || range.map(|(lo, hi)| {
+                        let u = unit.get();
+                        format!("{} to {}", format_temp(lo, &u), format_temp(hi, &u))
+                    })}
+                    {format!(" \u{00B7} {:.1} mm rain", total_rain)}
+                </span>
+            </div>
+            <p class="mt-0 mb-3 text-xs text-sky-700 dark:text-sky-400">{hint}</p>
+            <div class="flex gap-px items-end h-10">
+                {hours.get_value().into_iter().map(|h| {
+                    let height = format!("height: {}px", ((h.precipitation_mm / max_rain) * 40.0).max(1.0) as u32);
+                    let bar = if h.precipitation_mm > 0.0 {
+                        "flex-1 rounded-sm bg-sky-400/70 dark:bg-sky-500/50"
+                    } else {
+                        "flex-1 rounded-sm bg-stone-200 dark:bg-stone-700"
+                    };
+                    let chance = h.precipitation_probability.map(|p| format!(", {:.0}% chance", p)).unwrap_or_default();
+                    let title = move || format!(
+                        "{}: {}, {:.1} mm{}",
+                        h.time.with_timezone(&chrono::Local).format("%a %H:%M"),
+                        format_temp(h.temperature_c, &unit.get()), h.precipitation_mm, chance
+                    );
+                    view! { <div class=bar style=height title=title></div> }
+                }).collect::<Vec<_>>()}
+            </div>
+            <div class="flex justify-between mt-1 text-[10px] tabular-nums text-stone-400">
+                {hours.get_value().into_iter().step_by(12).map(|h| {
+                    view! {
+                        <span>
+                            {h.time.with_timezone(&chrono::Local).format("%a %H:%M").to_string()}" "
+                            {move || format_temp(h.temperature_c, &unit.get())}
+                        </span>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            <div class="mt-1 text-[10px] text-stone-400">{format!("Open-Meteo forecast, updated {}", updated)}</div>
+        </div>
+    }
+}
@@ -0,0 +1,197 @@
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use crate::growth::{series, GrowthMeasurement, Metric};
+use crate::orchid::{LogEntry, Orchid};
+use super::BTN_PRIMARY;
+
+const INPUT: &str = "py-1.5 px-2 w-full text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600";
+const SECTION_HEADING: &str = "mt-0 mb-3 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400";
+
+/// Chart drawing area, in SVG user units.
+const CHART_W: f64 = 300.0;
+const CHART_H: f64 = 80.0;
+/// Space kept around the line so end points and their dots aren't clipped.
+const CHART_PAD: f64 = 6.0;
+
+/// The Growth tab: a form to log a measurement, then a chart for each thing measured so far.
+#[component]
+pub fn GrowthTab(
+    orchid_signal: ReadSignal<Orchid>,
+    log_entries: ReadSignal<Vec<LogEntry>>,
+    set_log_entries: WriteSignal<Vec<LogEntry>>,
+    #[prop(optional)] read_only: bool,
+) -> impl IntoView {
+    let charts = Memo::new(move |_| log_entries.with(|entries| {
+        Metric::ALL.into_iter()
+            .map(|m| (m, series(entries, m)))
+            .filter(|(_, points)| !points.is_empty())
+            .collect::<Vec<_>>()
+    }));
+
+    view! {
+        {(!read_only).then(|| view! {
+            <MeasurementForm orchid_signal=orchid_signal set_log_entries=set_log_entries />
+        })}
+        {move || {
+            let list = charts.get();
+            if list.is_empty() {
+                view! {
+                    <p class="py-6 text-sm text-center text-stone-400">"No measurements yet. Log leaf span, pseudobulbs, spike length or roots to see growth over time."</p>
+                }.into_any()
+            } else {
+                list.into_iter().map(|(metric, points)| view! { <MetricChart metric=metric points=points /> }).collect::<Vec<_>>().into_any()
+            }
+        }}
+    }
+}
+
+/// Logs whichever values were measured as a 'Measured' journal entry.
+#[component]
+fn MeasurementForm(
+    orchid_signal: ReadSignal<Orchid>,
+    set_log_entries: WriteSignal<Vec<LogEntry>>,
+) -> impl IntoView {
+    let (leaf_span, set_leaf_span) = signal(String::new());
+    let (pseudobulbs, set_pseudobulbs) = signal(String::new());
+    let (spike_length, set_spike_length) = signal(String::new());
+    let (roots, set_roots) = signal(String::new());
+    let (note, set_note) = signal(String::new());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let save = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let measurement = GrowthMeasurement {
+            leaf_span_cm: leaf_span.get_untracked().trim().parse().ok(),
+            pseudobulb_count: pseudobulbs.get_untracked().trim().parse().ok(),
+            spike_length_cm: spike_length.get_untracked().trim().parse().ok(),
+            root_count: roots.get_untracked().trim().parse().ok(),
+        };
+        if let Err(e) = measurement.validate() {
+            set_error_msg.set(Some(e));
+            return;
+        }
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let orchid_id = orchid_signal.get_untracked().id;
+        let current_note = Some(note.get_untracked());
+
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::add_measurement(orchid_id, measurement, current_note).await {
+                Ok(entry) => {
+                    set_log_entries.update(|entries| entries.insert(0, entry));
+                    for set in [set_leaf_span, set_pseudobulbs, set_spike_length, set_roots, set_note] {
+                        set.set(String::new());
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("growth.add_measurement", &format!("Failed to add measurement: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let field = move |label: &'static str, step: &'static str, value: ReadSignal<String>, set: WriteSignal<String>| view! {
+        <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+            {label}
+            <input type="number" class=INPUT min="0" step=step
+                prop:value=value
+                on:input=move |ev| set.set(event_target_value(&ev))
+            />
+        </label>
+    };
+
+    view! {
+        <form class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700" on:submit=save>
+            <h4 class=SECTION_HEADING>"Log a measurement"</h4>
+            <div class="grid grid-cols-2 gap-3 mb-3 sm:grid-cols-4">
+                {field("Leaf span (cm)", "0.1", leaf_span, set_leaf_span)}
+                {field("Pseudobulbs", "1", pseudobulbs, set_pseudobulbs)}
+                {field("Spike length (cm)", "0.1", spike_length, set_spike_length)}
+                {field("Roots", "1", roots, set_roots)}
+            </div>
+            <input type="text" class=format!("{} mb-3", INPUT) maxlength="500" placeholder="Note (optional)"
+                prop:value=note
+                on:input=move |ev| set_note.set(event_target_value(&ev))
+            />
+            {move || error_msg.get().map(|e| view! { <p class="mt-0 mb-2 text-xs text-danger">{e}</p> })}
+            <button type="submit" class=BTN_PRIMARY disabled=move || is_saving.get()>
+                {move || if is_saving.get() { "Saving..." } else { "Save Measurement" }}
+            </button>
+        </form>
+    }
+}
+
+/// One metric over time: the latest value, the change since the first measurement, and a line chart.
+#[component]
+fn MetricChart(metric: Metric, points: Vec<(DateTime<Utc>, f64)>) -> impl IntoView {
+    let (first_at, first) = points[0];
+    let (_, latest) = points[points.len() - 1];
+    let change = (points.len() > 1).then(|| {
+        let delta = latest - first;
+        let sign = if delta > 0.0 { "+" } else if delta < 0.0 { "\u{2212}" } else { "\u{00B1}" };
+        format!("{}{} since {}", sign, metric.format_value(delta.abs()), first_at.format("%b %Y"))
+    });
+    let coords = chart_coords(&points);
+    let line = coords.iter().map(|(x, y)| format!("{:.1},{:.1}", x, y)).collect::<Vec<_>>().join(" ");
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="flex justify-between items-baseline mb-2">
+                <h4 class="m-0 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400">{metric.label()}</h4>
+                <div class="text-right">
+                    <span class="text-sm font-semibold text-stone-800 dark:text-stone-100">{metric.format_value(latest)}</span>
+                    {change.map(|c| view! { <span class="ml-2 text-xs text-stone-400">{c}</span> })}
+                </div>
+            </div>
+            <svg viewBox=format!("0 0 {} {}", CHART_W, CHART_H) class="w-full h-20" role="img" aria-label=format!("{} over time", metric.label())>
+                <polyline points=line fill="none" stroke="currentColor" stroke-width="2" stroke-linejoin="round" class="text-primary dark:text-primary-light" />
+                {coords.iter().zip(points.iter()).map(|((x, y), (at, v))| view! {
+                    <circle cx=format!("{:.1}", x) cy=format!("{:.1}", y) r="3" class="fill-primary dark:fill-primary-light">
+                        <title>{format!("{}: {}", at.format("%b %-d, %Y"), metric.format_value(*v))}</title>
+                    </circle>
+                }).collect::<Vec<_>>()}
+            </svg>
+            <div class="flex justify-between text-stone-400 text-[10px]">
+                <span>{first_at.format("%b %-d, %Y").to_string()}</span>
+                {(points.len() > 1).then(|| view! { <span>{points[points.len() - 1].0.format("%b %-d, %Y").to_string()}</span> })}
+            </div>
+        </div>
+    }
+}
+
+/// Maps dated values onto the chart area: time left to right, larger values higher. A single point,
+/// or a run of equal values, sits in the middle.
+fn chart_coords(points: &[(DateTime<Utc>, f64)]) -> Vec<(f64, f64)> {
+    let Some(((t0, _), (t1, _))) = points.first().zip(points.last()) else { return Vec::new() };
+    let span_secs = (*t1 - *t0).num_seconds() as f64;
+    let (lo, hi) = points.iter().fold((f64::MAX, f64::MIN), |(lo, hi), (_, v)| (lo.min(*v), hi.max(*v)));
+    let (w, h) = (CHART_W - 2.0 * CHART_PAD, CHART_H - 2.0 * CHART_PAD);
+
+    points.iter().map(|(t, v)| {
+        let x = if span_secs > 0.0 { (*t - *t0).num_seconds() as f64 / span_secs } else { 0.5 };
+        let y = if hi > lo { (v - lo) / (hi - lo) } else { 0.5 };
+        (CHART_PAD + x * w, CHART_PAD + (1.0 - y) * h)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_chart_coords_span_the_area() {
+        let at = |d| Utc.with_ymd_and_hms(2024, 1, d, 0, 0, 0).unwrap();
+        let coords = chart_coords(&[(at(1), 10.0), (at(11), 20.0), (at(21), 15.0)]);
+        assert_eq!(coords[0], (CHART_PAD, CHART_H - CHART_PAD));
+        assert_eq!(coords[1], (CHART_W / 2.0, CHART_PAD));
+        assert_eq!(coords[2].1, CHART_H / 2.0);
+
+        let single = chart_coords(&[(at(1), 4.0)]);
+        assert_eq!(single, vec![(CHART_W / 2.0, CHART_H / 2.0)]);
+    }
+}
@@ -1,16 +1,43 @@
 use leptos::prelude::*;
-use crate::orchid::LogEntry;
-use crate::components::event_types::get_event_info;
-use chrono::{Datelike, Local};
+use crate::orchid::{EntryClimate, LogEntry};
+use crate::components::event_types::lookup_event_type;
+use crate::components::markdown_note::MarkdownNote;
+use chrono::{Datelike, Local, NaiveDate};
+use crate::journal::{JournalQuery, MAX_JOURNAL_SEARCH_LEN};
+use crate::thumbnails::{thumbnail_url, ThumbSize};
 
 const THREAD_LINE: &str = "absolute left-[18px] top-0 bottom-0 w-0.5 bg-primary-light/30";
+const FILTER_INPUT: &str = "py-1.5 px-2 text-sm bg-white rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600 dark:text-stone-200";
+
+/// Small muted line showing the zone conditions captured with an entry.
+fn climate_line(climate: Option<EntryClimate>, unit: &str) -> Option<impl IntoView> {
+    climate.map(|c| view! {
+        <div class="mt-0.5 text-[11px] tabular-nums text-stone-400 dark:text-stone-500" title="Zone conditions when this entry was logged">
+            {format!("\u{1F321} {}", c.describe(unit))}
+        </div>
+    })
+}
+
+/// "💧 Soaked · 500 ml · Still wet": how a watering was done and how the medium was, falling back to plain "Watered".
+fn watering_label(entry: &LogEntry) -> String {
+    let mut label = format!("\u{1F4A7} {}", entry.watering_method.map(|m| m.label()).unwrap_or("Watered"));
+    if let Some(ml) = entry.water_volume_ml {
+        label.push_str(&format!(" \u{00B7} {} ml", ml));
+    }
+    if let Some(feedback) = entry.medium_feedback {
+        label.push_str(&format!(" \u{00B7} {}", feedback.label()));
+    }
+    label
+}
 
 #[component]
 pub fn GrowthThread(
-    entries: ReadSignal<Vec<LogEntry>>,
+    #[prop(into)] entries: Signal<Vec<LogEntry>>,
     #[prop(optional)] orchid_id: Option<String>,
+    #[prop(default = String::from("C"))] temp_unit: String,
 ) -> impl IntoView {
     let orchid_id = StoredValue::new(orchid_id.unwrap_or_default());
+    let temp_unit = StoredValue::new(temp_unit);
     view! {
         <div class="relative">
             // Thread vine line
@@ -41,13 +68,15 @@ pub fn GrowthThread(
                 }
 
                 let oid = orchid_id.get_value();
+                let unit = temp_unit.get_value();
                 view! {
                     <div>
                         {groups.into_iter().map(move |group| {
                             let (month, month_entries) = group;
                             let oid = oid.clone();
+                            let unit = unit.clone();
                             view! {
-                                <MonthSection month=month entries=month_entries orchid_id=oid />
+                                <MonthSection month=month entries=month_entries orchid_id=oid temp_unit=unit />
                             }
                         }).collect::<Vec<_>>()}
                     </div>
@@ -57,11 +86,56 @@ pub fn GrowthThread(
     }.into_any()
 }
 
+/// Keyword search and date range above the thread. The keyword applies on Enter or when the box
+/// loses focus, so typing doesn't fetch on every keystroke.
+#[component]
+pub fn JournalSearchBar(
+    query: ReadSignal<JournalQuery>,
+    set_query: WriteSignal<JournalQuery>,
+    /// Called after "Clear" resets every filter, for filters kept outside the query.
+    on_clear: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let parse_day = |v: String| NaiveDate::parse_from_str(&v, "%Y-%m-%d").ok();
+    let day_value = |d: Option<NaiveDate>| d.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+
+    view! {
+        <div class="flex flex-wrap gap-2 items-center mb-4">
+            <input type="search" class=format!("{} flex-1 min-w-[140px]", FILTER_INPUT)
+                placeholder="Search notes\u{2026}"
+                maxlength=MAX_JOURNAL_SEARCH_LEN.to_string()
+                prop:value=move || query.with(|q| q.text.clone())
+                on:change=move |ev| set_query.update(|q| q.text = event_target_value(&ev))
+            />
+            <input type="date" class=FILTER_INPUT aria-label="From date" title="From"
+                prop:value=move || day_value(query.with(|q| q.from))
+                on:change=move |ev| set_query.update(|q| q.from = parse_day(event_target_value(&ev)))
+            />
+            <span class="text-xs text-stone-400">"to"</span>
+            <input type="date" class=FILTER_INPUT aria-label="To date" title="To"
+                prop:value=move || day_value(query.with(|q| q.to))
+                on:change=move |ev| set_query.update(|q| q.to = parse_day(event_target_value(&ev)))
+            />
+            {move || query.with(|q| !q.is_empty()).then(|| view! {
+                <button type="button"
+                    class="py-1 px-2 text-xs font-medium bg-transparent rounded-lg border-none cursor-pointer text-stone-500 hover:text-stone-700 dark:hover:text-stone-300"
+                    on:click=move |_| {
+                        set_query.set(JournalQuery::default());
+                        on_clear();
+                    }
+                >
+                    "Clear"
+                </button>
+            })}
+        </div>
+    }
+}
+
 #[component]
 fn MonthSection(
     month: String,
     entries: Vec<LogEntry>,
     orchid_id: String,
+    temp_unit: String,
 ) -> impl IntoView {
     view! {
         <div class="mb-2">
@@ -86,15 +160,17 @@ fn MonthSection(
                     let is_milestone = matches!(event_type.as_deref(), Some("Flowering" | "Purchased" | "Repotted"));
                     let has_photo = entry.image_filename.is_some();
 
+                    let unit = temp_unit.clone();
+
                     if has_photo {
-                        view! { <PhotoNode entry=entry /> }.into_any()
+                        view! { <PhotoNode entry=entry temp_unit=unit /> }.into_any()
                     } else if is_watering {
-                        view! { <WateringNode entry=entry /> }.into_any()
+                        view! { <WateringNode entry=entry temp_unit=unit /> }.into_any()
                     } else if is_milestone {
                         let oid = orchid_id.clone();
-                        view! { <MilestoneNode entry=entry orchid_id=oid /> }.into_any()
+                        view! { <MilestoneNode entry=entry orchid_id=oid temp_unit=unit /> }.into_any()
                     } else {
-                        view! { <TextNode entry=entry /> }.into_any()
+                        view! { <TextNode entry=entry temp_unit=unit /> }.into_any()
                     }
                 }
             }).collect::<Vec<_>>()}
@@ -103,10 +179,10 @@ fn MonthSection(
 }
 
 #[component]
-fn PhotoNode(entry: LogEntry) -> impl IntoView {
-    let info = entry.event_type.as_deref().and_then(get_event_info);
-    let badge_class = info.map(|i| format!("{} {}", i.bg_class, i.color_class)).unwrap_or_default();
-    let badge_text = info.map(|i| format!("{} {}", i.emoji, i.label));
+fn PhotoNode(entry: LogEntry, temp_unit: String) -> impl IntoView {
+    let info = entry.event_type.as_deref().and_then(lookup_event_type);
+    let badge_class = info.as_ref().map(|i| format!("{} {}", i.bg_class(), i.color_class())).unwrap_or_default();
+    let badge_text = info.as_ref().map(|i| format!("{} {}", i.emoji, i.label));
     let filename = entry.image_filename.clone().unwrap_or_default();
     let (show_lightbox, set_show_lightbox) = signal(false);
     let note = entry.note.clone();
@@ -129,7 +205,7 @@ fn PhotoNode(entry: LogEntry) -> impl IntoView {
                 on:click=move |_| set_show_lightbox.set(true)
             >
                 <img
-                    src=format!("/images/{}", filename)
+                    src=thumbnail_url(&filename, ThumbSize::Medium)
                     class="block object-cover w-full max-h-[400px]"
                     alt="Growth photo"
                     loading="lazy"
@@ -144,8 +220,9 @@ fn PhotoNode(entry: LogEntry) -> impl IntoView {
 
             // Note
             {(!note.is_empty()).then(|| {
-                view! { <p class="text-sm text-stone-700 dark:text-stone-300">{note.clone()}</p> }
+                view! { <MarkdownNote text=note.clone() class="text-sm text-stone-700 dark:text-stone-300" /> }
             })}
+            {climate_line(entry.climate, &temp_unit)}
         </div>
 
         // Lightbox
@@ -165,11 +242,11 @@ fn PhotoNode(entry: LogEntry) -> impl IntoView {
 }
 
 #[component]
-fn TextNode(entry: LogEntry) -> impl IntoView {
-    let info = entry.event_type.as_deref().and_then(get_event_info);
-    let dot_color = info.map(|i| i.color_class).unwrap_or("text-stone-400");
-    let badge = info.map(|i| format!("{} {}", i.emoji, i.label));
-    let badge_classes = info.map(|i| format!("{} {}", i.bg_class, i.color_class));
+fn TextNode(entry: LogEntry, temp_unit: String) -> impl IntoView {
+    let info = entry.event_type.as_deref().and_then(lookup_event_type);
+    let dot_color = info.as_ref().map(|i| i.color_class()).unwrap_or("text-stone-400");
+    let badge = info.as_ref().map(|i| format!("{} {}", i.emoji, i.label));
+    let badge_classes = info.as_ref().map(|i| format!("{} {}", i.bg_class(), i.color_class()));
 
     view! {
         <div class="relative pb-3 pl-10">
@@ -188,14 +265,15 @@ fn TextNode(entry: LogEntry) -> impl IntoView {
                 })}
             </div>
             {(!entry.note.is_empty()).then(|| {
-                view! { <p class="mt-0.5 text-sm text-stone-700 dark:text-stone-300">{entry.note.clone()}</p> }
+                view! { <MarkdownNote text=entry.note.clone() class="mt-0.5 text-sm text-stone-700 dark:text-stone-300" /> }
             })}
+            {climate_line(entry.climate.clone(), &temp_unit)}
         </div>
     }.into_any()
 }
 
 #[component]
-fn WateringNode(entry: LogEntry) -> impl IntoView {
+fn WateringNode(entry: LogEntry, temp_unit: String) -> impl IntoView {
     view! {
         <div class="relative pb-1.5 pl-10">
             // Small droplet dot
@@ -205,19 +283,22 @@ fn WateringNode(entry: LogEntry) -> impl IntoView {
                     {entry.timestamp.with_timezone(&Local).format("%b %d").to_string()}
                 </span>
                 <span class="text-xs text-sky-500 dark:text-sky-400">
-                    "\u{1F4A7} Watered"
+                    {watering_label(&entry)}
                 </span>
+                {entry.climate.map(|c| view! {
+                    <span class="tabular-nums text-[11px] text-stone-400 dark:text-stone-500">{c.describe(&temp_unit)}</span>
+                })}
             </div>
         </div>
     }.into_any()
 }
 
 #[component]
-fn MilestoneNode(entry: LogEntry, orchid_id: String) -> impl IntoView {
-    let info = entry.event_type.as_deref().and_then(get_event_info);
-    let dot_color = info.map(|i| i.color_class).unwrap_or("text-primary-light");
-    let bg_color = info.map(|i| i.bg_class).unwrap_or("bg-primary-light/10");
-    let label = info.map(|i| format!("{} {}", i.emoji, i.label)).unwrap_or_default();
+fn MilestoneNode(entry: LogEntry, orchid_id: String, temp_unit: String) -> impl IntoView {
+    let info = entry.event_type.as_deref().and_then(lookup_event_type);
+    let dot_color = info.as_ref().map(|i| i.color_class()).unwrap_or("text-primary-light");
+    let bg_color = info.as_ref().map(|i| i.bg_class()).unwrap_or("bg-primary-light/10");
+    let label = info.as_ref().map(|i| format!("{} {}", i.emoji, i.label)).unwrap_or_default();
     let is_flowering = entry.event_type.as_deref() == Some("Flowering");
 
     // Care recap state (lazy-loaded)
@@ -269,8 +350,9 @@ fn MilestoneNode(entry: LogEntry, orchid_id: String) -> impl IntoView {
                     </span>
                 </div>
                 {(!entry.note.is_empty()).then(|| {
-                    view! { <p class="text-sm text-stone-700 dark:text-stone-300">{entry.note.clone()}</p> }
+                    view! { <MarkdownNote text=entry.note.clone() class="text-sm text-stone-700 dark:text-stone-300" /> }
                 })}
+                {climate_line(entry.climate.clone(), &temp_unit)}
 
                 // Care recap expander
                 <button
@@ -326,7 +408,7 @@ fn PhotoLightbox(
                     {timestamp.with_timezone(&Local).format("%B %d, %Y at %H:%M").to_string()}
                 </div>
                 {(!note.is_empty()).then(|| {
-                    view! { <p class="text-sm text-white/80">{note.clone()}</p> }
+                    view! { <MarkdownNote text=note.clone() class="text-sm text-white/80" /> }
                 })}
             </div>
             <button
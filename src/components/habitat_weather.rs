@@ -1,5 +1,6 @@
 use leptos::prelude::*;
-use crate::orchid::{HabitatWeather, HabitatWeatherSummary, ClimateReading};
+use crate::habitat_similarity::{MonthlySimilarity, CLOSE_MATCH_SCORE, SOME_DRIFT_SCORE};
+use crate::orchid::{HabitatWeather, HabitatWeatherSummary, ClimateReading, Orchid};
 
 const CARD: &str = "p-4 mt-4 rounded-xl border shadow-sm bg-gradient-to-br from-emerald-50/50 to-stone-50 border-emerald-200/60 dark:from-emerald-950/20 dark:to-stone-900 dark:border-emerald-800/40";
 const STAT_LABEL: &str = "text-xs font-medium tracking-wider uppercase text-stone-400";
@@ -11,6 +12,8 @@ pub fn HabitatWeatherCard(
     latitude: f64,
     longitude: f64,
     zone_reading: Option<ClimateReading>,
+    /// Monthly home-vs-habitat scores, when loaded.
+    #[prop(optional)] similarity: Option<ReadSignal<Option<Vec<MonthlySimilarity>>>>,
 ) -> impl IntoView {
     let lat = latitude;
     let lon = longitude;
@@ -70,6 +73,10 @@ pub fn HabitatWeatherCard(
                     }
                 }}
             </Suspense>
+
+            {move || similarity.and_then(|s| s.get()).filter(|months| !months.is_empty()).map(|months| {
+                view! { <HabitatSimilarityView months=months /> }
+            })}
         </div>
     }
 }
@@ -159,6 +166,48 @@ fn HabitatTrendView(summaries: Vec<HabitatWeatherSummary>) -> impl IntoView {
     }.into_any()
 }
 
+/// Text colour for a similarity score, by verdict band.
+pub(crate) fn similarity_class(score: u8) -> &'static str {
+    if score >= CLOSE_MATCH_SCORE {
+        "text-emerald-700 dark:text-emerald-400"
+    } else if score >= SOME_DRIFT_SCORE {
+        "text-amber-700 dark:text-amber-400"
+    } else {
+        "text-red-700 dark:text-red-400"
+    }
+}
+
+#[component]
+fn HabitatSimilarityView(months: Vec<MonthlySimilarity>) -> impl IntoView {
+    let latest = months.last().cloned();
+
+    view! {
+        <div class="pt-3 mt-3 border-t border-emerald-200/40 dark:border-emerald-800/30">
+            <h5 class="mt-0 mb-2 text-xs font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Monthly Match With Home"</h5>
+            <div class="flex gap-1">
+                {months.iter().map(|m| {
+                    let title = match m.main_gap() {
+                        Some(gap) => format!("{} {}: {}/100, {} ({})", Orchid::month_name(m.month), m.year, m.score, m.verdict(), gap),
+                        None => format!("{} {}: {}/100, {}", Orchid::month_name(m.month), m.year, m.score, m.verdict()),
+                    };
+                    view! {
+                        <div class="flex flex-col flex-1 items-center py-1 rounded-md bg-white/60 dark:bg-stone-800/60" title=title>
+                            <span class="text-[10px] text-stone-400">{Orchid::month_name(m.month)}</span>
+                            <span class=format!("text-sm font-semibold {}", similarity_class(m.score))>{m.score}</span>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            {latest.map(|m| view! {
+                <p class="mt-1 mb-0 text-xs text-stone-400">
+                    {format!("{}: {}", Orchid::month_name(m.month), m.verdict())}
+                    {m.main_gap().map(|gap| format!(" \u{2014} home runs {}", gap))}
+                </p>
+            })}
+        </div>
+    }.into_any()
+}
+
 fn format_time_ago(dt: &chrono::DateTime<chrono::Utc>) -> String {
     let now = chrono::Utc::now();
     let diff = now - *dt;
@@ -0,0 +1,194 @@
+use leptos::prelude::*;
+use crate::household::{HouseholdInfo, HouseholdRole};
+
+const SELECT_CLASS: &str = "py-1 px-2 text-xs rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50";
+
+/// Household members, roles, and invite links for the settings modal.
+#[component]
+pub fn HouseholdSettings() -> impl IntoView {
+    let household = Resource::new(|| (), |_| crate::server_fns::household::get_household());
+    let (invite_role, set_invite_role) = signal(HouseholdRole::Caretaker);
+    let (invite_link, set_invite_link) = signal(None::<String>);
+    let (is_busy, set_is_busy) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let create_invite = move |_| {
+        let role = invite_role.get_untracked();
+        set_is_busy.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::household::create_household_invite(role.as_str().to_string()).await {
+                Ok(link) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("household.invite", "Household invite created", &[("role", role.as_str())]);
+                    set_invite_link.set(Some(link));
+                    household.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("household.invite", &format!("Failed to create invite: {}", e), &[("role", role.as_str())]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    let revoke_invite = move |id: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::household::revoke_household_invite(id.clone()).await {
+                Ok(()) => household.refetch(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("household.revoke_invite", &format!("Failed to revoke invite: {}", e), &[("invite_id", &id)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let change_role = move |user_id: String, role: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::household::update_household_member_role(user_id.clone(), role.clone()).await {
+                Ok(()) => household.refetch(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("household.change_role", &format!("Failed to change role: {}", e), &[("member", &user_id), ("role", &role)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let remove_member = move |user_id: Option<String>| {
+        let _leaving = user_id.is_none();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::household::remove_household_member(user_id).await {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("household.remove", if _leaving { "Left household" } else { "Removed household member" }, &[]);
+                    household.refetch();
+                    // Leaving swaps the whole collection back to the user's own, so start fresh
+                    #[cfg(feature = "hydrate")]
+                    if _leaving
+                        && let Some(window) = web_sys::window()
+                    {
+                        let _ = window.location().set_href("/");
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("household.remove", &format!("Failed to remove member: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let render = move |info: Option<HouseholdInfo>| {
+        let is_owner = info.as_ref().is_none_or(|h| h.my_role == HouseholdRole::Owner);
+        let members = info.as_ref().map(|h| h.members.clone()).unwrap_or_default();
+        let invites = info.as_ref().map(|h| h.invites.clone()).unwrap_or_default();
+        let name = info.as_ref().map(|h| h.name.clone());
+
+        view! {
+            {name.map(|n| view! { <p class="mt-0 mb-2 text-sm font-medium text-stone-700 dark:text-stone-300">{n}</p> })}
+            {(!members.is_empty()).then(|| view! {
+                <div class="flex flex-col gap-1.5 mb-3">
+                    {members.into_iter().map(|m| {
+                        let id = m.user_id.clone();
+                        let id_remove = m.user_id.clone();
+                        let editable = is_owner && m.role != HouseholdRole::Owner;
+                        view! {
+                            <div class="flex justify-between items-center py-2 px-3 text-sm rounded-lg bg-secondary/30">
+                                <div>
+                                    <div class="font-medium text-stone-700 dark:text-stone-300">{m.username}</div>
+                                    <div class="text-[11px] text-stone-400">{m.role.description()}</div>
+                                </div>
+                                {if editable {
+                                    view! {
+                                        <div class="flex gap-1 items-center">
+                                            <select class=SELECT_CLASS
+                                                on:change=move |ev| change_role(id.clone(), event_target_value(&ev))
+                                            >
+                                                {HouseholdRole::INVITABLE.iter().map(|r| view! {
+                                                    <option value=r.as_str() selected=*r == m.role>{r.label()}</option>
+                                                }).collect::<Vec<_>>()}
+                                            </select>
+                                            <button
+                                                class="py-1 px-2 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                                on:click=move |_| remove_member(Some(id_remove.clone()))
+                                            >"Remove"</button>
+                                        </div>
+                                    }.into_any()
+                                } else {
+                                    view! { <span class="text-xs text-stone-500">{m.role.label()}</span> }.into_any()
+                                }}
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+            })}
+            {(!invites.is_empty()).then(|| view! {
+                <div class="flex flex-col gap-1 mb-3">
+                    {invites.into_iter().map(|inv| {
+                        let id = inv.id.clone();
+                        view! {
+                            <div class="flex justify-between items-center text-xs text-stone-500">
+                                <span>{format!("Pending {} invite \u{00B7} expires {}", inv.role.label().to_lowercase(), inv.expires_at.format("%b %d"))}</span>
+                                <button
+                                    class="py-0.5 px-2 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                    on:click=move |_| revoke_invite(id.clone())
+                                >"Revoke"</button>
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+            })}
+            {if is_owner {
+                view! {
+                    <div class="flex gap-2 items-center">
+                        <select class=SELECT_CLASS
+                            on:change=move |ev| {
+                                if let Some(role) = HouseholdRole::from_key(&event_target_value(&ev)) {
+                                    set_invite_role.set(role);
+                                }
+                            }
+                        >
+                            {HouseholdRole::INVITABLE.iter().map(|r| view! {
+                                <option value=r.as_str() selected=move || invite_role.get() == *r>{r.label()}</option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                        <button
+                            class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                            disabled=move || is_busy.get()
+                            on:click=create_invite
+                        >{move || if is_busy.get() { "Creating..." } else { "Create Invite Link" }}</button>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <button
+                        class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-danger bg-danger/10 hover:bg-danger/20"
+                        on:click=move |_| remove_member(None)
+                    >"Leave Household"</button>
+                }.into_any()
+            }}
+        }
+    };
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || household.get().map(|result| render(result.ok().flatten()))}
+        </Suspense>
+        {move || invite_link.get().map(|link| view! {
+            <div class="p-3 mt-3 text-sm rounded-lg bg-primary/5 dark:bg-primary-light/5">
+                <div class="text-xs font-medium text-stone-500 dark:text-stone-400">"Send this link to the person you're inviting. It works once and is only shown now:"</div>
+                <code class="text-xs break-all text-primary dark:text-primary-light">{link}</code>
+            </div>
+        })}
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
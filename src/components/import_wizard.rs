@@ -0,0 +1,201 @@
+use leptos::prelude::*;
+use crate::import::{convert, ColumnMapping, ImportSource, MappedField};
+
+const SELECT_CLASS: &str = "py-1.5 px-2 text-xs rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50";
+
+/// Reads the chosen file as text.
+#[cfg(feature = "hydrate")]
+async fn read_file(file: web_sys::File) -> Result<String, String> {
+    let file = gloo_file::File::from(file);
+    if file.size() as usize > crate::server_fns::import::MAX_IMPORT_BYTES {
+        return Err("File is too large (max 2 MB)".into());
+    }
+    gloo_file::futures::read_as_text(&file).await
+        .map_err(|e| format!("Couldn't read file: {}", e))
+}
+
+/// Import wizard for Planta, Greg, or generic CSV exports: upload, review the column mapping and preview, then import.
+#[component]
+pub fn ImportWizard(
+    on_imported: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let file_text = RwSignal::new(None::<String>);
+    let headers = RwSignal::new(Vec::<String>::new());
+    let rows = RwSignal::new(Vec::<Vec<String>>::new());
+    let source = RwSignal::new(ImportSource::Generic);
+    let mapping = RwSignal::new(ColumnMapping::default());
+    let show_mapping = RwSignal::new(false);
+    let is_importing = RwSignal::new(false);
+    let error_msg = RwSignal::new(None::<String>);
+    let result_msg = RwSignal::new(None::<String>);
+    let file_input_ref = NodeRef::<leptos::html::Input>::new();
+
+    let preview = Memo::new(move |_| {
+        if file_text.with(|t| t.is_none()) {
+            return None;
+        }
+        Some(rows.with(|r| mapping.with(|m| convert(source.get(), r, m))))
+    });
+
+    let on_file = move |_| {
+        error_msg.set(None);
+        result_msg.set(None);
+        #[cfg(feature = "hydrate")]
+        {
+            let Some(input) = file_input_ref.get() else { return };
+            let input_el: &web_sys::HtmlInputElement = input.as_ref();
+            let Some(file) = input_el.files().and_then(|f| f.get(0)) else { return };
+            leptos::task::spawn_local(async move {
+                let parsed = read_file(file).await
+                    .and_then(|text| crate::import::csv::parse_csv(&text).map(|parsed| (text, parsed)));
+                match parsed {
+                    Ok((text, (h, r))) => {
+                        let detected = ImportSource::detect(&h);
+                        mapping.set(ColumnMapping::guess(&h, detected));
+                        show_mapping.set(detected == ImportSource::Generic);
+                        source.set(detected);
+                        headers.set(h);
+                        rows.set(r);
+                        file_text.set(Some(text));
+                    }
+                    Err(e) => {
+                        crate::server_fns::telemetry::emit_warn("settings.import_read", &format!("Import file rejected: {}", e), &[]);
+                        file_text.set(None);
+                        error_msg.set(Some(e));
+                    }
+                }
+            });
+        }
+    };
+
+    let on_source_change = move |ev| {
+        let picked = ImportSource::from_code(&event_target_value(&ev));
+        source.set(picked);
+        headers.with_untracked(|h| mapping.set(ColumnMapping::guess(h, picked)));
+    };
+
+    let do_import = move |_| {
+        let Some(text) = file_text.get_untracked() else { return };
+        is_importing.set(true);
+        error_msg.set(None);
+        let src = source.get_untracked();
+        let map = mapping.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::import::import_plants(src.code().to_string(), text, map).await {
+                Ok(summary) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.import", "Plants imported", &[
+                        ("source", src.code()),
+                        ("plants", &summary.plants_created.to_string()),
+                        ("events", &summary.events_created.to_string()),
+                    ]);
+                    result_msg.set(Some(format!(
+                        "Imported {} plants and {} journal entries.",
+                        summary.plants_created, summary.events_created
+                    )));
+                    file_text.set(None);
+                    headers.set(Vec::new());
+                    rows.set(Vec::new());
+                    on_imported();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.import", &format!("Import failed: {}", e), &[("source", src.code())]);
+                    error_msg.set(Some(e.to_string()));
+                }
+            }
+            is_importing.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-2 items-center mb-3">
+            <select class=SELECT_CLASS on:change=on_source_change prop:value=move || source.get().code()>
+                {ImportSource::ALL.into_iter().map(|s| view! {
+                    <option value=s.code()>{s.label()}</option>
+                }).collect::<Vec<_>>()}
+            </select>
+            <input type="file" accept=".csv,text/csv,text/plain"
+                class="text-xs text-stone-500"
+                node_ref=file_input_ref
+                on:change=on_file
+            />
+        </div>
+
+        {move || preview.get().map(|result| {
+            let header_list = headers.get();
+            view! {
+                <div class="mb-3">
+                    <button
+                        class="p-0 mb-2 text-xs bg-transparent border-none cursor-pointer text-primary hover:underline"
+                        on:click=move |_| show_mapping.update(|v| *v = !*v)
+                    >
+                        {move || if show_mapping.get() { "Hide column mapping" } else { "Adjust column mapping" }}
+                    </button>
+                    {move || show_mapping.get().then(|| {
+                        let header_list = header_list.clone();
+                        view! {
+                            <div class="grid grid-cols-2 gap-2 mb-3">
+                                {MappedField::ALL.into_iter().map(|field| {
+                                    let header_list = header_list.clone();
+                                    view! {
+                                        <label class="flex flex-col gap-0.5 text-[11px] text-stone-500">
+                                            {field.label()}
+                                            <select class=SELECT_CLASS
+                                                prop:value=move || mapping.with(|m| m.get(field).map(|c| c.to_string()).unwrap_or_default())
+                                                on:change=move |ev| {
+                                                    let col = event_target_value(&ev).parse::<usize>().ok();
+                                                    mapping.update(|m| m.set(field, col));
+                                                }
+                                            >
+                                                <option value="">"\u{2014}"</option>
+                                                {header_list.into_iter().enumerate().map(|(i, h)| view! {
+                                                    <option value=i.to_string()>{h}</option>
+                                                }).collect::<Vec<_>>()}
+                                            </select>
+                                        </label>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }
+                    })}
+                    {match result {
+                        Err(e) => view! { <p class="my-2 text-xs text-red-600 dark:text-red-400">{e}</p> }.into_any(),
+                        Ok(p) => {
+                            let summary = format!("{} plants, {} journal entries", p.plants.len(), p.event_count());
+                            let more = p.plants.len().saturating_sub(5);
+                            let warnings = p.warnings.len();
+                            view! {
+                                <p class="mt-0 mb-2 text-xs font-semibold text-stone-600 dark:text-stone-300">{summary}</p>
+                                <div class="flex flex-col gap-1 mb-2">
+                                    {p.plants.into_iter().take(5).map(|plant| view! {
+                                        <div class="py-1.5 px-3 text-xs rounded-lg bg-secondary/30">
+                                            <span class="font-medium text-stone-700 dark:text-stone-300">{plant.name}</span>
+                                            <span class="text-stone-400">{format!(" \u{00B7} {} \u{00B7} every {}d \u{00B7} {} entries", plant.species, plant.water_frequency_days, plant.events.len())}</span>
+                                        </div>
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                                {(more > 0).then(|| view! { <p class="mt-0 mb-2 text-[11px] text-stone-400">{format!("\u{2026}and {} more", more)}</p> })}
+                                {(warnings > 0).then(|| view! { <p class="mt-0 mb-2 text-[11px] text-amber-600">{format!("{} rows skipped or adjusted", warnings)}</p> })}
+                                <button
+                                    class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                                    disabled=move || is_importing.get()
+                                    on:click=do_import
+                                >
+                                    {move || if is_importing.get() { "Importing..." } else { "Import" }}
+                                </button>
+                            }.into_any()
+                        }
+                    }}
+                </div>
+            }
+        })}
+
+        {move || result_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-emerald-700 dark:text-emerald-400">{msg}</p>
+        })}
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
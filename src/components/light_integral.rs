@@ -0,0 +1,92 @@
+use leptos::prelude::*;
+use crate::light::{compare_light, LightFit, ZoneDli};
+
+/// Days of history shown per zone.
+const DLI_DAYS: u32 = 7;
+
+/// Daily light integral cards for zones with light sensors, shown on the My Plants tab.
+#[component]
+pub fn ZoneDliCards() -> impl IntoView {
+    let dli = Resource::new(|| (), |_| crate::server_fns::climate::get_zone_dli(DLI_DAYS));
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || dli.get().map(|result| {
+                let zones = match result {
+                    Ok(z) => z,
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("zone_dli.load", &format!("Failed to load zone DLI: {}", _e), &[]);
+                        Vec::new()
+                    }
+                };
+                if zones.is_empty() {
+                    return view! { <div></div> }.into_any();
+                }
+                view! {
+                    <div class="mb-4">
+                        <h3 class="mt-0 mb-2 text-xs font-bold tracking-widest uppercase text-stone-400 dark:text-stone-500">"Daily Light Integral"</h3>
+                        <div class="grid grid-cols-1 gap-3 sm:grid-cols-2">
+                            {zones.into_iter().map(|z| view! { <ZoneDliCard zone=z /> }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+    }
+}
+
+/// One zone's latest DLI, its plants' target band, and a bar per recent day.
+#[component]
+fn ZoneDliCard(zone: ZoneDli) -> impl IntoView {
+    let latest = zone.days.last().cloned();
+    let scale = zone.days.iter().map(|d| d.dli)
+        .chain(zone.target.map(|(_, high)| high))
+        .fold(1.0_f64, f64::max);
+    let verdict = latest.as_ref().zip(zone.target).map(|(day, band)| match compare_light(day.dli, band) {
+        LightFit::TooDim => ("Below what its plants want", "text-amber-700 dark:text-amber-400"),
+        LightFit::Good => ("Within its plants' range", "text-emerald-700 dark:text-emerald-400"),
+        LightFit::TooBright => ("Above what its plants want", "text-amber-700 dark:text-amber-400"),
+    });
+    let band_style = zone.target.map(|(low, high)| format!(
+        "bottom: {:.0}%; height: {:.0}%",
+        low / scale * 100.0,
+        ((high - low) / scale * 100.0).max(1.0),
+    ));
+
+    view! {
+        <div class="p-4 rounded-xl border bg-surface border-stone-200/60 dark:border-stone-700/60">
+            <div class="flex gap-3 justify-between items-baseline mb-1">
+                <div class="text-sm font-semibold truncate text-stone-700 dark:text-stone-300">{zone.zone_name.clone()}</div>
+                {latest.as_ref().map(|d| view! {
+                    <div class="text-lg tabular-nums font-display text-primary dark:text-primary-light">
+                        {format!("{:.1}", d.dli)}
+                        <span class="ml-1 text-xs text-stone-400">"mol/m\u{00B2}/day"</span>
+                    </div>
+                })}
+            </div>
+            {verdict.map(|(text, class)| view! { <div class=format!("mb-2 text-xs {}", class)>{text}</div> })}
+            <div class="flex relative gap-1 items-end h-16">
+                {band_style.map(|style| view! {
+                    <div class="absolute inset-x-0 rounded bg-emerald-400/15" style=style></div>
+                })}
+                {zone.days.iter().map(|d| {
+                    let height = format!("height: {:.0}%", (d.dli / scale * 100.0).clamp(2.0, 100.0));
+                    let class = if d.estimated { "flex-1 rounded-t bg-amber-300/50" } else { "flex-1 rounded-t bg-amber-400/80" };
+                    let title = format!("{}: {:.1} mol/m\u{00B2}/day{}", d.date.format("%b %d"), d.dli, if d.estimated { " (estimated from lux)" } else { "" });
+                    view! { <div class=class style=height title=title></div> }
+                }).collect::<Vec<_>>()}
+            </div>
+            <div class="pt-2 mt-2 text-[11px] text-stone-400 border-t border-stone-100 dark:border-stone-700/50">
+                {match zone.target {
+                    Some((low, high)) => format!(
+                        "Target {:.1}\u{2013}{:.1} for {} plant{}",
+                        low, high, zone.plant_count, if zone.plant_count == 1 { "" } else { "s" },
+                    ),
+                    None => "No plants in this zone".to_string(),
+                }}
+                {zone.days.iter().any(|d| d.estimated).then_some(" \u{00B7} lighter bars are estimated from lux")}
+            </div>
+        </div>
+    }
+}
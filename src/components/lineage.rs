@@ -0,0 +1,227 @@
+use leptos::prelude::*;
+use crate::orchid::Orchid;
+use crate::propagation::{Lineage, Propagation, PropagationMethod, PropagationStatus};
+use super::{BTN_PRIMARY, BTN_SECONDARY};
+
+const INPUT: &str = "py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600";
+const LINK: &str = "p-0 text-sm font-medium bg-transparent border-none cursor-pointer text-primary dark:text-primary-light hover:underline";
+
+/// A plant's family tree (parents above, offshoots below) with a form to record a new propagation.
+#[component]
+pub fn LineagePanel(
+    orchid_signal: ReadSignal<Orchid>,
+    #[prop(optional)] read_only: bool,
+    /// Opens another plant's detail view by ID; without it, relatives are shown as plain names.
+    on_open: Option<Callback<String>>,
+    /// Called with the new plant after a propagation is recorded.
+    on_propagated: Option<Callback<Orchid>>,
+) -> impl IntoView {
+    let lineage = Resource::new(
+        move || orchid_signal.with(|o| o.id.clone()),
+        crate::server_fns::propagation::get_lineage,
+    );
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <h4 class="mt-0 mb-3 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400">"Family Tree"</h4>
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Loading lineage\u{2026}"</p> }>
+                {move || lineage.get().map(|result| {
+                    let tree: Lineage = match result {
+                        Ok(l) => l,
+                        Err(e) => {
+                            #[cfg(feature = "hydrate")]
+                            crate::server_fns::telemetry::emit_warn("lineage.load", &format!("Failed to load lineage: {}", e), &[]);
+                            return view! { <p class="text-sm text-danger">{format!("Couldn't load the family tree: {}", e)}</p> }.into_any();
+                        }
+                    };
+                    if tree.ancestors.is_empty() && tree.children.is_empty() {
+                        return view! {
+                            <p class="m-0 text-sm text-stone-400">"No recorded parent or propagations yet."</p>
+                        }.into_any();
+                    }
+                    // Oldest ancestor at the top
+                    let ancestors: Vec<Propagation> = tree.ancestors.into_iter().rev().collect();
+                    view! {
+                        <ol class="p-0 m-0 list-none">
+                            {ancestors.into_iter().map(|p| view! {
+                                <li class="py-1 text-sm text-stone-600 dark:text-stone-300">
+                                    <Relative id=p.parent_id name=p.parent_name on_open=on_open />
+                                    <span class="ml-2 text-xs text-stone-400">
+                                        {format!("\u{2193} {} \u{00B7} {}", p.method.label(), p.propagated_at.format("%b %Y"))}
+                                    </span>
+                                </li>
+                            }).collect::<Vec<_>>()}
+                            <li class="py-1 text-sm font-semibold text-stone-800 dark:text-stone-100">{move || orchid_signal.get().name}</li>
+                        </ol>
+                        {(!tree.children.is_empty()).then(|| view! {
+                            <ul class="pl-4 mt-1 mb-0 border-l-2 border-stone-200 dark:border-stone-700">
+                                {tree.children.into_iter().map(|p| view! {
+                                    <ChildRow propagation=p read_only=read_only on_open=on_open on_changed=move || lineage.refetch() />
+                                }).collect::<Vec<_>>()}
+                            </ul>
+                        })}
+                    }.into_any()
+                })}
+            </Suspense>
+        </div>
+        {(!read_only).then(|| view! {
+            <PropagateForm orchid_signal=orchid_signal on_done=move |child: Orchid| {
+                lineage.refetch();
+                if let Some(cb) = on_propagated {
+                    cb.run(child);
+                }
+            } />
+        })}
+    }
+}
+
+/// A relative's name, as a link when the detail view can open it.
+#[component]
+fn Relative(id: String, name: String, on_open: Option<Callback<String>>) -> impl IntoView {
+    match on_open {
+        Some(cb) => view! { <button class=LINK on:click=move |_| cb.run(id.clone())>{name}</button> }.into_any(),
+        None => view! { <span class="font-medium">{name}</span> }.into_any(),
+    }
+}
+
+/// One offshoot: how and when it was taken, where it is now, and the kept/gifted/sold controls.
+#[component]
+fn ChildRow(
+    propagation: Propagation,
+    read_only: bool,
+    on_open: Option<Callback<String>>,
+    on_changed: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (rehoming, set_rehoming) = signal(None::<PropagationStatus>);
+    let (recipient, set_recipient) = signal(propagation.recipient.clone().unwrap_or_default());
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let id = StoredValue::new(propagation.id.clone());
+
+    let save = move |status: PropagationStatus| {
+        set_error_msg.set(None);
+        let to = Some(recipient.get_untracked());
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::propagation::set_propagation_status(id.get_value(), status, to).await {
+                Ok(()) => {
+                    set_rehoming.set(None);
+                    on_changed();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("lineage.set_status", &format!("Failed to update propagation: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let status = propagation.status;
+    let mut detail = format!("{} \u{00B7} {}", propagation.method.label(), propagation.propagated_at.format("%b %-d, %Y"));
+    if status != PropagationStatus::Kept {
+        detail.push_str(&format!(" \u{00B7} {}", status.label()));
+        if let Some(to) = &propagation.recipient {
+            detail.push_str(&format!(" to {}", to));
+        }
+        if let Some(at) = propagation.rehomed_at {
+            detail.push_str(&format!(" on {}", at.format("%b %-d, %Y")));
+        }
+    }
+
+    view! {
+        <li class="py-1.5 list-none">
+            <div class="flex flex-wrap gap-2 justify-between items-center">
+                <div>
+                    <Relative id=propagation.child_id name=propagation.child_name on_open=on_open />
+                    <div class="text-xs text-stone-400">{detail}</div>
+                </div>
+                {(!read_only).then(|| if status == PropagationStatus::Kept {
+                    view! {
+                        <div class="flex gap-1">
+                            <button class=BTN_SECONDARY on:click=move |_| set_rehoming.set(Some(PropagationStatus::Gifted))>"Gifted"</button>
+                            <button class=BTN_SECONDARY on:click=move |_| set_rehoming.set(Some(PropagationStatus::Sold))>"Sold"</button>
+                        </div>
+                    }.into_any()
+                } else {
+                    view! {
+                        <button class=BTN_SECONDARY on:click=move |_| save(PropagationStatus::Kept)>"Back in collection"</button>
+                    }.into_any()
+                })}
+            </div>
+            {move || rehoming.get().map(|status| view! {
+                <div class="flex flex-wrap gap-2 items-end mt-2">
+                    <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[140px]">
+                        {if status == PropagationStatus::Sold { "Sold to" } else { "Gifted to" }}
+                        <input type="text" maxlength="200" placeholder="Optional" class=INPUT
+                            prop:value=recipient
+                            on:input=move |ev| set_recipient.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <button class=BTN_SECONDARY on:click=move |_| set_rehoming.set(None)>"Cancel"</button>
+                    <button class=BTN_PRIMARY on:click=move |_| save(status)>"Save"</button>
+                </div>
+            })}
+            {move || error_msg.get().map(|e| view! { <p class="mt-1 mb-0 text-xs text-danger">{e}</p> })}
+        </li>
+    }
+}
+
+/// Records a keiki, division or backbulb start as a new plant under this one.
+#[component]
+fn PropagateForm(
+    orchid_signal: ReadSignal<Orchid>,
+    on_done: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (method, set_method) = signal(PropagationMethod::Keiki);
+    let (name, set_name) = signal(String::new());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let save = move |_| {
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let parent_id = orchid_signal.get_untracked().id;
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::propagation::propagate_orchid(parent_id, method.get_untracked(), name.get_untracked()).await {
+                Ok(child) => {
+                    set_name.set(String::new());
+                    on_done(child);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("lineage.propagate", &format!("Failed to record propagation: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <h4 class="mt-0 mb-1 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400">"Record a Propagation"</h4>
+            <p class="mt-0 mb-3 text-xs text-stone-400">"The new plant starts with this one's species, placement and care settings."</p>
+            <div class="flex flex-wrap gap-3 items-end">
+                <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                    "Type"
+                    <select class=INPUT on:change=move |ev| if let Some(m) = PropagationMethod::parse(&event_target_value(&ev)) { set_method.set(m) }>
+                        {PropagationMethod::ALL.into_iter().map(|m| view! {
+                            <option value=m.as_str() selected=move || method.get() == m>{m.label()}</option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </label>
+                <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[160px]">
+                    "Name"
+                    <input type="text" class=INPUT
+                        placeholder=move || format!("{} ({})", orchid_signal.get().name, method.get().label())
+                        prop:value=name
+                        on:input=move |ev| set_name.set(event_target_value(&ev))
+                    />
+                </label>
+                <button class=BTN_PRIMARY disabled=move || is_saving.get() on:click=save>
+                    {move || if is_saving.get() { "Saving..." } else { "Add Propagation" }}
+                </button>
+            </div>
+            {move || error_msg.get().map(|e| view! { <p class="mt-2 mb-0 text-xs text-danger">{e}</p> })}
+        </div>
+    }
+}
@@ -0,0 +1,17 @@
+use leptos::prelude::*;
+use crate::markdown::to_html;
+
+/// Styles for the tags `to_html` writes, since the base stylesheet strips list bullets and link underlines.
+const PROSE: &str = "[&_p]:m-0 [&_p+*]:mt-1.5 [&_ul]:my-1 [&_ul]:pl-5 [&_ul]:list-disc [&_ol]:my-1 [&_ol]:pl-5 [&_ol]:list-decimal [&_a]:underline [&_a]:text-primary dark:[&_a]:text-primary-light [&_strong]:font-semibold";
+
+/// A journal or plant note with its Markdown (bold, italic, links, lists) rendered.
+#[component]
+pub fn MarkdownNote(
+    /// The note as stored.
+    text: String,
+    /// Text size and color classes for the note.
+    #[prop(into)]
+    class: String,
+) -> impl IntoView {
+    view! { <div class=format!("{} {}", class, PROSE) inner_html=to_html(&text)></div> }
+}
@@ -14,6 +14,10 @@ pub mod settings;
 /// It exists to help users identify orchids or parse care instructions from images and text.
 /// It is used either when adding a new plant or scanning a tag from the main dashboard.
 pub mod scanner;
+/// Modal chat with the AI care assistant, grounded in the grower's own collection.
+/// It exists so growers can ask open questions about their plants and get answers that use their journals and zone climate.
+/// It is used by opening it from the "Ask" button in the main application header.
+pub mod care_chat;
 /// Dashboard widget showing real-time and historical climate data for a zone.
 /// It exists to give users a quick overview of the environmental conditions affecting their orchids.
 /// It is used on the main home screen or within a zone's detailed view.
@@ -50,9 +54,21 @@ pub mod habitat_weather;
 /// It exists to abstract the complexities of subscribing to web push notifications.
 /// It is used within the settings modal or as a banner prompt for new users.
 pub mod notification_setup;
+/// Passkey (WebAuthn) browser ceremonies and the passkey manager.
+/// It exists to let users sign in with a phone or security key instead of a password.
+/// It is used by the login page and the account section of the settings modal.
+pub mod passkeys;
+/// Personal API token manager.
+/// It exists so scripts and integrations can authenticate with a scoped, revocable token instead of a password.
+/// It is used in the settings modal's API tokens section.
+pub mod api_tokens;
+/// Active session list with remote sign-out.
+/// It exists so a stolen or forgotten session can be ended without waiting for it to expire.
+/// It is used in the settings modal's sessions section.
+pub mod sessions;
 /// Definitions and constants for various timeline event types (watering, repotting, etc.).
 /// It exists to provide a centralized registry of event metadata and visual styling.
-/// It is used by the `orchid_detail` timeline and the `quick_actions` component.
+/// It is used by the timeline, event picker, gallery, and `quick_actions`, resolving custom types via the `EventTypeRegistry` context.
 pub mod event_types;
 /// Interactive picker component for selecting a timeline event type.
 /// It exists to give users a visual way to choose what kind of log entry they are creating.
@@ -86,10 +102,34 @@ pub mod seasonal_calendar;
 /// It exists to guide users through estimating indoor conditions or linking hardware sensors.
 /// It is used during user onboarding and when adding new zones from the settings.
 pub mod zone_wizard;
+/// Gantt-style care timeline for the next 30 days.
+/// It exists to help users plan watering, fertilizing, and rest across the whole collection.
+/// It is used as the Timeline tab on the home page.
+pub mod care_timeline;
+/// Card showing how often waterings over the last 90 days were on time, overall and per plant.
+/// It exists so growers can spot the plants that are habitually watered late.
+/// It is used on the home page's Today tab, below the task list.
+pub mod adherence;
+/// Two-week calendar of each day's waterings and fertilizings, with a trip planner.
+/// It exists so growers can see which days are busy and what needs doing before they go away.
+/// It is used in the home page's Next 2 Weeks tab.
+pub mod care_forecast;
 /// Dashboard for today's tasks.
-/// It exists to show a list of plants that need to be watered today.
+/// It exists to show a list of plants that need to be watered today, plus due follow-up reminders.
 /// It is used as a tab on the home page.
 pub mod today_tasks;
+/// Date-picker views of a plant and the collection as they stood on a past date.
+/// It exists for post-mortems: where a plant lived and how it was cared for before it declined.
+/// It is used as the History tab on the home page and the As Of tab in the orchid detail modal.
+pub mod time_travel;
+/// The getting-started tour: a hint anchored to the control for each first-run step.
+/// It exists because onboarding only creates zones, leaving climate estimates, plants and watering logs to be discovered.
+/// It is used on the home page, fed with `TourFacts` from the loaded collection.
+pub mod product_tour;
+/// Step-by-step troubleshooting wizard for a sick plant.
+/// It exists to turn symptoms into likely causes and a plan, saved to the journal with a follow-up reminder.
+/// It is used as the Troubleshoot tab in the orchid detail modal.
+pub mod troubleshoot_wizard;
 /// Inline form for manually entering current temperature and humidity.
 /// It exists to allow users without automated sensors to record climate snapshots.
 /// It is used within the `climate_dashboard` or zone settings.
@@ -98,6 +138,122 @@ pub mod manual_reading;
 /// It exists to surface critical environmental issues without occupying much screen space.
 /// It is used at the top of the home page or specific zone views.
 pub mod climate_strip;
+/// 48-hour Open-Meteo forecast cards for outdoor zones.
+/// It exists so growers can see whether rain is coming before watering outdoor mounts.
+/// It is used on the My Plants tab below the climate strip.
+pub mod forecast;
+/// Weekly climate report cards and the per-zone goal editor.
+/// It exists to show how well each zone held its target temperature and humidity ranges over the past week.
+/// It is used on the My Plants tab below the climate strip and in the settings modal.
+pub mod report_cards;
+/// Care confidence card with prompts for a plant's most impactful missing fields.
+/// It exists to show how much of the plant's advice rests on real data rather than defaults.
+/// It is used at the top of the plant details tab.
+pub mod care_confidence;
+/// Daily light integral cards with each zone's recent days and its plants' target band.
+/// It exists because DLI is how growers judge whether a spot gets enough light over a whole day.
+/// It is used on the My Plants tab below the weekly report cards.
+pub mod light_integral;
+/// A plant's family tree and the form that records keikis, divisions and backbulb starts.
+/// It exists so offshoots stay linked to their parent, including ones that were gifted or sold.
+/// It is used on the Lineage tab of the plant detail view.
+pub mod lineage;
+/// Acquisition form fields, the vendor picker and the collection value summary.
+/// It exists so growers can record where each plant came from and what it cost, and see their spend add up.
+/// It is used in the add and edit plant forms and on the Stats tab of the home page.
+pub mod acquisition;
+/// Award and show-result records, and the award badge on orchid cards.
+/// It exists so a plant's AOS awards, judging results and ribbons are kept as structured records.
+/// It is used on the details tab of the orchid detail modal and by `OrchidCard`.
+pub mod awards;
+/// Hybrid parentage fields and the ancestry card.
+/// It exists so a hybrid's grex and cross are recorded as structured data, not folded into the species string.
+/// It is used in the plant edit form and on the details tab of the orchid detail modal.
+pub mod parentage;
+/// The archive form and the Archive tab listing plants that left the collection.
+/// It exists so plants that died or were sold, traded or gifted drop out of care schedules without losing their journal.
+/// It is used on the details tab of the orchid detail modal and on the Archive tab of the home page.
+pub mod archive;
+/// The Reports tab listing the monthly collection reports.
+/// It exists so a report can be reread after its notification is gone, with the facts it was written from.
+/// It is used on the Reports tab of the home page.
+pub mod monthly_reports;
+/// Bloom history card: bloom count, average length, onset months and a year-by-month chart.
+/// It exists so growers can see how reliably a plant reblooms and whether it keeps to its season.
+/// It is used on the details tab of the orchid detail modal.
+pub mod bloom_history;
+/// Multi-select bar and form for writing one journal entry to many plants at once.
+/// It exists so collection-wide jobs like a fungicide spray or a fertilizing round are logged in one step.
+/// It is used by the collection grid's select mode.
+pub mod bulk_journal;
+/// Bulk photo upload in settings: queue many photos, upload them with progress, and assign each a plant and date.
+/// It exists so a camera roll can be moved into plant journals in one sitting.
+/// It is used by the settings modal's import section.
+pub mod bulk_photo_upload;
+/// Pest and disease treatments card, its form, and the treatment badge on orchid cards.
+/// It exists so a course of sprays is recorded with its product and dose and re-treatments aren't missed.
+/// It is used on the details tab of the orchid detail modal and on the collection grid's cards.
+pub mod treatments;
+/// Growth tab: the measurement form and a chart per measured quantity.
+/// It exists so leaf span, pseudobulb, spike and root numbers read as trends rather than scattered journal notes.
+/// It is used as the Growth tab of the orchid detail modal.
+pub mod growth_charts;
+/// Note template picker for the journal's note form and the template manager in settings.
+/// It exists so routine notes like a repotting checklist start pre-structured, with the right event type.
+/// It is used by the orchid detail Journal tab and the settings modal.
+pub mod note_templates;
+/// Renders a note's Markdown subset (bold, italic, links, lists) as styled HTML.
+/// It exists so structured notes like checklists read as lists rather than raw asterisks and dashes.
+/// It is used by the growth thread and the orchid detail Details tab.
+pub mod markdown_note;
+/// Reminders panel for the Today tab: schedule one-off or recurring reminders and review upcoming ones.
+/// It exists for care that isn't watering or fertilizing, like checking keiki roots in three weeks.
+/// It is used by the Today tab below the due follow-ups.
+pub mod reminders;
+/// Time-lapse maker under a plant's photo gallery.
+/// It exists so a plant's photo history can be played back and shared as one animated GIF.
+/// It is used by the orchid detail Gallery tab.
+pub mod timelapse;
+/// Zone running cost cards and the per-zone energy editor.
+/// It exists to show what each zone's lights, heaters, and humidifiers cost to run each month.
+/// It is used on the My Plants tab below the report cards and in the settings modal.
+pub mod zone_economics;
+/// Care automation rule builder and the shared rule context.
+/// It exists so growers can set up "when X happens, do Y" routines without code.
+/// It is used in the settings modal's automation section; the rule context is read by the orchid detail care schedule.
+pub mod care_rules;
+/// Wizard for importing plants from Planta, Greg, or a generic CSV export.
+/// It exists so growers switching apps can bring their schedules and care history with them.
+/// It is used in the settings modal's import section.
+pub mod import_wizard;
+/// Button that downloads a zip of everything stored about the current user.
+/// It exists to give users GDPR subject access to their data without contacting an admin.
+/// It is used in the settings modal's account section and on the account deletion page.
+pub mod data_export;
+/// The "Also known as" card listing common, trade and synonym names for a plant's species.
+/// It exists so growers can record the name on a nursery label or in their own language and find the plant by it.
+/// It is used on the details tab of the orchid detail modal.
+pub mod species_aliases;
+/// Species autocomplete backed by the bundled taxonomy dataset.
+/// It exists so picking a known species can fill in genus care defaults and the native range.
+/// It is used under the species input of the add plant form and the scanner's name search.
+pub mod species_search;
+/// Card header images and the species default image picker.
+/// It exists so plants without their own photos show species stock images or placeholder art instead of nothing.
+/// It is used by `OrchidCard` and the gallery tab of the orchid detail modal.
+pub mod species_photos;
+/// Household member list, role controls, and invite links.
+/// It exists so several people can look after one collection without sharing a login.
+/// It is used in the settings modal's household section.
+pub mod household;
+/// Blueprint export and import of zones, devices, climate goals and automation rules.
+/// It exists so an environment setup can be copied to another instance or shared without plant data or credentials.
+/// It is used in the settings modal's blueprint section.
+pub mod blueprint;
+/// Water sources with their pH and EC/TDS readings and warnings for sensitive genera.
+/// It exists so growers can notice drifting tap or RO water before it burns roots.
+/// It is used in the settings modal's water quality section.
+pub mod water_quality;
 /// Cookie consent banner shown on first visit.
 /// It exists to inform users about our essential session cookie per GDPR/CCPA.
 /// It is rendered globally in the App component and dismisses after acknowledgment.
@@ -177,6 +333,19 @@ pub fn source_badge(source: &Option<String>) -> Option<leptos::tachys::view::any
     }
 }
 
+/// Minutes east of UTC for the browser's time zone.
+/// It exists so server functions that split data into days can use the grower's local midnight.
+/// It is passed to calls like `get_zone_daily_extremes` and `get_care_forecast`; it is 0 during server rendering.
+#[cfg(feature = "hydrate")]
+pub fn browser_utc_offset_minutes() -> i32 {
+    -(js_sys::Date::new_0().get_timezone_offset() as i32)
+}
+
+#[cfg(not(feature = "hydrate"))]
+pub fn browser_utc_offset_minutes() -> i32 {
+    0
+}
+
 /// Formats a UTC timestamp into a human-readable relative string (e.g., "5 min ago").
 /// It exists to provide a more intuitive understanding of data freshness than absolute timestamps.
 /// It is used in UI components that display recent events or sensor readings.
@@ -0,0 +1,43 @@
+use leptos::prelude::*;
+use crate::monthly_report::MonthlyReport;
+
+/// The Reports tab: each month's collection report, newest first, with the facts it was written from
+/// tucked under a disclosure.
+#[component]
+pub fn MonthlyReports() -> impl IntoView {
+    let reports = Resource::new(|| (), |_| crate::server_fns::reports::get_monthly_reports());
+
+    view! {
+        <div class="flex flex-col gap-4">
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Loading reports\u{2026}"</p> }>
+                {move || reports.get().map(|result| match result {
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("reports.load", &format!("Failed to load monthly reports: {}", e), &[]);
+                        view! { <p class="text-sm text-danger">{format!("Couldn't load reports: {}", e)}</p> }.into_any()
+                    }
+                    Ok(list) if list.is_empty() => view! {
+                        <p class="text-sm text-stone-500 dark:text-stone-400">
+                            "No reports yet. At the start of each month you'll get a look back at the last one: what you logged, what bloomed, how the zones held up and which plants kept getting watered late."
+                        </p>
+                    }.into_any(),
+                    Ok(list) => list.into_iter().map(|report| view! { <ReportCard report=report /> }).collect::<Vec<_>>().into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn ReportCard(report: MonthlyReport) -> impl IntoView {
+    view! {
+        <article class="p-4 rounded-xl border sm:p-5 border-stone-200 dark:border-stone-700">
+            <h3 class="mt-0 mb-3 text-base font-semibold text-stone-800 dark:text-stone-100">{report.label}</h3>
+            <p class="m-0 text-sm leading-relaxed whitespace-pre-wrap text-stone-700 dark:text-stone-200">{report.summary}</p>
+            <details class="mt-3">
+                <summary class="text-xs font-medium cursor-pointer text-stone-500 dark:text-stone-400">"What this is based on"</summary>
+                <pre class="overflow-x-auto p-3 mt-2 mb-0 font-sans text-xs whitespace-pre-wrap rounded-lg text-stone-600 bg-stone-50 dark:text-stone-300 dark:bg-stone-800/60">{report.facts}</pre>
+            </details>
+        </article>
+    }
+}
@@ -0,0 +1,204 @@
+use leptos::prelude::*;
+use crate::note_template::{parse_fields, NoteTemplate, MAX_TEMPLATE_NAME_LEN};
+use super::event_types::registered_event_types;
+
+const SELECT: &str = "py-1.5 px-2 w-full text-sm bg-white rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600 dark:text-stone-200";
+const INPUT_SM: &str = "w-full px-3 py-2 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-500 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:placeholder:text-stone-400 dark:focus:bg-stone-800 dark:focus:border-primary-light/40 dark:focus:ring-primary-light/10";
+const LABEL_SM: &str = "block mb-1 text-xs font-semibold tracking-wider uppercase text-stone-600 dark:text-stone-400";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// "Use a template" dropdown for the journal's note form. Picking one hands it to `on_pick`,
+/// which fills in the note and event type; the dropdown then resets so it can be picked again.
+#[component]
+pub fn NoteTemplatePicker(
+    on_pick: impl Fn(NoteTemplate) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let templates = Resource::new(|| (), |_| crate::server_fns::note_templates::get_note_templates());
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || templates.get().map(|result| match result {
+                Err(_e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("note_templates.load", &format!("Failed to load note templates: {}", _e), &[]);
+                    ().into_any()
+                }
+                Ok(list) => {
+                    let list = StoredValue::new(list);
+                    view! {
+                        <select class=SELECT
+                            prop:value=""
+                            on:change=move |ev| {
+                                let id = event_target_value(&ev);
+                                if let Some(t) = list.with_value(|l| l.iter().find(|t| t.id == id).cloned()) {
+                                    on_pick(t);
+                                }
+                                // Back to the prompt so the same template can be picked twice
+                                #[cfg(feature = "hydrate")]
+                                {
+                                    use wasm_bindgen::JsCast;
+                                    if let Some(select) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlSelectElement>().ok()) {
+                                        select.set_value("");
+                                    }
+                                }
+                            }
+                        >
+                            <option value="">"\u{1F4CB} Use a template\u{2026}"</option>
+                            {list.get_value().into_iter().map(|t| view! {
+                                <option value=t.id.clone()>{t.name}</option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    }.into_any()
+                }
+            })}
+        </Transition>
+    }
+}
+
+/// Puts a template's expansion into the note: replaces an empty note, otherwise goes below what's there.
+pub fn apply_template(note: &str, template: &NoteTemplate) -> String {
+    if note.trim().is_empty() {
+        template.expand()
+    } else {
+        format!("{}\n\n{}", note.trim_end(), template.expand())
+    }
+}
+
+/// Note template manager within the settings modal: the starters, the user's own templates,
+/// and a form to add one.
+#[component]
+pub fn NoteTemplateSettings() -> impl IntoView {
+    let templates = RwSignal::new(Vec::<NoteTemplate>::new());
+    let (name, set_name) = signal(String::new());
+    let (event_type, set_event_type) = signal(String::new());
+    let (fields, set_fields) = signal(String::new());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let event_types = StoredValue::new(registered_event_types());
+
+    Effect::new(move |_| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::note_templates::get_note_templates().await {
+                Ok(list) => templates.set(list),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("settings.load_note_templates", &format!("Failed to load note templates: {}", e), &[]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+        });
+    });
+
+    let add = move |_| {
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let (n, f) = (name.get(), parse_fields(&fields.get()));
+        let et = Some(event_type.get()).filter(|k| !k.is_empty());
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::note_templates::create_note_template(n, et, f).await {
+                Ok(t) => {
+                    templates.update(|list| list.push(t));
+                    set_name.set(String::new());
+                    set_fields.set(String::new());
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.create_note_template", &format!("Failed to create note template: {}", e), &[]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let remove = move |id: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::note_templates::delete_note_template(id.clone()).await {
+                Ok(()) => templates.update(|list| list.retain(|t| t.builtin || t.id != id)),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.delete_note_template", &format!("Failed to delete note template: {}", e), &[("id", &id)]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <ul class="p-0 m-0 mb-3 list-none">
+            {move || templates.get().into_iter().map(|t| {
+                let id = t.id.clone();
+                let summary = t.fields.join(", ");
+                view! {
+                    <li class="flex gap-2 justify-between items-start py-1.5 border-b last:border-b-0 border-stone-100 dark:border-stone-800">
+                        <div class="min-w-0">
+                            <div class="text-sm font-medium text-stone-700 dark:text-stone-200">{t.name}</div>
+                            <div class="text-xs truncate text-stone-400">{summary}</div>
+                        </div>
+                        {(!t.builtin).then(|| view! {
+                            <button
+                                class="p-0 text-xs bg-transparent border-none opacity-60 cursor-pointer hover:opacity-100 text-inherit"
+                                title="Remove"
+                                on:click=move |_| remove(id.clone())
+                            >
+                                "\u{2715}"
+                            </button>
+                        })}
+                    </li>
+                }
+            }).collect::<Vec<_>>()}
+        </ul>
+        <div class="flex flex-wrap gap-2 mb-2">
+            <div class="flex-1 min-w-[140px]">
+                <label class=LABEL_SM>"Name"</label>
+                <input type="text" class=INPUT_SM
+                    maxlength=MAX_TEMPLATE_NAME_LEN.to_string()
+                    placeholder="e.g. Repotting checklist"
+                    prop:value=name
+                    on:input=move |ev| set_name.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="w-40">
+                <label class=LABEL_SM>"Logs as"</label>
+                <select class=INPUT_SM
+                    prop:value=event_type
+                    on:change=move |ev| set_event_type.set(event_target_value(&ev))
+                >
+                    <option value="">"No event type"</option>
+                    {event_types.get_value().into_iter().map(|et| view! {
+                        <option value=et.key.clone()>{format!("{} {}", et.emoji, et.label)}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+            </div>
+        </div>
+        <label class=LABEL_SM>"Fields (one per line)"</label>
+        <textarea class=format!("{} mb-2", INPUT_SM) rows="4"
+            placeholder="Medium\nPot size\nRoot condition"
+            prop:value=fields
+            on:input=move |ev| set_fields.set(event_target_value(&ev))
+        ></textarea>
+        <button
+            class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+            disabled=move || is_saving.get() || name.get().trim().is_empty() || parse_fields(&fields.get()).is_empty()
+            on:click=add
+        >
+            "Add Template"
+        </button>
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::note_template::builtin_note_templates;
+
+    #[test]
+    fn test_apply_template_keeps_existing_text() {
+        let t = NoteTemplate { fields: vec!["Medium".into(), "Pot size".into()], ..builtin_note_templates().remove(0) };
+        assert_eq!(apply_template("  ", &t), "Medium: \nPot size: ");
+        assert_eq!(apply_template("Roots look great\n", &t), "Roots look great\n\nMedium: \nPot size: ");
+    }
+}
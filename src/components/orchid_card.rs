@@ -1,4 +1,5 @@
 use super::BTN_DANGER;
+use super::species_photos::CardHeaderImage;
 use crate::orchid::{check_zone_compatibility, GrowingZone, Hemisphere, Orchid};
 use crate::watering::ClimateSnapshot;
 use leptos::prelude::*;
@@ -11,6 +12,7 @@ pub fn OrchidCard(
     zones: Vec<GrowingZone>,
     #[prop(default = None)] climate_snapshot: Option<ClimateSnapshot>,
     #[prop(default = String::new())] hemisphere: String,
+    #[prop(default = 0)] overdue_grace_days: u32,
     on_delete: impl Fn(String) + 'static + Copy + Send + Sync,
     on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     on_water: impl Fn(String) + 'static + Copy + Send + Sync,
@@ -19,6 +21,8 @@ pub fn OrchidCard(
     let orchid_id = orchid.id.clone();
     let orchid_id_water = orchid.id.clone();
     let orchid_clone = orchid.clone();
+    let orchid_header = orchid.clone();
+    let orchid_header_click = orchid.clone();
     let is_misplaced =
         !check_zone_compatibility(&orchid.placement, &orchid.light_requirement, &zones);
     let mismatch_reason = if is_misplaced {
@@ -47,8 +51,12 @@ pub fn OrchidCard(
     let climate_active = estimate.climate_active;
     let approx = if climate_active { "~" } else { "" };
 
+    let is_overdue = orchid.is_climate_overdue(&hemi, climate_snapshot.as_ref(), overdue_grace_days);
     let watering_text = match orchid.climate_days_until_due(&hemi, climate_snapshot.as_ref()) {
-        Some(days) if days < 0 => format!("Overdue by {}{} days", approx, -days),
+        Some(days) if days < 0 && is_overdue => format!("Overdue by {}{} days", approx, -days),
+        // Past due but still inside the grace period
+        Some(-1) => "Due yesterday".to_string(),
+        Some(days) if days < 0 => format!("Due {}{} days ago", approx, -days),
         Some(0) => "Due today".to_string(),
         Some(1) => "Due tomorrow".to_string(),
         Some(days) if days <= 2 => format!("Due in {}{} days", approx, days),
@@ -65,7 +73,6 @@ pub fn OrchidCard(
             }
         },
     };
-    let is_overdue = orchid.is_climate_overdue(&hemi, climate_snapshot.as_ref());
     let watering_class = if is_overdue {
         "font-medium text-danger"
     } else {
@@ -74,6 +81,9 @@ pub fn OrchidCard(
 
     view! {
         <div class="overflow-hidden rounded-xl border shadow-sm transition-all duration-200 hover:shadow-md hover:-translate-y-0.5 bg-surface border-stone-200/80 dark:border-stone-700 dark:hover:border-stone-600 hover:border-stone-300">
+            <div class="cursor-pointer" on:click=move |_| on_select(orchid_header_click.clone())>
+                <CardHeaderImage orchid=orchid_header />
+            </div>
             <div class="p-5 cursor-pointer" on:click=move |_| on_select(orchid_clone.clone())>
                 <div class="flex gap-2 justify-between items-start mb-1">
                     <h3 class="m-0 text-primary">{orchid.name}</h3>
@@ -88,6 +98,8 @@ pub fn OrchidCard(
                         {conservation.map(|status| {
                             view! { <span class="inline-block py-0.5 px-2 text-xs font-medium rounded-full border text-danger bg-danger/5 border-danger/20">{status}</span> }
                         })}
+                        <crate::components::awards::CardAwardBadge orchid_id=orchid_id.clone() />
+                        <crate::components::treatments::CardTreatmentBadge orchid_id=orchid_id.clone() />
                         {has_first_bloom.then(|| {
                             view! { <span class="inline-block py-0.5 px-2 text-xs font-medium text-amber-700 rounded-full border dark:text-amber-300 bg-amber-100/80 border-amber-300/40 dark:bg-amber-900/30 dark:border-amber-700/40">"\u{1F33C} First Bloom!"</span> }
                         })}
@@ -122,7 +134,7 @@ pub fn OrchidCard(
             </div>
             {(!read_only).then(|| view! {
                 <div class="flex gap-2 justify-end py-3 px-5 border-t border-stone-100 dark:border-stone-800">
-                    <button class=BTN_WATER on:click={
+                    <button class=BTN_WATER data-tour="log-watering" on:click={
                         let id = orchid_id_water.clone();
                         move |ev: leptos::ev::MouseEvent| {
                             ev.stop_propagation();
@@ -1,12 +1,26 @@
+use std::collections::HashSet;
+use crate::components::bulk_journal::BulkJournalBar;
 use crate::components::cabinet_table::OrchidCabinetTable;
+use crate::collection_view::{arrange, sanitize_saved_views, CollectionFilter, CollectionSort, SavedView};
 use crate::components::orchid_card::OrchidCard;
 use crate::model::ViewMode;
-use crate::orchid::{GrowingZone, Orchid};
+use crate::orchid::{GrowingZone, Hemisphere, Orchid};
+use crate::taxonomy::{SearchIndex, SpeciesAlias};
+use crate::virtual_grid::{visible_window, GridViewport};
 use crate::watering::ClimateSnapshot;
 use leptos::prelude::*;
 
 const TAB_ACTIVE: &str = "flex gap-1.5 items-center py-2 px-4 text-sm font-semibold rounded-lg border-none shadow-sm transition-all cursor-pointer text-primary bg-surface dark:text-primary-light";
 const TAB_INACTIVE: &str = "flex gap-1.5 items-center py-2 px-4 text-sm font-medium bg-transparent rounded-lg border-none transition-all cursor-pointer text-stone-500 hover:text-stone-700 dark:text-stone-400 dark:hover:text-stone-200";
+const SEARCH_INPUT: &str = "w-full py-2 px-3 text-sm bg-white/80 border border-stone-300/50 rounded-xl outline-none transition-all duration-200 placeholder:text-stone-400 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:placeholder:text-stone-500 dark:focus:bg-stone-800";
+const TOOLBAR_SELECT: &str = "py-1.5 px-2 text-sm rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50";
+const CHIP: &str = "py-1.5 px-3 text-xs font-semibold rounded-full border transition-colors cursor-pointer text-stone-600 bg-surface border-stone-200 dark:text-stone-300 dark:bg-stone-800 dark:border-stone-700 hover:border-primary/30";
+const CHIP_ACTIVE: &str = "py-1.5 px-3 text-xs font-semibold rounded-full border transition-colors cursor-pointer text-white bg-primary border-primary";
+const LOAD_MORE_BTN: &str = "py-2.5 px-6 text-sm font-medium rounded-xl border transition-colors cursor-pointer text-stone-600 bg-surface border-stone-200 dark:text-stone-300 dark:bg-stone-800 dark:border-stone-700 hover:border-primary/30 hover:bg-primary/5";
+
+/// Distance (in px) from the bottom of the page at which the next page is requested.
+#[cfg(feature = "hydrate")]
+const INFINITE_SCROLL_THRESHOLD_PX: f64 = 800.0;
 
 #[component]
 pub fn OrchidCollection(
@@ -14,17 +28,90 @@ pub fn OrchidCollection(
     zones: Memo<Vec<GrowingZone>>,
     #[prop(optional)] climate_snapshots: Option<Memo<Vec<ClimateSnapshot>>>,
     #[prop(optional)] hemisphere: Option<Memo<String>>,
+    /// The user's default watering grace period, in days past due.
+    #[prop(optional)] overdue_grace_days: Option<Memo<u32>>,
     view_mode: Memo<ViewMode>,
     on_set_view: impl Fn(ViewMode) + 'static + Copy + Send + Sync,
     on_delete: impl Fn(String) + 'static + Copy + Send + Sync,
     on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     on_update: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     on_water: impl Fn(String) + 'static + Copy + Send + Sync,
+    /// Marks several plants watered at once, for the table view's per-zone button.
+    #[prop(optional, into)] on_water_all: Option<Callback<Vec<String>>>,
     on_add: impl Fn() + 'static + Copy + Send + Sync,
     on_scan: impl Fn() + 'static + Copy + Send + Sync,
     #[prop(optional)] read_only: bool,
+    /// Whether more orchids are available on the server (incremental loading).
+    #[prop(optional)] has_more: Option<Memo<bool>>,
+    /// Requests the next page of orchids. Called on scroll near the end of the list.
+    #[prop(optional, into)] on_load_more: Option<Callback<()>>,
+    /// Applies the plants updated by a bulk journal entry. Enables the grid's select mode.
+    #[prop(optional, into)] on_bulk_logged: Option<Callback<Vec<Orchid>>>,
 ) -> impl IntoView {
     let is_empty = Memo::new(move |_| orchids.get().is_empty());
+    let can_select = !read_only && on_bulk_logged.is_some();
+    let selecting = RwSignal::new(false);
+    let selected = RwSignal::new(HashSet::<String>::new());
+    let stop_selecting = move || {
+        selecting.set(false);
+        selected.update(HashSet::clear);
+    };
+    let has_more = Memo::new(move |_| has_more.map(|m| m.get()).unwrap_or(false));
+
+    // The toolbar's sort and filters, applied to the loaded plants by `arrange`
+    let sort = RwSignal::new(CollectionSort::default());
+    let filter = RwSignal::new(CollectionFilter::default());
+
+    // Search by name, species, or any common/trade/synonym name for the species.
+    // Aliases need a signed-in user, so read-only collections search names only.
+    let (aliases, set_aliases) = signal(Vec::<SpeciesAlias>::new());
+    if !read_only {
+        Effect::new(move |_| {
+            leptos::task::spawn_local(async move {
+                match crate::server_fns::taxonomy::get_species_aliases().await {
+                    Ok(list) => set_aliases.set(list),
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("collection.load_aliases", &format!("Failed to load species aliases: {}", _e), &[]);
+                    }
+                }
+            });
+        });
+    }
+    let search_index = Memo::new(move |_| aliases.with(|a| orchids.with(|o| SearchIndex::build(o, a))));
+    let visible = Memo::new(move |_| {
+        let hemi = Hemisphere::from_code(&hemisphere.map(|m| m.get()).unwrap_or_else(|| "N".to_string()));
+        let snapshots = climate_snapshots.map(|m| m.get()).unwrap_or_default();
+        filter.with(|filter| {
+            let matches = if filter.search.trim().is_empty() {
+                HashSet::new()
+            } else {
+                search_index.with(|index| index.matching_ids(&filter.search))
+            };
+            arrange(orchids.get(), sort.get(), filter, &zones.get(), &matches, |o| {
+                o.climate_days_until_due(&hemi, snapshots.iter().find(|s| s.zone_name == o.placement))
+            })
+        })
+    });
+    let no_matches = Memo::new(move |_| visible.with(Vec::is_empty) && !filter.with(CollectionFilter::is_empty));
+
+    // Infinite scroll: request the next page once the viewport nears the bottom.
+    #[cfg(feature = "hydrate")]
+    if let Some(load_more) = on_load_more {
+        let handle = window_event_listener(leptos::ev::scroll, move |_| {
+            if !has_more.get_untracked() {
+                return;
+            }
+            let Some(window) = web_sys::window() else { return };
+            let Some(root) = window.document().and_then(|d| d.document_element()) else { return };
+            let viewport = window.inner_height().ok().and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let scrolled = window.scroll_y().unwrap_or(0.0);
+            if scrolled + viewport >= root.scroll_height() as f64 - INFINITE_SCROLL_THRESHOLD_PX {
+                load_more.run(());
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
 
     view! {
         <Show
@@ -37,8 +124,8 @@ pub fn OrchidCollection(
                 }
             }
         >
-            // View toggle
-            <div class="flex justify-center mb-6">
+            // View toggle, plus the select mode button for bulk journal entries
+            <div class="flex flex-wrap gap-2 justify-center items-center mb-6">
                 <div class="inline-flex gap-1 p-1 rounded-xl bg-secondary">
                     <button
                         class=move || if view_mode.get() == ViewMode::Grid { TAB_ACTIVE } else { TAB_INACTIVE }
@@ -59,8 +146,50 @@ pub fn OrchidCollection(
                         "By Zone"
                     </button>
                 </div>
+                {can_select.then(|| view! {
+                    <button
+                        class=move || if selecting.get() { TAB_ACTIVE } else { TAB_INACTIVE }
+                        aria-pressed=move || selecting.get().to_string()
+                        on:click=move |_| {
+                            if selecting.get_untracked() {
+                                stop_selecting();
+                            } else {
+                                // Selection happens on the cards, so switch to the grid
+                                on_set_view(ViewMode::Grid);
+                                selecting.set(true);
+                            }
+                        }
+                    >
+                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                            <path fill-rule="evenodd" d="M16.707 5.293a1 1 0 010 1.414l-8 8a1 1 0 01-1.414 0l-4-4a1 1 0 011.414-1.414L8 12.586l7.293-7.293a1 1 0 011.414 0z" clip-rule="evenodd"/>
+                        </svg>
+                        "Select"
+                    </button>
+                })}
+            </div>
+
+            <div class="mx-auto mb-6 max-w-md">
+                <input
+                    type="search"
+                    class=SEARCH_INPUT
+                    placeholder="Search by name, species, or common name"
+                    aria-label="Search plants"
+                    prop:value=move || filter.with(|f| f.search.clone())
+                    on:input=move |ev| filter.update(|f| f.search = event_target_value(&ev))
+                />
             </div>
 
+            <CollectionToolbar sort=sort filter=filter zones=zones read_only=read_only />
+
+            {move || no_matches.get().then(|| view! {
+                <p class="mb-6 text-sm text-center text-stone-400">
+                    {if has_more.get() { "No matches among the plants loaded so far." } else { "No plants match these filters." }}
+                </p>
+            })}
+            {move || (has_more.get() && sort.get() != CollectionSort::RecentlyAdded).then(|| view! {
+                <p class="mb-4 text-xs text-center text-stone-400">"Sorted among the plants loaded so far; load more to include the rest."</p>
+            })}
+
             // Current view — reactive closure only depends on view_mode,
             // so watering (which changes orchids data, not view_mode) does NOT
             // recreate the grid. The <For> inside OrchidGrid handles that.
@@ -68,70 +197,366 @@ pub fn OrchidCollection(
                 match view_mode.get() {
                     ViewMode::Grid => view! {
                         <OrchidGrid
-                            orchids=orchids
+                            orchids=visible
                             zones=zones
                             climate_snapshots=climate_snapshots
                             hemisphere=hemisphere
+                            overdue_grace_days=overdue_grace_days
                             on_delete=on_delete
                             on_select=on_select
                             on_water=on_water
                             read_only=read_only
+                            selecting=selecting
+                            selected=selected
                         />
                     }.into_any(),
                     ViewMode::Table => {
                         view! {
                             <OrchidCabinetTable
-                                orchids=orchids
+                                orchids=visible
                                 zones=zones
                                 climate_snapshots=climate_snapshots
                                 hemisphere=hemisphere
                                 on_delete=on_delete
                                 on_select=on_select
                                 on_update=on_update
+                                on_water_all=on_water_all
                             />
                         }.into_any()
                     }
                 }
             }}
+
+            // Manual fallback for incremental loading (also covers short pages
+            // that never produce a scroll event).
+            {move || (has_more.get() && on_load_more.is_some()).then(|| view! {
+                <div class="flex justify-center mt-6">
+                    <button
+                        class=LOAD_MORE_BTN
+                        on:click=move |_| {
+                            if let Some(cb) = on_load_more {
+                                cb.run(());
+                            }
+                        }
+                    >
+                        "Load more plants"
+                    </button>
+                </div>
+            })}
+
+            {move || on_bulk_logged.filter(|_| selecting.get() && view_mode.get() == ViewMode::Grid).map(|cb| view! {
+                <BulkJournalBar
+                    selected=selected
+                    visible=visible
+                    on_logged=Callback::new(move |updated: Vec<Orchid>| {
+                        cb.run(updated);
+                        stop_selecting();
+                    })
+                    on_done=stop_selecting
+                />
+            })}
         </Show>
     }.into_any()
 }
 
+/// Sort picker, zone and needs-water filters, and the signed-in user's saved views: one tap
+/// applies a view, and "Save view" stores the current sort and filters under a name.
+#[component]
+fn CollectionToolbar(
+    sort: RwSignal<CollectionSort>,
+    filter: RwSignal<CollectionFilter>,
+    zones: Memo<Vec<GrowingZone>>,
+    read_only: bool,
+) -> impl IntoView {
+    let saved_views = RwSignal::new(Vec::<SavedView>::new());
+    // Whether the name field for a new view is open, and what's typed in it
+    let naming = RwSignal::new(false);
+    let draft_name = RwSignal::new(String::new());
+    let error = RwSignal::new(None::<String>);
+
+    if !read_only {
+        Effect::new(move |_| {
+            leptos::task::spawn_local(async move {
+                match crate::server_fns::preferences::get_saved_views().await {
+                    Ok(views) => saved_views.set(views),
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("collection.load_views", &format!("Failed to load saved views: {}", _e), &[]);
+                    }
+                }
+            });
+        });
+    }
+
+    // Shows the change right away and puts the old list back if the save fails
+    let persist = move |views: Vec<SavedView>| {
+        let views = match sanitize_saved_views(views) {
+            Ok(views) => views,
+            Err(e) => {
+                error.set(Some(e));
+                return;
+            }
+        };
+        let previous = saved_views.get_untracked();
+        saved_views.set(views.clone());
+        error.set(None);
+        naming.set(false);
+        leptos::task::spawn_local(async move {
+            if let Err(e) = crate::server_fns::preferences::save_saved_views(views).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("collection.save_views", &format!("Failed to save views: {}", e), &[]);
+                saved_views.set(previous);
+                error.set(Some(format!("Couldn't save views: {}", e)));
+            }
+        });
+    };
+    let save_current = move || {
+        let mut views = saved_views.get_untracked();
+        views.push(SavedView { name: draft_name.get_untracked(), sort: sort.get_untracked(), filter: filter.get_untracked() });
+        persist(views);
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-2 justify-center items-center mb-4">
+            <select
+                class=TOOLBAR_SELECT
+                aria-label="Sort plants"
+                on:change=move |ev| sort.set(CollectionSort::parse(&event_target_value(&ev)))
+            >
+                {CollectionSort::ALL.into_iter().map(|option| view! {
+                    <option value=option.as_str() selected=move || sort.get() == option>{option.label()}</option>
+                }).collect::<Vec<_>>()}
+            </select>
+            <select
+                class=TOOLBAR_SELECT
+                aria-label="Show zone"
+                on:change=move |ev| {
+                    let zone = event_target_value(&ev);
+                    filter.update(|f| f.zone = (!zone.is_empty()).then_some(zone));
+                }
+            >
+                <option value="" selected=move || filter.with(|f| f.zone.is_none())>"All zones"</option>
+                {move || zones.get().into_iter().map(|zone| {
+                    let id = zone.id;
+                    let is_current = {
+                        let id = id.clone();
+                        move || filter.with(|f| f.zone.as_deref() == Some(id.as_str()))
+                    };
+                    view! { <option value=id selected=is_current>{zone.name}</option> }
+                }).collect::<Vec<_>>()}
+            </select>
+            <button
+                class=move || if filter.with(|f| f.needs_water) { CHIP_ACTIVE } else { CHIP }
+                aria-pressed=move || filter.with(|f| f.needs_water).to_string()
+                on:click=move |_| filter.update(|f| f.needs_water = !f.needs_water)
+            >
+                "Needs water"
+            </button>
+        </div>
+
+        {(!read_only).then(|| view! {
+            <div class="flex flex-wrap gap-2 justify-center items-center mb-6">
+                <For
+                    each=move || saved_views.get()
+                    key=|saved| saved.name.clone()
+                    children=move |saved: SavedView| {
+                        let name = StoredValue::new(saved.name.clone());
+                        let is_active = {
+                            let saved = saved.clone();
+                            move || sort.get() == saved.sort && filter.with(|f| *f == saved.filter)
+                        };
+                        let apply = saved.clone();
+                        view! {
+                            <span class="inline-flex items-center">
+                                <button
+                                    class=move || if is_active() { CHIP_ACTIVE } else { CHIP }
+                                    on:click=move |_| {
+                                        sort.set(apply.sort);
+                                        filter.set(apply.filter.clone());
+                                    }
+                                >
+                                    {saved.name.clone()}
+                                </button>
+                                <button
+                                    class="py-1 px-1.5 text-xs bg-transparent border-none cursor-pointer text-stone-400 hover:text-red-500"
+                                    aria-label=format!("Delete view {}", saved.name)
+                                    on:click=move |_| {
+                                        let name = name.get_value();
+                                        persist(saved_views.get_untracked().into_iter().filter(|v| v.name != name).collect());
+                                    }
+                                >
+                                    "\u{00d7}"
+                                </button>
+                            </span>
+                        }
+                    }
+                />
+                {move || if naming.get() {
+                    view! {
+                        <form
+                            class="inline-flex gap-1 items-center"
+                            on:submit=move |ev| {
+                                ev.prevent_default();
+                                save_current();
+                            }
+                        >
+                            <input
+                                type="text"
+                                class=TOOLBAR_SELECT
+                                placeholder="View name"
+                                aria-label="View name"
+                                maxlength=crate::collection_view::MAX_VIEW_NAME_LEN.to_string()
+                                prop:value=move || draft_name.get()
+                                on:input=move |ev| draft_name.set(event_target_value(&ev))
+                            />
+                            <button type="submit" class=CHIP_ACTIVE>"Save"</button>
+                            <button type="button" class=CHIP on:click=move |_| { naming.set(false); error.set(None); }>"Cancel"</button>
+                        </form>
+                    }.into_any()
+                } else {
+                    view! {
+                        <button
+                            class=CHIP
+                            on:click=move |_| {
+                                draft_name.set(String::new());
+                                naming.set(true);
+                            }
+                        >
+                            "+ Save view"
+                        </button>
+                    }.into_any()
+                }}
+                {move || error.get().map(|e| view! { <p class="w-full text-xs text-center text-red-500">{e}</p> })}
+            </div>
+        })}
+    }
+}
+
 /// Grid view with a stable `<For>` — orchid cards update in place when data
 /// changes, preserving scroll position. Uses a composite key that includes
 /// `last_watered_at` so only the watered card is replaced by `<For>`.
+/// In select mode each card is covered by a toggle that adds it to `selected`.
+/// Large collections only mount the rows near the viewport (see
+/// `crate::virtual_grid`), with padding standing in for the rest.
 #[component]
 fn OrchidGrid(
     orchids: Memo<Vec<Orchid>>,
     zones: Memo<Vec<GrowingZone>>,
     climate_snapshots: Option<Memo<Vec<ClimateSnapshot>>>,
     hemisphere: Option<Memo<String>>,
+    overdue_grace_days: Option<Memo<u32>>,
     on_delete: impl Fn(String) + 'static + Copy + Send + Sync,
     on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     on_water: impl Fn(String) + 'static + Copy + Send + Sync,
     read_only: bool,
+    selecting: RwSignal<bool>,
+    selected: RwSignal<HashSet<String>>,
 ) -> impl IntoView {
+    let grid_ref = NodeRef::<leptos::html::Div>::new();
+    // Starts from the same guess on the server and client so hydration matches, then follows the page
+    let viewport = RwSignal::new(GridViewport::default());
+    let shown = Memo::new(move |_| orchids.with(|o| visible_window(o.len(), &viewport.get())));
+
+    // Re-measure on scroll and resize, and after each change to the rendered cards
+    #[cfg(feature = "hydrate")]
+    {
+        use crate::virtual_grid::{columns, measured_row_height};
+
+        let measure = move || {
+            let Some(grid) = grid_ref.get_untracked() else { return };
+            let Some(window) = web_sys::window() else { return };
+            let rect = grid.get_bounding_client_rect();
+            let current = viewport.get_untracked();
+            let rendered = shown.get_untracked();
+            // Ignore sub-pixel differences so the measurement settles instead of nudging the padding forever
+            let row_height = measured_row_height(
+                rect.height() - rendered.space_before - rendered.space_after,
+                rendered.end - rendered.start,
+                columns(rect.width()),
+            )
+                .filter(|h| (h - current.row_height).abs() >= 1.0)
+                .unwrap_or(current.row_height);
+            let next = GridViewport {
+                width: rect.width(),
+                top: rect.top(),
+                height: window.inner_height().ok().and_then(|h| h.as_f64()).unwrap_or(current.height),
+                row_height,
+            };
+            if next != current {
+                viewport.set(next);
+            }
+        };
+        let on_scroll = window_event_listener(leptos::ev::scroll, move |_| measure());
+        let on_resize = window_event_listener(leptos::ev::resize, move |_| measure());
+        on_cleanup(move || {
+            on_scroll.remove();
+            on_resize.remove();
+        });
+        Effect::new(move |_| {
+            shown.track();
+            request_animation_frame(measure);
+        });
+    }
+
     view! {
-        <div class="grid gap-5 grid-cols-[repeat(auto-fill,minmax(300px,1fr))]">
+        <div
+            node_ref=grid_ref
+            class="grid gap-5 grid-cols-[repeat(auto-fill,minmax(300px,1fr))]"
+            style=move || shown.with(|w| format!("padding-top: {}px; padding-bottom: {}px", w.space_before, w.space_after))
+        >
             <For
-                each=move || orchids.get()
+                each=move || {
+                    let window = shown.get();
+                    orchids.with(|o| o.get(window.start..window.end).map(<[Orchid]>::to_vec).unwrap_or_default())
+                }
                 key=|orchid| serde_json::to_string(orchid).unwrap_or_default()
                 children=move |orchid| {
                     let zones_clone = zones.get();
                     let snaps = climate_snapshots.map(|m| m.get()).unwrap_or_default();
                     let hemi_str = hemisphere.map(|m| m.get()).unwrap_or_else(|| "N".to_string());
+                    let grace = overdue_grace_days.map(|m| m.get()).unwrap_or(0);
                     let snapshot = snaps.into_iter().find(|s| s.zone_name == orchid.placement);
+                    let id = StoredValue::new(orchid.id.clone());
+                    let select_label = format!("Select {}", orchid.name);
+                    let is_selected = move || selected.with(|s| id.with_value(|id| s.contains(id)));
                     view! {
-                        <OrchidCard
-                            orchid=orchid
-                            zones=zones_clone
-                            climate_snapshot=snapshot
-                            hemisphere=hemi_str
-                            on_delete=on_delete
-                            on_select=on_select
-                            on_water=on_water
-                            read_only=read_only
-                        />
+                        <div class="relative">
+                            <OrchidCard
+                                orchid=orchid
+                                zones=zones_clone
+                                climate_snapshot=snapshot
+                                hemisphere=hemi_str
+                                overdue_grace_days=grace
+                                on_delete=on_delete
+                                on_select=on_select
+                                on_water=on_water
+                                read_only=read_only
+                            />
+                            {move || selecting.get().then(|| view! {
+                                <button
+                                    type="button"
+                                    class=move || if is_selected() {
+                                        "absolute inset-0 z-10 rounded-2xl border-none cursor-pointer ring-4 ring-primary bg-primary/10"
+                                    } else {
+                                        "absolute inset-0 z-10 rounded-2xl border-none cursor-pointer bg-white/30 hover:bg-white/10 dark:bg-stone-900/30"
+                                    }
+                                    aria-pressed=move || is_selected().to_string()
+                                    aria-label=select_label.clone()
+                                    on:click=move |_| {
+                                        let id = id.get_value();
+                                        selected.update(|s| if !s.remove(&id) { s.insert(id); });
+                                    }
+                                >
+                                    <span class=move || if is_selected() {
+                                        "flex absolute top-3 left-3 justify-center items-center w-7 h-7 text-sm font-bold text-white rounded-full shadow bg-primary"
+                                    } else {
+                                        "flex absolute top-3 left-3 w-7 h-7 rounded-full border-2 shadow border-white bg-white/60 dark:bg-stone-800/60"
+                                    }>
+                                        {move || is_selected().then_some("\u{2713}")}
+                                    </span>
+                                </button>
+                            })}
+                        </div>
                     }
                 }
             />
@@ -1,13 +1,25 @@
 use leptos::prelude::*;
 use chrono::Datelike;
-use crate::orchid::{Orchid, LightRequirement, GrowingZone, ClimateReading, LogEntry, Hemisphere, SeasonalPhase, month_in_range};
+use crate::orchid::{Orchid, LightRequirement, GrowingZone, ClimateReading, LogEntry, Hemisphere, MediumFeedback, SeasonalPhase, SkipReason, WateringMethod, month_in_range};
 use crate::watering::ClimateSnapshot;
+use crate::journal::{JournalPage, JournalQuery, JOURNAL_PAGE_SIZE};
 use crate::components::habitat_weather::HabitatWeatherCard;
 use crate::components::quick_actions::QuickActions;
 use crate::components::photo_capture::PhotoCapture;
-use crate::components::growth_thread::GrowthThread;
+use crate::components::growth_thread::{GrowthThread, JournalSearchBar};
+use crate::components::event_type_picker::{EventTypeFilter, EventTypePicker};
 use crate::components::first_bloom::FirstBloomCelebration;
 use crate::components::photo_gallery::PhotoGallery;
+use crate::components::timelapse::TimelapseMaker;
+use crate::components::growth_charts::GrowthTab;
+use crate::components::markdown_note::MarkdownNote;
+use crate::components::note_templates::{apply_template, NoteTemplatePicker};
+use crate::components::troubleshoot_wizard::TroubleshootWizard;
+use crate::components::care_confidence::CareConfidenceCard;
+use crate::components::time_travel::PlantAsOfPanel;
+use crate::components::lineage::LineagePanel;
+use crate::components::archive::{ArchivePlantForm, archive_summary};
+use crate::components::parentage::{AncestryCard, ParentageFields, parentage_input};
 use super::{MODAL_OVERLAY, MODAL_CONTENT, MODAL_HEADER, BTN_PRIMARY, BTN_SECONDARY, BTN_CLOSE};
 
 /// Serialize an enum to its serde variant name (e.g., PotType::Mounted → "Mounted").
@@ -27,11 +39,25 @@ fn light_req_to_key(lr: &LightRequirement) -> String {
     lr.as_str().to_string()
 }
 
+/// "Mar 9, 2024 · Nursery · 24.50" from whichever acquisition fields are set; None if none are.
+fn acquisition_summary(o: &Orchid) -> Option<String> {
+    let parts: Vec<String> = [
+        o.acquired_at.map(|t| t.format("%b %-d, %Y").to_string()),
+        o.acquisition_source.map(|s| s.label().to_string()),
+        o.acquisition_price.map(|p| format!("{:.2}", p)),
+    ].into_iter().flatten().collect();
+    (!parts.is_empty()).then(|| parts.join(" \u{00B7} "))
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum DetailTab {
     Journal,
     Gallery,
+    Growth,
     Details,
+    Lineage,
+    AsOf,
+    Troubleshoot,
 }
 
 #[component]
@@ -41,16 +67,41 @@ pub fn OrchidDetail(
     climate_readings: Vec<ClimateReading>,
     #[prop(default = Vec::new())] climate_snapshots: Vec<ClimateSnapshot>,
     hemisphere: String,
+    #[prop(default = 0)] overdue_grace_days: u32,
+    #[prop(default = String::from("C"))] temp_unit: String,
     on_close: impl Fn() + 'static + Send + Sync,
     on_update: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     #[prop(optional)] read_only: bool,
     #[prop(optional)] public_username: Option<String>,
+    /// Opens another plant by ID, for links in the lineage view.
+    #[prop(optional, into)] on_open: Option<Callback<String>>,
+    /// Called with a new plant recorded as a propagation of this one.
+    #[prop(optional, into)] on_propagated: Option<Callback<Orchid>>,
+    /// Called with the plant once it's been archived; without it the archive form is hidden.
+    #[prop(optional, into)] on_archived: Option<Callback<Orchid>>,
+    /// Called with the plant after its cover photo changes.
+    #[prop(optional, into)] on_cover_changed: Option<Callback<Orchid>>,
+    /// The last plant the page saved; a newer copy of this plant replaces the one shown, so the
+    /// next edit starts from the saved `updated_at`.
+    #[prop(optional, into)] saved: Option<Signal<Option<Orchid>>>,
 ) -> impl IntoView {
     let (orchid_signal, set_orchid_signal) = signal(orchid.clone());
+    if let Some(saved) = saved {
+        Effect::new(move |_| {
+            if let Some(latest) = saved.get()
+                && orchid_signal.with_untracked(|o| o.id == latest.id && latest.updated_at > o.updated_at)
+            {
+                set_orchid_signal.set(latest);
+            }
+        });
+    }
+    let temp_unit = StoredValue::new(temp_unit);
     let (log_entries, set_log_entries) = signal(Vec::<LogEntry>::new());
     let (active_tab, set_active_tab) = signal(DetailTab::Journal);
     let (show_first_bloom, set_show_first_bloom) = signal(false);
 
+    let is_public = public_username.is_some();
+
     // Load log entries on mount
     {
         let orchid_id = orchid.id.clone();
@@ -72,6 +123,24 @@ pub fn OrchidDetail(
         });
     }
 
+    let set_cover = move |image: Option<String>| {
+        let id = orchid_signal.get_untracked().id;
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::set_cover_image(id.clone(), image).await {
+                Ok(updated) => {
+                    set_orchid_signal.set(updated.clone());
+                    if let Some(cb) = on_cover_changed {
+                        cb.run(updated);
+                    }
+                }
+                Err(_e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("orchid_detail.set_cover", &format!("Failed to set cover photo: {}", _e), &[("orchid_id", &id)]);
+                }
+            }
+        });
+    };
+
     // Edit mode state
     let (is_editing, set_is_editing) = signal(false);
     let zones_stored = StoredValue::new(zones);
@@ -100,6 +169,11 @@ pub fn OrchidDetail(
                     <div>
                         <h2 class="m-0">{move || orchid_signal.get().name}</h2>
                         <p class="mt-0.5 mb-0 text-sm italic text-stone-500 dark:text-stone-400">{move || orchid_signal.get().species}</p>
+                        {move || archive_summary(&orchid_signal.get()).map(|summary| view! {
+                            <span class="inline-block py-0.5 px-2 mt-1 text-xs font-medium rounded-full border text-stone-600 bg-stone-100 border-stone-200 dark:text-stone-300 dark:bg-stone-800 dark:border-stone-700">
+                                {format!("Archived: {}", summary)}
+                            </span>
+                        })}
                     </div>
                     <div class="flex gap-2">
                         <button class=BTN_CLOSE aria-label="Close details" title="Close" on:click=move |_| on_close()>"\u{00D7}"</button>
@@ -116,10 +190,28 @@ pub fn OrchidDetail(
                         class=move || if active_tab.get() == DetailTab::Gallery { TAB_ACTIVE } else { TAB_INACTIVE }
                         on:click=move |_| set_active_tab.set(DetailTab::Gallery)
                     >"Gallery"</button>
+                    <button
+                        class=move || if active_tab.get() == DetailTab::Growth { TAB_ACTIVE } else { TAB_INACTIVE }
+                        on:click=move |_| set_active_tab.set(DetailTab::Growth)
+                    >"Growth"</button>
                     <button
                         class=move || if active_tab.get() == DetailTab::Details { TAB_ACTIVE } else { TAB_INACTIVE }
                         on:click=move |_| set_active_tab.set(DetailTab::Details)
                     >"Details"</button>
+                    {(!read_only).then(|| view! {
+                        <button
+                            class=move || if active_tab.get() == DetailTab::Lineage { TAB_ACTIVE } else { TAB_INACTIVE }
+                            on:click=move |_| set_active_tab.set(DetailTab::Lineage)
+                        >"Lineage"</button>
+                        <button
+                            class=move || if active_tab.get() == DetailTab::AsOf { TAB_ACTIVE } else { TAB_INACTIVE }
+                            on:click=move |_| set_active_tab.set(DetailTab::AsOf)
+                        >"As Of"</button>
+                        <button
+                            class=move || if active_tab.get() == DetailTab::Troubleshoot { TAB_ACTIVE } else { TAB_INACTIVE }
+                            on:click=move |_| set_active_tab.set(DetailTab::Troubleshoot)
+                        >"Troubleshoot"</button>
+                    })}
                 </div>
 
                 // Tab content
@@ -132,11 +224,45 @@ pub fn OrchidDetail(
                                 log_entries=log_entries
                                 set_log_entries=set_log_entries
                                 set_show_first_bloom=set_show_first_bloom
+                                temp_unit=temp_unit.get_value()
                                 read_only=read_only
+                                is_public=is_public
                             />
                         }.into_any(),
                         DetailTab::Gallery => view! {
-                            <PhotoGallery entries=log_entries />
+                            <PhotoGallery
+                                entries=log_entries
+                                cover_image=Signal::derive(move || orchid_signal.with(|o| o.cover_image.clone()))
+                                read_only=read_only
+                                on_set_cover=set_cover
+                            />
+                            {(!is_public).then(|| {
+                                let href = format!("/api/images/archive?orchid={}", orchid_signal.get_untracked().id);
+                                move || log_entries.with(|l| l.iter().any(|e| e.image_filename.is_some())).then(|| view! {
+                                    <div class="mt-3 text-right">
+                                        <a href=href.clone() download class="text-xs font-semibold no-underline text-primary hover:underline">
+                                            "Download all photos (.zip)"
+                                        </a>
+                                    </div>
+                                })
+                            })}
+                            {(!is_public).then(|| {
+                                let orchid_id = orchid_signal.get_untracked().id;
+                                move || (log_entries.with(|l| l.iter().filter(|e| e.image_filename.is_some()).count()) >= 2).then(|| view! {
+                                    <TimelapseMaker orchid_id=orchid_id.clone() />
+                                })
+                            })}
+                            {(!read_only).then(|| view! {
+                                <crate::components::species_photos::SpeciesPhotoPicker species=orchid_signal.get_untracked().species />
+                            })}
+                        }.into_any(),
+                        DetailTab::Growth => view! {
+                            <GrowthTab
+                                orchid_signal=orchid_signal
+                                log_entries=log_entries
+                                set_log_entries=set_log_entries
+                                read_only=read_only
+                            />
                         }.into_any(),
                         DetailTab::Details => view! {
                             <DetailsTab
@@ -146,8 +272,11 @@ pub fn OrchidDetail(
                                 set_is_editing=set_is_editing
                                 zones=zones_stored
                                 hemisphere=hemisphere_stored
+                                overdue_grace_days=overdue_grace_days
                                 climate_snapshot=climate_snapshot_stored
+                                temp_unit=temp_unit
                                 on_update=on_update
+                                log_entries=log_entries
                                 set_log_entries=set_log_entries
                                 habitat_zone_reading=habitat_zone_reading
                                 native_region=native_region
@@ -155,6 +284,18 @@ pub fn OrchidDetail(
                                 native_lon=native_lon
                                 read_only=read_only
                             />
+                            {on_archived.filter(|_| !read_only).map(|cb| view! {
+                                <ArchivePlantForm orchid_signal=orchid_signal on_archived=move |o| cb.run(o) />
+                            })}
+                        }.into_any(),
+                        DetailTab::Lineage => view! {
+                            <LineagePanel orchid_signal=orchid_signal on_open=on_open on_propagated=on_propagated />
+                        }.into_any(),
+                        DetailTab::AsOf => view! {
+                            <PlantAsOfPanel orchid_id=orchid_signal.get_untracked().id />
+                        }.into_any(),
+                        DetailTab::Troubleshoot => view! {
+                            <TroubleshootWizard orchid_signal=orchid_signal set_log_entries=set_log_entries />
                         }.into_any(),
                     }}
                 </div>
@@ -179,7 +320,10 @@ fn JournalTab(
     log_entries: ReadSignal<Vec<LogEntry>>,
     set_log_entries: WriteSignal<Vec<LogEntry>>,
     set_show_first_bloom: WriteSignal<bool>,
+    #[prop(default = String::from("C"))] temp_unit: String,
     #[prop(optional)] read_only: bool,
+    /// Public profile view: the journal is already loaded in full, so it's filtered locally.
+    #[prop(optional)] is_public: bool,
 ) -> impl IntoView {
     let (note, set_note) = signal(String::new());
     // Staged photo data URL — NOT uploaded until the form is submitted
@@ -187,15 +331,58 @@ fn JournalTab(
     let (is_syncing, set_is_syncing) = signal(false);
     // Bumped after successful save to reset PhotoCapture preview
     let (photo_reset, set_photo_reset) = signal(0u32);
+    let (note_type, set_note_type) = signal(None::<String>);
+    // Timeline filters, and the pages of matching entries loaded so far
+    let (type_filter, set_type_filter) = signal(None::<String>);
+    let (query, set_query) = signal(JournalQuery::default());
+    let (page, set_page) = signal(JournalPage::default());
+    let (is_loading_page, set_is_loading_page) = signal(false);
+    let shown_entries = Signal::derive(move || page.with(|p| p.entries.clone()));
+
+    let load_page = move |offset: u32| {
+        let q = query.get_untracked();
+        let merge = move |next: JournalPage| set_page.update(|p| if offset == 0 {
+            *p = next;
+        } else {
+            p.total = next.total;
+            p.entries.extend(next.entries);
+        });
+        if is_public {
+            merge(log_entries.with_untracked(|all| q.page(all, offset, JOURNAL_PAGE_SIZE)));
+            return;
+        }
+        set_is_loading_page.set(true);
+        let orchid_id = orchid_signal.get_untracked().id;
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::get_log_entries_page(orchid_id, q.clone(), offset, JOURNAL_PAGE_SIZE).await {
+                // Drop the answer if the filters changed while it was loading
+                Ok(next) if query.get_untracked() == q => merge(next),
+                Ok(_) => {}
+                Err(_e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("orchid_detail.load_journal_page", &format!("Failed to load journal page: {}", _e), &[]);
+                }
+            }
+            set_is_loading_page.set(false);
+        });
+    };
+
+    // Back to the first page whenever the filters change or an entry is added
+    Effect::new(move |_| {
+        query.track();
+        log_entries.with(|l| l.len());
+        load_page(0);
+    });
 
     let on_submit_note = move |ev: leptos::ev::SubmitEvent| {
         ev.prevent_default();
 
         let current_note = note.get();
         let photo_data_url = staged_photo.get();
+        let event_type = note_type.get();
 
-        // Require at least a note or photo
-        if current_note.is_empty() && photo_data_url.is_none() {
+        // Require at least a note, photo or event type
+        if current_note.is_empty() && photo_data_url.is_none() && event_type.is_none() {
             return;
         }
 
@@ -228,6 +415,8 @@ fn JournalTab(
                 orchid_id,
                 current_note,
                 server_filename,
+                event_type,
+                None,
                 None,
             ).await {
                 Ok(response) => {
@@ -245,6 +434,7 @@ fn JournalTab(
             set_is_syncing.set(false);
             set_note.set(String::new());
             set_staged_photo.set(None);
+            set_note_type.set(None);
             set_photo_reset.update(|v| *v += 1);
         });
     };
@@ -275,13 +465,28 @@ fn JournalTab(
                         />
                     </div>
 
+                    // Template: fills in a pre-structured note and its event type
+                    <div class="mb-3">
+                        <NoteTemplatePicker on_pick=move |t| {
+                            set_note.update(|n| *n = apply_template(n, &t));
+                            if t.event_type.is_some() {
+                                set_note_type.set(t.event_type);
+                            }
+                        } />
+                    </div>
+
+                    // Optional event type, including the grower's own types
+                    <div class="mb-3">
+                        <EventTypePicker selected=note_type on_select=move |t| set_note_type.set(t) />
+                    </div>
+
                     // Note textarea
                     <div class="mb-3">
                         <textarea
                             prop:value=note
                             on:input=move |ev| set_note.set(event_target_value(&ev))
                             placeholder="Write a note about this orchid..."
-                            rows="2"
+                            rows=move || note.with(|n| n.lines().count().clamp(2, 10).to_string())
                             class="py-2 px-3 w-full text-sm bg-white rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600 dark:text-stone-200"
                         ></textarea>
                     </div>
@@ -293,8 +498,36 @@ fn JournalTab(
             </div>
         })}
 
-        // Growth Thread
-        <GrowthThread entries=log_entries orchid_id=orchid_signal.get_untracked().id />
+        // Growth Thread, narrowed by event type, dates and keyword, a page at a time
+        <EventTypeFilter entries=log_entries selected=type_filter on_select=move |t| {
+            set_type_filter.set(t.clone());
+            set_query.update(|q| q.event_type = t);
+        } />
+        <JournalSearchBar query=query set_query=set_query on_clear=move || set_type_filter.set(None) />
+        {move || {
+            let filtered = query.with(|q| !q.is_empty());
+            if filtered && page.with(|p| p.total == 0) && !is_loading_page.get() {
+                view! { <p class="py-8 text-sm italic text-center text-stone-400">"No entries match these filters."</p> }.into_any()
+            } else {
+                let orchid_id = orchid_signal.get_untracked().id;
+                view! {
+                    {filtered.then(|| view! {
+                        <p class="mt-0 mb-2 text-xs text-stone-400">{move || format!("{} matching entries", page.with(|p| p.total))}</p>
+                    })}
+                    <GrowthThread entries=shown_entries orchid_id=orchid_id temp_unit=temp_unit.clone() />
+                }.into_any()
+            }
+        }}
+        {move || {
+            let (shown, total) = page.with(|p| (p.entries.len() as u32, p.total));
+            (shown < total).then(|| view! {
+                <div class="mt-2 text-center">
+                    <button class=BTN_SECONDARY disabled=move || is_loading_page.get() on:click=move |_| load_page(shown)>
+                        {move || if is_loading_page.get() { "Loading\u{2026}".to_string() } else { format!("Show older entries ({} more)", total - shown) }}
+                    </button>
+                </div>
+            })
+        }}
     }.into_any()
 }
 
@@ -308,8 +541,11 @@ fn DetailsTab(
     set_is_editing: WriteSignal<bool>,
     zones: StoredValue<Vec<GrowingZone>>,
     hemisphere: StoredValue<String>,
+    overdue_grace_days: u32,
     climate_snapshot: StoredValue<Option<ClimateSnapshot>>,
+    temp_unit: StoredValue<String>,
     on_update: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+    log_entries: ReadSignal<Vec<LogEntry>>,
     set_log_entries: WriteSignal<Vec<LogEntry>>,
     habitat_zone_reading: StoredValue<Option<ClimateReading>>,
     native_region: StoredValue<Option<String>>,
@@ -318,6 +554,39 @@ fn DetailsTab(
     #[prop(optional)] read_only: bool,
 ) -> impl IntoView {
     let (is_watering, set_is_watering) = signal(false);
+    let (show_skip, set_show_skip) = signal(false);
+    // Shown after "Water Now" until the grower rates the medium or dismisses it
+    let (show_rating, set_show_rating) = signal(false);
+
+    // The vendor's name for the Plant Info grid; only fetched when the plant has one
+    let vendor_name = Resource::new(
+        move || orchid_signal.with(|o| o.vendor_id.clone()),
+        |vendor_id| async move {
+            match vendor_id {
+                Some(id) => crate::server_fns::acquisition::get_vendors().await
+                    .map(|vendors| vendors.into_iter().find(|v| v.id == id).map(|v| v.name)),
+                None => Ok(None),
+            }
+        },
+    );
+
+    // Month-by-month match with the native habitat (client-side; needs a signed-in user)
+    let (habitat_similarity, set_habitat_similarity) = signal(None::<Vec<crate::habitat_similarity::MonthlySimilarity>>);
+    if native_lat.is_some() && !read_only {
+        let orchid_id = Memo::new(move |_| orchid_signal.with(|o| o.id.clone()));
+        Effect::new(move |_| {
+            let id = orchid_id.get();
+            leptos::task::spawn_local(async move {
+                match crate::server_fns::climate::get_habitat_similarity(id).await {
+                    Ok(months) => set_habitat_similarity.set(Some(months)),
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("habitat.similarity", &format!("Failed to load habitat similarity: {}", _e), &[]);
+                    }
+                }
+            });
+        });
+    }
 
     // Edit form signals
     let (edit_name, set_edit_name) = signal(String::new());
@@ -339,6 +608,9 @@ fn DetailsTab(
     let (edit_pot_size, set_edit_pot_size) = signal(String::new());
     let (edit_pot_type, set_edit_pot_type) = signal(String::new());
     let (edit_par_ppfd, set_edit_par_ppfd) = signal(String::new());
+    let (edit_grace_days, set_edit_grace_days) = signal(String::new());
+    let (edit_watering_method, set_edit_watering_method) = signal(String::new());
+    let (edit_reservoir_freq, set_edit_reservoir_freq) = signal(String::new());
     let (edit_rest_start, set_edit_rest_start) = signal(String::new());
     let (edit_rest_end, set_edit_rest_end) = signal(String::new());
     let (edit_bloom_start, set_edit_bloom_start) = signal(String::new());
@@ -347,6 +619,13 @@ fn DetailsTab(
     let (edit_rest_fert_mult, set_edit_rest_fert_mult) = signal(String::new());
     let (edit_active_water_mult, set_edit_active_water_mult) = signal(String::new());
     let (edit_active_fert_mult, set_edit_active_fert_mult) = signal(String::new());
+    let (edit_acquired, set_edit_acquired) = signal(String::new());
+    let (edit_price, set_edit_price) = signal(String::new());
+    let (edit_source, set_edit_source) = signal(String::new());
+    let (edit_vendor, set_edit_vendor) = signal(String::new());
+    let (edit_grex, set_edit_grex) = signal(String::new());
+    let (edit_seed_parent, set_edit_seed_parent) = signal(String::new());
+    let (edit_pollen_parent, set_edit_pollen_parent) = signal(String::new());
 
     let populate_edit_fields = move || {
         let current = orchid_signal.get();
@@ -369,6 +648,9 @@ fn DetailsTab(
         set_edit_pot_size.set(current.pot_size.map(|v| serde_variant_name(&v)).unwrap_or_default());
         set_edit_pot_type.set(current.pot_type.map(|v| serde_variant_name(&v)).unwrap_or_default());
         set_edit_par_ppfd.set(current.par_ppfd.map(|v| v.to_string()).unwrap_or_default());
+        set_edit_grace_days.set(current.overdue_grace_days.map(|v| v.to_string()).unwrap_or_default());
+        set_edit_watering_method.set(current.default_watering_method.map(|m| m.as_str().to_string()).unwrap_or_default());
+        set_edit_reservoir_freq.set(current.reservoir_frequency_days.map(|v| v.to_string()).unwrap_or_default());
         set_edit_rest_start.set(current.rest_start_month.map(|v| v.to_string()).unwrap_or_default());
         set_edit_rest_end.set(current.rest_end_month.map(|v| v.to_string()).unwrap_or_default());
         set_edit_bloom_start.set(current.bloom_start_month.map(|v| v.to_string()).unwrap_or_default());
@@ -377,6 +659,13 @@ fn DetailsTab(
         set_edit_rest_fert_mult.set(current.rest_fertilizer_multiplier.map(|v| v.to_string()).unwrap_or_default());
         set_edit_active_water_mult.set(current.active_water_multiplier.map(|v| v.to_string()).unwrap_or_default());
         set_edit_active_fert_mult.set(current.active_fertilizer_multiplier.map(|v| v.to_string()).unwrap_or_default());
+        set_edit_acquired.set(crate::acquisition::date_input_value(current.acquired_at));
+        set_edit_price.set(current.acquisition_price.map(|v| v.to_string()).unwrap_or_default());
+        set_edit_source.set(current.acquisition_source.map(|s| s.as_str().to_string()).unwrap_or_default());
+        set_edit_vendor.set(current.vendor_id.unwrap_or_default());
+        set_edit_grex.set(current.grex_name.unwrap_or_default());
+        set_edit_seed_parent.set(current.seed_parent.unwrap_or_default());
+        set_edit_pollen_parent.set(current.pollen_parent.unwrap_or_default());
     };
 
     let on_edit_save = move |ev: leptos::ev::SubmitEvent| {
@@ -410,11 +699,12 @@ fn DetailsTab(
         let pot_medium_parsed = if pot_medium_val.is_empty() { None } else {
             match serde_json::from_str::<crate::orchid::PotMedium>(&format!("\"{}\"", pot_medium_val)) {
                 Ok(v) => Some(v),
-                Err(_e) => {
+                Err(e) => {
+                    tracing::warn!("Failed to parse pot_medium '{}': {}", pot_medium_val, e);
                     #[cfg(feature = "hydrate")]
                     crate::server_fns::telemetry::emit_warn(
                         "orchid_detail.on_edit_save",
-                        &format!("Failed to parse pot_medium '{}': {}", pot_medium_val, _e),
+                        &format!("Failed to parse pot_medium '{}': {}", pot_medium_val, e),
                         &[("orchid_id", &current.id), ("raw_value", &pot_medium_val)],
                     );
                     None
@@ -424,11 +714,12 @@ fn DetailsTab(
         let pot_size_parsed = if pot_size_val.is_empty() { None } else {
             match serde_json::from_str::<crate::orchid::PotSize>(&format!("\"{}\"", pot_size_val)) {
                 Ok(v) => Some(v),
-                Err(_e) => {
+                Err(e) => {
+                    tracing::warn!("Failed to parse pot_size '{}': {}", pot_size_val, e);
                     #[cfg(feature = "hydrate")]
                     crate::server_fns::telemetry::emit_warn(
                         "orchid_detail.on_edit_save",
-                        &format!("Failed to parse pot_size '{}': {}", pot_size_val, _e),
+                        &format!("Failed to parse pot_size '{}': {}", pot_size_val, e),
                         &[("orchid_id", &current.id), ("raw_value", &pot_size_val)],
                     );
                     None
@@ -438,11 +729,12 @@ fn DetailsTab(
         let pot_type_parsed = if pot_type_val.is_empty() { None } else {
             match serde_json::from_str::<crate::orchid::PotType>(&format!("\"{}\"", pot_type_val)) {
                 Ok(v) => Some(v),
-                Err(_e) => {
+                Err(e) => {
+                    tracing::warn!("Failed to parse pot_type '{}': {}", pot_type_val, e);
                     #[cfg(feature = "hydrate")]
                     crate::server_fns::telemetry::emit_warn(
                         "orchid_detail.on_edit_save",
-                        &format!("Failed to parse pot_type '{}': {}", pot_type_val, _e),
+                        &format!("Failed to parse pot_type '{}': {}", pot_type_val, e),
                         &[("orchid_id", &current.id), ("raw_value", &pot_type_val)],
                     );
                     None
@@ -463,6 +755,7 @@ fn DetailsTab(
             ],
         );
 
+        let is_semi_hydro = pot_medium_parsed == Some(crate::orchid::PotMedium::Leca);
         let updated = Orchid {
             id: current.id,
             name: edit_name.get(),
@@ -491,6 +784,17 @@ fn DetailsTab(
             pot_size: pot_size_parsed,
             pot_type: pot_type_parsed,
             par_ppfd: edit_par_ppfd.get().parse().ok(),
+            overdue_grace_days: edit_grace_days.get().parse::<u32>().ok()
+                .map(|d| d.min(crate::orchid::MAX_OVERDUE_GRACE_DAYS)),
+            default_watering_method: WateringMethod::parse(&edit_watering_method.get()),
+            water_deferred_until: current.water_deferred_until,
+            // Only meaningful for semi-hydro; cleared when the medium changes away from LECA
+            reservoir_frequency_days: if is_semi_hydro {
+                edit_reservoir_freq.get().parse::<u32>().ok().filter(|d| *d > 0)
+            } else {
+                None
+            },
+            last_topped_up_at: current.last_topped_up_at,
             rest_start_month: edit_rest_start.get().parse().ok(),
             rest_end_month: edit_rest_end.get().parse().ok(),
             bloom_start_month: edit_bloom_start.get().parse().ok(),
@@ -499,6 +803,17 @@ fn DetailsTab(
             rest_fertilizer_multiplier: edit_rest_fert_mult.get().parse().ok(),
             active_water_multiplier: edit_active_water_mult.get().parse().ok(),
             active_fertilizer_multiplier: edit_active_fert_mult.get().parse().ok(),
+            acquired_at: crate::acquisition::parse_date_input(&edit_acquired.get()),
+            acquisition_price: crate::acquisition::parse_price_input(&edit_price.get()),
+            acquisition_source: crate::orchid::AcquisitionSource::parse(&edit_source.get()),
+            vendor_id: Some(edit_vendor.get()).filter(|v| !v.is_empty()),
+            grex_name: parentage_input(&edit_grex.get()),
+            seed_parent: parentage_input(&edit_seed_parent.get()),
+            pollen_parent: parentage_input(&edit_pollen_parent.get()),
+            archived_at: current.archived_at,
+            archive_reason: current.archive_reason,
+            cover_image: current.cover_image.clone(),
+            updated_at: current.updated_at,
         };
         set_orchid_signal.set(updated.clone());
         on_update(updated);
@@ -536,6 +851,9 @@ fn DetailsTab(
                         edit_pot_size=edit_pot_size set_edit_pot_size=set_edit_pot_size
                         edit_pot_type=edit_pot_type set_edit_pot_type=set_edit_pot_type
                         edit_par_ppfd=edit_par_ppfd set_edit_par_ppfd=set_edit_par_ppfd
+                        edit_grace_days=edit_grace_days set_edit_grace_days=set_edit_grace_days
+                        edit_watering_method=edit_watering_method set_edit_watering_method=set_edit_watering_method
+                        edit_reservoir_freq=edit_reservoir_freq set_edit_reservoir_freq=set_edit_reservoir_freq
                         edit_rest_start=edit_rest_start set_edit_rest_start=set_edit_rest_start
                         edit_rest_end=edit_rest_end set_edit_rest_end=set_edit_rest_end
                         edit_bloom_start=edit_bloom_start set_edit_bloom_start=set_edit_bloom_start
@@ -544,6 +862,13 @@ fn DetailsTab(
                         edit_rest_fert_mult=edit_rest_fert_mult set_edit_rest_fert_mult=set_edit_rest_fert_mult
                         edit_active_water_mult=edit_active_water_mult set_edit_active_water_mult=set_edit_active_water_mult
                         edit_active_fert_mult=edit_active_fert_mult set_edit_active_fert_mult=set_edit_active_fert_mult
+                        edit_acquired=edit_acquired set_edit_acquired=set_edit_acquired
+                        edit_price=edit_price set_edit_price=set_edit_price
+                        edit_source=edit_source set_edit_source=set_edit_source
+                        edit_vendor=edit_vendor set_edit_vendor=set_edit_vendor
+                        edit_grex=edit_grex set_edit_grex=set_edit_grex
+                        edit_seed_parent=edit_seed_parent set_edit_seed_parent=set_edit_seed_parent
+                        edit_pollen_parent=edit_pollen_parent set_edit_pollen_parent=set_edit_pollen_parent
                         zones=zones_ref
                         on_save=on_edit_save
                         on_cancel=on_edit_cancel
@@ -590,7 +915,10 @@ fn DetailsTab(
                                     let hemi = Hemisphere::from_code(&hemisphere.get_value());
                                     let snap = climate_snapshot.get_value();
                                     let estimate = o.climate_adjusted_water_frequency(&hemi, snap.as_ref());
-                                    if estimate.climate_active {
+                                    let rained = estimate.factors.as_ref().is_some_and(|f| f.rain_factor > 1.0);
+                                    if estimate.climate_active && rained {
+                                        format!("~{} days (base: {}, stretched for rain)", estimate.adjusted_days, o.water_frequency_days)
+                                    } else if estimate.climate_active {
                                         format!("~{} days (base: {})", estimate.adjusted_days, o.water_frequency_days)
                                     } else {
                                         format!("{} days", o.water_frequency_days)
@@ -601,11 +929,20 @@ fn DetailsTab(
                                 <div class="text-xs text-stone-400">"Temp Range"</div>
                                 <div class="font-medium text-stone-700 dark:text-stone-300">{move || orchid_signal.get().temperature_range.clone()}</div>
                             </div>
+                            {move || acquisition_summary(&orchid_signal.get()).map(|summary| view! {
+                                <div>
+                                    <div class="text-xs text-stone-400">"Acquired"</div>
+                                    <div class="font-medium text-stone-700 dark:text-stone-300">
+                                        {summary}
+                                        {move || vendor_name.get().and_then(|r| r.ok()).flatten().map(|name| format!(" \u{00B7} {}", name))}
+                                    </div>
+                                </div>
+                            })}
                         </div>
                         {move || {
                             let notes = orchid_signal.get().notes.clone();
                             (!notes.is_empty()).then(|| {
-                                view! { <p class="mt-3 text-sm text-stone-600 dark:text-stone-400">{notes}</p> }
+                                view! { <MarkdownNote text=notes class="mt-3 text-sm text-stone-600 dark:text-stone-400" /> }
                             })
                         }}
                     </div>
@@ -613,14 +950,51 @@ fn DetailsTab(
             }
         }}
 
+        // Care confidence: how much of the advice rests on real data
+        {move || (!is_editing.get()).then(|| if read_only {
+            view! { <CareConfidenceCard orchid_signal=orchid_signal log_entries=log_entries /> }.into_any()
+        } else {
+            view! {
+                <CareConfidenceCard
+                    orchid_signal=orchid_signal
+                    log_entries=log_entries
+                    on_edit=move |_| {
+                        populate_edit_fields();
+                        set_is_editing.set(true);
+                    }
+                />
+            }.into_any()
+        })}
+
+        // Hybrid ancestry: grex and cross, when recorded
+        {move || (!is_editing.get()).then(|| view! { <AncestryCard orchid_signal=orchid_signal /> })}
+
         // Care Schedule: Fertilizer + Pot Info
-        <CareScheduleCard orchid_signal=orchid_signal set_orchid_signal=set_orchid_signal read_only=read_only />
+        <CareScheduleCard orchid_signal=orchid_signal set_orchid_signal=set_orchid_signal read_only=read_only hemisphere=hemisphere />
+
+        // Common, trade and synonym names (needs a signed-in user)
+        {(!read_only).then(|| view! {
+            <crate::components::species_aliases::SpeciesAliasCard orchid_signal=orchid_signal />
+        })}
+
+        // Awards and show results (needs a signed-in user)
+        {(!read_only).then(|| view! {
+            <crate::components::awards::AwardsCard orchid_signal=orchid_signal />
+        })}
+
+        // Pest and disease treatments with their re-treatment series (needs a signed-in user)
+        {(!read_only).then(|| view! {
+            <crate::components::treatments::TreatmentsCard orchid_signal=orchid_signal />
+        })}
+
+        // Bloom history derived from the journal's Flowering entries
+        <crate::components::bloom_history::BloomHistoryCard log_entries=log_entries />
         
         // Suitability (Scientific Setup Check)
         {move || {
             let snap = climate_snapshot.get_value();
             view! {
-                <crate::components::suitability_card::SuitabilityCard orchid_signal=orchid_signal climate_snapshot=snap />
+                <crate::components::suitability_card::SuitabilityCard orchid_signal=orchid_signal climate_snapshot=snap habitat_similarity=habitat_similarity />
             }
         }}
 
@@ -637,6 +1011,7 @@ fn DetailsTab(
                     latitude=lat
                     longitude=lon
                     zone_reading=zr
+                    similarity=habitat_similarity
                 />
             }
         })}
@@ -644,7 +1019,8 @@ fn DetailsTab(
         // Watering status + Water Now button
         <div class="flex gap-3 justify-between items-center p-4 mt-4 mb-4 rounded-xl bg-secondary">
             <div>
-                <div class="text-xs tracking-wide text-stone-400">"Watering Status"</div>
+                // In semi-hydro the "watering" is the periodic flush; the reservoir has its own row below
+                <div class="text-xs tracking-wide text-stone-400">{move || if orchid_signal.with(|o| o.is_semi_hydro()) { "Flush Status" } else { "Watering Status" }}</div>
                 <div class="text-sm font-medium text-stone-700 dark:text-stone-300">
                     {move || {
                         let o = orchid_signal.get();
@@ -653,8 +1029,11 @@ fn DetailsTab(
                         let estimate = o.climate_adjusted_water_frequency(&hemi, snap.as_ref());
                         let climate_active = estimate.climate_active;
                         let approx = if climate_active { "~" } else { "" };
+                        let is_overdue = o.is_climate_overdue(&hemi, snap.as_ref(), overdue_grace_days);
                         match o.climate_days_until_due(&hemi, snap.as_ref()) {
-                            Some(days) if days < 0 => format!("Overdue by {}{} days", approx, -days),
+                            Some(days) if days < 0 && is_overdue => format!("Overdue by {}{} days", approx, -days),
+                            Some(-1) => "Due yesterday".to_string(),
+                            Some(days) if days < 0 => format!("Due {}{} days ago", approx, -days),
                             Some(0) => "Due today".to_string(),
                             Some(1) => "Due tomorrow".to_string(),
                             Some(days) => format!("Due in {}{} days", approx, days),
@@ -662,41 +1041,292 @@ fn DetailsTab(
                         }
                     }}
                 </div>
+                {move || orchid_signal.with(|o| o.deferred_days_remaining().and(o.water_deferred_until)).map(|until| view! {
+                    <div class="mt-1 text-xs text-teal-700 dark:text-teal-400">
+                        {format!("Watering skipped until {}", until.with_timezone(&chrono::Local).format("%a %-d %b"))}
+                    </div>
+                })}
+                {move || climate_snapshot.get_value().as_ref().and_then(crate::watering::forecast_hint).map(|h| {
+                    let class = if h.is_urgent() {
+                        "mt-1 text-xs text-amber-700 dark:text-amber-400"
+                    } else {
+                        "mt-1 text-xs text-sky-700 dark:text-sky-400"
+                    };
+                    view! { <div class=class>{h.message(&temp_unit.get_value())}</div> }
+                })}
             </div>
             {(!read_only).then(|| view! {
-                <button
-                    class=BTN_PRIMARY
-                    disabled=move || is_watering.get()
-                    on:click=move |_| {
-                        set_is_watering.set(true);
-                        let orchid_id = orchid_signal.get().id.clone();
-                        let orchid_id_for_log = orchid_id.clone();
-                        leptos::task::spawn_local(async move {
-                            match crate::server_fns::orchids::mark_watered(orchid_id).await {
-                                Ok(updated) => {
-                                    set_orchid_signal.set(updated);
-                                    // Refresh journal so the watering entry appears
-                                    if let Ok(entries) = crate::server_fns::orchids::get_log_entries(orchid_id_for_log).await {
-                                        set_log_entries.set(entries);
+                <div class="flex gap-2 items-center">
+                    <button
+                        class=BTN_SECONDARY
+                        on:click=move |_| set_show_skip.update(|v| *v = !*v)
+                    >
+                        "Skip"
+                    </button>
+                    <button
+                        class=BTN_PRIMARY
+                        disabled=move || is_watering.get()
+                        on:click=move |_| {
+                            set_is_watering.set(true);
+                            let orchid_id = orchid_signal.get().id.clone();
+                            let orchid_id_for_log = orchid_id.clone();
+                            leptos::task::spawn_local(async move {
+                                match crate::server_fns::orchids::mark_watered(orchid_id).await {
+                                    Ok(updated) => {
+                                        set_orchid_signal.set(updated);
+                                        set_show_skip.set(false);
+                                        set_show_rating.set(true);
+                                        // Refresh journal so the watering entry appears
+                                        if let Ok(entries) = crate::server_fns::orchids::get_log_entries(orchid_id_for_log).await {
+                                            set_log_entries.set(entries);
+                                        }
+                                    }
+                                    Err(e) => {
+                                        tracing::error!("Failed to mark watered: {}", e);
+                                        #[cfg(feature = "hydrate")]
+                                        crate::server_fns::telemetry::emit_error("orchid_detail.mark_watered", &format!("Failed to mark watered: {}", e), &[]);
                                     }
                                 }
-                                Err(e) => {
-                                    tracing::error!("Failed to mark watered: {}", e);
-                                    #[cfg(feature = "hydrate")]
-                                    crate::server_fns::telemetry::emit_error("orchid_detail.mark_watered", &format!("Failed to mark watered: {}", e), &[]);
-                                }
-                            }
-                            set_is_watering.set(false);
-                        });
-                    }
-                >
-                    {move || if is_watering.get() { "Watering..." } else { "Water Now" }}
-                </button>
+                                set_is_watering.set(false);
+                            });
+                        }
+                    >
+                        {move || if is_watering.get() { "Watering..." } else { "Water Now" }}
+                    </button>
+                </div>
             })}
         </div>
+        {move || orchid_signal.with(|o| o.is_semi_hydro()).then(|| view! {
+            <ReservoirStatus
+                orchid_signal=orchid_signal
+                set_orchid_signal=set_orchid_signal
+                set_log_entries=set_log_entries
+                climate_snapshot=climate_snapshot
+                read_only=read_only
+            />
+        })}
+        {move || (show_rating.get() && !read_only).then(|| view! {
+            <WateringFeedbackPrompt
+                orchid_signal=orchid_signal
+                set_orchid_signal=set_orchid_signal
+                set_log_entries=set_log_entries
+                on_close=move || set_show_rating.set(false)
+            />
+        })}
+        {move || (show_skip.get() && !read_only).then(|| view! {
+            <SkipWateringForm
+                orchid_signal=orchid_signal
+                set_orchid_signal=set_orchid_signal
+                set_log_entries=set_log_entries
+                on_close=move || set_show_skip.set(false)
+            />
+        })}
     }.into_any()
 }
 
+// ── Semi-hydro reservoir ─────────────────────────────────────────────
+
+/// Reservoir top-up status and button for semi-hydro plants, separate from the flush schedule.
+#[component]
+fn ReservoirStatus(
+    orchid_signal: ReadSignal<Orchid>,
+    set_orchid_signal: WriteSignal<Orchid>,
+    set_log_entries: WriteSignal<Vec<LogEntry>>,
+    climate_snapshot: StoredValue<Option<ClimateSnapshot>>,
+    read_only: bool,
+) -> impl IntoView {
+    let (is_saving, set_is_saving) = signal(false);
+
+    let top_up = move |_| {
+        set_is_saving.set(true);
+        let orchid_id = orchid_signal.get_untracked().id;
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::add_log_entry(orchid_id, "Reservoir topped up".into(), None, Some("ToppedUp".into()), None, None).await {
+                Ok(response) => {
+                    set_orchid_signal.update(|o| o.last_topped_up_at = Some(response.entry.timestamp));
+                    set_log_entries.update(|entries| entries.insert(0, response.entry));
+                }
+                Err(e) => {
+                    tracing::error!("Failed to log reservoir top-up: {}", e);
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("orchid_detail.top_up_reservoir", &format!("Failed to log reservoir top-up: {}", e), &[]);
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex gap-3 justify-between items-center p-4 -mt-2 mb-4 rounded-xl bg-indigo-50 dark:bg-indigo-900/20">
+            <div>
+                <div class="text-xs tracking-wide text-stone-400">"Reservoir"</div>
+                <div class="text-sm font-medium text-stone-700 dark:text-stone-300">
+                    {move || {
+                        let o = orchid_signal.get();
+                        let snap = climate_snapshot.get_value();
+                        let every = o.reservoir_frequency(snap.as_ref()).unwrap_or(crate::orchid::DEFAULT_RESERVOIR_DAYS);
+                        let status = match (o.last_topped_up_at, o.reservoir_days_until_due(snap.as_ref())) {
+                            (None, _) => "Not topped up yet".to_string(),
+                            (_, Some(days)) if days < 0 => format!("Top up overdue by {} days", -days),
+                            (_, Some(0)) => "Top up today".to_string(),
+                            (_, Some(1)) => "Top up tomorrow".to_string(),
+                            (_, Some(days)) => format!("Top up in {} days", days),
+                            (_, None) => String::new(),
+                        };
+                        format!("{} \u{00B7} every {} days", status, every)
+                    }}
+                </div>
+            </div>
+            {(!read_only).then(|| view! {
+                <button class=BTN_SECONDARY disabled=move || is_saving.get() on:click=top_up>
+                    {move || if is_saving.get() { "Saving..." } else { "Topped Up" }}
+                </button>
+            })}
+        </div>
+    }
+}
+
+// ── Watering feedback prompt ─────────────────────────────────────────
+
+/// "How was the medium?" after a watering; the rating tunes the plant's base watering frequency.
+#[component]
+fn WateringFeedbackPrompt(
+    orchid_signal: ReadSignal<Orchid>,
+    set_orchid_signal: WriteSignal<Orchid>,
+    set_log_entries: WriteSignal<Vec<LogEntry>>,
+    on_close: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (is_saving, set_is_saving) = signal(false);
+    let (message, set_message) = signal(None::<String>);
+
+    let rate = move |feedback: MediumFeedback| {
+        set_is_saving.set(true);
+        let before = orchid_signal.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::rate_watering(before.id.clone(), feedback).await {
+                Ok(updated) => {
+                    set_message.set(Some(if updated.water_frequency_days == before.water_frequency_days {
+                        format!("Thanks \u{2014} keeping every {} days.", updated.water_frequency_days)
+                    } else {
+                        format!("Thanks \u{2014} now every {} days (was {}).", updated.water_frequency_days, before.water_frequency_days)
+                    }));
+                    set_orchid_signal.set(updated);
+                    if let Ok(entries) = crate::server_fns::orchids::get_log_entries(before.id).await {
+                        set_log_entries.set(entries);
+                    }
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("orchid_detail.rate_watering", &format!("Failed to rate watering: {}", e), &[]);
+                    set_message.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-2 items-center p-3 -mt-2 mb-4 text-xs rounded-xl border border-stone-200 dark:border-stone-700">
+            {move || match message.get() {
+                Some(msg) => view! { <span class="flex-1 text-stone-600 dark:text-stone-400">{msg}</span> }.into_any(),
+                None => view! {
+                    <span class="flex-1 text-stone-500 dark:text-stone-400">"How was the medium?"</span>
+                    {MediumFeedback::ALL.into_iter().map(|f| view! {
+                        <button class=BTN_SECONDARY disabled=move || is_saving.get() on:click=move |_| rate(f)>{f.label()}</button>
+                    }).collect::<Vec<_>>()}
+                }.into_any(),
+            }}
+            <button class=BTN_CLOSE aria-label="Dismiss" on:click=move |_| on_close()>"\u{00D7}"</button>
+        </div>
+    }
+}
+
+// ── Skip Watering form ───────────────────────────────────────────────
+
+/// Inline form under the watering status: put the next watering off by some days, with a reason for the journal.
+#[component]
+fn SkipWateringForm(
+    orchid_signal: ReadSignal<Orchid>,
+    set_orchid_signal: WriteSignal<Orchid>,
+    set_log_entries: WriteSignal<Vec<LogEntry>>,
+    on_close: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (days, set_days) = signal(2u32);
+    let (reason, set_reason) = signal(SkipReason::MediumStillWet);
+    let (note, set_note) = signal(String::new());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let save = move |_| {
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let orchid_id = orchid_signal.get_untracked().id;
+        let note_value = Some(note.get_untracked()).filter(|n| !n.trim().is_empty());
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::skip_watering(orchid_id.clone(), days.get_untracked(), reason.get_untracked(), note_value).await {
+                Ok(updated) => {
+                    set_orchid_signal.set(updated);
+                    // Refresh journal so the skipped entry appears
+                    if let Ok(entries) = crate::server_fns::orchids::get_log_entries(orchid_id).await {
+                        set_log_entries.set(entries);
+                    }
+                    on_close();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("orchid_detail.skip_watering", &format!("Failed to skip watering: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-4 -mt-2 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="flex flex-wrap gap-3 items-end">
+                <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                    "Skip for"
+                    <select class="py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600"
+                        on:change=move |ev| if let Ok(d) = event_target_value(&ev).parse() { set_days.set(d) }
+                    >
+                        {(1..=crate::orchid::MAX_SKIP_DAYS).map(|d| view! {
+                            <option value=d.to_string() selected=move || days.get() == d>
+                                {if d == 1 { "1 day".to_string() } else { format!("{} days", d) }}
+                            </option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </label>
+                <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                    "Because"
+                    <select class="py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600"
+                        on:change=move |ev| if let Some(r) = SkipReason::parse(&event_target_value(&ev)) { set_reason.set(r) }
+                    >
+                        {SkipReason::ALL.into_iter().map(|r| view! {
+                            <option value=r.as_str() selected=move || reason.get() == r>{r.label()}</option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </label>
+                <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[140px]">
+                    "Note"
+                    <input type="text" maxlength="500" placeholder="Optional"
+                        class="py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600"
+                        prop:value=note
+                        on:input=move |ev| set_note.set(event_target_value(&ev))
+                    />
+                </label>
+                <div class="flex gap-2">
+                    <button class=BTN_SECONDARY on:click=move |_| on_close()>"Cancel"</button>
+                    <button class=BTN_PRIMARY disabled=move || is_saving.get() on:click=save>
+                        {move || if is_saving.get() { "Saving..." } else { "Skip Watering" }}
+                    </button>
+                </div>
+            </div>
+            {move || error_msg.get().map(|e| view! { <p class="mt-2 mb-0 text-xs text-danger">{e}</p> })}
+        </div>
+    }
+}
+
 // ── Edit Form sub-component ──────────────────────────────────────────
 
 // ── Care Schedule Card ───────────────────────────────────────────────
@@ -710,8 +1340,20 @@ fn CareScheduleCard(
     orchid_signal: ReadSignal<Orchid>,
     set_orchid_signal: WriteSignal<Orchid>,
     #[prop(optional)] read_only: bool,
+    /// Needed to apply "pause fertilizer during phase" rules; without it no pause is shown.
+    #[prop(optional)] hemisphere: Option<StoredValue<String>>,
 ) -> impl IntoView {
     let (is_fertilizing, set_is_fertilizing) = signal(false);
+    let care_rules = use_context::<crate::components::care_rules::CareRules>();
+    // Name of the automation rule currently holding fertilizer reminders, if any
+    let paused_by = Memo::new(move |_| {
+        let (ctx, hemi) = (care_rules?, hemisphere?);
+        let o = orchid_signal.get();
+        ctx.0.with(|rules| {
+            crate::care_rules::fertilizer_paused(rules, &o, &Hemisphere::from_code(&hemi.get_value()))
+                .map(|r| r.name.clone())
+        })
+    });
 
     view! {
         <div class=CARE_CARD>
@@ -733,9 +1375,10 @@ fn CareScheduleCard(
                     <div class=CARE_STAT_VALUE>
                         {move || {
                             let o = orchid_signal.get();
-                            match o.fertilize_frequency_days {
-                                Some(d) => format!("{} days", d),
-                                None => "No schedule".to_string(),
+                            match (o.fertilize_frequency_days, paused_by.get()) {
+                                (Some(_), Some(rule)) => format!("Paused ({})", rule),
+                                (Some(d), None) => format!("{} days", d),
+                                (None, _) => "No schedule".to_string(),
                             }
                         }}
                     </div>
@@ -745,6 +1388,7 @@ fn CareScheduleCard(
                     <div class={move || {
                         let o = orchid_signal.get();
                         let overdue = o.fertilize_frequency_days.is_some()
+                            && paused_by.get().is_none()
                             && o.fertilize_days_until_due().map(|d| d < 0).unwrap_or(false);
                         if overdue { "text-sm font-medium text-danger" } else { CARE_STAT_VALUE }
                     }}>
@@ -982,6 +1626,9 @@ fn EditForm(
     edit_pot_size: ReadSignal<String>, set_edit_pot_size: WriteSignal<String>,
     edit_pot_type: ReadSignal<String>, set_edit_pot_type: WriteSignal<String>,
     edit_par_ppfd: ReadSignal<String>, set_edit_par_ppfd: WriteSignal<String>,
+    edit_grace_days: ReadSignal<String>, set_edit_grace_days: WriteSignal<String>,
+    edit_watering_method: ReadSignal<String>, set_edit_watering_method: WriteSignal<String>,
+    edit_reservoir_freq: ReadSignal<String>, set_edit_reservoir_freq: WriteSignal<String>,
     edit_rest_start: ReadSignal<String>, set_edit_rest_start: WriteSignal<String>,
     edit_rest_end: ReadSignal<String>, set_edit_rest_end: WriteSignal<String>,
     edit_bloom_start: ReadSignal<String>, set_edit_bloom_start: WriteSignal<String>,
@@ -990,6 +1637,13 @@ fn EditForm(
     edit_rest_fert_mult: ReadSignal<String>, set_edit_rest_fert_mult: WriteSignal<String>,
     edit_active_water_mult: ReadSignal<String>, set_edit_active_water_mult: WriteSignal<String>,
     edit_active_fert_mult: ReadSignal<String>, set_edit_active_fert_mult: WriteSignal<String>,
+    edit_acquired: ReadSignal<String>, set_edit_acquired: WriteSignal<String>,
+    edit_price: ReadSignal<String>, set_edit_price: WriteSignal<String>,
+    edit_source: ReadSignal<String>, set_edit_source: WriteSignal<String>,
+    edit_vendor: ReadSignal<String>, set_edit_vendor: WriteSignal<String>,
+    edit_grex: ReadSignal<String>, set_edit_grex: WriteSignal<String>,
+    edit_seed_parent: ReadSignal<String>, set_edit_seed_parent: WriteSignal<String>,
+    edit_pollen_parent: ReadSignal<String>, set_edit_pollen_parent: WriteSignal<String>,
     zones: Vec<GrowingZone>,
     on_save: impl Fn(leptos::ev::SubmitEvent) + 'static + Copy + Send + Sync,
     on_cancel: impl Fn(leptos::ev::MouseEvent) + 'static + Copy + Send + Sync,
@@ -1048,6 +1702,19 @@ fn EditForm(
                         </div>
                         <input type="number" prop:value=edit_water_freq on:input=move |ev| set_edit_water_freq.set(event_target_value(&ev)) required />
                     </div>
+                    <div class="flex-1">
+                        <label>"Overdue Grace (days):"</label>
+                        <input type="number" min="0" max=crate::orchid::MAX_OVERDUE_GRACE_DAYS.to_string() prop:value=edit_grace_days on:input=move |ev| set_edit_grace_days.set(event_target_value(&ev)) placeholder="Default" title="Days past due before this plant counts as overdue. Leave blank to use your default." />
+                    </div>
+                    <div class="flex-1">
+                        <label>"Usually Watered By:"</label>
+                        <select prop:value=edit_watering_method on:change=move |ev| set_edit_watering_method.set(event_target_value(&ev)) title="Recorded when you tap Water without picking a method. Mistings only restart the countdown for plants that are usually misted.">
+                            <option value="">"Not set"</option>
+                            {WateringMethod::ALL.into_iter().map(|m| view! {
+                                <option value=m.as_str()>{m.label()}</option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    </div>
                     <div class="flex-1">
                         <label>"Light Req:"</label>
                         <select prop:value=edit_light_req on:change=move |ev| set_edit_light_req.set(event_target_value(&ev))>
@@ -1128,6 +1795,14 @@ fn EditForm(
                                 </select>
                             </div>
                         })}
+                        {move || (serde_json::from_str::<crate::orchid::PotMedium>(&format!("\"{}\"", edit_pot_medium.get())).ok() == Some(crate::orchid::PotMedium::Leca)).then(|| view! {
+                            <div class="flex-1 animate-fade-in">
+                                <label>"Top Up Reservoir Every (days):"</label>
+                                <input type="number" min="1" max=crate::orchid::MAX_RESERVOIR_DAYS.to_string() prop:value=edit_reservoir_freq on:input=move |ev| set_edit_reservoir_freq.set(event_target_value(&ev))
+                                    placeholder=crate::orchid::DEFAULT_RESERVOIR_DAYS.to_string()
+                                    title="Semi-hydro: how often the reservoir needs refilling. Watering frequency above becomes the flush schedule." />
+                            </div>
+                        })}
                         <div class="flex-1">
                             <label>"Pot Type (Airflow):"</label>
                             <select prop:value=edit_pot_type on:change=move |ev| {
@@ -1204,6 +1879,19 @@ fn EditForm(
                     </div>
                 </div>
 
+                <crate::components::acquisition::AcquisitionFields
+                    acquired=edit_acquired set_acquired=set_edit_acquired
+                    price=edit_price set_price=set_edit_price
+                    source=edit_source set_source=set_edit_source
+                    vendor=edit_vendor set_vendor=set_edit_vendor
+                />
+
+                <ParentageFields
+                    grex=edit_grex set_grex=set_edit_grex
+                    seed=edit_seed_parent set_seed=set_edit_seed_parent
+                    pollen=edit_pollen_parent set_pollen=set_edit_pollen_parent
+                />
+
                 <div class="mb-4">
                     <label>"Notes:"</label>
                     <textarea prop:value=edit_notes on:input=move |ev| set_edit_notes.set(event_target_value(&ev)) rows="3"></textarea>
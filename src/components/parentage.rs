@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+use crate::orchid::{Orchid, MAX_PARENTAGE_LEN};
+
+const SECTION_HEADING: &str = "mt-0 mb-3 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400";
+
+/// The "Parentage" section of the edit form: grex name and the two parents of the cross.
+///
+/// Every value is the raw input string; trim it and store None when it's empty.
+#[component]
+pub fn ParentageFields(
+    grex: ReadSignal<String>, set_grex: WriteSignal<String>,
+    seed: ReadSignal<String>, set_seed: WriteSignal<String>,
+    pollen: ReadSignal<String>, set_pollen: WriteSignal<String>,
+) -> impl IntoView {
+    let max_len = MAX_PARENTAGE_LEN.to_string();
+    view! {
+        <div class="pt-4 mt-4 mb-4 border-t border-stone-200 dark:border-stone-700">
+            <h4 class=SECTION_HEADING>"Parentage"</h4>
+            <div class="mb-4">
+                <label>"Grex Name:"</label>
+                <input type="text" maxlength=max_len.clone()
+                    prop:value=grex
+                    on:input=move |ev| set_grex.set(event_target_value(&ev))
+                    placeholder="e.g. Paphiopedilum Maudiae"
+                />
+            </div>
+            <div class="flex flex-col gap-4 sm:flex-row">
+                <div class="flex-1">
+                    <label>"Seed Parent:"</label>
+                    <input type="text" maxlength=max_len.clone()
+                        prop:value=seed
+                        on:input=move |ev| set_seed.set(event_target_value(&ev))
+                        placeholder="e.g. Paph. callosum"
+                    />
+                </div>
+                <div class="flex-1">
+                    <label>"Pollen Parent:"</label>
+                    <input type="text" maxlength=max_len
+                        prop:value=pollen
+                        on:input=move |ev| set_pollen.set(event_target_value(&ev))
+                        placeholder="e.g. Paph. lawrenceanum"
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Trims a parentage input, mapping an empty one to None.
+pub fn parentage_input(value: &str) -> Option<String> {
+    Some(value.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+/// A hybrid's ancestry: its grex, and the cross it came from with the seed parent first.
+/// Renders nothing for plants with no parentage recorded.
+#[component]
+pub fn AncestryCard(orchid_signal: ReadSignal<Orchid>) -> impl IntoView {
+    move || {
+        let o = orchid_signal.get();
+        o.has_parentage().then(|| view! {
+            <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+                <h4 class=SECTION_HEADING>"Ancestry"</h4>
+                {o.grex_name.clone().map(|grex| view! {
+                    <p class="mt-0 mb-2 text-sm text-stone-500 dark:text-stone-400">
+                        "Grex "
+                        <span class="font-semibold text-stone-800 dark:text-stone-100">{grex}</span>
+                    </p>
+                })}
+                {o.cross_label().map(|_| view! {
+                    <div class="flex flex-wrap gap-2 items-center text-sm">
+                        <Parent role="Seed parent" name=o.seed_parent.clone() />
+                        <span class="text-lg text-stone-400">"\u{00D7}"</span>
+                        <Parent role="Pollen parent" name=o.pollen_parent.clone() />
+                    </div>
+                })}
+            </div>
+        })
+    }
+}
+
+#[component]
+fn Parent(role: &'static str, name: Option<String>) -> impl IntoView {
+    let known = name.is_some();
+    view! {
+        <div class="flex-1 py-2 px-3 rounded-lg min-w-[120px] bg-secondary">
+            <div class="text-xs text-stone-400">{role}</div>
+            <div class=if known { "italic font-medium text-stone-700 dark:text-stone-200" } else { "text-stone-400" }>
+                {name.unwrap_or_else(|| "Unknown".to_string())}
+            </div>
+        </div>
+    }
+}
+
@@ -0,0 +1,180 @@
+use leptos::prelude::*;
+
+/// Runs a WebAuthn browser ceremony (`create` or `get`) from server-issued JSON options
+/// and returns the resulting credential serialized as JSON.
+///
+/// Relies on `PublicKeyCredential.parse*OptionsFromJSON` / `toJSON()` so the base64url
+/// encoding used by the server round-trips without manual ArrayBuffer conversion.
+#[cfg(feature = "hydrate")]
+async fn run_ceremony(options_json: &str, create: bool) -> Result<String, String> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let window = web_sys::window().ok_or("No window object")?;
+    let pkc = js_sys::Reflect::get(&window, &"PublicKeyCredential".into())
+        .ok()
+        .filter(|v| !v.is_undefined())
+        .ok_or("Passkeys aren't supported in this browser")?;
+
+    let (parse_fn, method) = if create {
+        ("parseCreationOptionsFromJSON", "create")
+    } else {
+        ("parseRequestOptionsFromJSON", "get")
+    };
+    let parse = js_sys::Reflect::get(&pkc, &parse_fn.into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or("This browser is too old for passkeys")?;
+
+    let options = js_sys::JSON::parse(options_json)
+        .map_err(|_| "Malformed passkey options".to_string())?;
+    let public_key = js_sys::Reflect::get(&options, &"publicKey".into())
+        .map_err(|_| "Malformed passkey options".to_string())?;
+    let parsed = parse.call1(&pkc, &public_key)
+        .map_err(|e| format!("Passkey options rejected: {:?}", e))?;
+
+    let request = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&request, &"publicKey".into(), &parsed);
+
+    let credentials = js_sys::Reflect::get(&window.navigator(), &"credentials".into())
+        .map_err(|_| "Credential API unavailable".to_string())?;
+    let call = js_sys::Reflect::get(&credentials, &method.into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or("Credential API unavailable")?;
+    let promise = call.call1(&credentials, &request)
+        .map_err(|e| format!("Passkey request failed: {:?}", e))?
+        .dyn_into::<js_sys::Promise>()
+        .map_err(|_| "Passkey request returned unexpected type".to_string())?;
+
+    let credential = JsFuture::from(promise).await
+        .map_err(|_| "Passkey prompt was cancelled".to_string())?;
+    if credential.is_null() {
+        return Err("Passkey prompt was cancelled".into());
+    }
+
+    let to_json = js_sys::Reflect::get(&credential, &"toJSON".into())
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+        .ok_or("This browser is too old for passkeys")?;
+    let json = to_json.call0(&credential)
+        .map_err(|e| format!("Passkey serialize failed: {:?}", e))?;
+    js_sys::JSON::stringify(&json)
+        .map(String::from)
+        .map_err(|_| "Passkey serialize failed".to_string())
+}
+
+/// Signs in with a passkey for `username`, creating a session on success.
+#[cfg(feature = "hydrate")]
+pub(crate) async fn sign_in_with_passkey(username: String) -> Result<(), String> {
+    let options = crate::server_fns::auth::start_passkey_login(username).await
+        .map_err(|e| e.to_string())?;
+    let credential = run_ceremony(&options, false).await?;
+    crate::server_fns::auth::finish_passkey_login(credential).await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Registers a new passkey on this device for the signed-in user.
+#[cfg(feature = "hydrate")]
+async fn register_passkey(name: String) -> Result<crate::server_fns::auth::PasskeyInfo, String> {
+    let options = crate::server_fns::auth::start_passkey_registration().await
+        .map_err(|e| e.to_string())?;
+    let credential = run_ceremony(&options, true).await?;
+    crate::server_fns::auth::finish_passkey_registration(name, credential).await
+        .map_err(|e| e.to_string())
+}
+
+/// Passkey list with add/remove controls for the settings modal.
+#[component]
+pub fn PasskeySettings() -> impl IntoView {
+    let passkeys = Resource::new(|| (), |_| crate::server_fns::auth::list_passkeys());
+    let (name, set_name) = signal(String::new());
+    let (is_adding, set_is_adding) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let add = move |_| {
+        set_is_adding.set(true);
+        set_error_msg.set(None);
+        let _label = name.get_untracked();
+        leptos::task::spawn_local(async move {
+            #[cfg(feature = "hydrate")]
+            match register_passkey(_label).await {
+                Ok(_) => {
+                    crate::server_fns::telemetry::emit_info("settings.passkey_added", "Passkey registered", &[]);
+                    set_name.set(String::new());
+                    passkeys.refetch();
+                }
+                Err(e) => {
+                    crate::server_fns::telemetry::emit_error("settings.passkey_add", &format!("Passkey registration failed: {}", e), &[]);
+                    set_error_msg.set(Some(e));
+                }
+            }
+            set_is_adding.set(false);
+        });
+    };
+
+    let remove = move |id: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::auth::delete_passkey(id.clone()).await {
+                Ok(()) => passkeys.refetch(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.passkey_delete", &format!("Failed to delete passkey: {}", e), &[("passkey_id", &id)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || passkeys.get().map(|result| {
+                let list = result.unwrap_or_default();
+                if list.is_empty() {
+                    return view! { <p class="mt-0 mb-3 text-xs text-stone-400">"No passkeys yet."</p> }.into_any();
+                }
+                view! {
+                    <div class="flex flex-col gap-1.5 mb-3">
+                        {list.into_iter().map(|pk| {
+                            let id = pk.id.clone();
+                            let used = pk.last_used_at
+                                .map(|t| format!("last used {}", super::format_time_ago(&t)))
+                                .unwrap_or_else(|| "never used".into());
+                            view! {
+                                <div class="flex justify-between items-center py-2 px-3 text-sm rounded-lg bg-secondary/30">
+                                    <div>
+                                        <div class="font-medium text-stone-700 dark:text-stone-300">{"\u{1F511} "}{pk.name}</div>
+                                        <div class="text-[11px] text-stone-400">{format!("Added {} \u{00B7} {}", pk.created_at.format("%b %d, %Y"), used)}</div>
+                                    </div>
+                                    <button
+                                        class="py-1 px-2 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                        on:click=move |_| remove(id.clone())
+                                    >"Remove"</button>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+        <div class="flex gap-2 items-center">
+            <input type="text"
+                class="flex-1 py-2 px-3 text-sm rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50"
+                placeholder="Device name (e.g. iPhone)"
+                prop:value=name
+                on:input=move |ev| set_name.set(event_target_value(&ev))
+            />
+            <button
+                class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                disabled=move || is_adding.get()
+                on:click=add
+            >
+                {move || if is_adding.get() { "Waiting for device..." } else { "Add Passkey" }}
+            </button>
+        </div>
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
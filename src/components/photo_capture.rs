@@ -50,6 +50,9 @@ pub async fn upload_data_url(data_url: &str) -> Result<String, String> {
         .dyn_into()
         .map_err(|_| "Invalid response")?;
 
+    if upload_resp.status() == 429 {
+        return Err("Too many uploads; wait a while and try again".into());
+    }
     if !upload_resp.ok() {
         return Err(format!("Upload error: {}", upload_resp.status()));
     }
@@ -247,7 +250,7 @@ pub fn PhotoCapture(
 
 /// Resize an image from a blob URL using canvas, returning a JPEG data URL.
 #[cfg(feature = "hydrate")]
-async fn resize_to_data_url(blob_url: &str) -> Result<String, String> {
+pub(crate) async fn resize_to_data_url(blob_url: &str) -> Result<String, String> {
     use wasm_bindgen::JsCast;
     use wasm_bindgen_futures::JsFuture;
 
@@ -1,5 +1,6 @@
-use crate::components::event_types::get_event_info;
+use crate::components::event_types::lookup_event_type;
 use crate::orchid::LogEntry;
+use crate::thumbnails::{thumbnail_url, ThumbSize};
 use chrono::Local;
 use leptos::prelude::*;
 
@@ -8,8 +9,16 @@ const GALLERY_GRID: &str = "grid grid-cols-2 gap-2 sm:grid-cols-3";
 /// Photo-only view of an orchid's log entries, displayed as a
 /// chronological filmstrip grid with a full-screen lightbox
 /// supporting prev/next navigation and side-by-side compare.
+/// Unless `read_only`, the lightbox can also make a photo the plant's cover.
 #[component]
-pub fn PhotoGallery(entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
+pub fn PhotoGallery(
+    entries: ReadSignal<Vec<LogEntry>>,
+    /// The plant's chosen cover photo, badged in the grid.
+    cover_image: Signal<Option<String>>,
+    #[prop(optional)] read_only: bool,
+    /// Called with the new cover photo, or None to go back to the latest photo.
+    on_set_cover: impl Fn(Option<String>) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
     // Active lightbox index (None = closed)
     let (lightbox_idx, set_lightbox_idx) = signal(Option::<usize>::None);
     // Compare mode: show current vs. a second photo side-by-side
@@ -34,6 +43,7 @@ pub fn PhotoGallery(entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
                 }
 
                 let photo_count = photos.len();
+                let cover = cover_image.get();
                 view! {
                     <div class="mb-2 text-xs text-stone-500 dark:text-stone-400">
                         {format!("{} photo{}", photo_count, if photo_count == 1 { "" } else { "s" })}
@@ -41,21 +51,25 @@ pub fn PhotoGallery(entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
                     <div class=GALLERY_GRID>
                         {photos.into_iter().map(|(orig_idx, entry)| {
                             let filename = entry.image_filename.clone().unwrap_or_default();
-                            let info = entry.event_type.as_deref().and_then(get_event_info);
-                            let badge = info.map(|i| format!("{} {}", i.emoji, i.label));
-                            let badge_class = info.map(|i| format!("{} {}", i.bg_class, i.color_class));
+                            let info = entry.event_type.as_deref().and_then(lookup_event_type);
+                            let badge = info.as_ref().map(|i| format!("{} {}", i.emoji, i.label));
+                            let badge_class = info.as_ref().map(|i| format!("{} {}", i.bg_class(), i.color_class()));
                             let ts = entry.timestamp.with_timezone(&Local).format("%b %d").to_string();
+                            let is_cover = cover.as_deref() == Some(filename.as_str());
                             view! {
                                 <div
                                     class="overflow-hidden relative rounded-lg border transition-colors cursor-pointer aspect-square group border-stone-200 dark:border-stone-700 hover:border-primary-light/40"
                                     on:click=move |_| set_lightbox_idx.set(Some(orig_idx))
                                 >
                                     <img
-                                        src=format!("/images/{}", filename)
+                                        src=thumbnail_url(&filename, ThumbSize::Small)
                                         class="object-cover w-full h-full transition-transform duration-300 group-hover:scale-105"
                                         alt="Growth photo"
                                         loading="lazy"
                                     />
+                                    {is_cover.then(|| view! {
+                                        <span class="absolute top-1.5 left-1.5 py-0.5 px-1.5 text-[10px] font-semibold text-white rounded-full bg-primary/90">"\u{2605} Cover"</span>
+                                    })}
                                     <div class="absolute inset-x-0 bottom-0 p-2 bg-gradient-to-t to-transparent from-black/60">
                                         <div class="text-xs font-medium text-white/90">{ts}</div>
                                         {badge.map(|b| {
@@ -96,7 +110,7 @@ pub fn PhotoGallery(entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
 
             let filename = entry.image_filename.clone().unwrap_or_default();
             let note = entry.note.clone();
-            let info = entry.event_type.as_deref().and_then(get_event_info);
+            let info = entry.event_type.as_deref().and_then(lookup_event_type);
             let event_label = info.map(|i| format!("{} {}", i.emoji, i.label));
             let ts_str = entry.timestamp.with_timezone(&Local).format("%B %d, %Y at %H:%M").to_string();
 
@@ -105,6 +119,8 @@ pub fn PhotoGallery(entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
                 photo_entries.iter().find(|(i, _)| *i == ci).map(|(_, e)| e.clone())
             });
             let is_comparing = compare_entry.is_some();
+            let is_cover = cover_image.with(|c| c.as_deref() == Some(filename.as_str()));
+            let cover_target = (!is_cover).then(|| filename.clone());
 
             view! {
                 <GalleryLightbox
@@ -116,6 +132,9 @@ pub fn PhotoGallery(entries: ReadSignal<Vec<LogEntry>>) -> impl IntoView {
                     can_next=can_next
                     is_comparing=is_comparing
                     compare_entry=compare_entry
+                    is_cover=is_cover
+                    can_set_cover=!read_only
+                    on_cover=move || on_set_cover(cover_target.clone())
                     on_close=move || {
                         set_lightbox_idx.set(None);
                         set_compare_idx.set(None);
@@ -158,6 +177,9 @@ fn GalleryLightbox(
     can_next: bool,
     is_comparing: bool,
     compare_entry: Option<LogEntry>,
+    is_cover: bool,
+    can_set_cover: bool,
+    on_cover: impl Fn() + 'static + Clone + Send + Sync,
     on_close: impl Fn() + 'static + Clone + Send + Sync,
     on_prev: impl Fn() + 'static + Clone + Send + Sync,
     on_next: impl Fn() + 'static + Clone + Send + Sync,
@@ -170,7 +192,7 @@ fn GalleryLightbox(
             class="flex fixed inset-0 flex-col justify-center items-center cursor-pointer z-[2000] bg-black/90 animate-fade-in"
             on:click=move |_| on_close()
         >
-            // Top bar: close + compare toggle
+            // Top bar: close + compare toggle + cover action
             <div
                 class="flex absolute top-0 right-0 left-0 gap-3 justify-between items-center py-3 px-4 z-[2010]"
                 on:click=move |ev: leptos::ev::MouseEvent| ev.stop_propagation()
@@ -187,6 +209,17 @@ fn GalleryLightbox(
                             </button>
                         }
                     })}
+                    {can_set_cover.then(|| {
+                        let oc = on_cover.clone();
+                        view! {
+                            <button
+                                class="py-1.5 px-3 text-xs font-medium rounded-lg border transition-colors cursor-pointer hover:text-white text-white/80 border-white/20 bg-white/10 hover:bg-white/20"
+                                on:click=move |_| oc()
+                            >
+                                {if is_cover { "Remove Cover" } else { "Set as Cover" }}
+                            </button>
+                        }
+                    })}
                 </div>
                 <button
                     class="text-2xl bg-transparent border-none cursor-pointer hover:text-white text-white/70"
@@ -241,7 +274,7 @@ fn GalleryLightbox(
                         <div class="flex gap-3 w-full">
                             <div class="flex-1 text-center">
                                 <img
-                                    src=format!("/images/{}", compare_fname)
+                                    src=thumbnail_url(&compare_fname, ThumbSize::Medium)
                                     class="object-contain mx-auto rounded-lg max-h-[65vh]"
                                     alt="Earlier photo"
                                 />
@@ -250,7 +283,7 @@ fn GalleryLightbox(
                             <div class="self-stretch w-px bg-white/20"></div>
                             <div class="flex-1 text-center">
                                 <img
-                                    src=format!("/images/{}", fname)
+                                    src=thumbnail_url(&fname, ThumbSize::Medium)
                                     class="object-contain mx-auto rounded-lg max-h-[65vh]"
                                     alt="Current photo"
                                 />
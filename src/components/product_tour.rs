@@ -0,0 +1,213 @@
+use leptos::prelude::*;
+use crate::tour::{TourFacts, TourProgress, TourStep};
+use super::{BTN_PRIMARY, BTN_SECONDARY};
+
+/// Width of the hint card in px, used to keep it on screen.
+const HINT_WIDTH_PX: f64 = 320.0;
+/// Room the hint needs below its anchor before it flips above it.
+const HINT_HEIGHT_PX: f64 = 220.0;
+/// Gap between the anchor and the hint, and the minimum margin to the viewport edge.
+const HINT_GAP_PX: f64 = 10.0;
+
+const HINT_CARD: &str = "fixed z-[900] p-4 rounded-2xl border shadow-2xl bg-surface border-primary/30 animate-fade-in dark:border-primary-light/30";
+const HINT_DOCKED: &str = "fixed right-4 bottom-4 z-[900] p-4 rounded-2xl border shadow-2xl bg-surface border-primary/30 animate-fade-in dark:border-primary-light/30";
+
+/// Where the current step's anchor element sits in the viewport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AnchorRect {
+    top: f64,
+    left: f64,
+    width: f64,
+    height: f64,
+    viewport_width: f64,
+    viewport_height: f64,
+}
+
+impl AnchorRect {
+    /// Inline style placing the hint below the anchor, or above it near the bottom of the screen.
+    fn hint_style(&self) -> String {
+        let left = self.left.min(self.viewport_width - HINT_WIDTH_PX - HINT_GAP_PX).max(HINT_GAP_PX);
+        let below = self.top + self.height + HINT_GAP_PX;
+        if below + HINT_HEIGHT_PX <= self.viewport_height {
+            format!("top: {}px; left: {}px; width: {}px", below, left, HINT_WIDTH_PX)
+        } else {
+            let bottom = self.viewport_height - self.top + HINT_GAP_PX;
+            format!("bottom: {}px; left: {}px; width: {}px", bottom, left, HINT_WIDTH_PX)
+        }
+    }
+
+    /// Inline style for the highlight ring drawn around the anchor.
+    fn ring_style(&self) -> String {
+        format!(
+            "top: {}px; left: {}px; width: {}px; height: {}px",
+            self.top - 4.0, self.left - 4.0, self.width + 8.0, self.height + 8.0,
+        )
+    }
+}
+
+/// Finds the first visible element tagged `data-tour="{anchor}"` and measures it.
+#[cfg(feature = "hydrate")]
+fn measure_anchor(anchor: &str) -> Option<AnchorRect> {
+    let window = web_sys::window()?;
+    let document = window.document()?;
+    let nodes = document.query_selector_all(&format!("[data-tour=\"{}\"]", anchor)).ok()?;
+    let viewport_width = window.inner_width().ok()?.as_f64()?;
+    let viewport_height = window.inner_height().ok()?.as_f64()?;
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|n| wasm_bindgen::JsCast::dyn_into::<web_sys::Element>(n).ok())
+        .map(|el| el.get_bounding_client_rect())
+        .find(|r| r.width() > 0.0 && r.height() > 0.0)
+        .map(|r| AnchorRect {
+            top: r.top(),
+            left: r.left(),
+            width: r.width(),
+            height: r.height(),
+            viewport_width,
+            viewport_height,
+        })
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn measure_anchor(_anchor: &str) -> Option<AnchorRect> {
+    None
+}
+
+/// The getting-started tour: a hint anchored to the control for the next step, with progress saved per user.
+///
+/// Steps the user has already done (by `facts`) are skipped; when the anchor isn't on screen the hint docks to the corner.
+#[component]
+pub fn ProductTour(facts: Memo<TourFacts>) -> impl IntoView {
+    let saved = Resource::new(|| (), |_| crate::server_fns::preferences::get_tour_progress());
+    let progress = RwSignal::new(None::<TourProgress>);
+    Effect::new(move |_| {
+        if let Some(Ok(p)) = saved.get() {
+            progress.set(Some(p));
+        }
+    });
+
+    let current = Memo::new(move |_| {
+        progress.with(|p| p.as_ref().and_then(|p| p.current_step(&facts.get())))
+    });
+    let (anchor_rect, set_anchor_rect) = signal(None::<AnchorRect>);
+    let remeasure = move || {
+        set_anchor_rect.set(current.get_untracked().and_then(|step| measure_anchor(step.anchor())));
+    };
+
+    // Re-measure when the step changes (after the DOM settles), and on scroll or resize.
+    Effect::new(move |_| {
+        let _ = current.get();
+        #[cfg(feature = "hydrate")]
+        request_animation_frame(remeasure);
+        #[cfg(not(feature = "hydrate"))]
+        remeasure();
+    });
+    #[cfg(feature = "hydrate")]
+    {
+        let on_scroll = window_event_listener(leptos::ev::scroll, move |_| remeasure());
+        let on_resize = window_event_listener(leptos::ev::resize, move |_| remeasure());
+        on_cleanup(move || {
+            on_scroll.remove();
+            on_resize.remove();
+        });
+    }
+
+    let persist = move |p: TourProgress| {
+        progress.set(Some(p.clone()));
+        leptos::task::spawn_local(async move {
+            if let Err(_e) = crate::server_fns::preferences::save_tour_progress(p).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_warn("tour.save", &format!("Failed to save tour progress: {}", _e), &[]);
+            }
+        });
+    };
+    let skip_step = move |_| {
+        let (Some(mut p), Some(step)) = (progress.get_untracked(), current.get_untracked()) else { return };
+        p.complete(step, &facts.get_untracked());
+        #[cfg(feature = "hydrate")]
+        crate::server_fns::telemetry::emit_info("tour.step_skipped", "Tour step skipped", &[("step", step.as_str())]);
+        persist(p);
+    };
+    let end_tour = move |_| {
+        let Some(mut p) = progress.get_untracked() else { return };
+        p.dismiss();
+        #[cfg(feature = "hydrate")]
+        crate::server_fns::telemetry::emit_info("tour.dismissed", "Tour dismissed", &[]);
+        persist(p);
+    };
+
+    view! {
+        {move || current.get().map(|step| {
+            let done = progress.with(|p| p.as_ref().map(|p| p.done_count(&facts.get())).unwrap_or_default());
+            let rect = anchor_rect.get();
+            let is_last = progress.with(|p| p.as_ref().is_some_and(|p| {
+                TourStep::ALL.iter().filter(|s| !p.step_done(**s, &facts.get())).count() == 1
+            }));
+            view! {
+                {rect.map(|r| view! {
+                    <div
+                        class="fixed rounded-xl ring-2 ring-offset-2 pointer-events-none z-[899] ring-primary dark:ring-primary-light dark:ring-offset-stone-900"
+                        style=r.ring_style()
+                    ></div>
+                })}
+                <div
+                    role="dialog"
+                    aria-label="Getting started"
+                    class=if rect.is_some() { HINT_CARD } else { HINT_DOCKED }
+                    style=rect.map(|r| r.hint_style()).unwrap_or_else(|| format!("width: {}px", HINT_WIDTH_PX))
+                >
+                    <div class="flex gap-2 justify-between items-baseline mb-1">
+                        <span class="text-[10px] font-bold tracking-widest uppercase text-primary dark:text-primary-light">
+                            {format!("Getting started \u{00B7} {} of {}", done + 1, TourStep::ALL.len())}
+                        </span>
+                        <button
+                            class="p-0 text-sm bg-transparent border-none cursor-pointer text-stone-400 hover:text-stone-600 dark:hover:text-stone-200"
+                            aria-label="End tour"
+                            on:click=end_tour
+                        >
+                            "\u{00D7}"
+                        </button>
+                    </div>
+                    <h3 class="mt-0 mb-1 text-base font-semibold text-stone-800 dark:text-stone-100">{step.title()}</h3>
+                    <p class="mt-0 mb-3 text-sm leading-relaxed text-stone-600 dark:text-stone-400">{step.body()}</p>
+                    <ol class="flex gap-1.5 p-0 mt-0 mb-3 list-none" aria-hidden="true">
+                        {TourStep::ALL.into_iter().map(|s| {
+                            let is_done = progress.with(|p| p.as_ref().is_some_and(|p| p.step_done(s, &facts.get())));
+                            let class = if s == step {
+                                "flex-1 h-1 rounded-full bg-primary dark:bg-primary-light"
+                            } else if is_done {
+                                "flex-1 h-1 rounded-full bg-primary/40"
+                            } else {
+                                "flex-1 h-1 rounded-full bg-stone-200 dark:bg-stone-700"
+                            };
+                            view! { <li class=class title=s.title()></li> }
+                        }).collect::<Vec<_>>()}
+                    </ol>
+                    <div class="flex gap-2 justify-end">
+                        <button class=BTN_SECONDARY on:click=end_tour>"End tour"</button>
+                        <button class=BTN_PRIMARY on:click=skip_step>{if is_last { "Finish" } else { "Skip step" }}</button>
+                    </div>
+                </div>
+            }
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(top: f64, left: f64) -> AnchorRect {
+        AnchorRect { top, left, width: 80.0, height: 32.0, viewport_width: 1024.0, viewport_height: 768.0 }
+    }
+
+    #[test]
+    fn test_hint_sits_below_anchor_with_room() {
+        assert_eq!(rect(100.0, 200.0).hint_style(), "top: 142px; left: 200px; width: 320px");
+    }
+
+    #[test]
+    fn test_hint_flips_above_anchor_near_bottom_and_stays_on_screen() {
+        assert_eq!(rect(700.0, 1000.0).hint_style(), "bottom: 78px; left: 694px; width: 320px");
+    }
+}
@@ -1,7 +1,84 @@
 use leptos::prelude::*;
 use std::collections::HashMap;
-use crate::orchid::{Orchid, LogEntry};
-use crate::components::event_types::quick_action_types;
+use crate::orchid::{Orchid, LogEntry, QuickActionItem, WateringMethod, MAX_WATER_VOLUME_ML};
+use crate::components::event_types::{lookup_event_type, quick_action_types, registered_event_types};
+
+/// Upper bound on buttons in the quick log row.
+pub const MAX_QUICK_ACTIONS: usize = 12;
+const MAX_LABEL_LEN: usize = 24;
+const MAX_EMOJI_LEN: usize = 8;
+const MAX_NOTE_LEN: usize = 200;
+
+/// The built-in quick log row, used until the user customizes it.
+pub fn default_quick_actions() -> Vec<QuickActionItem> {
+    quick_action_types()
+        .map(|et| QuickActionItem {
+            event_type: et.key.to_string(),
+            label: None,
+            emoji: None,
+            note: None,
+            watering_method: None,
+        })
+        .collect()
+}
+
+/// The reservoir top-up shortcut added to the quick log row of semi-hydro plants.
+pub fn reservoir_quick_action() -> QuickActionItem {
+    QuickActionItem {
+        event_type: "ToppedUp".to_string(),
+        label: None,
+        emoji: None,
+        note: Some("Reservoir topped up".to_string()),
+        watering_method: None,
+    }
+}
+
+/// Validate and normalize a user-supplied quick action list: trims text,
+/// drops empty overrides and watering methods on non-watering shortcuts, and rejects
+/// event types not in `known_keys` or oversized input.
+pub fn sanitize_quick_actions(items: Vec<QuickActionItem>, known_keys: &[String]) -> Result<Vec<QuickActionItem>, String> {
+    if items.len() > MAX_QUICK_ACTIONS {
+        return Err(format!("At most {} quick actions are allowed", MAX_QUICK_ACTIONS));
+    }
+
+    let clean = |v: Option<String>, max: usize, what: &str| -> Result<Option<String>, String> {
+        match v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty()) {
+            Some(s) if s.chars().count() > max => {
+                Err(format!("Quick action {} must be at most {} characters", what, max))
+            }
+            other => Ok(other),
+        }
+    };
+
+    items
+        .into_iter()
+        .map(|item| {
+            if !known_keys.contains(&item.event_type) {
+                return Err(format!("Unknown event type: {}", item.event_type));
+            }
+            let watering_method = item.watering_method.filter(|_| item.event_type == "Watered");
+            Ok(QuickActionItem {
+                event_type: item.event_type,
+                label: clean(item.label, MAX_LABEL_LEN, "label")?,
+                emoji: clean(item.emoji, MAX_EMOJI_LEN, "emoji")?,
+                note: clean(item.note, MAX_NOTE_LEN, "note")?,
+                watering_method,
+            })
+        })
+        .collect()
+}
+
+/// Display label and emoji for a quick action, falling back to its event type.
+pub fn quick_action_display(item: &QuickActionItem) -> (String, String) {
+    let info = lookup_event_type(&item.event_type);
+    let label = item.label.clone()
+        .or_else(|| info.as_ref().map(|i| i.label.clone()))
+        .unwrap_or_else(|| item.event_type.clone());
+    let emoji = item.emoji.clone()
+        .or_else(|| info.as_ref().map(|i| i.emoji.clone()))
+        .unwrap_or_default();
+    (label, emoji)
+}
 
 #[derive(Clone, Copy, PartialEq)]
 enum BtnState {
@@ -17,64 +94,79 @@ pub fn QuickActions(
     set_log_entries: WriteSignal<Vec<LogEntry>>,
     set_show_first_bloom: WriteSignal<bool>,
 ) -> impl IntoView {
-    let btn_states = RwSignal::new(HashMap::<&'static str, BtnState>::new());
+    let actions_resource = Resource::new(|| (), |_| crate::server_fns::preferences::get_quick_actions());
+    let btn_states = RwSignal::new(HashMap::<usize, BtnState>::new());
+    // Method and volume for the next "Watered" tap; no method means the plant's default
+    let (water_method, set_water_method) = signal(None::<WateringMethod>);
+    let (water_volume, set_water_volume) = signal(String::new());
 
-    let buttons = quick_action_types().map(|et| {
-        let key = et.key;
-        let emoji = et.emoji;
-        let label = et.label;
-        let bg = et.bg_class;
-        let color = et.color_class;
+    let render_button = move |idx: usize, item: QuickActionItem| {
+        let (label, emoji) = quick_action_display(&item);
+        let info = lookup_event_type(&item.event_type);
+        let bg = info.as_ref().map(|i| i.bg_class()).unwrap_or("bg-stone-100 dark:bg-stone-800");
+        let color = info.as_ref().map(|i| i.color_class()).unwrap_or("text-stone-600 dark:text-stone-400");
+        let label = StoredValue::new(label);
+        let item = StoredValue::new(item);
 
         let state = Memo::new(move |_| {
-            btn_states.with(|m| m.get(key).copied().unwrap_or(BtnState::Idle))
+            btn_states.with(|m| m.get(&idx).copied().unwrap_or(BtnState::Idle))
         });
 
         let on_click = move |_: leptos::ev::MouseEvent| {
             if state.get() != BtnState::Idle {
                 return;
             }
-            btn_states.update(|m| { m.insert(key, BtnState::Loading); });
-            let orchid_id = orchid_signal.get().id.clone();
-            let event_key = key.to_string();
+            btn_states.update(|m| { m.insert(idx, BtnState::Loading); });
+            let orchid = orchid_signal.get();
+            let QuickActionItem { event_type, note, watering_method, .. } = item.get_value();
+            let is_watering = event_type == "Watered";
+            let method = watering_method.or(water_method.get_untracked()).filter(|_| is_watering);
+            let volume = water_volume.get_untracked().trim().parse::<u32>().ok().filter(|_| is_watering);
+            let resets_schedule = WateringMethod::resets_schedule(method, orchid.default_watering_method);
 
             leptos::task::spawn_local(async move {
                 match crate::server_fns::orchids::add_log_entry(
-                    orchid_id,
-                    String::new(),
+                    orchid.id,
+                    note.unwrap_or_default(),
                     None,
-                    Some(event_key),
+                    Some(event_type.clone()),
+                    method,
+                    volume,
                 ).await {
                     Ok(response) => {
                         if response.is_first_bloom {
                             set_show_first_bloom.set(true);
                         }
                         let now = chrono::Utc::now();
-                        match key {
-                            "Watered" => set_orchid_signal.update(|o| o.last_watered_at = Some(now)),
+                        match event_type.as_str() {
+                            "Watered" if resets_schedule => set_orchid_signal.update(|o| o.last_watered_at = Some(now)),
                             "Fertilized" => set_orchid_signal.update(|o| o.last_fertilized_at = Some(now)),
                             "Repotted" => set_orchid_signal.update(|o| o.last_repotted_at = Some(now)),
+                            "ToppedUp" => set_orchid_signal.update(|o| o.last_topped_up_at = Some(now)),
                             _ => {}
                         }
+                        if is_watering {
+                            set_water_volume.set(String::new());
+                        }
                         set_log_entries.update(|entries| entries.insert(0, response.entry));
-                        btn_states.update(|m| { m.insert(key, BtnState::Done); });
+                        btn_states.update(|m| { m.insert(idx, BtnState::Done); });
 
                         // Reset to idle after 1.5s
                         #[cfg(feature = "hydrate")]
                         {
                             gloo_timers::future::TimeoutFuture::new(1_500).await;
-                            btn_states.update(|m| { m.insert(key, BtnState::Idle); });
+                            btn_states.update(|m| { m.insert(idx, BtnState::Idle); });
                         }
                         #[cfg(not(feature = "hydrate"))]
                         {
-                            btn_states.update(|m| { m.insert(key, BtnState::Idle); });
+                            btn_states.update(|m| { m.insert(idx, BtnState::Idle); });
                         }
                     }
                     Err(e) => {
-                        tracing::error!("Quick action '{}' failed: {}", key, e);
+                        tracing::error!("Quick action '{}' failed: {}", event_type, e);
                         #[cfg(feature = "hydrate")]
-                        crate::server_fns::telemetry::emit_error("quick_actions.log_event", &format!("Quick action '{}' failed: {}", key, e), &[("action", key)]);
-                        btn_states.update(|m| { m.insert(key, BtnState::Idle); });
+                        crate::server_fns::telemetry::emit_error("quick_actions.log_event", &format!("Quick action '{}' failed: {}", event_type, e), &[("action", event_type.as_str())]);
+                        btn_states.update(|m| { m.insert(idx, BtnState::Idle); });
                     }
                 }
             });
@@ -101,19 +193,324 @@ pub fn QuickActions(
             >
                 <span>{emoji}</span>
                 <span>{move || match state.get() {
-                    BtnState::Done => "\u{2713}",
-                    _ => label,
+                    BtnState::Done => "\u{2713}".to_string(),
+                    _ => label.get_value(),
                 }}</span>
             </button>
         }
-    }).collect::<Vec<_>>();
+    };
 
     view! {
         <div class="mb-4">
             <h4 class="mt-0 mb-2 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400">"Quick Log"</h4>
             <div class="flex flex-wrap gap-2">
-                {buttons}
+                <Suspense fallback=|| ()>
+                    {move || {
+                        let mut actions = actions_resource.get()
+                            .and_then(|r| r.ok())
+                            .unwrap_or_else(default_quick_actions);
+                        if orchid_signal.with(|o| o.is_semi_hydro()) && !actions.iter().any(|a| a.event_type == "ToppedUp") {
+                            actions.push(reservoir_quick_action());
+                        }
+                        actions.into_iter().enumerate()
+                            .map(|(idx, item)| render_button(idx, item))
+                            .collect::<Vec<_>>()
+                    }}
+                </Suspense>
+            </div>
+            // How the next "Watered" tap waters, so a misting isn't logged as a soak
+            <div class="flex flex-wrap gap-2 items-center mt-2 text-xs text-stone-500 dark:text-stone-400">
+                <span>"Watering:"</span>
+                <select
+                    class="py-1 px-2 text-xs rounded-md border border-stone-300/50 bg-white/80 dark:bg-stone-800/80 dark:border-stone-600/50"
+                    on:change=move |ev| set_water_method.set(WateringMethod::parse(&event_target_value(&ev)))
+                >
+                    <option value="" selected=move || water_method.get().is_none()>
+                        {move || match orchid_signal.get().default_watering_method {
+                            Some(m) => format!("Usual ({})", m.label()),
+                            None => "Usual".to_string(),
+                        }}
+                    </option>
+                    {WateringMethod::ALL.into_iter().map(|m| view! {
+                        <option value=m.as_str() selected=move || water_method.get() == Some(m)>{m.label()}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <input
+                    type="number"
+                    min="1"
+                    max=MAX_WATER_VOLUME_ML.to_string()
+                    class="py-1 px-2 w-24 text-xs rounded-md border border-stone-300/50 bg-white/80 dark:bg-stone-800/80 dark:border-stone-600/50"
+                    placeholder="ml (optional)"
+                    prop:value=water_volume
+                    on:input=move |ev| set_water_volume.set(event_target_value(&ev))
+                />
             </div>
         </div>
     }.into_any()
 }
+
+const INPUT_SM: &str = "w-full px-3 py-2 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-500 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:placeholder:text-stone-400 dark:focus:bg-stone-800 dark:focus:border-primary-light/40 dark:focus:ring-primary-light/10";
+const LABEL_SM: &str = "block mb-1 text-xs font-semibold tracking-wider uppercase text-stone-600 dark:text-stone-400";
+const BTN_ICON: &str = "py-1 px-2 text-xs rounded-md border-none cursor-pointer transition-colors text-stone-500 bg-transparent hover:bg-stone-100 dark:text-stone-400 dark:hover:bg-stone-800 disabled:opacity-30 disabled:cursor-default";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Settings editor for the quick log row: reorder, remove, and add built-in
+/// or custom shortcuts, then save to preferences.
+#[component]
+pub fn QuickActionsEditor() -> impl IntoView {
+    let actions = RwSignal::new(Vec::<QuickActionItem>::new());
+    let (is_saving, set_is_saving) = signal(false);
+    let (status, set_status) = signal(None::<Result<String, String>>);
+
+    // Custom shortcut form
+    let (custom_label, set_custom_label) = signal(String::new());
+    let (custom_emoji, set_custom_emoji) = signal(String::new());
+    let (custom_type, set_custom_type) = signal("Note".to_string());
+    let (custom_method, set_custom_method) = signal(None::<WateringMethod>);
+
+    let loaded = Resource::new(|| (), |_| crate::server_fns::preferences::get_quick_actions());
+    Effect::new(move |_| {
+        if let Some(Ok(list)) = loaded.get() {
+            actions.set(list);
+        }
+    });
+
+    let move_item = move |idx: usize, up: bool| {
+        actions.update(|list| {
+            let target = if up { idx.checked_sub(1) } else { Some(idx + 1) };
+            if let Some(t) = target.filter(|t| *t < list.len()) {
+                list.swap(idx, t);
+            }
+        });
+    };
+
+    let add_custom = move |_: leptos::ev::MouseEvent| {
+        let label = custom_label.get().trim().to_string();
+        if label.is_empty() {
+            set_status.set(Some(Err("Give the shortcut a label".into())));
+            return;
+        }
+        let emoji = custom_emoji.get().trim().to_string();
+        actions.update(|list| list.push(QuickActionItem {
+            event_type: custom_type.get_untracked(),
+            label: Some(label.clone()),
+            emoji: (!emoji.is_empty()).then_some(emoji),
+            note: Some(label),
+            watering_method: custom_method.get_untracked().filter(|_| custom_type.get_untracked() == "Watered"),
+        }));
+        set_custom_label.set(String::new());
+        set_custom_emoji.set(String::new());
+        set_status.set(None);
+    };
+
+    let save = move |_| {
+        set_is_saving.set(true);
+        set_status.set(None);
+        let list = actions.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::preferences::save_quick_actions(list).await {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.save_quick_actions", "Quick actions saved", &[]);
+                    set_status.set(Some(Ok("Quick actions saved".into())));
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.save_quick_actions", &format!("Failed to save quick actions: {}", e), &[]);
+                    set_status.set(Some(Err(format!("Save failed: {}", e))));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-1 mb-3">
+            {move || {
+                let list = actions.get();
+                let len = list.len();
+                list.into_iter().enumerate().map(|(idx, item)| {
+                    let (label, emoji) = quick_action_display(&item);
+                    let is_custom = item.label.is_some() || item.note.is_some();
+                    view! {
+                        <div class="flex gap-2 items-center py-1.5 px-3 rounded-lg bg-secondary/30">
+                            <span>{emoji}</span>
+                            <span class="flex-1 text-sm text-stone-700 dark:text-stone-300">{label}</span>
+                            {is_custom.then(|| view! {
+                                <span class="text-[10px] uppercase tracking-wider text-stone-400">"Custom"</span>
+                            })}
+                            <button class=BTN_ICON disabled={idx == 0} on:click=move |_| move_item(idx, true) title="Move up">"\u{2191}"</button>
+                            <button class=BTN_ICON disabled={idx + 1 == len} on:click=move |_| move_item(idx, false) title="Move down">"\u{2193}"</button>
+                            <button class=BTN_ICON on:click=move |_| actions.update(|l| { l.remove(idx); }) title="Remove">"\u{2715}"</button>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()
+            }}
+        </div>
+
+        // Re-add built-ins that were removed
+        {move || {
+            let current = actions.get();
+            let missing: Vec<_> = quick_action_types()
+                .filter(|et| !current.iter().any(|a| a.event_type == et.key && a.label.is_none()))
+                .collect();
+            (!missing.is_empty()).then(|| view! {
+                <div class="flex flex-wrap gap-1.5 mb-3">
+                    {missing.into_iter().map(|et| view! {
+                        <button
+                            class=format!("{} {} {}", BTN_SM, et.bg_class, et.color_class)
+                            on:click=move |_| actions.update(|l| l.push(QuickActionItem {
+                                event_type: et.key.to_string(),
+                                label: None,
+                                emoji: None,
+                                note: None,
+                                watering_method: None,
+                            }))
+                        >
+                            {format!("+ {} {}", et.emoji, et.label)}
+                        </button>
+                    }).collect::<Vec<_>>()}
+                </div>
+            })
+        }}
+
+        // Custom shortcut (e.g. "Misted", "Staked")
+        <div class="flex flex-wrap gap-2 items-end mb-3">
+            <div class="flex-1 min-w-[120px]">
+                <label class=LABEL_SM>"Custom Shortcut"</label>
+                <input type="text" class=INPUT_SM
+                    placeholder="e.g. Misted"
+                    prop:value=custom_label
+                    on:input=move |ev| set_custom_label.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="w-16">
+                <label class=LABEL_SM>"Icon"</label>
+                <input type="text" class=INPUT_SM
+                    placeholder="\u{1F4A6}"
+                    prop:value=custom_emoji
+                    on:input=move |ev| set_custom_emoji.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="w-36">
+                <label class=LABEL_SM>"Logs As"</label>
+                <select class=INPUT_SM
+                    prop:value=custom_type
+                    on:change=move |ev| set_custom_type.set(event_target_value(&ev))
+                >
+                    {registered_event_types().into_iter().map(|et| view! {
+                        <option value=et.key>{et.label}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+            </div>
+            {move || (custom_type.get() == "Watered").then(|| view! {
+                <div class="w-28">
+                    <label class=LABEL_SM>"Method"</label>
+                    <select class=INPUT_SM on:change=move |ev| set_custom_method.set(WateringMethod::parse(&event_target_value(&ev)))>
+                        <option value="">"Usual"</option>
+                        {WateringMethod::ALL.into_iter().map(|m| view! {
+                            <option value=m.as_str() selected=move || custom_method.get() == Some(m)>{m.label()}</option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </div>
+            })}
+            <button
+                class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                disabled=move || { actions.get().len() >= MAX_QUICK_ACTIONS }
+                on:click=add_custom
+            >
+                "Add"
+            </button>
+        </div>
+
+        <div class="flex gap-2 items-center">
+            <button
+                class=format!("{} text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                disabled=move || is_saving.get()
+                on:click=save
+            >
+                {move || if is_saving.get() { "Saving..." } else { "Save Quick Actions" }}
+            </button>
+            <button
+                class=format!("{} text-stone-600 bg-stone-100 dark:text-stone-300 dark:bg-stone-800", BTN_SM)
+                on:click=move |_| actions.set(default_quick_actions())
+            >
+                "Reset to Defaults"
+            </button>
+            {move || status.get().map(|r| match r {
+                Ok(msg) => view! { <span class="text-xs text-emerald-600 dark:text-emerald-400">{msg}</span> }.into_any(),
+                Err(msg) => view! { <span class="text-xs text-red-600 dark:text-red-400">{msg}</span> }.into_any(),
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known() -> Vec<String> {
+        vec!["Watered".into(), "Note".into(), "Pollinated".into()]
+    }
+
+    fn item(event_type: &str, label: Option<&str>) -> QuickActionItem {
+        QuickActionItem {
+            event_type: event_type.into(),
+            label: label.map(String::from),
+            emoji: None,
+            note: label.map(String::from),
+            watering_method: None,
+        }
+    }
+
+    #[test]
+    fn test_default_quick_actions_match_builtins() {
+        let defaults = default_quick_actions();
+        assert_eq!(defaults.len(), quick_action_types().count());
+        assert!(defaults.iter().all(|a| a.label.is_none() && a.note.is_none()));
+    }
+
+    #[test]
+    fn test_sanitize_rejects_unknown_event_type() {
+        assert!(sanitize_quick_actions(vec![item("Danced", None)], &known()).is_err());
+    }
+
+    #[test]
+    fn test_sanitize_accepts_custom_event_type() {
+        assert!(sanitize_quick_actions(vec![item("Pollinated", None)], &known()).is_ok());
+    }
+
+    #[test]
+    fn test_sanitize_rejects_too_many() {
+        let items = vec![item("Watered", None); MAX_QUICK_ACTIONS + 1];
+        assert!(sanitize_quick_actions(items, &known()).is_err());
+    }
+
+    #[test]
+    fn test_sanitize_trims_and_drops_empty_overrides() {
+        let mut misted = item("Note", Some("  Misted "));
+        misted.emoji = Some("   ".into());
+        let out = sanitize_quick_actions(vec![misted], &known()).unwrap();
+        assert_eq!(out[0].label.as_deref(), Some("Misted"));
+        assert_eq!(out[0].emoji, None);
+    }
+
+    #[test]
+    fn test_display_falls_back_to_event_type() {
+        let (label, emoji) = quick_action_display(&item("Watered", None));
+        assert_eq!(label, "Watered");
+        assert!(!emoji.is_empty());
+
+        let (label, _) = quick_action_display(&item("Note", Some("Staked")));
+        assert_eq!(label, "Staked");
+    }
+
+    #[test]
+    fn test_sanitize_keeps_watering_method_only_on_watered() {
+        let misted = QuickActionItem { watering_method: Some(WateringMethod::Mist), ..item("Watered", Some("Misted")) };
+        let noted = QuickActionItem { watering_method: Some(WateringMethod::Mist), ..item("Note", Some("Misted")) };
+        let out = sanitize_quick_actions(vec![misted, noted], &known()).unwrap();
+        assert_eq!(out[0].watering_method, Some(WateringMethod::Mist));
+        assert_eq!(out[1].watering_method, None);
+    }
+}
@@ -0,0 +1,228 @@
+use chrono::{Duration, NaiveDate, Utc};
+use leptos::prelude::*;
+use crate::components::browser_utc_offset_minutes;
+use crate::orchid::{Orchid, Recurrence, RecurrenceUnit, Reminder, MAX_RECURRENCE_EVERY};
+
+const INPUT_SM: &str = "w-full px-3 py-2 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-500 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:placeholder:text-stone-400 dark:focus:bg-stone-800 dark:focus:border-primary-light/40 dark:focus:ring-primary-light/10";
+const LABEL_SM: &str = "block mb-1 text-xs font-semibold tracking-wider uppercase text-stone-600 dark:text-stone-400";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Local hour a reminder picked by date becomes due.
+const DUE_HOUR: u32 = 9;
+
+/// The browser's local date, `days` from today.
+fn local_date_in(days: i64) -> NaiveDate {
+    (Utc::now() + Duration::minutes(browser_utc_offset_minutes() as i64) + Duration::days(days)).date_naive()
+}
+
+/// The moment a reminder picked for `date` becomes due: `DUE_HOUR` in the browser's time zone.
+fn due_at_for(date: NaiveDate) -> chrono::DateTime<Utc> {
+    let local = date.and_hms_opt(DUE_HOUR, 0, 0).unwrap_or_default();
+    local.and_utc() - Duration::minutes(browser_utc_offset_minutes() as i64)
+}
+
+/// What a reminder is about and how often it repeats, e.g. "Phal Jane · Every 2 weeks".
+pub fn reminder_subtitle(reminder: &Reminder) -> String {
+    reminder.orchid_name.iter()
+        .chain(reminder.zone_name.iter())
+        .cloned()
+        .chain(reminder.recurrence.map(|r| r.label()))
+        .collect::<Vec<_>>()
+        .join(" \u{00B7} ")
+}
+
+/// Collapsible panel below the due follow-ups: upcoming reminders with a remove button, and a
+/// form to schedule one for a plant, a zone, or neither, optionally repeating. Bumps `version`
+/// after every change so the due list reloads too.
+#[component]
+pub fn RemindersPanel(
+    orchids: Memo<Vec<Orchid>>,
+    /// Shared with the due list; incremented whenever reminders change.
+    version: RwSignal<u32>,
+) -> impl IntoView {
+    let (is_open, set_is_open) = signal(false);
+    let upcoming = Resource::new(move || version.get(), |_| crate::server_fns::reminders::get_reminders());
+    let zones = Resource::new(|| (), |_| crate::server_fns::zones::get_zones());
+
+    let (title, set_title) = signal(String::new());
+    let (date, set_date) = signal(local_date_in(7).format("%Y-%m-%d").to_string());
+    let (orchid_id, set_orchid_id) = signal(String::new());
+    let (zone_id, set_zone_id) = signal(String::new());
+    let (repeat_unit, set_repeat_unit) = signal(String::new());
+    let (repeat_every, set_repeat_every) = signal(1u32);
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let save = move |_| {
+        let Ok(day) = NaiveDate::parse_from_str(&date.get(), "%Y-%m-%d") else {
+            set_error_msg.set(Some("Pick a date".into()));
+            return;
+        };
+        let recurrence = RecurrenceUnit::parse(&repeat_unit.get())
+            .map(|unit| Recurrence { every: repeat_every.get().clamp(1, MAX_RECURRENCE_EVERY), unit });
+        let t = title.get();
+        let o = Some(orchid_id.get()).filter(|id| !id.is_empty());
+        let z = Some(zone_id.get()).filter(|id| !id.is_empty());
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::reminders::schedule_reminder(t, o, z, due_at_for(day), recurrence).await {
+                Ok(_) => {
+                    set_title.set(String::new());
+                    version.update(|v| *v += 1);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("reminders.schedule", &format!("Failed to schedule reminder: {}", e), &[]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let remove = move |id: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::reminders::delete_reminder(id.clone()).await {
+                Ok(()) => version.update(|v| *v += 1),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("reminders.delete", &format!("Failed to delete reminder: {}", e), &[("reminder_id", id.as_str())]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-2">
+            <div class="flex justify-between items-center">
+                <h3 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">
+                    "Reminders"
+                    <Transition fallback=|| ()>
+                        {move || upcoming.get().and_then(|r| r.ok()).filter(|list| !list.is_empty()).map(|list| view! {
+                            <span class="ml-1 font-normal text-stone-400">{format!("({} upcoming)", list.len())}</span>
+                        })}
+                    </Transition>
+                </h3>
+                <button
+                    class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                    on:click=move |_| set_is_open.update(|o| *o = !*o)
+                >
+                    {move || if is_open.get() { "Close" } else { "+ Reminder" }}
+                </button>
+            </div>
+
+            <Show when=move || is_open.get()>
+                <div class="p-3 bg-white rounded-xl border dark:bg-stone-800 border-stone-100 dark:border-stone-700">
+                    <label class=LABEL_SM>"Remind me to"</label>
+                    <input type="text" class=format!("{} mb-2", INPUT_SM)
+                        maxlength="200"
+                        placeholder="e.g. Check keiki roots"
+                        prop:value=title
+                        on:input=move |ev| set_title.set(event_target_value(&ev))
+                    />
+                    <div class="flex flex-wrap gap-2 mb-2">
+                        <div class="flex-1 min-w-[130px]">
+                            <label class=LABEL_SM>"On"</label>
+                            <input type="date" class=INPUT_SM
+                                prop:value=date
+                                on:input=move |ev| set_date.set(event_target_value(&ev))
+                            />
+                        </div>
+                        <div class="flex-1 min-w-[130px]">
+                            <label class=LABEL_SM>"Plant"</label>
+                            <select class=INPUT_SM
+                                prop:value=orchid_id
+                                on:change=move |ev| set_orchid_id.set(event_target_value(&ev))
+                            >
+                                <option value="">"Any plant"</option>
+                                {move || orchids.get().into_iter().map(|o| view! {
+                                    <option value=o.id.clone()>{o.name}</option>
+                                }).collect::<Vec<_>>()}
+                            </select>
+                        </div>
+                        <div class="flex-1 min-w-[130px]">
+                            <label class=LABEL_SM>"Zone"</label>
+                            <select class=INPUT_SM
+                                prop:value=zone_id
+                                on:change=move |ev| set_zone_id.set(event_target_value(&ev))
+                            >
+                                <option value="">"Any zone"</option>
+                                <Transition fallback=|| ()>
+                                    {move || zones.get().and_then(|r| r.ok()).unwrap_or_default().into_iter().map(|z| view! {
+                                        <option value=z.id.clone()>{z.name}</option>
+                                    }).collect::<Vec<_>>()}
+                                </Transition>
+                            </select>
+                        </div>
+                    </div>
+                    <div class="flex flex-wrap gap-2 items-end mb-2">
+                        <div class="w-44">
+                            <label class=LABEL_SM>"Repeat"</label>
+                            <select class=INPUT_SM
+                                prop:value=repeat_unit
+                                on:change=move |ev| set_repeat_unit.set(event_target_value(&ev))
+                            >
+                                <option value="">"Doesn't repeat"</option>
+                                {RecurrenceUnit::ALL.into_iter().map(|u| view! {
+                                    <option value=u.as_str()>{format!("Every N {}", u.label(2))}</option>
+                                }).collect::<Vec<_>>()}
+                            </select>
+                        </div>
+                        <Show when=move || !repeat_unit.get().is_empty()>
+                            <div class="w-20">
+                                <label class=LABEL_SM>"N"</label>
+                                <input type="number" class=INPUT_SM
+                                    min="1" max=MAX_RECURRENCE_EVERY.to_string()
+                                    prop:value=move || repeat_every.get().to_string()
+                                    on:input=move |ev| set_repeat_every.set(event_target_value(&ev).parse().unwrap_or(1))
+                                />
+                            </div>
+                        </Show>
+                        <button
+                            class=format!("{} ml-auto text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                            disabled=move || is_saving.get() || title.get().trim().is_empty()
+                            on:click=save
+                        >
+                            {move || if is_saving.get() { "Saving..." } else { "Add Reminder" }}
+                        </button>
+                    </div>
+                    {move || error_msg.get().map(|msg| view! {
+                        <p class="mt-1 mb-2 text-xs text-red-600 dark:text-red-400">{msg}</p>
+                    })}
+
+                    <Transition fallback=|| ()>
+                        {move || upcoming.get().and_then(|r| r.ok()).map(|list| view! {
+                            <ul class="p-0 m-0 list-none">
+                                {list.into_iter().map(|reminder| {
+                                    let id = reminder.id.clone();
+                                    let subtitle = reminder_subtitle(&reminder);
+                                    let when = (reminder.due_at + Duration::minutes(browser_utc_offset_minutes() as i64))
+                                        .format("%b %d").to_string();
+                                    view! {
+                                        <li class="flex gap-2 justify-between items-start py-1.5 border-b last:border-b-0 border-stone-100 dark:border-stone-700">
+                                            <div class="min-w-0">
+                                                <div class="text-sm font-medium text-stone-700 dark:text-stone-200">{reminder.title}</div>
+                                                <div class="text-xs truncate text-stone-400">
+                                                    {if subtitle.is_empty() { when } else { format!("{} \u{00B7} {}", when, subtitle) }}
+                                                </div>
+                                            </div>
+                                            <button
+                                                class="p-0 text-xs bg-transparent border-none opacity-60 cursor-pointer hover:opacity-100 text-inherit"
+                                                title="Remove"
+                                                on:click=move |_| remove(id.clone())
+                                            >
+                                                "\u{2715}"
+                                            </button>
+                                        </li>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </ul>
+                        })}
+                    </Transition>
+                </div>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,295 @@
+use leptos::prelude::*;
+use crate::estimation::{c_to_f, f_to_c};
+use crate::goals::{GoalMetric, WeeklyReportCard, ZoneGoal};
+use crate::orchid::GrowingZone;
+
+const INPUT_SM: &str = "w-full px-2 py-1.5 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-400 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:focus:bg-stone-800";
+const LABEL_SM: &str = "block mb-1 text-[10px] font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Tailwind classes for a letter grade badge.
+fn grade_classes(grade: char) -> &'static str {
+    match grade {
+        'A' => "bg-emerald-100 text-emerald-700 dark:bg-emerald-900/30 dark:text-emerald-300",
+        'B' => "bg-lime-100 text-lime-700 dark:bg-lime-900/30 dark:text-lime-300",
+        'C' => "bg-amber-100 text-amber-700 dark:bg-amber-900/30 dark:text-amber-300",
+        'D' => "bg-orange-100 text-orange-700 dark:bg-orange-900/30 dark:text-orange-300",
+        'F' => "bg-red-100 text-red-700 dark:bg-red-900/30 dark:text-red-300",
+        _ => "bg-stone-100 text-stone-500 dark:bg-stone-800 dark:text-stone-400",
+    }
+}
+
+/// Formats a week-over-week change in percentage points, e.g. "▲ 12 pts".
+fn trend_label(trend: Option<f64>) -> Option<(String, &'static str)> {
+    let t = trend?;
+    if t.abs() < 1.0 {
+        Some(("\u{25CF} steady".into(), "text-stone-400"))
+    } else if t > 0.0 {
+        Some((format!("\u{25B2} {:.0} pts", t), "text-emerald-600 dark:text-emerald-400"))
+    } else {
+        Some((format!("\u{25BC} {:.0} pts", t.abs()), "text-red-600 dark:text-red-400"))
+    }
+}
+
+/// Converts a stored Celsius bound into the display unit for an input.
+fn to_display(c: Option<f64>, unit: &str) -> String {
+    c.map(|v| if unit == "F" { format!("{:.0}", c_to_f(v)) } else { format!("{:.1}", v) })
+        .unwrap_or_default()
+}
+
+/// Parses an optional numeric input, converting temperatures back to Celsius.
+fn parse_bound(raw: &str, is_temp: bool, unit: &str) -> Result<Option<f64>, String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    let v: f64 = raw.parse().map_err(|_| format!("'{}' is not a number", raw))?;
+    Ok(Some(if is_temp && unit == "F" { f_to_c(v) } else { v }))
+}
+
+/// Weekly climate report cards for zones with goals, shown on the My Plants tab.
+#[component]
+pub fn WeeklyReportCards(unit: Memo<String>) -> impl IntoView {
+    let cards = Resource::new(|| (), |_| crate::server_fns::goals::get_weekly_report_cards());
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || cards.get().map(|result| {
+                let cards = match result {
+                    Ok(c) => c,
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("report_cards.load", &format!("Failed to load report cards: {}", _e), &[]);
+                        Vec::new()
+                    }
+                };
+                if cards.is_empty() {
+                    return view! { <div></div> }.into_any();
+                }
+                view! {
+                    <div class="mb-4">
+                        <h3 class="mt-0 mb-2 text-xs font-bold tracking-widest uppercase text-stone-400 dark:text-stone-500">"This Week vs Goals"</h3>
+                        <div class="grid grid-cols-1 gap-3 sm:grid-cols-2">
+                            {cards.into_iter().map(|card| view! { <ReportCard card=card unit=unit /> }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+    }
+}
+
+/// A single zone's weekly report card.
+#[component]
+fn ReportCard(card: WeeklyReportCard, unit: Memo<String>) -> impl IntoView {
+    let grade_class = format!("flex justify-center items-center w-9 h-9 text-lg font-display rounded-lg {}", grade_classes(card.grade));
+    let rows = [
+        ("Temp", card.temp_in_range_pct, card.temp_trend),
+        ("Humidity", card.humidity_in_range_pct, card.humidity_trend),
+    ];
+    let excursions = StoredValue::new(card.worst_excursions.clone());
+
+    view! {
+        <div class="p-4 rounded-xl border bg-surface border-stone-200/60 dark:border-stone-700/60">
+            <div class="flex gap-3 items-center mb-3">
+                <div class=grade_class>{card.grade.to_string()}</div>
+                <div class="flex-1 min-w-0">
+                    <div class="text-sm font-semibold truncate text-stone-700 dark:text-stone-300">{card.zone_name.clone()}</div>
+                    <div class="text-[11px] text-stone-400">{format!("{} readings, last 7 days", card.sample_count)}</div>
+                </div>
+            </div>
+            {rows.into_iter().filter_map(|(label, pct, trend)| pct.map(|p| {
+                let width = format!("width: {:.0}%", p.clamp(0.0, 100.0));
+                view! {
+                    <div class="mb-2">
+                        <div class="flex justify-between text-xs text-stone-500 dark:text-stone-400">
+                            <span>{format!("{} in range", label)}</span>
+                            <span class="flex gap-2">
+                                {trend_label(trend).map(|(text, class)| view! { <span class=class>{text}</span> })}
+                                <span class="font-semibold tabular-nums text-stone-700 dark:text-stone-300">{format!("{:.0}%", p)}</span>
+                            </span>
+                        </div>
+                        <div class="overflow-hidden mt-1 h-1.5 rounded-full bg-stone-100 dark:bg-stone-800">
+                            <div class="h-full rounded-full bg-primary/70" style=width></div>
+                        </div>
+                    </div>
+                }
+            })).collect::<Vec<_>>()}
+            {move || {
+                let list = excursions.get_value();
+                (!list.is_empty()).then(|| {
+                    let u = unit.get();
+                    view! {
+                        <div class="pt-2 mt-2 border-t border-stone-100 dark:border-stone-700/50">
+                            <div class="mb-1 text-[10px] font-bold tracking-widest uppercase text-stone-400">"Worst excursions"</div>
+                            {list.into_iter().map(|e| {
+                                let (value, over) = match e.metric {
+                                    GoalMetric::Temperature if u == "F" => (
+                                        format!("{:.1}\u{00B0}F", c_to_f(e.value)),
+                                        format!("{:+.1}\u{00B0}", e.deviation * 9.0 / 5.0),
+                                    ),
+                                    GoalMetric::Temperature => (
+                                        format!("{:.1}\u{00B0}C", e.value),
+                                        format!("{:+.1}\u{00B0}", e.deviation),
+                                    ),
+                                    GoalMetric::Humidity => (
+                                        format!("{:.0}% RH", e.value),
+                                        format!("{:+.0}%", e.deviation),
+                                    ),
+                                };
+                                let when = e.recorded_at.format("%a %H:%M").to_string();
+                                view! {
+                                    <div class="flex justify-between text-xs text-stone-600 dark:text-stone-400">
+                                        <span>{value}" "<span class="text-stone-400">{format!("({})", over)}</span></span>
+                                        <span class="text-stone-400">{when}</span>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    }
+                })
+            }}
+        </div>
+    }
+}
+
+/// Settings section for per-zone temperature/humidity goals and the weekly push opt-in.
+#[component]
+pub fn ClimateGoalsEditor(
+    zones: ReadSignal<Vec<GrowingZone>>,
+    temp_unit: ReadSignal<String>,
+) -> impl IntoView {
+    let goals = Resource::new(|| (), |_| crate::server_fns::goals::get_zone_goals());
+    let push_pref = Resource::new(|| (), |_| crate::server_fns::goals::get_weekly_report_push());
+    let (push_enabled, set_push_enabled) = signal(false);
+
+    Effect::new(move |_| {
+        if let Some(Ok(enabled)) = push_pref.get() {
+            set_push_enabled.set(enabled);
+        }
+    });
+
+    let toggle_push = move |_| {
+        let next = !push_enabled.get_untracked();
+        set_push_enabled.set(next);
+        leptos::task::spawn_local(async move {
+            if let Err(_e) = crate::server_fns::goals::save_weekly_report_push(next).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("settings.save_weekly_report_push", &format!("Failed to save weekly report preference: {}", _e), &[]);
+                set_push_enabled.set(!next);
+            }
+        });
+    };
+
+    view! {
+        <Suspense fallback=|| view! { <p class="text-xs text-stone-400">"Loading goals..."</p> }>
+            {move || goals.get().map(|result| {
+                let existing = result.unwrap_or_default();
+                let zones = zones.get();
+                if zones.is_empty() {
+                    return view! { <p class="m-0 text-xs text-stone-400">"Add a growing zone to set goals."</p> }.into_any();
+                }
+                view! {
+                    <div class="flex flex-col gap-2">
+                        {zones.into_iter().map(|z| {
+                            let goal = existing.iter().find(|g| g.zone_id == z.id).cloned()
+                                .unwrap_or_else(|| ZoneGoal { zone_id: z.id.clone(), ..Default::default() });
+                            view! { <ZoneGoalRow zone_name=z.name goal=goal temp_unit=temp_unit /> }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+        <label class="flex gap-2 items-center mt-3 text-sm cursor-pointer text-stone-600 dark:text-stone-300">
+            <input type="checkbox" prop:checked=push_enabled on:change=toggle_push />
+            "Send my weekly report cards as a push notification (Mondays)"
+        </label>
+    }
+}
+
+/// Goal inputs for a single zone.
+#[component]
+fn ZoneGoalRow(zone_name: String, goal: ZoneGoal, temp_unit: ReadSignal<String>) -> impl IntoView {
+    let unit = temp_unit.get_untracked();
+    let zone_id = StoredValue::new(goal.zone_id.clone());
+    let (tmin, set_tmin) = signal(to_display(goal.temp_min_c, &unit));
+    let (tmax, set_tmax) = signal(to_display(goal.temp_max_c, &unit));
+    let (hmin, set_hmin) = signal(goal.humidity_min.map(|h| format!("{:.0}", h)).unwrap_or_default());
+    let (hmax, set_hmax) = signal(goal.humidity_max.map(|h| format!("{:.0}", h)).unwrap_or_default());
+    let (status, set_status) = signal(None::<Result<(), String>>);
+    let (is_saving, set_is_saving) = signal(false);
+
+    let save = move |_| {
+        let unit = temp_unit.get_untracked();
+        let parsed = (|| -> Result<ZoneGoal, String> {
+            Ok(ZoneGoal {
+                zone_id: zone_id.get_value(),
+                temp_min_c: parse_bound(&tmin.get_untracked(), true, &unit)?,
+                temp_max_c: parse_bound(&tmax.get_untracked(), true, &unit)?,
+                humidity_min: parse_bound(&hmin.get_untracked(), false, &unit)?,
+                humidity_max: parse_bound(&hmax.get_untracked(), false, &unit)?,
+            })
+        })()
+        .and_then(|g| g.validate().map(|_| g));
+
+        let goal = match parsed {
+            Ok(g) => g,
+            Err(msg) => {
+                set_status.set(Some(Err(msg)));
+                return;
+            }
+        };
+
+        set_is_saving.set(true);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::goals::save_zone_goal(goal).await {
+                Ok(()) => set_status.set(Some(Ok(()))),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.save_zone_goal", &format!("Failed to save zone goal: {}", e), &[]);
+                    set_status.set(Some(Err(format!("{}", e))));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let temp_label = move || format!("Temp \u{00B0}{}", temp_unit.get());
+
+    view! {
+        <div class="p-3 rounded-xl border bg-secondary/20 border-stone-200/60 dark:border-stone-700">
+            <div class="mb-2 text-sm font-semibold text-stone-700 dark:text-stone-300">{zone_name}</div>
+            <div class="grid grid-cols-2 gap-2 sm:grid-cols-4">
+                <div>
+                    <label class=LABEL_SM>{move || format!("{} min", temp_label())}</label>
+                    <input type="number" step="0.5" class=INPUT_SM prop:value=tmin on:input=move |ev| set_tmin.set(event_target_value(&ev)) />
+                </div>
+                <div>
+                    <label class=LABEL_SM>{move || format!("{} max", temp_label())}</label>
+                    <input type="number" step="0.5" class=INPUT_SM prop:value=tmax on:input=move |ev| set_tmax.set(event_target_value(&ev)) />
+                </div>
+                <div>
+                    <label class=LABEL_SM>"RH % min"</label>
+                    <input type="number" min="0" max="100" class=INPUT_SM prop:value=hmin on:input=move |ev| set_hmin.set(event_target_value(&ev)) />
+                </div>
+                <div>
+                    <label class=LABEL_SM>"RH % max"</label>
+                    <input type="number" min="0" max="100" class=INPUT_SM prop:value=hmax on:input=move |ev| set_hmax.set(event_target_value(&ev)) />
+                </div>
+            </div>
+            <div class="flex gap-2 items-center mt-2">
+                <button
+                    class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                    disabled=move || is_saving.get()
+                    on:click=save
+                >
+                    {move || if is_saving.get() { "Saving..." } else { "Save Goal" }}
+                </button>
+                {move || status.get().map(|s| match s {
+                    Ok(()) => view! { <span class="text-xs text-emerald-600 dark:text-emerald-400">"Saved"</span> }.into_any(),
+                    Err(msg) => view! { <span class="text-xs text-red-600 dark:text-red-400">{msg}</span> }.into_any(),
+                })}
+            </div>
+        </div>
+    }
+}
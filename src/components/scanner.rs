@@ -1,5 +1,9 @@
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
+use crate::care_tag::CareTag;
+use crate::diagnosis::{Diagnosis, LikelyCause, SuggestedTreatment};
+use crate::identification::SpeciesCandidate;
+use crate::root_health::{MediumCondition, RootAssessment};
 use crate::orchid::{FitCategory, LightRequirement, Orchid, GrowingZone, ClimateReading};
 use super::{MODAL_OVERLAY, BTN_PRIMARY, BTN_GHOST};
 
@@ -8,6 +12,7 @@ const SCANNER_HEADER: &str = "flex justify-between items-center mb-5 pb-4 border
 const SCANNER_CLOSE: &str = "py-2 px-3 text-sm text-stone-400 bg-stone-800 rounded-lg border-none cursor-pointer hover:bg-stone-700 hover:text-stone-200 transition-colors";
 const TAB_ACTIVE: &str = "flex-1 py-2.5 text-sm font-semibold text-white rounded-lg border-none cursor-pointer transition-colors bg-primary";
 const TAB_INACTIVE: &str = "flex-1 py-2.5 text-sm font-medium rounded-lg border-none cursor-pointer transition-colors text-stone-400 bg-stone-800 hover:text-stone-200 hover:bg-stone-700";
+const DARK_SELECT: &str = "w-full py-2.5 px-3 text-sm text-white rounded-xl border outline-none bg-stone-800 border-stone-700 focus:border-primary/50";
 const SEARCH_INPUT: &str = "w-full py-3 px-4 text-sm text-white rounded-xl border outline-none transition-all duration-200 bg-stone-800 border-stone-700 placeholder:text-stone-500 focus:border-primary/50 focus:ring-2 focus:ring-primary/20";
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -53,6 +58,15 @@ pub struct AnalysisResult {
     pub active_fertilizer_multiplier: Option<f64>,
 }
 
+/// Which of the scanner's modes is showing.
+#[derive(Clone, Copy, PartialEq)]
+enum ScannerTab {
+    Scan,
+    Search,
+    Diagnose,
+    Roots,
+}
+
 #[component]
 pub fn ScannerModal(
     on_close: impl Fn() + 'static + Copy + Send + Sync,
@@ -61,8 +75,7 @@ pub fn ScannerModal(
     climate_readings: Vec<ClimateReading>,
     zones: Vec<GrowingZone>,
 ) -> impl IntoView {
-    // Tab state: true = scan, false = search
-    let (scan_mode, set_scan_mode) = signal(true);
+    let (tab, set_tab) = signal(ScannerTab::Scan);
 
     view! {
         <div class=MODAL_OVERLAY>
@@ -77,7 +90,7 @@ pub fn ScannerModal(
                 <div class=SCANNER_HEADER>
                     <div>
                         <h2 class="m-0 text-white">"ID Plant"</h2>
-                        <p class="mt-1 mb-0 text-xs text-stone-500">"Scan a tag or search by name"</p>
+                        <p class="mt-1 mb-0 text-xs text-stone-500">"Scan a tag, search by name, diagnose a problem, or check roots when repotting"</p>
                     </div>
                     <button class=SCANNER_CLOSE on:click=move |_| on_close()>"Close"</button>
                 </div>
@@ -85,34 +98,47 @@ pub fn ScannerModal(
                 // Tab switcher
                 <div class="flex gap-1.5 p-1 mb-5 rounded-xl bg-stone-800/60">
                     <button
-                        class=move || if scan_mode.get() { TAB_ACTIVE } else { TAB_INACTIVE }
-                        on:click=move |_| set_scan_mode.set(true)
+                        class=move || if tab.get() == ScannerTab::Scan { TAB_ACTIVE } else { TAB_INACTIVE }
+                        on:click=move |_| set_tab.set(ScannerTab::Scan)
                     >"Scan Tag"</button>
                     <button
-                        class=move || if !scan_mode.get() { TAB_ACTIVE } else { TAB_INACTIVE }
-                        on:click=move |_| set_scan_mode.set(false)
+                        class=move || if tab.get() == ScannerTab::Search { TAB_ACTIVE } else { TAB_INACTIVE }
+                        on:click=move |_| set_tab.set(ScannerTab::Search)
                     >"Search by Name"</button>
+                    <button
+                        class=move || if tab.get() == ScannerTab::Diagnose { TAB_ACTIVE } else { TAB_INACTIVE }
+                        on:click=move |_| set_tab.set(ScannerTab::Diagnose)
+                    >"Diagnose"</button>
+                    <button
+                        class=move || if tab.get() == ScannerTab::Roots { TAB_ACTIVE } else { TAB_INACTIVE }
+                        on:click=move |_| set_tab.set(ScannerTab::Roots)
+                    >"Roots"</button>
                 </div>
 
                 <div class="relative">
-                    {move || if scan_mode.get() {
-                        view! {
+                    {move || match tab.get() {
+                        ScannerTab::Scan => view! {
                             <ScanTab
                                 on_add_to_collection=on_add_to_collection
                                 existing_orchids=existing_orchids.clone()
                                 climate_readings=climate_readings.clone()
                                 zones=zones.clone()
                             />
-                        }.into_any()
-                    } else {
-                        view! {
+                        }.into_any(),
+                        ScannerTab::Search => view! {
                             <SearchTab
                                 on_add_to_collection=on_add_to_collection
                                 existing_orchids=existing_orchids.clone()
                                 climate_readings=climate_readings.clone()
                                 zones=zones.clone()
                             />
-                        }.into_any()
+                        }.into_any(),
+                        ScannerTab::Diagnose => view! {
+                            <DiagnoseTab existing_orchids=existing_orchids.clone() />
+                        }.into_any(),
+                        ScannerTab::Roots => view! {
+                            <RootsTab existing_orchids=existing_orchids.clone() />
+                        }.into_any(),
                     }}
                 </div>
             </div>
@@ -120,7 +146,8 @@ pub fn ScannerModal(
     }.into_any()
 }
 
-/// Camera-based tag scanning tab.
+/// Camera-based tag scanning tab: snap a photo, pick the right species from the ranked
+/// candidates, then review its fit before adding it.
 #[component]
 fn ScanTab(
     on_add_to_collection: impl Fn(AnalysisResult) + 'static + Copy + Send + Sync,
@@ -129,6 +156,11 @@ fn ScanTab(
     zones: Vec<GrowingZone>,
 ) -> impl IntoView {
     let (is_scanning, set_is_scanning) = signal(false);
+    let (candidates, set_candidates) = signal::<Option<Vec<SpeciesCandidate>>>(None);
+    // Care instructions read off a grower's tag in the photo, laid over the chosen species' profile
+    let (care_tag, set_care_tag) = signal::<Option<CareTag>>(None);
+    // The candidate whose care profile is loading
+    let (profiling, set_profiling) = signal::<Option<String>>(None);
     let (analysis_result, set_analysis_result) = signal::<Option<AnalysisResult>>(None);
     let (error_msg, set_error_msg) = signal::<Option<String>>(None);
 
@@ -221,6 +253,7 @@ fn ScanTab(
     let capture_and_analyze = move |_| {
         set_is_scanning.set(true);
         set_error_msg.set(None);
+        set_candidates.set(None);
         set_analysis_result.set(None);
 
         #[cfg(feature = "hydrate")]
@@ -254,6 +287,42 @@ fn ScanTab(
                 orchids.iter().map(|o| o.species.clone()).collect()
             });
 
+            #[cfg(feature = "hydrate")]
+            crate::server_fns::telemetry::emit_info("scanner.analyze_start", "Image analysis started", &[("mode", "scan")]);
+
+            leptos::task::spawn_local(async move {
+                match crate::server_fns::scanner::identify_orchid_candidates(base64_image, Some(existing_names)).await {
+                    Ok(found) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_info("scanner.analyze_complete", "Image analysis complete", &[
+                            ("candidates", &found.candidates.len().to_string()),
+                            ("care_tag", if found.care_tag.is_some() { "true" } else { "false" }),
+                        ]);
+                        set_care_tag.set(found.care_tag);
+                        set_candidates.set(Some(found.candidates));
+                    }
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_error("scanner.analyze_image", &format!("Analysis failed: {}", e), &[]);
+                        set_error_msg.set(Some(format!("Analysis failed: {}", e)));
+                    }
+                }
+                set_is_scanning.set(false);
+            });
+        }
+    };
+
+    // Profile the species the user confirmed against their zones and climate
+    let pick_candidate = move |species_name: String| {
+        set_profiling.set(Some(species_name.clone()));
+        set_error_msg.set(None);
+
+        #[cfg(feature = "hydrate")]
+        {
+            let existing_names: Vec<String> = existing_orchids.with_value(|orchids| {
+                orchids.iter().map(|o| o.species.clone()).collect()
+            });
+
             let zone_names: Vec<String> = zones.with_value(|z| {
                 z.iter().map(|zone| zone.name.clone()).collect()
             });
@@ -269,32 +338,46 @@ fn ScanTab(
                 }
             });
 
-            #[cfg(feature = "hydrate")]
-            crate::server_fns::telemetry::emit_info("scanner.analyze_start", "Image analysis started", &[("mode", "scan")]);
+            crate::server_fns::telemetry::emit_info("scanner.candidate_pick", "Scan candidate picked", &[("species", &species_name)]);
 
             leptos::task::spawn_local(async move {
-                match crate::server_fns::scanner::analyze_orchid_image(
-                    base64_image,
+                match crate::server_fns::scanner::analyze_orchid_by_name(
+                    species_name,
                     Some(existing_names),
                     summary,
                     Some(zone_names),
                 ).await {
-                    Ok(result) => {
-                        #[cfg(feature = "hydrate")]
-                        crate::server_fns::telemetry::emit_info("scanner.analyze_complete", "Image analysis complete", &[("species", &result.species_name)]);
+                    Ok(mut result) => {
+                        if let Some(tag) = care_tag.get_untracked() {
+                            tag.apply_to(&mut result);
+                        }
                         set_analysis_result.set(Some(result));
                     }
                     Err(e) => {
-                        #[cfg(feature = "hydrate")]
-                        crate::server_fns::telemetry::emit_error("scanner.analyze_image", &format!("Analysis failed: {}", e), &[]);
-                        set_error_msg.set(Some(format!("Analysis failed: {}", e)));
+                        crate::server_fns::telemetry::emit_error("scanner.candidate_pick", &format!("Lookup failed: {}", e), &[]);
+                        set_error_msg.set(Some(format!("Lookup failed: {}", e)));
                     }
                 }
-                set_is_scanning.set(false);
+                set_profiling.set(None);
             });
         }
     };
 
+    let reject_candidate = move |species_name: String| {
+        set_candidates.update(|list| {
+            if let Some(list) = list {
+                list.retain(|c| c.species_name != species_name);
+            }
+        });
+    };
+
+    let reset = move || {
+        set_candidates.set(None);
+        set_care_tag.set(None);
+        set_analysis_result.set(None);
+        set_error_msg.set(None);
+    };
+
     view! {
         <div>
             {move || error_msg.get().map(|err| {
@@ -315,10 +398,17 @@ fn ScanTab(
             <div class="scanner-controls-rise">
             {move || {
                 if let Some(result) = analysis_result.get() {
-                    view! { <ScanResult result=result on_add=on_add_to_collection on_reset=move || {
-                        set_analysis_result.set(None);
-                        set_error_msg.set(None);
-                    } /> }.into_any()
+                    view! { <ScanResult result=result care_tag=care_tag.get() on_add=on_add_to_collection on_reset=reset /> }.into_any()
+                } else if let Some(list) = candidates.get() {
+                    view! {
+                        <CandidateList
+                            candidates=list
+                            profiling=profiling
+                            on_pick=pick_candidate
+                            on_reject=reject_candidate
+                            on_reset=reset
+                        />
+                    }.into_any()
                 } else {
                     view! {
                         <div class="flex gap-3 justify-center mt-4 text-center">
@@ -344,6 +434,72 @@ fn ScanTab(
     }.into_any()
 }
 
+/// Ranked species candidates from a scan, each with a reference photo to compare against.
+/// Picking one loads its care profile; rejecting one drops it from the list.
+#[component]
+fn CandidateList(
+    candidates: Vec<SpeciesCandidate>,
+    profiling: ReadSignal<Option<String>>,
+    on_pick: impl Fn(String) + 'static + Copy + Send + Sync,
+    on_reject: impl Fn(String) + 'static + Copy + Send + Sync,
+    on_reset: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let busy = move || profiling.get().is_some();
+
+    view! {
+        <div class="p-5 rounded-xl bg-stone-800">
+            <h3 class="mt-0 mb-1 text-white">"Which one is it?"</h3>
+            <p class="mt-0 mb-3 text-xs text-stone-500">"Compare with the reference photos and pick the match."</p>
+            {candidates.is_empty().then(|| view! {
+                <p class="text-sm text-stone-400">"No candidates left. Try another photo, or search by name."</p>
+            })}
+            <ul class="p-0 m-0 list-none">
+                {candidates.into_iter().map(|c| {
+                    let (pick_name, reject_name, this_name) = (c.species_name.clone(), c.species_name.clone(), c.species_name.clone());
+                    let loading = move || profiling.get().as_deref() == Some(this_name.as_str());
+                    view! {
+                        <li class="flex gap-3 items-center py-2.5 border-b last:border-b-0 border-stone-700">
+                            <div class="overflow-hidden w-14 h-14 rounded-lg shrink-0 bg-stone-700">
+                                {match c.reference_image.clone() {
+                                    Some(src) => view! { <img src=src class="object-cover w-full h-full" alt=c.species_name.clone() loading="lazy" /> }.into_any(),
+                                    None => view! { <div class="flex justify-center items-center w-full h-full text-xl opacity-30">{"\u{1FAB4}"}</div> }.into_any(),
+                                }}
+                            </div>
+                            <div class="flex-1 min-w-0">
+                                <div class="flex flex-wrap gap-2 items-center">
+                                    <span class="font-medium text-white">{c.species_name.clone()}</span>
+                                    <span class="py-0.5 px-2 text-xs font-semibold rounded-full bg-stone-700 text-stone-200">{c.confidence_label()}</span>
+                                    {c.already_owned.then(|| view! { <span class="text-xs font-semibold text-amber-400">"Owned"</span> })}
+                                </div>
+                                {(!c.reason.is_empty()).then(|| view! {
+                                    <p class="mt-0.5 mb-0 text-xs text-stone-400">{c.reason.clone()}</p>
+                                })}
+                            </div>
+                            <div class="flex gap-1.5 shrink-0">
+                                <button
+                                    class="py-1.5 px-3 text-xs font-semibold text-white rounded-lg border-none cursor-pointer bg-primary hover:bg-primary-dark disabled:opacity-40 disabled:cursor-not-allowed"
+                                    disabled=busy
+                                    on:click=move |_| on_pick(pick_name.clone())
+                                >{move || if loading() { "Checking..." } else { "This One" }}</button>
+                                <button
+                                    class="py-1.5 px-2 text-xs rounded-lg border-none cursor-pointer text-stone-400 bg-stone-700 hover:text-stone-200 disabled:opacity-40"
+                                    title="Not this one"
+                                    disabled=busy
+                                    on:click=move |_| on_reject(reject_name.clone())
+                                >{"\u{2715}"}</button>
+                            </div>
+                        </li>
+                    }
+                }).collect::<Vec<_>>()}
+            </ul>
+            <button class="py-3 mt-4 w-full text-sm font-medium rounded-lg border-none transition-colors cursor-pointer text-stone-300 bg-stone-700 hover:bg-stone-600"
+                disabled=busy
+                on:click=move |_| on_reset()
+            >"None of These \u{2014} Scan Again"</button>
+        </div>
+    }.into_any()
+}
+
 /// Result card with add/retry actions.
 #[component]
 fn ScanResult(
@@ -352,6 +508,9 @@ fn ScanResult(
     on_reset: impl Fn() + 'static + Copy + Send + Sync,
     #[prop(default = "Scan Another")]
     reset_label: &'static str,
+    /// Care instructions read off the plant's tag, already applied to `result`.
+    #[prop(default = None)]
+    care_tag: Option<CareTag>,
 ) -> impl IntoView {
     let fit_class = match result.fit_category {
         FitCategory::GoodFit => "py-1 px-3 text-sm font-semibold rounded-full bg-primary-light/20 text-primary-light",
@@ -368,6 +527,16 @@ fn ScanResult(
             {result.already_owned.then(|| {
                 view! { <p class="mt-2 text-sm font-semibold text-amber-400">"You already own this species!"</p> }
             })}
+            {care_tag.map(|tag| view! {
+                <div class="mt-3">
+                    <div class="mb-1.5 text-xs font-semibold tracking-wider uppercase text-stone-400">"From the tag"</div>
+                    <div class="flex flex-wrap gap-1.5">
+                        {tag.highlights().into_iter().map(|h| view! {
+                            <span class="py-0.5 px-2 text-xs rounded-full bg-stone-700 text-stone-200">{h}</span>
+                        }).collect::<Vec<_>>()}
+                    </div>
+                </div>
+            })}
             <div class="grid grid-cols-2 gap-4 mt-4">
                 <button class=BTN_PRIMARY on:click=move |_| on_add(result_clone.clone())>
                     "Add to Collection"
@@ -499,6 +668,7 @@ fn SearchTab(
                                     prop:value=query
                                     on:input=move |ev| set_query.set(event_target_value(&ev))
                                     on:keydown=on_keydown
+                                    autocomplete="off"
                                     disabled=move || is_searching.get()
                                 />
                                 <div class="flex absolute top-0 right-0 justify-center items-center px-3 h-full pointer-events-none text-stone-500">
@@ -506,6 +676,11 @@ fn SearchTab(
                                         <path stroke-linecap="round" stroke-linejoin="round" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z"/>
                                     </svg>
                                 </div>
+                                <crate::components::species_search::SpeciesSuggestions
+                                    query=query
+                                    on_pick=move |s: crate::taxonomy::SpeciesSuggestion| set_query.set(s.name)
+                                    dark=true
+                                />
                             </div>
                             {move || if is_searching.get() {
                                 view! {
@@ -534,3 +709,420 @@ fn SearchTab(
         </div>
     }.into_any()
 }
+
+/// Photo-based problem diagnosis tab: pick the plant and a photo of the damage, get likely
+/// causes with treatments, and log a treatment against the plant in one tap.
+#[component]
+fn DiagnoseTab(existing_orchids: Vec<Orchid>) -> impl IntoView {
+    let orchids = StoredValue::new(existing_orchids);
+    let (plant_id, set_plant_id) = signal(String::new());
+    let (photo, set_photo) = signal(None::<String>);
+    let (is_diagnosing, set_is_diagnosing) = signal(false);
+    let (diagnosis, set_diagnosis) = signal(None::<Diagnosis>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let (logged_msg, set_logged_msg) = signal(None::<String>);
+    let file_ref = NodeRef::<leptos::html::Input>::new();
+    // Only used in #[cfg(feature = "hydrate")] blocks
+    let _ = (&file_ref, &set_photo);
+
+    let on_pick = move |_ev: leptos::ev::Event| {
+        #[cfg(feature = "hydrate")]
+        {
+            let Some(input) = file_ref.get() else { return };
+            let input_el: &web_sys::HtmlInputElement = input.as_ref();
+            let Some(file) = input_el.files().and_then(|f| f.get(0)) else { return };
+            input_el.set_value("");
+            set_diagnosis.set(None);
+            set_error_msg.set(None);
+            set_logged_msg.set(None);
+            leptos::task::spawn_local(async move {
+                let resized = match web_sys::Url::create_object_url_with_blob(&file) {
+                    Ok(blob_url) => {
+                        let resized = crate::components::photo_capture::resize_to_data_url(&blob_url).await;
+                        let _ = web_sys::Url::revoke_object_url(&blob_url);
+                        resized
+                    }
+                    Err(_) => Err("Failed to read image file".to_string()),
+                };
+                match resized {
+                    Ok(data_url) => set_photo.set(Some(data_url)),
+                    Err(e) => set_error_msg.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let diagnose = move |_| {
+        let Some(data_url) = photo.get() else { return };
+        let base64_image = data_url.split(',').nth(1).unwrap_or("").to_string();
+        let orchid_id = Some(plant_id.get()).filter(|id| !id.is_empty());
+        set_is_diagnosing.set(true);
+        set_error_msg.set(None);
+        set_logged_msg.set(None);
+
+        #[cfg(feature = "hydrate")]
+        crate::server_fns::telemetry::emit_info("scanner.diagnose_start", "Diagnosis started", &[("mode", "diagnose")]);
+
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::scanner::diagnose_plant_photo(base64_image, orchid_id).await {
+                Ok(result) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("scanner.diagnose_complete", "Diagnosis complete", &[("causes", &result.causes.len().to_string())]);
+                    set_diagnosis.set(Some(result));
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("scanner.diagnose", &format!("Diagnosis failed: {}", e), &[]);
+                    set_error_msg.set(Some(format!("Diagnosis failed: {}", e)));
+                }
+            }
+            set_is_diagnosing.set(false);
+        });
+    };
+
+    // Pests and diseases start a re-treatment series; care changes are journalled as a note
+    let log_treatment = move |cause: LikelyCause, treatment: SuggestedTreatment| {
+        let orchid_id = plant_id.get_untracked();
+        if orchid_id.is_empty() {
+            return;
+        }
+        let plant_name = orchids.with_value(|list| list.iter().find(|o| o.id == orchid_id).map(|o| o.name.clone()))
+            .unwrap_or_default();
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            let result = match cause.kind.problem_kind() {
+                Some(kind) => {
+                    let (interval_days, applications) = treatment.series();
+                    crate::server_fns::treatments::start_treatment(
+                        orchid_id, kind, cause.name.clone(), treatment.product.clone(), treatment.dose.clone(), interval_days, applications,
+                    ).await.map(|_| ())
+                }
+                None => {
+                    let note = format!("Diagnosed {} ({}): {}. {}", cause.name, cause.confidence_label(), treatment.product, treatment.instructions);
+                    crate::server_fns::orchids::add_log_entry(orchid_id, note.trim().to_string(), None, None, None, None)
+                        .await.map(|_| ())
+                }
+            };
+            match result {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("scanner.diagnose_log", "Treatment logged from diagnosis", &[("cause", &cause.name)]);
+                    set_logged_msg.set(Some(format!("Logged {} for {} on {}.", treatment.product, cause.name, plant_name)));
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("scanner.diagnose_log", &format!("Failed to log treatment: {}", e), &[]);
+                    set_error_msg.set(Some(format!("Failed to log treatment: {}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div>
+            {move || error_msg.get().map(|err| {
+                view! { <div class="p-3 mb-4 text-sm text-red-300 rounded-lg bg-danger/20">{err}</div> }
+            })}
+
+            <label class="block mb-1 text-xs font-semibold tracking-wider uppercase text-stone-400">"Plant"</label>
+            <select class=format!("{} mb-4", DARK_SELECT)
+                prop:value=plant_id
+                on:change=move |ev| set_plant_id.set(event_target_value(&ev))
+            >
+                <option value="">"Not in my collection / not sure"</option>
+                {orchids.with_value(|list| list.iter().map(|o| view! {
+                    <option value=o.id.clone()>{o.name.clone()}</option>
+                }).collect::<Vec<_>>())}
+            </select>
+
+            <label class="flex overflow-hidden relative justify-center items-center mb-4 w-full rounded-xl border border-dashed cursor-pointer h-[220px] bg-stone-800/60 border-stone-600 hover:border-primary/50">
+                {move || match photo.get() {
+                    Some(src) => view! { <img src=src class="object-contain w-full h-full" alt="Photo to diagnose" /> }.into_any(),
+                    None => view! {
+                        <div class="text-center text-stone-400">
+                            <div class="mb-2 text-3xl opacity-40">{"\u{1F50D}"}</div>
+                            <p class="m-0 text-sm">"Take or choose a close photo of the damaged leaves, roots or flowers"</p>
+                        </div>
+                    }.into_any(),
+                }}
+                <input node_ref=file_ref type="file" accept="image/*" capture="environment" class="hidden" on:change=on_pick />
+            </label>
+
+            {move || if let Some(result) = diagnosis.get() {
+                view! {
+                    <div class="p-5 rounded-xl bg-stone-800">
+                        <p class="mt-0 text-sm leading-relaxed text-stone-300">{result.summary.clone()}</p>
+                        {result.causes.is_empty().then(|| view! {
+                            <p class="mb-0 text-sm font-semibold text-primary-light">"No clear problem found."</p>
+                        })}
+                        {result.causes.into_iter().map(|cause| {
+                            let treatments = cause.treatments.clone();
+                            view! {
+                                <div class="pt-3 mt-3 border-t border-stone-700">
+                                    <div class="flex gap-2 justify-between items-center">
+                                        <h4 class="m-0 text-white">{format!("{} {}", cause.kind.emoji(), cause.name)}</h4>
+                                        <span class="py-0.5 px-2 text-xs font-semibold rounded-full bg-stone-700 text-stone-200">{cause.confidence_label()}</span>
+                                    </div>
+                                    {(!cause.signs.is_empty()).then(|| view! {
+                                        <p class="mt-1 mb-2 text-xs text-stone-400">{cause.signs.clone()}</p>
+                                    })}
+                                    <ul class="p-0 m-0 list-none">
+                                        {treatments.into_iter().map(|t| {
+                                            let (c, t2) = (cause.clone(), t.clone());
+                                            let detail = [t.dose.clone(), t.interval_days.map(|d| format!("every {} days", d))]
+                                                .into_iter().flatten().collect::<Vec<_>>().join(", ");
+                                            view! {
+                                                <li class="flex gap-3 justify-between items-start py-1.5">
+                                                    <div class="min-w-0 text-sm text-stone-200">
+                                                        <span class="font-medium">{t.product.clone()}</span>
+                                                        {(!detail.is_empty()).then(|| view! { <span class="text-stone-400">{format!(" \u{00B7} {}", detail)}</span> })}
+                                                        {(!t.instructions.is_empty()).then(|| view! {
+                                                            <div class="text-xs text-stone-400">{t.instructions.clone()}</div>
+                                                        })}
+                                                    </div>
+                                                    <button
+                                                        class="py-1 px-2.5 text-xs font-semibold text-white rounded-lg border-none cursor-pointer shrink-0 bg-primary hover:bg-primary-dark disabled:opacity-40 disabled:cursor-not-allowed"
+                                                        disabled=move || plant_id.get().is_empty()
+                                                        title=move || if plant_id.get().is_empty() { "Pick the plant first" } else { "Log this treatment" }
+                                                        on:click=move |_| log_treatment(c.clone(), t2.clone())
+                                                    >"Log"</button>
+                                                </li>
+                                            }
+                                        }).collect::<Vec<_>>()}
+                                    </ul>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                        {move || logged_msg.get().map(|msg| view! {
+                            <p class="mt-3 mb-0 text-sm font-semibold text-primary-light">{msg}</p>
+                        })}
+                        <p class="mt-3 mb-0 text-xs text-stone-500">"AI suggestions can be wrong; check before spraying."</p>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="flex justify-center">
+                        {move || if is_diagnosing.get() {
+                            view! {
+                                <button class="flex gap-2 items-center py-3 px-6 text-sm font-semibold text-white rounded-lg border-none cursor-not-allowed bg-primary/70" disabled>
+                                    <div class="w-4 h-4 rounded-full border-2 border-white animate-spin border-t-transparent"></div>
+                                    "Diagnosing..."
+                                </button>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <button class=BTN_PRIMARY disabled=move || photo.get().is_none() on:click=diagnose>"Diagnose"</button>
+                            }.into_any()
+                        }}
+                    </div>
+                }.into_any()
+            }}
+        </div>
+    }.into_any()
+}
+
+/// Root check at repotting: pick the plant and a photo of the unpotted roots, get the healthy, rotten
+/// and dried-out shares, the old medium's mix and a next repot, and save it all as a 'Repotted' entry.
+#[component]
+fn RootsTab(existing_orchids: Vec<Orchid>) -> impl IntoView {
+    let orchids = StoredValue::new(existing_orchids);
+    let (plant_id, set_plant_id) = signal(String::new());
+    let (photo, set_photo) = signal(None::<String>);
+    let (is_assessing, set_is_assessing) = signal(false);
+    let (assessment, set_assessment) = signal(None::<RootAssessment>);
+    let (note, set_note) = signal(String::new());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let (saved_msg, set_saved_msg) = signal(None::<String>);
+    let file_ref = NodeRef::<leptos::html::Input>::new();
+    // Only used in #[cfg(feature = "hydrate")] blocks
+    let _ = (&file_ref, &set_photo);
+
+    let on_pick = move |_ev: leptos::ev::Event| {
+        #[cfg(feature = "hydrate")]
+        {
+            let Some(input) = file_ref.get() else { return };
+            let input_el: &web_sys::HtmlInputElement = input.as_ref();
+            let Some(file) = input_el.files().and_then(|f| f.get(0)) else { return };
+            input_el.set_value("");
+            set_assessment.set(None);
+            set_error_msg.set(None);
+            set_saved_msg.set(None);
+            leptos::task::spawn_local(async move {
+                let resized = match web_sys::Url::create_object_url_with_blob(&file) {
+                    Ok(blob_url) => {
+                        let resized = crate::components::photo_capture::resize_to_data_url(&blob_url).await;
+                        let _ = web_sys::Url::revoke_object_url(&blob_url);
+                        resized
+                    }
+                    Err(_) => Err("Failed to read image file".to_string()),
+                };
+                match resized {
+                    Ok(data_url) => set_photo.set(Some(data_url)),
+                    Err(e) => set_error_msg.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let assess = move |_| {
+        let Some(data_url) = photo.get() else { return };
+        let base64_image = data_url.split(',').nth(1).unwrap_or("").to_string();
+        let orchid_id = Some(plant_id.get()).filter(|id| !id.is_empty());
+        set_is_assessing.set(true);
+        set_error_msg.set(None);
+        set_saved_msg.set(None);
+
+        #[cfg(feature = "hydrate")]
+        crate::server_fns::telemetry::emit_info("scanner.roots_start", "Root assessment started", &[("mode", "roots")]);
+
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::scanner::assess_root_photo(base64_image, orchid_id).await {
+                Ok(result) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("scanner.roots_complete", "Root assessment complete", &[("rot_pct", &result.rot_pct.to_string())]);
+                    set_assessment.set(Some(result));
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("scanner.roots", &format!("Root assessment failed: {}", e), &[]);
+                    set_error_msg.set(Some(format!("Root assessment failed: {}", e)));
+                }
+            }
+            set_is_assessing.set(false);
+        });
+    };
+
+    let save = move |_| {
+        let orchid_id = plant_id.get_untracked();
+        let Some(result) = assessment.get_untracked() else { return };
+        if orchid_id.is_empty() {
+            return;
+        }
+        let plant_name = orchids.with_value(|list| list.iter().find(|o| o.id == orchid_id).map(|o| o.name.clone()))
+            .unwrap_or_default();
+        let note = Some(note.get_untracked()).filter(|n| !n.trim().is_empty());
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::orchids::log_root_assessment(orchid_id, result, note).await {
+                Ok(_) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("scanner.roots_log", "Root assessment logged", &[]);
+                    set_saved_msg.set(Some(format!("Saved as a repot in {}'s journal.", plant_name)));
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("scanner.roots_log", &format!("Failed to log root assessment: {}", e), &[]);
+                    set_error_msg.set(Some(format!("Failed to save: {}", e)));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div>
+            {move || error_msg.get().map(|err| {
+                view! { <div class="p-3 mb-4 text-sm text-red-300 rounded-lg bg-danger/20">{err}</div> }
+            })}
+
+            <label class="block mb-1 text-xs font-semibold tracking-wider uppercase text-stone-400">"Plant"</label>
+            <select class=format!("{} mb-4", DARK_SELECT)
+                prop:value=plant_id
+                on:change=move |ev| set_plant_id.set(event_target_value(&ev))
+            >
+                <option value="">"Pick the plant being repotted"</option>
+                {orchids.with_value(|list| list.iter().map(|o| view! {
+                    <option value=o.id.clone()>{o.name.clone()}</option>
+                }).collect::<Vec<_>>())}
+            </select>
+
+            <label class="flex overflow-hidden relative justify-center items-center mb-4 w-full rounded-xl border border-dashed cursor-pointer h-[220px] bg-stone-800/60 border-stone-600 hover:border-primary/50">
+                {move || match photo.get() {
+                    Some(src) => view! { <img src=src class="object-contain w-full h-full" alt="Roots to assess" /> }.into_any(),
+                    None => view! {
+                        <div class="text-center text-stone-400">
+                            <div class="mb-2 text-3xl opacity-40">{"\u{1FAB4}"}</div>
+                            <p class="m-0 text-sm">"Take or choose a photo of the roots out of the pot, with the old medium loosened"</p>
+                        </div>
+                    }.into_any(),
+                }}
+                <input node_ref=file_ref type="file" accept="image/*" capture="environment" class="hidden" on:change=on_pick />
+            </label>
+
+            {move || if let Some(result) = assessment.get() {
+                let condition = (result.medium_condition != MediumCondition::Unknown)
+                    .then(|| format!("{} medium", result.medium_condition.label()));
+                view! {
+                    <div class="p-5 rounded-xl bg-stone-800">
+                        <div class="flex overflow-hidden mb-2 h-3 rounded-full bg-stone-700" aria-hidden="true">
+                            <div class="bg-emerald-500" style=format!("width: {}%", result.healthy_pct)></div>
+                            <div class="bg-red-500" style=format!("width: {}%", result.rot_pct)></div>
+                            <div class="bg-amber-400" style=format!("width: {}%", result.dry_pct)></div>
+                        </div>
+                        <div class="flex flex-wrap gap-x-4 gap-y-1 mb-3 text-xs text-stone-300">
+                            <span>{format!("{:.0}% healthy", result.healthy_pct)}</span>
+                            <span class=if result.is_concerning() { "font-semibold text-red-300" } else { "" }>{format!("{:.0}% rot", result.rot_pct)}</span>
+                            <span>{format!("{:.0}% dried out", result.dry_pct)}</span>
+                        </div>
+                        <p class="mt-0 text-sm leading-relaxed text-stone-300">{result.summary.clone()}</p>
+                        {(!result.medium.is_empty()).then(|| view! {
+                            <div class="mb-3">
+                                <div class="mb-1 text-xs font-semibold tracking-wider uppercase text-stone-400">
+                                    {match condition {
+                                        Some(c) => format!("Old medium \u{00B7} {}", c),
+                                        None => "Old medium".to_string(),
+                                    }}
+                                </div>
+                                <div class="flex flex-wrap gap-1.5">
+                                    {result.medium.iter().map(|p| view! {
+                                        <span class="py-0.5 px-2 text-xs rounded-full bg-stone-700 text-stone-200">{format!("{} {:.0}%", p.material, p.pct)}</span>
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                            </div>
+                        })}
+                        {result.next_repot_months.map(|months| view! {
+                            <p class="mt-0 mb-3 text-sm font-semibold text-primary-light">{format!("Repot again in about {} months", months)}</p>
+                        })}
+                        <input type="text"
+                            class=SEARCH_INPUT
+                            placeholder="Note (optional), e.g. new pot size or mix"
+                            maxlength="500"
+                            prop:value=note
+                            on:input=move |ev| set_note.set(event_target_value(&ev))
+                        />
+                        <div class="flex gap-3 items-center mt-3">
+                            <button class=BTN_PRIMARY
+                                disabled=move || plant_id.get().is_empty() || is_saving.get() || saved_msg.get().is_some()
+                                title=move || if plant_id.get().is_empty() { "Pick the plant first" } else { "Save as a repot in the journal" }
+                                on:click=save
+                            >{move || if is_saving.get() { "Saving..." } else { "Save to Journal" }}</button>
+                            {move || saved_msg.get().map(|msg| view! {
+                                <span class="text-sm font-semibold text-primary-light">{msg}</span>
+                            })}
+                        </div>
+                        <p class="mt-3 mb-0 text-xs text-stone-500">"An estimate from one photo; trim anything mushy or hollow regardless."</p>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="flex justify-center">
+                        {move || if is_assessing.get() {
+                            view! {
+                                <button class="flex gap-2 items-center py-3 px-6 text-sm font-semibold text-white rounded-lg border-none cursor-not-allowed bg-primary/70" disabled>
+                                    <div class="w-4 h-4 rounded-full border-2 border-white animate-spin border-t-transparent"></div>
+                                    "Assessing..."
+                                </button>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <button class=BTN_PRIMARY disabled=move || photo.get().is_none() on:click=assess>"Assess Roots"</button>
+                            }.into_any()
+                        }}
+                    </div>
+                }.into_any()
+            }}
+        </div>
+    }.into_any()
+}
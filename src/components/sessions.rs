@@ -0,0 +1,104 @@
+use leptos::prelude::*;
+
+/// Active session list with per-device and "sign out everywhere" controls for the settings modal.
+#[component]
+pub fn SessionSettings() -> impl IntoView {
+    let sessions = Resource::new(|| (), |_| crate::server_fns::auth::list_sessions());
+    let (is_revoking, set_is_revoking) = signal(false);
+    let (status_msg, set_status_msg) = signal(None::<String>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let revoke = move |id: String| {
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::auth::revoke_session(id.clone()).await {
+                Ok(()) => sessions.refetch(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.session_revoke", &format!("Failed to revoke session: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let revoke_others = move |_| {
+        set_is_revoking.set(true);
+        set_error_msg.set(None);
+        set_status_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::auth::revoke_other_sessions().await {
+                Ok(count) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.sessions_revoked", "Signed out other sessions", &[("count", &count.to_string())]);
+                    set_status_msg.set(Some(match count {
+                        0 => "No other sessions were signed in.".to_string(),
+                        1 => "Signed out 1 other session.".to_string(),
+                        n => format!("Signed out {} other sessions.", n),
+                    }));
+                    sessions.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.sessions_revoke", &format!("Failed to sign out other sessions: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_revoking.set(false);
+        });
+    };
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || sessions.get().map(|result| {
+                let list = result.unwrap_or_default();
+                view! {
+                    <div class="flex flex-col gap-1.5 mb-3">
+                        {list.into_iter().map(|s| {
+                            let id = s.id.clone();
+                            let seen = s.last_seen_at
+                                .map(|t| format!("active {}", super::format_time_ago(&t)))
+                                .unwrap_or_else(|| "activity unknown".into());
+                            let detail = [
+                                s.signed_in_at.map(|t| format!("Signed in {}", t.format("%b %d, %Y"))),
+                                s.ip.clone(),
+                                Some(seen),
+                            ].into_iter().flatten().collect::<Vec<_>>().join(" \u{00B7} ");
+                            view! {
+                                <div class="flex justify-between items-center py-2 px-3 text-sm rounded-lg bg-secondary/30">
+                                    <div>
+                                        <div class="font-medium text-stone-700 dark:text-stone-300">{s.device}</div>
+                                        <div class="text-[11px] text-stone-400">{detail}</div>
+                                    </div>
+                                    {if s.current {
+                                        view! { <span class="py-1 px-2 text-xs font-medium text-primary">"This device"</span> }.into_any()
+                                    } else {
+                                        view! {
+                                            <button
+                                                class="py-1 px-2 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                                on:click=move |_| revoke(id.clone())
+                                            >"Sign out"</button>
+                                        }.into_any()
+                                    }}
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }
+            })}
+        </Suspense>
+        <button
+            class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-danger bg-danger/10 hover:bg-danger/20"
+            disabled=move || is_revoking.get()
+            on:click=revoke_others
+        >
+            {move || if is_revoking.get() { "Signing out..." } else { "Sign Out Everywhere Else" }}
+        </button>
+        {move || status_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-stone-500">{msg}</p>
+        })}
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
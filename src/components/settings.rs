@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use crate::model::AccessibilityPrefs;
 use crate::orchid::{GrowingZone, HardwareDevice};
 use super::{MODAL_OVERLAY, MODAL_CONTENT, MODAL_HEADER, BTN_PRIMARY, BTN_CLOSE, BTN_SECONDARY, BTN_DANGER};
 
@@ -13,15 +14,40 @@ pub fn SettingsModal(
     devices: Vec<HardwareDevice>,
     initial_temp_unit: String,
     initial_hemisphere: String,
+    #[prop(optional)] initial_overdue_grace_days: u32,
     #[prop(optional)] initial_collection_public: bool,
+    #[prop(optional)] initial_accessibility: AccessibilityPrefs,
     #[prop(optional)] username: String,
+    #[prop(optional)] is_admin: bool,
     on_close: impl Fn(String) + 'static + Copy + Send + Sync,
     on_zones_changed: impl Fn() + 'static + Copy + Send + Sync,
+    on_orchids_changed: impl Fn() + 'static + Copy + Send + Sync,
+    on_grace_changed: impl Fn(u32) + 'static + Copy + Send + Sync,
+    on_accessibility_changed: impl Fn(AccessibilityPrefs) + 'static + Copy + Send + Sync,
     on_show_wizard: impl Fn(GrowingZone) + 'static + Copy + Send + Sync,
 ) -> impl IntoView {
     let (temp_unit, set_temp_unit) = signal(initial_temp_unit);
     let (hemisphere, set_hemisphere) = signal(initial_hemisphere);
+    let (grace_days, set_grace_days) = signal(initial_overdue_grace_days);
     let (collection_public, set_collection_public) = signal(initial_collection_public);
+    let accessibility = RwSignal::new(initial_accessibility);
+
+    // Applies immediately, then persists; the previous setting is restored if saving fails
+    let update_accessibility = move |change: fn(&mut AccessibilityPrefs)| {
+        let previous = accessibility.get_untracked();
+        let mut prefs = previous;
+        change(&mut prefs);
+        accessibility.set(prefs);
+        on_accessibility_changed(prefs);
+        leptos::task::spawn_local(async move {
+            if let Err(_e) = crate::server_fns::preferences::save_accessibility_prefs(prefs).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("settings.save_accessibility", &format!("Failed to save accessibility settings: {}", _e), &[]);
+                accessibility.set(previous);
+                on_accessibility_changed(previous);
+            }
+        });
+    };
     let username_stored = StoredValue::new(username);
     let (local_devices, set_local_devices) = signal(devices);
 
@@ -103,6 +129,18 @@ pub fn SettingsModal(
         });
     };
 
+    let on_blueprint_imported = move || {
+        leptos::task::spawn_local(async move {
+            if let Ok(zones) = crate::server_fns::zones::get_zones().await {
+                set_local_zones.set(zones);
+            }
+            if let Ok(devices) = crate::server_fns::devices::get_devices().await {
+                set_local_devices.set(devices);
+            }
+            on_zones_changed();
+        });
+    };
+
     view! {
         <div class=MODAL_OVERLAY>
             <div class=MODAL_CONTENT>
@@ -133,6 +171,34 @@ pub fn SettingsModal(
                             <option value="S">"Southern Hemisphere"</option>
                         </select>
                     </div>
+                    <div class="mb-4">
+                        <label>"Overdue Grace Period (days):"</label>
+                        <input
+                            type="number"
+                            min="0"
+                            max=crate::orchid::MAX_OVERDUE_GRACE_DAYS.to_string()
+                            on:change=move |ev| {
+                                let Ok(days) = event_target_value(&ev).parse::<u32>() else { return };
+                                let days = days.min(crate::orchid::MAX_OVERDUE_GRACE_DAYS);
+                                set_grace_days.set(days);
+                                leptos::task::spawn_local(async move {
+                                    match crate::server_fns::preferences::save_overdue_grace_days(days).await {
+                                        Ok(()) => {
+                                            #[cfg(feature = "hydrate")]
+                                            crate::server_fns::telemetry::emit_info("settings.save_overdue_grace", "Overdue grace saved", &[("days", &days.to_string())]);
+                                            on_grace_changed(days);
+                                        }
+                                        Err(_e) => {
+                                            #[cfg(feature = "hydrate")]
+                                            crate::server_fns::telemetry::emit_error("settings.save_overdue_grace", &format!("Failed to save overdue grace: {}", _e), &[("days", &days.to_string())]);
+                                        }
+                                    }
+                                });
+                            }
+                            prop:value=move || grace_days.get().to_string()
+                        />
+                        <p class="mt-1 mb-0 text-xs text-stone-500">"Plants only show as overdue, and push alerts only fire, once watering is this many days late. Individual plants can override it."</p>
+                    </div>
                     <div class="mb-4">
                         <label>"Temperature Unit:"</label>
                         <select
@@ -158,6 +224,50 @@ pub fn SettingsModal(
 
                     <hr class="my-6 border-stone-200 dark:border-stone-700" />
 
+                    // Accessibility toggles
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Accessibility"</h3>
+                        <div class="flex flex-col gap-3">
+                            <SettingToggle
+                                label="Reduce motion"
+                                description="Turn off animations, fades and the first-bloom confetti"
+                                checked=Signal::derive(move || accessibility.get().reduced_motion)
+                                on_toggle=move || update_accessibility(|p| p.reduced_motion = !p.reduced_motion)
+                            />
+                            <SettingToggle
+                                label="High contrast"
+                                description="Darker text, stronger borders and focus outlines"
+                                checked=Signal::derive(move || accessibility.get().high_contrast)
+                                on_toggle=move || update_accessibility(|p| p.high_contrast = !p.high_contrast)
+                            />
+                        </div>
+                        <div class="flex justify-between items-center mt-4">
+                            <div>
+                                <div class="text-sm font-medium text-stone-700 dark:text-stone-300">"Getting-started tour"</div>
+                                <div class="text-xs text-stone-500">"Show the step-by-step hints again"</div>
+                            </div>
+                            <button class=BTN_SECONDARY on:click=move |_| {
+                                leptos::task::spawn_local(async move {
+                                    match crate::server_fns::preferences::save_tour_progress(crate::tour::TourProgress::default()).await {
+                                        Ok(()) => {
+                                            #[cfg(feature = "hydrate")]
+                                            if let Some(window) = web_sys::window() {
+                                                let _ = window.location().set_href("/");
+                                            }
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!("Failed to restart tour: {}", e);
+                                            #[cfg(feature = "hydrate")]
+                                            crate::server_fns::telemetry::emit_warn("settings.restart_tour", &format!("Failed to restart tour: {}", e), &[]);
+                                        }
+                                    }
+                                });
+                            }>"Restart"</button>
+                        </div>
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
                     // Public Collection toggle
                     <div class="mb-6">
                         <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Public Collection"</h3>
@@ -203,6 +313,7 @@ pub fn SettingsModal(
                                         <div class="text-xs font-medium text-stone-500 dark:text-stone-400">"Shareable link:"</div>
                                         <code class="text-sm text-primary dark:text-primary-light">{url}</code>
                                     </div>
+                                    <PublicFeedSettings />
                                 }
                             })}
                         </div>
@@ -210,6 +321,33 @@ pub fn SettingsModal(
 
                     <hr class="my-6 border-stone-200 dark:border-stone-700" />
 
+                    // Event types section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Event Types"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Add your own log categories (pollination, pest sightings, staking) with an icon and color."</p>
+                        <EventTypeSettings />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Note templates section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Note Templates"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Reusable journal notes, like a repotting checklist, that fill in their fields and event type when picked."</p>
+                        <crate::components::note_templates::NoteTemplateSettings />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Quick Log actions section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Quick Log Actions"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Choose which one-tap log buttons appear on each plant, and in what order."</p>
+                        <crate::components::quick_actions::QuickActionsEditor />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
                     // Hardware Devices section
                     <div class="mb-6">
                         <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Hardware Devices"</h3>
@@ -321,10 +459,113 @@ pub fn SettingsModal(
 
                     <hr class="my-6 border-stone-200 dark:border-stone-700" />
 
+                    // Climate goals section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Climate Goals"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Set a target range per zone. Each week you'll get a report card with time in range, the worst excursions, and the trend vs last week."</p>
+                        <crate::components::report_cards::ClimateGoalsEditor zones=local_zones temp_unit=temp_unit />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Energy costs section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Energy Costs"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"List each zone's lights, heaters, humidifiers, and fans with their wattage and hours per day. Add your electricity price to see what each zone costs to run per month."</p>
+                        <crate::components::zone_economics::EnergySettingsEditor zones=local_zones />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Water quality section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Water Quality"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Log pH and EC or TDS meter readings for each water source. You'll be warned when a source drifts out of range for the genera in your collection."</p>
+                        <crate::components::water_quality::WaterQualityEditor />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Care automation rules section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Automation Rules"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Tell Velamen what to do when conditions change: alert you when the greenhouse stays hot, suggest extra misting for humidity lovers, or pause fertilizer while plants rest."</p>
+                        <crate::components::care_rules::CareRuleSettings zones=local_zones temp_unit=temp_unit />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Blueprint section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Environment Blueprint"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Copy your zones, devices, climate goals and automation rules to another instance or share your setup with another grower. Plants and passwords or API tokens are never included."</p>
+                        <crate::components::blueprint::BlueprintTransfer on_imported=on_blueprint_imported />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Import section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Import from Other Apps"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Bring your plants, watering schedules and care history over from Planta, Greg, or any CSV export. Imported plants are added alongside your existing collection."</p>
+                        <crate::components::import_wizard::ImportWizard on_imported=on_orchids_changed />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Bulk photo upload section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Bulk Photo Upload"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Moving over from a camera roll? Drop in a batch of photos or a whole folder, pick the plant and date for each, and they are added to each plant's journal on the day they were taken."</p>
+                        <crate::components::bulk_photo_upload::BulkPhotoUpload />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Household section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Household"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Share this collection with a partner or plant-sitter. Caretakers can water and log care; viewers can only look."</p>
+                        <crate::components::household::HouseholdSettings />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
                     // Notifications section
                     <div class="mb-6">
                         <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Notifications"</h3>
                         <NotificationSettings />
+                        <AlertWebhookSettings />
+                        <EscalationSettings />
+                        <NtfySettings />
+                        <NotificationHistory />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Passkeys section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Passkeys"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Sign in with Face ID, a fingerprint, or a security key instead of your password."</p>
+                        <crate::components::passkeys::PasskeySettings />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // Active sessions section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Active Sessions"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Devices currently signed in to your account. Sign out any you don't recognise."</p>
+                        <crate::components::sessions::SessionSettings />
+                    </div>
+
+                    <hr class="my-6 border-stone-200 dark:border-stone-700" />
+
+                    // API tokens section
+                    <div class="mb-6">
+                        <h3 class="mb-4 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"API Tokens"</h3>
+                        <p class="mt-0 mb-3 text-xs text-stone-500">"Let scripts and integrations, such as a greenhouse camera, upload photos or read your collection without your password."</p>
+                        <crate::components::api_tokens::ApiTokenSettings />
                     </div>
 
                     <hr class="my-6 border-stone-200 dark:border-stone-700" />
@@ -352,6 +593,32 @@ pub fn SettingsModal(
                             }
                         >"Log Out"</button>
 
+                        // Instance admin
+                        {is_admin.then(|| view! {
+                            <div class="pt-4 mt-6 border-t border-stone-200 dark:border-stone-700">
+                                <p class="mb-1 text-xs font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Instance Admin"</p>
+                                <p class="mt-0 mb-2 text-xs text-stone-500 dark:text-stone-400">"Manage accounts and check storage for everyone on this server."</p>
+                                <a href="/admin" class="text-sm font-medium underline text-primary">"Open admin panel"</a>
+                            </div>
+                        })}
+
+                        // Your data
+                        <div class="pt-4 mt-6 border-t border-stone-200 dark:border-stone-700">
+                            <p class="mb-1 text-xs font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Your Data"</p>
+                            <p class="mt-0 mb-2 text-xs text-stone-500 dark:text-stone-400">"Download a copy of everything stored about you: plants, journal, zones, readings, settings and photos."</p>
+                            <crate::components::data_export::DataExportButton />
+                            <p class="mt-3 mb-2 text-xs text-stone-500 dark:text-stone-400">"Every journal photo at full size, in one folder per plant."</p>
+                            <a
+                                href="/api/images/archive"
+                                download
+                                class="block py-2 px-4 w-full text-sm font-semibold text-center no-underline rounded-lg transition-colors text-primary bg-primary/10 hover:bg-primary/20"
+                            >
+                                "Download All Photos"
+                            </a>
+                            <p class="mt-3 mb-2 text-xs text-stone-500 dark:text-stone-400">"Climate history as Parquet files, one per zone per year, for pandas, Polars or DuckDB."</p>
+                            <crate::components::data_export::ClimateExportButton />
+                        </div>
+
                         // Danger Zone
                         <div class="pt-4 mt-6 border-t border-red-200/60 dark:border-red-900/40">
                             <p class="mb-1 text-xs font-semibold tracking-wider text-red-600 uppercase dark:text-red-400">"Danger Zone"</p>
@@ -388,6 +655,7 @@ pub fn SettingsModal(
                                                     <li>"Uploaded photos"</li>
                                                     <li>"Hardware device credentials (Tempest, AC Infinity)"</li>
                                                     <li>"Notification subscriptions"</li>
+                                                    <li>"Sessions on all of your devices"</li>
                                                     <li>"All account settings and data"</li>
                                                 </ul>
                                                 <p class="mb-5 text-sm font-semibold text-red-600 dark:text-red-400">"This action is permanent and cannot be undone."</p>
@@ -437,7 +705,7 @@ pub fn SettingsModal(
                                                         set_is_deleting.set(true);
                                                         set_delete_error.set(String::new());
                                                         leptos::task::spawn_local(async move {
-                                                            match crate::server_fns::auth::delete_account(typed).await {
+                                                            match crate::server_fns::auth::delete_my_account(typed).await {
                                                                 Ok(()) => {
                                                                     #[cfg(feature = "hydrate")]
                                                                     {
@@ -596,9 +864,10 @@ fn ZoneCard(
 
 /// Data source configuration form for a single zone.
 /// Supports three modes:
-/// - Device-linked: tempest/ac_infinity via shared hardware_device (picker shown)
+/// - Device-linked: tempest/ac_infinity/mqtt/sensorpush via shared hardware_device (picker shown)
 /// - Legacy direct: tempest/ac_infinity with zone-level credentials (when no devices exist)
 /// - Weather API: always zone-level lat/lon config
+/// - HTTP push: sensors POST readings with a per-zone token (no polling)
 #[component]
 fn DataSourceConfig(
     zone_id: String,
@@ -700,6 +969,8 @@ fn DataSourceConfig(
         match prov.as_str() {
             "tempest" => devs.iter().any(|d| d.device_type == "tempest"),
             "ac_infinity" => devs.iter().any(|d| d.device_type == "ac_infinity"),
+            "mqtt" => devs.iter().any(|d| d.device_type == "mqtt"),
+            "sensorpush" => devs.iter().any(|d| d.device_type == "sensorpush"),
             _ => false,
         }
     };
@@ -745,6 +1016,15 @@ fn DataSourceConfig(
     let do_save = move || {
         let prov = provider.get();
         let zid = zone_id_save.get_value();
+        // MQTT and SensorPush have no zone-level config; readings only arrive through a device.
+        if prov == "mqtt" && selected_device_id.get().is_empty() {
+            set_test_result.set(Some(Err("Select an MQTT device first".into())));
+            return;
+        }
+        if prov == "sensorpush" && selected_device_id.get().is_empty() {
+            set_test_result.set(Some(Err("Select a SensorPush device first".into())));
+            return;
+        }
         set_is_saving_ds.set(true);
 
         if prov.is_empty() {
@@ -775,7 +1055,8 @@ fn DataSourceConfig(
         } else if has_devices_for_provider() && !selected_device_id.get().is_empty() {
             // Device-linked save: link zone to shared device
             let dev_id = selected_device_id.get();
-            let port = if prov == "ac_infinity" {
+            // For Tempest devices the port selects the station, for MQTT the topic, for SensorPush the sensor (1-based).
+            let port = if prov == "ac_infinity" || prov == "tempest" || prov == "mqtt" || prov == "sensorpush" {
                 Some(selected_port.get().parse::<i32>().unwrap_or(1))
             } else {
                 None
@@ -856,6 +1137,9 @@ fn DataSourceConfig(
                     <option value="">"None"</option>
                     <option value="tempest">"Tempest Weather Station"</option>
                     <option value="ac_infinity">"AC Infinity Controller"</option>
+                    <option value="mqtt">"MQTT Sensor"</option>
+                    <option value="sensorpush">"SensorPush Sensor"</option>
+                    <option value="http">"HTTP Push (ESP32 / DIY)"</option>
                     <option value="weather_api">"Weather API (Outdoor)"</option>
                 </select>
             </div>
@@ -890,13 +1174,18 @@ fn DataSourceConfig(
                                 </div>
                             }.into_any()
                         } else {
-                            // Shared devices available — show picker
+                            // Shared devices available — show picker, plus a station
+                            // picker when the selected device covers several stations.
+                            let devices_for_stations = StoredValue::new(filtered.clone());
                             view! {
                                 <div class="p-3 mb-3 rounded-lg bg-sky-50/50 dark:bg-sky-900/10">
                                     <label class=LABEL_SM>"Device"</label>
                                     <select class=INPUT_SM
                                         prop:value=selected_device_id
-                                        on:change=move |ev| set_selected_device_id.set(event_target_value(&ev))
+                                        on:change=move |ev| {
+                                            set_selected_device_id.set(event_target_value(&ev));
+                                            set_selected_port.set("1".to_string());
+                                        }
                                     >
                                         <option value="">"Select device..."</option>
                                         {filtered.into_iter().map(|d| {
@@ -904,6 +1193,27 @@ fn DataSourceConfig(
                                             view! { <option value=id>{d.name}</option> }
                                         }).collect::<Vec<_>>()}
                                     </select>
+                                    {move || {
+                                        let sel = selected_device_id.get();
+                                        let stations = devices_for_stations.get_value().into_iter()
+                                            .find(|d| d.id == sel)
+                                            .map(|d| d.tempest_stations())
+                                            .unwrap_or_default();
+                                        (stations.len() > 1).then(|| view! {
+                                            <div class="mt-3">
+                                                <label class=LABEL_SM>"Station"</label>
+                                                <select class=INPUT_SM
+                                                    prop:value=selected_port
+                                                    on:change=move |ev| set_selected_port.set(event_target_value(&ev))
+                                                >
+                                                    {stations.into_iter().enumerate().map(|(i, id)| {
+                                                        let value = (i + 1).to_string();
+                                                        view! { <option value=value>{format!("Station {}", id)}</option> }
+                                                    }).collect::<Vec<_>>()}
+                                                </select>
+                                            </div>
+                                        })
+                                    }}
                                 </div>
                             }.into_any()
                         }
@@ -983,6 +1293,109 @@ fn DataSourceConfig(
                             }.into_any()
                         }
                     }
+                    "mqtt" => {
+                        let filtered: Vec<HardwareDevice> = devices.get().into_iter()
+                            .filter(|d| d.device_type == "mqtt")
+                            .collect();
+                        if filtered.is_empty() {
+                            view! {
+                                <p class="mb-3 text-xs text-stone-500 dark:text-stone-400">"Add an MQTT broker under Devices first, then pick one of its topics here."</p>
+                            }.into_any()
+                        } else {
+                            let devices_for_topics = StoredValue::new(filtered.clone());
+                            view! {
+                                <div class="p-3 mb-3 rounded-lg bg-emerald-50/50 dark:bg-emerald-900/10">
+                                    <label class=LABEL_SM>"Device"</label>
+                                    <select class=INPUT_SM
+                                        prop:value=selected_device_id
+                                        on:change=move |ev| {
+                                            set_selected_device_id.set(event_target_value(&ev));
+                                            set_selected_port.set("1".to_string());
+                                        }
+                                    >
+                                        <option value="">"Select device..."</option>
+                                        {filtered.into_iter().map(|d| {
+                                            let id = d.id.clone();
+                                            view! { <option value=id>{d.name}</option> }
+                                        }).collect::<Vec<_>>()}
+                                    </select>
+                                    {move || {
+                                        let sel = selected_device_id.get();
+                                        let topics = devices_for_topics.get_value().into_iter()
+                                            .find(|d| d.id == sel)
+                                            .map(|d| d.mqtt_topics())
+                                            .unwrap_or_default();
+                                        (!topics.is_empty()).then(|| view! {
+                                            <div class="mt-3">
+                                                <label class=LABEL_SM>"Topic"</label>
+                                                <select class=INPUT_SM
+                                                    prop:value=selected_port
+                                                    on:change=move |ev| set_selected_port.set(event_target_value(&ev))
+                                                >
+                                                    {topics.into_iter().enumerate().map(|(i, topic)| {
+                                                        let value = (i + 1).to_string();
+                                                        view! { <option value=value>{topic}</option> }
+                                                    }).collect::<Vec<_>>()}
+                                                </select>
+                                            </div>
+                                        })
+                                    }}
+                                </div>
+                            }.into_any()
+                        }
+                    }
+                    "sensorpush" => {
+                        let filtered: Vec<HardwareDevice> = devices.get().into_iter()
+                            .filter(|d| d.device_type == "sensorpush")
+                            .collect();
+                        if filtered.is_empty() {
+                            view! {
+                                <p class="mb-3 text-xs text-stone-500 dark:text-stone-400">"Add your SensorPush account under Devices first, then pick one of its sensors here."</p>
+                            }.into_any()
+                        } else {
+                            let devices_for_sensors = StoredValue::new(filtered.clone());
+                            view! {
+                                <div class="p-3 mb-3 rounded-lg bg-orange-50/50 dark:bg-orange-900/10">
+                                    <label class=LABEL_SM>"Device"</label>
+                                    <select class=INPUT_SM
+                                        prop:value=selected_device_id
+                                        on:change=move |ev| {
+                                            set_selected_device_id.set(event_target_value(&ev));
+                                            set_selected_port.set("1".to_string());
+                                        }
+                                    >
+                                        <option value="">"Select device..."</option>
+                                        {filtered.into_iter().map(|d| {
+                                            let id = d.id.clone();
+                                            view! { <option value=id>{d.name}</option> }
+                                        }).collect::<Vec<_>>()}
+                                    </select>
+                                    {move || {
+                                        let sel = selected_device_id.get();
+                                        let sensors = devices_for_sensors.get_value().into_iter()
+                                            .find(|d| d.id == sel)
+                                            .map(|d| d.sensorpush_sensors())
+                                            .unwrap_or_default();
+                                        (!sensors.is_empty()).then(|| view! {
+                                            <div class="mt-3">
+                                                <label class=LABEL_SM>"Sensor"</label>
+                                                <select class=INPUT_SM
+                                                    prop:value=selected_port
+                                                    on:change=move |ev| set_selected_port.set(event_target_value(&ev))
+                                                >
+                                                    {sensors.into_iter().enumerate().map(|(i, (id, name))| {
+                                                        let value = (i + 1).to_string();
+                                                        let label = if name.is_empty() { id } else { name };
+                                                        view! { <option value=value>{label}</option> }
+                                                    }).collect::<Vec<_>>()}
+                                                </select>
+                                            </div>
+                                        })
+                                    }}
+                                </div>
+                            }.into_any()
+                        }
+                    }
                     "weather_api" => view! {
                         <div class="p-3 mb-3 rounded-lg bg-emerald-50/50 dark:bg-emerald-900/10">
                             <div class="flex gap-3">
@@ -1009,20 +1422,43 @@ fn DataSourceConfig(
                         // Currently linked to a device — show which one
                         let dev_id = selected_device_id.get();
                         let devs = devices.get();
-                        let device_name = devs.iter()
-                            .find(|d| d.id == dev_id)
+                        let linked = devs.iter().find(|d| d.id == dev_id);
+                        let device_name = linked
                             .map(|d| format!("{} ({})", d.name, d.device_type))
                             .unwrap_or_else(|| "Unknown device".to_string());
+                        let port_label = match (linked, current_hardware_port) {
+                            (Some(d), Some(p)) if d.device_type == "tempest" => d.tempest_stations()
+                                .get((p.max(1) - 1) as usize)
+                                .map(|id| format!(" (Station {})", id))
+                                .unwrap_or_default(),
+                            (Some(d), Some(p)) if d.device_type == "mqtt" => d.mqtt_topics()
+                                .get((p.max(1) - 1) as usize)
+                                .map(|topic| format!(" (Topic {})", topic))
+                                .unwrap_or_default(),
+                            (Some(d), Some(p)) if d.device_type == "sensorpush" => d.sensorpush_sensors()
+                                .get((p.max(1) - 1) as usize)
+                                .map(|(id, name)| format!(" (Sensor {})", if name.is_empty() { id } else { name }))
+                                .unwrap_or_default(),
+                            (_, Some(p)) => format!(" (Port {})", p),
+                            _ => String::new(),
+                        };
                         view! {
                             <div class="p-3 mb-3 rounded-lg bg-emerald-50/50 dark:bg-emerald-900/10">
                                 <p class="text-sm text-stone-600 dark:text-stone-400">
                                     "Linked to: " <strong>{device_name}</strong>
-                                    {current_hardware_port.map(|p| format!(" (Port {})", p)).unwrap_or_default()}
+                                    {port_label}
                                 </p>
                                 <p class="mt-1 text-xs text-stone-500">"Change the data source type above to reconfigure."</p>
                             </div>
                         }.into_any()
                     }
+                    "http" => view! {
+                        <HttpIngestSettings
+                            zone_id=zone_id_save.get_value()
+                            on_saved=on_saved
+                            set_local_zones=set_local_zones
+                        />
+                    }.into_any(),
                     _ => view! {
                         <p class="mb-3 text-xs text-stone-500 dark:text-stone-400">"No data source configured for this zone."</p>
                     }.into_any(),
@@ -1054,7 +1490,7 @@ fn DataSourceConfig(
                             >"Remove Data Source"</button>
                         </div>
                     }.into_any()
-                } else if !prov.is_empty() && prov != "device_linked" {
+                } else if !prov.is_empty() && prov != "device_linked" && prov != "http" {
                     view! {
                         <div class="flex gap-2">
                             <button
@@ -1081,6 +1517,364 @@ fn DataSourceConfig(
     }
 }
 
+/// Create/revoke controls for a zone's HTTP ingest token, with the URL and an example body shown once.
+#[component]
+fn HttpIngestSettings(
+    zone_id: String,
+    on_saved: impl Fn() + 'static + Copy + Send + Sync,
+    set_local_zones: WriteSignal<Vec<GrowingZone>>,
+) -> impl IntoView {
+    let zone_id = StoredValue::new(zone_id);
+    let prefix = Resource::new(
+        move || zone_id.get_value(),
+        crate::server_fns::ingest::get_zone_ingest_prefix,
+    );
+    let (is_busy, set_is_busy) = signal(false);
+    let (new_token, set_new_token) = signal(None::<String>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let create = move |_| {
+        set_is_busy.set(true);
+        set_error_msg.set(None);
+        let zid = zone_id.get_value();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::ingest::create_zone_ingest_token(zid.clone()).await {
+                Ok(token) => {
+                    set_local_zones.update(|zones| {
+                        if let Some(z) = zones.iter_mut().find(|z| z.id == zid) {
+                            z.data_source_type = Some("http".into());
+                            z.data_source_config = String::new();
+                            z.hardware_device_id = None;
+                            z.hardware_port = None;
+                        }
+                    });
+                    set_new_token.set(Some(token));
+                    prefix.refetch();
+                    on_saved();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.ingest_token_create", &format!("Failed to create ingest token: {}", e), &[("zone_id", zid.as_str())]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    let revoke = move |_| {
+        set_is_busy.set(true);
+        set_error_msg.set(None);
+        let zid = zone_id.get_value();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::ingest::revoke_zone_ingest_token(zid.clone()).await {
+                Ok(()) => {
+                    set_local_zones.update(|zones| {
+                        if let Some(z) = zones.iter_mut().find(|z| z.id == zid && z.data_source_type.as_deref() == Some("http")) {
+                            z.data_source_type = None;
+                        }
+                    });
+                    set_new_token.set(None);
+                    prefix.refetch();
+                    on_saved();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.ingest_token_revoke", &format!("Failed to revoke ingest token: {}", e), &[("zone_id", zid.as_str())]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 mb-3 rounded-lg bg-teal-50/50 dark:bg-teal-900/10">
+            <p class="mt-0 mb-2 text-xs text-stone-500 dark:text-stone-400">
+                "Sensors POST JSON like "
+                <code>{r#"{"temperature": 22.4, "humidity": 61, "lux": 12000}"#}</code>
+                " to a private URL. One reading per minute is stored."
+            </p>
+            <Suspense fallback=|| ()>
+                {move || prefix.get().map(|result| {
+                    let current = result.ok().flatten();
+                    let enabled = current.is_some();
+                    view! {
+                        <div class="flex gap-2 items-center">
+                            {current.map(|p| view! {
+                                <code class="flex-1 text-[11px] text-stone-400">{format!("{}\u{2026}", p)}</code>
+                            })}
+                            <button
+                                class="py-1.5 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                                disabled=move || is_busy.get()
+                                on:click=create
+                            >
+                                {if enabled { "New URL" } else { "Create Ingest URL" }}
+                            </button>
+                            {enabled.then(|| view! {
+                                <button
+                                    class="py-1.5 px-3 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                    disabled=move || is_busy.get()
+                                    on:click=revoke
+                                >"Revoke"</button>
+                            })}
+                        </div>
+                    }
+                })}
+            </Suspense>
+            {move || new_token.get().map(|token| view! {
+                <div class="p-3 mt-3 rounded-lg bg-primary/5 dark:bg-primary-light/5">
+                    <div class="text-xs font-medium text-stone-500 dark:text-stone-400">"Copy this URL into your sensor now. It won't be shown again:"</div>
+                    <code class="text-xs break-all text-primary dark:text-primary-light">{format!("POST /api/ingest/{}", token)}</code>
+                    <div class="mt-1 text-[11px] text-stone-400">"Creating a new URL stops the old one."</div>
+                </div>
+            })}
+            {move || error_msg.get().map(|msg| view! {
+                <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+            })}
+        </div>
+    }
+}
+
+/// A labelled on/off switch row for boolean settings.
+#[component]
+fn SettingToggle(
+    label: &'static str,
+    description: &'static str,
+    checked: Signal<bool>,
+    on_toggle: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    view! {
+        <div class="flex justify-between items-center">
+            <div>
+                <div class="text-sm font-medium text-stone-700 dark:text-stone-300">{label}</div>
+                <div class="text-xs text-stone-500">{description}</div>
+            </div>
+            <button
+                role="switch"
+                aria-label=label
+                aria-checked=move || checked.get().to_string()
+                class=move || if checked.get() {
+                    "relative w-11 h-6 bg-primary rounded-full transition-colors cursor-pointer border-none shrink-0"
+                } else {
+                    "relative w-11 h-6 bg-stone-300 dark:bg-stone-600 rounded-full transition-colors cursor-pointer border-none shrink-0"
+                }
+                on:click=move |_| on_toggle()
+            >
+                <span class=move || if checked.get() {
+                    "absolute top-0.5 left-5.5 w-5 h-5 bg-white rounded-full transition-all shadow-sm"
+                } else {
+                    "absolute top-0.5 left-0.5 w-5 h-5 bg-white rounded-full transition-all shadow-sm"
+                }></span>
+            </button>
+        </div>
+    }
+}
+
+/// Create/revoke controls for the public JSON feed, shown while the collection is public.
+#[component]
+fn PublicFeedSettings() -> impl IntoView {
+    let status = Resource::new(|| (), |_| crate::server_fns::preferences::get_public_feed_status());
+    let (is_busy, set_is_busy) = signal(false);
+    let (new_key, set_new_key) = signal(None::<String>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let create = move |_| {
+        set_is_busy.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::preferences::create_public_feed_key().await {
+                Ok(key) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.public_feed_created", "Public feed key created", &[]);
+                    set_new_key.set(Some(key));
+                    status.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.public_feed_create", &format!("Failed to create public feed key: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    let revoke = move |_| {
+        set_is_busy.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::preferences::revoke_public_feed_key().await {
+                Ok(()) => {
+                    set_new_key.set(None);
+                    status.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.public_feed_revoke", &format!("Failed to revoke public feed key: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 text-sm rounded-lg border border-stone-200 dark:border-stone-700">
+            <div class="text-sm font-medium text-stone-700 dark:text-stone-300">"JSON feed"</div>
+            <div class="mb-2 text-xs text-stone-500">"Let community sites and Discord bots show your plants and blooms. Notes and care schedules are never included."</div>
+            <Suspense fallback=|| ()>
+                {move || status.get().map(|result| {
+                    let enabled = result.as_ref().map(|s| s.enabled).unwrap_or(false);
+                    let prefix = result.ok().and_then(|s| s.prefix).unwrap_or_default();
+                    view! {
+                        <div class="flex gap-2 items-center">
+                            {enabled.then(|| view! {
+                                <code class="flex-1 text-[11px] text-stone-400">{format!("{}\u{2026}", prefix)}</code>
+                            })}
+                            <button
+                                class="py-1.5 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                                disabled=move || is_busy.get()
+                                on:click=create
+                            >
+                                {if enabled { "New Key" } else { "Create Feed Key" }}
+                            </button>
+                            {enabled.then(|| view! {
+                                <button
+                                    class="py-1.5 px-3 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                    disabled=move || is_busy.get()
+                                    on:click=revoke
+                                >"Revoke"</button>
+                            })}
+                        </div>
+                    }
+                })}
+            </Suspense>
+            {move || new_key.get().map(|key| view! {
+                <div class="p-3 mt-3 rounded-lg bg-primary/5 dark:bg-primary-light/5">
+                    <div class="text-xs font-medium text-stone-500 dark:text-stone-400">"Copy this feed URL now. It won't be shown again:"</div>
+                    <code class="text-xs break-all text-primary dark:text-primary-light">{format!("/api/public/collection/{}", key)}</code>
+                    <div class="mt-1 text-[11px] text-stone-400">"Making your collection private turns the feed off too."</div>
+                </div>
+            })}
+            {move || error_msg.get().map(|msg| view! {
+                <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+            })}
+        </div>
+    }
+}
+
+/// Custom event type manager within the settings modal. Edits the
+/// `EventTypeRegistry` context directly so open views pick up changes.
+#[component]
+fn EventTypeSettings() -> impl IntoView {
+    use crate::components::event_types::{EventTypeRegistry, EVENT_COLORS, builtin_event_types};
+
+    let registry = use_context::<EventTypeRegistry>()
+        .unwrap_or_else(|| EventTypeRegistry(RwSignal::new(builtin_event_types())));
+    let (label, set_label) = signal(String::new());
+    let (emoji, set_emoji) = signal(String::new());
+    let (color, set_color) = signal("teal".to_string());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let add = move |_| {
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let (l, e, c) = (label.get(), emoji.get(), color.get());
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::event_types::create_event_type(l, e, c).await {
+                Ok(def) => {
+                    registry.0.update(|list| list.push(def));
+                    set_label.set(String::new());
+                    set_emoji.set(String::new());
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.create_event_type", &format!("Failed to create event type: {}", e), &[]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let remove = move |key: String| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::event_types::delete_event_type(key.clone()).await {
+                Ok(()) => registry.0.update(|list| list.retain(|e| e.builtin || e.key != key)),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.delete_event_type", &format!("Failed to delete event type: {}", e), &[("key", &key)]);
+                    set_error_msg.set(Some(format!("{}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-wrap gap-1.5 mb-3">
+            {move || registry.0.get().into_iter().map(|et| {
+                let classes = format!("inline-flex gap-1 items-center py-1 px-2.5 text-xs font-medium rounded-full {} {}", et.bg_class(), et.color_class());
+                let key = et.key.clone();
+                view! {
+                    <span class=classes>
+                        {format!("{} {}", et.emoji, et.label)}
+                        {(!et.builtin).then(|| view! {
+                            <button
+                                class="p-0 ml-0.5 text-xs bg-transparent border-none opacity-60 cursor-pointer hover:opacity-100 text-inherit"
+                                title="Remove"
+                                on:click=move |_| remove(key.clone())
+                            >
+                                "\u{2715}"
+                            </button>
+                        })}
+                    </span>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+        <div class="flex flex-wrap gap-2 items-end">
+            <div class="flex-1 min-w-[120px]">
+                <label class=LABEL_SM>"Label"</label>
+                <input type="text" class=INPUT_SM
+                    placeholder="e.g. Pollinated"
+                    prop:value=label
+                    on:input=move |ev| set_label.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="w-16">
+                <label class=LABEL_SM>"Icon"</label>
+                <input type="text" class=INPUT_SM
+                    placeholder="\u{1F41D}"
+                    prop:value=emoji
+                    on:input=move |ev| set_emoji.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="w-28">
+                <label class=LABEL_SM>"Color"</label>
+                <select class=INPUT_SM
+                    prop:value=color
+                    on:change=move |ev| set_color.set(event_target_value(&ev))
+                >
+                    {EVENT_COLORS.iter().map(|(name, _, _)| view! {
+                        <option value=*name>{*name}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+            </div>
+            <button
+                class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                disabled=move || is_saving.get() || label.get().trim().is_empty()
+                on:click=add
+            >
+                "Add Type"
+            </button>
+        </div>
+        {move || error_msg.get().map(|msg| view! {
+            <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+        })}
+    }
+}
+
 /// Notification settings section within the settings modal
 #[component]
 fn NotificationSettings() -> impl IntoView {
@@ -1221,6 +2015,362 @@ fn NotificationSettings() -> impl IntoView {
     }.into_any()
 }
 
+/// Discord or Slack webhook that the household's alerts are also posted to.
+#[component]
+fn AlertWebhookSettings() -> impl IntoView {
+    let status = Resource::new(|| (), |_| crate::server_fns::alerts::get_alert_webhook());
+    let (url, set_url) = signal(String::new());
+    let (is_busy, set_is_busy) = signal(false);
+    let (result_msg, set_result_msg) = signal::<Option<Result<String, String>>>(None);
+
+    let save = move |new_url: String| {
+        set_is_busy.set(true);
+        set_result_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::alerts::save_alert_webhook(new_url).await {
+                Ok(saved) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.alert_webhook_saved", "Alert webhook saved", &[]);
+                    set_url.set(String::new());
+                    set_result_msg.set(saved.map(|s| Ok(format!("Alerts will post to {}", s.kind.label()))));
+                    status.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.alert_webhook_save", &format!("Failed to save alert webhook: {}", e), &[]);
+                    set_result_msg.set(Some(Err(e.to_string())));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    let send_test = move |_| {
+        set_is_busy.set(true);
+        set_result_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::alerts::send_test_webhook().await {
+                Ok(msg) => set_result_msg.set(Some(Ok(msg))),
+                Err(e) => set_result_msg.set(Some(Err(e.to_string()))),
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 mt-3 text-sm rounded-lg border border-stone-200 dark:border-stone-700">
+            <div class="text-sm font-medium text-stone-700 dark:text-stone-300">"Discord or Slack channel"</div>
+            <div class="mb-2 text-xs text-stone-500">"Post climate and overdue-watering alerts to a channel your household already watches. Paste the channel's webhook URL."</div>
+            <Suspense fallback=|| ()>
+                {move || status.get().map(|result| match result.ok().flatten() {
+                    Some(current) => view! {
+                        <div class="flex gap-2 items-center mb-2">
+                            <span class="text-xs font-semibold text-stone-600 dark:text-stone-300">{current.kind.label()}</span>
+                            <code class="flex-1 min-w-0 text-[11px] truncate text-stone-400">{current.masked_url}</code>
+                            <button
+                                class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                                disabled=move || is_busy.get()
+                                on:click=send_test
+                            >"Send Test"</button>
+                            <button
+                                class="py-1.5 px-3 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                disabled=move || is_busy.get()
+                                on:click=move |_| save(String::new())
+                            >"Remove"</button>
+                        </div>
+                    }.into_any(),
+                    None => ().into_any(),
+                })}
+            </Suspense>
+            <div class="flex gap-2 items-center">
+                <input type="url" class=INPUT_SM
+                    placeholder="https://discord.com/api/webhooks/..."
+                    aria-label="Webhook URL"
+                    prop:value=url
+                    on:input=move |ev| set_url.set(event_target_value(&ev))
+                />
+                <button
+                    class=format!("{} text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                    disabled=move || is_busy.get() || url.get().trim().is_empty()
+                    on:click=move |_| save(url.get_untracked())
+                >"Save"</button>
+            </div>
+            {move || result_msg.get().map(|result| match result {
+                Ok(msg) => view! {
+                    <div class="p-2 mt-2 text-xs text-emerald-700 bg-emerald-50 rounded-lg dark:text-emerald-300 dark:bg-emerald-900/20">{msg}</div>
+                }.into_any(),
+                Err(msg) => view! {
+                    <div class="p-2 mt-2 text-xs text-red-700 bg-red-50 rounded-lg dark:text-red-300 dark:bg-red-900/20">{msg}</div>
+                }.into_any(),
+            })}
+        </div>
+    }
+}
+
+/// How long overdue care may go unhandled before its alert escalates, and an optional second channel for escalations.
+#[component]
+fn EscalationSettings() -> impl IntoView {
+    let status = Resource::new(|| (), |_| crate::server_fns::alerts::get_escalation_settings());
+    let (days, set_days) = signal(String::new());
+    let (url, set_url) = signal(String::new());
+    let (is_busy, set_is_busy) = signal(false);
+    let (result_msg, set_result_msg) = signal::<Option<Result<String, String>>>(None);
+
+    let save = move |webhook_url: Option<String>| {
+        let current = status.get_untracked().and_then(|r| r.ok()).map(|s| s.after_days).unwrap_or(0);
+        let after_days = match days.get_untracked().trim() {
+            "" => current,
+            typed => match typed.parse::<u32>() {
+                Ok(d) => d,
+                Err(_) => {
+                    set_result_msg.set(Some(Err("Enter a whole number of days".into())));
+                    return;
+                }
+            },
+        };
+        set_is_busy.set(true);
+        set_result_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::alerts::save_escalation_settings(after_days, webhook_url).await {
+                Ok(saved) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("settings.escalation_saved", "Escalation settings saved", &[]);
+                    set_days.set(String::new());
+                    set_url.set(String::new());
+                    set_result_msg.set(Some(Ok(if saved.after_days == 0 {
+                        "Escalation is off".to_string()
+                    } else {
+                        format!("Overdue care escalates after {} day{}", saved.after_days, if saved.after_days == 1 { "" } else { "s" })
+                    })));
+                    status.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.escalation_save", &format!("Failed to save escalation settings: {}", e), &[]);
+                    set_result_msg.set(Some(Err(e.to_string())));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 mt-3 text-sm rounded-lg border border-stone-200 dark:border-stone-700">
+            <div class="text-sm font-medium text-stone-700 dark:text-stone-300">"Escalate overdue care"</div>
+            <div class="mb-2 text-xs text-stone-500">"If a plant stays overdue and nobody acknowledges or snoozes its alert, raise it to a warning, then to critical, after this many days. Escalations can also go to a second channel."</div>
+            <Suspense fallback=|| ()>
+                {move || status.get().map(|result| {
+                    let current = result.unwrap_or_default();
+                    view! {
+                        <div class="flex gap-2 items-center mb-2 text-xs text-stone-600 dark:text-stone-300">
+                            <span>{if current.after_days == 0 { "Off".to_string() } else { format!("After {} days", current.after_days) }}</span>
+                            {current.webhook.map(|w| view! {
+                                <span class="font-semibold">{w.kind.label()}</span>
+                                <code class="flex-1 min-w-0 text-[11px] truncate text-stone-400">{w.masked_url}</code>
+                                <button
+                                    class="py-1.5 px-3 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                                    disabled=move || is_busy.get()
+                                    on:click=move |_| save(Some(String::new()))
+                                >"Remove"</button>
+                            })}
+                        </div>
+                    }
+                })}
+            </Suspense>
+            <div class="flex flex-col gap-2">
+                <input type="number" min="0" max=crate::orchid::MAX_ESCALATION_DAYS.to_string() class=INPUT_SM
+                    placeholder="Days, 0 for off"
+                    aria-label="Days before escalating"
+                    prop:value=days
+                    on:input=move |ev| set_days.set(event_target_value(&ev))
+                />
+                <input type="url" class=INPUT_SM
+                    placeholder="Escalation webhook (optional)"
+                    aria-label="Escalation webhook URL"
+                    prop:value=url
+                    on:input=move |ev| set_url.set(event_target_value(&ev))
+                />
+                <div>
+                    <button
+                        class=format!("{} text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                        disabled=move || is_busy.get() || (days.get().trim().is_empty() && url.get().trim().is_empty())
+                        on:click=move |_| {
+                            let typed = url.get_untracked();
+                            save((!typed.trim().is_empty()).then_some(typed))
+                        }
+                    >"Save"</button>
+                </div>
+            </div>
+            {move || result_msg.get().map(|result| match result {
+                Ok(msg) => view! {
+                    <div class="p-2 mt-2 text-xs text-emerald-700 bg-emerald-50 rounded-lg dark:text-emerald-300 dark:bg-emerald-900/20">{msg}</div>
+                }.into_any(),
+                Err(msg) => view! {
+                    <div class="p-2 mt-2 text-xs text-red-700 bg-red-50 rounded-lg dark:text-red-300 dark:bg-red-900/20">{msg}</div>
+                }.into_any(),
+            })}
+        </div>
+    }
+}
+
+/// ntfy server and topic that the current user's notifications are also published to.
+#[component]
+fn NtfySettings() -> impl IntoView {
+    let (server, set_server) = signal(String::new());
+    let (topic, set_topic) = signal(String::new());
+    let (token, set_token) = signal(String::new());
+    let (has_token, set_has_token) = signal(false);
+    let (is_set_up, set_is_set_up) = signal(false);
+    let (is_busy, set_is_busy) = signal(false);
+    let (result_msg, set_result_msg) = signal::<Option<Result<String, String>>>(None);
+
+    let show = move |status: Option<crate::ntfy::NtfyStatus>| {
+        set_is_set_up.set(status.is_some());
+        set_has_token.set(status.as_ref().is_some_and(|s| s.has_token));
+        let (s, t) = status.map(|s| (s.server, s.topic)).unwrap_or_default();
+        set_server.set(s);
+        set_topic.set(t);
+        set_token.set(String::new());
+    };
+
+    leptos::task::spawn_local(async move {
+        match crate::server_fns::alerts::get_ntfy_settings().await {
+            Ok(status) => show(status),
+            Err(_e) => {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_warn("settings.ntfy_load", &format!("Failed to load ntfy settings: {}", _e), &[]);
+            }
+        }
+    });
+
+    let save = move |topic: String| {
+        set_is_busy.set(true);
+        set_result_msg.set(None);
+        // A blank token field keeps the stored token
+        let new_token = Some(token.get_untracked()).filter(|t| !t.trim().is_empty());
+        let server = server.get_untracked();
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::alerts::save_ntfy_settings(server, topic, new_token).await {
+                Ok(status) => {
+                    set_result_msg.set(status.as_ref().map(|s| Ok(format!("Publishing to {}/{}", s.server, s.topic))));
+                    show(status);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.ntfy_save", &format!("Failed to save ntfy settings: {}", e), &[]);
+                    set_result_msg.set(Some(Err(e.to_string())));
+                }
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    let send_test = move |_| {
+        set_is_busy.set(true);
+        set_result_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::alerts::send_test_ntfy().await {
+                Ok(msg) => set_result_msg.set(Some(Ok(msg))),
+                Err(e) => set_result_msg.set(Some(Err(e.to_string()))),
+            }
+            set_is_busy.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 mt-3 text-sm rounded-lg border border-stone-200 dark:border-stone-700">
+            <div class="text-sm font-medium text-stone-700 dark:text-stone-300">"ntfy"</div>
+            <div class="mb-2 text-xs text-stone-500">"Also publish your alerts, reminders and reports to an ntfy topic. Leave the server blank for ntfy.sh; anyone who knows a public topic can read it, so pick a hard-to-guess name or use a token."</div>
+            <div class="flex flex-col gap-2">
+                <input type="url" class=INPUT_SM placeholder="https://ntfy.sh" aria-label="ntfy server"
+                    prop:value=server on:input=move |ev| set_server.set(event_target_value(&ev)) />
+                <input type="text" class=INPUT_SM placeholder="Topic, e.g. greenhouse-8f3k" aria-label="ntfy topic"
+                    prop:value=topic on:input=move |ev| set_topic.set(event_target_value(&ev)) />
+                <input type="password" class=INPUT_SM aria-label="ntfy access token"
+                    placeholder=move || if has_token.get() { "Token saved (leave blank to keep)" } else { "Access token (optional)" }
+                    prop:value=token on:input=move |ev| set_token.set(event_target_value(&ev)) />
+                <div class="flex gap-2">
+                    <button
+                        class=format!("{} text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                        disabled=move || is_busy.get() || topic.get().trim().is_empty()
+                        on:click=move |_| save(topic.get_untracked())
+                    >"Save"</button>
+                    {move || is_set_up.get().then(|| view! {
+                        <button
+                            class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                            disabled=move || is_busy.get()
+                            on:click=send_test
+                        >"Send Test"</button>
+                        <button
+                            class="py-1.5 px-3 text-xs bg-transparent rounded border-none cursor-pointer text-danger hover:bg-danger/10"
+                            disabled=move || is_busy.get()
+                            on:click=move |_| save(String::new())
+                        >"Turn Off"</button>
+                    })}
+                </div>
+            </div>
+            {move || result_msg.get().map(|result| match result {
+                Ok(msg) => view! {
+                    <div class="p-2 mt-2 text-xs text-emerald-700 bg-emerald-50 rounded-lg dark:text-emerald-300 dark:bg-emerald-900/20">{msg}</div>
+                }.into_any(),
+                Err(msg) => view! {
+                    <div class="p-2 mt-2 text-xs text-red-700 bg-red-50 rounded-lg dark:text-red-300 dark:bg-red-900/20">{msg}</div>
+                }.into_any(),
+            })}
+        </div>
+    }
+}
+
+/// Collapsible list of recently sent notifications and whether each channel accepted them.
+#[component]
+fn NotificationHistory() -> impl IntoView {
+    let (is_open, set_is_open) = signal(false);
+    let history = Resource::new(move || is_open.get(), |open| async move {
+        if open {
+            crate::server_fns::alerts::get_notification_history().await
+        } else {
+            Ok(Vec::new())
+        }
+    });
+
+    view! {
+        <div class="mt-3">
+            <button
+                class=format!("{} text-stone-500 bg-stone-100 hover:bg-stone-200 dark:text-stone-400 dark:bg-stone-800 dark:hover:bg-stone-700", BTN_SM)
+                on:click=move |_| set_is_open.update(|o| *o = !*o)
+            >{move || if is_open.get() { "Hide Notification History" } else { "Notification History" }}</button>
+            {move || is_open.get().then(|| view! {
+                <Suspense fallback=|| view! { <p class="text-xs text-stone-400">"Loading..."</p> }>
+                    {move || history.get().map(|result| match result {
+                        Err(e) => view! { <p class="text-xs text-danger">{format!("Couldn't load history: {}", e)}</p> }.into_any(),
+                        Ok(list) if list.is_empty() => view! {
+                            <p class="mt-2 mb-0 text-xs text-stone-500">"Nothing sent to you in the last 90 days."</p>
+                        }.into_any(),
+                        Ok(list) => view! {
+                            <ul class="overflow-y-auto p-0 mt-2 mb-0 list-none max-h-[320px]">
+                                {list.into_iter().map(|n| view! {
+                                    <li class="py-2 border-b last:border-b-0 border-stone-100 dark:border-stone-800">
+                                        <div class="flex gap-2 items-center text-xs">
+                                            <span class=if n.delivered { "font-semibold text-emerald-600 dark:text-emerald-400" } else { "font-semibold text-danger" }>
+                                                {if n.delivered { "Sent" } else { "Failed" }}
+                                            </span>
+                                            <span class="text-stone-400">{n.channel.label()}</span>
+                                            <span class="ml-auto text-stone-400">{n.created_at.with_timezone(&chrono::Local).format("%b %-d, %H:%M").to_string()}</span>
+                                        </div>
+                                        <div class="mt-0.5 text-xs font-medium text-stone-700 dark:text-stone-300">{n.title}</div>
+                                        <div class="text-xs whitespace-pre-wrap text-stone-500">{n.body}</div>
+                                        {n.error.map(|e| view! { <div class="mt-0.5 text-[11px] text-danger">{e}</div> })}
+                                    </li>
+                                }).collect::<Vec<_>>()}
+                            </ul>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            })}
+        </div>
+    }
+}
+
 /// Unsubscribe the browser's PushManager subscription.
 #[cfg(feature = "hydrate")]
 async fn unsubscribe_browser_push() {
@@ -0,0 +1,140 @@
+use leptos::prelude::*;
+use crate::orchid::Orchid;
+use crate::taxonomy::{aliases_for, language_label, AliasKind, SpeciesAlias, ALIAS_LANGUAGES};
+use super::BTN_PRIMARY;
+
+const INPUT_SM: &str = "w-full px-2 py-1.5 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-400 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:focus:bg-stone-800";
+
+/// The genus of a species name: its first word.
+fn genus_of(species: &str) -> String {
+    species.split_whitespace().next().unwrap_or_default().to_string()
+}
+
+/// "Also known as": the common, trade and synonym names that apply to a plant's species, with a form to add more.
+///
+/// Names can be added for the exact species or for the whole genus; built-in names can't be removed.
+#[component]
+pub fn SpeciesAliasCard(orchid_signal: ReadSignal<Orchid>) -> impl IntoView {
+    let aliases = Resource::new(|| (), |_| crate::server_fns::taxonomy::get_species_aliases());
+    let (new_alias, set_new_alias) = signal(String::new());
+    let (new_language, set_new_language) = signal(String::new());
+    let (new_kind, set_new_kind) = signal(AliasKind::Common);
+    let (whole_genus, set_whole_genus) = signal(false);
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let add = move |_| {
+        let alias = new_alias.get_untracked();
+        if alias.trim().is_empty() {
+            return;
+        }
+        let species = orchid_signal.get_untracked().species;
+        let scientific_name = if whole_genus.get_untracked() { genus_of(&species) } else { species };
+        let language = Some(new_language.get_untracked()).filter(|l| !l.is_empty());
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::taxonomy::add_species_alias(scientific_name, alias, language, new_kind.get_untracked()).await {
+                Ok(_) => {
+                    set_new_alias.set(String::new());
+                    aliases.refetch();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("aliases.add", &format!("Failed to add species alias: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let remove = move |id: String| {
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::taxonomy::delete_species_alias(id).await {
+                Ok(()) => aliases.refetch(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("aliases.delete", &format!("Failed to delete species alias: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 bg-stone-50 dark:border-stone-700 dark:bg-stone-800/50">
+            <h3 class="mt-0 mb-2 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Also Known As"</h3>
+            <Suspense fallback=|| view! { <p class="m-0 text-sm text-stone-400">"Loading names\u{2026}"</p> }>
+                {move || aliases.get().map(|result| {
+                    let all: Vec<SpeciesAlias> = result.unwrap_or_default();
+                    let species = orchid_signal.get().species;
+                    let names: Vec<SpeciesAlias> = aliases_for(&species, &all).into_iter().cloned().collect();
+                    if names.is_empty() {
+                        return view! {
+                            <p class="mt-0 mb-3 text-sm text-stone-400">"No other names recorded for this species."</p>
+                        }.into_any();
+                    }
+                    view! {
+                        <ul class="flex flex-wrap gap-2 p-0 mt-0 mb-3 list-none">
+                            {names.into_iter().map(|a| {
+                                let detail = [
+                                    Some(a.kind.label().to_string()),
+                                    a.language.as_deref().map(|l| language_label(l).to_string()),
+                                    (a.scientific_name.split_whitespace().count() == 1).then(|| format!("all {}", a.scientific_name)),
+                                ].into_iter().flatten().collect::<Vec<_>>().join(" \u{00B7} ");
+                                let id = a.id.clone();
+                                view! {
+                                    <li class="inline-flex gap-1.5 items-center py-1 px-2.5 text-sm rounded-full border bg-surface border-stone-200 dark:border-stone-600" title=detail>
+                                        <span class="text-stone-700 dark:text-stone-300">{a.alias.clone()}</span>
+                                        {a.language.clone().map(|l| view! { <span class="text-[10px] font-bold uppercase text-stone-400">{l}</span> })}
+                                        {(!a.builtin).then(|| view! {
+                                            <button
+                                                class="p-0 text-xs bg-transparent border-none cursor-pointer text-stone-400 hover:text-danger"
+                                                aria-label="Remove name"
+                                                on:click=move |_| remove(id.clone())
+                                            >
+                                                "\u{00D7}"
+                                            </button>
+                                        })}
+                                    </li>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any()
+                })}
+            </Suspense>
+            <div class="grid grid-cols-2 gap-2 sm:grid-cols-4">
+                <input
+                    class=format!("col-span-2 {}", INPUT_SM)
+                    placeholder="Add a name (e.g. trade name)"
+                    maxlength="100"
+                    prop:value=move || new_alias.get()
+                    on:input=move |ev| set_new_alias.set(event_target_value(&ev))
+                />
+                <select class=INPUT_SM on:change=move |ev| set_new_kind.set(AliasKind::from_str_lossy(&event_target_value(&ev)))>
+                    {AliasKind::ALL.into_iter().map(|k| view! {
+                        <option value=k.as_str() selected=move || new_kind.get() == k>{k.label()}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <select class=INPUT_SM on:change=move |ev| set_new_language.set(event_target_value(&ev))>
+                    <option value="">"Any language"</option>
+                    {ALIAS_LANGUAGES.iter().map(|(code, label)| view! {
+                        <option value=*code>{*label}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+            </div>
+            <div class="flex flex-wrap gap-3 justify-between items-center mt-2">
+                <label class="flex gap-1.5 items-center text-xs text-stone-500 dark:text-stone-400">
+                    <input type="checkbox" prop:checked=move || whole_genus.get() on:change=move |ev| set_whole_genus.set(event_target_checked(&ev)) />
+                    {move || format!("Applies to all {}", genus_of(&orchid_signal.get().species))}
+                </label>
+                <button class=BTN_PRIMARY disabled=move || is_saving.get() on:click=add>
+                    {move || if is_saving.get() { "Adding\u{2026}" } else { "Add Name" }}
+                </button>
+            </div>
+            {move || error_msg.get().map(|e| view! { <p class="mt-2 mb-0 text-sm text-danger">{e}</p> })}
+        </div>
+    }
+}
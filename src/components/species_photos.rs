@@ -0,0 +1,168 @@
+use leptos::prelude::*;
+use crate::orchid::Orchid;
+use crate::species_photos::{genus_key, species_key, CardImage, CardPhotoIndex, PRESET_ART};
+use crate::thumbnails::{thumbnail_url, ThumbSize};
+
+/// Card photo index (latest photos + species defaults), provided as context by the home page.
+#[derive(Clone, Copy)]
+pub struct CardPhotos(pub RwSignal<CardPhotoIndex>);
+
+/// Reloads the card photo index after a change.
+fn refresh_card_photos(ctx: Option<CardPhotos>) {
+    let Some(ctx) = ctx else { return };
+    leptos::task::spawn_local(async move {
+        match crate::server_fns::species_photos::get_card_photos().await {
+            Ok(index) => ctx.0.set(index),
+            Err(_e) => {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("species_photos.refresh", &format!("Failed to reload card photos: {}", _e), &[]);
+            }
+        }
+    });
+}
+
+/// Image band at the top of an orchid card: the plant's photo, its species default, or placeholder art.
+/// Without the home page's index (e.g. on public pages) only the chosen cover photo can show.
+#[component]
+pub fn CardHeaderImage(orchid: Orchid) -> impl IntoView {
+    let ctx = use_context::<CardPhotos>();
+    let name = orchid.name.clone();
+    let image = move || match ctx {
+        Some(c) => c.0.with(|index| index.resolve(&orchid)),
+        None => CardPhotoIndex::default().resolve(&orchid),
+    };
+
+    view! {
+        <div class="overflow-hidden h-28">
+            {move || match image() {
+                CardImage::Photo(filename) => view! {
+                    <img src=thumbnail_url(&filename, ThumbSize::Small) class="object-cover w-full h-full" alt=name.clone() loading="lazy" />
+                }.into_any(),
+                CardImage::Preset(art) => view! {
+                    <div class=format!("flex justify-center items-center w-full h-full {}", art.gradient)>
+                        <div class=format!("py-2 h-full [&>svg]:w-auto [&>svg]:h-full {}", art.ink) inner_html=include_str!("../../public/svg/orchid_accent.svg")></div>
+                    </div>
+                }.into_any(),
+            }}
+        </div>
+    }
+}
+
+/// Picker for the default card image of a plant's species or genus: a preset swatch or an uploaded photo.
+#[component]
+pub fn SpeciesPhotoPicker(species: String) -> impl IntoView {
+    let genus = genus_key(&species);
+    let species_label = species.trim().to_string();
+    let genus_label = species_label.split_whitespace().next().unwrap_or_default().to_string();
+    let has_genus_scope = genus != species_key(&species);
+    // true = apply to the whole genus
+    let (whole_genus, set_whole_genus) = signal(false);
+    let (staged, set_staged) = signal(None::<String>);
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let (reset, set_reset) = signal(0u32);
+    let card_photos = use_context::<CardPhotos>();
+    // Only used in #[cfg(feature = "hydrate")] blocks
+    let _ = &set_reset;
+    let species_stored = StoredValue::new(species);
+    let genus_stored = StoredValue::new(genus);
+
+    let target = move || if whole_genus.get_untracked() { genus_stored.get_value() } else { species_stored.get_value() };
+
+    let save = move |image_filename: Option<String>, preset: Option<String>| {
+        let target = target();
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::species_photos::set_species_photo(target.clone(), image_filename, preset).await {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("species_photos.save", "Species photo updated", &[("species", &target)]);
+                    refresh_card_photos(card_photos);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("species_photos.save", &format!("Failed to set species photo: {}", e), &[("species", &target)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let upload = move |_| {
+        let Some(_data_url) = staged.get_untracked() else { return };
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            #[cfg(feature = "hydrate")]
+            match crate::components::photo_capture::upload_data_url(&_data_url).await {
+                Ok(filename) => {
+                    set_staged.set(None);
+                    set_reset.update(|n| *n += 1);
+                    save(Some(filename), None);
+                }
+                Err(e) => {
+                    crate::server_fns::telemetry::emit_error("species_photos.upload", &format!("Species photo upload failed: {}", e), &[]);
+                    set_error_msg.set(Some(e));
+                    set_is_saving.set(false);
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="pt-4 mt-6 border-t border-stone-200 dark:border-stone-700">
+            <h3 class="mt-0 mb-1 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Default Card Image"</h3>
+            <p class="mt-0 mb-3 text-xs text-stone-500">"Shown on cards for plants of this species that don't have a photo yet."</p>
+            {has_genus_scope.then(|| view! {
+                <div class="flex gap-2 mb-3 text-xs">
+                    <button
+                        class=move || if whole_genus.get() { "py-1 px-2.5 rounded-full border-none cursor-pointer text-stone-500 bg-secondary/30" } else { "py-1 px-2.5 rounded-full border-none cursor-pointer text-white bg-primary" }
+                        on:click=move |_| set_whole_genus.set(false)
+                    >{species_label.clone()}</button>
+                    <button
+                        class=move || if whole_genus.get() { "py-1 px-2.5 rounded-full border-none cursor-pointer text-white bg-primary" } else { "py-1 px-2.5 rounded-full border-none cursor-pointer text-stone-500 bg-secondary/30" }
+                        on:click=move |_| set_whole_genus.set(true)
+                    >{format!("All {}", genus_label)}</button>
+                </div>
+            })}
+            <div class="flex flex-wrap gap-2 mb-3">
+                {PRESET_ART.iter().map(|art| {
+                    let key = art.key;
+                    view! {
+                        <button
+                            class=format!("overflow-hidden p-0 w-12 h-12 rounded-lg border cursor-pointer border-stone-200 dark:border-stone-700 hover:border-primary {}", art.gradient)
+                            title=art.label
+                            disabled=move || is_saving.get()
+                            on:click=move |_| save(None, Some(key.to_string()))
+                        >
+                            <div class=format!("p-1 w-full h-full [&>svg]:w-full [&>svg]:h-full {}", art.ink) inner_html=include_str!("../../public/svg/orchid_accent.svg")></div>
+                        </button>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            <crate::components::photo_capture::PhotoCapture
+                on_photo_ready=move |data_url| set_staged.set(Some(data_url))
+                reset=reset
+            />
+            <div class="flex gap-2 mt-2">
+                {move || staged.get().is_some().then(|| view! {
+                    <button
+                        class="py-2 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                        disabled=move || is_saving.get()
+                        on:click=upload
+                    >{move || if is_saving.get() { "Saving..." } else { "Use This Photo" }}</button>
+                })}
+                <button
+                    class="py-2 px-3 text-xs bg-transparent rounded-lg border-none cursor-pointer text-stone-500 hover:text-stone-700 dark:hover:text-stone-300"
+                    disabled=move || is_saving.get()
+                    on:click=move |_| save(None, None)
+                >"Reset to Default"</button>
+            </div>
+            {move || error_msg.get().map(|msg| view! {
+                <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,74 @@
+use leptos::prelude::*;
+use crate::taxonomy::SpeciesSuggestion;
+
+/// Fewest characters typed before suggestions are fetched.
+const MIN_QUERY_CHARS: usize = 2;
+
+const LIST_LIGHT: &str = "overflow-y-auto absolute right-0 left-0 top-full z-20 p-1 m-0 mt-1 max-h-64 list-none rounded-lg border shadow-lg bg-surface border-stone-200 dark:border-stone-700";
+const LIST_DARK: &str = "overflow-y-auto absolute right-0 left-0 top-full z-20 p-1 m-0 mt-1 max-h-64 list-none rounded-xl border shadow-lg bg-stone-800 border-stone-700";
+const ITEM_LIGHT: &str = "py-1.5 px-2 w-full text-left text-sm rounded-md border-none cursor-pointer bg-transparent hover:bg-stone-100 dark:hover:bg-stone-800";
+const ITEM_DARK: &str = "py-1.5 px-2 w-full text-left text-sm text-white rounded-md border-none cursor-pointer bg-transparent hover:bg-stone-700";
+
+/// Species and genus suggestions for the text in `query`, shown as a dropdown under the input.
+///
+/// Place it right after the species input inside a `relative` wrapper. Picking a suggestion calls
+/// `on_pick` and hides the list until the text changes again.
+#[component]
+pub fn SpeciesSuggestions(
+    query: ReadSignal<String>,
+    on_pick: impl Fn(SpeciesSuggestion) + 'static + Copy + Send + Sync,
+    /// Styles the list for the scanner's dark modal.
+    #[prop(optional)] dark: bool,
+) -> impl IntoView {
+    let (picked, set_picked) = signal(String::new());
+    let suggestions = Resource::new(
+        move || query.get().trim().to_string(),
+        |q| async move {
+            if q.chars().count() < MIN_QUERY_CHARS {
+                return Ok(Vec::new());
+            }
+            crate::server_fns::public::search_species(q).await
+        },
+    );
+    let (list_class, item_class) = if dark { (LIST_DARK, ITEM_DARK) } else { (LIST_LIGHT, ITEM_LIGHT) };
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || {
+                let current = query.get();
+                if current.trim() == picked.get() {
+                    return None;
+                }
+                let list = match suggestions.get()? {
+                    Ok(list) => list,
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("species_search.load", &format!("Species search failed: {}", _e), &[]);
+                        return None;
+                    }
+                };
+                (!list.is_empty()).then(|| view! {
+                    <ul class=list_class>
+                        {list.into_iter().map(|s| {
+                            let name = s.name.clone();
+                            let region = s.native_region.clone();
+                            let suggestion = StoredValue::new(s);
+                            view! {
+                                <li>
+                                    <button type="button" class=item_class on:click=move |_| {
+                                        let s = suggestion.get_value();
+                                        set_picked.set(s.name.clone());
+                                        on_pick(s);
+                                    }>
+                                        <span class="italic">{name}</span>
+                                        {region.map(|r| view! { <span class="ml-2 text-xs text-stone-400">{r}</span> })}
+                                    </button>
+                                </li>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                })
+            }}
+        </Transition>
+    }
+}
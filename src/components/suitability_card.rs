@@ -1,6 +1,8 @@
 use leptos::prelude::*;
 use crate::orchid::Orchid;
+use crate::habitat_similarity::MonthlySimilarity;
 use crate::estimation::{recommend_potting_setup, VPD_BASELINE};
+use crate::light::{compare_light, ppfd_to_lux, target_ppfd_range, LightFit};
 use crate::watering::ClimateSnapshot;
 
 /// Estimate native habitat VPD from measured PAR (PPFD, µmol/m²/s).
@@ -20,6 +22,8 @@ fn native_vpd_from_par(ppfd: f64) -> f64 {
 pub fn SuitabilityCard(
     orchid_signal: ReadSignal<Orchid>,
     #[prop(default = None)] climate_snapshot: Option<ClimateSnapshot>,
+    /// Monthly home-vs-habitat scores; the most recent month is summarized.
+    #[prop(optional)] habitat_similarity: Option<ReadSignal<Option<Vec<MonthlySimilarity>>>>,
 ) -> impl IntoView {
     view! {
         {move || {
@@ -35,6 +39,10 @@ pub fn SuitabilityCard(
             };
 
             let recommendation = recommend_potting_setup(native_vpd, home_vpd);
+            let light = climate_snapshot.as_ref().and_then(|s| s.peak_light_ppfd()).map(|peak| {
+                let target = target_ppfd_range(orchid.par_ppfd, &orchid.light_lux, &orchid.light_requirement);
+                (peak, target, compare_light(peak, target))
+            });
 
             view! {
                 <div class="p-4 mb-4 rounded-xl border border-stone-200 bg-stone-50 dark:border-stone-700 dark:bg-stone-800/50">
@@ -55,6 +63,35 @@ pub fn SuitabilityCard(
                             <div class="font-medium text-stone-700 dark:text-stone-300">{recommendation.suggested_pot_type.to_string()}</div>
                         </div>
                     </div>
+                    {habitat_similarity.and_then(|s| s.with(|months| months.as_ref().and_then(|m| m.last().cloned()))).map(|m| view! {
+                        <div class="pt-3 mt-3 text-sm border-t border-stone-200/60 dark:border-stone-700/60">
+                            <div class="text-xs tracking-wide text-stone-400">{format!("Native Habitat Match ({})", crate::orchid::Orchid::month_name(m.month))}</div>
+                            <div class=format!("font-medium {}", crate::components::habitat_weather::similarity_class(m.score))>
+                                {format!("{}/100 \u{00B7} {}", m.score, m.verdict())}
+                            </div>
+                            {m.main_gap().map(|gap| view! {
+                                <div class="text-xs text-stone-500 dark:text-stone-400">{format!("Home runs {}", gap)}</div>
+                            })}
+                        </div>
+                    })}
+                    {light.map(|(peak, (low, high), fit)| {
+                        let (verdict, class) = match fit {
+                            LightFit::TooDim => ("Too dim: move closer to the light or add a grow light", "text-amber-700 dark:text-amber-400"),
+                            LightFit::Good => ("Within this plant's range", "text-emerald-700 dark:text-emerald-400"),
+                            LightFit::TooBright => ("Too bright: add shade or move further from the light", "text-amber-700 dark:text-amber-400"),
+                        };
+                        view! {
+                            <div class="pt-3 mt-3 text-sm border-t border-stone-200/60 dark:border-stone-700/60">
+                                <div class="text-xs tracking-wide text-stone-400">"Measured Light (peak)"</div>
+                                <div class="font-medium text-stone-700 dark:text-stone-300">
+                                    {format!("{:.0} \u{00B5}mol/m\u{00B2}/s (\u{2248}{:.0} lux)", peak, ppfd_to_lux(peak))}
+                                </div>
+                                <div class=format!("text-xs {}", class)>
+                                    {format!("{} (wants {:.0}\u{2013}{:.0} \u{00B5}mol/m\u{00B2}/s)", verdict, low, high)}
+                                </div>
+                            </div>
+                        }
+                    })}
                 </div>
             }
         }}
@@ -85,6 +122,56 @@ mod tests {
             assert!(html.contains("Sphagnum Moss"));
             assert!(html.contains("Solid"));
             assert!(html.contains("significantly drier"));
+            assert!(!html.contains("Measured Light"));
+        });
+    }
+
+    #[test]
+    fn test_suitability_card_compares_measured_light() {
+        let owner = leptos::reactive::owner::Owner::new();
+        owner.with(|| {
+            let mut orchid = test_orchid();
+            orchid.par_ppfd = Some(200.0);
+            let (orchid_signal, _) = signal(orchid);
+            let mut snap = test_climate_snapshot_hot_dry();
+            snap.peak_lux = Some(2_700.0);
+
+            let html = view! {
+                <SuitabilityCard
+                    orchid_signal=orchid_signal
+                    climate_snapshot=Some(snap)
+                />
+            }.to_html();
+
+            assert!(html.contains("Measured Light"));
+            assert!(html.contains("Too dim"));
+        });
+    }
+
+    #[test]
+    fn test_suitability_card_summarizes_latest_habitat_month() {
+        let owner = leptos::reactive::owner::Owner::new();
+        owner.with(|| {
+            let (orchid_signal, _) = signal(test_orchid());
+            let (similarity, _) = signal(Some(vec![MonthlySimilarity {
+                year: 2026,
+                month: 6,
+                score: 57,
+                avg_temp_diff: -1.0,
+                low_temp_diff: -11.0,
+                humidity_diff: -2.0,
+            }]));
+
+            let html = view! {
+                <SuitabilityCard
+                    orchid_signal=orchid_signal
+                    habitat_similarity=similarity
+                />
+            }.to_html();
+
+            assert!(html.contains("Native Habitat Match (Jun)"));
+            assert!(html.contains("Far from habitat"));
+            assert!(html.contains("colder at night"));
         });
     }
 }
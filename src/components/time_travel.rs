@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use leptos::prelude::*;
+use crate::time_travel::PlantAsOf;
+
+/// How far back the date picker starts.
+const DEFAULT_DAYS_BACK: i64 = 30;
+
+const DATE_INPUT: &str = "py-1.5 px-2 text-sm rounded-lg border border-stone-300 bg-surface dark:border-stone-600 text-stone-700 dark:text-stone-300";
+
+fn default_date() -> NaiveDate {
+    (Utc::now() - Duration::days(DEFAULT_DAYS_BACK)).date_naive()
+}
+
+/// The end of the chosen day, so that day's journal entries are included.
+fn end_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(23, 59, 59)
+        .map(|d| d.and_utc())
+        .unwrap_or_else(Utc::now)
+}
+
+fn format_day(at: Option<DateTime<Utc>>) -> String {
+    at.map(|t| t.format("%b %d, %Y").to_string()).unwrap_or_else(|| "\u{2014}".to_string())
+}
+
+/// A date input bound to a `NaiveDate` signal; future dates are not allowed.
+#[component]
+fn AsOfDatePicker(date: ReadSignal<NaiveDate>, set_date: WriteSignal<NaiveDate>) -> impl IntoView {
+    view! {
+        <label class="flex gap-2 items-center text-sm text-stone-500 dark:text-stone-400">
+            "View as of"
+            <input
+                type="date"
+                class=DATE_INPUT
+                max=Utc::now().date_naive().to_string()
+                prop:value=move || date.get().to_string()
+                on:change=move |ev| {
+                    if let Ok(d) = NaiveDate::parse_from_str(&event_target_value(&ev), "%Y-%m-%d") {
+                        set_date.set(d.min(Utc::now().date_naive()));
+                    }
+                }
+            />
+        </label>
+    }
+}
+
+/// The History tab: every plant grouped by the zone it was in on the chosen date, with its watering state then.
+#[component]
+pub fn CollectionAsOf() -> impl IntoView {
+    let (date, set_date) = signal(default_date());
+    let plants = Resource::new(
+        move || date.get(),
+        |d| crate::server_fns::time_travel::get_collection_as_of(end_of_day(d)),
+    );
+
+    view! {
+        <div>
+            <div class="flex flex-wrap gap-3 justify-between items-center mb-4">
+                <p class="m-0 text-sm text-stone-500 dark:text-stone-400">
+                    "Where each plant lived and how its care stood on a past date."
+                </p>
+                <AsOfDatePicker date=date set_date=set_date />
+            </div>
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Reconstructing\u{2026}"</p> }>
+                {move || plants.get().map(|result| {
+                    let plants = match result {
+                        Ok(p) => p,
+                        Err(_e) => {
+                            #[cfg(feature = "hydrate")]
+                            crate::server_fns::telemetry::emit_warn("time_travel.load_collection", &format!("Failed to load collection as of date: {}", _e), &[]);
+                            Vec::new()
+                        }
+                    };
+                    if plants.is_empty() {
+                        return view! {
+                            <p class="text-sm text-stone-400">"No plants in the collection on this date."</p>
+                        }.into_any();
+                    }
+                    let unrecorded = plants.iter().any(|p| !p.profile_recorded);
+                    let mut by_zone: BTreeMap<String, Vec<PlantAsOf>> = BTreeMap::new();
+                    for p in plants {
+                        by_zone.entry(p.profile.placement.clone()).or_default().push(p);
+                    }
+                    view! {
+                        <div>
+                            {unrecorded.then(|| view! {
+                                <p class="mt-0 mb-3 text-xs text-stone-400">
+                                    "Care-profile history starts when it was first recorded; earlier dates show the earliest known setup."
+                                </p>
+                            })}
+                            {by_zone.into_iter().map(|(zone, plants)| view! {
+                                <div class="mb-5">
+                                    <h3 class="mt-0 mb-2 text-xs font-bold tracking-widest uppercase text-stone-400 dark:text-stone-500">{zone}</h3>
+                                    <div class="grid grid-cols-1 gap-3 sm:grid-cols-2 lg:grid-cols-3">
+                                        {plants.into_iter().map(|p| view! { <PlantAsOfCard plant=p /> }).collect::<Vec<_>>()}
+                                    </div>
+                                </div>
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    }.into_any()
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// One reconstructed plant in the collection view.
+#[component]
+fn PlantAsOfCard(plant: PlantAsOf) -> impl IntoView {
+    let watered = match plant.days_since_watered() {
+        Some(days) => format!("Watered {} day{} before", days, if days == 1 { "" } else { "s" }),
+        None => "No watering logged yet".to_string(),
+    };
+    let overdue = plant.was_overdue();
+
+    view! {
+        <div class="p-4 rounded-xl border bg-surface border-stone-200/60 dark:border-stone-700/60">
+            <div class="text-sm font-semibold truncate text-stone-700 dark:text-stone-300">{plant.name.clone()}</div>
+            <div class="mb-2 text-xs italic truncate text-stone-400">{plant.species.clone()}</div>
+            <div class=if overdue { "text-xs font-semibold text-amber-700 dark:text-amber-400" } else { "text-xs text-stone-500 dark:text-stone-400" }>
+                {watered}
+                {overdue.then_some(" \u{00B7} overdue")}
+            </div>
+            <div class="mt-1 text-[11px] text-stone-400">
+                {format!("Every {} days", plant.profile.water_frequency_days)}
+                {plant.last_bloom_at.map(|at| format!(" \u{00B7} last bloom {}", at.format("%b %Y")))}
+            </div>
+        </div>
+    }
+}
+
+/// The "As Of" tab in the plant detail modal: the plant's setup, last care and journal on a chosen date.
+#[component]
+pub fn PlantAsOfPanel(orchid_id: String) -> impl IntoView {
+    let (date, set_date) = signal(default_date());
+    let orchid_id = StoredValue::new(orchid_id);
+    let state = Resource::new(
+        move || date.get(),
+        move |d| crate::server_fns::time_travel::get_plant_as_of(orchid_id.get_value(), end_of_day(d)),
+    );
+
+    view! {
+        <div>
+            <div class="mb-4">
+                <AsOfDatePicker date=date set_date=set_date />
+            </div>
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Reconstructing\u{2026}"</p> }>
+                {move || state.get().map(|result| match result {
+                    Ok(p) => view! { <PlantAsOfDetails plant=p /> }.into_any(),
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("time_travel.load_plant", &format!("Failed to load plant as of date: {}", _e), &[]);
+                        view! { <p class="text-sm text-stone-400">"Couldn't reconstruct this plant for that date."</p> }.into_any()
+                    }
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn PlantAsOfDetails(plant: PlantAsOf) -> impl IntoView {
+    let profile = plant.profile.clone();
+    let pot = [
+        profile.pot_size.as_ref().map(|s| s.to_string()),
+        profile.pot_type.as_ref().map(|t| t.to_string()),
+        profile.pot_medium.as_ref().map(|m| m.to_string()),
+    ].into_iter().flatten().collect::<Vec<_>>().join(" \u{00B7} ");
+    let rows = [
+        ("Zone", profile.placement.clone()),
+        ("Light", profile.light_requirement.to_string()),
+        ("Watering", format!("Every {} days", profile.water_frequency_days)),
+        ("Pot", if pot.is_empty() { "\u{2014}".to_string() } else { pot }),
+        ("Fertilizing", profile.fertilize_frequency_days
+            .map(|d| format!("Every {} days{}", d, profile.fertilizer_type.as_ref().map(|t| format!(" ({})", t)).unwrap_or_default()))
+            .unwrap_or_else(|| "\u{2014}".to_string())),
+        ("Last watered", format_day(plant.last_watered_at)),
+        ("Last fertilized", format_day(plant.last_fertilized_at)),
+        ("Last repotted", format_day(plant.last_repotted_at)),
+        ("Last bloom", format_day(plant.last_bloom_at)),
+    ];
+    let overdue = plant.was_overdue();
+
+    view! {
+        <div>
+            {overdue.then(|| view! {
+                <div class="py-2 px-3 mb-3 text-sm font-semibold text-amber-800 bg-amber-50 rounded-lg dark:text-amber-300 dark:bg-amber-900/30">
+                    {format!("Watering was overdue on this date ({} days since the last one).", plant.days_since_watered().unwrap_or_default())}
+                </div>
+            })}
+            {(!plant.profile_recorded).then(|| view! {
+                <p class="mt-0 mb-3 text-xs text-stone-400">
+                    "No care-profile change was recorded before this date; showing the earliest known setup."
+                </p>
+            })}
+            <dl class="grid grid-cols-2 gap-y-2 gap-x-4 mt-0 mb-4 text-sm">
+                {rows.into_iter().map(|(label, value)| view! {
+                    <dt class="text-stone-400">{label}</dt>
+                    <dd class="m-0 text-stone-700 dark:text-stone-300">{value}</dd>
+                }).collect::<Vec<_>>()}
+            </dl>
+            <h3 class="mt-0 mb-2 text-xs font-bold tracking-widest uppercase text-stone-400 dark:text-stone-500">"Journal up to this date"</h3>
+            {if plant.recent_entries.is_empty() {
+                view! { <p class="text-sm text-stone-400">"No journal entries yet."</p> }.into_any()
+            } else {
+                view! {
+                    <ul class="pl-0 m-0 list-none">
+                        {plant.recent_entries.into_iter().map(|e| view! {
+                            <li class="py-2 text-sm border-b border-stone-100 dark:border-stone-700/50">
+                                <span class="mr-2 text-xs tabular-nums text-stone-400">{e.timestamp.format("%b %d, %Y").to_string()}</span>
+                                {e.event_type.map(|t| view! { <span class="mr-2 text-xs font-semibold text-primary">{t}</span> })}
+                                <span class="text-stone-600 dark:text-stone-400">{e.note}</span>
+                            </li>
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                }.into_any()
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+use crate::server_fns::timelapse::Timelapse;
+use crate::timelapse::DEFAULT_FRAME_MS;
+
+const SELECT: &str = "py-1.5 px-2 text-sm bg-white rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600 dark:text-stone-200";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Playback speeds offered, as (label, milliseconds per photo).
+const SPEEDS: &[(&str, u32)] = &[("Fast", 200), ("Normal", DEFAULT_FRAME_MS), ("Slow", 1000), ("Very slow", 2000)];
+
+/// "Make a time-lapse" card under the photo gallery: pick a speed and whether to stamp dates,
+/// then preview the animation and download it as a GIF.
+#[component]
+pub fn TimelapseMaker(orchid_id: String) -> impl IntoView {
+    let orchid_id = StoredValue::new(orchid_id);
+    let (frame_ms, set_frame_ms) = signal(DEFAULT_FRAME_MS);
+    let (date_overlay, set_date_overlay) = signal(true);
+    let (is_making, set_is_making) = signal(false);
+    let (result, set_result) = signal(None::<Timelapse>);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let make = move |_| {
+        set_is_making.set(true);
+        set_error_msg.set(None);
+        let (id, ms, dates) = (orchid_id.get_value(), frame_ms.get(), date_overlay.get());
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::timelapse::create_timelapse(id.clone(), ms, dates).await {
+                Ok(t) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("timelapse.create", "Time-lapse generated", &[("orchid_id", &id)]);
+                    set_result.set(Some(t));
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("timelapse.create", &format!("Time-lapse failed: {}", e), &[("orchid_id", &id)]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_making.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 mt-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <h4 class="mt-0 mb-2 text-sm font-semibold text-stone-700 dark:text-stone-200">"Time-lapse"</h4>
+            <div class="flex flex-wrap gap-3 items-center">
+                <select class=SELECT
+                    prop:value=move || frame_ms.get().to_string()
+                    on:change=move |ev| set_frame_ms.set(event_target_value(&ev).parse().unwrap_or(DEFAULT_FRAME_MS))
+                >
+                    {SPEEDS.iter().map(|(label, ms)| view! {
+                        <option value=ms.to_string()>{*label}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <label class="flex gap-1.5 items-center text-sm cursor-pointer text-stone-600 dark:text-stone-300">
+                    <input type="checkbox"
+                        prop:checked=date_overlay
+                        on:change=move |ev| set_date_overlay.set(event_target_checked(&ev))
+                    />
+                    "Show dates"
+                </label>
+                <button
+                    class=format!("{} ml-auto text-primary bg-primary/10 hover:bg-primary/20 disabled:opacity-50", BTN_SM)
+                    disabled=move || is_making.get()
+                    on:click=make
+                >
+                    {move || if is_making.get() { "Making..." } else { "Make Time-lapse" }}
+                </button>
+            </div>
+            {move || error_msg.get().map(|msg| view! {
+                <p class="mt-2 mb-0 text-xs text-red-600 dark:text-red-400">{msg}</p>
+            })}
+            {move || result.get().map(|t| {
+                let src = format!("data:image/gif;base64,{}", t.gif_base64);
+                view! {
+                    <div class="mt-3 text-center">
+                        <img src=src.clone() class="mx-auto max-w-full rounded-lg" alt="Time-lapse" />
+                        <div class="flex gap-3 justify-center items-center mt-2">
+                            <span class="text-xs text-stone-500 dark:text-stone-400">{format!("{} photos", t.frame_count)}</span>
+                            <a
+                                href=src
+                                download=t.filename
+                                class=format!("{} no-underline text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                            >
+                                "Download GIF"
+                            </a>
+                        </div>
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
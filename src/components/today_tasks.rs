@@ -1,5 +1,6 @@
-use crate::orchid::{Hemisphere, Orchid};
-use crate::watering::ClimateSnapshot;
+use crate::orchid::{Hemisphere, Orchid, Reminder};
+use crate::components::reminders::{reminder_subtitle, RemindersPanel};
+use crate::watering::{forecast_hint, ClimateSnapshot, ForecastHint};
 use leptos::prelude::*;
 
 #[component]
@@ -7,15 +8,23 @@ pub fn TodayTasks(
     orchids: Memo<Vec<Orchid>>,
     climate_snapshots: Memo<Vec<ClimateSnapshot>>,
     hemisphere: Memo<String>,
+    /// The user's default watering grace period, in days past due.
+    overdue_grace_days: Memo<u32>,
+    /// Temperature unit ("C" or "F") for forecast hints.
+    unit: Memo<String>,
     on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
     on_water: impl Fn(String) + 'static + Copy + Send + Sync,
     on_water_all: impl Fn(Vec<String>) + 'static + Copy + Send + Sync,
 ) -> impl IntoView {
+    // Bumped when reminders are scheduled, completed or removed so both reminder lists reload
+    let reminders_version = RwSignal::new(0u32);
+
     // Determine which orchids are due for watering today
     let tasks_data = Memo::new(move |_| {
         let current_hemisphere = Hemisphere::from_code(&hemisphere.get());
         let snapshots = climate_snapshots.get();
         let current_orchids = orchids.get();
+        let grace = overdue_grace_days.get();
 
         let mut due_orchids = Vec::new();
 
@@ -23,11 +32,11 @@ pub fn TodayTasks(
             let zone_snapshot = snapshots.iter().find(|s| s.zone_name == orchid.placement);
             let days_until = orchid.climate_days_until_due(&current_hemisphere, zone_snapshot);
 
-            // If days_until is <= 0 or None (never watered), they need watering today.
-            let needs_water = days_until.map(|d| d <= 0).unwrap_or(true);
-
-            if needs_water {
-                due_orchids.push((orchid, days_until));
+            if orchid.is_climate_due(&current_hemisphere, zone_snapshot) {
+                // Due plants stay on the list; only the "overdue" alarm waits out the grace period
+                let overdue = orchid.is_climate_overdue(&current_hemisphere, zone_snapshot, grace);
+                let hint = zone_snapshot.and_then(forecast_hint);
+                due_orchids.push((orchid, days_until, overdue, hint));
             }
         }
 
@@ -44,7 +53,7 @@ pub fn TodayTasks(
     let due_count = Memo::new(move |_| tasks_data.get().len());
 
     let handle_water_all = move |_| {
-        let ids: Vec<String> = tasks_data.get().into_iter().map(|(o, _)| o.id).collect();
+        let ids: Vec<String> = tasks_data.get().into_iter().map(|(o, _, _, _)| o.id).collect();
         if !ids.is_empty() {
             on_water_all(ids);
         }
@@ -91,6 +100,10 @@ pub fn TodayTasks(
                 </div>
             </div>
 
+            <DueReminders orchids=orchids version=reminders_version on_select=on_select />
+            <RemindersPanel orchids=orchids version=reminders_version />
+            <DueReservoirs orchids=orchids climate_snapshots=climate_snapshots on_select=on_select />
+
             // Task List
             {move || {
                 let tasks = tasks_data.get();
@@ -108,73 +121,117 @@ pub fn TodayTasks(
                         </div>
                     }.into_any()
                 } else {
+                    // Group by zone, keeping the most urgent zone first
+                    let mut groups: Vec<(String, Vec<_>)> = Vec::new();
+                    for task in tasks {
+                        match groups.iter_mut().find(|(zone, _)| *zone == task.0.placement) {
+                            Some((_, list)) => list.push(task),
+                            None => groups.push((task.0.placement.clone(), vec![task])),
+                        }
+                    }
+                    let several_zones = groups.len() > 1;
                     view! {
-                        <div class="grid gap-4 sm:grid-cols-2 lg:grid-cols-3">
-                            {tasks.into_iter().enumerate().map(|(i, (orchid, days_until))| {
-                                let orchid_clone = orchid.clone();
-                                let orchid_id = orchid.id.clone();
-
-                                let status_text = match days_until {
-                                    None => "Needs first watering".to_string(),
-                                    Some(0) => "Due today".to_string(),
-                                    Some(1) => "Due tomorrow".to_string(),
-                                    Some(d) if d < 0 => format!("{} days overdue", -d),
-                                    Some(d) => format!("Due in {} days", d),
-                                };
-
-                                let status_color = match days_until {
-                                    None | Some(0) => "text-amber-600 bg-amber-50 dark:text-amber-400 dark:bg-amber-900/20",
-                                    Some(d) if d < 0 => "text-danger bg-danger/10 dark:text-red-400 dark:bg-red-900/20",
-                                    _ => "text-sky-600 bg-sky-50 dark:text-sky-400 dark:bg-sky-900/20",
-                                };
-
-                                // Staggered animation delay
-                                let delay_class = format!("animation-delay-{}", (i % 5) * 100);
-
+                        <div class="flex flex-col gap-6">
+                            {groups.into_iter().map(|(zone, tasks)| {
+                                let zone_ids: Vec<String> = tasks.iter().map(|(o, _, _, _)| o.id.clone()).collect();
+                                let zone_label = if zone.is_empty() { "No zone".to_string() } else { zone.clone() };
+                                let count = tasks.len();
                                 view! {
-                                    <div
-                                        class=format!("group flex relative flex-col p-5 bg-white rounded-2xl border shadow-sm transition-all duration-300 cursor-pointer dark:bg-stone-800 border-stone-100 dark:border-stone-700 hover:shadow-md hover:border-primary/30 dark:hover:border-primary-light/30 animate-in fade-in slide-in-from-bottom-2 fill-mode-both {}", delay_class)
-                                        on:click=move |_| on_select(orchid_clone.clone())
-                                    >
-                                        <div class="flex justify-between items-start mb-3">
-                                            <div class="flex flex-col min-w-0">
-                                                <h4 class="font-serif text-lg transition-colors truncate text-stone-800 dark:text-stone-100 dark:group-hover:text-primary-light group-hover:text-primary">
-                                                    {orchid.name.clone()}
-                                                </h4>
-                                                <p class="text-sm italic truncate text-stone-500 dark:text-stone-400">
-                                                    {orchid.species.clone()}
-                                                </p>
-                                            </div>
-                                            <button
-                                                class="flex flex-shrink-0 justify-center items-center w-10 h-10 rounded-full transition-colors text-sky-600 bg-sky-50 dark:bg-sky-900/30 dark:text-sky-400 dark:hover:bg-sky-900/50 hover:bg-sky-100 hover:text-sky-700"
-                                                on:click=move |e| {
-                                                    e.prevent_default();
-                                                    e.stop_propagation();
-                                                    on_water(orchid_id.clone());
-                                                }
-                                                aria-label=format!("Water {}", orchid.name)
-                                                title="Mark as watered"
-                                            >
-                                                <svg xmlns="http://www.w3.org/2000/svg" class="w-5 h-5" viewBox="0 0 20 20" fill="currentColor">
-                                                    <path fill-rule="evenodd" d="M3.172 5.172a4 4 0 015.656 0L10 6.343l1.172-1.171a4 4 0 115.656 5.656L10 17.657l-6.828-6.829a4 4 0 010-5.656z" clip-rule="evenodd" />
-                                                </svg>
-                                            </button>
+                                    <section class="flex flex-col gap-3">
+                                        <div class="flex gap-3 justify-between items-center">
+                                            <h3 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">
+                                                {format!("{} \u{00B7} {} due", zone_label, count)}
+                                            </h3>
+                                            {several_zones.then(|| view! {
+                                                <button
+                                                    class="py-1.5 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-sky-700 bg-sky-50 hover:bg-sky-100 dark:text-sky-300 dark:bg-sky-900/30 dark:hover:bg-sky-900/50"
+                                                    aria-label=format!("Water all due in {}", zone_label)
+                                                    on:click=move |_| on_water_all(zone_ids.clone())
+                                                >
+                                                    {format!("Water all {}", count)}
+                                                </button>
+                                            })}
                                         </div>
+                                        <div class="grid gap-4 sm:grid-cols-2 lg:grid-cols-3">
+                                            {tasks.into_iter().enumerate().map(|(i, (orchid, days_until, overdue, hint))| {
+                                                let orchid_clone = orchid.clone();
+                                                let orchid_id = orchid.id.clone();
+
+                                                let status_text = match days_until {
+                                                    None => "Needs first watering".to_string(),
+                                                    Some(0) => "Due today".to_string(),
+                                                    Some(1) => "Due tomorrow".to_string(),
+                                                    Some(d) if d < 0 && overdue => format!("{} days overdue", -d),
+                                                    Some(-1) => "Due yesterday".to_string(),
+                                                    Some(d) if d < 0 => format!("Due {} days ago", -d),
+                                                    Some(d) => format!("Due in {} days", d),
+                                                };
 
-                                        <div class="mt-auto">
-                                            <div class="flex gap-2 items-center">
-                                                <span class=format!("px-2.5 py-1 text-xs font-semibold rounded-md {}", status_color)>
-                                                    {status_text}
-                                                </span>
-                                                <span class="flex gap-1 items-center text-xs text-stone-400 dark:text-stone-500">
-                                                    <svg xmlns="http://www.w3.org/2000/svg" class="w-3.5 h-3.5" viewBox="0 0 20 20" fill="currentColor">
-                                                        <path fill-rule="evenodd" d="M5.05 4.05a7 7 0 119.9 9.9L10 18.9l-4.95-4.95a7 7 0 010-9.9zM10 11a2 2 0 100-4 2 2 0 000 4z" clip-rule="evenodd" />
-                                                    </svg>
-                                                    {orchid.placement.clone()}
-                                                </span>
-                                            </div>
+                                                let status_color = match days_until {
+                                                    Some(d) if d < 0 && overdue => "text-danger bg-danger/10 dark:text-red-400 dark:bg-red-900/20",
+                                                    None | Some(..=0) => "text-amber-600 bg-amber-50 dark:text-amber-400 dark:bg-amber-900/20",
+                                                    _ => "text-sky-600 bg-sky-50 dark:text-sky-400 dark:bg-sky-900/20",
+                                                };
+
+                                                // Staggered animation delay
+                                                let delay_class = format!("animation-delay-{}", (i % 5) * 100);
+
+                                                view! {
+                                                    <div
+                                                        class=format!("group flex relative flex-col p-5 bg-white rounded-2xl border shadow-sm transition-all duration-300 cursor-pointer dark:bg-stone-800 border-stone-100 dark:border-stone-700 hover:shadow-md hover:border-primary/30 dark:hover:border-primary-light/30 animate-in fade-in slide-in-from-bottom-2 fill-mode-both {}", delay_class)
+                                                        on:click=move |_| on_select(orchid_clone.clone())
+                                                    >
+                                                        <div class="flex justify-between items-start mb-3">
+                                                            <div class="flex flex-col min-w-0">
+                                                                <h4 class="font-serif text-lg transition-colors truncate text-stone-800 dark:text-stone-100 dark:group-hover:text-primary-light group-hover:text-primary">
+                                                                    {orchid.name.clone()}
+                                                                </h4>
+                                                                <p class="text-sm italic truncate text-stone-500 dark:text-stone-400">
+                                                                    {orchid.species.clone()}
+                                                                </p>
+                                                            </div>
+                                                            <button
+                                                                class="flex flex-shrink-0 justify-center items-center w-10 h-10 rounded-full transition-colors text-sky-600 bg-sky-50 dark:bg-sky-900/30 dark:text-sky-400 dark:hover:bg-sky-900/50 hover:bg-sky-100 hover:text-sky-700"
+                                                                on:click=move |e| {
+                                                                    e.prevent_default();
+                                                                    e.stop_propagation();
+                                                                    on_water(orchid_id.clone());
+                                                                }
+                                                                aria-label=format!("Water {}", orchid.name)
+                                                                title="Mark as watered"
+                                                            >
+                                                                <svg xmlns="http://www.w3.org/2000/svg" class="w-5 h-5" viewBox="0 0 20 20" fill="currentColor">
+                                                                    <path fill-rule="evenodd" d="M3.172 5.172a4 4 0 015.656 0L10 6.343l1.172-1.171a4 4 0 115.656 5.656L10 17.657l-6.828-6.829a4 4 0 010-5.656z" clip-rule="evenodd" />
+                                                                </svg>
+                                                            </button>
+                                                        </div>
+
+                                                        <div class="mt-auto">
+                                                            <div class="flex gap-2 items-center">
+                                                                <span class=format!("px-2.5 py-1 text-xs font-semibold rounded-md {}", status_color)>
+                                                                    {status_text}
+                                                                </span>
+                                                                <span class="flex gap-1 items-center text-xs text-stone-400 dark:text-stone-500">
+                                                                    <svg xmlns="http://www.w3.org/2000/svg" class="w-3.5 h-3.5" viewBox="0 0 20 20" fill="currentColor">
+                                                                        <path fill-rule="evenodd" d="M5.05 4.05a7 7 0 119.9 9.9L10 18.9l-4.95-4.95a7 7 0 010-9.9zM10 11a2 2 0 100-4 2 2 0 000 4z" clip-rule="evenodd" />
+                                                                    </svg>
+                                                                    {orchid.placement.clone()}
+                                                                </span>
+                                                            </div>
+                                                            {hint.map(|h: ForecastHint| {
+                                                                let class = if h.is_urgent() {
+                                                                    "mt-2 mb-0 text-xs text-amber-700 dark:text-amber-400"
+                                                                } else {
+                                                                    "mt-2 mb-0 text-xs text-sky-700 dark:text-sky-400"
+                                                                };
+                                                                view! { <p class=class>{h.message(&unit.get())}</p> }
+                                                            })}
+                                                        </div>
+                                                    </div>
+                                                }
+                                            }).collect::<Vec<_>>()}
                                         </div>
-                                    </div>
+                                    </section>
                                 }
                             }).collect::<Vec<_>>()}
                         </div>
@@ -203,3 +260,138 @@ pub fn TodayTasks(
         </div>
     }
 }
+
+/// Follow-up reminders that are due, each with a "Done" button; renders nothing when none are due.
+#[component]
+fn DueReminders(
+    orchids: Memo<Vec<Orchid>>,
+    version: RwSignal<u32>,
+    on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let reminders = Resource::new(move || version.get(), |_| crate::server_fns::reminders::get_due_reminders());
+    let (done_ids, set_done_ids) = signal(Vec::<String>::new());
+    // Completing a re-treatment reminder records the application, which moves the card badge on
+    let treatment_badges = use_context::<crate::components::treatments::TreatmentBadges>();
+
+    let complete = move |id: String| {
+        set_done_ids.update(|ids| ids.push(id.clone()));
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::reminders::complete_reminder(id.clone()).await {
+                Ok(()) => {
+                    crate::components::treatments::refresh_treatment_badges(treatment_badges);
+                    // A recurring reminder has just scheduled its next occurrence
+                    version.update(|v| *v += 1);
+                }
+                Err(_e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("today_tasks.complete_reminder", &format!("Failed to complete reminder: {}", _e), &[("reminder_id", id.as_str())]);
+                    set_done_ids.update(|ids| ids.retain(|d| *d != id));
+                }
+            }
+        });
+    };
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || {
+                let done = done_ids.get();
+                let due: Vec<Reminder> = reminders.get()
+                    .and_then(|r| r.ok())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|r| !done.contains(&r.id))
+                    .collect();
+                (!due.is_empty()).then(|| view! {
+                    <div class="flex flex-col gap-2">
+                        <h3 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Follow-ups"</h3>
+                        {due.into_iter().map(|reminder| {
+                            let id = reminder.id.clone();
+                            let orchid_id = reminder.orchid_id.clone();
+                            let subtitle = reminder_subtitle(&reminder);
+                            let open = move |_| {
+                                if let Some(o) = orchid_id.as_ref().and_then(|oid| orchids.get_untracked().into_iter().find(|o| o.id == *oid)) {
+                                    on_select(o);
+                                }
+                            };
+                            view! {
+                                <div class="flex gap-3 items-center p-3 bg-white rounded-xl border dark:bg-stone-800 border-stone-100 dark:border-stone-700">
+                                    <div class="flex-1 min-w-0 cursor-pointer" on:click=open>
+                                        <div class="text-sm font-medium text-stone-800 dark:text-stone-100">{reminder.title}</div>
+                                        {(!subtitle.is_empty()).then(|| view! {
+                                            <div class="text-xs truncate text-stone-400">{subtitle}</div>
+                                        })}
+                                    </div>
+                                    <button
+                                        class="py-1.5 px-3 text-xs font-semibold rounded-lg border-none transition-colors cursor-pointer text-primary bg-primary/10 hover:bg-primary/20"
+                                        on:click=move |_| complete(id.clone())
+                                    >"Done"</button>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                })
+            }}
+        </Suspense>
+    }
+}
+
+/// Semi-hydro plants whose reservoir needs topping up, each with a "Topped Up" button; renders nothing when none are due.
+#[component]
+fn DueReservoirs(
+    orchids: Memo<Vec<Orchid>>,
+    climate_snapshots: Memo<Vec<ClimateSnapshot>>,
+    on_select: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (done_ids, set_done_ids) = signal(Vec::<String>::new());
+
+    let top_up = move |id: String| {
+        set_done_ids.update(|ids| ids.push(id.clone()));
+        leptos::task::spawn_local(async move {
+            if let Err(_e) = crate::server_fns::orchids::add_log_entry(id.clone(), "Reservoir topped up".into(), None, Some("ToppedUp".into()), None, None).await {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("today_tasks.top_up_reservoir", &format!("Failed to log reservoir top-up: {}", _e), &[("orchid_id", id.as_str())]);
+                set_done_ids.update(|ids| ids.retain(|d| *d != id));
+            }
+        });
+    };
+
+    move || {
+        let done = done_ids.get();
+        let snapshots = climate_snapshots.get();
+        let due: Vec<(Orchid, i64)> = orchids.get()
+            .into_iter()
+            .filter(|o| !done.contains(&o.id))
+            .filter_map(|o| {
+                let snapshot = snapshots.iter().find(|s| s.zone_name == o.placement);
+                let days = o.reservoir_days_until_due(snapshot)?;
+                (days <= 0).then_some((o, days))
+            })
+            .collect();
+        (!due.is_empty()).then(|| view! {
+            <div class="flex flex-col gap-2">
+                <h3 class="m-0 text-sm font-semibold tracking-wide text-stone-500 dark:text-stone-400">"Reservoir Top-Ups"</h3>
+                {due.into_iter().map(|(orchid, days)| {
+                    let id = orchid.id.clone();
+                    let name = orchid.name.clone();
+                    let status = match (orchid.last_topped_up_at, days) {
+                        (None, _) => "Not topped up yet".to_string(),
+                        (_, 0) => "Due today".to_string(),
+                        (_, d) => format!("{} days overdue", -d),
+                    };
+                    view! {
+                        <div class="flex gap-3 items-center p-3 bg-white rounded-xl border dark:bg-stone-800 border-stone-100 dark:border-stone-700">
+                            <div class="flex-1 min-w-0 cursor-pointer" on:click=move |_| on_select(orchid.clone())>
+                                <div class="text-sm font-medium text-stone-800 dark:text-stone-100">{name}</div>
+                                <div class="text-xs truncate text-stone-400">{status}</div>
+                            </div>
+                            <button
+                                class="py-1.5 px-3 text-xs font-semibold text-indigo-700 rounded-lg border-none transition-colors cursor-pointer dark:text-indigo-300 bg-indigo-500/10 hover:bg-indigo-500/20"
+                                on:click=move |_| top_up(id.clone())
+                            >"Topped Up"</button>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+        })
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use leptos::prelude::*;
+use crate::orchid::Orchid;
+use crate::treatment::{ProblemKind, Treatment, TreatmentStatus, MAX_TREATMENT_APPLICATIONS, MAX_TREATMENT_INTERVAL_DAYS};
+use super::{BTN_DANGER, BTN_PRIMARY, BTN_SECONDARY};
+
+const INPUT: &str = "py-1.5 px-2 text-sm rounded-lg border border-stone-300 dark:bg-stone-800 dark:border-stone-600";
+const BADGE_DUE: &str = "inline-block py-0.5 px-2 text-xs font-medium text-red-700 rounded-full border dark:text-red-300 bg-red-100/80 border-red-300/40 dark:bg-red-900/30 dark:border-red-700/40";
+const BADGE_OPEN: &str = "inline-block py-0.5 px-2 text-xs font-medium text-orange-700 rounded-full border dark:text-orange-300 bg-orange-100/80 border-orange-300/40 dark:bg-orange-900/30 dark:border-orange-700/40";
+
+/// Open treatments for the collection grid, keyed by orchid ID, provided as context by the home page.
+#[derive(Clone, Copy)]
+pub struct TreatmentBadges(pub RwSignal<HashMap<String, Treatment>>);
+
+/// Reloads the treatment badges after a treatment is started, applied, resolved or deleted.
+pub fn refresh_treatment_badges(ctx: Option<TreatmentBadges>) {
+    let Some(ctx) = ctx else { return };
+    leptos::task::spawn_local(async move {
+        match crate::server_fns::treatments::get_treatment_badges().await {
+            Ok(badges) => ctx.0.set(badges),
+            Err(_e) => {
+                #[cfg(feature = "hydrate")]
+                crate::server_fns::telemetry::emit_error("treatments.refresh_badges", &format!("Failed to reload treatment badges: {}", _e), &[]);
+            }
+        }
+    });
+}
+
+/// The treatment badge on an orchid card: the problem and when to re-treat, red once it's due.
+#[component]
+pub fn CardTreatmentBadge(orchid_id: String) -> impl IntoView {
+    let ctx = use_context::<TreatmentBadges>();
+    move || {
+        let t = ctx.and_then(|c| c.0.with(|b| b.get(&orchid_id).cloned()))?;
+        let now = chrono::Utc::now();
+        let class = if matches!(t.status(now), TreatmentStatus::Due { .. }) { BADGE_DUE } else { BADGE_OPEN };
+        let text = format!("{} {} \u{00B7} {}", t.kind.emoji(), t.problem, t.status_label(now));
+        Some(view! { <span class=class>{text}</span> })
+    }
+}
+
+/// A plant's pest and disease treatments, open ones with their series progress, and a form to start one.
+#[component]
+pub fn TreatmentsCard(orchid_signal: ReadSignal<Orchid>) -> impl IntoView {
+    let treatments = Resource::new(
+        move || orchid_signal.with(|o| o.id.clone()),
+        crate::server_fns::treatments::get_treatments,
+    );
+    let (show_form, set_show_form) = signal(false);
+    let badges = use_context::<TreatmentBadges>();
+
+    let on_changed = move || {
+        treatments.refetch();
+        refresh_treatment_badges(badges);
+    };
+
+    view! {
+        <div class="p-4 mb-4 rounded-xl border border-stone-200 dark:border-stone-700">
+            <div class="flex justify-between items-center mb-3">
+                <h4 class="m-0 text-xs font-semibold tracking-widest uppercase text-stone-500 dark:text-stone-400">"Pests & Treatments"</h4>
+                <button class=BTN_SECONDARY on:click=move |_| set_show_form.update(|s| *s = !*s)>
+                    {move || if show_form.get() { "Cancel" } else { "Start Treatment" }}
+                </button>
+            </div>
+            {move || show_form.get().then(|| view! {
+                <TreatmentForm orchid_signal=orchid_signal on_saved=move || {
+                    set_show_form.set(false);
+                    on_changed();
+                } />
+            })}
+            <Suspense fallback=|| view! { <p class="text-sm text-stone-400">"Loading treatments\u{2026}"</p> }>
+                {move || treatments.get().map(|result| match result {
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("treatments.load", &format!("Failed to load treatments: {}", e), &[]);
+                        view! { <p class="m-0 text-sm text-danger">{format!("Couldn't load treatments: {}", e)}</p> }.into_any()
+                    }
+                    Ok(list) if list.is_empty() => view! {
+                        <p class="m-0 text-sm text-stone-400">"No treatments recorded."</p>
+                    }.into_any(),
+                    Ok(list) => view! {
+                        <ul class="p-0 m-0 list-none">
+                            {list.into_iter().map(|t| view! { <TreatmentRow treatment=t on_changed=on_changed /> }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any(),
+                })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// One treatment: problem, product and dose, series progress and status, with Applied / Resolved
+/// actions while it's open and a two-step delete.
+#[component]
+fn TreatmentRow(treatment: Treatment, on_changed: impl Fn() + 'static + Copy + Send + Sync) -> impl IntoView {
+    let (confirming, set_confirming) = signal(false);
+    let (busy, set_busy) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let id = StoredValue::new(treatment.id.clone());
+    let now = chrono::Utc::now();
+    let status = treatment.status(now);
+    let can_apply = treatment.next_due_at().is_some();
+    let is_open = !treatment.is_resolved();
+
+    let run = move |action: &'static str| {
+        set_busy.set(true);
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            let id = id.get_value();
+            let result = match action {
+                "apply" => crate::server_fns::treatments::apply_treatment(id).await.map(|_| ()),
+                "resolve" => crate::server_fns::treatments::resolve_treatment(id).await.map(|_| ()),
+                _ => crate::server_fns::treatments::delete_treatment(id).await,
+            };
+            match result {
+                Ok(()) => on_changed(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("treatments.update", &format!("Failed to {} treatment: {}", action, e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_busy.set(false);
+        });
+    };
+
+    let mut detail = vec![treatment.product.clone()];
+    if let Some(dose) = &treatment.dose {
+        detail.push(dose.clone());
+    }
+    detail.push(format!("every {} days", treatment.interval_days));
+    detail.push(format!("applied {}", treatment.progress_label()));
+    let since = match treatment.resolved_at {
+        Some(at) => format!("{} \u{2013} {}", treatment.started_at.format("%b %-d"), at.format("%b %-d, %Y")),
+        None => format!("Since {}", treatment.started_at.format("%b %-d, %Y")),
+    };
+    let status_class = match status {
+        TreatmentStatus::Due { .. } => "text-xs font-semibold text-danger",
+        TreatmentStatus::Resolved => "text-xs text-stone-400",
+        _ => "text-xs font-medium text-orange-600 dark:text-orange-400",
+    };
+
+    view! {
+        <li class="flex gap-3 items-start py-2 border-t first:border-t-0 border-stone-100 dark:border-stone-800">
+            <span class="text-lg" aria-hidden="true">{treatment.kind.emoji()}</span>
+            <div class="flex-1 min-w-0">
+                <div class="flex gap-2 items-baseline">
+                    <span class=if is_open { "text-sm font-semibold text-stone-800 dark:text-stone-100" } else { "text-sm font-semibold text-stone-500 dark:text-stone-400" }>
+                        {treatment.problem.clone()}
+                    </span>
+                    <span class=status_class>{treatment.status_label(now)}</span>
+                </div>
+                <div class="text-xs text-stone-400">{detail.join(" \u{00B7} ")}</div>
+                <div class="text-xs text-stone-400">{since}</div>
+                {move || error_msg.get().map(|e| view! { <p class="mt-1 mb-0 text-xs text-danger">{e}</p> })}
+                {is_open.then(|| view! {
+                    <div class="flex gap-2 mt-2">
+                        {can_apply.then(|| view! {
+                            <button class=BTN_SECONDARY disabled=move || busy.get() on:click=move |_| run("apply")>"Applied Again"</button>
+                        })}
+                        <button class=BTN_SECONDARY disabled=move || busy.get() on:click=move |_| run("resolve")>"Resolved"</button>
+                    </div>
+                })}
+            </div>
+            {move || if confirming.get() {
+                view! {
+                    <div class="flex gap-1">
+                        <button class=BTN_DANGER disabled=move || busy.get() on:click=move |_| run("delete")>"Delete"</button>
+                        <button class=BTN_SECONDARY on:click=move |_| set_confirming.set(false)>"Keep"</button>
+                    </div>
+                }.into_any()
+            } else {
+                view! { <button class=BTN_DANGER on:click=move |_| set_confirming.set(true)>"Remove"</button> }.into_any()
+            }}
+        </li>
+    }
+}
+
+/// Starts a treatment with its first application made today, and the re-treatment series to follow.
+#[component]
+fn TreatmentForm(
+    orchid_signal: ReadSignal<Orchid>,
+    on_saved: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (kind, set_kind) = signal(ProblemKind::Pest);
+    let (problem, set_problem) = signal(String::new());
+    let (product, set_product) = signal(String::new());
+    let (dose, set_dose) = signal(String::new());
+    let (interval, set_interval) = signal("7".to_string());
+    let (applications, set_applications) = signal("3".to_string());
+    let (is_saving, set_is_saving) = signal(false);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let save = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let (Ok(interval_days), Ok(planned)) = (
+            interval.get_untracked().trim().parse::<u32>(),
+            applications.get_untracked().trim().parse::<u32>(),
+        ) else {
+            set_error_msg.set(Some("Enter the days between applications and how many to make".into()));
+            return;
+        };
+        set_is_saving.set(true);
+        set_error_msg.set(None);
+        let orchid_id = orchid_signal.get_untracked().id;
+
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::treatments::start_treatment(
+                orchid_id,
+                kind.get_untracked(),
+                problem.get_untracked(),
+                product.get_untracked(),
+                Some(dose.get_untracked()),
+                interval_days,
+                planned,
+            ).await {
+                Ok(_) => on_saved(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("treatments.start", &format!("Failed to start treatment: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <form class="p-3 mb-3 rounded-lg bg-secondary" on:submit=save>
+            <div class="flex flex-wrap gap-3 mb-3">
+                <label class="flex flex-col gap-1 text-xs text-stone-500 dark:text-stone-400">
+                    "Type"
+                    <select class=INPUT on:change=move |ev| if let Some(k) = ProblemKind::parse(&event_target_value(&ev)) { set_kind.set(k) }>
+                        {ProblemKind::ALL.into_iter().map(|k| view! {
+                            <option value=k.as_str() selected=move || kind.get() == k>{k.label()}</option>
+                        }).collect::<Vec<_>>()}
+                    </select>
+                </label>
+                <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[140px]">
+                    "Pest or disease"
+                    <input type="text" class=INPUT maxlength="100" required
+                        placeholder=move || kind.get().placeholder()
+                        prop:value=problem
+                        on:input=move |ev| set_problem.set(event_target_value(&ev))
+                    />
+                </label>
+            </div>
+            <div class="flex flex-wrap gap-3 mb-3">
+                <label class="flex flex-col flex-1 gap-1 text-xs text-stone-500 dark:text-stone-400 min-w-[140px]">
+                    "Product"
+                    <input type="text" class=INPUT maxlength="100" required placeholder="e.g. Neem oil"
+                        prop:value=product
+                        on:input=move |ev| set_product.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="flex flex-col gap-1 w-28 text-xs text-stone-500 dark:text-stone-400">
+                    "Dose"
+                    <input type="text" class=INPUT maxlength="100" placeholder="e.g. 5 ml/L"
+                        prop:value=dose
+                        on:input=move |ev| set_dose.set(event_target_value(&ev))
+                    />
+                </label>
+            </div>
+            <div class="flex flex-wrap gap-3 items-end mb-3">
+                <label class="flex flex-col gap-1 w-28 text-xs text-stone-500 dark:text-stone-400">
+                    "Re-treat every"
+                    <input type="number" class=INPUT required min="1" max=MAX_TREATMENT_INTERVAL_DAYS.to_string()
+                        prop:value=interval
+                        on:input=move |ev| set_interval.set(event_target_value(&ev))
+                    />
+                </label>
+                <span class="pb-2 text-xs text-stone-400">"days,"</span>
+                <label class="flex flex-col gap-1 w-20 text-xs text-stone-500 dark:text-stone-400">
+                    "Applications"
+                    <input type="number" class=INPUT required min="1" max=MAX_TREATMENT_APPLICATIONS.to_string()
+                        prop:value=applications
+                        on:input=move |ev| set_applications.set(event_target_value(&ev))
+                    />
+                </label>
+                <span class="pb-2 text-xs text-stone-400">"in all, starting today"</span>
+            </div>
+            {move || error_msg.get().map(|e| view! { <p class="mt-0 mb-2 text-xs text-danger">{e}</p> })}
+            <button type="submit" class=BTN_PRIMARY disabled=move || is_saving.get()>
+                {move || if is_saving.get() { "Saving..." } else { "Record First Application" }}
+            </button>
+        </form>
+    }
+}
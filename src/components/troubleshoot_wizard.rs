@@ -0,0 +1,216 @@
+use leptos::prelude::*;
+use crate::orchid::{LogEntry, Orchid};
+use crate::troubleshoot::{causes, diagnosis_note, follow_up_days, question, Answer, Cause, Next, Question, START_QUESTION};
+use super::{BTN_PRIMARY, BTN_SECONDARY};
+
+const ANSWER_BTN: &str = "py-2.5 px-3 w-full text-sm text-left rounded-lg border cursor-pointer transition-colors bg-white/60 border-stone-200 text-stone-700 hover:border-primary/40 hover:bg-primary/5 dark:bg-stone-800/60 dark:border-stone-700 dark:text-stone-300";
+
+/// The questions answered so far, each with the answer given.
+type AnsweredPath = Vec<(&'static Question, &'static Answer)>;
+
+/// Where the wizard is: the questions answered so far, then either the next question or the causes.
+fn walk(answers: &[usize]) -> (AnsweredPath, Result<&'static Question, Vec<&'static Cause>>) {
+    let mut path = Vec::with_capacity(answers.len());
+    let mut current = question(START_QUESTION).expect("start question exists");
+    for &idx in answers {
+        let Some(answer) = current.answers.get(idx) else { break };
+        path.push((current, answer));
+        match answer.next {
+            Next::Ask(id) => match question(id) {
+                Some(q) => current = q,
+                None => return (path, Err(Vec::new())),
+            },
+            Next::Diagnose(ids) => return (path, Err(causes(ids))),
+        }
+    }
+    (path, Ok(current))
+}
+
+/// Decision-tree wizard that asks about symptoms, shows likely causes with a plan, and can save
+/// the result as a Diagnosis journal entry plus a follow-up reminder.
+#[component]
+pub fn TroubleshootWizard(
+    orchid_signal: ReadSignal<Orchid>,
+    set_log_entries: WriteSignal<Vec<LogEntry>>,
+) -> impl IntoView {
+    let (answers, set_answers) = signal(Vec::<usize>::new());
+    let (save_journal, set_save_journal) = signal(true);
+    let (add_reminder, set_add_reminder) = signal(true);
+    let (is_saving, set_is_saving) = signal(false);
+    let (status, set_status) = signal(None::<Result<String, String>>);
+
+    let state = Memo::new(move |_| walk(&answers.get()));
+
+    let restart = move |_| {
+        set_answers.set(Vec::new());
+        set_status.set(None);
+    };
+    let back = move |_| {
+        set_answers.update(|a| { a.pop(); });
+        set_status.set(None);
+    };
+
+    let save = move |_| {
+        let (path, result) = state.get_untracked();
+        let Err(found) = result else { return };
+        let orchid = orchid_signal.get_untracked();
+        let note = diagnosis_note(&path, &found);
+        let reminder = add_reminder.get_untracked()
+            .then(|| found.first().zip(follow_up_days(&found)))
+            .flatten()
+            .map(|(cause, days)| (format!("Check {} for {}", orchid.name, cause.name.to_lowercase()), days));
+        let journal = save_journal.get_untracked();
+        if !journal && reminder.is_none() {
+            return;
+        }
+
+        set_is_saving.set(true);
+        set_status.set(None);
+        leptos::task::spawn_local(async move {
+            let mut saved = Vec::new();
+            if journal {
+                match crate::server_fns::orchids::add_log_entry(orchid.id.clone(), note, None, Some("Diagnosis".into()), None, None).await {
+                    Ok(response) => {
+                        set_log_entries.update(|entries| entries.insert(0, response.entry));
+                        saved.push("Saved to journal".to_string());
+                    }
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_error("troubleshoot.save_journal", &format!("Failed to save diagnosis: {}", e), &[]);
+                        set_status.set(Some(Err(format!("Couldn't save to journal: {}", e))));
+                        set_is_saving.set(false);
+                        return;
+                    }
+                }
+            }
+            if let Some((title, days)) = reminder {
+                match crate::server_fns::reminders::create_reminder(Some(orchid.id.clone()), title, days).await {
+                    Ok(_) => saved.push(format!("reminder set for {} days from now", days)),
+                    Err(e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_error("troubleshoot.create_reminder", &format!("Failed to create follow-up reminder: {}", e), &[]);
+                        set_status.set(Some(Err(format!("Couldn't set reminder: {}", e))));
+                        set_is_saving.set(false);
+                        return;
+                    }
+                }
+            }
+            set_status.set(Some(Ok(saved.join(", "))));
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-4">
+            // Answers so far
+            {move || {
+                let (path, _) = state.get();
+                (!path.is_empty()).then(|| view! {
+                    <ol class="pl-5 m-0 text-xs text-stone-500 dark:text-stone-400">
+                        {path.into_iter().map(|(_, a)| view! { <li>{a.label}</li> }).collect::<Vec<_>>()}
+                    </ol>
+                })
+            }}
+
+            {move || match state.get().1 {
+                Ok(q) => view! {
+                    <div>
+                        <h3 class="mt-0 mb-3 text-base font-semibold text-stone-800 dark:text-stone-100">{q.prompt}</h3>
+                        <div class="flex flex-col gap-2">
+                            {q.answers.iter().enumerate().map(|(idx, a)| view! {
+                                <button
+                                    class=ANSWER_BTN
+                                    on:click=move |_| set_answers.update(|list| list.push(idx))
+                                >{a.label}</button>
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }.into_any(),
+                Err(found) => {
+                    let days = follow_up_days(&found);
+                    view! {
+                        <div class="flex flex-col gap-3">
+                            <h3 class="m-0 text-base font-semibold text-stone-800 dark:text-stone-100">"Likely causes"</h3>
+                            {found.into_iter().enumerate().map(|(i, c)| view! {
+                                <div class="p-3 rounded-xl border border-stone-200 dark:border-stone-700">
+                                    <div class="text-sm font-semibold text-stone-800 dark:text-stone-100">
+                                        {if i == 0 { format!("{} (most likely)", c.name) } else { c.name.to_string() }}
+                                    </div>
+                                    <p class="mt-1 mb-2 text-xs text-stone-500 dark:text-stone-400">{c.explanation}</p>
+                                    <ul class="pl-5 m-0 text-sm text-stone-700 dark:text-stone-300">
+                                        {c.actions.iter().map(|a| view! { <li>{*a}</li> }).collect::<Vec<_>>()}
+                                    </ul>
+                                </div>
+                            }).collect::<Vec<_>>()}
+
+                            <label class="flex gap-2 items-center text-sm text-stone-600 dark:text-stone-400">
+                                <input type="checkbox"
+                                    prop:checked=save_journal
+                                    on:change=move |ev| set_save_journal.set(event_target_checked(&ev))
+                                />
+                                "Save this diagnosis to the journal"
+                            </label>
+                            {days.map(|d| view! {
+                                <label class="flex gap-2 items-center text-sm text-stone-600 dark:text-stone-400">
+                                    <input type="checkbox"
+                                        prop:checked=add_reminder
+                                        on:change=move |ev| set_add_reminder.set(event_target_checked(&ev))
+                                    />
+                                    {format!("Remind me to check again in {} days", d)}
+                                </label>
+                            })}
+                            <div>
+                                <button
+                                    class=BTN_PRIMARY
+                                    disabled=move || is_saving.get() || status.get().is_some_and(|s| s.is_ok())
+                                    on:click=save
+                                >
+                                    {move || if is_saving.get() { "Saving..." } else { "Save" }}
+                                </button>
+                            </div>
+                        </div>
+                    }.into_any()
+                }
+            }}
+
+            {move || status.get().map(|result| match result {
+                Ok(msg) => view! {
+                    <div class="p-2 text-xs text-emerald-700 bg-emerald-50 rounded-lg dark:text-emerald-300 dark:bg-emerald-900/20">{msg}</div>
+                }.into_any(),
+                Err(msg) => view! {
+                    <div class="p-2 text-xs text-red-700 bg-red-50 rounded-lg dark:text-red-300 dark:bg-red-900/20">{msg}</div>
+                }.into_any(),
+            })}
+
+            {move || (!answers.get().is_empty()).then(|| view! {
+                <div class="flex gap-2">
+                    <button class=BTN_SECONDARY on:click=back>"Back"</button>
+                    <button class=BTN_SECONDARY on:click=restart>"Start Over"</button>
+                </div>
+            })}
+
+            <p class="m-0 text-[11px] text-stone-400">"A guide, not a guarantee: when several plants show the same symptoms, check the zone's climate first."</p>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_walk_reaches_diagnosis() {
+        // "Leaves wrinkled" -> "roots brown and mushy"
+        let (path, result) = walk(&[0, 1]);
+        assert_eq!(path.len(), 2);
+        let found = result.unwrap_err();
+        assert_eq!(found[0].id, "root_rot");
+    }
+
+    #[test]
+    fn test_walk_ignores_out_of_range_answers() {
+        let (path, result) = walk(&[99]);
+        assert!(path.is_empty());
+        assert_eq!(result.unwrap().id, START_QUESTION);
+    }
+}
@@ -0,0 +1,256 @@
+use leptos::prelude::*;
+use crate::water_quality::{WaterQualityWarning, WaterSource, WaterSourceKind};
+use super::{format_time_ago, BTN_DANGER, BTN_PRIMARY};
+
+const INPUT_SM: &str = "w-full px-2 py-1.5 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-400 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:focus:bg-stone-800";
+const LABEL_SM: &str = "block mb-1 text-[10px] font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Parses an optional number field: blank is `None`, anything else must be a number.
+fn parse_optional(raw: &str, label: &str) -> Result<Option<f64>, String> {
+    match raw.trim() {
+        "" => Ok(None),
+        v => v.parse::<f64>().map(Some).map_err(|_| format!("{} '{}' is not a number", label, v)),
+    }
+}
+
+/// Water sources with their latest pH and EC/TDS readings, warnings for the collection's genera, and forms to log more.
+#[component]
+pub fn WaterQualityEditor() -> impl IntoView {
+    let sources = Resource::new(|| (), |_| crate::server_fns::water_quality::get_water_sources());
+    let warnings = Resource::new(|| (), |_| crate::server_fns::water_quality::get_water_quality_warnings());
+    let (new_name, set_new_name) = signal(String::new());
+    let (new_kind, set_new_kind) = signal(WaterSourceKind::Tap);
+    let (error_msg, set_error_msg) = signal(None::<String>);
+
+    let reload = move || {
+        sources.refetch();
+        warnings.refetch();
+    };
+
+    let add = move |_| {
+        let name = new_name.get_untracked();
+        if name.trim().is_empty() {
+            return;
+        }
+        set_error_msg.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::water_quality::add_water_source(name, new_kind.get_untracked()).await {
+                Ok(_) => {
+                    set_new_name.set(String::new());
+                    reload();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("settings.add_water_source", &format!("Failed to add water source: {}", e), &[]);
+                    set_error_msg.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    view! {
+        <Suspense fallback=|| view! { <p class="text-xs text-stone-400">"Loading water sources..."</p> }>
+            {move || sources.get().map(|result| {
+                let list: Vec<WaterSource> = result.unwrap_or_default();
+                let all_warnings: Vec<WaterQualityWarning> = warnings.get().and_then(|r| r.ok()).unwrap_or_default();
+                if list.is_empty() {
+                    return view! { <p class="mt-0 mb-3 text-xs text-stone-400">"No water sources yet."</p> }.into_any();
+                }
+                view! {
+                    <div class="flex flex-col gap-2 mb-3">
+                        {list.into_iter().map(|s| {
+                            let source_warnings: Vec<String> = all_warnings.iter()
+                                .filter(|w| w.source_id == s.id)
+                                .map(|w| w.issue.message())
+                                .collect();
+                            view! { <WaterSourceRow source=s warnings=source_warnings on_changed=reload /> }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+        <div class="flex gap-2 items-end">
+            <div class="flex-1">
+                <label class=LABEL_SM>"Source name"</label>
+                <input
+                    type="text"
+                    class=INPUT_SM
+                    placeholder="Kitchen tap"
+                    maxlength=crate::water_quality::MAX_SOURCE_NAME_CHARS.to_string()
+                    prop:value=move || new_name.get()
+                    on:input=move |ev| set_new_name.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="w-40">
+                <label class=LABEL_SM>"Kind"</label>
+                <select class=INPUT_SM on:change=move |ev| {
+                    if let Some(kind) = WaterSourceKind::parse(&event_target_value(&ev)) {
+                        set_new_kind.set(kind);
+                    }
+                }>
+                    {WaterSourceKind::ALL.into_iter().map(|k| view! {
+                        <option value=k.as_str() selected=move || new_kind.get() == k>{k.label()}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+            </div>
+            <button class=BTN_PRIMARY on:click=add>"Add Source"</button>
+        </div>
+        {move || error_msg.get().map(|e| view! { <p class="mt-2 mb-0 text-xs text-danger">{e}</p> })}
+    }
+}
+
+/// One water source: its latest reading and warnings, a form to log a reading, and its recent history.
+#[component]
+fn WaterSourceRow(
+    source: WaterSource,
+    warnings: Vec<String>,
+    on_changed: impl Fn() + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let source_id = StoredValue::new(source.id.clone());
+    let (ph, set_ph) = signal(String::new());
+    let (ec, set_ec) = signal(String::new());
+    let (tds, set_tds) = signal(String::new());
+    let (note, set_note) = signal(String::new());
+    let (status, set_status) = signal(None::<String>);
+    let (is_saving, set_is_saving) = signal(false);
+    let (show_history, set_show_history) = signal(false);
+    let history = Resource::new(
+        move || show_history.get(),
+        move |open| async move {
+            if open {
+                crate::server_fns::water_quality::get_water_readings(source_id.get_value()).await
+            } else {
+                Ok(Vec::new())
+            }
+        },
+    );
+
+    let log = move |_| {
+        let values = (
+            parse_optional(&ph.get_untracked(), "pH"),
+            parse_optional(&ec.get_untracked(), "EC"),
+            parse_optional(&tds.get_untracked(), "TDS"),
+        );
+        let (ph_value, ec_value, tds_value) = match values {
+            (Ok(p), Ok(e), Ok(t)) => (p, e, t),
+            (Err(msg), _, _) | (_, Err(msg), _) | (_, _, Err(msg)) => {
+                set_status.set(Some(msg));
+                return;
+            }
+        };
+        let note_value = Some(note.get_untracked()).filter(|n| !n.trim().is_empty());
+        set_is_saving.set(true);
+        set_status.set(None);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::water_quality::log_water_reading(source_id.get_value(), ph_value, ec_value, tds_value, note_value).await {
+                Ok(_) => {
+                    set_ph.set(String::new());
+                    set_ec.set(String::new());
+                    set_tds.set(String::new());
+                    set_note.set(String::new());
+                    history.refetch();
+                    on_changed();
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("settings.log_water_reading", &format!("Failed to log water reading: {}", e), &[]);
+                    set_status.set(Some(e.to_string()));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    let delete = move |_| {
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::water_quality::delete_water_source(source_id.get_value()).await {
+                Ok(()) => on_changed(),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.delete_water_source", &format!("Failed to delete water source: {}", e), &[]);
+                    set_status.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    let latest = source.latest.as_ref()
+        .map(|r| format!("{} \u{00B7} {}", r.summary(), format_time_ago(&r.measured_at)))
+        .unwrap_or_else(|| "No readings yet".to_string());
+
+    view! {
+        <div class="p-3 rounded-xl border bg-secondary/20 border-stone-200/60 dark:border-stone-700">
+            <div class="flex gap-3 justify-between items-start mb-2">
+                <div>
+                    <div class="text-sm font-semibold text-stone-700 dark:text-stone-300">{source.name.clone()}</div>
+                    <div class="text-xs text-stone-500 dark:text-stone-400">{format!("{} \u{00B7} {}", source.kind.label(), latest)}</div>
+                </div>
+                <button class=BTN_DANGER on:click=delete>"Remove"</button>
+            </div>
+            {warnings.into_iter().map(|w| view! {
+                <p class="py-1.5 px-2.5 mt-0 mb-2 text-xs rounded-lg text-amber-800 bg-amber-50 dark:text-amber-200 dark:bg-amber-900/30">{w}</p>
+            }).collect::<Vec<_>>()}
+            <div class="grid grid-cols-3 gap-2 items-end sm:grid-cols-6">
+                <div>
+                    <label class=LABEL_SM>"pH"</label>
+                    <input type="number" step="0.1" min="0" max="14" class=INPUT_SM placeholder="6.5" prop:value=ph on:input=move |ev| set_ph.set(event_target_value(&ev)) />
+                </div>
+                <div>
+                    <label class=LABEL_SM>"EC \u{00B5}S/cm"</label>
+                    <input type="number" min="0" class=INPUT_SM placeholder="150" prop:value=ec on:input=move |ev| set_ec.set(event_target_value(&ev)) />
+                </div>
+                <div>
+                    <label class=LABEL_SM>"TDS ppm"</label>
+                    <input type="number" min="0" class=INPUT_SM placeholder="75" prop:value=tds on:input=move |ev| set_tds.set(event_target_value(&ev)) />
+                </div>
+                <div class="col-span-2">
+                    <label class=LABEL_SM>"Note"</label>
+                    <input type="text" maxlength="200" class=INPUT_SM prop:value=note on:input=move |ev| set_note.set(event_target_value(&ev)) />
+                </div>
+                <button
+                    class=format!("{} text-white bg-primary hover:bg-primary-dark", BTN_SM)
+                    disabled=move || is_saving.get()
+                    on:click=log
+                >
+                    {move || if is_saving.get() { "Saving\u{2026}" } else { "Log" }}
+                </button>
+            </div>
+            {move || status.get().map(|e| view! { <p class="mt-2 mb-0 text-xs text-danger">{e}</p> })}
+            {source.latest.is_some().then(|| view! {
+                <button
+                    class="p-0 mt-2 text-xs bg-transparent border-none cursor-pointer text-primary dark:text-primary-light"
+                    on:click=move |_| set_show_history.update(|v| *v = !*v)
+                >
+                    {move || if show_history.get() { "Hide history" } else { "Show history" }}
+                </button>
+            })}
+            {move || show_history.get().then(|| view! {
+                <Suspense fallback=|| view! { <p class="mt-1 mb-0 text-xs text-stone-400">"Loading..."</p> }>
+                    {move || history.get().map(|result| view! {
+                        <ul class="p-0 mt-1 mb-0 list-none">
+                            {result.unwrap_or_default().into_iter().map(|r| view! {
+                                <li class="flex gap-2 justify-between py-0.5 text-xs text-stone-600 dark:text-stone-400">
+                                    <span>{r.summary()}{r.note.map(|n| format!(" \u{2014} {}", n))}</span>
+                                    <span class="text-stone-400">{r.measured_at.format("%Y-%m-%d").to_string()}</span>
+                                </li>
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    })}
+                </Suspense>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_optional() {
+        assert_eq!(parse_optional("  ", "pH"), Ok(None));
+        assert_eq!(parse_optional("6.4", "pH"), Ok(Some(6.4)));
+        assert_eq!(parse_optional("six", "pH"), Err("pH 'six' is not a number".to_string()));
+    }
+}
@@ -0,0 +1,237 @@
+use leptos::prelude::*;
+use crate::energy::{EnergyDevice, EnergyDeviceKind, ZoneEconomics, ZoneEnergyProfile};
+use crate::orchid::GrowingZone;
+
+const INPUT_SM: &str = "w-full px-2 py-1.5 text-sm bg-white/80 border border-stone-300/50 rounded-lg outline-none transition-all duration-200 placeholder:text-stone-400 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:focus:bg-stone-800";
+const LABEL_SM: &str = "block mb-1 text-[10px] font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400";
+const BTN_SM: &str = "py-1.5 px-3 text-xs font-semibold rounded-lg border-none cursor-pointer transition-colors";
+
+/// Formats a monthly cost, or the energy alone when no price is set.
+fn cost_label(cost: Option<f64>, kwh: f64) -> String {
+    match cost {
+        Some(c) => format!("{:.2} / month", c),
+        None => format!("{:.0} kWh / month", kwh),
+    }
+}
+
+/// Monthly running cost cards for zones with devices listed, shown on the My Plants tab.
+#[component]
+pub fn ZoneEconomicsCards() -> impl IntoView {
+    let economics = Resource::new(|| (), |_| crate::server_fns::energy::get_zone_economics());
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || economics.get().map(|result| {
+                let zones = match result {
+                    Ok(z) => z,
+                    Err(_e) => {
+                        #[cfg(feature = "hydrate")]
+                        crate::server_fns::telemetry::emit_warn("zone_economics.load", &format!("Failed to load zone economics: {}", _e), &[]);
+                        Vec::new()
+                    }
+                };
+                if zones.is_empty() {
+                    return view! { <div></div> }.into_any();
+                }
+                view! {
+                    <div class="mb-4">
+                        <h3 class="mt-0 mb-2 text-xs font-bold tracking-widest uppercase text-stone-400 dark:text-stone-500">"Running Costs"</h3>
+                        <div class="grid grid-cols-1 gap-3 sm:grid-cols-2">
+                            {zones.into_iter().map(|z| view! { <ZoneEconomicsCard economics=z /> }).collect::<Vec<_>>()}
+                        </div>
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+    }
+}
+
+/// A single zone's monthly cost with a per-device breakdown.
+#[component]
+fn ZoneEconomicsCard(economics: ZoneEconomics) -> impl IntoView {
+    let total = economics.monthly_kwh.max(f64::EPSILON);
+
+    view! {
+        <div class="p-4 rounded-xl border bg-surface border-stone-200/60 dark:border-stone-700/60">
+            <div class="flex gap-3 justify-between items-baseline mb-3">
+                <div class="text-sm font-semibold truncate text-stone-700 dark:text-stone-300">{economics.zone_name.clone()}</div>
+                <div class="text-lg tabular-nums font-display text-primary dark:text-primary-light">
+                    {cost_label(economics.monthly_cost, economics.monthly_kwh)}
+                </div>
+            </div>
+            {economics.devices.into_iter().map(|d| {
+                let width = format!("width: {:.0}%", (d.monthly_kwh / total * 100.0).clamp(0.0, 100.0));
+                view! {
+                    <div class="mb-2">
+                        <div class="flex justify-between text-xs text-stone-500 dark:text-stone-400">
+                            <span>{d.name}</span>
+                            <span class="font-semibold tabular-nums text-stone-700 dark:text-stone-300">{cost_label(d.monthly_cost, d.monthly_kwh)}</span>
+                        </div>
+                        <div class="overflow-hidden mt-1 h-1.5 rounded-full bg-stone-100 dark:bg-stone-800">
+                            <div class="h-full rounded-full bg-amber-400/70" style=width></div>
+                        </div>
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
+            <div class="pt-2 mt-2 text-[11px] text-stone-400 border-t border-stone-100 dark:border-stone-700/50">
+                {format!("{:.0} kWh per month", economics.monthly_kwh)}
+                {economics.price_per_kwh.map(|p| format!(" at {:.3} per kWh", p))}
+            </div>
+        </div>
+    }
+}
+
+/// Settings section for each zone's electricity price and powered devices.
+#[component]
+pub fn EnergySettingsEditor(zones: ReadSignal<Vec<GrowingZone>>) -> impl IntoView {
+    let profiles = Resource::new(|| (), |_| crate::server_fns::energy::get_zone_energy_profiles());
+
+    view! {
+        <Suspense fallback=|| view! { <p class="text-xs text-stone-400">"Loading devices..."</p> }>
+            {move || profiles.get().map(|result| {
+                let existing = result.unwrap_or_default();
+                let zones = zones.get();
+                if zones.is_empty() {
+                    return view! { <p class="m-0 text-xs text-stone-400">"Add a growing zone to list its devices."</p> }.into_any();
+                }
+                view! {
+                    <div class="flex flex-col gap-2">
+                        {zones.into_iter().map(|z| {
+                            let profile = existing.iter().find(|p| p.zone_id == z.id).cloned()
+                                .unwrap_or_else(|| ZoneEnergyProfile { zone_id: z.id.clone(), ..Default::default() });
+                            view! { <ZoneEnergyRow zone_name=z.name profile=profile /> }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            })}
+        </Suspense>
+    }
+}
+
+/// Price and device inputs for a single zone.
+#[component]
+fn ZoneEnergyRow(zone_name: String, profile: ZoneEnergyProfile) -> impl IntoView {
+    let zone_id = StoredValue::new(profile.zone_id.clone());
+    let (price, set_price) = signal(profile.price_per_kwh.map(|p| p.to_string()).unwrap_or_default());
+    let (devices, set_devices) = signal(profile.devices);
+    let (status, set_status) = signal(None::<Result<(), String>>);
+    let (is_saving, set_is_saving) = signal(false);
+
+    let update_device = move |idx: usize, f: Box<dyn FnOnce(&mut EnergyDevice)>| {
+        set_devices.update(|list| {
+            if let Some(d) = list.get_mut(idx) {
+                f(d);
+            }
+        });
+    };
+
+    let save = move |_| {
+        let raw_price = price.get_untracked();
+        let price_per_kwh = match raw_price.trim() {
+            "" => None,
+            p => match p.parse::<f64>() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    set_status.set(Some(Err(format!("'{}' is not a number", p))));
+                    return;
+                }
+            },
+        };
+        let profile = ZoneEnergyProfile {
+            zone_id: zone_id.get_value(),
+            price_per_kwh,
+            devices: devices.get_untracked(),
+        };
+        if let Err(msg) = profile.validate() {
+            set_status.set(Some(Err(msg)));
+            return;
+        }
+
+        set_is_saving.set(true);
+        leptos::task::spawn_local(async move {
+            match crate::server_fns::energy::save_zone_energy_profile(profile).await {
+                Ok(()) => set_status.set(Some(Ok(()))),
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("settings.save_zone_energy", &format!("Failed to save zone energy profile: {}", e), &[]);
+                    set_status.set(Some(Err(format!("{}", e))));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="p-3 rounded-xl border bg-secondary/20 border-stone-200/60 dark:border-stone-700">
+            <div class="flex gap-3 justify-between items-end mb-2">
+                <div class="text-sm font-semibold text-stone-700 dark:text-stone-300">{zone_name}</div>
+                <div class="w-32">
+                    <label class=LABEL_SM>"Price / kWh"</label>
+                    <input type="number" step="0.01" min="0" class=INPUT_SM placeholder="0.25" prop:value=price on:input=move |ev| set_price.set(event_target_value(&ev)) />
+                </div>
+            </div>
+            {move || devices.get().into_iter().enumerate().map(|(idx, d)| view! {
+                <div class="grid grid-cols-2 gap-2 items-end mb-2 sm:grid-cols-5">
+                    <div>
+                        <label class=LABEL_SM>"Type"</label>
+                        <select class=INPUT_SM on:change=move |ev| {
+                            let kind = EnergyDeviceKind::from_key(&event_target_value(&ev));
+                            update_device(idx, Box::new(move |d| d.kind = kind));
+                        }>
+                            {EnergyDeviceKind::ALL.into_iter().map(|k| view! {
+                                <option value=k.as_str() selected=k == d.kind>{k.label()}</option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    </div>
+                    <div class="sm:col-span-2">
+                        <label class=LABEL_SM>"Name"</label>
+                        <input type="text" class=INPUT_SM prop:value=d.name.clone() on:change=move |ev| {
+                            let name = event_target_value(&ev);
+                            update_device(idx, Box::new(move |d| d.name = name));
+                        } />
+                    </div>
+                    <div>
+                        <label class=LABEL_SM>"Watts"</label>
+                        <input type="number" min="0" class=INPUT_SM prop:value=d.watts.to_string() on:change=move |ev| {
+                            let watts = event_target_value(&ev).parse().unwrap_or(0.0);
+                            update_device(idx, Box::new(move |d| d.watts = watts));
+                        } />
+                    </div>
+                    <div class="flex gap-1 items-end">
+                        <div class="flex-1">
+                            <label class=LABEL_SM>"Hours/day"</label>
+                            <input type="number" min="0" max="24" step="0.5" class=INPUT_SM prop:value=d.hours_per_day.to_string() on:change=move |ev| {
+                                let hours = event_target_value(&ev).parse().unwrap_or(0.0);
+                                update_device(idx, Box::new(move |d| d.hours_per_day = hours));
+                            } />
+                        </div>
+                        <button
+                            class=format!("{} text-red-600 bg-transparent hover:bg-red-50 dark:hover:bg-red-900/20", BTN_SM)
+                            title="Remove device"
+                            on:click=move |_| set_devices.update(|list| { list.remove(idx); })
+                        >"\u{00D7}"</button>
+                    </div>
+                </div>
+            }).collect::<Vec<_>>()}
+            <div class="flex gap-2 items-center mt-2">
+                <button
+                    class=format!("{} text-stone-600 bg-stone-100 hover:bg-stone-200 dark:text-stone-300 dark:bg-stone-800 dark:hover:bg-stone-700", BTN_SM)
+                    on:click=move |_| set_devices.update(|list| list.push(EnergyDevice { hours_per_day: 12.0, ..Default::default() }))
+                >
+                    "+ Device"
+                </button>
+                <button
+                    class=format!("{} text-primary bg-primary/10 hover:bg-primary/20", BTN_SM)
+                    disabled=move || is_saving.get()
+                    on:click=save
+                >
+                    {move || if is_saving.get() { "Saving..." } else { "Save" }}
+                </button>
+                {move || status.get().map(|s| match s {
+                    Ok(()) => view! { <span class="text-xs text-emerald-600 dark:text-emerald-400">"Saved"</span> }.into_any(),
+                    Err(msg) => view! { <span class="text-xs text-red-600 dark:text-red-400">{msg}</span> }.into_any(),
+                })}
+            </div>
+        </div>
+    }
+}
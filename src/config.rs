@@ -17,6 +17,12 @@ pub struct AppConfig {
     pub surreal_pass: String,
     /// Directory path for storing uploaded images.
     pub image_storage_path: String,
+    /// SurrealDB's data directory or file, when it runs on this host; only used to report the database's size.
+    pub surreal_data_path: String,
+    /// AI requests (scans, diagnoses, recaps, chat) each user may make per day; 0 for no cap.
+    pub ai_daily_quota: u32,
+    /// Photo uploads each user may make per day; 0 for no cap.
+    pub upload_daily_quota: u32,
     /// API key for Google Gemini.
     pub gemini_api_key: String,
     /// The Google Gemini model to use.
@@ -25,6 +31,18 @@ pub struct AppConfig {
     pub claude_api_key: String,
     /// The Anthropic Claude model to use.
     pub claude_model: String,
+    /// API key for OpenAI (or a compatible service).
+    pub openai_api_key: String,
+    /// The OpenAI model to use.
+    pub openai_model: String,
+    /// Base URL of the OpenAI-compatible API.
+    pub openai_base_url: String,
+    /// URL of a local Ollama server; empty disables it.
+    pub ollama_url: String,
+    /// The Ollama model to use; needs vision support for photo features.
+    pub ollama_model: String,
+    /// Comma-separated AI providers to try, in order; empty tries every configured one.
+    pub ai_providers: String,
     /// Secret key used for session encryption.
     pub session_secret: String,
     /// Address to bind the Leptos server to.
@@ -37,6 +55,30 @@ pub struct AppConfig {
     pub vapid_public_key: String,
     /// Contact information (email/URL) for VAPID.
     pub vapid_contact: String,
+    /// WebAuthn relying party ID (the bare domain passkeys are bound to).
+    pub webauthn_rp_id: String,
+    /// WebAuthn relying party origin (scheme + host + optional port).
+    pub webauthn_rp_origin: String,
+    /// Public base URL used to build links in outgoing email.
+    pub app_base_url: String,
+    /// SMTP relay hostname; empty disables outgoing email.
+    pub smtp_host: String,
+    /// SMTP relay port (STARTTLS).
+    pub smtp_port: u16,
+    /// SMTP username.
+    pub smtp_username: String,
+    /// SMTP password.
+    pub smtp_password: String,
+    /// The From address for outgoing email.
+    pub smtp_from: String,
+    /// Days raw climate readings are kept before being rolled up into hourly summaries.
+    pub climate_raw_retention_days: u32,
+    /// Days hourly climate summaries are kept before being rolled up into daily summaries.
+    pub climate_hourly_retention_days: u32,
+    /// Days daily climate summaries are kept; 0 keeps them forever.
+    pub climate_daily_retention_days: u32,
+    /// WebP quality (1-100) uploaded photos are recompressed at; 0 stores uploads as sent.
+    pub image_webp_quality: u8,
 }
 
 impl AppConfig {
@@ -49,16 +91,37 @@ impl AppConfig {
             surreal_user: std::env::var("SURREAL_USER").unwrap_or_else(|_| "root".into()),
             surreal_pass: std::env::var("SURREAL_PASS").unwrap_or_else(|_| "root".into()),
             image_storage_path: std::env::var("IMAGE_STORAGE_PATH").unwrap_or_else(|_| "./data/images".into()),
+            surreal_data_path: std::env::var("SURREAL_DATA_PATH").unwrap_or_default(),
+            ai_daily_quota: std::env::var("AI_DAILY_QUOTA").unwrap_or_else(|_| "200".into()).parse::<u32>().unwrap_or(200),
+            upload_daily_quota: std::env::var("UPLOAD_DAILY_QUOTA").unwrap_or_else(|_| "500".into()).parse::<u32>().unwrap_or(500),
             gemini_api_key: std::env::var("GEMINI_API_KEY").unwrap_or_default(),
             gemini_model: std::env::var("GEMINI_MODEL").unwrap_or_else(|_| "gemini-2.0-flash".into()),
             claude_api_key: std::env::var("CLAUDE_API_KEY").unwrap_or_default(),
             claude_model: std::env::var("CLAUDE_MODEL").unwrap_or_else(|_| "claude-sonnet-4-20250514".into()),
+            openai_api_key: std::env::var("OPENAI_API_KEY").unwrap_or_default(),
+            openai_model: std::env::var("OPENAI_MODEL").unwrap_or_else(|_| "gpt-4o-mini".into()),
+            openai_base_url: std::env::var("OPENAI_BASE_URL").unwrap_or_else(|_| "https://api.openai.com/v1".into()),
+            ollama_url: std::env::var("OLLAMA_URL").unwrap_or_default(),
+            ollama_model: std::env::var("OLLAMA_MODEL").unwrap_or_else(|_| "llama3.2-vision".into()),
+            ai_providers: std::env::var("AI_PROVIDERS").unwrap_or_default(),
             session_secret: std::env::var("SESSION_SECRET").unwrap_or_else(|_| "change-me-in-production-must-be-at-least-64-chars-long-for-security-purposes-ok".into()),
             site_addr: std::env::var("LEPTOS_SITE_ADDR").unwrap_or_else(|_| "0.0.0.0:3000".into()),
             reload_port: std::env::var("LEPTOS_RELOAD_PORT").unwrap_or_else(|_| "3001".into()).parse::<u32>().unwrap_or(3001),
             vapid_private_key: std::env::var("VAPID_PRIVATE_KEY").unwrap_or_default(),
             vapid_public_key: std::env::var("VAPID_PUBLIC_KEY").unwrap_or_default(),
             vapid_contact: std::env::var("VAPID_CONTACT").unwrap_or_else(|_| "mailto:admin@example.com".into()),
+            webauthn_rp_id: std::env::var("WEBAUTHN_RP_ID").unwrap_or_else(|_| "localhost".into()),
+            webauthn_rp_origin: std::env::var("WEBAUTHN_RP_ORIGIN").unwrap_or_else(|_| "http://localhost:3000".into()),
+            app_base_url: std::env::var("APP_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".into()),
+            smtp_host: std::env::var("SMTP_HOST").unwrap_or_default(),
+            smtp_port: std::env::var("SMTP_PORT").unwrap_or_else(|_| "587".into()).parse::<u16>().unwrap_or(587),
+            smtp_username: std::env::var("SMTP_USERNAME").unwrap_or_default(),
+            smtp_password: std::env::var("SMTP_PASSWORD").unwrap_or_default(),
+            smtp_from: std::env::var("SMTP_FROM").unwrap_or_else(|_| "Velamen <noreply@velamen.app>".into()),
+            climate_raw_retention_days: std::env::var("CLIMATE_RAW_RETENTION_DAYS").unwrap_or_else(|_| "30".into()).parse::<u32>().unwrap_or(30).max(1),
+            climate_hourly_retention_days: std::env::var("CLIMATE_HOURLY_RETENTION_DAYS").unwrap_or_else(|_| "365".into()).parse::<u32>().unwrap_or(365),
+            climate_daily_retention_days: std::env::var("CLIMATE_DAILY_RETENTION_DAYS").unwrap_or_else(|_| "0".into()).parse::<u32>().unwrap_or(0),
+            image_webp_quality: std::env::var("IMAGE_WEBP_QUALITY").unwrap_or_else(|_| "80".into()).parse::<u8>().unwrap_or(80).min(100),
         }
     }
 }
@@ -68,6 +68,28 @@ pub fn decrypt_or_raw(value: &str) -> String {
     decrypt(value).unwrap_or_else(|_| value.to_string())
 }
 
+/// Generates a random URL-safe token and returns `(token, sha256_hex_of_token)`.
+///
+/// Send the token to the user and store only the hash.
+pub fn generate_token() -> (String, String) {
+    use aes_gcm::aead::rand_core::RngCore;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    let token = URL_SAFE_NO_PAD.encode(bytes);
+    let hash = hash_token(&token);
+    (token, hash)
+}
+
+/// Hex-encoded SHA-256 of a token, for lookup without storing the token itself.
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +135,14 @@ mod tests {
         let result = decrypt_or_raw("");
         assert_eq!(result, "");
     }
+
+    #[test]
+    fn generated_token_matches_its_hash() {
+        let (token, hash) = generate_token();
+        assert_eq!(token.len(), 43);
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash_token(&token), hash);
+        let (other, _) = generate_token();
+        assert_ne!(token, other);
+    }
 }
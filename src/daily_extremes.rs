@@ -0,0 +1,168 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Offset, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::ClimateReading;
+
+/// Largest UTC offset accepted from the browser, in minutes (UTC+14).
+pub const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// Min, max and mean temperature and humidity over one local day.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DayStats {
+    /// Lowest temperature, °C.
+    pub min_temperature: f64,
+    /// Highest temperature, °C.
+    pub max_temperature: f64,
+    /// Mean temperature, °C.
+    pub avg_temperature: f64,
+    /// Lowest relative humidity, %.
+    pub min_humidity: f64,
+    /// Highest relative humidity, %.
+    pub max_humidity: f64,
+    /// Mean relative humidity, %.
+    pub avg_humidity: f64,
+    /// Readings the figures are based on.
+    pub samples: usize,
+}
+
+impl DayStats {
+    /// Summarizes readings, or `None` when there are none.
+    pub fn from_readings<'a>(readings: impl IntoIterator<Item = &'a ClimateReading>) -> Option<Self> {
+        let mut stats: Option<DayStats> = None;
+        let (mut temp_sum, mut humidity_sum) = (0.0, 0.0);
+        for r in readings {
+            temp_sum += r.temperature;
+            humidity_sum += r.humidity;
+            let s = stats.get_or_insert(DayStats {
+                min_temperature: r.temperature,
+                max_temperature: r.temperature,
+                avg_temperature: 0.0,
+                min_humidity: r.humidity,
+                max_humidity: r.humidity,
+                avg_humidity: 0.0,
+                samples: 0,
+            });
+            s.min_temperature = s.min_temperature.min(r.temperature);
+            s.max_temperature = s.max_temperature.max(r.temperature);
+            s.min_humidity = s.min_humidity.min(r.humidity);
+            s.max_humidity = s.max_humidity.max(r.humidity);
+            s.samples += 1;
+        }
+        stats.map(|mut s| {
+            s.avg_temperature = temp_sum / s.samples as f64;
+            s.avg_humidity = humidity_sum / s.samples as f64;
+            s
+        })
+    }
+}
+
+/// **What is it?**
+/// One zone's temperature and humidity ranges for yesterday and today, in the grower's local time.
+///
+/// **Why does it exist?**
+/// It exists because the latest reading hides the overnight low, which is what actually damages plants in a cold window or greenhouse.
+///
+/// **How should it be used?**
+/// Fetch with `server_fns::climate::get_zone_daily_extremes` and show next to the zone's current reading. A day without readings is `None`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ZoneDailyExtremes {
+    /// The zone record ID.
+    pub zone_id: String,
+    /// The local date of "today".
+    pub today_date: NaiveDate,
+    /// Yesterday's figures, midnight to midnight local time.
+    pub yesterday: Option<DayStats>,
+    /// Today's figures so far.
+    pub today: Option<DayStats>,
+}
+
+/// Parses a browser UTC offset in minutes east of UTC, clamped to real time zones.
+pub fn local_offset(utc_offset_minutes: i32) -> FixedOffset {
+    let minutes = utc_offset_minutes.clamp(-MAX_UTC_OFFSET_MINUTES, MAX_UTC_OFFSET_MINUTES);
+    FixedOffset::east_opt(minutes * 60).unwrap_or(Utc.fix())
+}
+
+/// The UTC instant local yesterday began, for bounding the readings query.
+pub fn yesterday_start(now: DateTime<Utc>, offset: FixedOffset) -> DateTime<Utc> {
+    let today = now.with_timezone(&offset).date_naive();
+    let midnight = (today - Duration::days(1)).and_hms_opt(0, 0, 0).unwrap_or_default();
+    (midnight - Duration::seconds(offset.local_minus_utc() as i64)).and_utc()
+}
+
+/// Splits one zone's readings into local yesterday and today and summarizes each.
+pub fn zone_extremes(zone_id: &str, readings: &[ClimateReading], now: DateTime<Utc>, offset: FixedOffset) -> ZoneDailyExtremes {
+    let today = now.with_timezone(&offset).date_naive();
+    let yesterday = today - Duration::days(1);
+    let local_date = |r: &ClimateReading| r.recorded_at.with_timezone(&offset).date_naive();
+    ZoneDailyExtremes {
+        zone_id: zone_id.to_string(),
+        today_date: today,
+        yesterday: DayStats::from_readings(readings.iter().filter(|r| local_date(r) == yesterday)),
+        today: DayStats::from_readings(readings.iter().filter(|r| local_date(r) == today)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn reading(at: DateTime<Utc>, temperature: f64, humidity: f64) -> ClimateReading {
+        ClimateReading {
+            id: String::new(),
+            zone_id: "growing_zone:tent".into(),
+            zone_name: "Tent".into(),
+            temperature,
+            humidity,
+            vpd: None,
+            dew_point: None,
+            precipitation: None,
+            uv_index: None,
+            solar_radiation: None,
+            wind_speed: None,
+            wind_gust: None,
+            lux: None,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
+            source: Some("sensorpush".into()),
+            recorded_at: at,
+        }
+    }
+
+    #[test]
+    fn test_day_stats() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 10, 12, 0, 0).unwrap();
+        let readings = [reading(now, 20.0, 60.0), reading(now, 14.0, 80.0), reading(now, 23.0, 55.0)];
+        let stats = DayStats::from_readings(&readings).unwrap();
+        assert_eq!((stats.min_temperature, stats.max_temperature), (14.0, 23.0));
+        assert_eq!((stats.min_humidity, stats.max_humidity), (55.0, 80.0));
+        assert!((stats.avg_temperature - 19.0).abs() < 1e-9);
+        assert_eq!(stats.samples, 3);
+        assert!(DayStats::from_readings(&[]).is_none());
+    }
+
+    #[test]
+    fn test_days_split_on_local_midnight() {
+        // 10:00 local in UTC-5; local midnight is 05:00 UTC.
+        let now = Utc.with_ymd_and_hms(2026, 1, 10, 15, 0, 0).unwrap();
+        let offset = local_offset(-300);
+        assert_eq!(yesterday_start(now, offset), Utc.with_ymd_and_hms(2026, 1, 9, 5, 0, 0).unwrap());
+
+        let readings = [
+            reading(Utc.with_ymd_and_hms(2026, 1, 9, 4, 0, 0).unwrap(), 30.0, 50.0),   // Jan 8 local
+            reading(Utc.with_ymd_and_hms(2026, 1, 10, 3, 0, 0).unwrap(), 9.5, 90.0),   // Jan 9 local, overnight low
+            reading(Utc.with_ymd_and_hms(2026, 1, 9, 20, 0, 0).unwrap(), 21.0, 60.0),  // Jan 9 local
+            reading(Utc.with_ymd_and_hms(2026, 1, 10, 6, 0, 0).unwrap(), 12.0, 85.0),  // Jan 10 local
+        ];
+        let extremes = zone_extremes("growing_zone:tent", &readings, now, offset);
+        assert_eq!(extremes.today_date, NaiveDate::from_ymd_opt(2026, 1, 10).unwrap());
+        let yesterday = extremes.yesterday.unwrap();
+        assert_eq!((yesterday.min_temperature, yesterday.max_temperature, yesterday.samples), (9.5, 21.0, 2));
+        assert_eq!(extremes.today.unwrap().samples, 1);
+    }
+
+    #[test]
+    fn test_offset_is_clamped() {
+        assert_eq!(local_offset(10_000).local_minus_utc(), MAX_UTC_OFFSET_MINUTES * 60);
+    }
+}
@@ -1,26 +1,75 @@
 use crate::config::AppConfig;
 use crate::error::AppError;
+use crate::migration::{AppliedMigration, MigrationFile};
 use surrealdb::engine::remote::ws::{Client, Ws};
 use surrealdb::opt::auth::Root;
 use surrealdb::types::SurrealValue;
 use surrealdb::Surreal;
-use std::sync::LazyLock;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError, RwLock};
+use std::time::Duration;
 
-static DB: LazyLock<Surreal<Client>> = LazyLock::new(Surreal::init);
+/// The current connection. `monitor` swaps in a new one when the old one stops answering, since
+/// a `Surreal` handle can only be connected once.
+static DB: LazyLock<RwLock<Arc<Surreal<Client>>>> = LazyLock::new(|| RwLock::new(Arc::new(Surreal::init())));
+
+/// Connection attempts at startup before giving up, so the server can start alongside SurrealDB
+/// instead of losing the race and exiting.
+const STARTUP_CONNECT_ATTEMPTS: u32 = 6;
+/// How often `monitor` checks the connection.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+/// How long a health check may take before the connection counts as down.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+/// The longest wait between reconnect attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+/// The migration that adds `version` and `checksum` to the `migration` table. Rows for earlier
+/// migrations are recorded by name alone and get both once it has been applied.
+const CHECKSUM_FIELDS_VERSION: u32 = 69;
 
 /// What is it? An asynchronous initialization routine for the application's SurrealDB connection.
 /// Why does it exist? It manages the early-boot setup sequence, including resolving connection details, authenticating the root user, selecting the namespace/db, and automatically applying schema migrations before traffic is accepted.
-/// How should it be used? Call this exactly once during the server startup phase (e.g., in `main.rs`) before binding the Axum router. If it fails, the application should panic and exit.
+/// How should it be used? Call this exactly once during the server startup phase (e.g., in `main.rs`) before binding the Axum router. It retries the connection with backoff for about a minute; if it still fails, the application should panic and exit.
 pub async fn init_db(config: &AppConfig) -> Result<(), AppError> {
+    let mut attempt = 0;
+    while let Err(e) = connect(config).await {
+        attempt += 1;
+        if attempt >= STARTUP_CONNECT_ATTEMPTS {
+            return Err(e);
+        }
+        let delay = reconnect_delay(attempt - 1);
+        tracing::warn!("{}; retrying in {}s", e, delay.as_secs());
+        tokio::time::sleep(delay).await;
+    }
+
+    // Run migrations
+    run_migrations().await?;
+    record_check(true, false);
+
+    Ok(())
+}
+
+/// Connects, signs in and selects the namespace/db without touching the schema, then makes the
+/// new connection the one `db()` returns. `init_db` runs migrations afterwards; `Command::Doctor`
+/// connects this way so it can report pending migrations instead of applying them.
+pub async fn connect(config: &AppConfig) -> Result<(), AppError> {
+    let handle = open(config).await?;
+    // Anything still holding the old handle finishes with it; it closes when the last one is dropped
+    *DB.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(handle);
+    Ok(())
+}
+
+/// A new, signed-in connection with the namespace/db selected.
+async fn open(config: &AppConfig) -> Result<Surreal<Client>, AppError> {
     tracing::info!("Connecting to SurrealDB at {}", config.surreal_url);
 
-    DB.connect::<Ws>(&config.surreal_url)
+    let handle = Surreal::<Client>::init();
+    handle.connect::<Ws>(&config.surreal_url)
         .await
         .map_err(|e| AppError::Database(format!("Connection failed: {}", e)))?;
 
     tracing::info!("WebSocket connected, signing in...");
 
-    DB.signin(Root {
+    handle.signin(Root {
         username: config.surreal_user.clone(),
         password: config.surreal_pass.clone(),
     })
@@ -29,95 +78,301 @@ pub async fn init_db(config: &AppConfig) -> Result<(), AppError> {
 
     tracing::info!("Signed in, selecting namespace/db: {}/{}", config.surreal_ns, config.surreal_db);
 
-    DB.use_ns(&config.surreal_ns)
+    handle.use_ns(&config.surreal_ns)
         .use_db(&config.surreal_db)
         .await
         .map_err(|e| AppError::Database(format!("Namespace/DB selection failed: {}", e)))?;
 
     tracing::info!("DB connected and configured");
 
-    // Run migrations
-    run_migrations().await?;
-
-    Ok(())
+    Ok(handle)
 }
 
-/// What is it? An accessor function for the global, lazily-initialized SurrealDB client.
-/// Why does it exist? It provides a thread-safe, static reference to the database connection pool, eliminating the need to pass connection clones manually through every function layer or framework context.
-/// How should it be used? Call `crate::db::db()` inside server functions or background tasks to obtain the client, then chain `.query()` or `.create()` methods to interact with SurrealDB.
-pub fn db() -> &'static Surreal<Client> {
-    &DB
+/// What is it? An accessor function for the global SurrealDB client.
+/// Why does it exist? It provides a thread-safe, shared handle to the database connection, eliminating the need to pass connection clones manually through every function layer or framework context.
+/// How should it be used? Call `crate::db::db()` inside server functions or background tasks to obtain the client, then chain `.query()` or `.create()` methods to interact with SurrealDB. Call it again for each unit of work rather than keeping the handle, so work started after a reconnect uses the new connection.
+pub fn db() -> Arc<Surreal<Client>> {
+    DB.read().unwrap_or_else(PoisonError::into_inner).clone()
 }
 
-/// What is it? An asynchronous utility that scans and executes `.surql` schema and data definition files.
-/// Why does it exist? It ensures the SurrealDB schema (tables, fields, events, and indexes) stays synchronized with the codebase structure and prevents older schema versions from causing runtime errors.
-/// How should it be used? It is called automatically by `init_db()` during startup. It reads files from the local `migrations/` directory, checks a `migration` tracking table to skip previously applied files, and runs new files sequentially.
-pub async fn run_migrations() -> Result<(), AppError> {
-    let db = db();
+/// The outcome of the latest connection checks, reported by `/api/health`.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct DbHealth {
+    /// Whether the last check got an answer.
+    pub connected: bool,
+    /// When the last check ran; `None` until `init_db` has connected.
+    pub checked_at: Option<DateTime<Utc>>,
+    /// When a check last got an answer.
+    pub last_ok_at: Option<DateTime<Utc>>,
+    /// Checks and reconnect attempts that have failed in a row.
+    pub consecutive_failures: u32,
+    /// Times the connection has been replaced since startup.
+    pub reconnects: u32,
+}
 
-    // Read migration files
-    let mut entries: Vec<_> = std::fs::read_dir("migrations")
-        .map_err(|e| AppError::Database(format!("Can't read migrations dir: {}", e)))?
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| {
-            entry.path().extension().is_some_and(|ext| ext == "surql")
-        })
-        .collect();
+static HEALTH: LazyLock<Mutex<DbHealth>> = LazyLock::new(|| Mutex::new(DbHealth::default()));
 
-    entries.sort_by_key(|e| e.file_name());
-    tracing::info!("Found {} migration files", entries.len());
+/// What is it? The database's health as of the latest check by `monitor`.
+/// Why does it exist? So the health endpoint can report on the database without querying it on every request.
+/// How should it be used? Call from health and status endpoints; `connected` is false until `init_db` succeeds.
+pub fn health() -> DbHealth {
+    HEALTH.lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
 
-    for entry in entries {
-        let name = entry.file_name().to_string_lossy().to_string();
+fn record_check(ok: bool, reconnected: bool) {
+    let mut health = HEALTH.lock().unwrap_or_else(PoisonError::into_inner);
+    let now = Utc::now();
+    health.connected = ok;
+    health.checked_at = Some(now);
+    if ok {
+        health.last_ok_at = Some(now);
+        health.consecutive_failures = 0;
+    } else {
+        health.consecutive_failures += 1;
+    }
+    if reconnected {
+        health.reconnects += 1;
+    }
+}
 
-        // Check if already applied — use .check() to surface real SurrealDB errors
-        // instead of the misleading "Connection uninitialised" from .take()
-        let mut response = db
-            .query("SELECT * FROM migration WHERE name = $name LIMIT 1")
-            .bind(("name", name.clone()))
+/// Whether the database answers a query that needs the session's sign-in and namespace/db, within
+/// `HEALTH_CHECK_TIMEOUT`.
+pub async fn ping() -> Result<(), AppError> {
+    let handle = db();
+    let check = async {
+        let mut response = handle.query("SELECT count() FROM migration GROUP ALL")
             .await
-            .map_err(|e| AppError::Database(format!("Migration query failed: {}", e)))?;
-
-        // Extract any query-level errors (SurrealDB returns errors per-statement)
+            .map_err(|e| AppError::Database(format!("Health check failed: {}", e)))?;
         let errors = response.take_errors();
-        if !errors.is_empty() {
-            tracing::warn!("Migration check query returned errors: {:?}", errors);
-            // On first run, the migration table doesn't exist yet — treat errors as "not applied"
-            tracing::info!("Treating migration {} as not yet applied (table may not exist)", name);
+        if errors.is_empty() {
+            Ok(())
         } else {
-            let applied: Option<MigrationRecord> = response.take(0)
-                .map_err(|e| AppError::Database(format!("Migration deserialize failed: {}", e)))?;
+            Err(AppError::Database(format!(
+                "Health check failed: {}",
+                errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ")
+            )))
+        }
+    };
+    tokio::time::timeout(HEALTH_CHECK_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| Err(AppError::Database("Health check timed out".into())))
+}
+
+/// What is it? A background loop that checks the database connection and replaces it when it stops answering.
+/// Why does it exist? Without it, a SurrealDB restart or network drop could leave the connection dead, and every poller and request would keep failing until the server was restarted.
+/// How should it be used? Spawn once at startup after `init_db`. It checks every 15 seconds; after a failed check it reconnects with exponential backoff (1s, 2s, 4s, ... up to a minute) until the new connection answers.
+pub async fn monitor(config: &AppConfig) {
+    loop {
+        tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        match ping().await {
+            Ok(()) => record_check(true, false),
+            Err(e) => {
+                record_check(false, false);
+                tracing::warn!("SurrealDB health check failed, reconnecting: {}", e);
+                reconnect(config).await;
+            }
+        }
+    }
+}
 
-            if applied.is_some() {
-                tracing::info!("Migration {} already applied, skipping", name);
-                continue;
+/// Replaces the connection until the new one passes a health check, backing off between attempts.
+async fn reconnect(config: &AppConfig) {
+    let mut attempt = 0;
+    loop {
+        tokio::time::sleep(reconnect_delay(attempt)).await;
+        attempt += 1;
+        let result = match connect(config).await {
+            Ok(()) => ping().await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => {
+                record_check(true, true);
+                tracing::info!("SurrealDB reconnected after {} attempt(s)", attempt);
+                return;
+            }
+            Err(e) => {
+                record_check(false, false);
+                tracing::warn!("SurrealDB reconnect attempt {} failed: {}", attempt, e);
             }
         }
+    }
+}
 
-        // Read and execute
-        let sql = std::fs::read_to_string(entry.path())
-            .map_err(|e| AppError::Database(format!("Can't read migration {}: {}", name, e)))?;
+/// The wait before reconnect attempt `attempt` (counting from 0): one second, doubling each
+/// time, at most `MAX_RECONNECT_DELAY`.
+fn reconnect_delay(attempt: u32) -> Duration {
+    Duration::from_secs(1u64 << attempt.min(16)).min(MAX_RECONNECT_DELAY)
+}
 
-        tracing::info!("Applying migration: {}", name);
-        db.query(&sql)
-            .await
-            .map_err(|e| AppError::Database(format!("Migration {} failed: {}", name, e)))?;
+/// What is it? An asynchronous utility that brings the schema up to date from the numbered `.surql` files in `migrations/`.
+/// Why does it exist? It ensures the SurrealDB schema (tables, fields, events, and indexes) stays synchronized with the codebase structure and prevents older schema versions from causing runtime errors.
+/// How should it be used? It is called automatically by `init_db()` during startup, and by `Command::Migrate`. It records each applied file's version and checksum in the `migration` table, applies new files in version order, and fails if an applied file has been edited since.
+pub async fn run_migrations() -> Result<(), AppError> {
+    let (files, applied) = migration_status().await?;
+    tracing::info!("Found {} migration files, schema at version {}", files.len(), crate::migration::schema_version(&files, &applied));
 
-        // Record it
-        db.query("CREATE migration SET name = $name")
-            .bind(("name", name.clone()))
+    let plan = crate::migration::plan(&files, &applied);
+    if !plan.changed.is_empty() {
+        return Err(AppError::Database(format!(
+            "Migration {} changed after it was applied; restore the original and put the change in a new migration",
+            plan.changed.join(", ")
+        )));
+    }
+
+    for file in &plan.pending {
+        apply_migration(file).await?;
+    }
+
+    // Migrations applied before checksums were recorded, or just now but before the migration
+    // adding the fields, are trusted as they are on disk now
+    let unrecorded = plan.unrecorded.iter()
+        .chain(plan.pending.iter().filter(|file| file.version < CHECKSUM_FIELDS_VERSION));
+    for file in unrecorded {
+        let mut response = db().query("UPDATE migration SET version = $version, checksum = $checksum WHERE name = $name")
+            .bind(("name", file.name.clone()))
+            .bind(("version", file.version as i64))
+            .bind(("checksum", file.checksum.clone()))
             .await
-            .map_err(|e| AppError::Database(format!("Failed to record migration {}: {}", name, e)))?;
+            .map_err(|e| AppError::Database(format!("Failed to record checksum of {}: {}", file.name, e)))?;
+        let errors = response.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(AppError::Database(format!("Failed to record checksum of {}: {}", file.name, err_msg)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a migration's up script and records it, with its version and checksum once the
+/// `migration` table has fields for them.
+async fn apply_migration(file: &MigrationFile) -> Result<(), AppError> {
+    let sql = read_migration(&file.name)?;
 
-        tracing::info!("Migration {} applied successfully", name);
+    tracing::info!("Applying migration: {}", file.name);
+    let mut response = db().query(&sql)
+        .await
+        .map_err(|e| AppError::Database(format!("Migration {} failed: {}", file.name, e)))?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(AppError::Database(format!("Migration {} failed: {}", file.name, err_msg)));
+    }
+
+    let record = if file.version < CHECKSUM_FIELDS_VERSION {
+        "CREATE migration SET name = $name, applied_at = time::now()"
+    } else {
+        "CREATE migration SET name = $name, version = $version, checksum = $checksum, applied_at = time::now()"
+    };
+    let mut response = db().query(record)
+        .bind(("name", file.name.clone()))
+        .bind(("version", file.version as i64))
+        .bind(("checksum", file.checksum.clone()))
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record migration {}: {}", file.name, e)))?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(AppError::Database(format!("Failed to record migration {}: {}", file.name, err_msg)));
     }
 
+    tracing::info!("Migration {} applied successfully", file.name);
     Ok(())
 }
 
+/// Runs a migration's down script and forgets it was applied, so the next `run_migrations`
+/// applies it again. Errors if the migration has no down script.
+pub async fn revert_migration(file: &MigrationFile) -> Result<(), AppError> {
+    let down = file.down.as_ref()
+        .ok_or_else(|| AppError::Database(format!("Migration {} has no down script", file.name)))?;
+    let sql = read_migration(down)?;
+
+    tracing::info!("Reverting migration: {}", file.name);
+    let mut response = db().query(&sql)
+        .await
+        .map_err(|e| AppError::Database(format!("Down migration {} failed: {}", down, e)))?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(AppError::Database(format!("Down migration {} failed: {}", down, err_msg)));
+    }
+
+    let mut response = db().query("DELETE migration WHERE name = $name")
+        .bind(("name", file.name.clone()))
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to forget migration {}: {}", file.name, e)))?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(AppError::Database(format!("Failed to forget migration {}: {}", file.name, err_msg)));
+    }
+
+    tracing::info!("Migration {} reverted", file.name);
+    Ok(())
+}
+
+/// A file in `migrations/`.
+fn read_migration(name: &str) -> Result<String, AppError> {
+    std::fs::read_to_string(std::path::Path::new("migrations").join(name))
+        .map_err(|e| AppError::Database(format!("Can't read migration {}: {}", name, e)))
+}
+
+/// The migrations in `migrations/`, oldest first, with up and down scripts paired.
+fn migration_files() -> Result<Vec<MigrationFile>, AppError> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir("migrations")
+        .map_err(|e| AppError::Database(format!("Can't read migrations dir: {}", e)))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "surql"))
+    {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let sql = read_migration(&name)?;
+        files.push((name, crate::crypto::hash_token(&sql)));
+    }
+
+    crate::migration::collect(&files).map_err(AppError::Database)
+}
+
+/// The migration files on disk and the `migration` table's rows, for planning with
+/// `crate::migration`.
+pub async fn migration_status() -> Result<(Vec<MigrationFile>, Vec<AppliedMigration>), AppError> {
+    let files = migration_files()?;
+
+    let mut response = db()
+        .query("SELECT name, checksum FROM migration")
+        .await
+        .map_err(|e| AppError::Database(format!("Migration query failed: {}", e)))?;
+    // A database that has never been migrated has no migration table: nothing is applied
+    let applied: Vec<MigrationRecord> = if response.take_errors().is_empty() {
+        response.take(0)
+            .map_err(|e| AppError::Database(format!("Migration deserialize failed: {}", e)))?
+    } else {
+        tracing::info!("No migration table yet; treating every migration as not applied");
+        Vec::new()
+    };
+
+    let applied = applied.into_iter()
+        .map(|row| AppliedMigration { name: row.name, checksum: row.checksum })
+        .collect();
+    Ok((files, applied))
+}
+
 #[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
 #[surreal(crate = "surrealdb::types")]
 struct MigrationRecord {
-    #[allow(dead_code)]
     name: String,
+    checksum: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_delay_backs_off_to_a_cap() {
+        let delays: Vec<u64> = (0..8).map(|a| reconnect_delay(a).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60]);
+        assert_eq!(reconnect_delay(u32::MAX), MAX_RECONNECT_DELAY);
+    }
 }
@@ -0,0 +1,273 @@
+use chrono::{DateTime, Timelike, Utc};
+use crate::orchid::{LightRequirement, LocationType};
+
+/// Longest history `Command::Seed` will generate, in days.
+pub const MAX_SEED_DAYS: u32 = 365;
+/// Minutes between synthetic climate readings.
+pub const READING_INTERVAL_MINUTES: i64 = 30;
+
+/// A sample growing zone and the climate its synthetic sensor reports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoZone {
+    /// Zone name, also used as the plants' placement.
+    pub name: &'static str,
+    /// Light level.
+    pub light_level: LightRequirement,
+    /// Indoors or out.
+    pub location_type: LocationType,
+    /// Free-text temperature range shown on the zone card.
+    pub temperature_range: &'static str,
+    /// Free-text humidity shown on the zone card.
+    pub humidity: &'static str,
+    /// Short description.
+    pub description: &'static str,
+    /// Mid-afternoon high and pre-dawn low, in °C.
+    pub temp_c: (f64, f64),
+    /// Humidity at the coolest and warmest point of the day, in percent.
+    pub humidity_pct: (f64, f64),
+}
+
+/// A sample plant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoOrchid {
+    /// Display name.
+    pub name: &'static str,
+    /// Species or hybrid name.
+    pub species: &'static str,
+    /// Index into `DEMO_ZONES`.
+    pub zone: usize,
+    /// Days between waterings.
+    pub water_frequency_days: u32,
+    /// Days between feedings, if fed.
+    pub fertilize_frequency_days: Option<u32>,
+    /// Light requirement.
+    pub light_requirement: LightRequirement,
+    /// Comfortable temperature and humidity ranges: (°C min, °C max, % min, % max).
+    pub climate: (f64, f64, f64, f64),
+    /// Days since the last watering when seeded; more than `water_frequency_days` makes it overdue.
+    pub last_watered_days_ago: u32,
+    /// Notes on the plant card.
+    pub notes: &'static str,
+}
+
+/// The sample zones: a windowsill, a grow tent and a patio, so indoor, tent and outdoor views all have data.
+pub const DEMO_ZONES: [DemoZone; 3] = [
+    DemoZone {
+        name: "Living Room Window",
+        light_level: LightRequirement::Medium,
+        location_type: LocationType::Indoor,
+        temperature_range: "18-26°C",
+        humidity: "45-60%",
+        description: "East-facing window with sheer curtain",
+        temp_c: (25.0, 19.0),
+        humidity_pct: (62.0, 46.0),
+    },
+    DemoZone {
+        name: "Grow Tent",
+        light_level: LightRequirement::High,
+        location_type: LocationType::Indoor,
+        temperature_range: "20-28°C",
+        humidity: "60-75%",
+        description: "2x2 ft tent with LED bar and humidifier",
+        temp_c: (27.5, 21.0),
+        humidity_pct: (76.0, 62.0),
+    },
+    DemoZone {
+        name: "Patio",
+        light_level: LightRequirement::High,
+        location_type: LocationType::Outdoor,
+        temperature_range: "12-30°C",
+        humidity: "40-85%",
+        description: "Shade cloth over the south rack",
+        temp_c: (29.0, 13.0),
+        humidity_pct: (86.0, 42.0),
+    },
+];
+
+/// The sample plants. Two are overdue for water so the Today view and alerts have something to show.
+pub const DEMO_ORCHIDS: [DemoOrchid; 8] = [
+    DemoOrchid {
+        name: "Kingie",
+        species: "Dendrobium kingianum",
+        zone: 2,
+        water_frequency_days: 5,
+        fertilize_frequency_days: Some(14),
+        light_requirement: LightRequirement::High,
+        climate: (5.0, 30.0, 40.0, 80.0),
+        last_watered_days_ago: 9,
+        notes: "Cool winter rest brings on the spikes.",
+    },
+    DemoOrchid {
+        name: "Moth Orchid",
+        species: "Phalaenopsis amabilis",
+        zone: 0,
+        water_frequency_days: 7,
+        fertilize_frequency_days: Some(14),
+        light_requirement: LightRequirement::Low,
+        climate: (18.0, 29.0, 50.0, 80.0),
+        last_watered_days_ago: 2,
+        notes: "Reblooming from last year's spike.",
+    },
+    DemoOrchid {
+        name: "Sweetheart",
+        species: "Phalaenopsis schilleriana",
+        zone: 0,
+        water_frequency_days: 7,
+        fertilize_frequency_days: None,
+        light_requirement: LightRequirement::Low,
+        climate: (18.0, 29.0, 50.0, 80.0),
+        last_watered_days_ago: 4,
+        notes: "Mottled leaves; keep out of afternoon sun.",
+    },
+    DemoOrchid {
+        name: "Lady Slipper",
+        species: "Paphiopedilum insigne",
+        zone: 0,
+        water_frequency_days: 4,
+        fertilize_frequency_days: Some(21),
+        light_requirement: LightRequirement::Low,
+        climate: (12.0, 26.0, 50.0, 75.0),
+        last_watered_days_ago: 1,
+        notes: "Never let it dry out completely.",
+    },
+    DemoOrchid {
+        name: "Chocolate Drop",
+        species: "Oncidium Sharry Baby",
+        zone: 1,
+        water_frequency_days: 5,
+        fertilize_frequency_days: Some(14),
+        light_requirement: LightRequirement::Medium,
+        climate: (15.0, 30.0, 50.0, 75.0),
+        last_watered_days_ago: 3,
+        notes: "Smells of chocolate in bloom.",
+    },
+    DemoOrchid {
+        name: "Cattleya",
+        species: "Cattleya walkeriana",
+        zone: 1,
+        water_frequency_days: 6,
+        fertilize_frequency_days: Some(14),
+        light_requirement: LightRequirement::High,
+        climate: (15.0, 32.0, 50.0, 80.0),
+        last_watered_days_ago: 10,
+        notes: "Dry out between waterings.",
+    },
+    DemoOrchid {
+        name: "Mini Vanda",
+        species: "Vanda falcata",
+        zone: 2,
+        water_frequency_days: 2,
+        fertilize_frequency_days: Some(7),
+        light_requirement: LightRequirement::High,
+        climate: (10.0, 32.0, 50.0, 85.0),
+        last_watered_days_ago: 1,
+        notes: "Mounted on cork; fragrant at night.",
+    },
+    DemoOrchid {
+        name: "Tolumnia",
+        species: "Tolumnia Jairak Firm",
+        zone: 1,
+        water_frequency_days: 3,
+        fertilize_frequency_days: None,
+        light_requirement: LightRequirement::Medium,
+        climate: (16.0, 30.0, 50.0, 80.0),
+        last_watered_days_ago: 2,
+        notes: "Twig orchid, dries within a day.",
+    },
+];
+
+/// A journal entry to seed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoEntry {
+    /// Index into `DEMO_ORCHIDS`.
+    pub orchid: usize,
+    /// How many days before now it was logged.
+    pub days_ago: u32,
+    /// Built-in event type.
+    pub event_type: &'static str,
+    /// The note.
+    pub note: &'static str,
+}
+
+/// Journal history for the last `days` days: each plant's waterings and feedings on its schedule up
+/// to its last watering, plus a repotting and two blooms, oldest first.
+pub fn demo_journal(days: u32) -> Vec<DemoEntry> {
+    let mut entries = Vec::new();
+    for (i, orchid) in DEMO_ORCHIDS.iter().enumerate() {
+        let mut day = orchid.last_watered_days_ago;
+        let mut count = 0;
+        while day < days {
+            entries.push(DemoEntry { orchid: i, days_ago: day, event_type: "Watered", note: "Watered" });
+            let feed_every = orchid.fertilize_frequency_days.map(|f| (f / orchid.water_frequency_days).max(1));
+            if feed_every.is_some_and(|every| count % every == 0) {
+                entries.push(DemoEntry { orchid: i, days_ago: day, event_type: "Fertilized", note: "Quarter-strength feed with the watering" });
+            }
+            day += orchid.water_frequency_days;
+            count += 1;
+        }
+    }
+    let notable = [
+        DemoEntry { orchid: 5, days_ago: days * 4 / 5, event_type: "Repotted", note: "Up-potted into coarse bark after the new growth rooted" },
+        DemoEntry { orchid: 1, days_ago: days / 3, event_type: "Flowering", note: "First flower open on the old spike" },
+        DemoEntry { orchid: 4, days_ago: days / 6, event_type: "Flowering", note: "Spike full of buds" },
+    ];
+    entries.extend(notable.into_iter().filter(|e| e.days_ago < days));
+    entries.sort_by(|a, b| b.days_ago.cmp(&a.days_ago).then(a.orchid.cmp(&b.orchid)));
+    entries
+}
+
+/// The synthetic reading for `zone` at `at`: a daily curve that peaks mid-afternoon (UTC) with
+/// humidity moving the other way, plus a little repeatable jitter so charts don't look drawn.
+/// Returns (°C, % relative humidity), rounded to one decimal.
+pub fn demo_reading(zone: &DemoZone, at: DateTime<Utc>) -> (f64, f64) {
+    let hours = at.hour() as f64 + at.minute() as f64 / 60.0;
+    // 1.0 at 14:00, -1.0 at 02:00
+    let warmth = ((hours - 14.0) / 24.0 * std::f64::consts::TAU).cos();
+    let (high, low) = zone.temp_c;
+    let (humid, dry) = zone.humidity_pct;
+    let jitter = jitter(at.timestamp() / 60 + zone.name.len() as i64);
+    let temperature = (high + low) / 2.0 + (high - low) / 2.0 * warmth + jitter * 0.6;
+    let humidity = (humid + dry) / 2.0 - (humid - dry) / 2.0 * warmth - jitter * 2.0;
+    ((temperature * 10.0).round() / 10.0, (humidity.clamp(5.0, 99.0) * 10.0).round() / 10.0)
+}
+
+/// A repeatable value in [-1, 1) for `seed`.
+fn jitter(seed: i64) -> f64 {
+    let mut x = seed as u64 ^ 0x9E37_79B9_7F4A_7C15;
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    x ^= x >> 33;
+    (x % 2000) as f64 / 1000.0 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_journal_follows_each_schedule() {
+        let journal = demo_journal(30);
+        assert!(journal.windows(2).all(|w| w[0].days_ago >= w[1].days_ago));
+        assert!(journal.iter().all(|e| e.days_ago < 30 && e.orchid < DEMO_ORCHIDS.len()));
+
+        // The newest watering is the one the plant card shows, so overdue plants stay overdue
+        for (i, orchid) in DEMO_ORCHIDS.iter().enumerate() {
+            let newest = journal.iter().filter(|e| e.orchid == i && e.event_type == "Watered").map(|e| e.days_ago).min();
+            assert_eq!(newest, Some(orchid.last_watered_days_ago));
+        }
+        assert!(DEMO_ORCHIDS.iter().any(|o| o.last_watered_days_ago > o.water_frequency_days));
+        assert!(demo_journal(1).iter().all(|e| e.days_ago == 0));
+    }
+
+    #[test]
+    fn test_reading_follows_the_day() {
+        let zone = &DEMO_ZONES[0];
+        let afternoon = demo_reading(zone, Utc.with_ymd_and_hms(2026, 6, 1, 14, 0, 0).unwrap());
+        let night = demo_reading(zone, Utc.with_ymd_and_hms(2026, 6, 1, 2, 0, 0).unwrap());
+        assert!(afternoon.0 > night.0 + 3.0);
+        assert!(afternoon.1 < night.1);
+        assert!((zone.temp_c.1 - 1.0..=zone.temp_c.0 + 1.0).contains(&night.0));
+        assert_eq!(afternoon, demo_reading(zone, Utc.with_ymd_and_hms(2026, 6, 1, 14, 0, 0).unwrap()));
+    }
+}
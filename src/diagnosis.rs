@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use crate::treatment::{ProblemKind, MAX_DOSE_LEN, MAX_PROBLEM_LEN, MAX_PRODUCT_LEN, MAX_TREATMENT_APPLICATIONS, MAX_TREATMENT_INTERVAL_DAYS};
+
+/// Most likely causes kept from one diagnosis.
+pub const MAX_DIAGNOSIS_CAUSES: usize = 5;
+/// Most treatments kept per cause.
+pub const MAX_CAUSE_TREATMENTS: usize = 3;
+/// Re-treatment gap used when the model doesn't suggest one, in days.
+pub const DEFAULT_TREATMENT_INTERVAL_DAYS: u32 = 7;
+/// Application count used when the model doesn't suggest one.
+pub const DEFAULT_TREATMENT_APPLICATIONS: u32 = 3;
+
+/// What kind of problem a likely cause is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CauseKind {
+    /// An insect or mite, such as scale or spider mites.
+    Pest,
+    /// A rot, fungus, bacterium or virus.
+    Disease,
+    /// Growing conditions, such as sunburn, cold damage or overwatering. Anything unrecognised lands here.
+    #[serde(other)]
+    Environmental,
+}
+
+impl CauseKind {
+    /// The treatment kind a cause is tracked as; environmental problems aren't sprayed.
+    pub fn problem_kind(&self) -> Option<ProblemKind> {
+        match self {
+            CauseKind::Pest => Some(ProblemKind::Pest),
+            CauseKind::Disease => Some(ProblemKind::Disease),
+            CauseKind::Environmental => None,
+        }
+    }
+
+    /// Icon for the result list.
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            CauseKind::Pest => "\u{1F41B}",
+            CauseKind::Disease => "\u{1F344}",
+            CauseKind::Environmental => "\u{2600}\u{FE0F}",
+        }
+    }
+}
+
+/// A remedy suggested for one cause.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SuggestedTreatment {
+    /// What to apply or change, such as "Horticultural oil" or "Move to bright shade".
+    pub product: String,
+    /// How much, such as "10 ml/L".
+    #[serde(default)]
+    pub dose: Option<String>,
+    /// Days between applications, for products that need repeating.
+    #[serde(default)]
+    pub interval_days: Option<u32>,
+    /// How many applications in all.
+    #[serde(default)]
+    pub applications: Option<u32>,
+    /// One or two sentences on how to do it.
+    #[serde(default)]
+    pub instructions: String,
+}
+
+impl SuggestedTreatment {
+    /// The re-treatment series to start: days between applications and how many, within the treatment limits.
+    pub fn series(&self) -> (u32, u32) {
+        (
+            self.interval_days.unwrap_or(DEFAULT_TREATMENT_INTERVAL_DAYS).clamp(1, MAX_TREATMENT_INTERVAL_DAYS),
+            self.applications.unwrap_or(DEFAULT_TREATMENT_APPLICATIONS).clamp(1, MAX_TREATMENT_APPLICATIONS),
+        )
+    }
+}
+
+/// One possible explanation for the damage in the photo.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LikelyCause {
+    /// The problem, such as "Scale" or "Sunburn".
+    pub name: String,
+    /// Pest, disease or growing conditions.
+    pub kind: CauseKind,
+    /// How likely this cause is, from 0 to 1.
+    pub confidence: f64,
+    /// What in the photo points to it.
+    #[serde(default)]
+    pub signs: String,
+    /// What to do about it, best first.
+    #[serde(default)]
+    pub treatments: Vec<SuggestedTreatment>,
+}
+
+impl LikelyCause {
+    /// Confidence as a whole percentage, e.g. "72%".
+    pub fn confidence_label(&self) -> String {
+        format!("{:.0}%", self.confidence * 100.0)
+    }
+}
+
+/// The result of diagnosing a photo of a struggling plant.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Diagnosis {
+    /// A sentence or two on what the photo shows.
+    #[serde(default)]
+    pub summary: String,
+    /// Possible causes, most likely first.
+    #[serde(default)]
+    pub causes: Vec<LikelyCause>,
+}
+
+/// Cuts `s` to at most `max` bytes on a character boundary.
+fn truncate(s: &str, max: usize) -> String {
+    let mut end = s.len().min(max);
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    s[..end].trim().to_string()
+}
+
+impl Diagnosis {
+    /// Tidies a model's answer: confidences as 0-1 (percentages are rescaled), most likely first,
+    /// nameless causes and products dropped, and names cut to what a treatment can store.
+    pub fn normalize(mut self) -> Self {
+        self.causes.retain(|c| !c.name.trim().is_empty());
+        for cause in &mut self.causes {
+            cause.name = truncate(&cause.name, MAX_PROBLEM_LEN);
+            if cause.confidence > 1.0 {
+                cause.confidence /= 100.0;
+            }
+            cause.confidence = if cause.confidence.is_finite() { cause.confidence.clamp(0.0, 1.0) } else { 0.0 };
+            cause.treatments.retain(|t| !t.product.trim().is_empty());
+            cause.treatments.truncate(MAX_CAUSE_TREATMENTS);
+            for t in &mut cause.treatments {
+                t.product = truncate(&t.product, MAX_PRODUCT_LEN);
+                t.dose = t.dose.as_deref().map(|d| truncate(d, MAX_DOSE_LEN)).filter(|d| !d.is_empty());
+            }
+        }
+        self.causes.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        self.causes.truncate(MAX_DIAGNOSIS_CAUSES);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cause(name: &str, confidence: f64) -> LikelyCause {
+        LikelyCause { name: name.into(), kind: CauseKind::Pest, confidence, signs: String::new(), treatments: Vec::new() }
+    }
+
+    #[test]
+    fn test_normalize_orders_and_rescales() {
+        let diagnosis = Diagnosis {
+            summary: String::new(),
+            causes: vec![cause("Mites", 0.3), cause(" ", 0.9), cause("Scale", 80.0), cause("Rot", f64::NAN)],
+        }.normalize();
+        let names: Vec<_> = diagnosis.causes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Scale", "Mites", "Rot"]);
+        assert_eq!(diagnosis.causes[0].confidence_label(), "80%");
+        assert_eq!(diagnosis.causes[2].confidence, 0.0);
+    }
+
+    #[test]
+    fn test_unknown_kind_is_environmental() {
+        let parsed: LikelyCause = serde_json::from_str(r#"{"name": "Sunburn", "kind": "Abiotic", "confidence": 0.6}"#).unwrap();
+        assert_eq!(parsed.kind, CauseKind::Environmental);
+        assert_eq!(parsed.kind.problem_kind(), None);
+    }
+
+    #[test]
+    fn test_series_defaults_and_limits() {
+        let mut t = SuggestedTreatment { product: "Neem oil".into(), dose: None, interval_days: None, applications: None, instructions: String::new() };
+        assert_eq!(t.series(), (DEFAULT_TREATMENT_INTERVAL_DAYS, DEFAULT_TREATMENT_APPLICATIONS));
+        t.interval_days = Some(0);
+        t.applications = Some(50);
+        assert_eq!(t.series(), (1, MAX_TREATMENT_APPLICATIONS));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::orchid::Orchid;
+
+/// The message `update_orchid` fails with when the plant was saved elsewhere after the edit began.
+/// The edit form matches on it to offer a merge instead of a plain error.
+pub const EDIT_CONFLICT: &str = "This plant was changed on another device while you were editing it";
+
+/// The result of replaying an edit onto the plant's latest saved copy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergedEdit {
+    /// The latest copy with the user's edits applied; saving it succeeds unless the plant changes again.
+    pub orchid: Orchid,
+    /// The latest saved copy, for discarding the edit instead.
+    pub theirs: Orchid,
+    /// Fields only the other device changed, kept as it saved them.
+    pub theirs_changed: Vec<&'static str>,
+    /// Fields both sides changed to different values; the user's value is in `orchid`.
+    pub both_changed: Vec<&'static str>,
+}
+
+/// Replays `mine` (an edit of `base`) onto `theirs`, field by field: what only one side changed
+/// is kept from that side, and where both changed a field differently the edit wins and the field
+/// is listed in `both_changed`. Care timestamps and other fields the edit form doesn't touch
+/// always come from `theirs`, as does `updated_at`, so the merged copy saves cleanly.
+pub fn merge_edit(base: &Orchid, mine: &Orchid, theirs: &Orchid) -> MergedEdit {
+    let mut orchid = theirs.clone();
+    let mut theirs_changed = Vec::new();
+    let mut both_changed = Vec::new();
+
+    macro_rules! merge {
+        ($($field:ident => $label:literal),* $(,)?) => {
+            $(
+                let mine_changed = mine.$field != base.$field;
+                let other_changed = theirs.$field != base.$field;
+                if mine_changed {
+                    orchid.$field = mine.$field.clone();
+                    if other_changed && theirs.$field != mine.$field {
+                        both_changed.push($label);
+                    }
+                } else if other_changed {
+                    theirs_changed.push($label);
+                }
+            )*
+        };
+    }
+
+    merge! {
+        name => "Name",
+        species => "Species",
+        water_frequency_days => "Watering interval",
+        light_requirement => "Light",
+        notes => "Notes",
+        placement => "Placement",
+        light_lux => "Light level",
+        temperature_range => "Temperature range",
+        conservation_status => "Conservation status",
+        native_region => "Native region",
+        native_latitude => "Native latitude",
+        native_longitude => "Native longitude",
+        temp_min => "Minimum temperature",
+        temp_max => "Maximum temperature",
+        humidity_min => "Minimum humidity",
+        humidity_max => "Maximum humidity",
+        fertilize_frequency_days => "Feeding interval",
+        fertilizer_type => "Fertilizer",
+        pot_medium => "Potting medium",
+        pot_size => "Pot size",
+        pot_type => "Pot type",
+        rest_start_month => "Rest start",
+        rest_end_month => "Rest end",
+        bloom_start_month => "Bloom start",
+        bloom_end_month => "Bloom end",
+        rest_water_multiplier => "Rest watering",
+        rest_fertilizer_multiplier => "Rest feeding",
+        active_water_multiplier => "Growing-season watering",
+        active_fertilizer_multiplier => "Growing-season feeding",
+        par_ppfd => "Light target",
+        overdue_grace_days => "Overdue grace period",
+        default_watering_method => "Watering method",
+        reservoir_frequency_days => "Reservoir interval",
+        acquired_at => "Acquired",
+        acquisition_price => "Price",
+        acquisition_source => "Source",
+        vendor_id => "Vendor",
+        grex_name => "Grex",
+        seed_parent => "Seed parent",
+        pollen_parent => "Pollen parent",
+    }
+
+    MergedEdit { orchid, theirs: theirs.clone(), theirs_changed, both_changed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    #[test]
+    fn test_merge_keeps_both_sides_changes() {
+        let base = test_orchid();
+        let mine = Orchid { notes: "Repotted in bark".into(), placement: "Patio".into(), ..base.clone() };
+        let theirs = Orchid {
+            name: "Kingie".into(),
+            placement: "Grow Tent".into(),
+            last_watered_at: Some(chrono::Utc::now()),
+            updated_at: Some(chrono::Utc::now()),
+            ..base.clone()
+        };
+
+        let merged = merge_edit(&base, &mine, &theirs);
+        assert_eq!(merged.orchid.name, "Kingie");
+        assert_eq!(merged.orchid.notes, "Repotted in bark");
+        assert_eq!(merged.orchid.placement, "Patio");
+        assert_eq!(merged.orchid.last_watered_at, theirs.last_watered_at);
+        assert_eq!(merged.orchid.updated_at, theirs.updated_at);
+        assert_eq!(merged.theirs_changed, vec!["Name"]);
+        assert_eq!(merged.both_changed, vec!["Placement"]);
+
+        // The same change on both sides isn't a conflict
+        let same = merge_edit(&base, &mine, &Orchid { notes: "Repotted in bark".into(), ..base.clone() });
+        assert!(same.both_changed.is_empty() && same.theirs_changed.is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+/// Average days per month, so a year's estimate adds up to twelve months.
+pub const DAYS_PER_MONTH: f64 = 30.44;
+
+/// Most devices a single zone profile may list.
+pub const MAX_DEVICES_PER_ZONE: usize = 20;
+
+/// What kind of equipment a device is, for grouping on the economics card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EnergyDeviceKind {
+    /// Grow lights.
+    #[default]
+    Light,
+    /// Heaters and heat mats.
+    Heater,
+    /// Humidifiers and foggers.
+    Humidifier,
+    /// Fans and exhaust.
+    Fan,
+    /// Anything else (pumps, chillers, controllers).
+    Other,
+}
+
+impl EnergyDeviceKind {
+    /// Every kind, in display order.
+    pub const ALL: [EnergyDeviceKind; 5] = [
+        EnergyDeviceKind::Light,
+        EnergyDeviceKind::Heater,
+        EnergyDeviceKind::Humidifier,
+        EnergyDeviceKind::Fan,
+        EnergyDeviceKind::Other,
+    ];
+
+    /// The label shown in the UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            EnergyDeviceKind::Light => "Lights",
+            EnergyDeviceKind::Heater => "Heater",
+            EnergyDeviceKind::Humidifier => "Humidifier",
+            EnergyDeviceKind::Fan => "Fans",
+            EnergyDeviceKind::Other => "Other",
+        }
+    }
+
+    /// The stable key used in forms.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EnergyDeviceKind::Light => "light",
+            EnergyDeviceKind::Heater => "heater",
+            EnergyDeviceKind::Humidifier => "humidifier",
+            EnergyDeviceKind::Fan => "fan",
+            EnergyDeviceKind::Other => "other",
+        }
+    }
+
+    /// Parse a form key; unknown keys are `Other`.
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "light" => EnergyDeviceKind::Light,
+            "heater" => EnergyDeviceKind::Heater,
+            "humidifier" => EnergyDeviceKind::Humidifier,
+            "fan" => EnergyDeviceKind::Fan,
+            _ => EnergyDeviceKind::Other,
+        }
+    }
+}
+
+/// One powered device in a zone: its rated draw and how long it runs each day.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct EnergyDevice {
+    /// What kind of equipment this is.
+    pub kind: EnergyDeviceKind,
+    /// The grower's name for the device, e.g. "Spider Farmer SF1000".
+    #[serde(default)]
+    pub name: String,
+    /// Power draw while running, in watts.
+    pub watts: f64,
+    /// Duty cycle: hours per day the device is drawing power (a 12h photoperiod, a heater running 30% of the time = 7.2).
+    pub hours_per_day: f64,
+}
+
+impl EnergyDevice {
+    /// Energy used per day, in kWh.
+    pub fn daily_kwh(&self) -> f64 {
+        self.watts * self.hours_per_day / 1000.0
+    }
+}
+
+/// **What is it?**
+/// A zone's electricity price and the powered devices running in it.
+///
+/// **Why does it exist?**
+/// It exists so growers can see what a tent or greenhouse costs to run each month, and which device is responsible.
+///
+/// **How should it be used?**
+/// Load it with `get_zone_energy_profiles`, edit it in settings, and pass it to `estimate_monthly` for the economics card. A profile with no devices is removed.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ZoneEnergyProfile {
+    /// The zone this profile applies to.
+    pub zone_id: String,
+    /// Electricity price per kWh, in the grower's currency.
+    #[serde(default)]
+    pub price_per_kwh: Option<f64>,
+    /// Powered devices in the zone.
+    #[serde(default)]
+    pub devices: Vec<EnergyDevice>,
+}
+
+impl ZoneEnergyProfile {
+    /// Whether the profile lists no devices.
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+
+    /// Checks that prices, wattages, and duty cycles are in sensible ranges.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(price) = self.price_per_kwh
+            && !(0.0..=10.0).contains(&price) {
+            return Err("Electricity price must be between 0 and 10 per kWh".into());
+        }
+        if self.devices.len() > MAX_DEVICES_PER_ZONE {
+            return Err(format!("A zone can list at most {} devices", MAX_DEVICES_PER_ZONE));
+        }
+        for d in &self.devices {
+            if d.name.len() > 100 {
+                return Err("Device names must be 100 characters or fewer".into());
+            }
+            if !(0.0..=10_000.0).contains(&d.watts) {
+                return Err("Device wattage must be between 0 and 10,000 W".into());
+            }
+            if !(0.0..=24.0).contains(&d.hours_per_day) {
+                return Err("Hours per day must be between 0 and 24".into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One device's share of a zone's monthly running cost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCost {
+    /// What kind of equipment this is.
+    pub kind: EnergyDeviceKind,
+    /// The device's name, or its kind's label when unnamed.
+    pub name: String,
+    /// Estimated energy per month, in kWh.
+    pub monthly_kwh: f64,
+    /// Estimated cost per month, when the zone has a price.
+    pub monthly_cost: Option<f64>,
+}
+
+/// **What is it?**
+/// The estimated monthly running cost of one zone, in total and per device.
+///
+/// **Why does it exist?**
+/// It exists to answer "what does this tent cost me?" with a number, to help justify (or kill) a zone.
+///
+/// **How should it be used?**
+/// Fetch with `get_zone_economics` for the economics card, or build with `estimate_monthly`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneEconomics {
+    /// The zone the estimate covers.
+    pub zone_id: String,
+    /// The zone's display name.
+    pub zone_name: String,
+    /// Electricity price used, per kWh.
+    pub price_per_kwh: Option<f64>,
+    /// Estimated energy per month across all devices, in kWh.
+    pub monthly_kwh: f64,
+    /// Estimated cost per month across all devices, when the zone has a price.
+    pub monthly_cost: Option<f64>,
+    /// Per-device breakdown, most expensive first.
+    pub devices: Vec<DeviceCost>,
+}
+
+/// **What is it?**
+/// A pure function that turns a zone's devices and duty cycles into a monthly energy and cost estimate.
+///
+/// **Why does it exist?**
+/// It exists so the estimate is computed one way, testable without a database.
+///
+/// **How should it be used?**
+/// Call with a zone's profile and name. Costs are `None` when no price is set.
+pub fn estimate_monthly(profile: &ZoneEnergyProfile, zone_name: &str) -> ZoneEconomics {
+    let price = profile.price_per_kwh;
+    let mut devices: Vec<DeviceCost> = profile.devices.iter().map(|d| {
+        let monthly_kwh = d.daily_kwh() * DAYS_PER_MONTH;
+        DeviceCost {
+            kind: d.kind,
+            name: if d.name.trim().is_empty() { d.kind.label().to_string() } else { d.name.clone() },
+            monthly_kwh,
+            monthly_cost: price.map(|p| monthly_kwh * p),
+        }
+    }).collect();
+    devices.sort_by(|a, b| b.monthly_kwh.total_cmp(&a.monthly_kwh));
+
+    let monthly_kwh = devices.iter().map(|d| d.monthly_kwh).sum::<f64>();
+    ZoneEconomics {
+        zone_id: profile.zone_id.clone(),
+        zone_name: zone_name.to_string(),
+        price_per_kwh: price,
+        monthly_kwh,
+        monthly_cost: price.map(|p| monthly_kwh * p),
+        devices,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(kind: EnergyDeviceKind, watts: f64, hours: f64) -> EnergyDevice {
+        EnergyDevice { kind, name: String::new(), watts, hours_per_day: hours }
+    }
+
+    #[test]
+    fn test_estimate_monthly_totals_and_sorts() {
+        let profile = ZoneEnergyProfile {
+            zone_id: "growing_zone:tent".into(),
+            price_per_kwh: Some(0.30),
+            devices: vec![
+                device(EnergyDeviceKind::Fan, 20.0, 24.0),
+                device(EnergyDeviceKind::Light, 100.0, 12.0),
+            ],
+        };
+        let econ = estimate_monthly(&profile, "Tent");
+        // Light: 1.2 kWh/day, fan: 0.48 kWh/day
+        assert!((econ.monthly_kwh - 1.68 * DAYS_PER_MONTH).abs() < 1e-9);
+        assert!((econ.monthly_cost.unwrap() - 1.68 * DAYS_PER_MONTH * 0.30).abs() < 1e-9);
+        assert_eq!(econ.devices[0].kind, EnergyDeviceKind::Light);
+        assert_eq!(econ.devices[0].name, "Lights");
+    }
+
+    #[test]
+    fn test_estimate_without_price_has_no_cost() {
+        let profile = ZoneEnergyProfile {
+            zone_id: "growing_zone:gh".into(),
+            price_per_kwh: None,
+            devices: vec![device(EnergyDeviceKind::Heater, 1500.0, 4.0)],
+        };
+        let econ = estimate_monthly(&profile, "Greenhouse");
+        assert!(econ.monthly_kwh > 0.0);
+        assert_eq!(econ.monthly_cost, None);
+        assert_eq!(econ.devices[0].monthly_cost, None);
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range() {
+        let mut profile = ZoneEnergyProfile {
+            zone_id: "growing_zone:a".into(),
+            price_per_kwh: Some(0.25),
+            devices: vec![device(EnergyDeviceKind::Light, 200.0, 25.0)],
+        };
+        assert!(profile.validate().is_err());
+        profile.devices[0].hours_per_day = 14.0;
+        assert!(profile.validate().is_ok());
+        profile.price_per_kwh = Some(-1.0);
+        assert!(profile.validate().is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How many hours ahead the outdoor forecast covers.
+pub const FORECAST_HOURS: usize = 48;
+
+/// Precipitation below this (mm) over a window is treated as dry.
+const RAIN_THRESHOLD_MM: f64 = 1.0;
+
+/// One hour of forecast weather.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ForecastHour {
+    /// Start of the hour.
+    pub time: DateTime<Utc>,
+    /// Air temperature at 2m in Celsius.
+    pub temperature_c: f64,
+    /// Expected precipitation in mm during the hour.
+    pub precipitation_mm: f64,
+    /// Chance of precipitation (0-100), when the model provides one.
+    #[serde(default)]
+    pub precipitation_probability: Option<f64>,
+}
+
+/// The next 48 hours of weather for one outdoor zone.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ZoneForecast {
+    /// The zone's record ID.
+    pub zone_id: String,
+    /// The zone's display name.
+    pub zone_name: String,
+    /// Hourly forecast, oldest first.
+    pub hours: Vec<ForecastHour>,
+    /// When the forecast was fetched from the provider (may be older than now when cached).
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl ZoneForecast {
+    /// Hours starting within `window` of `now`.
+    fn within(&self, now: DateTime<Utc>, window: Duration) -> impl Iterator<Item = &ForecastHour> {
+        let end = now + window;
+        self.hours.iter().filter(move |h| h.time + Duration::hours(1) > now && h.time < end)
+    }
+
+    /// Total expected precipitation (mm) over the next `hours` hours.
+    pub fn precipitation_next(&self, now: DateTime<Utc>, hours: i64) -> f64 {
+        self.within(now, Duration::hours(hours)).map(|h| h.precipitation_mm).sum()
+    }
+
+    /// Lowest and highest temperature (Celsius) over the whole forecast, if any hours exist.
+    pub fn temperature_range(&self) -> Option<(f64, f64)> {
+        let mut temps = self.hours.iter().map(|h| h.temperature_c);
+        let first = temps.next()?;
+        Some(temps.fold((first, first), |(lo, hi), t| (lo.min(t), hi.max(t))))
+    }
+
+    /// A one-line watering hint for the next 12 hours, such as whether rain makes watering outdoor mounts unnecessary.
+    pub fn watering_hint(&self, now: DateTime<Utc>) -> String {
+        let rain = self.precipitation_next(now, 12);
+        if rain >= RAIN_THRESHOLD_MM {
+            return format!("{:.1} mm of rain in the next 12h, outdoor mounts can likely skip watering", rain);
+        }
+        let chance = self.within(now, Duration::hours(12))
+            .filter_map(|h| h.precipitation_probability)
+            .fold(0.0_f64, f64::max);
+        if chance >= 50.0 {
+            format!("Showers possible in the next 12h ({:.0}% chance), check before watering", chance)
+        } else {
+            "Dry for the next 12h, water outdoor mounts as usual".into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forecast(rain: &[f64], probability: Option<f64>) -> (ZoneForecast, DateTime<Utc>) {
+        let start = DateTime::parse_from_rfc3339("2026-06-01T18:00:00Z").unwrap().with_timezone(&Utc);
+        let hours = rain.iter().enumerate().map(|(i, &mm)| ForecastHour {
+            time: start + Duration::hours(i as i64),
+            temperature_c: 20.0 - i as f64,
+            precipitation_mm: mm,
+            precipitation_probability: probability,
+        }).collect();
+        (ZoneForecast { zone_id: "growing_zone:a".into(), zone_name: "Patio".into(), hours, fetched_at: start }, start)
+    }
+
+    #[test]
+    fn test_precipitation_next_sums_window() {
+        let mut rain = vec![0.5; 12];
+        rain.extend(vec![10.0; 12]);
+        let (f, now) = forecast(&rain, None);
+        assert!((f.precipitation_next(now, 12) - 6.0).abs() < 1e-9);
+        assert!((f.precipitation_next(now, 24) - 126.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature_range() {
+        let (f, _) = forecast(&[0.0; 5], None);
+        assert_eq!(f.temperature_range(), Some((16.0, 20.0)));
+        let empty = ZoneForecast { hours: vec![], ..f };
+        assert_eq!(empty.temperature_range(), None);
+    }
+
+    #[test]
+    fn test_watering_hint() {
+        let (wet, now) = forecast(&[0.5; 12], None);
+        assert!(wet.watering_hint(now).contains("skip watering"));
+        let (maybe, now) = forecast(&[0.0; 12], Some(70.0));
+        assert!(maybe.watering_hint(now).contains("70% chance"));
+        let (dry, now) = forecast(&[0.0; 12], Some(10.0));
+        assert!(dry.watering_hint(now).starts_with("Dry"));
+    }
+}
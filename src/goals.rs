@@ -0,0 +1,365 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::ClimateReading;
+
+/// How many of the largest excursions a report card keeps.
+pub const MAX_EXCURSIONS: usize = 3;
+
+/// **What is it?**
+/// A user-defined target range for temperature and humidity in a single growing zone.
+///
+/// **Why does it exist?**
+/// It exists so growers can state what "good conditions" mean for each zone and measure how often the zone actually delivers them.
+///
+/// **How should it be used?**
+/// Load it with `get_zone_goals`, edit it in settings, and pass it to `build_report_card` alongside the zone's readings. Unset bounds are not scored.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ZoneGoal {
+    /// The zone this goal applies to.
+    pub zone_id: String,
+    /// Lowest acceptable temperature in Celsius.
+    #[serde(default)]
+    pub temp_min_c: Option<f64>,
+    /// Highest acceptable temperature in Celsius.
+    #[serde(default)]
+    pub temp_max_c: Option<f64>,
+    /// Lowest acceptable relative humidity percentage.
+    #[serde(default)]
+    pub humidity_min: Option<f64>,
+    /// Highest acceptable relative humidity percentage.
+    #[serde(default)]
+    pub humidity_max: Option<f64>,
+}
+
+impl ZoneGoal {
+    /// Whether a temperature bound has been set.
+    pub fn has_temp(&self) -> bool {
+        self.temp_min_c.is_some() || self.temp_max_c.is_some()
+    }
+
+    /// Whether a humidity bound has been set.
+    pub fn has_humidity(&self) -> bool {
+        self.humidity_min.is_some() || self.humidity_max.is_some()
+    }
+
+    /// Whether any bound has been set.
+    pub fn is_empty(&self) -> bool {
+        !self.has_temp() && !self.has_humidity()
+    }
+
+    /// Checks that each min is below its max and humidity stays within 0–100%.
+    pub fn validate(&self) -> Result<(), String> {
+        if let (Some(lo), Some(hi)) = (self.temp_min_c, self.temp_max_c)
+            && lo >= hi {
+            return Err("Temperature minimum must be below the maximum".into());
+        }
+        if let (Some(lo), Some(hi)) = (self.humidity_min, self.humidity_max)
+            && lo >= hi {
+            return Err("Humidity minimum must be below the maximum".into());
+        }
+        for h in [self.humidity_min, self.humidity_max].into_iter().flatten() {
+            if !(0.0..=100.0).contains(&h) {
+                return Err("Humidity targets must be between 0 and 100%".into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Which metric a goal or excursion refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalMetric {
+    /// Air temperature.
+    Temperature,
+    /// Relative humidity.
+    Humidity,
+}
+
+/// **What is it?**
+/// A single reading that fell outside a zone's goal range, with how far it strayed.
+///
+/// **Why does it exist?**
+/// It exists so the weekly report card can point at the worst moments (e.g., "31.2°C on Tuesday, 3.2° over") rather than only a percentage.
+///
+/// **How should it be used?**
+/// Produced by `build_report_card`; render `value` with the user's unit and `recorded_at` as a local time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Excursion {
+    /// The metric that left its range.
+    pub metric: GoalMetric,
+    /// The measured value (Celsius for temperature).
+    pub value: f64,
+    /// The bound that was crossed.
+    pub limit: f64,
+    /// Signed distance past the bound: positive when too high, negative when too low.
+    pub deviation: f64,
+    /// When the reading was taken.
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// **What is it?**
+/// A weekly summary of how well one zone stayed within its goal ranges.
+///
+/// **Why does it exist?**
+/// It exists to turn a week of raw sensor readings into something a grower can act on: a grade, time in range, the worst excursions, and whether things are improving.
+///
+/// **How should it be used?**
+/// Fetch with `get_weekly_report_cards` for the dashboard, or build directly with `build_report_card` in the weekly push job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeeklyReportCard {
+    /// The zone the card covers.
+    pub zone_id: String,
+    /// The zone's display name.
+    pub zone_name: String,
+    /// Start of the seven-day window.
+    pub week_start: DateTime<Utc>,
+    /// Number of readings in the window.
+    pub sample_count: usize,
+    /// Percent of time temperature was within range, if a temperature goal is set.
+    pub temp_in_range_pct: Option<f64>,
+    /// Percent of time humidity was within range, if a humidity goal is set.
+    pub humidity_in_range_pct: Option<f64>,
+    /// Change in temperature time-in-range versus the previous week, in percentage points.
+    pub temp_trend: Option<f64>,
+    /// Change in humidity time-in-range versus the previous week, in percentage points.
+    pub humidity_trend: Option<f64>,
+    /// The largest excursions of the week, worst first.
+    pub worst_excursions: Vec<Excursion>,
+    /// Letter grade derived from the overall time in range.
+    pub grade: char,
+}
+
+impl WeeklyReportCard {
+    /// Average of the scored metrics' time-in-range percentages.
+    pub fn overall_pct(&self) -> Option<f64> {
+        let scores: Vec<f64> = [self.temp_in_range_pct, self.humidity_in_range_pct]
+            .into_iter()
+            .flatten()
+            .collect();
+        if scores.is_empty() {
+            None
+        } else {
+            Some(scores.iter().sum::<f64>() / scores.len() as f64)
+        }
+    }
+
+    /// One-line summary suitable for a push notification body.
+    pub fn summary_line(&self) -> String {
+        let mut parts = vec![format!("{}: grade {}", self.zone_name, self.grade)];
+        if let Some(p) = self.temp_in_range_pct {
+            parts.push(format!("temp {:.0}% in range", p));
+        }
+        if let Some(p) = self.humidity_in_range_pct {
+            parts.push(format!("humidity {:.0}% in range", p));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Maps an overall time-in-range percentage to a letter grade.
+pub fn grade_for(pct: Option<f64>) -> char {
+    match pct {
+        Some(p) if p >= 90.0 => 'A',
+        Some(p) if p >= 75.0 => 'B',
+        Some(p) if p >= 60.0 => 'C',
+        Some(p) if p >= 40.0 => 'D',
+        Some(_) => 'F',
+        None => '-',
+    }
+}
+
+fn deviation(value: f64, min: Option<f64>, max: Option<f64>) -> Option<(f64, f64)> {
+    if let Some(lo) = min
+        && value < lo {
+        return Some((lo, value - lo));
+    }
+    if let Some(hi) = max
+        && value > hi {
+        return Some((hi, value - hi));
+    }
+    None
+}
+
+fn in_range_pct(readings: &[ClimateReading], value: impl Fn(&ClimateReading) -> f64, min: Option<f64>, max: Option<f64>) -> Option<f64> {
+    if readings.is_empty() || (min.is_none() && max.is_none()) {
+        return None;
+    }
+    let ok = readings.iter().filter(|r| deviation(value(r), min, max).is_none()).count();
+    Some(ok as f64 / readings.len() as f64 * 100.0)
+}
+
+/// **What is it?**
+/// A pure function that scores one zone's readings for a week against its goal and compares the result with the previous week.
+///
+/// **Why does it exist?**
+/// It exists so the dashboard and the weekly push job share one grading rule and so the scoring can be unit tested without a database.
+///
+/// **How should it be used?**
+/// Pass the goal, the zone name, readings for the last seven days, readings for the seven days before that, and the window start. Readings are sampled at a regular polling interval, so each counts equally toward time in range.
+pub fn build_report_card(
+    goal: &ZoneGoal,
+    zone_name: &str,
+    this_week: &[ClimateReading],
+    last_week: &[ClimateReading],
+    week_start: DateTime<Utc>,
+) -> WeeklyReportCard {
+    let temp_pct = in_range_pct(this_week, |r| r.temperature, goal.temp_min_c, goal.temp_max_c);
+    let hum_pct = in_range_pct(this_week, |r| r.humidity, goal.humidity_min, goal.humidity_max);
+    let prev_temp = in_range_pct(last_week, |r| r.temperature, goal.temp_min_c, goal.temp_max_c);
+    let prev_hum = in_range_pct(last_week, |r| r.humidity, goal.humidity_min, goal.humidity_max);
+
+    let mut excursions: Vec<Excursion> = Vec::new();
+    for r in this_week {
+        if let Some((limit, dev)) = deviation(r.temperature, goal.temp_min_c, goal.temp_max_c) {
+            excursions.push(Excursion {
+                metric: GoalMetric::Temperature,
+                value: r.temperature,
+                limit,
+                deviation: dev,
+                recorded_at: r.recorded_at,
+            });
+        }
+        if let Some((limit, dev)) = deviation(r.humidity, goal.humidity_min, goal.humidity_max) {
+            excursions.push(Excursion {
+                metric: GoalMetric::Humidity,
+                value: r.humidity,
+                limit,
+                deviation: dev,
+                recorded_at: r.recorded_at,
+            });
+        }
+    }
+    // Humidity deviations are in % points, temperature in degrees; a 1° miss
+    // matters about as much as a 5% humidity miss for most orchids.
+    let severity = |e: &Excursion| match e.metric {
+        GoalMetric::Temperature => e.deviation.abs() * 5.0,
+        GoalMetric::Humidity => e.deviation.abs(),
+    };
+    excursions.sort_by(|a, b| severity(b).partial_cmp(&severity(a)).unwrap_or(std::cmp::Ordering::Equal));
+    excursions.truncate(MAX_EXCURSIONS);
+
+    let mut card = WeeklyReportCard {
+        zone_id: goal.zone_id.clone(),
+        zone_name: zone_name.to_string(),
+        week_start,
+        sample_count: this_week.len(),
+        temp_in_range_pct: temp_pct,
+        humidity_in_range_pct: hum_pct,
+        temp_trend: temp_pct.zip(prev_temp).map(|(now, prev)| now - prev),
+        humidity_trend: hum_pct.zip(prev_hum).map(|(now, prev)| now - prev),
+        worst_excursions: excursions,
+        grade: '-',
+    };
+    card.grade = grade_for(card.overall_pct());
+    card
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn reading(temp: f64, humidity: f64, hours_ago: i64) -> ClimateReading {
+        ClimateReading {
+            id: String::new(),
+            zone_id: "growing_zone:a".into(),
+            zone_name: "Tent".into(),
+            temperature: temp,
+            humidity,
+            vpd: None,
+            dew_point: None,
+            precipitation: None,
+            uv_index: None,
+            solar_radiation: None,
+            wind_speed: None,
+            wind_gust: None,
+            lux: None,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
+            source: None,
+            recorded_at: Utc::now() - Duration::hours(hours_ago),
+        }
+    }
+
+    fn goal() -> ZoneGoal {
+        ZoneGoal {
+            zone_id: "growing_zone:a".into(),
+            temp_min_c: Some(18.0),
+            temp_max_c: Some(28.0),
+            humidity_min: Some(50.0),
+            humidity_max: Some(80.0),
+        }
+    }
+
+    #[test]
+    fn test_all_in_range_is_grade_a() {
+        let week = vec![reading(22.0, 60.0, 1), reading(24.0, 65.0, 2)];
+        let card = build_report_card(&goal(), "Tent", &week, &[], Utc::now());
+        assert_eq!(card.temp_in_range_pct, Some(100.0));
+        assert_eq!(card.humidity_in_range_pct, Some(100.0));
+        assert_eq!(card.grade, 'A');
+        assert!(card.worst_excursions.is_empty());
+        assert_eq!(card.temp_trend, None);
+    }
+
+    #[test]
+    fn test_excursions_sorted_worst_first() {
+        let week = vec![
+            reading(29.0, 60.0, 1),
+            reading(31.0, 60.0, 2),
+            reading(22.0, 40.0, 3),
+            reading(22.0, 60.0, 4),
+        ];
+        let card = build_report_card(&goal(), "Tent", &week, &[], Utc::now());
+        assert_eq!(card.temp_in_range_pct, Some(50.0));
+        assert_eq!(card.humidity_in_range_pct, Some(75.0));
+        assert_eq!(card.worst_excursions[0].value, 31.0);
+        assert_eq!(card.worst_excursions[0].deviation, 3.0);
+        assert_eq!(card.worst_excursions[1].metric, GoalMetric::Humidity);
+        assert_eq!(card.worst_excursions[1].deviation, -10.0);
+    }
+
+    #[test]
+    fn test_trend_vs_last_week() {
+        let this_week = vec![reading(22.0, 60.0, 1), reading(22.0, 60.0, 2)];
+        let last_week = vec![reading(35.0, 60.0, 170), reading(22.0, 60.0, 171)];
+        let card = build_report_card(&goal(), "Tent", &this_week, &last_week, Utc::now());
+        assert_eq!(card.temp_trend, Some(50.0));
+        assert_eq!(card.humidity_trend, Some(0.0));
+    }
+
+    #[test]
+    fn test_unset_metric_not_scored() {
+        let g = ZoneGoal { humidity_min: None, humidity_max: None, ..goal() };
+        let card = build_report_card(&g, "Tent", &[reading(22.0, 10.0, 1)], &[], Utc::now());
+        assert_eq!(card.humidity_in_range_pct, None);
+        assert!(card.worst_excursions.is_empty());
+        assert_eq!(card.grade, 'A');
+    }
+
+    #[test]
+    fn test_no_readings_has_no_grade() {
+        let card = build_report_card(&goal(), "Tent", &[], &[], Utc::now());
+        assert_eq!(card.grade, '-');
+        assert_eq!(card.overall_pct(), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(goal().validate().is_ok());
+        let bad = ZoneGoal { temp_min_c: Some(30.0), ..goal() };
+        assert!(bad.validate().is_err());
+        let bad_hum = ZoneGoal { humidity_max: Some(120.0), ..goal() };
+        assert!(bad_hum.validate().is_err());
+    }
+
+    #[test]
+    fn test_grade_boundaries() {
+        assert_eq!(grade_for(Some(90.0)), 'A');
+        assert_eq!(grade_for(Some(75.0)), 'B');
+        assert_eq!(grade_for(Some(60.0)), 'C');
+        assert_eq!(grade_for(Some(40.0)), 'D');
+        assert_eq!(grade_for(Some(39.9)), 'F');
+    }
+}
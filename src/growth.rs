@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::LogEntry;
+
+/// The journal event type for a measurement entry.
+pub const MEASURED_EVENT: &str = "Measured";
+
+/// Longest leaf span or spike accepted, in centimetres.
+pub const MAX_LENGTH_CM: f64 = 500.0;
+/// Most pseudobulbs or roots accepted in one count.
+pub const MAX_COUNT: u32 = 1000;
+
+/// What is it? The sizes and counts taken when a plant is measured; any of them may be left out.
+/// Why does it exist? "It's growing well" is hard to check months later; numbers logged over time show whether a plant is actually putting on size, roots and spikes.
+/// How should it be used? Attach to a 'Measured' log entry through `server_fns::orchids::add_measurement`; chart with `series`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types"))]
+pub struct GrowthMeasurement {
+    /// Tip-to-tip span of the leaves, in centimetres.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub leaf_span_cm: Option<f64>,
+    /// Number of pseudobulbs, for sympodial plants.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub pseudobulb_count: Option<u32>,
+    /// Length of the longest flower spike, in centimetres.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub spike_length_cm: Option<f64>,
+    /// Number of live roots.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub root_count: Option<u32>,
+}
+
+impl GrowthMeasurement {
+    /// Whether nothing was measured.
+    pub fn is_empty(&self) -> bool {
+        Metric::ALL.iter().all(|m| m.value(self).is_none())
+    }
+
+    /// Checks every value is in range; the error names the first that isn't.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.is_empty() {
+            return Err("Enter at least one measurement".into());
+        }
+        for metric in Metric::ALL {
+            if let Some(v) = metric.value(self)
+                && !(0.0..=metric.max()).contains(&v)
+            {
+                return Err(format!("{} must be 0-{}", metric.label(), metric.max()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Short display form, e.g. "Leaf span 32 cm · 4 roots".
+    pub fn describe(&self) -> String {
+        Metric::ALL.iter()
+            .filter_map(|m| m.value(self).map(|v| m.describe(v)))
+            .collect::<Vec<_>>()
+            .join(" \u{00B7} ")
+    }
+}
+
+/// One of the measured quantities, for charts and the measurement form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// `leaf_span_cm`.
+    LeafSpan,
+    /// `pseudobulb_count`.
+    PseudobulbCount,
+    /// `spike_length_cm`.
+    SpikeLength,
+    /// `root_count`.
+    RootCount,
+}
+
+impl Metric {
+    /// Every metric, in the order shown.
+    pub const ALL: [Metric; 4] = [Metric::LeafSpan, Metric::PseudobulbCount, Metric::SpikeLength, Metric::RootCount];
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Metric::LeafSpan => "Leaf span",
+            Metric::PseudobulbCount => "Pseudobulbs",
+            Metric::SpikeLength => "Spike length",
+            Metric::RootCount => "Roots",
+        }
+    }
+
+    /// Unit suffix for values, empty for counts.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Metric::LeafSpan | Metric::SpikeLength => "cm",
+            Metric::PseudobulbCount | Metric::RootCount => "",
+        }
+    }
+
+    /// Whether values are whole counts rather than lengths.
+    pub fn is_count(&self) -> bool {
+        self.unit().is_empty()
+    }
+
+    /// Largest value accepted.
+    pub fn max(&self) -> f64 {
+        if self.is_count() { MAX_COUNT as f64 } else { MAX_LENGTH_CM }
+    }
+
+    /// This metric's value in a measurement, if it was taken.
+    pub fn value(&self, m: &GrowthMeasurement) -> Option<f64> {
+        match self {
+            Metric::LeafSpan => m.leaf_span_cm,
+            Metric::PseudobulbCount => m.pseudobulb_count.map(f64::from),
+            Metric::SpikeLength => m.spike_length_cm,
+            Metric::RootCount => m.root_count.map(f64::from),
+        }
+    }
+
+    /// A value with its unit, e.g. "32.5 cm"; counts are bare numbers.
+    pub fn format_value(&self, v: f64) -> String {
+        match self {
+            Metric::LeafSpan | Metric::SpikeLength => format!("{} cm", trim_decimal(v)),
+            Metric::PseudobulbCount | Metric::RootCount => format!("{:.0}", v),
+        }
+    }
+
+    /// A value with its label, e.g. "Leaf span 32 cm" or "4 pseudobulbs".
+    fn describe(&self, v: f64) -> String {
+        match self {
+            Metric::LeafSpan | Metric::SpikeLength => format!("{} {}", self.label(), self.format_value(v)),
+            Metric::PseudobulbCount => format!("{:.0} pseudobulb{}", v, if v == 1.0 { "" } else { "s" }),
+            Metric::RootCount => format!("{:.0} root{}", v, if v == 1.0 { "" } else { "s" }),
+        }
+    }
+}
+
+/// "32" for a whole number, "32.5" otherwise.
+fn trim_decimal(v: f64) -> String {
+    if v.fract() == 0.0 { format!("{:.0}", v) } else { format!("{:.1}", v) }
+}
+
+/// Every logged value of `metric` in a plant's journal, oldest first.
+pub fn series(entries: &[LogEntry], metric: Metric) -> Vec<(DateTime<Utc>, f64)> {
+    let mut points: Vec<(DateTime<Utc>, f64)> = entries.iter()
+        .filter_map(|e| e.measurement.as_ref().and_then(|m| metric.value(m)).map(|v| (e.timestamp, v)))
+        .collect();
+    points.sort_by_key(|(t, _)| *t);
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(day: u32, measurement: Option<GrowthMeasurement>) -> LogEntry {
+        LogEntry {
+            id: format!("log_entry:{}", day),
+            timestamp: Utc.with_ymd_and_hms(2024, 3, day, 9, 0, 0).unwrap(),
+            note: String::new(),
+            image_filename: None,
+            event_type: measurement.as_ref().map(|_| MEASURED_EVENT.to_string()),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement,
+            root_assessment: None,
+        }
+    }
+
+    #[test]
+    fn test_series_skips_missing_values_and_sorts() {
+        let entries = vec![
+            entry(20, Some(GrowthMeasurement { leaf_span_cm: Some(34.0), root_count: Some(9), ..Default::default() })),
+            entry(10, None),
+            entry(1, Some(GrowthMeasurement { leaf_span_cm: Some(30.5), ..Default::default() })),
+        ];
+        let span = series(&entries, Metric::LeafSpan);
+        assert_eq!(span.iter().map(|(_, v)| *v).collect::<Vec<_>>(), vec![30.5, 34.0]);
+        assert_eq!(series(&entries, Metric::RootCount).len(), 1);
+        assert!(series(&entries, Metric::SpikeLength).is_empty());
+    }
+
+    #[test]
+    fn test_validate_and_describe() {
+        assert!(GrowthMeasurement::default().validate().is_err());
+        assert!(GrowthMeasurement { spike_length_cm: Some(-1.0), ..Default::default() }.validate().is_err());
+        assert!(GrowthMeasurement { leaf_span_cm: Some(f64::NAN), ..Default::default() }.validate().is_err());
+
+        let m = GrowthMeasurement { leaf_span_cm: Some(32.0), pseudobulb_count: Some(1), root_count: Some(4), ..Default::default() };
+        assert!(m.validate().is_ok());
+        assert_eq!(m.describe(), "Leaf span 32 cm \u{00B7} 1 pseudobulb \u{00B7} 4 roots");
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::{ClimateReading, HabitatWeather, HabitatWeatherSummary};
+
+/// Average temperature difference (°C) that still scores full marks.
+const AVG_TEMP_TOLERANCE_C: f64 = 1.5;
+/// Night-low difference (°C) that still scores full marks.
+const LOW_TEMP_TOLERANCE_C: f64 = 2.0;
+/// Humidity difference (% RH) that still scores full marks.
+const HUMIDITY_TOLERANCE_PCT: f64 = 5.0;
+/// Points lost per °C beyond tolerance.
+const TEMP_PENALTY_PER_C: f64 = 8.0;
+/// Points lost per % RH beyond tolerance.
+const HUMIDITY_PENALTY_PER_PCT: f64 = 2.0;
+/// Weights of average temperature, night low and humidity in the score.
+const WEIGHTS: (f64, f64, f64) = (0.4, 0.3, 0.3);
+
+/// Score at or above which a month counts as a close match.
+pub const CLOSE_MATCH_SCORE: u8 = 80;
+/// Score at or above which a month counts as drifting rather than far off.
+pub const SOME_DRIFT_SCORE: u8 = 60;
+
+/// A climate observation or summary to fold into monthly figures, weighted by how many readings it stands for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClimateSample {
+    /// When the sample (or its period) starts.
+    pub at: DateTime<Utc>,
+    /// Mean temperature, °C.
+    pub avg_temperature: f64,
+    /// Lowest temperature, °C.
+    pub min_temperature: f64,
+    /// Highest temperature, °C.
+    pub max_temperature: f64,
+    /// Mean relative humidity, %.
+    pub avg_humidity: f64,
+    /// Number of readings the sample represents.
+    pub weight: f64,
+}
+
+impl From<&ClimateReading> for ClimateSample {
+    fn from(r: &ClimateReading) -> Self {
+        Self {
+            at: r.recorded_at,
+            avg_temperature: r.temperature,
+            min_temperature: r.temperature,
+            max_temperature: r.temperature,
+            avg_humidity: r.humidity,
+            weight: 1.0,
+        }
+    }
+}
+
+impl From<&HabitatWeather> for ClimateSample {
+    fn from(w: &HabitatWeather) -> Self {
+        Self {
+            at: w.recorded_at,
+            avg_temperature: w.temperature,
+            min_temperature: w.temperature,
+            max_temperature: w.temperature,
+            avg_humidity: w.humidity,
+            weight: 1.0,
+        }
+    }
+}
+
+impl From<&HabitatWeatherSummary> for ClimateSample {
+    fn from(s: &HabitatWeatherSummary) -> Self {
+        Self {
+            at: s.period_start,
+            avg_temperature: s.avg_temperature,
+            min_temperature: s.min_temperature,
+            max_temperature: s.max_temperature,
+            avg_humidity: s.avg_humidity,
+            weight: s.sample_count.max(1) as f64,
+        }
+    }
+}
+
+/// One calendar month of climate, folded from samples.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonthClimate {
+    /// Weighted mean temperature, °C.
+    pub avg_temperature: f64,
+    /// Lowest temperature seen, °C.
+    pub min_temperature: f64,
+    /// Highest temperature seen, °C.
+    pub max_temperature: f64,
+    /// Weighted mean relative humidity, %.
+    pub avg_humidity: f64,
+}
+
+/// Running weighted temperature and humidity sums, min, max and total weight for one month.
+type MonthTotals = (f64, f64, f64, f64, f64);
+
+/// Folds samples into calendar months, keyed by (year, month).
+pub fn monthly_climate(samples: impl IntoIterator<Item = ClimateSample>) -> BTreeMap<(i32, u32), MonthClimate> {
+    let mut acc: BTreeMap<(i32, u32), MonthTotals> = BTreeMap::new();
+    for s in samples {
+        if s.weight <= 0.0 {
+            continue;
+        }
+        let entry = acc.entry((s.at.year(), s.at.month()))
+            .or_insert((0.0, 0.0, f64::INFINITY, f64::NEG_INFINITY, 0.0));
+        entry.0 += s.avg_temperature * s.weight;
+        entry.1 += s.avg_humidity * s.weight;
+        entry.2 = entry.2.min(s.min_temperature);
+        entry.3 = entry.3.max(s.max_temperature);
+        entry.4 += s.weight;
+    }
+    acc.into_iter()
+        .map(|(month, (temp_sum, humidity_sum, min, max, weight))| (month, MonthClimate {
+            avg_temperature: temp_sum / weight,
+            min_temperature: min,
+            max_temperature: max,
+            avg_humidity: humidity_sum / weight,
+        }))
+        .collect()
+}
+
+/// **What is it?**
+/// How closely one month at home matched the species' native habitat in the same month, scored 0–100.
+///
+/// **Why does it exist?**
+/// It exists so growers can see how far their conditions drift from the plant's native climate through the year, not just today.
+///
+/// **How should it be used?**
+/// Fetch with `server_fns::climate::get_habitat_similarity` and show the months as a strip; use `verdict` for the label. Differences are home minus habitat.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonthlySimilarity {
+    /// Calendar year.
+    pub year: i32,
+    /// Calendar month, 1–12.
+    pub month: u32,
+    /// 0 (nothing alike) to 100 (same climate).
+    pub score: u8,
+    /// Home average temperature minus habitat average, °C.
+    pub avg_temp_diff: f64,
+    /// Home lowest temperature minus habitat lowest, °C.
+    pub low_temp_diff: f64,
+    /// Home average humidity minus habitat average, % RH.
+    pub humidity_diff: f64,
+}
+
+impl MonthlySimilarity {
+    /// Short label for the score.
+    pub fn verdict(&self) -> &'static str {
+        if self.score >= CLOSE_MATCH_SCORE {
+            "Close match"
+        } else if self.score >= SOME_DRIFT_SCORE {
+            "Some drift"
+        } else {
+            "Far from habitat"
+        }
+    }
+
+    /// The biggest contributor to the gap, as advice ("12.0°C colder at night").
+    pub fn main_gap(&self) -> Option<String> {
+        let gaps = [
+            (component_penalty(self.avg_temp_diff, AVG_TEMP_TOLERANCE_C, TEMP_PENALTY_PER_C) * WEIGHTS.0, "on average", self.avg_temp_diff),
+            (component_penalty(self.low_temp_diff, LOW_TEMP_TOLERANCE_C, TEMP_PENALTY_PER_C) * WEIGHTS.1, "at night", self.low_temp_diff),
+        ];
+        let humidity = component_penalty(self.humidity_diff, HUMIDITY_TOLERANCE_PCT, HUMIDITY_PENALTY_PER_PCT) * WEIGHTS.2;
+        let (temp_penalty, when, diff) = gaps.into_iter().fold((0.0, "", 0.0), |best, g| if g.0 > best.0 { g } else { best });
+        if temp_penalty <= 0.0 && humidity <= 0.0 {
+            return None;
+        }
+        Some(if humidity > temp_penalty {
+            format!("{:.0}% {} humid", self.humidity_diff.abs(), if self.humidity_diff < 0.0 { "less" } else { "more" })
+        } else {
+            format!("{:.1}\u{00B0}C {} {}", diff.abs(), if diff < 0.0 { "colder" } else { "warmer" }, when)
+        })
+    }
+}
+
+/// Points lost for one component: nothing within tolerance, then a linear penalty, capped at 100.
+fn component_penalty(diff: f64, tolerance: f64, per_unit: f64) -> f64 {
+    ((diff.abs() - tolerance).max(0.0) * per_unit).min(100.0)
+}
+
+/// Scores one month of home climate against the habitat's same month.
+pub fn score_month(year: i32, month: u32, home: &MonthClimate, habitat: &MonthClimate) -> MonthlySimilarity {
+    let avg_temp_diff = home.avg_temperature - habitat.avg_temperature;
+    let low_temp_diff = home.min_temperature - habitat.min_temperature;
+    let humidity_diff = home.avg_humidity - habitat.avg_humidity;
+    let score = 100.0
+        - component_penalty(avg_temp_diff, AVG_TEMP_TOLERANCE_C, TEMP_PENALTY_PER_C) * WEIGHTS.0
+        - component_penalty(low_temp_diff, LOW_TEMP_TOLERANCE_C, TEMP_PENALTY_PER_C) * WEIGHTS.1
+        - component_penalty(humidity_diff, HUMIDITY_TOLERANCE_PCT, HUMIDITY_PENALTY_PER_PCT) * WEIGHTS.2;
+    MonthlySimilarity {
+        year,
+        month,
+        score: score.round().clamp(0.0, 100.0) as u8,
+        avg_temp_diff,
+        low_temp_diff,
+        humidity_diff,
+    }
+}
+
+/// Scores every calendar month that has both home and habitat data, oldest first.
+pub fn compare_monthly(home: &[ClimateSample], habitat: &[ClimateSample]) -> Vec<MonthlySimilarity> {
+    let home = monthly_climate(home.iter().copied());
+    let habitat = monthly_climate(habitat.iter().copied());
+    home.iter()
+        .filter_map(|(&(year, month), h)| {
+            habitat.get(&(year, month)).map(|native| score_month(year, month, h, native))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample(month: u32, day: u32, avg: f64, min: f64, humidity: f64, weight: f64) -> ClimateSample {
+        ClimateSample {
+            at: Utc.with_ymd_and_hms(2026, month, day, 0, 0, 0).unwrap(),
+            avg_temperature: avg,
+            min_temperature: min,
+            max_temperature: avg + 5.0,
+            avg_humidity: humidity,
+            weight,
+        }
+    }
+
+    #[test]
+    fn test_monthly_climate_is_weighted() {
+        let months = monthly_climate([sample(3, 1, 20.0, 15.0, 60.0, 1.0), sample(3, 20, 26.0, 18.0, 80.0, 3.0)]);
+        let march = months[&(2026, 3)];
+        assert!((march.avg_temperature - 24.5).abs() < 1e-9);
+        assert!((march.avg_humidity - 75.0).abs() < 1e-9);
+        assert_eq!(march.min_temperature, 15.0);
+    }
+
+    #[test]
+    fn test_matching_months_score_full_and_drift_loses_points() {
+        let habitat = [sample(5, 1, 22.0, 16.0, 80.0, 30.0), sample(6, 1, 22.0, 16.0, 80.0, 30.0)];
+        let home = [
+            sample(5, 10, 22.5, 17.0, 77.0, 1.0),  // within tolerance
+            sample(6, 10, 22.0, 5.0, 40.0, 1.0),   // cold nights, dry air
+            sample(7, 10, 22.0, 16.0, 80.0, 1.0),  // no habitat data for July
+        ];
+        let months = compare_monthly(&home, &habitat);
+        assert_eq!(months.len(), 2);
+        assert_eq!(months[0].score, 100);
+        assert_eq!(months[0].verdict(), "Close match");
+        assert!(months[0].main_gap().is_none());
+
+        let june = &months[1];
+        assert!(june.score < SOME_DRIFT_SCORE, "score {}", june.score);
+        assert_eq!(june.verdict(), "Far from habitat");
+        assert_eq!(june.main_gap().as_deref(), Some("11.0\u{00B0}C colder at night"));
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How long a household invite link stays valid, in days.
+pub const INVITE_VALID_DAYS: i64 = 7;
+
+/// Maximum number of people (including the owner) in one household.
+pub const MAX_HOUSEHOLD_MEMBERS: usize = 10;
+
+/// What someone may do in a shared collection. Variants are ordered from least to most access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum HouseholdRole {
+    /// Can see plants, journals, and climate data, but not change anything.
+    Viewer,
+    /// Can water, log care, and add or edit plants.
+    Caretaker,
+    /// Owns the collection: manages zones, devices, preferences, and members.
+    Owner,
+}
+
+impl HouseholdRole {
+    /// Roles that can be handed out through an invite link.
+    pub const INVITABLE: [HouseholdRole; 2] = [HouseholdRole::Caretaker, HouseholdRole::Viewer];
+
+    /// Stable key stored in `household_member.role`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HouseholdRole::Viewer => "viewer",
+            HouseholdRole::Caretaker => "caretaker",
+            HouseholdRole::Owner => "owner",
+        }
+    }
+
+    /// Parses a stored role key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "viewer" => Some(HouseholdRole::Viewer),
+            "caretaker" => Some(HouseholdRole::Caretaker),
+            "owner" => Some(HouseholdRole::Owner),
+            _ => None,
+        }
+    }
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            HouseholdRole::Viewer => "Viewer",
+            HouseholdRole::Caretaker => "Caretaker",
+            HouseholdRole::Owner => "Owner",
+        }
+    }
+
+    /// One-line summary of what the role can do.
+    pub fn description(&self) -> &'static str {
+        match self {
+            HouseholdRole::Viewer => "Can look, but not change anything",
+            HouseholdRole::Caretaker => "Can water, log care, and edit plants",
+            HouseholdRole::Owner => "Full control, including zones and members",
+        }
+    }
+
+    /// Whether this role grants at least `required` access.
+    pub fn allows(&self, required: HouseholdRole) -> bool {
+        *self >= required
+    }
+}
+
+/// One person in a household.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdMember {
+    /// The member's user record ID.
+    pub user_id: String,
+    /// The member's username.
+    pub username: String,
+    /// The member's role.
+    pub role: HouseholdRole,
+    /// When they joined (None for the owner).
+    pub joined_at: Option<DateTime<Utc>>,
+}
+
+/// An outstanding invite link. The token itself is only shown once, when created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdInvite {
+    /// The invite record ID.
+    pub id: String,
+    /// The role the invitee will get.
+    pub role: HouseholdRole,
+    /// When the link stops working.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The household the signed-in user belongs to, as shown in settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseholdInfo {
+    /// The household record ID.
+    pub id: String,
+    /// The household's display name.
+    pub name: String,
+    /// The signed-in user's role.
+    pub my_role: HouseholdRole,
+    /// Everyone in the household, owner first.
+    pub members: Vec<HouseholdMember>,
+    /// Outstanding invites (only filled in for the owner).
+    pub invites: Vec<HouseholdInvite>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_ordering() {
+        assert!(HouseholdRole::Owner.allows(HouseholdRole::Caretaker));
+        assert!(HouseholdRole::Caretaker.allows(HouseholdRole::Caretaker));
+        assert!(HouseholdRole::Caretaker.allows(HouseholdRole::Viewer));
+        assert!(!HouseholdRole::Viewer.allows(HouseholdRole::Caretaker));
+        assert!(!HouseholdRole::Caretaker.allows(HouseholdRole::Owner));
+    }
+
+    #[test]
+    fn test_role_key_roundtrip() {
+        for role in [HouseholdRole::Viewer, HouseholdRole::Caretaker, HouseholdRole::Owner] {
+            assert_eq!(HouseholdRole::from_key(role.as_str()), Some(role));
+        }
+        assert_eq!(HouseholdRole::from_key("admin"), None);
+    }
+
+    #[test]
+    fn test_owner_is_not_invitable() {
+        assert!(!HouseholdRole::INVITABLE.contains(&HouseholdRole::Owner));
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use crate::care_tag::CareTag;
+use crate::taxonomy::normalize;
+
+/// Most candidate species offered for one photo.
+pub const MAX_SPECIES_CANDIDATES: usize = 5;
+
+/// One possible identification of a scanned plant.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesCandidate {
+    /// Full botanical name, or the grex for a hybrid.
+    pub species_name: String,
+    /// How likely this identification is, from 0 to 1.
+    pub confidence: f64,
+    /// What in the photo (tag text, flower shape, leaves) points to it.
+    #[serde(default)]
+    pub reason: String,
+    /// A reference photo of the species to compare against, when one could be found.
+    #[serde(default)]
+    pub reference_image: Option<String>,
+    /// Whether a plant of this species is already in the collection.
+    #[serde(default)]
+    pub already_owned: bool,
+}
+
+/// What a scan found: the candidate species and, when the photo shows a grower's tag or care card,
+/// the care instructions printed on it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanIdentification {
+    /// Possible species, most likely first.
+    #[serde(default)]
+    pub candidates: Vec<SpeciesCandidate>,
+    /// Care instructions read off a tag, if the tag had any.
+    #[serde(default)]
+    pub care_tag: Option<CareTag>,
+}
+
+impl SpeciesCandidate {
+    /// Confidence as a whole percentage, e.g. "72%".
+    pub fn confidence_label(&self) -> String {
+        format!("{:.0}%", self.confidence * 100.0)
+    }
+}
+
+/// Tidies a model's candidate list: blank and repeated names dropped (the first spelling wins),
+/// confidences as 0-1 (percentages are rescaled), most likely first, and ownership marked
+/// against `existing_species`.
+pub fn rank_candidates(mut candidates: Vec<SpeciesCandidate>, existing_species: &[String]) -> Vec<SpeciesCandidate> {
+    let owned: Vec<String> = existing_species.iter().map(|s| normalize(s)).collect();
+    let mut seen = Vec::new();
+    candidates.retain(|c| {
+        let key = normalize(&c.species_name);
+        if key.is_empty() || seen.contains(&key) {
+            return false;
+        }
+        seen.push(key);
+        true
+    });
+    for c in &mut candidates {
+        c.species_name = c.species_name.trim().to_string();
+        if c.confidence > 1.0 {
+            c.confidence /= 100.0;
+        }
+        c.confidence = if c.confidence.is_finite() { c.confidence.clamp(0.0, 1.0) } else { 0.0 };
+        c.already_owned = owned.contains(&normalize(&c.species_name));
+    }
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    candidates.truncate(MAX_SPECIES_CANDIDATES);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(name: &str, confidence: f64) -> SpeciesCandidate {
+        SpeciesCandidate { species_name: name.into(), confidence, reason: String::new(), reference_image: None, already_owned: false }
+    }
+
+    #[test]
+    fn test_rank_candidates() {
+        let ranked = rank_candidates(
+            vec![
+                candidate("Phalaenopsis amabilis", 0.2),
+                candidate("Phalaenopsis bellina", 65.0),
+                candidate(" ", 0.9),
+                candidate("phalaenopsis  Bellina", 0.8),
+                candidate("Dendrobium kingianum", f64::NAN),
+            ],
+            &["Phalaenopsis amabilis".to_string()],
+        );
+        let names: Vec<_> = ranked.iter().map(|c| c.species_name.as_str()).collect();
+        assert_eq!(names, vec!["Phalaenopsis bellina", "Phalaenopsis amabilis", "Dendrobium kingianum"]);
+        assert_eq!(ranked[0].confidence_label(), "65%");
+        assert!(ranked[1].already_owned);
+        assert!(!ranked[0].already_owned);
+    }
+}
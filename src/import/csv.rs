@@ -0,0 +1,113 @@
+//! A small RFC 4180 CSV reader that compiles for both the server and WASM client.
+
+/// Parse CSV text into a header row and data rows.
+///
+/// Handles quoted fields, escaped quotes (`""`), embedded newlines, CRLF line
+/// endings, a leading UTF-8 BOM, and `;` or tab delimiters (detected from the
+/// header line). Blank lines are skipped. Short rows are padded to the header width.
+pub fn parse_csv(text: &str) -> Result<(Vec<String>, Vec<Vec<String>>), String> {
+    let text = text.strip_prefix('\u{FEFF}').unwrap_or(text);
+    let delimiter = detect_delimiter(text);
+
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                push_record(&mut records, std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("CSV has an unterminated quoted field".into());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        push_record(&mut records, record);
+    }
+
+    let mut iter = records.into_iter();
+    let headers: Vec<String> = iter
+        .next()
+        .ok_or("CSV file is empty")?
+        .into_iter()
+        .map(|h| h.trim().to_string())
+        .collect();
+    let width = headers.len();
+    let rows = iter
+        .map(|mut r| {
+            r.resize(width.max(r.len()), String::new());
+            r
+        })
+        .collect();
+    Ok((headers, rows))
+}
+
+fn push_record(records: &mut Vec<Vec<String>>, record: Vec<String>) {
+    if record.iter().any(|f| !f.trim().is_empty()) {
+        records.push(record);
+    }
+}
+
+fn detect_delimiter(text: &str) -> char {
+    let first_line = text.lines().next().unwrap_or("");
+    [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| first_line.matches(*d).count())
+        .filter(|d| first_line.contains(*d))
+        .unwrap_or(',')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple() {
+        let (h, rows) = parse_csv("a,b\n1,2\n3,4\n").unwrap();
+        assert_eq!(h, vec!["a", "b"]);
+        assert_eq!(rows, vec![vec!["1", "2"], vec!["3", "4"]]);
+    }
+
+    #[test]
+    fn test_quotes_and_newlines() {
+        let (_, rows) = parse_csv("name,notes\r\n\"Phal, white\",\"said \"\"hi\"\"\nline2\"\r\n").unwrap();
+        assert_eq!(rows[0][0], "Phal, white");
+        assert_eq!(rows[0][1], "said \"hi\"\nline2");
+    }
+
+    #[test]
+    fn test_bom_semicolon_and_padding() {
+        let (h, rows) = parse_csv("\u{FEFF}a;b;c\n1;2\n\n").unwrap();
+        assert_eq!(h, vec!["a", "b", "c"]);
+        assert_eq!(rows, vec![vec!["1", "2", ""]]);
+    }
+
+    #[test]
+    fn test_unterminated_quote() {
+        assert!(parse_csv("a\n\"oops").is_err());
+        assert!(parse_csv("").is_err());
+    }
+}
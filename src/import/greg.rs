@@ -0,0 +1,64 @@
+//! Greg's CSV export: a history log with one row per completed care task.
+
+use super::MappedField;
+
+/// Header names Greg uses for each field (lowercased).
+pub(super) fn aliases(field: MappedField) -> &'static [&'static str] {
+    match field {
+        MappedField::Name => &["plant nickname", "plant", "nickname"],
+        MappedField::Species => &["plant species", "species name"],
+        MappedField::Location => &["room", "room name"],
+        MappedField::WaterInterval => &["water every (days)", "watering frequency (days)", "water every"],
+        MappedField::LastWatered => &["last watered"],
+        MappedField::FertilizeInterval => &["fertilize every (days)"],
+        MappedField::LastFertilized => &["last fertilized"],
+        MappedField::LastRepotted => &["last repotted"],
+        MappedField::Notes => &["plant notes"],
+        MappedField::EventDate => &["completed at", "date completed", "date"],
+        MappedField::EventType => &["task", "task type", "event"],
+        MappedField::EventNote => &["note", "notes", "task note"],
+    }
+}
+
+/// True when the headers carry Greg's distinctive column names.
+pub(super) fn looks_like(headers: &[String]) -> bool {
+    let has = |name: &str| headers.iter().any(|h| h.trim().eq_ignore_ascii_case(name));
+    has("plant nickname") || (has("task") && has("completed at"))
+}
+
+/// Maps Greg's task names (lowercased) to built-in event types.
+pub(super) fn event_type(raw: &str) -> Option<&'static str> {
+    match raw {
+        "water" | "watered" | "bottom water" => Some("Watered"),
+        "fertilize" | "fertilized" => Some("Fertilized"),
+        "repot" | "repotted" => Some("Repotted"),
+        "new leaf" | "new growth" => Some("NewGrowth"),
+        "bloom" | "blooming" => Some("Flowering"),
+        "adopted" | "added" => Some("Purchased"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{convert, ColumnMapping, ImportSource};
+
+    #[test]
+    fn test_detect_and_convert_greg_history() {
+        let text = "Plant nickname,Plant species,Task,Completed at,Note\n\
+                    Oncy,Oncidium,Water,2024-03-01 09:00:00,\n\
+                    Oncy,Oncidium,Water,2024-03-08 09:00:00,\n\
+                    Oncy,Oncidium,New leaf,2024-03-10 09:00:00,second one this year\n";
+        let (headers, rows) = super::super::csv::parse_csv(text).unwrap();
+        assert_eq!(ImportSource::detect(&headers), ImportSource::Greg);
+        let mapping = ColumnMapping::guess(&headers, ImportSource::Greg);
+        let preview = convert(ImportSource::Greg, &rows, &mapping).unwrap();
+        assert_eq!(preview.plants.len(), 1);
+        let plant = &preview.plants[0];
+        assert_eq!(plant.species, "Oncidium");
+        assert_eq!(plant.events.len(), 3);
+        assert_eq!(plant.events[2].event_type, "NewGrowth");
+        assert_eq!(plant.events[2].note, "second one this year");
+        assert_eq!(plant.last_watered_at, Some(plant.events[1].timestamp));
+    }
+}
@@ -0,0 +1,558 @@
+//! **What is it?**
+//! The import subsystem: reads plant-app exports (Planta, Greg, or any CSV) and converts them into orchids and care history.
+//!
+//! **Why does it exist?**
+//! It exists so growers switching from another app keep their watering schedules and history instead of starting from zero.
+//!
+//! **How should it be used?**
+//! Parse the file with `csv::parse_csv`, pick an `ImportSource` (or `ImportSource::detect`), start from `ColumnMapping::guess`, let the user adjust the mapping, then call `convert` to preview. The `import_plants` server function runs the same conversion and writes the result.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// CSV parsing shared by the browser preview and the server import.
+pub mod csv;
+/// Column names and action vocabulary for Planta exports.
+pub mod planta;
+/// Column names and action vocabulary for Greg exports.
+pub mod greg;
+
+/// Maximum number of plants accepted in one import.
+pub const MAX_IMPORT_PLANTS: usize = 1000;
+/// Maximum number of history events accepted in one import.
+pub const MAX_IMPORT_EVENTS: usize = 20_000;
+/// Watering interval used when the export doesn't have one.
+pub const DEFAULT_WATER_DAYS: u32 = 7;
+
+/// Which app produced the file being imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportSource {
+    /// Planta CSV export.
+    Planta,
+    /// Greg CSV export.
+    Greg,
+    /// Any other CSV, mapped by the user.
+    Generic,
+}
+
+impl ImportSource {
+    /// All sources, in picker order.
+    pub const ALL: [ImportSource; 3] = [ImportSource::Planta, ImportSource::Greg, ImportSource::Generic];
+
+    /// Display label.
+    pub fn label(self) -> &'static str {
+        match self {
+            ImportSource::Planta => "Planta",
+            ImportSource::Greg => "Greg",
+            ImportSource::Generic => "Other CSV",
+        }
+    }
+
+    /// Stable code for forms and server calls.
+    pub fn code(self) -> &'static str {
+        match self {
+            ImportSource::Planta => "planta",
+            ImportSource::Greg => "greg",
+            ImportSource::Generic => "generic",
+        }
+    }
+
+    /// Parses a code produced by `code()`, defaulting to `Generic`.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "planta" => ImportSource::Planta,
+            "greg" => ImportSource::Greg,
+            _ => ImportSource::Generic,
+        }
+    }
+
+    /// Guesses the source from a file's header row.
+    pub fn detect(headers: &[String]) -> Self {
+        if planta::looks_like(headers) {
+            ImportSource::Planta
+        } else if greg::looks_like(headers) {
+            ImportSource::Greg
+        } else {
+            ImportSource::Generic
+        }
+    }
+
+    fn aliases(self, field: MappedField) -> &'static [&'static str] {
+        match self {
+            ImportSource::Planta => planta::aliases(field),
+            ImportSource::Greg => greg::aliases(field),
+            ImportSource::Generic => generic_aliases(field),
+        }
+    }
+
+    /// Maps the source app's action name to a built-in event type key.
+    fn event_type(self, raw: &str) -> Option<&'static str> {
+        let raw = raw.trim().to_lowercase();
+        let specific = match self {
+            ImportSource::Planta => planta::event_type(&raw),
+            ImportSource::Greg => greg::event_type(&raw),
+            ImportSource::Generic => None,
+        };
+        specific.or_else(|| generic_event_type(&raw))
+    }
+}
+
+/// A target field that a CSV column can be mapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MappedField {
+    /// Plant nickname (required).
+    Name,
+    /// Species or scientific name.
+    Species,
+    /// Room, site, or location.
+    Location,
+    /// Watering interval (days, or "2 weeks").
+    WaterInterval,
+    /// Date last watered.
+    LastWatered,
+    /// Fertilizing interval.
+    FertilizeInterval,
+    /// Date last fertilized.
+    LastFertilized,
+    /// Date last repotted.
+    LastRepotted,
+    /// Free-text notes.
+    Notes,
+    /// Date of a history entry (one row per care action).
+    EventDate,
+    /// Kind of history entry (e.g. "Watering").
+    EventType,
+    /// Note attached to a history entry.
+    EventNote,
+}
+
+impl MappedField {
+    /// All mappable fields, in editor order.
+    pub const ALL: [MappedField; 12] = [
+        MappedField::Name,
+        MappedField::Species,
+        MappedField::Location,
+        MappedField::WaterInterval,
+        MappedField::LastWatered,
+        MappedField::FertilizeInterval,
+        MappedField::LastFertilized,
+        MappedField::LastRepotted,
+        MappedField::Notes,
+        MappedField::EventDate,
+        MappedField::EventType,
+        MappedField::EventNote,
+    ];
+
+    /// Display label.
+    pub fn label(self) -> &'static str {
+        match self {
+            MappedField::Name => "Plant name",
+            MappedField::Species => "Species",
+            MappedField::Location => "Location",
+            MappedField::WaterInterval => "Water every",
+            MappedField::LastWatered => "Last watered",
+            MappedField::FertilizeInterval => "Fertilize every",
+            MappedField::LastFertilized => "Last fertilized",
+            MappedField::LastRepotted => "Last repotted",
+            MappedField::Notes => "Notes",
+            MappedField::EventDate => "History: date",
+            MappedField::EventType => "History: action",
+            MappedField::EventNote => "History: note",
+        }
+    }
+}
+
+fn generic_aliases(field: MappedField) -> &'static [&'static str] {
+    match field {
+        MappedField::Name => &["name", "plant", "plant name", "nickname"],
+        MappedField::Species => &["species", "scientific name", "latin name", "botanical name", "type"],
+        MappedField::Location => &["location", "room", "placement", "site", "zone"],
+        MappedField::WaterInterval => &["water every", "watering interval", "watering frequency", "water frequency", "water frequency (days)"],
+        MappedField::LastWatered => &["last watered", "last watering", "watered"],
+        MappedField::FertilizeInterval => &["fertilize every", "fertilizing interval", "fertilizer frequency", "fertilizing frequency"],
+        MappedField::LastFertilized => &["last fertilized", "last fertilizing", "fertilized"],
+        MappedField::LastRepotted => &["last repotted", "repotted", "last repotting"],
+        MappedField::Notes => &["notes", "note", "comments", "description"],
+        MappedField::EventDate => &["date", "event date", "completed", "completed at", "done at"],
+        MappedField::EventType => &["action", "event", "task", "activity", "event type"],
+        MappedField::EventNote => &["event note", "action note", "task note"],
+    }
+}
+
+fn generic_event_type(raw: &str) -> Option<&'static str> {
+    if raw.contains("water") || raw.contains("mist") || raw.contains("soak") {
+        Some("Watered")
+    } else if raw.contains("fertili") || raw.contains("feed") {
+        Some("Fertilized")
+    } else if raw.contains("repot") || raw.contains("pot") {
+        Some("Repotted")
+    } else if raw.contains("bloom") || raw.contains("flower") {
+        Some("Flowering")
+    } else if raw.contains("pest") || raw.contains("spray") || raw.contains("treat") {
+        Some("PestTreatment")
+    } else if raw.contains("leaf") || raw.contains("growth") || raw.contains("root") {
+        Some("NewGrowth")
+    } else if raw.contains("bought") || raw.contains("purchase") || raw.contains("acquired") {
+        Some("Purchased")
+    } else if raw.contains("note") || raw.contains("photo") {
+        Some("Note")
+    } else {
+        None
+    }
+}
+
+/// Which CSV column (by index) feeds each target field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnMapping {
+    /// Column for each `MappedField`, in `MappedField::ALL` order.
+    pub columns: Vec<Option<usize>>,
+}
+
+impl ColumnMapping {
+    /// Column currently mapped to `field`.
+    pub fn get(&self, field: MappedField) -> Option<usize> {
+        let idx = MappedField::ALL.iter().position(|f| *f == field)?;
+        self.columns.get(idx).copied().flatten()
+    }
+
+    /// Maps `field` to a column (or unmaps it with `None`).
+    pub fn set(&mut self, field: MappedField, column: Option<usize>) {
+        if let Some(idx) = MappedField::ALL.iter().position(|f| *f == field) {
+            self.columns.resize(MappedField::ALL.len(), None);
+            self.columns[idx] = column;
+        }
+    }
+
+    /// Builds a starting mapping by matching header names against the source's known column names,
+    /// falling back to common generic names for anything the source doesn't cover.
+    pub fn guess(headers: &[String], source: ImportSource) -> Self {
+        let normalized: Vec<String> = headers.iter().map(|h| h.trim().to_lowercase()).collect();
+        let mut mapping = ColumnMapping::default();
+        let mut taken = vec![false; headers.len()];
+        for pass in [source, ImportSource::Generic] {
+            for field in MappedField::ALL {
+                if mapping.get(field).is_some() {
+                    continue;
+                }
+                let found = pass.aliases(field).iter().find_map(|alias| {
+                    (0..normalized.len()).find(|&i| !taken[i] && normalized[i] == *alias)
+                });
+                if let Some(i) = found {
+                    taken[i] = true;
+                    mapping.set(field, Some(i));
+                }
+            }
+        }
+        mapping
+    }
+}
+
+/// One care action from an imported history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types"))]
+pub struct ImportedEvent {
+    /// Built-in event type key.
+    pub event_type: String,
+    /// When the action happened.
+    pub timestamp: DateTime<Utc>,
+    /// Note text (includes the original action name if it had no direct equivalent).
+    pub note: String,
+}
+
+/// A plant converted from an export, ready to be created as an orchid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportedPlant {
+    /// Plant nickname.
+    pub name: String,
+    /// Species; "Unknown" when the export had none.
+    pub species: String,
+    /// Location/room name, used as the placement.
+    pub placement: Option<String>,
+    /// Watering interval in days.
+    pub water_frequency_days: u32,
+    /// Fertilizing interval in days.
+    pub fertilize_frequency_days: Option<u32>,
+    /// Free-text notes.
+    pub notes: String,
+    /// Most recent watering.
+    pub last_watered_at: Option<DateTime<Utc>>,
+    /// Most recent fertilizing.
+    pub last_fertilized_at: Option<DateTime<Utc>>,
+    /// Most recent repotting.
+    pub last_repotted_at: Option<DateTime<Utc>>,
+    /// Care history, oldest first.
+    pub events: Vec<ImportedEvent>,
+}
+
+/// The result of converting a file: plants plus any rows that were skipped or adjusted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportPreview {
+    /// Converted plants.
+    pub plants: Vec<ImportedPlant>,
+    /// Human-readable notes about skipped or adjusted rows.
+    pub warnings: Vec<String>,
+}
+
+impl ImportPreview {
+    /// Total number of history events across all plants.
+    pub fn event_count(&self) -> usize {
+        self.plants.iter().map(|p| p.events.len()).sum()
+    }
+}
+
+/// Parses intervals like "7", "7 days", "every 2 weeks", "1 month" into days.
+pub fn parse_interval_days(raw: &str) -> Option<u32> {
+    let lower = raw.trim().to_lowercase();
+    let number: String = lower
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let n: f64 = number.parse().ok()?;
+    let factor = if lower.contains("week") {
+        7.0
+    } else if lower.contains("month") {
+        30.0
+    } else {
+        1.0
+    };
+    let days = (n * factor).round();
+    (1.0..=365.0).contains(&days).then_some(days as u32)
+}
+
+/// Parses the date formats common in plant-app exports.
+pub fn parse_date(raw: &str) -> Option<DateTime<Utc>> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return None;
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(raw) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    for fmt in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%m/%d/%Y %H:%M", "%d.%m.%Y %H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(raw, fmt) {
+            return Some(dt.and_utc());
+        }
+    }
+    for fmt in ["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y", "%d.%m.%Y", "%b %d, %Y", "%d %b %Y"] {
+        if let Ok(d) = NaiveDate::parse_from_str(raw, fmt) {
+            return d.and_hms_opt(12, 0, 0).map(|dt| dt.and_utc());
+        }
+    }
+    None
+}
+
+fn cell(row: &[String], col: Option<usize>) -> &str {
+    col.and_then(|c| row.get(c)).map(|s| s.trim()).unwrap_or("")
+}
+
+fn latest(a: Option<DateTime<Utc>>, b: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+    match (a, b) {
+        (Some(x), Some(y)) => Some(x.max(y)),
+        (x, y) => x.or(y),
+    }
+}
+
+/// **What is it?**
+/// Converts parsed CSV rows into plants and care history using a column mapping.
+///
+/// **Why does it exist?**
+/// It exists as the single, pure conversion shared by the client preview and the server import, so what the user sees is exactly what gets written.
+///
+/// **How should it be used?**
+/// Rows sharing a plant name are merged: the first non-empty value wins for plant fields, and rows with a history date become events. Last-care dates take the latest of the dedicated column and the history.
+pub fn convert(
+    source: ImportSource,
+    rows: &[Vec<String>],
+    mapping: &ColumnMapping,
+) -> Result<ImportPreview, String> {
+    let name_col = mapping.get(MappedField::Name).ok_or("Choose which column holds the plant name")?;
+    let col = |f| mapping.get(f);
+
+    let mut preview = ImportPreview::default();
+    for (line, row) in rows.iter().enumerate() {
+        let line = line + 2; // 1-based, after the header
+        let name: String = cell(row, Some(name_col)).chars().take(200).collect();
+        if name.is_empty() {
+            preview.warnings.push(format!("Row {}: no plant name, skipped", line));
+            continue;
+        }
+
+        let idx = match preview.plants.iter().position(|p| p.name.eq_ignore_ascii_case(&name)) {
+            Some(i) => i,
+            None => {
+                if preview.plants.len() >= MAX_IMPORT_PLANTS {
+                    return Err(format!("Too many plants (max {})", MAX_IMPORT_PLANTS));
+                }
+                preview.plants.push(ImportedPlant {
+                    name: name.clone(),
+                    species: String::new(),
+                    placement: None,
+                    water_frequency_days: 0,
+                    fertilize_frequency_days: None,
+                    notes: String::new(),
+                    last_watered_at: None,
+                    last_fertilized_at: None,
+                    last_repotted_at: None,
+                    events: Vec::new(),
+                });
+                preview.plants.len() - 1
+            }
+        };
+        let plant = &mut preview.plants[idx];
+
+        if plant.species.is_empty() {
+            plant.species = cell(row, col(MappedField::Species)).chars().take(200).collect();
+        }
+        if plant.placement.is_none() {
+            let loc: String = cell(row, col(MappedField::Location)).chars().take(100).collect();
+            plant.placement = (!loc.is_empty()).then_some(loc);
+        }
+        if plant.water_frequency_days == 0 {
+            let raw = cell(row, col(MappedField::WaterInterval));
+            match parse_interval_days(raw) {
+                Some(d) => plant.water_frequency_days = d,
+                None if !raw.is_empty() => preview.warnings.push(format!("Row {}: couldn't read watering interval '{}'", line, raw)),
+                None => {}
+            }
+        }
+        if plant.fertilize_frequency_days.is_none() {
+            plant.fertilize_frequency_days = parse_interval_days(cell(row, col(MappedField::FertilizeInterval)));
+        }
+        if plant.notes.is_empty() {
+            plant.notes = cell(row, col(MappedField::Notes)).chars().take(5000).collect();
+        }
+        plant.last_watered_at = latest(plant.last_watered_at, parse_date(cell(row, col(MappedField::LastWatered))));
+        plant.last_fertilized_at = latest(plant.last_fertilized_at, parse_date(cell(row, col(MappedField::LastFertilized))));
+        plant.last_repotted_at = latest(plant.last_repotted_at, parse_date(cell(row, col(MappedField::LastRepotted))));
+
+        let date_raw = cell(row, col(MappedField::EventDate));
+        if date_raw.is_empty() {
+            continue;
+        }
+        let Some(timestamp) = parse_date(date_raw) else {
+            preview.warnings.push(format!("Row {}: couldn't read date '{}', history entry skipped", line, date_raw));
+            continue;
+        };
+        let action = cell(row, col(MappedField::EventType));
+        let extra = cell(row, col(MappedField::EventNote));
+        let (event_type, note) = match source.event_type(action) {
+            Some(key) => (key, extra.to_string()),
+            None if action.is_empty() => ("Note", extra.to_string()),
+            None => ("Note", if extra.is_empty() { action.to_string() } else { format!("{}: {}", action, extra) }),
+        };
+        match event_type {
+            "Watered" => plant.last_watered_at = latest(plant.last_watered_at, Some(timestamp)),
+            "Fertilized" => plant.last_fertilized_at = latest(plant.last_fertilized_at, Some(timestamp)),
+            "Repotted" => plant.last_repotted_at = latest(plant.last_repotted_at, Some(timestamp)),
+            _ => {}
+        }
+        plant.events.push(ImportedEvent {
+            event_type: event_type.to_string(),
+            timestamp,
+            note: note.chars().take(5000).collect(),
+        });
+    }
+
+    for plant in &mut preview.plants {
+        if plant.species.is_empty() {
+            plant.species = "Unknown".into();
+        }
+        if plant.water_frequency_days == 0 {
+            plant.water_frequency_days = DEFAULT_WATER_DAYS;
+        }
+        plant.events.sort_by_key(|e| e.timestamp);
+    }
+
+    if preview.event_count() > MAX_IMPORT_EVENTS {
+        return Err(format!("Too many history entries (max {})", MAX_IMPORT_EVENTS));
+    }
+    Ok(preview)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(h: &[&str]) -> Vec<String> {
+        h.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_interval_days() {
+        assert_eq!(parse_interval_days("7"), Some(7));
+        assert_eq!(parse_interval_days("every 10 days"), Some(10));
+        assert_eq!(parse_interval_days("2 weeks"), Some(14));
+        assert_eq!(parse_interval_days("1 month"), Some(30));
+        assert_eq!(parse_interval_days("soon"), None);
+        assert_eq!(parse_interval_days("0"), None);
+    }
+
+    #[test]
+    fn test_parse_date_formats() {
+        assert!(parse_date("2024-03-05").is_some());
+        assert!(parse_date("2024-03-05 08:30:00").is_some());
+        assert!(parse_date("2024-03-05T08:30:00Z").is_some());
+        assert!(parse_date("03/05/2024").is_some());
+        assert!(parse_date("05.03.2024").is_some());
+        assert!(parse_date("yesterday").is_none());
+    }
+
+    #[test]
+    fn test_guess_generic_mapping() {
+        let h = headers(&["Name", "Species", "Room", "Water every", "Notes"]);
+        let m = ColumnMapping::guess(&h, ImportSource::Generic);
+        assert_eq!(m.get(MappedField::Name), Some(0));
+        assert_eq!(m.get(MappedField::Species), Some(1));
+        assert_eq!(m.get(MappedField::Location), Some(2));
+        assert_eq!(m.get(MappedField::WaterInterval), Some(3));
+        assert_eq!(m.get(MappedField::EventDate), None);
+    }
+
+    #[test]
+    fn test_convert_merges_history_rows() {
+        let h = headers(&["Name", "Species", "Water every", "Date", "Action"]);
+        let m = ColumnMapping::guess(&h, ImportSource::Generic);
+        let rows = vec![
+            row(&["Phal", "Phalaenopsis", "7 days", "2024-01-01", "Watering"]),
+            row(&["phal", "", "", "2024-01-08", "Fertilizing"]),
+            row(&["Phal", "", "", "2024-01-09", "Took a photo"]),
+            row(&["Catt", "", "", "", ""]),
+            row(&["", "", "", "", ""]),
+        ];
+        let preview = convert(ImportSource::Generic, &rows, &m).unwrap();
+        assert_eq!(preview.plants.len(), 2);
+        let phal = &preview.plants[0];
+        assert_eq!(phal.water_frequency_days, 7);
+        assert_eq!(phal.events.len(), 3);
+        assert_eq!(phal.events[0].event_type, "Watered");
+        assert_eq!(phal.events[1].event_type, "Fertilized");
+        assert_eq!(phal.events[2].event_type, "Note");
+        assert!(phal.last_watered_at.is_some());
+        assert!(phal.last_fertilized_at.is_some());
+        let catt = &preview.plants[1];
+        assert_eq!(catt.species, "Unknown");
+        assert_eq!(catt.water_frequency_days, DEFAULT_WATER_DAYS);
+        assert_eq!(preview.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_convert_requires_name_column() {
+        assert!(convert(ImportSource::Generic, &[], &ColumnMapping::default()).is_err());
+    }
+
+    #[test]
+    fn test_unknown_action_kept_as_note() {
+        let h = headers(&["Name", "Date", "Action", "Event note"]);
+        let m = ColumnMapping::guess(&h, ImportSource::Generic);
+        let rows = vec![row(&["Phal", "2024-01-01", "Rotated", "quarter turn"])];
+        let preview = convert(ImportSource::Generic, &rows, &m).unwrap();
+        assert_eq!(preview.plants[0].events[0].event_type, "Note");
+        assert_eq!(preview.plants[0].events[0].note, "Rotated: quarter turn");
+    }
+}
@@ -0,0 +1,59 @@
+//! Planta's CSV export: one row per plant, with its site, care intervals and last-care dates.
+
+use super::MappedField;
+
+/// Header names Planta uses for each field (lowercased).
+pub(super) fn aliases(field: MappedField) -> &'static [&'static str] {
+    match field {
+        MappedField::Name => &["plant name", "nickname", "name"],
+        MappedField::Species => &["scientific name", "plant type", "variety"],
+        MappedField::Location => &["site", "site name", "room"],
+        MappedField::WaterInterval => &["watering interval", "watering interval (days)", "water interval"],
+        MappedField::LastWatered => &["last watering", "last watered"],
+        MappedField::FertilizeInterval => &["fertilizing interval", "fertilizing interval (days)"],
+        MappedField::LastFertilized => &["last fertilizing", "last fertilized"],
+        MappedField::LastRepotted => &["last repotting", "last repotted"],
+        MappedField::Notes => &["notes", "plant notes"],
+        MappedField::EventDate => &["completed", "completed date", "action date"],
+        MappedField::EventType => &["action type", "action"],
+        MappedField::EventNote => &["action note", "comment"],
+    }
+}
+
+/// True when the headers carry Planta's distinctive column names.
+pub(super) fn looks_like(headers: &[String]) -> bool {
+    let has = |name: &str| headers.iter().any(|h| h.trim().eq_ignore_ascii_case(name));
+    has("site") || has("site name") || (has("last watering") && has("last fertilizing"))
+}
+
+/// Maps Planta's action names (lowercased) to built-in event types.
+pub(super) fn event_type(raw: &str) -> Option<&'static str> {
+    match raw {
+        "watering" | "misting" => Some("Watered"),
+        "fertilizing" => Some("Fertilized"),
+        "repotting" => Some("Repotted"),
+        "cleaning" | "pruning" => Some("Note"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{convert, ColumnMapping, ImportSource, MappedField};
+
+    #[test]
+    fn test_detect_and_convert_planta_export() {
+        let text = "Plant name,Scientific name,Site,Watering interval,Last watering,Last fertilizing\n\
+                    Kitchen Phal,Phalaenopsis amabilis,Kitchen window,10,2024-02-01,2024-01-15\n";
+        let (headers, rows) = super::super::csv::parse_csv(text).unwrap();
+        assert_eq!(ImportSource::detect(&headers), ImportSource::Planta);
+        let mapping = ColumnMapping::guess(&headers, ImportSource::Planta);
+        assert_eq!(mapping.get(MappedField::Location), Some(2));
+        let preview = convert(ImportSource::Planta, &rows, &mapping).unwrap();
+        let plant = &preview.plants[0];
+        assert_eq!(plant.name, "Kitchen Phal");
+        assert_eq!(plant.placement.as_deref(), Some("Kitchen window"));
+        assert_eq!(plant.water_frequency_days, 10);
+        assert!(plant.last_fertilized_at.is_some());
+    }
+}
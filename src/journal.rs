@@ -0,0 +1,124 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::orchid::LogEntry;
+
+/// Entries loaded per page of a plant's timeline.
+pub const JOURNAL_PAGE_SIZE: u32 = 50;
+/// Most entries one page request may ask for.
+pub const MAX_JOURNAL_PAGE_SIZE: u32 = 200;
+/// Longest keyword search accepted.
+pub const MAX_JOURNAL_SEARCH_LEN: usize = 100;
+
+/// What is it? The filters narrowing a plant's journal: one event type, a date range, and a keyword.
+/// Why does it exist? Plants with years of history have timelines too long to scroll; these narrow it to the entries the grower is looking for.
+/// How should it be used? Send it to `server_fns::orchids::get_log_entries_page`, or call `page` on entries already loaded (the public view). Dates are whole UTC days, both ends included.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct JournalQuery {
+    /// Only entries with this event type key.
+    #[serde(default)]
+    pub event_type: Option<String>,
+    /// Only entries on or after this day.
+    #[serde(default)]
+    pub from: Option<NaiveDate>,
+    /// Only entries on or before this day.
+    #[serde(default)]
+    pub to: Option<NaiveDate>,
+    /// Only entries whose note contains this text, ignoring case. Empty matches everything.
+    #[serde(default)]
+    pub text: String,
+}
+
+impl JournalQuery {
+    /// Whether no filter is set.
+    pub fn is_empty(&self) -> bool {
+        self.event_type.is_none() && self.from.is_none() && self.to.is_none() && self.text.trim().is_empty()
+    }
+
+    /// The keyword as matched: trimmed and lowercased.
+    pub fn needle(&self) -> String {
+        self.text.trim().to_lowercase()
+    }
+
+    /// Whether `entry` passes every filter.
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        let day = entry.timestamp.date_naive();
+        let needle = self.needle();
+        self.event_type.as_ref().is_none_or(|k| entry.event_type.as_ref() == Some(k))
+            && self.from.is_none_or(|from| day >= from)
+            && self.to.is_none_or(|to| day <= to)
+            && (needle.is_empty() || entry.note.to_lowercase().contains(&needle))
+    }
+
+    /// One page of the matching entries, for journals already loaded in full. Keeps the order of `entries`.
+    pub fn page(&self, entries: &[LogEntry], offset: u32, limit: u32) -> JournalPage {
+        let matching: Vec<&LogEntry> = entries.iter().filter(|e| self.matches(e)).collect();
+        JournalPage {
+            total: matching.len() as u32,
+            entries: matching.into_iter().skip(offset as usize).take(limit as usize).cloned().collect(),
+        }
+    }
+}
+
+/// A page of a plant's journal, newest first, with how many entries match in all.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct JournalPage {
+    /// The entries on this page.
+    pub entries: Vec<LogEntry>,
+    /// How many entries match the query across every page.
+    pub total: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn entry(day: u32, event_type: Option<&str>, note: &str) -> LogEntry {
+        LogEntry {
+            id: format!("log_entry:{}", day),
+            timestamp: Utc.with_ymd_and_hms(2024, 5, day, 18, 0, 0).unwrap(),
+            note: note.to_string(),
+            image_filename: None,
+            event_type: event_type.map(str::to_string),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
+        }
+    }
+
+    #[test]
+    fn test_filters_combine() {
+        let entries = vec![
+            entry(20, Some("Repotted"), "Moved to **bark** mix"),
+            entry(12, Some("Watered"), ""),
+            entry(5, Some("Repotted"), "Sphagnum, roots rotting"),
+            entry(1, None, "Bought at the BARK show"),
+        ];
+        let q = |event_type: Option<&str>, from: Option<u32>, to: Option<u32>, text: &str| JournalQuery {
+            event_type: event_type.map(str::to_string),
+            from: from.map(|d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap()),
+            to: to.map(|d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap()),
+            text: text.to_string(),
+        };
+        let ids = |query: JournalQuery| query.page(&entries, 0, 10).entries.into_iter().map(|e| e.id).collect::<Vec<_>>();
+
+        assert!(JournalQuery::default().is_empty());
+        assert_eq!(ids(JournalQuery::default()).len(), 4);
+        assert_eq!(ids(q(Some("Repotted"), None, None, "")), vec!["log_entry:20", "log_entry:5"]);
+        assert_eq!(ids(q(None, None, None, " bark ")), vec!["log_entry:20", "log_entry:1"]);
+        assert_eq!(ids(q(None, Some(5), Some(12), "")), vec!["log_entry:12", "log_entry:5"]);
+        assert_eq!(ids(q(Some("Repotted"), Some(6), None, "bark")), vec!["log_entry:20"]);
+    }
+
+    #[test]
+    fn test_page_counts_all_matches() {
+        let entries: Vec<LogEntry> = (1..=7).rev().map(|d| entry(d, None, "note")).collect();
+        let page = JournalQuery::default().page(&entries, 5, 5);
+        assert_eq!(page.total, 7);
+        assert_eq!(page.entries.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(), vec!["log_entry:2", "log_entry:1"]);
+    }
+}
@@ -7,6 +7,96 @@ pub mod app;
 #[allow(missing_docs)]
 pub mod components;
 
+/// What is it? Acquisition records: vendors, and the collection-value summary built from each plant's price, source and date.
+/// Why does it exist? Serious collectors track provenance and spend, and want to see where the money went.
+/// How should it be used? Manage vendors and load the summary through `server_fns::acquisition`; `collection_value` does the sums for the Stats tab.
+pub mod acquisition;
+
+/// What is it? Watering adherence: how many of each plant's recent waterings came on time versus late.
+/// Why does it exist? A plant that is watered a few days late every time never looks alarming on any one day; the pattern only shows over weeks.
+/// How should it be used? Call `collection_adherence` with the plants and their 'Watered'/'Skipped' journal events; show `on_time_percent` overall and per plant.
+pub mod adherence;
+
+/// What is it? Personal API token types: scopes, display summaries, and bearer header parsing.
+/// Why does it exist? To let scripts and integrations authenticate without a browser session, limited to the scopes their owner granted.
+/// How should it be used? Manage tokens through `server_fns::api_tokens`; HTTP handlers call `auth::authenticate_bearer` with the `ApiScope` they require.
+pub mod api_tokens;
+
+/// What is it? Awards and show results: AOS and other judging awards, and show ribbons, linked to the plant that won them.
+/// Why does it exist? Awarded plants are the crown jewels of a collection and deserve a structured record, not a line in the notes.
+/// How should it be used? Record and list awards through `server_fns::awards`; `award_badges` picks what each orchid card shows.
+pub mod award;
+
+/// What is it? Whole-account backups for the `export` and `import` CLI commands: a JSON document or per-table CSV files.
+/// Why does it exist? Self-hosters need headless backups from cron and a way to move a collection between instances without the browser.
+/// How should it be used? Write with `rows_to_csv` or a serialized `Backup`; restore the `RESTORE_TABLES` with `restore_columns` and `restore_query` against the live schema.
+pub mod backup;
+
+/// What is it? Bloom analytics: blooms derived from a plant's 'Flowering' journal entries, with their length, onset months and years.
+/// Why does it exist? The journal records each flowering as scattered entries; growers want to know how often and how long a plant blooms, and whether it's keeping to its season.
+/// How should it be used? Call `bloom_stats` with a plant's journal and draw the bloom history card from it.
+pub mod bloom;
+
+/// What is it? Environment blueprints: a shareable file of zones, devices, climate goals and automation rules.
+/// Why does it exist? To replicate a setup on a second instance or share a cabinet design with another grower, without plant data or credentials.
+/// How should it be used? Export and import through `server_fns::blueprint`; call `Blueprint::parse` on uploaded files before importing.
+pub mod blueprint;
+
+/// What is it? A small TTL cache for values server functions read on every page load.
+/// Why does it exist? The home page asks for zones, preferences and the latest reading per zone from several components at once; caching them per user or zone turns a dozen identical queries into one.
+/// How should it be used? Keep one `TtlCache` in a `LazyLock` static next to the read path it serves, and invalidate its key from every write to the rows behind it.
+pub mod cache;
+
+/// What is it? Care confidence: how complete a plant's profile is, and which missing fields matter most.
+/// Why does it exist? To show when alerts and schedules are running on defaults, and nudge growers toward the data that unlocks the smarter features.
+/// How should it be used? Call `care_confidence::assess` with a plant and its newest photo time, and show the score with the top `missing` prompts.
+pub mod care_confidence;
+
+/// What is it? The care timeline: each plant's watering windows, fertilizer due days and rest periods over the next month.
+/// Why does it exist? To give a planning view across the collection that the card grid and the Today list can't.
+/// How should it be used? Call `build_timeline` from the timeline tab with the zone-filtered plants and climate snapshots, then draw each row's `CareSpan`s.
+pub mod care_timeline;
+
+/// What is it? User-defined care automation rules ("when X happens, do Y") and their pure evaluation logic.
+/// Why does it exist? To let growers encode their own routines, such as extra misting during heat waves or pausing fertilizer during rest, on top of the built-in alerts.
+/// How should it be used? Edit `CareRule`s in settings; the climate poller and daily job evaluate them via `climate::care_rules`, and the UI calls `fertilizer_paused` for fertilizer reminders.
+pub mod care_rules;
+
+/// What is it? Care instructions read off a grower's plant tag or care card: temperatures, light, watering, humidity and bloom season.
+/// Why does it exist? Tags carry the grower's own advice for that plant, which should land in the add form's fields rather than be lost or pasted into notes.
+/// How should it be used? `server_fns::scanner::identify_orchid_candidates` returns a normalized `CareTag` when it reads one; call `apply_to` on the chosen species' `AnalysisResult` before prefilling the form.
+pub mod care_tag;
+
+/// What is it? The AI care assistant's chat messages and the pure helpers behind a grounded answer.
+/// Why does it exist? To pick which plants a question is about and turn earlier messages into a transcript, separately from the server function that calls the model.
+/// How should it be used? `server_fns::chat::send_chat_message` validates with `validate_question`, focuses on `plants_mentioned`, and sends `transcript` of recent history; the chat panel renders `ChatMessage`s.
+pub mod chat;
+
+/// What is it? Sorting and filtering for the collection, and the saved views that remember them.
+/// Why does it exist? The newest-first order is little help for triage; growers want the thirstiest plants, one genus or one zone at the top, and to get back to those lists with one tap.
+/// How should it be used? Call `arrange` on the loaded plants with the toolbar's sort and filter; persist `SavedView`s through `server_fns::preferences::save_saved_views`, which runs `sanitize_saved_views`.
+pub mod collection_view;
+
+/// What is it? Per-zone min/max/average temperature and humidity for the grower's local yesterday and today.
+/// Why does it exist? The latest reading hides the overnight low, which is what actually kills plants.
+/// How should it be used? Call `zone_extremes` with a zone's readings and the browser's UTC offset, or fetch them with `server_fns::climate::get_zone_daily_extremes`.
+pub mod daily_extremes;
+
+/// What is it? The sample collection the `seed` CLI command writes: zones, plants, a journal that follows their schedules, and synthetic climate readings.
+/// Why does it exist? Development, demos and screenshots need an account that looks lived-in without hand-entering weeks of history.
+/// How should it be used? Iterate `DEMO_ZONES` and `DEMO_ORCHIDS`, take entries from `demo_journal`, and call `demo_reading` for each reading time.
+pub mod demo_data;
+
+/// What is it? The result of an AI diagnosis of a struggling plant's photo: likely causes with confidence and suggested treatments.
+/// Why does it exist? To give the scanner's Diagnose mode a stable shape to show, and to tidy what the model returns before it reaches the screen.
+/// How should it be used? `server_fns::scanner::diagnose_plant_photo` returns a normalized `Diagnosis`; start a treatment from a cause with `SuggestedTreatment::series`.
+pub mod diagnosis;
+
+/// What is it? Merging a plant edit that lost a race with a save from another device.
+/// Why does it exist? `update_orchid` refuses edits made to an outdated copy instead of silently overwriting the other device's changes, and the user needs a way forward.
+/// How should it be used? When a save fails with `EDIT_CONFLICT`, load the latest copy and call `merge_edit` with the copy the edit started from; offer to save `MergedEdit::orchid` or keep `theirs`.
+pub mod edit_conflict;
+
 /// What is it? Application error types and handling.
 /// Why does it exist? To provide a centralized definition of all ways the application can fail, allowing for structured error reporting.
 /// How should it be used? Use the `AppError` enum throughout the codebase via `Result<T, AppError>` and map underlying errors into it.
@@ -17,6 +107,86 @@ pub mod error;
 /// How should it be used? Call functions like `estimate_indoor_climate` within the onboarding wizard or zone configuration.
 pub mod estimation;
 
+/// What is it? Per-zone electricity prices, powered devices, and monthly running cost estimates.
+/// Why does it exist? To show what lights, heaters, and humidifiers cost to run in each zone, so growers can weigh a zone's cost against its plants.
+/// How should it be used? Load profiles via `server_fns::energy`, and call `estimate_monthly` with a profile to build the economics card.
+pub mod energy;
+
+/// What is it? Hourly weather forecasts for outdoor zones and the summaries shown on the forecast card.
+/// Why does it exist? To answer "will it rain on my outdoor mounts tonight?" from the dashboard instead of a separate weather app.
+/// How should it be used? Load `ZoneForecast`s via `server_fns::climate::get_outdoor_forecasts` and call `watering_hint` or `precipitation_next` for display.
+pub mod forecast;
+
+/// What is it? Per-zone climate goals and weekly report card scoring.
+/// Why does it exist? To measure how often each zone stays within the temperature and humidity ranges its owner chose, and whether that is improving.
+/// How should it be used? Call `build_report_card` with a `ZoneGoal` and a week of readings from the dashboard server functions or the weekly push job.
+pub mod goals;
+
+/// What is it? Growth measurements: leaf span, pseudobulb and root counts, and spike length, logged on 'Measured' journal entries.
+/// Why does it exist? Progress is hard to judge by eye over months; logged numbers turn it into a trend the grower can see.
+/// How should it be used? Log through `server_fns::orchids::add_measurement`; draw the Growth tab's charts from `series`.
+pub mod growth;
+
+/// What is it? Monthly similarity scores between a plant's zone climate and its native habitat's weather.
+/// Why does it exist? To show growers which months their conditions drift from the species' native climate, and in which direction.
+/// How should it be used? Convert readings and summaries to `ClimateSample`s and call `compare_monthly`; the `get_habitat_similarity` server function does this per plant.
+pub mod habitat_similarity;
+
+/// What is it? Shared households: member roles and the types shown in household settings.
+/// Why does it exist? To let several accounts care for one collection, with each person's access limited by their role.
+/// How should it be used? Server functions call `auth::require_role` with a `HouseholdRole`; the settings UI renders `HouseholdInfo` from `get_household`.
+pub mod household;
+
+/// What is it? Candidate species for a scanned plant photo, ranked by the model's confidence.
+/// Why does it exist? A single guess is often wrong for look-alike species; a ranked list with reference photos lets the grower pick the right one or reject them all.
+/// How should it be used? `server_fns::scanner::identify_orchid_candidates` returns candidates already passed through `rank_candidates`; profile the chosen one with `analyze_orchid_by_name`.
+pub mod identification;
+
+/// What is it? Importers for other plant apps' CSV exports (Planta, Greg, and generic CSV with column mapping).
+/// Why does it exist? To convert schedules and care histories from other apps into orchids and log entries so switchers keep their data.
+/// How should it be used? Parse with `import::csv::parse_csv`, build a `ColumnMapping`, and call `import::convert` to preview; the `import_plants` server function writes the result.
+pub mod import;
+
+/// What is it? Journal filters and pages: the event type, date range and keyword narrowing a plant's timeline.
+/// Why does it exist? Plants with years of history produce timelines too long to scroll; filtering and paging keep them usable.
+/// How should it be used? Build a `JournalQuery` from the timeline's filter bar and fetch pages with `server_fns::orchids::get_log_entries_page`.
+pub mod journal;
+
+/// What is it? Light measurement helpers: lux/PPFD conversion and comparing measured light with a plant's needs.
+/// Why does it exist? To let zones with light sensors tell growers whether a plant is getting the light its `par_ppfd` or `light_lux` asks for.
+/// How should it be used? Call `target_ppfd_range` for a plant and `compare_light` with a zone's peak PPFD (convert lux with `lux_to_ppfd`).
+pub mod light;
+
+/// What is it? The Markdown subset allowed in notes (bold, italic, links, lists): a sanitizer and an HTML renderer.
+/// Why does it exist? Checklists and links read better formatted, but notes are user input and must never inject markup or scripts.
+/// How should it be used? Server functions pass every saved note through `sanitize`; views render notes with `to_html` via `components::markdown_note`.
+pub mod markdown;
+
+/// What is it? Schema migration planning: pairing numbered up and down scripts and comparing them with what a database has applied.
+/// Why does it exist? Migrations are checksummed and versioned so an edited file or a gap is caught before it's run, and rollbacks need to know which down scripts to run in what order.
+/// How should it be used? `db::migration_status` reads the files and the `migration` table; pass them to `plan`, `schema_version` or `rollback_plan`.
+pub mod migration;
+
+/// What is it? The monthly collection report: what a month's journal, climate alerts and watering record add up to.
+/// Why does it exist? Growers get a readable look back each month, written by the AI provider from facts gathered here, or the facts themselves when none answers.
+/// How should it be used? `server_fns::reports` gathers `MonthlyFacts` and writes reports in the background; `notification_body` trims one for its push.
+pub mod monthly_report;
+
+/// What is it? Note templates: named lists of fields that expand into a pre-structured journal note.
+/// Why does it exist? Repotting, spike checks and health checks record the same details every time; a template asks for them and sets the event type so none get forgotten.
+/// How should it be used? Load them with `server_fns::note_templates::get_note_templates`; `expand` fills the note form when one is picked.
+pub mod note_template;
+
+/// What is it? The notification delivery log: each Web Push, webhook and ntfy send and whether the channel accepted it.
+/// Why does it exist? So a grower who didn't see an alert can tell whether it was never sent or sent and missed.
+/// How should it be used? `push::deliver_push`/`deliver_webhook` and the ntfy sender record entries; `server_fns::alerts::get_notification_history` lists them.
+pub mod notification_log;
+
+/// What is it? ntfy topics as a notification destination: a server, a topic and an optional access token.
+/// Why does it exist? Self-hosters who run ntfy, or don't want VAPID Web Push, can get the same alerts and reminders in the ntfy app.
+/// How should it be used? Build an `NtfyTarget` with `NtfyTarget::new` to check settings, show `NtfyStatus` in the browser, and publish through `push::send_ntfy`.
+pub mod ntfy;
+
 /// What is it? Core domain models for orchids and related entities.
 /// Why does it exist? To define the shape and constraints of the data fundamental to tracking an orchid collection.
 /// How should it be used? Import structs like `Orchid`, `GrowingZone`, and `LogEntry` when manipulating data or sending it between client and server.
@@ -27,6 +197,71 @@ pub mod orchid;
 /// How should it be used? Read from the `Model` in UI components and construct `Msg` enums to request state changes.
 pub mod model;
 
+/// What is it? Naming for photo backup archives: the folder and file each photo gets, and the download name.
+/// Why does it exist? So a zip of hundreds of camera-roll photos unpacks into something a grower can browse by plant and date.
+/// How should it be used? The `/api/images/archive` handler in `server_fns::images` calls `archive_entry_name` for each photo and `archive_filename` for the download.
+pub mod photo_archive;
+
+/// What is it? Propagation lineage: keikis, divisions and backbulb starts linked to the plant they came from.
+/// Why does it exist? To keep a plant's family tree and record which offshoots were kept, gifted or sold.
+/// How should it be used? Create propagations through `server_fns::propagation`; call `build_lineage` with the collection's records to get one plant's ancestors and children.
+pub mod propagation;
+
+/// What is it? The public JSON feed of a shared collection: plants and their bloom history.
+/// Why does it exist? To let community sites and Discord bots showcase a grower's collection, with the grower's consent key and without private fields.
+/// How should it be used? Build documents with `build_feed`; growers manage their feed key through `server_fns::preferences`, and `server_fns::public::handlers` serves it.
+pub mod public_feed;
+
+/// What is it? Buttons on Web Push notifications, such as "Mark watered" on an overdue-watering alert.
+/// Why does it exist? Logging a watering from the notification itself saves opening the app for the one thing the notification asked for.
+/// How should it be used? Build the push body with `payload`; `public/sw.js` posts a tapped action to its endpoint, which `server_fns::alerts::handlers` serves.
+pub mod push_action;
+
+/// What is it? Root health assessed from a photo at repotting: healthy, rotten and dried-out shares, the old medium's mix and condition, and the next repot.
+/// Why does it exist? Roots are only visible for a few minutes every year or two, and they're the best record of how a plant's care has been going; storing the assessment as numbers lets repots be compared.
+/// How should it be used? `server_fns::scanner::assess_root_photo` returns a normalized `RootAssessment`; save it on a 'Repotted' journal entry with `server_fns::orchids::log_root_assessment`.
+pub mod root_health;
+
+/// What is it? Species-level default card images and built-in placeholder art.
+/// Why does it exist? To give plants without their own photos a recognisable picture so a fresh collection doesn't look barren.
+/// How should it be used? Load a `CardPhotoIndex` via `get_card_photos` and call `resolve` for each orchid card.
+pub mod species_photos;
+
+/// What is it? Time travel: reconstructing a plant or the collection as it stood on a past date.
+/// Why does it exist? For post-mortems on plants that declined, where the question is what the zone, pot and watering routine were back then.
+/// How should it be used? Call `reconstruct` with a plant's `CareRevision`s and journal, or use `server_fns::time_travel` which does it for you.
+pub mod time_travel;
+
+/// What is it? The taxonomy layer: common, trade and synonym names for species and genera, and the collection search index built from them.
+/// Why does it exist? Growers know plants by nursery labels and names in their own language, so search has to find a plant by any of its names.
+/// How should it be used? Build a `SearchIndex` from the collection and the aliases from `server_fns::taxonomy::get_species_aliases`, then call `matching_ids`.
+pub mod taxonomy;
+
+/// What is it? Thumbnail sizes and the URL and storage path of each photo's thumbnails.
+/// Why does it exist? So grids and timelines load small images instead of full camera originals.
+/// How should it be used? Call `thumbnail_url` when rendering a photo; the upload handler and `/thumbs` route use `thumbnail_path`.
+pub mod thumbnails;
+
+/// What is it? Frame selection and the date-caption pixel font for photo time-lapses.
+/// Why does it exist? So a plant's photo history can be played back as one animation without pulling in a font renderer.
+/// How should it be used? `server_fns::timelapse::create_timelapse` samples photos with `sample_frames` and stamps dates with `text_mask`.
+pub mod timelapse;
+
+/// What is it? The getting-started tour: its steps, the hints shown for each, and a user's saved progress.
+/// Why does it exist? The first-run wizard only creates zones, so new users never discovered climate estimates, plant cards or watering logs.
+/// How should it be used? Load a `TourProgress`, build `TourFacts` from the collection, and show the hint for `current_step`.
+pub mod tour;
+
+/// What is it? Pest and disease treatments: the problem, the product and dose, and the re-treatment series that follows.
+/// Why does it exist? Most sprays only kill what's hatched, so a missed follow-up lets the infestation come back; the series and its status keep it on schedule.
+/// How should it be used? Start, apply and resolve treatments through `server_fns::treatments`; `treatment_badges` picks what each orchid card shows.
+pub mod treatment;
+
+/// What is it? The sick-plant troubleshooting decision tree: questions, answers, and the likely causes they lead to.
+/// Why does it exist? To walk growers from a symptom ("leaves wrinkled?") to a cause and a plan, instead of guessing or searching forums.
+/// How should it be used? Start at `START_QUESTION`, follow each `Answer`'s `Next`, and save `diagnosis_note` as a `Diagnosis` journal entry.
+pub mod troubleshoot;
+
 /// What is it? State transition logic following The Elm Architecture (TEA).
 /// Why does it exist? To encapsulate the pure logic of how the UI state changes in response to messages without directly coupling to the DOM.
 /// How should it be used? Call `update::dispatch` from UI event handlers to push a new `Msg` into the system.
@@ -40,21 +275,42 @@ pub mod pages;
 /// How should it be used? Call these functions directly from frontend code as if they were local async functions; they automatically serialize over HTTP.
 pub mod server_fns;
 
+/// What is it? Windowing for the collection grid: which cards are near the viewport and how much space stands in for the rest.
+/// Why does it exist? Mounting hundreds of reactive cards at once makes large collections slow to render and scroll on phones.
+/// How should it be used? Measure the grid into a `GridViewport` and render the cards `visible_window` returns, padding the grid by its `space_before` and `space_after`.
+pub mod virtual_grid;
+
 /// What is it? Climate-aware dynamic watering algorithm.
 /// Why does it exist? To calculate adaptive watering intervals based on real-time temperature, humidity, and species requirements.
 /// How should it be used? Call `climate_adjusted_watering` before displaying watering countdowns in the UI or processing alerts.
 pub mod watering;
 
+/// What is it? Water sources, their pH and EC/TDS readings, and the genus-specific limits they are checked against.
+/// Why does it exist? Salts and pH drift in tap water burn the roots of sensitive genera like Phragmipedium long before the grower notices.
+/// How should it be used? Call `water_issues` on a source's latest reading with the collection's genera; show `WaterIssue::message`.
+pub mod water_quality;
+
+/// What is it? Discord and Slack channel webhooks as an alert destination.
+/// Why does it exist? A household that already watches a chat channel sees climate and overdue-watering alerts there, on every member's devices, without each person enabling push.
+/// How should it be used? Check a pasted URL with `WebhookKind::detect`, show it with `WebhookStatus`, and send through `push::send_webhook`.
+pub mod webhook;
+
 #[cfg(test)]
 /// What is it? Helper functions and utilities for tests.
 /// Why does it exist? To provide shared mock data and setup routines for the test suite without compiling them into the production binary.
 /// How should it be used? Import inside `#[cfg(test)]` modules to quickly scaffold test scenarios.
 pub mod test_helpers;
 
+#[cfg(feature = "ssr")]
+/// What is it? The AI providers (Gemini, Claude, OpenAI, Ollama) behind the scanner and other AI features.
+/// Why does it exist? So self-hosters can keep photos on their network with a local model and everyone else can pick their vendor, without each feature knowing which API it talks to.
+/// How should it be used? Call `ai::vision` or `ai::text`; they try the providers `AI_PROVIDERS` lists, in order, falling back when one fails.
+pub mod ai;
+
 #[cfg(feature = "ssr")]
 /// What is it? Database connection and repository implementations.
 /// Why does it exist? To manage the SurrealDB lifecycle, schema migrations, and low-level data access for the backend.
-/// How should it be used? Call `init_db()` at server startup and use `db()` to acquire a connection handle for queries.
+/// How should it be used? Call `init_db()` at server startup, spawn `monitor()` to reconnect after outages, and use `db()` to acquire a connection handle for queries.
 pub mod db;
 
 #[cfg(feature = "ssr")]
@@ -87,12 +343,24 @@ pub mod config;
 /// How should it be used? Call these functions when generating secure push notification payloads or resetting passwords.
 pub mod crypto;
 
+#[cfg(feature = "ssr")]
+/// What is it? Outgoing email over SMTP.
+/// Why does it exist? To deliver account messages such as password reset links without admin involvement.
+/// How should it be used? Check `is_configured()` and call `send_email` from server functions; configure via the `SMTP_*` environment variables.
+pub mod mail;
+
 #[cfg(feature = "ssr")]
 /// What is it? Push notification delivery mechanisms.
 /// Why does it exist? To handle the Web Push protocol and dispatch alerts to subscribed user devices.
 /// How should it be used? Call functions in this module from background tasks when an alert condition is met.
 pub mod push;
 
+#[cfg(feature = "ssr")]
+/// What is it? Per-user rate limits and daily quotas.
+/// Why does it exist? Per-IP limits lump a whole household behind one NAT together and don't cap AI spend, so requests are limited per account, with stricter buckets for AI calls and photo uploads.
+/// How should it be used? `limit_requests` is Axum middleware inside the session layer; call `check_user` at the top of AI and upload endpoints.
+pub mod rate_limit;
+
 #[cfg(feature = "ssr")]
 /// What is it? Management of user sessions.
 /// Why does it exist? To store and retrieve active session data (like the logged-in user ID) from SurrealDB via the `tower-sessions` crate.
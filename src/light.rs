@@ -0,0 +1,192 @@
+//! Light measurements: lux/PPFD conversion and comparing a zone's light with a plant's needs.
+//!
+//! Light sensors report illuminance (lux), quantum sensors report PPFD (µmol/m²/s).
+//! Comparisons are done in PPFD, converting lux with the daylight factor. The daily light
+//! integral (DLI, mol/m²/day) is the day's PPFD summed over time.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::orchid::LightRequirement;
+
+/// Lux per µmol/m²/s under sunlight. Grow lights differ (roughly 60–85 for white LEDs),
+/// so converted values are an estimate.
+pub const SUNLIGHT_LUX_PER_PPFD: f64 = 54.0;
+
+/// Photoperiod assumed when turning a plant's PPFD target into a DLI target, in hours.
+pub const DLI_PHOTOPERIOD_HOURS: f64 = 12.0;
+
+/// How far either side of a plant's single PPFD value still counts as suitable.
+const PPFD_TOLERANCE: f64 = 0.3;
+
+/// Convert illuminance in lux to an estimated PPFD in µmol/m²/s.
+pub fn lux_to_ppfd(lux: f64) -> f64 {
+    lux / SUNLIGHT_LUX_PER_PPFD
+}
+
+/// Convert PPFD in µmol/m²/s to an estimated illuminance in lux.
+pub fn ppfd_to_lux(ppfd: f64) -> f64 {
+    ppfd * SUNLIGHT_LUX_PER_PPFD
+}
+
+/// Parse a free-text lux target such as "5000", "15,000 lux", or "10000-20000" into a `(low, high)` range.
+/// Returns `None` when the text has no usable number.
+pub fn parse_lux_range(text: &str) -> Option<(f64, f64)> {
+    let numbers: Vec<f64> = text
+        .split(|c: char| !(c.is_ascii_digit() || c == ',' || c == '.'))
+        .filter_map(|part| part.replace(',', "").parse::<f64>().ok())
+        .filter(|n| *n > 0.0)
+        .collect();
+    let low = numbers.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = numbers.iter().copied().fold(0.0_f64, f64::max);
+    (!numbers.is_empty()).then_some((low, high))
+}
+
+/// How a measured light level compares with what a plant needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LightFit {
+    /// Below the plant's range.
+    TooDim,
+    /// Within the plant's range.
+    Good,
+    /// Above the plant's range.
+    TooBright,
+}
+
+/// The PPFD range a plant wants: its measured `par_ppfd` with some slack, else its lux target,
+/// else a range for its light tier.
+pub fn target_ppfd_range(par_ppfd: Option<f64>, light_lux: &str, requirement: &LightRequirement) -> (f64, f64) {
+    if let Some(ppfd) = par_ppfd.filter(|p| *p > 0.0) {
+        return (ppfd * (1.0 - PPFD_TOLERANCE), ppfd * (1.0 + PPFD_TOLERANCE));
+    }
+    if let Some((low, high)) = parse_lux_range(light_lux) {
+        let (low, high) = if low == high {
+            (low * (1.0 - PPFD_TOLERANCE), high * (1.0 + PPFD_TOLERANCE))
+        } else {
+            (low, high)
+        };
+        return (lux_to_ppfd(low), lux_to_ppfd(high));
+    }
+    match requirement {
+        LightRequirement::Low => (50.0, 150.0),
+        LightRequirement::Medium => (150.0, 300.0),
+        LightRequirement::High => (300.0, 600.0),
+    }
+}
+
+/// Compare a measured PPFD with a target range.
+pub fn compare_light(measured_ppfd: f64, (low, high): (f64, f64)) -> LightFit {
+    if measured_ppfd < low {
+        LightFit::TooDim
+    } else if measured_ppfd > high {
+        LightFit::TooBright
+    } else {
+        LightFit::Good
+    }
+}
+
+/// The DLI (mol/m²/day) of a constant `ppfd` held for `hours`.
+pub fn ppfd_to_dli(ppfd: f64, hours: f64) -> f64 {
+    ppfd * hours * 3600.0 / 1_000_000.0
+}
+
+/// The DLI range a plant wants: its PPFD target over a `DLI_PHOTOPERIOD_HOURS` day.
+pub fn target_dli_range(par_ppfd: Option<f64>, light_lux: &str, requirement: &LightRequirement) -> (f64, f64) {
+    let (low, high) = target_ppfd_range(par_ppfd, light_lux, requirement);
+    (ppfd_to_dli(low, DLI_PHOTOPERIOD_HOURS), ppfd_to_dli(high, DLI_PHOTOPERIOD_HOURS))
+}
+
+/// One target band for a zone from its plants' DLI ranges: the range all of them share,
+/// or the span of all of them when they don't overlap. `None` when the zone has no plants.
+pub fn zone_dli_band(ranges: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let low = ranges.iter().map(|r| r.0).fold(f64::NEG_INFINITY, f64::max);
+    let high = ranges.iter().map(|r| r.1).fold(f64::INFINITY, f64::min);
+    if ranges.is_empty() {
+        None
+    } else if low <= high {
+        Some((low, high))
+    } else {
+        let low = ranges.iter().map(|r| r.0).fold(f64::INFINITY, f64::min);
+        let high = ranges.iter().map(|r| r.1).fold(f64::NEG_INFINITY, f64::max);
+        Some((low, high))
+    }
+}
+
+/// One day's daily light integral for a zone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DliDay {
+    /// The UTC day the integral covers.
+    pub date: NaiveDate,
+    /// Light received that day, in mol/m²/day.
+    pub dli: f64,
+    /// True when the value was estimated from lux rather than measured PPFD.
+    pub estimated: bool,
+}
+
+/// **What is it?**
+/// A zone's recent daily light integrals and the DLI band its plants want.
+///
+/// **Why does it exist?**
+/// It exists because DLI, not instantaneous brightness, is how growers judge whether a spot gives enough light over a whole day.
+///
+/// **How should it be used?**
+/// Fetch with `get_zone_dli` for the dashboard, and compare each day with `target` using `compare_light`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneDli {
+    /// The zone the values belong to.
+    pub zone_id: String,
+    /// The zone's display name.
+    pub zone_name: String,
+    /// Recorded days, oldest first.
+    pub days: Vec<DliDay>,
+    /// The DLI band for the zone's plants, from `zone_dli_band`.
+    pub target: Option<(f64, f64)>,
+    /// How many plants the band is based on.
+    pub plant_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lux_ppfd_round_trip() {
+        assert!((lux_to_ppfd(10_800.0) - 200.0).abs() < 1e-9);
+        assert!((ppfd_to_lux(lux_to_ppfd(25_000.0)) - 25_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_lux_range() {
+        assert_eq!(parse_lux_range("5000"), Some((5000.0, 5000.0)));
+        assert_eq!(parse_lux_range("15,000 - 25,000 lux"), Some((15000.0, 25000.0)));
+        assert_eq!(parse_lux_range("bright, indirect"), None);
+        assert_eq!(parse_lux_range(""), None);
+    }
+
+    #[test]
+    fn test_target_prefers_par_then_lux_then_tier() {
+        let (low, high) = target_ppfd_range(Some(200.0), "50000", &LightRequirement::Low);
+        assert!((low - 140.0).abs() < 1e-9 && (high - 260.0).abs() < 1e-9);
+        let (low, high) = target_ppfd_range(None, "10800-21600", &LightRequirement::Low);
+        assert!((low - 200.0).abs() < 1e-9 && (high - 400.0).abs() < 1e-9);
+        assert_eq!(target_ppfd_range(None, "", &LightRequirement::High), (300.0, 600.0));
+    }
+
+    #[test]
+    fn test_dli_targets_and_zone_band() {
+        assert!((ppfd_to_dli(200.0, 12.0) - 8.64).abs() < 1e-9);
+        let (low, high) = target_dli_range(None, "", &LightRequirement::Low);
+        assert!((low - 2.16).abs() < 1e-9 && (high - 6.48).abs() < 1e-9);
+
+        assert_eq!(zone_dli_band(&[]), None);
+        assert_eq!(zone_dli_band(&[(4.0, 10.0), (6.0, 12.0)]), Some((6.0, 10.0)));
+        // No overlap: span both
+        assert_eq!(zone_dli_band(&[(2.0, 6.0), (13.0, 26.0)]), Some((2.0, 26.0)));
+    }
+
+    #[test]
+    fn test_compare_light() {
+        assert_eq!(compare_light(50.0, (100.0, 200.0)), LightFit::TooDim);
+        assert_eq!(compare_light(150.0, (100.0, 200.0)), LightFit::Good);
+        assert_eq!(compare_light(500.0, (100.0, 200.0)), LightFit::TooBright);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::config::config;
+use crate::error::AppError;
+
+/// Whether an SMTP relay has been configured.
+pub fn is_configured() -> bool {
+    !config().smtp_host.is_empty()
+}
+
+/// Send a plain-text email through the configured SMTP relay (STARTTLS).
+pub async fn send_email(to: &str, subject: &str, body: &str) -> Result<(), AppError> {
+    use lettre::message::header::ContentType;
+    use lettre::transport::smtp::authentication::Credentials;
+    use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+    let cfg = config();
+
+    if !is_configured() {
+        tracing::error!("SMTP host not configured");
+        return Err(AppError::Network("SMTP host not configured".into()));
+    }
+
+    let message = Message::builder()
+        .from(cfg.smtp_from.parse().map_err(|e| {
+            AppError::Validation(format!("Invalid SMTP_FROM address: {}", e))
+        })?)
+        .to(to.parse().map_err(|e| {
+            AppError::Validation(format!("Invalid recipient address: {}", e))
+        })?)
+        .subject(subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(body.to_string())
+        .map_err(|e| AppError::Validation(format!("Email build error: {}", e)))?;
+
+    let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.smtp_host)
+        .map_err(|e| AppError::Network(format!("SMTP relay error: {}", e)))?
+        .port(cfg.smtp_port);
+    if !cfg.smtp_username.is_empty() {
+        builder = builder.credentials(Credentials::new(
+            cfg.smtp_username.clone(),
+            cfg.smtp_password.clone(),
+        ));
+    }
+
+    builder
+        .build()
+        .send(message)
+        .await
+        .map_err(|e| {
+            tracing::error!("SMTP send failed: {}", e);
+            AppError::Network(format!("SMTP send error: {}", e))
+        })?;
+
+    tracing::info!(subject = %subject, "Email sent");
+    Ok(())
+}
+
+/// Body of the password reset email.
+pub fn password_reset_body(username: &str, link: &str, valid_minutes: i64) -> String {
+    format!(
+        "Hi {username},\n\n\
+         Someone (hopefully you) asked to reset the password for your Velamen account.\n\n\
+         Choose a new password here:\n{link}\n\n\
+         This link expires in {valid_minutes} minutes and can only be used once. \
+         If you didn't request this, you can ignore this email; your password won't change.\n\n\
+         \u{2014} Velamen"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_body_contains_link_and_expiry() {
+        let body = password_reset_body("alice", "https://example.com/reset-password?token=abc", 60);
+        assert!(body.starts_with("Hi alice,"));
+        assert!(body.contains("https://example.com/reset-password?token=abc"));
+        assert!(body.contains("60 minutes"));
+    }
+}
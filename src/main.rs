@@ -21,10 +21,20 @@ async fn main() {
     // Load .env file
     let _ = dotenvy::dotenv();
 
+    // Parse CLI args
+    let cli = Cli::parse();
+
     // Init tracing — write to stdout so journald always captures it
     use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-    let filter = tracing_subscriber::filter::LevelFilter::INFO;
+    // `poll` is for debugging an integration, so it also shows the app's debug logs
+    let app_level = match cli.command {
+        Some(Command::Poll { .. }) => tracing_subscriber::filter::LevelFilter::DEBUG,
+        _ => tracing_subscriber::filter::LevelFilter::INFO,
+    };
+    let filter = tracing_subscriber::filter::Targets::new()
+        .with_default(tracing_subscriber::filter::LevelFilter::INFO)
+        .with_target("orchid_tracker", app_level);
     let fmt_layer = tracing_subscriber::fmt::layer().with_writer(std::io::stdout);
 
     let axiom_layer = if let (Ok(token), Ok(dataset)) = (std::env::var("AXIOM_TOKEN"), std::env::var("AXIOM_DATASET")) {
@@ -43,13 +53,32 @@ async fn main() {
         .with(axiom_layer)
         .init();
 
-    // Parse CLI args
-    let cli = Cli::parse();
-
     // Init config
     orchid_tracker::config::init_config();
     let cfg = orchid_tracker::config::config();
 
+    // The doctor connects on its own, so it can report on installs that fail the startup below
+    if let Some(Command::Doctor) = cli.command {
+        match orchid_tracker::cli::run_doctor(cfg).await {
+            Ok(()) => std::process::exit(0),
+            Err(e) => {
+                tracing::error!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // So does migrate, since the startup below applies every pending migration
+    if let Some(Command::Migrate { dry_run, down_to }) = cli.command {
+        match orchid_tracker::cli::run_migrate(cfg, dry_run, down_to).await {
+            Ok(()) => std::process::exit(0),
+            Err(e) => {
+                tracing::error!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Init SurrealDB (also runs migrations)
     orchid_tracker::db::init_db(cfg)
         .instrument(tracing::info_span!("database_startup"))
@@ -70,6 +99,15 @@ async fn main() {
                     }
                 }
             }
+            Command::SetAdmin { username, revoke } => {
+                match orchid_tracker::cli::run_set_admin(&username, revoke).await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             Command::ReprocessPlants { user, batch_size, delay_secs, dry_run } => {
                 match orchid_tracker::cli::run_reprocess_plants(&user, batch_size, delay_secs, dry_run).await {
                     Ok(()) => std::process::exit(0),
@@ -79,6 +117,62 @@ async fn main() {
                     }
                 }
             }
+            Command::ExportClimate { user, out } => {
+                match orchid_tracker::cli::run_export_climate(&user, &out).await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Command::Export { user, format, path } => {
+                match orchid_tracker::cli::run_export(&user, format, &path).await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Command::Import { user, path } => {
+                match orchid_tracker::cli::run_import(&user, &path).await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            // Handled before the database starts
+            Command::Doctor | Command::Migrate { .. } => unreachable!(),
+            Command::Stats => {
+                match orchid_tracker::cli::run_stats().await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Command::Poll { zone_or_all } => {
+                match orchid_tracker::cli::run_poll(&zone_or_all).await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Command::Seed { user, password, days } => {
+                match orchid_tracker::cli::run_seed(&user, password.as_deref(), days).await {
+                    Ok(()) => std::process::exit(0),
+                    Err(e) => {
+                        tracing::error!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
         }
     }
 
@@ -90,10 +184,12 @@ async fn main() {
         .with_http_only(true)
         .with_secure(true);
 
-    // Rate limiting: 100 requests/sec per real client IP (reads X-Forwarded-For/X-Real-IP)
+    // Flood guard: 500 requests/sec per real client IP (reads X-Forwarded-For/X-Real-IP). The
+    // tighter site-wide limit is per signed-in user (`rate_limit::limit_requests`), so a household
+    // behind one NAT doesn't share it.
     let governor_conf = GovernorConfigBuilder::default()
-        .per_second(100)
-        .burst_size(200)
+        .per_second(500)
+        .burst_size(1000)
         .key_extractor(SmartIpKeyExtractor)
         .finish()
         .expect("Failed to build rate limiter config");
@@ -134,6 +230,12 @@ async fn main() {
     // Build router
     let app = Router::new()
         .merge(orchid_tracker::server_fns::images::handlers::upload_router())
+        .merge(orchid_tracker::server_fns::images::handlers::thumbnail_router())
+        .merge(orchid_tracker::server_fns::images::handlers::archive_router())
+        .merge(orchid_tracker::server_fns::public::handlers::public_api_router())
+        .merge(orchid_tracker::server_fns::ingest::handlers::ingest_router())
+        .merge(orchid_tracker::server_fns::alerts::handlers::push_action_router())
+        .merge(orchid_tracker::server_fns::health::handlers::health_router())
         .nest_service("/images", image_service)
         .leptos_routes(&leptos_options, routes, {
             let leptos_options = leptos_options.clone();
@@ -144,6 +246,8 @@ async fn main() {
         })
         .fallback(leptos_axum::file_and_error_handler(shell_fn))
         .layer(TraceLayer::new_for_http())
+        // Per-user rate limiting; needs the session, so it sits inside the session layer
+        .layer(axum::middleware::from_fn(orchid_tracker::rate_limit::limit_requests))
         .layer(session_layer)
         // Security headers
         .layer(SetResponseHeaderLayer::overriding(
@@ -173,11 +277,17 @@ async fn main() {
         .with_state(leptos_options);
 
     use tracing::Instrument;
+    // Spawn database health check task; reconnects with backoff when the connection drops
+    tokio::spawn(async move {
+        orchid_tracker::db::monitor(cfg).await;
+    }.instrument(tracing::info_span!("db_monitor_task")));
+
     // Spawn background task to periodically clean up rate limiter + expired sessions
     tokio::spawn(async move {
         loop {
             tokio::time::sleep(std::time::Duration::from_secs(60)).await;
             governor_limiter.retain_recent();
+            orchid_tracker::rate_limit::retain_recent();
             session_store.cleanup_expired().await;
         }
     }.instrument(tracing::info_span!("cleanup_task")));
@@ -192,16 +302,57 @@ async fn main() {
         }
     }.instrument(tracing::info_span!("climate_poller_task")));
 
-    // Spawn seasonal alert check task (daily)
+    // Spawn MQTT climate ingestion (long-lived broker subscriptions)
+    tokio::spawn(async move {
+        // Initial delay to let the server fully start
+        tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+        orchid_tracker::climate::mqtt::run_mqtt_ingestion().await;
+    }.instrument(tracing::info_span!("mqtt_ingestion_task")));
+
+    // Spawn seasonal alert + phase rule check task (daily)
     tokio::spawn(async move {
         // Initial delay to let the server fully start
         tokio::time::sleep(std::time::Duration::from_secs(120)).await;
         loop {
             orchid_tracker::climate::seasonal_alerts::check_seasonal_alerts().await;
+            orchid_tracker::climate::care_rules::evaluate_phase_rules().await;
             tokio::time::sleep(std::time::Duration::from_secs(24 * 60 * 60)).await;
         }
     }.instrument(tracing::info_span!("seasonal_alerts_task")));
 
+    // Spawn weekly report card push task (checked every 6 hours, sends Mondays)
+    tokio::spawn(async move {
+        // Initial delay to let the server fully start
+        tokio::time::sleep(std::time::Duration::from_secs(180)).await;
+        loop {
+            orchid_tracker::climate::weekly_reports::send_weekly_reports().await;
+            tokio::time::sleep(std::time::Duration::from_secs(6 * 60 * 60)).await;
+        }
+    }.instrument(tracing::info_span!("weekly_reports_task")));
+
+    // Spawn monthly collection report task (checks every 6 hours, writes once per month)
+    tokio::spawn(async move {
+        // Initial delay to let the server fully start
+        tokio::time::sleep(std::time::Duration::from_secs(300)).await;
+        loop {
+            orchid_tracker::server_fns::reports::generate_monthly_reports().await;
+            tokio::time::sleep(std::time::Duration::from_secs(6 * 60 * 60)).await;
+        }
+    }.instrument(tracing::info_span!("monthly_reports_task")));
+
+    // Spawn daily light integral + climate rollup/retention + notification log retention task (daily)
+    tokio::spawn(async move {
+        // Initial delay to let the server fully start
+        tokio::time::sleep(std::time::Duration::from_secs(240)).await;
+        loop {
+            // DLI first, so it sees the raw readings before they are compacted
+            orchid_tracker::climate::dli::record_daily_dli().await;
+            orchid_tracker::climate::rollups::compact_climate_readings().await;
+            orchid_tracker::server_fns::alerts::prune_notification_log().await;
+            tokio::time::sleep(std::time::Duration::from_secs(24 * 60 * 60)).await;
+        }
+    }.instrument(tracing::info_span!("climate_rollups_task")));
+
     // Spawn habitat weather polling task (every 2 hours)
     tokio::spawn(async move {
         // Initial delay to let the server fully start
@@ -212,6 +363,16 @@ async fn main() {
         }
     }.instrument(tracing::info_span!("habitat_weather_task")));
 
+    // Spawn due reminder push task (every 15 minutes)
+    tokio::spawn(async move {
+        // Initial delay to let the server fully start
+        tokio::time::sleep(std::time::Duration::from_secs(90)).await;
+        loop {
+            orchid_tracker::server_fns::reminders::push_due_reminders().await;
+            tokio::time::sleep(std::time::Duration::from_secs(15 * 60)).await;
+        }
+    }.instrument(tracing::info_span!("reminder_push_task")));
+
     let listener = tokio::net::TcpListener::bind(&cfg.site_addr).await.unwrap();
     tracing::info!("Listening on http://{}", cfg.site_addr);
     axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>()).await.unwrap();
@@ -0,0 +1,217 @@
+//! The small Markdown subset allowed in journal and plant notes: `**bold**`, `*italic*`,
+//! `[links](https://…)`, and `-` or `1.` lists. Anything else is shown as typed.
+
+/// Link schemes a note may point at. Everything else (`javascript:`, `data:`, …) is dropped.
+const SAFE_SCHEMES: &[&str] = &["https://", "http://", "mailto:"];
+
+/// Cleans a note before it is stored: removes raw HTML tags and turns links with an unsafe
+/// scheme into their plain label. Run by the server on every note it saves; `to_html` still
+/// escapes everything, so older notes render safely too.
+pub fn sanitize(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut i = 0;
+    while i < src.len() {
+        let rest = &src[i..];
+        if let Some(end) = html_tag_len(rest) {
+            i += end;
+        } else if let Some((label, url, end)) = parse_link(rest) {
+            if is_safe_url(url) {
+                out.push_str(&rest[..end]);
+            } else {
+                out.push_str(label);
+            }
+            i += end;
+        } else {
+            let c = rest.chars().next().unwrap_or_default();
+            if !c.is_control() || c == '\n' || c == '\t' {
+                out.push(c);
+            }
+            i += c.len_utf8();
+        }
+    }
+    out
+}
+
+/// Renders a note as HTML. Text is escaped first, so the only tags in the output are the
+/// ones this function writes: `p`, `br`, `ul`, `ol`, `li`, `strong`, `em` and `a`.
+pub fn to_html(src: &str) -> String {
+    let mut out = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<(&str, Vec<&str>)> = None;
+
+    for line in src.lines() {
+        let trimmed = line.trim();
+        if let Some((tag, item)) = list_item(trimmed) {
+            flush_paragraph(&mut out, &mut paragraph);
+            match &mut list {
+                Some((open, items)) if *open == tag => items.push(item),
+                _ => {
+                    flush_list(&mut out, &mut list);
+                    list = Some((tag, vec![item]));
+                }
+            }
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut out, &mut paragraph);
+            flush_list(&mut out, &mut list);
+        } else {
+            flush_list(&mut out, &mut list);
+            paragraph.push(trimmed);
+        }
+    }
+    flush_paragraph(&mut out, &mut paragraph);
+    flush_list(&mut out, &mut list);
+    out
+}
+
+/// `("ul", "text")` for "- text", "* text" or "+ text"; `("ol", "text")` for "1. text".
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(bullet) {
+            return Some(("ul", item.trim_start()));
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    (digits > 0 && digits <= 3)
+        .then(|| line[digits..].strip_prefix(". "))
+        .flatten()
+        .map(|item| ("ol", item.trim_start()))
+}
+
+fn flush_paragraph(out: &mut String, lines: &mut Vec<&str>) {
+    if lines.is_empty() {
+        return;
+    }
+    out.push_str("<p>");
+    for (n, line) in lines.drain(..).enumerate() {
+        if n > 0 {
+            out.push_str("<br>");
+        }
+        inline(line, out);
+    }
+    out.push_str("</p>");
+}
+
+fn flush_list(out: &mut String, list: &mut Option<(&str, Vec<&str>)>) {
+    let Some((tag, items)) = list.take() else { return };
+    out.push_str(&format!("<{}>", tag));
+    for item in items {
+        out.push_str("<li>");
+        inline(item, out);
+        out.push_str("</li>");
+    }
+    out.push_str(&format!("</{}>", tag));
+}
+
+/// Bold, italic and links within one line; everything else is escaped.
+fn inline(s: &str, out: &mut String) {
+    let mut i = 0;
+    while i < s.len() {
+        let rest = &s[i..];
+        if let Some(inner) = rest.strip_prefix("**").and_then(|r| r.find("**").filter(|&n| n > 0).map(|n| &r[..n])) {
+            out.push_str("<strong>");
+            inline(inner, out);
+            out.push_str("</strong>");
+            i += inner.len() + 4;
+        } else if let Some(inner) = rest.strip_prefix('*')
+            .filter(|r| !r.starts_with([' ', '*']))
+            .and_then(|r| r.find('*').map(|n| &r[..n]))
+            .filter(|inner| !inner.is_empty() && !inner.ends_with(' '))
+        {
+            out.push_str("<em>");
+            inline(inner, out);
+            out.push_str("</em>");
+            i += inner.len() + 2;
+        } else if let Some((label, url, end)) = parse_link(rest) {
+            if is_safe_url(url) {
+                out.push_str(&format!("<a href=\"{}\" target=\"_blank\" rel=\"noopener noreferrer nofollow\">", escape(url)));
+                inline(label, out);
+                out.push_str("</a>");
+            } else {
+                inline(label, out);
+            }
+            i += end;
+        } else {
+            let c = rest.chars().next().unwrap_or_default();
+            out.push_str(&escape(c.encode_utf8(&mut [0; 4])));
+            i += c.len_utf8();
+        }
+    }
+}
+
+/// `[label](url)` at the start of `s`: the label, the URL, and the length consumed.
+fn parse_link(s: &str) -> Option<(&str, &str, usize)> {
+    let rest = s.strip_prefix('[')?;
+    let label_end = rest.find("](")?;
+    let label = &rest[..label_end];
+    if label.is_empty() || label.contains(['[', '\n']) {
+        return None;
+    }
+    let after = &rest[label_end + 2..];
+    let url_end = after.find(')')?;
+    let url = after[..url_end].trim();
+    if url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((label, url, 1 + label_end + 2 + url_end + 1))
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    SAFE_SCHEMES.iter().any(|scheme| lower.starts_with(scheme) && lower.len() > scheme.len())
+}
+
+/// Length of a raw HTML tag (`<b>`, `</div>`, `<!-- -->`, `<img …>`) at the start of `s`.
+/// A lone `<`, as in "temp < 20", is left alone.
+fn html_tag_len(s: &str) -> Option<usize> {
+    let mut chars = s.chars();
+    if chars.next() != Some('<') || !chars.next().is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?')) {
+        return None;
+    }
+    let line = s.split('\n').next().unwrap_or(s);
+    line.find('>').map(|n| n + 1)
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_formatting_and_links() {
+        assert_eq!(
+            to_html("Repotted in **bark** with *some* perlite, see [guide](https://example.com/a?b=1&c=2)"),
+            "<p>Repotted in <strong>bark</strong> with <em>some</em> perlite, see \
+             <a href=\"https://example.com/a?b=1&amp;c=2\" target=\"_blank\" rel=\"noopener noreferrer nofollow\">guide</a></p>"
+        );
+        assert_eq!(to_html("2 * 3 = 6, 5 ** 2"), "<p>2 * 3 = 6, 5 ** 2</p>");
+    }
+
+    #[test]
+    fn test_lists_and_paragraphs() {
+        assert_eq!(
+            to_html("Medium: bark\nPot size: 12 cm\n\n- roots firm\n- 2 trimmed\n1. water\n2. wait"),
+            "<p>Medium: bark<br>Pot size: 12 cm</p><ul><li>roots firm</li><li>2 trimmed</li></ul><ol><li>water</li><li>wait</li></ol>"
+        );
+    }
+
+    #[test]
+    fn test_html_is_escaped_and_unsafe_links_dropped() {
+        assert_eq!(to_html("<script>alert(1)</script>"), "<p>&lt;script&gt;alert(1)&lt;/script&gt;</p>");
+        assert_eq!(to_html("[click](javascript:alert)"), "<p>click</p>");
+        assert_eq!(to_html("[x](https://a.b/\"onmouseover=\")"), "<p><a href=\"https://a.b/&quot;onmouseover=&quot;\" target=\"_blank\" rel=\"noopener noreferrer nofollow\">x</a></p>");
+    }
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(sanitize("<b>Bold</b> temp < 20 <img src=x onerror=alert(1)>"), "Bold temp < 20 ");
+        assert_eq!(sanitize("[a](javascript:alert) [b](https://ok.example)"), "a [b](https://ok.example)");
+        assert_eq!(sanitize("line one\nline\u{0007} two"), "line one\nline two");
+    }
+}
@@ -0,0 +1,164 @@
+/// A schema migration in `migrations/`: `0042_orchid_revisions.surql` moves the schema to version
+/// 42, and an optional `0042_orchid_revisions.down.surql` next to it moves it back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationFile {
+    /// The number the file name starts with.
+    pub version: u32,
+    /// The up script's file name, which is what the `migration` table records.
+    pub name: String,
+    /// Hex SHA-256 of the up script as it is on disk.
+    pub checksum: String,
+    /// The down script's file name, if the migration can be rolled back.
+    pub down: Option<String>,
+}
+
+/// A row of the `migration` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    /// The up script's file name.
+    pub name: String,
+    /// The checksum recorded when it was applied; `None` for migrations applied before checksums
+    /// were recorded.
+    pub checksum: Option<String>,
+}
+
+/// What `run_migrations` would do to a database.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationPlan {
+    /// Migrations to apply, oldest first.
+    pub pending: Vec<MigrationFile>,
+    /// Applied migrations with no recorded checksum; the file's current checksum is recorded.
+    pub unrecorded: Vec<MigrationFile>,
+    /// Applied migrations whose file has been edited since. Startup refuses to continue while
+    /// there are any, since the database no longer matches what the files say.
+    pub changed: Vec<String>,
+}
+
+/// The version and direction of a migration file name: `Some((42, false))` for
+/// `0042_orchid_revisions.surql`, `Some((42, true))` for `0042_orchid_revisions.down.surql`, and
+/// `None` for names that aren't `<number>_<name>.surql`.
+pub fn parse_file_name(name: &str) -> Option<(u32, bool)> {
+    let stem = name.strip_suffix(".surql")?;
+    let (number, rest) = stem.split_once('_')?;
+    if rest.is_empty() || number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let version = number.parse().ok()?;
+    Some((version, rest.ends_with(".down")))
+}
+
+/// Pairs each up script with its down script and sorts them by version. `files` holds every
+/// `.surql` file name with its checksum. Misnamed files, two up scripts sharing a version and
+/// down scripts with no up script are errors, since any of them would make the order ambiguous.
+pub fn collect(files: &[(String, String)]) -> Result<Vec<MigrationFile>, String> {
+    let mut migrations: Vec<MigrationFile> = Vec::new();
+    let mut downs = Vec::new();
+    for (name, checksum) in files {
+        match parse_file_name(name) {
+            None => return Err(format!("{} isn't named like 0001_description.surql", name)),
+            Some((_, true)) => downs.push(name.clone()),
+            Some((version, false)) => {
+                if let Some(other) = migrations.iter().find(|m| m.version == version) {
+                    return Err(format!("{} and {} both have version {}", other.name, name, version));
+                }
+                migrations.push(MigrationFile { version, name: name.clone(), checksum: checksum.clone(), down: None });
+            }
+        }
+    }
+    for down in downs {
+        let up = format!("{}.surql", down.strip_suffix(".down.surql").unwrap_or(&down));
+        match migrations.iter_mut().find(|m| m.name == up) {
+            Some(migration) => migration.down = Some(down),
+            None => return Err(format!("{} has no matching {}", down, up)),
+        }
+    }
+    migrations.sort_by_key(|m| m.version);
+    Ok(migrations)
+}
+
+/// Compares the files with what the database has applied. Rows for files that no longer exist
+/// are left alone.
+pub fn plan(files: &[MigrationFile], applied: &[AppliedMigration]) -> MigrationPlan {
+    let mut plan = MigrationPlan::default();
+    for file in files {
+        match applied.iter().find(|a| a.name == file.name) {
+            None => plan.pending.push(file.clone()),
+            Some(AppliedMigration { checksum: None, .. }) => plan.unrecorded.push(file.clone()),
+            Some(AppliedMigration { checksum: Some(checksum), .. }) if *checksum != file.checksum => {
+                plan.changed.push(file.name.clone())
+            }
+            Some(_) => {}
+        }
+    }
+    plan
+}
+
+/// The schema version of a database: the newest applied migration that still has a file, or 0.
+pub fn schema_version(files: &[MigrationFile], applied: &[AppliedMigration]) -> u32 {
+    files.iter()
+        .filter(|f| applied.iter().any(|a| a.name == f.name))
+        .map(|f| f.version)
+        .max()
+        .unwrap_or(0)
+}
+
+/// The applied migrations to revert to bring the schema back to `target`, newest first. Fails
+/// without reverting anything if one of them has no down script.
+pub fn rollback_plan(files: &[MigrationFile], applied: &[AppliedMigration], target: u32) -> Result<Vec<MigrationFile>, String> {
+    let revert: Vec<MigrationFile> = files.iter()
+        .rev()
+        .filter(|f| f.version > target && applied.iter().any(|a| a.name == f.name))
+        .cloned()
+        .collect();
+    let irreversible: Vec<&str> = revert.iter().filter(|f| f.down.is_none()).map(|f| f.name.as_str()).collect();
+    if !irreversible.is_empty() {
+        return Err(format!("No down script for {}", irreversible.join(", ")));
+    }
+    Ok(revert)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(names: &[&str]) -> Vec<(String, String)> {
+        names.iter().map(|n| (n.to_string(), format!("sum-{}", n))).collect()
+    }
+
+    fn applied(name: &str, checksum: Option<&str>) -> AppliedMigration {
+        AppliedMigration { name: name.into(), checksum: checksum.map(Into::into) }
+    }
+
+    #[test]
+    fn test_collect_pairs_down_scripts() {
+        let migrations = collect(&files(&["0002_zones.surql", "0001_initial.surql", "0002_zones.down.surql"])).unwrap();
+        assert_eq!(migrations.iter().map(|m| m.version).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(migrations[1].down.as_deref(), Some("0002_zones.down.surql"));
+        assert_eq!(migrations[0].down, None);
+
+        assert!(collect(&files(&["0001_a.surql", "0001_b.surql"])).unwrap_err().contains("both have version 1"));
+        assert!(collect(&files(&["0003_c.down.surql"])).unwrap_err().contains("no matching 0003_c.surql"));
+        assert!(collect(&files(&["initial.surql"])).is_err());
+    }
+
+    #[test]
+    fn test_plan_and_rollback() {
+        let migrations = collect(&files(&["0001_a.surql", "0002_b.surql", "0002_b.down.surql", "0003_c.surql", "0003_c.down.surql", "0004_d.surql"])).unwrap();
+        let rows = vec![
+            applied("0001_a.surql", None),
+            applied("0002_b.surql", Some("sum-0002_b.surql")),
+            applied("0003_c.surql", Some("edited")),
+        ];
+
+        let plan = plan(&migrations, &rows);
+        assert_eq!(plan.pending.iter().map(|m| m.version).collect::<Vec<_>>(), vec![4]);
+        assert_eq!(plan.unrecorded.iter().map(|m| m.version).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(plan.changed, vec!["0003_c.surql".to_string()]);
+        assert_eq!(schema_version(&migrations, &rows), 3);
+
+        let revert = rollback_plan(&migrations, &rows, 1).unwrap();
+        assert_eq!(revert.iter().map(|m| m.version).collect::<Vec<_>>(), vec![3, 2]);
+        assert!(rollback_plan(&migrations, &rows, 3).unwrap().is_empty());
+        assert_eq!(rollback_plan(&migrations, &rows, 0).unwrap_err(), "No down script for 0001_a.surql");
+    }
+}
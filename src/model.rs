@@ -21,8 +21,31 @@ pub enum HomeTab {
     MyPlants,
     /// The tab displaying tasks that need to be done today.
     Tasks,
+    /// The tab displaying each plant's care windows over the next month.
+    Timeline,
+    /// The tab displaying a two-week calendar of care due each day, for planning trips.
+    Forecast,
     /// The tab displaying seasonal care information and transitions.
     Seasons,
+    /// The tab reconstructing the collection as it stood on a past date.
+    History,
+    /// The tab summarizing what the collection cost, by vendor, source and year.
+    Stats,
+    /// The tab listing plants that died or left the collection, with their journals.
+    Archive,
+    /// The tab listing the monthly collection reports.
+    Reports,
+}
+
+/// What is it? The user's accessibility display preferences.
+/// Why does it exist? Some growers are made unwell by motion or need stronger contrast than the default botanical palette, and the choice should follow them across devices.
+/// How should it be used? Load via `server_fns::preferences::get_accessibility_prefs`, dispatch `Msg::SetAccessibility`, and let `Cmd::ApplyAccessibility` put the matching classes on the document root.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AccessibilityPrefs {
+    /// Turns off modal/fade animations, transitions, and the first-bloom confetti.
+    pub reduced_motion: bool,
+    /// Switches to the high-contrast theme variant (darker muted text, stronger borders and focus rings).
+    pub high_contrast: bool,
 }
 
 /// What is it? The central state struct for the application's UI, following The Elm Architecture (TEA).
@@ -38,6 +61,8 @@ pub struct Model {
     pub show_settings: bool,
     /// Whether the AI plant scanner modal is currently active.
     pub show_scanner: bool,
+    /// Whether the AI care assistant chat is open.
+    pub show_chat: bool,
     /// Whether the modal for adding a new orchid is open.
     pub show_add_modal: bool,
     /// Scanned data ready to be pre-filled into the add/edit form.
@@ -46,8 +71,12 @@ pub struct Model {
     pub temp_unit: String,
     /// The user's hemisphere ("N" or "S") for seasonal calculations.
     pub hemisphere: String,
+    /// Days past due before watering counts as overdue (per-plant overrides win).
+    pub overdue_grace_days: u32,
     /// Whether the dark visual theme is currently enabled.
     pub dark_mode: bool,
+    /// Reduced motion and high-contrast settings.
+    pub accessibility: AccessibilityPrefs,
     /// The growing zone currently being configured in the setup wizard.
     pub wizard_zone: Option<GrowingZone>,
     /// The currently active tab on the home dashboard.
@@ -61,11 +90,14 @@ impl Default for Model {
             selected_orchid: None,
             show_settings: false,
             show_scanner: false,
+            show_chat: false,
             show_add_modal: false,
             prefill_data: None,
             temp_unit: "C".to_string(),
             hemisphere: "N".to_string(),
+            overdue_grace_days: 0,
             dark_mode: false,
+            accessibility: AccessibilityPrefs::default(),
             wizard_zone: None,
             home_tab: HomeTab::MyPlants,
         }
@@ -78,7 +110,7 @@ impl Default for Model {
 pub enum Msg {
     // Navigation
     /// Select an orchid to view details, or clear the selection.
-    SelectOrchid(Option<Orchid>),
+    SelectOrchid(Option<Box<Orchid>>),
     /// Change the layout mode of the plant list.
     SetViewMode(ViewMode),
 
@@ -87,6 +119,8 @@ pub enum Msg {
     ShowSettings(bool),
     /// Toggle the visibility of the AI scanner modal.
     ShowScanner(bool),
+    /// Toggle the visibility of the AI care assistant chat.
+    ShowChat(bool),
     /// Toggle the visibility of the add orchid modal.
     ShowAddModal(bool),
 
@@ -100,10 +134,14 @@ pub enum Msg {
         /// The new temperature unit to apply.
         temp_unit: String,
     },
+    /// Apply a new default watering overdue grace period.
+    SetOverdueGraceDays(u32),
 
     // Theme
     /// Toggle between light and dark visual themes.
     ToggleDarkMode,
+    /// Apply new reduced motion / high-contrast preferences.
+    SetAccessibility(AccessibilityPrefs),
 
     // Wizard
     /// Open the setup wizard, optionally for a specific growing zone.
@@ -138,4 +176,6 @@ pub enum Msg {
 pub enum Cmd {
     /// Command to apply the dark mode theme to the document body.
     ApplyDarkMode(bool),
+    /// Command to set the reduced motion and high-contrast classes on the document root.
+    ApplyAccessibility(AccessibilityPrefs),
 }
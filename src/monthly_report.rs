@@ -0,0 +1,266 @@
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Most journal notes quoted to the model for one month.
+pub const MAX_REPORT_NOTES: usize = 40;
+/// Longest journal note quoted to the model, in characters.
+pub const MAX_QUOTED_NOTE_LEN: usize = 200;
+/// Alert types that count as climate excursions.
+pub const CLIMATE_ALERT_TYPES: &[&str] = &["temperature_low", "temperature_high", "humidity_low", "humidity_high", "condensation_risk"];
+/// Most late-watered plants listed in a month's facts.
+pub const MAX_LATE_PLANTS: usize = 5;
+/// Longest push notification body, in characters.
+pub const MAX_NOTIFICATION_LEN: usize = 180;
+/// Most past reports returned to the reports tab.
+pub const REPORT_HISTORY_LIMIT: usize = 24;
+
+/// A calendar month a report covers, in UTC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportMonth {
+    /// The stored key, e.g. "2026-09".
+    pub key: String,
+    /// Display form, e.g. "September 2026".
+    pub label: String,
+    /// First instant of the month.
+    pub start: DateTime<Utc>,
+    /// First instant of the following month.
+    pub end: DateTime<Utc>,
+}
+
+impl ReportMonth {
+    /// The month `year`/`month` (1-12).
+    pub fn new(year: i32, month: u32) -> Self {
+        let first = NaiveDate::from_ymd_opt(year, month, 1).expect("valid month");
+        let next = if month == 12 {
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(year, month + 1, 1)
+        }.expect("valid month");
+        let midnight = |d: NaiveDate| Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).expect("valid time"));
+        Self {
+            key: first.format("%Y-%m").to_string(),
+            label: first.format("%B %Y").to_string(),
+            start: midnight(first),
+            end: midnight(next),
+        }
+    }
+
+    /// The last full month before `now`.
+    pub fn previous(now: DateTime<Utc>) -> Self {
+        if now.month() == 1 {
+            Self::new(now.year() - 1, 12)
+        } else {
+            Self::new(now.year(), now.month() - 1)
+        }
+    }
+}
+
+/// A stretch of climate alerts in one zone during the month.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Excursion {
+    /// The zone's name, or "Collection" for alerts not tied to a zone.
+    pub zone: String,
+    /// What went out of range, e.g. "Temperature high".
+    pub kind: String,
+    /// How many times it was raised.
+    pub count: u32,
+}
+
+/// Groups `(zone name, alert type)` pairs into excursions, most frequent first. Alert types are
+/// shown as words: "humidity_low" becomes "Humidity low".
+pub fn group_excursions(alerts: impl IntoIterator<Item = (String, String)>) -> Vec<Excursion> {
+    let mut out: Vec<Excursion> = Vec::new();
+    for (zone, alert_type) in alerts {
+        let words = alert_type.replace('_', " ");
+        let mut chars = words.chars();
+        let kind = chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default();
+        match out.iter_mut().find(|e| e.zone == zone && e.kind == kind) {
+            Some(e) => e.count += 1,
+            None => out.push(Excursion { zone, kind, count: 1 }),
+        }
+    }
+    out.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.zone.cmp(&b.zone)));
+    out
+}
+
+/// A plant whose waterings ran late during the month.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LatePlant {
+    /// The plant's name.
+    pub name: String,
+    /// Late waterings in the month.
+    pub late: u32,
+    /// Average days past due across them.
+    pub average_days_late: f64,
+}
+
+/// What happened in a collection over one month, gathered from the journal, alerts and watering history.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonthlyFacts {
+    /// Plants in the collection.
+    pub plants: usize,
+    /// Journal entries per event type, most frequent first; untyped notes count as "Note".
+    pub entries_by_event: Vec<(String, u32)>,
+    /// Notes from the month's journal as "Plant (Event): note", oldest first.
+    pub notes: Vec<String>,
+    /// Plants with a 'Flowering' entry in the month.
+    pub blooming: Vec<String>,
+    /// Climate alerts raised, most frequent first.
+    pub excursions: Vec<Excursion>,
+    /// Waterings that came on time.
+    pub waterings_on_time: u32,
+    /// Waterings that came late.
+    pub waterings_late: u32,
+    /// The plants watered late most often.
+    pub late_plants: Vec<LatePlant>,
+}
+
+impl MonthlyFacts {
+    /// Whether nothing was logged or raised, so there's nothing to report.
+    pub fn is_quiet(&self) -> bool {
+        self.entries_by_event.is_empty() && self.excursions.is_empty()
+    }
+
+    /// Counts `(event type, count)` pairs into `entries_by_event`, most frequent first.
+    pub fn tally_events<'a>(&mut self, events: impl IntoIterator<Item = Option<&'a str>>) {
+        let mut tally: Vec<(String, u32)> = Vec::new();
+        for event in events {
+            let event = event.filter(|e| !e.is_empty()).unwrap_or("Note");
+            match tally.iter_mut().find(|(e, _)| e == event) {
+                Some((_, n)) => *n += 1,
+                None => tally.push((event.to_string(), 1)),
+            }
+        }
+        tally.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        self.entries_by_event = tally;
+    }
+
+    /// The facts as plain lines, for the model's prompt and as the report when no model is configured.
+    pub fn to_text(&self, month: &ReportMonth) -> String {
+        let mut lines = vec![format!("{} \u{2014} {} plants in the collection.", month.label, self.plants)];
+        let total: u32 = self.entries_by_event.iter().map(|(_, n)| n).sum();
+        if total == 0 {
+            lines.push("No journal entries.".into());
+        } else {
+            let by_event = self.entries_by_event.iter()
+                .map(|(e, n)| format!("{} {}", n, e))
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(format!("Journal: {} entries ({}).", total, by_event));
+        }
+        if !self.blooming.is_empty() {
+            lines.push(format!("In bloom: {}.", self.blooming.join(", ")));
+        }
+        let judged = self.waterings_on_time + self.waterings_late;
+        if judged > 0 {
+            lines.push(format!(
+                "Waterings: {} of {} on time ({:.0}%).",
+                self.waterings_on_time, judged, self.waterings_on_time as f64 / judged as f64 * 100.0,
+            ));
+        }
+        if !self.late_plants.is_empty() {
+            let late = self.late_plants.iter()
+                .map(|p| format!("{} ({} late, {:.1} days on average)", p.name, p.late, p.average_days_late))
+                .collect::<Vec<_>>()
+                .join("; ");
+            lines.push(format!("Watered late most often: {}.", late));
+        }
+        if self.excursions.is_empty() {
+            lines.push("No climate alerts.".into());
+        } else {
+            lines.push("Climate alerts:".into());
+            lines.extend(self.excursions.iter().map(|e| format!("- {}: {} (\u{00D7}{})", e.zone, e.kind, e.count)));
+        }
+        if !self.notes.is_empty() {
+            lines.push("Journal notes:".into());
+            lines.extend(self.notes.iter().map(|n| format!("- {}", n)));
+        }
+        lines.join("\n")
+    }
+}
+
+/// A stored monthly collection report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MonthlyReport {
+    /// The report's record ID.
+    pub id: String,
+    /// The month covered, e.g. "2026-09".
+    pub month: String,
+    /// Display form of the month, e.g. "September 2026".
+    pub label: String,
+    /// The readable report.
+    pub summary: String,
+    /// The facts it was written from.
+    pub facts: String,
+    /// When it was written.
+    pub created_at: DateTime<Utc>,
+}
+
+/// The push notification body for a report: its first paragraph, cut at a sentence or word boundary
+/// to at most `MAX_NOTIFICATION_LEN` characters.
+pub fn notification_body(summary: &str) -> String {
+    let first = summary.trim().split("\n\n").next().unwrap_or_default().trim();
+    if first.chars().count() <= MAX_NOTIFICATION_LEN {
+        return first.to_string();
+    }
+    let cut: String = first.chars().take(MAX_NOTIFICATION_LEN - 1).collect();
+    if let Some(end) = cut.rfind(". ") {
+        return cut[..=end].to_string();
+    }
+    let end = cut.rfind(' ').unwrap_or(cut.len());
+    format!("{}\u{2026}", cut[..end].trim_end_matches([',', ';', ':']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previous_month_wraps_the_year() {
+        let month = ReportMonth::previous(Utc.with_ymd_and_hms(2027, 1, 3, 8, 0, 0).unwrap());
+        assert_eq!(month.key, "2026-12");
+        assert_eq!(month.label, "December 2026");
+        assert_eq!(month.start, Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap());
+        assert_eq!(month.end, Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_facts_text() {
+        let mut facts = MonthlyFacts {
+            plants: 12,
+            blooming: vec!["Big Phal".into()],
+            excursions: group_excursions([
+                ("Window".to_string(), "temperature_high".to_string()),
+                ("Tent".to_string(), "humidity_low".to_string()),
+                ("Tent".to_string(), "humidity_low".to_string()),
+            ]),
+            waterings_on_time: 9,
+            waterings_late: 3,
+            late_plants: vec![LatePlant { name: "Kingie".into(), late: 2, average_days_late: 2.5 }],
+            ..Default::default()
+        };
+        facts.tally_events([Some("Watered"), None, Some("Watered"), Some("Flowering")]);
+        assert!(!facts.is_quiet());
+        assert_eq!(
+            facts.to_text(&ReportMonth::new(2026, 9)),
+            "September 2026 \u{2014} 12 plants in the collection.\n\
+             Journal: 4 entries (2 Watered, 1 Flowering, 1 Note).\n\
+             In bloom: Big Phal.\n\
+             Waterings: 9 of 12 on time (75%).\n\
+             Watered late most often: Kingie (2 late, 2.5 days on average).\n\
+             Climate alerts:\n\
+             - Tent: Humidity low (\u{00D7}2)\n\
+             - Window: Temperature high (\u{00D7}1)",
+        );
+        assert!(MonthlyFacts::default().is_quiet());
+    }
+
+    #[test]
+    fn test_notification_body() {
+        assert_eq!(notification_body("A quiet month.\n\nMore detail."), "A quiet month.");
+        let long = format!("First sentence here. {}", "word ".repeat(60));
+        assert_eq!(notification_body(&long), "First sentence here.");
+        let body = notification_body(&"word ".repeat(60));
+        assert!(body.ends_with("word\u{2026}") && body.chars().count() <= MAX_NOTIFICATION_LEN);
+    }
+}
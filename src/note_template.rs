@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+/// Longest template name accepted, matching the schema assertion.
+pub const MAX_TEMPLATE_NAME_LEN: usize = 60;
+/// Most fields one template can hold.
+pub const MAX_TEMPLATE_FIELDS: usize = 20;
+/// Longest field label accepted.
+pub const MAX_TEMPLATE_FIELD_LEN: usize = 60;
+
+/// A reusable journal note: a name, the event type it logs as, and the fields it asks for.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoteTemplate {
+    /// The note_template record ID; `builtin:<slug>` for the shared starters.
+    pub id: String,
+    /// What the picker shows, such as "Repotting checklist".
+    pub name: String,
+    /// Event type key set on the note when the template is picked.
+    pub event_type: Option<String>,
+    /// Field labels, one line each in the expanded note.
+    pub fields: Vec<String>,
+    /// True for the starters offered to everyone; these cannot be deleted.
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+impl NoteTemplate {
+    /// The pre-structured note text: one "Label: " line per field, ready to be filled in.
+    pub fn expand(&self) -> String {
+        self.fields.iter().map(|f| format!("{}: ", f)).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Checks the name and fields against the schema limits; the error says what to fix.
+    pub fn validate(&self) -> Result<(), String> {
+        let name_len = self.name.trim().chars().count();
+        if name_len == 0 || name_len > MAX_TEMPLATE_NAME_LEN {
+            return Err(format!("Name must be between 1 and {} characters", MAX_TEMPLATE_NAME_LEN));
+        }
+        if self.fields.is_empty() || self.fields.len() > MAX_TEMPLATE_FIELDS {
+            return Err(format!("Add between 1 and {} fields", MAX_TEMPLATE_FIELDS));
+        }
+        if let Some(f) = self.fields.iter().find(|f| f.chars().count() > MAX_TEMPLATE_FIELD_LEN) {
+            return Err(format!("Field '{}' is longer than {} characters", f, MAX_TEMPLATE_FIELD_LEN));
+        }
+        Ok(())
+    }
+}
+
+/// Splits the settings textarea into field labels: one per line, trimmed, blanks and a
+/// trailing colon dropped.
+pub fn parse_fields(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|l| l.trim().trim_end_matches(':').trim_end().to_string())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// The starter templates every user sees ahead of their own.
+pub fn builtin_note_templates() -> Vec<NoteTemplate> {
+    let starter = |slug: &str, name: &str, event_type: &str, fields: &[&str]| NoteTemplate {
+        id: format!("builtin:{}", slug),
+        name: name.to_string(),
+        event_type: Some(event_type.to_string()),
+        fields: fields.iter().map(|f| f.to_string()).collect(),
+        builtin: true,
+    };
+    vec![
+        starter("repotting", "Repotting checklist", "Repotted",
+            &["Medium", "Pot size", "Pot type", "Root condition", "Roots trimmed", "Next check"]),
+        starter("spike", "Spike check", "Flowering",
+            &["Spike length", "Buds", "Open flowers", "Staked"]),
+        starter("health", "Health check", "Note",
+            &["Leaves", "Roots", "Pests seen", "Action taken"]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_and_parse_fields() {
+        let t = NoteTemplate {
+            id: "note_template:a".into(),
+            name: "Repot".into(),
+            event_type: Some("Repotted".into()),
+            fields: parse_fields("Medium\n\n  Pot size:  \nRoot condition\n"),
+            builtin: false,
+        };
+        assert_eq!(t.fields, vec!["Medium", "Pot size", "Root condition"]);
+        assert_eq!(t.expand(), "Medium: \nPot size: \nRoot condition: ");
+        assert!(t.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_limits() {
+        let mut t = builtin_note_templates().remove(0);
+        assert!(t.validate().is_ok());
+        t.fields = vec!["x".repeat(MAX_TEMPLATE_FIELD_LEN + 1)];
+        assert!(t.validate().is_err());
+        t.fields = Vec::new();
+        assert!(t.validate().is_err());
+        t.fields = vec!["Medium".into()];
+        t.name = "  ".into();
+        assert!(t.validate().is_err());
+    }
+}
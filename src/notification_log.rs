@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Longest message body kept in the log, in characters.
+pub const MAX_LOGGED_BODY_LEN: usize = 200;
+/// Longest delivery error kept in the log, in characters.
+pub const MAX_LOGGED_ERROR_LEN: usize = 300;
+/// Most entries returned to the notification history.
+pub const NOTIFICATION_HISTORY_LIMIT: usize = 100;
+/// How long log entries are kept, in days.
+pub const NOTIFICATION_LOG_RETENTION_DAYS: i64 = 90;
+
+/// Where a notification was sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationChannel {
+    /// Web Push to a browser or installed app.
+    Push,
+    /// A Discord or Slack channel webhook.
+    Webhook,
+    /// An ntfy topic.
+    Ntfy,
+}
+
+impl NotificationChannel {
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationChannel::Push => "push",
+            NotificationChannel::Webhook => "webhook",
+            NotificationChannel::Ntfy => "ntfy",
+        }
+    }
+
+    /// Parses a stored name; anything unrecognised reads as Web Push.
+    pub fn from_str_lossy(s: &str) -> Self {
+        match s {
+            "webhook" => NotificationChannel::Webhook,
+            "ntfy" => NotificationChannel::Ntfy,
+            _ => NotificationChannel::Push,
+        }
+    }
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            NotificationChannel::Push => "Push",
+            NotificationChannel::Webhook => "Discord/Slack",
+            NotificationChannel::Ntfy => "ntfy",
+        }
+    }
+}
+
+/// One notification the server tried to send, and whether the channel accepted it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NotificationRecord {
+    /// The log entry's record ID.
+    pub id: String,
+    /// Where it was sent.
+    pub channel: NotificationChannel,
+    /// The notification's title.
+    pub title: String,
+    /// The start of its body, cut to `MAX_LOGGED_BODY_LEN`.
+    pub body: String,
+    /// Whether the push service, webhook or ntfy server accepted it.
+    pub delivered: bool,
+    /// Why it wasn't accepted.
+    pub error: Option<String>,
+    /// When it was sent.
+    pub created_at: DateTime<Utc>,
+}
+
+/// `s` cut to at most `max` characters, with an ellipsis when anything was dropped.
+pub fn clip(s: &str, max: usize) -> String {
+    let s = s.trim();
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let cut: String = s.chars().take(max.saturating_sub(1)).collect();
+    format!("{}\u{2026}", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_round_trip_and_clip() {
+        for channel in [NotificationChannel::Push, NotificationChannel::Webhook, NotificationChannel::Ntfy] {
+            assert_eq!(NotificationChannel::from_str_lossy(channel.as_str()), channel);
+        }
+        assert_eq!(NotificationChannel::from_str_lossy("carrier pigeon"), NotificationChannel::Push);
+        assert_eq!(clip("  short  ", 10), "short");
+        assert_eq!(clip("Tent humidity low", 8), "Tent hu\u{2026}");
+        assert_eq!(clip("Tent humidity low", 8).chars().count(), 8);
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+/// The public ntfy server, used when no server is given.
+pub const DEFAULT_NTFY_SERVER: &str = "https://ntfy.sh";
+/// Longest server URL accepted, in bytes.
+pub const MAX_NTFY_SERVER_LEN: usize = 200;
+/// Longest topic name ntfy allows.
+pub const MAX_NTFY_TOPIC_LEN: usize = 64;
+
+/// Where a person's notifications are published on ntfy: a server, a topic on it, and an access
+/// token for servers that require one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NtfyTarget {
+    /// The server's base URL without a trailing slash, e.g. "https://ntfy.example.org".
+    pub server: String,
+    /// The topic subscribers listen on.
+    pub topic: String,
+    /// A bearer token (`tk_...`) for servers with access control.
+    pub token: Option<String>,
+}
+
+impl NtfyTarget {
+    /// Checks and tidies settings as typed: a blank server means ntfy.sh, the server must be an http(s)
+    /// URL without credentials in it, and the topic may only use letters, digits, `-` and `_`.
+    pub fn new(server: &str, topic: &str, token: Option<&str>) -> Result<Self, String> {
+        let server = server.trim().trim_end_matches('/');
+        let server = if server.is_empty() { DEFAULT_NTFY_SERVER } else { server };
+        if server.len() > MAX_NTFY_SERVER_LEN {
+            return Err(format!("Server URL must be at most {} characters", MAX_NTFY_SERVER_LEN));
+        }
+        let host = server.strip_prefix("https://").or_else(|| server.strip_prefix("http://"))
+            .ok_or_else(|| "Server URL must start with https:// or http://".to_string())?;
+        if host.is_empty() || host.contains(['@', '?', '#', ' ']) {
+            return Err("Server URL must be a plain address like https://ntfy.example.org".into());
+        }
+
+        let topic = topic.trim();
+        if topic.is_empty() || topic.len() > MAX_NTFY_TOPIC_LEN
+            || !topic.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!("Topic must be 1-{} letters, digits, - or _", MAX_NTFY_TOPIC_LEN));
+        }
+
+        Ok(Self {
+            server: server.to_string(),
+            topic: topic.to_string(),
+            token: token.map(str::trim).filter(|t| !t.is_empty()).map(String::from),
+        })
+    }
+
+    /// The JSON message to POST to `server`. ntfy's JSON form keeps non-ASCII titles intact, which
+    /// the `Title` header wouldn't.
+    pub fn payload(&self, title: &str, body: &str) -> serde_json::Value {
+        serde_json::json!({ "topic": self.topic, "title": title, "message": body })
+    }
+
+    /// The settings as shown in the browser; the token never leaves the server.
+    pub fn status(&self) -> NtfyStatus {
+        NtfyStatus { server: self.server.clone(), topic: self.topic.clone(), has_token: self.token.is_some() }
+    }
+}
+
+/// A person's ntfy settings as shown in notification settings.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NtfyStatus {
+    /// The server's base URL.
+    pub server: String,
+    /// The topic notifications are published to.
+    pub topic: String,
+    /// Whether an access token is stored.
+    pub has_token: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_defaults_and_validation() {
+        let target = NtfyTarget::new(" ", "orchid-alerts_42", Some("  ")).unwrap();
+        assert_eq!(target.server, DEFAULT_NTFY_SERVER);
+        assert_eq!(target.token, None);
+
+        let own = NtfyTarget::new("http://ntfy.lan:8080/", "greenhouse", Some("tk_abc")).unwrap();
+        assert_eq!(own.server, "http://ntfy.lan:8080");
+        assert!(own.status().has_token);
+
+        assert!(NtfyTarget::new("ftp://ntfy.lan", "greenhouse", None).is_err());
+        assert!(NtfyTarget::new("https://user:pw@ntfy.lan", "greenhouse", None).is_err());
+        assert!(NtfyTarget::new("", "my topic", None).is_err());
+        assert!(NtfyTarget::new("", &"t".repeat(65), None).is_err());
+    }
+
+    #[test]
+    fn test_payload() {
+        let target = NtfyTarget::new("", "greenhouse", None).unwrap();
+        assert_eq!(
+            target.payload("Warning", "Tent is 33.2°C"),
+            serde_json::json!({ "topic": "greenhouse", "title": "Warning", "message": "Tent is 33.2°C" }),
+        );
+    }
+}
@@ -161,6 +161,60 @@ pub struct HardwareDevice {
     pub config: String,
 }
 
+impl HardwareDevice {
+    /// Station IDs configured on a Tempest device, primary first.
+    /// Zones linked to the device pick one by 1-based `hardware_port`.
+    pub fn tempest_stations(&self) -> Vec<String> {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(&self.config) else {
+            return Vec::new();
+        };
+        let primary = json.get("station_id").and_then(|v| v.as_str());
+        let extra = json.get("station_ids")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str());
+        let mut out: Vec<String> = Vec::new();
+        for id in primary.into_iter().chain(extra) {
+            let id = id.trim();
+            if !id.is_empty() && !out.iter().any(|s| s == id) {
+                out.push(id.to_string());
+            }
+        }
+        out
+    }
+
+    /// Topics configured on an MQTT broker device, in order.
+    /// Zones linked to the device pick one by 1-based `hardware_port`.
+    pub fn mqtt_topics(&self) -> Vec<String> {
+        serde_json::from_str::<serde_json::Value>(&self.config)
+            .ok()
+            .and_then(|json| json.get("topics").and_then(|v| v.as_array()).cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect()
+    }
+
+    /// `(sensor_id, name)` of the sensors chosen on a SensorPush device, in order.
+    /// Zones linked to the device pick one by 1-based `hardware_port`.
+    pub fn sensorpush_sensors(&self) -> Vec<(String, String)> {
+        serde_json::from_str::<serde_json::Value>(&self.config)
+            .ok()
+            .and_then(|json| json.get("sensors").and_then(|v| v.as_array()).cloned())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|s| {
+                let id = s.get("id").and_then(|v| v.as_str())?;
+                let name = s.get("name").and_then(|v| v.as_str()).unwrap_or_default();
+                Some((id.to_string(), name.to_string()))
+            })
+            .collect()
+    }
+}
+
 /// What is it? A snapshot of environmental metrics (temperature, humidity, etc.) recorded at a specific moment in time.
 /// Why does it exist? It provides the historical and current real-world data necessary to analyze zone conditions, calculate VPD, and adjust watering schedules dynamically.
 /// How should it be used? Insert these records into SurrealDB periodically via sensor polling or manual entry, and query them to generate climate charts and alerts.
@@ -182,10 +236,46 @@ pub struct ClimateReading {
     #[serde(default)]
     #[cfg_attr(feature = "ssr", surreal(default))]
     pub vpd: Option<f64>,
+    /// Dew point in Celsius, calculated from temperature and humidity.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub dew_point: Option<f64>,
     /// Amount of precipitation recorded in mm, if any.
     #[serde(default)]
     #[cfg_attr(feature = "ssr", surreal(default))]
     pub precipitation: Option<f64>,
+    /// UV index, reported by outdoor weather stations.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub uv_index: Option<f64>,
+    /// Solar radiation in W/m², reported by outdoor weather stations.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub solar_radiation: Option<f64>,
+    /// Average wind speed in m/s, reported by outdoor weather stations.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub wind_speed: Option<f64>,
+    /// Wind gust speed in m/s, reported by outdoor weather stations.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub wind_gust: Option<f64>,
+    /// Illuminance in lux, reported by light sensors and weather stations.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub lux: Option<f64>,
+    /// Photosynthetic photon flux density in µmol/m²/s, reported by quantum (PAR) sensors.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub ppfd: Option<f64>,
+    /// CO2 concentration in ppm, reported by CO2 sensors.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub co2_ppm: Option<f64>,
+    /// Substrate moisture percentage, reported by soil/substrate probes.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub substrate_moisture_pct: Option<f64>,
     /// The system or device that generated this reading.
     #[serde(default)]
     #[cfg_attr(feature = "ssr", surreal(default))]
@@ -194,6 +284,26 @@ pub struct ClimateReading {
     pub recorded_at: DateTime<Utc>,
 }
 
+impl ClimateReading {
+    /// Whether this reading carries any outdoor-only metrics (UV, solar radiation, wind).
+    pub fn has_outdoor_metrics(&self) -> bool {
+        self.uv_index.is_some()
+            || self.solar_radiation.is_some()
+            || self.wind_speed.is_some()
+            || self.wind_gust.is_some()
+    }
+
+    /// Whether this reading carries a light measurement (lux or PPFD).
+    pub fn has_light_metrics(&self) -> bool {
+        self.lux.is_some() || self.ppfd.is_some()
+    }
+
+    /// Whether this reading carries a CO2 or substrate moisture measurement.
+    pub fn has_co2_or_moisture(&self) -> bool {
+        self.co2_ppm.is_some() || self.substrate_moisture_pct.is_some()
+    }
+}
+
 /// What is it? A utility function comparing an orchid's required light against the light available in its current placement.
 /// Why does it exist? It provides a quick way to validate whether a user has placed their plant in an environment that meets its basic photosynthetic needs.
 /// How should it be used? Call it with the orchid's placement name and light requirement, passing the list of known zones, to trigger warnings if it returns false.
@@ -230,6 +340,395 @@ pub struct LogEntry {
     #[serde(default)]
     #[cfg_attr(feature = "ssr", surreal(default))]
     pub event_type: Option<String>,
+    /// Conditions in the plant's zone when the entry was logged, if a recent reading existed.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub climate: Option<EntryClimate>,
+    /// How the plant was watered, on 'Watered' entries.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub watering_method: Option<WateringMethod>,
+    /// How much water was given in millilitres, on 'Watered' entries, if recorded.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub water_volume_ml: Option<u32>,
+    /// Why the watering was put off, on 'Skipped' entries.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub skip_reason: Option<SkipReason>,
+    /// How the medium was when watered, on 'Watered' entries the grower rated.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub medium_feedback: Option<MediumFeedback>,
+    /// Sizes and counts taken, on 'Measured' entries.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub measurement: Option<crate::growth::GrowthMeasurement>,
+    /// Root health and the old medium, on 'Repotted' entries assessed from a photo.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub root_assessment: Option<crate::root_health::RootAssessment>,
+}
+
+/// Largest watering volume accepted on a log entry, in millilitres.
+pub const MAX_WATER_VOLUME_ML: u32 = 20_000;
+
+/// What is it? How a plant was watered: a full soak, a top-down drench, a light misting, or a flush to rinse out salts.
+/// Why does it exist? A misting and a soak are both "watering", but only one rewets the medium; recording the method keeps next-due dates honest.
+/// How should it be used? Set on 'Watered' log entries and as a plant's default. Use `resets_schedule` to decide whether an entry moves `last_watered_at`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types", untagged))]
+pub enum WateringMethod {
+    /// Pot submerged until the medium is saturated.
+    Soak,
+    /// Watered through from the top until it runs out of the drainage holes.
+    Drench,
+    /// Leaves, roots or mount sprayed; the medium stays mostly dry.
+    Mist,
+    /// Heavy rinse with plain water to wash out fertilizer salts.
+    Flush,
+}
+
+impl WateringMethod {
+    /// Every method, in the order shown in pickers.
+    pub const ALL: [WateringMethod; 4] = [
+        WateringMethod::Soak,
+        WateringMethod::Drench,
+        WateringMethod::Mist,
+        WateringMethod::Flush,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WateringMethod::Soak => "Soak",
+            WateringMethod::Drench => "Drench",
+            WateringMethod::Mist => "Mist",
+            WateringMethod::Flush => "Flush",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == s)
+    }
+
+    /// Display label for pickers and the journal.
+    pub fn label(&self) -> &'static str {
+        match self {
+            WateringMethod::Soak => "Soaked",
+            WateringMethod::Drench => "Drenched",
+            WateringMethod::Mist => "Misted",
+            WateringMethod::Flush => "Flushed",
+        }
+    }
+
+    /// Whether a watering with `method` restarts the plant's watering countdown.
+    ///
+    /// A misting only counts for plants whose usual method is misting (mounts, for instance);
+    /// otherwise it leaves the medium dry and the plant still due. No method means the plant's default.
+    pub fn resets_schedule(method: Option<WateringMethod>, default: Option<WateringMethod>) -> bool {
+        method != Some(WateringMethod::Mist) || default == Some(WateringMethod::Mist)
+    }
+}
+
+/// What is it? Where a plant came from: a nursery, a show or sale, an online order, a trade, a gift, a rescue, or a propagation of another plant.
+/// Why does it exist? Collectors track provenance alongside price and vendor, and the collection-value summary breaks spend down by it.
+/// How should it be used? Set on `Orchid::acquisition_source` from the add and edit forms. Use `label` in pickers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types", untagged))]
+pub enum AcquisitionSource {
+    /// Bought in person from a nursery or garden centre.
+    Nursery,
+    /// Bought at an orchid show or society sale.
+    Show,
+    /// Ordered online or by mail.
+    Online,
+    /// Swapped with another grower.
+    Trade,
+    /// Received as a gift.
+    Gift,
+    /// Taken in from a clearance rack or someone giving up on it.
+    Rescue,
+    /// Grown from another plant in the collection.
+    Propagation,
+}
+
+impl AcquisitionSource {
+    /// Every source, in the order shown in pickers.
+    pub const ALL: [AcquisitionSource; 7] = [
+        AcquisitionSource::Nursery,
+        AcquisitionSource::Show,
+        AcquisitionSource::Online,
+        AcquisitionSource::Trade,
+        AcquisitionSource::Gift,
+        AcquisitionSource::Rescue,
+        AcquisitionSource::Propagation,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AcquisitionSource::Nursery => "Nursery",
+            AcquisitionSource::Show => "Show",
+            AcquisitionSource::Online => "Online",
+            AcquisitionSource::Trade => "Trade",
+            AcquisitionSource::Gift => "Gift",
+            AcquisitionSource::Rescue => "Rescue",
+            AcquisitionSource::Propagation => "Propagation",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|v| v.as_str() == s)
+    }
+
+    /// Display label for pickers and the details view.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AcquisitionSource::Nursery => "Nursery",
+            AcquisitionSource::Show => "Show or sale",
+            AcquisitionSource::Online => "Online order",
+            AcquisitionSource::Trade => "Trade",
+            AcquisitionSource::Gift => "Gift",
+            AcquisitionSource::Rescue => "Rescue",
+            AcquisitionSource::Propagation => "Propagation",
+        }
+    }
+}
+
+/// What is it? Why a plant left the collection: it died, or it was sold, traded or given away.
+/// Why does it exist? Plants that are gone shouldn't clutter today's tasks or watering schedules, but their journals are still worth keeping.
+/// How should it be used? Pass to `archive_orchid`; it's stored on `Orchid::archive_reason`. Use `label` in pickers and the archive view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types", untagged))]
+pub enum ArchiveReason {
+    /// The plant died.
+    Died,
+    /// Sold to someone else.
+    Sold,
+    /// Swapped with another grower.
+    Traded,
+    /// Given away.
+    Gifted,
+}
+
+impl ArchiveReason {
+    /// Every reason, in the order shown in pickers.
+    pub const ALL: [ArchiveReason; 4] = [
+        ArchiveReason::Died,
+        ArchiveReason::Sold,
+        ArchiveReason::Traded,
+        ArchiveReason::Gifted,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArchiveReason::Died => "Died",
+            ArchiveReason::Sold => "Sold",
+            ArchiveReason::Traded => "Traded",
+            ArchiveReason::Gifted => "Gifted",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|v| v.as_str() == s)
+    }
+
+    /// Display label for pickers and the archive view.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArchiveReason::Died => "Died",
+            ArchiveReason::Sold => "Sold",
+            ArchiveReason::Traded => "Traded away",
+            ArchiveReason::Gifted => "Gifted",
+        }
+    }
+}
+
+/// What is it? Why a grower put off a watering: the medium is still wet, the plant is in spike, it's resting, it's too cold, or something else.
+/// Why does it exist? "Overdue" is wrong when the grower checked the pot and chose to wait; recording the reason explains the gap in the journal and tells the schedule the plant dries slower than its interval says.
+/// How should it be used? Pass to `skip_watering`; it's stored on the 'Skipped' log entry. Use `label` in pickers and in the entry's note.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types", untagged))]
+pub enum SkipReason {
+    /// The medium was checked and is still damp.
+    MediumStillWet,
+    /// A flower spike is developing and the grower is holding back.
+    InSpike,
+    /// The plant is in its dry rest.
+    Resting,
+    /// Too cold to water safely.
+    TooCold,
+    /// Anything else; the note says why.
+    Other,
+}
+
+impl SkipReason {
+    /// Every reason, in the order shown in pickers.
+    pub const ALL: [SkipReason; 5] = [
+        SkipReason::MediumStillWet,
+        SkipReason::InSpike,
+        SkipReason::Resting,
+        SkipReason::TooCold,
+        SkipReason::Other,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::MediumStillWet => "MediumStillWet",
+            SkipReason::InSpike => "InSpike",
+            SkipReason::Resting => "Resting",
+            SkipReason::TooCold => "TooCold",
+            SkipReason::Other => "Other",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.as_str() == s)
+    }
+
+    /// Display label for pickers and the journal.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SkipReason::MediumStillWet => "Medium still wet",
+            SkipReason::InSpike => "In spike",
+            SkipReason::Resting => "Resting",
+            SkipReason::TooCold => "Too cold",
+            SkipReason::Other => "Other",
+        }
+    }
+}
+
+/// What is it? The grower's verdict on a watering: the medium had dried out too far, was just right, or was still wet.
+/// Why does it exist? Pot, medium and climate factors are only estimates; what the grower finds in the pot is the ground truth that lets each plant's schedule learn.
+/// How should it be used? Pass to `rate_watering` after "Water Now"; `watering::learn_water_frequency` turns it into a new base frequency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types", untagged))]
+pub enum MediumFeedback {
+    /// Bone dry, roots silvery for a while: water sooner.
+    TooDry,
+    /// Just dried out: the schedule is right.
+    JustRight,
+    /// Still damp: water later.
+    StillWet,
+}
+
+impl MediumFeedback {
+    /// Every rating, in the order shown on the buttons.
+    pub const ALL: [MediumFeedback; 3] = [
+        MediumFeedback::TooDry,
+        MediumFeedback::JustRight,
+        MediumFeedback::StillWet,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MediumFeedback::TooDry => "TooDry",
+            MediumFeedback::JustRight => "JustRight",
+            MediumFeedback::StillWet => "StillWet",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == s)
+    }
+
+    /// Display label for buttons and the journal.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MediumFeedback::TooDry => "Was too dry",
+            MediumFeedback::JustRight => "Just right",
+            MediumFeedback::StillWet => "Still wet",
+        }
+    }
+}
+
+/// What is it? A snapshot of a zone's temperature, humidity, and VPD taken when a journal entry was created.
+/// Why does it exist? Bud blast, yellowing, and other problems are often climate-driven; attaching conditions to the entry lets growers look back at what the plant was experiencing without cross-referencing climate history.
+/// How should it be used? Filled in server-side when log entries are created; display with `describe` next to the entry in the growth thread.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types"))]
+pub struct EntryClimate {
+    /// Temperature in Celsius.
+    pub temperature: f64,
+    /// Relative humidity percentage.
+    pub humidity: f64,
+    /// Vapor pressure deficit in kPa, if the source reported or derived it.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub vpd: Option<f64>,
+}
+
+impl EntryClimate {
+    /// Short display form, e.g. "24.5°C · 62% RH · 1.16 kPa", with temperature in the given unit ("C" or "F").
+    pub fn describe(&self, unit: &str) -> String {
+        let temp = if unit == "F" {
+            format!("{:.1}\u{00B0}F", self.temperature * 9.0 / 5.0 + 32.0)
+        } else {
+            format!("{:.1}\u{00B0}C", self.temperature)
+        };
+        let mut parts = vec![temp, format!("{:.0}% RH", self.humidity)];
+        if let Some(vpd) = self.vpd {
+            parts.push(format!("{:.2} kPa", vpd));
+        }
+        parts.join(" \u{00B7} ")
+    }
+}
+
+/// What is it? A care event type (e.g., 'Watered', or a user's own 'Pollinated') with its display icon and color.
+/// Why does it exist? Event types live in a DB-backed registry so users can add their own categories, and every view (timeline, picker, gallery, quick actions) styles them the same way.
+/// How should it be used? Load the registry via `get_event_types`, provide it as context, and resolve keys with `lookup_event_type`. `color` is a palette name, not a CSS class.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventTypeDef {
+    /// Stable key stored on log entries (e.g., 'Watered').
+    pub key: String,
+    /// Human-readable label.
+    pub label: String,
+    /// Emoji icon shown next to the label.
+    pub emoji: String,
+    /// Palette color name (e.g., 'sky', 'pink').
+    pub color: String,
+    /// Whether this type is offered in the default quick log row.
+    #[serde(default)]
+    pub quick_action: bool,
+    /// True for the seeded defaults shared by all users; these cannot be deleted.
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+/// What is it? One button in the user's "Quick Log" row on the orchid detail view.
+/// Why does it exist? Growers lean on different routines (misting, staking, fertilizing), so the row of one-tap log buttons is chosen and ordered per user instead of being fixed.
+/// How should it be used? Store an ordered list in user preferences. Built-in shortcuts set only `event_type`; custom shortcuts also set a `label`, `emoji`, and the `note` text written to the log entry.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuickActionItem {
+    /// The event type key logged when tapped (e.g., 'Watered', 'Note').
+    pub event_type: String,
+    /// Custom button label; falls back to the event type's label.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Custom button emoji; falls back to the event type's emoji.
+    #[serde(default)]
+    pub emoji: Option<String>,
+    /// Note text written to the log entry (used by custom shortcuts like "Misted").
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Watering method recorded by 'Watered' shortcuts; None uses the plant's default.
+    #[serde(default)]
+    pub watering_method: Option<WateringMethod>,
 }
 
 /// What is it? A standardized enumeration of pot sizes based on volumetric capacity.
@@ -479,25 +978,128 @@ pub struct Orchid {
     #[serde(default)]
     #[cfg_attr(feature = "ssr", surreal(default))]
     pub par_ppfd: Option<f64>,
+    /// Per-plant override for how many days past due watering may slip before
+    /// the plant counts as overdue. None = use the owner's default.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub overdue_grace_days: Option<u32>,
+    /// How this plant is usually watered; quick logs and "Water Now" record it when no method is picked.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub default_watering_method: Option<WateringMethod>,
+    /// Set when a watering was skipped: the plant isn't due again before this time.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub water_deferred_until: Option<DateTime<Utc>>,
+    /// Semi-hydro only: days between reservoir top-ups. None = `DEFAULT_RESERVOIR_DAYS`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub reservoir_frequency_days: Option<u32>,
+    /// Semi-hydro only: when the reservoir was last topped up.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub last_topped_up_at: Option<DateTime<Utc>>,
+    /// When the plant joined the collection.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub acquired_at: Option<DateTime<Utc>>,
+    /// What was paid for it, in the grower's currency. None = not recorded; 0 for gifts and trades.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub acquisition_price: Option<f64>,
+    /// How it was obtained.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub acquisition_source: Option<AcquisitionSource>,
+    /// The vendor record it was bought from, if any.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub vendor_id: Option<String>,
+    /// The registered grex (hybrid group) name, such as "Paphiopedilum Maudiae".
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub grex_name: Option<String>,
+    /// The seed (pod) parent of the cross, always written first.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub seed_parent: Option<String>,
+    /// The pollen parent of the cross.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub pollen_parent: Option<String>,
+    /// When the plant left the collection. None while it's still being grown.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Why it left the collection.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub archive_reason: Option<ArchiveReason>,
+    /// The journal photo chosen to represent the plant. None shows the latest photo instead.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub cover_image: Option<String>,
+    /// When the plant's details were last saved. `update_orchid` refuses an edit made to an older copy.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
+/// Upper bound for the overdue grace period, per user or per plant.
+pub const MAX_OVERDUE_GRACE_DAYS: u32 = 30;
+
+/// Longest a watering can be skipped for in one go, in days.
+pub const MAX_SKIP_DAYS: u32 = 14;
+
+/// Reservoir top-up interval for semi-hydro plants without their own, in days.
+pub const DEFAULT_RESERVOIR_DAYS: u32 = 7;
+/// Longest reservoir top-up interval accepted, in days.
+pub const MAX_RESERVOIR_DAYS: u32 = 60;
+
+/// Longest grex or parent name accepted, matching the schema assertion.
+pub const MAX_PARENTAGE_LEN: usize = 200;
+
 impl Orchid {
     /// Days since last watered, or None if never watered.
     pub fn days_since_watered(&self) -> Option<i64> {
         self.last_watered_at.map(|dt| (Utc::now() - dt).num_days())
     }
 
-    /// True if watering is overdue based on water_frequency_days.
-    pub fn is_overdue(&self) -> bool {
-        self.days_since_watered()
-            .map(|days| days > self.water_frequency_days as i64)
+    /// Grace days for this plant: its own override, else the owner's default.
+    pub fn grace_days(&self, default_grace_days: u32) -> u32 {
+        self.overdue_grace_days.unwrap_or(default_grace_days)
+    }
+
+    /// True if watering is overdue based on water_frequency_days, once more
+    /// than the grace period has passed since the due date.
+    pub fn is_overdue(&self, default_grace_days: u32) -> bool {
+        let grace = self.grace_days(default_grace_days) as i64;
+        self.days_until_due()
+            .map(|days| days < -grace)
             .unwrap_or(false)
     }
 
-    /// Days until watering is due. Negative = overdue. None if never watered.
+    /// Days until watering is due. Negative = overdue. None if never watered
+    /// (unless a skip is in effect).
     pub fn days_until_due(&self) -> Option<i64> {
-        self.days_since_watered()
-            .map(|days| self.water_frequency_days as i64 - days)
+        self.apply_deferral(self.days_since_watered()
+            .map(|days| self.water_frequency_days as i64 - days))
+    }
+
+    /// Whole days left on a skipped watering, rounded up, or None if no skip is in effect.
+    pub fn deferred_days_remaining(&self) -> Option<i64> {
+        let now = Utc::now();
+        self.water_deferred_until
+            .filter(|until| *until > now)
+            .map(|until| ((until - now).num_seconds() + 86_399) / 86_400)
+    }
+
+    /// Pushes a days-until-due figure out to the end of any skip in effect.
+    fn apply_deferral(&self, due: Option<i64>) -> Option<i64> {
+        match self.deferred_days_remaining() {
+            Some(wait) => Some(due.map_or(wait, |d| d.max(wait))),
+            None => due,
+        }
     }
 
     /// Days since last fertilized, or None if never fertilized.
@@ -517,6 +1119,56 @@ impl Orchid {
         self.last_repotted_at.map(|dt| (Utc::now() - dt).num_days())
     }
 
+    /// Whether the plant is grown in semi-hydro (LECA), where "watering" means a flush and
+    /// day-to-day care is keeping the reservoir topped up.
+    pub fn is_semi_hydro(&self) -> bool {
+        self.pot_medium == Some(PotMedium::Leca)
+    }
+
+    /// Whether the plant has left the collection. Archived plants have no care due.
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Whether any parentage is recorded, which marks the plant as a hybrid.
+    pub fn has_parentage(&self) -> bool {
+        self.grex_name.is_some() || self.seed_parent.is_some() || self.pollen_parent.is_some()
+    }
+
+    /// The cross in registration order, "seed × pollen", with "?" for an unknown side.
+    /// None when neither parent is recorded.
+    pub fn cross_label(&self) -> Option<String> {
+        if self.seed_parent.is_none() && self.pollen_parent.is_none() {
+            return None;
+        }
+        Some(format!(
+            "{} \u{00D7} {}",
+            self.seed_parent.as_deref().unwrap_or("?"),
+            self.pollen_parent.as_deref().unwrap_or("?"),
+        ))
+    }
+
+    /// Reservoir top-up interval, stretched or shortened by the zone's climate like watering.
+    /// None for plants that aren't semi-hydro.
+    pub fn reservoir_frequency(&self, climate: Option<&crate::watering::ClimateSnapshot>) -> Option<u32> {
+        if !self.is_semi_hydro() {
+            return None;
+        }
+        let base = self.reservoir_frequency_days.unwrap_or(DEFAULT_RESERVOIR_DAYS);
+        // The medium factor is left out: the reservoir, not the LECA, holds the water
+        Some(crate::watering::climate_adjusted_frequency(base, climate, None, &self.light_requirement, self.par_ppfd).adjusted_days)
+    }
+
+    /// Days until the reservoir needs topping up. Negative = overdue; Some(0) if never topped up.
+    /// None for plants that aren't semi-hydro.
+    pub fn reservoir_days_until_due(&self, climate: Option<&crate::watering::ClimateSnapshot>) -> Option<i64> {
+        let every = self.reservoir_frequency(climate)? as i64;
+        Some(match self.last_topped_up_at {
+            Some(at) => every - (Utc::now() - at).num_days(),
+            None => 0,
+        })
+    }
+
     /// Climate-adjusted watering frequency, falling back to seasonal-only
     /// when no climate data is available.
     pub fn climate_adjusted_water_frequency(
@@ -535,25 +1187,40 @@ impl Orchid {
     }
 
     /// Days until watering is due using climate-adjusted frequency.
-    /// Negative = overdue. None if never watered.
+    /// Negative = overdue. None if never watered (unless a skip is in effect).
     pub fn climate_days_until_due(
         &self,
         hemisphere: &Hemisphere,
         climate: Option<&crate::watering::ClimateSnapshot>,
     ) -> Option<i64> {
         let estimate = self.climate_adjusted_water_frequency(hemisphere, climate);
-        self.days_since_watered()
-            .map(|days| estimate.adjusted_days as i64 - days)
+        self.apply_deferral(self.days_since_watered()
+            .map(|days| estimate.adjusted_days as i64 - days))
     }
 
-    /// Whether this orchid is overdue for watering using climate-adjusted frequency.
+    /// Whether this orchid needs watering today using climate-adjusted frequency:
+    /// due or past due, or never watered. This is what puts a plant on the Today list.
+    pub fn is_climate_due(
+        &self,
+        hemisphere: &Hemisphere,
+        climate: Option<&crate::watering::ClimateSnapshot>,
+    ) -> bool {
+        self.climate_days_until_due(hemisphere, climate)
+            .map(|days| days <= 0)
+            .unwrap_or(true)
+    }
+
+    /// Whether this orchid is overdue for watering using climate-adjusted frequency,
+    /// once more than the grace period has passed since the due date.
     pub fn is_climate_overdue(
         &self,
         hemisphere: &Hemisphere,
         climate: Option<&crate::watering::ClimateSnapshot>,
+        default_grace_days: u32,
     ) -> bool {
+        let grace = self.grace_days(default_grace_days) as i64;
         self.climate_days_until_due(hemisphere, climate)
-            .map(|days| days < 0)
+            .map(|days| days < -grace)
             .unwrap_or(false)
     }
 
@@ -564,8 +1231,11 @@ impl Orchid {
 
     /// Determine the current seasonal phase for the given hemisphere.
     pub fn current_phase(&self, hemisphere: &Hemisphere) -> SeasonalPhase {
-        let now_month = Utc::now().month();
+        self.phase_in_month(hemisphere, Utc::now().month())
+    }
 
+    /// Determine the seasonal phase in calendar month `now_month` (1-12) for the given hemisphere.
+    pub fn phase_in_month(&self, hemisphere: &Hemisphere, now_month: u32) -> SeasonalPhase {
         // Check bloom season first (most specific)
         if let (Some(bs), Some(be)) = (self.bloom_start_month, self.bloom_end_month) {
             let start = hemisphere.adjust_month(bs);
@@ -594,8 +1264,13 @@ impl Orchid {
 
     /// Get effective water frequency adjusted for current season.
     pub fn effective_water_frequency(&self, hemisphere: &Hemisphere) -> u32 {
+        self.water_frequency_in_month(hemisphere, Utc::now().month())
+    }
+
+    /// Watering frequency adjusted for the seasonal phase in calendar month `month` (1-12).
+    pub fn water_frequency_in_month(&self, hemisphere: &Hemisphere, month: u32) -> u32 {
         let base = self.water_frequency_days;
-        let multiplier = match self.current_phase(hemisphere) {
+        let multiplier = match self.phase_in_month(hemisphere, month) {
             SeasonalPhase::Rest => self.rest_water_multiplier,
             SeasonalPhase::Active | SeasonalPhase::Blooming => self.active_water_multiplier,
             SeasonalPhase::Unknown => None,
@@ -782,6 +1457,161 @@ pub struct Alert {
     pub message: String,
     /// When this alert was generated.
     pub created_at: DateTime<Utc>,
+    /// The record ID of the zone this alert relates to, if any.
+    #[serde(default)]
+    pub zone_id: Option<String>,
+    /// When someone acknowledged the alert; it stays quiet until the condition clears.
+    #[serde(default)]
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    /// Hidden, and not re-sent, until this time.
+    #[serde(default)]
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// When the condition cleared on its own.
+    #[serde(default)]
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Snooze lengths offered for an alert, in hours.
+pub const ALERT_SNOOZE_HOURS: [u32; 3] = [1, 4, 24];
+
+/// Longest an alert can be snoozed, in hours (one week).
+pub const MAX_ALERT_SNOOZE_HOURS: u32 = 7 * 24;
+
+/// Longest an overdue-care alert can be left before it escalates, in days.
+pub const MAX_ESCALATION_DAYS: u32 = 30;
+
+/// What is it? An owner's escalation settings for overdue care, as shown in notification settings.
+/// Why does it exist? A plant that stays overdue should get louder, not stay at the quiet severity of its first alert, and can also reach a second channel.
+/// How should it be used? Load with `server_fns::alerts::get_escalation_settings` and save with `save_escalation_settings`; the webhook URL itself never reaches the browser.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EscalationSettings {
+    /// Days an overdue-care alert may stay open and unacknowledged before each step up in severity; 0 turns escalation off.
+    pub after_days: u32,
+    /// The second channel escalated alerts are also posted to, if set.
+    pub webhook: Option<crate::webhook::WebhookStatus>,
+}
+
+impl Alert {
+    /// Whether the alert still needs attention at `now`: not acknowledged, not resolved, and not snoozed.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.acknowledged_at.is_none()
+            && self.resolved_at.is_none()
+            && self.snoozed_until.is_none_or(|until| until <= now)
+    }
+}
+
+/// What is it? A dated to-do for one plant or zone, such as re-checking it a week after a diagnosis, optionally repeating.
+/// Why does it exist? Care that isn't watering or fertilizing still needs a nudge on the right day, or it is forgotten.
+/// How should it be used? Create through `server_fns::reminders`; the Today tab lists reminders that are due until they are marked done, and a push goes out when each comes due.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Reminder {
+    /// The unique identifier of the reminder.
+    pub id: String,
+    /// The plant this reminder is about, if any.
+    #[serde(default)]
+    pub orchid_id: Option<String>,
+    /// The plant's name, for display.
+    #[serde(default)]
+    pub orchid_name: Option<String>,
+    /// The zone this reminder is about, if any.
+    #[serde(default)]
+    pub zone_id: Option<String>,
+    /// The zone's name, for display.
+    #[serde(default)]
+    pub zone_name: Option<String>,
+    /// What to do, e.g. "Check Phal for root rot".
+    pub title: String,
+    /// When the reminder becomes due.
+    pub due_at: DateTime<Utc>,
+    /// How often it comes back once done; None for a one-off.
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
+}
+
+/// Longest repeat interval accepted, in any unit.
+pub const MAX_RECURRENCE_EVERY: u32 = 365;
+
+/// The unit a recurring reminder repeats in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecurrenceUnit {
+    /// Every N days.
+    Days,
+    /// Every N weeks.
+    Weeks,
+    /// Every N calendar months, on the same day of the month where it exists.
+    Months,
+}
+
+impl RecurrenceUnit {
+    /// Every unit, in the order shown in pickers.
+    pub const ALL: [RecurrenceUnit; 3] = [RecurrenceUnit::Days, RecurrenceUnit::Weeks, RecurrenceUnit::Months];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecurrenceUnit::Days => "Days",
+            RecurrenceUnit::Weeks => "Weeks",
+            RecurrenceUnit::Months => "Months",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|u| u.as_str() == s)
+    }
+
+    /// Display label, singular or plural.
+    pub fn label(&self, count: u32) -> &'static str {
+        match (self, count == 1) {
+            (RecurrenceUnit::Days, true) => "day",
+            (RecurrenceUnit::Days, false) => "days",
+            (RecurrenceUnit::Weeks, true) => "week",
+            (RecurrenceUnit::Weeks, false) => "weeks",
+            (RecurrenceUnit::Months, true) => "month",
+            (RecurrenceUnit::Months, false) => "months",
+        }
+    }
+}
+
+/// How often a recurring reminder comes back, e.g. every 2 weeks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recurrence {
+    /// How many units between occurrences, 1 to `MAX_RECURRENCE_EVERY`.
+    pub every: u32,
+    /// The unit `every` counts.
+    pub unit: RecurrenceUnit,
+}
+
+impl Recurrence {
+    /// The occurrence one step after `at`.
+    pub fn advance(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let every = self.every.max(1);
+        match self.unit {
+            RecurrenceUnit::Days => at + chrono::Duration::days(every as i64),
+            RecurrenceUnit::Weeks => at + chrono::Duration::weeks(every as i64),
+            RecurrenceUnit::Months => at.checked_add_months(chrono::Months::new(every))
+                .unwrap_or(at + chrono::Duration::days(30 * every as i64)),
+        }
+    }
+
+    /// The first occurrence after both `due_at` and `now`, so a reminder finished late doesn't
+    /// come straight back due. Keeps the original schedule rather than restarting from `now`.
+    pub fn next_after(&self, due_at: DateTime<Utc>, now: DateTime<Utc>) -> DateTime<Utc> {
+        let mut next = self.advance(due_at);
+        while next <= now {
+            next = self.advance(next);
+        }
+        next
+    }
+
+    /// Display text, e.g. "Every 2 weeks" or "Every month".
+    pub fn label(&self) -> String {
+        if self.every == 1 {
+            format!("Every {}", self.unit.label(1))
+        } else {
+            format!("Every {} {}", self.every, self.unit.label(self.every))
+        }
+    }
 }
 
 /// What is it? A record of specific meteorological conditions observed at an orchid species' natural geographic origin.
@@ -826,6 +1656,47 @@ pub struct HabitatWeatherSummary {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tempest_stations_primary_first_deduped() {
+        let device = HardwareDevice {
+            id: "hardware_device:1".into(),
+            name: "Tempest".into(),
+            device_type: "tempest".into(),
+            config: r#"{"station_id":"101","station_ids":["202","101"," "],"token":"t"}"#.into(),
+        };
+        assert_eq!(device.tempest_stations(), vec!["101".to_string(), "202".to_string()]);
+    }
+
+    #[test]
+    fn test_mqtt_topics_in_order() {
+        let device = HardwareDevice {
+            id: "hardware_device:2".into(),
+            name: "Broker".into(),
+            device_type: "mqtt".into(),
+            config: r#"{"host":"broker.local","port":1883,"topics":["tent/climate"," ","bench/+/climate"]}"#.into(),
+        };
+        assert_eq!(device.mqtt_topics(), vec!["tent/climate".to_string(), "bench/+/climate".to_string()]);
+    }
+
+    #[test]
+    fn test_sensorpush_sensors_in_order() {
+        let device = HardwareDevice {
+            id: "hardware_device:3".into(),
+            name: "SensorPush".into(),
+            device_type: "sensorpush".into(),
+            config: r#"{"email":"a@b.c","password":"pw","sensors":[{"id":"2.2","name":"Tent"},{"id":"1.1"}]}"#.into(),
+        };
+        assert_eq!(device.sensorpush_sensors(), vec![("2.2".to_string(), "Tent".to_string()), ("1.1".to_string(), String::new())]);
+    }
+
+    #[test]
+    fn test_climate_reading_deserialize_without_extended_metrics() {
+        let json = r#"{"id":"cr:1","zone_id":"gz:1","zone_name":"Patio","temperature":20.0,"humidity":50.0,"recorded_at":"2026-01-01T00:00:00Z"}"#;
+        let reading: ClimateReading = serde_json::from_str(json).unwrap();
+        assert!(!reading.has_outdoor_metrics());
+        assert_eq!(reading.uv_index, None);
+    }
+
     #[test]
     fn test_climate_reading_serde_with_source() {
         let reading = ClimateReading {
@@ -835,7 +1706,16 @@ mod tests {
             temperature: 22.5,
             humidity: 55.0,
             vpd: Some(0.85),
+            dew_point: Some(13.1),
             precipitation: None,
+            uv_index: None,
+            solar_radiation: None,
+            wind_speed: None,
+            wind_gust: None,
+            lux: None,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
             source: Some("wizard".into()),
             recorded_at: Utc::now(),
         };
@@ -953,6 +1833,22 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
 
         assert_eq!(orchid.name, "Test Orchid");
@@ -998,9 +1894,25 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
         assert_eq!(orchid.days_since_watered(), None);
-        assert!(!orchid.is_overdue());
+        assert!(!orchid.is_overdue(0));
         assert_eq!(orchid.days_until_due(), None);
     }
 
@@ -1042,9 +1954,25 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
         assert_eq!(orchid.days_since_watered(), Some(2));
-        assert!(!orchid.is_overdue());
+        assert!(!orchid.is_overdue(0));
         assert_eq!(orchid.days_until_due(), Some(5));
     }
 
@@ -1086,12 +2014,98 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
         assert_eq!(orchid.days_since_watered(), Some(10));
-        assert!(orchid.is_overdue());
+        assert!(orchid.is_overdue(0));
         assert_eq!(orchid.days_until_due(), Some(-3));
     }
 
+    #[test]
+    fn test_overdue_grace_period() {
+        let mut orchid = crate::test_helpers::test_orchid();
+        orchid.water_frequency_days = 7;
+        orchid.last_watered_at = Some(Utc::now() - chrono::Duration::days(10));
+        assert!(orchid.is_overdue(2));
+        assert!(!orchid.is_overdue(3));
+
+        // A per-plant override beats the owner's default
+        orchid.overdue_grace_days = Some(5);
+        assert_eq!(orchid.grace_days(0), 5);
+        assert!(!orchid.is_overdue(0));
+        orchid.overdue_grace_days = Some(0);
+        assert!(orchid.is_overdue(10));
+    }
+
+    #[test]
+    fn test_is_climate_due() {
+        let mut orchid = crate::test_helpers::test_orchid();
+        orchid.water_frequency_days = 7;
+        assert!(orchid.is_climate_due(&Hemisphere::Northern, None), "never watered counts as due");
+        orchid.last_watered_at = Some(Utc::now() - chrono::Duration::days(7));
+        assert!(orchid.is_climate_due(&Hemisphere::Northern, None));
+        orchid.last_watered_at = Some(Utc::now() - chrono::Duration::days(2));
+        assert!(!orchid.is_climate_due(&Hemisphere::Northern, None));
+    }
+
+    #[test]
+    fn test_skipped_watering_defers_due_date() {
+        let mut orchid = crate::test_helpers::test_orchid();
+        orchid.water_frequency_days = 7;
+        orchid.last_watered_at = Some(Utc::now() - chrono::Duration::days(12));
+        assert!(orchid.is_overdue(0));
+
+        orchid.water_deferred_until = Some(Utc::now() + chrono::Duration::days(3));
+        assert_eq!(orchid.deferred_days_remaining(), Some(3));
+        assert_eq!(orchid.days_until_due(), Some(3));
+        assert!(!orchid.is_overdue(0));
+        assert!(!orchid.is_climate_due(&Hemisphere::Northern, None));
+
+        // A deferral that has run out no longer counts
+        orchid.water_deferred_until = Some(Utc::now() - chrono::Duration::days(1));
+        assert_eq!(orchid.deferred_days_remaining(), None);
+        assert_eq!(orchid.days_until_due(), Some(-5));
+    }
+
+    #[test]
+    fn test_reservoir_schedule_only_for_semi_hydro() {
+        let mut orchid = crate::test_helpers::test_orchid();
+        assert_eq!(orchid.reservoir_days_until_due(None), None);
+
+        orchid.pot_medium = Some(PotMedium::Leca);
+        // Never topped up: due straight away
+        assert_eq!(orchid.reservoir_days_until_due(None), Some(0));
+
+        orchid.reservoir_frequency_days = Some(5);
+        orchid.last_topped_up_at = Some(Utc::now() - chrono::Duration::days(2));
+        assert_eq!(orchid.reservoir_days_until_due(None), Some(3));
+        // Topping up doesn't touch the flush (watering) schedule
+        assert_eq!(orchid.days_until_due(), None);
+    }
+
+    #[test]
+    fn test_skip_reason_roundtrip() {
+        for reason in SkipReason::ALL {
+            assert_eq!(SkipReason::parse(reason.as_str()), Some(reason));
+        }
+        assert_eq!(SkipReason::parse("Bored"), None);
+    }
+
     #[test]
     fn test_fit_category_serde() {
         let good: FitCategory = serde_json::from_str("\"Good Fit\"").unwrap();
@@ -1138,6 +2152,13 @@ mod tests {
             note: "New spike emerging".into(),
             image_filename: Some("user1/photo.jpg".into()),
             event_type: Some("Flowering".into()),
+            climate: Some(EntryClimate { temperature: 21.0, humidity: 60.0, vpd: None }),
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
         };
 
         let json = serde_json::to_string(&entry).unwrap();
@@ -1147,6 +2168,15 @@ mod tests {
         assert_eq!(deserialized.note, entry.note);
         assert_eq!(deserialized.image_filename, Some("user1/photo.jpg".into()));
         assert_eq!(deserialized.event_type, Some("Flowering".into()));
+        assert_eq!(deserialized.climate, entry.climate);
+    }
+
+    #[test]
+    fn test_entry_climate_describe() {
+        let climate = EntryClimate { temperature: 24.5, humidity: 62.4, vpd: Some(1.163) };
+        assert_eq!(climate.describe("C"), "24.5\u{00B0}C \u{00B7} 62% RH \u{00B7} 1.16 kPa");
+        let no_vpd = EntryClimate { vpd: None, ..climate };
+        assert_eq!(no_vpd.describe("F"), "76.1\u{00B0}F \u{00B7} 62% RH");
     }
 
     #[test]
@@ -1200,12 +2230,28 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
 
         let json = serde_json::to_string(&orchid).unwrap();
         let deserialized: Orchid = serde_json::from_str(&json).unwrap();
 
-        assert_eq!(deserialized.first_bloom_at.is_some(), true);
+        assert!(deserialized.first_bloom_at.is_some());
         assert_eq!(deserialized.name, "Blooming Beauty");
         assert_eq!(deserialized.native_region, Some("Brazil".into()));
     }
@@ -1286,6 +2332,22 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
         assert!(!orchid.has_seasonal_data());
         orchid.rest_start_month = Some(11);
@@ -1293,6 +2355,7 @@ mod tests {
     }
 
     /// Helper to create a minimal orchid with seasonal fields for testing.
+    #[allow(clippy::too_many_arguments)]
     fn seasonal_orchid(
         water_freq: u32,
         fert_freq: Option<u32>,
@@ -1339,6 +2402,22 @@ mod tests {
             active_water_multiplier: active_water_mult,
             active_fertilizer_multiplier: active_fert_mult,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         }
     }
 
@@ -1629,7 +2708,7 @@ mod tests {
         // Should return Some with a month (1-12) and a label
         assert!(transition.is_some());
         let (month, label) = transition.unwrap();
-        assert!(month >= 1 && month <= 12);
+        assert!((1..=12).contains(&month));
         assert!(!label.is_empty());
     }
 
@@ -1809,4 +2888,79 @@ mod tests {
         );
         assert_eq!(deserialized.hardware_port, Some(3));
     }
+
+    #[test]
+    fn test_alert_active_until_acknowledged_resolved_or_snoozed() {
+        let now = Utc::now();
+        let alert = Alert {
+            id: "alert:1".into(),
+            orchid_name: None,
+            zone_name: Some("Cabinet".into()),
+            alert_type: "humidity_low".into(),
+            severity: "warning".into(),
+            message: "Cabinet: Humidity 40% is below minimum 60%".into(),
+            created_at: now - chrono::Duration::hours(2),
+            zone_id: Some("growing_zone:a".into()),
+            acknowledged_at: None,
+            snoozed_until: None,
+            resolved_at: None,
+        };
+        assert!(alert.is_active(now));
+        assert!(!Alert { snoozed_until: Some(now + chrono::Duration::hours(1)), ..alert.clone() }.is_active(now));
+        assert!(Alert { snoozed_until: Some(now - chrono::Duration::minutes(1)), ..alert.clone() }.is_active(now));
+        assert!(!Alert { acknowledged_at: Some(now), ..alert.clone() }.is_active(now));
+        assert!(!Alert { resolved_at: Some(now), ..alert }.is_active(now));
+    }
+
+    #[test]
+    fn test_misting_only_resets_schedule_for_misted_plants() {
+        use WateringMethod::*;
+        assert!(WateringMethod::resets_schedule(Some(Soak), None));
+        assert!(WateringMethod::resets_schedule(None, Some(Drench)));
+        assert!(!WateringMethod::resets_schedule(Some(Mist), None));
+        assert!(!WateringMethod::resets_schedule(Some(Mist), Some(Soak)));
+        assert!(WateringMethod::resets_schedule(Some(Mist), Some(Mist)));
+        for m in WateringMethod::ALL {
+            assert_eq!(WateringMethod::parse(m.as_str()), Some(m));
+        }
+    }
+
+    #[test]
+    fn test_recurrence_keeps_schedule_and_skips_missed_occurrences() {
+        use chrono::TimeZone;
+        let due = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+        let monthly = Recurrence { every: 1, unit: RecurrenceUnit::Months };
+        assert_eq!(monthly.advance(due), Utc.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap());
+        assert_eq!(monthly.label(), "Every month");
+
+        let fortnightly = Recurrence { every: 2, unit: RecurrenceUnit::Weeks };
+        assert_eq!(fortnightly.label(), "Every 2 weeks");
+        // Done on time: two weeks on from the due date
+        assert_eq!(fortnightly.next_after(due, due), Utc.with_ymd_and_hms(2024, 2, 14, 9, 0, 0).unwrap());
+        // Done five weeks late: the next occurrence still in the future
+        let late = Utc.with_ymd_and_hms(2024, 3, 6, 12, 0, 0).unwrap();
+        assert_eq!(fortnightly.next_after(due, late), Utc.with_ymd_and_hms(2024, 3, 13, 9, 0, 0).unwrap());
+
+        for u in RecurrenceUnit::ALL {
+            assert_eq!(RecurrenceUnit::parse(u.as_str()), Some(u));
+        }
+    }
+
+    #[test]
+    fn test_cross_label_marks_unknown_parent() {
+        let species = crate::test_helpers::test_orchid();
+        assert!(!species.has_parentage());
+        assert_eq!(species.cross_label(), None);
+
+        let hybrid = Orchid {
+            seed_parent: Some("Paph. callosum".into()),
+            ..crate::test_helpers::test_orchid()
+        };
+        assert!(hybrid.has_parentage());
+        assert_eq!(hybrid.cross_label().as_deref(), Some("Paph. callosum \u{00D7} ?"));
+
+        let grex_only = Orchid { grex_name: Some("Paph. Maudiae".into()), ..species };
+        assert!(grex_only.has_parentage());
+        assert_eq!(grex_only.cross_label(), None);
+    }
 }
@@ -121,10 +121,16 @@ fn AccountDeleteInner(username: String) -> impl IntoView {
                                             <li>"Uploaded photos"</li>
                                             <li>"Hardware device credentials (Tempest, AC Infinity)"</li>
                                             <li>"Notification subscriptions"</li>
+                                            <li>"Sessions on all of your devices"</li>
                                             <li>"All account settings and data"</li>
                                         </ul>
                                     </div>
 
+                                    <div class="mb-6">
+                                        <p class="mb-2 text-sm text-stone-600 dark:text-stone-300">"Want a copy first? Download everything as a zip of JSON files and photos."</p>
+                                        <crate::components::data_export::DataExportButton />
+                                    </div>
+
                                     <p class="mb-8 text-sm font-semibold text-red-600 dark:text-red-400">"This action is permanent and cannot be undone."</p>
 
                                     <div class="flex gap-3">
@@ -187,7 +193,7 @@ fn AccountDeleteInner(username: String) -> impl IntoView {
                                             set_is_deleting.set(true);
                                             set_delete_error.set(String::new());
                                             leptos::task::spawn_local(async move {
-                                                match crate::server_fns::auth::delete_account(typed).await {
+                                                match crate::server_fns::auth::delete_my_account(typed).await {
                                                     Ok(()) => {
                                                         #[cfg(feature = "hydrate")]
                                                         {
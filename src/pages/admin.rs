@@ -0,0 +1,238 @@
+use leptos::prelude::*;
+use crate::server_fns::admin::{
+    admin_list_users, admin_reset_password, admin_set_user_admin, admin_set_user_disabled,
+    admin_storage_usage, AdminUserSummary,
+};
+use crate::server_fns::auth::get_current_user;
+
+const SMALL_BTN: &str = "py-1 px-2 text-xs bg-transparent rounded border-none cursor-pointer hover:bg-secondary/50 text-stone-600 dark:text-stone-300";
+const INPUT_CLASS: &str = "py-1.5 px-2 text-xs rounded-lg border outline-none bg-white/80 border-stone-300/50 dark:bg-stone-800/80 dark:border-stone-600/50";
+
+/// Formats a byte count for display (e.g., "12.4 MB").
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[component]
+pub fn AdminPage() -> impl IntoView {
+    let user = Resource::new(|| (), |_| get_current_user());
+
+    view! {
+        <Suspense fallback=move || view! { <p class="p-8 text-center text-stone-500">"Loading..."</p> }>
+            {move || user.get().map(|result| match result {
+                Ok(Some(info)) if info.is_admin => view! { <AdminPanel current_user_id=info.id /> }.into_any(),
+                Ok(Some(_)) => view! {
+                    <main class="flex justify-center items-center px-6 min-h-screen bg-cream">
+                        <div class="text-center">
+                            <p class="mb-4 text-sm text-stone-600 dark:text-stone-300">"Only instance admins can open this page."</p>
+                            <a href="/" class="text-sm font-medium underline text-primary">"Back to your collection"</a>
+                        </div>
+                    </main>
+                }.into_any(),
+                _ => {
+                    #[cfg(feature = "ssr")]
+                    leptos_axum::redirect("/login");
+                    #[cfg(feature = "hydrate")]
+                    {
+                        if let Some(window) = web_sys::window() {
+                            let _ = window.location().set_href("/login");
+                        }
+                    }
+                    view! { <div></div> }.into_any()
+                }
+            })}
+        </Suspense>
+    }
+}
+
+#[component]
+fn AdminPanel(current_user_id: String) -> impl IntoView {
+    let users = Resource::new(|| (), |_| admin_list_users());
+    let storage = Resource::new(|| (), |_| admin_storage_usage());
+    let (error_msg, set_error_msg) = signal(None::<String>);
+    let (status_msg, set_status_msg) = signal(None::<String>);
+    let current_user_id = StoredValue::new(current_user_id);
+
+    let report = move |result: Result<(), ServerFnError>, done: String| match result {
+        Ok(()) => {
+            set_status_msg.set(Some(done));
+            users.refetch();
+        }
+        Err(e) => {
+            #[cfg(feature = "hydrate")]
+            crate::server_fns::telemetry::emit_error("admin.action", &format!("Admin action failed: {}", e), &[]);
+            set_error_msg.set(Some(e.to_string()));
+        }
+    };
+
+    let toggle_disabled = move |u: AdminUserSummary| {
+        set_error_msg.set(None);
+        set_status_msg.set(None);
+        leptos::task::spawn_local(async move {
+            let verb = if u.disabled { "Re-enabled" } else { "Disabled" };
+            report(admin_set_user_disabled(u.id, !u.disabled).await, format!("{} {}.", verb, u.username));
+        });
+    };
+
+    let toggle_admin = move |u: AdminUserSummary| {
+        set_error_msg.set(None);
+        set_status_msg.set(None);
+        leptos::task::spawn_local(async move {
+            let done = if u.is_admin {
+                format!("{} is no longer an admin.", u.username)
+            } else {
+                format!("{} is now an admin.", u.username)
+            };
+            report(admin_set_user_admin(u.id, !u.is_admin).await, done);
+        });
+    };
+
+    let reset_target = RwSignal::new(None::<AdminUserSummary>);
+    let (new_password, set_new_password) = signal(String::new());
+    let submit_reset = move |_| {
+        let Some(u) = reset_target.get_untracked() else { return };
+        let password = new_password.get_untracked();
+        set_error_msg.set(None);
+        set_status_msg.set(None);
+        leptos::task::spawn_local(async move {
+            let result = admin_reset_password(u.id, password).await;
+            if result.is_ok() {
+                reset_target.set(None);
+                set_new_password.set(String::new());
+            }
+            report(result, format!("Password reset for {}. They've been signed out everywhere.", u.username));
+        });
+    };
+
+    view! {
+        <main class="py-10 px-6 min-h-screen bg-cream">
+            <div class="mx-auto max-w-4xl">
+                <div class="flex justify-between items-center mb-8">
+                    <h2 class="m-0 text-3xl text-stone-800 dark:text-stone-100">"Instance Admin"</h2>
+                    <a href="/" class="text-sm font-medium underline text-primary">"Back to your collection"</a>
+                </div>
+
+                {move || status_msg.get().map(|msg| view! {
+                    <div class="p-3 mb-4 text-sm rounded-xl border text-primary bg-primary/5 border-primary/10">{msg}</div>
+                })}
+                {move || error_msg.get().map(|msg| view! {
+                    <div class="p-3 mb-4 text-sm rounded-xl border text-danger bg-danger/5 border-danger/10">{msg}</div>
+                })}
+
+                <h3 class="mb-3 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Storage"</h3>
+                <Suspense fallback=|| ()>
+                    {move || storage.get().map(|result| match result {
+                        Ok(usage) => view! {
+                            <div class="grid grid-cols-2 gap-3 mb-8 sm:grid-cols-4">
+                                <div class="p-3 rounded-xl bg-white/60 dark:bg-stone-800/60">
+                                    <div class="text-xs text-stone-400">"Images"</div>
+                                    <div class="text-lg font-semibold text-stone-700 dark:text-stone-200">{format_bytes(usage.image_bytes)}</div>
+                                    <div class="text-[11px] text-stone-400">{format!("{} files", usage.image_files)}</div>
+                                </div>
+                                {usage.database_bytes.map(|bytes| view! {
+                                    <div class="p-3 rounded-xl bg-white/60 dark:bg-stone-800/60">
+                                        <div class="text-xs text-stone-400">"Database"</div>
+                                        <div class="text-lg font-semibold text-stone-700 dark:text-stone-200">{format_bytes(bytes)}</div>
+                                        <div class="text-[11px] text-stone-400">"on disk"</div>
+                                    </div>
+                                })}
+                                {usage.table_rows.into_iter().map(|(table, rows)| view! {
+                                    <div class="p-3 rounded-xl bg-white/60 dark:bg-stone-800/60">
+                                        <div class="text-xs text-stone-400">{table}</div>
+                                        <div class="text-lg font-semibold text-stone-700 dark:text-stone-200">{rows}</div>
+                                        <div class="text-[11px] text-stone-400">"rows"</div>
+                                    </div>
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! { <p class="mb-8 text-xs text-danger">{e.to_string()}</p> }.into_any(),
+                    })}
+                </Suspense>
+
+                <h3 class="mb-3 text-sm font-semibold tracking-wider uppercase text-stone-500 dark:text-stone-400">"Accounts"</h3>
+                <Suspense fallback=|| ()>
+                    {move || users.get().map(|result| match result {
+                        Ok(list) => view! {
+                            <div class="flex flex-col gap-2">
+                                {list.into_iter().map(|u| {
+                                    let is_self = current_user_id.with_value(|id| *id == u.id);
+                                    let seen = u.last_seen_at
+                                        .map(|t| format!("active {}", crate::components::format_time_ago(&t)))
+                                        .unwrap_or_else(|| "no active sessions".into());
+                                    let detail = format!(
+                                        "{} \u{00B7} joined {} \u{00B7} {} plants \u{00B7} {} entries \u{00B7} {} photos \u{00B7} {} readings \u{00B7} {} images \u{00B7} {}",
+                                        u.email, u.created_at.format("%b %d, %Y"), u.plant_count, u.journal_count, u.photo_count,
+                                        u.reading_count, format_bytes(u.image_bytes), seen
+                                    );
+                                    let (for_disable, for_admin, for_reset) = (u.clone(), u.clone(), u.clone());
+                                    view! {
+                                        <div class="flex flex-wrap gap-2 justify-between items-center py-2 px-3 text-sm rounded-lg bg-white/60 dark:bg-stone-800/60">
+                                            <div>
+                                                <div class="font-medium text-stone-700 dark:text-stone-300">
+                                                    {u.username.clone()}
+                                                    {u.is_admin.then(|| view! { <span class="ml-2 text-[11px] font-semibold text-primary">"ADMIN"</span> })}
+                                                    {u.disabled.then(|| view! { <span class="ml-2 text-[11px] font-semibold text-danger">"DISABLED"</span> })}
+                                                </div>
+                                                <div class="text-[11px] text-stone-400">{detail}</div>
+                                            </div>
+                                            <div class="flex gap-1">
+                                                <button class=SMALL_BTN on:click=move |_| reset_target.set(Some(for_reset.clone()))>"Reset password"</button>
+                                                {(!is_self).then(|| view! {
+                                                    <button class=SMALL_BTN on:click=move |_| toggle_admin(for_admin.clone())>
+                                                        {if u.is_admin { "Remove admin" } else { "Make admin" }}
+                                                    </button>
+                                                    <button class=SMALL_BTN on:click=move |_| toggle_disabled(for_disable.clone())>
+                                                        {if u.disabled { "Enable" } else { "Disable" }}
+                                                    </button>
+                                                })}
+                                            </div>
+                                        </div>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }.into_any(),
+                        Err(e) => view! { <p class="text-xs text-danger">{e.to_string()}</p> }.into_any(),
+                    })}
+                </Suspense>
+
+                {move || reset_target.get().map(|u| view! {
+                    <div class="flex flex-wrap gap-2 items-center p-3 mt-4 rounded-xl bg-primary/5 dark:bg-primary-light/5">
+                        <span class="text-xs text-stone-600 dark:text-stone-300">{format!("New password for {}:", u.username)}</span>
+                        <input type="password" class=INPUT_CLASS
+                            autocomplete="new-password"
+                            prop:value=new_password
+                            on:input=move |ev| set_new_password.set(event_target_value(&ev))
+                        />
+                        <button
+                            class="py-1.5 px-3 text-xs font-semibold text-white rounded-lg border-none cursor-pointer bg-primary hover:bg-primary-dark"
+                            on:click=submit_reset
+                        >"Set Password"</button>
+                        <button class=SMALL_BTN on:click=move |_| reset_target.set(None)>"Cancel"</button>
+                    </div>
+                })}
+            </div>
+        </main>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 + 512 * 1024), "5.5 MB");
+    }
+}
@@ -0,0 +1,92 @@
+use leptos::prelude::*;
+use crate::server_fns::auth::request_password_reset;
+
+const INPUT_CLASS: &str = "w-full px-4 py-3 text-sm bg-white/80 border border-stone-300/50 rounded-xl outline-none transition-all duration-200 placeholder:text-stone-500 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:placeholder:text-stone-400 dark:focus:bg-stone-800 dark:focus:border-primary-light/40 dark:focus:ring-primary-light/10";
+const LABEL_CLASS: &str = "block mb-2 text-xs font-semibold tracking-widest uppercase text-stone-600 dark:text-stone-400";
+const SUBMIT_CLASS: &str = "flex gap-2 justify-center items-center py-3 w-full text-sm font-semibold text-white rounded-xl border-none transition-all duration-200 cursor-pointer hover:shadow-lg disabled:opacity-50 disabled:cursor-not-allowed bg-primary hover:bg-primary-dark hover:shadow-primary/20 active:scale-[0.98]";
+
+#[component]
+pub fn ForgotPasswordPage() -> impl IntoView {
+    let (email, set_email) = signal(String::new());
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (sent, set_sent) = signal(false);
+    let (is_loading, set_is_loading) = signal(false);
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        set_is_loading.set(true);
+        set_error.set(None);
+
+        leptos::task::spawn_local(async move {
+            match request_password_reset(email.get()).await {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("forgot_password.requested", "Password reset requested", &[]);
+                    set_sent.set(true);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("forgot_password.submit", &format!("Password reset request failed: {}", e), &[]);
+                    set_error.set(Some(e.to_string()));
+                }
+            }
+            set_is_loading.set(false);
+        });
+    };
+
+    view! {
+        <main class="flex justify-center items-center px-6 min-h-screen bg-cream">
+            <div class="w-full max-w-sm">
+                <div class="flex gap-2 justify-center items-center mb-8">
+                    <div class="flex justify-center items-center w-8 h-8 text-sm rounded-lg bg-primary [&>svg]:w-4 [&>svg]:h-4" inner_html=include_str!("../../public/svg/app_logo.svg")></div>
+                    <span class="text-sm font-semibold tracking-widest uppercase text-primary">"Velamen"</span>
+                </div>
+
+                <h2 class="mb-2 text-3xl text-stone-800 dark:text-stone-100">"Forgot password"</h2>
+
+                {move || if sent.get() {
+                    view! {
+                        <div>
+                            <p class="mb-8 text-sm text-stone-500 dark:text-stone-400">
+                                "If an account uses that address, a reset link is on its way. It expires in an hour \u{2014} check your spam folder if it doesn't arrive."
+                            </p>
+                            <a href="/login" class="font-medium text-primary dark:text-primary-light">"Back to sign in"</a>
+                        </div>
+                    }.into_any()
+                } else {
+                    view! {
+                        <div>
+                            <p class="mb-8 text-sm text-stone-500 dark:text-stone-400">"Enter your account email and we'll send you a link to choose a new password."</p>
+
+                            {move || error.get().map(|err| view! {
+                                <div class="p-3 mb-6 text-sm rounded-xl border animate-fade-in text-danger bg-danger/5 border-danger/10">{err}</div>
+                            })}
+
+                            <form on:submit=on_submit>
+                                <div class="mb-8">
+                                    <label class=LABEL_CLASS>"Email"</label>
+                                    <input
+                                        type="email"
+                                        class=INPUT_CLASS
+                                        placeholder="you@example.com"
+                                        prop:value=email
+                                        on:input=move |ev| set_email.set(event_target_value(&ev))
+                                        required
+                                        autocomplete="email"
+                                    />
+                                </div>
+                                <button type="submit" class=SUBMIT_CLASS disabled=move || is_loading.get()>
+                                    {move || if is_loading.get() { "Sending..." } else { "Send Reset Link" }}
+                                </button>
+                            </form>
+
+                            <div class="mt-8 text-sm text-center">
+                                <a href="/login" class="font-medium text-primary dark:text-primary-light">"Back to sign in"</a>
+                            </div>
+                        </div>
+                    }.into_any()
+                }}
+            </div>
+        </main>
+    }
+}
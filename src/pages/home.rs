@@ -9,14 +9,17 @@ use crate::components::orchid_collection::OrchidCollection;
 use crate::components::orchid_detail::OrchidDetail;
 use crate::components::seasonal_calendar::SeasonalCalendar;
 use crate::components::scanner::ScannerModal;
+use crate::components::care_chat::CareChatPanel;
 use crate::components::settings::SettingsModal;
 use crate::components::today_tasks::TodayTasks;
+use crate::components::care_timeline::CareTimeline;
 use crate::orchid::Alert;
 use crate::model::{HomeTab, Model, Msg};
 use crate::orchid::Orchid;
 use crate::server_fns::auth::get_current_user;
-use crate::server_fns::orchids::{get_orchids, create_orchid, update_orchid, delete_orchid, mark_watered, mark_watered_batch};
-use crate::server_fns::preferences::{get_temp_unit, get_hemisphere, get_collection_public};
+use crate::server_fns::orchids::{get_orchid, get_orchids_page, ORCHID_PAGE_SIZE, create_orchid, update_orchid, delete_orchid, mark_watered, mark_many_watered};
+use crate::edit_conflict::{merge_edit, MergedEdit, EDIT_CONFLICT};
+use crate::server_fns::preferences::{get_temp_unit, get_hemisphere, get_collection_public, get_overdue_grace_days, get_accessibility_prefs};
 use crate::server_fns::devices::get_devices;
 use crate::server_fns::zones::{get_zones, migrate_legacy_placements};
 use crate::update::dispatch;
@@ -26,12 +29,15 @@ pub fn HomePage() -> impl IntoView {
     // Check auth — redirect to login if not authenticated
     let user = Resource::new(|| (), |_| get_current_user());
 
-    // Load orchids from server
-    let orchids_resource = Resource::new(|| (), |_| get_orchids());
+    // Load the first page of orchids from server; later pages are appended on scroll
+    let orchids_resource = Resource::new(|| (), |_| get_orchids_page(0, ORCHID_PAGE_SIZE));
 
     // Local orchid state — synced from resource, patched in-place by water handler
     // to avoid refetch (which would recreate the DOM and reset scroll position).
     let orchids_local = RwSignal::new(Vec::<Orchid>::new());
+    // Cursor for the next page (None = fully loaded) + in-flight guard for scroll events
+    let next_offset = RwSignal::new(None::<u32>);
+    let page_loading = RwSignal::new(false);
 
     // Run legacy migration once on load, then load zones
     let migration_resource = Resource::new(|| (), |_| migrate_legacy_placements());
@@ -67,6 +73,7 @@ pub fn HomePage() -> impl IntoView {
     let selected_orchid = Memo::new(move |_| model.get().selected_orchid.clone());
     let show_settings = Memo::new(move |_| model.get().show_settings);
     let show_scanner = Memo::new(move |_| model.get().show_scanner);
+    let show_chat = Memo::new(move |_| model.get().show_chat);
     let show_add_modal = Memo::new(move |_| model.get().show_add_modal);
     let prefill_data = Memo::new(move |_| model.get().prefill_data.clone());
     let temp_unit = Memo::new(move |_| model.get().temp_unit.clone());
@@ -103,11 +110,18 @@ pub fn HomePage() -> impl IntoView {
         move || zones_version.get(),
         |_| crate::server_fns::alerts::get_active_alerts(),
     );
+    let active_alerts = Memo::new(move |_| {
+        alerts_resource.get()
+            .and_then(|r| r.ok())
+            .unwrap_or_default()
+    });
 
     // Load saved temp unit preference from server
     let temp_unit_resource = Resource::new(|| (), |_| get_temp_unit());
     let hemisphere_resource = Resource::new(|| (), |_| get_hemisphere());
+    let grace_resource = Resource::new(|| (), |_| get_overdue_grace_days());
     let collection_public_resource = Resource::new(|| (), |_| get_collection_public());
+    let accessibility_resource = Resource::new(|| (), |_| get_accessibility_prefs());
 
     // Initialize model temp_unit from server preference when it loads
     Effect::new(move |_| {
@@ -133,19 +147,146 @@ pub fn HomePage() -> impl IntoView {
 
     let hemisphere = Memo::new(move |_| model.get().hemisphere.clone());
 
+    // Initialize model overdue grace period from server preference when it loads
+    Effect::new(move |_| {
+        if let Some(Ok(days)) = grace_resource.get() {
+            set_model.update(|m| {
+                if m.overdue_grace_days != days {
+                    m.overdue_grace_days = days;
+                }
+            });
+        }
+    });
+
+    let overdue_grace_days = Memo::new(move |_| model.get().overdue_grace_days);
+
+    // Apply reduced motion / high contrast from the server preference when it loads
+    Effect::new(move |_| {
+        if let Some(Ok(prefs)) = accessibility_resource.get()
+            && model.get_untracked().accessibility != prefs {
+                send(Msg::SetAccessibility(prefs));
+            }
+    });
+
+    // Care event type registry (built-ins + user's custom types), shared by the
+    // timeline, event picker, gallery, and quick actions via context.
+    let event_types = RwSignal::new(crate::components::event_types::builtin_event_types());
+    provide_context(crate::components::event_types::EventTypeRegistry(event_types));
+    let event_types_resource = Resource::new(|| (), |_| crate::server_fns::event_types::get_event_types());
+    Effect::new(move |_| {
+        if let Some(Ok(list)) = event_types_resource.get() {
+            event_types.set(list);
+        }
+    });
+
+    // Card images (latest photo per plant + species defaults), shared with every
+    // OrchidCard and the species photo picker via context.
+    let card_photos = RwSignal::new(crate::species_photos::CardPhotoIndex::default());
+    provide_context(crate::components::species_photos::CardPhotos(card_photos));
+    let card_photos_resource = Resource::new(|| (), |_| crate::server_fns::species_photos::get_card_photos());
+    Effect::new(move |_| {
+        if let Some(Ok(index)) = card_photos_resource.get() {
+            card_photos.set(index);
+        }
+    });
+
+    // Award badges for the collection grid, refreshed by the awards card after a change.
+    let award_badges = RwSignal::new(std::collections::HashMap::new());
+    provide_context(crate::components::awards::AwardBadges(award_badges));
+    let award_badges_resource = Resource::new(|| (), |_| crate::server_fns::awards::get_award_badges());
+    Effect::new(move |_| {
+        if let Some(Ok(badges)) = award_badges_resource.get() {
+            award_badges.set(badges);
+        }
+    });
+
+    // Open treatments for the collection grid, refreshed by the treatments card after a change.
+    let treatment_badges = RwSignal::new(std::collections::HashMap::new());
+    provide_context(crate::components::treatments::TreatmentBadges(treatment_badges));
+    let treatment_badges_resource = Resource::new(|| (), |_| crate::server_fns::treatments::get_treatment_badges());
+    Effect::new(move |_| {
+        if let Some(Ok(badges)) = treatment_badges_resource.get() {
+            treatment_badges.set(badges);
+        }
+    });
+
+    // Care automation rules, shared with the settings rule builder and the
+    // orchid detail care schedule (fertilizer pauses) via context.
+    let care_rules = RwSignal::new(Vec::<crate::care_rules::CareRule>::new());
+    provide_context(crate::components::care_rules::CareRules(care_rules));
+    let care_rules_resource = Resource::new(|| (), |_| crate::server_fns::care_rules::get_care_rules());
+    Effect::new(move |_| {
+        if let Some(Ok(list)) = care_rules_resource.get() {
+            care_rules.set(list);
+        }
+    });
+
     // Sync orchid data from server resource into local writable state.
     // Water handler patches this directly; add/delete/update refetch the resource
-    // which re-triggers this Effect to sync.
+    // which re-triggers this Effect to sync (resetting to the first page).
     Effect::new(move |_| {
-        if let Some(Ok(orchids)) = orchids_resource.get() {
-            orchids_local.set(orchids);
+        if let Some(Ok(page)) = orchids_resource.get() {
+            orchids_local.set(page.orchids);
+            next_offset.set(page.next_offset);
         }
     });
     let orchids_memo = Memo::new(move |_| orchids_local.get());
 
+    // What the user has already done, so the getting-started tour skips those steps
+    let tour_facts = Memo::new(move |_| crate::tour::TourFacts {
+        zone_count: zones_memo.with(Vec::len),
+        has_climate_data: climate_readings.with(|r| !r.is_empty()),
+        plant_count: orchids_memo.with(Vec::len),
+        has_watering: orchids_memo.with(|o| o.iter().any(|o| o.last_watered_at.is_some())),
+    });
+
     // Error toast signal
     let (toast_msg, set_toast_msg) = signal::<Option<String>>(None);
 
+    let has_more_orchids = Memo::new(move |_| next_offset.get().is_some());
+
+    // Append the next page of orchids. Skips duplicates in case a plant was added
+    // between page requests and shifted the offset window.
+    let load_more = move || {
+        let Some(offset) = next_offset.get_untracked() else { return };
+        if page_loading.get_untracked() {
+            return;
+        }
+        page_loading.set(true);
+        leptos::task::spawn_local(async move {
+            match get_orchids_page(offset, ORCHID_PAGE_SIZE).await {
+                Ok(page) => {
+                    orchids_local.update(|list| {
+                        for orchid in page.orchids {
+                            if !list.iter().any(|o| o.id == orchid.id) {
+                                list.push(orchid);
+                            }
+                        }
+                    });
+                    next_offset.set(page.next_offset);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("home.load_more", &format!("Failed to load more plants: {}", e), &[("offset", &offset.to_string())]);
+                    // Stop paging so the drain Effect below doesn't retry in a tight loop;
+                    // the next refetch (add/delete) resets the cursor.
+                    next_offset.set(None);
+                    set_toast_msg.set(Some(format!("Failed to load more plants: {}", e)));
+                }
+            }
+            page_loading.set(false);
+        });
+    };
+
+    // Today and Seasons need the whole collection — drain remaining pages there.
+    // History, Stats and Archive load their own lists from the server.
+    Effect::new(move |_| {
+        let needs_all = !matches!(home_tab.get(), HomeTab::MyPlants | HomeTab::History | HomeTab::Stats | HomeTab::Archive | HomeTab::Reports);
+        if needs_all && has_more_orchids.get() && !page_loading.get() {
+            load_more();
+        }
+    });
+
     // Orchid operations via server functions (async I/O — not TEA state)
     let on_add = move |orchid: Orchid| {
         leptos::task::spawn_local(async move {
@@ -180,6 +321,10 @@ pub fn HomePage() -> impl IntoView {
                 orchid.active_water_multiplier,
                 orchid.active_fertilizer_multiplier,
                 orchid.par_ppfd,
+                orchid.acquired_at,
+                orchid.acquisition_price,
+                orchid.acquisition_source,
+                orchid.vendor_id,
             ).await {
                 Ok(_) => {
                     #[cfg(feature = "hydrate")]
@@ -195,10 +340,54 @@ pub fn HomePage() -> impl IntoView {
         });
     };
 
+    // An edit that lost a race with another device, merged onto that device's copy and awaiting a choice
+    let edit_conflict = RwSignal::new(None::<MergedEdit>);
+
+    // Patch the local orchid list in-place — no refetch, no scroll reset.
+    let patch_local = move |saved: Orchid| {
+        orchids_local.update(|list| {
+            if let Some(o) = list.iter_mut().find(|o| o.id == saved.id) {
+                *o = saved;
+            }
+        });
+    };
+
+    // The last plant saved from this page. The open detail view picks it up, so its next edit
+    // carries the saved `updated_at` instead of conflicting with its own earlier save.
+    let saved_orchid = RwSignal::new(None::<Orchid>);
+    let apply_saved = move |saved: Orchid| {
+        patch_local(saved.clone());
+        saved_orchid.set(Some(saved));
+    };
+
     let on_update = move |orchid: Orchid| {
         leptos::task::spawn_local(async move {
             let _orchid_id = orchid.id.clone();
+            let mine = orchid.clone();
             match update_orchid(orchid).await {
+                Err(e) if e.to_string().contains(EDIT_CONFLICT) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_warn("home.update_orchid", "Edit conflicted with another device", &[("orchid_id", &_orchid_id)]);
+                    // The local list still holds the copy the edit started from
+                    let base = orchids_local.get_untracked().into_iter().find(|o| o.id == mine.id);
+                    match (base, get_orchid(mine.id.clone()).await) {
+                        (Some(base), Ok(theirs)) => {
+                            let merged = merge_edit(&base, &mine, &theirs);
+                            if !merged.theirs_changed.is_empty() || !merged.both_changed.is_empty() {
+                                edit_conflict.set(Some(merged));
+                                return;
+                            }
+                            // Only fields the edit form doesn't touch (care timestamps) changed
+                            // since, so the edit goes straight through
+                            match update_orchid(merged.orchid).await {
+                                Ok(saved) => apply_saved(saved),
+                                Err(e) => set_toast_msg.set(Some(format!("Failed to update plant: {}", e))),
+                            }
+                        }
+                        (_, Err(e)) => set_toast_msg.set(Some(format!("Failed to update plant: {}", e))),
+                        (None, Ok(_)) => set_toast_msg.set(Some(EDIT_CONFLICT.to_string())),
+                    }
+                }
                 Err(_e) => {
                     #[cfg(feature = "hydrate")]
                     crate::server_fns::telemetry::emit_error("home.update_orchid", &format!("Failed to update plant: {}", _e), &[("orchid_id", &_orchid_id)]);
@@ -207,12 +396,7 @@ pub fn HomePage() -> impl IntoView {
                 Ok(updated) => {
                     #[cfg(feature = "hydrate")]
                     crate::server_fns::telemetry::emit_info("home.update_orchid", "Orchid updated", &[("orchid_id", &_orchid_id)]);
-                    // Patch the local orchid list in-place — no refetch, no scroll reset.
-                    orchids_local.update(|list| {
-                        if let Some(o) = list.iter_mut().find(|o| o.id == updated.id) {
-                            *o = updated;
-                        }
-                    });
+                    apply_saved(updated);
                 }
             }
         });
@@ -251,14 +435,7 @@ pub fn HomePage() -> impl IntoView {
 
         leptos::task::spawn_local(async move {
             match mark_watered(id.clone()).await {
-                Ok(updated) => {
-                    // Patch the local orchid list in-place — no refetch, no scroll reset.
-                    orchids_local.update(|list| {
-                        if let Some(o) = list.iter_mut().find(|o| o.id == updated.id) {
-                            *o = updated;
-                        }
-                    });
-                }
+                Ok(updated) => apply_saved(updated),
                 Err(e) => {
                     #[cfg(feature = "hydrate")]
                     crate::server_fns::telemetry::emit_error("home.mark_watered", &format!("Failed to mark watered: {}", e), &[("orchid_id", &id)]);
@@ -285,20 +462,16 @@ pub fn HomePage() -> impl IntoView {
         }
 
         leptos::task::spawn_local(async move {
-            match mark_watered_batch(to_water.clone()).await {
+            match mark_many_watered(to_water.clone()).await {
                 Ok(updated_orchids) => {
-                    orchids_local.update(|list| {
-                        for updated in updated_orchids {
-                            if let Some(o) = list.iter_mut().find(|o| o.id == updated.id) {
-                                *o = updated;
-                            }
-                        }
-                    });
+                    for updated in updated_orchids {
+                        apply_saved(updated);
+                    }
                 }
                 Err(e) => {
                     let _count = to_water.len().to_string();
                     #[cfg(feature = "hydrate")]
-                    crate::server_fns::telemetry::emit_error("home.mark_watered_batch", &format!("Failed to mark all watered: {}", e), &[("count", &_count)]);
+                    crate::server_fns::telemetry::emit_error("home.mark_many_watered", &format!("Failed to mark all watered: {}", e), &[("count", &_count)]);
                     set_toast_msg.set(Some(format!("Failed to mark all watered: {}", e)));
                 }
             }
@@ -310,6 +483,13 @@ pub fn HomePage() -> impl IntoView {
         });
     };
 
+    // Patch the plants a bulk journal entry touched (care timestamps may have moved)
+    let on_bulk_logged = move |updated_orchids: Vec<Orchid>| {
+        for updated in updated_orchids {
+            apply_saved(updated);
+        }
+    };
+
     let on_zones_changed = move || {
         set_zones_version.update(|v| *v += 1);
     };
@@ -329,11 +509,14 @@ pub fn HomePage() -> impl IntoView {
                 let _ = zones_resource.get();
                 let _ = temp_unit_resource.get();
                 let _ = hemisphere_resource.get();
+                let _ = grace_resource.get();
                 let _ = collection_public_resource.get();
+                let _ = accessibility_resource.get();
 
                 user.get().map(|result| match result {
                     Ok(Some(ref _user_info)) => {
                         let current_username = _user_info.username.clone();
+                        let current_is_admin = _user_info.is_admin;
                         // Check if user needs onboarding (no zones)
                         let zones = zones_memo.get();
                         if zones.is_empty()
@@ -358,6 +541,7 @@ pub fn HomePage() -> impl IntoView {
                                 on_toggle_dark=move || send(Msg::ToggleDarkMode)
                                 on_add=move || send(Msg::ShowAddModal(true))
                                 on_scan=move || send(Msg::ShowScanner(true))
+                                on_chat=move || send(Msg::ShowChat(true))
                                 on_settings=move || send(Msg::ShowSettings(true))
                             />
 
@@ -399,6 +583,32 @@ pub fn HomePage() -> impl IntoView {
                                         </svg>
                                         "Today"
                                     </button>
+                                    <button
+                                        class=move || if home_tab.get() == HomeTab::Timeline {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
+                                        } else {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-medium border-b-2 border-transparent cursor-pointer transition-colors text-stone-500 hover:text-stone-600 dark:text-stone-400 dark:hover:text-stone-300"
+                                        }
+                                        on:click=move |_| send(Msg::SetHomeTab(HomeTab::Timeline))
+                                    >
+                                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                                            <path d="M3 4a1 1 0 011-1h7a1 1 0 110 2H4a1 1 0 01-1-1zM6 9a1 1 0 011-1h9a1 1 0 110 2H7a1 1 0 01-1-1zM4 14a1 1 0 011-1h5a1 1 0 110 2H5a1 1 0 01-1-1z" />
+                                        </svg>
+                                        "Timeline"
+                                    </button>
+                                    <button
+                                        class=move || if home_tab.get() == HomeTab::Forecast {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
+                                        } else {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-medium border-b-2 border-transparent cursor-pointer transition-colors text-stone-500 hover:text-stone-600 dark:text-stone-400 dark:hover:text-stone-300"
+                                        }
+                                        on:click=move |_| send(Msg::SetHomeTab(HomeTab::Forecast))
+                                    >
+                                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                                            <path fill-rule="evenodd" d="M6 2a1 1 0 00-1 1v1H4a2 2 0 00-2 2v10a2 2 0 002 2h12a2 2 0 002-2V6a2 2 0 00-2-2h-1V3a1 1 0 10-2 0v1H7V3a1 1 0 00-1-1zM4 8h12v8H4V8zm2 2v2h2v-2H6zm4 0v2h2v-2h-2z" clip-rule="evenodd" />
+                                        </svg>
+                                        "2 Weeks"
+                                    </button>
                                     <button
                                         class=move || if home_tab.get() == HomeTab::Seasons {
                                             "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
@@ -412,6 +622,59 @@ pub fn HomePage() -> impl IntoView {
                                         </svg>
                                         "Seasons"
                                     </button>
+                                    <button
+                                        class=move || if home_tab.get() == HomeTab::History {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
+                                        } else {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-medium border-b-2 border-transparent cursor-pointer transition-colors text-stone-500 hover:text-stone-600 dark:text-stone-400 dark:hover:text-stone-300"
+                                        }
+                                        on:click=move |_| send(Msg::SetHomeTab(HomeTab::History))
+                                    >
+                                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                                            <path fill-rule="evenodd" d="M10 18a8 8 0 100-16 8 8 0 000 16zm1-12a1 1 0 10-2 0v4a1 1 0 00.293.707l2.828 2.829a1 1 0 101.415-1.415L11 9.586V6z" clip-rule="evenodd" />
+                                        </svg>
+                                        "History"
+                                    </button>
+                                    <button
+                                        class=move || if home_tab.get() == HomeTab::Stats {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
+                                        } else {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-medium border-b-2 border-transparent cursor-pointer transition-colors text-stone-500 hover:text-stone-600 dark:text-stone-400 dark:hover:text-stone-300"
+                                        }
+                                        on:click=move |_| send(Msg::SetHomeTab(HomeTab::Stats))
+                                    >
+                                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                                            <path d="M2 11a1 1 0 011-1h2a1 1 0 011 1v5a1 1 0 01-1 1H3a1 1 0 01-1-1v-5zM8 7a1 1 0 011-1h2a1 1 0 011 1v9a1 1 0 01-1 1H9a1 1 0 01-1-1V7zM14 4a1 1 0 011-1h2a1 1 0 011 1v12a1 1 0 01-1 1h-2a1 1 0 01-1-1V4z" />
+                                        </svg>
+                                        "Stats"
+                                    </button>
+                                    <button
+                                        class=move || if home_tab.get() == HomeTab::Archive {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
+                                        } else {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-medium border-b-2 border-transparent cursor-pointer transition-colors text-stone-500 hover:text-stone-600 dark:text-stone-400 dark:hover:text-stone-300"
+                                        }
+                                        on:click=move |_| send(Msg::SetHomeTab(HomeTab::Archive))
+                                    >
+                                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                                            <path d="M4 3a2 2 0 100 4h12a2 2 0 100-4H4z" />
+                                            <path fill-rule="evenodd" d="M3 8h14v7a2 2 0 01-2 2H5a2 2 0 01-2-2V8zm5 3a1 1 0 011-1h2a1 1 0 110 2H9a1 1 0 01-1-1z" clip-rule="evenodd" />
+                                        </svg>
+                                        "Archive"
+                                    </button>
+                                    <button
+                                        class=move || if home_tab.get() == HomeTab::Reports {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-semibold border-b-2 cursor-pointer transition-colors text-primary border-primary dark:text-primary-light dark:border-primary-light"
+                                        } else {
+                                            "flex gap-2 items-center py-2.5 px-5 text-sm font-medium border-b-2 border-transparent cursor-pointer transition-colors text-stone-500 hover:text-stone-600 dark:text-stone-400 dark:hover:text-stone-300"
+                                        }
+                                        on:click=move |_| send(Msg::SetHomeTab(HomeTab::Reports))
+                                    >
+                                        <svg xmlns="http://www.w3.org/2000/svg" class="w-4 h-4" viewBox="0 0 20 20" fill="currentColor">
+                                            <path fill-rule="evenodd" d="M4 4a2 2 0 012-2h4.586A2 2 0 0112 2.586L15.414 6A2 2 0 0116 7.414V16a2 2 0 01-2 2H6a2 2 0 01-2-2V4zm2 6a1 1 0 011-1h6a1 1 0 110 2H7a1 1 0 01-1-1zm1 3a1 1 0 100 2h6a1 1 0 100-2H7z" clip-rule="evenodd" />
+                                        </svg>
+                                        "Reports"
+                                    </button>
                                 </nav>
 
                                 // Tab content
@@ -431,16 +694,30 @@ pub fn HomePage() -> impl IntoView {
                                                             on_show_wizard=move |z| send(Msg::ShowWizard(Some(z)))
                                                             on_zones_changed=on_zones_changed
                                                             temp_unit_str=tu
+                                                            alerts=Signal::from(active_alerts)
+                                                            on_alerts_changed=move || alerts_resource.refetch()
                                                         /> }
                                                     }}
                                                 </Suspense>
 
+                                                <crate::components::forecast::OutdoorForecastCards unit=temp_unit />
+
+                                                <crate::components::report_cards::WeeklyReportCards unit=temp_unit />
+
+                                                <crate::components::light_integral::ZoneDliCards />
+
+                                                <crate::components::zone_economics::ZoneEconomicsCards />
+
                                                 <NotificationSetup />
 
                                                 <Suspense fallback=|| ()>
                                                     {move || {
                                                         alerts_resource.get().map(|result| {
-                                                            let alerts = result.unwrap_or_default();
+                                                            // Alerts for zones in the climate strip are shown (and snoozed) there.
+                                                            let strip_zones: Vec<String> = climate_readings.with(|r| r.iter().map(|r| r.zone_id.clone()).collect());
+                                                            let alerts: Vec<Alert> = result.unwrap_or_default().into_iter()
+                                                                .filter(|a| a.zone_id.as_ref().is_none_or(|z| !strip_zones.contains(z)))
+                                                                .collect();
                                                             if alerts.is_empty() {
                                                                 view! { <div></div> }.into_any()
                                                             } else {
@@ -463,14 +740,19 @@ pub fn HomePage() -> impl IntoView {
                                                     zones=zones_memo
                                                     climate_snapshots=climate_snapshots
                                                     hemisphere=hemisphere
+                                                    overdue_grace_days=overdue_grace_days
                                                     view_mode=view_mode
                                                     on_set_view=move |mode| send(Msg::SetViewMode(mode))
                                                     on_delete=on_delete
-                                                    on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(o)))
+                                                    on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(Box::new(o))))
                                                     on_update=on_update
                                                     on_water=on_water
+                                                    on_water_all=on_water_all
                                                     on_add=move || send(Msg::ShowAddModal(true))
                                                     on_scan=move || send(Msg::ShowScanner(true))
+                                                    has_more=has_more_orchids
+                                                    on_load_more=move |_| load_more()
+                                                    on_bulk_logged=on_bulk_logged
                                                 />
                                             </div>
                                         }.into_any(),
@@ -486,15 +768,41 @@ pub fn HomePage() -> impl IntoView {
                                                                 orchids=o_memo 
                                                                 climate_snapshots=snap_memo 
                                                                 hemisphere=h_memo
-                                                                on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(o)))
+                                                                overdue_grace_days=overdue_grace_days
+                                                                unit=temp_unit
+                                                                on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(Box::new(o))))
                                                                 on_water=on_water
                                                                 on_water_all=on_water_all
                                                             /> 
                                                         }
                                                     }}
                                                 </Suspense>
+                                                <crate::components::adherence::WateringAdherenceCard
+                                                    orchids=orchids_memo
+                                                    on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(Box::new(o))))
+                                                />
                                             </div>
                                         }.into_any(),
+                                        HomeTab::Timeline => view! {
+                                            <div>
+                                                <Suspense fallback=|| ()>
+                                                    <CareTimeline
+                                                        orchids=orchids_memo
+                                                        zones=zones_memo
+                                                        climate_snapshots=climate_snapshots
+                                                        hemisphere=hemisphere
+                                                        overdue_grace_days=overdue_grace_days
+                                                        on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(Box::new(o))))
+                                                    />
+                                                </Suspense>
+                                            </div>
+                                        }.into_any(),
+                                        HomeTab::Forecast => view! {
+                                            <crate::components::care_forecast::CareForecast
+                                                orchids=orchids_memo
+                                                on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(Box::new(o))))
+                                            />
+                                        }.into_any(),
                                         HomeTab::Seasons => view! {
                                             <div>
                                                 <Suspense fallback=|| ()>
@@ -506,6 +814,21 @@ pub fn HomePage() -> impl IntoView {
                                                 </Suspense>
                                             </div>
                                         }.into_any(),
+                                        HomeTab::History => view! {
+                                            <crate::components::time_travel::CollectionAsOf />
+                                        }.into_any(),
+                                        HomeTab::Stats => view! {
+                                            <crate::components::acquisition::CollectionValueCard />
+                                        }.into_any(),
+                                        HomeTab::Archive => view! {
+                                            <crate::components::archive::ArchiveView
+                                                on_select=move |o: Orchid| send(Msg::SelectOrchid(Some(Box::new(o))))
+                                                on_restored=move |_| orchids_resource.refetch()
+                                            />
+                                        }.into_any(),
+                                        HomeTab::Reports => view! {
+                                            <crate::components::monthly_reports::MonthlyReports />
+                                        }.into_any(),
                                     }
                                 }}
                             </main>
@@ -529,15 +852,33 @@ pub fn HomePage() -> impl IntoView {
                                 let current_readings = climate_readings.get_untracked();
                                 let current_snapshots = climate_snapshots.get_untracked();
                                 let current_hemi = hemisphere.get_untracked();
+                                let current_grace = overdue_grace_days.get_untracked();
+                                // Archived plants open from the Archive tab to browse, not to tend
+                                let archived = orchid.is_archived();
                                 view! {
                                     <OrchidDetail
                                         orchid=orchid
+                                        read_only=archived
                                         zones=current_zones
                                         climate_readings=current_readings
                                         climate_snapshots=current_snapshots
                                         hemisphere=current_hemi
+                                        overdue_grace_days=current_grace
+                                        temp_unit=temp_unit.get_untracked()
                                         on_close=move || send(Msg::SelectOrchid(None))
                                         on_update=on_update
+                                        saved=saved_orchid
+                                        on_open=move |id: String| {
+                                            if let Some(o) = orchids_local.get_untracked().into_iter().find(|o| o.id == id) {
+                                                send(Msg::SelectOrchid(Some(Box::new(o))));
+                                            }
+                                        }
+                                        on_propagated=move |child: Orchid| orchids_local.update(|list| list.push(child))
+                                        on_archived=move |archived: Orchid| {
+                                            orchids_local.update(|list| list.retain(|o| o.id != archived.id));
+                                            send(Msg::SelectOrchid(None));
+                                        }
+                                        on_cover_changed=apply_saved
                                     />
                                 }.into_any()
                             })}
@@ -547,6 +888,8 @@ pub fn HomePage() -> impl IntoView {
                                 let current_devices = devices_memo.get();
                                 let current_temp_unit = temp_unit.get();
                                 let current_hemi = hemisphere.get();
+                                let current_grace = overdue_grace_days.get_untracked();
+                                let current_accessibility = model.get_untracked().accessibility;
                                 let current_public = collection_public_resource.get()
                                     .and_then(|r| r.ok())
                                     .unwrap_or(false);
@@ -557,12 +900,18 @@ pub fn HomePage() -> impl IntoView {
                                         devices=current_devices
                                         initial_temp_unit=current_temp_unit.clone()
                                         initial_hemisphere=current_hemi
+                                        initial_overdue_grace_days=current_grace
                                         initial_collection_public=current_public
+                                        initial_accessibility=current_accessibility
                                         username=uname
+                                        is_admin=current_is_admin
                                         on_close=move |new_unit: String| {
                                     send(Msg::SettingsClosed { temp_unit: new_unit });
                                 }
                                         on_zones_changed=on_zones_changed
+                                        on_orchids_changed=move || orchids_resource.refetch()
+                                        on_grace_changed=move |days| send(Msg::SetOverdueGraceDays(days))
+                                        on_accessibility_changed=move |prefs| send(Msg::SetAccessibility(prefs))
                                         on_show_wizard=move |z| send(Msg::ShowWizard(Some(z)))
                                     />
                                 }.into_any()
@@ -583,6 +932,10 @@ pub fn HomePage() -> impl IntoView {
                                 }.into_any()
                             })}
 
+                            {move || show_chat.get().then(|| view! {
+                                <CareChatPanel on_close=move || send(Msg::ShowChat(false)) />
+                            })}
+
                             {move || wizard_zone.get().map(|zone| {
                                 let current_unit = temp_unit.get();
                                 view! {
@@ -598,6 +951,22 @@ pub fn HomePage() -> impl IntoView {
                                 }.into_any()
                             })}
 
+                            <crate::components::product_tour::ProductTour facts=tour_facts />
+
+                            {move || edit_conflict.get().map(|merged| view! {
+                                <EditConflictPrompt
+                                    merged=merged
+                                    on_resolved=move |saved: Orchid| {
+                                        edit_conflict.set(None);
+                                        apply_saved(saved);
+                                    }
+                                    on_error=move |msg: String| {
+                                        edit_conflict.set(None);
+                                        set_toast_msg.set(Some(msg));
+                                    }
+                                />
+                            })}
+
                             <ErrorToast msg=toast_msg set_msg=set_toast_msg />
                         }.into_any()
                     },
@@ -677,6 +1046,73 @@ fn ErrorToast(
     }
 }
 
+/// Merge prompt for an edit that conflicted with a save from another device: lists what the other
+/// device changed and what both changed, then saves the merged copy or keeps theirs.
+#[component]
+fn EditConflictPrompt(
+    merged: MergedEdit,
+    on_resolved: impl Fn(Orchid) + 'static + Copy + Send + Sync,
+    on_error: impl Fn(String) + 'static + Copy + Send + Sync,
+) -> impl IntoView {
+    let (is_saving, set_is_saving) = signal(false);
+    let MergedEdit { orchid, theirs, theirs_changed, both_changed } = merged;
+    let name = theirs.name.clone();
+    let merged_copy = StoredValue::new(orchid);
+    let theirs = StoredValue::new(theirs);
+
+    let save_merged = move |_| {
+        set_is_saving.set(true);
+        leptos::task::spawn_local(async move {
+            match update_orchid(merged_copy.get_value()).await {
+                Ok(saved) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("home.edit_conflict_merged", "Merged conflicting edit saved", &[("orchid_id", &saved.id)]);
+                    on_resolved(saved);
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("home.edit_conflict_merged", &format!("Failed to save merged edit: {}", e), &[]);
+                    on_error(format!("Failed to update plant: {}", e));
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="flex fixed inset-0 z-50 justify-center items-center p-4 bg-black/40" role="dialog" aria-modal="true" aria-labelledby="edit-conflict-title">
+            <div class="p-5 w-full max-w-md rounded-2xl shadow-xl bg-surface dark:bg-stone-900">
+                <h3 id="edit-conflict-title" class="m-0 mb-2 text-lg text-stone-800 dark:text-stone-100">{format!("{} was changed elsewhere", name)}</h3>
+                <p class="mb-3 text-sm text-stone-600 dark:text-stone-300">"Someone saved this plant on another device while you were editing it. Your changes haven't been saved yet."</p>
+                {(!theirs_changed.is_empty()).then(|| view! {
+                    <p class="mb-2 text-xs text-stone-500">
+                        <span class="font-semibold">"Changed on the other device (kept): "</span>
+                        {theirs_changed.join(", ")}
+                    </p>
+                })}
+                {(!both_changed.is_empty()).then(|| view! {
+                    <p class="mb-2 text-xs text-amber-700 dark:text-amber-300">
+                        <span class="font-semibold">"Changed on both (yours will replace theirs): "</span>
+                        {both_changed.join(", ")}
+                    </p>
+                })}
+                <div class="flex gap-2 justify-end mt-4">
+                    <button
+                        class="py-2 px-3 text-sm bg-transparent rounded-lg border-none cursor-pointer text-stone-600 dark:text-stone-300 hover:bg-secondary/50"
+                        disabled=move || is_saving.get()
+                        on:click=move |_| on_resolved(theirs.get_value())
+                    >"Discard my changes"</button>
+                    <button
+                        class="py-2 px-3 text-sm font-semibold text-white rounded-lg border-none cursor-pointer bg-primary hover:bg-primary-dark"
+                        disabled=move || is_saving.get()
+                        on:click=save_merged
+                    >{move || if is_saving.get() { "Saving..." } else { "Save merged" }}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
 /// Alert banner showing active condition/watering alerts
 #[component]
 fn AlertBanner(
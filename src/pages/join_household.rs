@@ -0,0 +1,83 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use crate::server_fns::auth::get_current_user;
+use crate::server_fns::household::accept_household_invite;
+
+const SUBMIT_CLASS: &str = "flex gap-2 justify-center items-center py-3 w-full text-sm font-semibold text-white rounded-xl border-none transition-all duration-200 cursor-pointer hover:shadow-lg disabled:opacity-50 disabled:cursor-not-allowed bg-primary hover:bg-primary-dark hover:shadow-primary/20 active:scale-[0.98]";
+
+#[component]
+pub fn JoinHouseholdPage() -> impl IntoView {
+    let query = use_query_map();
+    let token = Memo::new(move |_| query.read().get("token").unwrap_or_default());
+    let user = Resource::new(|| (), |_| get_current_user());
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (is_loading, set_is_loading) = signal(false);
+    let navigate = use_navigate();
+
+    let on_join = move |_| {
+        set_is_loading.set(true);
+        set_error.set(None);
+
+        let nav = navigate.clone();
+        leptos::task::spawn_local(async move {
+            match accept_household_invite(token.get_untracked()).await {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("household.join", "Joined household", &[]);
+                    nav("/", Default::default());
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("household.join", &format!("Joining household failed: {}", e), &[]);
+                    set_error.set(Some(e.to_string()));
+                    set_is_loading.set(false);
+                }
+            }
+        });
+    };
+
+    view! {
+        <main class="flex justify-center items-center px-6 min-h-screen bg-cream">
+            <div class="w-full max-w-sm">
+                <div class="flex gap-2 justify-center items-center mb-8">
+                    <div class="flex justify-center items-center w-8 h-8 text-sm rounded-lg bg-primary [&>svg]:w-4 [&>svg]:h-4" inner_html=include_str!("../../public/svg/app_logo.svg")></div>
+                    <span class="text-sm font-semibold tracking-widest uppercase text-primary">"Velamen"</span>
+                </div>
+
+                <h2 class="mb-2 text-3xl text-stone-800 dark:text-stone-100">"Join a household"</h2>
+                <p class="mb-8 text-sm text-stone-500 dark:text-stone-400">"You've been invited to share someone's orchid collection. Joining replaces your own collection view until you leave the household."</p>
+
+                {move || token.get().is_empty().then(|| view! {
+                    <div class="p-3 mb-6 text-sm rounded-xl border text-danger bg-danger/5 border-danger/10">
+                        "This invite link is incomplete. Ask the owner for a new one."
+                    </div>
+                })}
+
+                {move || error.get().map(|err| view! {
+                    <div class="p-3 mb-6 text-sm rounded-xl border animate-fade-in text-danger bg-danger/5 border-danger/10">{err}</div>
+                })}
+
+                <Suspense fallback=move || view! { <p class="text-sm text-center text-stone-500">"Loading..."</p> }>
+                    {move || user.get().map(|result| match result {
+                        Ok(Some(info)) => view! {
+                            <p class="mb-4 text-sm text-stone-600 dark:text-stone-300">
+                                "Signed in as " <strong>{info.username}</strong>
+                            </p>
+                            <button class=SUBMIT_CLASS disabled=move || is_loading.get() || token.get().is_empty() on:click=on_join.clone()>
+                                {move || if is_loading.get() { "Joining..." } else { "Join Household" }}
+                            </button>
+                        }.into_any(),
+                        _ => view! {
+                            <div class="p-3 text-sm rounded-xl border text-stone-600 bg-white/60 border-stone-200 dark:text-stone-300 dark:bg-stone-800/60 dark:border-stone-700">
+                                <a href="/login" class="font-medium underline text-primary">"Sign in"</a>
+                                " or "
+                                <a href="/register" class="font-medium underline text-primary">"create an account"</a>
+                                ", then open this invite link again."
+                            </div>
+                        }.into_any(),
+                    })}
+                </Suspense>
+            </div>
+        </main>
+    }
+}
@@ -38,6 +38,35 @@ pub fn LoginPage() -> impl IntoView {
         });
     };
 
+    let navigate_passkey = use_navigate();
+    let on_passkey = move |_| {
+        let uname = username.get();
+        if uname.trim().is_empty() {
+            set_error.set(Some("Enter your username to sign in with a passkey".into()));
+            return;
+        }
+        set_is_loading.set(true);
+        set_error.set(None);
+
+        let _nav = navigate_passkey.clone();
+        leptos::task::spawn_local(async move {
+            #[cfg(feature = "hydrate")]
+            match crate::components::passkeys::sign_in_with_passkey(uname.clone()).await {
+                Ok(()) => {
+                    crate::server_fns::telemetry::emit_info("login.passkey_success", "User logged in with passkey", &[("username", &uname)]);
+                    _nav("/", Default::default());
+                }
+                Err(e) => {
+                    crate::server_fns::telemetry::emit_error("login.passkey", "Passkey login failed", &[("username", &uname)]);
+                    set_error.set(Some(e));
+                    set_is_loading.set(false);
+                }
+            }
+            #[cfg(not(feature = "hydrate"))]
+            let _ = uname;
+        });
+    };
+
     view! {
         <main class="flex min-h-screen bg-cream">
             // Left panel — botanical atmosphere (hidden on mobile)
@@ -122,11 +151,14 @@ pub fn LoginPage() -> impl IntoView {
                                 prop:value=username
                                 on:input=move |ev| set_username.set(event_target_value(&ev))
                                 required
-                                autocomplete="username"
+                                autocomplete="username webauthn"
                             />
                         </div>
                         <div class="mb-8">
-                            <label class=LABEL_CLASS>"Password"</label>
+                            <div class="flex justify-between items-baseline">
+                                <label class=LABEL_CLASS>"Password"</label>
+                                <a href="/forgot-password" class="text-xs font-medium transition-colors text-primary dark:text-primary-light hover:text-primary-light">"Forgot password?"</a>
+                            </div>
                             <input
                                 type="password"
                                 class=INPUT_CLASS
@@ -156,6 +188,21 @@ pub fn LoginPage() -> impl IntoView {
                         </button>
                     </form>
 
+                    <div class="flex gap-3 items-center my-5 text-xs text-stone-400">
+                        <div class="flex-1 h-px bg-stone-200 dark:bg-stone-700"></div>
+                        "or"
+                        <div class="flex-1 h-px bg-stone-200 dark:bg-stone-700"></div>
+                    </div>
+                    <button
+                        type="button"
+                        class="flex gap-2 justify-center items-center py-3 w-full text-sm font-semibold rounded-xl border transition-all duration-200 cursor-pointer disabled:opacity-50 disabled:cursor-not-allowed text-primary bg-white/80 border-stone-300/50 hover:bg-primary/5 dark:bg-stone-800/80 dark:text-primary-light dark:border-stone-600/50"
+                        disabled=move || is_loading.get()
+                        on:click=on_passkey
+                    >
+                        <span>"\u{1F511}"</span>
+                        <span>"Sign in with a passkey"</span>
+                    </button>
+
                     <div class="flex gap-1 justify-center items-center mt-8 text-sm">
                         <span class="text-stone-500 dark:text-stone-400">"New to Velamen?"</span>
                         <a href="/register" class="font-medium transition-colors text-primary dark:text-primary-light dark:hover:text-accent-light hover:text-primary-light">"Create account"</a>
@@ -6,6 +6,18 @@ pub mod home;
 /// It exists to verify user credentials and establish a secure session.
 /// It is used by the router for the `/login` path.
 pub mod login;
+/// The "forgot password" screen that emails a reset link.
+/// It exists so users can recover access without the admin running `reset-password` on the CLI.
+/// It is used by the router for the `/forgot-password` path, linked from the login page.
+pub mod forgot_password;
+/// The screen for choosing a new password from an emailed reset link.
+/// It exists to complete the self-service password reset flow using the link's token.
+/// It is used by the router for the `/reset-password?token=...` path.
+pub mod reset_password;
+/// The screen for accepting a shared household invite link.
+/// It exists so a partner or plant-sitter can join someone's collection with their own login.
+/// It is used by the router for the `/join?token=...` path.
+pub mod join_household;
 /// The guided setup experience for brand new users.
 /// It exists to collect initial data (like growing zones and first plants) smoothly.
 /// It is used by the router for the `/welcome` path after registration.
@@ -26,6 +38,10 @@ pub mod cookie_policy;
 /// It exists as a dedicated URL that can be referenced from emails, support pages, or privacy policies.
 /// It is used by the router for the `/account/delete` path.
 pub mod account_delete;
+/// The instance admin panel for managing accounts and checking storage.
+/// It exists so running a family instance doesn't require raw database queries.
+/// It is used by the router for the `/admin` path and is only shown to instance admins.
+pub mod admin;
 /// The Terms of Service page outlining usage rules and legal obligations.
 /// It exists for legal compliance and user transparency about service usage terms.
 /// It is used by the router for the `/terms` path.
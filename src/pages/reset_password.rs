@@ -0,0 +1,103 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use crate::server_fns::auth::reset_password;
+
+const INPUT_CLASS: &str = "w-full px-4 py-3 text-sm bg-white/80 border border-stone-300/50 rounded-xl outline-none transition-all duration-200 placeholder:text-stone-500 focus:bg-white focus:border-primary/40 focus:ring-2 focus:ring-primary/10 dark:bg-stone-800/80 dark:border-stone-600/50 dark:placeholder:text-stone-400 dark:focus:bg-stone-800 dark:focus:border-primary-light/40 dark:focus:ring-primary-light/10";
+const LABEL_CLASS: &str = "block mb-2 text-xs font-semibold tracking-widest uppercase text-stone-600 dark:text-stone-400";
+const SUBMIT_CLASS: &str = "flex gap-2 justify-center items-center py-3 w-full text-sm font-semibold text-white rounded-xl border-none transition-all duration-200 cursor-pointer hover:shadow-lg disabled:opacity-50 disabled:cursor-not-allowed bg-primary hover:bg-primary-dark hover:shadow-primary/20 active:scale-[0.98]";
+
+#[component]
+pub fn ResetPasswordPage() -> impl IntoView {
+    let query = use_query_map();
+    let token = Memo::new(move |_| query.read().get("token").unwrap_or_default());
+    let (password, set_password) = signal(String::new());
+    let (confirm, set_confirm) = signal(String::new());
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (is_loading, set_is_loading) = signal(false);
+    let navigate = use_navigate();
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+
+        if password.get() != confirm.get() {
+            set_error.set(Some("Passwords do not match".into()));
+            return;
+        }
+
+        set_is_loading.set(true);
+        set_error.set(None);
+
+        let nav = navigate.clone();
+        leptos::task::spawn_local(async move {
+            match reset_password(token.get_untracked(), password.get_untracked()).await {
+                Ok(()) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_info("reset_password.success", "Password reset", &[]);
+                    nav("/login", Default::default());
+                }
+                Err(e) => {
+                    #[cfg(feature = "hydrate")]
+                    crate::server_fns::telemetry::emit_error("reset_password.submit", &format!("Password reset failed: {}", e), &[]);
+                    set_error.set(Some(e.to_string()));
+                    set_is_loading.set(false);
+                }
+            }
+        });
+    };
+
+    view! {
+        <main class="flex justify-center items-center px-6 min-h-screen bg-cream">
+            <div class="w-full max-w-sm">
+                <div class="flex gap-2 justify-center items-center mb-8">
+                    <div class="flex justify-center items-center w-8 h-8 text-sm rounded-lg bg-primary [&>svg]:w-4 [&>svg]:h-4" inner_html=include_str!("../../public/svg/app_logo.svg")></div>
+                    <span class="text-sm font-semibold tracking-widest uppercase text-primary">"Velamen"</span>
+                </div>
+
+                <h2 class="mb-2 text-3xl text-stone-800 dark:text-stone-100">"Choose a new password"</h2>
+                <p class="mb-8 text-sm text-stone-500 dark:text-stone-400">"After saving, sign in with your new password."</p>
+
+                {move || token.get().is_empty().then(|| view! {
+                    <div class="p-3 mb-6 text-sm rounded-xl border text-danger bg-danger/5 border-danger/10">
+                        "This reset link is incomplete. "
+                        <a href="/forgot-password" class="font-medium underline">"Request a new one"</a>
+                    </div>
+                })}
+
+                {move || error.get().map(|err| view! {
+                    <div class="p-3 mb-6 text-sm rounded-xl border animate-fade-in text-danger bg-danger/5 border-danger/10">{err}</div>
+                })}
+
+                <form on:submit=on_submit>
+                    <div class="mb-5">
+                        <label class=LABEL_CLASS>"New password"</label>
+                        <input
+                            type="password"
+                            class=INPUT_CLASS
+                            placeholder="At least 8 characters"
+                            prop:value=password
+                            on:input=move |ev| set_password.set(event_target_value(&ev))
+                            required
+                            minlength="8"
+                            autocomplete="new-password"
+                        />
+                    </div>
+                    <div class="mb-8">
+                        <label class=LABEL_CLASS>"Confirm password"</label>
+                        <input
+                            type="password"
+                            class=INPUT_CLASS
+                            placeholder="Repeat your new password"
+                            prop:value=confirm
+                            on:input=move |ev| set_confirm.set(event_target_value(&ev))
+                            required
+                            autocomplete="new-password"
+                        />
+                    </div>
+                    <button type="submit" class=SUBMIT_CLASS disabled=move || is_loading.get() || token.get().is_empty()>
+                        {move || if is_loading.get() { "Saving..." } else { "Set Password" }}
+                    </button>
+                </form>
+            </div>
+        </main>
+    }
+}
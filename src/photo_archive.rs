@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Lowercase ASCII letters and digits joined by single hyphens, e.g. "Phal. Jane #2" becomes "phal-jane-2".
+pub fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Path of a photo inside a photo archive: one folder per plant, files prefixed with the day
+/// they were taken so they sort oldest first.
+pub fn archive_entry_name(plant: &str, taken_at: DateTime<Utc>, filename: &str) -> String {
+    let folder = slugify(plant);
+    let base = filename.rsplit('/').next().unwrap_or(filename);
+    format!(
+        "{}/{}-{}",
+        if folder.is_empty() { "orchid" } else { &folder },
+        taken_at.format("%Y-%m-%d"),
+        base,
+    )
+}
+
+/// `name`, or the first of "name-2", "name-3", ... (counted before the extension) that isn't in
+/// `used`, which then records it. Two plants whose names slugify the same, or one photo logged
+/// on two entries, would otherwise write duplicate zip entries.
+pub fn unique_entry_name(name: String, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let (stem, ext) = match name.rfind('.') {
+        Some(dot) if dot > name.rfind('/').map_or(0, |slash| slash + 1) => name.split_at(dot),
+        _ => (name.as_str(), ""),
+    };
+    let mut n = 2;
+    while used.contains(&candidate) {
+        candidate = format!("{}-{}{}", stem, n, ext);
+        n += 1;
+    }
+    used.insert(candidate.clone());
+    candidate
+}
+
+/// Download name for a photo archive of one plant, or of the whole collection when `plant` is None.
+pub fn archive_filename(plant: Option<&str>, today: NaiveDate) -> String {
+    let scope = match plant.map(slugify) {
+        Some(slug) if !slug.is_empty() => slug,
+        Some(_) => "orchid".to_string(),
+        None => "collection".to_string(),
+    };
+    format!("{}-photos-{}.zip", scope, today.format("%Y-%m-%d"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Phal. Jane #2"), "phal-jane-2");
+        assert_eq!(slugify("  --  "), "");
+    }
+
+    #[test]
+    fn test_archive_names() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 9, 14, 0, 0).unwrap();
+        assert_eq!(archive_entry_name("Phal Jane", at, "user_1/abc.webp"), "phal-jane/2026-03-09-abc.webp");
+        assert_eq!(archive_entry_name("???", at, "abc.jpg"), "orchid/2026-03-09-abc.jpg");
+
+        let mut used = HashSet::new();
+        assert_eq!(unique_entry_name("phal/2026-03-09-abc.webp".into(), &mut used), "phal/2026-03-09-abc.webp");
+        assert_eq!(unique_entry_name("phal/2026-03-09-abc.webp".into(), &mut used), "phal/2026-03-09-abc-2.webp");
+        assert_eq!(unique_entry_name("phal/2026-03-09-abc.webp".into(), &mut used), "phal/2026-03-09-abc-3.webp");
+        assert_eq!(unique_entry_name("v1.0/noext".into(), &mut used), "v1.0/noext");
+        assert_eq!(unique_entry_name("v1.0/noext".into(), &mut used), "v1.0/noext-2");
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        assert_eq!(archive_filename(Some("Phal Jane"), today), "phal-jane-photos-2026-10-16.zip");
+        assert_eq!(archive_filename(None, today), "collection-photos-2026-10-16.zip");
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Ancestors followed before the lineage walk gives up, which also guards against a cycle in bad data.
+pub const MAX_LINEAGE_DEPTH: usize = 20;
+
+/// How a new plant was taken from its parent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PropagationMethod {
+    /// A plantlet that grew on the parent's spike or cane.
+    Keiki,
+    /// A split of the parent's rhizome into growing divisions.
+    Division,
+    /// An old leafless pseudobulb started on its own.
+    Backbulb,
+}
+
+impl PropagationMethod {
+    /// Every method, in the order shown in pickers.
+    pub const ALL: [PropagationMethod; 3] = [
+        PropagationMethod::Keiki,
+        PropagationMethod::Division,
+        PropagationMethod::Backbulb,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PropagationMethod::Keiki => "Keiki",
+            PropagationMethod::Division => "Division",
+            PropagationMethod::Backbulb => "Backbulb",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == s)
+    }
+
+    /// Display label for pickers and the lineage view.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PropagationMethod::Keiki => "Keiki",
+            PropagationMethod::Division => "Division",
+            PropagationMethod::Backbulb => "Backbulb start",
+        }
+    }
+}
+
+/// Where a propagation ended up: still in the collection, or passed on to someone else.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PropagationStatus {
+    /// Still growing in this collection.
+    Kept,
+    /// Given away.
+    Gifted,
+    /// Sold.
+    Sold,
+}
+
+impl PropagationStatus {
+    /// Every status, in the order shown in pickers.
+    pub const ALL: [PropagationStatus; 3] = [
+        PropagationStatus::Kept,
+        PropagationStatus::Gifted,
+        PropagationStatus::Sold,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PropagationStatus::Kept => "Kept",
+            PropagationStatus::Gifted => "Gifted",
+            PropagationStatus::Sold => "Sold",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|v| v.as_str() == s)
+    }
+
+    /// Display label for pickers and the lineage view.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PropagationStatus::Kept => "In collection",
+            PropagationStatus::Gifted => "Gifted",
+            PropagationStatus::Sold => "Sold",
+        }
+    }
+}
+
+/// One parent-to-child link. Names are copied when the link is made so the record still
+/// reads sensibly after either plant is deleted or passed on.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Propagation {
+    /// The propagation record ID.
+    pub id: String,
+    /// The parent plant's record ID.
+    pub parent_id: String,
+    /// The parent's name when the propagation was taken.
+    pub parent_name: String,
+    /// The new plant's record ID.
+    pub child_id: String,
+    /// The new plant's name when it was created.
+    pub child_name: String,
+    /// How the new plant was taken.
+    pub method: PropagationMethod,
+    /// When the propagation was recorded.
+    pub propagated_at: DateTime<Utc>,
+    /// Whether the new plant is still here, gifted or sold.
+    pub status: PropagationStatus,
+    /// Who it went to, for gifted and sold plants.
+    pub recipient: Option<String>,
+    /// When it was gifted or sold.
+    pub rehomed_at: Option<DateTime<Utc>>,
+}
+
+/// A plant's place in its family tree.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lineage {
+    /// The links above the plant, its own parent first, then the parent's parent and so on.
+    pub ancestors: Vec<Propagation>,
+    /// Plants taken directly from this one, oldest first.
+    pub children: Vec<Propagation>,
+}
+
+/// Builds a plant's lineage from every propagation record in the collection.
+///
+/// A plant has at most one parent; if the data somehow holds more, the oldest link wins.
+/// The ancestor walk stops at `MAX_LINEAGE_DEPTH` or on reaching a plant it has already seen.
+pub fn build_lineage(records: &[Propagation], orchid_id: &str) -> Lineage {
+    let parent_link = |child: &str| {
+        records.iter()
+            .filter(|p| p.child_id == child)
+            .min_by_key(|p| p.propagated_at)
+    };
+
+    let mut ancestors: Vec<Propagation> = Vec::new();
+    let mut current = orchid_id.to_string();
+    while ancestors.len() < MAX_LINEAGE_DEPTH {
+        let Some(link) = parent_link(&current) else { break };
+        if link.parent_id == orchid_id || ancestors.iter().any(|a| a.child_id == link.parent_id) {
+            break;
+        }
+        current = link.parent_id.clone();
+        ancestors.push(link.clone());
+    }
+
+    let mut children: Vec<Propagation> = records.iter()
+        .filter(|p| p.parent_id == orchid_id)
+        .cloned()
+        .collect();
+    children.sort_by_key(|p| p.propagated_at);
+
+    Lineage { ancestors, children }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn link(parent: &str, child: &str, days_ago: i64) -> Propagation {
+        Propagation {
+            id: format!("propagation:{}", child),
+            parent_id: parent.into(),
+            parent_name: parent.into(),
+            child_id: child.into(),
+            child_name: child.into(),
+            method: PropagationMethod::Keiki,
+            propagated_at: Utc::now() - Duration::days(days_ago),
+            status: PropagationStatus::Kept,
+            recipient: None,
+            rehomed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_build_lineage_walks_up_and_lists_children() {
+        let records = vec![
+            link("a", "b", 300),
+            link("b", "c", 100),
+            link("c", "e", 5),
+            link("c", "d", 10),
+        ];
+        let lineage = build_lineage(&records, "c");
+        assert_eq!(lineage.ancestors.iter().map(|p| p.parent_id.as_str()).collect::<Vec<_>>(), vec!["b", "a"]);
+        assert_eq!(lineage.children.iter().map(|p| p.child_id.as_str()).collect::<Vec<_>>(), vec!["d", "e"]);
+
+        let root = build_lineage(&records, "a");
+        assert!(root.ancestors.is_empty());
+        assert_eq!(root.children.len(), 1);
+    }
+
+    #[test]
+    fn test_build_lineage_stops_on_cycle() {
+        let records = vec![link("a", "b", 20), link("b", "a", 10)];
+        let lineage = build_lineage(&records, "a");
+        assert_eq!(lineage.ancestors.len(), 1);
+    }
+
+    #[test]
+    fn test_method_and_status_roundtrip() {
+        for m in PropagationMethod::ALL {
+            assert_eq!(PropagationMethod::parse(m.as_str()), Some(m));
+        }
+        for s in PropagationStatus::ALL {
+            assert_eq!(PropagationStatus::parse(s.as_str()), Some(s));
+        }
+        assert_eq!(PropagationMethod::parse("Cutting"), None);
+    }
+}
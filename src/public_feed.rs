@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::{LogEntry, Orchid};
+
+/// Prefix on every public feed key, so it can't be mistaken for a personal API token (`vlm_`).
+pub const FEED_KEY_PREFIX: &str = "vlp_";
+
+/// The journal event type that marks a bloom.
+const BLOOM_EVENT: &str = "Flowering";
+
+/// The JSON document served at `/api/public/collection/{key}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicCollectionFeed {
+    /// The grower's username, also the path of their public page (`/u/{username}`).
+    pub username: String,
+    /// "N" or "S", so bloom months can be read in the grower's seasons.
+    pub hemisphere: String,
+    /// When this document was built.
+    pub generated_at: DateTime<Utc>,
+    /// Every plant still in the collection, newest first.
+    pub plants: Vec<PublicPlant>,
+}
+
+/// One plant as shown to the public: identity and bloom history only, no care notes or schedules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicPlant {
+    /// The plant's record ID, stable across requests.
+    pub id: String,
+    /// The plant's display name.
+    pub name: String,
+    /// Species or hybrid name.
+    pub species: String,
+    /// The zone the plant grows in.
+    pub placement: String,
+    /// IUCN or CITES status, if known.
+    pub conservation_status: Option<String>,
+    /// Where the species grows in the wild.
+    pub native_region: Option<String>,
+    /// When the plant first bloomed in this collection.
+    pub first_bloom_at: Option<DateTime<Utc>>,
+    /// Site-relative URL of the photo the grower chose to represent the plant, if any.
+    pub cover_image_url: Option<String>,
+    /// Flowering journal entries, newest first.
+    pub blooms: Vec<PublicBloom>,
+}
+
+/// A bloom event from the plant's journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicBloom {
+    /// When the bloom was logged.
+    pub timestamp: DateTime<Utc>,
+    /// Site-relative URL of the bloom photo, if one was attached.
+    pub image_url: Option<String>,
+}
+
+/// Whether the feed is enabled, shown in settings. The key itself is only returned once, when created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicFeedStatus {
+    /// True when a feed key exists.
+    pub enabled: bool,
+    /// The first few characters of the key, so growers can tell which key is live.
+    pub prefix: Option<String>,
+}
+
+/// **What is it?**
+/// A function that turns a collection and its journal entries into the public JSON feed.
+///
+/// **Why does it exist?**
+/// It exists so the fields a grower exposes to community sites and bots are decided in one place: notes, care schedules, sensor data and journal text never leave the server.
+///
+/// **How should it be used?**
+/// Pass the owner's plants and their journal entries keyed by plant ID; archived plants and entries other than blooms are dropped.
+pub fn build_feed(
+    username: &str,
+    hemisphere: &str,
+    orchids: &[Orchid],
+    entries: &[(String, LogEntry)],
+    generated_at: DateTime<Utc>,
+) -> PublicCollectionFeed {
+    let plants = orchids.iter().filter(|orchid| !orchid.is_archived()).map(|orchid| {
+        let mut blooms: Vec<PublicBloom> = entries.iter()
+            .filter(|(orchid_id, entry)| *orchid_id == orchid.id && entry.event_type.as_deref() == Some(BLOOM_EVENT))
+            .map(|(_, entry)| PublicBloom {
+                timestamp: entry.timestamp,
+                image_url: entry.image_filename.as_ref().map(|f| format!("/images/{}", f)),
+            })
+            .collect();
+        blooms.sort_by_key(|b| std::cmp::Reverse(b.timestamp));
+
+        PublicPlant {
+            id: orchid.id.clone(),
+            name: orchid.name.clone(),
+            species: orchid.species.clone(),
+            placement: orchid.placement.clone(),
+            conservation_status: orchid.conservation_status.clone(),
+            native_region: orchid.native_region.clone(),
+            first_bloom_at: orchid.first_bloom_at,
+            cover_image_url: orchid.cover_image.as_ref().map(|f| format!("/images/{}", f)),
+            blooms,
+        }
+    }).collect();
+
+    PublicCollectionFeed {
+        username: username.to_string(),
+        hemisphere: hemisphere.to_string(),
+        generated_at,
+        plants,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    fn entry(days_ago: i64, event_type: &str, image: Option<&str>) -> LogEntry {
+        LogEntry {
+            id: format!("log_entry:{}", days_ago),
+            timestamp: Utc::now() - chrono::Duration::days(days_ago),
+            note: "Private note".into(),
+            image_filename: image.map(String::from),
+            event_type: Some(event_type.into()),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
+        }
+    }
+
+    #[test]
+    fn test_feed_keeps_only_blooms_newest_first() {
+        let orchid = Orchid { id: "orchid:1".into(), ..test_orchid() };
+        let entries = vec![
+            ("orchid:1".to_string(), entry(30, "Flowering", None)),
+            ("orchid:1".to_string(), entry(2, "Flowering", Some("o/bloom.jpg"))),
+            ("orchid:1".to_string(), entry(1, "Watered", None)),
+            ("orchid:2".to_string(), entry(5, "Flowering", None)),
+        ];
+        let feed = build_feed("grower", "N", &[orchid], &entries, Utc::now());
+        let blooms = &feed.plants[0].blooms;
+        assert_eq!(blooms.len(), 2);
+        assert!(blooms[0].timestamp > blooms[1].timestamp);
+        assert_eq!(blooms[0].image_url.as_deref(), Some("/images/o/bloom.jpg"));
+    }
+
+    #[test]
+    fn test_feed_omits_private_fields() {
+        let orchid = Orchid { notes: "Bought from a friend".into(), ..test_orchid() };
+        let entries = vec![(orchid.id.clone(), entry(1, "Flowering", None))];
+        let feed = build_feed("grower", "S", &[orchid], &entries, Utc::now());
+        let json = serde_json::to_string(&feed).unwrap();
+        assert!(!json.contains("Bought from a friend"));
+        assert!(!json.contains("Private note"));
+        assert!(!json.contains("water_frequency_days"));
+    }
+
+    #[test]
+    fn test_feed_excludes_archived_plants() {
+        let kept = Orchid { id: "orchid:1".into(), ..test_orchid() };
+        let gifted = Orchid { id: "orchid:2".into(), archived_at: Some(Utc::now()), ..test_orchid() };
+        let entries = vec![("orchid:2".to_string(), entry(3, "Flowering", None))];
+        let feed = build_feed("grower", "N", &[kept, gifted], &entries, Utc::now());
+        let ids: Vec<&str> = feed.plants.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["orchid:1"]);
+    }
+}
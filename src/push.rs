@@ -15,6 +15,16 @@ pub async fn send_push(
     subscription: &PushSubscriptionRow,
     title: &str,
     body: &str,
+) -> Result<(), crate::error::AppError> {
+    send_push_with_actions(subscription, title, body, &[]).await
+}
+
+/// Send a Web Push notification with action buttons (see `push_action`) to a single subscriber.
+pub async fn send_push_with_actions(
+    subscription: &PushSubscriptionRow,
+    title: &str,
+    body: &str,
+    actions: &[crate::push_action::PushAction],
 ) -> Result<(), crate::error::AppError> {
     use web_push::*;
 
@@ -39,11 +49,7 @@ pub async fn send_push(
         &subscription.auth,
     );
 
-    let payload = serde_json::json!({
-        "title": title,
-        "body": body,
-        "url": "/"
-    });
+    let payload = crate::push_action::payload(title, body, actions);
 
     let mut builder = WebPushMessageBuilder::new(&subscription_info);
     let payload_bytes = payload.to_string().into_bytes();
@@ -91,3 +97,195 @@ pub async fn send_push(
     tracing::info!(endpoint = %subscription.endpoint, "Push notification sent successfully");
     Ok(())
 }
+
+/// Post a notification to a Discord or Slack channel webhook.
+pub async fn send_webhook(url: &str, title: &str, body: &str) -> Result<(), crate::error::AppError> {
+    let kind = crate::webhook::WebhookKind::detect(url)
+        .map_err(crate::error::AppError::Network)?;
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| crate::error::AppError::Network(format!("Webhook client error: {}", e)))?;
+
+    let resp = client
+        .post(url.trim())
+        .json(&kind.payload(title, body))
+        .send()
+        .await
+        .map_err(|e| {
+            tracing::error!(kind = kind.label(), "Webhook send failed: {}", e);
+            crate::error::AppError::Network(format!("Webhook send error: {}", e))
+        })?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        tracing::error!(kind = kind.label(), %status, "Webhook rejected the message");
+        return Err(crate::error::AppError::Network(format!("{} webhook returned {}", kind.label(), status)));
+    }
+
+    tracing::info!(kind = kind.label(), title = %title, "Webhook notification sent");
+    Ok(())
+}
+
+/// Publish a notification to an ntfy topic.
+pub async fn send_ntfy(
+    target: &crate::ntfy::NtfyTarget,
+    title: &str,
+    body: &str,
+) -> Result<(), crate::error::AppError> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| crate::error::AppError::Network(format!("ntfy client error: {}", e)))?;
+
+    let mut request = client.post(&target.server).json(&target.payload(title, body));
+    if let Some(token) = &target.token {
+        request = request.bearer_auth(token);
+    }
+    let resp = request.send().await.map_err(|e| {
+        tracing::error!(server = %target.server, "ntfy send failed: {}", e);
+        crate::error::AppError::Network(format!("ntfy send error: {}", e))
+    })?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        tracing::error!(server = %target.server, %status, "ntfy rejected the message");
+        return Err(crate::error::AppError::Network(format!("ntfy server returned {}", status)));
+    }
+
+    tracing::info!(server = %target.server, title = %title, "ntfy notification sent");
+    Ok(())
+}
+
+/// Publish a notification to the ntfy topic of one user, if they set one up.
+pub async fn send_ntfy_to_user(user: &surrealdb::types::RecordId, title: &str, body: &str) {
+    send_ntfy_matching("owner = $owner", user, title, body).await;
+}
+
+/// Publish a notification to the ntfy topics of everyone in a household: the owner and each member.
+pub async fn send_ntfy_to_household(owner: &surrealdb::types::RecordId, title: &str, body: &str) {
+    send_ntfy_matching(
+        "(owner = $owner OR owner IN (SELECT VALUE user FROM household_member WHERE household.owner = $owner))",
+        owner,
+        title,
+        body,
+    ).await;
+}
+
+/// Load the ntfy targets whose `user_preference` row matches `filter` and publish to each.
+async fn send_ntfy_matching(filter: &str, owner: &surrealdb::types::RecordId, title: &str, body: &str) {
+    for (user, target) in ntfy_targets(filter, owner).await {
+        let result = send_ntfy(&target, title, body).await;
+        log_delivery(&user, crate::notification_log::NotificationChannel::Ntfy, title, body, &result).await;
+        if let Err(e) = result {
+            tracing::warn!("ntfy notification failed: {}", e);
+        }
+    }
+}
+
+/// The ntfy targets set up on `user_preference` rows matching `filter`, a fixed SurrealQL condition
+/// over `$owner`, with tokens decrypted, each with the user it belongs to.
+pub(crate) async fn ntfy_targets(
+    filter: &str,
+    owner: &surrealdb::types::RecordId,
+) -> Vec<(surrealdb::types::RecordId, crate::ntfy::NtfyTarget)> {
+    use crate::db::db;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct NtfyRow {
+        owner: surrealdb::types::RecordId,
+        #[surreal(default)]
+        ntfy_server: Option<String>,
+        ntfy_topic: String,
+        #[surreal(default)]
+        ntfy_token: Option<String>,
+    }
+
+    let query = format!(
+        "SELECT owner, ntfy_server, ntfy_topic, ntfy_token FROM user_preference WHERE {} AND ntfy_topic != NONE",
+        filter,
+    );
+    let mut resp = match db().query(query).bind(("owner", owner.clone())).await {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("ntfy: failed to query targets: {}", e);
+            return Vec::new();
+        }
+    };
+    let _ = resp.take_errors();
+    let rows: Vec<NtfyRow> = resp.take(0).unwrap_or_default();
+
+    rows.into_iter()
+        .filter_map(|row| {
+            let token = row.ntfy_token.map(|t| crate::crypto::decrypt_or_raw(&t));
+            crate::ntfy::NtfyTarget::new(row.ntfy_server.as_deref().unwrap_or_default(), &row.ntfy_topic, token.as_deref())
+                .ok()
+                .map(|target| (row.owner, target))
+        })
+        .collect()
+}
+
+/// Send a Web Push notification and record it in the user's notification log.
+pub async fn deliver_push(
+    user: &surrealdb::types::RecordId,
+    subscription: &PushSubscriptionRow,
+    title: &str,
+    body: &str,
+) -> Result<(), crate::error::AppError> {
+    deliver_push_with_actions(user, subscription, title, body, &[]).await
+}
+
+/// Send a Web Push notification with action buttons and record it in the user's notification log.
+pub async fn deliver_push_with_actions(
+    user: &surrealdb::types::RecordId,
+    subscription: &PushSubscriptionRow,
+    title: &str,
+    body: &str,
+    actions: &[crate::push_action::PushAction],
+) -> Result<(), crate::error::AppError> {
+    let result = send_push_with_actions(subscription, title, body, actions).await;
+    log_delivery(user, crate::notification_log::NotificationChannel::Push, title, body, &result).await;
+    result
+}
+
+/// Post to a channel webhook and record it in the owner's notification log.
+pub async fn deliver_webhook(
+    owner: &surrealdb::types::RecordId,
+    url: &str,
+    title: &str,
+    body: &str,
+) -> Result<(), crate::error::AppError> {
+    let result = send_webhook(url, title, body).await;
+    log_delivery(owner, crate::notification_log::NotificationChannel::Webhook, title, body, &result).await;
+    result
+}
+
+/// Record a send attempt in `notification_log`, so growers can tell a missed alert from one never sent.
+/// Logging failures are only traced; they never hold up delivery.
+pub async fn log_delivery(
+    user: &surrealdb::types::RecordId,
+    channel: crate::notification_log::NotificationChannel,
+    title: &str,
+    body: &str,
+    result: &Result<(), crate::error::AppError>,
+) {
+    use crate::db::db;
+    use crate::notification_log::{clip, MAX_LOGGED_BODY_LEN, MAX_LOGGED_ERROR_LEN};
+
+    let error = result.as_ref().err().map(|e| clip(&e.to_string(), MAX_LOGGED_ERROR_LEN));
+    if let Err(e) = db()
+        .query("CREATE notification_log SET owner = $owner, channel = $channel, title = $title, body = $body, delivered = $delivered, error = $error")
+        .bind(("owner", user.clone()))
+        .bind(("channel", channel.as_str().to_string()))
+        .bind(("title", clip(title, MAX_LOGGED_BODY_LEN)))
+        .bind(("body", clip(body, MAX_LOGGED_BODY_LEN)))
+        .bind(("delivered", result.is_ok()))
+        .bind(("error", error))
+        .await
+    {
+        tracing::warn!("Failed to log notification delivery: {}", e);
+    }
+}
@@ -0,0 +1,72 @@
+/// Where the service worker posts "Mark watered" taps.
+pub const MARK_WATERED_PATH: &str = "/api/push/mark-watered";
+
+/// A button on a Web Push notification that does something without opening the app.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PushAction {
+    /// Log a watering for the plant the notification is about.
+    MarkWatered {
+        /// The plant's record ID.
+        orchid_id: String,
+    },
+}
+
+impl PushAction {
+    /// The action name the service worker receives in `notificationclick`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            PushAction::MarkWatered { .. } => "mark-watered",
+        }
+    }
+
+    /// The button label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PushAction::MarkWatered { .. } => "Mark watered",
+        }
+    }
+
+    /// What the service worker needs to carry it out: the route to POST to and the JSON body.
+    fn request(&self) -> (&'static str, serde_json::Value) {
+        match self {
+            PushAction::MarkWatered { orchid_id } => (MARK_WATERED_PATH, serde_json::json!({ "orchid_id": orchid_id })),
+        }
+    }
+}
+
+/// The JSON a push notification carries to `public/sw.js`: title, body and the page to open, plus
+/// each action's button and the request to make when it's tapped.
+pub fn payload(title: &str, body: &str, actions: &[PushAction]) -> serde_json::Value {
+    let actions: Vec<serde_json::Value> = actions
+        .iter()
+        .map(|action| {
+            let (endpoint, request) = action.request();
+            serde_json::json!({ "action": action.key(), "title": action.label(), "endpoint": endpoint, "body": request })
+        })
+        .collect();
+    serde_json::json!({ "title": title, "body": body, "url": "/", "actions": actions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_carries_action_requests() {
+        assert_eq!(
+            payload("Warning", "Kingie: Watering overdue by 5 days", &[PushAction::MarkWatered { orchid_id: "orchid:k1".into() }]),
+            serde_json::json!({
+                "title": "Warning",
+                "body": "Kingie: Watering overdue by 5 days",
+                "url": "/",
+                "actions": [{
+                    "action": "mark-watered",
+                    "title": "Mark watered",
+                    "endpoint": "/api/push/mark-watered",
+                    "body": { "orchid_id": "orchid:k1" },
+                }],
+            }),
+        );
+        assert_eq!(payload("Reminder", "Repot", &[])["actions"], serde_json::json!([]));
+    }
+}
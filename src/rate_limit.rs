@@ -0,0 +1,175 @@
+use std::num::NonZeroU32;
+use std::sync::LazyLock;
+use std::time::Duration;
+use governor::clock::{Clock, DefaultClock};
+use governor::{DefaultKeyedRateLimiter, Quota, RateLimiter};
+
+/// Requests per second each signed-in user (or, signed out, each client IP) may make across the site.
+const REQUESTS_PER_SECOND: NonZeroU32 = NonZeroU32::new(100).unwrap();
+/// How many requests may arrive at once before `REQUESTS_PER_SECOND` applies.
+const REQUEST_BURST: NonZeroU32 = NonZeroU32::new(200).unwrap();
+
+/// Site-wide limits, keyed by `user:<id>` for signed-in requests and `ip:<address>` otherwise, so
+/// a household behind one NAT doesn't share a single allowance.
+static REQUESTS: LazyLock<DefaultKeyedRateLimiter<String>> =
+    LazyLock::new(|| RateLimiter::keyed(Quota::per_second(REQUESTS_PER_SECOND).allow_burst(REQUEST_BURST)));
+
+/// Endpoints that cost real money or disk per call, each limited per user on top of the site-wide limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expensive {
+    /// A call to an AI provider: scans, diagnoses, care recaps and chat.
+    Ai,
+    /// A photo upload.
+    Upload,
+}
+
+impl Expensive {
+    /// The short-term limit: a burst, then one more per interval.
+    fn quota(self) -> Quota {
+        match self {
+            Expensive::Ai => Quota::per_minute(NonZeroU32::new(6).unwrap()).allow_burst(NonZeroU32::new(10).unwrap()),
+            Expensive::Upload => Quota::per_minute(NonZeroU32::new(30).unwrap()).allow_burst(NonZeroU32::new(30).unwrap()),
+        }
+    }
+
+    /// The daily cap from config, spread evenly over the day once the allowance is spent; `None`
+    /// when it's 0.
+    fn daily_quota(self) -> Option<Quota> {
+        let cfg = crate::config::config();
+        let per_day = NonZeroU32::new(match self {
+            Expensive::Ai => cfg.ai_daily_quota,
+            Expensive::Upload => cfg.upload_daily_quota,
+        })?;
+        Quota::with_period(Duration::from_secs(24 * 60 * 60) / per_day.get()).map(|q| q.allow_burst(per_day))
+    }
+
+    fn noun(self) -> &'static str {
+        match self {
+            Expensive::Ai => "AI requests",
+            Expensive::Upload => "photo uploads",
+        }
+    }
+}
+
+/// Short-term and daily limiters for one kind of expensive endpoint, keyed by user ID.
+struct ExpensiveLimiters {
+    short: DefaultKeyedRateLimiter<String>,
+    daily: Option<DefaultKeyedRateLimiter<String>>,
+}
+
+impl ExpensiveLimiters {
+    fn new(kind: Expensive) -> Self {
+        Self { short: RateLimiter::keyed(kind.quota()), daily: kind.daily_quota().map(RateLimiter::keyed) }
+    }
+}
+
+static AI: LazyLock<ExpensiveLimiters> = LazyLock::new(|| ExpensiveLimiters::new(Expensive::Ai));
+static UPLOAD: LazyLock<ExpensiveLimiters> = LazyLock::new(|| ExpensiveLimiters::new(Expensive::Upload));
+
+/// Counts one `kind` call against `user_id`, or says how long to wait when the user is over the
+/// per-minute limit or the daily cap. Call it after authenticating and before doing the work.
+pub fn check_user(kind: Expensive, user_id: &str) -> Result<(), String> {
+    let limiters = match kind {
+        Expensive::Ai => &*AI,
+        Expensive::Upload => &*UPLOAD,
+    };
+    let key = user_id.to_string();
+    let clock = DefaultClock::default();
+
+    // Checking the daily cap second means a burst turned away by the short-term limit doesn't use it up
+    if let Err(not_until) = limiters.short.check_key(&key) {
+        let wait = not_until.wait_time_from(clock.now());
+        return Err(format!("Too many {} just now; try again in {}", kind.noun(), describe_wait(wait)));
+    }
+    if let Some(daily) = &limiters.daily
+        && let Err(not_until) = daily.check_key(&key)
+    {
+        let wait = not_until.wait_time_from(clock.now());
+        return Err(format!("You've reached today's limit on {}; try again in {}", kind.noun(), describe_wait(wait)));
+    }
+    Ok(())
+}
+
+/// Forgets keys that have been idle long enough to have a full allowance again. Run periodically.
+pub fn retain_recent() {
+    REQUESTS.retain_recent();
+    for limiters in [&*AI, &*UPLOAD] {
+        limiters.short.retain_recent();
+        if let Some(daily) = &limiters.daily {
+            daily.retain_recent();
+        }
+    }
+}
+
+/// Axum middleware applying the site-wide limit: per user for signed-in requests, per client IP
+/// otherwise. Static files are always keyed by IP so serving them never loads the session. Must
+/// sit inside the session layer.
+pub async fn limit_requests(
+    session: tower_sessions::Session,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+    use tower_governor::key_extractor::{KeyExtractor, SmartIpKeyExtractor};
+
+    let user_id = if is_static_path(request.uri().path()) {
+        None
+    } else {
+        session.get::<String>("user_id").await.ok().flatten()
+    };
+    let key = match user_id {
+        Some(user_id) => format!("user:{}", user_id),
+        None => match SmartIpKeyExtractor.extract(&request) {
+            Ok(ip) => format!("ip:{}", ip),
+            Err(_) => "ip:unknown".to_string(),
+        },
+    };
+
+    if let Err(not_until) = REQUESTS.check_key(&key) {
+        let wait = not_until.wait_time_from(DefaultClock::default().now());
+        return (
+            axum::http::StatusCode::TOO_MANY_REQUESTS,
+            [(axum::http::header::RETRY_AFTER, wait.as_secs().max(1).to_string())],
+            "Too many requests",
+        )
+            .into_response();
+    }
+    next.run(request).await
+}
+
+/// Whether `path` is a static file (the compiled app, photos, thumbnails, icons) rather than a
+/// page or API call.
+fn is_static_path(path: &str) -> bool {
+    ["/pkg/", "/images/", "/thumbs/"].iter().any(|prefix| path.starts_with(prefix))
+        || path.rsplit('/').next().is_some_and(|last| last.contains('.'))
+}
+
+/// A wait as a person would say it, rounded up: "20 seconds", "3 minutes", "2 hours".
+fn describe_wait(wait: Duration) -> String {
+    let secs = wait.as_secs().max(1);
+    let (n, unit) = match secs {
+        0..60 => (secs, "second"),
+        60..3600 => (secs.div_ceil(60), "minute"),
+        _ => (secs.div_ceil(3600), "hour"),
+    };
+    format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_static_paths_and_waits() {
+        assert!(is_static_path("/pkg/orchid-tracker.wasm"));
+        assert!(is_static_path("/images/user_1/abc.webp"));
+        assert!(is_static_path("/favicon.ico"));
+        assert!(!is_static_path("/api/get_orchids_page"));
+        assert!(!is_static_path("/api/images/upload"));
+        assert!(!is_static_path("/settings"));
+
+        assert_eq!(describe_wait(Duration::from_millis(300)), "1 second");
+        assert_eq!(describe_wait(Duration::from_secs(61)), "2 minutes");
+        assert_eq!(describe_wait(Duration::from_secs(3 * 3600)), "3 hours");
+    }
+}
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+
+/// The journal event type a root assessment is logged as.
+pub const ROOT_ASSESSMENT_EVENT: &str = "Repotted";
+/// Most materials kept in a medium breakdown.
+pub const MAX_MEDIUM_PARTS: usize = 6;
+/// Longest material name kept, in bytes.
+pub const MAX_MATERIAL_LEN: usize = 40;
+/// Longest summary kept, in bytes.
+pub const MAX_ROOT_SUMMARY_LEN: usize = 600;
+/// Soonest next repot that can be suggested, in months.
+pub const MIN_REPOT_MONTHS: u32 = 1;
+/// Latest next repot that can be suggested, in months.
+pub const MAX_REPOT_MONTHS: u32 = 36;
+/// Share of rotten roots, in percent, at which the next repot is brought forward.
+pub const ROT_CONCERN_PCT: f64 = 25.0;
+
+/// How far the old medium had broken down.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types", untagged))]
+pub enum MediumCondition {
+    /// Chunky and open, still draining well.
+    Fresh,
+    /// Softening, with some fines.
+    Aging,
+    /// Soggy, compacted or sour.
+    BrokenDown,
+    /// Not visible in the photo. Anything unrecognised lands here.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl MediumCondition {
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MediumCondition::Fresh => "Fresh",
+            MediumCondition::Aging => "Aging",
+            MediumCondition::BrokenDown => "Broken down",
+            MediumCondition::Unknown => "Unknown",
+        }
+    }
+}
+
+/// One material in the old medium and its share of the mix.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types"))]
+pub struct MediumPart {
+    /// The material, such as "Fir bark" or "Perlite".
+    pub material: String,
+    /// Share of the mix, in percent.
+    pub pct: f64,
+}
+
+/// An assessment of a plant's roots from a photo taken at repotting: how much is healthy, rotten or
+/// dried out, what the old medium was made of and how worn it was, and when to repot next.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(surrealdb::types::SurrealValue))]
+#[cfg_attr(feature = "ssr", surreal(crate = "surrealdb::types"))]
+pub struct RootAssessment {
+    /// Firm, green or silvery roots, in percent.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub healthy_pct: f64,
+    /// Brown, mushy or hollow roots, in percent.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub rot_pct: f64,
+    /// Papery, dried-out roots, in percent.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub dry_pct: f64,
+    /// What the old medium was made of, largest share first.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub medium: Vec<MediumPart>,
+    /// How far the old medium had broken down.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub medium_condition: MediumCondition,
+    /// Months until the plant should be repotted again.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub next_repot_months: Option<u32>,
+    /// A sentence or two on what the photo shows.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", surreal(default))]
+    pub summary: String,
+}
+
+/// Cuts `s` to at most `max` bytes on a character boundary.
+fn truncate(s: &str, max: usize) -> String {
+    let mut end = s.len().min(max);
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    s[..end].trim().to_string()
+}
+
+/// `v` held to a whole percentage within 0-100.
+fn percent(v: f64) -> f64 {
+    if v.is_finite() { v.clamp(0.0, 100.0).round() } else { 0.0 }
+}
+
+impl RootAssessment {
+    /// Tidies a model's answer: root shares as whole percentages adding up to at most 100, the medium
+    /// breakdown scaled to 100 with blank and repeated materials dropped, and a next repot within
+    /// `MIN_REPOT_MONTHS..=MAX_REPOT_MONTHS` (estimated from the medium when the model gave none).
+    pub fn normalize(mut self) -> Self {
+        // A model answering in fractions answers in fractions throughout
+        let fractions = [self.healthy_pct, self.rot_pct, self.dry_pct].iter().all(|v| (0.0..=1.0).contains(v));
+        let scale = |v: f64| if fractions { percent(v * 100.0) } else { percent(v) };
+        let (mut healthy, mut rot, mut dry) = (scale(self.healthy_pct), scale(self.rot_pct), scale(self.dry_pct));
+        let total = healthy + rot + dry;
+        if total > 100.0 {
+            healthy = (healthy * 100.0 / total).round();
+            rot = (rot * 100.0 / total).round();
+            dry = (100.0 - healthy - rot).max(0.0);
+        }
+        (self.healthy_pct, self.rot_pct, self.dry_pct) = (healthy, rot, dry);
+
+        let mut seen = Vec::new();
+        self.medium.retain_mut(|part| {
+            part.material = truncate(&part.material, MAX_MATERIAL_LEN);
+            let key = part.material.to_lowercase();
+            if key.is_empty() || seen.contains(&key) || !part.pct.is_finite() || part.pct <= 0.0 {
+                return false;
+            }
+            seen.push(key);
+            true
+        });
+        self.medium.sort_by(|a, b| b.pct.total_cmp(&a.pct));
+        self.medium.truncate(MAX_MEDIUM_PARTS);
+        let total: f64 = self.medium.iter().map(|p| p.pct).sum();
+        for part in &mut self.medium {
+            part.pct = (part.pct * 100.0 / total).round();
+        }
+
+        self.summary = truncate(&self.summary, MAX_ROOT_SUMMARY_LEN);
+        let months = self.next_repot_months.filter(|m| *m > 0).unwrap_or_else(|| self.estimated_repot_months());
+        self.next_repot_months = Some(months.clamp(MIN_REPOT_MONTHS, MAX_REPOT_MONTHS));
+        self
+    }
+
+    /// Checks a submitted assessment is in range, as `normalize` leaves it; the error names the first problem.
+    pub fn validate(&self) -> Result<(), String> {
+        let shares = [self.healthy_pct, self.rot_pct, self.dry_pct];
+        if shares.iter().any(|v| !(0.0..=100.0).contains(v)) || shares.iter().sum::<f64>() > 100.5 {
+            return Err("Root shares must be 0-100% and add up to at most 100%".into());
+        }
+        if self.medium.len() > MAX_MEDIUM_PARTS
+            || self.medium.iter().any(|p| p.material.trim().is_empty() || p.material.len() > MAX_MATERIAL_LEN || !(0.0..=100.0).contains(&p.pct))
+        {
+            return Err(format!("The medium can list at most {} named materials, each 0-100%", MAX_MEDIUM_PARTS));
+        }
+        if self.next_repot_months.is_some_and(|m| !(MIN_REPOT_MONTHS..=MAX_REPOT_MONTHS).contains(&m)) {
+            return Err(format!("Next repot must be {}-{} months away", MIN_REPOT_MONTHS, MAX_REPOT_MONTHS));
+        }
+        if self.summary.len() > MAX_ROOT_SUMMARY_LEN {
+            return Err(format!("Summary must be at most {} characters", MAX_ROOT_SUMMARY_LEN));
+        }
+        Ok(())
+    }
+
+    /// A next repot estimated from the medium: sphagnum sours in about a year, bark lasts about two and
+    /// inorganic mixes about three. A worn-out medium or a lot of rot halves it, so the roots get checked sooner.
+    pub fn estimated_repot_months(&self) -> u32 {
+        let main = self.medium.first().map(|p| p.material.to_lowercase()).unwrap_or_default();
+        let months = if main.contains("sphagnum") || main.contains("moss") {
+            12
+        } else if ["leca", "clay", "lava", "pumice", "perlite", "rock", "inorganic"].iter().any(|m| main.contains(m)) {
+            36
+        } else {
+            24
+        };
+        if self.rot_pct >= ROT_CONCERN_PCT || self.medium_condition == MediumCondition::BrokenDown {
+            months / 2
+        } else {
+            months
+        }
+    }
+
+    /// Whether enough of the roots are rotten to be worth watching.
+    pub fn is_concerning(&self) -> bool {
+        self.rot_pct >= ROT_CONCERN_PCT
+    }
+
+    /// The medium breakdown, e.g. "60% fir bark, 30% perlite, 10% charcoal".
+    pub fn describe_medium(&self) -> String {
+        self.medium.iter()
+            .map(|p| format!("{:.0}% {}", p.pct, p.material.to_lowercase()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The journal note for a repot, e.g. "Roots 70% healthy · 20% rot · 10% dried out. Old medium (aging): 60% bark, 40% perlite. Next repot in about 12 months."
+    pub fn describe(&self) -> String {
+        let mut out = format!(
+            "Roots {:.0}% healthy \u{00B7} {:.0}% rot \u{00B7} {:.0}% dried out.",
+            self.healthy_pct, self.rot_pct, self.dry_pct,
+        );
+        if !self.medium.is_empty() {
+            let condition = match self.medium_condition {
+                MediumCondition::Unknown => String::new(),
+                c => format!(" ({})", c.label().to_lowercase()),
+            };
+            out.push_str(&format!(" Old medium{}: {}.", condition, self.describe_medium()));
+        }
+        if let Some(months) = self.next_repot_months {
+            out.push_str(&format!(" Next repot in about {} months.", months));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(material: &str, pct: f64) -> MediumPart {
+        MediumPart { material: material.into(), pct }
+    }
+
+    #[test]
+    fn test_normalize_scales_shares() {
+        let assessment = RootAssessment {
+            healthy_pct: 0.6,
+            rot_pct: 0.3,
+            dry_pct: 0.3,
+            medium: vec![part("Perlite", 1.0), part("Fir bark", 3.0), part(" ", 5.0), part("fir bark", 2.0)],
+            medium_condition: MediumCondition::BrokenDown,
+            next_repot_months: None,
+            summary: "Some rot at the base.".into(),
+        }.normalize();
+        assert_eq!((assessment.healthy_pct, assessment.rot_pct, assessment.dry_pct), (50.0, 25.0, 25.0));
+        assert_eq!(assessment.describe_medium(), "75% fir bark, 25% perlite");
+        // Bark lasts two years, halved for a broken-down mix with a quarter of the roots rotten
+        assert_eq!(assessment.next_repot_months, Some(12));
+        assert!(assessment.is_concerning());
+        assert!(assessment.validate().is_ok());
+        assert!(RootAssessment { healthy_pct: 80.0, rot_pct: 40.0, ..Default::default() }.validate().is_err());
+        assert_eq!(
+            assessment.describe(),
+            "Roots 50% healthy \u{00B7} 25% rot \u{00B7} 25% dried out. Old medium (broken down): 75% fir bark, 25% perlite. Next repot in about 12 months.",
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_model_timing_in_range() {
+        let assessment = RootAssessment {
+            healthy_pct: 90.0,
+            rot_pct: 5.0,
+            medium: vec![part("Sphagnum moss", 100.0)],
+            next_repot_months: Some(60),
+            ..Default::default()
+        }.normalize();
+        assert_eq!(assessment.next_repot_months, Some(MAX_REPOT_MONTHS));
+        assert_eq!(assessment.dry_pct, 0.0);
+        assert_eq!(RootAssessment { medium: vec![part("Sphagnum moss", 100.0)], ..Default::default() }.estimated_repot_months(), 12);
+    }
+}
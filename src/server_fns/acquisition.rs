@@ -0,0 +1,206 @@
+use leptos::prelude::*;
+use crate::acquisition::{CollectionValue, Vendor};
+
+/// Longest vendor name accepted, matching the schema assertion.
+#[cfg(feature = "ssr")]
+const MAX_VENDOR_NAME_LEN: usize = 200;
+/// Longest vendor website accepted, matching the schema assertion.
+#[cfg(feature = "ssr")]
+const MAX_WEBSITE_LEN: usize = 500;
+
+#[cfg(feature = "ssr")]
+async fn load_vendors(owner: surrealdb::types::RecordId) -> Result<Vec<Vendor>, ServerFnError> {
+    use surrealdb::types::SurrealValue;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct VendorDbRow {
+        id: surrealdb::types::RecordId,
+        name: String,
+        #[surreal(default)]
+        website: Option<String>,
+        created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    let mut resp = db()
+        .query("SELECT id, name, website, created_at FROM vendor WHERE owner = $owner ORDER BY name ASC")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get vendors query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get vendors query error", err_msg));
+    }
+
+    let rows: Vec<VendorDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get vendors parse failed", e))?;
+
+    Ok(rows.into_iter().map(|r| Vendor {
+        id: record_id_to_string(&r.id),
+        name: r.name,
+        website: r.website,
+        created_at: r.created_at,
+    }).collect())
+}
+
+/// **What is it?**
+/// A server function that lists the collection's vendors, alphabetically.
+///
+/// **Why does it exist?**
+/// It exists to fill the vendor pickers in the add and edit forms, and to name vendors in the details view.
+///
+/// **How should it be used?**
+/// Load once per form; add new vendors with `create_vendor`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_vendors() -> Result<Vec<Vendor>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    load_vendors(parse_owner(&owner_id)?).await
+}
+
+/// **What is it?**
+/// A server function that adds a vendor to the collection.
+///
+/// **Why does it exist?**
+/// It exists so plants bought from the same nursery or grower share one vendor record, which the value summary groups spend by.
+///
+/// **How should it be used?**
+/// Call from the vendor picker's "Add" action. Returns the new vendor so the picker can select it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_vendor(
+    /// The vendor's display name.
+    name: String,
+    /// Website or shop link.
+    website: Option<String>,
+) -> Result<Vendor, ServerFnError> {
+    use surrealdb::types::SurrealValue;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::server_fns::climate::parse_owner;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct CreatedRow {
+        id: surrealdb::types::RecordId,
+        created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    let name = name.trim().to_string();
+    if name.is_empty() || name.len() > MAX_VENDOR_NAME_LEN {
+        return Err(ServerFnError::new(format!("Vendor name must be 1 to {} characters", MAX_VENDOR_NAME_LEN)));
+    }
+    let website = website.map(|w| w.trim().to_string()).filter(|w| !w.is_empty());
+    if website.as_ref().is_some_and(|w| w.len() > MAX_WEBSITE_LEN) {
+        return Err(ServerFnError::new(format!("Website must be at most {} characters", MAX_WEBSITE_LEN)));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("CREATE vendor SET owner = $owner, name = $name, website = $website RETURN id, created_at")
+        .bind(("owner", owner))
+        .bind(("name", name.clone()))
+        .bind(("website", website.clone()))
+        .await
+        .map_err(|e| internal_error("Create vendor query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create vendor query error", err_msg));
+    }
+
+    let created: Option<CreatedRow> = resp.take(0)
+        .map_err(|e| internal_error("Create vendor parse failed", e))?;
+    let created = created.ok_or_else(|| ServerFnError::new("Failed to create vendor"))?;
+
+    Ok(Vendor {
+        id: record_id_to_string(&created.id),
+        name,
+        website,
+        created_at: created.created_at,
+    })
+}
+
+/// **What is it?**
+/// A server function that deletes a vendor and clears it from the plants bought there.
+///
+/// **Why does it exist?**
+/// It exists to tidy up duplicates and typos in the vendor list without leaving plants pointing at a missing record.
+///
+/// **How should it be used?**
+/// Call from the vendor list in the Stats tab after the grower confirms. Prices, dates and sources on the plants are kept.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_vendor(
+    /// The unique identifier of the vendor.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let vendor = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Vendor ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             UPDATE orchid SET vendor = NONE, updated_at = time::now() WHERE owner = $owner AND vendor = $id; \
+             DELETE $id WHERE owner = $owner; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("id", vendor))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete vendor query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete vendor query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that summarizes what the collection cost: total and average spend, and spend by vendor, source and year.
+///
+/// **Why does it exist?**
+/// It exists for the Stats tab, so collectors can see where their money went without exporting to a spreadsheet.
+///
+/// **How should it be used?**
+/// Load when the Stats tab opens. Plants without a price count towards `unpriced_plants` only; see `crate::acquisition::collection_value`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_collection_value() -> Result<CollectionValue, ServerFnError> {
+    use crate::acquisition::collection_value;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let vendors = load_vendors(parse_owner(&owner_id)?).await?;
+    let orchids = crate::server_fns::orchids::get_orchids().await?;
+
+    Ok(collection_value(&orchids, &vendors))
+}
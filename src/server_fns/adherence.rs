@@ -0,0 +1,116 @@
+use leptos::prelude::*;
+use crate::adherence::AdherenceStats;
+
+/// **What is it?**
+/// An SSR-only helper that loads an owner's waterings and skips between two instants, grouped by plant as `CareEvent`s.
+///
+/// **Why does it exist?**
+/// It exists so the adherence widget and the monthly report judge waterings from the same events, with mistings that don't restart a plant's countdown already left out.
+///
+/// **How should it be used?**
+/// Pass the owner's plants (for their default watering methods) and start `from` well before the window being judged, so its first watering has one to be judged against.
+#[cfg(feature = "ssr")]
+pub(crate) async fn care_events_for_owner(
+    owner: &surrealdb::types::RecordId,
+    orchids: &[crate::orchid::Orchid],
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+) -> Result<std::collections::HashMap<String, Vec<crate::adherence::CareEvent>>, ServerFnError> {
+    use std::collections::HashMap;
+    use surrealdb::types::SurrealValue;
+    use crate::adherence::CareEvent;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::orchid::WateringMethod;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct CareEventRow {
+        orchid: surrealdb::types::RecordId,
+        timestamp: chrono::DateTime<chrono::Utc>,
+        event_type: String,
+        #[surreal(default)]
+        watering_method: Option<String>,
+    }
+
+    let mut resp = db()
+        .query(
+            "SELECT orchid, timestamp, event_type, watering_method FROM log_entry \
+             WHERE owner = $owner AND event_type IN ['Watered', 'Skipped'] \
+             AND timestamp > $from AND timestamp < $to \
+             ORDER BY timestamp ASC"
+        )
+        .bind(("owner", owner.clone()))
+        .bind(("from", from))
+        .bind(("to", to))
+        .await
+        .map_err(|e| internal_error("Adherence query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Adherence query error", err_msg));
+    }
+
+    let rows: Vec<CareEventRow> = resp.take(0)
+        .map_err(|e| internal_error("Adherence parse failed", e))?;
+
+    let default_methods: HashMap<&str, Option<WateringMethod>> = orchids.iter()
+        .map(|o| (o.id.as_str(), o.default_watering_method))
+        .collect();
+    let mut events: HashMap<String, Vec<CareEvent>> = HashMap::new();
+    for row in rows {
+        let orchid_id = record_id_to_string(&row.orchid);
+        let event = match row.event_type.as_str() {
+            "Skipped" => CareEvent::Skipped(row.timestamp),
+            _ => {
+                let method = row.watering_method.as_deref().and_then(WateringMethod::parse);
+                let default = default_methods.get(orchid_id.as_str()).copied().flatten();
+                if !WateringMethod::resets_schedule(method, default) {
+                    continue;
+                }
+                CareEvent::Watered(row.timestamp)
+            }
+        };
+        events.entry(orchid_id).or_default().push(event);
+    }
+    Ok(events)
+}
+
+/// **What is it?**
+/// A server function that measures how often each plant's waterings over the last 90 days came on time versus late.
+///
+/// **Why does it exist?**
+/// It exists so growers can see which plants slip through the cracks, judged from the journal's 'Watered' entries against each plant's seasonal schedule and grace period rather than from a single overdue flag.
+///
+/// **How should it be used?**
+/// Load from the adherence widget on the Today tab. Mistings that don't restart the countdown are ignored, and a skipped watering excuses the gap it falls in; see `crate::adherence::plant_adherence`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_watering_adherence() -> Result<AdherenceStats, ServerFnError> {
+    use crate::adherence::{collection_adherence, ADHERENCE_WINDOW_DAYS};
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::orchid::Hemisphere;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let orchids = crate::server_fns::orchids::get_orchids().await?;
+    let hemisphere = Hemisphere::from_code(&crate::server_fns::preferences::get_hemisphere().await?);
+    let grace_days = crate::server_fns::preferences::get_overdue_grace_days().await?;
+
+    // Look back twice the window so the first watering inside it has one to be judged against
+    let now = chrono::Utc::now();
+    let events = care_events_for_owner(&owner, &orchids, now - chrono::Duration::days(ADHERENCE_WINDOW_DAYS * 2), now).await?;
+
+    Ok(collection_adherence(
+        &orchids,
+        |id| events.get(id).map(Vec::as_slice).unwrap_or(&[]),
+        &hemisphere,
+        grace_days,
+        now,
+    ))
+}
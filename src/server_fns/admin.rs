@@ -0,0 +1,477 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ssr")]
+use surrealdb::types::SurrealValue;
+
+/// **What is it?**
+/// One account as shown in the instance admin panel.
+///
+/// **Why does it exist?**
+/// It exists so admins can see who uses the instance and how much they store without querying SurrealDB by hand.
+///
+/// **How should it be used?**
+/// Returned by `admin_list_users`; pass `id` to the other admin server functions to act on the account.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AdminUserSummary {
+    /// The user record ID.
+    pub id: String,
+    /// The username.
+    pub username: String,
+    /// The email address.
+    pub email: String,
+    /// Whether the user is an instance admin.
+    pub is_admin: bool,
+    /// Whether sign-in is blocked.
+    pub disabled: bool,
+    /// When the account was created.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When any of the user's sessions last made a request.
+    pub last_seen_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Plants in the user's own collection.
+    pub plant_count: usize,
+    /// Journal entries the user has logged.
+    pub journal_count: usize,
+    /// Journal entries with a photo attached.
+    pub photo_count: usize,
+    /// Raw climate readings across the user's zones.
+    pub reading_count: usize,
+    /// Bytes of uploaded images in the user's storage directory.
+    pub image_bytes: u64,
+}
+
+/// **What is it?**
+/// Instance-wide storage usage: uploaded images on disk and row counts of the largest tables.
+///
+/// **Why does it exist?**
+/// It exists so admins of small self-hosted instances can tell when disk or database growth needs attention.
+///
+/// **How should it be used?**
+/// Returned by `admin_storage_usage` and rendered on the admin page.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct StorageUsage {
+    /// Total bytes under the image storage path.
+    pub image_bytes: u64,
+    /// Number of image files stored.
+    pub image_files: usize,
+    /// `(table, rows)` for the main tables, largest first.
+    pub table_rows: Vec<(String, usize)>,
+    /// Bytes under `SURREAL_DATA_PATH`, when SurrealDB's data lives on this host and the path is set.
+    pub database_bytes: Option<u64>,
+}
+
+/// Tables counted in `StorageUsage::table_rows`.
+#[cfg(feature = "ssr")]
+const COUNTED_TABLES: &[&str] = &["user", "orchid", "log_entry", "climate_reading", "alert", "growing_zone", "session"];
+
+/// **What is it?**
+/// An SSR-only helper that totals the size and number of files below a directory.
+///
+/// **Why does it exist?**
+/// It exists to measure per-user image storage and the instance total for the admin panel.
+///
+/// **How should it be used?**
+/// Call through `tokio::task::spawn_blocking`; a missing directory counts as empty.
+#[cfg(feature = "ssr")]
+fn dir_usage(path: &std::path::Path) -> (u64, usize) {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return (0, 0);
+    };
+    entries.flatten().fold((0, 0), |(bytes, files), entry| {
+        match entry.metadata() {
+            Ok(meta) if meta.is_dir() => {
+                let (b, f) = dir_usage(&entry.path());
+                (bytes + b, files + f)
+            }
+            Ok(meta) => (bytes + meta.len(), files + 1),
+            Err(_) => (bytes, files),
+        }
+    })
+}
+
+/// **What is it?**
+/// An SSR-only helper that signs a user out of every session.
+///
+/// **Why does it exist?**
+/// It exists so disabling an account or resetting its password takes effect immediately rather than when sessions expire.
+///
+/// **How should it be used?**
+/// Call after changing the account, with the user's "table:key" ID.
+#[cfg(feature = "ssr")]
+async fn revoke_user_sessions(user_id: &str) -> Result<(), ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut response = db()
+        .query("DELETE session WHERE user_id = $uid OR string::contains(data, $session_marker)")
+        .bind(("uid", user_id.to_string()))
+        .bind(("session_marker", format!("\"user_id\":\"{}\"", user_id)))
+        .await
+        .map_err(|e| internal_error("Revoke user sessions failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke user sessions error", err_msg));
+    }
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that lists every account on the instance with usage details.
+///
+/// **Why does it exist?**
+/// It exists so the admin panel can show accounts, their status, and how much each one stores.
+///
+/// **How should it be used?**
+/// Call from the `/admin` page. Only instance admins may call it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn admin_list_users() -> Result<Vec<AdminUserSummary>, ServerFnError> {
+    use crate::auth::require_admin;
+
+    require_admin().await?;
+    list_users().await
+}
+
+/// **What is it?**
+/// The unauthenticated body of `admin_list_users`: every account with its row counts and image storage.
+///
+/// **Why does it exist?**
+/// It exists so `Command::Stats` can print the same per-user numbers as the admin panel from the shell.
+///
+/// **How should it be used?**
+/// Only call it after checking the caller is an instance admin, or from the CLI.
+#[cfg(feature = "ssr")]
+pub(crate) async fn list_users() -> Result<Vec<AdminUserSummary>, ServerFnError> {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use crate::config::config;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct UserRow {
+        id: surrealdb::types::RecordId,
+        username: String,
+        email: String,
+        #[surreal(default)]
+        is_admin: bool,
+        #[surreal(default)]
+        disabled: bool,
+        created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct OwnerCountRow {
+        owner: surrealdb::types::RecordId,
+        count: i64,
+    }
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct LastSeenRow {
+        user_id: String,
+        last_seen: i64,
+    }
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct JournalCountRow {
+        owner: surrealdb::types::RecordId,
+        count: i64,
+        photos: i64,
+    }
+
+    let mut resp = db()
+        .query(
+            "SELECT id, username, email, is_admin, disabled, created_at FROM user ORDER BY created_at ASC; \
+             SELECT owner, count() AS count FROM orchid GROUP BY owner; \
+             SELECT user_id, math::max(last_seen) AS last_seen FROM session WHERE user_id != NONE GROUP BY user_id; \
+             SELECT owner, count() AS count, count(image_filename != NONE) AS photos FROM log_entry GROUP BY owner; \
+             SELECT zone.owner AS owner, count() AS count FROM climate_reading GROUP BY owner;"
+        )
+        .await
+        .map_err(|e| internal_error("Admin users query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Admin users query error", err_msg));
+    }
+
+    let users: Vec<UserRow> = resp.take(0)
+        .map_err(|e| internal_error("Admin users parse failed", e))?;
+    let plant_counts: HashMap<String, usize> = resp.take::<Vec<OwnerCountRow>>(1).unwrap_or_default()
+        .into_iter()
+        .map(|r| (record_id_to_string(&r.owner), r.count.max(0) as usize))
+        .collect();
+    let last_seen: HashMap<String, i64> = resp.take::<Vec<LastSeenRow>>(2).unwrap_or_default()
+        .into_iter()
+        .map(|r| (r.user_id, r.last_seen))
+        .collect();
+    let journal_counts: HashMap<String, (usize, usize)> = resp.take::<Vec<JournalCountRow>>(3).unwrap_or_default()
+        .into_iter()
+        .map(|r| (record_id_to_string(&r.owner), (r.count.max(0) as usize, r.photos.max(0) as usize)))
+        .collect();
+    let reading_counts: HashMap<String, usize> = resp.take::<Vec<OwnerCountRow>>(4).unwrap_or_default()
+        .into_iter()
+        .map(|r| (record_id_to_string(&r.owner), r.count.max(0) as usize))
+        .collect();
+
+    let storage_root = PathBuf::from(&config().image_storage_path);
+    let mut summaries = Vec::with_capacity(users.len());
+    for user in users {
+        let id = record_id_to_string(&user.id);
+        let user_dir = storage_root.join(id.replace(':', "_"));
+        let (image_bytes, _) = tokio::task::spawn_blocking(move || dir_usage(&user_dir))
+            .await
+            .unwrap_or((0, 0));
+        let (journal_count, photo_count) = journal_counts.get(&id).copied().unwrap_or((0, 0));
+        summaries.push(AdminUserSummary {
+            plant_count: plant_counts.get(&id).copied().unwrap_or(0),
+            journal_count,
+            photo_count,
+            reading_count: reading_counts.get(&id).copied().unwrap_or(0),
+            last_seen_at: last_seen.get(&id).and_then(|t| chrono::DateTime::from_timestamp(*t, 0)),
+            id,
+            username: user.username,
+            email: user.email,
+            is_admin: user.is_admin,
+            disabled: user.disabled,
+            created_at: user.created_at,
+            image_bytes,
+        });
+    }
+    Ok(summaries)
+}
+
+/// **What is it?**
+/// A server function that reports instance-wide image storage and database row counts.
+///
+/// **Why does it exist?**
+/// It exists so admins can keep an eye on disk and database growth from the browser.
+///
+/// **How should it be used?**
+/// Call from the `/admin` page. Only instance admins may call it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn admin_storage_usage() -> Result<StorageUsage, ServerFnError> {
+    use crate::auth::require_admin;
+
+    require_admin().await?;
+    storage_usage().await
+}
+
+/// **What is it?**
+/// The unauthenticated body of `admin_storage_usage`: image storage, table row counts and, when known, the database's size on disk.
+///
+/// **Why does it exist?**
+/// It exists so `Command::Stats` reports the same instance totals as the admin panel.
+///
+/// **How should it be used?**
+/// Only call it after checking the caller is an instance admin, or from the CLI.
+#[cfg(feature = "ssr")]
+pub(crate) async fn storage_usage() -> Result<StorageUsage, ServerFnError> {
+    use std::path::PathBuf;
+    use crate::config::config;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let root = PathBuf::from(&config().image_storage_path);
+    let (image_bytes, image_files) = tokio::task::spawn_blocking(move || dir_usage(&root))
+        .await
+        .map_err(|e| internal_error("Image storage scan failed", e))?;
+
+    let query = COUNTED_TABLES.iter()
+        .map(|t| format!("SELECT VALUE count() FROM {} GROUP ALL;", t))
+        .collect::<String>();
+    let mut resp = db()
+        .query(query)
+        .await
+        .map_err(|e| internal_error("Table count query failed", e))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Table count query error", err_msg));
+    }
+
+    let mut table_rows = Vec::with_capacity(COUNTED_TABLES.len());
+    for (i, table) in COUNTED_TABLES.iter().enumerate() {
+        let counts: Vec<i64> = resp.take(i)
+            .map_err(|e| internal_error("Table count parse failed", e))?;
+        table_rows.push((table.to_string(), counts.first().copied().unwrap_or(0).max(0) as usize));
+    }
+    table_rows.sort_by(|a, b| b.1.cmp(&a.1));
+
+    // SurrealDB has no size query, so the data directory is measured when it's on this host
+    let database_bytes = if config().surreal_data_path.is_empty() {
+        None
+    } else {
+        let data = PathBuf::from(&config().surreal_data_path);
+        tokio::task::spawn_blocking(move || match std::fs::metadata(&data) {
+            Ok(meta) if meta.is_file() => Some(meta.len()),
+            Ok(_) => Some(dir_usage(&data).0),
+            Err(_) => None,
+        })
+        .await
+        .map_err(|e| internal_error("Database size scan failed", e))?
+    };
+
+    Ok(StorageUsage { image_bytes, image_files, table_rows, database_bytes })
+}
+
+/// **What is it?**
+/// A server function that sets a new password for any account.
+///
+/// **Why does it exist?**
+/// It exists so admins can help family members who are locked out when email reset isn't configured, without running `reset-password` on the CLI.
+///
+/// **How should it be used?**
+/// Call from the `/admin` page. The user is signed out everywhere and must sign in with the new password.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn admin_reset_password(
+    /// The user's record ID.
+    user_id: String,
+    /// The new password (8-128 characters).
+    new_password: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::{hash_password, require_admin};
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let admin_id = require_admin().await?;
+    if new_password.len() < 8 || new_password.len() > 128 {
+        return Err(ServerFnError::new("Password must be 8-128 characters"));
+    }
+    let user = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|_| ServerFnError::new("Invalid user"))?;
+    let password_hash = hash_password(&new_password)
+        .map_err(|e| internal_error("Password hashing failed", e))?;
+
+    let mut resp = db()
+        .query("UPDATE $user SET password_hash = $hash RETURN VALUE id")
+        .bind(("user", user))
+        .bind(("hash", password_hash))
+        .await
+        .map_err(|e| internal_error("Admin password reset failed", e))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Admin password reset error", err_msg));
+    }
+    let updated: Vec<surrealdb::types::RecordId> = resp.take(0)
+        .map_err(|e| internal_error("Admin password reset parse failed", e))?;
+    if updated.is_empty() {
+        return Err(ServerFnError::new("User not found"));
+    }
+
+    if user_id != admin_id {
+        revoke_user_sessions(&user_id).await?;
+    }
+    tracing::info!("Admin {} reset the password for {}", admin_id, user_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that disables or re-enables an account.
+///
+/// **Why does it exist?**
+/// It exists so admins can lock out a compromised or departed account without deleting its data.
+///
+/// **How should it be used?**
+/// Call from the `/admin` page. Disabling signs the user out everywhere and stops their API tokens; admins can't disable themselves.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn admin_set_user_disabled(
+    /// The user's record ID.
+    user_id: String,
+    /// `true` to block sign-in, `false` to allow it again.
+    disabled: bool,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_admin;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let admin_id = require_admin().await?;
+    if user_id == admin_id {
+        return Err(ServerFnError::new("You can't disable your own account"));
+    }
+    let user = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|_| ServerFnError::new("Invalid user"))?;
+
+    let mut resp = db()
+        .query("UPDATE $user SET disabled = $disabled RETURN VALUE id")
+        .bind(("user", user))
+        .bind(("disabled", disabled))
+        .await
+        .map_err(|e| internal_error("Admin disable user failed", e))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Admin disable user error", err_msg));
+    }
+    let updated: Vec<surrealdb::types::RecordId> = resp.take(0)
+        .map_err(|e| internal_error("Admin disable user parse failed", e))?;
+    if updated.is_empty() {
+        return Err(ServerFnError::new("User not found"));
+    }
+
+    if disabled {
+        revoke_user_sessions(&user_id).await?;
+    }
+    tracing::info!("Admin {} set disabled={} for {}", admin_id, disabled, user_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that grants or removes instance admin rights.
+///
+/// **Why does it exist?**
+/// It exists so an instance can have more than one admin, and admin rights can be handed over.
+///
+/// **How should it be used?**
+/// Call from the `/admin` page. Admins can't remove their own rights, so an instance always keeps at least one admin.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn admin_set_user_admin(
+    /// The user's record ID.
+    user_id: String,
+    /// Whether the user should be an admin.
+    is_admin: bool,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_admin;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let admin_id = require_admin().await?;
+    if user_id == admin_id && !is_admin {
+        return Err(ServerFnError::new("You can't remove your own admin rights"));
+    }
+    let user = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|_| ServerFnError::new("Invalid user"))?;
+
+    let mut resp = db()
+        .query("UPDATE $user SET is_admin = $is_admin RETURN VALUE id")
+        .bind(("user", user))
+        .bind(("is_admin", is_admin))
+        .await
+        .map_err(|e| internal_error("Admin set admin failed", e))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Admin set admin error", err_msg));
+    }
+    let updated: Vec<surrealdb::types::RecordId> = resp.take(0)
+        .map_err(|e| internal_error("Admin set admin parse failed", e))?;
+    if updated.is_empty() {
+        return Err(ServerFnError::new("User not found"));
+    }
+
+    tracing::info!("Admin {} set is_admin={} for {}", admin_id, is_admin, user_id);
+    Ok(())
+}
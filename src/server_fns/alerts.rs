@@ -100,23 +100,24 @@ pub async fn unsubscribe_push() -> Result<(), ServerFnError> {
 }
 
 /// **What is it?**
-/// A server function that retrieves a list of active, unacknowledged alerts for the currently authenticated user.
+/// A server function that retrieves a list of active alerts for the currently authenticated user: not acknowledged, not resolved, and not snoozed.
 ///
 /// **Why does it exist?**
 /// It exists to keep the user informed about urgent issues requiring their attention, such as critical temperature drops or watering reminders.
 ///
 /// **How should it be used?**
-/// Query this function from an application-wide notification panel or polling loop to populate the user's current alerts view.
+/// Query this function from an application-wide notification panel or polling loop to populate the user's current alerts view. Zone alerts carry `zone_id` so the climate strip can show them next to the zone.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_active_alerts() -> Result<Vec<Alert>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
     use surrealdb::types::SurrealValue;
 
-    let user_id = require_auth().await?;
-    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
         .map_err(|e| internal_error("Owner ID parse failed", e))?;
 
     #[derive(serde::Deserialize, SurrealValue)]
@@ -127,11 +128,22 @@ pub async fn get_active_alerts() -> Result<Vec<Alert>, ServerFnError> {
         severity: String,
         message: String,
         created_at: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        zone: Option<surrealdb::types::RecordId>,
+        #[surreal(default)]
+        zone_name: Option<String>,
+        #[surreal(default)]
+        orchid_name: Option<String>,
+        #[surreal(default)]
+        snoozed_until: Option<chrono::DateTime<chrono::Utc>>,
     }
 
     let mut response = db()
         .query(
-            "SELECT id, alert_type, severity, message, created_at FROM alert WHERE owner = $owner AND acknowledged_at IS NULL ORDER BY created_at DESC LIMIT 20"
+            "SELECT id, alert_type, severity, message, created_at, zone, zone.name AS zone_name, orchid.name AS orchid_name, snoozed_until \
+             FROM alert WHERE owner = $owner AND acknowledged_at IS NULL AND resolved_at IS NONE \
+                AND (snoozed_until IS NONE OR snoozed_until <= time::now()) \
+             ORDER BY created_at DESC LIMIT 20"
         )
         .bind(("owner", owner))
         .await
@@ -149,12 +161,16 @@ pub async fn get_active_alerts() -> Result<Vec<Alert>, ServerFnError> {
     Ok(rows.into_iter().map(|r| {
         Alert {
             id: crate::server_fns::auth::record_id_to_string(&r.id),
-            orchid_name: None,
-            zone_name: None,
+            orchid_name: r.orchid_name,
+            zone_name: r.zone_name,
             alert_type: r.alert_type,
             severity: r.severity,
             message: r.message,
             created_at: r.created_at,
+            zone_id: r.zone.as_ref().map(crate::server_fns::auth::record_id_to_string),
+            acknowledged_at: None,
+            snoozed_until: r.snoozed_until,
+            resolved_at: None,
         }
     }).collect())
 }
@@ -226,7 +242,7 @@ pub async fn send_test_push() -> Result<String, ServerFnError> {
 
     let mut resp = db()
         .query("SELECT endpoint, p256dh, auth FROM push_subscription WHERE owner = $owner")
-        .bind(("owner", owner))
+        .bind(("owner", owner.clone()))
         .await
         .map_err(|e| internal_error("Query push subs failed", e))?;
 
@@ -253,7 +269,8 @@ pub async fn send_test_push() -> Result<String, ServerFnError> {
             p256dh: sub.p256dh.clone(),
             auth: sub.auth.clone(),
         };
-        match crate::push::send_push(
+        match crate::push::deliver_push(
+            &owner,
             &push_sub,
             "Test Notification",
             "Push notifications are working! You'll receive alerts for watering and climate conditions.",
@@ -272,6 +289,403 @@ pub async fn send_test_push() -> Result<String, ServerFnError> {
     }
 }
 
+/// **What is it?**
+/// An SSR-only helper that loads and decrypts the Discord or Slack webhook an owner's alerts are posted to.
+///
+/// **Why does it exist?**
+/// It exists so the alert job and the settings test button read the stored URL the same way.
+///
+/// **How should it be used?**
+/// Call with the household owner's `RecordId`; `None` means no webhook is set (or it can't be read).
+#[cfg(feature = "ssr")]
+pub(crate) async fn alert_webhook_for_owner(owner: &surrealdb::types::RecordId) -> Option<String> {
+    use crate::db::db;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        alert_webhook_url: Option<String>,
+    }
+
+    let mut resp = db()
+        .query("SELECT alert_webhook_url FROM user_preference WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner.clone()))
+        .await
+        .ok()?;
+    let _ = resp.take_errors();
+    let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
+    let encrypted = row?.alert_webhook_url?;
+    match crate::crypto::decrypt(&encrypted) {
+        Ok(url) => Some(url),
+        Err(e) => {
+            tracing::warn!("Alert webhook could not be decrypted: {}", e);
+            None
+        }
+    }
+}
+
+/// **What is it?**
+/// A server function that reports which Discord or Slack webhook the household's alerts are posted to.
+///
+/// **Why does it exist?**
+/// It exists so the notification settings can show that a webhook is set without ever sending its secret URL back to the browser.
+///
+/// **How should it be used?**
+/// Call when rendering the notification settings. `None` means no webhook is set.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_alert_webhook() -> Result<Option<crate::webhook::WebhookStatus>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+    use crate::webhook::WebhookStatus;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    Ok(alert_webhook_for_owner(&owner).await.and_then(|url| WebhookStatus::for_url(&url)))
+}
+
+/// **What is it?**
+/// A server function that sets or clears the Discord or Slack webhook the household's alerts are posted to.
+///
+/// **Why does it exist?**
+/// It exists so climate and overdue-watering alerts can land in a channel the whole household already watches.
+///
+/// **How should it be used?**
+/// Call from the notification settings with the pasted webhook URL, or an empty string to stop posting. Only the owner can change it; the URL is encrypted at rest.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_alert_webhook(
+    /// The webhook URL from Discord's or Slack's channel settings, or empty to clear it.
+    url: String
+) -> Result<Option<crate::webhook::WebhookStatus>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::crypto::encrypt;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+    use crate::webhook::{WebhookKind, WebhookStatus};
+
+    let url = url.trim().to_string();
+    let (stored, status) = if url.is_empty() {
+        (None, None)
+    } else {
+        WebhookKind::detect(&url).map_err(ServerFnError::new)?;
+        let encrypted = encrypt(&url).map_err(|e| internal_error("Webhook encrypt failed", e))?;
+        (Some(encrypted), WebhookStatus::for_url(&url))
+    };
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET alert_webhook_url = $url WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("url", stored.clone()))
+        .await
+        .map_err(|e| internal_error("Save alert webhook query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save alert webhook query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, alert_webhook_url = $url")
+            .bind(("owner", owner))
+            .bind(("url", stored))
+            .await
+            .map_err(|e| internal_error("Create alert webhook preference query failed", e))?;
+    }
+
+    Ok(status)
+}
+
+/// **What is it?**
+/// A server function that posts a test message to the household's alert webhook.
+///
+/// **Why does it exist?**
+/// It exists so the owner can confirm the channel receives messages before a real alert depends on it.
+///
+/// **How should it be used?**
+/// Call from the "Send Test" button next to the webhook in the notification settings.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn send_test_webhook() -> Result<String, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let Some(url) = alert_webhook_for_owner(&owner).await else {
+        return Err(ServerFnError::new("No webhook is set"));
+    };
+    crate::push::deliver_webhook(
+        &owner,
+        &url,
+        "Test Notification",
+        "Velamen can post here. Climate and overdue-watering alerts for this collection will show up in this channel.",
+    ).await.map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok("Test message posted".into())
+}
+
+/// **What is it?**
+/// A server function that returns the household's escalation settings for overdue care.
+///
+/// **Why does it exist?**
+/// It exists so the notification settings can show the escalation period and whether a second channel is set, without sending that channel's URL back to the browser.
+///
+/// **How should it be used?**
+/// Call when rendering the notification settings. An `after_days` of 0 means escalation is off.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_escalation_settings() -> Result<crate::orchid::EscalationSettings, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::orchid::EscalationSettings;
+    use crate::server_fns::climate::parse_owner;
+    use crate::webhook::WebhookStatus;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        escalation_after_days: Option<i64>,
+        #[surreal(default)]
+        escalation_webhook_url: Option<String>,
+    }
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT escalation_after_days, escalation_webhook_url FROM user_preference WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Escalation settings query failed", e))?;
+    let _ = resp.take_errors();
+    let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
+    let Some(row) = row else {
+        return Ok(EscalationSettings::default());
+    };
+
+    Ok(EscalationSettings {
+        after_days: row.escalation_after_days.unwrap_or(0).clamp(0, crate::orchid::MAX_ESCALATION_DAYS as i64) as u32,
+        webhook: row.escalation_webhook_url
+            .and_then(|u| crate::crypto::decrypt(&u).ok())
+            .and_then(|url| WebhookStatus::for_url(&url)),
+    })
+}
+
+/// **What is it?**
+/// A server function that sets how long overdue care may go unhandled before its alert escalates, and where escalations also go.
+///
+/// **Why does it exist?**
+/// It exists so a plant that stays overdue gets louder after the first ping instead of going quiet, and can reach a channel that gets more attention.
+///
+/// **How should it be used?**
+/// Call from the notification settings. `after_days` of 0 turns escalation off. `webhook_url` of `None` keeps the current second channel; an empty string clears it. Only the owner can change these; the URL is encrypted at rest.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_escalation_settings(
+    /// Days an overdue-care alert may stay open before each step up in severity, 0 to turn escalation off.
+    after_days: u32,
+    /// A Discord or Slack webhook URL for escalations, empty to clear it, or omitted to keep it.
+    webhook_url: Option<String>,
+) -> Result<crate::orchid::EscalationSettings, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::crypto::encrypt;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::orchid::{EscalationSettings, MAX_ESCALATION_DAYS};
+    use crate::server_fns::climate::parse_owner;
+    use crate::webhook::{WebhookKind, WebhookStatus};
+
+    if after_days > MAX_ESCALATION_DAYS {
+        return Err(ServerFnError::new(format!("Escalation can wait at most {} days", MAX_ESCALATION_DAYS)));
+    }
+    let webhook = match webhook_url.as_deref().map(str::trim) {
+        None => None,
+        Some("") => Some((None, None)),
+        Some(url) => {
+            WebhookKind::detect(url).map_err(ServerFnError::new)?;
+            let encrypted = encrypt(url).map_err(|e| internal_error("Webhook encrypt failed", e))?;
+            Some((Some(encrypted), WebhookStatus::for_url(url)))
+        }
+    };
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let set_webhook = if webhook.is_some() { ", escalation_webhook_url = $url" } else { "" };
+    let (stored, status) = webhook.unzip();
+    let mut resp = db()
+        .query(format!("UPDATE user_preference SET escalation_after_days = $days{} WHERE owner = $owner", set_webhook))
+        .bind(("owner", owner.clone()))
+        .bind(("days", after_days as i64))
+        .bind(("url", stored.clone().flatten()))
+        .await
+        .map_err(|e| internal_error("Save escalation settings query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save escalation settings query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query(format!("CREATE user_preference SET owner = $owner, escalation_after_days = $days{}", set_webhook))
+            .bind(("owner", owner))
+            .bind(("days", after_days as i64))
+            .bind(("url", stored.flatten()))
+            .await
+            .map_err(|e| internal_error("Create escalation preference query failed", e))?;
+        return Ok(EscalationSettings { after_days, webhook: status.flatten() });
+    }
+
+    let current = match status {
+        Some(status) => status,
+        None => get_escalation_settings().await?.webhook,
+    };
+    Ok(EscalationSettings { after_days, webhook: current })
+}
+
+/// **What is it?**
+/// A server function that returns the current user's ntfy server and topic.
+///
+/// **Why does it exist?**
+/// It exists so the notification settings can show where notifications are published without sending the access token back to the browser.
+///
+/// **How should it be used?**
+/// Call when rendering the notification settings. `None` means ntfy isn't set up.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_ntfy_settings() -> Result<Option<crate::ntfy::NtfyStatus>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::server_fns::climate::parse_owner;
+
+    let user_id = require_auth().await?;
+    let user = parse_owner(&user_id)?;
+    Ok(crate::push::ntfy_targets("owner = $owner", &user).await.first().map(|(_, t)| t.status()))
+}
+
+/// **What is it?**
+/// A server function that sets the current user's ntfy server, topic and access token, or turns ntfy off.
+///
+/// **Why does it exist?**
+/// It exists for self-hosters who prefer ntfy to VAPID Web Push: alerts, reminders and reports are published to the topic as well as pushed.
+///
+/// **How should it be used?**
+/// Call from the notification settings. A blank server means ntfy.sh and a blank topic turns ntfy off. Pass `token` as `None` to keep the stored one, or an empty string to remove it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_ntfy_settings(
+    /// The ntfy server's base URL, or empty for ntfy.sh.
+    server: String,
+    /// The topic to publish to, or empty to turn ntfy off.
+    topic: String,
+    /// A new access token, empty to remove it, or `None` to keep the stored one.
+    token: Option<String>,
+) -> Result<Option<crate::ntfy::NtfyStatus>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::crypto::encrypt;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::ntfy::NtfyTarget;
+    use crate::server_fns::climate::parse_owner;
+
+    let user_id = require_auth().await?;
+    let user = parse_owner(&user_id)?;
+
+    let (query, target) = if topic.trim().is_empty() {
+        ("UPDATE user_preference SET ntfy_server = NONE, ntfy_topic = NONE, ntfy_token = NONE WHERE owner = $owner", None)
+    } else {
+        let target = NtfyTarget::new(&server, &topic, token.as_deref()).map_err(ServerFnError::new)?;
+        let query = match token {
+            None => "UPDATE user_preference SET ntfy_server = $server, ntfy_topic = $topic WHERE owner = $owner",
+            Some(_) => "UPDATE user_preference SET ntfy_server = $server, ntfy_topic = $topic, ntfy_token = $token WHERE owner = $owner",
+        };
+        (query, Some(target))
+    };
+    let encrypted_token = match target.as_ref().and_then(|t| t.token.as_deref()) {
+        Some(t) => Some(encrypt(t).map_err(|e| internal_error("ntfy token encrypt failed", e))?),
+        None => None,
+    };
+
+    let mut resp = db()
+        .query(query)
+        .bind(("owner", user.clone()))
+        .bind(("server", target.as_ref().map(|t| t.server.clone())))
+        .bind(("topic", target.as_ref().map(|t| t.topic.clone())))
+        .bind(("token", encrypted_token.clone()))
+        .await
+        .map_err(|e| internal_error("Save ntfy settings query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save ntfy settings query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() && target.is_some() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, ntfy_server = $server, ntfy_topic = $topic, ntfy_token = $token")
+            .bind(("owner", user.clone()))
+            .bind(("server", target.as_ref().map(|t| t.server.clone())))
+            .bind(("topic", target.as_ref().map(|t| t.topic.clone())))
+            .bind(("token", encrypted_token))
+            .await
+            .map_err(|e| internal_error("Create ntfy preference query failed", e))?;
+    }
+
+    Ok(crate::push::ntfy_targets("owner = $owner", &user).await.first().map(|(_, t)| t.status()))
+}
+
+/// **What is it?**
+/// A server function that publishes a test notification to the current user's ntfy topic.
+///
+/// **Why does it exist?**
+/// It exists so a self-hoster can confirm the server, topic and token work before relying on them.
+///
+/// **How should it be used?**
+/// Call from the "Send Test" button next to the ntfy settings.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn send_test_ntfy() -> Result<String, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::server_fns::climate::parse_owner;
+
+    let user_id = require_auth().await?;
+    let user = parse_owner(&user_id)?;
+
+    let Some((_, target)) = crate::push::ntfy_targets("owner = $owner", &user).await.into_iter().next() else {
+        return Err(ServerFnError::new("ntfy isn't set up"));
+    };
+    let (title, body) = ("Test Notification", "ntfy is working! You'll receive alerts for watering and climate conditions.");
+    let result = crate::push::send_ntfy(&target, title, body).await;
+    crate::push::log_delivery(&user, crate::notification_log::NotificationChannel::Ntfy, title, body, &result).await;
+    result.map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(format!("Published to {}/{}", target.server, target.topic))
+}
+
 /// **What is it?**
 /// A server function that marks a specific alert as acknowledged by the current user.
 ///
@@ -279,19 +693,20 @@ pub async fn send_test_push() -> Result<String, ServerFnError> {
 /// It exists to clear an alert from the active list, indicating that the user has seen and resolved or accepted the notification.
 ///
 /// **How should it be used?**
-/// Call this function when the user clicks the "Acknowledge" or "X" button on an active alert component.
+/// Call this function when the user clicks the "Acknowledge" or "X" button on an active alert component. An acknowledged temperature, humidity, condensation or watering alert stays quiet until its condition clears, or until it gets worse.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn acknowledge_alert(
     /// The unique identifier of the alert to acknowledge.
     alert_id: String
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
         .map_err(|e| internal_error("Owner ID parse failed", e))?;
     let aid = surrealdb::types::RecordId::parse_simple(&alert_id)
         .map_err(|e| internal_error("Alert ID parse failed", e))?;
@@ -305,3 +720,209 @@ pub async fn acknowledge_alert(
 
     Ok(())
 }
+
+/// **What is it?**
+/// A server function that hides an alert, and holds back repeat notifications for it, for a number of hours.
+///
+/// **Why does it exist?**
+/// It exists for warnings the grower is already handling ("heater is on its way") but wants to hear about again if they persist.
+///
+/// **How should it be used?**
+/// Call from the snooze buttons in the climate strip with one of `ALERT_SNOOZE_HOURS`; values are clamped to 1 hour through `MAX_ALERT_SNOOZE_HOURS`. The alert reappears when the snooze ends unless it has resolved.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn snooze_alert(
+    /// The unique identifier of the alert to snooze.
+    alert_id: String,
+    /// How long to snooze, in hours.
+    hours: u32,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::orchid::MAX_ALERT_SNOOZE_HOURS;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+    let aid = surrealdb::types::RecordId::parse_simple(&alert_id)
+        .map_err(|e| internal_error("Alert ID parse failed", e))?;
+    let until = chrono::Utc::now() + chrono::Duration::hours(hours.clamp(1, MAX_ALERT_SNOOZE_HOURS) as i64);
+
+    let mut resp = db()
+        .query("UPDATE $id SET snoozed_until = $until WHERE owner = $owner")
+        .bind(("id", aid))
+        .bind(("until", until))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Snooze alert query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Snooze alert query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that lists the notifications recently sent to the current user, newest first, with whether each was delivered.
+///
+/// **Why does it exist?**
+/// It exists so a grower who didn't see an alert can tell whether it was never sent or sent and missed, and which channel failed.
+///
+/// **How should it be used?**
+/// Call from the notification history in settings. At most `NOTIFICATION_HISTORY_LIMIT` entries are returned; webhook posts are logged for the household owner.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_notification_history() -> Result<Vec<crate::notification_log::NotificationRecord>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::notification_log::{NotificationChannel, NotificationRecord, NOTIFICATION_HISTORY_LIMIT};
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::server_fns::climate::parse_owner;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct LogRow {
+        id: surrealdb::types::RecordId,
+        channel: String,
+        title: String,
+        body: String,
+        delivered: bool,
+        #[surreal(default)]
+        error: Option<String>,
+        created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    let user_id = require_auth().await?;
+    let user = parse_owner(&user_id)?;
+
+    let mut resp = db()
+        .query("SELECT id, channel, title, body, delivered, error, created_at FROM notification_log WHERE owner = $owner ORDER BY created_at DESC LIMIT $limit")
+        .bind(("owner", user))
+        .bind(("limit", NOTIFICATION_HISTORY_LIMIT as i64))
+        .await
+        .map_err(|e| internal_error("Notification history query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Notification history query error", err_msg));
+    }
+
+    let rows: Vec<LogRow> = resp.take(0)
+        .map_err(|e| internal_error("Notification history parse failed", e))?;
+    Ok(rows.into_iter().map(|r| NotificationRecord {
+        id: record_id_to_string(&r.id),
+        channel: NotificationChannel::from_str_lossy(&r.channel),
+        title: r.title,
+        body: r.body,
+        delivered: r.delivered,
+        error: r.error,
+        created_at: r.created_at,
+    }).collect())
+}
+
+/// **What is it?**
+/// A background task that deletes notification log entries older than `NOTIFICATION_LOG_RETENTION_DAYS`.
+///
+/// **Why does it exist?**
+/// It exists because every alert, reminder and report adds a row per recipient and channel; the history only needs to cover recent months.
+///
+/// **How should it be used?**
+/// Run it daily from the background loop.
+#[cfg(feature = "ssr")]
+pub async fn prune_notification_log() {
+    use crate::db::db;
+    use crate::notification_log::NOTIFICATION_LOG_RETENTION_DAYS;
+
+    if let Err(e) = db()
+        .query("DELETE FROM notification_log WHERE created_at < time::now() - duration::from_days($days)")
+        .bind(("days", NOTIFICATION_LOG_RETENTION_DAYS))
+        .await
+    {
+        tracing::warn!("Notification log: failed to prune: {}", e);
+    }
+}
+
+/// **What is it?**
+/// Axum handlers for the buttons on push notifications.
+///
+/// **Why does it exist?**
+/// It exists because the service worker handles a tapped notification action with no app page open, so it can't call a Leptos server function; it posts to a plain route with the session cookie instead.
+///
+/// **How should it be used?**
+/// Merge `push_action_router()` into the app router in `main.rs`. `public/sw.js` posts to the endpoint named in each action of the push payload (see `push_action::payload`).
+#[cfg(feature = "ssr")]
+pub mod handlers {
+    use axum::{extract::DefaultBodyLimit, http::StatusCode, response::Json};
+    use serde_json::json;
+
+    /// Largest accepted body; an action request is one record ID.
+    const MAX_BODY_BYTES: usize = 1024;
+
+    /// Body of a "Mark watered" tap.
+    #[derive(serde::Deserialize)]
+    pub struct MarkWateredRequest {
+        /// The plant the notification was about.
+        orchid_id: String,
+    }
+
+    /// Returns the router for the push notification action endpoints.
+    pub fn push_action_router() -> axum::Router<leptos::prelude::LeptosOptions> {
+        axum::Router::new()
+            .route(crate::push_action::MARK_WATERED_PATH, axum::routing::post(mark_watered_from_push))
+            .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
+    }
+
+    /// Logs a watering for the plant in a "Mark watered" notification, exactly like "Water Now".
+    ///
+    /// Needs a signed-in session with caretaker access to the plant's collection: no session returns
+    /// 401, a viewer 403, and a plant outside the collection 404. Replies with a confirmation
+    /// message for the service worker to show.
+    pub async fn mark_watered_from_push(
+        session: tower_sessions::Session,
+        Json(request): Json<MarkWateredRequest>,
+    ) -> Result<Json<serde_json::Value>, StatusCode> {
+        use crate::auth::collection_access;
+        use crate::household::HouseholdRole;
+
+        let user_id: String = session.get("user_id").await
+            .map_err(|e| {
+                tracing::error!("Session read error: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+        let access = collection_access(&user_id).await.map_err(|e| {
+            tracing::error!("Push action collection lookup failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        if !access.role.allows(HouseholdRole::Caretaker) {
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        let orchid = surrealdb::types::RecordId::parse_simple(&request.orchid_id).map_err(|_| StatusCode::NOT_FOUND)?;
+        let owner = surrealdb::types::RecordId::parse_simple(&access.owner_id).map_err(|e| {
+            tracing::error!("Push action owner ID parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+        let watered = crate::server_fns::orchids::record_watering(owner, orchid).await
+            .map_err(|e| {
+                tracing::error!("Mark watered from push failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?
+            .ok_or(StatusCode::NOT_FOUND)?;
+        tracing::info!(orchid_id = %request.orchid_id, user_id = %user_id, "Watering logged from push notification");
+
+        Ok(Json(json!({
+            "orchid_id": request.orchid_id,
+            "message": format!("{} is logged as watered.", watered.name),
+        })))
+    }
+}
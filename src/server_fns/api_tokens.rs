@@ -0,0 +1,198 @@
+use leptos::prelude::*;
+use crate::api_tokens::{ApiScope, ApiTokenInfo, NewApiToken};
+
+#[cfg(feature = "ssr")]
+use surrealdb::types::SurrealValue;
+
+#[cfg(feature = "ssr")]
+#[derive(serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct ApiTokenDbRow {
+    id: surrealdb::types::RecordId,
+    name: String,
+    prefix: String,
+    scopes: Vec<String>,
+    created_at: chrono::DateTime<chrono::Utc>,
+    #[surreal(default)]
+    last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[cfg(feature = "ssr")]
+impl ApiTokenDbRow {
+    fn into_info(self) -> ApiTokenInfo {
+        ApiTokenInfo {
+            id: crate::server_fns::auth::record_id_to_string(&self.id),
+            name: self.name,
+            prefix: self.prefix,
+            // Unknown keys are dropped so a retired scope can't be shown as granted
+            scopes: self.scopes.iter().filter_map(|s| ApiScope::from_key(s)).collect(),
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+}
+
+/// **What is it?**
+/// A server function that lists the signed-in user's personal API tokens.
+///
+/// **Why does it exist?**
+/// It exists so the settings panel can show each token's name, scopes, and last use without ever returning the secret.
+///
+/// **How should it be used?**
+/// Call from the API token settings panel; pass a token's `id` to `revoke_api_token` to revoke it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn list_api_tokens() -> Result<Vec<ApiTokenInfo>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    let mut response = db()
+        .query("SELECT id, name, prefix, scopes, created_at, last_used_at FROM api_token WHERE owner = $owner ORDER BY created_at ASC")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("List API tokens query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("List API tokens query error", err_msg));
+    }
+
+    let rows: Vec<ApiTokenDbRow> = response.take(0)
+        .map_err(|e| internal_error("List API tokens parse failed", e))?;
+    Ok(rows.into_iter().map(|r| r.into_info()).collect())
+}
+
+/// **What is it?**
+/// A server function that creates a named, scoped personal API token for the signed-in user.
+///
+/// **Why does it exist?**
+/// It exists so scripts and integrations (e.g. a greenhouse camera uploading photos) can call the API without a browser session.
+///
+/// **How should it be used?**
+/// Call from the API token settings panel. The returned `token` is shown once; only its SHA-256 hash is stored, so it cannot be recovered later. Clients send it as `Authorization: Bearer <token>`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_api_token(
+    /// A label for the token (e.g., "Greenhouse camera").
+    name: String,
+    /// What the token may be used for.
+    scopes: Vec<ApiScope>,
+) -> Result<NewApiToken, ServerFnError> {
+    use crate::api_tokens::{display_prefix, MAX_TOKENS, TOKEN_PREFIX};
+    use crate::auth::require_auth;
+    use crate::crypto::{generate_token, hash_token};
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ServerFnError::new("Give the token a name"));
+    }
+    let name: String = name.chars().take(50).collect();
+
+    let mut scope_keys: Vec<String> = Vec::new();
+    for scope in ApiScope::ALL {
+        if scopes.contains(&scope) {
+            scope_keys.push(scope.as_str().to_string());
+        }
+    }
+    if scope_keys.is_empty() {
+        return Err(ServerFnError::new("Choose at least one scope"));
+    }
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    let mut count_resp = db()
+        .query("SELECT VALUE id FROM api_token WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("API token count failed", e))?;
+    let errors = count_resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("API token count query error", err_msg));
+    }
+    let existing: Vec<surrealdb::types::RecordId> = count_resp.take(0)
+        .map_err(|e| internal_error("API token count parse failed", e))?;
+    if existing.len() >= MAX_TOKENS {
+        return Err(ServerFnError::new(format!("You can have at most {} API tokens", MAX_TOKENS)));
+    }
+
+    let (secret, _) = generate_token();
+    let token = format!("{}{}", TOKEN_PREFIX, secret);
+
+    let mut response = db()
+        .query(
+            "CREATE api_token SET owner = $owner, name = $name, token_hash = $hash, \
+             prefix = $prefix, scopes = $scopes \
+             RETURN id, name, prefix, scopes, created_at, last_used_at"
+        )
+        .bind(("owner", owner))
+        .bind(("name", name))
+        .bind(("hash", hash_token(&token)))
+        .bind(("prefix", display_prefix(&token)))
+        .bind(("scopes", scope_keys))
+        .await
+        .map_err(|e| internal_error("Create API token query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create API token query error", err_msg));
+    }
+
+    let row: Option<ApiTokenDbRow> = response.take(0)
+        .map_err(|e| internal_error("Create API token parse failed", e))?;
+    let info = row.ok_or_else(|| internal_error("Create API token failed", "no row returned"))?
+        .into_info();
+
+    Ok(NewApiToken { info, token })
+}
+
+/// **What is it?**
+/// A server function that revokes one of the signed-in user's personal API tokens.
+///
+/// **Why does it exist?**
+/// It exists so a leaked or unused token stops working immediately.
+///
+/// **How should it be used?**
+/// Call with an `ApiTokenInfo::id` from `list_api_tokens`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn revoke_api_token(
+    /// The token record ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+    let token_id = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("API token ID parse failed", e))?;
+
+    let mut response = db()
+        .query("DELETE $id WHERE owner = $owner")
+        .bind(("id", token_id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Revoke API token query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke API token query error", err_msg));
+    }
+
+    Ok(())
+}
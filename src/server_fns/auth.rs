@@ -20,6 +20,9 @@ pub struct UserInfo {
     pub username: String,
     /// The user's email address.
     pub email: String,
+    /// Whether the user can manage this instance from `/admin`.
+    #[serde(default)]
+    pub is_admin: bool,
 }
 
 /// **What is it?**
@@ -59,6 +62,9 @@ pub struct UserDbRow {
     pub username: String,
     /// The user's email address.
     pub email: String,
+    /// Whether the user is an instance admin.
+    #[surreal(default)]
+    pub is_admin: bool,
 }
 
 #[cfg(feature = "ssr")]
@@ -69,6 +75,7 @@ impl UserDbRow {
             id: record_id_to_string(&self.id),
             username: self.username,
             email: self.email,
+            is_admin: self.is_admin,
         }
     }
 }
@@ -114,11 +121,25 @@ pub async fn register(
     let password_hash = hash_password(&password)
         .map_err(|e| internal_error("Password hashing failed", e))?;
 
+    // The first account on a fresh instance administers it
+    let mut existing = db()
+        .query("SELECT VALUE id FROM user LIMIT 1")
+        .await
+        .map_err(|e| internal_error("Registration user count failed", e))?;
+    let errors = existing.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Registration user count error", err_msg));
+    }
+    let is_first: Vec<surrealdb::types::RecordId> = existing.take(0)
+        .map_err(|e| internal_error("Registration user count parse failed", e))?;
+
     let mut response = db()
-        .query("CREATE user SET username = $username, email = $email, password_hash = $hash RETURN id, username, email")
+        .query("CREATE user SET username = $username, email = $email, password_hash = $hash, is_admin = $is_admin RETURN id, username, email, is_admin")
         .bind(("username", username))
         .bind(("email", email))
         .bind(("hash", password_hash))
+        .bind(("is_admin", is_first.is_empty()))
         .await
         .map_err(|e| internal_error("Registration query failed", e))?;
 
@@ -138,6 +159,9 @@ pub async fn register(
     Ok(user)
 }
 
+/// Shown when a disabled account tries to sign in.
+pub const ACCOUNT_DISABLED_MSG: &str = "This account has been disabled. Please contact the administrator.";
+
 /// **What is it?**
 /// A server function that authenticates an existing user and establishes an active HTTP session.
 ///
@@ -172,10 +196,14 @@ pub async fn login(
         username: String,
         email: String,
         password_hash: String,
+        #[surreal(default)]
+        is_admin: bool,
+        #[surreal(default)]
+        disabled: bool,
     }
 
     let mut response = db()
-        .query("SELECT id, username, email, password_hash FROM user WHERE username = $username LIMIT 1")
+        .query("SELECT id, username, email, password_hash, is_admin, disabled FROM user WHERE username = $username LIMIT 1")
         .bind(("username", username))
         .await
         .map_err(|e| internal_error("Login query failed", e))?;
@@ -196,6 +224,10 @@ pub async fn login(
     {
         return Err(ServerFnError::new("Invalid credentials"));
     }
+    // Checked after the password so disabled usernames can't be probed
+    if user_row.disabled {
+        return Err(ServerFnError::new(ACCOUNT_DISABLED_MSG));
+    }
 
     let user_id = record_id_to_string(&user_row.id);
     crate::auth::create_session(&user_id).await?;
@@ -204,6 +236,7 @@ pub async fn login(
         id: user_id,
         username: user_row.username,
         email: user_row.email,
+        is_admin: user_row.is_admin,
     })
 }
 
@@ -235,6 +268,186 @@ pub async fn get_current_user() -> Result<Option<UserInfo>, ServerFnError> {
     crate::auth::get_session_user().await
 }
 
+/// **What is it?**
+/// A downloadable archive of everything stored about the current user.
+///
+/// **Why does it exist?**
+/// It exists to carry the zip produced by `export_my_data` across the server function boundary, which only speaks JSON.
+///
+/// **How should it be used?**
+/// Offer `zip_base64` to the browser as a `data:application/zip;base64,` link named `filename`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DataExport {
+    /// Suggested download name, e.g. `velamen-export-alice-2024-05-01.zip`.
+    pub filename: String,
+    /// The zip archive, base64-encoded.
+    pub zip_base64: String,
+}
+
+/// One JSON file in the data export: file name and the query that fills it.
+/// Secrets (password hash, device credentials, passkey keys, push keys) are left out.
+#[cfg(feature = "ssr")]
+const EXPORT_QUERIES: &[(&str, &str)] = &[
+    ("account.json", "SELECT * OMIT password_hash, webauthn_id FROM user WHERE id = $uid"),
+    ("preferences.json", "SELECT * FROM user_preference WHERE owner = $uid"),
+    ("orchids.json", "SELECT * FROM orchid WHERE owner = $uid"),
+    ("orchid_revisions.json", "SELECT * FROM orchid_revision WHERE owner = $uid ORDER BY changed_at ASC"),
+    ("journal.json", "SELECT * FROM log_entry WHERE owner = $uid ORDER BY timestamp ASC"),
+    ("propagations.json", "SELECT * FROM propagation WHERE owner = $uid ORDER BY propagated_at ASC"),
+    ("vendors.json", "SELECT * FROM vendor WHERE owner = $uid"),
+    ("awards.json", "SELECT * FROM award WHERE owner = $uid ORDER BY awarded_at ASC"),
+    ("treatments.json", "SELECT * FROM treatment WHERE owner = $uid ORDER BY started_at ASC"),
+    ("event_types.json", "SELECT * FROM event_type WHERE owner = $uid"),
+    ("note_templates.json", "SELECT * FROM note_template WHERE owner = $uid"),
+    ("reminders.json", "SELECT * FROM reminder WHERE owner = $uid"),
+    ("species_aliases.json", "SELECT * FROM species_alias WHERE owner = $uid"),
+    ("species_photos.json", "SELECT * FROM species_photo WHERE owner = $uid"),
+    ("zones.json", "SELECT * OMIT data_source_config FROM growing_zone WHERE owner = $uid"),
+    ("zone_goals.json", "SELECT * FROM zone_goal WHERE owner = $uid"),
+    ("zone_energy.json", "SELECT * FROM zone_energy WHERE owner = $uid"),
+    ("water_sources.json", "SELECT * FROM water_source WHERE owner = $uid"),
+    ("water_readings.json", "SELECT * FROM water_reading WHERE owner = $uid ORDER BY measured_at ASC"),
+    ("climate_readings.json", "SELECT * FROM climate_reading WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $uid) ORDER BY recorded_at ASC"),
+    ("zone_dli.json", "SELECT * FROM zone_dli WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $uid) ORDER BY date ASC"),
+    ("climate_summaries.json", "SELECT * FROM climate_reading_summary WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $uid) ORDER BY period_start ASC"),
+    ("alerts.json", "SELECT * FROM alert WHERE owner = $uid"),
+    ("devices.json", "SELECT * OMIT config FROM hardware_device WHERE owner = $uid"),
+    ("passkeys.json", "SELECT name, created_at, last_used_at FROM passkey WHERE owner = $uid"),
+    ("api_tokens.json", "SELECT name, prefix, scopes, created_at, last_used_at FROM api_token WHERE owner = $uid"),
+    ("push_subscriptions.json", "SELECT endpoint, created_at FROM push_subscription WHERE owner = $uid"),
+    ("chat_messages.json", "SELECT role, content, created_at FROM chat_message WHERE owner = $uid ORDER BY created_at ASC"),
+    ("notification_log.json", "SELECT channel, title, body, delivered, error, created_at FROM notification_log WHERE owner = $uid ORDER BY created_at ASC"),
+    ("monthly_reports.json", "SELECT month, label, summary, facts, created_at FROM monthly_report WHERE owner = $uid ORDER BY month ASC"),
+    ("care_rules.json", "SELECT name, enabled, trigger, actions, last_fired_at, created_at FROM care_rule WHERE owner = $uid"),
+    ("household.json", "SELECT name, created_at, (SELECT user.username AS username, role, joined_at FROM household_member WHERE household = $parent.id) AS members FROM household WHERE owner = $uid"),
+    ("household_membership.json", "SELECT household.name AS household, role, joined_at FROM household_member WHERE user = $uid"),
+];
+
+/// **What is it?**
+/// A helper that runs every `EXPORT_QUERIES` query for one user and returns each file name with its rows.
+///
+/// **Why does it exist?**
+/// It exists so the "Download my data" zip and the `export` CLI command cover exactly the same data.
+///
+/// **How should it be used?**
+/// Call with the user's `RecordId`; no session is needed, so callers must have authorized the export themselves.
+#[cfg(feature = "ssr")]
+pub(crate) async fn export_tables(uid: surrealdb::types::RecordId) -> Result<Vec<(String, Vec<serde_json::Value>)>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let query = EXPORT_QUERIES.iter().map(|(_, q)| *q).collect::<Vec<_>>().join("; ");
+    let mut response = db()
+        .query(query)
+        .bind(("uid", uid))
+        .await
+        .map_err(|e| internal_error("Data export query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Data export query error", err_msg));
+    }
+
+    let mut tables = Vec::with_capacity(EXPORT_QUERIES.len());
+    for (i, (name, _)) in EXPORT_QUERIES.iter().enumerate() {
+        let rows: Vec<serde_json::Value> = response.take(i)
+            .map_err(|e| internal_error("Data export parse failed", e))?;
+        tables.push((name.to_string(), rows));
+    }
+    Ok(tables)
+}
+
+/// **What is it?**
+/// A helper that packs `(path, bytes)` pairs into an in-memory zip archive.
+///
+/// **Why does it exist?**
+/// It exists so every download that hands the browser a zip (data export, climate Parquet export) builds it the same way.
+///
+/// **How should it be used?**
+/// Call inside `spawn_blocking`. Files for which `stored` returns true are added without compression, for content that is already compressed.
+#[cfg(feature = "ssr")]
+pub(crate) fn zip_files(
+    files: Vec<(String, Vec<u8>)>,
+    stored: impl Fn(&str) -> bool,
+) -> Result<Vec<u8>, zip::result::ZipError> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, bytes) in files {
+        let method = if stored(&name) {
+            zip::CompressionMethod::Stored
+        } else {
+            zip::CompressionMethod::Deflated
+        };
+        zip.start_file(name, SimpleFileOptions::default().compression_method(method))?;
+        zip.write_all(&bytes)?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+/// **What is it?**
+/// A server function that packages all of the current user's records and uploaded photos into a zip archive.
+///
+/// **Why does it exist?**
+/// GDPR gives users a right of access to their personal data in a portable format; the cookie banner promises compliance, so this is the subject-access tool.
+///
+/// **How should it be used?**
+/// Call from the "Download my data" button in settings or on the account deletion page. The archive has one JSON file per data type plus an `images/` folder.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn export_my_data() -> Result<DataExport, ServerFnError> {
+    use crate::auth::{require_auth, get_session_user};
+    use crate::config::config;
+    use crate::error::internal_error;
+    use base64::Engine;
+    use std::path::PathBuf;
+
+    let user_id = require_auth().await?;
+    let username = get_session_user().await?
+        .map(|u| u.username)
+        .unwrap_or_default();
+    let uid = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    for (name, rows) in export_tables(uid).await? {
+        let json = serde_json::to_vec_pretty(&rows)
+            .map_err(|e| internal_error("Data export encode failed", e))?;
+        files.push((name, json));
+    }
+
+    // Uploaded photos live in a per-user directory
+    let safe_user_dir = user_id.replace(':', "_");
+    let image_dir = PathBuf::from(&config().image_storage_path).join(&safe_user_dir);
+    if let Ok(mut entries) = tokio::fs::read_dir(&image_dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            match tokio::fs::read(&path).await {
+                Ok(bytes) => files.push((format!("images/{}", entry.file_name().to_string_lossy()), bytes)),
+                Err(e) => tracing::warn!("Data export skipped unreadable image {:?}: {}", path, e),
+            }
+        }
+    }
+
+    // Photos are already compressed; deflating them again only costs CPU
+    let archive = tokio::task::spawn_blocking(move || zip_files(files, |name| name.starts_with("images/")))
+        .await
+        .map_err(|e| internal_error("Data export task failed", e))?
+        .map_err(|e| internal_error("Data export zip failed", e))?;
+
+    tracing::info!(bytes = archive.len(), "Data export generated");
+
+    Ok(DataExport {
+        filename: format!("velamen-export-{}-{}.zip", username, chrono::Utc::now().format("%Y-%m-%d")),
+        zip_base64: base64::engine::general_purpose::STANDARD.encode(archive),
+    })
+}
+
 /// **What is it?**
 /// A server function that permanently deletes the current user's account and all associated data.
 ///
@@ -242,10 +455,10 @@ pub async fn get_current_user() -> Result<Option<UserInfo>, ServerFnError> {
 /// GDPR and CCPA regulations require users to be able to delete their accounts and all personal data.
 ///
 /// **How should it be used?**
-/// Call this from the settings modal after the user has confirmed the deletion by typing their username.
+/// Call this from the settings modal or the account deletion page after the user has confirmed the deletion by typing their username. Every session the user has open, on any device, is revoked too.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
-pub async fn delete_account(
+pub async fn delete_my_account(
     /// The user's username, typed as confirmation of the irreversible action.
     confirmation_username: String,
 ) -> Result<(), ServerFnError> {
@@ -272,17 +485,46 @@ pub async fn delete_account(
         .query("
             BEGIN TRANSACTION;
             DELETE FROM climate_reading WHERE zone IN (SELECT id FROM growing_zone WHERE owner = $uid);
+            DELETE FROM climate_reading_summary WHERE zone IN (SELECT id FROM growing_zone WHERE owner = $uid);
+            DELETE FROM zone_dli WHERE zone IN (SELECT id FROM growing_zone WHERE owner = $uid);
             DELETE FROM log_entry WHERE owner = $uid;
             DELETE FROM alert WHERE owner = $uid;
             DELETE FROM push_subscription WHERE owner = $uid;
+            DELETE FROM passkey WHERE owner = $uid;
+            DELETE FROM api_token WHERE owner = $uid;
+            DELETE FROM password_reset WHERE user = $uid;
             DELETE FROM hardware_device WHERE owner = $uid;
+            DELETE FROM zone_goal WHERE owner = $uid;
+            DELETE FROM zone_energy WHERE owner = $uid;
+            DELETE FROM water_reading WHERE owner = $uid;
+            DELETE FROM water_source WHERE owner = $uid;
+            DELETE FROM orchid_revision WHERE owner = $uid;
+            DELETE FROM propagation WHERE owner = $uid;
+            DELETE FROM award WHERE owner = $uid;
+            DELETE FROM treatment WHERE owner = $uid;
             DELETE FROM orchid WHERE owner = $uid;
+            DELETE FROM vendor WHERE owner = $uid;
             DELETE FROM growing_zone WHERE owner = $uid;
             DELETE FROM user_preference WHERE owner = $uid;
+            DELETE FROM event_type WHERE owner = $uid;
+            DELETE FROM note_template WHERE owner = $uid;
+            DELETE FROM reminder WHERE owner = $uid;
+            DELETE FROM species_alias WHERE owner = $uid;
+            DELETE FROM species_photo WHERE owner = $uid;
+            DELETE FROM care_rule WHERE owner = $uid;
+            DELETE FROM chat_message WHERE owner = $uid;
+            DELETE FROM monthly_report WHERE owner = $uid;
+            DELETE FROM notification_log WHERE owner = $uid;
+            DELETE FROM household_member WHERE user = $uid OR household.owner = $uid;
+            DELETE FROM household_invite WHERE household.owner = $uid;
+            DELETE FROM household WHERE owner = $uid;
+            DELETE FROM session WHERE string::contains(data, $session_marker);
             DELETE FROM user WHERE id = $uid;
             COMMIT TRANSACTION;
         ")
         .bind(("uid", uid))
+        // Session data is stored as serialized JSON; this matches the user_id entry
+        .bind(("session_marker", format!("\"user_id\":\"{}\"", user_id)))
         .await
         .map_err(|e| internal_error("Account deletion query failed", e))?;
 
@@ -308,6 +550,790 @@ pub async fn delete_account(
     Ok(())
 }
 
+/// How long a password reset link stays valid, in minutes.
+pub const PASSWORD_RESET_VALID_MINUTES: i64 = 60;
+
+/// **What is it?**
+/// A server function that emails a single-use password reset link to the account registered with the given address.
+///
+/// **Why does it exist?**
+/// It exists so users can recover their account themselves instead of asking an admin to run `reset-password` on the CLI.
+///
+/// **How should it be used?**
+/// Call from the forgot password page. It always succeeds for well-formed input so the response never reveals whether an address has an account.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn request_password_reset(
+    /// The email address on the account.
+    email: String,
+) -> Result<(), ServerFnError> {
+    use crate::config::config;
+    use crate::crypto::generate_token;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let email = email.trim().to_string();
+    if email.is_empty() || email.len() > 254 || !email.contains('@') {
+        return Err(ServerFnError::new("A valid email address is required"));
+    }
+    if !crate::mail::is_configured() {
+        return Err(ServerFnError::new("Password reset by email isn't enabled on this server. Please contact the administrator."));
+    }
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct ResetUserRow {
+        id: surrealdb::types::RecordId,
+        username: String,
+        email: String,
+    }
+
+    let mut response = db()
+        .query("SELECT id, username, email FROM user WHERE string::lowercase(email) = string::lowercase($email)")
+        .bind(("email", email))
+        .await
+        .map_err(|e| internal_error("Password reset user query failed", e))?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Password reset user query error", err_msg));
+    }
+    let users: Vec<ResetUserRow> = response.take(0)
+        .map_err(|e| internal_error("Password reset user parse failed", e))?;
+
+    for user in users {
+        // Throttle: at most one outstanding link per user every few minutes
+        let mut recent = db()
+            .query("SELECT VALUE id FROM password_reset WHERE user = $user AND used_at IS NONE AND created_at > time::now() - 5m")
+            .bind(("user", user.id.clone()))
+            .await
+            .map_err(|e| internal_error("Password reset throttle query failed", e))?;
+        let errors = recent.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Password reset throttle query error", err_msg));
+        }
+        let recent_ids: Vec<surrealdb::types::RecordId> = recent.take(0)
+            .map_err(|e| internal_error("Password reset throttle parse failed", e))?;
+        if !recent_ids.is_empty() {
+            tracing::info!("Password reset throttled for recent request");
+            continue;
+        }
+
+        let (token, token_hash) = generate_token();
+        let mut created = db()
+            .query("CREATE password_reset SET user = $user, token_hash = $hash, expires_at = time::now() + <duration> $valid")
+            .bind(("user", user.id.clone()))
+            .bind(("hash", token_hash))
+            .bind(("valid", format!("{}m", PASSWORD_RESET_VALID_MINUTES)))
+            .await
+            .map_err(|e| internal_error("Password reset token insert failed", e))?;
+        let errors = created.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Password reset token insert error", err_msg));
+        }
+
+        let link = format!("{}/reset-password?token={}", config().app_base_url.trim_end_matches('/'), token);
+        let body = crate::mail::password_reset_body(&user.username, &link, PASSWORD_RESET_VALID_MINUTES);
+        // Failing here would tell the caller the address has an account, so the error is only logged
+        if let Err(e) = crate::mail::send_email(&user.email, "Reset your Velamen password", &body).await {
+            tracing::error!("Password reset email failed: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that sets a new password using a token from a reset email.
+///
+/// **Why does it exist?**
+/// It exists to complete the self-service reset flow; the token is single-use and expires after `PASSWORD_RESET_VALID_MINUTES`.
+///
+/// **How should it be used?**
+/// Call from the reset password page with the `token` query parameter and the new password. On success all of the user's outstanding reset links are invalidated and every session is signed out.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn reset_password(
+    /// The token from the reset link.
+    token: String,
+    /// The new password.
+    new_password: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::hash_password;
+    use crate::crypto::hash_token;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if new_password.len() < 8 || new_password.len() > 128 {
+        return Err(ServerFnError::new("Password must be 8-128 characters"));
+    }
+    if token.is_empty() || token.len() > 128 {
+        return Err(ServerFnError::new("This reset link is invalid or has expired"));
+    }
+
+    let password_hash = hash_password(&new_password)
+        .map_err(|e| internal_error("Password hashing failed", e))?;
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct ClaimedResetRow {
+        user: surrealdb::types::RecordId,
+    }
+
+    // Claiming the token and checking it are one statement, so two requests racing with the same
+    // link can't both succeed
+    let mut response = db()
+        .query("UPDATE password_reset SET used_at = time::now() WHERE token_hash = $hash AND used_at = NONE AND expires_at > time::now() RETURN AFTER")
+        .bind(("hash", hash_token(&token)))
+        .await
+        .map_err(|e| internal_error("Password reset claim failed", e))?;
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Password reset claim error", err_msg));
+    }
+    let claimed: Vec<ClaimedResetRow> = response.take(0)
+        .map_err(|e| internal_error("Password reset claim parse failed", e))?;
+    let user = claimed.into_iter().next()
+        .map(|row| row.user)
+        .ok_or_else(|| ServerFnError::new("This reset link is invalid or has expired"))?;
+    let user_id = record_id_to_string(&user);
+
+    // Signs the user out everywhere, since whoever knew the old password may still be signed in
+    let mut response = db()
+        .query("
+            BEGIN TRANSACTION;
+            UPDATE $user SET password_hash = $hash;
+            UPDATE password_reset SET used_at = time::now() WHERE user = $user AND used_at IS NONE;
+            DELETE session WHERE user_id = $uid OR string::contains(data, $session_marker);
+            COMMIT TRANSACTION;
+        ")
+        .bind(("user", user))
+        .bind(("hash", password_hash))
+        .bind(("uid", user_id.clone()))
+        // Session data is stored as serialized JSON; this matches the user_id entry
+        .bind(("session_marker", format!("\"user_id\":\"{}\"", user_id)))
+        .await
+        .map_err(|e| internal_error("Password reset update failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Password reset update error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A client-facing summary of one registered passkey.
+///
+/// **Why does it exist?**
+/// It exists so the settings screen can list and remove passkeys without ever sending the stored public key material to the browser.
+///
+/// **How should it be used?**
+/// Returned by `list_passkeys`; pass `id` to `delete_passkey` to revoke one.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PasskeyInfo {
+    /// The passkey record ID.
+    pub id: String,
+    /// User-chosen label (e.g., "iPhone").
+    pub name: String,
+    /// When the passkey was registered.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When the passkey was last used to sign in.
+    pub last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[cfg(feature = "ssr")]
+#[derive(Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct PasskeyDbRow {
+    id: surrealdb::types::RecordId,
+    credential: String,
+}
+
+/// **What is it?**
+/// An SSR-only helper that encodes a WebAuthn credential ID as unpadded base64url.
+///
+/// **Why does it exist?**
+/// It exists so credential IDs are stored and compared in one canonical string form.
+///
+/// **How should it be used?**
+/// Call when writing a new passkey row or matching an authentication result to a stored key.
+#[cfg(feature = "ssr")]
+fn credential_id_string(id: &webauthn_rs::prelude::CredentialID) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(id.as_ref())
+}
+
+/// **What is it?**
+/// An SSR-only helper that loads and deserializes every stored passkey for a user.
+///
+/// **Why does it exist?**
+/// It exists because registration (to exclude existing keys) and sign-in (to verify an assertion) both need the full credential set.
+///
+/// **How should it be used?**
+/// Call with the user's `RecordId`; rows that fail to deserialize are logged and skipped.
+#[cfg(feature = "ssr")]
+async fn load_passkeys(
+    owner: &surrealdb::types::RecordId,
+) -> Result<Vec<(surrealdb::types::RecordId, webauthn_rs::prelude::Passkey)>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut response = db()
+        .query("SELECT id, credential FROM passkey WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Passkey query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Passkey query error", err_msg));
+    }
+
+    let rows: Vec<PasskeyDbRow> = response.take(0)
+        .map_err(|e| internal_error("Passkey parse failed", e))?;
+
+    Ok(rows.into_iter().filter_map(|r| {
+        match serde_json::from_str(&r.credential) {
+            Ok(pk) => Some((r.id, pk)),
+            Err(e) => {
+                tracing::warn!("Skipping unreadable passkey {:?}: {}", r.id, e);
+                None
+            }
+        }
+    }).collect())
+}
+
+/// **What is it?**
+/// A server function that begins registering a new passkey for the signed-in user.
+///
+/// **Why does it exist?**
+/// It exists to issue a WebAuthn creation challenge, so a phone or security key can be used to sign in without a password.
+///
+/// **How should it be used?**
+/// Call from the passkey settings, pass the returned JSON to `navigator.credentials.create()`, then send the result to `finish_passkey_registration`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn start_passkey_registration() -> Result<String, ServerFnError> {
+    use crate::auth::{require_auth, store_passkey_registration, webauthn};
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct WebauthnUserRow {
+        username: String,
+        #[surreal(default)]
+        webauthn_id: Option<String>,
+    }
+
+    let mut response = db()
+        .query("SELECT username, webauthn_id FROM user WHERE id = $id LIMIT 1")
+        .bind(("id", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Passkey user query failed", e))?;
+    let _ = response.take_errors();
+    let row: WebauthnUserRow = response.take::<Option<WebauthnUserRow>>(0)
+        .map_err(|e| internal_error("Passkey user parse failed", e))?
+        .ok_or_else(|| ServerFnError::new("Not authenticated"))?;
+
+    // Each user gets a stable random WebAuthn handle so the record ID is never exposed to authenticators
+    let handle = match row.webauthn_id.as_deref().and_then(|s| uuid::Uuid::parse_str(s).ok()) {
+        Some(h) => h,
+        None => {
+            let h = uuid::Uuid::new_v4();
+            db()
+                .query("UPDATE $id SET webauthn_id = $handle")
+                .bind(("id", owner.clone()))
+                .bind(("handle", h.to_string()))
+                .await
+                .map_err(|e| internal_error("Passkey handle update failed", e))?;
+            h
+        }
+    };
+
+    let exclude: Vec<_> = load_passkeys(&owner).await?
+        .into_iter()
+        .map(|(_, pk)| pk.cred_id().clone())
+        .collect();
+
+    let (challenge, state) = webauthn()
+        .map_err(|e| internal_error("WebAuthn unavailable", e))?
+        .start_passkey_registration(handle, &row.username, &row.username, Some(exclude))
+        .map_err(|e| internal_error("Passkey registration start failed", e))?;
+
+    store_passkey_registration(&state).await?;
+
+    serde_json::to_string(&challenge)
+        .map_err(|e| internal_error("Passkey challenge serialize failed", e))
+}
+
+/// **What is it?**
+/// A server function that verifies the browser's response to a registration challenge and stores the new passkey.
+///
+/// **Why does it exist?**
+/// It exists to complete the WebAuthn ceremony started by `start_passkey_registration` and persist the public key credential.
+///
+/// **How should it be used?**
+/// Call with the JSON-serialized `PublicKeyCredential` returned by `navigator.credentials.create()` and a label for the device.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn finish_passkey_registration(
+    /// A label for this passkey (e.g., "iPhone").
+    name: String,
+    /// The JSON-serialized registration credential from the browser.
+    credential: String,
+) -> Result<PasskeyInfo, ServerFnError> {
+    use crate::auth::{require_auth, take_passkey_registration, webauthn};
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    let name = name.trim();
+    let name = if name.is_empty() { "Passkey".to_string() } else { name.chars().take(50).collect() };
+
+    let state = take_passkey_registration().await?;
+    let reg: webauthn_rs::prelude::RegisterPublicKeyCredential = serde_json::from_str(&credential)
+        .map_err(|_| ServerFnError::new("Invalid passkey response"))?;
+
+    let passkey = webauthn()
+        .map_err(|e| internal_error("WebAuthn unavailable", e))?
+        .finish_passkey_registration(&reg, &state)
+        .map_err(|e| {
+            tracing::warn!("Passkey registration rejected: {}", e);
+            ServerFnError::new("Passkey could not be verified")
+        })?;
+
+    let serialized = serde_json::to_string(&passkey)
+        .map_err(|e| internal_error("Passkey serialize failed", e))?;
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct CreatedRow {
+        id: surrealdb::types::RecordId,
+        name: String,
+        created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    let mut response = db()
+        .query("CREATE passkey SET owner = $owner, name = $name, credential_id = $cred_id, credential = $credential RETURN id, name, created_at")
+        .bind(("owner", owner))
+        .bind(("name", name))
+        .bind(("cred_id", credential_id_string(passkey.cred_id())))
+        .bind(("credential", serialized))
+        .await
+        .map_err(|e| internal_error("Passkey insert failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Passkey insert error", err_msg));
+    }
+
+    let row: CreatedRow = response.take::<Option<CreatedRow>>(0)
+        .map_err(|e| internal_error("Passkey insert parse failed", e))?
+        .ok_or_else(|| ServerFnError::new("Failed to save passkey"))?;
+
+    Ok(PasskeyInfo {
+        id: record_id_to_string(&row.id),
+        name: row.name,
+        created_at: row.created_at,
+        last_used_at: None,
+    })
+}
+
+/// **What is it?**
+/// A server function that begins a passkey sign-in for the given username.
+///
+/// **Why does it exist?**
+/// It exists to issue a WebAuthn assertion challenge limited to that user's registered passkeys.
+///
+/// **How should it be used?**
+/// Call from the login page, pass the returned JSON to `navigator.credentials.get()`, then send the result to `finish_passkey_login`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn start_passkey_login(
+    /// The username to sign in as.
+    username: String,
+) -> Result<String, ServerFnError> {
+    use crate::auth::{store_passkey_authentication, webauthn};
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if username.is_empty() || username.len() > 50 {
+        return Err(ServerFnError::new("Invalid credentials"));
+    }
+
+    let mut response = db()
+        .query("SELECT VALUE id FROM user WHERE username = $username LIMIT 1")
+        .bind(("username", username))
+        .await
+        .map_err(|e| internal_error("Passkey login query failed", e))?;
+    let _ = response.take_errors();
+    let ids: Vec<surrealdb::types::RecordId> = response.take(0).unwrap_or_default();
+    let owner = ids.into_iter().next().ok_or_else(|| ServerFnError::new("Invalid credentials"))?;
+
+    let passkeys: Vec<_> = load_passkeys(&owner).await?.into_iter().map(|(_, pk)| pk).collect();
+    if passkeys.is_empty() {
+        return Err(ServerFnError::new("Invalid credentials"));
+    }
+
+    let (challenge, state) = webauthn()
+        .map_err(|e| internal_error("WebAuthn unavailable", e))?
+        .start_passkey_authentication(&passkeys)
+        .map_err(|e| internal_error("Passkey login start failed", e))?;
+
+    store_passkey_authentication(&record_id_to_string(&owner), &state).await?;
+
+    serde_json::to_string(&challenge)
+        .map_err(|e| internal_error("Passkey challenge serialize failed", e))
+}
+
+/// **What is it?**
+/// A server function that verifies a passkey assertion and signs the user in.
+///
+/// **Why does it exist?**
+/// It exists to complete the passwordless login started by `start_passkey_login`, updating the stored signature counter.
+///
+/// **How should it be used?**
+/// Call with the JSON-serialized `PublicKeyCredential` returned by `navigator.credentials.get()`; on success a session is created just like `login`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn finish_passkey_login(
+    /// The JSON-serialized assertion credential from the browser.
+    credential: String,
+) -> Result<UserInfo, ServerFnError> {
+    use crate::auth::{create_session, take_passkey_authentication, webauthn};
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let (user_id, state) = take_passkey_authentication().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    let assertion: webauthn_rs::prelude::PublicKeyCredential = serde_json::from_str(&credential)
+        .map_err(|_| ServerFnError::new("Invalid passkey response"))?;
+
+    let result = webauthn()
+        .map_err(|e| internal_error("WebAuthn unavailable", e))?
+        .finish_passkey_authentication(&assertion, &state)
+        .map_err(|e| {
+            tracing::warn!("Passkey assertion rejected: {}", e);
+            ServerFnError::new("Invalid credentials")
+        })?;
+
+    // Persist counter/backup-state changes and record usage
+    let used_id = credential_id_string(result.cred_id());
+    for (record, mut pk) in load_passkeys(&owner).await? {
+        if credential_id_string(pk.cred_id()) != used_id {
+            continue;
+        }
+        let credential = if pk.update_credential(&result) == Some(true) {
+            serde_json::to_string(&pk).ok()
+        } else {
+            None
+        };
+        let _ = db()
+            .query("UPDATE $id SET last_used_at = time::now(), credential = $credential ?? credential")
+            .bind(("id", record))
+            .bind(("credential", credential))
+            .await;
+    }
+
+    let mut response = db()
+        .query("SELECT id, username, email, is_admin FROM user WHERE id = $id AND disabled != true LIMIT 1")
+        .bind(("id", owner))
+        .await
+        .map_err(|e| internal_error("Passkey user query failed", e))?;
+    let _ = response.take_errors();
+    let user = response.take::<Option<UserDbRow>>(0)
+        .map_err(|e| internal_error("Passkey user parse failed", e))?
+        .ok_or_else(|| ServerFnError::new("Invalid credentials"))?
+        .into_user_info();
+
+    create_session(&user.id).await?;
+
+    Ok(user)
+}
+
+/// **What is it?**
+/// A server function that lists the signed-in user's registered passkeys.
+///
+/// **Why does it exist?**
+/// It exists so users can see which devices can sign in to their account.
+///
+/// **How should it be used?**
+/// Call from the passkey section of the settings modal.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn list_passkeys() -> Result<Vec<PasskeyInfo>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PasskeyListRow {
+        id: surrealdb::types::RecordId,
+        name: String,
+        created_at: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        last_used_at: Option<chrono::DateTime<chrono::Utc>>,
+    }
+
+    let mut response = db()
+        .query("SELECT id, name, created_at, last_used_at FROM passkey WHERE owner = $owner ORDER BY created_at ASC")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("List passkeys query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("List passkeys query error", err_msg));
+    }
+
+    let rows: Vec<PasskeyListRow> = response.take(0)
+        .map_err(|e| internal_error("List passkeys parse failed", e))?;
+
+    Ok(rows.into_iter().map(|r| PasskeyInfo {
+        id: record_id_to_string(&r.id),
+        name: r.name,
+        created_at: r.created_at,
+        last_used_at: r.last_used_at,
+    }).collect())
+}
+
+/// **What is it?**
+/// A server function that removes one of the signed-in user's passkeys.
+///
+/// **Why does it exist?**
+/// It exists so a lost or replaced device can no longer be used to sign in.
+///
+/// **How should it be used?**
+/// Call with a `PasskeyInfo::id` from `list_passkeys`. Password sign-in is unaffected.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_passkey(
+    /// The passkey record ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("User ID parse failed", e))?;
+    let passkey_id = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Passkey ID parse failed", e))?;
+
+    db()
+        .query("DELETE $id WHERE owner = $owner")
+        .bind(("id", passkey_id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete passkey query failed", e))?;
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A client-facing summary of one signed-in browser session.
+///
+/// **Why does it exist?**
+/// It exists so users can spot sessions they don't recognise and sign them out remotely instead of waiting for them to expire.
+///
+/// **How should it be used?**
+/// Returned by `list_sessions`; pass `id` to `revoke_session` to sign that device out.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SessionInfo {
+    /// The session ID.
+    pub id: String,
+    /// A short device description (e.g., "Safari on iOS").
+    pub device: String,
+    /// The client IP address at sign-in, if known.
+    pub ip: Option<String>,
+    /// When the user signed in, if recorded.
+    pub signed_in_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// When the session last made a request.
+    pub last_seen_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether this is the session making the request.
+    pub current: bool,
+}
+
+/// **What is it?**
+/// An SSR-only helper that returns the ID of the session making the current request.
+///
+/// **Why does it exist?**
+/// It exists so session listing can flag the current device and bulk revocation can leave it signed in.
+///
+/// **How should it be used?**
+/// Call from session management server functions; returns `None` if the session hasn't been stored yet.
+#[cfg(feature = "ssr")]
+async fn current_session_id() -> Result<Option<String>, ServerFnError> {
+    let session: tower_sessions::Session = leptos_axum::extract().await?;
+    Ok(session.id().map(|id| id.to_string()))
+}
+
+/// **What is it?**
+/// A server function that lists the signed-in user's active sessions across all devices.
+///
+/// **Why does it exist?**
+/// It exists so a stolen or forgotten session can be found from settings; sessions otherwise only end after 7 days of inactivity.
+///
+/// **How should it be used?**
+/// Call from the active sessions settings panel. Sessions are ordered most recently used first.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn list_sessions() -> Result<Vec<SessionInfo>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::session_store::describe_user_agent;
+    use surrealdb::types::RecordIdKey;
+
+    #[derive(Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct SessionListRow {
+        id: surrealdb::types::RecordId,
+        data: String,
+        #[surreal(default)]
+        last_seen: i64,
+    }
+
+    let user_id = require_auth().await?;
+    let current = current_session_id().await?;
+
+    let mut response = db()
+        .query("SELECT id, data, last_seen FROM session WHERE user_id = $uid AND expiry > $now ORDER BY last_seen DESC")
+        .bind(("uid", user_id))
+        .bind(("now", chrono::Utc::now().timestamp()))
+        .await
+        .map_err(|e| internal_error("List sessions query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("List sessions query error", err_msg));
+    }
+
+    let rows: Vec<SessionListRow> = response.take(0)
+        .map_err(|e| internal_error("List sessions parse failed", e))?;
+
+    Ok(rows.into_iter().filter_map(|r| {
+        let RecordIdKey::String(id) = r.id.key else { return None };
+        let data: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&r.data).unwrap_or_default();
+        let text = |key: &str| data.get(key).and_then(|v| v.as_str()).filter(|s| !s.is_empty()).map(str::to_string);
+        let timestamp = |secs: i64| chrono::DateTime::from_timestamp(secs, 0).filter(|_| secs > 0);
+        Some(SessionInfo {
+            current: current.as_deref() == Some(id.as_str()),
+            id,
+            device: text("user_agent").map(|ua| describe_user_agent(&ua)).unwrap_or_else(|| "Unknown device".into()),
+            ip: text("ip"),
+            signed_in_at: data.get("signed_in_at").and_then(|v| v.as_i64()).and_then(timestamp),
+            last_seen_at: timestamp(r.last_seen),
+        })
+    }).collect())
+}
+
+/// **What is it?**
+/// A server function that signs out one of the signed-in user's sessions.
+///
+/// **Why does it exist?**
+/// It exists so a lost or shared device can be signed out remotely.
+///
+/// **How should it be used?**
+/// Call with a `SessionInfo::id` from `list_sessions`. Revoking the current session signs this browser out.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn revoke_session(
+    /// The session ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+
+    let mut response = db()
+        .query("DELETE type::record('session', $id) WHERE user_id = $uid RETURN BEFORE")
+        .bind(("id", id))
+        .bind(("uid", user_id))
+        .await
+        .map_err(|e| internal_error("Revoke session query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke session query error", err_msg));
+    }
+
+    let revoked: Vec<serde_json::Value> = response.take(0)
+        .map_err(|e| internal_error("Revoke session parse failed", e))?;
+    if revoked.is_empty() {
+        return Err(ServerFnError::new("Session not found or already signed out"));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that signs the user out everywhere except the browser making the request.
+///
+/// **Why does it exist?**
+/// It exists as the "sign out everywhere" escape hatch when an account may be compromised.
+///
+/// **How should it be used?**
+/// Call from the active sessions settings panel. Returns how many sessions were revoked.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn revoke_other_sessions() -> Result<usize, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let current = current_session_id().await?.unwrap_or_default();
+
+    let mut response = db()
+        // Sessions written before user_id was recorded are matched on their data instead
+        .query("DELETE session WHERE (user_id = $uid OR string::contains(data, $session_marker)) AND id != type::record('session', $current) RETURN BEFORE")
+        .bind(("session_marker", format!("\"user_id\":\"{}\"", user_id)))
+        .bind(("uid", user_id))
+        .bind(("current", current))
+        .await
+        .map_err(|e| internal_error("Revoke sessions query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke sessions query error", err_msg));
+    }
+
+    let revoked: Vec<serde_json::Value> = response.take(0)
+        .map_err(|e| internal_error("Revoke sessions parse failed", e))?;
+    Ok(revoked.len())
+}
+
 #[cfg(all(test, feature = "ssr"))]
 mod tests {
     use super::*;
@@ -345,10 +1371,12 @@ mod tests {
             id: RecordId::new("user", "xyz789"),
             username: "alice".to_string(),
             email: "alice@example.com".to_string(),
+            is_admin: true,
         };
         let info = row.into_user_info();
         assert_eq!(info.id, "user:xyz789");
         assert_eq!(info.username, "alice");
         assert_eq!(info.email, "alice@example.com");
+        assert!(info.is_admin);
     }
 }
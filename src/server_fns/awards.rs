@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use leptos::prelude::*;
+use chrono::{DateTime, Utc};
+use crate::award::{Award, AwardBadge, AwardKind};
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::award::{Award, AwardKind};
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct AwardDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub orchid: surrealdb::types::RecordId,
+        pub kind: String,
+        pub title: String,
+        #[surreal(default)]
+        pub show_name: Option<String>,
+        #[surreal(default)]
+        pub points: Option<u32>,
+        pub awarded_at: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        pub image_filename: Option<String>,
+    }
+
+    impl AwardDbRow {
+        /// None for a row whose kind this build doesn't know.
+        pub fn into_award(self) -> Option<Award> {
+            Some(Award {
+                id: record_id_to_string(&self.id),
+                orchid_id: record_id_to_string(&self.orchid),
+                kind: AwardKind::parse(&self.kind)?,
+                title: self.title,
+                show_name: self.show_name,
+                points: self.points,
+                awarded_at: self.awarded_at,
+                image_filename: self.image_filename,
+            })
+        }
+    }
+}
+
+/// **What is it?**
+/// A server function that lists a plant's awards, most recent first.
+///
+/// **Why does it exist?**
+/// It exists to show a plant's show and judging record in its detail view.
+///
+/// **How should it be used?**
+/// Load from the awards card on the Details tab; reload after `add_award` or `delete_award`.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn get_awards(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+) -> Result<Vec<Award>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::AwardDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("SELECT * FROM award WHERE owner = $owner AND orchid = $orchid ORDER BY awarded_at DESC")
+        .bind(("owner", owner))
+        .bind(("orchid", orchid))
+        .await
+        .map_err(|e| internal_error("Get awards query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get awards query error", err_msg));
+    }
+
+    let rows: Vec<AwardDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get awards parse failed", e))?;
+
+    Ok(rows.into_iter().filter_map(AwardDbRow::into_award).collect())
+}
+
+/// **What is it?**
+/// A server function that returns the award badge for every awarded plant in the collection, keyed by orchid ID.
+///
+/// **Why does it exist?**
+/// It exists so the collection grid can mark awarded plants with one request instead of one per card.
+///
+/// **How should it be used?**
+/// Load once on the home page and share through the `AwardBadges` context; see `crate::award::award_badges` for which award a badge names.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_award_badges() -> Result<HashMap<String, AwardBadge>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::award::award_badges;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::AwardDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT * FROM award WHERE owner = $owner")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get award badges query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get award badges query error", err_msg));
+    }
+
+    let rows: Vec<AwardDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get award badges parse failed", e))?;
+    let awards: Vec<Award> = rows.into_iter().filter_map(AwardDbRow::into_award).collect();
+
+    Ok(award_badges(&awards))
+}
+
+/// **What is it?**
+/// A server function that records an award or show result for a plant.
+///
+/// **Why does it exist?**
+/// It exists so a collection's awarded plants carry a structured record (what, where, when, and the score) rather than a line in the notes.
+///
+/// **How should it be used?**
+/// Call from the awards card after uploading any photo through `/api/images/upload`. Returns the saved award.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn add_award(
+    /// The plant that won the award.
+    orchid_id: String,
+    /// AOS, other judging, or a show ribbon.
+    kind: AwardKind,
+    /// The award as written, such as "HCC/AOS".
+    title: String,
+    /// The show or judging centre.
+    show_name: Option<String>,
+    /// The judges' score, for point-scored awards.
+    points: Option<u32>,
+    /// When it was awarded.
+    awarded_at: DateTime<Utc>,
+    /// Photo filename returned by the upload endpoint.
+    image_filename: Option<String>,
+) -> Result<Award, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::award::{MAX_AWARD_POINTS, MAX_AWARD_TITLE_LEN, MAX_SHOW_NAME_LEN};
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::orchids::{load_owned_orchid, validate_filename};
+    use ssr_types::AwardDbRow;
+
+    let title = title.trim().to_string();
+    if title.is_empty() || title.len() > MAX_AWARD_TITLE_LEN {
+        return Err(ServerFnError::new(format!("Award title must be 1 to {} characters", MAX_AWARD_TITLE_LEN)));
+    }
+    let show_name = show_name.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    if show_name.as_ref().is_some_and(|s| s.len() > MAX_SHOW_NAME_LEN) {
+        return Err(ServerFnError::new(format!("Show name must be at most {} characters", MAX_SHOW_NAME_LEN)));
+    }
+    if points.is_some_and(|p| p > MAX_AWARD_POINTS) {
+        return Err(ServerFnError::new(format!("Points must be 0-{}", MAX_AWARD_POINTS)));
+    }
+    if awarded_at > Utc::now() {
+        return Err(ServerFnError::new("Award date can't be in the future"));
+    }
+    if let Some(ref filename) = image_filename {
+        validate_filename(filename)?;
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+    load_owned_orchid(&orchid, &owner).await?;
+
+    let mut resp = db()
+        .query(
+            "CREATE award SET owner = $owner, orchid = $orchid, kind = $kind, title = $title, \
+             show_name = $show_name, points = $points, awarded_at = $awarded_at, image_filename = $image_filename"
+        )
+        .bind(("owner", owner))
+        .bind(("orchid", orchid))
+        .bind(("kind", kind.as_str().to_string()))
+        .bind(("title", title))
+        .bind(("show_name", show_name))
+        .bind(("points", points))
+        .bind(("awarded_at", awarded_at))
+        .bind(("image_filename", image_filename))
+        .await
+        .map_err(|e| internal_error("Add award query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Add award query error", err_msg));
+    }
+
+    let row: Option<AwardDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Add award parse failed", e))?;
+    row.and_then(AwardDbRow::into_award)
+        .ok_or_else(|| ServerFnError::new("Failed to add award"))
+}
+
+/// **What is it?**
+/// A server function that deletes an award.
+///
+/// **Why does it exist?**
+/// It exists to correct an award entered against the wrong plant or with the wrong details.
+///
+/// **How should it be used?**
+/// Call from the awards card's delete action. The photo file is left in place, like a deleted journal entry's.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_award(
+    /// The unique identifier of the award.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let award = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Award ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("DELETE $id WHERE owner = $owner")
+        .bind(("id", award))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete award query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete award query error", err_msg));
+    }
+
+    Ok(())
+}
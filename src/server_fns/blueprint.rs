@@ -0,0 +1,343 @@
+use leptos::prelude::*;
+use crate::blueprint::{Blueprint, BlueprintImportSummary};
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+/// **What is it?**
+/// A server function that exports the collection's zones, devices, climate goals and automation rules as a blueprint.
+///
+/// **Why does it exist?**
+/// It exists so a grower can replicate their environment setup on a second instance, or share a cabinet blueprint with another grower, without handing over plants, history or credentials.
+///
+/// **How should it be used?**
+/// Call from the blueprint section in settings and save the result as JSON. Credential fields in device and data source configs are removed; zones, devices and rules refer to each other by name.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn export_blueprint() -> Result<Blueprint, ServerFnError> {
+    use std::collections::HashMap;
+    use crate::auth::require_role;
+    use crate::blueprint::{strip_credentials, BlueprintDevice, BlueprintZone, BLUEPRINT_VERSION};
+    use crate::care_rules::RuleTrigger;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::care_rules::ssr_types::CareRuleDbRow;
+    use crate::server_fns::devices::ssr_types::HardwareDeviceDbRow;
+    use crate::server_fns::goals::ssr_types::ZoneGoalDbRow;
+    use crate::server_fns::zones::ssr_types::GrowingZoneDbRow;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT * FROM hardware_device WHERE owner = $owner ORDER BY created_at ASC; \
+             SELECT * FROM growing_zone WHERE owner = $owner ORDER BY sort_order ASC; \
+             SELECT * FROM zone_goal WHERE owner = $owner; \
+             SELECT * FROM care_rule WHERE owner = $owner ORDER BY created_at ASC;"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Blueprint export query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Blueprint export query error", err_msg));
+    }
+
+    let devices: Vec<HardwareDeviceDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Blueprint devices parse failed", e))?;
+    let zones: Vec<GrowingZoneDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Blueprint zones parse failed", e))?;
+    let goals: Vec<ZoneGoalDbRow> = resp.take(2)
+        .map_err(|e| internal_error("Blueprint goals parse failed", e))?;
+    let rules: Vec<CareRuleDbRow> = resp.take(3)
+        .map_err(|e| internal_error("Blueprint rules parse failed", e))?;
+
+    let devices: Vec<_> = devices.into_iter().map(|r| r.into_hardware_device()).collect();
+    let device_names: HashMap<String, String> = devices.iter()
+        .map(|d| (d.id.clone(), d.name.clone()))
+        .collect();
+    let mut goals: HashMap<String, crate::goals::ZoneGoal> = goals.into_iter()
+        .map(|r| r.into_zone_goal())
+        .map(|g| (g.zone_id.clone(), g))
+        .collect();
+
+    let zones: Vec<_> = zones.into_iter().map(|r| r.into_growing_zone()).collect();
+    let zone_names: HashMap<String, String> = zones.iter()
+        .map(|z| (z.id.clone(), z.name.clone()))
+        .collect();
+
+    let rules = rules.into_iter()
+        .filter_map(|r| r.into_care_rule())
+        // Plants aren't part of a blueprint, so neither are rules that watch one
+        .filter(|rule| !matches!(rule.trigger, RuleTrigger::Climate { orchid_id: Some(_), .. }))
+        .map(|mut rule| {
+            rule.id = String::new();
+            rule.last_fired_at = None;
+            if let RuleTrigger::Climate { ref mut zone_id, .. } = rule.trigger {
+                *zone_id = zone_id.as_ref().and_then(|id| zone_names.get(id).cloned());
+            }
+            rule
+        })
+        .collect();
+
+    Ok(Blueprint {
+        version: BLUEPRINT_VERSION,
+        exported_at: chrono::Utc::now(),
+        devices: devices.into_iter().map(|d| BlueprintDevice {
+            config: strip_credentials(&d.config),
+            name: d.name,
+            device_type: d.device_type,
+        }).collect(),
+        zones: zones.into_iter().map(|z| BlueprintZone {
+            goal: goals.remove(&z.id).map(|g| crate::goals::ZoneGoal { zone_id: String::new(), ..g }),
+            device: z.hardware_device_id.as_ref().and_then(|id| device_names.get(id).cloned()),
+            data_source_config: strip_credentials(&z.data_source_config),
+            name: z.name,
+            light_level: z.light_level,
+            location_type: z.location_type,
+            temperature_range: z.temperature_range,
+            humidity: z.humidity,
+            description: z.description,
+            sort_order: z.sort_order,
+            data_source_type: z.data_source_type,
+            hardware_port: z.hardware_port,
+        }).collect(),
+        rules,
+    })
+}
+
+/// **What is it?**
+/// A server function that recreates a blueprint's devices, zones, climate goals and automation rules in the collection.
+///
+/// **Why does it exist?**
+/// It exists to set up a new instance or a friend's cabinet from an exported blueprint instead of configuring everything by hand.
+///
+/// **How should it be used?**
+/// Call from the blueprint section in settings with a blueprint parsed by `Blueprint::parse`. Items whose name already exists are kept as they are and reported in `warnings`; devices are created without credentials, which must be re-entered before they can be polled.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn import_blueprint(
+    /// The blueprint to import.
+    blueprint: Blueprint,
+) -> Result<BlueprintImportSummary, ServerFnError> {
+    use std::collections::HashMap;
+    use surrealdb::types::{RecordId, SurrealValue};
+    use crate::auth::require_role;
+    use crate::care_rules::{RuleTrigger, MAX_RULES};
+    use crate::crypto::encrypt;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct NamedRow {
+        id: RecordId,
+        name: String,
+    }
+
+    blueprint.validate().map_err(ServerFnError::new)?;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT id, name FROM hardware_device WHERE owner = $owner; \
+             SELECT id, name FROM growing_zone WHERE owner = $owner; \
+             SELECT VALUE id FROM care_rule WHERE owner = $owner;"
+        )
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Blueprint existing items query failed", e))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Blueprint existing items query error", err_msg));
+    }
+    let existing_devices: Vec<NamedRow> = resp.take(0)
+        .map_err(|e| internal_error("Blueprint existing devices parse failed", e))?;
+    let existing_zones: Vec<NamedRow> = resp.take(1)
+        .map_err(|e| internal_error("Blueprint existing zones parse failed", e))?;
+    let existing_rules: Vec<RecordId> = resp.take(2)
+        .map_err(|e| internal_error("Blueprint existing rules parse failed", e))?;
+
+    let mut device_ids: HashMap<String, RecordId> = existing_devices.into_iter().map(|r| (r.name, r.id)).collect();
+    let mut zone_ids: HashMap<String, RecordId> = existing_zones.into_iter().map(|r| (r.name, r.id)).collect();
+    let mut summary = BlueprintImportSummary::default();
+
+    let encrypt_config = |config: &str| -> Result<String, ServerFnError> {
+        if config.is_empty() {
+            Ok(String::new())
+        } else {
+            encrypt(config).map_err(|e| internal_error("Encrypt config failed", e))
+        }
+    };
+
+    for device in &blueprint.devices {
+        if device_ids.contains_key(&device.name) {
+            summary.warnings.push(format!("Device \"{}\" already exists; kept yours", device.name));
+            continue;
+        }
+        if device.name.is_empty() || device.name.len() > 100 || !["tempest", "ac_infinity", "mqtt", "sensorpush"].contains(&device.device_type.as_str()) {
+            summary.warnings.push(format!("Skipped device \"{}\": unsupported name or type", device.name));
+            continue;
+        }
+        let mut r = db()
+            .query("CREATE hardware_device SET owner = $owner, name = $name, device_type = $device_type, config = $config RETURN VALUE id")
+            .bind(("owner", owner.clone()))
+            .bind(("name", device.name.clone()))
+            .bind(("device_type", device.device_type.clone()))
+            .bind(("config", encrypt_config(&device.config)?))
+            .await
+            .map_err(|e| internal_error("Blueprint device create failed", e))?;
+        let errors = r.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::warn!("Blueprint device \"{}\" create error: {}", device.name, err_msg);
+            summary.warnings.push(format!("Couldn't create device \"{}\"", device.name));
+            continue;
+        }
+        let created: Option<RecordId> = r.take(0)
+            .map_err(|e| internal_error("Blueprint device create parse failed", e))?;
+        let Some(id) = created else {
+            summary.warnings.push(format!("Couldn't create device \"{}\"", device.name));
+            continue;
+        };
+        device_ids.insert(device.name.clone(), id);
+        summary.devices_created += 1;
+    }
+    if summary.devices_created > 0 {
+        summary.warnings.push("Re-enter the login or API token for imported devices before they can be polled".into());
+    }
+
+    for zone in &blueprint.zones {
+        if zone_ids.contains_key(&zone.name) {
+            summary.warnings.push(format!("Zone \"{}\" already exists; kept yours and its goal", zone.name));
+            continue;
+        }
+        if zone.name.is_empty() || zone.name.len() > 100
+            || zone.temperature_range.len() > 100 || zone.humidity.len() > 100 || zone.description.len() > 500
+        {
+            summary.warnings.push(format!("Skipped zone \"{}\": a field is too long", zone.name));
+            continue;
+        }
+        let device = zone.device.as_ref().and_then(|name| device_ids.get(name).cloned());
+        let mut r = db()
+            .query(
+                "CREATE growing_zone SET \
+                 owner = $owner, name = $name, light_level = $light_level, \
+                 location_type = $location_type, temperature_range = $temp_range, \
+                 humidity = $humidity, description = $description, sort_order = $sort_order, \
+                 data_source_type = $source_type, data_source_config = $source_config, \
+                 hardware_device = $device, hardware_port = $port \
+                 RETURN VALUE id"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("name", zone.name.clone()))
+            .bind(("light_level", zone.light_level.as_str().to_string()))
+            .bind(("location_type", zone.location_type.to_string()))
+            .bind(("temp_range", zone.temperature_range.clone()))
+            .bind(("humidity", zone.humidity.clone()))
+            .bind(("description", zone.description.clone()))
+            .bind(("sort_order", zone.sort_order as i64))
+            .bind(("source_type", zone.data_source_type.clone()))
+            .bind(("source_config", encrypt_config(&zone.data_source_config)?))
+            .bind(("device", device.clone()))
+            .bind(("port", if device.is_some() { zone.hardware_port.map(|p| p as i64) } else { None }))
+            .await
+            .map_err(|e| internal_error("Blueprint zone create failed", e))?;
+        let errors = r.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::warn!("Blueprint zone \"{}\" create error: {}", zone.name, err_msg);
+            summary.warnings.push(format!("Couldn't create zone \"{}\"", zone.name));
+            continue;
+        }
+        let created: Option<RecordId> = r.take(0)
+            .map_err(|e| internal_error("Blueprint zone create parse failed", e))?;
+        let Some(zone_id) = created else {
+            summary.warnings.push(format!("Couldn't create zone \"{}\"", zone.name));
+            continue;
+        };
+        zone_ids.insert(zone.name.clone(), zone_id.clone());
+        summary.zones_created += 1;
+        crate::server_fns::zones::invalidate_zones(&owner_id);
+
+        let Some(goal) = zone.goal.as_ref().filter(|g| !g.is_empty()) else { continue };
+        if let Err(e) = goal.validate() {
+            summary.warnings.push(format!("Skipped goal for \"{}\": {}", zone.name, e));
+            continue;
+        }
+        let mut r = db()
+            .query(
+                "CREATE zone_goal SET owner = $owner, zone = $zone, temp_min_c = $tmin, temp_max_c = $tmax, \
+                 humidity_min = $hmin, humidity_max = $hmax"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("zone", zone_id))
+            .bind(("tmin", goal.temp_min_c))
+            .bind(("tmax", goal.temp_max_c))
+            .bind(("hmin", goal.humidity_min))
+            .bind(("hmax", goal.humidity_max))
+            .await
+            .map_err(|e| internal_error("Blueprint goal create failed", e))?;
+        let errors = r.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::warn!("Blueprint goal for \"{}\" create error: {}", zone.name, err_msg);
+            summary.warnings.push(format!("Couldn't set the goal for \"{}\"", zone.name));
+            continue;
+        }
+        summary.goals_set += 1;
+    }
+
+    let mut rule_count = existing_rules.len();
+    for rule in &blueprint.rules {
+        if rule_count >= MAX_RULES {
+            summary.warnings.push(format!("Skipped rule \"{}\": you can have at most {} rules", rule.name, MAX_RULES));
+            continue;
+        }
+        let mut rule = rule.clone();
+        if let RuleTrigger::Climate { ref mut zone_id, .. } = rule.trigger {
+            *zone_id = zone_id.as_ref().and_then(|name| zone_ids.get(name)).map(record_id_to_string);
+        }
+        if let Err(e) = rule.validate() {
+            summary.warnings.push(format!("Skipped rule \"{}\": {}", rule.name, e));
+            continue;
+        }
+        let trigger = serde_json::to_string(&rule.trigger)
+            .map_err(|e| internal_error("Rule trigger serialize failed", e))?;
+        let actions = serde_json::to_string(&rule.actions)
+            .map_err(|e| internal_error("Rule actions serialize failed", e))?;
+        let mut r = db()
+            .query("CREATE care_rule SET owner = $owner, name = $name, enabled = $enabled, trigger = $trigger, actions = $actions")
+            .bind(("owner", owner.clone()))
+            .bind(("name", rule.name.trim().to_string()))
+            .bind(("enabled", rule.enabled))
+            .bind(("trigger", trigger))
+            .bind(("actions", actions))
+            .await
+            .map_err(|e| internal_error("Blueprint rule create failed", e))?;
+        let errors = r.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::warn!("Blueprint rule \"{}\" create error: {}", rule.name, err_msg);
+            summary.warnings.push(format!("Couldn't create rule \"{}\"", rule.name));
+            continue;
+        }
+        summary.rules_created += 1;
+        rule_count += 1;
+    }
+
+    tracing::info!(
+        "Blueprint import: {} devices, {} zones, {} goals, {} rules",
+        summary.devices_created, summary.zones_created, summary.goals_set, summary.rules_created
+    );
+    Ok(summary)
+}
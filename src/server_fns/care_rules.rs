@@ -0,0 +1,241 @@
+use leptos::prelude::*;
+use crate::care_rules::CareRule;
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+#[cfg(feature = "ssr")]
+pub(crate) mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::care_rules::CareRule;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct CareRuleDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub owner: surrealdb::types::RecordId,
+        pub name: String,
+        pub enabled: bool,
+        pub trigger: String,
+        pub actions: String,
+        #[surreal(default)]
+        pub last_fired_at: Option<chrono::DateTime<chrono::Utc>>,
+    }
+
+    impl CareRuleDbRow {
+        /// Decodes the stored JSON; rows that no longer parse are skipped by callers.
+        pub fn into_care_rule(self) -> Option<CareRule> {
+            Some(CareRule {
+                id: record_id_to_string(&self.id),
+                name: self.name,
+                enabled: self.enabled,
+                trigger: serde_json::from_str(&self.trigger).ok()?,
+                actions: serde_json::from_str(&self.actions).ok()?,
+                last_fired_at: self.last_fired_at,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// **What is it?**
+/// A server function that returns the collection's care automation rules.
+///
+/// **Why does it exist?**
+/// It exists so the rule builder can list rules and the dashboard can tell which fertilizer reminders a rule has paused.
+///
+/// **How should it be used?**
+/// Call once when the home page loads and provide the result as context; the settings rule list reloads it after edits.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_care_rules() -> Result<Vec<CareRule>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT * FROM care_rule WHERE owner = $owner ORDER BY created_at ASC")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Care rules query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Care rules query error", err_msg));
+    }
+
+    let rows: Vec<CareRuleDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Care rules parse failed", e))?;
+    Ok(rows.into_iter().filter_map(|r| r.into_care_rule()).collect())
+}
+
+/// **What is it?**
+/// A server function that creates or updates a care automation rule.
+///
+/// **Why does it exist?**
+/// It exists to persist rules built in the settings rule builder, including enabling or disabling them.
+///
+/// **How should it be used?**
+/// Pass a rule with an empty `id` to create it, or an existing rule's `id` to replace it. The rule is validated and any watched zone or plant must belong to the collection. Returns the saved rule.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_care_rule(
+    /// The rule to save.
+    rule: CareRule,
+) -> Result<CareRule, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::care_rules::{RuleTrigger, MAX_RULES};
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    rule.validate().map_err(ServerFnError::new)?;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    if let RuleTrigger::Climate { zone_id: Some(ref zone_id), .. } = rule.trigger {
+        let zone = surrealdb::types::RecordId::parse_simple(zone_id)
+            .map_err(|_| ServerFnError::new("Invalid zone"))?;
+        let mut resp = db()
+            .query("SELECT VALUE id FROM growing_zone WHERE id = $zone AND owner = $owner")
+            .bind(("zone", zone))
+            .bind(("owner", owner.clone()))
+            .await
+            .map_err(|e| internal_error("Zone ownership query failed", e))?;
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Zone ownership query error", err_msg));
+        }
+        let found: Vec<surrealdb::types::RecordId> = resp.take(0)
+            .map_err(|e| internal_error("Zone ownership parse failed", e))?;
+        if found.is_empty() {
+            return Err(ServerFnError::new("Zone not found"));
+        }
+    }
+
+    if let RuleTrigger::Climate { orchid_id: Some(ref orchid_id), .. } = rule.trigger {
+        let orchid = surrealdb::types::RecordId::parse_simple(orchid_id)
+            .map_err(|_| ServerFnError::new("Invalid plant"))?;
+        let mut resp = db()
+            .query("SELECT VALUE id FROM orchid WHERE id = $orchid AND owner = $owner")
+            .bind(("orchid", orchid))
+            .bind(("owner", owner.clone()))
+            .await
+            .map_err(|e| internal_error("Plant ownership query failed", e))?;
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Plant ownership query error", err_msg));
+        }
+        let found: Vec<surrealdb::types::RecordId> = resp.take(0)
+            .map_err(|e| internal_error("Plant ownership parse failed", e))?;
+        if found.is_empty() {
+            return Err(ServerFnError::new("Plant not found"));
+        }
+    }
+
+    let trigger = serde_json::to_string(&rule.trigger)
+        .map_err(|e| internal_error("Rule trigger serialize failed", e))?;
+    let actions = serde_json::to_string(&rule.actions)
+        .map_err(|e| internal_error("Rule actions serialize failed", e))?;
+
+    let query = if rule.id.is_empty() {
+        let mut count_resp = db()
+            .query("SELECT VALUE id FROM care_rule WHERE owner = $owner")
+            .bind(("owner", owner.clone()))
+            .await
+            .map_err(|e| internal_error("Care rule count failed", e))?;
+        let errors = count_resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Care rule count query error", err_msg));
+        }
+        let existing: Vec<surrealdb::types::RecordId> = count_resp.take(0)
+            .map_err(|e| internal_error("Care rule count parse failed", e))?;
+        if existing.len() >= MAX_RULES {
+            return Err(ServerFnError::new(format!("You can have at most {} rules", MAX_RULES)));
+        }
+        "CREATE care_rule SET owner = $owner, name = $name, enabled = $enabled, trigger = $trigger, actions = $actions"
+    } else {
+        // Editing a rule restarts its cooldown so the new condition is evaluated fresh
+        "UPDATE $id SET name = $name, enabled = $enabled, trigger = $trigger, actions = $actions, last_fired_at = NONE WHERE owner = $owner"
+    };
+    let id = if rule.id.is_empty() {
+        None
+    } else {
+        Some(surrealdb::types::RecordId::parse_simple(&rule.id)
+            .map_err(|_| ServerFnError::new("Invalid rule"))?)
+    };
+
+    let mut resp = db()
+        .query(query)
+        .bind(("id", id))
+        .bind(("owner", owner))
+        .bind(("name", rule.name.trim().to_string()))
+        .bind(("enabled", rule.enabled))
+        .bind(("trigger", trigger))
+        .bind(("actions", actions))
+        .await
+        .map_err(|e| internal_error("Save care rule query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save care rule query error", err_msg));
+    }
+
+    let row: Option<CareRuleDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Save care rule parse failed", e))?;
+    row.and_then(|r| r.into_care_rule())
+        .ok_or_else(|| ServerFnError::new("Rule not found"))
+}
+
+/// **What is it?**
+/// A server function that deletes a care automation rule.
+///
+/// **Why does it exist?**
+/// It exists so users can remove rules they no longer want.
+///
+/// **How should it be used?**
+/// Call from the rule list in settings with the rule's ID.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_care_rule(
+    /// The rule's record ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let id = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|_| ServerFnError::new("Invalid rule"))?;
+
+    let mut resp = db()
+        .query("DELETE $id WHERE owner = $owner")
+        .bind(("id", id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete care rule query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete care rule query error", err_msg));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use leptos::prelude::*;
+use crate::care_timeline::ForecastDay;
+
+/// **What is it?**
+/// A server function that projects every plant's climate-adjusted watering and fertilizing due dates over the next two weeks.
+///
+/// **Why does it exist?**
+/// It exists so growers can plan trips around busy days. It runs on the server so the projection uses the same zone snapshots, forecast rain and hemisphere as alerts and push reminders.
+///
+/// **How should it be used?**
+/// Call from the browser with its UTC offset in minutes east of UTC, so "today" is the grower's local date. Returns `FORECAST_DAYS` days starting today.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_care_forecast(
+    /// The browser's offset from UTC, in minutes east.
+    utc_offset_minutes: i32,
+) -> Result<Vec<ForecastDay>, ServerFnError> {
+    use crate::care_timeline::build_forecast;
+    use crate::daily_extremes::local_offset;
+    use crate::orchid::Hemisphere;
+
+    let orchids = crate::server_fns::orchids::get_orchids().await?;
+    let snapshots = crate::server_fns::climate::get_all_zone_snapshots().await?;
+    let hemisphere = Hemisphere::from_code(&crate::server_fns::preferences::get_hemisphere().await?);
+    let today = chrono::Utc::now().with_timezone(&local_offset(utc_offset_minutes)).date_naive();
+
+    Ok(build_forecast(&orchids, &snapshots, &hemisphere, today))
+}
@@ -0,0 +1,272 @@
+use leptos::prelude::*;
+use crate::chat::ChatMessage;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::chat::{ChatMessage, ChatRole};
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct ChatMessageDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub role: String,
+        pub content: String,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    impl ChatMessageDbRow {
+        pub fn into_chat_message(self) -> ChatMessage {
+            ChatMessage {
+                id: record_id_to_string(&self.id),
+                role: ChatRole::from_str_lossy(&self.role),
+                content: self.content,
+                created_at: self.created_at,
+            }
+        }
+    }
+}
+
+/// Loads the signed-in person's latest `limit` chat messages, oldest first.
+#[cfg(feature = "ssr")]
+async fn load_history(user: &surrealdb::types::RecordId, limit: usize) -> Result<Vec<ChatMessage>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+    use ssr_types::ChatMessageDbRow;
+
+    let mut resp = db()
+        .query("SELECT id, role, content, created_at FROM chat_message WHERE owner = $owner ORDER BY created_at DESC LIMIT $limit")
+        .bind(("owner", user.clone()))
+        .bind(("limit", limit as i64))
+        .await
+        .map_err(|e| internal_error("Chat history query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Chat history query error", err_msg));
+    }
+
+    let rows: Vec<ChatMessageDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Chat history parse failed", e))?;
+    Ok(rows.into_iter().rev().map(ChatMessageDbRow::into_chat_message).collect())
+}
+
+/// One plant's profile and recent journal, for the assistant's context.
+#[cfg(feature = "ssr")]
+async fn plant_context(orchid: &crate::orchid::Orchid) -> Result<String, ServerFnError> {
+    use crate::server_fns::orchids::get_log_entries;
+    use crate::taxonomy::species_dataset;
+
+    let mut out = format!(
+        "## {} ({})\nZone: {}. Light: {}. Watered about every {} days. Temperature range: {}.",
+        orchid.name,
+        orchid.species,
+        orchid.placement,
+        orchid.light_requirement,
+        orchid.water_frequency_days,
+        if orchid.temperature_range.is_empty() { "not set" } else { orchid.temperature_range.as_str() },
+    );
+    if let Some(medium) = &orchid.pot_medium {
+        out.push_str(&format!(" Medium: {}.", medium));
+    }
+    if !orchid.notes.trim().is_empty() {
+        out.push_str(&format!("\nGrower's notes: {}", orchid.notes.trim()));
+    }
+
+    let dataset = species_dataset();
+    if let Some(care) = dataset.genus_care(&orchid.species) {
+        out.push_str(&format!(
+            "\nTypical {} care: water every {} days, {}, {}-{}C, {}-{}% humidity.",
+            care.genus, care.water_frequency_days, care.light_requirement,
+            care.temp_min, care.temp_max, care.humidity_min, care.humidity_max,
+        ));
+    }
+    if let Some(region) = dataset.find(&orchid.species).and_then(|s| s.native_region.as_deref()) {
+        out.push_str(&format!("\nNative to: {}.", region));
+    }
+
+    let entries = get_log_entries(orchid.id.clone()).await?;
+    let recent: Vec<String> = entries.iter()
+        .take(15)
+        .map(|e| {
+            let conditions = e.climate.as_ref().map(|c| format!(" ({})", c.describe("C"))).unwrap_or_default();
+            format!(
+                "- {} {}: {}{}",
+                e.timestamp.format("%Y-%m-%d"),
+                e.event_type.as_deref().unwrap_or("Note"),
+                e.note.trim(),
+                conditions,
+            )
+        })
+        .collect();
+    if recent.is_empty() {
+        out.push_str("\nJournal: no entries yet.");
+    } else {
+        out.push_str(&format!("\nRecent journal, newest first:\n{}", recent.join("\n")));
+    }
+    Ok(out)
+}
+
+/// **What is it?**
+/// A server function that returns the signed-in person's conversation with the care assistant.
+///
+/// **Why does it exist?**
+/// It exists so the chat panel picks up where the grower left off, on any device.
+///
+/// **How should it be used?**
+/// Call it when the chat panel opens; messages come oldest first, at most `CHAT_HISTORY_LIMIT` of them.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_chat_history() -> Result<Vec<ChatMessage>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::chat::CHAT_HISTORY_LIMIT;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    let user_id = require_role(HouseholdRole::Viewer).await?.user_id;
+    load_history(&parse_owner(&user_id)?, CHAT_HISTORY_LIMIT).await
+}
+
+/// **What is it?**
+/// A server function that answers a care question using the grower's own collection: their plants, zone climate, journals and species profiles.
+///
+/// **Why does it exist?**
+/// It exists because generic advice ("don't overwater") rarely explains what is happening to *this* plant; with its watering history and
+/// the zone's humidity in front of it, the model can point at the actual cause.
+///
+/// **How should it be used?**
+/// Call this from the chat panel. Plants named in the question (by name, species or genus) get their journal and profile included;
+/// pass `orchid_id` to focus on one plant regardless. Both the question and the answer are saved to the person's history, and the
+/// answer is returned.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn send_chat_message(
+    /// The grower's question.
+    message: String,
+    /// A plant to focus on, e.g. when asking from its detail view.
+    orchid_id: Option<String>,
+) -> Result<ChatMessage, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::chat::{plants_mentioned, transcript, validate_question, CHAT_CONTEXT_MESSAGES, MAX_FOCUS_PLANTS};
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::rate_limit::{check_user, Expensive};
+    use crate::server_fns::climate::{get_climate_summary_for_scanner, parse_owner};
+    use crate::server_fns::orchids::get_orchids;
+    use ssr_types::ChatMessageDbRow;
+
+    let question = validate_question(&message).map_err(ServerFnError::new)?;
+    let user_id = require_role(HouseholdRole::Viewer).await?.user_id;
+    check_user(Expensive::Ai, &user_id).map_err(ServerFnError::new)?;
+    let user = parse_owner(&user_id)?;
+    let asked_at = chrono::Utc::now();
+
+    let history = load_history(&user, CHAT_CONTEXT_MESSAGES).await?;
+    let orchids = get_orchids().await?;
+    let climate = get_climate_summary_for_scanner().await?;
+
+    let overview: Vec<String> = orchids.iter()
+        .take(60)
+        .map(|o| {
+            let watered = o.last_watered_at
+                .map(|t| format!("last watered {} days ago", (asked_at - t).num_days()))
+                .unwrap_or_else(|| "never logged as watered".to_string());
+            format!("- {} ({}), zone '{}', water every {} days, {}", o.name, o.species, o.placement, o.water_frequency_days, watered)
+        })
+        .collect();
+
+    let mut focus: Vec<usize> = orchid_id.iter()
+        .filter_map(|id| orchids.iter().position(|o| &o.id == id))
+        .collect();
+    if focus.is_empty() {
+        focus = plants_mentioned(&question, orchids.iter().map(|o| (o.name.as_str(), o.species.as_str())));
+    }
+    let mut details = Vec::new();
+    for i in focus.into_iter().take(MAX_FOCUS_PLANTS) {
+        details.push(plant_context(&orchids[i]).await?);
+    }
+
+    let prompt = format!(
+        "You are the care assistant in an orchid collection tracker, talking to the grower who owns the collection below. \
+        Answer their latest question using their actual data: the plant's journal, its zone's current climate, and the species' needs. \
+        Point to specific entries or readings when they explain the problem, say plainly when the data doesn't show enough to be sure, \
+        and suggest what to check or log next. Keep answers short and practical, in plain text without markdown headings.\n\n\
+        # Current zone climate\n{}\n\n\
+        # Collection ({} plants)\n{}\n\n\
+        # Plants this question is about\n{}\n\n\
+        # Conversation so far\n{}\n\n\
+        Grower: {}\nAssistant:",
+        climate,
+        orchids.len(),
+        if overview.is_empty() { "No plants yet.".to_string() } else { overview.join("\n") },
+        if details.is_empty() { "None in particular.".to_string() } else { details.join("\n\n") },
+        if history.is_empty() { "(new conversation)".to_string() } else { transcript(&history) },
+        question,
+    );
+
+    let answer = crate::ai::text(&prompt).await
+        .map_err(|e| internal_error("AI chat call failed", e))?;
+    let answer = answer.trim().trim_start_matches("Assistant:").trim().to_string();
+    if answer.is_empty() {
+        return Err(ServerFnError::new("The assistant didn't answer; try again"));
+    }
+
+    let mut resp = db()
+        .query(
+            "CREATE chat_message SET owner = $owner, role = 'user', content = $question, created_at = $asked_at; \
+             CREATE chat_message SET owner = $owner, role = 'assistant', content = $answer, created_at = time::now() \
+             RETURN id, role, content, created_at;"
+        )
+        .bind(("owner", user))
+        .bind(("question", question))
+        .bind(("asked_at", asked_at))
+        .bind(("answer", answer))
+        .await
+        .map_err(|e| internal_error("Save chat query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save chat query error", err_msg));
+    }
+
+    let saved: Option<ChatMessageDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Save chat parse failed", e))?;
+    saved.map(ChatMessageDbRow::into_chat_message)
+        .ok_or_else(|| internal_error("Save chat failed", "No record returned"))
+}
+
+/// **What is it?**
+/// A server function that deletes the signed-in person's whole conversation with the care assistant.
+///
+/// **Why does it exist?**
+/// It exists so a grower can start fresh, since earlier messages are sent along with each new question.
+///
+/// **How should it be used?**
+/// Call this from the chat panel's "Clear" button.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn clear_chat_history() -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    let user_id = require_role(HouseholdRole::Viewer).await?.user_id;
+    let mut resp = db()
+        .query("DELETE chat_message WHERE owner = $owner")
+        .bind(("owner", parse_owner(&user_id)?))
+        .await
+        .map_err(|e| internal_error("Clear chat query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Clear chat query error", err_msg));
+    }
+    Ok(())
+}
@@ -12,51 +12,62 @@ use crate::orchid::{ClimateReading, HabitatWeather, HabitatWeatherSummary};
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_current_readings() -> Result<Vec<ClimateReading>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
-
-    // Get all zones for this user (includes wizard/manual readings too)
-    let mut zone_resp = db()
-        .query("SELECT id, name FROM growing_zone WHERE owner = $owner")
-        .bind(("owner", owner))
-        .await
-        .map_err(|e| internal_error("Get climate zones query failed", e))?;
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
 
-    let errors = zone_resp.take_errors();
-    if !errors.is_empty() {
-        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
-        return Err(internal_error("Get climate zones query error", err_msg));
-    }
-
-    let zones: Vec<ZoneIdRow> = zone_resp.take(0)
-        .map_err(|e| internal_error("Get climate zones parse failed", e))?;
+    // Every zone, including ones with only wizard/manual readings
+    let zones = crate::server_fns::zones::owner_zones(&owner_id).await?;
 
     let mut readings = Vec::new();
 
     for zone in &zones {
+        if let Some(cached) = LATEST_READINGS.get(&zone.id) {
+            readings.extend(cached);
+            continue;
+        }
+
+        let zone_record = surrealdb::types::RecordId::parse_simple(&zone.id)
+            .map_err(|e| internal_error("Zone ID parse failed", e))?;
         let mut resp = db()
             .query(
                 "SELECT * FROM climate_reading WHERE zone = $zone_id ORDER BY recorded_at DESC LIMIT 1"
             )
-            .bind(("zone_id", zone.id.clone()))
+            .bind(("zone_id", zone_record))
             .await
             .map_err(|e| internal_error("Get reading query failed", e))?;
 
-        let _ = resp.take_errors();
-
-        let reading: Option<ReadingDbRow> = resp.take(0).unwrap_or(None);
-        if let Some(row) = reading {
-            readings.push(row.into_climate_reading());
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Get reading query error", err_msg));
         }
+
+        let reading: Option<ReadingDbRow> = resp.take(0)
+            .map_err(|e| internal_error("Get reading parse failed", e))?;
+        let latest = reading.map(|row| row.into_climate_reading());
+        LATEST_READINGS.insert(zone.id.clone(), latest.clone());
+        readings.extend(latest);
     }
 
     Ok(readings)
 }
 
+/// Each zone's newest reading (`None` for a zone without any), keyed by zone ID, for
+/// `get_current_readings`. Everything that stores a reading calls `invalidate_latest_reading`.
+#[cfg(feature = "ssr")]
+static LATEST_READINGS: std::sync::LazyLock<crate::cache::TtlCache<String, Option<ClimateReading>>> =
+    std::sync::LazyLock::new(|| crate::cache::TtlCache::new(std::time::Duration::from_secs(2 * 60)));
+
+/// Drops a zone's cached newest reading after a reading is stored for it.
+#[cfg(feature = "ssr")]
+pub(crate) fn invalidate_latest_reading(zone_id: &str) {
+    LATEST_READINGS.invalidate(&zone_id.to_string());
+}
+
 /// **What is it?**
 /// A server function that retrieves the historical climate readings for a specific zone over the last specified number of hours.
 ///
@@ -64,7 +75,7 @@ pub async fn get_current_readings() -> Result<Vec<ClimateReading>, ServerFnError
 /// It exists to provide the time-series data necessary to render line charts showing temperature, humidity, and VPD trends over time.
 ///
 /// **How should it be used?**
-/// Call this from a climate dashboard component, passing the desired `zone_id` and the `hours` lookback period (e.g., 24 or 48) to plot the historical data points.
+/// Call this from a climate dashboard component, passing the desired `zone_id` and the `hours` lookback period (e.g., 24 or 48) to plot the historical data points. Windows reaching past the raw retention period are filled in with hourly/daily averages (`source` ends in "summary").
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_zone_history(
@@ -73,11 +84,12 @@ pub async fn get_zone_history(
     /// The number of hours of history to fetch.
     hours: u32
 ) -> Result<Vec<ClimateReading>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let _user_id = require_auth().await?;
+    require_role(HouseholdRole::Viewer).await?;
 
     let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
@@ -87,8 +99,8 @@ pub async fn get_zone_history(
         .query(
             "SELECT * FROM climate_reading WHERE zone = $zone_id AND recorded_at > time::now() - $duration ORDER BY recorded_at ASC"
         )
-        .bind(("zone_id", zone_record))
-        .bind(("duration", duration_str))
+        .bind(("zone_id", zone_record.clone()))
+        .bind(("duration", duration_str.clone()))
         .await
         .map_err(|e| internal_error("Get zone history query failed", e))?;
 
@@ -100,8 +112,31 @@ pub async fn get_zone_history(
 
     let rows: Vec<ReadingDbRow> = response.take(0)
         .map_err(|e| internal_error("Get zone history parse failed", e))?;
+    let mut readings: Vec<ClimateReading> = rows.into_iter().map(|r| r.into_climate_reading()).collect();
+
+    // Older than the raw retention window, only rolled-up summaries remain
+    if hours > crate::config::config().climate_raw_retention_days.saturating_mul(24) {
+        let mut summary_resp = db()
+            .query(
+                "SELECT * FROM climate_reading_summary WHERE zone = $zone_id AND period_start > time::now() - $duration ORDER BY period_start ASC"
+            )
+            .bind(("zone_id", zone_record))
+            .bind(("duration", duration_str))
+            .await
+            .map_err(|e| internal_error("Get zone history summary query failed", e))?;
+        let errors = summary_resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Get zone history summary query error", err_msg));
+        }
+        let summaries: Vec<ReadingSummaryDbRow> = summary_resp.take(0)
+            .map_err(|e| internal_error("Get zone history summary parse failed", e))?;
+        let mut merged: Vec<ClimateReading> = summaries.into_iter().map(|r| r.into_climate_reading()).collect();
+        merged.append(&mut readings);
+        readings = merged;
+    }
 
-    Ok(rows.into_iter().map(|r| r.into_climate_reading()).collect())
+    Ok(readings)
 }
 
 /// **What is it?**
@@ -237,17 +272,19 @@ pub async fn save_wizard_estimation(
     /// The estimated humidity percentage.
     humidity: f64,
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
-    use crate::climate::calculate_vpd;
+    use crate::climate::{calculate_dew_point, calculate_vpd};
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
 
     let vpd = calculate_vpd(temperature, humidity);
+    let dew_point = calculate_dew_point(temperature, humidity);
 
     // Create climate reading and update zone text fields atomically
     let temp_range = format!("{:.0}-{:.0}C", temperature - 2.0, temperature + 2.0);
@@ -259,7 +296,7 @@ pub async fn save_wizard_estimation(
              CREATE climate_reading SET \
                  zone = $zone_id, zone_name = $zone_name, \
                  temperature = $temp, humidity = $humidity, \
-                 vpd = $vpd, source = $source, recorded_at = time::now(); \
+                 vpd = $vpd, dew_point = $dew_point, source = $source, recorded_at = time::now(); \
              UPDATE $zone_id SET temperature_range = $temp_range, humidity = $hum WHERE owner = $owner; \
              COMMIT TRANSACTION;"
         )
@@ -268,6 +305,7 @@ pub async fn save_wizard_estimation(
         .bind(("temp", temperature))
         .bind(("humidity", humidity))
         .bind(("vpd", vpd))
+        .bind(("dew_point", dew_point))
         .bind(("source", "wizard".to_string()))
         .bind(("temp_range", temp_range))
         .bind(("hum", humidity_str))
@@ -281,6 +319,8 @@ pub async fn save_wizard_estimation(
         return Err(internal_error("Save wizard estimation error", err_msg));
     }
 
+    invalidate_latest_reading(&zone_id);
+    crate::server_fns::zones::invalidate_zones(&owner_id);
     Ok(())
 }
 
@@ -304,29 +344,32 @@ pub async fn log_manual_reading(
     /// The manually recorded humidity percentage.
     humidity: f64,
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
-    use crate::climate::calculate_vpd;
+    use crate::climate::{calculate_dew_point, calculate_vpd};
 
-    let _user_id = require_auth().await?;
+    require_role(HouseholdRole::Caretaker).await?;
     let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
 
     let vpd = calculate_vpd(temperature, humidity);
+    let dew_point = calculate_dew_point(temperature, humidity);
 
     let mut resp = db()
         .query(
             "CREATE climate_reading SET \
              zone = $zone_id, zone_name = $zone_name, \
              temperature = $temp, humidity = $humidity, \
-             vpd = $vpd, source = $source, recorded_at = time::now()"
+             vpd = $vpd, dew_point = $dew_point, source = $source, recorded_at = time::now()"
         )
         .bind(("zone_id", zone_record))
         .bind(("zone_name", zone_name))
         .bind(("temp", temperature))
         .bind(("humidity", humidity))
         .bind(("vpd", vpd))
+        .bind(("dew_point", dew_point))
         .bind(("source", "manual".to_string()))
         .await
         .map_err(|e| internal_error("Log manual reading failed", e))?;
@@ -337,6 +380,7 @@ pub async fn log_manual_reading(
         return Err(internal_error("Log manual reading error", err_msg));
     }
 
+    invalidate_latest_reading(&zone_id);
     Ok(())
 }
 
@@ -389,13 +433,14 @@ pub async fn configure_zone_data_source(
     /// The JSON configuration string for the data source.
     config_json: String,
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
     use crate::crypto::encrypt;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
 
@@ -422,6 +467,7 @@ pub async fn configure_zone_data_source(
         return Err(internal_error("Configure data source query error", err_msg));
     }
 
+    crate::server_fns::zones::invalidate_zones(&owner_id);
     Ok(())
 }
 
@@ -432,7 +478,7 @@ pub async fn configure_zone_data_source(
 /// It exists to provide a common, error-checked way to extract the authenticated user's ID for database constraints across the climate module.
 ///
 /// **How should it be used?**
-/// Call this inside server functions after `require_auth` to obtain the `RecordId` needed for the `owner` field in database queries.
+/// Call this inside server functions after `require_auth` (or `require_role`, passing its `owner_id`) to obtain the `RecordId` needed for the `owner` field in database queries.
 #[cfg(feature = "ssr")]
 pub(crate) fn parse_owner(user_id: &str) -> Result<surrealdb::types::RecordId, ServerFnError> {
     use crate::error::internal_error;
@@ -539,6 +585,119 @@ pub async fn get_habitat_history(
     Ok(rows.into_iter().map(|r| r.into_summary()).collect())
 }
 
+/// **What is it?**
+/// A server function that scores, month by month over the past year, how closely a plant's zone matched its native habitat.
+///
+/// **Why does it exist?**
+/// It exists because a single "vs your zone" reading says nothing about seasons; growers need to see which months drift (cold winter nights, dry summer air) to know when to adjust.
+///
+/// **How should it be used?**
+/// Call from the plant detail page for plants with native coordinates. Home figures come from the zone named by the plant's placement (screened raw readings plus rollups); habitat figures from the habitat weather poller. Months missing either side are left out, and a plant without coordinates or a matching zone gets an empty list.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_habitat_similarity(
+    /// The orchid record ID.
+    orchid_id: String,
+) -> Result<Vec<crate::habitat_similarity::MonthlySimilarity>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::habitat_similarity::{compare_monthly, ClimateSample};
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::orchids::ssr_types::OrchidDbRow;
+
+    #[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct ZoneRollupRow {
+        period_start: chrono::DateTime<chrono::Utc>,
+        avg_temperature: f64,
+        min_temperature: f64,
+        max_temperature: f64,
+        avg_humidity: f64,
+        sample_count: i64,
+    }
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let id = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("SELECT * FROM orchid WHERE id = $id AND owner = $owner")
+        .bind(("id", id))
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Habitat similarity orchid query failed", e))?;
+    let _ = resp.take_errors();
+    let orchid: Option<OrchidDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Habitat similarity orchid parse failed", e))?;
+    let Some(orchid) = orchid.map(|o| o.into_orchid()) else {
+        return Err(ServerFnError::new("Plant not found"));
+    };
+    let (Some(latitude), Some(longitude)) = (orchid.native_latitude, orchid.native_longitude) else {
+        return Ok(Vec::new());
+    };
+
+    // Round to 2 decimals to match poller grouping
+    let lat = (latitude * 100.0).round() / 100.0;
+    let lon = (longitude * 100.0).round() / 100.0;
+
+    let mut resp = db()
+        .query(
+            "LET $zones = (SELECT VALUE id FROM growing_zone WHERE owner = $owner AND name = $placement); \
+             SELECT * FROM climate_reading \
+                WHERE zone IN $zones AND recorded_at > time::now() - 365d ORDER BY recorded_at ASC; \
+             SELECT period_start, avg_temperature, min_temperature, max_temperature, avg_humidity, sample_count \
+                FROM climate_reading_summary WHERE zone IN $zones AND period_start > time::now() - 365d; \
+             SELECT period_type, period_start, avg_temperature, min_temperature, \
+                    max_temperature, avg_humidity, total_precipitation, sample_count \
+                FROM habitat_weather_summary \
+                WHERE latitude = $lat AND longitude = $lon AND period_start > time::now() - 365d; \
+             SELECT temperature, humidity, precipitation, recorded_at FROM habitat_weather \
+                WHERE latitude = $lat AND longitude = $lon;"
+        )
+        .bind(("owner", owner))
+        .bind(("placement", orchid.placement))
+        .bind(("lat", lat))
+        .bind(("lon", lon))
+        .await
+        .map_err(|e| internal_error("Habitat similarity query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Habitat similarity query error", err_msg));
+    }
+
+    let readings: Vec<ReadingDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Habitat similarity readings parse failed", e))?;
+    let rollups: Vec<ZoneRollupRow> = resp.take(2)
+        .map_err(|e| internal_error("Habitat similarity rollups parse failed", e))?;
+    let habitat_summaries: Vec<HabitatSummaryDbRow> = resp.take(3)
+        .map_err(|e| internal_error("Habitat similarity habitat summaries parse failed", e))?;
+    let habitat_raw: Vec<HabitatWeatherDbRow> = resp.take(4)
+        .map_err(|e| internal_error("Habitat similarity habitat weather parse failed", e))?;
+
+    // Screen raw readings so a faulty sensor doesn't skew the month; rollups are already averaged
+    let readings: Vec<_> = readings.into_iter().map(|r| r.into_climate_reading()).collect();
+    let screened = crate::climate::alerts::screen_readings(&readings);
+    let home: Vec<ClimateSample> = screened.kept.iter().map(ClimateSample::from)
+        .chain(rollups.into_iter().map(|r| ClimateSample {
+            at: r.period_start,
+            avg_temperature: r.avg_temperature,
+            min_temperature: r.min_temperature,
+            max_temperature: r.max_temperature,
+            avg_humidity: r.avg_humidity,
+            weight: r.sample_count.max(1) as f64,
+        }))
+        .collect();
+    let habitat: Vec<ClimateSample> = habitat_summaries.into_iter().map(|r| ClimateSample::from(&r.into_summary()))
+        .chain(habitat_raw.into_iter().map(|r| ClimateSample::from(&r.into_habitat_weather())))
+        .collect();
+
+    Ok(compare_monthly(&home, &habitat))
+}
+
 /// **What is it?**
 /// A server function that retrieves climate snapshots (aggregated 48-hour data) for all zones the current user owns.
 ///
@@ -546,21 +705,22 @@ pub async fn get_habitat_history(
 /// It exists to provide a high-level, performant overview of the recent environmental conditions across all of a user's growing locations at once, without running N separate queries.
 ///
 /// **How should it be used?**
-/// Call this from a dashboard component that needs to display aggregate metrics like average temperature, VPD, or humidity for all zones.
+/// Call this from a dashboard component that needs to display aggregate metrics like average temperature, VPD, or humidity for all zones. Outdoor zones fed by the weather API also carry the next 24 hours of forecast rain and the 48-hour forecast high (from the 30-minute forecast cache), so watering intervals stretch before a storm and `watering::forecast_hint` can warn of heat waves.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_all_zone_snapshots() -> Result<Vec<crate::watering::ClimateSnapshot>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
     use std::collections::HashMap;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
 
     // Get all zones for this user with their location type
     let mut zone_resp = db()
-        .query("SELECT id, name, location_type FROM growing_zone WHERE owner = $owner")
+        .query("SELECT id, name, location_type, data_source_type, data_source_config FROM growing_zone WHERE owner = $owner")
         .bind(("owner", owner))
         .await
         .map_err(|e| internal_error("Get zones for snapshots failed", e))?;
@@ -599,19 +759,318 @@ pub async fn get_all_zone_snapshots() -> Result<Vec<crate::watering::ClimateSnap
         (crate::server_fns::auth::record_id_to_string(&z.id), is_outdoor)
     }).collect();
 
+    // Weather API coordinates for outdoor zones, so forecast rain can count too
+    let forecast_locations: HashMap<String, crate::climate::poller::WeatherApiConfig> = zones.iter()
+        .filter(|z| z.location_type.as_deref() == Some("Outdoor") && z.data_source_type.as_deref() == Some("weather_api"))
+        .filter_map(|z| {
+            let config_str = crate::crypto::decrypt_or_raw(z.data_source_config.as_deref()?);
+            let config = serde_json::from_str(&config_str).ok()?;
+            Some((crate::server_fns::auth::record_id_to_string(&z.id), config))
+        })
+        .collect();
+    let client = reqwest::Client::new();
+
     // Build snapshots
     let mut snapshots = Vec::new();
     for (zone_id, readings) in &by_zone {
         let is_outdoor = zone_outdoor.get(zone_id).copied().unwrap_or(false);
         let zone_name = readings.first().map(|r| r.zone_name.as_str()).unwrap_or("Unknown");
-        if let Some(snap) = crate::watering::ClimateSnapshot::from_readings(zone_name, readings, is_outdoor) {
-            snapshots.push(snap);
+        // Spikes, impossible values and stuck runs would skew the averages the watering algorithm uses
+        let screened = crate::climate::alerts::screen_readings(readings);
+        let Some(mut snap) = crate::watering::ClimateSnapshot::from_readings(zone_name, &screened.kept, is_outdoor) else {
+            continue;
+        };
+        if let Some(config) = forecast_locations.get(zone_id) {
+            match crate::climate::open_meteo::fetch_forecast(&client, config.latitude, config.longitude).await {
+                Ok((fetched_at, hours)) => {
+                    let forecast = crate::forecast::ZoneForecast {
+                        zone_id: zone_id.clone(),
+                        zone_name: zone_name.to_string(),
+                        hours,
+                        fetched_at,
+                    };
+                    snap.forecast_precipitation_24h_mm = Some(forecast.precipitation_next(chrono::Utc::now(), crate::watering::FORECAST_RAIN_HOURS));
+                    snap.forecast_max_temp_c = forecast.temperature_range().map(|(_, high)| high);
+                }
+                Err(e) => tracing::warn!("Snapshots: forecast fetch failed for zone '{}': {}", zone_name, e),
+            }
         }
+        snapshots.push(snap);
     }
 
     Ok(snapshots)
 }
 
+/// **What is it?**
+/// A server function that returns each zone's min, max and average temperature and humidity for yesterday and today.
+///
+/// **Why does it exist?**
+/// It exists because the latest reading hides the overnight low that actually kills plants; the climate strip shows these ranges next to it.
+///
+/// **How should it be used?**
+/// Call from the browser with its UTC offset in minutes east of UTC (the negation of JavaScript's `getTimezoneOffset`), so days split at local midnight. Readings are screened for sensor faults first, so a single bad sample doesn't set the low.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zone_daily_extremes(
+    /// The browser's offset from UTC, in minutes east.
+    utc_offset_minutes: i32,
+) -> Result<Vec<crate::daily_extremes::ZoneDailyExtremes>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::daily_extremes::{local_offset, yesterday_start, zone_extremes};
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::record_id_to_string;
+    use std::collections::HashMap;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let now = chrono::Utc::now();
+    let offset = local_offset(utc_offset_minutes);
+
+    let mut resp = db()
+        .query(
+            "SELECT id, name FROM growing_zone WHERE owner = $owner ORDER BY sort_order ASC; \
+             SELECT * FROM climate_reading \
+                WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $owner) AND recorded_at >= $since \
+                ORDER BY recorded_at ASC;"
+        )
+        .bind(("owner", owner))
+        .bind(("since", yesterday_start(now, offset)))
+        .await
+        .map_err(|e| internal_error("Zone daily extremes query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Zone daily extremes query error", err_msg));
+    }
+
+    let zones: Vec<ZoneIdRow> = resp.take(0)
+        .map_err(|e| internal_error("Zone daily extremes zones parse failed", e))?;
+    let rows: Vec<ReadingDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Zone daily extremes parse failed", e))?;
+
+    let mut by_zone: HashMap<String, Vec<crate::orchid::ClimateReading>> = HashMap::new();
+    for row in rows {
+        let reading = row.into_climate_reading();
+        by_zone.entry(reading.zone_id.clone()).or_default().push(reading);
+    }
+
+    Ok(zones.into_iter()
+        .filter_map(|zone| {
+            let zone_id = record_id_to_string(&zone.id);
+            let readings = by_zone.get(&zone_id)?;
+            let screened = crate::climate::alerts::screen_readings(readings);
+            Some(zone_extremes(&zone_id, &screened.kept, now, offset))
+        })
+        .filter(|z| z.yesterday.is_some() || z.today.is_some())
+        .collect())
+}
+
+/// **What is it?**
+/// A server function that returns the next 48 hours of hourly temperature and precipitation for each outdoor zone with weather coordinates.
+///
+/// **Why does it exist?**
+/// It exists so growers can decide whether to water outdoor mounts tonight from the dashboard, without cross-referencing a separate weather app.
+///
+/// **How should it be used?**
+/// Call from the forecast card on the My Plants tab. Only outdoor zones whose data source is the weather API (and so have coordinates) are included; forecasts are cached server-side for 30 minutes per location, and a zone whose forecast can't be fetched is left out.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_outdoor_forecasts() -> Result<Vec<crate::forecast::ZoneForecast>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::climate::poller::WeatherApiConfig;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::forecast::ZoneForecast;
+
+    #[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct OutdoorZoneRow {
+        id: surrealdb::types::RecordId,
+        name: String,
+        #[surreal(default)]
+        data_source_config: String,
+    }
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT id, name, data_source_config FROM growing_zone \
+             WHERE owner = $owner AND location_type = 'Outdoor' AND data_source_type = 'weather_api' \
+             ORDER BY sort_order ASC"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get outdoor zones query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get outdoor zones query error", err_msg));
+    }
+
+    let zones: Vec<OutdoorZoneRow> = resp.take(0)
+        .map_err(|e| internal_error("Get outdoor zones parse failed", e))?;
+
+    let client = reqwest::Client::new();
+    let mut forecasts = Vec::new();
+    for zone in zones {
+        let config_str = crate::crypto::decrypt_or_raw(&zone.data_source_config);
+        let Ok(config) = serde_json::from_str::<WeatherApiConfig>(&config_str) else {
+            tracing::warn!("Forecast: bad weather_api config for zone '{}'", zone.name);
+            continue;
+        };
+        match crate::climate::open_meteo::fetch_forecast(&client, config.latitude, config.longitude).await {
+            Ok((fetched_at, hours)) => forecasts.push(ZoneForecast {
+                zone_id: crate::server_fns::auth::record_id_to_string(&zone.id),
+                zone_name: zone.name,
+                hours,
+                fetched_at,
+            }),
+            Err(e) => tracing::warn!("Forecast: fetch failed for zone '{}': {}", zone.name, e),
+        }
+    }
+
+    Ok(forecasts)
+}
+
+/// **What is it?**
+/// A server function that returns each zone's daily light integrals for the last `days` days, with the DLI band its plants want.
+///
+/// **Why does it exist?**
+/// It exists so growers can see whether a windowsill or tent delivers enough light over the whole day, not just at its brightest.
+///
+/// **How should it be used?**
+/// Call from the DLI card on the My Plants tab. Only zones with at least one recorded day are returned; days come from the daily `climate::dli` job.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zone_dli(
+    /// How many days back to include (1–60).
+    days: u32,
+) -> Result<Vec<crate::light::ZoneDli>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::light::{target_dli_range, zone_dli_band, DliDay, ZoneDli};
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::server_fns::orchids::ssr_types::OrchidDbRow;
+
+    #[derive(serde::Deserialize, surrealdb::types::SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct DliRow {
+        zone: surrealdb::types::RecordId,
+        date: String,
+        dli: f64,
+        #[surreal(default)]
+        estimated: bool,
+    }
+
+    let days = days.clamp(1, 60);
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let since = (chrono::Utc::now().date_naive() - chrono::Duration::days(days as i64)).to_string();
+
+    let mut resp = db()
+        .query(
+            "SELECT id, name FROM growing_zone WHERE owner = $owner ORDER BY sort_order ASC; \
+             SELECT zone, date, dli, estimated FROM zone_dli \
+                WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $owner) AND date >= $since \
+                ORDER BY date ASC; \
+             SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE;"
+        )
+        .bind(("owner", owner))
+        .bind(("since", since))
+        .await
+        .map_err(|e| internal_error("Zone DLI query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Zone DLI query error", err_msg));
+    }
+
+    let zones: Vec<ZoneIdRow> = resp.take(0)
+        .map_err(|e| internal_error("Zone DLI zones parse failed", e))?;
+    let rows: Vec<DliRow> = resp.take(1)
+        .map_err(|e| internal_error("Zone DLI parse failed", e))?;
+    let orchids: Vec<OrchidDbRow> = resp.take(2)
+        .map_err(|e| internal_error("Zone DLI plants parse failed", e))?;
+    let orchids: Vec<_> = orchids.into_iter().map(|o| o.into_orchid()).collect();
+
+    Ok(zones.into_iter()
+        .filter_map(|zone| {
+            let days: Vec<DliDay> = rows.iter()
+                .filter(|r| r.zone == zone.id)
+                .filter_map(|r| Some(DliDay {
+                    date: r.date.parse().ok()?,
+                    dli: r.dli,
+                    estimated: r.estimated,
+                }))
+                .collect();
+            if days.is_empty() {
+                return None;
+            }
+            let ranges: Vec<(f64, f64)> = orchids.iter()
+                .filter(|o| o.placement == zone.name)
+                .map(|o| target_dli_range(o.par_ppfd, &o.light_lux, &o.light_requirement))
+                .collect();
+            Some(ZoneDli {
+                zone_id: record_id_to_string(&zone.id),
+                zone_name: zone.name,
+                days,
+                target: zone_dli_band(&ranges),
+                plant_count: ranges.len(),
+            })
+        })
+        .collect())
+}
+
+/// **What is it?**
+/// A server function that exports the household's climate readings and rollups as Parquet files, zipped.
+///
+/// **Why does it exist?**
+/// It exists for growers who analyze years of telemetry in pandas, Polars or DuckDB, where the JSON data export is slow to load and loses column types.
+///
+/// **How should it be used?**
+/// Call from the "Download climate data" button in settings and hand the archive to the browser like `export_my_data`. The zip holds `{zone}/{year}/readings.parquet` and `{zone}/{year}/summaries.parquet`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn export_climate_parquet() -> Result<crate::server_fns::auth::DataExport, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::{zip_files, DataExport};
+    use base64::Engine;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let files = crate::climate::parquet_export::export_climate_parquet(owner).await
+        .map_err(|e| internal_error("Climate export failed", e))?;
+    if files.is_empty() {
+        return Err(ServerFnError::new("No climate readings to export yet"));
+    }
+
+    // Parquet pages are already Snappy-compressed
+    let archive = tokio::task::spawn_blocking(move || zip_files(files, |_| true))
+        .await
+        .map_err(|e| internal_error("Climate export task failed", e))?
+        .map_err(|e| internal_error("Climate export zip failed", e))?;
+
+    tracing::info!(bytes = archive.len(), "Climate Parquet export generated");
+
+    Ok(DataExport {
+        filename: format!("velamen-climate-{}.zip", chrono::Utc::now().format("%Y-%m-%d")),
+        zip_base64: base64::engine::general_purpose::STANDARD.encode(archive),
+    })
+}
+
 #[cfg(feature = "ssr")]
 pub(crate) mod ssr_types {
     use surrealdb::types::SurrealValue;
@@ -633,6 +1092,10 @@ pub(crate) mod ssr_types {
         pub name: String,
         #[surreal(default)]
         pub location_type: Option<String>,
+        #[surreal(default)]
+        pub data_source_type: Option<String>,
+        #[surreal(default)]
+        pub data_source_config: Option<String>,
     }
 
     #[derive(serde::Deserialize, SurrealValue)]
@@ -646,8 +1109,26 @@ pub(crate) mod ssr_types {
         #[surreal(default)]
         pub vpd: Option<f64>,
         #[surreal(default)]
+        pub dew_point: Option<f64>,
+        #[surreal(default)]
         pub precipitation: Option<f64>,
         #[surreal(default)]
+        pub uv_index: Option<f64>,
+        #[surreal(default)]
+        pub solar_radiation: Option<f64>,
+        #[surreal(default)]
+        pub wind_speed: Option<f64>,
+        #[surreal(default)]
+        pub wind_gust: Option<f64>,
+        #[surreal(default)]
+        pub lux: Option<f64>,
+        #[surreal(default)]
+        pub ppfd: Option<f64>,
+        #[surreal(default)]
+        pub co2_ppm: Option<f64>,
+        #[surreal(default)]
+        pub substrate_moisture_pct: Option<f64>,
+        #[surreal(default)]
         pub source: Option<String>,
         pub recorded_at: chrono::DateTime<chrono::Utc>,
     }
@@ -661,13 +1142,60 @@ pub(crate) mod ssr_types {
                 temperature: self.temperature,
                 humidity: self.humidity,
                 vpd: self.vpd,
+                dew_point: self.dew_point,
                 precipitation: self.precipitation,
+                uv_index: self.uv_index,
+                solar_radiation: self.solar_radiation,
+                wind_speed: self.wind_speed,
+                wind_gust: self.wind_gust,
+                lux: self.lux,
+                ppfd: self.ppfd,
+                co2_ppm: self.co2_ppm,
+                substrate_moisture_pct: self.substrate_moisture_pct,
                 source: self.source,
                 recorded_at: self.recorded_at,
             }
         }
     }
 
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct ReadingSummaryDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub zone: surrealdb::types::RecordId,
+        pub zone_name: String,
+        pub period_type: String,
+        pub period_start: chrono::DateTime<chrono::Utc>,
+        pub avg_temperature: f64,
+        pub avg_humidity: f64,
+    }
+
+    impl ReadingSummaryDbRow {
+        /// Presents an hourly or daily summary as a reading at the start of its period, so charts can plot it alongside raw data.
+        pub fn into_climate_reading(self) -> ClimateReading {
+            ClimateReading {
+                id: record_id_to_string(&self.id),
+                zone_id: record_id_to_string(&self.zone),
+                zone_name: self.zone_name,
+                temperature: self.avg_temperature,
+                humidity: self.avg_humidity,
+                vpd: None,
+                dew_point: None,
+                precipitation: None,
+                uv_index: None,
+                solar_radiation: None,
+                wind_speed: None,
+                wind_gust: None,
+                lux: None,
+                ppfd: None,
+                co2_ppm: None,
+                substrate_moisture_pct: None,
+                source: Some(format!("{} summary", self.period_type)),
+                recorded_at: self.period_start,
+            }
+        }
+    }
+
     #[derive(serde::Deserialize, SurrealValue)]
     #[surreal(crate = "surrealdb::types")]
     pub struct HabitatWeatherDbRow {
@@ -8,7 +8,7 @@ use crate::orchid::HardwareDevice;
 /// It exists to standardize error handling across the backend when extracting the authenticated user's ID for database constraints.
 ///
 /// **How should it be used?**
-/// Call this inside server functions after `require_auth` to obtain the `RecordId` needed for the `owner` field in database queries.
+/// Call this inside server functions after `require_auth` (or `require_role`, passing its `owner_id`) to obtain the `RecordId` needed for the `owner` field in database queries.
 #[cfg(feature = "ssr")]
 fn parse_owner(user_id: &str) -> Result<surrealdb::types::RecordId, ServerFnError> {
     use crate::error::internal_error;
@@ -17,7 +17,7 @@ fn parse_owner(user_id: &str) -> Result<surrealdb::types::RecordId, ServerFnErro
 }
 
 #[cfg(feature = "ssr")]
-mod ssr_types {
+pub(crate) mod ssr_types {
     use surrealdb::types::SurrealValue;
     use crate::orchid::HardwareDevice;
     use crate::server_fns::auth::record_id_to_string;
@@ -58,12 +58,13 @@ use ssr_types::*;
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_devices() -> Result<Vec<HardwareDevice>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
 
     let mut response = db()
         .query("SELECT * FROM hardware_device WHERE owner = $owner ORDER BY created_at ASC")
@@ -96,12 +97,13 @@ pub async fn get_devices() -> Result<Vec<HardwareDevice>, ServerFnError> {
 pub async fn create_device(
     /// The user-defined name for the device.
     name: String,
-    /// The type of the device (e.g., "tempest", "ac_infinity").
+    /// The type of the device (e.g., "tempest", "ac_infinity", "mqtt", "sensorpush").
     device_type: String,
     /// The JSON configuration string for the device.
     config_json: String,
 ) -> Result<HardwareDevice, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
     use crate::crypto::encrypt;
@@ -109,12 +111,12 @@ pub async fn create_device(
     if name.is_empty() || name.len() > 100 {
         return Err(ServerFnError::new("Device name must be 1-100 characters"));
     }
-    if !["tempest", "ac_infinity"].contains(&device_type.as_str()) {
-        return Err(ServerFnError::new("Device type must be 'tempest' or 'ac_infinity'"));
+    if !["tempest", "ac_infinity", "mqtt", "sensorpush"].contains(&device_type.as_str()) {
+        return Err(ServerFnError::new("Device type must be 'tempest', 'ac_infinity', 'mqtt' or 'sensorpush'"));
     }
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
 
     let stored_config = if config_json.is_empty() {
         config_json
@@ -167,7 +169,8 @@ pub async fn update_device(
     /// The new JSON configuration string.
     config_json: String,
 ) -> Result<HardwareDevice, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
     use crate::crypto::encrypt;
@@ -176,8 +179,8 @@ pub async fn update_device(
         return Err(ServerFnError::new("Device name must be 1-100 characters"));
     }
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let dev_id = surrealdb::types::RecordId::parse_simple(&device_id)
         .map_err(|e| internal_error("Device ID parse failed", e))?;
 
@@ -227,12 +230,13 @@ pub async fn delete_device(
     /// The unique identifier of the device to delete.
     device_id: String
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let dev_id = surrealdb::types::RecordId::parse_simple(&device_id)
         .map_err(|e| internal_error("Device ID parse failed", e))?;
 
@@ -256,6 +260,7 @@ pub async fn delete_device(
         return Err(internal_error("Delete device query error", err_msg));
     }
 
+    crate::server_fns::zones::invalidate_zones(&owner_id);
     Ok(())
 }
 
@@ -275,35 +280,79 @@ pub async fn test_device(
     /// The JSON configuration string for the device.
     config_json: String,
 ) -> Result<String, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
 
-    require_auth().await?;
+    require_role(HouseholdRole::Owner).await?;
 
+    probe_device(&device_type, &config_json).await
+}
+
+/// **What is it?**
+/// The live test fetch behind `test_device`: one reading per configured station, port or sensor, summarized.
+///
+/// **Why does it exist?**
+/// It exists so `Command::Doctor` can test-poll every saved device with the same checks (and messages) the device form uses, without a session.
+///
+/// **How should it be used?**
+/// Pass a device type and its decrypted JSON config; `Ok` carries the "Connected! ..." summary, `Err` says what failed. Authorize the caller first.
+#[cfg(feature = "ssr")]
+pub(crate) async fn probe_device(device_type: &str, config_json: &str) -> Result<String, ServerFnError> {
     let client = reqwest::Client::new();
 
-    match device_type.as_str() {
+    match device_type {
         "tempest" => {
             let config: crate::climate::poller::TempestConfig =
-                serde_json::from_str(&config_json)
+                serde_json::from_str(config_json)
                     .map_err(|e| ServerFnError::new(format!("Invalid Tempest config: {}", e)))?;
 
-            let reading = crate::climate::tempest::fetch_tempest_reading(
-                &client,
-                &config.station_id,
-                &config.token,
-            )
-            .await
-            .map_err(|e| ServerFnError::new(format!("Tempest connection failed: {}", e)))?;
+            let stations = config.stations();
+            if stations.is_empty() {
+                // No station entered yet: list what the token can see so the user can pick.
+                let available = crate::climate::tempest::fetch_tempest_stations(&client, &config.token)
+                    .await
+                    .map_err(|e| ServerFnError::new(format!("Tempest connection failed: {}", e)))?;
+                if available.is_empty() {
+                    return Err(ServerFnError::new("No Tempest stations found for this token"));
+                }
+                let list = available.iter()
+                    .map(|(id, name)| format!("{} ({})", id, name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(ServerFnError::new(format!("Enter a station ID. Stations on this account: {}", list)));
+            }
 
-            let vpd_str = reading.vpd_kpa.map(|v| format!(", {:.2} kPa VPD", v)).unwrap_or_default();
-            Ok(format!(
-                "Connected! Current: {:.1}C, {:.1}% Humidity{}",
-                reading.temperature_c, reading.humidity_pct, vpd_str
-            ))
+            let mut parts = Vec::with_capacity(stations.len());
+            for station_id in &stations {
+                let reading = crate::climate::tempest::fetch_tempest_reading(
+                    &client,
+                    station_id,
+                    &config.token,
+                )
+                .await
+                .map_err(|e| ServerFnError::new(format!("Tempest connection failed for station {}: {}", station_id, e)))?;
+
+                let vpd_str = reading.vpd_kpa.map(|v| format!(", {:.2} kPa VPD", v)).unwrap_or_default();
+                let uv_str = reading.uv_index.map(|v| format!(", UV {:.1}", v)).unwrap_or_default();
+                let wind_str = reading.wind_avg_ms.map(|v| format!(", wind {:.1} m/s", v)).unwrap_or_default();
+                parts.push(format!(
+                    "{:.1}C, {:.1}% Humidity{}{}{}",
+                    reading.temperature_c, reading.humidity_pct, vpd_str, uv_str, wind_str
+                ));
+            }
+
+            if parts.len() == 1 {
+                return Ok(format!("Connected! Current: {}", parts[0]));
+            }
+            let summary = stations.iter().zip(parts.iter()).enumerate()
+                .map(|(i, (id, p))| format!("#{} ({}): {}", i + 1, id, p))
+                .collect::<Vec<_>>()
+                .join("; ");
+            Ok(format!("Connected to {} stations! {}", stations.len(), summary))
         }
         "ac_infinity" => {
             let config: crate::climate::poller::AcInfinityConfig =
-                serde_json::from_str(&config_json)
+                serde_json::from_str(config_json)
                     .map_err(|e| ServerFnError::new(format!("Invalid AC Infinity config: {}", e)))?;
 
             let reading = crate::climate::ac_infinity::fetch_ac_infinity_reading(
@@ -322,10 +371,87 @@ pub async fn test_device(
                 reading.temperature_c, reading.humidity_pct, vpd_str
             ))
         }
+        "mqtt" => {
+            let config: crate::climate::mqtt::MqttConfig =
+                serde_json::from_str(config_json)
+                    .map_err(|e| ServerFnError::new(format!("Invalid MQTT config: {}", e)))?;
+
+            let (topic, reading) = crate::climate::mqtt::wait_for_reading(&config, std::time::Duration::from_secs(10))
+                .await
+                .map_err(|e| ServerFnError::new(format!("MQTT test failed: {}", e)))?;
+
+            let vpd_str = reading.vpd_kpa.map(|v| format!(", {:.2} kPa VPD", v)).unwrap_or_default();
+            Ok(format!(
+                "Connected! '{}': {:.1}C, {:.1}% Humidity{}",
+                topic, reading.temperature_c, reading.humidity_pct, vpd_str
+            ))
+        }
+        "sensorpush" => {
+            let config: crate::climate::sensorpush::SensorPushConfig =
+                serde_json::from_str(config_json)
+                    .map_err(|e| ServerFnError::new(format!("Invalid SensorPush config: {}", e)))?;
+
+            let sensor_ids = config.sensor_ids();
+            if sensor_ids.is_empty() {
+                return Err(ServerFnError::new("No sensors selected. Edit the device and use Find Sensors."));
+            }
+
+            let readings = crate::climate::sensorpush::fetch_sensorpush_readings(
+                &client,
+                &config.email,
+                &config.password,
+                &sensor_ids,
+            )
+            .await
+            .map_err(|e| ServerFnError::new(format!("SensorPush connection failed: {}", e)))?;
+
+            let summary = config.sensors.iter().enumerate()
+                .map(|(i, sensor)| {
+                    let label = if sensor.name.is_empty() { &sensor.id } else { &sensor.name };
+                    match readings.get(&sensor.id) {
+                        Some(r) => format!("#{} ({}): {:.1}C, {:.1}% Humidity", i + 1, label, r.temperature_c, r.humidity_pct),
+                        None => format!("#{} ({}): no recent sample", i + 1, label),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            Ok(format!("Connected! {}", summary))
+        }
         _ => Err(ServerFnError::new(format!("Unknown device type: {}", device_type))),
     }
 }
 
+/// **What is it?**
+/// A server function that lists the active sensors on a SensorPush account.
+///
+/// **Why does it exist?**
+/// It exists so users can choose sensors by name while setting up a SensorPush device, instead of looking up sensor IDs in the vendor's app.
+///
+/// **How should it be used?**
+/// Call this from the "Find Sensors" button in the device form with the entered login; save the chosen sensors in the device config's `sensors` list.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn discover_sensorpush_sensors(
+    /// SensorPush account email.
+    email: String,
+    /// SensorPush account password.
+    password: String,
+) -> Result<Vec<(String, String)>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    require_role(HouseholdRole::Owner).await?;
+
+    let client = reqwest::Client::new();
+    let sensors = crate::climate::sensorpush::fetch_sensorpush_sensors(&client, &email, &password)
+        .await
+        .map_err(|e| ServerFnError::new(format!("SensorPush connection failed: {}", e)))?;
+    if sensors.is_empty() {
+        return Err(ServerFnError::new("No active sensors found on this SensorPush account"));
+    }
+    Ok(sensors.into_iter().map(|s| (s.id, s.name)).collect())
+}
+
 /// **What is it?**
 /// A server function that assigns a growing zone to pull its climate data from a specific shared hardware device.
 ///
@@ -344,12 +470,13 @@ pub async fn link_zone_to_device(
     /// The specific port or sensor on the device, if applicable.
     port: Option<i32>,
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
     let dev_record = surrealdb::types::RecordId::parse_simple(&device_id)
@@ -376,6 +503,7 @@ pub async fn link_zone_to_device(
         return Err(internal_error("Link zone to device query error", err_msg));
     }
 
+    crate::server_fns::zones::invalidate_zones(&owner_id);
     Ok(())
 }
 
@@ -393,12 +521,13 @@ pub async fn unlink_zone_from_device(
     /// The unique identifier of the zone.
     zone_id: String
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
 
@@ -419,5 +548,6 @@ pub async fn unlink_zone_from_device(
         return Err(internal_error("Unlink zone from device query error", err_msg));
     }
 
+    crate::server_fns::zones::invalidate_zones(&owner_id);
     Ok(())
 }
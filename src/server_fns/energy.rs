@@ -0,0 +1,164 @@
+use leptos::prelude::*;
+use crate::energy::{ZoneEconomics, ZoneEnergyProfile};
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+#[cfg(feature = "ssr")]
+pub(crate) mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::energy::ZoneEnergyProfile;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct ZoneEnergyDbRow {
+        pub zone: surrealdb::types::RecordId,
+        #[surreal(default)]
+        pub zone_name: Option<String>,
+        #[surreal(default)]
+        pub price_per_kwh: Option<f64>,
+        pub devices: String,
+    }
+
+    impl ZoneEnergyDbRow {
+        /// Converts the row, dropping the device list if its JSON no longer parses.
+        pub fn into_profile(self) -> ZoneEnergyProfile {
+            ZoneEnergyProfile {
+                zone_id: record_id_to_string(&self.zone),
+                price_per_kwh: self.price_per_kwh,
+                devices: serde_json::from_str(&self.devices).unwrap_or_default(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// Loads every energy profile for an owner, with the zone names, sorted by zone name.
+#[cfg(feature = "ssr")]
+async fn load_energy_rows(owner: surrealdb::types::RecordId) -> Result<Vec<ZoneEnergyDbRow>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut resp = db()
+        .query(
+            "SELECT zone, zone.name AS zone_name, price_per_kwh, devices \
+             FROM zone_energy WHERE owner = $owner ORDER BY zone_name ASC"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Zone energy query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Zone energy query error", err_msg));
+    }
+
+    resp.take(0).map_err(|e| internal_error("Zone energy parse failed", e))
+}
+
+/// **What is it?**
+/// A server function that returns the current user's electricity price and device list for each zone that has one.
+///
+/// **Why does it exist?**
+/// It exists to pre-fill the energy editor in settings.
+///
+/// **How should it be used?**
+/// Call when opening the energy section; zones without an entry have no devices listed.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zone_energy_profiles() -> Result<Vec<ZoneEnergyProfile>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let rows = load_energy_rows(owner).await?;
+    Ok(rows.into_iter().map(|r| r.into_profile()).collect())
+}
+
+/// **What is it?**
+/// A server function that sets (or clears) the electricity price and powered devices for one of the user's zones.
+///
+/// **Why does it exist?**
+/// It exists so growers can describe each zone's equipment once and see its running cost from then on.
+///
+/// **How should it be used?**
+/// Call from the energy editor. Passing a profile with no devices removes it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_zone_energy_profile(
+    /// The profile to store; an empty device list deletes it.
+    profile: ZoneEnergyProfile,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    profile.validate().map_err(ServerFnError::new)?;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let zone = surrealdb::types::RecordId::parse_simple(&profile.zone_id)
+        .map_err(|e| internal_error("Zone ID parse failed", e))?;
+    let devices = serde_json::to_string(&profile.devices)
+        .map_err(|e| internal_error("Energy devices serialize failed", e))?;
+
+    let query = if profile.is_empty() {
+        "DELETE zone_energy WHERE zone = $zone AND owner = $owner"
+    } else {
+        "IF (SELECT VALUE id FROM growing_zone WHERE id = $zone AND owner = $owner) = [] { THROW 'Zone not found' }; \
+         DELETE zone_energy WHERE zone = $zone AND owner = $owner; \
+         CREATE zone_energy SET owner = $owner, zone = $zone, price_per_kwh = $price, \
+            devices = $devices, updated_at = time::now();"
+    };
+
+    let mut resp = db()
+        .query(query)
+        .bind(("owner", owner))
+        .bind(("zone", zone))
+        .bind(("price", profile.price_per_kwh))
+        .bind(("devices", devices))
+        .await
+        .map_err(|e| internal_error("Save zone energy query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save zone energy query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that estimates the monthly running cost of each zone with devices listed.
+///
+/// **Why does it exist?**
+/// It exists to feed the zone economics cards, so the cost of a tent or greenhouse sits next to its climate.
+///
+/// **How should it be used?**
+/// Call from the dashboard; an empty list means no zone has devices listed.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zone_economics() -> Result<Vec<ZoneEconomics>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::energy::estimate_monthly;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let rows = load_energy_rows(owner).await?;
+
+    Ok(rows.into_iter()
+        .map(|r| {
+            let name = r.zone_name.clone().unwrap_or_default();
+            estimate_monthly(&r.into_profile(), &name)
+        })
+        .filter(|e| !e.devices.is_empty())
+        .collect())
+}
@@ -0,0 +1,287 @@
+use leptos::prelude::*;
+use crate::orchid::EventTypeDef;
+
+/// Most custom event types one collection can define.
+#[cfg(feature = "ssr")]
+const MAX_CUSTOM_EVENT_TYPES: usize = 50;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::orchid::EventTypeDef;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct EventTypeDbRow {
+        pub key: String,
+        pub label: String,
+        pub emoji: String,
+        #[surreal(default)]
+        pub color: String,
+        #[surreal(default)]
+        pub quick_action: bool,
+        #[surreal(default)]
+        pub owner: Option<surrealdb::types::RecordId>,
+    }
+
+    impl EventTypeDbRow {
+        pub fn into_event_type(self) -> EventTypeDef {
+            EventTypeDef {
+                key: self.key,
+                label: self.label,
+                emoji: self.emoji,
+                color: self.color,
+                quick_action: self.quick_action,
+                builtin: self.owner.is_none(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// **What is it?**
+/// An SSR-only helper that checks whether an event type key is valid for a user.
+///
+/// **Why does it exist?**
+/// It exists because log entries may now use custom event types, so validation has to consult the registry instead of a fixed list.
+///
+/// **How should it be used?**
+/// Call this before writing a log entry or saving a preference that references an event type key.
+#[cfg(feature = "ssr")]
+pub(crate) async fn is_known_event_type(
+    owner: &surrealdb::types::RecordId,
+    key: &str,
+) -> Result<bool, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if crate::components::event_types::get_event_info(key).is_some() {
+        return Ok(true);
+    }
+
+    let mut resp = db()
+        .query("SELECT VALUE key FROM event_type WHERE owner = $owner AND key = $key LIMIT 1")
+        .bind(("owner", owner.clone()))
+        .bind(("key", key.to_string()))
+        .await
+        .map_err(|e| internal_error("Event type lookup query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Event type lookup query error", err_msg));
+    }
+
+    let keys: Vec<String> = resp.take(0)
+        .map_err(|e| internal_error("Event type lookup parse failed", e))?;
+    Ok(!keys.is_empty())
+}
+
+/// **What is it?**
+/// An SSR-only helper that lists every event type key a user may log: built-ins plus their custom types.
+///
+/// **Why does it exist?**
+/// It exists for validating lists of keys (like quick action preferences) in a single query.
+///
+/// **How should it be used?**
+/// Call this before persisting anything that references several event type keys.
+#[cfg(feature = "ssr")]
+pub(crate) async fn known_event_type_keys(
+    owner: &surrealdb::types::RecordId,
+) -> Result<Vec<String>, ServerFnError> {
+    use crate::components::event_types::ALLOWED_EVENT_TYPE_KEYS;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut resp = db()
+        .query("SELECT VALUE key FROM event_type WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Event type keys query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Event type keys query error", err_msg));
+    }
+
+    let custom: Vec<String> = resp.take(0)
+        .map_err(|e| internal_error("Event type keys parse failed", e))?;
+    Ok(ALLOWED_EVENT_TYPE_KEYS.iter().map(|k| k.to_string()).chain(custom).collect())
+}
+
+/// **What is it?**
+/// A server function that returns every event type available to the current user: the built-in defaults followed by their custom types.
+///
+/// **Why does it exist?**
+/// It exists so the timeline, event picker, gallery, and quick actions all render icons and colors from one per-user registry.
+///
+/// **How should it be used?**
+/// Call once on page load and provide the result as `EventTypeRegistry` context. Falls back to the compiled-in defaults if the registry table is empty.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_event_types() -> Result<Vec<EventTypeDef>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::components::event_types::builtin_event_types;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT key, label, emoji, color, quick_action, owner, sort_order, created_at FROM event_type \
+             WHERE owner IS NONE OR owner = $owner \
+             ORDER BY sort_order ASC, created_at ASC"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get event types query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get event types query error", err_msg));
+    }
+
+    let rows: Vec<EventTypeDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get event types parse failed", e))?;
+
+    if rows.is_empty() {
+        return Ok(builtin_event_types());
+    }
+    Ok(rows.into_iter().map(|r| r.into_event_type()).collect())
+}
+
+/// **What is it?**
+/// A server function that adds a custom event type (e.g., "Pollinated", "Pest Sighting") to the current user's registry.
+///
+/// **Why does it exist?**
+/// It exists so growers can track breeding, pest, and other routines that the built-in categories don't cover, with their own icon and color.
+///
+/// **How should it be used?**
+/// Call this from the event type manager in settings. The key is derived from the label and must not collide with a built-in or existing type.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_event_type(
+    /// Human-readable label.
+    label: String,
+    /// Emoji icon.
+    emoji: String,
+    /// Palette color name.
+    color: String,
+) -> Result<EventTypeDef, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::components::event_types::{event_type_key_from_label, EVENT_COLORS};
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let label = label.trim().to_string();
+    let emoji = emoji.trim().to_string();
+    if label.is_empty() || label.chars().count() > 40 {
+        return Err(ServerFnError::new("Label must be between 1 and 40 characters"));
+    }
+    if emoji.is_empty() || emoji.chars().count() > 8 {
+        return Err(ServerFnError::new("Choose a single emoji icon"));
+    }
+    if !EVENT_COLORS.iter().any(|(name, _, _)| *name == color) {
+        return Err(ServerFnError::new("Unknown color"));
+    }
+    let key = event_type_key_from_label(&label)
+        .ok_or_else(|| ServerFnError::new("Label must contain letters or numbers"))?;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    if is_known_event_type(&owner, &key).await? {
+        return Err(ServerFnError::new(format!("An event type named '{}' already exists", label)));
+    }
+
+    let mut count_resp = db()
+        .query("SELECT VALUE id FROM event_type WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Event type count query failed", e))?;
+    let errors = count_resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Event type count query error", err_msg));
+    }
+    let existing: Vec<surrealdb::types::RecordId> = count_resp.take(0)
+        .map_err(|e| internal_error("Event type count parse failed", e))?;
+    if existing.len() >= MAX_CUSTOM_EVENT_TYPES {
+        return Err(ServerFnError::new(format!("You can have at most {} custom event types", MAX_CUSTOM_EVENT_TYPES)));
+    }
+
+    let mut resp = db()
+        .query(
+            "CREATE event_type SET owner = $owner, key = $key, label = $label, \
+             emoji = $emoji, color = $color, quick_action = false, sort_order = 100 \
+             RETURN key, label, emoji, color, quick_action, owner"
+        )
+        .bind(("owner", owner))
+        .bind(("key", key))
+        .bind(("label", label))
+        .bind(("emoji", emoji))
+        .bind(("color", color))
+        .await
+        .map_err(|e| internal_error("Create event type query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create event type query error", err_msg));
+    }
+
+    let row: Option<EventTypeDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Create event type parse failed", e))?;
+
+    row.map(|r| r.into_event_type())
+        .ok_or_else(|| ServerFnError::new("Failed to create event type"))
+}
+
+/// **What is it?**
+/// A server function that removes one of the current user's custom event types.
+///
+/// **Why does it exist?**
+/// It exists so users can tidy up categories they no longer use. Built-in types are shared and cannot be deleted.
+///
+/// **How should it be used?**
+/// Call this from the event type manager. Existing log entries keep their key and simply render without a badge.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_event_type(
+    /// The key of the custom event type to delete.
+    key: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("DELETE event_type WHERE owner = $owner AND key = $key")
+        .bind(("owner", owner))
+        .bind(("key", key))
+        .await
+        .map_err(|e| internal_error("Delete event type query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete event type query error", err_msg));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,308 @@
+use leptos::prelude::*;
+use crate::goals::{WeeklyReportCard, ZoneGoal};
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+#[cfg(feature = "ssr")]
+pub(crate) mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::goals::ZoneGoal;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct ZoneGoalDbRow {
+        pub zone: surrealdb::types::RecordId,
+        #[surreal(default)]
+        pub temp_min_c: Option<f64>,
+        #[surreal(default)]
+        pub temp_max_c: Option<f64>,
+        #[surreal(default)]
+        pub humidity_min: Option<f64>,
+        #[surreal(default)]
+        pub humidity_max: Option<f64>,
+    }
+
+    impl ZoneGoalDbRow {
+        pub fn into_zone_goal(self) -> ZoneGoal {
+            ZoneGoal {
+                zone_id: record_id_to_string(&self.zone),
+                temp_min_c: self.temp_min_c,
+                temp_max_c: self.temp_max_c,
+                humidity_min: self.humidity_min,
+                humidity_max: self.humidity_max,
+            }
+        }
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct ZoneNameRow {
+        pub id: surrealdb::types::RecordId,
+        pub name: String,
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// **What is it?**
+/// An SSR-only helper that builds the current weekly report card for every zone the owner has set a goal on.
+///
+/// **Why does it exist?**
+/// It exists so the dashboard server function and the weekly push job score zones with exactly the same data window.
+///
+/// **How should it be used?**
+/// Call with the owner's `RecordId`. Zones with no readings in the last week are skipped.
+#[cfg(feature = "ssr")]
+pub(crate) async fn report_cards_for_owner(
+    owner: &surrealdb::types::RecordId,
+) -> Result<Vec<WeeklyReportCard>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::goals::build_report_card;
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::server_fns::climate::ssr_types::ReadingDbRow;
+
+    let mut resp = db()
+        .query(
+            "SELECT zone, temp_min_c, temp_max_c, humidity_min, humidity_max FROM zone_goal WHERE owner = $owner; \
+             SELECT id, name FROM growing_zone WHERE owner = $owner ORDER BY sort_order ASC; \
+             SELECT * FROM climate_reading \
+                WHERE zone IN (SELECT VALUE zone FROM zone_goal WHERE owner = $owner) \
+                AND recorded_at > time::now() - 14d ORDER BY recorded_at ASC;"
+        )
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Report card query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Report card query error", err_msg));
+    }
+
+    let goals: Vec<ZoneGoalDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Report card goals parse failed", e))?;
+    let zones: Vec<ZoneNameRow> = resp.take(1)
+        .map_err(|e| internal_error("Report card zones parse failed", e))?;
+    let readings: Vec<ReadingDbRow> = resp.take(2)
+        .map_err(|e| internal_error("Report card readings parse failed", e))?;
+    let readings: Vec<_> = readings.into_iter().map(|r| r.into_climate_reading()).collect();
+
+    let week_start = chrono::Utc::now() - chrono::Duration::days(7);
+    let goals: Vec<ZoneGoal> = goals.into_iter().map(|g| g.into_zone_goal()).collect();
+
+    let mut cards = Vec::new();
+    for zone in &zones {
+        let zone_id = record_id_to_string(&zone.id);
+        let Some(goal) = goals.iter().find(|g| g.zone_id == zone_id) else {
+            continue;
+        };
+        let (this_week, last_week): (Vec<_>, Vec<_>) = readings.iter()
+            .filter(|r| r.zone_id == zone_id)
+            .cloned()
+            .partition(|r| r.recorded_at >= week_start);
+        if this_week.is_empty() {
+            continue;
+        }
+        cards.push(build_report_card(goal, &zone.name, &this_week, &last_week, week_start));
+    }
+    Ok(cards)
+}
+
+/// **What is it?**
+/// A server function that returns the current user's temperature and humidity goals for each zone that has one.
+///
+/// **Why does it exist?**
+/// It exists to pre-fill the goal editor in settings.
+///
+/// **How should it be used?**
+/// Call when opening the climate goals section; zones without an entry have no goal.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zone_goals() -> Result<Vec<ZoneGoal>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT zone, temp_min_c, temp_max_c, humidity_min, humidity_max FROM zone_goal WHERE owner = $owner")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get zone goals query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get zone goals query error", err_msg));
+    }
+
+    let rows: Vec<ZoneGoalDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get zone goals parse failed", e))?;
+    Ok(rows.into_iter().map(|r| r.into_zone_goal()).collect())
+}
+
+/// **What is it?**
+/// A server function that sets (or clears) the temperature and humidity goal for one of the user's zones.
+///
+/// **Why does it exist?**
+/// It exists so growers can define what "in range" means for each zone before report cards are scored.
+///
+/// **How should it be used?**
+/// Call from the goal editor. Temperatures are in Celsius. Passing a goal with no bounds removes it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_zone_goal(
+    /// The goal to store; all bounds empty deletes it.
+    goal: ZoneGoal,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    goal.validate().map_err(ServerFnError::new)?;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let zone = surrealdb::types::RecordId::parse_simple(&goal.zone_id)
+        .map_err(|e| internal_error("Zone ID parse failed", e))?;
+
+    let query = if goal.is_empty() {
+        "DELETE zone_goal WHERE zone = $zone AND owner = $owner"
+    } else {
+        "IF (SELECT VALUE id FROM growing_zone WHERE id = $zone AND owner = $owner) = [] { THROW 'Zone not found' }; \
+         DELETE zone_goal WHERE zone = $zone AND owner = $owner; \
+         CREATE zone_goal SET owner = $owner, zone = $zone, temp_min_c = $tmin, temp_max_c = $tmax, \
+            humidity_min = $hmin, humidity_max = $hmax, updated_at = time::now();"
+    };
+
+    let mut resp = db()
+        .query(query)
+        .bind(("owner", owner))
+        .bind(("zone", zone))
+        .bind(("tmin", goal.temp_min_c))
+        .bind(("tmax", goal.temp_max_c))
+        .bind(("hmin", goal.humidity_min))
+        .bind(("hmax", goal.humidity_max))
+        .await
+        .map_err(|e| internal_error("Save zone goal query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save zone goal query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that returns this week's report card for each of the user's zones with a goal.
+///
+/// **Why does it exist?**
+/// It exists to show, on the dashboard, how well each zone held its target range over the past seven days and whether it's improving.
+///
+/// **How should it be used?**
+/// Call from the dashboard; an empty list means no goals are set or no readings arrived this week.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_weekly_report_cards() -> Result<Vec<WeeklyReportCard>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    report_cards_for_owner(&owner).await
+}
+
+/// **What is it?**
+/// A server function that reports whether the user receives their weekly report cards as a push notification.
+///
+/// **Why does it exist?**
+/// It exists to reflect the opt-in toggle in settings.
+///
+/// **How should it be used?**
+/// Call when rendering the climate goals section. Defaults to `false`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_weekly_report_push() -> Result<bool, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use surrealdb::types::SurrealValue;
+
+    let user_id = require_auth().await?;
+    let owner = parse_owner(&user_id)?;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        weekly_report_push: bool,
+    }
+
+    let mut resp = db()
+        .query("SELECT weekly_report_push FROM user_preference WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get weekly_report_push query failed", e))?;
+
+    let _ = resp.take_errors();
+    let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
+    Ok(row.map(|r| r.weekly_report_push).unwrap_or(false))
+}
+
+/// **What is it?**
+/// A server function that turns the weekly report card push notification on or off.
+///
+/// **Why does it exist?**
+/// It exists so report cards stay on the dashboard by default and only reach the user's devices when they ask for it.
+///
+/// **How should it be used?**
+/// Call from the toggle in the climate goals settings section. Delivery also requires an active push subscription.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_weekly_report_push(
+    /// Whether to push the weekly report cards.
+    enabled: bool,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = parse_owner(&user_id)?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET weekly_report_push = $enabled WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("enabled", enabled))
+        .await
+        .map_err(|e| internal_error("Save weekly_report_push query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save weekly_report_push query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, weekly_report_push = $enabled")
+            .bind(("owner", owner))
+            .bind(("enabled", enabled))
+            .await
+            .map_err(|e| internal_error("Create weekly_report_push preference query failed", e))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+/// HTTP handler for the health check, outside the server function protocol so uptime monitors and
+/// load balancers can call a plain URL.
+#[cfg(feature = "ssr")]
+pub mod handlers {
+    use axum::{http::StatusCode, response::Json};
+    use serde_json::json;
+
+    /// Returns the router for `GET /api/health`.
+    pub fn health_router() -> axum::Router<leptos::prelude::LeptosOptions> {
+        axum::Router::new().route("/api/health", axum::routing::get(health))
+    }
+
+    /// Reports whether the server can reach its database, from `crate::db::monitor`'s latest check
+    /// rather than a fresh query, so polling it costs nothing. Answers 200 with `"status": "ok"`
+    /// while connected and 503 with `"status": "degraded"` while the database is unreachable.
+    pub async fn health() -> (StatusCode, Json<serde_json::Value>) {
+        let database = crate::db::health();
+        let (status, label) = if database.connected {
+            (StatusCode::OK, "ok")
+        } else {
+            (StatusCode::SERVICE_UNAVAILABLE, "degraded")
+        };
+        (status, Json(json!({ "status": label, "database": database })))
+    }
+}
@@ -0,0 +1,477 @@
+use leptos::prelude::*;
+use crate::household::HouseholdInfo;
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct HouseholdRow {
+        pub id: surrealdb::types::RecordId,
+        pub name: String,
+        pub owner: surrealdb::types::RecordId,
+        #[surreal(default)]
+        pub owner_username: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct MemberRow {
+        pub user: surrealdb::types::RecordId,
+        #[surreal(default)]
+        pub username: Option<String>,
+        pub role: String,
+        pub joined_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct InviteRow {
+        pub id: surrealdb::types::RecordId,
+        pub role: String,
+        pub expires_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct InviteLookupRow {
+        pub id: surrealdb::types::RecordId,
+        pub household: surrealdb::types::RecordId,
+        pub owner: surrealdb::types::RecordId,
+        pub role: String,
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// **What is it?**
+/// An SSR-only helper that loads the household owned by `owner`, if one has been created.
+///
+/// **Why does it exist?**
+/// It exists because households are created lazily — the first invite creates one — so every household operation starts by looking it up.
+///
+/// **How should it be used?**
+/// Pass the collection owner's `RecordId` (from `CollectionAccess::owner_id`).
+#[cfg(feature = "ssr")]
+async fn find_household(owner: &surrealdb::types::RecordId) -> Result<Option<HouseholdRow>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut resp = db()
+        .query("SELECT id, name, owner, owner.username AS owner_username FROM household WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Household query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Household query error", err_msg));
+    }
+
+    resp.take(0).map_err(|e| internal_error("Household parse failed", e))
+}
+
+/// **What is it?**
+/// An SSR-only helper that counts the non-owner members of a household.
+///
+/// **Why does it exist?**
+/// It exists to enforce `MAX_HOUSEHOLD_MEMBERS` when inviting and joining.
+///
+/// **How should it be used?**
+/// Call before creating invites or memberships.
+#[cfg(feature = "ssr")]
+async fn member_count(household: &surrealdb::types::RecordId) -> Result<usize, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut resp = db()
+        .query("SELECT VALUE id FROM household_member WHERE household = $hh")
+        .bind(("hh", household.clone()))
+        .await
+        .map_err(|e| internal_error("Household member count failed", e))?;
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Household member count query error", err_msg));
+    }
+    let ids: Vec<surrealdb::types::RecordId> = resp.take(0)
+        .map_err(|e| internal_error("Household member count parse failed", e))?;
+    Ok(ids.len())
+}
+
+/// **What is it?**
+/// A server function that returns the household the signed-in user belongs to, with its members and (for the owner) outstanding invites.
+///
+/// **Why does it exist?**
+/// It exists so the settings screen can show who shares the collection and what each person may do, and so the UI can hide actions the user's role doesn't allow.
+///
+/// **How should it be used?**
+/// Call when rendering the household settings section or deciding whether to show read-only views. Returns `None` for users who have never shared their collection.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_household() -> Result<Option<HouseholdInfo>, ServerFnError> {
+    use crate::auth::{collection_access, require_auth};
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::{HouseholdInvite, HouseholdMember, HouseholdRole};
+    use crate::server_fns::auth::record_id_to_string;
+
+    let user_id = require_auth().await?;
+    let access = collection_access(&user_id).await?;
+    let owner = parse_owner(&access.owner_id)?;
+
+    let Some(household) = find_household(&owner).await? else {
+        return Ok(None);
+    };
+
+    let mut resp = db()
+        .query(
+            "SELECT user, user.username AS username, role, joined_at FROM household_member \
+                WHERE household = $hh ORDER BY joined_at ASC; \
+             SELECT id, role, expires_at FROM household_invite \
+                WHERE household = $hh AND used_at IS NONE AND expires_at > time::now() ORDER BY created_at DESC;"
+        )
+        .bind(("hh", household.id.clone()))
+        .await
+        .map_err(|e| internal_error("Household members query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Household members query error", err_msg));
+    }
+
+    let member_rows: Vec<MemberRow> = resp.take(0)
+        .map_err(|e| internal_error("Household members parse failed", e))?;
+    let invite_rows: Vec<InviteRow> = resp.take(1)
+        .map_err(|e| internal_error("Household invites parse failed", e))?;
+
+    let mut members = vec![HouseholdMember {
+        user_id: record_id_to_string(&household.owner),
+        username: household.owner_username.unwrap_or_default(),
+        role: HouseholdRole::Owner,
+        joined_at: None,
+    }];
+    members.extend(member_rows.into_iter().map(|m| HouseholdMember {
+        user_id: record_id_to_string(&m.user),
+        username: m.username.unwrap_or_default(),
+        role: HouseholdRole::from_key(&m.role).unwrap_or(HouseholdRole::Viewer),
+        joined_at: Some(m.joined_at),
+    }));
+
+    // Only the owner manages invites
+    let invites = if access.role == HouseholdRole::Owner {
+        invite_rows.into_iter()
+            .filter_map(|i| Some(HouseholdInvite {
+                id: record_id_to_string(&i.id),
+                role: HouseholdRole::from_key(&i.role)?,
+                expires_at: i.expires_at,
+            }))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(Some(HouseholdInfo {
+        id: record_id_to_string(&household.id),
+        name: household.name,
+        my_role: access.role,
+        members,
+        invites,
+    }))
+}
+
+/// **What is it?**
+/// A server function that creates a single-use invite link granting a role in the owner's household.
+///
+/// **Why does it exist?**
+/// It exists so partners, housemates, or plant-sitters can get their own login to the same collection instead of sharing one account.
+///
+/// **How should it be used?**
+/// Call from the household settings section with `"caretaker"` or `"viewer"`. The returned URL is shown once for the owner to send; it expires after `INVITE_VALID_DAYS`. Creates the household on first use.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_household_invite(
+    /// Role for the invitee: "caretaker" or "viewer".
+    role: String,
+) -> Result<String, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::config::config;
+    use crate::crypto::generate_token;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::{HouseholdRole, INVITE_VALID_DAYS, MAX_HOUSEHOLD_MEMBERS};
+
+    let role = HouseholdRole::from_key(&role)
+        .filter(|r| HouseholdRole::INVITABLE.contains(r))
+        .ok_or_else(|| ServerFnError::new("Invites can grant the caretaker or viewer role"))?;
+
+    let access = require_role(HouseholdRole::Owner).await?;
+    let owner = parse_owner(&access.owner_id)?;
+
+    let household = match find_household(&owner).await? {
+        Some(h) => h.id,
+        None => {
+            let mut resp = db()
+                .query("CREATE household SET owner = $owner, name = string::concat(($owner.username ?? 'My'), '''s household') RETURN VALUE id")
+                .bind(("owner", owner.clone()))
+                .await
+                .map_err(|e| internal_error("Create household query failed", e))?;
+            let errors = resp.take_errors();
+            if !errors.is_empty() {
+                let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+                return Err(internal_error("Create household query error", err_msg));
+            }
+            let ids: Vec<surrealdb::types::RecordId> = resp.take(0)
+                .map_err(|e| internal_error("Create household parse failed", e))?;
+            ids.into_iter().next()
+                .ok_or_else(|| internal_error("Create household failed", "no id returned"))?
+        }
+    };
+
+    // The owner counts as one member
+    if member_count(&household).await? + 1 >= MAX_HOUSEHOLD_MEMBERS {
+        return Err(ServerFnError::new(format!("A household can have at most {} people", MAX_HOUSEHOLD_MEMBERS)));
+    }
+
+    let (token, token_hash) = generate_token();
+    let mut resp = db()
+        .query("CREATE household_invite SET household = $hh, role = $role, token_hash = $hash, expires_at = time::now() + <duration> $valid")
+        .bind(("hh", household))
+        .bind(("role", role.as_str().to_string()))
+        .bind(("hash", token_hash))
+        .bind(("valid", format!("{}d", INVITE_VALID_DAYS)))
+        .await
+        .map_err(|e| internal_error("Create invite query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create invite query error", err_msg));
+    }
+
+    Ok(format!("{}/join?token={}", config().app_base_url.trim_end_matches('/'), token))
+}
+
+/// **What is it?**
+/// A server function that cancels an outstanding invite link.
+///
+/// **Why does it exist?**
+/// It exists so an owner can take back a link sent to the wrong person before it is used.
+///
+/// **How should it be used?**
+/// Call from the invite list in household settings with the invite's ID.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn revoke_household_invite(
+    /// The invite record ID.
+    invite_id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let access = require_role(HouseholdRole::Owner).await?;
+    let owner = parse_owner(&access.owner_id)?;
+    let invite = surrealdb::types::RecordId::parse_simple(&invite_id)
+        .map_err(|_| ServerFnError::new("Invalid invite"))?;
+
+    let mut resp = db()
+        .query("DELETE $invite WHERE household.owner = $owner")
+        .bind(("invite", invite))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Revoke invite query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke invite query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that joins the signed-in user to a household using an invite link's token.
+///
+/// **Why does it exist?**
+/// It exists to complete the invite flow; the token is single-use and expires after `INVITE_VALID_DAYS`.
+///
+/// **How should it be used?**
+/// Call from the `/join?token=...` page once the user is signed in. While a member, the user sees the household's collection instead of their own; leaving restores it.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn accept_household_invite(
+    /// The token from the invite link.
+    token: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::{collection_access, require_auth};
+    use crate::crypto::hash_token;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::MAX_HOUSEHOLD_MEMBERS;
+
+    const INVALID: &str = "This invite link is invalid or has expired";
+
+    if token.is_empty() || token.len() > 128 {
+        return Err(ServerFnError::new(INVALID));
+    }
+
+    let user_id = require_auth().await?;
+    let user = parse_owner(&user_id)?;
+
+    let mut resp = db()
+        .query("SELECT id, household, household.owner AS owner, role FROM household_invite \
+                WHERE token_hash = $hash AND used_at IS NONE AND expires_at > time::now() LIMIT 1")
+        .bind(("hash", hash_token(&token)))
+        .await
+        .map_err(|e| internal_error("Invite lookup failed", e))?;
+    let _ = resp.take_errors();
+    let invite: Option<InviteLookupRow> = resp.take(0).unwrap_or(None);
+    let invite = invite.ok_or_else(|| ServerFnError::new(INVALID))?;
+
+    if invite.owner == user {
+        return Err(ServerFnError::new("You already own this household"));
+    }
+    if collection_access(&user_id).await?.owner_id != user_id {
+        return Err(ServerFnError::new("You're already in a household. Leave it before joining another."));
+    }
+    if let Some(own) = find_household(&user).await?
+        && member_count(&own.id).await? > 0
+    {
+        return Err(ServerFnError::new("Other people share your collection. Remove them before joining another household."));
+    }
+    if member_count(&invite.household).await? + 1 >= MAX_HOUSEHOLD_MEMBERS {
+        return Err(ServerFnError::new("This household is full"));
+    }
+
+    let mut resp = db()
+        .query("
+            BEGIN TRANSACTION;
+            CREATE household_member SET household = $hh, user = $user, role = $role;
+            UPDATE $invite SET used_at = time::now();
+            COMMIT TRANSACTION;
+        ")
+        .bind(("hh", invite.household))
+        .bind(("user", user))
+        .bind(("role", invite.role))
+        .bind(("invite", invite.id))
+        .await
+        .map_err(|e| internal_error("Join household query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Join household query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that changes a household member's role.
+///
+/// **Why does it exist?**
+/// It exists so an owner can promote a viewer to caretaker (or the reverse) without re-inviting them.
+///
+/// **How should it be used?**
+/// Call from the member list in household settings with the member's user ID and `"caretaker"` or `"viewer"`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn update_household_member_role(
+    /// The member's user record ID.
+    member_user_id: String,
+    /// The new role: "caretaker" or "viewer".
+    role: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let role = HouseholdRole::from_key(&role)
+        .filter(|r| HouseholdRole::INVITABLE.contains(r))
+        .ok_or_else(|| ServerFnError::new("Members can be caretakers or viewers"))?;
+
+    let access = require_role(HouseholdRole::Owner).await?;
+    let owner = parse_owner(&access.owner_id)?;
+    let member = parse_owner(&member_user_id)?;
+
+    let mut resp = db()
+        .query("UPDATE household_member SET role = $role WHERE user = $member AND household.owner = $owner")
+        .bind(("role", role.as_str().to_string()))
+        .bind(("member", member))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Update member role query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Update member role query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that removes someone from the household: the owner removing a member, or a member leaving.
+///
+/// **Why does it exist?**
+/// It exists so access can be withdrawn when someone moves out or no longer helps with the plants.
+///
+/// **How should it be used?**
+/// Owners pass the member's user ID. Members pass `None` to leave; they go back to their own collection.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn remove_household_member(
+    /// The member to remove, or `None` to leave the household yourself.
+    member_user_id: Option<String>,
+) -> Result<(), ServerFnError> {
+    use crate::auth::{collection_access, require_auth};
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let user_id = require_auth().await?;
+    let access = collection_access(&user_id).await?;
+    let owner = parse_owner(&access.owner_id)?;
+
+    let member = match member_user_id {
+        Some(id) if id != user_id => {
+            if access.role != HouseholdRole::Owner {
+                return Err(ServerFnError::new("Only the owner can remove members"));
+            }
+            parse_owner(&id)?
+        }
+        _ => {
+            if access.role == HouseholdRole::Owner {
+                return Err(ServerFnError::new("The owner can't leave their own household"));
+            }
+            parse_owner(&user_id)?
+        }
+    };
+
+    let mut resp = db()
+        .query("DELETE household_member WHERE user = $member AND household.owner = $owner")
+        .bind(("member", member))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Remove member query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Remove member query error", err_msg));
+    }
+
+    Ok(())
+}
@@ -9,16 +9,20 @@
 /// It exists because Leptos Server Functions do not natively support streaming raw `multipart/form-data` payloads (like large JPEGs) efficiently, requiring a direct Axum route.
 ///
 /// **How should it be used?**
-/// Register the `upload_router` in the main Axum application setup (`src/main.rs`) to expose the `/api/images/upload` endpoint.
+/// Register the `upload_router`, `thumbnail_router` and `archive_router` in the main Axum application setup (`src/main.rs`) to expose the `/api/images/upload`, `/thumbs/{size}/{*path}` and `/api/images/archive` endpoints.
 #[cfg(feature = "ssr")]
 pub mod handlers {
     use axum::{
-        extract::{DefaultBodyLimit, Multipart},
-        http::StatusCode,
-        response::Json,
+        extract::{DefaultBodyLimit, Multipart, Path, Query},
+        http::{header, HeaderMap, StatusCode},
+        response::{IntoResponse, Json},
     };
     use serde_json::json;
     use std::path::PathBuf;
+    use crate::thumbnails::{thumbnail_path, ThumbSize};
+
+    /// JPEG quality used for thumbnails.
+    const THUMB_QUALITY: u8 = 80;
 
     /// Returns an Axum Router layer that overrides the default body limit for
     /// the upload route, allowing uploads up to 15MB (matching the tower-http
@@ -30,20 +34,159 @@ pub mod handlers {
             .layer(DefaultBodyLimit::max(15 * 1024 * 1024))
     }
 
-    /// Receives a multipart image upload, validates its size and format, and stores it.
+    /// Returns an Axum Router serving photo thumbnails at `/thumbs/{size}/{*path}`.
+    pub fn thumbnail_router() -> axum::Router<leptos::prelude::LeptosOptions> {
+        axum::Router::new()
+            .route("/thumbs/{size}/{*path}", axum::routing::get(serve_thumbnail))
+    }
+
+    /// Returns the router for `GET /api/images/archive`, with its own rate limiter (bursts of 3,
+    /// then one archive every 20 seconds per client IP) since each request reads every photo from disk.
+    pub fn archive_router() -> axum::Router<leptos::prelude::LeptosOptions> {
+        use tower_governor::governor::GovernorConfigBuilder;
+        use tower_governor::key_extractor::SmartIpKeyExtractor;
+        use tower_governor::GovernorLayer;
+
+        let governor_conf = GovernorConfigBuilder::default()
+            .per_second(20)
+            .burst_size(3)
+            .key_extractor(SmartIpKeyExtractor)
+            .finish()
+            .expect("Failed to build photo archive rate limiter config");
+        let governor_limiter = governor_conf.limiter().clone();
+
+        // Forget idle clients, like the site-wide limiter's cleanup task
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                governor_limiter.retain_recent();
+            }
+        });
+
+        axum::Router::new()
+            .route("/api/images/archive", axum::routing::get(download_archive))
+            .layer(GovernorLayer::new(governor_conf))
+    }
+
+    /// Decodes an upload and turns it upright using its EXIF orientation, so the pixels are
+    /// correct once the metadata is gone.
+    pub(crate) fn decode_upright(data: &[u8]) -> Result<image::DynamicImage, image::ImageError> {
+        use image::ImageDecoder;
+
+        let mut decoder = image::ImageReader::new(std::io::Cursor::new(data))
+            .with_guessed_format()?
+            .into_decoder()?;
+        let orientation = decoder.orientation()?;
+        let mut img = image::DynamicImage::from_decoder(decoder)?;
+        img.apply_orientation(orientation);
+        Ok(img)
+    }
+
+    /// Re-encodes an upload as lossy WebP at `quality` (1-100), dropping EXIF, GPS and other
+    /// metadata along the way. CPU-bound; call from a blocking task.
+    fn recompress_webp(data: &[u8], quality: u8) -> Result<Vec<u8>, image::ImageError> {
+        let img = decode_upright(data)?;
+        let (width, height) = (img.width(), img.height());
+        let encoded = if img.color().has_alpha() {
+            webp::Encoder::from_rgba(img.to_rgba8().as_raw(), width, height).encode(quality as f32).to_vec()
+        } else {
+            webp::Encoder::from_rgb(img.to_rgb8().as_raw(), width, height).encode(quality as f32).to_vec()
+        };
+        Ok(encoded)
+    }
+
+    /// Downscales `data` to fit within `size` and encodes it as JPEG. CPU-bound; call from a blocking task.
+    fn render_thumbnail(data: &[u8], size: ThumbSize) -> Result<Vec<u8>, image::ImageError> {
+        let img = decode_upright(data)?;
+        let edge = size.max_edge();
+        let img = if img.width() > edge || img.height() > edge { img.thumbnail(edge, edge) } else { img };
+        let mut out = Vec::new();
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, THUMB_QUALITY)
+            .encode_image(&img.to_rgb8())?;
+        Ok(out)
+    }
+
+    /// Renders and stores the thumbnail for `filename` (relative to the storage root) and returns its bytes.
+    async fn write_thumbnail(filename: &str, data: Vec<u8>, size: ThumbSize) -> Result<Vec<u8>, String> {
+        use crate::config::config;
+
+        let relative = thumbnail_path(filename, size).ok_or("invalid image path")?;
+        let thumb = tokio::task::spawn_blocking(move || render_thumbnail(&data, size))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+
+        let path = PathBuf::from(&config().image_storage_path).join(relative);
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await.map_err(|e| e.to_string())?;
+        }
+        tokio::fs::write(&path, &thumb).await.map_err(|e| e.to_string())?;
+        Ok(thumb)
+    }
+
+    /// Serves a stored thumbnail, creating it from the original the first time it's asked for
+    /// (photos uploaded before thumbnails existed, or whose upload-time render failed).
+    async fn serve_thumbnail(Path((size, filename)): Path<(String, String)>) -> Result<impl IntoResponse, StatusCode> {
+        use crate::config::config;
+
+        let size = ThumbSize::parse(&size).ok_or(StatusCode::NOT_FOUND)?;
+        let relative = thumbnail_path(&filename, size).ok_or(StatusCode::NOT_FOUND)?;
+        let root = PathBuf::from(&config().image_storage_path);
+
+        let bytes = match tokio::fs::read(root.join(&relative)).await {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                let original = tokio::fs::read(root.join(&filename)).await
+                    .map_err(|_| StatusCode::NOT_FOUND)?;
+                write_thumbnail(&filename, original, size).await.map_err(|e| {
+                    tracing::warn!("Failed to create {} thumbnail for {}: {}", size.as_str(), filename, e);
+                    StatusCode::UNPROCESSABLE_ENTITY
+                })?
+            }
+        };
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, "image/jpeg"),
+                // Filenames are unique per upload, so a thumbnail never changes
+                (header::CACHE_CONTROL, "public, max-age=31536000, immutable"),
+            ],
+            bytes,
+        ))
+    }
+
+    /// Receives a multipart image upload, validates its size and format, recompresses it to WebP
+    /// (see `IMAGE_WEBP_QUALITY`), and stores it along with its thumbnails.
+    ///
+    /// Accepts either a signed-in session or a personal API token with the upload scope.
     pub async fn upload_image(
         session: tower_sessions::Session,
+        headers: HeaderMap,
         mut multipart: Multipart,
     ) -> Result<Json<serde_json::Value>, StatusCode> {
+        use crate::api_tokens::ApiScope;
+        use crate::auth::{authenticate_bearer, bearer_rejection_status};
         use crate::config::config;
 
-        // Require authentication
-        let user_id: String = session.get("user_id").await
+        // Require authentication: an API token when one is presented, else the session
+        let token_user = authenticate_bearer(&headers, ApiScope::Upload).await
             .map_err(|e| {
-                tracing::error!("Session read error: {}", e);
-                StatusCode::INTERNAL_SERVER_ERROR
-            })?
-            .ok_or(StatusCode::UNAUTHORIZED)?;
+                tracing::warn!("API token rejected for image upload: {}", e);
+                bearer_rejection_status(&e)
+            })?;
+        let user_id: String = match token_user {
+            Some(user_id) => user_id,
+            None => session.get("user_id").await
+                .map_err(|e| {
+                    tracing::error!("Session read error: {}", e);
+                    StatusCode::INTERNAL_SERVER_ERROR
+                })?
+                .ok_or(StatusCode::UNAUTHORIZED)?,
+        };
+        crate::rate_limit::check_user(crate::rate_limit::Expensive::Upload, &user_id).map_err(|e| {
+            tracing::warn!("Image upload from {} refused: {}", user_id, e);
+            StatusCode::TOO_MANY_REQUESTS
+        })?;
 
         while let Some(field) = multipart.next_field().await.map_err(|e| {
             tracing::error!("Multipart field read error: {}", e);
@@ -81,7 +224,25 @@ pub mod handlers {
                 return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE);
             }
 
-            let ext = if is_jpeg { "jpg" } else if is_png { "png" } else { "webp" };
+            // Recompress to WebP unless disabled; an image that can't be decoded is rejected
+            let quality = config().image_webp_quality;
+            let (data, ext) = if quality == 0 {
+                (data.to_vec(), if is_jpeg { "jpg" } else if is_png { "png" } else { "webp" })
+            } else {
+                let original_len = data.len();
+                let webp = tokio::task::spawn_blocking(move || recompress_webp(&data, quality))
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Image recompression task failed: {}", e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })?
+                    .map_err(|e| {
+                        tracing::warn!("Could not decode uploaded image: {}", e);
+                        StatusCode::UNSUPPORTED_MEDIA_TYPE
+                    })?;
+                tracing::info!("Image recompressed: {} -> {} bytes", original_len, webp.len());
+                (webp, "webp")
+            };
             let filename = format!("{}.{}", uuid::Uuid::new_v4(), ext);
 
             // Sanitize user_id for filesystem use — SurrealDB record IDs contain
@@ -104,10 +265,183 @@ pub mod handlers {
 
             // Return path relative to storage root (safe_user_dir/filename)
             let relative_path = format!("{}/{}", safe_user_dir, filename);
+
+            // Thumbnails are best effort here; `serve_thumbnail` retries on first view
+            for size in ThumbSize::ALL {
+                if let Err(e) = write_thumbnail(&relative_path, data.clone(), size).await {
+                    tracing::warn!("Failed to create {} thumbnail for {}: {}", size.as_str(), relative_path, e);
+                }
+            }
             return Ok(Json(json!({ "filename": relative_path })));
         }
 
         tracing::warn!("No 'image' field found in multipart upload");
         Err(StatusCode::BAD_REQUEST)
     }
+
+    /// Query string of `/api/images/archive`.
+    #[derive(serde::Deserialize)]
+    pub struct ArchiveQuery {
+        /// Limit the archive to one plant; without it every photo in the collection is included.
+        orchid: Option<String>,
+    }
+
+    /// Writes into the response body channel; `blocking_send` waits while the client catches up,
+    /// so only a few chunks are ever held in memory.
+    struct ChannelWriter(tokio::sync::mpsc::Sender<Result<axum::body::Bytes, std::io::Error>>);
+
+    impl std::io::Write for ChannelWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.blocking_send(Ok(axum::body::Bytes::copy_from_slice(buf)))
+                .map_err(|_| std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Client went away"))?;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Streams a zip of one plant's journal photos, or the whole collection's, for personal backup.
+    ///
+    /// Accepts either a signed-in session or a personal API token with the read scope. Photos are
+    /// stored as-is, one folder per plant, and the zip is written while it downloads rather than
+    /// built in memory first.
+    pub async fn download_archive(
+        session: tower_sessions::Session,
+        headers: HeaderMap,
+        Query(query): Query<ArchiveQuery>,
+    ) -> Result<impl IntoResponse, StatusCode> {
+        use crate::api_tokens::ApiScope;
+        use crate::auth::{authenticate_bearer, bearer_rejection_status, collection_access};
+        use crate::config::config;
+        use crate::db::db;
+        use crate::photo_archive::{archive_entry_name, archive_filename, unique_entry_name};
+        use crate::server_fns::orchids::validate_filename;
+        use std::io::Write;
+        use surrealdb::types::SurrealValue;
+
+        #[derive(serde::Deserialize, SurrealValue)]
+        #[surreal(crate = "surrealdb::types")]
+        struct PhotoRow {
+            image_filename: String,
+            timestamp: chrono::DateTime<chrono::Utc>,
+            #[surreal(default)]
+            plant: Option<String>,
+        }
+
+        let token_user = authenticate_bearer(&headers, ApiScope::Read).await
+            .map_err(|e| {
+                tracing::warn!("API token rejected for photo archive: {}", e);
+                bearer_rejection_status(&e)
+            })?;
+        let user_id: String = match token_user {
+            Some(user_id) => user_id,
+            None => session.get("user_id").await
+                .map_err(|e| {
+                    tracing::error!("Session read error: {}", e);
+                    StatusCode::INTERNAL_SERVER_ERROR
+                })?
+                .ok_or(StatusCode::UNAUTHORIZED)?,
+        };
+        let access = collection_access(&user_id).await.map_err(|e| {
+            tracing::error!("Photo archive access lookup failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        let owner = surrealdb::types::RecordId::parse_simple(&access.owner_id)
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let orchid = query.orchid.as_deref()
+            .map(surrealdb::types::RecordId::parse_simple)
+            .transpose()
+            .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+        // The first statement looks the plant up so another collection's ID reads as not found
+        let sql = if orchid.is_some() {
+            "SELECT VALUE name FROM $orchid WHERE owner = $owner; \
+             SELECT image_filename, timestamp, orchid.name AS plant FROM log_entry \
+             WHERE owner = $owner AND orchid = $orchid AND image_filename != NONE ORDER BY timestamp ASC"
+        } else {
+            "RETURN []; \
+             SELECT image_filename, timestamp, orchid.name AS plant FROM log_entry \
+             WHERE owner = $owner AND image_filename != NONE ORDER BY timestamp ASC"
+        };
+        let mut resp = db()
+            .query(sql)
+            .bind(("owner", owner))
+            .bind(("orchid", orchid))
+            .await
+            .map_err(|e| {
+                tracing::error!("Photo archive query failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::error!("Photo archive query error: {}", err_msg);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+
+        let plant_name: Option<String> = resp.take::<Vec<String>>(0)
+            .map_err(|e| {
+                tracing::error!("Photo archive plant parse failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?
+            .into_iter().next();
+        if query.orchid.is_some() && plant_name.is_none() {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        let photos: Vec<PhotoRow> = resp.take(1).map_err(|e| {
+            tracing::error!("Photo archive parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        tracing::info!(photos = photos.len(), "Photo archive requested by {}", user_id);
+
+        let root = PathBuf::from(&config().image_storage_path);
+        let (tx, rx) = tokio::sync::mpsc::channel(8);
+        tokio::task::spawn_blocking(move || {
+            use zip::write::SimpleFileOptions;
+
+            // Buffer so the zip's many small header writes go out as a few large chunks
+            let writer = std::io::BufWriter::with_capacity(64 * 1024, ChannelWriter(tx.clone()));
+            let mut zip = zip::ZipWriter::new_stream(writer);
+            // Photos are already compressed; deflating them again only costs CPU
+            let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+            let result = (|| -> zip::result::ZipResult<()> {
+                let mut used = std::collections::HashSet::new();
+                for photo in photos {
+                    if validate_filename(&photo.image_filename).is_err() {
+                        continue;
+                    }
+                    let bytes = match std::fs::read(root.join(&photo.image_filename)) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            tracing::warn!("Photo archive skipped unreadable image {}: {}", photo.image_filename, e);
+                            continue;
+                        }
+                    };
+                    let plant = photo.plant.as_deref().unwrap_or_default();
+                    let name = unique_entry_name(archive_entry_name(plant, photo.timestamp, &photo.image_filename), &mut used);
+                    zip.start_file(name, options)?;
+                    zip.write_all(&bytes)?;
+                }
+                zip.finish()?.flush()?;
+                Ok(())
+            })();
+            if let Err(e) = result {
+                tracing::warn!("Photo archive stream ended early: {}", e);
+                let _ = tx.blocking_send(Err(std::io::Error::other(e.to_string())));
+            }
+        });
+
+        let filename = archive_filename(plant_name.as_deref(), chrono::Utc::now().date_naive());
+        Ok((
+            [
+                (header::CONTENT_TYPE, "application/zip".to_string()),
+                (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", filename)),
+                (header::CACHE_CONTROL, "no-store".to_string()),
+            ],
+            axum::body::Body::from_stream(tokio_stream::wrappers::ReceiverStream::new(rx)),
+        ))
+    }
 }
@@ -0,0 +1,114 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::import::ColumnMapping;
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+/// Maximum size of an uploaded export, in bytes.
+pub const MAX_IMPORT_BYTES: usize = 2 * 1024 * 1024;
+
+/// What an import created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportSummary {
+    /// Orchids created.
+    pub plants_created: usize,
+    /// Journal entries created from the export's history.
+    pub events_created: usize,
+    /// Rows that were skipped or adjusted during conversion.
+    pub warnings: Vec<String>,
+}
+
+/// **What is it?**
+/// A server function that imports a Planta, Greg, or generic CSV export as new orchids with their care history.
+///
+/// **Why does it exist?**
+/// It exists so growers moving from another plant app keep their watering schedules, last-care dates and journal instead of re-entering everything.
+///
+/// **How should it be used?**
+/// Call from the import wizard with the raw file text, the source code (`planta`, `greg`, `generic`) and the column mapping the user confirmed. The conversion is the same one the wizard previews, so the result matches what the user saw. Existing orchids are never modified.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn import_plants(
+    /// Source app code from `ImportSource::code`.
+    source: String,
+    /// Raw CSV file contents.
+    csv: String,
+    /// Which column feeds each field.
+    mapping: ColumnMapping,
+) -> Result<ImportSummary, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::import::{convert, csv::parse_csv, ImportSource, ImportedEvent};
+
+    if csv.len() > MAX_IMPORT_BYTES {
+        return Err(ServerFnError::new("File is too large (max 2 MB)"));
+    }
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let source = ImportSource::from_code(&source);
+    let (_headers, rows) = parse_csv(&csv).map_err(ServerFnError::new)?;
+    let preview = convert(source, &rows, &mapping).map_err(ServerFnError::new)?;
+    if preview.plants.is_empty() {
+        return Err(ServerFnError::new("No plants found in this file"));
+    }
+
+    let mut plants_created = 0;
+    let mut events_created = 0;
+    for plant in preview.plants {
+        let event_count = plant.events.len();
+        let mut resp = db()
+            .query(
+                "BEGIN TRANSACTION; \
+                 LET $orchid = (CREATE orchid SET \
+                     owner = $owner, name = $name, species = $species, \
+                     water_frequency_days = $water_freq, light_requirement = 'Medium', \
+                     notes = $notes, placement = $placement, light_lux = '', temperature_range = '', \
+                     fertilize_frequency_days = $fert_freq, \
+                     last_watered_at = $last_watered, last_fertilized_at = $last_fertilized, \
+                     last_repotted_at = $last_repotted \
+                     RETURN VALUE id)[0]; \
+                 FOR $e IN $events { \
+                     CREATE log_entry SET orchid = $orchid, owner = $owner, \
+                         event_type = $e.event_type, note = $e.note, timestamp = $e.timestamp; \
+                 }; \
+                 COMMIT TRANSACTION;"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("name", plant.name))
+            .bind(("species", plant.species))
+            .bind(("water_freq", plant.water_frequency_days as i64))
+            .bind(("notes", crate::markdown::sanitize(&plant.notes)))
+            .bind(("placement", plant.placement.unwrap_or_default()))
+            .bind(("fert_freq", plant.fertilize_frequency_days.map(|v| v as i64)))
+            .bind(("last_watered", plant.last_watered_at))
+            .bind(("last_fertilized", plant.last_fertilized_at))
+            .bind(("last_repotted", plant.last_repotted_at))
+            .bind(("events", plant.events.into_iter()
+                .map(|e| ImportedEvent { note: crate::markdown::sanitize(&e.note), ..e })
+                .collect::<Vec<_>>()))
+            .await
+            .map_err(|e| internal_error("Import plant query failed", e))?;
+
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            return Err(internal_error("Import plant query error", err_msg));
+        }
+
+        plants_created += 1;
+        events_created += event_count;
+    }
+
+    tracing::info!(plants_created, events_created, source = source.code(), "Plant import finished");
+
+    Ok(ImportSummary {
+        plants_created,
+        events_created,
+        warnings: preview.warnings,
+    })
+}
@@ -0,0 +1,375 @@
+use leptos::prelude::*;
+
+/// Prefix on every zone ingest token, so it can't be mistaken for a personal API token (`vlm_`) or feed key (`vlp_`).
+pub const INGEST_TOKEN_PREFIX: &str = "vli_";
+
+/// **What is it?**
+/// A server function that returns the display prefix of a zone's HTTP ingest token, if it has one.
+///
+/// **Why does it exist?**
+/// It exists so the zone settings can show that DIY sensors are pushing to the zone, and which token they use, without ever storing the token itself.
+///
+/// **How should it be used?**
+/// Call this when the "HTTP Push" data source is shown for a zone. `None` means no token has been created or it was revoked.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zone_ingest_prefix(
+    /// The unique identifier of the zone.
+    zone_id: String,
+) -> Result<Option<String>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefixRow {
+        #[surreal(default)]
+        ingest_token_prefix: Option<String>,
+    }
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
+        .map_err(|e| internal_error("Zone ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("SELECT ingest_token_prefix FROM growing_zone WHERE id = $id AND owner = $owner")
+        .bind(("id", zone_record))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Ingest token query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Ingest token query error", err_msg));
+    }
+
+    let row: Option<PrefixRow> = resp.take(0).unwrap_or(None);
+    Ok(row.and_then(|r| r.ingest_token_prefix))
+}
+
+/// **What is it?**
+/// A server function that creates a new HTTP ingest token for a zone, replacing any existing one, and returns it once.
+///
+/// **Why does it exist?**
+/// It exists for DIY sensors (ESP32, Raspberry Pi) that can't speak a vendor cloud or MQTT but can send a simple HTTP POST to `/api/ingest/{token}`.
+///
+/// **How should it be used?**
+/// Call this from the zone's data source settings and show the returned token (or the URL built from it) immediately, since only its hash is stored. The zone's data source becomes `http` and any linked device or polled source is cleared.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_zone_ingest_token(
+    /// The unique identifier of the zone.
+    zone_id: String,
+) -> Result<String, ServerFnError> {
+    use crate::api_tokens::display_prefix;
+    use crate::auth::require_role;
+    use crate::crypto::{generate_token, hash_token};
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
+        .map_err(|e| internal_error("Zone ID parse failed", e))?;
+
+    let (secret, _) = generate_token();
+    let token = format!("{}{}", INGEST_TOKEN_PREFIX, secret);
+
+    let mut resp = db()
+        .query(
+            "UPDATE $id SET ingest_token_hash = $hash, ingest_token_prefix = $prefix, \
+             data_source_type = 'http', data_source_config = '', \
+             hardware_device = NONE, hardware_port = NONE \
+             WHERE owner = $owner RETURN id"
+        )
+        .bind(("id", zone_record))
+        .bind(("owner", owner))
+        .bind(("hash", hash_token(&token)))
+        .bind(("prefix", display_prefix(&token)))
+        .await
+        .map_err(|e| internal_error("Create ingest token query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create ingest token query error", err_msg));
+    }
+
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        return Err(ServerFnError::new("Zone not found"));
+    }
+
+    crate::server_fns::zones::invalidate_zones(&owner_id);
+    Ok(token)
+}
+
+/// **What is it?**
+/// A server function that deletes a zone's HTTP ingest token.
+///
+/// **Why does it exist?**
+/// It exists so a grower can cut off a lost or retired sensor; requests with the old token get 404 straight away.
+///
+/// **How should it be used?**
+/// Call this from the "Revoke" button in the zone's HTTP Push settings. The zone's data source is cleared if it was `http`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn revoke_zone_ingest_token(
+    /// The unique identifier of the zone.
+    zone_id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let zone_record = surrealdb::types::RecordId::parse_simple(&zone_id)
+        .map_err(|e| internal_error("Zone ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "UPDATE $id SET data_source_type = NONE WHERE owner = $owner AND data_source_type = 'http'; \
+             UPDATE $id SET ingest_token_hash = NONE, ingest_token_prefix = NONE WHERE owner = $owner"
+        )
+        .bind(("id", zone_record))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Revoke ingest token query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke ingest token query error", err_msg));
+    }
+
+    crate::server_fns::zones::invalidate_zones(&owner_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// Axum handlers for pushing sensor readings over plain HTTP.
+///
+/// **Why does it exist?**
+/// It exists because a JSON body in a POST is the one thing every microcontroller HTTP library can send, with no broker or vendor account in between.
+///
+/// **How should it be used?**
+/// Merge `ingest_router()` into the app router in `main.rs`.
+#[cfg(feature = "ssr")]
+pub mod handlers {
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, Mutex};
+    use std::time::{Duration, Instant};
+    use axum::{
+        body::Bytes,
+        extract::{DefaultBodyLimit, Path},
+        http::{header, HeaderMap, StatusCode},
+        response::{IntoResponse, Json, Response},
+    };
+    use serde_json::json;
+    use surrealdb::types::SurrealValue;
+    use super::INGEST_TOKEN_PREFIX;
+
+    /// Readings for one zone are stored at most this often; a sensor posting faster gets 429.
+    const MIN_STORE_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Largest accepted body; a reading is a few dozen bytes.
+    const MAX_BODY_BYTES: usize = 4 * 1024;
+
+    /// When each zone last stored a pushed reading, keyed by zone ID.
+    static LAST_STORED: LazyLock<Mutex<HashMap<String, Instant>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    /// A zone that accepts pushed readings.
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct IngestZoneRow {
+        id: surrealdb::types::RecordId,
+        name: String,
+        #[surreal(default)]
+        location_type: Option<String>,
+    }
+
+    /// Returns the router for `POST /api/ingest/{zone_token}` and `POST /api/zones/{zone_id}/readings`, with a small body limit.
+    pub fn ingest_router() -> axum::Router<leptos::prelude::LeptosOptions> {
+        axum::Router::new()
+            .route("/api/ingest/{zone_token}", axum::routing::post(ingest_reading))
+            .route("/api/zones/{zone_id}/readings", axum::routing::post(ingest_zone_reading))
+            .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
+    }
+
+    /// Stores one reading for the zone the token belongs to.
+    ///
+    /// The body is JSON in any shape `parse_sensor_payload` understands, e.g.
+    /// `{"temperature": 22.4, "humidity": 61, "lux": 12000}`. Stored readings return 201, and
+    /// readings held while the database is unreachable 202. Unknown tokens return 404,
+    /// unreadable bodies 400, and readings sooner than `MIN_STORE_INTERVAL` after the last one 429.
+    pub async fn ingest_reading(
+        Path(zone_token): Path<String>,
+        body: Bytes,
+    ) -> Result<Response, StatusCode> {
+        use crate::crypto::hash_token;
+        use crate::db::db;
+
+        if !zone_token.starts_with(INGEST_TOKEN_PREFIX) || zone_token.len() > 100 {
+            return Err(StatusCode::NOT_FOUND);
+        }
+
+        let mut resp = db()
+            .query(
+                "SELECT id, name, location_type FROM growing_zone \
+                 WHERE ingest_token_hash = $hash AND data_source_type = 'http' AND owner.disabled != true LIMIT 1"
+            )
+            .bind(("hash", hash_token(&zone_token)))
+            .await
+            .map_err(|e| {
+                tracing::error!("Ingest zone lookup failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::error!("Ingest zone lookup error: {}", err_msg);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+        let zone: Option<IngestZoneRow> = resp.take(0).map_err(|e| {
+            tracing::error!("Ingest zone parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        let zone = zone.ok_or(StatusCode::NOT_FOUND)?;
+
+        store_pushed_reading(zone, &body).await
+    }
+
+    /// Stores one reading for a zone named by ID, authenticated with a personal API token.
+    ///
+    /// The token needs the `climate:ingest` scope and its owner must be able to log care in the
+    /// collection. Only HTTP push zones accept readings. Missing or rejected tokens return 401,
+    /// and zones outside the token's collection 404; otherwise this behaves like `ingest_reading`.
+    pub async fn ingest_zone_reading(
+        Path(zone_id): Path<String>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> Result<Response, StatusCode> {
+        use crate::api_tokens::ApiScope;
+        use crate::auth::{authenticate_bearer, bearer_rejection_status, collection_access};
+        use crate::db::db;
+        use crate::household::HouseholdRole;
+
+        let user_id = authenticate_bearer(&headers, ApiScope::Ingest).await
+            .map_err(|e| {
+                tracing::warn!("API token rejected for climate ingest: {}", e);
+                bearer_rejection_status(&e)
+            })?
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+        let access = collection_access(&user_id).await.map_err(|e| {
+            tracing::error!("Ingest collection lookup failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        if !access.role.allows(HouseholdRole::Caretaker) {
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        let zone = surrealdb::types::RecordId::parse_simple(&zone_id).map_err(|_| StatusCode::NOT_FOUND)?;
+        let owner = surrealdb::types::RecordId::parse_simple(&access.owner_id).map_err(|e| {
+            tracing::error!("Ingest owner ID parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+        let mut resp = db()
+            .query(
+                "SELECT id, name, location_type FROM growing_zone \
+                 WHERE id = $zone AND owner = $owner AND data_source_type = 'http' LIMIT 1"
+            )
+            .bind(("zone", zone))
+            .bind(("owner", owner))
+            .await
+            .map_err(|e| {
+                tracing::error!("Ingest zone lookup failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::error!("Ingest zone lookup error: {}", err_msg);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+        let zone: Option<IngestZoneRow> = resp.take(0).map_err(|e| {
+            tracing::error!("Ingest zone parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        let zone = zone.ok_or(StatusCode::NOT_FOUND)?;
+
+        store_pushed_reading(zone, &body).await
+    }
+
+    /// Parses, rate-limits, and stores a pushed reading for `zone`.
+    async fn store_pushed_reading(zone: IngestZoneRow, body: &[u8]) -> Result<Response, StatusCode> {
+        use crate::climate::payload::parse_sensor_payload;
+        use crate::climate::poller::{store_readings, PendingReading};
+        use crate::db::db;
+        use crate::server_fns::auth::record_id_to_string;
+
+        let raw = match parse_sensor_payload(body) {
+            Ok(raw) => raw,
+            Err(e) => {
+                return Ok((StatusCode::BAD_REQUEST, Json(json!({ "error": e.to_string() }))).into_response());
+            }
+        };
+
+        let zone_key = record_id_to_string(&zone.id);
+        {
+            let mut last_stored = LAST_STORED.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(wait) = last_stored.get(&zone_key)
+                .map(|at| at.elapsed())
+                .filter(|elapsed| *elapsed < MIN_STORE_INTERVAL)
+                .map(|elapsed| MIN_STORE_INTERVAL - elapsed)
+            {
+                let retry_after = wait.as_secs().max(1).to_string();
+                return Ok((
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, retry_after)],
+                    Json(json!({ "error": format!("Readings are stored at most every {}s", MIN_STORE_INTERVAL.as_secs()) })),
+                ).into_response());
+            }
+            // Forget zones that have gone quiet so the map doesn't grow forever
+            last_stored.retain(|_, at| at.elapsed() < MIN_STORE_INTERVAL);
+            last_stored.insert(zone_key, Instant::now());
+        }
+
+        let reading = PendingReading::for_zone(&zone.id, &zone.name, zone.location_type.as_deref(), &raw, "http");
+        let outcome = store_readings(&db(), &[reading]).await;
+        if outcome.held > 0 {
+            // Written later by `store_readings`, so the sensor shouldn't resend it
+            return Ok((StatusCode::ACCEPTED, Json(json!({
+                "zone": zone.name,
+                "held": true,
+                "error": "The database is unreachable; the reading will be stored once it is back",
+            }))).into_response());
+        }
+        if outcome.stored == 0 {
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+
+        Ok((StatusCode::CREATED, Json(json!({
+            "zone": zone.name,
+            "temperature": raw.temperature_c,
+            "humidity": raw.humidity_pct,
+            "vpd": raw.vpd_kpa,
+            "lux": raw.lux,
+            "ppfd": raw.ppfd,
+            "co2_ppm": raw.co2_ppm,
+            "substrate_moisture_pct": raw.substrate_moisture_pct,
+        }))).into_response())
+    }
+}
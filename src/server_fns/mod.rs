@@ -8,6 +8,33 @@
 //! **How should it be used?**
 //! Frontend components should import and call the exposed `#[server]` functions from these submodules to interact with the backend database and external APIs.
 
+/// **What is it?**
+/// A module containing server functions for vendors and the collection-value summary.
+///
+/// **Why does it exist?**
+/// It exists so plants can be linked to where they were bought, and so spend can be totalled across the whole collection on the server.
+///
+/// **How should it be used?**
+/// Call `get_vendors`/`create_vendor` from the vendor pickers and `get_collection_value` from the Stats tab.
+pub mod acquisition;
+/// **What is it?**
+/// A module containing server functions for watering adherence statistics.
+///
+/// **Why does it exist?**
+/// It exists to compare the journal's watering timestamps with each plant's schedule on the server, where the full 90-day history is at hand.
+///
+/// **How should it be used?**
+/// Call `get_watering_adherence` from the adherence card; the pure calculation lives in `crate::adherence`.
+pub mod adherence;
+/// **What is it?**
+/// A module containing server functions for instance administration.
+///
+/// **Why does it exist?**
+/// It exists so admins can list accounts, reset passwords, disable accounts and check storage from the browser instead of running raw SurrealDB queries.
+///
+/// **How should it be used?**
+/// Call these functions from the `/admin` page; each one checks `crate::auth::require_admin`.
+pub mod admin;
 /// **What is it?**
 /// A module containing server functions for retrieving and managing system alerts.
 ///
@@ -18,6 +45,15 @@
 /// Call these functions from UI components that need to display or acknowledge alerts.
 pub mod alerts;
 /// **What is it?**
+/// A module containing server functions for personal API token management.
+///
+/// **Why does it exist?**
+/// It exists so users can create, scope, and revoke long-lived tokens for scripts and integrations that call the API without a browser session.
+///
+/// **How should it be used?**
+/// Call these functions from the API token settings panel; endpoints verify presented tokens with `crate::auth::authenticate_bearer`.
+pub mod api_tokens;
+/// **What is it?**
 /// A module containing server functions for user authentication and session management.
 ///
 /// **Why does it exist?**
@@ -27,6 +63,51 @@ pub mod alerts;
 /// Call these functions from authentication forms or middleware to verify user identity and manage sessions.
 pub mod auth;
 /// **What is it?**
+/// A module containing server functions for plant awards and show results.
+///
+/// **Why does it exist?**
+/// It exists to store each award against its plant and to summarize them as badges for the collection grid.
+///
+/// **How should it be used?**
+/// Call `get_awards`/`add_award`/`delete_award` from the awards card in the plant detail view and `get_award_badges` once on the home page.
+pub mod awards;
+/// **What is it?**
+/// A module containing server functions for exporting and importing environment blueprints.
+///
+/// **Why does it exist?**
+/// It exists so zone, device, goal and rule configuration can be moved between instances or shared without plant data or credentials.
+///
+/// **How should it be used?**
+/// Call these functions from the blueprint section in settings; the file format lives in `crate::blueprint`.
+pub mod blueprint;
+/// **What is it?**
+/// A module containing server functions for care automation rules.
+///
+/// **Why does it exist?**
+/// It exists to store the "when X happens, do Y" rules users build in settings.
+///
+/// **How should it be used?**
+/// Call these functions from the rule builder; background evaluation lives in `crate::climate::care_rules`.
+pub mod care_rules;
+/// **What is it?**
+/// A module containing server functions for the care forecast calendar.
+///
+/// **Why does it exist?**
+/// It exists so the two-week projection of watering and fertilizing dates is computed with the server's zone snapshots and preferences.
+///
+/// **How should it be used?**
+/// Call `get_care_forecast` from the home page's forecast tab; the pure projection lives in `crate::care_timeline`.
+pub mod care_timeline;
+/// **What is it?**
+/// A module containing server functions for the AI care assistant chat.
+///
+/// **Why does it exist?**
+/// It exists to answer growers' questions with their own plants, journals and zone climate in the model's context, and to keep each person's conversation.
+///
+/// **How should it be used?**
+/// Call these functions from the chat panel to load, send and clear messages.
+pub mod chat;
+/// **What is it?**
 /// A module containing server functions for managing climate data and sensor readings.
 ///
 /// **Why does it exist?**
@@ -45,6 +126,87 @@ pub mod climate;
 /// Call these functions from device management UI views to register new devices or change their settings.
 pub mod devices;
 /// **What is it?**
+/// A module containing server functions for per-zone electricity prices, powered devices, and running cost estimates.
+///
+/// **Why does it exist?**
+/// It exists to store what each zone's lights, heaters, and humidifiers draw, and turn that into a monthly cost.
+///
+/// **How should it be used?**
+/// Call these functions from the zone economics cards on the dashboard and the energy section of settings.
+pub mod energy;
+/// **What is it?**
+/// A module containing server functions for the per-user care event type registry.
+///
+/// **Why does it exist?**
+/// It exists so custom event categories (breeding, pest sightings, etc.) can be stored alongside the built-in defaults with their own icon and color.
+///
+/// **How should it be used?**
+/// Call these functions to load the registry on page load and to add or remove custom types from settings.
+pub mod event_types;
+/// **What is it?**
+/// A module containing server functions for per-zone climate goals and weekly report cards.
+///
+/// **Why does it exist?**
+/// It exists to store target temperature/humidity ranges and score each zone's week against them.
+///
+/// **How should it be used?**
+/// Call these functions from the dashboard report cards and the climate goals section of settings.
+pub mod goals;
+/// **What is it?**
+/// A module containing the `/api/health` endpoint.
+///
+/// **Why does it exist?**
+/// It exists so uptime monitors and reverse proxies can tell when the server has lost its database, instead of finding out from failing pages.
+///
+/// **How should it be used?**
+/// Merge `handlers::health_router()` into the Axum router; point monitors at `GET /api/health`.
+pub mod health;
+/// **What is it?**
+/// A module containing server functions for shared households, invite links, and member roles.
+///
+/// **Why does it exist?**
+/// It exists so several accounts can care for one collection, each with owner, caretaker, or viewer access.
+///
+/// **How should it be used?**
+/// Call these functions from the household section of settings and the `/join` page; other server functions enforce roles through `crate::auth::require_role`.
+pub mod household;
+/// **What is it?**
+/// A module containing server functions for importing plants from other plant apps.
+///
+/// **Why does it exist?**
+/// It exists to turn Planta, Greg, or generic CSV exports into orchids and journal entries so switchers don't start from zero.
+///
+/// **How should it be used?**
+/// Call `import_plants` from the import wizard in settings after the user has reviewed the preview.
+pub mod import;
+/// **What is it?**
+/// A module containing the HTTP ingest endpoint and its per-zone tokens.
+///
+/// **Why does it exist?**
+/// It exists so DIY sensors that can only send an HTTP POST can feed a zone, without a vendor cloud or MQTT broker.
+///
+/// **How should it be used?**
+/// Call the token functions from the zone's data source settings; sensors POST JSON to `/api/ingest/{token}`.
+pub mod ingest;
+/// **What is it?**
+/// A module containing server functions for card photos and species-level default images.
+///
+/// **Why does it exist?**
+/// It exists so every collection card can show a picture: the plant's latest photo, a species stock image, or preset art.
+///
+/// **How should it be used?**
+/// Call `get_card_photos` when the collection loads and `set_species_photo` from the species photo picker.
+pub mod species_photos;
+/// **What is it?**
+/// A module containing server functions for reusable journal note templates.
+///
+/// **Why does it exist?**
+/// It exists to store each user's templates alongside the built-in starters, so a checklist defined once can be picked on any plant.
+///
+/// **How should it be used?**
+/// Call `get_note_templates` from the journal's note form and settings; `create_note_template`/`delete_note_template` from the template manager.
+pub mod note_templates;
+/// **What is it?**
 /// A module containing server functions for managing orchid data and collections.
 ///
 /// **Why does it exist?**
@@ -81,6 +243,15 @@ pub mod preferences;
 /// Call these functions when submitting an image from the scanner UI to identify an orchid or diagnose a problem.
 pub mod scanner;
 /// **What is it?**
+/// A module containing server functions for propagations: keikis, divisions and backbulb starts.
+///
+/// **Why does it exist?**
+/// It exists to create the new plant and its link to the parent in one step, and to record when an offshoot is gifted or sold.
+///
+/// **How should it be used?**
+/// Call from the Lineage tab of the plant detail view; `get_lineage` returns the plant's ancestors and children.
+pub mod propagation;
+/// **What is it?**
 /// A module containing server functions for handling public data access.
 ///
 /// **Why does it exist?**
@@ -90,6 +261,24 @@ pub mod scanner;
 /// Call these functions from landing pages or public gallery views where no user session is required.
 pub mod public;
 /// **What is it?**
+/// A module containing server functions for dated reminders, one-off or recurring, tied to a plant, a zone, or nothing.
+///
+/// **Why does it exist?**
+/// It exists so follow-ups that aren't watering or fertilizing, such as re-checking a sick plant, show up on the right day.
+///
+/// **How should it be used?**
+/// Schedule reminders from the Today tab's reminders panel or flows like the troubleshooting wizard; the Today tab lists due ones via `get_due_reminders`, and `push_due_reminders` runs in the background to notify.
+pub mod reminders;
+/// **What is it?**
+/// A module containing the monthly collection report job and the server function that lists past reports.
+///
+/// **Why does it exist?**
+/// It exists to turn each month's journal, climate alerts and watering record into a readable report that is pushed to the grower and kept for rereading.
+///
+/// **How should it be used?**
+/// Spawn `generate_monthly_reports` in the background loop and call `get_monthly_reports` from the Reports tab.
+pub mod reports;
+/// **What is it?**
 /// A module providing a client-side telemetry proxy to Axiom.
 ///
 /// **Why does it exist?**
@@ -100,6 +289,51 @@ pub mod public;
 /// Call `telemetry::emit_info/emit_warn/emit_error` from client-side code to send structured events to Axiom.
 pub mod telemetry;
 /// **What is it?**
+/// A module containing the server function that animates a plant's photo history.
+///
+/// **Why does it exist?**
+/// It exists so months of journal photos can be watched, downloaded and shared as a single time-lapse.
+///
+/// **How should it be used?**
+/// Call `create_timelapse` from the Gallery tab's time-lapse maker and offer the returned GIF for download.
+pub mod timelapse;
+/// **What is it?**
+/// A module containing server functions for species aliases: common, trade and synonym names.
+///
+/// **Why does it exist?**
+/// It exists so a plant can be found by the name on its nursery label or in the grower's own language.
+///
+/// **How should it be used?**
+/// Call these functions from the collection search and the plant's "Also known as" card; built-in names are shared, the rest belong to the owner.
+pub mod taxonomy;
+/// **What is it?**
+/// A module containing server functions that reconstruct plants and the collection as of a past date.
+///
+/// **Why does it exist?**
+/// It exists so growers can look back at where a plant lived and how it was cared for before something went wrong.
+///
+/// **How should it be used?**
+/// Call these functions from the History tab and a plant's "As Of" tab; they read `orchid_revision` and the journal.
+pub mod time_travel;
+/// **What is it?**
+/// A module containing server functions for pest and disease treatments and their follow-up series.
+///
+/// **Why does it exist?**
+/// It exists to record each treatment against its plant, schedule the re-treatments as reminders, and summarize open ones as badges for the collection grid.
+///
+/// **How should it be used?**
+/// Call `get_treatments`/`start_treatment`/`apply_treatment`/`resolve_treatment` from the treatments card in the plant detail view and `get_treatment_badges` once on the home page.
+pub mod treatments;
+/// **What is it?**
+/// A module containing server functions for water sources and their pH and EC/TDS readings.
+///
+/// **Why does it exist?**
+/// It exists so growers can track the water itself and be warned when it drifts out of range for the genera they keep.
+///
+/// **How should it be used?**
+/// Call these functions from the water quality section in settings; readings belong to a source, and sources to the owner.
+pub mod water_quality;
+/// **What is it?**
 /// A module containing server functions for managing physical zones.
 ///
 /// **Why does it exist?**
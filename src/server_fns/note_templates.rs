@@ -0,0 +1,183 @@
+use leptos::prelude::*;
+use crate::note_template::NoteTemplate;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::note_template::NoteTemplate;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct NoteTemplateDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub name: String,
+        #[surreal(default)]
+        pub event_type: Option<String>,
+        pub fields: Vec<String>,
+    }
+
+    impl NoteTemplateDbRow {
+        pub fn into_note_template(self) -> NoteTemplate {
+            NoteTemplate {
+                id: record_id_to_string(&self.id),
+                name: self.name,
+                event_type: self.event_type,
+                fields: self.fields,
+                builtin: false,
+            }
+        }
+    }
+}
+
+/// **What is it?**
+/// A server function that returns the note templates offered in the journal's note form: the built-in starters followed by the user's own.
+///
+/// **Why does it exist?**
+/// It exists so the note form and the template manager in settings read from one list.
+///
+/// **How should it be used?**
+/// Call it when the journal tab or the settings section mounts; picking one fills the note with `NoteTemplate::expand` and sets its event type.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_note_templates() -> Result<Vec<NoteTemplate>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::note_template::builtin_note_templates;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::NoteTemplateDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT id, name, event_type, fields, created_at FROM note_template WHERE owner = $owner ORDER BY created_at ASC")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get note templates query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get note templates query error", err_msg));
+    }
+
+    let rows: Vec<NoteTemplateDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get note templates parse failed", e))?;
+
+    Ok(builtin_note_templates().into_iter()
+        .chain(rows.into_iter().map(NoteTemplateDbRow::into_note_template))
+        .collect())
+}
+
+/// **What is it?**
+/// A server function that saves a new note template for the current user.
+///
+/// **Why does it exist?**
+/// It exists so growers can capture their own routines (a repotting checklist with medium, pot size and root condition, say) once and reuse them on every plant.
+///
+/// **How should it be used?**
+/// Call this from the template manager in settings. `event_type` must be a built-in or one of the user's custom types.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_note_template(
+    /// Name shown in the picker.
+    name: String,
+    /// Event type key the note is logged as, if any.
+    event_type: Option<String>,
+    /// Field labels, in order.
+    fields: Vec<String>,
+) -> Result<NoteTemplate, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::event_types::is_known_event_type;
+    use ssr_types::NoteTemplateDbRow;
+
+    let draft = NoteTemplate {
+        id: String::new(),
+        name: name.trim().to_string(),
+        event_type: event_type.filter(|k| !k.is_empty()),
+        fields: fields.into_iter().map(|f| f.trim().to_string()).filter(|f| !f.is_empty()).collect(),
+        builtin: false,
+    };
+    draft.validate().map_err(ServerFnError::new)?;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    if let Some(key) = &draft.event_type
+        && !is_known_event_type(&owner, key).await?
+    {
+        return Err(ServerFnError::new(format!("Unknown event type '{}'", key)));
+    }
+
+    let mut resp = db()
+        .query(
+            "CREATE note_template SET owner = $owner, name = $name, event_type = $event_type, fields = $fields \
+             RETURN id, name, event_type, fields"
+        )
+        .bind(("owner", owner))
+        .bind(("name", draft.name))
+        .bind(("event_type", draft.event_type))
+        .bind(("fields", draft.fields))
+        .await
+        .map_err(|e| internal_error("Create note template query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create note template query error", err_msg));
+    }
+
+    let row: Option<NoteTemplateDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Create note template parse failed", e))?;
+
+    row.map(NoteTemplateDbRow::into_note_template)
+        .ok_or_else(|| ServerFnError::new("Failed to create note template"))
+}
+
+/// **What is it?**
+/// A server function that removes one of the current user's note templates.
+///
+/// **Why does it exist?**
+/// It exists so users can retire templates they no longer use. The built-in starters are shared and cannot be deleted.
+///
+/// **How should it be used?**
+/// Call this from the template manager. Notes already written from the template are untouched.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_note_template(
+    /// The note_template record ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let id = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Note template ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("DELETE $id WHERE owner = $owner")
+        .bind(("id", id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete note template query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete note template query error", err_msg));
+    }
+
+    Ok(())
+}
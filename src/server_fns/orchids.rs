@@ -2,7 +2,7 @@
 
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
-use crate::orchid::{Orchid, LogEntry};
+use crate::orchid::{ArchiveReason, Orchid, LogEntry, MediumFeedback, SkipReason, WateringMethod};
 
 /// **What is it?**
 /// The struct representing the response when successfully adding a log entry for an orchid.
@@ -27,10 +27,23 @@ fn parse_record_id(id: &str) -> Result<surrealdb::types::RecordId, ServerFnError
         .map_err(|e| internal_error("Record ID parse failed", e))
 }
 
+/// SurrealQL expression for the latest reading (under 3 hours old) from the zone the orchid in `orchid_var` is placed in,
+/// as a `{ temperature, humidity, vpd }` object, or NONE when there is none. Used to set `climate` on new log entries.
+#[cfg(feature = "ssr")]
+pub(crate) fn climate_snapshot_expr(orchid_var: &str) -> String {
+    format!(
+        "(SELECT temperature, humidity, vpd FROM climate_reading \
+          WHERE zone IN (SELECT VALUE id FROM growing_zone WHERE owner = $owner AND name = {}.placement) \
+          AND recorded_at > time::now() - 3h \
+          ORDER BY recorded_at DESC LIMIT 1)[0]",
+        orchid_var
+    )
+}
+
 #[cfg(feature = "ssr")]
 pub(crate) mod ssr_types {
     use surrealdb::types::SurrealValue;
-    use crate::orchid::{Orchid, LogEntry, LightRequirement};
+    use crate::orchid::{AcquisitionSource, ArchiveReason, Orchid, LogEntry, EntryClimate, LightRequirement, MediumFeedback, SkipReason, WateringMethod};
     use crate::server_fns::auth::record_id_to_string;
 
     #[derive(serde::Deserialize, SurrealValue)]
@@ -98,6 +111,38 @@ pub(crate) mod ssr_types {
         pub active_fertilizer_multiplier: Option<f64>,
         #[surreal(default)]
         pub par_ppfd: Option<f64>,
+        #[surreal(default)]
+        pub overdue_grace_days: Option<u32>,
+        #[surreal(default)]
+        pub default_watering_method: Option<String>,
+        #[surreal(default)]
+        pub water_deferred_until: Option<chrono::DateTime<chrono::Utc>>,
+        #[surreal(default)]
+        pub reservoir_frequency_days: Option<u32>,
+        #[surreal(default)]
+        pub last_topped_up_at: Option<chrono::DateTime<chrono::Utc>>,
+        #[surreal(default)]
+        pub acquired_at: Option<chrono::DateTime<chrono::Utc>>,
+        #[surreal(default)]
+        pub acquisition_price: Option<f64>,
+        #[surreal(default)]
+        pub acquisition_source: Option<String>,
+        #[surreal(default)]
+        pub vendor: Option<surrealdb::types::RecordId>,
+        #[surreal(default)]
+        pub grex_name: Option<String>,
+        #[surreal(default)]
+        pub seed_parent: Option<String>,
+        #[surreal(default)]
+        pub pollen_parent: Option<String>,
+        #[surreal(default)]
+        pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+        #[surreal(default)]
+        pub archive_reason: Option<String>,
+        #[surreal(default)]
+        pub cover_image: Option<String>,
+        #[surreal(default)]
+        pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
     }
 
     #[derive(serde::Deserialize, SurrealValue, Clone)]
@@ -110,6 +155,20 @@ pub(crate) mod ssr_types {
         pub image_filename: Option<String>,
         #[surreal(default)]
         pub event_type: Option<String>,
+        #[surreal(default)]
+        pub climate: Option<EntryClimate>,
+        #[surreal(default)]
+        pub watering_method: Option<String>,
+        #[surreal(default)]
+        pub water_volume_ml: Option<u32>,
+        #[surreal(default)]
+        pub skip_reason: Option<String>,
+        #[surreal(default)]
+        pub medium_feedback: Option<String>,
+        #[surreal(default)]
+        pub measurement: Option<crate::growth::GrowthMeasurement>,
+        #[surreal(default)]
+        pub root_assessment: Option<crate::root_health::RootAssessment>,
     }
 
     impl OrchidDbRow {
@@ -167,6 +226,22 @@ pub(crate) mod ssr_types {
                 active_water_multiplier: self.active_water_multiplier,
                 active_fertilizer_multiplier: self.active_fertilizer_multiplier,
                 par_ppfd: self.par_ppfd,
+                overdue_grace_days: self.overdue_grace_days,
+                default_watering_method: self.default_watering_method.as_deref().and_then(WateringMethod::parse),
+                water_deferred_until: self.water_deferred_until,
+                reservoir_frequency_days: self.reservoir_frequency_days,
+                last_topped_up_at: self.last_topped_up_at,
+                acquired_at: self.acquired_at,
+                acquisition_price: self.acquisition_price,
+                acquisition_source: self.acquisition_source.as_deref().and_then(AcquisitionSource::parse),
+                vendor_id: self.vendor.as_ref().map(record_id_to_string),
+                grex_name: self.grex_name,
+                seed_parent: self.seed_parent,
+                pollen_parent: self.pollen_parent,
+                archived_at: self.archived_at,
+                archive_reason: self.archive_reason.as_deref().and_then(ArchiveReason::parse),
+                cover_image: self.cover_image,
+                updated_at: self.updated_at,
             }
         }
     }
@@ -179,6 +254,13 @@ pub(crate) mod ssr_types {
                 note: self.note,
                 image_filename: self.image_filename,
                 event_type: self.event_type,
+                climate: self.climate,
+                watering_method: self.watering_method.as_deref().and_then(WateringMethod::parse),
+                water_volume_ml: self.water_volume_ml,
+                skip_reason: self.skip_reason.as_deref().and_then(SkipReason::parse),
+                medium_feedback: self.medium_feedback.as_deref().and_then(MediumFeedback::parse),
+                measurement: self.measurement,
+                root_assessment: self.root_assessment,
             }
         }
     }
@@ -255,7 +337,26 @@ fn validate_orchid_fields(
 }
 
 #[cfg(feature = "ssr")]
-fn validate_filename(filename: &str) -> Result<(), ServerFnError> {
+fn validate_acquisition_price(price: Option<f64>) -> Result<(), ServerFnError> {
+    if price.is_some_and(|p| !p.is_finite() || p < 0.0 || p > 1_000_000.0) {
+        return Err(ServerFnError::new("Price must be between 0 and 1,000,000"));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+fn validate_parentage(names: &[&Option<String>]) -> Result<(), ServerFnError> {
+    use crate::orchid::MAX_PARENTAGE_LEN;
+    if names.iter().any(|n| n.as_ref().is_some_and(|n| n.len() > MAX_PARENTAGE_LEN)) {
+        return Err(ServerFnError::new(format!(
+            "Grex and parent names must be at most {} characters", MAX_PARENTAGE_LEN
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn validate_filename(filename: &str) -> Result<(), ServerFnError> {
     // Block path traversal
     if filename.contains("..") || filename.contains('\\') || filename.starts_with('/') {
         return Err(ServerFnError::new("Invalid image filename"));
@@ -285,15 +386,16 @@ fn validate_filename(filename: &str) -> Result<(), ServerFnError> {
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_orchids() -> Result<Vec<Orchid>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_record_id(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_record_id(&owner_id)?;
 
     let mut response = db()
-        .query("SELECT * FROM orchid WHERE owner = $owner ORDER BY created_at DESC")
+        .query("SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE ORDER BY created_at DESC")
         .bind(("owner", owner))
         .await
         .map_err(|e| internal_error("Get orchids query failed", e))?;
@@ -311,6 +413,94 @@ pub async fn get_orchids() -> Result<Vec<Orchid>, ServerFnError> {
     Ok(db_rows.into_iter().map(|r| r.into_orchid()).collect())
 }
 
+/// Number of orchids returned per page when the collection is loaded incrementally.
+pub const ORCHID_PAGE_SIZE: u32 = 48;
+
+/// Upper bound on the page size a client may request from `get_orchids_page`.
+const MAX_ORCHID_PAGE_SIZE: u32 = 200;
+
+/// **What is it?**
+/// A single page of the user's orchid collection, plus the cursor for the next page.
+///
+/// **Why does it exist?**
+/// It exists so large collections (hundreds of plants with photos) can be streamed to the client in slices instead of one large payload that delays the first render.
+///
+/// **How should it be used?**
+/// Render `orchids` immediately and, when the user scrolls near the end of the list, call `get_orchids_page` again with `next_offset`. A `None` cursor means the collection is fully loaded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrchidPage {
+    /// The orchids in this page, newest first.
+    pub orchids: Vec<Orchid>,
+    /// The offset to request for the following page, or `None` if this was the last page.
+    pub next_offset: Option<u32>,
+}
+
+/// Clamp a client-requested page size into the supported range.
+pub fn clamp_page_size(limit: u32) -> u32 {
+    limit.clamp(1, MAX_ORCHID_PAGE_SIZE)
+}
+
+/// Split a "limit + 1" query result into a page and its next cursor.
+/// The extra row (if present) only signals that more data exists and is dropped.
+pub fn split_page<T>(mut rows: Vec<T>, offset: u32, limit: u32) -> (Vec<T>, Option<u32>) {
+    let has_more = rows.len() > limit as usize;
+    rows.truncate(limit as usize);
+    let next = has_more.then(|| offset + limit);
+    (rows, next)
+}
+
+/// **What is it?**
+/// A server function that retrieves one page of the authenticated user's orchid collection.
+///
+/// **Why does it exist?**
+/// It exists to keep the first render of the home page fast for large collections by loading plants in slices, newest first, rather than all at once.
+///
+/// **How should it be used?**
+/// Call it with `offset = 0` on page load, then with the returned `next_offset` each time the collection view requests more (e.g. infinite scroll).
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(offset = offset, limit = limit))]
+pub async fn get_orchids_page(
+    /// The number of orchids to skip (the cursor returned by the previous page).
+    offset: u32,
+    /// The maximum number of orchids to return.
+    limit: u32,
+) -> Result<OrchidPage, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let limit = clamp_page_size(limit);
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_record_id(&owner_id)?;
+
+    // Fetch one extra row to know whether another page exists without a count() query
+    let mut response = db()
+        .query("SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE ORDER BY created_at DESC LIMIT $limit START $start")
+        .bind(("owner", owner))
+        .bind(("limit", limit as i64 + 1))
+        .bind(("start", offset as i64))
+        .await
+        .map_err(|e| internal_error("Get orchids page query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get orchids page query error", err_msg));
+    }
+
+    let db_rows: Vec<OrchidDbRow> = response.take(0)
+        .map_err(|e| internal_error("Get orchids page parse failed", e))?;
+
+    let (rows, next_offset) = split_page(db_rows, offset, limit);
+    tracing::debug!(count = rows.len(), has_more = next_offset.is_some(), "get_orchids_page: loaded page from DB");
+
+    Ok(OrchidPage {
+        orchids: rows.into_iter().map(|r| r.into_orchid()).collect(),
+        next_offset,
+    })
+}
+
 /// **What is it?**
 /// A server function that validates and creates a new orchid record in the database.
 ///
@@ -382,21 +572,36 @@ pub async fn create_orchid(
     active_fertilizer_multiplier: Option<f64>,
     /// Measured PAR (PPFD) in µmol/m²/s.
     par_ppfd: Option<f64>,
+    /// When the plant was acquired.
+    acquired_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// What was paid for it.
+    acquisition_price: Option<f64>,
+    /// How it was obtained.
+    acquisition_source: Option<crate::orchid::AcquisitionSource>,
+    /// The vendor record it was bought from.
+    vendor_id: Option<String>,
 ) -> Result<Orchid, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
     let light_requirement = normalize_light_requirement(&light_requirement);
 
+    let notes = crate::markdown::sanitize(&notes);
     validate_orchid_fields(&name, &species, &notes, water_frequency_days, &light_requirement, &placement, &light_lux, &temperature_range, &conservation_status)?;
+    validate_acquisition_price(acquisition_price)?;
 
-    let user_id = require_auth().await?;
-    let owner = parse_record_id(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_record_id(&owner_id)?;
+    let vendor = vendor_id.as_deref().map(parse_record_id).transpose()?;
 
     let mut response = db()
         .query(
-            "CREATE orchid SET \
+            "IF $vendor != NONE AND (SELECT VALUE id FROM $vendor WHERE owner = $owner) = [] { \
+                 THROW 'Vendor not found'; \
+             }; \
+             CREATE orchid SET \
              owner = $owner, name = $name, species = $species, \
              water_frequency_days = $water_freq, light_requirement = $light_req, \
              notes = $notes, placement = $placement, light_lux = $light_lux, \
@@ -411,7 +616,9 @@ pub async fn create_orchid(
              bloom_start_month = $bloom_start, bloom_end_month = $bloom_end, \
              rest_water_multiplier = $rest_water_mult, rest_fertilizer_multiplier = $rest_fert_mult, \
              active_water_multiplier = $active_water_mult, active_fertilizer_multiplier = $active_fert_mult, \
-             par_ppfd = $par_ppfd \
+             par_ppfd = $par_ppfd, \
+             acquired_at = $acquired_at, acquisition_price = $price, \
+             acquisition_source = $source, vendor = $vendor \
              RETURN *"
         )
         .bind(("owner", owner))
@@ -445,6 +652,10 @@ pub async fn create_orchid(
         .bind(("active_water_mult", active_water_multiplier))
         .bind(("active_fert_mult", active_fertilizer_multiplier))
         .bind(("par_ppfd", par_ppfd))
+        .bind(("acquired_at", acquired_at))
+        .bind(("price", acquisition_price))
+        .bind(("source", acquisition_source.map(|s| s.as_str().to_string())))
+        .bind(("vendor", vendor))
         .await
         .map_err(|e| internal_error("Create orchid query failed", e))?;
 
@@ -454,13 +665,56 @@ pub async fn create_orchid(
         return Err(internal_error("Create orchid query error", err_msg));
     }
 
-    let db_row: Option<OrchidDbRow> = response.take(0)
+    let db_row: Option<OrchidDbRow> = response.take(1)
         .map_err(|e| internal_error("Create orchid parse failed", e))?;
 
     db_row.map(|r| r.into_orchid())
         .ok_or_else(|| ServerFnError::new("Failed to create orchid"))
 }
 
+/// **What is it?**
+/// A server function that loads one plant in the user's collection as it is saved now.
+///
+/// **Why does it exist?**
+/// It exists so an edit that `update_orchid` rejected as a conflict can be merged with the copy the other device saved.
+///
+/// **How should it be used?**
+/// Call it with the plant's ID when `update_orchid` fails with `edit_conflict::EDIT_CONFLICT`, then pass the result to `merge_edit`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_orchid(
+    /// The plant's record ID.
+    orchid_id: String,
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_record_id(&owner_id)?;
+    let id = parse_record_id(&orchid_id)?;
+
+    let mut response = db()
+        .query("SELECT * FROM $id WHERE owner = $owner")
+        .bind(("id", id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get orchid query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get orchid query error", err_msg));
+    }
+
+    let rows: Vec<OrchidDbRow> = response.take(0)
+        .map_err(|e| internal_error("Get orchid parse failed", e))?;
+    rows.into_iter().next()
+        .map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
 /// **What is it?**
 /// A server function that applies changes to an existing orchid record.
 ///
@@ -475,10 +729,12 @@ pub async fn update_orchid(
     /// The fully populated Orchid struct containing the updated data.
     orchid: Orchid
 ) -> Result<Orchid, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
+    let orchid = Orchid { notes: crate::markdown::sanitize(&orchid.notes), ..orchid };
     let light_req_str = orchid.light_requirement.as_str();
     let placement_str = orchid.placement.clone();
 
@@ -492,14 +748,30 @@ pub async fn update_orchid(
     );
 
     validate_orchid_fields(&orchid.name, &orchid.species, &orchid.notes, orchid.water_frequency_days, light_req_str, &placement_str, &orchid.light_lux, &orchid.temperature_range, &orchid.conservation_status)?;
+    if orchid.overdue_grace_days.is_some_and(|d| d > crate::orchid::MAX_OVERDUE_GRACE_DAYS) {
+        return Err(ServerFnError::new(format!(
+            "Overdue grace period must be 0-{} days", crate::orchid::MAX_OVERDUE_GRACE_DAYS
+        )));
+    }
+    if orchid.reservoir_frequency_days.is_some_and(|d| d == 0 || d > crate::orchid::MAX_RESERVOIR_DAYS) {
+        return Err(ServerFnError::new(format!(
+            "Reservoir top-up interval must be 1-{} days", crate::orchid::MAX_RESERVOIR_DAYS
+        )));
+    }
+    validate_acquisition_price(orchid.acquisition_price)?;
+    validate_parentage(&[&orchid.grex_name, &orchid.seed_parent, &orchid.pollen_parent])?;
 
-    let user_id = require_auth().await?;
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
     let orchid_id = parse_record_id(&orchid.id)?;
-    let owner = parse_record_id(&user_id)?;
+    let owner = parse_record_id(&owner_id)?;
+    let vendor = orchid.vendor_id.as_deref().map(parse_record_id).transpose()?;
 
     let mut response = db()
         .query(
-            "UPDATE $id SET \
+            "IF $vendor != NONE AND (SELECT VALUE id FROM $vendor WHERE owner = $owner) = [] { \
+                 THROW 'Vendor not found'; \
+             }; \
+             UPDATE $id SET \
              name = $name, species = $species, \
              water_frequency_days = $water_freq, light_requirement = $light_req, \
              notes = $notes, placement = $placement, light_lux = $light_lux, \
@@ -514,12 +786,19 @@ pub async fn update_orchid(
              bloom_start_month = $bloom_start, bloom_end_month = $bloom_end, \
              rest_water_multiplier = $rest_water_mult, rest_fertilizer_multiplier = $rest_fert_mult, \
              active_water_multiplier = $active_water_mult, active_fertilizer_multiplier = $active_fert_mult, \
-             par_ppfd = $par_ppfd, \
+             par_ppfd = $par_ppfd, overdue_grace_days = $grace_days, \
+             default_watering_method = $default_watering_method, \
+             reservoir_frequency_days = $reservoir_freq, \
+             acquired_at = $acquired_at, acquisition_price = $price, \
+             acquisition_source = $source, vendor = $vendor, \
+             grex_name = $grex_name, seed_parent = $seed_parent, pollen_parent = $pollen_parent, \
              updated_at = time::now() \
-             WHERE owner = $owner \
-             RETURN *"
+             WHERE owner = $owner AND updated_at = $expected \
+             RETURN *; \
+             SELECT VALUE id FROM $id WHERE owner = $owner;"
         )
         .bind(("id", orchid_id))
+        .bind(("expected", orchid.updated_at))
         .bind(("owner", owner))
         .bind(("name", orchid.name))
         .bind(("species", orchid.species))
@@ -551,6 +830,16 @@ pub async fn update_orchid(
         .bind(("active_water_mult", orchid.active_water_multiplier))
         .bind(("active_fert_mult", orchid.active_fertilizer_multiplier))
         .bind(("par_ppfd", orchid.par_ppfd))
+        .bind(("grace_days", orchid.overdue_grace_days.map(|v| v as i64)))
+        .bind(("default_watering_method", orchid.default_watering_method.map(|m| m.as_str().to_string())))
+        .bind(("reservoir_freq", orchid.reservoir_frequency_days.map(|v| v as i64)))
+        .bind(("acquired_at", orchid.acquired_at))
+        .bind(("price", orchid.acquisition_price))
+        .bind(("source", orchid.acquisition_source.map(|s| s.as_str().to_string())))
+        .bind(("vendor", vendor))
+        .bind(("grex_name", orchid.grex_name))
+        .bind(("seed_parent", orchid.seed_parent))
+        .bind(("pollen_parent", orchid.pollen_parent))
         .await
         .map_err(|e| internal_error("Update orchid query failed", e))?;
 
@@ -560,7 +849,7 @@ pub async fn update_orchid(
         return Err(internal_error("Update orchid query error", err_msg));
     }
 
-    let updated: Option<OrchidDbRow> = response.take(0)
+    let updated: Option<OrchidDbRow> = response.take(1)
         .map_err(|e| internal_error("Update orchid parse failed", e))?;
 
     let result = updated.map(|r| {
@@ -572,8 +861,18 @@ pub async fn update_orchid(
         );
         r.into_orchid()
     });
+    if let Some(orchid) = result {
+        return Ok(orchid);
+    }
 
-    result.ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+    // Nothing updated: either the plant isn't ours, or it was saved since this copy was loaded
+    let exists: Vec<surrealdb::types::RecordId> = response.take(2)
+        .map_err(|e| internal_error("Update orchid parse failed", e))?;
+    if exists.is_empty() {
+        Err(ServerFnError::new("Orchid not found or not owned by you"))
+    } else {
+        Err(ServerFnError::new(crate::edit_conflict::EDIT_CONFLICT))
+    }
 }
 
 /// **What is it?**
@@ -590,16 +889,18 @@ pub async fn delete_orchid(
     /// The unique identifier of the orchid to delete.
     id: String
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
     let orchid_id = parse_record_id(&id)?;
-    let owner = parse_record_id(&user_id)?;
+    let owner = parse_record_id(&owner_id)?;
 
     db()
-        .query("DELETE $id WHERE owner = $owner")
+        .query("DELETE $id WHERE owner = $owner; DELETE orchid_revision WHERE orchid = $id AND owner = $owner; DELETE award WHERE orchid = $id AND owner = $owner; \
+                DELETE reminder WHERE (orchid = $id OR treatment.orchid = $id) AND owner = $owner; DELETE treatment WHERE orchid = $id AND owner = $owner;")
         .bind(("id", orchid_id))
         .bind(("owner", owner))
         .await
@@ -608,11 +909,197 @@ pub async fn delete_orchid(
     Ok(())
 }
 
+/// **What is it?**
+/// A server function that lists the plants that have left the collection, most recently archived first.
+///
+/// **Why does it exist?**
+/// It exists because `get_orchids` only returns plants still being grown, while the archive view needs the ones that died or were passed on.
+///
+/// **How should it be used?**
+/// Load from the Archive tab. Open a plant's journal with `get_log_entries` as usual; it's kept intact.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_archived_orchids() -> Result<Vec<Orchid>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_record_id(&owner_id)?;
+
+    let mut response = db()
+        .query("SELECT * FROM orchid WHERE owner = $owner AND archived_at != NONE ORDER BY archived_at DESC")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get archived orchids query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get archived orchids query error", err_msg));
+    }
+
+    let db_rows: Vec<OrchidDbRow> = response.take(0)
+        .map_err(|e| internal_error("Get archived orchids parse failed", e))?;
+
+    Ok(db_rows.into_iter().map(|r| r.into_orchid()).collect())
+}
+
+/// **What is it?**
+/// A server function that moves a plant out of the collection into the archive, recording why and when it left.
+///
+/// **Why does it exist?**
+/// It exists so plants that died or were sold, traded or gifted stop showing up in today's tasks, watering schedules and alerts, without deleting their journal the way `delete_orchid` does.
+///
+/// **How should it be used?**
+/// Call from the archive form in the plant detail view, then drop the plant from the local collection list. Undo with `restore_orchid`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn archive_orchid(
+    /// The unique identifier of the orchid.
+    id: String,
+    /// Why the plant left the collection.
+    reason: ArchiveReason,
+    /// When it left.
+    archived_at: chrono::DateTime<chrono::Utc>,
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if archived_at > chrono::Utc::now() {
+        return Err(ServerFnError::new("Archive date can't be in the future"));
+    }
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let orchid_id = parse_record_id(&id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    let mut response = db()
+        .query("UPDATE $id SET archived_at = $archived_at, archive_reason = $reason, updated_at = time::now() WHERE owner = $owner RETURN *")
+        .bind(("id", orchid_id))
+        .bind(("owner", owner))
+        .bind(("archived_at", archived_at))
+        .bind(("reason", reason.as_str().to_string()))
+        .await
+        .map_err(|e| internal_error("Archive orchid query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Archive orchid query error", err_msg));
+    }
+
+    let updated: Option<OrchidDbRow> = response.take(0)
+        .map_err(|e| internal_error("Archive orchid parse failed", e))?;
+    updated.map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
+/// **What is it?**
+/// A server function that brings an archived plant back into the collection.
+///
+/// **Why does it exist?**
+/// It exists to undo an archive made by mistake, or to take back a plant that was lent out.
+///
+/// **How should it be used?**
+/// Call from the Archive tab, then reload the collection so the plant reappears in its schedules.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn restore_orchid(
+    /// The unique identifier of the orchid.
+    id: String,
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let orchid_id = parse_record_id(&id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    let mut response = db()
+        .query("UPDATE $id SET archived_at = NONE, archive_reason = NONE, updated_at = time::now() WHERE owner = $owner RETURN *")
+        .bind(("id", orchid_id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Restore orchid query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Restore orchid query error", err_msg));
+    }
+
+    let updated: Option<OrchidDbRow> = response.take(0)
+        .map_err(|e| internal_error("Restore orchid parse failed", e))?;
+    updated.map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
+/// **What is it?**
+/// A server function that picks which of a plant's journal photos represents it on cards and public pages.
+///
+/// **Why does it exist?**
+/// It exists because the latest photo is often a close-up of a root or a pest, not the shot a grower wants to show the plant by.
+///
+/// **How should it be used?**
+/// Call from the "Set as cover" action in the photo gallery with one of the plant's own journal photos, or `None` to go back to the latest photo.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn set_cover_image(
+    /// The unique identifier of the orchid.
+    id: String,
+    /// A photo from the plant's journal, or None to clear the cover.
+    image_filename: Option<String>,
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if let Some(ref filename) = image_filename {
+        validate_filename(filename)?;
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let orchid_id = parse_record_id(&id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    // Only photos already in this plant's journal can become its cover
+    let mut response = db()
+        .query(
+            "IF $image != NONE AND (SELECT VALUE id FROM log_entry \
+             WHERE orchid = $id AND owner = $owner AND image_filename = $image LIMIT 1) = [] \
+             { THROW 'Photo not found' }; \
+             UPDATE $id SET cover_image = $image, updated_at = time::now() WHERE owner = $owner RETURN *"
+        )
+        .bind(("id", orchid_id))
+        .bind(("owner", owner))
+        .bind(("image", image_filename))
+        .await
+        .map_err(|e| internal_error("Set cover image query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Set cover image query error", err_msg));
+    }
+
+    let updated: Option<OrchidDbRow> = response.take(1)
+        .map_err(|e| internal_error("Set cover image parse failed", e))?;
+    updated.map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
 /// **What is it?**
 /// A server function that creates a new log entry for a specific orchid, such as a watering or repotting event.
 ///
 /// **Why does it exist?**
-/// It exists to allow users to maintain a detailed history of care actions, and to automatically trigger side effects (like updating `last_watered_at`).
+/// It exists to allow users to maintain a detailed history of care actions, and to automatically trigger side effects (like updating `last_watered_at`, or `last_topped_up_at` for a semi-hydro reservoir top-up).
 ///
 /// **How should it be used?**
 /// Call this from the "Add Entry" timeline UI, specifying the plant, the type of action performed, and any optional notes or images.
@@ -627,53 +1114,71 @@ pub async fn add_log_entry(
     image_filename: Option<String>,
     /// The type of event (e.g., "Watered", "Fertilized").
     event_type: Option<String>,
+    /// How the plant was watered, for "Watered" entries; None records the plant's default.
+    watering_method: Option<WateringMethod>,
+    /// Water given in millilitres, for "Watered" entries.
+    water_volume_ml: Option<u32>,
 ) -> Result<AddLogEntryResponse, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
+    let note = crate::markdown::sanitize(&note);
     if note.len() > 5000 {
         return Err(ServerFnError::new("Note must be at most 5000 characters"));
     }
     if let Some(ref filename) = image_filename {
         validate_filename(filename)?;
     }
+    if water_volume_ml.is_some_and(|ml| ml == 0 || ml > crate::orchid::MAX_WATER_VOLUME_ML) {
+        return Err(ServerFnError::new(format!("Water volume must be 1-{} ml", crate::orchid::MAX_WATER_VOLUME_ML)));
+    }
+    // Method and volume only describe waterings
+    let is_watering = event_type.as_deref() == Some("Watered");
+    let watering_method = watering_method.filter(|_| is_watering).map(|m| m.as_str().to_string());
+    let water_volume_ml = water_volume_ml.filter(|_| is_watering).map(|ml| ml as i64);
 
-    // Validate event_type against allowed values
-    let allowed_event_types = [
-        "Flowering", "NewGrowth", "Repotted", "Fertilized",
-        "PestTreatment", "Purchased", "Watered", "Note",
-    ];
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let orchid_record = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    // Validate event_type against the built-ins and the user's custom registry
     if let Some(ref et) = event_type
-        && !allowed_event_types.contains(&et.as_str())
+        && !crate::server_fns::event_types::is_known_event_type(&owner, et).await?
     {
         return Err(ServerFnError::new("Invalid event type"));
     }
 
-    let user_id = require_auth().await?;
-    let orchid_record = parse_record_id(&orchid_id)?;
-    let owner = parse_record_id(&user_id)?;
-
     // Create log entry + update care timestamps atomically
-    // The WHERE clause with $event_type comparison makes non-matching UPDATEs no-ops
+    // The WHERE clause with $event_type comparison makes non-matching UPDATEs no-ops.
+    // A watering with no method records the plant's default; a misting only restarts the
+    // countdown on plants that are normally misted (see `WateringMethod::resets_schedule`).
     let mut response = db()
-        .query(
+        .query(format!(
             "BEGIN TRANSACTION; \
              CREATE log_entry SET \
                  orchid = $orchid_id, owner = $owner, \
                  note = $note, image_filename = $image_filename, \
-                 event_type = $event_type \
+                 event_type = $event_type, climate = {}, \
+                 watering_method = IF $event_type = 'Watered' THEN $watering_method ?? $orchid_id.default_watering_method END, \
+                 water_volume_ml = $water_volume_ml \
                  RETURN *; \
-             UPDATE $orchid_id SET last_watered_at = time::now() WHERE owner = $owner AND $event_type = 'Watered'; \
-             UPDATE $orchid_id SET last_fertilized_at = time::now() WHERE owner = $owner AND $event_type = 'Fertilized'; \
-             UPDATE $orchid_id SET last_repotted_at = time::now() WHERE owner = $owner AND $event_type = 'Repotted'; \
-             COMMIT TRANSACTION;"
-        )
+             UPDATE $orchid_id SET last_watered_at = time::now(), water_deferred_until = NONE, updated_at = time::now() WHERE owner = $owner AND $event_type = 'Watered' \
+                 AND ($watering_method != 'Mist' OR default_watering_method = 'Mist'); \
+             UPDATE $orchid_id SET last_fertilized_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'Fertilized'; \
+             UPDATE $orchid_id SET last_repotted_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'Repotted'; \
+             UPDATE $orchid_id SET last_topped_up_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'ToppedUp'; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$orchid_id")
+        ))
         .bind(("orchid_id", orchid_record.clone()))
         .bind(("owner", owner.clone()))
         .bind(("note", note))
         .bind(("image_filename", image_filename))
         .bind(("event_type", event_type.clone()))
+        .bind(("watering_method", watering_method))
+        .bind(("water_volume_ml", water_volume_ml))
         .await
         .map_err(|e| internal_error("Add log entry query failed", e))?;
 
@@ -723,7 +1228,7 @@ pub async fn add_log_entry(
             is_first_bloom = true;
             db()
                 .query(
-                    "UPDATE $orchid_id SET first_bloom_at = time::now() \
+                    "UPDATE $orchid_id SET first_bloom_at = time::now(), updated_at = time::now() \
                      WHERE owner = $owner"
                 )
                 .bind(("orchid_id", orchid_record))
@@ -750,13 +1255,14 @@ pub async fn get_log_entries(
     /// The unique identifier of the orchid.
     orchid_id: String
 ) -> Result<Vec<LogEntry>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
     let orchid_record = parse_record_id(&orchid_id)?;
-    let owner = parse_record_id(&user_id)?;
+    let owner = parse_record_id(&owner_id)?;
 
     let mut response = db()
         .query("SELECT * FROM log_entry WHERE orchid = $orchid_id AND owner = $owner ORDER BY timestamp DESC")
@@ -778,43 +1284,126 @@ pub async fn get_log_entries(
 }
 
 /// **What is it?**
-/// A server function that marks a specific orchid as having just been watered.
+/// A server function that returns one page of an orchid's journal, newest first, narrowed by event type, date range and keyword.
 ///
 /// **Why does it exist?**
-/// It exists as a convenience endpoint to quickly update the `last_watered_at` timestamp and automatically create a corresponding log entry without requiring the user to fill out a full form.
+/// It exists because plants with years of history produce timelines too long to load and scroll at once; the timeline fetches a page at a time and only what matches its filters.
 ///
 /// **How should it be used?**
-/// Call this from a "Water Now" button in the collection grid or detailed view.
+/// Call this from the Journal tab with the filter bar's `JournalQuery`, starting at offset 0 and adding `JOURNAL_PAGE_SIZE` for "Show older entries". `total` counts every match, so the caller knows when it has them all.
 #[server]
-#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
-pub async fn mark_watered(
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_log_entries_page(
     /// The unique identifier of the orchid.
-    orchid_id: String
-) -> Result<Orchid, ServerFnError> {
-    use crate::auth::require_auth;
+    orchid_id: String,
+    /// The filters to apply; the default matches every entry.
+    query: crate::journal::JournalQuery,
+    /// How many matching entries to skip.
+    offset: u32,
+    /// How many entries to return, at most `MAX_JOURNAL_PAGE_SIZE`.
+    limit: u32,
+) -> Result<crate::journal::JournalPage, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
+    use crate::journal::{JournalPage, MAX_JOURNAL_PAGE_SIZE, MAX_JOURNAL_SEARCH_LEN};
 
-    let user_id = require_auth().await?;
-    tracing::info!(orchid_id = %orchid_id, user_id = %user_id, "mark_watered called");
-    let oid = parse_record_id(&orchid_id)?;
-    let owner = parse_record_id(&user_id)?;
+    if query.text.chars().count() > MAX_JOURNAL_SEARCH_LEN {
+        return Err(ServerFnError::new(format!("Search must be at most {} characters", MAX_JOURNAL_SEARCH_LEN)));
+    }
+    if let (Some(from), Some(to)) = (query.from, query.to)
+        && from > to
+    {
+        return Err(ServerFnError::new("The start date must be on or before the end date"));
+    }
 
-    // Update orchid + create log entry atomically
-    let mut response = db()
-        .query(
-            "BEGIN TRANSACTION; \
-             UPDATE $id SET last_watered_at = time::now() WHERE owner = $owner RETURN *; \
-             CREATE log_entry SET orchid = $id, owner = $owner, note = 'Watered', event_type = 'Watered'; \
-             COMMIT TRANSACTION;"
-        )
-        .bind(("id", oid))
-        .bind(("owner", owner))
-        .await
-        .map_err(|e| internal_error("Mark watered query failed", e))?;
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let orchid_record = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
 
-    let errors = response.take_errors();
-    if !errors.is_empty() {
+    let mut conditions = vec!["orchid = $orchid_id", "owner = $owner"];
+    if query.event_type.is_some() {
+        conditions.push("event_type = $event_type");
+    }
+    if query.from.is_some() {
+        conditions.push("timestamp >= $from");
+    }
+    if query.to.is_some() {
+        conditions.push("timestamp < $until");
+    }
+    let needle = query.needle();
+    if !needle.is_empty() {
+        conditions.push("string::contains(string::lowercase(note), $needle)");
+    }
+    let filter = conditions.join(" AND ");
+
+    let mut response = db()
+        .query(format!(
+            "SELECT VALUE count() FROM log_entry WHERE {filter} GROUP ALL; \
+             SELECT * FROM log_entry WHERE {filter} ORDER BY timestamp DESC LIMIT $limit START $offset;"
+        ))
+        .bind(("orchid_id", orchid_record))
+        .bind(("owner", owner))
+        .bind(("event_type", query.event_type))
+        .bind(("from", query.from.map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())))
+        .bind(("until", query.to.and_then(|d| d.succ_opt()).map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())))
+        .bind(("needle", needle))
+        .bind(("limit", limit.clamp(1, MAX_JOURNAL_PAGE_SIZE) as i64))
+        .bind(("offset", offset as i64))
+        .await
+        .map_err(|e| internal_error("Get log entries page query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get log entries page query error", err_msg));
+    }
+
+    let counts: Vec<i64> = response.take(0)
+        .map_err(|e| internal_error("Get log entries count parse failed", e))?;
+    let db_rows: Vec<LogEntryDbRow> = response.take(1)
+        .map_err(|e| internal_error("Get log entries page parse failed", e))?;
+
+    Ok(JournalPage {
+        entries: db_rows.into_iter().map(|r| r.into_log_entry()).collect(),
+        total: counts.first().copied().unwrap_or(0).max(0) as u32,
+    })
+}
+
+/// **What is it?**
+/// The shared write behind "Water Now": restarts an orchid's watering countdown and journals a "Watered" entry with its usual method, in one transaction.
+///
+/// **Why does it exist?**
+/// It exists so `mark_watered` and the "Mark watered" push notification action log a watering the same way.
+///
+/// **How should it be used?**
+/// Call after checking the caller may act as a caretaker in `owner`'s collection. `None` means the orchid doesn't exist or isn't in that collection.
+#[cfg(feature = "ssr")]
+pub(crate) async fn record_watering(
+    owner: surrealdb::types::RecordId,
+    orchid: surrealdb::types::RecordId,
+) -> Result<Option<Orchid>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    // Update orchid + create log entry atomically
+    let mut response = db()
+        .query(format!(
+            "BEGIN TRANSACTION; \
+             UPDATE $id SET last_watered_at = time::now(), water_deferred_until = NONE, updated_at = time::now() WHERE owner = $owner RETURN *; \
+             CREATE log_entry SET orchid = $id, owner = $owner, note = 'Watered', event_type = 'Watered', climate = {}, \
+                 watering_method = $id.default_watering_method; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$id")
+        ))
+        .bind(("id", orchid))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Mark watered query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
         let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
         return Err(internal_error("Mark watered query error", err_msg));
     }
@@ -823,27 +1412,51 @@ pub async fn mark_watered(
     let db_row: Option<OrchidDbRow> = response.take(1)
         .map_err(|e| internal_error("Mark watered parse failed", e))?;
 
-    let orchid = db_row.map(|r| r.into_orchid())
-        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))?;
+    Ok(db_row.map(|r| r.into_orchid()))
+}
 
-    Ok(orchid)
+/// **What is it?**
+/// A server function that marks a specific orchid as having just been watered.
+///
+/// **Why does it exist?**
+/// It exists as a convenience endpoint to quickly update the `last_watered_at` timestamp and automatically create a corresponding log entry without requiring the user to fill out a full form.
+///
+/// **How should it be used?**
+/// Call this from a "Water Now" button in the collection grid or detailed view. The entry records the plant's default watering method, and always restarts the countdown.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn mark_watered(
+    /// The unique identifier of the orchid.
+    orchid_id: String
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    tracing::info!(orchid_id = %orchid_id, owner_id = %owner_id, "mark_watered called");
+    let oid = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    record_watering(owner, oid).await?
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
 }
 
 /// **What is it?**
 /// A server function that marks multiple orchids as having just been watered.
 ///
 /// **Why does it exist?**
-/// It provides a bulk action endpoint to update the `last_watered_at` timestamp for a group of plants in a single request.
+/// It provides a bulk action endpoint so watering day is one click per zone instead of one tap per plant: every plant's `last_watered_at` is updated and a journal entry (with its usual watering method) written in one transaction.
 ///
 /// **How should it be used?**
-/// Call this from a "Water All" button in the Today tasks view or collection view.
+/// Call this from the "Water All Due" buttons in the Today tasks view and the cabinet table's zone headers. IDs not owned by the collection are skipped; the returned orchids are the ones actually updated.
 #[server]
 #[tracing::instrument(level = "info", skip_all, fields(count = orchid_ids.len()))]
-pub async fn mark_watered_batch(
+pub async fn mark_many_watered(
     /// The unique identifiers of the orchids to water.
     orchid_ids: Vec<String>
 ) -> Result<Vec<Orchid>, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
@@ -851,9 +1464,9 @@ pub async fn mark_watered_batch(
         return Ok(vec![]);
     }
 
-    let user_id = require_auth().await?;
-    tracing::info!(user_id = %user_id, count = %orchid_ids.len(), "mark_watered_batch called");
-    let owner = parse_record_id(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    tracing::info!(owner_id = %owner_id, count = %orchid_ids.len(), "mark_many_watered called");
+    let owner = parse_record_id(&owner_id)?;
 
     let mut oids = Vec::new();
     for id in &orchid_ids {
@@ -862,14 +1475,16 @@ pub async fn mark_watered_batch(
 
     // Update orchids + create log entries atomically
     let mut response = db()
-        .query(
+        .query(format!(
             "BEGIN TRANSACTION; \
-             UPDATE $ids SET last_watered_at = time::now() WHERE owner = $owner RETURN *; \
-             FOR $oid IN $ids { \
-                 CREATE log_entry SET orchid = $oid, owner = $owner, note = 'Watered', event_type = 'Watered'; \
-             }; \
-             COMMIT TRANSACTION;"
-        )
+             UPDATE $ids SET last_watered_at = time::now(), water_deferred_until = NONE, updated_at = time::now() WHERE owner = $owner RETURN *; \
+             FOR $oid IN (SELECT VALUE id FROM $ids WHERE owner = $owner) {{ \
+                 CREATE log_entry SET orchid = $oid, owner = $owner, note = 'Watered', event_type = 'Watered', climate = {}, \
+                     watering_method = $oid.default_watering_method; \
+             }}; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$oid")
+        ))
         .bind(("ids", oids))
         .bind(("owner", owner))
         .await
@@ -890,6 +1505,590 @@ pub async fn mark_watered_batch(
     Ok(orchids)
 }
 
+/// Most plants one bulk journal entry may be applied to.
+pub const MAX_BULK_LOG_PLANTS: usize = 100;
+
+/// **What is it?**
+/// A server function that writes the same journal entry (note, photo and event type) to several orchids at once.
+///
+/// **Why does it exist?**
+/// Collection-wide jobs like a preventative fungicide spray or a spring repotting session touch dozens of plants; logging them one by one is tedious and easy to abandon halfway.
+///
+/// **How should it be used?**
+/// Call this from the collection's multi-select mode. Each plant gets its own entry with its own climate snapshot, and the event's care timestamp is updated as `add_log_entry` would. IDs not owned by the collection are skipped; the returned orchids are the ones logged.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(count = orchid_ids.len()))]
+pub async fn add_log_entry_bulk(
+    /// The unique identifiers of the orchids to log the entry for.
+    orchid_ids: Vec<String>,
+    /// The note or description of the event.
+    note: String,
+    /// An optional image filename shared by every entry.
+    image_filename: Option<String>,
+    /// The type of event (e.g., "Watered", "Fertilized").
+    event_type: Option<String>,
+) -> Result<Vec<Orchid>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if orchid_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    if orchid_ids.len() > MAX_BULK_LOG_PLANTS {
+        return Err(ServerFnError::new(format!("A bulk entry can cover at most {} plants", MAX_BULK_LOG_PLANTS)));
+    }
+    let note = crate::markdown::sanitize(&note);
+    if note.len() > 5000 {
+        return Err(ServerFnError::new("Note must be at most 5000 characters"));
+    }
+    if note.trim().is_empty() && image_filename.is_none() && event_type.is_none() {
+        return Err(ServerFnError::new("Add a note, photo or event type"));
+    }
+    if let Some(ref filename) = image_filename {
+        validate_filename(filename)?;
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    tracing::info!(owner_id = %owner_id, count = %orchid_ids.len(), "add_log_entry_bulk called");
+    let owner = parse_record_id(&owner_id)?;
+
+    let mut oids = Vec::new();
+    for id in &orchid_ids {
+        oids.push(parse_record_id(id)?);
+    }
+
+    if let Some(ref et) = event_type
+        && !crate::server_fns::event_types::is_known_event_type(&owner, et).await?
+    {
+        return Err(ServerFnError::new("Invalid event type"));
+    }
+
+    // Create the entries + update care timestamps atomically, as in `add_log_entry`.
+    // Waterings record each plant's default method, so they always restart the countdown.
+    // A 'Flowering' entry only sets `first_bloom_at` on plants that have never bloomed.
+    let mut response = db()
+        .query(format!(
+            "BEGIN TRANSACTION; \
+             FOR $oid IN (SELECT VALUE id FROM $ids WHERE owner = $owner) {{ \
+                 CREATE log_entry SET orchid = $oid, owner = $owner, \
+                     note = $note, image_filename = $image_filename, \
+                     event_type = $event_type, climate = {}, \
+                     watering_method = IF $event_type = 'Watered' THEN $oid.default_watering_method END; \
+             }}; \
+             UPDATE $ids SET last_watered_at = time::now(), water_deferred_until = NONE, updated_at = time::now() WHERE owner = $owner AND $event_type = 'Watered'; \
+             UPDATE $ids SET last_fertilized_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'Fertilized'; \
+             UPDATE $ids SET last_repotted_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'Repotted'; \
+             UPDATE $ids SET last_topped_up_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'ToppedUp'; \
+             UPDATE $ids SET first_bloom_at = time::now(), updated_at = time::now() WHERE owner = $owner AND $event_type = 'Flowering' AND first_bloom_at = NONE; \
+             SELECT * FROM $ids WHERE owner = $owner; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$oid")
+        ))
+        .bind(("ids", oids))
+        .bind(("owner", owner))
+        .bind(("note", note))
+        .bind(("image_filename", image_filename))
+        .bind(("event_type", event_type))
+        .await
+        .map_err(|e| internal_error("Bulk log entry query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Bulk log entry query error", err_msg));
+    }
+
+    // Index 7 = final SELECT (BEGIN, FOR, five UPDATEs before it)
+    let db_rows: Vec<OrchidDbRow> = response.take(7)
+        .map_err(|e| internal_error("Bulk log entry parse failed", e))?;
+
+    Ok(db_rows.into_iter().map(|r| r.into_orchid()).collect())
+}
+
+/// Most photos saved by one `add_photo_entries` call.
+pub const MAX_BULK_PHOTOS: usize = 100;
+
+/// **What is it?**
+/// One uploaded photo and the journal entry it should become: which plant, when it was taken, and an optional note.
+///
+/// **Why does it exist?**
+/// It exists so the bulk photo upload can send many photos, each for a different plant and date, in one request.
+///
+/// **How should it be used?**
+/// Fill one per photo after uploading it through `/api/images/upload`, then pass them to `add_photo_entries`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PhotoAssignment {
+    /// The plant the photo is of.
+    pub orchid_id: String,
+    /// The stored filename returned by the upload endpoint.
+    pub image_filename: String,
+    /// When the photo was taken; becomes the entry's timestamp.
+    pub taken_at: chrono::DateTime<chrono::Utc>,
+    /// Optional caption.
+    #[serde(default)]
+    pub note: String,
+}
+
+/// **What is it?**
+/// A server function that creates one dated photo entry per uploaded photo, each on its own plant's journal.
+///
+/// **Why does it exist?**
+/// Growers moving over from a camera roll arrive with hundreds of photos taken over years; adding them one journal entry at a time is not realistic.
+///
+/// **How should it be used?**
+/// Call this from the bulk photo upload once the photos are uploaded and assigned, at most `MAX_BULK_PHOTOS` at a time.
+/// Entries are back-dated to `taken_at` and leave care timestamps alone. Photos for plants outside the collection are skipped; returns how many entries were created.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(count = photos.len()))]
+pub async fn add_photo_entries(
+    /// The photos and where they belong.
+    photos: Vec<PhotoAssignment>,
+) -> Result<u32, ServerFnError> {
+    use surrealdb::types::SurrealValue;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    #[derive(serde::Serialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PhotoEntryRow {
+        orchid: surrealdb::types::RecordId,
+        image_filename: String,
+        timestamp: chrono::DateTime<chrono::Utc>,
+        note: String,
+    }
+
+    if photos.is_empty() {
+        return Ok(0);
+    }
+    if photos.len() > MAX_BULK_PHOTOS {
+        return Err(ServerFnError::new(format!("Save at most {} photos at a time", MAX_BULK_PHOTOS)));
+    }
+    let latest = chrono::Utc::now() + chrono::Duration::days(1);
+    let mut rows = Vec::with_capacity(photos.len());
+    for photo in photos {
+        validate_filename(&photo.image_filename)?;
+        if photo.taken_at > latest {
+            return Err(ServerFnError::new("A photo date is in the future"));
+        }
+        let note = crate::markdown::sanitize(&photo.note);
+        if note.len() > 5000 {
+            return Err(ServerFnError::new("Note must be at most 5000 characters"));
+        }
+        rows.push(PhotoEntryRow {
+            orchid: parse_record_id(&photo.orchid_id)?,
+            image_filename: photo.image_filename,
+            timestamp: photo.taken_at,
+            note,
+        });
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_record_id(&owner_id)?;
+
+    // Keep only photos for plants in this collection
+    let ids: Vec<surrealdb::types::RecordId> = rows.iter().map(|r| r.orchid.clone()).collect();
+    let mut owned_resp = db()
+        .query("SELECT VALUE id FROM $ids WHERE owner = $owner")
+        .bind(("ids", ids))
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Photo entries ownership query failed", e))?;
+    let owned: Vec<surrealdb::types::RecordId> = owned_resp.take(0)
+        .map_err(|e| internal_error("Photo entries ownership parse failed", e))?;
+    rows.retain(|r| owned.contains(&r.orchid));
+    if rows.is_empty() {
+        return Ok(0);
+    }
+    let created = rows.len() as u32;
+
+    let mut response = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             FOR $p IN $photos { \
+                 CREATE log_entry SET orchid = $p.orchid, owner = $owner, note = $p.note, \
+                     image_filename = $p.image_filename, timestamp = $p.timestamp; \
+             }; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("photos", rows))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Photo entries query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Photo entries query error", err_msg));
+    }
+
+    tracing::info!(created, "Bulk photo entries created");
+    Ok(created)
+}
+
+/// Loads one orchid owned by `owner`, for server functions that compute an update in Rust.
+#[cfg(feature = "ssr")]
+pub(crate) async fn load_owned_orchid(
+    id: &surrealdb::types::RecordId,
+    owner: &surrealdb::types::RecordId,
+) -> Result<Orchid, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut response = db()
+        .query("SELECT * FROM $id WHERE owner = $owner")
+        .bind(("id", id.clone()))
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Load orchid query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Load orchid query error", err_msg));
+    }
+
+    let rows: Vec<OrchidDbRow> = response.take(0)
+        .map_err(|e| internal_error("Load orchid parse failed", e))?;
+    rows.into_iter().next()
+        .map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
+/// **What is it?**
+/// A server function that records how the medium was at a plant's latest watering and lets its base watering frequency learn from it.
+///
+/// **Why does it exist?**
+/// The pot, medium and climate factors in `crate::watering` are estimates. "Was too dry" or "still wet" from the grower is the real signal, and nudging the base frequency a little each time lets every plant's schedule settle where it belongs.
+///
+/// **How should it be used?**
+/// Offer the three ratings right after "Water Now". Each watering can be rated once; the returned orchid carries the adjusted `water_frequency_days`. See `watering::learn_water_frequency` for how far one rating moves it.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn rate_watering(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+    /// How the medium was when the plant was watered.
+    feedback: MediumFeedback,
+) -> Result<Orchid, ServerFnError> {
+    use chrono::Datelike;
+    use surrealdb::types::SurrealValue;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::orchid::Hemisphere;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct WateringRow {
+        id: surrealdb::types::RecordId,
+        timestamp: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        medium_feedback: Option<String>,
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let oid = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
+    let orchid = load_owned_orchid(&oid, &owner).await?;
+
+    let mut response = db()
+        .query(
+            "SELECT id, timestamp, medium_feedback FROM log_entry \
+             WHERE orchid = $id AND owner = $owner AND event_type = 'Watered' \
+             ORDER BY timestamp DESC LIMIT 2"
+        )
+        .bind(("id", oid.clone()))
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Rate watering lookup failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Rate watering lookup error", err_msg));
+    }
+
+    let waterings: Vec<WateringRow> = response.take(0)
+        .map_err(|e| internal_error("Rate watering lookup parse failed", e))?;
+    let Some(latest) = waterings.first() else {
+        return Err(ServerFnError::new("This plant has no watering to rate"));
+    };
+    if latest.medium_feedback.is_some() {
+        return Err(ServerFnError::new("This watering has already been rated"));
+    }
+
+    // Judge the rating against the schedule that applied after the previous watering
+    let hemisphere = Hemisphere::from_code(&crate::server_fns::preferences::get_hemisphere().await?);
+    let (interval, scheduled) = match waterings.get(1) {
+        Some(prev) => (
+            Some((latest.timestamp - prev.timestamp).num_days()),
+            orchid.water_frequency_in_month(&hemisphere, prev.timestamp.month()),
+        ),
+        None => (None, orchid.effective_water_frequency(&hemisphere)),
+    };
+    let frequency = crate::watering::learn_water_frequency(orchid.water_frequency_days, scheduled, interval, feedback);
+    tracing::info!(from = orchid.water_frequency_days, to = frequency, feedback = feedback.as_str(), "rate_watering adjusted frequency");
+
+    let mut response = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             UPDATE $entry SET medium_feedback = $feedback; \
+             UPDATE $id SET water_frequency_days = $freq, updated_at = time::now() WHERE owner = $owner RETURN *; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("entry", latest.id.clone()))
+        .bind(("feedback", feedback.as_str().to_string()))
+        .bind(("id", oid))
+        .bind(("owner", owner))
+        .bind(("freq", frequency as i64))
+        .await
+        .map_err(|e| internal_error("Rate watering query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Rate watering query error", err_msg));
+    }
+
+    // Index 2 = orchid UPDATE result (index 0 = BEGIN, 1 = entry UPDATE)
+    let db_row: Option<OrchidDbRow> = response.take(2)
+        .map_err(|e| internal_error("Rate watering parse failed", e))?;
+
+    db_row.map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
+/// **What is it?**
+/// A server function that puts off a plant's next watering by a number of days and journals why.
+///
+/// **Why does it exist?**
+/// Growers often check a pot and decide to wait: the medium is still wet, a spike is coming, it's too cold. Without a way to say so the plant shows "overdue" until the next real watering; recording the skip moves the due date and leaves a 'Skipped' entry the schedule can learn from.
+///
+/// **How should it be used?**
+/// Call this from the "Skip" action next to "Water Now" with 1 to `MAX_SKIP_DAYS` days. The plant isn't due again until the deferral ends; the next watering clears it. A "medium still wet" skip also lengthens the base frequency the way a still-wet `rate_watering` does. Returns the updated orchid.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn skip_watering(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+    /// How many days to put the watering off for.
+    days: u32,
+    /// Why the watering is being skipped.
+    reason: SkipReason,
+    /// Optional extra detail for the journal entry.
+    note: Option<String>,
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if days == 0 || days > crate::orchid::MAX_SKIP_DAYS {
+        return Err(ServerFnError::new(format!("Skip must be 1-{} days", crate::orchid::MAX_SKIP_DAYS)));
+    }
+    let note = note.map(|n| crate::markdown::sanitize(n.trim())).filter(|n| !n.is_empty());
+    if note.as_ref().is_some_and(|n| n.len() > 500) {
+        return Err(ServerFnError::new("Note must be at most 500 characters"));
+    }
+    let entry_note = match note {
+        Some(n) => format!("Skipped watering for {} days: {} \u{2014} {}", days, reason.label(), n),
+        None => format!("Skipped watering for {} days: {}", days, reason.label()),
+    };
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let oid = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    // Finding the medium still wet when the plant came due is the same signal as a "still wet" rating
+    let orchid = load_owned_orchid(&oid, &owner).await?;
+    let frequency = if reason == SkipReason::MediumStillWet {
+        let hemisphere = crate::orchid::Hemisphere::from_code(&crate::server_fns::preferences::get_hemisphere().await?);
+        crate::watering::learn_water_frequency(
+            orchid.water_frequency_days,
+            orchid.effective_water_frequency(&hemisphere),
+            orchid.days_since_watered(),
+            MediumFeedback::StillWet,
+        )
+    } else {
+        orchid.water_frequency_days
+    };
+
+    // Defer the orchid + journal the skip atomically
+    let mut response = db()
+        .query(format!(
+            "BEGIN TRANSACTION; \
+             UPDATE $id SET water_deferred_until = time::now() + duration::from_days($days), water_frequency_days = $freq, updated_at = time::now() \
+                 WHERE owner = $owner RETURN *; \
+             CREATE log_entry SET orchid = $id, owner = $owner, note = $note, event_type = 'Skipped', climate = {}, \
+                 skip_reason = $reason; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$id")
+        ))
+        .bind(("id", oid))
+        .bind(("owner", owner))
+        .bind(("days", days as i64))
+        .bind(("note", entry_note))
+        .bind(("reason", reason.as_str().to_string()))
+        .bind(("freq", frequency as i64))
+        .await
+        .map_err(|e| internal_error("Skip watering query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Skip watering query error", err_msg));
+    }
+
+    // Index 1 = UPDATE result (index 0 = BEGIN)
+    let db_row: Option<OrchidDbRow> = response.take(1)
+        .map_err(|e| internal_error("Skip watering parse failed", e))?;
+
+    db_row.map(|r| r.into_orchid())
+        .ok_or_else(|| ServerFnError::new("Orchid not found or not owned by you"))
+}
+
+/// **What is it?**
+/// A server function that logs a growth measurement (leaf span, pseudobulbs, spike length, roots) as a 'Measured' journal entry.
+///
+/// **Why does it exist?**
+/// It exists so sizes and counts are stored as numbers the Growth tab can chart, rather than buried in note text.
+///
+/// **How should it be used?**
+/// Call this from the Growth tab's measurement form with whichever values were taken; at least one is required. Returns the new entry.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn add_measurement(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+    /// The values measured.
+    measurement: crate::growth::GrowthMeasurement,
+    /// Optional note for the journal entry.
+    note: Option<String>,
+) -> Result<LogEntry, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::growth::MEASURED_EVENT;
+
+    measurement.validate().map_err(ServerFnError::new)?;
+    let note = note.map(|n| crate::markdown::sanitize(n.trim())).filter(|n| !n.is_empty());
+    if note.as_ref().is_some_and(|n| n.len() > 500) {
+        return Err(ServerFnError::new("Note must be at most 500 characters"));
+    }
+    let entry_note = match note {
+        Some(n) => format!("{} \u{2014} {}", measurement.describe(), n),
+        None => measurement.describe(),
+    };
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let oid = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    let mut response = db()
+        .query(format!(
+            "IF (SELECT VALUE id FROM $id WHERE owner = $owner) = [] {{ THROW 'Orchid not found or not owned by you'; }}; \
+             CREATE log_entry SET orchid = $id, owner = $owner, note = $note, event_type = $event_type, climate = {}, \
+                 measurement = $measurement;",
+            climate_snapshot_expr("$id")
+        ))
+        .bind(("id", oid))
+        .bind(("owner", owner))
+        .bind(("note", entry_note))
+        .bind(("event_type", MEASURED_EVENT.to_string()))
+        .bind(("measurement", measurement))
+        .await
+        .map_err(|e| internal_error("Add measurement query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Add measurement query error", err_msg));
+    }
+
+    // Index 1 = CREATE result (index 0 = ownership check)
+    let db_row: Option<LogEntryDbRow> = response.take(1)
+        .map_err(|e| internal_error("Add measurement parse failed", e))?;
+
+    db_row.map(|r| r.into_log_entry())
+        .ok_or_else(|| ServerFnError::new("Failed to add measurement"))
+}
+
+/// **What is it?**
+/// A server function that saves a root assessment as a 'Repotted' journal entry and marks the plant as just repotted.
+///
+/// **Why does it exist?**
+/// It exists so each repot records root health and the old medium as structured data, with the suggested next repot in the note, rather than a bare "Repotted".
+///
+/// **How should it be used?**
+/// Call this from the scanner's Roots mode with the assessment returned by `server_fns::scanner::assess_root_photo`, after the grower picks the plant. Returns the new entry.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn log_root_assessment(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+    /// The assessment to save.
+    assessment: crate::root_health::RootAssessment,
+    /// Optional note for the journal entry.
+    note: Option<String>,
+) -> Result<LogEntry, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::root_health::ROOT_ASSESSMENT_EVENT;
+
+    assessment.validate().map_err(ServerFnError::new)?;
+    let note = note.map(|n| crate::markdown::sanitize(n.trim())).filter(|n| !n.is_empty());
+    if note.as_ref().is_some_and(|n| n.len() > 500) {
+        return Err(ServerFnError::new("Note must be at most 500 characters"));
+    }
+    let entry_note = match note {
+        Some(n) => format!("{} \u{2014} {}", assessment.describe(), n),
+        None => assessment.describe(),
+    };
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let oid = parse_record_id(&orchid_id)?;
+    let owner = parse_record_id(&owner_id)?;
+
+    let mut response = db()
+        .query(format!(
+            "BEGIN TRANSACTION; \
+             IF (SELECT VALUE id FROM $id WHERE owner = $owner) = [] {{ THROW 'Orchid not found or not owned by you'; }}; \
+             CREATE log_entry SET orchid = $id, owner = $owner, note = $note, event_type = $event_type, climate = {}, \
+                 root_assessment = $assessment; \
+             UPDATE $id SET last_repotted_at = time::now(), updated_at = time::now() WHERE owner = $owner; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$id")
+        ))
+        .bind(("id", oid))
+        .bind(("owner", owner))
+        .bind(("note", entry_note))
+        .bind(("event_type", ROOT_ASSESSMENT_EVENT.to_string()))
+        .bind(("assessment", assessment))
+        .await
+        .map_err(|e| internal_error("Log root assessment query failed", e))?;
+
+    let errors = response.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Log root assessment query error", err_msg));
+    }
+
+    // Index 2 = CREATE result (index 0 = BEGIN, 1 = ownership check)
+    let db_row: Option<LogEntryDbRow> = response.take(2)
+        .map_err(|e| internal_error("Log root assessment parse failed", e))?;
+
+    db_row.map(|r| r.into_log_entry())
+        .ok_or_else(|| ServerFnError::new("Failed to log root assessment"))
+}
+
 /// **What is it?**
 /// A server function that marks a specific orchid as having just been fertilized.
 ///
@@ -904,22 +2103,24 @@ pub async fn mark_fertilized(
     /// The unique identifier of the orchid.
     orchid_id: String
 ) -> Result<Orchid, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
     let oid = parse_record_id(&orchid_id)?;
-    let owner = parse_record_id(&user_id)?;
+    let owner = parse_record_id(&owner_id)?;
 
     // Update orchid + create log entry atomically
     let mut response = db()
-        .query(
+        .query(format!(
             "BEGIN TRANSACTION; \
-             UPDATE $id SET last_fertilized_at = time::now() WHERE owner = $owner RETURN *; \
-             CREATE log_entry SET orchid = $id, owner = $owner, note = 'Fertilized', event_type = 'Fertilized'; \
-             COMMIT TRANSACTION;"
-        )
+             UPDATE $id SET last_fertilized_at = time::now(), updated_at = time::now() WHERE owner = $owner RETURN *; \
+             CREATE log_entry SET orchid = $id, owner = $owner, note = 'Fertilized', event_type = 'Fertilized', climate = {}; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$id")
+        ))
         .bind(("id", oid))
         .bind(("owner", owner))
         .await
@@ -959,22 +2160,24 @@ pub async fn mark_repotted(
     /// The new pot size used.
     pot_size: Option<String>
 ) -> Result<Orchid, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
     let oid = parse_record_id(&orchid_id)?;
-    let owner = parse_record_id(&user_id)?;
+    let owner = parse_record_id(&owner_id)?;
 
     // Update orchid + create log entry atomically
     let mut response = db()
-        .query(
+        .query(format!(
             "BEGIN TRANSACTION; \
-             UPDATE $id SET last_repotted_at = time::now(), pot_medium = $pot_medium, pot_size = $pot_size WHERE owner = $owner RETURN *; \
-             CREATE log_entry SET orchid = $id, owner = $owner, note = 'Repotted', event_type = 'Repotted'; \
-             COMMIT TRANSACTION;"
-        )
+             UPDATE $id SET last_repotted_at = time::now(), pot_medium = $pot_medium, pot_size = $pot_size, updated_at = time::now() WHERE owner = $owner RETURN *; \
+             CREATE log_entry SET orchid = $id, owner = $owner, note = 'Repotted', event_type = 'Repotted', climate = {}; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$id")
+        ))
         .bind(("id", oid))
         .bind(("owner", owner))
         .bind(("pot_medium", pot_medium))
@@ -1042,6 +2245,42 @@ mod tests {
         assert_eq!(normalize_light_requirement(" Medium Light "), "Medium");
     }
 
+    // ── Pagination helpers ──
+
+    #[test]
+    fn test_split_page_with_more_rows() {
+        use super::split_page;
+        let rows: Vec<u32> = (0..11).collect();
+        let (page, next) = split_page(rows, 20, 10);
+        assert_eq!(page.len(), 10, "Extra sentinel row must be dropped");
+        assert_eq!(next, Some(30));
+    }
+
+    #[test]
+    fn test_split_page_last_page() {
+        use super::split_page;
+        let rows: Vec<u32> = (0..4).collect();
+        let (page, next) = split_page(rows, 40, 10);
+        assert_eq!(page.len(), 4);
+        assert_eq!(next, None, "Short page means the collection is fully loaded");
+    }
+
+    #[test]
+    fn test_split_page_exact_fit_has_no_next() {
+        use super::split_page;
+        let rows: Vec<u32> = (0..10).collect();
+        let (_, next) = split_page(rows, 0, 10);
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn test_clamp_page_size() {
+        use super::clamp_page_size;
+        assert_eq!(clamp_page_size(0), 1);
+        assert_eq!(clamp_page_size(48), 48);
+        assert_eq!(clamp_page_size(10_000), 200);
+    }
+
     // ── into_orchid() pot enum conversion tests (regression for pot_medium deserialization bug) ──
 
     #[cfg(feature = "ssr")]
@@ -1086,9 +2325,36 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         }
     }
 
+    #[test]
+    #[cfg(feature = "ssr")]
+    fn test_validate_acquisition_price() {
+        use super::validate_acquisition_price;
+        assert!(validate_acquisition_price(None).is_ok());
+        assert!(validate_acquisition_price(Some(0.0)).is_ok());
+        assert!(validate_acquisition_price(Some(45.5)).is_ok());
+        assert!(validate_acquisition_price(Some(-1.0)).is_err());
+        assert!(validate_acquisition_price(Some(f64::NAN)).is_err());
+    }
+
     #[test]
     #[cfg(feature = "ssr")]
     fn test_into_orchid_pot_medium_all_variants() {
@@ -1256,6 +2522,22 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         };
 
         // JSON roundtrip (simulates server function boundary)
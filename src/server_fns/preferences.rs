@@ -1,40 +1,96 @@
 use leptos::prelude::*;
+use crate::collection_view::SavedView;
+use crate::orchid::QuickActionItem;
+use crate::model::AccessibilityPrefs;
+use crate::public_feed::PublicFeedStatus;
+use crate::tour::TourProgress;
+
+/// The preference columns read on every page load, for one user (or household owner).
+#[cfg(feature = "ssr")]
+#[derive(Clone, serde::Deserialize, surrealdb::types::SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub(crate) struct CachedPreferences {
+    #[surreal(default)]
+    pub temp_unit: Option<String>,
+    #[surreal(default)]
+    pub hemisphere: Option<String>,
+    #[surreal(default)]
+    pub collection_public: bool,
+    #[surreal(default)]
+    pub overdue_grace_days: Option<i64>,
+    #[surreal(default)]
+    pub reduced_motion: bool,
+    #[surreal(default)]
+    pub high_contrast: bool,
+}
+
+/// Each user's `CachedPreferences`, or `None` for users who never saved a preference. The getters
+/// below share one query per user through it; their savers call `invalidate_preferences`.
+#[cfg(feature = "ssr")]
+static PREFERENCES: std::sync::LazyLock<crate::cache::TtlCache<String, Option<CachedPreferences>>> =
+    std::sync::LazyLock::new(|| crate::cache::TtlCache::new(std::time::Duration::from_secs(5 * 60)));
 
 /// **What is it?**
-/// A server function that retrieves the user's preferred temperature unit ("C" or "F").
+/// The user's display and collection preferences, from the preference cache when it's fresh.
 ///
 /// **Why does it exist?**
-/// It exists to ensure that climate data is displayed according to the individual user's regional preferences rather than a forced default.
+/// The temperature unit, hemisphere, accessibility and grace-period getters all run on each page load; one cached read replaces a query apiece.
 ///
 /// **How should it be used?**
-/// Call this from the frontend upon application load or when rendering settings panels to populate the current temperature unit state.
-#[server]
-#[tracing::instrument(level = "info", skip_all)]
-pub async fn get_temp_unit() -> Result<String, ServerFnError> {
-    use crate::auth::require_auth;
+/// Pass the ID the preference belongs to (`require_auth` for personal settings, `require_role(...).owner_id` for collection-wide ones) and fall back to each field's default when it's `None` or unset.
+#[cfg(feature = "ssr")]
+pub(crate) async fn cached_preferences(user_id: &str) -> Result<Option<CachedPreferences>, ServerFnError> {
     use crate::db::db;
     use crate::error::internal_error;
-    use surrealdb::types::SurrealValue;
-
-    let user_id = require_auth().await?;
-    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
-        .map_err(|e| internal_error("Owner ID parse failed", e))?;
 
-    #[derive(serde::Deserialize, SurrealValue)]
-    #[surreal(crate = "surrealdb::types")]
-    struct PrefRow {
-        temp_unit: String,
+    if let Some(prefs) = PREFERENCES.get(&user_id.to_string()) {
+        return Ok(prefs);
     }
 
+    let owner = surrealdb::types::RecordId::parse_simple(user_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
     let mut resp = db()
-        .query("SELECT temp_unit FROM user_preference WHERE owner = $owner LIMIT 1")
+        .query(
+            "SELECT temp_unit, hemisphere, collection_public, overdue_grace_days, reduced_motion, high_contrast \
+             FROM user_preference WHERE owner = $owner LIMIT 1"
+        )
         .bind(("owner", owner))
         .await
-        .map_err(|e| internal_error("Get preference query failed", e))?;
+        .map_err(|e| internal_error("Get preferences query failed", e))?;
 
-    let _ = resp.take_errors();
-    let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
-    Ok(row.map(|r| r.temp_unit).unwrap_or_else(|| "C".to_string()))
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get preferences query error", err_msg));
+    }
+    let prefs: Option<CachedPreferences> = resp.take(0)
+        .map_err(|e| internal_error("Get preferences parse failed", e))?;
+    PREFERENCES.insert(user_id.to_string(), prefs.clone());
+    Ok(prefs)
+}
+
+/// Drops the user's cached preferences after one of the cached columns is saved.
+#[cfg(feature = "ssr")]
+pub(crate) fn invalidate_preferences(user_id: &str) {
+    PREFERENCES.invalidate(&user_id.to_string());
+}
+
+/// **What is it?**
+/// A server function that retrieves the user's preferred temperature unit ("C" or "F").
+///
+/// **Why does it exist?**
+/// It exists to ensure that climate data is displayed according to the individual user's regional preferences rather than a forced default.
+///
+/// **How should it be used?**
+/// Call this from the frontend upon application load or when rendering settings panels to populate the current temperature unit state.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_temp_unit() -> Result<String, ServerFnError> {
+    use crate::auth::require_auth;
+
+    let user_id = require_auth().await?;
+    let prefs = cached_preferences(&user_id).await?;
+    Ok(prefs.and_then(|p| p.temp_unit).unwrap_or_else(|| "C".to_string()))
 }
 
 /// **What is it?**
@@ -87,6 +143,7 @@ pub async fn save_temp_unit(
             .map_err(|e| internal_error("Create preference query failed", e))?;
     }
 
+    invalidate_preferences(&user_id);
     Ok(())
 }
 
@@ -101,7 +158,431 @@ pub async fn save_temp_unit(
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn get_hemisphere() -> Result<String, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let prefs = cached_preferences(&owner_id).await?;
+    Ok(prefs.and_then(|p| p.hemisphere).unwrap_or_else(|| "N".to_string()))
+}
+
+/// **What is it?**
+/// A server function that saves the user's hemisphere preference.
+///
+/// **Why does it exist?**
+/// It allows users to manually specify their geographic hemisphere, correcting or setting the baseline for seasonal alerts and plant care cycles.
+///
+/// **How should it be used?**
+/// Call this from the settings form whenever the user updates their location preferences.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_hemisphere(
+    /// The hemisphere ("N" or "S").
+    hemisphere: String
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let hemisphere = if hemisphere == "S" { "S" } else { "N" };
+
+    // Update existing preference row (preserves temp_unit and other fields)
+    let mut resp = db()
+        .query("UPDATE user_preference SET hemisphere = $hemi WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("hemi", hemisphere.to_string()))
+        .await
+        .map_err(|e| internal_error("Save hemisphere query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save hemisphere query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, hemisphere = $hemi")
+            .bind(("owner", owner))
+            .bind(("hemi", hemisphere.to_string()))
+            .await
+            .map_err(|e| internal_error("Create hemisphere preference query failed", e))?;
+    }
+
+    invalidate_preferences(&owner_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that checks if the user's orchid collection is marked as public.
+///
+/// **Why does it exist?**
+/// It exists to determine the privacy level of a collection, controlling whether unauthenticated users or a public gallery link can access the user's orchids.
+///
+/// **How should it be used?**
+/// Query this to show the current visibility status in the user's settings or to conditionally enable sharing features on the frontend.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_collection_public() -> Result<bool, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let prefs = cached_preferences(&owner_id).await?;
+    Ok(prefs.is_some_and(|p| p.collection_public))
+}
+
+/// **What is it?**
+/// A server function that updates whether the user's collection should be public or private.
+///
+/// **Why does it exist?**
+/// It provides the means for users to toggle their collection's privacy status on the backend, granting or revoking public viewing access.
+///
+/// **How should it be used?**
+/// Call this function when the user clicks a "Make Public" or "Make Private" toggle in their account settings.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_collection_public(
+    /// True if public, false if private.
+    public: bool
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET collection_public = $public WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("public", public))
+        .await
+        .map_err(|e| internal_error("Save collection_public query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save collection_public query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, collection_public = $public")
+            .bind(("owner", owner))
+            .bind(("public", public))
+            .await
+            .map_err(|e| internal_error("Create collection_public preference query failed", e))?;
+    }
+
+    invalidate_preferences(&owner_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that reports whether the user's public JSON feed has a key, and the key's display prefix.
+///
+/// **Why does it exist?**
+/// It exists so the settings panel can show whether community sites and bots can currently read the collection, without ever revealing the key.
+///
+/// **How should it be used?**
+/// Call this when the Public Collection section opens to choose between the "Create feed key" and "Revoke" controls.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_public_feed_status() -> Result<PublicFeedStatus, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use surrealdb::types::SurrealValue;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        public_feed_key_prefix: Option<String>,
+    }
+
+    let mut resp = db()
+        .query("SELECT public_feed_key_prefix FROM user_preference WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get public feed status query failed", e))?;
+
+    let _ = resp.take_errors();
+    let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
+    let prefix = row.and_then(|r| r.public_feed_key_prefix);
+    Ok(PublicFeedStatus { enabled: prefix.is_some(), prefix })
+}
+
+/// **What is it?**
+/// A server function that creates a new public feed key, replacing any existing one, and returns it once.
+///
+/// **Why does it exist?**
+/// It exists so a grower gives explicit consent before their collection is readable as JSON, and can rotate the key if a bot or site should lose access.
+///
+/// **How should it be used?**
+/// Call this from the Public Collection settings; show the returned key (or the feed URL built from it) immediately, since only its hash is stored.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_public_feed_key() -> Result<String, ServerFnError> {
+    use crate::api_tokens::display_prefix;
+    use crate::auth::require_role;
+    use crate::crypto::{generate_token, hash_token};
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::public_feed::FEED_KEY_PREFIX;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let (secret, _) = generate_token();
+    let key = format!("{}{}", FEED_KEY_PREFIX, secret);
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET public_feed_key_hash = $hash, public_feed_key_prefix = $prefix WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("hash", hash_token(&key)))
+        .bind(("prefix", display_prefix(&key)))
+        .await
+        .map_err(|e| internal_error("Create public feed key query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create public feed key query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, public_feed_key_hash = $hash, public_feed_key_prefix = $prefix")
+            .bind(("owner", owner))
+            .bind(("hash", hash_token(&key)))
+            .bind(("prefix", display_prefix(&key)))
+            .await
+            .map_err(|e| internal_error("Create public feed key preference query failed", e))?;
+    }
+
+    Ok(key)
+}
+
+/// **What is it?**
+/// A server function that deletes the user's public feed key.
+///
+/// **Why does it exist?**
+/// It exists so a grower can withdraw consent for the JSON feed while keeping their public page at `/u/{username}` online.
+///
+/// **How should it be used?**
+/// Call this from the "Revoke" button in the Public Collection settings; requests with the old key get 404 straight away.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn revoke_public_feed_key() -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET public_feed_key_hash = NONE, public_feed_key_prefix = NONE WHERE owner = $owner")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Revoke public feed key query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Revoke public feed key query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that retrieves the user's reduced motion and high-contrast settings.
+///
+/// **Why does it exist?**
+/// It exists so accessibility choices are stored with the account and follow the user to every device, rather than living in one browser.
+///
+/// **How should it be used?**
+/// Load once on the home page and dispatch `Msg::SetAccessibility` with the result so the document root gets the matching classes. Defaults to both off.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_accessibility_prefs() -> Result<AccessibilityPrefs, ServerFnError> {
+    use crate::auth::require_auth;
+
+    let user_id = require_auth().await?;
+    let prefs = cached_preferences(&user_id).await?;
+    Ok(prefs.map(|p| AccessibilityPrefs { reduced_motion: p.reduced_motion, high_contrast: p.high_contrast })
+        .unwrap_or_default())
+}
+
+/// **What is it?**
+/// A server function that saves the user's reduced motion and high-contrast settings.
+///
+/// **Why does it exist?**
+/// It persists the accessibility toggles from the settings panel so they are applied on the next visit from any device.
+///
+/// **How should it be used?**
+/// Call this when either toggle in the Accessibility section of settings changes, passing both values.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_accessibility_prefs(
+    /// The new preferences.
+    prefs: AccessibilityPrefs,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET reduced_motion = $reduced_motion, high_contrast = $high_contrast WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("reduced_motion", prefs.reduced_motion))
+        .bind(("high_contrast", prefs.high_contrast))
+        .await
+        .map_err(|e| internal_error("Save accessibility prefs query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save accessibility prefs query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, reduced_motion = $reduced_motion, high_contrast = $high_contrast")
+            .bind(("owner", owner))
+            .bind(("reduced_motion", prefs.reduced_motion))
+            .bind(("high_contrast", prefs.high_contrast))
+            .await
+            .map_err(|e| internal_error("Create accessibility preference query failed", e))?;
+    }
+
+    invalidate_preferences(&user_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that retrieves how many days past due watering may slip before a plant counts as overdue.
+///
+/// **Why does it exist?**
+/// It exists so growers with drought-tolerant collections aren't nagged the moment a watering date passes; overdue styling, task lists, and push alerts all wait out this grace period.
+///
+/// **How should it be used?**
+/// Load once on the home page and pass it to anything that calls `Orchid::is_overdue` or `is_climate_overdue`. Plants with their own `overdue_grace_days` override ignore this default. Defaults to 0.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_overdue_grace_days() -> Result<u32, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let prefs = cached_preferences(&owner_id).await?;
+    Ok(prefs
+        .and_then(|p| p.overdue_grace_days)
+        .map(|d| d.clamp(0, crate::orchid::MAX_OVERDUE_GRACE_DAYS as i64) as u32)
+        .unwrap_or(0))
+}
+
+/// **What is it?**
+/// A server function that saves the user's default watering overdue grace period.
+///
+/// **Why does it exist?**
+/// It lets users decide how strict "overdue" should be across their collection, cutting reminders for plants that tolerate a few extra dry days.
+///
+/// **How should it be used?**
+/// Call from the settings form when the grace period changes. Values above `MAX_OVERDUE_GRACE_DAYS` are rejected.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_overdue_grace_days(
+    /// Days past due before a plant counts as overdue (0-30).
+    days: u32
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    if days > crate::orchid::MAX_OVERDUE_GRACE_DAYS {
+        return Err(ServerFnError::new(format!(
+            "Grace period must be 0-{} days", crate::orchid::MAX_OVERDUE_GRACE_DAYS
+        )));
+    }
+
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET overdue_grace_days = $days WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("days", days as i64))
+        .await
+        .map_err(|e| internal_error("Save overdue grace query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save overdue grace query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, overdue_grace_days = $days")
+            .bind(("owner", owner))
+            .bind(("days", days as i64))
+            .await
+            .map_err(|e| internal_error("Create overdue grace preference query failed", e))?;
+    }
+
+    invalidate_preferences(&owner_id);
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that retrieves the user's configured "Quick Log" buttons, in display order.
+///
+/// **Why does it exist?**
+/// It exists so each grower can keep the one-tap log actions they actually use (e.g., misting, staking) and drop the ones they don't.
+///
+/// **How should it be used?**
+/// Call this when rendering the quick actions row or its settings editor. Users who never customized the row get the built-in defaults.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_quick_actions() -> Result<Vec<QuickActionItem>, ServerFnError> {
     use crate::auth::require_auth;
+    use crate::components::quick_actions::default_quick_actions;
     use crate::db::db;
     use crate::error::internal_error;
     use surrealdb::types::SurrealValue;
@@ -113,35 +594,41 @@ pub async fn get_hemisphere() -> Result<String, ServerFnError> {
     #[derive(serde::Deserialize, SurrealValue)]
     #[surreal(crate = "surrealdb::types")]
     struct PrefRow {
-        hemisphere: String,
+        #[surreal(default)]
+        quick_actions: Option<String>,
     }
 
     let mut resp = db()
-        .query("SELECT hemisphere FROM user_preference WHERE owner = $owner LIMIT 1")
+        .query("SELECT quick_actions FROM user_preference WHERE owner = $owner LIMIT 1")
         .bind(("owner", owner))
         .await
-        .map_err(|e| internal_error("Get hemisphere query failed", e))?;
+        .map_err(|e| internal_error("Get quick_actions query failed", e))?;
 
     let _ = resp.take_errors();
     let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
-    Ok(row.map(|r| r.hemisphere).unwrap_or_else(|| "N".to_string()))
+    let actions = row
+        .and_then(|r| r.quick_actions)
+        .and_then(|json| serde_json::from_str::<Vec<QuickActionItem>>(&json).ok())
+        .unwrap_or_else(default_quick_actions);
+    Ok(actions)
 }
 
 /// **What is it?**
-/// A server function that saves the user's hemisphere preference.
+/// A server function that saves the user's ordered list of "Quick Log" buttons.
 ///
 /// **Why does it exist?**
-/// It allows users to manually specify their geographic hemisphere, correcting or setting the baseline for seasonal alerts and plant care cycles.
+/// It persists the choices made in the quick actions editor so the same row appears on every device.
 ///
 /// **How should it be used?**
-/// Call this from the settings form whenever the user updates their location preferences.
+/// Call this from the settings editor with the full list in display order. The list is validated (known event types, length limits) before it is stored.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
-pub async fn save_hemisphere(
-    /// The hemisphere ("N" or "S").
-    hemisphere: String
+pub async fn save_quick_actions(
+    /// The quick actions in display order.
+    actions: Vec<QuickActionItem>
 ) -> Result<(), ServerFnError> {
     use crate::auth::require_auth;
+    use crate::components::quick_actions::sanitize_quick_actions;
     use crate::db::db;
     use crate::error::internal_error;
 
@@ -149,50 +636,53 @@ pub async fn save_hemisphere(
     let owner = surrealdb::types::RecordId::parse_simple(&user_id)
         .map_err(|e| internal_error("Owner ID parse failed", e))?;
 
-    let hemisphere = if hemisphere == "S" { "S" } else { "N" };
+    let known_keys = crate::server_fns::event_types::known_event_type_keys(&owner).await?;
+    let actions = sanitize_quick_actions(actions, &known_keys).map_err(ServerFnError::new)?;
+    let json = serde_json::to_string(&actions)
+        .map_err(|e| internal_error("Quick actions serialize failed", e))?;
 
-    // Update existing preference row (preserves temp_unit and other fields)
     let mut resp = db()
-        .query("UPDATE user_preference SET hemisphere = $hemi WHERE owner = $owner")
+        .query("UPDATE user_preference SET quick_actions = $actions WHERE owner = $owner")
         .bind(("owner", owner.clone()))
-        .bind(("hemi", hemisphere.to_string()))
+        .bind(("actions", json.clone()))
         .await
-        .map_err(|e| internal_error("Save hemisphere query failed", e))?;
+        .map_err(|e| internal_error("Save quick_actions query failed", e))?;
 
     let errors = resp.take_errors();
     if !errors.is_empty() {
         let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
-        return Err(internal_error("Save hemisphere query error", err_msg));
+        return Err(internal_error("Save quick_actions query error", err_msg));
     }
 
     // If no row existed, create one
     let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
     if updated.is_empty() {
         db()
-            .query("CREATE user_preference SET owner = $owner, hemisphere = $hemi")
+            .query("CREATE user_preference SET owner = $owner, quick_actions = $actions")
             .bind(("owner", owner))
-            .bind(("hemi", hemisphere.to_string()))
+            .bind(("actions", json))
             .await
-            .map_err(|e| internal_error("Create hemisphere preference query failed", e))?;
+            .map_err(|e| internal_error("Create quick_actions preference query failed", e))?;
     }
 
     Ok(())
 }
 
 /// **What is it?**
-/// A server function that checks if the user's orchid collection is marked as public.
+/// A server function that retrieves the signed-in user's progress through the getting-started tour.
 ///
 /// **Why does it exist?**
-/// It exists to determine the privacy level of a collection, controlling whether unauthenticated users or a public gallery link can access the user's orchids.
+/// It exists so the tour resumes at the same step on every device and stays closed once dismissed.
 ///
 /// **How should it be used?**
-/// Query this to show the current visibility status in the user's settings or to conditionally enable sharing features on the frontend.
+/// Load once on the home page and pass it to `ProductTour`. Progress belongs to the person, not the household, so invited caretakers get their own tour. Unknown step names are dropped.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
-pub async fn get_collection_public() -> Result<bool, ServerFnError> {
+pub async fn get_tour_progress() -> Result<TourProgress, ServerFnError> {
     use crate::auth::require_auth;
     use crate::db::db;
     use crate::error::internal_error;
+    use crate::tour::TourStep;
     use surrealdb::types::SurrealValue;
 
     let user_id = require_auth().await?;
@@ -203,33 +693,38 @@ pub async fn get_collection_public() -> Result<bool, ServerFnError> {
     #[surreal(crate = "surrealdb::types")]
     struct PrefRow {
         #[surreal(default)]
-        collection_public: bool,
+        tour_completed: Vec<String>,
+        #[surreal(default)]
+        tour_dismissed: bool,
     }
 
     let mut resp = db()
-        .query("SELECT collection_public FROM user_preference WHERE owner = $owner LIMIT 1")
+        .query("SELECT tour_completed, tour_dismissed FROM user_preference WHERE owner = $owner LIMIT 1")
         .bind(("owner", owner))
         .await
-        .map_err(|e| internal_error("Get collection_public query failed", e))?;
+        .map_err(|e| internal_error("Get tour progress query failed", e))?;
 
     let _ = resp.take_errors();
     let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
-    Ok(row.map(|r| r.collection_public).unwrap_or(false))
+    Ok(row.map(|r| TourProgress {
+        completed: r.tour_completed.iter().filter_map(|s| TourStep::parse(s)).collect(),
+        dismissed: r.tour_dismissed,
+    }).unwrap_or_default())
 }
 
 /// **What is it?**
-/// A server function that updates whether the user's collection should be public or private.
+/// A server function that saves the signed-in user's getting-started tour progress.
 ///
 /// **Why does it exist?**
-/// It provides the means for users to toggle their collection's privacy status on the backend, granting or revoking public viewing access.
+/// It persists each finished or skipped step, and the dismissal, so the tour doesn't repeat itself.
 ///
 /// **How should it be used?**
-/// Call this function when the user clicks a "Make Public" or "Make Private" toggle in their account settings.
+/// Call after `TourProgress::complete`, `dismiss` or `restart`, passing the whole progress.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
-pub async fn save_collection_public(
-    /// True if public, false if private.
-    public: bool
+pub async fn save_tour_progress(
+    /// The full tour progress.
+    progress: TourProgress,
 ) -> Result<(), ServerFnError> {
     use crate::auth::require_auth;
     use crate::db::db;
@@ -239,28 +734,133 @@ pub async fn save_collection_public(
     let owner = surrealdb::types::RecordId::parse_simple(&user_id)
         .map_err(|e| internal_error("Owner ID parse failed", e))?;
 
+    let completed: Vec<String> = progress.completed.iter().map(|s| s.as_str().to_string()).collect();
+
     let mut resp = db()
-        .query("UPDATE user_preference SET collection_public = $public WHERE owner = $owner")
+        .query("UPDATE user_preference SET tour_completed = $completed, tour_dismissed = $dismissed WHERE owner = $owner")
         .bind(("owner", owner.clone()))
-        .bind(("public", public))
+        .bind(("completed", completed.clone()))
+        .bind(("dismissed", progress.dismissed))
         .await
-        .map_err(|e| internal_error("Save collection_public query failed", e))?;
+        .map_err(|e| internal_error("Save tour progress query failed", e))?;
 
     let errors = resp.take_errors();
     if !errors.is_empty() {
         let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
-        return Err(internal_error("Save collection_public query error", err_msg));
+        return Err(internal_error("Save tour progress query error", err_msg));
     }
 
     // If no row existed, create one
     let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
     if updated.is_empty() {
         db()
-            .query("CREATE user_preference SET owner = $owner, collection_public = $public")
+            .query("CREATE user_preference SET owner = $owner, tour_completed = $completed, tour_dismissed = $dismissed")
             .bind(("owner", owner))
-            .bind(("public", public))
+            .bind(("completed", completed))
+            .bind(("dismissed", progress.dismissed))
             .await
-            .map_err(|e| internal_error("Create collection_public preference query failed", e))?;
+            .map_err(|e| internal_error("Create tour preference query failed", e))?;
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that retrieves the signed-in user's saved collection views (named sort and filter presets).
+///
+/// **Why does it exist?**
+/// It exists so a grower can get back to the same triage lists, like "Thirsty in the tent", with one tap on any device.
+///
+/// **How should it be used?**
+/// Load once in the collection toolbar. Views belong to the person, not the household, like quick actions; users who never saved one get an empty list.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_saved_views() -> Result<Vec<SavedView>, ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use surrealdb::types::SurrealValue;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        saved_views: Option<String>,
+    }
+
+    let mut resp = db()
+        .query("SELECT saved_views FROM user_preference WHERE owner = $owner LIMIT 1")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get saved views query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get saved views query error", err_msg));
+    }
+    let row: Option<PrefRow> = resp.take(0)
+        .map_err(|e| internal_error("Get saved views parse failed", e))?;
+    match row.and_then(|r| r.saved_views) {
+        Some(json) => serde_json::from_str::<Vec<SavedView>>(&json)
+            .map_err(|e| internal_error("Saved views parse failed", e)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// **What is it?**
+/// A server function that saves the signed-in user's list of saved collection views.
+///
+/// **Why does it exist?**
+/// It persists views added, renamed or removed in the collection toolbar so the same buttons appear on every device.
+///
+/// **How should it be used?**
+/// Call with the full list in display order after each change. The list is validated (names, sizes, duplicates) with `crate::collection_view::sanitize_saved_views` before it is stored.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn save_saved_views(
+    /// The saved views in display order.
+    views: Vec<SavedView>
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_auth;
+    use crate::collection_view::sanitize_saved_views;
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let user_id = require_auth().await?;
+    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+        .map_err(|e| internal_error("Owner ID parse failed", e))?;
+
+    let views = sanitize_saved_views(views).map_err(ServerFnError::new)?;
+    let json = serde_json::to_string(&views)
+        .map_err(|e| internal_error("Saved views serialize failed", e))?;
+
+    let mut resp = db()
+        .query("UPDATE user_preference SET saved_views = $views WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .bind(("views", json.clone()))
+        .await
+        .map_err(|e| internal_error("Save saved views query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Save saved views query error", err_msg));
+    }
+
+    // If no row existed, create one
+    let updated: Vec<serde_json::Value> = resp.take(0).unwrap_or_default();
+    if updated.is_empty() {
+        db()
+            .query("CREATE user_preference SET owner = $owner, saved_views = $views")
+            .bind(("owner", owner))
+            .bind(("views", json))
+            .await
+            .map_err(|e| internal_error("Create saved views preference query failed", e))?;
     }
 
     Ok(())
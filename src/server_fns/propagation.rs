@@ -0,0 +1,253 @@
+use leptos::prelude::*;
+use crate::orchid::Orchid;
+use crate::propagation::{Lineage, PropagationMethod, PropagationStatus};
+
+/// Longest recipient name accepted, matching the schema assertion.
+#[cfg(feature = "ssr")]
+const MAX_RECIPIENT_LEN: usize = 200;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::propagation::{Propagation, PropagationMethod, PropagationStatus};
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct PropagationDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub parent: surrealdb::types::RecordId,
+        pub parent_name: String,
+        pub child: surrealdb::types::RecordId,
+        pub child_name: String,
+        pub method: String,
+        pub propagated_at: chrono::DateTime<chrono::Utc>,
+        pub status: String,
+        #[surreal(default)]
+        pub recipient: Option<String>,
+        #[surreal(default)]
+        pub rehomed_at: Option<chrono::DateTime<chrono::Utc>>,
+    }
+
+    impl PropagationDbRow {
+        /// None for a row whose method or status this build doesn't know.
+        pub fn into_propagation(self) -> Option<Propagation> {
+            Some(Propagation {
+                id: record_id_to_string(&self.id),
+                parent_id: record_id_to_string(&self.parent),
+                parent_name: self.parent_name,
+                child_id: record_id_to_string(&self.child),
+                child_name: self.child_name,
+                method: PropagationMethod::parse(&self.method)?,
+                propagated_at: self.propagated_at,
+                status: PropagationStatus::parse(&self.status)?,
+                recipient: self.recipient,
+                rehomed_at: self.rehomed_at,
+            })
+        }
+    }
+}
+
+/// **What is it?**
+/// A server function that records a keiki, division or backbulb start as a new plant linked to its parent.
+///
+/// **Why does it exist?**
+/// It exists so an offshoot starts life with its parent's species, placement and care profile, and so the family tree is kept from the moment it's potted up.
+///
+/// **How should it be used?**
+/// Call from the Lineage tab of the parent's detail view. An empty name becomes "<parent> (<method>)". Returns the new plant so it can be added to the collection list.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(parent_id = %parent_id))]
+pub async fn propagate_orchid(
+    /// The plant the propagation was taken from.
+    parent_id: String,
+    /// How it was taken.
+    method: PropagationMethod,
+    /// The new plant's name; empty for a name based on the parent's.
+    name: String,
+) -> Result<Orchid, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::orchids::load_owned_orchid;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let parent_rid = surrealdb::types::RecordId::parse_simple(&parent_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+    let parent = load_owned_orchid(&parent_rid, &owner).await?;
+
+    let name = match name.trim() {
+        "" => format!("{} ({})", parent.name, method.label()),
+        n => n.to_string(),
+    };
+
+    let child = crate::server_fns::orchids::create_orchid(
+        name,
+        parent.species.clone(),
+        parent.water_frequency_days,
+        parent.light_requirement.as_str().to_string(),
+        format!("{} of {}", method.label(), parent.name),
+        parent.placement.clone(),
+        parent.light_lux.clone(),
+        parent.temperature_range.clone(),
+        parent.conservation_status.clone(),
+        parent.native_region.clone(),
+        parent.native_latitude,
+        parent.native_longitude,
+        parent.temp_min,
+        parent.temp_max,
+        parent.humidity_min,
+        parent.humidity_max,
+        parent.fertilize_frequency_days,
+        parent.fertilizer_type.clone(),
+        parent.pot_medium,
+        parent.pot_size,
+        parent.pot_type,
+        parent.rest_start_month,
+        parent.rest_end_month,
+        parent.bloom_start_month,
+        parent.bloom_end_month,
+        parent.rest_water_multiplier,
+        parent.rest_fertilizer_multiplier,
+        parent.active_water_multiplier,
+        parent.active_fertilizer_multiplier,
+        parent.par_ppfd,
+        Some(chrono::Utc::now()),
+        Some(0.0),
+        Some(crate::orchid::AcquisitionSource::Propagation),
+        None,
+    ).await?;
+    let child_rid = surrealdb::types::RecordId::parse_simple(&child.id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "CREATE propagation SET owner = $owner, parent = $parent, parent_name = $parent_name, \
+             child = $child, child_name = $child_name, method = $method"
+        )
+        .bind(("owner", owner.clone()))
+        .bind(("parent", parent_rid))
+        .bind(("parent_name", parent.name))
+        .bind(("child", child_rid.clone()))
+        .bind(("child_name", child.name.clone()))
+        .bind(("method", method.as_str().to_string()))
+        .await
+        .map_err(|e| internal_error("Create propagation query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        // Don't leave a plant behind that the family tree doesn't know about
+        let _ = db().query("DELETE $id WHERE owner = $owner").bind(("id", child_rid)).bind(("owner", owner)).await;
+        return Err(internal_error("Create propagation query error", err_msg));
+    }
+
+    Ok(child)
+}
+
+/// **What is it?**
+/// A server function that returns a plant's lineage: the chain of parents above it and the propagations taken from it.
+///
+/// **Why does it exist?**
+/// It exists to show where a plant came from and what came of it, including offshoots that have since been gifted or sold.
+///
+/// **How should it be used?**
+/// Load from the Lineage tab of the plant detail view. The walk itself is `crate::propagation::build_lineage`.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn get_lineage(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+) -> Result<Lineage, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::propagation::build_lineage;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::PropagationDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    // A collection holds few propagations, so the tree is walked in Rust rather than in the query
+    let mut resp = db()
+        .query("SELECT * FROM propagation WHERE owner = $owner")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get lineage query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get lineage query error", err_msg));
+    }
+
+    let rows: Vec<PropagationDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get lineage parse failed", e))?;
+    let records: Vec<_> = rows.into_iter().filter_map(PropagationDbRow::into_propagation).collect();
+
+    Ok(build_lineage(&records, &orchid_id))
+}
+
+/// **What is it?**
+/// A server function that marks a propagation as kept, gifted or sold, with who it went to.
+///
+/// **Why does it exist?**
+/// It exists so the family tree remembers offshoots that left the collection, and to whom, even after the plant record is deleted.
+///
+/// **How should it be used?**
+/// Call from the lineage view's "Gifted"/"Sold" action on a child. Setting `Kept` clears the recipient and date.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn set_propagation_status(
+    /// The unique identifier of the propagation record.
+    id: String,
+    /// Where the offshoot is now.
+    status: PropagationStatus,
+    /// Who it went to, for gifted and sold offshoots.
+    recipient: Option<String>,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let recipient = match status {
+        PropagationStatus::Kept => None,
+        _ => recipient.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+    };
+    if recipient.as_ref().is_some_and(|r| r.len() > MAX_RECIPIENT_LEN) {
+        return Err(ServerFnError::new(format!("Recipient must be at most {} characters", MAX_RECIPIENT_LEN)));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let propagation = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Propagation ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "UPDATE $id SET status = $status, recipient = $recipient, \
+             rehomed_at = IF $status = 'Kept' { NONE } ELSE { time::now() } \
+             WHERE owner = $owner"
+        )
+        .bind(("id", propagation))
+        .bind(("status", status.as_str().to_string()))
+        .bind(("recipient", recipient))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Set propagation status query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Set propagation status query error", err_msg));
+    }
+
+    Ok(())
+}
@@ -69,7 +69,7 @@ pub async fn get_public_orchids(
     let owner = parse_owner(&user_id)?;
 
     let mut response = db()
-        .query("SELECT * FROM orchid WHERE owner = $owner ORDER BY created_at DESC")
+        .query("SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE ORDER BY created_at DESC")
         .bind(("owner", owner))
         .await
         .map_err(|e| internal_error("Public get orchids query failed", e))?;
@@ -83,7 +83,14 @@ pub async fn get_public_orchids(
     let db_rows: Vec<OrchidDbRow> = response.take(0)
         .map_err(|e| internal_error("Public get orchids parse failed", e))?;
 
-    Ok(db_rows.into_iter().map(|r| r.into_orchid()).collect())
+    // What a plant cost and where it came from stay private to the grower
+    Ok(db_rows.into_iter().map(|r| Orchid {
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        ..r.into_orchid()
+    }).collect())
 }
 
 /// **What is it?**
@@ -315,3 +322,186 @@ pub async fn get_public_temp_unit(
     let row: Option<PrefRow> = resp.take(0).unwrap_or(None);
     Ok(row.map(|r| r.temp_unit).unwrap_or_else(|| "C".to_string()))
 }
+
+/// Longest autocomplete query accepted.
+#[cfg(feature = "ssr")]
+const MAX_SPECIES_QUERY_LEN: usize = 100;
+
+/// **What is it?**
+/// A server function that suggests species and genera from the bundled taxonomy dataset as the grower types.
+///
+/// **Why does it exist?**
+/// It exists so picking a known species fills in genus-level care defaults, the native region and its coordinates, instead of the grower looking them up.
+///
+/// **How should it be used?**
+/// Call from the species autocomplete with the text typed so far (two characters or more). It reads only bundled reference data, so no sign-in is needed; see `crate::taxonomy::SpeciesDataset::search` for ranking.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn search_species(
+    /// The text typed into the species field.
+    query: String,
+) -> Result<Vec<crate::taxonomy::SpeciesSuggestion>, ServerFnError> {
+    use crate::taxonomy::{species_dataset, MAX_SPECIES_SUGGESTIONS};
+
+    if query.len() > MAX_SPECIES_QUERY_LEN {
+        return Err(ServerFnError::new(format!("Search must be at most {} characters", MAX_SPECIES_QUERY_LEN)));
+    }
+    Ok(species_dataset().search(&query, MAX_SPECIES_SUGGESTIONS))
+}
+
+/// HTTP handlers for the public JSON feed, outside the server function protocol so bots can call a plain URL.
+#[cfg(feature = "ssr")]
+pub mod handlers {
+    use axum::{
+        extract::Path,
+        http::{header, HeaderValue, StatusCode},
+        response::Json,
+    };
+    use tower_governor::governor::GovernorConfigBuilder;
+    use tower_governor::key_extractor::SmartIpKeyExtractor;
+    use tower_governor::GovernorLayer;
+    use tower_http::set_header::SetResponseHeaderLayer;
+    use crate::public_feed::PublicCollectionFeed;
+
+    /// Returns the router for `GET /api/public/collection/{key}`, with its own rate limiter
+    /// (bursts of 20, then one request every 3 seconds per client IP) on top of the site-wide one,
+    /// and CORS/caching headers so community sites can fetch it from the browser.
+    pub fn public_api_router() -> axum::Router<leptos::prelude::LeptosOptions> {
+        let governor_conf = GovernorConfigBuilder::default()
+            .per_second(3)
+            .burst_size(20)
+            .key_extractor(SmartIpKeyExtractor)
+            .finish()
+            .expect("Failed to build public API rate limiter config");
+        let governor_limiter = governor_conf.limiter().clone();
+
+        // Forget idle clients, like the site-wide limiter's cleanup task
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                governor_limiter.retain_recent();
+            }
+        });
+
+        axum::Router::new()
+            .route("/api/public/collection/{key}", axum::routing::get(public_collection_feed))
+            .layer(SetResponseHeaderLayer::overriding(
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                HeaderValue::from_static("*"),
+            ))
+            .layer(SetResponseHeaderLayer::overriding(
+                header::CACHE_CONTROL,
+                HeaderValue::from_static("public, max-age=300"),
+            ))
+            .layer(GovernorLayer::new(governor_conf))
+    }
+
+    /// Serves a public collection as JSON: plants and their bloom events, no notes, schedules or sensor data.
+    ///
+    /// Unknown keys, revoked keys, and collections that were made private all return 404, so the response
+    /// never reveals whether a grower exists.
+    pub async fn public_collection_feed(
+        Path(key): Path<String>,
+    ) -> Result<Json<PublicCollectionFeed>, StatusCode> {
+        use crate::crypto::hash_token;
+        use crate::db::db;
+        use crate::orchid::LogEntry;
+        use crate::public_feed::{build_feed, FEED_KEY_PREFIX};
+        use crate::server_fns::auth::record_id_to_string;
+        use crate::server_fns::orchids::ssr_types::OrchidDbRow;
+        use surrealdb::types::SurrealValue;
+
+        #[derive(serde::Deserialize, SurrealValue)]
+        #[surreal(crate = "surrealdb::types")]
+        struct FeedOwnerRow {
+            owner: surrealdb::types::RecordId,
+            username: String,
+            #[surreal(default)]
+            hemisphere: Option<String>,
+        }
+
+        #[derive(serde::Deserialize, SurrealValue)]
+        #[surreal(crate = "surrealdb::types")]
+        struct BloomRow {
+            id: surrealdb::types::RecordId,
+            orchid: surrealdb::types::RecordId,
+            timestamp: chrono::DateTime<chrono::Utc>,
+            #[surreal(default)]
+            image_filename: Option<String>,
+            #[surreal(default)]
+            event_type: Option<String>,
+        }
+
+        if !key.starts_with(FEED_KEY_PREFIX) || key.len() > 100 {
+            return Err(StatusCode::NOT_FOUND);
+        }
+
+        let mut resp = db()
+            .query(
+                "SELECT owner, owner.username AS username, hemisphere FROM user_preference \
+                 WHERE public_feed_key_hash = $hash AND collection_public = true AND owner.disabled != true \
+                 LIMIT 1"
+            )
+            .bind(("hash", hash_token(&key)))
+            .await
+            .map_err(|e| {
+                tracing::error!("Public feed owner lookup failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+        let _ = resp.take_errors();
+        let feed_owner: Option<FeedOwnerRow> = resp.take(0).unwrap_or(None);
+        let feed_owner = feed_owner.ok_or(StatusCode::NOT_FOUND)?;
+
+        let mut resp = db()
+            .query(
+                "SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE ORDER BY created_at DESC; \
+                 SELECT id, orchid, timestamp, image_filename, event_type FROM log_entry \
+                 WHERE owner = $owner AND event_type = 'Flowering' ORDER BY timestamp DESC"
+            )
+            .bind(("owner", feed_owner.owner))
+            .await
+            .map_err(|e| {
+                tracing::error!("Public feed query failed: {}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+
+        let errors = resp.take_errors();
+        if !errors.is_empty() {
+            let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+            tracing::error!("Public feed query error: {}", err_msg);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+
+        let orchid_rows: Vec<OrchidDbRow> = resp.take(0).map_err(|e| {
+            tracing::error!("Public feed orchid parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        let bloom_rows: Vec<BloomRow> = resp.take(1).map_err(|e| {
+            tracing::error!("Public feed bloom parse failed: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+        let orchids: Vec<_> = orchid_rows.into_iter().map(|r| r.into_orchid()).collect();
+        // Journal text is never selected, so it can't leak into the feed
+        let entries: Vec<(String, LogEntry)> = bloom_rows.into_iter().map(|r| (
+            record_id_to_string(&r.orchid),
+            LogEntry {
+                id: record_id_to_string(&r.id),
+                timestamp: r.timestamp,
+                note: String::new(),
+                image_filename: r.image_filename,
+                event_type: r.event_type,
+                climate: None,
+                watering_method: None,
+                water_volume_ml: None,
+                skip_reason: None,
+                medium_feedback: None,
+                measurement: None,
+                root_assessment: None,
+            },
+        )).collect();
+
+        let hemisphere = feed_owner.hemisphere.unwrap_or_else(|| "N".to_string());
+        Ok(Json(build_feed(&feed_owner.username, &hemisphere, &orchids, &entries, chrono::Utc::now())))
+    }
+}
@@ -0,0 +1,504 @@
+use leptos::prelude::*;
+use crate::orchid::{Recurrence, Reminder};
+
+/// Longest reminder title accepted, matching the schema assertion.
+#[cfg(feature = "ssr")]
+const MAX_TITLE_LEN: usize = 200;
+/// Furthest ahead a reminder can be scheduled, in days.
+#[cfg(feature = "ssr")]
+const MAX_DAYS_AHEAD: i64 = 2 * 366;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::orchid::{Recurrence, RecurrenceUnit, Reminder};
+    use crate::server_fns::auth::record_id_to_string;
+
+    /// The columns every reminder query selects, matching `ReminderDbRow`.
+    pub const REMINDER_FIELDS: &str = "id, orchid, orchid.name AS orchid_name, zone, zone.name AS zone_name, \
+        title, due_at, repeat_every, repeat_unit, treatment";
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct ReminderDbRow {
+        pub id: surrealdb::types::RecordId,
+        #[surreal(default)]
+        pub orchid: Option<surrealdb::types::RecordId>,
+        #[surreal(default)]
+        pub orchid_name: Option<String>,
+        #[surreal(default)]
+        pub zone: Option<surrealdb::types::RecordId>,
+        #[surreal(default)]
+        pub zone_name: Option<String>,
+        pub title: String,
+        pub due_at: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        pub repeat_every: Option<u32>,
+        #[surreal(default)]
+        pub repeat_unit: Option<String>,
+        #[surreal(default)]
+        pub treatment: Option<surrealdb::types::RecordId>,
+    }
+
+    impl ReminderDbRow {
+        pub fn recurrence(&self) -> Option<Recurrence> {
+            let unit = self.repeat_unit.as_deref().and_then(RecurrenceUnit::parse)?;
+            self.repeat_every.map(|every| Recurrence { every, unit })
+        }
+
+        pub fn into_reminder(self) -> Reminder {
+            Reminder {
+                recurrence: self.recurrence(),
+                id: record_id_to_string(&self.id),
+                orchid_id: self.orchid.as_ref().map(record_id_to_string),
+                orchid_name: self.orchid_name,
+                zone_id: self.zone.as_ref().map(record_id_to_string),
+                zone_name: self.zone_name,
+                title: self.title,
+                due_at: self.due_at,
+            }
+        }
+    }
+}
+
+/// Checks the title and repeat rule, then creates the reminder after confirming the plant and zone
+/// belong to the same collection. Shared by `create_reminder` and `schedule_reminder`.
+#[cfg(feature = "ssr")]
+async fn insert_reminder(
+    owner: surrealdb::types::RecordId,
+    orchid_id: Option<String>,
+    zone_id: Option<String>,
+    title: String,
+    due_at: chrono::DateTime<chrono::Utc>,
+    recurrence: Option<Recurrence>,
+) -> Result<Reminder, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::orchid::MAX_RECURRENCE_EVERY;
+    use ssr_types::{ReminderDbRow, REMINDER_FIELDS};
+
+    let title = title.trim().to_string();
+    if title.is_empty() || title.len() > MAX_TITLE_LEN {
+        return Err(ServerFnError::new(format!("Title must be 1 to {} characters", MAX_TITLE_LEN)));
+    }
+    if recurrence.is_some_and(|r| r.every == 0 || r.every > MAX_RECURRENCE_EVERY) {
+        return Err(ServerFnError::new(format!("Repeat every 1 to {}", MAX_RECURRENCE_EVERY)));
+    }
+    let parse = |id: Option<String>, what: &'static str| id.as_deref()
+        .map(surrealdb::types::RecordId::parse_simple)
+        .transpose()
+        .map_err(|e| internal_error(what, e));
+    let orchid = parse(orchid_id, "Orchid ID parse failed")?;
+    let zone = parse(zone_id, "Zone ID parse failed")?;
+
+    // The plant and zone must belong to the same collection
+    let mut resp = db()
+        .query(format!(
+            "IF $orchid != NONE AND (SELECT VALUE id FROM $orchid WHERE owner = $owner) = [] {{ \
+                 THROW 'Plant not found'; \
+             }}; \
+             IF $zone != NONE AND (SELECT VALUE id FROM $zone WHERE owner = $owner) = [] {{ \
+                 THROW 'Zone not found'; \
+             }}; \
+             CREATE reminder SET owner = $owner, orchid = $orchid, zone = $zone, title = $title, due_at = $due_at, \
+                 repeat_every = $repeat_every, repeat_unit = $repeat_unit \
+                 RETURN {};",
+            REMINDER_FIELDS
+        ))
+        .bind(("owner", owner))
+        .bind(("orchid", orchid))
+        .bind(("zone", zone))
+        .bind(("title", title))
+        .bind(("due_at", due_at))
+        .bind(("repeat_every", recurrence.map(|r| r.every as i64)))
+        .bind(("repeat_unit", recurrence.map(|r| r.unit.as_str().to_string())))
+        .await
+        .map_err(|e| internal_error("Create reminder query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Create reminder query error", err_msg));
+    }
+
+    // Index 2 = CREATE (0 and 1 are the ownership checks)
+    let created: Option<ReminderDbRow> = resp.take(2)
+        .map_err(|e| internal_error("Create reminder parse failed", e))?;
+    created.map(ReminderDbRow::into_reminder)
+        .ok_or_else(|| ServerFnError::new("Failed to create reminder"))
+}
+
+/// **What is it?**
+/// A server function that schedules a reminder, optionally tied to one plant, a number of days from now.
+///
+/// **Why does it exist?**
+/// It exists so follow-ups like "check the roots again next week" land on the Today tab on the right day instead of relying on memory.
+///
+/// **How should it be used?**
+/// Call this from flows that end in a follow-up, such as the troubleshooting wizard. Returns the new reminder.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_reminder(
+    /// The plant the reminder is about, if any.
+    orchid_id: Option<String>,
+    /// What to do when the reminder is due.
+    title: String,
+    /// Days from now until the reminder is due.
+    due_in_days: u32,
+) -> Result<Reminder, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    if due_in_days > 366 {
+        return Err(ServerFnError::new("Reminders can be at most a year ahead"));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let due_at = chrono::Utc::now() + chrono::Duration::days(due_in_days as i64);
+    insert_reminder(owner, orchid_id, None, title, due_at, None).await
+}
+
+/// **What is it?**
+/// A server function that schedules a reminder for a chosen time, tied to a plant, a zone, or neither, and optionally repeating.
+///
+/// **Why does it exist?**
+/// It exists for care that isn't watering or fertilizing, like "check keiki roots in 3 weeks" or "clean the humidifier every month", which still needs a nudge on the right day.
+///
+/// **How should it be used?**
+/// Call this from the reminders panel on the Today tab with the due time (the browser converts the picked local date). A recurring reminder schedules its next occurrence when it's marked done.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn schedule_reminder(
+    /// What to do when the reminder is due.
+    title: String,
+    /// The plant the reminder is about, if any.
+    orchid_id: Option<String>,
+    /// The zone the reminder is about, if any.
+    zone_id: Option<String>,
+    /// When the reminder becomes due.
+    due_at: chrono::DateTime<chrono::Utc>,
+    /// How often it repeats; None for a one-off.
+    recurrence: Option<Recurrence>,
+) -> Result<Reminder, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::climate::parse_owner;
+
+    let now = chrono::Utc::now();
+    if due_at < now - chrono::Duration::days(1) {
+        return Err(ServerFnError::new("Pick today or a later date"));
+    }
+    if due_at > now + chrono::Duration::days(MAX_DAYS_AHEAD) {
+        return Err(ServerFnError::new("Reminders can be at most two years ahead"));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    insert_reminder(owner, orchid_id, zone_id, title, due_at, recurrence).await
+}
+
+/// **What is it?**
+/// A server function that lists every open reminder in the collection, due or not, soonest first.
+///
+/// **Why does it exist?**
+/// It exists for the reminders panel, where growers review what's coming up and remove reminders they no longer need.
+///
+/// **How should it be used?**
+/// Load this when the panel opens and again after scheduling, completing or deleting a reminder.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_reminders() -> Result<Vec<Reminder>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::{ReminderDbRow, REMINDER_FIELDS};
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(format!(
+            "SELECT {} FROM reminder WHERE owner = $owner AND completed_at IS NONE ORDER BY due_at ASC LIMIT 200",
+            REMINDER_FIELDS
+        ))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get all reminders query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get all reminders query error", err_msg));
+    }
+
+    let rows: Vec<ReminderDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get all reminders parse failed", e))?;
+    Ok(rows.into_iter().map(ReminderDbRow::into_reminder).collect())
+}
+
+/// **What is it?**
+/// A server function that lists the collection's open reminders that are due now or overdue.
+///
+/// **Why does it exist?**
+/// It exists to feed the Today tab, which shows follow-ups next to the plants that need watering.
+///
+/// **How should it be used?**
+/// Load this when the Today tab opens. Reminders stay in the list until `complete_reminder` is called.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_due_reminders() -> Result<Vec<Reminder>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::{ReminderDbRow, REMINDER_FIELDS};
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(format!(
+            "SELECT {} FROM reminder \
+             WHERE owner = $owner AND completed_at IS NONE AND due_at <= time::now() \
+             ORDER BY due_at ASC LIMIT 50",
+            REMINDER_FIELDS
+        ))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get reminders query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get reminders query error", err_msg));
+    }
+
+    let rows: Vec<ReminderDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get reminders parse failed", e))?;
+    Ok(rows.into_iter().map(ReminderDbRow::into_reminder).collect())
+}
+
+/// **What is it?**
+/// A server function that marks a reminder as done.
+///
+/// **Why does it exist?**
+/// It exists so a handled follow-up leaves the Today tab while its record stays in the database.
+///
+/// **How should it be used?**
+/// Call this from the "Done" button next to a due reminder. For a treatment's re-treatment reminder this records the application
+/// (see `server_fns::treatments::record_application`), which also schedules the next reminder in the series. A recurring
+/// reminder schedules its next occurrence, keeping to its original schedule.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn complete_reminder(
+    /// The unique identifier of the reminder.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::{ReminderDbRow, REMINDER_FIELDS};
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let reminder = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Reminder ID parse failed", e))?;
+
+    let mut open = db()
+        .query(format!("SELECT {} FROM $id WHERE owner = $owner AND completed_at = NONE", REMINDER_FIELDS))
+        .bind(("id", reminder.clone()))
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Open reminder query failed", e))?;
+    let row: Option<ReminderDbRow> = open.take::<Vec<ReminderDbRow>>(0)
+        .map_err(|e| internal_error("Open reminder parse failed", e))?
+        .into_iter()
+        .next();
+
+    if let Some(treatment) = row.as_ref().and_then(|r| r.treatment.clone()) {
+        // Fall back to a plain completion when the treatment was resolved or finished in the meantime
+        match crate::server_fns::treatments::record_application(&treatment, &owner).await {
+            Ok(_) => return Ok(()),
+            Err(e) => tracing::warn!(error = %e, "complete_reminder: treatment application not recorded"),
+        }
+    }
+    let next_due = row.as_ref()
+        .and_then(|r| r.recurrence().map(|rec| rec.next_after(r.due_at, chrono::Utc::now())));
+
+    let mut resp = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             UPDATE $id SET completed_at = time::now() WHERE owner = $owner; \
+             IF $next_due != NONE { \
+                 CREATE reminder SET owner = $owner, orchid = $id.orchid, zone = $id.zone, title = $id.title, \
+                     repeat_every = $id.repeat_every, repeat_unit = $id.repeat_unit, due_at = $next_due; \
+             }; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("id", reminder))
+        .bind(("owner", owner))
+        .bind(("next_due", next_due))
+        .await
+        .map_err(|e| internal_error("Complete reminder query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Complete reminder query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that removes an open reminder, along with any future occurrences of a recurring one.
+///
+/// **Why does it exist?**
+/// It exists so plans that changed (the keiki was potted up early, the humidifier was replaced) don't keep nagging.
+///
+/// **How should it be used?**
+/// Call this from the delete button in the reminders panel. Completed reminders are kept as history.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_reminder(
+    /// The unique identifier of the reminder.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let reminder = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Reminder ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("DELETE $id WHERE owner = $owner AND completed_at = NONE")
+        .bind(("id", reminder))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete reminder query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete reminder query error", err_msg));
+    }
+
+    Ok(())
+}
+
+/// **What is it?**
+/// A background task that sends a push notification when reminders come due.
+///
+/// **Why does it exist?**
+/// It exists so a reminder reaches the grower on its day even when they don't open the Today tab.
+///
+/// **How should it be used?**
+/// Spawn this in the main background loop every few minutes. Each reminder is pushed once (`notified_at` is set before sending),
+/// and a user with several due at once gets a single notification listing them.
+#[cfg(feature = "ssr")]
+pub async fn push_due_reminders() {
+    use std::collections::BTreeMap;
+    use crate::db::db;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct DueRow {
+        id: surrealdb::types::RecordId,
+        owner: surrealdb::types::RecordId,
+        title: String,
+        #[surreal(default)]
+        subject: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PushSubRow {
+        endpoint: String,
+        p256dh: String,
+        auth: String,
+    }
+
+    let mut due_resp = match db()
+        .query(
+            "SELECT id, owner, title, (orchid.name ?? zone.name) AS subject FROM reminder \
+             WHERE completed_at = NONE AND notified_at = NONE AND due_at <= time::now() \
+             ORDER BY due_at ASC LIMIT 500"
+        )
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("Reminder push: failed to query due reminders: {}", e);
+            return;
+        }
+    };
+    let _ = due_resp.take_errors();
+    let due: Vec<DueRow> = due_resp.take(0).unwrap_or_default();
+    if due.is_empty() {
+        return;
+    }
+
+    // Mark first so a failing push can't cause a resend loop
+    let ids: Vec<surrealdb::types::RecordId> = due.iter().map(|r| r.id.clone()).collect();
+    if let Err(e) = db()
+        .query("UPDATE reminder SET notified_at = time::now() WHERE id IN $ids")
+        .bind(("ids", ids))
+        .await
+    {
+        tracing::warn!("Reminder push: failed to mark reminders notified: {}", e);
+        return;
+    }
+
+    let mut by_owner: BTreeMap<String, (surrealdb::types::RecordId, Vec<String>)> = BTreeMap::new();
+    for row in due {
+        let line = match row.subject {
+            Some(subject) => format!("{} \u{00B7} {}", row.title, subject),
+            None => row.title,
+        };
+        by_owner.entry(crate::server_fns::auth::record_id_to_string(&row.owner))
+            .or_insert_with(|| (row.owner, Vec::new()))
+            .1.push(line);
+    }
+
+    for (owner, lines) in by_owner.into_values() {
+        let title = if lines.len() == 1 { "Reminder".to_string() } else { format!("{} reminders due", lines.len()) };
+        let body = lines.join("\n");
+
+        crate::push::send_ntfy_to_user(&owner, &title, &body).await;
+
+        let mut sub_resp = match db()
+            .query("SELECT endpoint, p256dh, auth FROM push_subscription WHERE owner = $owner")
+            .bind(("owner", owner.clone()))
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        let _ = sub_resp.take_errors();
+        let subs: Vec<PushSubRow> = sub_resp.take(0).unwrap_or_default();
+
+        for sub in subs {
+            let push_sub = crate::push::PushSubscriptionRow {
+                endpoint: sub.endpoint,
+                p256dh: sub.p256dh,
+                auth: sub.auth,
+            };
+            if let Err(e) = crate::push::deliver_push(&owner, &push_sub, &title, &body).await {
+                tracing::warn!("Reminder push failed: {}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use leptos::prelude::*;
+use crate::monthly_report::MonthlyReport;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::monthly_report::MonthlyReport;
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct MonthlyReportDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub month: String,
+        pub label: String,
+        pub summary: String,
+        pub facts: String,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    impl MonthlyReportDbRow {
+        pub fn into_monthly_report(self) -> MonthlyReport {
+            MonthlyReport {
+                id: record_id_to_string(&self.id),
+                month: self.month,
+                label: self.label,
+                summary: self.summary,
+                facts: self.facts,
+                created_at: self.created_at,
+            }
+        }
+    }
+}
+
+/// **What is it?**
+/// An SSR-only helper that gathers what happened in an owner's collection over one month: journal entries, climate alerts and watering adherence.
+///
+/// **Why does it exist?**
+/// It exists so the monthly report is written from the grower's actual records, the same journal and alerts the app shows them.
+///
+/// **How should it be used?**
+/// Call with the owner's `RecordId` and the month to cover; check `MonthlyFacts::is_quiet` before writing a report from the result.
+#[cfg(feature = "ssr")]
+pub(crate) async fn month_facts_for_owner(
+    owner: &surrealdb::types::RecordId,
+    month: &crate::monthly_report::ReportMonth,
+) -> Result<crate::monthly_report::MonthlyFacts, ServerFnError> {
+    use surrealdb::types::SurrealValue;
+    use crate::adherence::{collection_adherence_since, ADHERENCE_WINDOW_DAYS};
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::monthly_report::*;
+    use crate::orchid::Hemisphere;
+    use crate::server_fns::orchids::ssr_types::OrchidDbRow;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct EntryRow {
+        #[surreal(default)]
+        event_type: Option<String>,
+        #[surreal(default)]
+        note: String,
+        #[surreal(default)]
+        plant: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct AlertRow {
+        alert_type: String,
+        #[surreal(default)]
+        zone_name: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PrefRow {
+        #[surreal(default)]
+        hemisphere: Option<String>,
+        #[surreal(default)]
+        overdue_grace_days: Option<i64>,
+    }
+
+    let mut resp = db()
+        .query(
+            "SELECT * FROM orchid WHERE owner = $owner AND archived_at = NONE; \
+             SELECT event_type, note, orchid.name AS plant FROM log_entry \
+                WHERE owner = $owner AND timestamp >= $start AND timestamp < $end ORDER BY timestamp ASC; \
+             SELECT alert_type, zone.name AS zone_name FROM alert \
+                WHERE owner = $owner AND alert_type IN $climate AND created_at >= $start AND created_at < $end; \
+             SELECT hemisphere, overdue_grace_days FROM user_preference WHERE owner = $owner LIMIT 1;"
+        )
+        .bind(("owner", owner.clone()))
+        .bind(("start", month.start))
+        .bind(("end", month.end))
+        .bind(("climate", CLIMATE_ALERT_TYPES.iter().map(|t| t.to_string()).collect::<Vec<_>>()))
+        .await
+        .map_err(|e| internal_error("Monthly facts query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Monthly facts query error", err_msg));
+    }
+
+    let orchids: Vec<OrchidDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Monthly facts orchids parse failed", e))?;
+    let orchids: Vec<_> = orchids.into_iter().map(|r| r.into_orchid()).collect();
+    let entries: Vec<EntryRow> = resp.take(1)
+        .map_err(|e| internal_error("Monthly facts entries parse failed", e))?;
+    let alerts: Vec<AlertRow> = resp.take(2)
+        .map_err(|e| internal_error("Monthly facts alerts parse failed", e))?;
+    let pref: Option<PrefRow> = resp.take(3).unwrap_or(None);
+
+    let mut facts = MonthlyFacts { plants: orchids.len(), ..Default::default() };
+    facts.tally_events(entries.iter().map(|e| e.event_type.as_deref()));
+    for entry in &entries {
+        let plant = entry.plant.as_deref().unwrap_or("A plant");
+        let event = entry.event_type.as_deref().filter(|e| !e.is_empty()).unwrap_or("Note");
+        if event == "Flowering" && !facts.blooming.iter().any(|b| b == plant) {
+            facts.blooming.push(plant.to_string());
+        }
+        let note = entry.note.trim();
+        if !note.is_empty() && facts.notes.len() < MAX_REPORT_NOTES {
+            let note: String = note.chars().take(MAX_QUOTED_NOTE_LEN).collect();
+            facts.notes.push(format!("{} ({}): {}", plant, event, note));
+        }
+    }
+    facts.excursions = group_excursions(alerts.into_iter().map(|a| {
+        (a.zone_name.unwrap_or_else(|| "Collection".into()), a.alert_type)
+    }));
+
+    // Waterings are judged against the one before, so load from well before the month starts
+    let hemisphere = Hemisphere::from_code(pref.as_ref().and_then(|p| p.hemisphere.as_deref()).unwrap_or("N"));
+    let grace_days = pref.and_then(|p| p.overdue_grace_days)
+        .map(|d| d.clamp(0, crate::orchid::MAX_OVERDUE_GRACE_DAYS as i64) as u32)
+        .unwrap_or(0);
+    let from = month.start - chrono::Duration::days(ADHERENCE_WINDOW_DAYS);
+    let events = crate::server_fns::adherence::care_events_for_owner(owner, &orchids, from, month.end).await?;
+    let adherence = collection_adherence_since(
+        &orchids,
+        |id| events.get(id).map(Vec::as_slice).unwrap_or(&[]),
+        &hemisphere,
+        grace_days,
+        month.start,
+    );
+    facts.waterings_on_time = adherence.on_time;
+    facts.waterings_late = adherence.late;
+    facts.late_plants = adherence.plants.into_iter()
+        .filter(|p| p.late > 0)
+        .take(MAX_LATE_PLANTS)
+        .map(|p| LatePlant { name: p.name, late: p.late, average_days_late: p.average_days_late })
+        .collect();
+
+    Ok(facts)
+}
+
+/// **What is it?**
+/// A background task that writes last month's collection report for every grower, stores it and pushes its opening lines.
+///
+/// **Why does it exist?**
+/// It exists so growers get a readable look back at the month (what was logged, what bloomed, which zones drifted and which plants kept getting watered late) without digging through the journal.
+///
+/// **How should it be used?**
+/// Spawn this in the main background loop every few hours. The report is stored before the push and `monthly_report` is unique per owner and month,
+/// so restarts never double-send. Quiet months are skipped, and the plain facts stand in for the report when no AI provider answers.
+#[cfg(feature = "ssr")]
+pub async fn generate_monthly_reports() {
+    use crate::db::db;
+    use crate::monthly_report::{notification_body, ReportMonth};
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct OwnerRow {
+        owner: surrealdb::types::RecordId,
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PushSubRow {
+        endpoint: String,
+        p256dh: String,
+        auth: String,
+    }
+
+    let month = ReportMonth::previous(chrono::Utc::now());
+
+    // 1. Owners with plants who don't have this month's report yet
+    let mut owner_resp = match db()
+        .query(
+            "SELECT owner FROM orchid WHERE archived_at = NONE GROUP BY owner; \
+             SELECT owner FROM monthly_report WHERE month = $month;"
+        )
+        .bind(("month", month.key.clone()))
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!("Monthly reports: failed to query owners: {}", e);
+            return;
+        }
+    };
+    let _ = owner_resp.take_errors();
+    let owners: Vec<OwnerRow> = owner_resp.take(0).unwrap_or_default();
+    let done: Vec<OwnerRow> = owner_resp.take(1).unwrap_or_default();
+
+    for owner in owners.into_iter().map(|r| r.owner).filter(|o| !done.iter().any(|d| &d.owner == o)) {
+        let facts = match month_facts_for_owner(&owner, &month).await {
+            Ok(f) => f,
+            Err(e) => {
+                tracing::warn!("Monthly reports: failed to gather facts: {}", e);
+                continue;
+            }
+        };
+        if facts.is_quiet() {
+            continue;
+        }
+
+        let facts_text = facts.to_text(&month);
+        let prompt = format!(
+            "You are the care assistant in an orchid collection tracker. Write the grower's report for {} from the facts below. \
+            Open with one or two sentences on how the month went overall; this opening is sent on its own as a notification. \
+            Then cover what stood out: blooms and growth, climate trouble by zone, and plants whose watering kept slipping, \
+            with a practical suggestion for each problem. Use only these facts, keep it under 250 words, \
+            and write plain text paragraphs without markdown headings.\n\n{}",
+            month.label,
+            facts_text,
+        );
+        let summary = match crate::ai::text(&prompt).await {
+            Ok(text) if !text.trim().is_empty() => text.trim().to_string(),
+            Ok(_) => facts_text.clone(),
+            Err(e) => {
+                tracing::warn!("Monthly reports: AI summary failed, storing the facts: {}", e);
+                facts_text.clone()
+            }
+        };
+
+        // Store first so a failing push can't cause a resend; the unique index turns a race into an error here
+        let created = db()
+            .query(
+                "CREATE monthly_report SET owner = $owner, month = $month, label = $label, summary = $summary, facts = $facts"
+            )
+            .bind(("owner", owner.clone()))
+            .bind(("month", month.key.clone()))
+            .bind(("label", month.label.clone()))
+            .bind(("summary", summary.clone()))
+            .bind(("facts", facts_text))
+            .await;
+        match created.map(|mut r| r.take_errors()) {
+            Ok(errors) if errors.is_empty() => {}
+            Ok(errors) => {
+                let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+                tracing::warn!("Monthly reports: failed to store report: {}", err_msg);
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("Monthly reports: failed to store report: {}", e);
+                continue;
+            }
+        }
+
+        let title = format!("Your {} collection report", month.label);
+        let body = notification_body(&summary);
+        crate::push::send_ntfy_to_user(&owner, &title, &body).await;
+
+        let mut sub_resp = match db()
+            .query("SELECT endpoint, p256dh, auth FROM push_subscription WHERE owner = $owner")
+            .bind(("owner", owner.clone()))
+            .await
+        {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        let _ = sub_resp.take_errors();
+        let subs: Vec<PushSubRow> = sub_resp.take(0).unwrap_or_default();
+
+        for sub in subs {
+            let push_sub = crate::push::PushSubscriptionRow {
+                endpoint: sub.endpoint,
+                p256dh: sub.p256dh,
+                auth: sub.auth,
+            };
+            if let Err(e) = crate::push::deliver_push(&owner, &push_sub, &title, &body).await {
+                tracing::warn!("Monthly report push failed: {}", e);
+            }
+        }
+    }
+}
+
+/// **What is it?**
+/// A server function that lists the collection's monthly reports, newest first.
+///
+/// **Why does it exist?**
+/// It exists so growers can reread a report after the notification is gone and compare months.
+///
+/// **How should it be used?**
+/// Call from the Reports tab. At most `REPORT_HISTORY_LIMIT` reports are returned; an empty list means none has been written yet.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_monthly_reports() -> Result<Vec<MonthlyReport>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::monthly_report::REPORT_HISTORY_LIMIT;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::MonthlyReportDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT id, month, label, summary, facts, created_at FROM monthly_report WHERE owner = $owner ORDER BY month DESC LIMIT $limit")
+        .bind(("owner", owner))
+        .bind(("limit", REPORT_HISTORY_LIMIT as i64))
+        .await
+        .map_err(|e| internal_error("Monthly reports query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Monthly reports query error", err_msg));
+    }
+
+    let rows: Vec<MonthlyReportDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Monthly reports parse failed", e))?;
+    Ok(rows.into_iter().map(MonthlyReportDbRow::into_monthly_report).collect())
+}
@@ -1,282 +1,6 @@
 use leptos::prelude::*;
 use crate::components::scanner::AnalysisResult;
 
-// ── AI Provider Helpers ─────────────────────────────────────────────
-
-/// Call Gemini API with a vision (image + text) prompt.
-#[cfg(feature = "ssr")]
-async fn call_gemini_vision(
-    api_key: &str,
-    model: &str,
-    prompt: &str,
-    image_base64: &str,
-) -> Result<String, String> {
-    let request_body = serde_json::json!({
-        "contents": [{
-            "parts": [
-                { "text": prompt },
-                { "inline_data": { "mime_type": "image/jpeg", "data": image_base64 } }
-            ]
-        }]
-    });
-
-    let url = format!(
-        "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
-        model
-    );
-
-    let client = reqwest::Client::new();
-    let resp = client.post(&url)
-        .header("x-goog-api-key", api_key)
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Gemini network error: {}", e))?;
-
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        return Err(format!("Gemini API error: {} {}", status, body));
-    }
-
-    let json_resp: serde_json::Value = resp.json().await
-        .map_err(|e| format!("Gemini parse error: {}", e))?;
-
-    extract_gemini_text(&json_resp)
-}
-
-/// Call Gemini API with a text-only prompt.
-#[cfg(feature = "ssr")]
-async fn call_gemini_text(
-    api_key: &str,
-    model: &str,
-    prompt: &str,
-) -> Result<String, String> {
-    let request_body = serde_json::json!({
-        "contents": [{
-            "parts": [{ "text": prompt }]
-        }]
-    });
-
-    let url = format!(
-        "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent",
-        model
-    );
-
-    let client = reqwest::Client::new();
-    let resp = client.post(&url)
-        .header("x-goog-api-key", api_key)
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Gemini network error: {}", e))?;
-
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        return Err(format!("Gemini API error: {} {}", status, body));
-    }
-
-    let json_resp: serde_json::Value = resp.json().await
-        .map_err(|e| format!("Gemini parse error: {}", e))?;
-
-    extract_gemini_text(&json_resp)
-}
-
-/// Extract text from a Gemini API response.
-#[cfg(feature = "ssr")]
-fn extract_gemini_text(json: &serde_json::Value) -> Result<String, String> {
-    json.get("candidates")
-        .and_then(|c| c.get(0))
-        .and_then(|c| c.get("content"))
-        .and_then(|c| c.get("parts"))
-        .and_then(|p| p.get(0))
-        .and_then(|p| p.get("text"))
-        .and_then(|t| t.as_str())
-        .map(|s| s.replace("```json", "").replace("```", "").trim().to_string())
-        .ok_or_else(|| "Could not extract text from Gemini response".to_string())
-}
-
-/// Call Claude Messages API with a vision (image + text) prompt.
-#[cfg(feature = "ssr")]
-async fn call_claude_vision(
-    api_key: &str,
-    model: &str,
-    prompt: &str,
-    image_base64: &str,
-) -> Result<String, String> {
-    let request_body = serde_json::json!({
-        "model": model,
-        "max_tokens": 4096,
-        "messages": [{
-            "role": "user",
-            "content": [
-                {
-                    "type": "image",
-                    "source": {
-                        "type": "base64",
-                        "media_type": "image/jpeg",
-                        "data": image_base64
-                    }
-                },
-                {
-                    "type": "text",
-                    "text": prompt
-                }
-            ]
-        }]
-    });
-
-    let client = reqwest::Client::new();
-    let resp = client.post("https://api.anthropic.com/v1/messages")
-        .header("x-api-key", api_key)
-        .header("anthropic-version", "2023-06-01")
-        .header("content-type", "application/json")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Claude network error: {}", e))?;
-
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        return Err(format!("Claude API error: {} {}", status, body));
-    }
-
-    let json_resp: serde_json::Value = resp.json().await
-        .map_err(|e| format!("Claude parse error: {}", e))?;
-
-    extract_claude_text(&json_resp)
-}
-
-/// Call Claude Messages API with a text-only prompt.
-#[cfg(feature = "ssr")]
-async fn call_claude_text(
-    api_key: &str,
-    model: &str,
-    prompt: &str,
-) -> Result<String, String> {
-    let request_body = serde_json::json!({
-        "model": model,
-        "max_tokens": 1024,
-        "messages": [{
-            "role": "user",
-            "content": prompt
-        }]
-    });
-
-    let client = reqwest::Client::new();
-    let resp = client.post("https://api.anthropic.com/v1/messages")
-        .header("x-api-key", api_key)
-        .header("anthropic-version", "2023-06-01")
-        .header("content-type", "application/json")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Claude network error: {}", e))?;
-
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        return Err(format!("Claude API error: {} {}", status, body));
-    }
-
-    let json_resp: serde_json::Value = resp.json().await
-        .map_err(|e| format!("Claude parse error: {}", e))?;
-
-    extract_claude_text(&json_resp)
-}
-
-/// Extract text from a Claude Messages API response.
-#[cfg(feature = "ssr")]
-fn extract_claude_text(json: &serde_json::Value) -> Result<String, String> {
-    json.get("content")
-        .and_then(|c| c.get(0))
-        .and_then(|c| c.get("text"))
-        .and_then(|t| t.as_str())
-        .map(|s| s.replace("```json", "").replace("```", "").trim().to_string())
-        .ok_or_else(|| "Could not extract text from Claude response".to_string())
-}
-
-// ── Fallback Orchestration ──────────────────────────────────────────
-
-/// Call AI vision with automatic fallback: tries Gemini first, then Claude.
-#[cfg(feature = "ssr")]
-async fn call_ai_vision(prompt: &str, image_base64: &str) -> Result<String, String> {
-    use crate::config::config;
-    let cfg = config();
-
-    let has_gemini = !cfg.gemini_api_key.is_empty();
-    let has_claude = !cfg.claude_api_key.is_empty();
-
-    if !has_gemini && !has_claude {
-        return Err("No AI API keys configured. Set GEMINI_API_KEY and/or CLAUDE_API_KEY in your .env file.".to_string());
-    }
-
-    // Try Gemini first
-    if has_gemini {
-        match call_gemini_vision(&cfg.gemini_api_key, &cfg.gemini_model, prompt, image_base64).await {
-            Ok(text) => return Ok(text),
-            Err(e) => {
-                if has_claude {
-                    tracing::warn!("Gemini failed ({}), falling back to Claude", e);
-                } else {
-                    return Err(e);
-                }
-            }
-        }
-    }
-
-    // Fallback to Claude
-    if has_claude {
-        match call_claude_vision(&cfg.claude_api_key, &cfg.claude_model, prompt, image_base64).await {
-            Ok(text) => return Ok(text),
-            Err(e) => {
-                return Err(format!(
-                    "AI analysis failed (both providers). Last error: {}", e
-                ));
-            }
-        }
-    }
-
-    unreachable!()
-}
-
-/// Call AI text with automatic fallback: tries Gemini first, then Claude.
-#[cfg(feature = "ssr")]
-async fn call_ai_text(prompt: &str) -> Result<String, String> {
-    use crate::config::config;
-    let cfg = config();
-
-    let has_gemini = !cfg.gemini_api_key.is_empty();
-    let has_claude = !cfg.claude_api_key.is_empty();
-
-    if !has_gemini && !has_claude {
-        return Err("No AI API keys configured".to_string());
-    }
-
-    // Try Gemini first
-    if has_gemini {
-        match call_gemini_text(&cfg.gemini_api_key, &cfg.gemini_model, prompt).await {
-            Ok(text) => return Ok(text),
-            Err(e) => {
-                if has_claude {
-                    tracing::warn!("Gemini text failed ({}), falling back to Claude", e);
-                } else {
-                    return Err(e);
-                }
-            }
-        }
-    }
-
-    // Fallback to Claude
-    if has_claude {
-        return call_claude_text(&cfg.claude_api_key, &cfg.claude_model, prompt).await;
-    }
-
-    unreachable!()
-}
-
 // ── Andy's Orchids Care Data ────────────────────────────────────────
 
 /// Strip HTML tags from a string fragment, preserving inner text.
@@ -415,84 +139,107 @@ async fn fetch_andys_orchids_care(species_name: &str) -> Option<String> {
     extract_care_from_html(&product_html)
 }
 
+// ── Reference Photos ────────────────────────────────────────────────
+
+/// Extract the thumbnail URL from a Wikipedia page summary response.
+#[cfg(feature = "ssr")]
+fn extract_wikipedia_thumbnail(json: &serde_json::Value) -> Option<String> {
+    json.get("thumbnail")
+        .and_then(|t| t.get("source"))
+        .and_then(|s| s.as_str())
+        .filter(|s| s.starts_with("https://"))
+        .map(|s| s.to_string())
+}
+
+/// Fetch a reference photo for a species from its Wikipedia article.
+/// Returns None if there's no article, it has no image, or on any error.
+#[cfg(feature = "ssr")]
+async fn fetch_reference_thumbnail(species_name: &str) -> Option<String> {
+    let title = species_name.split_whitespace().collect::<Vec<_>>().join("_");
+    if title.is_empty() {
+        return None;
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .ok()?;
+
+    let url = format!("https://en.wikipedia.org/api/rest_v1/page/summary/{}", title);
+    let resp = client.get(&url)
+        .header("user-agent", "OrchidTracker")
+        .send()
+        .await
+        .ok()?;
+    if !resp.status().is_success() {
+        return None;
+    }
+
+    let json: serde_json::Value = resp.json().await.ok()?;
+    extract_wikipedia_thumbnail(&json)
+}
+
 // ── Server Functions ────────────────────────────────────────────────
 
 /// **What is it?**
-/// A server function that analyzes a base64-encoded image using an AI vision model to identify an orchid and determine its care requirements.
+/// A server function that looks at a photo of a plant or its tag and returns the species it could be, most likely first,
+/// along with any care instructions printed on a grower's tag.
 ///
 /// **Why does it exist?**
-/// It exists to provide an automated, intelligent way for users to add plants to their collection by simply snapping a photo, offloading complex identification and climate matching to the backend.
+/// It exists because look-alike species and half-legible tags make a single guess unreliable; a ranked list with confidence and
+/// reference photos lets the grower confirm the right species, or reject them all, before anything is added to the collection.
 ///
 /// **How should it be used?**
-/// Call this from the scanner UI after capturing an image from the device camera, passing in the user's current climate and zone data to receive a tailored `AnalysisResult`.
+/// Call this from the scanner after capturing a frame from the device camera, then profile the candidate the user picks with
+/// `analyze_orchid_by_name` and overlay the tag's instructions with `CareTag::apply_to`.
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
-pub async fn analyze_orchid_image(
+pub async fn identify_orchid_candidates(
     /// The base64-encoded image data.
     image_base64: String,
     /// A list of species names the user already owns.
     existing_species: Option<Vec<String>>,
-    /// A text summary of the user's current climate conditions.
-    climate_summary: String,
-    /// A list of the user's configured growing zones.
-    zone_names: Option<Vec<String>>,
-) -> Result<AnalysisResult, ServerFnError> {
+) -> Result<crate::identification::ScanIdentification, ServerFnError> {
     use crate::auth::require_auth;
+    use crate::care_tag::CareTag;
+    use crate::identification::{rank_candidates, ScanIdentification, SpeciesCandidate, MAX_SPECIES_CANDIDATES};
+    use crate::rate_limit::{check_user, Expensive};
 
-    require_auth().await?;
+    let user_id = require_auth().await?;
+    check_user(Expensive::Ai, &user_id).map_err(ServerFnError::new)?;
 
     let existing_species = existing_species.unwrap_or_default();
-    let zone_names = zone_names.unwrap_or_default();
 
     // Cap base64 payload at ~15MB to prevent abuse
     if image_base64.len() > 15 * 1024 * 1024 {
         return Err(ServerFnError::new("Image too large (max 15MB)"));
     }
 
-    let zone_list = if zone_names.is_empty() {
-        "No zones configured".to_string()
-    } else {
-        zone_names.join(", ")
-    };
-
     let prompt = format!(
         "Identify the plant species from this image. This is most likely an orchid but could be any houseplant or companion plant (e.g. Rhipsalis, Hoya, fern, Tillandsia). \
-        Think step-by-step: \
-        1. Identify the species with high confidence (look for tags). \
-        2. Analyze its natural habitat and care requirements. \
-        3. Compare requirements against my conditions: {}. \
-        4. Consider my growing zones: {}. \
-        5. Check if I own it: {:?}. \
-        6. Determine the plant's native habitat region and approximate center-point coordinates for its primary native range. \
-        Then, evaluate the fit. \
-        Finally, return ONLY valid JSON with this structure (no markdown): \
-        {{ \"species_name\": \"...\", \"fit_category\": \"Good Fit\", \"reason\": \"...\", \"already_owned\": false, \"water_freq\": 7, \"light_req\": \"Medium\", \"temp_range\": \"18-28C\", \"temp_min\": 18.0, \"temp_max\": 28.0, \"humidity_min\": 50.0, \"humidity_max\": 80.0, \"placement_suggestion\": \"...\", \"conservation_status\": \"CITES II\", \"native_region\": \"Cloud forests of Ecuador\", \"native_latitude\": -1.83, \"native_longitude\": -78.18 }} \
-        Allowed fit_categories: 'Good Fit', 'Bad Fit', 'Caution Fit'. \
-        For light_req, choose from: 'High', 'Medium', 'Low'. \
-        For placement_suggestion, choose from my zones: {}. \
-        For conservation_status, use 'CITES I', 'CITES II', 'Endangered', 'Vulnerable', or null if unknown/common. \
-        For native_region, provide a brief description of where this species naturally grows. \
-        For native_latitude and native_longitude, provide approximate decimal coordinates for the center of its native range. Set to null if unknown. \
-        For temp_min/temp_max, provide the FULL TOLERANCE temperature range in Celsius \u{2014} the absolute minimum and maximum the species can handle without damage. These values drive alerts, so use tolerance limits, not just the ideal range. \
-        For humidity_min/humidity_max, provide the ideal humidity range as percentages (e.g. 50.0 and 80.0). Set to null if unknown. \
-        Also include seasonal care data in Northern Hemisphere terms: \
-        \"rest_start_month\": 11, \"rest_end_month\": 2, \"bloom_start_month\": 3, \"bloom_end_month\": 5, \
-        \"rest_water_multiplier\": 0.3, \"rest_fertilizer_multiplier\": 0.0, \
-        \"active_water_multiplier\": 1.0, \"active_fertilizer_multiplier\": 1.0 \
-        Months are 1-12. Multipliers are 0.0-1.0 (0.3 = 30% of normal frequency, 0.0 = stop entirely). \
-        Set seasonal fields to null if the species has no distinct rest period or seasonal cycle.",
-        climate_summary,
-        zone_list,
-        existing_species,
-        zone_list,
+        Read any plant tag first; a legible tag outweighs appearance. \
+        List up to {} candidate species, most likely first, each with your confidence from 0.0 to 1.0. \
+        Include look-alikes the photo doesn't clearly rule out; the confidences across all candidates should add up to at most 1.0. \
+        For each candidate, briefly say what in the photo points to it or what would tell it apart from the others. \
+        If the photo shows a grower's tag or care card, also transcribe its care instructions into care_tag; otherwise set care_tag to null. \
+        Only fill in what the tag actually prints and use null for the rest; don't fill gaps from your own knowledge. \
+        Return ONLY valid JSON with this structure (no markdown): \
+        {{ \"candidates\": [ {{ \"species_name\": \"Phalaenopsis bellina\", \"confidence\": 0.7, \"reason\": \"...\" }} ], \
+        \"care_tag\": {{ \"species_name\": \"Phal. bellina\", \"temp_min\": 18.0, \"temp_max\": 30.0, \"fahrenheit\": false, \"light\": \"Low\", \"water_every_days\": 7, \
+        \"humidity_min\": 50.0, \"humidity_max\": 80.0, \"bloom_start_month\": 3, \"bloom_end_month\": 5 }} }} \
+        Use full botanical names, or the registered grex name for a hybrid. \
+        For care_tag.fahrenheit, use true when the tag gives temperatures in \u{00B0}F. \
+        For care_tag.light, choose from: 'High', 'Medium', 'Low'. Convert a watering instruction like 'twice a week' to days. \
+        Months are 1-12; convert a bloom season like 'spring' to Northern Hemisphere months.",
+        MAX_SPECIES_CANDIDATES,
     );
 
-    let text = call_ai_vision(&prompt, &image_base64).await
+    let text = crate::ai::vision(&prompt, &image_base64).await
         .map_err(|e| crate::error::internal_error("AI vision call failed", e))?;
 
-    tracing::debug!("AI vision raw response ({} chars): {}", text.len(), &text[..text.len().min(500)]);
+    tracing::debug!("AI identification raw response ({} chars): {}", text.len(), &text[..text.len().min(500)]);
 
-    let mut result: AnalysisResult = serde_json::from_str(&text)
+    let parsed: serde_json::Value = serde_json::from_str(&text)
         .map_err(|e| {
             crate::error::internal_error(
                 "Failed to parse AI response",
@@ -500,48 +247,34 @@ pub async fn analyze_orchid_image(
             )
         })?;
 
-    // Refine with Andy's Orchids data if available
-    if let Some(care_data) = fetch_andys_orchids_care(&result.species_name).await {
-        tracing::info!("Found Andy's Orchids data for {}, refining analysis", result.species_name);
-        let refinement_prompt = format!(
-            "The species {} was identified. Here is real-world nursery care data from Andy's Orchids:\n{}\n\n\
-            The current analysis has: temp_min={}, temp_max={}, humidity_min={}, humidity_max={}, temp_range=\"{}\"\n\n\
-            Based on the nursery data, return ONLY valid JSON adjusting these fields:\n\
-            {{\"temp_min\": X, \"temp_max\": X, \"humidity_min\": X, \"humidity_max\": X, \"temp_range\": \"X-YC\"}}\n\
-            For temp_min/temp_max, use the FULL TOLERANCE range (absolute min/max the plant can handle in °C).",
-            result.species_name,
-            care_data,
-            result.temp_min.map_or("null".to_string(), |v| v.to_string()),
-            result.temp_max.map_or("null".to_string(), |v| v.to_string()),
-            result.humidity_min.map_or("null".to_string(), |v| v.to_string()),
-            result.humidity_max.map_or("null".to_string(), |v| v.to_string()),
-            result.temp_range,
-        );
-
-        if let Ok(refinement_text) = call_ai_text(&refinement_prompt).await {
-            if let Ok(adjustments) = serde_json::from_str::<serde_json::Value>(&refinement_text) {
-                if let Some(v) = adjustments.get("temp_min").and_then(|v| v.as_f64()) {
-                    result.temp_min = Some(v);
-                }
-                if let Some(v) = adjustments.get("temp_max").and_then(|v| v.as_f64()) {
-                    result.temp_max = Some(v);
-                }
-                if let Some(v) = adjustments.get("humidity_min").and_then(|v| v.as_f64()) {
-                    result.humidity_min = Some(v);
-                }
-                if let Some(v) = adjustments.get("humidity_max").and_then(|v| v.as_f64()) {
-                    result.humidity_max = Some(v);
-                }
-                if let Some(v) = adjustments.get("temp_range").and_then(|v| v.as_str()) {
-                    result.temp_range = v.to_string();
-                }
-            } else {
-                tracing::warn!("Failed to parse Andy's refinement response, using original values");
-            }
+    let candidates: Vec<SpeciesCandidate> = match parsed.get("candidates") {
+        Some(list) if !list.is_null() => serde_json::from_value(list.clone())
+            .map_err(|e| crate::error::internal_error("Failed to parse AI candidates", e))?,
+        _ => Vec::new(),
+    };
+    // A garbled tag reading shouldn't cost the user their candidates
+    let care_tag = parsed.get("care_tag")
+        .and_then(|tag| serde_json::from_value::<CareTag>(tag.clone()).ok())
+        .map(CareTag::normalize)
+        .filter(CareTag::has_care);
+    let mut candidates = rank_candidates(candidates, &existing_species);
+    if candidates.is_empty() {
+        return Err(ServerFnError::new("Couldn't identify a plant in this photo"));
+    }
+
+    // Look reference photos up side by side; a missing one just leaves the card without a picture
+    let mut lookups = tokio::task::JoinSet::new();
+    for (i, c) in candidates.iter().enumerate() {
+        let name = c.species_name.clone();
+        lookups.spawn(async move { (i, fetch_reference_thumbnail(&name).await) });
+    }
+    while let Some(joined) = lookups.join_next().await {
+        if let Ok((i, Some(url))) = joined {
+            candidates[i].reference_image = Some(url);
         }
     }
 
-    Ok(result)
+    Ok(ScanIdentification { candidates, care_tag })
 }
 
 /// **What is it?**
@@ -616,17 +349,26 @@ pub(crate) async fn analyze_species_core(
         andys_section,
     );
 
-    let text = call_ai_text(&prompt).await
+    let text = crate::ai::text(&prompt).await
         .map_err(|e| format!("AI text call failed for '{}': {}", species_name, e))?;
 
     tracing::debug!("AI text raw response for '{}' ({} chars): {}", species_name, text.len(), &text[..text.len().min(500)]);
 
-    let result: AnalysisResult = serde_json::from_str(&text)
+    let mut result: AnalysisResult = serde_json::from_str(&text)
         .map_err(|e| {
             tracing::error!("Failed to parse AI response for '{}': {}. Raw text: {}", species_name, e, &text[..text.len().min(1000)]);
             format!("Failed to parse AI response: {}", e)
         })?;
 
+    // The bundled taxonomy's native range beats the model's guess for species it knows
+    if let Some(known) = crate::taxonomy::species_dataset().find(&result.species_name)
+        && known.native_latitude.is_some()
+    {
+        result.native_region = known.native_region.clone();
+        result.native_latitude = known.native_latitude;
+        result.native_longitude = known.native_longitude;
+    }
+
     Ok(result)
 }
 
@@ -651,8 +393,10 @@ pub async fn analyze_orchid_by_name(
     zone_names: Option<Vec<String>>,
 ) -> Result<AnalysisResult, ServerFnError> {
     use crate::auth::require_auth;
+    use crate::rate_limit::{check_user, Expensive};
 
-    require_auth().await?;
+    let user_id = require_auth().await?;
+    check_user(Expensive::Ai, &user_id).map_err(ServerFnError::new)?;
 
     let species_name = species_name.trim().to_string();
     if species_name.is_empty() {
@@ -683,21 +427,24 @@ pub async fn generate_care_recap(
     /// The type of event (e.g., "Flowering").
     event_type: String,
 ) -> Result<String, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
+    use crate::rate_limit::{check_user, Expensive};
 
-    let user_id = require_auth().await?;
+    let access = require_role(HouseholdRole::Viewer).await?;
+    let owner_id = access.owner_id;
 
     let orchid_record = surrealdb::types::RecordId::parse_simple(&orchid_id)
         .map_err(|e| internal_error("Parse orchid ID failed", e))?;
-    let owner = surrealdb::types::RecordId::parse_simple(&user_id)
+    let owner = surrealdb::types::RecordId::parse_simple(&owner_id)
         .map_err(|e| internal_error("Parse user ID failed", e))?;
 
     // Gather care history for past 6 months
     let mut response = db()
         .query(
-            "SELECT event_type, note, timestamp FROM log_entry \
+            "SELECT event_type, note, timestamp, climate FROM log_entry \
              WHERE orchid = $orchid_id AND owner = $owner \
              AND timestamp > time::now() - 6m \
              ORDER BY timestamp ASC"
@@ -738,7 +485,14 @@ pub async fn generate_care_recap(
         match et {
             "Watered" => watering_count += 1,
             "" => {},
-            _ => care_events.push(format!("{}: {}", et, note)),
+            _ => {
+                // Conditions captured at the time help explain events like bud blast
+                let conditions = entry.get("climate")
+                    .and_then(|c| serde_json::from_value::<crate::orchid::EntryClimate>(c.clone()).ok())
+                    .map(|c| format!(" (conditions: {})", c.describe("C")))
+                    .unwrap_or_default();
+                care_events.push(format!("{}: {}{}", et, note, conditions))
+            }
         }
     }
 
@@ -762,7 +516,12 @@ pub async fn generate_care_recap(
         species, event_type, care_summary
     );
 
-    match call_ai_text(&prompt).await {
+    // The recap is a nicety, so a user over their AI limit gets the plain stats rather than an error
+    if let Err(e) = check_user(Expensive::Ai, &access.user_id) {
+        tracing::info!("Skipping AI care recap: {}", e);
+        return Ok(fallback_stats);
+    }
+    match crate::ai::text(&prompt).await {
         Ok(text) => Ok(text),
         Err(e) => {
             tracing::warn!("AI care recap failed ({}), returning fallback stats", e);
@@ -771,106 +530,190 @@ pub async fn generate_care_recap(
     }
 }
 
-#[cfg(all(test, feature = "ssr"))]
-mod tests {
-    use super::*;
+/// **What is it?**
+/// A server function that looks at a photo of damaged leaves, roots or flowers and returns the likely causes, with confidence and suggested treatments.
+///
+/// **Why does it exist?**
+/// It exists because telling scale from sunburn, or mites from rot, is hard for newer growers, and the right treatment depends on getting it right.
+///
+/// **How should it be used?**
+/// Call from the scanner's Diagnose mode with a resized JPEG. Pass the plant when known so its species, medium and placement inform the answer; start a treatment from a cause with `start_treatment`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn diagnose_plant_photo(
+    /// The base64-encoded image data.
+    image_base64: String,
+    /// The plant in the photo, if the grower picked one.
+    orchid_id: Option<String>,
+) -> Result<crate::diagnosis::Diagnosis, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::diagnosis::{Diagnosis, MAX_CAUSE_TREATMENTS, MAX_DIAGNOSIS_CAUSES};
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::orchid::{PotMedium, PotType};
+    use crate::rate_limit::{check_user, Expensive};
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::orchids::load_owned_orchid;
 
-    // ── extract_gemini_text ─────────────────────────────────────────
+    let access = require_role(HouseholdRole::Viewer).await?;
+    check_user(Expensive::Ai, &access.user_id).map_err(ServerFnError::new)?;
+    let owner_id = access.owner_id;
 
-    #[test]
-    fn test_extract_gemini_text_valid_response() {
-        let json = serde_json::json!({
-            "candidates": [{
-                "content": {
-                    "parts": [{ "text": "{\"species_name\": \"Phalaenopsis bellina\"}" }]
-                }
-            }]
-        });
-        let result = extract_gemini_text(&json);
-        assert!(result.is_ok());
-        assert!(result.unwrap().contains("Phalaenopsis bellina"));
+    // Cap base64 payload at ~15MB to prevent abuse
+    if image_base64.len() > 15 * 1024 * 1024 {
+        return Err(ServerFnError::new("Image too large (max 15MB)"));
     }
 
-    #[test]
-    fn test_extract_gemini_text_strips_markdown_fences() {
-        let json = serde_json::json!({
-            "candidates": [{
-                "content": {
-                    "parts": [{ "text": "```json\n{\"species_name\": \"Dendrobium\"}\n```" }]
-                }
-            }]
-        });
-        let result = extract_gemini_text(&json).unwrap();
-        assert!(!result.contains("```"));
-        assert!(result.contains("Dendrobium"));
-    }
+    let plant_context = match orchid_id.filter(|id| !id.is_empty()) {
+        Some(id) => {
+            let orchid = surrealdb::types::RecordId::parse_simple(&id)
+                .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+            let plant = load_owned_orchid(&orchid, &parse_owner(&owner_id)?).await?;
+            let medium = plant.pot_medium.filter(|m| !matches!(m, PotMedium::Unknown))
+                .map(|m| format!(" in {}", m)).unwrap_or_default();
+            let pot = plant.pot_type.filter(|t| !matches!(t, PotType::Unknown))
+                .map(|t| format!(", pot: {}", t)).unwrap_or_default();
+            format!(
+                "The plant is a {} growing{}{} in the '{}' zone, watered about every {} days. ",
+                plant.species, medium, pot, plant.placement, plant.water_frequency_days,
+            )
+        }
+        None => "The plant is most likely an orchid. ".to_string(),
+    };
 
-    #[test]
-    fn test_extract_gemini_text_missing_candidates() {
-        let json = serde_json::json!({});
-        assert!(extract_gemini_text(&json).is_err());
-    }
+    let prompt = format!(
+        "Diagnose the problem visible in this photo of a plant's leaves, roots, pseudobulbs or flowers. {}\
+        Think step-by-step: describe the damage, then weigh pests (scale, mealybug, spider mites, thrips, aphids), \
+        diseases (crown rot, root rot, black rot, bacterial brown spot, botrytis, fungal leaf spot, virus) and \
+        growing conditions (sunburn, cold damage, dehydration, overwatering, salt burn, natural leaf ageing). \
+        Return ONLY valid JSON with this structure (no markdown): \
+        {{ \"summary\": \"...\", \"causes\": [ {{ \"name\": \"Scale\", \"kind\": \"Pest\", \"confidence\": 0.7, \"signs\": \"...\", \
+        \"treatments\": [ {{ \"product\": \"Horticultural oil\", \"dose\": \"10 ml/L\", \"interval_days\": 7, \"applications\": 3, \"instructions\": \"...\" }} ] }} ] }} \
+        List at most {} causes, most likely first. kind is one of 'Pest', 'Disease', 'Environmental'. \
+        confidence is a number from 0.0 to 1.0, and confidences across causes should not sum to more than 1.0. \
+        signs names what in the photo points to the cause. \
+        Give at most {} treatments per cause, best first. For sprays and drenches give dose, interval_days and applications; \
+        for changes in care (such as moving out of direct sun) set them to null and explain in instructions. \
+        If the plant looks healthy, return an empty causes list and say so in summary.",
+        plant_context,
+        MAX_DIAGNOSIS_CAUSES,
+        MAX_CAUSE_TREATMENTS,
+    );
 
-    #[test]
-    fn test_extract_gemini_text_empty_candidates() {
-        let json = serde_json::json!({ "candidates": [] });
-        assert!(extract_gemini_text(&json).is_err());
-    }
+    let text = crate::ai::vision(&prompt, &image_base64).await
+        .map_err(|e| internal_error("AI diagnosis call failed", e))?;
 
-    #[test]
-    fn test_extract_gemini_text_missing_parts() {
-        let json = serde_json::json!({
-            "candidates": [{ "content": {} }]
-        });
-        assert!(extract_gemini_text(&json).is_err());
-    }
+    tracing::debug!("AI diagnosis raw response ({} chars): {}", text.len(), &text[..text.len().min(500)]);
 
-    // ── extract_claude_text ─────────────────────────────────────────
+    let diagnosis: Diagnosis = serde_json::from_str(&text)
+        .map_err(|e| {
+            internal_error(
+                "Failed to parse AI diagnosis",
+                format!("{}. Raw text: {}", e, &text[..text.len().min(1000)])
+            )
+        })?;
 
-    #[test]
-    fn test_extract_claude_text_valid_response() {
-        let json = serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": "{\"species_name\": \"Oncidium sharry baby\"}"
-            }]
-        });
-        let result = extract_claude_text(&json);
-        assert!(result.is_ok());
-        assert!(result.unwrap().contains("Oncidium sharry baby"));
-    }
+    Ok(diagnosis.normalize())
+}
 
-    #[test]
-    fn test_extract_claude_text_strips_markdown_fences() {
-        let json = serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": "```json\n{\"species_name\": \"Cattleya\"}\n```"
-            }]
-        });
-        let result = extract_claude_text(&json).unwrap();
-        assert!(!result.contains("```"));
-        assert!(result.contains("Cattleya"));
-    }
+/// **What is it?**
+/// A server function that looks at a photo of a plant's roots, taken out of the pot at repotting, and returns how much is healthy, rotten or dried out, what the old medium was made of, and when to repot next.
+///
+/// **Why does it exist?**
+/// It exists because judging root health by eye is subjective and easy to forget; a structured assessment at each repot can be compared against the last one.
+///
+/// **How should it be used?**
+/// Call from the scanner's Roots mode with a resized JPEG. Pass the plant when known so its species and current medium inform the answer, then save the result with `server_fns::orchids::log_root_assessment`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn assess_root_photo(
+    /// The base64-encoded image data.
+    image_base64: String,
+    /// The plant being repotted, if the grower picked one.
+    orchid_id: Option<String>,
+) -> Result<crate::root_health::RootAssessment, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::orchid::PotMedium;
+    use crate::rate_limit::{check_user, Expensive};
+    use crate::root_health::{RootAssessment, MAX_MEDIUM_PARTS, MAX_REPOT_MONTHS, MIN_REPOT_MONTHS};
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::orchids::load_owned_orchid;
 
-    #[test]
-    fn test_extract_claude_text_missing_content() {
-        let json = serde_json::json!({});
-        assert!(extract_claude_text(&json).is_err());
-    }
+    let access = require_role(HouseholdRole::Viewer).await?;
+    check_user(Expensive::Ai, &access.user_id).map_err(ServerFnError::new)?;
+    let owner_id = access.owner_id;
 
-    #[test]
-    fn test_extract_claude_text_empty_content() {
-        let json = serde_json::json!({ "content": [] });
-        assert!(extract_claude_text(&json).is_err());
+    // Cap base64 payload at ~15MB to prevent abuse
+    if image_base64.len() > 15 * 1024 * 1024 {
+        return Err(ServerFnError::new("Image too large (max 15MB)"));
     }
 
+    let plant_context = match orchid_id.filter(|id| !id.is_empty()) {
+        Some(id) => {
+            let orchid = surrealdb::types::RecordId::parse_simple(&id)
+                .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+            let plant = load_owned_orchid(&orchid, &parse_owner(&owner_id)?).await?;
+            let medium = plant.pot_medium.filter(|m| !matches!(m, PotMedium::Unknown))
+                .map(|m| format!(", recorded as potted in {}", m)).unwrap_or_default();
+            let since = plant.days_since_repotted()
+                .map(|d| format!(", last repotted {} days ago", d)).unwrap_or_default();
+            format!("The plant is a {}{}{}. ", plant.species, medium, since)
+        }
+        None => "The plant is most likely an orchid. ".to_string(),
+    };
+
+    let prompt = format!(
+        "This photo shows a plant's roots, taken out of the pot at repotting. {}\
+        Assess root health: healthy roots are firm and green or silvery-white with velamen intact; rotten roots are brown or black, \
+        mushy or hollow, with the velamen sliding off; dried-out roots are papery and flat. Estimate each share of the visible root mass. \
+        Then identify the old potting medium still on or around the roots (for example fir bark, sphagnum moss, perlite, charcoal, \
+        LECA, lava rock, coconut husk) with each material's share, and how far it has broken down. \
+        Return ONLY valid JSON with this structure (no markdown): \
+        {{ \"healthy_pct\": 70, \"rot_pct\": 20, \"dry_pct\": 10, \
+        \"medium\": [ {{ \"material\": \"Fir bark\", \"pct\": 70 }}, {{ \"material\": \"Perlite\", \"pct\": 30 }} ], \
+        \"medium_condition\": \"Aging\", \"next_repot_months\": 18, \"summary\": \"...\" }} \
+        Percentages are 0-100; the three root shares should add up to 100. List at most {} materials, largest share first. \
+        medium_condition is one of 'Fresh', 'Aging', 'BrokenDown', or 'Unknown' if no medium is visible. \
+        next_repot_months is when the fresh medium will need replacing, from {} to {}: sooner for sphagnum, rot or a plant outgrowing its pot. \
+        summary is one or two sentences on what the roots show and anything to do now, such as trimming rotten roots.",
+        plant_context,
+        MAX_MEDIUM_PARTS,
+        MIN_REPOT_MONTHS,
+        MAX_REPOT_MONTHS,
+    );
+
+    let text = crate::ai::vision(&prompt, &image_base64).await
+        .map_err(|e| internal_error("AI root assessment call failed", e))?;
+
+    tracing::debug!("AI root assessment raw response ({} chars): {}", text.len(), &text[..text.len().min(500)]);
+
+    let assessment: RootAssessment = serde_json::from_str(&text)
+        .map_err(|e| {
+            internal_error(
+                "Failed to parse AI root assessment",
+                format!("{}. Raw text: {}", e, &text[..text.len().min(1000)])
+            )
+        })?;
+
+    Ok(assessment.normalize())
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    // ── extract_wikipedia_thumbnail ─────────────────────────────────
+
     #[test]
-    fn test_extract_claude_text_missing_text_field() {
+    fn test_extract_wikipedia_thumbnail() {
         let json = serde_json::json!({
-            "content": [{ "type": "text" }]
+            "title": "Phalaenopsis bellina",
+            "thumbnail": { "source": "https://upload.wikimedia.org/bellina.jpg", "width": 320, "height": 240 }
         });
-        assert!(extract_claude_text(&json).is_err());
+        assert_eq!(extract_wikipedia_thumbnail(&json).as_deref(), Some("https://upload.wikimedia.org/bellina.jpg"));
+        assert_eq!(extract_wikipedia_thumbnail(&serde_json::json!({ "title": "Phalaenopsis" })), None);
     }
 
     // ── strip_html_tags ────────────────────────────────────────────
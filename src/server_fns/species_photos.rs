@@ -0,0 +1,164 @@
+use leptos::prelude::*;
+use crate::species_photos::CardPhotoIndex;
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::species_photos::SpeciesPhoto;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct SpeciesPhotoDbRow {
+        pub species: String,
+        #[surreal(default)]
+        pub image_filename: Option<String>,
+        #[surreal(default)]
+        pub preset: Option<String>,
+    }
+
+    impl SpeciesPhotoDbRow {
+        pub fn into_species_photo(self) -> SpeciesPhoto {
+            SpeciesPhoto {
+                species: self.species,
+                image_filename: self.image_filename,
+                preset: self.preset,
+            }
+        }
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct LatestPhotoRow {
+        pub orchid: surrealdb::types::RecordId,
+        pub image_filename: String,
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// **What is it?**
+/// A server function that returns each orchid's latest journal photo plus the user's species-level default images.
+///
+/// **Why does it exist?**
+/// It exists so collection cards can show a picture for every plant — their own photo when there is one, otherwise a species stock image or placeholder art — from a single request.
+///
+/// **How should it be used?**
+/// Call once when the collection loads and provide the result as context; resolve each card with `CardPhotoIndex::resolve`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_card_photos() -> Result<CardPhotoIndex, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::auth::record_id_to_string;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT orchid, image_filename, timestamp FROM log_entry \
+                WHERE owner = $owner AND image_filename != NONE ORDER BY timestamp DESC; \
+             SELECT species, image_filename, preset FROM species_photo WHERE owner = $owner;"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Card photos query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Card photos query error", err_msg));
+    }
+
+    let photos: Vec<LatestPhotoRow> = resp.take(0)
+        .map_err(|e| internal_error("Card photos parse failed", e))?;
+    let species: Vec<SpeciesPhotoDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Species photos parse failed", e))?;
+
+    let mut index = CardPhotoIndex::default();
+    // Rows are newest first, so the first photo seen for an orchid wins
+    for row in photos {
+        index.orchid_photos.entry(record_id_to_string(&row.orchid)).or_insert(row.image_filename);
+    }
+    index.species = species.into_iter().map(|r| r.into_species_photo()).collect();
+    Ok(index)
+}
+
+/// **What is it?**
+/// A server function that sets the default card image for a species or genus, either an uploaded photo or a built-in preset.
+///
+/// **Why does it exist?**
+/// It exists so plants without their own photos still get a recognisable picture, making a fresh collection look less barren.
+///
+/// **How should it be used?**
+/// Call from the species photo picker. Pass a full species name to target that species, or just the genus to cover every species in it. Provide exactly one of `image_filename` (from `/api/images/upload`) or `preset` (a `PRESET_ART` key); passing neither removes the default.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn set_species_photo(
+    /// Species or genus name; normalized before storing.
+    species: String,
+    /// An uploaded image owned by the user.
+    image_filename: Option<String>,
+    /// A built-in preset key.
+    preset: Option<String>,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::species_photos::{species_key, PRESET_ART};
+
+    let species = species_key(&species);
+    if species.is_empty() || species.len() > 200 {
+        return Err(ServerFnError::new("Species must be 1-200 characters"));
+    }
+    if image_filename.is_some() && preset.is_some() {
+        return Err(ServerFnError::new("Choose either a photo or a preset, not both"));
+    }
+    if let Some(ref p) = preset
+        && !PRESET_ART.iter().any(|a| a.key == p)
+    {
+        return Err(ServerFnError::new("Unknown preset"));
+    }
+
+    let access = require_role(HouseholdRole::Caretaker).await?;
+    if let Some(ref filename) = image_filename {
+        crate::server_fns::orchids::validate_filename(filename)?;
+        // Uploads are stored under the uploader's directory
+        if !filename.starts_with(&format!("{}/", access.user_id.replace(':', "_"))) {
+            return Err(ServerFnError::new("Invalid image filename"));
+        }
+    }
+    let owner = parse_owner(&access.owner_id)?;
+
+    let query = if image_filename.is_none() && preset.is_none() {
+        "DELETE species_photo WHERE owner = $owner AND species = $species"
+    } else {
+        "DELETE species_photo WHERE owner = $owner AND species = $species; \
+         CREATE species_photo SET owner = $owner, species = $species, \
+            image_filename = $image_filename, preset = $preset, updated_at = time::now();"
+    };
+
+    let mut resp = db()
+        .query(query)
+        .bind(("owner", owner))
+        .bind(("species", species))
+        .bind(("image_filename", image_filename))
+        .bind(("preset", preset))
+        .await
+        .map_err(|e| internal_error("Set species photo query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Set species photo query error", err_msg));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use leptos::prelude::*;
+use crate::taxonomy::{AliasKind, SpeciesAlias};
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+/// Longest alias or scientific name accepted, matching the table assertions.
+#[cfg(feature = "ssr")]
+const MAX_ALIAS_CHARS: usize = 100;
+#[cfg(feature = "ssr")]
+const MAX_SCIENTIFIC_NAME_CHARS: usize = 120;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::taxonomy::{AliasKind, SpeciesAlias};
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct SpeciesAliasDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub scientific_name: String,
+        pub alias: String,
+        #[surreal(default)]
+        pub language: Option<String>,
+        pub kind: String,
+        #[surreal(default)]
+        pub owner: Option<surrealdb::types::RecordId>,
+    }
+
+    impl SpeciesAliasDbRow {
+        pub fn into_alias(self) -> SpeciesAlias {
+            SpeciesAlias {
+                id: record_id_to_string(&self.id),
+                scientific_name: self.scientific_name,
+                alias: self.alias,
+                language: self.language,
+                kind: AliasKind::from_str_lossy(&self.kind),
+                builtin: self.owner.is_none(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// **What is it?**
+/// A server function that returns every species alias available to the current user: the built-in names followed by their own.
+///
+/// **Why does it exist?**
+/// It exists so the collection search and plant details can match and show common, trade and synonym names.
+///
+/// **How should it be used?**
+/// Call once when the collection loads and pass the result to `SearchIndex::build`.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_species_aliases() -> Result<Vec<SpeciesAlias>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT id, scientific_name, alias, language, kind, owner, created_at FROM species_alias \
+             WHERE owner IS NONE OR owner = $owner \
+             ORDER BY scientific_name ASC, created_at ASC"
+        )
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get species aliases query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get species aliases query error", err_msg));
+    }
+
+    let rows: Vec<SpeciesAliasDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get species aliases parse failed", e))?;
+    Ok(rows.into_iter().map(|r| r.into_alias()).collect())
+}
+
+/// **What is it?**
+/// A server function that records another name for a species or genus.
+///
+/// **Why does it exist?**
+/// It exists so growers can add the trade name a plant was sold under, or its name in their language, and find it by that name later.
+///
+/// **How should it be used?**
+/// Call from the "Also known as" card. Pass a bare genus to cover every plant in it, or the full species to cover just that one.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn add_species_alias(
+    /// The species or genus the alias belongs to.
+    scientific_name: String,
+    /// The alternative name.
+    alias: String,
+    /// ISO 639-1 language code, if the name belongs to one language.
+    language: Option<String>,
+    /// What kind of name this is.
+    kind: AliasKind,
+) -> Result<SpeciesAlias, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let scientific_name = scientific_name.split_whitespace().collect::<Vec<_>>().join(" ");
+    let alias = alias.trim().to_string();
+    if scientific_name.is_empty() || scientific_name.chars().count() > MAX_SCIENTIFIC_NAME_CHARS {
+        return Err(ServerFnError::new("Species name must be between 1 and 120 characters"));
+    }
+    if alias.is_empty() || alias.chars().count() > MAX_ALIAS_CHARS {
+        return Err(ServerFnError::new("Name must be between 1 and 100 characters"));
+    }
+    let language = language.filter(|l| !l.trim().is_empty());
+    if let Some(l) = &language
+        && !crate::taxonomy::ALIAS_LANGUAGES.iter().any(|(code, _)| code == l) {
+        return Err(ServerFnError::new("Unknown language"));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "CREATE species_alias SET owner = $owner, scientific_name = $scientific_name, \
+             alias = $alias, language = $language, kind = $kind \
+             RETURN id, scientific_name, alias, language, kind, owner"
+        )
+        .bind(("owner", owner))
+        .bind(("scientific_name", scientific_name))
+        .bind(("alias", alias))
+        .bind(("language", language))
+        .bind(("kind", kind.as_str().to_string()))
+        .await
+        .map_err(|e| internal_error("Add species alias query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        if err_msg.contains("idx_species_alias_unique") {
+            return Err(ServerFnError::new("That name is already recorded for this species"));
+        }
+        return Err(internal_error("Add species alias query error", err_msg));
+    }
+
+    let row: Option<SpeciesAliasDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Add species alias parse failed", e))?;
+    row.map(|r| r.into_alias())
+        .ok_or_else(|| ServerFnError::new("Failed to add name"))
+}
+
+/// **What is it?**
+/// A server function that removes one of the current user's species aliases.
+///
+/// **Why does it exist?**
+/// It exists to correct mistaken names. Built-in names are shared and cannot be deleted.
+///
+/// **How should it be used?**
+/// Call from the "Also known as" card with the alias ID.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_species_alias(
+    /// The alias record ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let alias_id = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Alias ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("DELETE species_alias WHERE id = $id AND owner = $owner")
+        .bind(("id", alias_id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete species alias query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete species alias query error", err_msg));
+    }
+    Ok(())
+}
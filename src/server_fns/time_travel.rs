@@ -0,0 +1,252 @@
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use crate::time_travel::PlantAsOf;
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+#[cfg(feature = "ssr")]
+pub(crate) mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::orchid::{EntryClimate, LightRequirement, LogEntry};
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::time_travel::CareRevision;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct RevisionDbRow {
+        pub orchid: surrealdb::types::RecordId,
+        pub changed_at: chrono::DateTime<chrono::Utc>,
+        pub placement: String,
+        pub water_frequency_days: u32,
+        pub light_requirement: String,
+        #[surreal(default)]
+        pub pot_medium: Option<String>,
+        #[surreal(default)]
+        pub pot_type: Option<String>,
+        #[surreal(default)]
+        pub pot_size: Option<String>,
+        #[surreal(default)]
+        pub fertilize_frequency_days: Option<u32>,
+        #[surreal(default)]
+        pub fertilizer_type: Option<String>,
+    }
+
+    /// Parses a pot enum stored as its serde variant name; unknown values are dropped.
+    fn parse_db_enum<T: serde::de::DeserializeOwned>(value: Option<String>) -> Option<T> {
+        value.and_then(|s| serde_json::from_str(&format!("\"{}\"", s)).ok())
+    }
+
+    impl RevisionDbRow {
+        pub fn into_revision(self) -> CareRevision {
+            CareRevision {
+                changed_at: self.changed_at,
+                placement: self.placement,
+                water_frequency_days: self.water_frequency_days,
+                light_requirement: match self.light_requirement.as_str() {
+                    "Low" => LightRequirement::Low,
+                    "High" => LightRequirement::High,
+                    _ => LightRequirement::Medium,
+                },
+                pot_medium: parse_db_enum(self.pot_medium),
+                pot_type: parse_db_enum(self.pot_type),
+                pot_size: parse_db_enum(self.pot_size),
+                fertilize_frequency_days: self.fertilize_frequency_days,
+                fertilizer_type: self.fertilizer_type,
+            }
+        }
+    }
+
+    /// A journal entry with the plant it belongs to, for collection-wide queries.
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct JournalEntryDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub orchid: surrealdb::types::RecordId,
+        pub timestamp: chrono::DateTime<chrono::Utc>,
+        pub note: String,
+        #[surreal(default)]
+        pub image_filename: Option<String>,
+        #[surreal(default)]
+        pub event_type: Option<String>,
+        #[surreal(default)]
+        pub climate: Option<EntryClimate>,
+        #[surreal(default)]
+        pub watering_method: Option<String>,
+        #[surreal(default)]
+        pub water_volume_ml: Option<u32>,
+        #[surreal(default)]
+        pub skip_reason: Option<String>,
+        #[surreal(default)]
+        pub medium_feedback: Option<String>,
+        #[surreal(default)]
+        pub measurement: Option<crate::growth::GrowthMeasurement>,
+        #[surreal(default)]
+        pub root_assessment: Option<crate::root_health::RootAssessment>,
+    }
+
+    impl JournalEntryDbRow {
+        pub fn into_log_entry(self) -> LogEntry {
+            LogEntry {
+                id: record_id_to_string(&self.id),
+                timestamp: self.timestamp,
+                note: self.note,
+                image_filename: self.image_filename,
+                event_type: self.event_type,
+                climate: self.climate,
+                watering_method: self.watering_method.as_deref().and_then(crate::orchid::WateringMethod::parse),
+                water_volume_ml: self.water_volume_ml,
+                skip_reason: self.skip_reason.as_deref().and_then(crate::orchid::SkipReason::parse),
+                medium_feedback: self.medium_feedback.as_deref().and_then(crate::orchid::MediumFeedback::parse),
+                measurement: self.measurement,
+                root_assessment: self.root_assessment,
+            }
+        }
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct OrchidCreatedRow {
+        pub id: surrealdb::types::RecordId,
+        #[surreal(default)]
+        pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    }
+}
+
+/// **What is it?**
+/// A server function that reconstructs one plant as it stood on a past date.
+///
+/// **Why does it exist?**
+/// It exists for post-mortems: what zone and pot the plant was in, and when it was last watered, before it declined.
+///
+/// **How should it be used?**
+/// Call from the plant's "As Of" tab with the chosen date.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_plant_as_of(
+    /// The plant to reconstruct.
+    orchid_id: String,
+    /// The moment to reconstruct.
+    as_of: DateTime<Utc>,
+) -> Result<PlantAsOf, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::orchids::ssr_types::{LogEntryDbRow, OrchidDbRow};
+    use crate::time_travel::reconstruct;
+    use ssr_types::RevisionDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "SELECT * FROM orchid WHERE id = $orchid AND owner = $owner; \
+             SELECT * FROM orchid_revision WHERE orchid = $orchid AND owner = $owner ORDER BY changed_at ASC; \
+             SELECT * FROM log_entry WHERE orchid = $orchid AND owner = $owner AND timestamp <= $as_of ORDER BY timestamp DESC;"
+        )
+        .bind(("orchid", orchid))
+        .bind(("owner", owner))
+        .bind(("as_of", as_of))
+        .await
+        .map_err(|e| internal_error("Plant as-of query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Plant as-of query error", err_msg));
+    }
+
+    let plant: Option<OrchidDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Plant as-of parse failed", e))?;
+    let plant = plant.ok_or_else(|| ServerFnError::new("Plant not found"))?.into_orchid();
+    let revisions: Vec<RevisionDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Plant revisions parse failed", e))?;
+    let revisions: Vec<_> = revisions.into_iter().map(|r| r.into_revision()).collect();
+    let entries: Vec<LogEntryDbRow> = resp.take(2)
+        .map_err(|e| internal_error("Plant journal parse failed", e))?;
+    let entries: Vec<_> = entries.into_iter().map(|e| e.into_log_entry()).collect();
+
+    Ok(reconstruct(&plant, &revisions, &entries, as_of))
+}
+
+/// **What is it?**
+/// A server function that reconstructs the whole collection as it stood on a past date.
+///
+/// **Why does it exist?**
+/// It exists to answer "what did the collection look like last spring?": which plants were in which zone, and which were overdue.
+///
+/// **How should it be used?**
+/// Call from the "As Of" tab with the chosen date. Plants added after that date are left out; plants deleted since are gone for good and can't be shown.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_collection_as_of(
+    /// The moment to reconstruct.
+    as_of: DateTime<Utc>,
+) -> Result<Vec<PlantAsOf>, ServerFnError> {
+    use std::collections::HashMap;
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::server_fns::orchids::ssr_types::OrchidDbRow;
+    use crate::time_travel::reconstruct;
+    use ssr_types::{JournalEntryDbRow, OrchidCreatedRow, RevisionDbRow};
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query(
+            "SELECT * FROM orchid WHERE owner = $owner ORDER BY name ASC; \
+             SELECT id, created_at FROM orchid WHERE owner = $owner; \
+             SELECT * FROM orchid_revision WHERE owner = $owner ORDER BY changed_at ASC; \
+             SELECT * FROM log_entry WHERE owner = $owner AND timestamp <= $as_of ORDER BY timestamp DESC;"
+        )
+        .bind(("owner", owner))
+        .bind(("as_of", as_of))
+        .await
+        .map_err(|e| internal_error("Collection as-of query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Collection as-of query error", err_msg));
+    }
+
+    let plants: Vec<OrchidDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Collection as-of parse failed", e))?;
+    let created: Vec<OrchidCreatedRow> = resp.take(1)
+        .map_err(|e| internal_error("Collection created-at parse failed", e))?;
+    let revisions: Vec<RevisionDbRow> = resp.take(2)
+        .map_err(|e| internal_error("Collection revisions parse failed", e))?;
+    let entry_rows: Vec<JournalEntryDbRow> = resp.take(3)
+        .map_err(|e| internal_error("Collection journal parse failed", e))?;
+
+    let created: HashMap<String, DateTime<Utc>> = created.into_iter()
+        .filter_map(|r| Some((record_id_to_string(&r.id), r.created_at?)))
+        .collect();
+    let mut revisions_by_plant: HashMap<String, Vec<_>> = HashMap::new();
+    for r in revisions {
+        revisions_by_plant.entry(record_id_to_string(&r.orchid)).or_default().push(r.into_revision());
+    }
+    let mut entries_by_plant: HashMap<String, Vec<_>> = HashMap::new();
+    for r in entry_rows {
+        let orchid = record_id_to_string(&r.orchid);
+        entries_by_plant.entry(orchid).or_default().push(r.into_log_entry());
+    }
+
+    Ok(plants.into_iter()
+        .map(|p| p.into_orchid())
+        .filter(|o| created.get(&o.id).is_none_or(|at| *at <= as_of))
+        .map(|o| {
+            let revisions = revisions_by_plant.get(&o.id).map(Vec::as_slice).unwrap_or_default();
+            let entries = entries_by_plant.get(&o.id).map(Vec::as_slice).unwrap_or_default();
+            reconstruct(&o, revisions, entries, as_of)
+        })
+        .collect())
+}
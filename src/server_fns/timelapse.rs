@@ -0,0 +1,200 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// **What is it?**
+/// An animated GIF of one plant's photo history, ready to download.
+///
+/// **Why does it exist?**
+/// It exists to carry the animation produced by `create_timelapse` across the server function boundary, which only speaks JSON.
+///
+/// **How should it be used?**
+/// Show `gif_base64` as a `data:image/gif;base64,` image and offer the same URL as a download named `filename`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Timelapse {
+    /// Suggested download name, e.g. `phal-jane-timelapse.gif`.
+    pub filename: String,
+    /// The animation, base64-encoded.
+    pub gif_base64: String,
+    /// How many photos it plays through.
+    pub frame_count: u32,
+}
+
+/// Scales `img` to fit the frame and centres it on black, then stamps `caption` in the bottom-left corner.
+#[cfg(feature = "ssr")]
+fn compose_frame(img: &image::DynamicImage, width: u32, height: u32, caption: Option<&str>) -> image::RgbaImage {
+    use crate::timelapse::text_mask;
+
+    let fitted = img.resize(width, height, image::imageops::FilterType::Triangle).to_rgba8();
+    let mut frame = image::RgbaImage::from_pixel(width, height, image::Rgba([0, 0, 0, 255]));
+    let (x, y) = ((width - fitted.width()) / 2, (height - fitted.height()) / 2);
+    image::imageops::overlay(&mut frame, &fitted, x as i64, y as i64);
+
+    if let Some(text) = caption {
+        let (tw, th, mask) = text_mask(text);
+        let scale = (width / 160).max(2);
+        let pad = scale * 2;
+        let (left, top) = (pad, height.saturating_sub(th * scale + pad * 3));
+        // Darken a band behind the text so it reads on bright photos
+        for py in top..(top + th * scale + pad * 2).min(height) {
+            for px in left..(left + tw * scale + pad * 2).min(width) {
+                let p = frame.get_pixel_mut(px, py);
+                for c in 0..3 {
+                    p.0[c] /= 3;
+                }
+            }
+        }
+        for ty in 0..th {
+            for tx in 0..tw {
+                if !mask[(ty * tw + tx) as usize] {
+                    continue;
+                }
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        let (px, py) = (left + pad + tx * scale + dx, top + pad + ty * scale + dy);
+                        if px < width && py < height {
+                            frame.put_pixel(px, py, image::Rgba([255, 255, 255, 255]));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    frame
+}
+
+/// **What is it?**
+/// A server function that turns a plant's journal photos, oldest first, into an animated GIF.
+///
+/// **Why does it exist?**
+/// It exists because watching a spike lengthen or a new leaf unfurl over months is the payoff of photographing a plant regularly, and a single animation is easy to share.
+///
+/// **How should it be used?**
+/// Call this from the time-lapse maker on the Gallery tab. Plants with more than `MAX_TIMELAPSE_FRAMES` photos are sampled evenly;
+/// `date_overlay` stamps each frame with the day it was taken.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn create_timelapse(
+    /// The plant whose photos to animate.
+    orchid_id: String,
+    /// How long each photo stays on screen, in milliseconds.
+    frame_ms: u32,
+    /// Whether to stamp each frame with its date.
+    date_overlay: bool,
+) -> Result<Timelapse, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::config::config;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::photo_archive::slugify;
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::images::handlers::decode_upright;
+    use crate::thumbnails::{thumbnail_path, ThumbSize};
+    use crate::timelapse::{sample_frames, MAX_FRAME_MS, MAX_TIMELAPSE_FRAMES, MIN_FRAME_MS, TIMELAPSE_EDGE};
+    use base64::Engine;
+    use std::path::PathBuf;
+    use surrealdb::types::SurrealValue;
+
+    #[derive(serde::Deserialize, SurrealValue, Clone)]
+    #[surreal(crate = "surrealdb::types")]
+    struct PhotoRow {
+        image_filename: String,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    }
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+    let frame_ms = frame_ms.clamp(MIN_FRAME_MS, MAX_FRAME_MS);
+
+    let mut resp = db()
+        .query(
+            "SELECT VALUE name FROM $orchid WHERE owner = $owner; \
+             SELECT image_filename, timestamp FROM log_entry \
+             WHERE orchid = $orchid AND owner = $owner AND image_filename != NONE ORDER BY timestamp ASC"
+        )
+        .bind(("orchid", orchid))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Time-lapse query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Time-lapse query error", err_msg));
+    }
+
+    let name: Option<String> = resp.take::<Vec<String>>(0)
+        .map_err(|e| internal_error("Time-lapse name parse failed", e))?
+        .into_iter()
+        .next();
+    let name = name.ok_or_else(|| ServerFnError::new("Plant not found"))?;
+    let photos: Vec<PhotoRow> = resp.take(1)
+        .map_err(|e| internal_error("Time-lapse photos parse failed", e))?;
+    if photos.len() < 2 {
+        return Err(ServerFnError::new("A time-lapse needs at least two photos"));
+    }
+
+    // The medium thumbnail is already upright and small; fall back to the original
+    let root = PathBuf::from(&config().image_storage_path);
+    let mut sources: Vec<(Vec<u8>, chrono::DateTime<chrono::Utc>)> = Vec::new();
+    for photo in sample_frames(&photos, MAX_TIMELAPSE_FRAMES) {
+        let thumb = thumbnail_path(&photo.image_filename, ThumbSize::Medium);
+        let bytes = match thumb {
+            Some(t) => match tokio::fs::read(root.join(t)).await {
+                Ok(bytes) => Ok(bytes),
+                Err(_) => tokio::fs::read(root.join(&photo.image_filename)).await,
+            },
+            None => continue,
+        };
+        match bytes {
+            Ok(bytes) => sources.push((bytes, photo.timestamp)),
+            Err(e) => tracing::warn!("Time-lapse skipped unreadable photo {}: {}", photo.image_filename, e),
+        }
+    }
+
+    let encoded = tokio::task::spawn_blocking(move || -> Result<(Vec<u8>, u32), image::ImageError> {
+        use image::codecs::gif::{GifEncoder, Repeat};
+
+        let frames: Vec<_> = sources.iter()
+            .filter_map(|(bytes, at)| decode_upright(bytes).ok().map(|img| (img, *at)))
+            .collect();
+        let Some((first, _)) = frames.first() else {
+            return Ok((Vec::new(), 0));
+        };
+        // Every frame shares the first photo's shape
+        let scale = TIMELAPSE_EDGE as f64 / first.width().max(first.height()) as f64;
+        let width = ((first.width() as f64 * scale).round() as u32).max(1);
+        let height = ((first.height() as f64 * scale).round() as u32).max(1);
+
+        let mut out = Vec::new();
+        {
+            let mut encoder = GifEncoder::new_with_speed(&mut out, 10);
+            encoder.set_repeat(Repeat::Infinite)?;
+            for (img, at) in &frames {
+                let caption = date_overlay.then(|| at.format("%Y-%m-%d").to_string());
+                let frame = compose_frame(img, width, height, caption.as_deref());
+                let delay = image::Delay::from_numer_denom_ms(frame_ms, 1);
+                encoder.encode_frame(image::Frame::from_parts(frame, 0, 0, delay))?;
+            }
+        }
+        Ok((out, frames.len() as u32))
+    })
+    .await
+    .map_err(|e| internal_error("Time-lapse task failed", e))?
+    .map_err(|e| internal_error("Time-lapse encode failed", e))?;
+
+    let (gif, frame_count) = encoded;
+    if frame_count < 2 {
+        return Err(ServerFnError::new("Not enough readable photos for a time-lapse"));
+    }
+    tracing::info!(bytes = gif.len(), frames = frame_count, "Time-lapse generated");
+
+    let slug = slugify(&name);
+    Ok(Timelapse {
+        filename: format!("{}-timelapse.gif", if slug.is_empty() { "orchid" } else { &slug }),
+        gif_base64: base64::engine::general_purpose::STANDARD.encode(gif),
+        frame_count,
+    })
+}
@@ -0,0 +1,447 @@
+use std::collections::HashMap;
+use leptos::prelude::*;
+use crate::treatment::{ProblemKind, Treatment};
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::treatment::{ProblemKind, Treatment};
+    use crate::server_fns::auth::record_id_to_string;
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct TreatmentDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub orchid: surrealdb::types::RecordId,
+        pub kind: String,
+        pub problem: String,
+        pub product: String,
+        #[surreal(default)]
+        pub dose: Option<String>,
+        pub interval_days: u32,
+        pub planned_applications: u32,
+        pub applications: u32,
+        pub started_at: chrono::DateTime<chrono::Utc>,
+        pub last_applied_at: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    }
+
+    impl TreatmentDbRow {
+        /// None for a row whose kind this build doesn't know.
+        pub fn into_treatment(self) -> Option<Treatment> {
+            Some(Treatment {
+                id: record_id_to_string(&self.id),
+                orchid_id: record_id_to_string(&self.orchid),
+                kind: ProblemKind::parse(&self.kind)?,
+                problem: self.problem,
+                product: self.product,
+                dose: self.dose,
+                interval_days: self.interval_days,
+                planned_applications: self.planned_applications,
+                applications: self.applications,
+                started_at: self.started_at,
+                last_applied_at: self.last_applied_at,
+                resolved_at: self.resolved_at,
+            })
+        }
+    }
+}
+
+/// Longest reminder title accepted, matching the reminder schema assertion.
+#[cfg(feature = "ssr")]
+const MAX_REMINDER_TITLE_LEN: usize = 200;
+
+/// Journals the application `treatment` just had, completes the reminder that asked for it, and
+/// schedules the reminder for the next one if the series isn't finished, all in one transaction.
+#[cfg(feature = "ssr")]
+async fn log_application(
+    treatment: &Treatment,
+    owner: &surrealdb::types::RecordId,
+) -> Result<(), ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::orchids::climate_snapshot_expr;
+    use crate::treatment::TREATMENT_EVENT;
+
+    let id = surrealdb::types::RecordId::parse_simple(&treatment.id)
+        .map_err(|e| internal_error("Treatment ID parse failed", e))?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&treatment.orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+
+    let mut note = treatment.product.clone();
+    if let Some(dose) = &treatment.dose {
+        note.push_str(&format!(" ({})", dose));
+    }
+    note.push_str(&format!(" for {}, application {}", treatment.problem.to_lowercase(), treatment.progress_label()));
+    let title: String = treatment.reminder_title().chars().take(MAX_REMINDER_TITLE_LEN).collect();
+
+    let mut resp = db()
+        .query(format!(
+            "BEGIN TRANSACTION; \
+             UPDATE reminder SET completed_at = time::now() WHERE treatment = $treatment AND owner = $owner AND completed_at = NONE; \
+             CREATE log_entry SET orchid = $orchid, owner = $owner, note = $note, event_type = $event_type, climate = {}; \
+             IF $next_due != NONE {{ \
+                 CREATE reminder SET owner = $owner, orchid = $orchid, treatment = $treatment, title = $title, due_at = $next_due; \
+             }}; \
+             COMMIT TRANSACTION;",
+            climate_snapshot_expr("$orchid")
+        ))
+        .bind(("treatment", id))
+        .bind(("orchid", orchid))
+        .bind(("owner", owner.clone()))
+        .bind(("note", note))
+        .bind(("event_type", TREATMENT_EVENT.to_string()))
+        .bind(("title", title))
+        .bind(("next_due", treatment.next_due_at()))
+        .await
+        .map_err(|e| internal_error("Log treatment query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Log treatment query error", err_msg));
+    }
+    Ok(())
+}
+
+/// Records the next application of an open treatment: bumps its count, journals it, and moves the
+/// follow-up reminder on. Shared by `apply_treatment` and completing a treatment's reminder.
+#[cfg(feature = "ssr")]
+pub(crate) async fn record_application(
+    id: &surrealdb::types::RecordId,
+    owner: &surrealdb::types::RecordId,
+) -> Result<Treatment, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+    use ssr_types::TreatmentDbRow;
+
+    let mut resp = db()
+        .query(
+            "UPDATE $id SET applications += 1, last_applied_at = time::now() \
+             WHERE owner = $owner AND resolved_at = NONE AND applications < planned_applications \
+             RETURN AFTER"
+        )
+        .bind(("id", id.clone()))
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Apply treatment query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Apply treatment query error", err_msg));
+    }
+
+    let row: Option<TreatmentDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Apply treatment parse failed", e))?;
+    let treatment = row.and_then(TreatmentDbRow::into_treatment)
+        .ok_or_else(|| ServerFnError::new("Treatment not found, resolved, or already fully applied"))?;
+
+    log_application(&treatment, owner).await?;
+    Ok(treatment)
+}
+
+/// **What is it?**
+/// A server function that lists a plant's treatments, open ones first, then most recently started.
+///
+/// **Why does it exist?**
+/// It exists to show what a plant is being treated for, how far through the series it is, and what it was treated for before.
+///
+/// **How should it be used?**
+/// Load from the treatments card on the Details tab; reload after starting, applying, resolving or deleting a treatment.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn get_treatments(
+    /// The unique identifier of the orchid.
+    orchid_id: String,
+) -> Result<Vec<Treatment>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::TreatmentDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+
+    let mut resp = db()
+        .query("SELECT * FROM treatment WHERE owner = $owner AND orchid = $orchid ORDER BY started_at DESC")
+        .bind(("owner", owner))
+        .bind(("orchid", orchid))
+        .await
+        .map_err(|e| internal_error("Get treatments query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get treatments query error", err_msg));
+    }
+
+    let rows: Vec<TreatmentDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get treatments parse failed", e))?;
+
+    let mut treatments: Vec<Treatment> = rows.into_iter().filter_map(TreatmentDbRow::into_treatment).collect();
+    // Open treatments first; the sort is stable, so each group stays newest first
+    treatments.sort_by_key(Treatment::is_resolved);
+    Ok(treatments)
+}
+
+/// **What is it?**
+/// A server function that returns the open treatment shown on each plant's card, keyed by orchid ID.
+///
+/// **Why does it exist?**
+/// It exists so the collection grid can flag plants under treatment with one request instead of one per card.
+///
+/// **How should it be used?**
+/// Load once on the home page and share through the `TreatmentBadges` context; see `crate::treatment::treatment_badges` for which treatment a card shows.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_treatment_badges() -> Result<HashMap<String, Treatment>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::treatment::treatment_badges;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::TreatmentDbRow;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT * FROM treatment WHERE owner = $owner AND resolved_at = NONE AND orchid.archived_at = NONE")
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get treatment badges query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get treatment badges query error", err_msg));
+    }
+
+    let rows: Vec<TreatmentDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get treatment badges parse failed", e))?;
+    let treatments: Vec<Treatment> = rows.into_iter().filter_map(TreatmentDbRow::into_treatment).collect();
+
+    Ok(treatment_badges(&treatments))
+}
+
+/// **What is it?**
+/// A server function that starts a treatment: it records the first application and schedules the re-treatment series.
+///
+/// **Why does it exist?**
+/// It exists so a spray that needs repeating (most only kill what has hatched) comes back as a reminder on the right day until the series is done.
+///
+/// **How should it be used?**
+/// Call from the treatments card once the first application has been made. The application is journalled as a 'PestTreatment' entry and, if more are planned, a reminder is set `interval_days` out. Returns the new treatment.
+#[server]
+#[tracing::instrument(level = "info", skip_all, fields(orchid_id = %orchid_id))]
+pub async fn start_treatment(
+    /// The plant being treated.
+    orchid_id: String,
+    /// Pest or disease.
+    kind: ProblemKind,
+    /// What the plant has, such as "Mealybug".
+    problem: String,
+    /// What it's treated with, such as "Neem oil".
+    product: String,
+    /// How much, such as "5 ml/L".
+    dose: Option<String>,
+    /// Days between applications.
+    interval_days: u32,
+    /// How many applications the series has, this one included.
+    planned_applications: u32,
+) -> Result<Treatment, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::treatment::{MAX_DOSE_LEN, MAX_PROBLEM_LEN, MAX_PRODUCT_LEN, MAX_TREATMENT_APPLICATIONS, MAX_TREATMENT_INTERVAL_DAYS};
+    use crate::server_fns::climate::parse_owner;
+    use crate::server_fns::orchids::load_owned_orchid;
+    use ssr_types::TreatmentDbRow;
+
+    let problem = problem.trim().to_string();
+    if problem.is_empty() || problem.len() > MAX_PROBLEM_LEN {
+        return Err(ServerFnError::new(format!("Pest or disease must be 1 to {} characters", MAX_PROBLEM_LEN)));
+    }
+    let product = product.trim().to_string();
+    if product.is_empty() || product.len() > MAX_PRODUCT_LEN {
+        return Err(ServerFnError::new(format!("Product must be 1 to {} characters", MAX_PRODUCT_LEN)));
+    }
+    let dose = dose.map(|d| d.trim().to_string()).filter(|d| !d.is_empty());
+    if dose.as_ref().is_some_and(|d| d.len() > MAX_DOSE_LEN) {
+        return Err(ServerFnError::new(format!("Dose must be at most {} characters", MAX_DOSE_LEN)));
+    }
+    if interval_days == 0 || interval_days > MAX_TREATMENT_INTERVAL_DAYS {
+        return Err(ServerFnError::new(format!("Re-treat every 1-{} days", MAX_TREATMENT_INTERVAL_DAYS)));
+    }
+    if planned_applications == 0 || planned_applications > MAX_TREATMENT_APPLICATIONS {
+        return Err(ServerFnError::new(format!("Plan 1-{} applications", MAX_TREATMENT_APPLICATIONS)));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let orchid = surrealdb::types::RecordId::parse_simple(&orchid_id)
+        .map_err(|e| internal_error("Orchid ID parse failed", e))?;
+    load_owned_orchid(&orchid, &owner).await?;
+
+    let mut resp = db()
+        .query(
+            "CREATE treatment SET owner = $owner, orchid = $orchid, kind = $kind, problem = $problem, product = $product, \
+             dose = $dose, interval_days = $interval_days, planned_applications = $planned_applications"
+        )
+        .bind(("owner", owner.clone()))
+        .bind(("orchid", orchid))
+        .bind(("kind", kind.as_str().to_string()))
+        .bind(("problem", problem))
+        .bind(("product", product))
+        .bind(("dose", dose))
+        .bind(("interval_days", interval_days as i64))
+        .bind(("planned_applications", planned_applications as i64))
+        .await
+        .map_err(|e| internal_error("Start treatment query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Start treatment query error", err_msg));
+    }
+
+    let row: Option<TreatmentDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Start treatment parse failed", e))?;
+    let treatment = row.and_then(TreatmentDbRow::into_treatment)
+        .ok_or_else(|| ServerFnError::new("Failed to start treatment"))?;
+
+    log_application(&treatment, &owner).await?;
+    Ok(treatment)
+}
+
+/// **What is it?**
+/// A server function that records the next application of an open treatment.
+///
+/// **Why does it exist?**
+/// It exists so each re-treatment is counted, journalled, and followed by the reminder for the one after it.
+///
+/// **How should it be used?**
+/// Call from the treatment's "Applied" button. Marking the treatment's reminder done on the Today tab does the same. Fails once every planned application is made.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn apply_treatment(
+    /// The unique identifier of the treatment.
+    id: String,
+) -> Result<Treatment, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let treatment = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Treatment ID parse failed", e))?;
+
+    record_application(&treatment, &owner).await
+}
+
+/// **What is it?**
+/// A server function that marks a treatment resolved, ending its series.
+///
+/// **Why does it exist?**
+/// It exists so a plant that's clear of the problem stops showing the treatment badge and gets no more re-treatment reminders.
+///
+/// **How should it be used?**
+/// Call from the treatment's "Resolved" button, whether or not every planned application was made. Open reminders for it are marked done.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn resolve_treatment(
+    /// The unique identifier of the treatment.
+    id: String,
+) -> Result<Treatment, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+    use ssr_types::TreatmentDbRow;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let treatment = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Treatment ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             UPDATE $id SET resolved_at = time::now() WHERE owner = $owner AND resolved_at = NONE RETURN AFTER; \
+             UPDATE reminder SET completed_at = time::now() WHERE treatment = $id AND owner = $owner AND completed_at = NONE; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("id", treatment))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Resolve treatment query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Resolve treatment query error", err_msg));
+    }
+
+    // Index 1 = UPDATE result (index 0 = BEGIN)
+    let row: Option<TreatmentDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Resolve treatment parse failed", e))?;
+    row.and_then(TreatmentDbRow::into_treatment)
+        .ok_or_else(|| ServerFnError::new("Treatment not found or already resolved"))
+}
+
+/// **What is it?**
+/// A server function that deletes a treatment and its reminders.
+///
+/// **Why does it exist?**
+/// It exists to correct a treatment entered against the wrong plant or with the wrong series.
+///
+/// **How should it be used?**
+/// Call from the treatments card's delete action. Its journal entries are kept, like a deleted award's photo.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_treatment(
+    /// The unique identifier of the treatment.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::server_fns::climate::parse_owner;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let treatment = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Treatment ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             DELETE reminder WHERE treatment = $id AND owner = $owner; \
+             DELETE $id WHERE owner = $owner; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("id", treatment))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete treatment query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete treatment query error", err_msg));
+    }
+
+    Ok(())
+}
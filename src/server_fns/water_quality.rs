@@ -0,0 +1,384 @@
+use leptos::prelude::*;
+use crate::water_quality::{WaterQualityWarning, WaterReading, WaterSource, WaterSourceKind};
+
+#[cfg(feature = "ssr")]
+use crate::server_fns::climate::parse_owner;
+
+/// Longest reading note accepted.
+#[cfg(feature = "ssr")]
+const MAX_NOTE_CHARS: usize = 200;
+/// Readings returned per source in the history list.
+#[cfg(feature = "ssr")]
+const HISTORY_LIMIT: usize = 20;
+
+#[cfg(feature = "ssr")]
+mod ssr_types {
+    use surrealdb::types::SurrealValue;
+    use crate::server_fns::auth::record_id_to_string;
+    use crate::water_quality::{WaterReading, WaterSource, WaterSourceKind};
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct WaterReadingDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub measured_at: chrono::DateTime<chrono::Utc>,
+        #[surreal(default)]
+        pub ph: Option<f64>,
+        #[surreal(default)]
+        pub ec_us_cm: Option<f64>,
+        #[surreal(default)]
+        pub tds_ppm: Option<f64>,
+        #[surreal(default)]
+        pub note: Option<String>,
+    }
+
+    impl WaterReadingDbRow {
+        pub fn into_reading(self) -> WaterReading {
+            WaterReading {
+                id: record_id_to_string(&self.id),
+                measured_at: self.measured_at,
+                ph: self.ph,
+                ec_us_cm: self.ec_us_cm,
+                tds_ppm: self.tds_ppm,
+                note: self.note,
+            }
+        }
+    }
+
+    #[derive(serde::Deserialize, SurrealValue)]
+    #[surreal(crate = "surrealdb::types")]
+    pub struct WaterSourceDbRow {
+        pub id: surrealdb::types::RecordId,
+        pub name: String,
+        pub kind: String,
+        #[surreal(default)]
+        pub latest: Option<WaterReadingDbRow>,
+    }
+
+    impl WaterSourceDbRow {
+        pub fn into_source(self) -> WaterSource {
+            WaterSource {
+                id: record_id_to_string(&self.id),
+                name: self.name,
+                kind: WaterSourceKind::parse(&self.kind).unwrap_or(WaterSourceKind::Other),
+                latest: self.latest.map(|r| r.into_reading()),
+            }
+        }
+    }
+
+    /// Sources with their newest reading, for `$owner`.
+    pub const SOURCES_QUERY: &str =
+        "SELECT id, name, kind, created_at, \
+             (SELECT id, measured_at, ph, ec_us_cm, tds_ppm, note FROM water_reading \
+              WHERE source = $parent.id ORDER BY measured_at DESC LIMIT 1)[0] AS latest \
+         FROM water_source WHERE owner = $owner ORDER BY created_at ASC";
+}
+
+#[cfg(feature = "ssr")]
+use ssr_types::*;
+
+/// Loads the owner's water sources with their latest readings.
+#[cfg(feature = "ssr")]
+async fn load_sources(owner: surrealdb::types::RecordId) -> Result<Vec<WaterSource>, ServerFnError> {
+    use crate::db::db;
+    use crate::error::internal_error;
+
+    let mut resp = db()
+        .query(SOURCES_QUERY)
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Get water sources query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get water sources query error", err_msg));
+    }
+
+    let rows: Vec<WaterSourceDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get water sources parse failed", e))?;
+    Ok(rows.into_iter().map(|r| r.into_source()).collect())
+}
+
+/// **What is it?**
+/// A server function that lists the collection's water sources, each with its newest reading.
+///
+/// **Why does it exist?**
+/// It exists to fill the water quality section in settings.
+///
+/// **How should it be used?**
+/// Load when the settings modal opens and refetch after adding a source or logging a reading.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_water_sources() -> Result<Vec<WaterSource>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    load_sources(parse_owner(&owner_id)?).await
+}
+
+/// **What is it?**
+/// A server function that adds a water source.
+///
+/// **Why does it exist?**
+/// It exists so readings can be kept apart per source: the tap drifts with the season, an RO unit drifts as its membrane wears.
+///
+/// **How should it be used?**
+/// Call from the water quality settings section. Returns the new source.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn add_water_source(
+    /// The grower's name for the source.
+    name: String,
+    /// What kind of water it is.
+    kind: WaterSourceKind,
+) -> Result<WaterSource, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::water_quality::MAX_SOURCE_NAME_CHARS;
+
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_SOURCE_NAME_CHARS {
+        return Err(ServerFnError::new(format!("Name must be 1 to {} characters", MAX_SOURCE_NAME_CHARS)));
+    }
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("CREATE water_source SET owner = $owner, name = $name, kind = $kind RETURN id, name, kind")
+        .bind(("owner", owner))
+        .bind(("name", name))
+        .bind(("kind", kind.as_str().to_string()))
+        .await
+        .map_err(|e| internal_error("Add water source query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Add water source query error", err_msg));
+    }
+
+    let row: Option<WaterSourceDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Add water source parse failed", e))?;
+    row.map(|r| r.into_source())
+        .ok_or_else(|| ServerFnError::new("Failed to add water source"))
+}
+
+/// **What is it?**
+/// A server function that removes a water source and all its readings.
+///
+/// **Why does it exist?**
+/// It exists for sources the grower no longer uses, such as a replaced RO unit.
+///
+/// **How should it be used?**
+/// Call from the water quality settings section with the source ID.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn delete_water_source(
+    /// The source record ID.
+    id: String,
+) -> Result<(), ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let source_id = surrealdb::types::RecordId::parse_simple(&id)
+        .map_err(|e| internal_error("Water source ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "BEGIN TRANSACTION; \
+             DELETE water_reading WHERE source = $id AND owner = $owner; \
+             DELETE water_source WHERE id = $id AND owner = $owner; \
+             COMMIT TRANSACTION;"
+        )
+        .bind(("id", source_id))
+        .bind(("owner", owner))
+        .await
+        .map_err(|e| internal_error("Delete water source query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Delete water source query error", err_msg));
+    }
+    Ok(())
+}
+
+/// **What is it?**
+/// A server function that records a pH and/or EC/TDS meter reading for a water source.
+///
+/// **Why does it exist?**
+/// It exists so drift in the water shows up as a warning instead of as burnt root tips weeks later.
+///
+/// **How should it be used?**
+/// Call from the water quality settings section. At least one of `ph`, `ec_us_cm` and `tds_ppm` is required.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn log_water_reading(
+    /// The source record ID.
+    source_id: String,
+    /// pH, 0–14.
+    ph: Option<f64>,
+    /// Electrical conductivity, µS/cm.
+    ec_us_cm: Option<f64>,
+    /// Total dissolved solids, ppm.
+    tds_ppm: Option<f64>,
+    /// Free-text note.
+    note: Option<String>,
+) -> Result<WaterReading, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if note.as_ref().is_some_and(|n| n.chars().count() > MAX_NOTE_CHARS) {
+        return Err(ServerFnError::new(format!("Note must be at most {} characters", MAX_NOTE_CHARS)));
+    }
+    let draft = WaterReading { id: String::new(), measured_at: chrono::Utc::now(), ph, ec_us_cm, tds_ppm, note };
+    draft.validate().map_err(ServerFnError::new)?;
+
+    let owner_id = require_role(HouseholdRole::Caretaker).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let source = surrealdb::types::RecordId::parse_simple(&source_id)
+        .map_err(|e| internal_error("Water source ID parse failed", e))?;
+
+    // The source must belong to the same collection
+    let mut resp = db()
+        .query(
+            "IF (SELECT VALUE id FROM $source WHERE owner = $owner) = [] { \
+                 THROW 'Water source not found'; \
+             }; \
+             CREATE water_reading SET owner = $owner, source = $source, \
+                 ph = $ph, ec_us_cm = $ec, tds_ppm = $tds, note = $note \
+                 RETURN id, measured_at, ph, ec_us_cm, tds_ppm, note;"
+        )
+        .bind(("owner", owner))
+        .bind(("source", source))
+        .bind(("ph", draft.ph))
+        .bind(("ec", draft.ec_us_cm))
+        .bind(("tds", draft.tds_ppm))
+        .bind(("note", draft.note))
+        .await
+        .map_err(|e| internal_error("Log water reading query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Log water reading query error", err_msg));
+    }
+
+    let row: Option<WaterReadingDbRow> = resp.take(1)
+        .map_err(|e| internal_error("Log water reading parse failed", e))?;
+    row.map(|r| r.into_reading())
+        .ok_or_else(|| ServerFnError::new("Failed to log reading"))
+}
+
+/// **What is it?**
+/// A server function that returns a water source's recent readings, newest first.
+///
+/// **Why does it exist?**
+/// It exists so growers can see whether their water is drifting, not just where it stands today.
+///
+/// **How should it be used?**
+/// Call when a source's history is expanded in settings.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_water_readings(
+    /// The source record ID.
+    source_id: String,
+) -> Result<Vec<WaterReading>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+    let source = surrealdb::types::RecordId::parse_simple(&source_id)
+        .map_err(|e| internal_error("Water source ID parse failed", e))?;
+
+    let mut resp = db()
+        .query(
+            "SELECT id, measured_at, ph, ec_us_cm, tds_ppm, note FROM water_reading \
+             WHERE source = $source AND owner = $owner \
+             ORDER BY measured_at DESC LIMIT $limit"
+        )
+        .bind(("source", source))
+        .bind(("owner", owner))
+        .bind(("limit", HISTORY_LIMIT as i64))
+        .await
+        .map_err(|e| internal_error("Get water readings query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Get water readings query error", err_msg));
+    }
+
+    let rows: Vec<WaterReadingDbRow> = resp.take(0)
+        .map_err(|e| internal_error("Get water readings parse failed", e))?;
+    Ok(rows.into_iter().map(|r| r.into_reading()).collect())
+}
+
+/// **What is it?**
+/// A server function that checks every water source's latest reading against the safe pH range and the EC limits of the genera in the collection.
+///
+/// **Why does it exist?**
+/// It exists because the same tap water that suits a Phalaenopsis can burn a Phragmipedium; the warning depends on what the grower keeps.
+///
+/// **How should it be used?**
+/// Load alongside `get_water_sources` and show each warning under its source. Refetch after logging a reading.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_water_quality_warnings() -> Result<Vec<WaterQualityWarning>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::db::db;
+    use crate::error::internal_error;
+    use crate::household::HouseholdRole;
+    use crate::water_quality::water_issues;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
+
+    let mut resp = db()
+        .query("SELECT VALUE species FROM orchid WHERE owner = $owner")
+        .bind(("owner", owner.clone()))
+        .await
+        .map_err(|e| internal_error("Collection genera query failed", e))?;
+
+    let errors = resp.take_errors();
+    if !errors.is_empty() {
+        let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
+        return Err(internal_error("Collection genera query error", err_msg));
+    }
+
+    let species: Vec<String> = resp.take(0)
+        .map_err(|e| internal_error("Collection genera parse failed", e))?;
+    let mut genera: Vec<String> = species.iter()
+        .filter_map(|s| s.split_whitespace().next().map(str::to_string))
+        .collect();
+    genera.sort();
+    genera.dedup();
+
+    let sources = load_sources(owner).await?;
+    Ok(sources.into_iter()
+        .filter_map(|s| s.latest.map(|r| (s.id, s.name, r)))
+        .flat_map(|(source_id, source_name, reading)| {
+            water_issues(&reading, &genera).into_iter().map(move |issue| WaterQualityWarning {
+                source_id: source_id.clone(),
+                source_name: source_name.clone(),
+                measured_at: reading.measured_at,
+                issue,
+            })
+        })
+        .collect())
+}
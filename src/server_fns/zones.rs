@@ -8,7 +8,7 @@ use crate::orchid::GrowingZone;
 /// It exists to standardize error handling across the backend when extracting the authenticated user's ID for database constraints.
 ///
 /// **How should it be used?**
-/// Call this inside server functions after `require_auth` to obtain the `RecordId` needed for the `owner` field in database queries.
+/// Call this inside server functions after `require_auth` (or `require_role`, passing its `owner_id`) to obtain the `RecordId` needed for the `owner` field in database queries.
 #[cfg(feature = "ssr")]
 fn parse_owner(user_id: &str) -> Result<surrealdb::types::RecordId, ServerFnError> {
     use crate::error::internal_error;
@@ -94,27 +94,32 @@ pub(crate) mod ssr_types {
 #[cfg(feature = "ssr")]
 use ssr_types::*;
 
+/// Each owner's zones as `get_zones` returns them. The home page, settings and climate views all
+/// list zones on one load; every write to an owner's zones calls `invalidate_zones`.
+#[cfg(feature = "ssr")]
+static ZONES: std::sync::LazyLock<crate::cache::TtlCache<String, Vec<GrowingZone>>> =
+    std::sync::LazyLock::new(|| crate::cache::TtlCache::new(std::time::Duration::from_secs(5 * 60)));
+
 /// **What is it?**
-/// A server function that retrieves all growing zones configured by the currently authenticated user.
+/// The owner's growing zones in display order, from the zone cache when it's fresh.
 ///
 /// **Why does it exist?**
-/// It exists to provide the frontend with the user's customized list of physical locations (like "Living Room" or "Greenhouse") where their orchids are grown.
+/// Several views read the zone list on every page load; this spares the database all but the first of those queries.
 ///
 /// **How should it be used?**
-/// Call this from the dashboard or zone management settings page to populate the list of available zones and their climate data sources.
-#[server]
-#[tracing::instrument(level = "info", skip_all)]
-pub async fn get_zones() -> Result<Vec<GrowingZone>, ServerFnError> {
-    use crate::auth::require_auth;
+/// Call it with a `require_role(...)` owner ID wherever the full zone list is needed, and call `invalidate_zones` after changing any of the owner's zones.
+#[cfg(feature = "ssr")]
+pub(crate) async fn owner_zones(owner_id: &str) -> Result<Vec<GrowingZone>, ServerFnError> {
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    if let Some(zones) = ZONES.get(&owner_id.to_string()) {
+        return Ok(zones);
+    }
 
     let mut response = db()
         .query("SELECT * FROM growing_zone WHERE owner = $owner ORDER BY sort_order ASC")
-        .bind(("owner", owner))
+        .bind(("owner", parse_owner(owner_id)?))
         .await
         .map_err(|e| internal_error("Get zones query failed", e))?;
 
@@ -127,7 +132,33 @@ pub async fn get_zones() -> Result<Vec<GrowingZone>, ServerFnError> {
     let db_rows: Vec<GrowingZoneDbRow> = response.take(0)
         .map_err(|e| internal_error("Get zones parse failed", e))?;
 
-    Ok(db_rows.into_iter().map(|r| r.into_growing_zone()).collect())
+    let zones: Vec<GrowingZone> = db_rows.into_iter().map(|r| r.into_growing_zone()).collect();
+    ZONES.insert(owner_id.to_string(), zones.clone());
+    Ok(zones)
+}
+
+/// Drops the owner's cached zone list after a zone is created, changed or deleted.
+#[cfg(feature = "ssr")]
+pub(crate) fn invalidate_zones(owner_id: &str) {
+    ZONES.invalidate(&owner_id.to_string());
+}
+
+/// **What is it?**
+/// A server function that retrieves all growing zones configured by the currently authenticated user.
+///
+/// **Why does it exist?**
+/// It exists to provide the frontend with the user's customized list of physical locations (like "Living Room" or "Greenhouse") where their orchids are grown.
+///
+/// **How should it be used?**
+/// Call this from the dashboard or zone management settings page to populate the list of available zones and their climate data sources.
+#[server]
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn get_zones() -> Result<Vec<GrowingZone>, ServerFnError> {
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
+
+    let owner_id = require_role(HouseholdRole::Viewer).await?.owner_id;
+    owner_zones(&owner_id).await
 }
 
 /// **What is it?**
@@ -156,7 +187,8 @@ pub async fn create_zone(
     /// The ordering for UI display.
     sort_order: i32,
 ) -> Result<GrowingZone, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
@@ -179,8 +211,8 @@ pub async fn create_zone(
         return Err(ServerFnError::new("Description must be at most 500 characters"));
     }
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
 
     let mut response = db()
         .query(
@@ -209,6 +241,7 @@ pub async fn create_zone(
 
     let db_row: Option<GrowingZoneDbRow> = response.take(0)
         .map_err(|e| internal_error("Create zone parse failed", e))?;
+    invalidate_zones(&owner_id);
 
     db_row.map(|r| r.into_growing_zone())
         .ok_or_else(|| ServerFnError::new("Failed to create zone"))
@@ -228,7 +261,8 @@ pub async fn update_zone(
     /// The updated growing zone struct.
     zone: GrowingZone
 ) -> Result<GrowingZone, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
@@ -236,8 +270,8 @@ pub async fn update_zone(
         return Err(ServerFnError::new("Zone name must be 1-100 characters"));
     }
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let zone_id = surrealdb::types::RecordId::parse_simple(&zone.id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
 
@@ -280,6 +314,7 @@ pub async fn update_zone(
 
     let updated: Option<GrowingZoneDbRow> = response.take(0)
         .map_err(|e| internal_error("Update zone parse failed", e))?;
+    invalidate_zones(&owner_id);
 
     updated.map(|r| r.into_growing_zone())
         .ok_or_else(|| ServerFnError::new("Zone not found or not owned by you"))
@@ -299,22 +334,24 @@ pub async fn delete_zone(
     /// The unique identifier of the zone to delete.
     id: String
 ) -> Result<(), ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let owner_id = require_role(HouseholdRole::Owner).await?.owner_id;
+    let owner = parse_owner(&owner_id)?;
     let zone_id = surrealdb::types::RecordId::parse_simple(&id)
         .map_err(|e| internal_error("Zone ID parse failed", e))?;
 
     db()
-        .query("DELETE $id WHERE owner = $owner")
+        .query("DELETE zone_dli WHERE zone = $id AND zone.owner = $owner; DELETE $id WHERE owner = $owner; DELETE zone_goal WHERE zone = $id AND owner = $owner; DELETE zone_energy WHERE zone = $id AND owner = $owner; DELETE reminder WHERE zone = $id AND owner = $owner;")
         .bind(("id", zone_id))
         .bind(("owner", owner))
         .await
         .map_err(|e| internal_error("Delete zone query failed", e))?;
 
+    invalidate_zones(&owner_id);
     Ok(())
 }
 
@@ -329,13 +366,18 @@ pub async fn delete_zone(
 #[server]
 #[tracing::instrument(level = "info", skip_all)]
 pub async fn migrate_legacy_placements() -> Result<bool, ServerFnError> {
-    use crate::auth::require_auth;
+    use crate::auth::require_role;
+    use crate::household::HouseholdRole;
     use crate::db::db;
     use crate::error::internal_error;
     use crate::orchid::Orchid;
 
-    let user_id = require_auth().await?;
-    let owner = parse_owner(&user_id)?;
+    let access = require_role(HouseholdRole::Viewer).await?;
+    // Only the owner's own collection is ever migrated; household members skip it
+    if access.role != HouseholdRole::Owner {
+        return Ok(false);
+    }
+    let owner = parse_owner(&access.owner_id)?;
 
     // Check if user already has zones
     let mut response = db()
@@ -396,7 +438,7 @@ pub async fn migrate_legacy_placements() -> Result<bool, ServerFnError> {
                          owner = $owner, name = $name, light_level = $light_level, \
                          location_type = $location_type, temperature_range = '', \
                          humidity = '', description = '', sort_order = $sort_order; \
-                     UPDATE orchid SET placement = $new_name WHERE owner = $owner AND placement = $old_val; \
+                     UPDATE orchid SET placement = $new_name, updated_at = time::now() WHERE owner = $owner AND placement = $old_val; \
                      COMMIT TRANSACTION;"
                 )
                 .bind(("owner", owner.clone()))
@@ -414,6 +456,7 @@ pub async fn migrate_legacy_placements() -> Result<bool, ServerFnError> {
                 let err_msg = errors.into_values().map(|e| e.to_string()).collect::<Vec<_>>().join("; ");
                 return Err(internal_error("Migrate placement query error", err_msg));
             }
+            invalidate_zones(&access.owner_id);
         }
     }
 
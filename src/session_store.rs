@@ -8,6 +8,10 @@ use crate::db::db;
 /// Maximum number of retry attempts for transient DB failures.
 const MAX_DB_RETRIES: u32 = 3;
 
+/// How stale a session's `last_seen` may get before a load writes it again. Sessions are only
+/// saved when their data changes, so without this "last active" would stop at sign-in.
+const LAST_SEEN_REFRESH_SECS: i64 = 60;
+
 /// Check if a SurrealDB error looks like a transient connection issue worth retrying.
 fn is_transient_error(e: &surrealdb::Error) -> bool {
     let msg = e.to_string().to_lowercase();
@@ -34,10 +38,7 @@ impl session_store::SessionStore for SurrealSessionStore {
         const MAX_RETRIES: u32 = 5;
         for attempt in 0..MAX_RETRIES {
             let id = record.id.to_string();
-            let row = SessionRow {
-                data: data.clone(),
-                expiry,
-            };
+            let row = SessionRow::new(record, data.clone(), expiry);
 
             let result: surrealdb::Result<Option<SessionRow>> = db()
                 .create(("session", id))
@@ -76,7 +77,7 @@ impl session_store::SessionStore for SurrealSessionStore {
         let expiry = record.expiry_date.unix_timestamp();
 
         for attempt in 0..MAX_DB_RETRIES {
-            let row = SessionRow { data: data.clone(), expiry };
+            let row = SessionRow::new(record, data.clone(), expiry);
             let result: surrealdb::Result<Option<SessionRow>> = db()
                 .upsert(("session", id.clone()))
                 .content(row)
@@ -135,6 +136,9 @@ impl session_store::SessionStore for SurrealSessionStore {
                     .map_err(|e| session_store::Error::Decode(e.to_string()))?;
                 let expiry_date = time::OffsetDateTime::from_unix_timestamp(row.expiry)
                     .map_err(|e| session_store::Error::Decode(e.to_string()))?;
+                if now - row.last_seen >= LAST_SEEN_REFRESH_SECS {
+                    touch_last_seen(&id, now).await;
+                }
                 Ok(Some(Record {
                     id: *session_id,
                     data,
@@ -177,6 +181,20 @@ impl session_store::SessionStore for SurrealSessionStore {
     }
 }
 
+/// Records `now` as the session's last request. Best-effort: a failure only leaves "last active"
+/// stale, so it's logged rather than failing the request.
+async fn touch_last_seen(id: &str, now: i64) {
+    let result = db()
+        .query("UPDATE type::record('session', $id) SET last_seen = $now")
+        .bind(("id", id.to_string()))
+        .bind(("now", now))
+        .await;
+    match result.map(|r| r.check()) {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) | Err(e) => tracing::warn!("Failed to refresh session last_seen: {}", e),
+    }
+}
+
 impl SurrealSessionStore {
     /// Periodically cleans up expired sessions from the database.
     pub async fn cleanup_expired(&self) {
@@ -201,6 +219,62 @@ impl SurrealSessionStore {
 struct SessionRow {
     data: String,
     expiry: i64,
+    /// Mirrors `data.user_id` so a user's sessions can be listed without scanning every row.
+    #[surreal(default)]
+    user_id: Option<String>,
+    /// Unix timestamp of the last request that touched the session.
+    #[surreal(default)]
+    last_seen: i64,
+}
+
+impl SessionRow {
+    fn new(record: &Record, data: String, expiry: i64) -> Self {
+        Self {
+            data,
+            expiry,
+            user_id: record.data.get("user_id").and_then(|v| v.as_str()).map(str::to_string),
+            last_seen: time::OffsetDateTime::now_utc().unix_timestamp(),
+        }
+    }
+}
+
+/// Summarizes a User-Agent header as "Browser on OS" for the active sessions list.
+pub fn describe_user_agent(ua: &str) -> String {
+    // Order matters: Edge and Opera also claim Chrome, and Chrome also claims Safari
+    let browser = if ua.contains("Edg/") {
+        "Edge"
+    } else if ua.contains("OPR/") {
+        "Opera"
+    } else if ua.contains("Firefox/") || ua.contains("FxiOS/") {
+        "Firefox"
+    } else if ua.contains("Chrome/") || ua.contains("CriOS/") {
+        "Chrome"
+    } else if ua.contains("Safari/") {
+        "Safari"
+    } else {
+        ""
+    };
+    let os = if ua.contains("iPhone") || ua.contains("iPad") {
+        "iOS"
+    } else if ua.contains("Android") {
+        "Android"
+    } else if ua.contains("Mac OS X") {
+        "macOS"
+    } else if ua.contains("Windows") {
+        "Windows"
+    } else if ua.contains("CrOS") {
+        "ChromeOS"
+    } else if ua.contains("Linux") {
+        "Linux"
+    } else {
+        ""
+    };
+    match (browser, os) {
+        ("", "") => "Unknown device".to_string(),
+        (b, "") => b.to_string(),
+        ("", o) => format!("Browser on {}", o),
+        (b, o) => format!("{} on {}", b, o),
+    }
 }
 
 #[cfg(test)]
@@ -247,10 +321,11 @@ mod tests {
         };
 
         let id = record.id.to_string();
-        let row = SessionRow {
-            data: serde_json::to_string(&record.data).unwrap(),
-            expiry: record.expiry_date.unix_timestamp(),
-        };
+        let row = SessionRow::new(
+            &record,
+            serde_json::to_string(&record.data).unwrap(),
+            record.expiry_date.unix_timestamp(),
+        );
 
         // Create
         let expected_expiry = row.expiry;
@@ -285,6 +360,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_session_row_mirrors_user_id() {
+        let mut data = HashMap::new();
+        data.insert("user_id".to_string(), serde_json::json!("user:abc"));
+        let record = Record {
+            id: Id::default(),
+            data,
+            expiry_date: time::OffsetDateTime::now_utc(),
+        };
+        let row = SessionRow::new(&record, String::new(), 0);
+        assert_eq!(row.user_id.as_deref(), Some("user:abc"));
+        assert!(row.last_seen > 0);
+    }
+
+    #[test]
+    fn test_describe_user_agent() {
+        let mac_safari = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.4 Safari/605.1.15";
+        assert_eq!(describe_user_agent(mac_safari), "Safari on macOS");
+        let win_edge = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0 Safari/537.36 Edg/124.0";
+        assert_eq!(describe_user_agent(win_edge), "Edge on Windows");
+        let android_chrome = "Mozilla/5.0 (Linux; Android 14) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0 Mobile Safari/537.36";
+        assert_eq!(describe_user_agent(android_chrome), "Chrome on Android");
+        let iphone_firefox = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_4 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) FxiOS/125.0 Mobile/15E148 Safari/605.1.15";
+        assert_eq!(describe_user_agent(iphone_firefox), "Firefox on iOS");
+        assert_eq!(describe_user_agent("curl/8.0"), "Unknown device");
+    }
+
     #[test]
     fn test_max_db_retries_is_reasonable() {
         // Sanity check: retry count should be small to avoid excessive delays
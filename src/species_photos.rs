@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::orchid::Orchid;
+
+/// A built-in placeholder illustration: the botanical line art over a genus-tinted gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PresetArt {
+    /// Stable key stored in `species_photo.preset`.
+    pub key: &'static str,
+    /// Display label in the picker.
+    pub label: &'static str,
+    /// Tailwind gradient classes for the card header.
+    pub gradient: &'static str,
+    /// Tailwind text color for the line art.
+    pub ink: &'static str,
+}
+
+/// The built-in placeholder presets. The last entry is the generic fallback.
+pub const PRESET_ART: &[PresetArt] = &[
+    PresetArt { key: "phalaenopsis", label: "Phalaenopsis", gradient: "bg-gradient-to-br from-pink-100 to-fuchsia-200 dark:from-pink-950/60 dark:to-fuchsia-900/40", ink: "text-fuchsia-700/40 dark:text-fuchsia-300/30" },
+    PresetArt { key: "cattleya", label: "Cattleya", gradient: "bg-gradient-to-br from-purple-100 to-violet-200 dark:from-purple-950/60 dark:to-violet-900/40", ink: "text-violet-700/40 dark:text-violet-300/30" },
+    PresetArt { key: "dendrobium", label: "Dendrobium", gradient: "bg-gradient-to-br from-rose-50 to-rose-200 dark:from-rose-950/60 dark:to-rose-900/40", ink: "text-rose-700/40 dark:text-rose-300/30" },
+    PresetArt { key: "oncidium", label: "Oncidium", gradient: "bg-gradient-to-br from-yellow-50 to-amber-200 dark:from-amber-950/60 dark:to-yellow-900/40", ink: "text-amber-700/40 dark:text-amber-300/30" },
+    PresetArt { key: "paphiopedilum", label: "Paphiopedilum", gradient: "bg-gradient-to-br from-lime-50 to-emerald-200 dark:from-emerald-950/60 dark:to-lime-900/40", ink: "text-emerald-800/40 dark:text-emerald-300/30" },
+    PresetArt { key: "vanda", label: "Vanda", gradient: "bg-gradient-to-br from-indigo-100 to-sky-200 dark:from-indigo-950/60 dark:to-sky-900/40", ink: "text-indigo-700/40 dark:text-indigo-300/30" },
+    PresetArt { key: "cymbidium", label: "Cymbidium", gradient: "bg-gradient-to-br from-orange-50 to-orange-200 dark:from-orange-950/60 dark:to-orange-900/40", ink: "text-orange-700/40 dark:text-orange-300/30" },
+    PresetArt { key: "default", label: "Botanical", gradient: "bg-gradient-to-br from-stone-100 to-emerald-100 dark:from-stone-800 dark:to-emerald-950/50", ink: "text-primary/30 dark:text-primary-light/25" },
+];
+
+/// Looks up a preset by key, falling back to the generic one.
+pub fn preset_art(key: &str) -> &'static PresetArt {
+    PRESET_ART.iter()
+        .find(|p| p.key == key)
+        .unwrap_or(&PRESET_ART[PRESET_ART.len() - 1])
+}
+
+/// Normalizes a species name for matching: trimmed, lowercased, single-spaced.
+pub fn species_key(species: &str) -> String {
+    species.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// The genus (first word) of a normalized species key.
+pub fn genus_key(species: &str) -> String {
+    species_key(species).split(' ').next().unwrap_or_default().to_string()
+}
+
+/// A user's default image for a species (or a whole genus when `species` is a single word).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesPhoto {
+    /// The species key as returned by `species_key`.
+    pub species: String,
+    /// An uploaded image, if the user chose one.
+    pub image_filename: Option<String>,
+    /// A preset key, if the user chose built-in art instead.
+    pub preset: Option<String>,
+}
+
+/// What to draw at the top of an orchid card.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CardImage {
+    /// An uploaded image path under `/images/`.
+    Photo(String),
+    /// Built-in placeholder art.
+    Preset(&'static PresetArt),
+}
+
+/// Everything needed to pick a card image for each plant, loaded once per page.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CardPhotoIndex {
+    /// Latest journal photo per orchid ID.
+    pub orchid_photos: HashMap<String, String>,
+    /// The user's species-level defaults.
+    pub species: Vec<SpeciesPhoto>,
+}
+
+impl CardPhotoIndex {
+    /// The species default that applies to `species`: an exact match first, then the genus.
+    pub fn default_for(&self, species: &str) -> Option<&SpeciesPhoto> {
+        let key = species_key(species);
+        let genus = genus_key(species);
+        self.species.iter().find(|p| p.species == key)
+            .or_else(|| self.species.iter().find(|p| p.species == genus))
+    }
+
+    /// Picks the card image: the plant's chosen cover, then its own latest photo, then the
+    /// species default, then preset art matching the genus, then the generic preset.
+    pub fn resolve(&self, orchid: &Orchid) -> CardImage {
+        if let Some(ref cover) = orchid.cover_image {
+            return CardImage::Photo(cover.clone());
+        }
+        if let Some(photo) = self.orchid_photos.get(&orchid.id) {
+            return CardImage::Photo(photo.clone());
+        }
+        if let Some(default) = self.default_for(&orchid.species) {
+            if let Some(ref filename) = default.image_filename {
+                return CardImage::Photo(filename.clone());
+            }
+            if let Some(ref preset) = default.preset {
+                return CardImage::Preset(preset_art(preset));
+            }
+        }
+        CardImage::Preset(preset_art(&genus_key(&orchid.species)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    #[test]
+    fn test_species_key_normalizes() {
+        assert_eq!(species_key("  Phalaenopsis   Amabilis "), "phalaenopsis amabilis");
+        assert_eq!(genus_key("Phalaenopsis amabilis"), "phalaenopsis");
+        assert_eq!(genus_key(""), "");
+    }
+
+    #[test]
+    fn test_preset_fallback() {
+        assert_eq!(preset_art("vanda").label, "Vanda");
+        assert_eq!(preset_art("nope").key, "default");
+    }
+
+    #[test]
+    fn test_resolve_prefers_own_photo() {
+        let orchid = test_orchid();
+        let mut index = CardPhotoIndex::default();
+        index.orchid_photos.insert(orchid.id.clone(), "u/own.jpg".into());
+        index.species.push(SpeciesPhoto { species: species_key(&orchid.species), image_filename: Some("u/stock.jpg".into()), preset: None });
+        assert_eq!(index.resolve(&orchid), CardImage::Photo("u/own.jpg".into()));
+    }
+
+    #[test]
+    fn test_resolve_prefers_cover_over_latest() {
+        let mut orchid = test_orchid();
+        orchid.cover_image = Some("u/cover.jpg".into());
+        let mut index = CardPhotoIndex::default();
+        assert_eq!(index.resolve(&orchid), CardImage::Photo("u/cover.jpg".into()));
+        index.orchid_photos.insert(orchid.id.clone(), "u/latest.jpg".into());
+        assert_eq!(index.resolve(&orchid), CardImage::Photo("u/cover.jpg".into()));
+    }
+
+    #[test]
+    fn test_resolve_species_then_genus_then_preset() {
+        let mut orchid = test_orchid();
+        orchid.species = "Cattleya walkeriana".into();
+        let mut index = CardPhotoIndex::default();
+        assert_eq!(index.resolve(&orchid), CardImage::Preset(preset_art("cattleya")));
+
+        index.species.push(SpeciesPhoto { species: "cattleya".into(), image_filename: None, preset: Some("vanda".into()) });
+        assert_eq!(index.resolve(&orchid), CardImage::Preset(preset_art("vanda")));
+
+        index.species.push(SpeciesPhoto { species: "cattleya walkeriana".into(), image_filename: Some("u/walk.jpg".into()), preset: None });
+        assert_eq!(index.resolve(&orchid), CardImage::Photo("u/walk.jpg".into()));
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use serde::{Deserialize, Serialize};
+use crate::orchid::{LightRequirement, Orchid};
+
+/// Languages offered when adding a common name, as (ISO 639-1 code, label).
+pub const ALIAS_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("de", "German"),
+    ("fr", "French"),
+    ("es", "Spanish"),
+    ("pt", "Portuguese"),
+    ("nl", "Dutch"),
+    ("it", "Italian"),
+    ("ja", "Japanese"),
+    ("zh", "Chinese"),
+    ("th", "Thai"),
+];
+
+/// The display label for a language code, or the code itself when it isn't in `ALIAS_LANGUAGES`.
+pub fn language_label(code: &str) -> &str {
+    ALIAS_LANGUAGES.iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, label)| *label)
+        .unwrap_or(code)
+}
+
+/// What kind of name an alias is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AliasKind {
+    /// A vernacular name ("Moth orchid", "Frauenschuh").
+    Common,
+    /// A name a plant is sold under by nurseries and garden centres.
+    Trade,
+    /// A former or alternative scientific name ("Neofinetia falcata").
+    Synonym,
+}
+
+impl AliasKind {
+    /// Every kind, in the order offered in forms.
+    pub const ALL: [AliasKind; 3] = [AliasKind::Common, AliasKind::Trade, AliasKind::Synonym];
+
+    /// The stored and serialized name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AliasKind::Common => "Common",
+            AliasKind::Trade => "Trade",
+            AliasKind::Synonym => "Synonym",
+        }
+    }
+
+    /// Human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AliasKind::Common => "Common name",
+            AliasKind::Trade => "Trade name",
+            AliasKind::Synonym => "Synonym",
+        }
+    }
+
+    /// Parses a stored name; unknown values read as `Common`.
+    pub fn from_str_lossy(s: &str) -> Self {
+        Self::ALL.into_iter().find(|k| k.as_str() == s).unwrap_or(AliasKind::Common)
+    }
+}
+
+/// **What is it?**
+/// Another name a species or genus goes by: a common name in some language, a trade name, or a taxonomic synonym.
+///
+/// **Why does it exist?**
+/// It exists because growers know many plants by the name on the nursery label or in their own language, not the accepted botanical name stored on the plant.
+///
+/// **How should it be used?**
+/// Load with `server_fns::taxonomy::get_species_aliases`, show with `aliases_for`, and feed into `SearchIndex::build` so plants are found by any of their names.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesAlias {
+    /// The alias record ID.
+    pub id: String,
+    /// The species ("Vanda falcata") or genus ("Phalaenopsis") the alias belongs to.
+    pub scientific_name: String,
+    /// The alternative name.
+    pub alias: String,
+    /// ISO 639-1 language code, if the name belongs to one language.
+    pub language: Option<String>,
+    /// What kind of name this is.
+    pub kind: AliasKind,
+    /// True for the shared defaults; these can't be deleted.
+    pub builtin: bool,
+}
+
+/// Folds a name for matching: lowercase, common Latin accents removed, punctuation dropped, whitespace collapsed.
+/// Scripts without case (Japanese, Chinese, Thai) pass through unchanged.
+pub fn normalize(s: &str) -> String {
+    let folded: String = s.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            '\'' | '\u{2019}' | '"' | '.' | ',' | '×' => ' ',
+            other => other,
+        })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether an alias recorded for `scientific_name` applies to a plant of `species`.
+/// A bare genus covers every species and hybrid in it; a species name must match whole words.
+pub fn applies_to(scientific_name: &str, species: &str) -> bool {
+    let target = normalize(scientific_name);
+    let species = normalize(species);
+    !target.is_empty() && (species == target || species.starts_with(&format!("{} ", target)))
+}
+
+/// The aliases that apply to a plant's species, species-level names before genus-level ones.
+pub fn aliases_for<'a>(species: &str, aliases: &'a [SpeciesAlias]) -> Vec<&'a SpeciesAlias> {
+    let mut matching: Vec<&SpeciesAlias> = aliases.iter()
+        .filter(|a| applies_to(&a.scientific_name, species))
+        .collect();
+    matching.sort_by_key(|a| std::cmp::Reverse(a.scientific_name.split_whitespace().count()));
+    matching
+}
+
+/// **What is it?**
+/// An in-memory search index over the collection: each plant's name, species, and every alias that applies to it.
+///
+/// **Why does it exist?**
+/// It exists so searching "moth orchid", "Frauenschuh" or an old synonym finds the plants recorded under their accepted names.
+///
+/// **How should it be used?**
+/// Build it whenever the plants or aliases change, then call `matching_ids` with the search box text. Every word of the query must appear in one of the plant's names.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchIndex {
+    entries: Vec<(String, Vec<String>)>,
+}
+
+impl SearchIndex {
+    /// Indexes `orchids` under their own names and the `aliases` that apply to them.
+    pub fn build(orchids: &[Orchid], aliases: &[SpeciesAlias]) -> Self {
+        let entries = orchids.iter()
+            .map(|o| {
+                let mut terms = vec![normalize(&o.name), normalize(&o.species)];
+                terms.extend(aliases_for(&o.species, aliases).into_iter().map(|a| normalize(&a.alias)));
+                (o.id.clone(), terms)
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// IDs of plants matching `query`; an empty query matches everything.
+    pub fn matching_ids(&self, query: &str) -> HashSet<String> {
+        let query = normalize(query);
+        let words: Vec<&str> = query.split(' ').filter(|w| !w.is_empty()).collect();
+        self.entries.iter()
+            .filter(|(_, terms)| words.iter().all(|w| terms.iter().any(|t| t.contains(w))))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+}
+
+/// Most suggestions returned for one autocomplete query.
+pub const MAX_SPECIES_SUGGESTIONS: usize = 10;
+
+/// Starting care values for every plant in a genus, from the bundled dataset.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GenusCare {
+    /// The genus name ("Phalaenopsis").
+    pub genus: String,
+    /// Typical days between waterings in bark.
+    pub water_frequency_days: u32,
+    /// Typical light level.
+    pub light_requirement: LightRequirement,
+    /// Lowest comfortable temperature (°C).
+    pub temp_min: f64,
+    /// Highest comfortable temperature (°C).
+    pub temp_max: f64,
+    /// Lowest comfortable relative humidity (%).
+    pub humidity_min: f64,
+    /// Highest comfortable relative humidity (%).
+    pub humidity_max: f64,
+    /// CITES appendix covering the genus.
+    pub conservation_status: Option<String>,
+}
+
+/// A species in the bundled dataset, with where it grows wild.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesRecord {
+    /// The accepted name ("Phalaenopsis bellina").
+    pub name: String,
+    /// Where it grows in the wild.
+    pub native_region: Option<String>,
+    /// Approximate latitude of the native range, for habitat weather.
+    pub native_latitude: Option<f64>,
+    /// Approximate longitude of the native range.
+    pub native_longitude: Option<f64>,
+}
+
+/// The species and genus dataset bundled with the app (`src/data/species.json`).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesDataset {
+    /// Genus-level care defaults.
+    pub genera: Vec<GenusCare>,
+    /// Known species.
+    pub species: Vec<SpeciesRecord>,
+}
+
+static SPECIES_DATASET: LazyLock<SpeciesDataset> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("data/species.json")).expect("bundled species dataset is valid JSON")
+});
+
+/// The bundled species dataset, parsed on first use.
+pub fn species_dataset() -> &'static SpeciesDataset {
+    &SPECIES_DATASET
+}
+
+/// One autocomplete suggestion: a species (or a bare genus) with what is known about it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpeciesSuggestion {
+    /// The name to fill into the species field.
+    pub name: String,
+    /// Where it grows in the wild; None for a bare genus.
+    pub native_region: Option<String>,
+    /// Latitude of the native range.
+    pub native_latitude: Option<f64>,
+    /// Longitude of the native range.
+    pub native_longitude: Option<f64>,
+    /// Care defaults for the genus, when the dataset has them.
+    pub care: Option<GenusCare>,
+}
+
+impl SpeciesDataset {
+    /// The care defaults for the genus of `species`.
+    pub fn genus_care(&self, species: &str) -> Option<&GenusCare> {
+        let genus = normalize(species).split(' ').next().unwrap_or_default().to_string();
+        self.genera.iter().find(|g| normalize(&g.genus) == genus)
+    }
+
+    /// The species recorded under exactly this name, ignoring case and spacing.
+    pub fn find(&self, name: &str) -> Option<&SpeciesRecord> {
+        let name = normalize(name);
+        self.species.iter().find(|s| normalize(&s.name) == name)
+    }
+
+    /// Genera and species matching `query`, best first: names starting with the query, then names
+    /// with a word starting with it ("bellina"), then names containing it. At most `limit` results.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SpeciesSuggestion> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let rank = |name: &str| {
+            let name = normalize(name);
+            if name.starts_with(&query) {
+                Some(0)
+            } else if name.split(' ').any(|w| w.starts_with(&query)) {
+                Some(1)
+            } else if name.contains(&query) {
+                Some(2)
+            } else {
+                None
+            }
+        };
+
+        let genera = self.genera.iter().filter_map(|g| rank(&g.genus).map(|r| (r, SpeciesSuggestion {
+            name: g.genus.clone(),
+            native_region: None,
+            native_latitude: None,
+            native_longitude: None,
+            care: Some(g.clone()),
+        })));
+        let species = self.species.iter().filter_map(|s| rank(&s.name).map(|r| (r, SpeciesSuggestion {
+            name: s.name.clone(),
+            native_region: s.native_region.clone(),
+            native_latitude: s.native_latitude,
+            native_longitude: s.native_longitude,
+            care: self.genus_care(&s.name).cloned(),
+        })));
+
+        let mut matches: Vec<(u8, SpeciesSuggestion)> = genera.chain(species).collect();
+        matches.sort_by(|(ra, a), (rb, b)| ra.cmp(rb).then_with(|| a.name.cmp(&b.name)));
+        matches.into_iter().take(limit).map(|(_, s)| s).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::test_orchid;
+
+    fn alias(scientific_name: &str, alias: &str, kind: AliasKind) -> SpeciesAlias {
+        SpeciesAlias {
+            id: format!("species_alias:{}", alias.len()),
+            scientific_name: scientific_name.into(),
+            alias: alias.into(),
+            language: None,
+            kind,
+            builtin: true,
+        }
+    }
+
+    #[test]
+    fn test_genus_alias_applies_to_species_and_hybrids() {
+        assert!(applies_to("Phalaenopsis", "Phalaenopsis amabilis"));
+        assert!(applies_to("Phalaenopsis", "phalaenopsis"));
+        assert!(!applies_to("Phal", "Phalaenopsis amabilis"));
+        assert!(!applies_to("Vanda falcata", "Vanda coerulea"));
+    }
+
+    #[test]
+    fn test_search_finds_plants_by_alias() {
+        let mut moth = test_orchid();
+        moth.id = "orchid:moth".into();
+        moth.name = "Kitchen plant".into();
+        moth.species = "Phalaenopsis schilleriana".into();
+        let mut slipper = test_orchid();
+        slipper.id = "orchid:slipper".into();
+        slipper.name = "Birthday gift".into();
+        slipper.species = "Paphiopedilum insigne".into();
+
+        let aliases = [
+            alias("Phalaenopsis", "Moth orchid", AliasKind::Common),
+            alias("Paphiopedilum", "Sabot de Vénus", AliasKind::Common),
+        ];
+        let index = SearchIndex::build(&[moth, slipper], &aliases);
+
+        assert_eq!(index.matching_ids("moth"), HashSet::from(["orchid:moth".to_string()]));
+        assert_eq!(index.matching_ids("sabot venus"), HashSet::from(["orchid:slipper".to_string()]));
+        assert_eq!(index.matching_ids("GIFT"), HashSet::from(["orchid:slipper".to_string()]));
+        assert_eq!(index.matching_ids("").len(), 2);
+        assert!(index.matching_ids("moth insigne").is_empty());
+    }
+
+    #[test]
+    fn test_bundled_species_dataset_parses() {
+        let data = species_dataset();
+        assert!(!data.genera.is_empty());
+        for s in &data.species {
+            assert!(data.genus_care(&s.name).is_some(), "{} has no genus entry", s.name);
+        }
+    }
+
+    #[test]
+    fn test_species_search_ranks_prefix_first() {
+        let data = species_dataset();
+        let results = data.search("phal", MAX_SPECIES_SUGGESTIONS);
+        assert_eq!(results[0].name, "Phalaenopsis");
+        assert!(results.len() <= MAX_SPECIES_SUGGESTIONS);
+
+        let bellina = data.search("bellina", 5);
+        assert_eq!(bellina[0].name, "Phalaenopsis bellina");
+        assert!(bellina[0].native_latitude.is_some());
+        assert_eq!(bellina[0].care.as_ref().map(|c| c.light_requirement.clone()), Some(LightRequirement::Low));
+
+        assert_eq!(data.find("phalaenopsis  BELLINA").map(|s| s.name.as_str()), Some("Phalaenopsis bellina"));
+        assert!(data.search("", 5).is_empty());
+        assert!(data.search("zzz", 5).is_empty());
+    }
+}
@@ -39,6 +39,22 @@ pub fn test_orchid() -> Orchid {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     }
 }
 
@@ -87,10 +103,14 @@ pub fn test_climate_snapshot() -> ClimateSnapshot {
         avg_humidity_pct: 55.0,
         avg_vpd_kpa: crate::watering::REFERENCE_VPD_KPA,
         precipitation_48h_mm: None,
+        forecast_precipitation_24h_mm: None,
+        forecast_max_temp_c: None,
         newest_reading_at: chrono::Utc::now(),
         reading_count: 10,
         quality: DataQuality::Fresh,
         is_outdoor: false,
+        peak_lux: None,
+        peak_ppfd: None,
     }
 }
 
@@ -102,10 +122,14 @@ pub fn test_climate_snapshot_hot_dry() -> ClimateSnapshot {
         avg_humidity_pct: 30.0,
         avg_vpd_kpa: 2.97,
         precipitation_48h_mm: None,
+        forecast_precipitation_24h_mm: None,
+        forecast_max_temp_c: None,
         newest_reading_at: chrono::Utc::now(),
         reading_count: 10,
         quality: DataQuality::Fresh,
         is_outdoor: false,
+        peak_lux: None,
+        peak_ppfd: None,
     }
 }
 
@@ -117,9 +141,13 @@ pub fn test_climate_snapshot_rainy() -> ClimateSnapshot {
         avg_humidity_pct: 85.0,
         avg_vpd_kpa: 0.31,
         precipitation_48h_mm: Some(25.0),
+        forecast_precipitation_24h_mm: None,
+        forecast_max_temp_c: None,
         newest_reading_at: chrono::Utc::now(),
         reading_count: 48,
         quality: DataQuality::Fresh,
         is_outdoor: true,
+        peak_lux: None,
+        peak_ppfd: None,
     }
 }
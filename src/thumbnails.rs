@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// What is it? The downscaled sizes kept alongside every uploaded photo.
+/// Why does it exist? Grids and timelines show photos a few hundred pixels wide; sending the full camera original to each card wastes mobile data and slows the page.
+/// How should it be used? Build `<img>` URLs with `thumbnail_url`; the server writes both sizes on upload and creates any missing one on first request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThumbSize {
+    /// Card headers and gallery grids.
+    Small,
+    /// Journal timelines and side-by-side comparisons.
+    Medium,
+}
+
+impl ThumbSize {
+    /// Every size, smallest first.
+    pub const ALL: [ThumbSize; 2] = [ThumbSize::Small, ThumbSize::Medium];
+
+    /// The name used in URLs and storage paths.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThumbSize::Small => "sm",
+            ThumbSize::Medium => "md",
+        }
+    }
+
+    /// Parses a URL name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|size| size.as_str() == s)
+    }
+
+    /// Longest edge in pixels. Photos already smaller are re-encoded but not enlarged.
+    pub fn max_edge(&self) -> u32 {
+        match self {
+            ThumbSize::Small => 320,
+            ThumbSize::Medium => 960,
+        }
+    }
+}
+
+/// URL of a photo's thumbnail, given the stored filename (`user_dir/uuid.ext`).
+pub fn thumbnail_url(filename: &str, size: ThumbSize) -> String {
+    format!("/thumbs/{}/{}", size.as_str(), filename)
+}
+
+/// Where a photo's thumbnail is stored, relative to the image storage root: a `thumbs/<size>`
+/// folder next to the original, always as JPEG. `None` for names that could escape the root.
+pub fn thumbnail_path(filename: &str, size: ThumbSize) -> Option<String> {
+    if filename.is_empty() || filename.starts_with('/') || filename.contains('\\') || filename.split('/').any(|part| part == ".." || part.is_empty()) {
+        return None;
+    }
+    let (dir, name) = match filename.rsplit_once('/') {
+        Some((dir, name)) => (format!("{}/", dir), name),
+        None => (String::new(), filename),
+    };
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    Some(format!("{}thumbs/{}/{}.jpg", dir, size.as_str(), stem))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnail_path() {
+        assert_eq!(thumbnail_path("user_abc/1234.png", ThumbSize::Small).as_deref(), Some("user_abc/thumbs/sm/1234.jpg"));
+        assert_eq!(thumbnail_path("8_1771269993851.jpg", ThumbSize::Medium).as_deref(), Some("thumbs/md/8_1771269993851.jpg"));
+        assert_eq!(thumbnail_path("../secret.jpg", ThumbSize::Small), None);
+        assert_eq!(thumbnail_path("/etc/passwd", ThumbSize::Small), None);
+        assert_eq!(thumbnail_url("user_abc/1234.png", ThumbSize::Small), "/thumbs/sm/user_abc/1234.png");
+    }
+}
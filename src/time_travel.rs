@@ -0,0 +1,225 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::orchid::{LightRequirement, LogEntry, Orchid, PotMedium, PotSize, PotType, WateringMethod};
+
+/// Journal entries shown with a reconstructed plant, newest first.
+pub const RECENT_ENTRY_LIMIT: usize = 5;
+
+/// **What is it?**
+/// A plant's care profile (where it lived, what it was potted in, how often it was watered) from one point in time.
+///
+/// **Why does it exist?**
+/// It exists so "what was this plant's setup in March?" can be answered after the plant has been moved or repotted.
+///
+/// **How should it be used?**
+/// Revisions are recorded by the database whenever these fields change (see `orchid_revision`); pass them to `reconstruct` to pick the one in force on a date.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CareRevision {
+    /// When this profile took effect.
+    pub changed_at: DateTime<Utc>,
+    /// The zone the plant was in.
+    pub placement: String,
+    /// The watering interval, in days.
+    pub water_frequency_days: u32,
+    /// The light tier.
+    pub light_requirement: LightRequirement,
+    /// The potting medium.
+    pub pot_medium: Option<PotMedium>,
+    /// The pot type.
+    pub pot_type: Option<PotType>,
+    /// The pot size.
+    pub pot_size: Option<PotSize>,
+    /// The fertilizer interval, in days.
+    pub fertilize_frequency_days: Option<u32>,
+    /// The fertilizer used.
+    pub fertilizer_type: Option<String>,
+}
+
+impl CareRevision {
+    /// The profile a plant has now, as if it took effect at `changed_at`.
+    pub fn from_orchid(orchid: &Orchid, changed_at: DateTime<Utc>) -> Self {
+        Self {
+            changed_at,
+            placement: orchid.placement.clone(),
+            water_frequency_days: orchid.water_frequency_days,
+            light_requirement: orchid.light_requirement.clone(),
+            pot_medium: orchid.pot_medium.clone(),
+            pot_type: orchid.pot_type.clone(),
+            pot_size: orchid.pot_size.clone(),
+            fertilize_frequency_days: orchid.fertilize_frequency_days,
+            fertilizer_type: orchid.fertilizer_type.clone(),
+        }
+    }
+}
+
+/// **What is it?**
+/// A plant as it stood on a past date: its care profile then, its last care events before that date, and the journal leading up to it.
+///
+/// **Why does it exist?**
+/// It exists for post-mortems on plants that declined, where the question is what the setup and routine were at the time, not now.
+///
+/// **How should it be used?**
+/// Build with `reconstruct` (the server functions in `server_fns::time_travel` do this) and render read-only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlantAsOf {
+    /// The plant's record ID.
+    pub orchid_id: String,
+    /// The plant's current name.
+    pub name: String,
+    /// The plant's current species.
+    pub species: String,
+    /// The moment reconstructed.
+    pub as_of: DateTime<Utc>,
+    /// The care profile in force at `as_of`.
+    pub profile: CareRevision,
+    /// False when no revision predates `as_of`, so the earliest known profile stands in.
+    pub profile_recorded: bool,
+    /// Last watering at or before `as_of`.
+    pub last_watered_at: Option<DateTime<Utc>>,
+    /// Last fertilizing at or before `as_of`.
+    pub last_fertilized_at: Option<DateTime<Utc>>,
+    /// Last repotting at or before `as_of`.
+    pub last_repotted_at: Option<DateTime<Utc>>,
+    /// Last bloom entry at or before `as_of`.
+    pub last_bloom_at: Option<DateTime<Utc>>,
+    /// Journal entries up to `as_of`, newest first, at most `RECENT_ENTRY_LIMIT`.
+    pub recent_entries: Vec<LogEntry>,
+}
+
+impl PlantAsOf {
+    /// Days between the last watering and `as_of`.
+    pub fn days_since_watered(&self) -> Option<i64> {
+        self.last_watered_at.map(|at| (self.as_of - at).num_days())
+    }
+
+    /// Whether watering was past due at `as_of`, by the interval in force then.
+    pub fn was_overdue(&self) -> bool {
+        self.days_since_watered()
+            .is_some_and(|days| days > self.profile.water_frequency_days as i64)
+    }
+}
+
+/// The latest entry of `event_type` at or before `as_of`.
+fn last_event(entries: &[LogEntry], event_type: &str, as_of: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    entries.iter()
+        .filter(|e| e.timestamp <= as_of && e.event_type.as_deref() == Some(event_type))
+        .map(|e| e.timestamp)
+        .max()
+}
+
+/// The latest watering at or before `as_of` that restarted the countdown (not a passing misting).
+fn last_watering(entries: &[LogEntry], default: Option<WateringMethod>, as_of: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    entries.iter()
+        .filter(|e| e.timestamp <= as_of && e.event_type.as_deref() == Some("Watered"))
+        .filter(|e| WateringMethod::resets_schedule(e.watering_method, default))
+        .map(|e| e.timestamp)
+        .max()
+}
+
+/// **What is it?**
+/// A pure function that reconstructs a plant's state on a past date from its care-profile revisions and journal.
+///
+/// **Why does it exist?**
+/// It exists so the reconstruction rules live in one testable place, shared by the plant and collection views.
+///
+/// **How should it be used?**
+/// Pass the plant as it is now, all its revisions and journal entries (any order), and the date. Without a revision before `as_of`, the earliest revision (or the current profile) is used and `profile_recorded` is false.
+pub fn reconstruct(orchid: &Orchid, revisions: &[CareRevision], entries: &[LogEntry], as_of: DateTime<Utc>) -> PlantAsOf {
+    let in_force = revisions.iter()
+        .filter(|r| r.changed_at <= as_of)
+        .max_by_key(|r| r.changed_at);
+    let (profile, profile_recorded) = match in_force {
+        Some(r) => (r.clone(), true),
+        None => (
+            revisions.iter().min_by_key(|r| r.changed_at).cloned()
+                .unwrap_or_else(|| CareRevision::from_orchid(orchid, as_of)),
+            false,
+        ),
+    };
+
+    let mut recent_entries: Vec<LogEntry> = entries.iter()
+        .filter(|e| e.timestamp <= as_of)
+        .cloned()
+        .collect();
+    recent_entries.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+    recent_entries.truncate(RECENT_ENTRY_LIMIT);
+
+    PlantAsOf {
+        orchid_id: orchid.id.clone(),
+        name: orchid.name.clone(),
+        species: orchid.species.clone(),
+        as_of,
+        profile,
+        profile_recorded,
+        last_watered_at: last_watering(entries, orchid.default_watering_method, as_of),
+        last_fertilized_at: last_event(entries, "Fertilized", as_of),
+        last_repotted_at: last_event(entries, "Repotted", as_of),
+        last_bloom_at: last_event(entries, "Flowering", as_of),
+        recent_entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use crate::test_helpers::test_orchid;
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, d, 12, 0, 0).unwrap()
+    }
+
+    fn entry(at: DateTime<Utc>, event_type: &str) -> LogEntry {
+        LogEntry {
+            id: format!("log_entry:{}", at.timestamp()),
+            timestamp: at,
+            note: event_type.to_string(),
+            image_filename: None,
+            event_type: Some(event_type.to_string()),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
+        }
+    }
+
+    #[test]
+    fn test_reconstruct_picks_revision_in_force() {
+        let orchid = test_orchid();
+        let moved = CareRevision { placement: "Windowsill".into(), ..CareRevision::from_orchid(&orchid, day(10)) };
+        let original = CareRevision { placement: "Tent".into(), ..CareRevision::from_orchid(&orchid, day(1)) };
+        let revisions = [moved, original];
+
+        let state = reconstruct(&orchid, &revisions, &[], day(5));
+        assert!(state.profile_recorded);
+        assert_eq!(state.profile.placement, "Tent");
+        assert_eq!(reconstruct(&orchid, &revisions, &[], day(20)).profile.placement, "Windowsill");
+
+        // Before any revision: the earliest stands in
+        let early = reconstruct(&orchid, &revisions, &[], day(1) - Duration::days(3));
+        assert!(!early.profile_recorded);
+        assert_eq!(early.profile.placement, "Tent");
+    }
+
+    #[test]
+    fn test_reconstruct_ignores_later_journal_entries() {
+        let orchid = test_orchid();
+        let entries = [
+            entry(day(2), "Watered"),
+            entry(day(3), "Fertilized"),
+            entry(day(15), "Watered"),
+            entry(day(16), "Repotted"),
+        ];
+        let state = reconstruct(&orchid, &[], &entries, day(12));
+        assert_eq!(state.last_watered_at, Some(day(2)));
+        assert_eq!(state.last_fertilized_at, Some(day(3)));
+        assert_eq!(state.last_repotted_at, None);
+        assert_eq!(state.recent_entries.len(), 2);
+        assert_eq!(state.recent_entries[0].timestamp, day(3));
+        // 10 days since watering on a 7-day interval
+        assert!(state.was_overdue());
+    }
+}
@@ -0,0 +1,91 @@
+/// Most photos in one time-lapse; longer histories are sampled evenly.
+pub const MAX_TIMELAPSE_FRAMES: usize = 60;
+/// Longest edge of a time-lapse frame, in pixels.
+pub const TIMELAPSE_EDGE: u32 = 480;
+/// Shortest time each photo stays on screen, in milliseconds.
+pub const MIN_FRAME_MS: u32 = 100;
+/// Longest time each photo stays on screen, in milliseconds.
+pub const MAX_FRAME_MS: u32 = 3000;
+/// Frame time the maker starts with.
+pub const DEFAULT_FRAME_MS: u32 = 500;
+
+/// Height of a glyph in `text_mask`, in pixels before scaling.
+pub const GLYPH_HEIGHT: u32 = 7;
+const GLYPH_WIDTH: u32 = 5;
+
+/// Picks at most `max` items spread evenly from first to last, keeping both ends so the
+/// time-lapse still spans the plant's whole history.
+pub fn sample_frames<T: Clone>(items: &[T], max: usize) -> Vec<T> {
+    if items.len() <= max {
+        return items.to_vec();
+    }
+    if max < 2 {
+        return items.iter().take(max).cloned().collect();
+    }
+    let last = items.len() - 1;
+    (0..max).map(|i| items[i * last / (max - 1)].clone()).collect()
+}
+
+/// Rows of a 5x7 glyph, high bit on the left. Covers what date captions need.
+fn glyph(c: char) -> [u8; 7] {
+    match c {
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        '-' => [0, 0, 0, 0b11111, 0, 0, 0],
+        '/' => [0b00001, 0b00010, 0b00010, 0b00100, 0b01000, 0b01000, 0b10000],
+        _ => [0; 7],
+    }
+}
+
+/// Lays `text` out in the built-in pixel font: width, height and a row-major mask of lit pixels.
+/// Characters other than digits, `-` and `/` render as blanks.
+pub fn text_mask(text: &str) -> (u32, u32, Vec<bool>) {
+    let count = text.chars().count() as u32;
+    if count == 0 {
+        return (0, GLYPH_HEIGHT, Vec::new());
+    }
+    let width = count * (GLYPH_WIDTH + 1) - 1;
+    let mut mask = vec![false; (width * GLYPH_HEIGHT) as usize];
+    for (n, c) in text.chars().enumerate() {
+        let left = n as u32 * (GLYPH_WIDTH + 1);
+        for (y, row) in glyph(c).iter().enumerate() {
+            for x in 0..GLYPH_WIDTH {
+                if row & (1 << (GLYPH_WIDTH - 1 - x)) != 0 {
+                    mask[y * width as usize + (left + x) as usize] = true;
+                }
+            }
+        }
+    }
+    (width, GLYPH_HEIGHT, mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_frames_keeps_ends() {
+        let items: Vec<u32> = (0..100).collect();
+        let sampled = sample_frames(&items, 5);
+        assert_eq!(sampled, vec![0, 24, 49, 74, 99]);
+        assert_eq!(sample_frames(&items[..3], 5), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_text_mask() {
+        let (w, h, mask) = text_mask("1-");
+        assert_eq!((w, h), (11, 7));
+        // Top of the "1" stem, and the dash across the middle row
+        assert!(mask[2]);
+        assert!((6..11).all(|x| mask[3 * 11 + x]));
+        assert!(!mask[6]);
+    }
+}
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+
+/// One step of the getting-started tour, in the order they are shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TourStep {
+    /// Set up a growing space in settings.
+    AddZone,
+    /// Run the climate wizard for a zone without sensor data.
+    EstimateClimate,
+    /// Add the first plant.
+    AddPlant,
+    /// Log the first watering from a plant card.
+    LogWatering,
+}
+
+impl TourStep {
+    /// Every step, in tour order.
+    pub const ALL: [TourStep; 4] = [
+        TourStep::AddZone,
+        TourStep::EstimateClimate,
+        TourStep::AddPlant,
+        TourStep::LogWatering,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TourStep::AddZone => "AddZone",
+            TourStep::EstimateClimate => "EstimateClimate",
+            TourStep::AddPlant => "AddPlant",
+            TourStep::LogWatering => "LogWatering",
+        }
+    }
+
+    /// Parses a stored name; unknown names (from removed steps) are ignored.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|step| step.as_str() == s)
+    }
+
+    /// The `data-tour` attribute value of the element the hint points at.
+    pub fn anchor(&self) -> &'static str {
+        match self {
+            TourStep::AddZone => "add-zone",
+            TourStep::EstimateClimate => "estimate-climate",
+            TourStep::AddPlant => "add-plant",
+            TourStep::LogWatering => "log-watering",
+        }
+    }
+
+    /// Short heading for the hint and checklist.
+    pub fn title(&self) -> &'static str {
+        match self {
+            TourStep::AddZone => "Set up a growing zone",
+            TourStep::EstimateClimate => "Estimate its climate",
+            TourStep::AddPlant => "Add your first plant",
+            TourStep::LogWatering => "Log a watering",
+        }
+    }
+
+    /// What to do, and what it unlocks.
+    pub fn body(&self) -> &'static str {
+        match self {
+            TourStep::AddZone => "Zones are the windowsills, tents and greenhouses your plants live in. Add or edit them under Settings \u{2192} Zones.",
+            TourStep::EstimateClimate => "No sensor yet? Answer a few questions and we'll estimate temperature and humidity so watering schedules and alerts can adapt.",
+            TourStep::AddPlant => "Add a plant by name, or use ID Plant to identify one from a photo. Care needs are filled in for you.",
+            TourStep::LogWatering => "Tap Water on a plant card after watering. The schedule, overdue reminders and journal all build on this.",
+        }
+    }
+
+    /// Whether the user has already done this step, judging by their data.
+    pub fn is_done(&self, facts: &TourFacts) -> bool {
+        match self {
+            TourStep::AddZone => facts.zone_count > 0,
+            TourStep::EstimateClimate => facts.has_climate_data,
+            TourStep::AddPlant => facts.plant_count > 0,
+            TourStep::LogWatering => facts.has_watering,
+        }
+    }
+}
+
+/// What the user's collection already contains, used to tick off tour steps they did on their own.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TourFacts {
+    /// Number of growing zones.
+    pub zone_count: usize,
+    /// Whether any zone has a climate reading (estimated, manual or live).
+    pub has_climate_data: bool,
+    /// Number of plants.
+    pub plant_count: usize,
+    /// Whether any plant has a watering logged.
+    pub has_watering: bool,
+}
+
+/// **What is it?**
+/// A user's progress through the getting-started tour: steps they finished or skipped, and whether they dismissed it.
+///
+/// **Why does it exist?**
+/// It exists so the tour picks up where the user left off on any device, and stays gone once dismissed or finished.
+///
+/// **How should it be used?**
+/// Load with `server_fns::preferences::get_tour_progress`, ask `current_step` which hint to show, and save after `complete` or `dismiss`. Saving the default restarts the tour.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TourProgress {
+    /// Steps the user finished or skipped past.
+    pub completed: Vec<TourStep>,
+    /// True once the user closed the tour or finished every step.
+    pub dismissed: bool,
+}
+
+impl TourProgress {
+    /// Whether a step counts as done: marked complete, or already achieved in the user's data.
+    pub fn step_done(&self, step: TourStep, facts: &TourFacts) -> bool {
+        self.completed.contains(&step) || step.is_done(facts)
+    }
+
+    /// The first step still to do, or `None` when the tour is dismissed or complete.
+    pub fn current_step(&self, facts: &TourFacts) -> Option<TourStep> {
+        if self.dismissed {
+            return None;
+        }
+        TourStep::ALL.into_iter().find(|step| !self.step_done(*step, facts))
+    }
+
+    /// Number of steps done, for the "2 of 4" indicator.
+    pub fn done_count(&self, facts: &TourFacts) -> usize {
+        TourStep::ALL.iter().filter(|step| self.step_done(**step, facts)).count()
+    }
+
+    /// Marks a step complete (idempotent). Finishing the last step dismisses the tour.
+    pub fn complete(&mut self, step: TourStep, facts: &TourFacts) {
+        if !self.completed.contains(&step) {
+            self.completed.push(step);
+        }
+        if self.current_step(facts).is_none() {
+            self.dismissed = true;
+        }
+    }
+
+    /// Closes the tour for good.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_step_skips_steps_already_done_in_data() {
+        let progress = TourProgress::default();
+        let facts = TourFacts { zone_count: 2, ..Default::default() };
+        assert_eq!(progress.current_step(&facts), Some(TourStep::EstimateClimate));
+
+        let facts = TourFacts { zone_count: 1, has_climate_data: true, plant_count: 1, has_watering: false };
+        assert_eq!(progress.current_step(&facts), Some(TourStep::LogWatering));
+        assert_eq!(progress.done_count(&facts), 3);
+    }
+
+    #[test]
+    fn test_completing_last_step_dismisses_tour() {
+        let facts = TourFacts { zone_count: 1, has_climate_data: true, plant_count: 1, has_watering: false };
+        let mut progress = TourProgress::default();
+        progress.complete(TourStep::LogWatering, &facts);
+        progress.complete(TourStep::LogWatering, &facts);
+        assert_eq!(progress.completed, vec![TourStep::LogWatering]);
+        assert!(progress.dismissed);
+        assert_eq!(progress.current_step(&facts), None);
+    }
+
+    #[test]
+    fn test_dismissed_tour_and_stored_names() {
+        let mut progress = TourProgress::default();
+        assert_eq!(progress.current_step(&TourFacts::default()), Some(TourStep::AddZone));
+        progress.dismiss();
+        assert_eq!(progress.current_step(&TourFacts::default()), None);
+        for step in TourStep::ALL {
+            assert_eq!(TourStep::parse(step.as_str()), Some(step));
+        }
+        assert_eq!(TourStep::parse("Removed"), None);
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Longest pest or disease name accepted, matching the schema assertion.
+pub const MAX_PROBLEM_LEN: usize = 100;
+/// Longest product name accepted, matching the schema assertion.
+pub const MAX_PRODUCT_LEN: usize = 100;
+/// Longest dose accepted, matching the schema assertion.
+pub const MAX_DOSE_LEN: usize = 100;
+/// Longest gap between applications, in days.
+pub const MAX_TREATMENT_INTERVAL_DAYS: u32 = 90;
+/// Most applications one treatment can plan.
+pub const MAX_TREATMENT_APPLICATIONS: u32 = 12;
+
+/// The journal event type written for each application.
+pub const TREATMENT_EVENT: &str = "PestTreatment";
+
+/// Whether a treatment is fighting an insect or mite, or a rot, fungus or virus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProblemKind {
+    /// Mealybug, scale, spider mite, thrips and the like.
+    Pest,
+    /// Crown rot, black rot, botrytis, leaf spot and the like.
+    Disease,
+}
+
+impl ProblemKind {
+    /// Every kind, in the order shown in pickers.
+    pub const ALL: [ProblemKind; 2] = [ProblemKind::Pest, ProblemKind::Disease];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProblemKind::Pest => "Pest",
+            ProblemKind::Disease => "Disease",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+
+    /// Display label for pickers.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProblemKind::Pest => "Pest",
+            ProblemKind::Disease => "Disease",
+        }
+    }
+
+    /// Icon for cards and lists.
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            ProblemKind::Pest => "\u{1F41B}",
+            ProblemKind::Disease => "\u{1F344}",
+        }
+    }
+
+    /// Example problem shown as the picker's placeholder.
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            ProblemKind::Pest => "e.g. Mealybug",
+            ProblemKind::Disease => "e.g. Crown rot",
+        }
+    }
+}
+
+/// A course of treatment for one plant: the problem, what it's treated with, and the
+/// re-treatment series (every `interval_days`, `planned_applications` times in all).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Treatment {
+    /// The treatment record ID.
+    pub id: String,
+    /// The plant being treated.
+    pub orchid_id: String,
+    /// Pest or disease.
+    pub kind: ProblemKind,
+    /// What the plant has, such as "Mealybug".
+    pub problem: String,
+    /// What it's treated with, such as "Neem oil".
+    pub product: String,
+    /// How much, such as "5 ml/L"; free text since products are dosed differently.
+    pub dose: Option<String>,
+    /// Days between applications.
+    pub interval_days: u32,
+    /// How many applications the series has, the first included.
+    pub planned_applications: u32,
+    /// How many have been applied so far.
+    pub applications: u32,
+    /// When the first application was made.
+    pub started_at: DateTime<Utc>,
+    /// When the latest application was made.
+    pub last_applied_at: DateTime<Utc>,
+    /// When the problem was marked gone; None while the treatment is open.
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Where a treatment stands, for the card badge and the treatments list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreatmentStatus {
+    /// The next application is due today or overdue by this many days.
+    Due {
+        /// Days past the due date; 0 when due today.
+        overdue_days: i64,
+    },
+    /// The next application is this many days away.
+    Upcoming {
+        /// Days until the next application.
+        in_days: i64,
+    },
+    /// Every planned application is done; waiting to see whether the problem is gone.
+    Watching,
+    /// The problem is gone.
+    Resolved,
+}
+
+impl Treatment {
+    /// Whether the problem has been marked gone.
+    pub fn is_resolved(&self) -> bool {
+        self.resolved_at.is_some()
+    }
+
+    /// When the next application is due. None once the series is done or the treatment is resolved.
+    pub fn next_due_at(&self) -> Option<DateTime<Utc>> {
+        (!self.is_resolved() && self.applications < self.planned_applications)
+            .then(|| self.last_applied_at + Duration::days(self.interval_days as i64))
+    }
+
+    /// Where the treatment stands at `now`.
+    pub fn status(&self, now: DateTime<Utc>) -> TreatmentStatus {
+        if self.is_resolved() {
+            return TreatmentStatus::Resolved;
+        }
+        match self.next_due_at() {
+            None => TreatmentStatus::Watching,
+            Some(due) if due.date_naive() <= now.date_naive() => {
+                TreatmentStatus::Due { overdue_days: (now.date_naive() - due.date_naive()).num_days() }
+            }
+            Some(due) => TreatmentStatus::Upcoming { in_days: (due.date_naive() - now.date_naive()).num_days() },
+        }
+    }
+
+    /// Short status text, e.g. "Re-treat in 3 days" or "Re-treat 2 days overdue".
+    pub fn status_label(&self, now: DateTime<Utc>) -> String {
+        match self.status(now) {
+            TreatmentStatus::Due { overdue_days: 0 } => "Re-treat today".to_string(),
+            TreatmentStatus::Due { overdue_days: 1 } => "Re-treat 1 day overdue".to_string(),
+            TreatmentStatus::Due { overdue_days } => format!("Re-treat {} days overdue", overdue_days),
+            TreatmentStatus::Upcoming { in_days: 1 } => "Re-treat tomorrow".to_string(),
+            TreatmentStatus::Upcoming { in_days } => format!("Re-treat in {} days", in_days),
+            TreatmentStatus::Watching => "Watching".to_string(),
+            TreatmentStatus::Resolved => "Resolved".to_string(),
+        }
+    }
+
+    /// Progress through the series, e.g. "2 of 3".
+    pub fn progress_label(&self) -> String {
+        format!("{} of {}", self.applications, self.planned_applications)
+    }
+
+    /// Title of the reminder for the next application, e.g. "Re-treat mealybug with Neem oil (2 of 3)".
+    pub fn reminder_title(&self) -> String {
+        format!(
+            "Re-treat {} with {} ({} of {})",
+            self.problem.to_lowercase(), self.product, self.applications + 1, self.planned_applications,
+        )
+    }
+}
+
+/// Picks the open treatment each orchid card shows: the one whose next application is soonest,
+/// then the most recently started.
+pub fn treatment_badges(treatments: &[Treatment]) -> HashMap<String, Treatment> {
+    let mut badges: HashMap<String, Treatment> = HashMap::new();
+    for t in treatments.iter().filter(|t| !t.is_resolved()) {
+        let rank = |t: &Treatment| (t.next_due_at().unwrap_or(DateTime::<Utc>::MAX_UTC), std::cmp::Reverse(t.started_at));
+        match badges.get(&t.orchid_id) {
+            Some(current) if rank(current) <= rank(t) => {}
+            _ => {
+                badges.insert(t.orchid_id.clone(), t.clone());
+            }
+        }
+    }
+    badges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn treatment(orchid: &str, problem: &str, applications: u32, last_applied_days_ago: i64) -> Treatment {
+        let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+        Treatment {
+            id: format!("treatment:{}", problem),
+            orchid_id: orchid.into(),
+            kind: ProblemKind::Pest,
+            problem: problem.into(),
+            product: "Neem oil".into(),
+            dose: None,
+            interval_days: 7,
+            planned_applications: 3,
+            applications,
+            started_at: now - Duration::days(30),
+            last_applied_at: now - Duration::days(last_applied_days_ago),
+            resolved_at: None,
+        }
+    }
+
+    #[test]
+    fn test_status_through_the_series() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+        assert_eq!(treatment("orchid:a", "Mealybug", 1, 2).status(now), TreatmentStatus::Upcoming { in_days: 5 });
+        assert_eq!(treatment("orchid:a", "Mealybug", 1, 7).status(now), TreatmentStatus::Due { overdue_days: 0 });
+        assert_eq!(treatment("orchid:a", "Mealybug", 2, 9).status(now), TreatmentStatus::Due { overdue_days: 2 });
+        assert_eq!(treatment("orchid:a", "Mealybug", 3, 9).status(now), TreatmentStatus::Watching);
+        assert_eq!(treatment("orchid:a", "Mealybug", 3, 9).next_due_at(), None);
+
+        let mut resolved = treatment("orchid:a", "Mealybug", 1, 9);
+        resolved.resolved_at = Some(now);
+        assert_eq!(resolved.status(now), TreatmentStatus::Resolved);
+        assert_eq!(resolved.next_due_at(), None);
+    }
+
+    #[test]
+    fn test_labels() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+        let t = treatment("orchid:a", "Mealybug", 1, 6);
+        assert_eq!(t.status_label(now), "Re-treat tomorrow");
+        assert_eq!(t.progress_label(), "1 of 3");
+        assert_eq!(t.reminder_title(), "Re-treat mealybug with Neem oil (2 of 3)");
+    }
+
+    #[test]
+    fn test_badge_prefers_soonest_open_treatment() {
+        let mut resolved = treatment("orchid:b", "Scale", 1, 1);
+        resolved.resolved_at = Some(Utc::now());
+        let treatments = vec![
+            treatment("orchid:a", "Mealybug", 1, 1),
+            treatment("orchid:a", "Thrips", 1, 5),
+            treatment("orchid:a", "Spider mite", 3, 10),
+            resolved,
+        ];
+        let badges = treatment_badges(&treatments);
+        assert_eq!(badges["orchid:a"].problem, "Thrips");
+        assert!(!badges.contains_key("orchid:b"));
+    }
+
+    #[test]
+    fn test_kind_roundtrip() {
+        for k in ProblemKind::ALL {
+            assert_eq!(ProblemKind::parse(k.as_str()), Some(k));
+        }
+        assert_eq!(ProblemKind::parse("Weed"), None);
+    }
+}
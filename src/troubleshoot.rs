@@ -0,0 +1,399 @@
+/// The question every troubleshooting session starts with.
+pub const START_QUESTION: &str = "symptom";
+
+/// Where an answer leads: another question, or the likely causes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Next {
+    /// Ask the question with this ID next.
+    Ask(&'static str),
+    /// Stop and show these causes, most likely first.
+    Diagnose(&'static [&'static str]),
+}
+
+/// One answer button under a question.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Answer {
+    /// The button label, also quoted in the diagnosis journal entry.
+    pub label: &'static str,
+    /// Where the answer leads.
+    pub next: Next,
+}
+
+/// One step of the decision tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Question {
+    /// Stable ID, referenced by `Next::Ask`.
+    pub id: &'static str,
+    /// What the grower is asked to check.
+    pub prompt: &'static str,
+    /// The possible answers, in display order.
+    pub answers: &'static [Answer],
+}
+
+/// A likely cause of the symptoms, with what to do about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cause {
+    /// Stable ID, referenced by `Next::Diagnose`.
+    pub id: &'static str,
+    /// Short name, e.g. "Root rot".
+    pub name: &'static str,
+    /// Why the symptoms point here.
+    pub explanation: &'static str,
+    /// Recommended actions, in the order to do them.
+    pub actions: &'static [&'static str],
+    /// Days until the plant should be checked again.
+    pub follow_up_days: u32,
+}
+
+const QUESTIONS: &[Question] = &[
+    Question {
+        id: "symptom",
+        prompt: "What worries you most about this plant?",
+        answers: &[
+            Answer { label: "Leaves are wrinkled, limp, or leathery", next: Next::Ask("roots") },
+            Answer { label: "Leaves are turning yellow", next: Next::Ask("yellowing") },
+            Answer { label: "Spots or patches on the leaves", next: Next::Ask("spots") },
+            Answer { label: "Buds shrivel or drop before opening", next: Next::Diagnose(&["bud_blast"]) },
+            Answer { label: "Sticky residue, webbing, or tiny insects", next: Next::Ask("pests") },
+            Answer { label: "The crown or base of the leaves is soft or black", next: Next::Diagnose(&["crown_rot", "bacterial_rot"]) },
+        ],
+    },
+    Question {
+        id: "roots",
+        prompt: "Take the plant out of its pot if you can. What do the roots look like?",
+        answers: &[
+            Answer { label: "Firm, silver when dry or green when wet", next: Next::Ask("medium") },
+            Answer { label: "Brown, mushy, or hollow when squeezed", next: Next::Diagnose(&["root_rot", "overwatering"]) },
+            Answer { label: "Dry, papery, and brittle", next: Next::Diagnose(&["underwatering", "low_humidity"]) },
+        ],
+    },
+    Question {
+        id: "medium",
+        prompt: "How does the potting medium feel two days after watering?",
+        answers: &[
+            Answer { label: "Bone dry", next: Next::Diagnose(&["underwatering", "low_humidity"]) },
+            Answer { label: "Slightly damp", next: Next::Diagnose(&["low_humidity", "heat_stress"]) },
+            Answer { label: "Soggy, compacted, or smells sour", next: Next::Diagnose(&["broken_down_medium", "root_rot"]) },
+        ],
+    },
+    Question {
+        id: "yellowing",
+        prompt: "Which leaves are yellowing?",
+        answers: &[
+            Answer { label: "Only the oldest, lowest leaf, slowly", next: Next::Diagnose(&["natural_senescence"]) },
+            Answer { label: "Several leaves at once, soft to the touch", next: Next::Diagnose(&["overwatering", "root_rot"]) },
+            Answer { label: "Bleached patches on the side facing the light", next: Next::Diagnose(&["sunburn"]) },
+            Answer { label: "New leaves come in pale all over", next: Next::Diagnose(&["nutrient_deficiency", "too_much_light"]) },
+        ],
+    },
+    Question {
+        id: "spots",
+        prompt: "What do the spots look like?",
+        answers: &[
+            Answer { label: "Wet-looking, brown or black, and spreading fast", next: Next::Diagnose(&["bacterial_rot"]) },
+            Answer { label: "Dry, sunken, tan or black with a yellow halo", next: Next::Diagnose(&["fungal_leaf_spot"]) },
+            Answer { label: "White or tan patches that appeared after a sunny day", next: Next::Diagnose(&["sunburn"]) },
+            Answer { label: "Fine silvery stippling", next: Next::Diagnose(&["spider_mites"]) },
+        ],
+    },
+    Question {
+        id: "pests",
+        prompt: "What do you see up close?",
+        answers: &[
+            Answer { label: "White cottony fluff in leaf joints or on roots", next: Next::Diagnose(&["mealybugs"]) },
+            Answer { label: "Hard brown or white bumps stuck to leaves", next: Next::Diagnose(&["scale"]) },
+            Answer { label: "Fine webbing and stippled leaves", next: Next::Diagnose(&["spider_mites"]) },
+            Answer { label: "Clusters of small green or black insects on buds", next: Next::Diagnose(&["aphids"]) },
+        ],
+    },
+];
+
+const CAUSES: &[Cause] = &[
+    Cause {
+        id: "underwatering",
+        name: "Underwatering",
+        explanation: "Healthy-looking but dry roots and a dry medium mean the plant isn't getting water often enough to keep its leaves turgid.",
+        actions: &[
+            "Soak the pot for 15 minutes, then let it drain completely.",
+            "Shorten the watering interval by a few days and watch the roots between waterings.",
+        ],
+        follow_up_days: 7,
+    },
+    Cause {
+        id: "low_humidity",
+        name: "Low humidity",
+        explanation: "Leaves lose water faster than the roots can replace it when the air is dry, even with regular watering.",
+        actions: &[
+            "Raise humidity with a tray, humidifier, or by grouping plants.",
+            "Check the zone's humidity readings against the species' range.",
+        ],
+        follow_up_days: 14,
+    },
+    Cause {
+        id: "heat_stress",
+        name: "Heat stress",
+        explanation: "High temperatures drive transpiration past what the roots can supply, so leaves wilt even in damp media.",
+        actions: &[
+            "Move the plant away from heat sources or hot glass.",
+            "Improve air movement and check the zone's maximum temperatures.",
+        ],
+        follow_up_days: 7,
+    },
+    Cause {
+        id: "overwatering",
+        name: "Overwatering",
+        explanation: "Roots that sit wet can't breathe; they die back and the plant dehydrates from the bottom up.",
+        actions: &[
+            "Let the medium dry out more between waterings.",
+            "Make sure the pot drains freely and never stands in water.",
+        ],
+        follow_up_days: 10,
+    },
+    Cause {
+        id: "root_rot",
+        name: "Root rot",
+        explanation: "Mushy, hollow roots have rotted away and can no longer take up water, so the leaves wrinkle even when watered.",
+        actions: &[
+            "Unpot and cut away every soft or hollow root with sterile scissors.",
+            "Repot into fresh, chunky medium in a pot that fits the remaining roots.",
+            "Hold off watering for a few days and keep humidity high while new roots form.",
+        ],
+        follow_up_days: 14,
+    },
+    Cause {
+        id: "broken_down_medium",
+        name: "Broken-down potting medium",
+        explanation: "Bark and moss decompose over time, packing tight and staying wet, which suffocates roots.",
+        actions: &[
+            "Repot into fresh medium, trimming any dead roots.",
+            "Log the repot so the next one is scheduled.",
+        ],
+        follow_up_days: 21,
+    },
+    Cause {
+        id: "natural_senescence",
+        name: "Normal leaf aging",
+        explanation: "Orchids shed their oldest leaf now and then, especially after blooming or in a new growth cycle.",
+        actions: &[
+            "Let the leaf yellow fully and fall off on its own.",
+            "No change in care is needed if new growth looks healthy.",
+        ],
+        follow_up_days: 30,
+    },
+    Cause {
+        id: "sunburn",
+        name: "Sunburn",
+        explanation: "Sudden bleached or tan patches on the side facing the light are scorched tissue.",
+        actions: &[
+            "Move the plant back from the window or add shade cloth.",
+            "Leave the damaged tissue in place unless it starts to rot.",
+        ],
+        follow_up_days: 14,
+    },
+    Cause {
+        id: "too_much_light",
+        name: "Too much light",
+        explanation: "Pale, yellow-green new leaves can mean the plant gets more light than the species likes.",
+        actions: &[
+            "Compare the zone's light level with the species' light requirement.",
+            "Move the plant somewhere with slightly less direct light.",
+        ],
+        follow_up_days: 30,
+    },
+    Cause {
+        id: "nutrient_deficiency",
+        name: "Nutrient deficiency",
+        explanation: "Uniformly pale new growth often means the plant is short of nitrogen or magnesium.",
+        actions: &[
+            "Fertilize weakly but regularly during active growth.",
+            "Flush the pot with plain water monthly to prevent salt buildup.",
+        ],
+        follow_up_days: 30,
+    },
+    Cause {
+        id: "bud_blast",
+        name: "Bud blast",
+        explanation: "Buds drop when conditions change suddenly: a move, a cold draft, a heat spike, or letting the plant dry out.",
+        actions: &[
+            "Keep the plant in one spot while it's in bud.",
+            "Avoid drafts, heaters, and ripening fruit nearby.",
+            "Keep watering and humidity steady.",
+        ],
+        follow_up_days: 14,
+    },
+    Cause {
+        id: "crown_rot",
+        name: "Crown rot",
+        explanation: "Water left sitting in the crown lets rot set in where the leaves meet, and it can kill a monopodial orchid quickly.",
+        actions: &[
+            "Cut away the soft tissue with a sterile blade.",
+            "Dust the wound with cinnamon or apply a fungicide.",
+            "Water in the morning and never let water stand in the crown.",
+        ],
+        follow_up_days: 3,
+    },
+    Cause {
+        id: "bacterial_rot",
+        name: "Bacterial soft or brown rot",
+        explanation: "Wet, spreading, foul-smelling spots are usually bacterial and can move through a leaf in days.",
+        actions: &[
+            "Isolate the plant from the rest of the collection.",
+            "Cut away the affected tissue well into healthy leaf with a sterile blade.",
+            "Keep the leaves dry and increase air movement.",
+        ],
+        follow_up_days: 3,
+    },
+    Cause {
+        id: "fungal_leaf_spot",
+        name: "Fungal leaf spot",
+        explanation: "Dry, sunken spots with a yellow halo are typical of fungal infections that thrive in still, damp air.",
+        actions: &[
+            "Remove badly affected leaves.",
+            "Treat with a fungicide labelled for orchids.",
+            "Improve air circulation and water early in the day.",
+        ],
+        follow_up_days: 7,
+    },
+    Cause {
+        id: "mealybugs",
+        name: "Mealybugs",
+        explanation: "The cottony fluff is the mealybugs' wax coating; they hide in leaf joints, under sheaths, and on roots.",
+        actions: &[
+            "Isolate the plant.",
+            "Wipe off every visible bug with isopropyl alcohol on a cotton swab.",
+            "Follow up with insecticidal soap or a systemic insecticide.",
+        ],
+        follow_up_days: 7,
+    },
+    Cause {
+        id: "scale",
+        name: "Scale insects",
+        explanation: "Scale hides under a hard shell and feeds on sap, leaving yellow spots and sticky honeydew.",
+        actions: &[
+            "Isolate the plant.",
+            "Scrape off the scales and wipe the leaves with isopropyl alcohol.",
+            "Treat with horticultural oil or a systemic insecticide.",
+        ],
+        follow_up_days: 7,
+    },
+    Cause {
+        id: "spider_mites",
+        name: "Spider mites",
+        explanation: "Mites thrive in warm, dry air and leave silvery stippling and fine webbing under the leaves.",
+        actions: &[
+            "Rinse the leaves, especially the undersides, with water.",
+            "Raise humidity; mites dislike damp air.",
+            "Treat with a miticide if they come back.",
+        ],
+        follow_up_days: 7,
+    },
+    Cause {
+        id: "aphids",
+        name: "Aphids",
+        explanation: "Aphids cluster on buds and new growth and suck sap, causing distorted flowers.",
+        actions: &[
+            "Rinse them off with water.",
+            "Treat with insecticidal soap.",
+        ],
+        follow_up_days: 7,
+    },
+];
+
+/// Looks up a question by ID.
+pub fn question(id: &str) -> Option<&'static Question> {
+    QUESTIONS.iter().find(|q| q.id == id)
+}
+
+/// Looks up a cause by ID.
+pub fn cause(id: &str) -> Option<&'static Cause> {
+    CAUSES.iter().find(|c| c.id == id)
+}
+
+/// The causes a diagnosis points to, most likely first, skipping unknown IDs.
+pub fn causes(ids: &[&str]) -> Vec<&'static Cause> {
+    ids.iter().filter_map(|id| cause(id)).collect()
+}
+
+/// Days until the follow-up check: the soonest any of the causes asks for.
+pub fn follow_up_days(causes: &[&Cause]) -> Option<u32> {
+    causes.iter().map(|c| c.follow_up_days).min()
+}
+
+/// **What is it?**
+/// A function that writes the journal note for a finished troubleshooting session.
+///
+/// **Why does it exist?**
+/// It exists so the diagnosis is kept in the plant's history with the symptoms that led to it, making it easy to see later whether the recommended actions worked.
+///
+/// **How should it be used?**
+/// Pass the (question, answer) pairs in the order they were answered and the diagnosed causes; save the result as a `Diagnosis` log entry.
+pub fn diagnosis_note(path: &[(&Question, &Answer)], causes: &[&Cause]) -> String {
+    let mut note = String::from("Troubleshooting\n");
+    for (question, answer) in path {
+        note.push_str(&format!("- {} {}\n", question.prompt, answer.label));
+    }
+    if let Some((first, rest)) = causes.split_first() {
+        note.push_str(&format!("\nLikely cause: {}", first.name));
+        if !rest.is_empty() {
+            let others = rest.iter().map(|c| c.name).collect::<Vec<_>>().join(", ");
+            note.push_str(&format!(" (also possible: {})", others));
+        }
+        note.push_str("\n\nPlan:\n");
+        for action in first.actions {
+            note.push_str(&format!("- {}\n", action));
+        }
+    }
+    note.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_answer_leads_somewhere() {
+        for q in QUESTIONS {
+            assert!(!q.answers.is_empty(), "question '{}' has no answers", q.id);
+            for a in q.answers {
+                match a.next {
+                    Next::Ask(id) => assert!(question(id).is_some(), "'{}' asks unknown question '{}'", q.id, id),
+                    Next::Diagnose(ids) => {
+                        assert!(!ids.is_empty(), "'{}' diagnoses nothing", a.label);
+                        for id in ids {
+                            assert!(cause(id).is_some(), "'{}' names unknown cause '{}'", a.label, id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tree_always_ends_in_a_diagnosis() {
+        // Walk every path from the start; a question seen twice on one path would loop forever
+        fn walk(id: &str, seen: &mut Vec<&'static str>) {
+            let q = question(id).unwrap();
+            assert!(!seen.contains(&q.id), "question '{}' loops", q.id);
+            seen.push(q.id);
+            for a in q.answers {
+                if let Next::Ask(next) = a.next {
+                    walk(next, seen);
+                }
+            }
+            seen.pop();
+        }
+        walk(START_QUESTION, &mut Vec::new());
+    }
+
+    #[test]
+    fn test_diagnosis_note_lists_symptoms_and_plan() {
+        let q = question("roots").unwrap();
+        let a = &q.answers[1];
+        let found = causes(&["root_rot", "overwatering"]);
+        let note = diagnosis_note(&[(q, a)], &found);
+        assert!(note.contains("Brown, mushy, or hollow"));
+        assert!(note.contains("Likely cause: Root rot (also possible: Overwatering)"));
+        assert!(note.contains("- Unpot and cut away"));
+        assert_eq!(follow_up_days(&found), Some(10));
+    }
+}
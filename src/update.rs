@@ -7,7 +7,7 @@ use leptos::prelude::*;
 pub fn update(model: &mut Model, msg: Msg) -> Vec<Cmd> {
     match msg {
         Msg::SelectOrchid(orchid) => {
-            model.selected_orchid = orchid;
+            model.selected_orchid = orchid.map(|o| *o);
             vec![]
         }
         Msg::SetViewMode(mode) => {
@@ -22,6 +22,10 @@ pub fn update(model: &mut Model, msg: Msg) -> Vec<Cmd> {
             model.show_scanner = show;
             vec![]
         }
+        Msg::ShowChat(show) => {
+            model.show_chat = show;
+            vec![]
+        }
         Msg::ShowAddModal(show) => {
             model.show_add_modal = show;
             vec![]
@@ -37,10 +41,18 @@ pub fn update(model: &mut Model, msg: Msg) -> Vec<Cmd> {
             model.temp_unit = temp_unit;
             vec![]
         }
+        Msg::SetOverdueGraceDays(days) => {
+            model.overdue_grace_days = days;
+            vec![]
+        }
         Msg::ToggleDarkMode => {
             model.dark_mode = !model.dark_mode;
             vec![Cmd::ApplyDarkMode(model.dark_mode)]
         }
+        Msg::SetAccessibility(prefs) => {
+            model.accessibility = prefs;
+            vec![Cmd::ApplyAccessibility(prefs)]
+        }
         Msg::ShowWizard(zone) => {
             model.wizard_zone = zone;
             vec![]
@@ -109,6 +121,19 @@ fn execute_cmd(cmd: Cmd) {
             }
             let _ = enabled; // suppress unused warning in SSR
         }
+        Cmd::ApplyAccessibility(prefs) => {
+            #[cfg(feature = "hydrate")]
+            {
+                if let Some(document) = web_sys::window().and_then(|w| w.document())
+                    && let Some(root) = document.document_element() {
+                        let class_list = root.class_list();
+                        for (class, enabled) in [("reduce-motion", prefs.reduced_motion), ("high-contrast", prefs.high_contrast)] {
+                            let _ = if enabled { class_list.add_1(class) } else { class_list.remove_1(class) };
+                        }
+                    }
+            }
+            let _ = prefs; // suppress unused warning in SSR
+        }
     }
 }
 
@@ -155,6 +180,22 @@ mod tests {
             active_water_multiplier: None,
             active_fertilizer_multiplier: None,
             par_ppfd: None,
+            overdue_grace_days: None,
+            default_watering_method: None,
+            water_deferred_until: None,
+            reservoir_frequency_days: None,
+            last_topped_up_at: None,
+            acquired_at: None,
+            acquisition_price: None,
+            acquisition_source: None,
+            vendor_id: None,
+            grex_name: None,
+            seed_parent: None,
+            pollen_parent: None,
+            archived_at: None,
+            archive_reason: None,
+            cover_image: None,
+            updated_at: None,
         }
     }
 
@@ -163,7 +204,7 @@ mod tests {
         let mut model = Model::default();
         let orchid = test_orchid("1");
 
-        let cmds = update(&mut model, Msg::SelectOrchid(Some(orchid.clone())));
+        let cmds = update(&mut model, Msg::SelectOrchid(Some(Box::new(orchid.clone()))));
 
         assert_eq!(model.selected_orchid, Some(orchid));
         assert!(cmds.is_empty());
@@ -190,6 +231,9 @@ mod tests {
         update(&mut model, Msg::ShowScanner(true));
         assert!(model.show_scanner);
 
+        update(&mut model, Msg::ShowChat(true));
+        assert!(model.show_chat);
+
         update(&mut model, Msg::ShowAddModal(true));
         assert!(model.show_add_modal);
     }
@@ -255,6 +299,17 @@ mod tests {
         assert!(cmds.is_empty());
     }
 
+    #[test]
+    fn test_set_overdue_grace_days() {
+        let mut model = Model::default();
+        assert_eq!(model.overdue_grace_days, 0);
+
+        let cmds = update(&mut model, Msg::SetOverdueGraceDays(3));
+
+        assert_eq!(model.overdue_grace_days, 3);
+        assert!(cmds.is_empty());
+    }
+
     #[test]
     fn test_show_wizard() {
         let mut model = Model::default();
@@ -300,6 +355,15 @@ mod tests {
         assert!(cmds.is_empty());
     }
 
+    #[test]
+    fn test_set_accessibility_applies_classes() {
+        let mut model = Model::default();
+        let prefs = crate::model::AccessibilityPrefs { reduced_motion: true, high_contrast: false };
+        let cmds = update(&mut model, Msg::SetAccessibility(prefs));
+        assert_eq!(model.accessibility, prefs);
+        assert_eq!(cmds, vec![Cmd::ApplyAccessibility(prefs)]);
+    }
+
     #[test]
     fn test_toggle_dark_mode() {
         let mut model = Model::default();
@@ -0,0 +1,125 @@
+/// Narrowest a card column gets; matches the grid's `minmax(300px, 1fr)`.
+pub const CARD_MIN_WIDTH_PX: f64 = 300.0;
+/// Space between cards; matches the grid's `gap-5`.
+pub const GRID_GAP_PX: f64 = 20.0;
+/// Collections up to this size render every card; above it only the rows near the viewport.
+pub const VIRTUALIZE_ABOVE: usize = 60;
+/// Rows rendered beyond each edge of the viewport, so fast flings don't show blank space.
+pub const OVERSCAN_ROWS: usize = 2;
+
+/// Where the grid sits on screen, measured in the browser.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridViewport {
+    /// The grid's width.
+    pub width: f64,
+    /// The grid's top edge relative to the top of the viewport; negative once scrolled past.
+    pub top: f64,
+    /// The viewport's height.
+    pub height: f64,
+    /// Average height of a rendered row of cards.
+    pub row_height: f64,
+}
+
+impl Default for GridViewport {
+    /// A guess used for the server render and until the grid has been measured: a three-column
+    /// desktop grid at the top of a laptop-sized screen.
+    fn default() -> Self {
+        Self { width: 960.0, top: 300.0, height: 900.0, row_height: 420.0 }
+    }
+}
+
+/// The cards to render, and the space standing in for the rows above and below them so the
+/// page keeps its full height and scroll position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridWindow {
+    /// Index of the first card rendered.
+    pub start: usize,
+    /// One past the index of the last card rendered.
+    pub end: usize,
+    /// Height of the rows skipped above, in px.
+    pub space_before: f64,
+    /// Height of the rows skipped below, in px.
+    pub space_after: f64,
+}
+
+/// How many columns the grid lays out at `width`.
+pub fn columns(width: f64) -> usize {
+    (((width + GRID_GAP_PX) / (CARD_MIN_WIDTH_PX + GRID_GAP_PX)).floor() as usize).max(1)
+}
+
+/// The cards of a `count`-card grid that are on screen or within `OVERSCAN_ROWS` of it, in whole
+/// rows. Small collections get every card.
+pub fn visible_window(count: usize, viewport: &GridViewport) -> GridWindow {
+    if count <= VIRTUALIZE_ABOVE {
+        return GridWindow { start: 0, end: count, space_before: 0.0, space_after: 0.0 };
+    }
+    let columns = columns(viewport.width);
+    let rows = count.div_ceil(columns);
+    let pitch = viewport.row_height.max(1.0) + GRID_GAP_PX;
+
+    // The slice of the grid between the viewport's top and bottom edges, in px from the grid's top
+    let seen_from = (-viewport.top).max(0.0);
+    let seen_to = (viewport.height - viewport.top).max(0.0);
+    let first_row = ((seen_from / pitch).floor() as usize).saturating_sub(OVERSCAN_ROWS).min(rows);
+    let last_row = ((seen_to / pitch).ceil() as usize + OVERSCAN_ROWS).clamp(first_row, rows);
+
+    GridWindow {
+        start: first_row * columns,
+        end: (last_row * columns).min(count),
+        space_before: first_row as f64 * pitch,
+        space_after: (rows - last_row) as f64 * pitch,
+    }
+}
+
+/// The average row height of a rendered window `rendered_height` px tall holding `cards` cards in
+/// `columns` columns, not counting the space standing in for skipped rows. `None` when nothing
+/// is rendered.
+pub fn measured_row_height(rendered_height: f64, cards: usize, columns: usize) -> Option<f64> {
+    let rows = cards.div_ceil(columns.max(1));
+    (rows > 0 && rendered_height > 0.0).then(|| (rendered_height + GRID_GAP_PX) / rows as f64 - GRID_GAP_PX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_follow_width() {
+        assert_eq!(columns(360.0), 1);
+        assert_eq!(columns(620.0), 2);
+        assert_eq!(columns(939.0), 2);
+        assert_eq!(columns(940.0), 3);
+        assert_eq!(columns(0.0), 1);
+    }
+
+    #[test]
+    fn test_window_covers_viewport_plus_overscan() {
+        // Two columns, rows 400px apart, scrolled 10 rows into a 500-card grid
+        let viewport = GridViewport { width: 620.0, top: -4000.0, height: 800.0, row_height: 380.0 };
+        let window = visible_window(500, &viewport);
+        assert_eq!((window.start, window.end), (16, 28));
+        assert_eq!(window.space_before, 8.0 * 400.0);
+        assert_eq!(window.space_after, (250.0 - 14.0) * 400.0);
+
+        // Near the end, the last row is partly filled and nothing stands in below it
+        let end = visible_window(501, &GridViewport { top: -250.0 * 400.0, ..viewport });
+        assert_eq!(end.end, 501);
+        assert_eq!(end.space_after, 0.0);
+
+        // Above the fold, rendering starts at the first card
+        let top = visible_window(500, &GridViewport { top: 300.0, ..viewport });
+        assert_eq!((top.start, top.space_before), (0, 0.0));
+        assert_eq!(top.end, 8);
+
+        // Small collections aren't windowed
+        let small = visible_window(VIRTUALIZE_ABOVE, &viewport);
+        assert_eq!((small.start, small.end), (0, VIRTUALIZE_ABOVE));
+    }
+
+    #[test]
+    fn test_measured_row_height() {
+        assert_eq!(measured_row_height(1180.0, 6, 2), Some(380.0));
+        assert_eq!(measured_row_height(380.0, 1, 3), Some(380.0));
+        assert_eq!(measured_row_height(0.0, 0, 3), None);
+    }
+}
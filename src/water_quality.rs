@@ -0,0 +1,307 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::species_photos::genus_key;
+
+/// Longest water source name accepted, matching the table assertion.
+pub const MAX_SOURCE_NAME_CHARS: usize = 60;
+/// Highest EC accepted from a meter, µS/cm (well above any water fit for plants).
+pub const MAX_EC_US_CM: f64 = 10_000.0;
+/// Highest TDS accepted from a meter, ppm.
+pub const MAX_TDS_PPM: f64 = 5_000.0;
+/// TDS meters report on the 0.5 scale (NaCl): EC in µS/cm is about twice the ppm.
+pub const TDS_TO_EC_FACTOR: f64 = 2.0;
+
+/// EC ceiling for genera without a stricter entry below, µS/cm.
+pub const DEFAULT_EC_LIMIT_US_CM: f64 = 500.0;
+/// Safe pH range of irrigation water for epiphytic orchids.
+pub const PH_SAFE_RANGE: (f64, f64) = (5.5, 7.0);
+
+/// Genera whose roots are burned by dissolved salts, with the highest EC (µS/cm) their water should reach.
+const SENSITIVE_GENERA_EC_LIMITS: &[(&str, f64)] = &[
+    ("disa", 50.0),
+    ("phragmipedium", 100.0),
+    ("dracula", 150.0),
+    ("masdevallia", 150.0),
+    ("pleurothallis", 200.0),
+    ("paphiopedilum", 300.0),
+    ("miltoniopsis", 300.0),
+];
+
+/// The EC ceiling for one genus: its sensitive-genus entry, or the default.
+pub fn ec_limit_for_genus(genus: &str) -> f64 {
+    let genus = genus_key(genus);
+    SENSITIVE_GENERA_EC_LIMITS.iter()
+        .find(|(g, _)| *g == genus)
+        .map(|(_, limit)| *limit)
+        .unwrap_or(DEFAULT_EC_LIMIT_US_CM)
+}
+
+/// Where a grower's water comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaterSourceKind {
+    /// Municipal tap water.
+    Tap,
+    /// Collected rainwater.
+    Rain,
+    /// Reverse osmosis filtered water.
+    ReverseOsmosis,
+    /// Distilled water.
+    Distilled,
+    /// Well water.
+    Well,
+    /// Anything else.
+    Other,
+}
+
+impl WaterSourceKind {
+    /// Every kind, in the order shown in the picker.
+    pub const ALL: [WaterSourceKind; 6] = [
+        WaterSourceKind::Tap,
+        WaterSourceKind::Rain,
+        WaterSourceKind::ReverseOsmosis,
+        WaterSourceKind::Distilled,
+        WaterSourceKind::Well,
+        WaterSourceKind::Other,
+    ];
+
+    /// The stored name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaterSourceKind::Tap => "Tap",
+            WaterSourceKind::Rain => "Rain",
+            WaterSourceKind::ReverseOsmosis => "ReverseOsmosis",
+            WaterSourceKind::Distilled => "Distilled",
+            WaterSourceKind::Well => "Well",
+            WaterSourceKind::Other => "Other",
+        }
+    }
+
+    /// Parses a stored name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+
+    /// Display name.
+    pub fn label(&self) -> &'static str {
+        match self {
+            WaterSourceKind::Tap => "Tap water",
+            WaterSourceKind::Rain => "Rainwater",
+            WaterSourceKind::ReverseOsmosis => "Reverse osmosis",
+            WaterSourceKind::Distilled => "Distilled",
+            WaterSourceKind::Well => "Well water",
+            WaterSourceKind::Other => "Other",
+        }
+    }
+}
+
+/// One meter reading of a water source.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaterReading {
+    /// The reading record ID.
+    pub id: String,
+    /// When the water was measured.
+    pub measured_at: DateTime<Utc>,
+    /// pH, 0–14.
+    pub ph: Option<f64>,
+    /// Electrical conductivity, µS/cm.
+    pub ec_us_cm: Option<f64>,
+    /// Total dissolved solids, ppm.
+    pub tds_ppm: Option<f64>,
+    /// Free-text note ("after the filter change").
+    pub note: Option<String>,
+}
+
+impl WaterReading {
+    /// The EC in µS/cm, measured or converted from TDS.
+    pub fn effective_ec(&self) -> Option<f64> {
+        self.ec_us_cm.or(self.tds_ppm.map(|tds| tds * TDS_TO_EC_FACTOR))
+    }
+
+    /// Short summary ("pH 6.4 · 180 µS/cm"), or an empty string when nothing was measured.
+    pub fn summary(&self) -> String {
+        let ec = match (self.ec_us_cm, self.tds_ppm) {
+            (Some(ec), _) => Some(format!("{:.0} \u{00B5}S/cm", ec)),
+            (None, Some(tds)) => Some(format!("{:.0} ppm", tds)),
+            (None, None) => None,
+        };
+        [self.ph.map(|ph| format!("pH {:.1}", ph)), ec]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" \u{00B7} ")
+    }
+
+    /// Checks the ranges a meter can report; at least one value is required.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ph.is_none() && self.ec_us_cm.is_none() && self.tds_ppm.is_none() {
+            return Err("Enter a pH, EC or TDS value".into());
+        }
+        if self.ph.is_some_and(|ph| !(0.0..=14.0).contains(&ph)) {
+            return Err("pH must be between 0 and 14".into());
+        }
+        if self.ec_us_cm.is_some_and(|ec| !(0.0..=MAX_EC_US_CM).contains(&ec)) {
+            return Err(format!("EC must be between 0 and {:.0} \u{00B5}S/cm", MAX_EC_US_CM));
+        }
+        if self.tds_ppm.is_some_and(|tds| !(0.0..=MAX_TDS_PPM).contains(&tds)) {
+            return Err(format!("TDS must be between 0 and {:.0} ppm", MAX_TDS_PPM));
+        }
+        Ok(())
+    }
+}
+
+/// **What is it?**
+/// A water source the grower uses (their tap, a rain barrel, an RO unit) with its most recent reading.
+///
+/// **Why does it exist?**
+/// It exists because dissolved salts and pH build up in the pot from the water itself, and tap water changes with the season and the supplier.
+///
+/// **How should it be used?**
+/// Fetch with `server_fns::water_quality::get_water_sources` for the settings section; log readings with `log_water_reading`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaterSource {
+    /// The source record ID.
+    pub id: String,
+    /// The grower's name for it ("Kitchen tap").
+    pub name: String,
+    /// What kind of water it is.
+    pub kind: WaterSourceKind,
+    /// The newest reading, if any were logged.
+    pub latest: Option<WaterReading>,
+}
+
+/// Something wrong with a water source's latest reading.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WaterIssue {
+    /// EC above the limit of the most sensitive genus it harms; `genera` lists every genus in the collection over its limit.
+    EcTooHigh {
+        /// The measured EC, in µS/cm.
+        ec_us_cm: f64,
+        /// The limit it exceeds, in µS/cm.
+        limit_us_cm: f64,
+        /// Genera in the collection over their limit.
+        genera: Vec<String>,
+    },
+    /// pH outside `PH_SAFE_RANGE`.
+    PhOutOfRange {
+        /// The measured pH.
+        ph: f64,
+    },
+}
+
+impl WaterIssue {
+    /// The warning shown to the grower.
+    pub fn message(&self) -> String {
+        match self {
+            WaterIssue::EcTooHigh { ec_us_cm, limit_us_cm, genera } if genera.is_empty() => format!(
+                "EC {:.0} \u{00B5}S/cm is above {:.0}; salts will build up in the pot. Flush regularly or blend with RO or rainwater.",
+                ec_us_cm, limit_us_cm,
+            ),
+            WaterIssue::EcTooHigh { ec_us_cm, limit_us_cm, genera } => format!(
+                "EC {:.0} \u{00B5}S/cm is too salty for {} (keep below {:.0}). Use RO, distilled or rainwater for them.",
+                ec_us_cm, genera.join(", "), limit_us_cm,
+            ),
+            WaterIssue::PhOutOfRange { ph } if *ph < PH_SAFE_RANGE.0 => format!(
+                "pH {:.1} is acidic; most orchids take up nutrients best between {:.1} and {:.1}.",
+                ph, PH_SAFE_RANGE.0, PH_SAFE_RANGE.1,
+            ),
+            WaterIssue::PhOutOfRange { ph } => format!(
+                "pH {:.1} is alkaline; above {:.1} iron and manganese lock out and leaves yellow.",
+                ph, PH_SAFE_RANGE.1,
+            ),
+        }
+    }
+}
+
+/// Checks one reading against the safe pH range and the EC limits of the genera in the collection.
+///
+/// `genera` are the genus names of the collection's plants (duplicates are fine). Only genera whose limit is
+/// exceeded are named; the default limit applies even to an empty collection.
+pub fn water_issues(reading: &WaterReading, genera: &[String]) -> Vec<WaterIssue> {
+    let mut issues = Vec::new();
+    if let Some(ec) = reading.effective_ec() {
+        let mut harmed: Vec<(f64, String)> = Vec::new();
+        for genus in genera {
+            let limit = ec_limit_for_genus(genus);
+            if ec > limit && !harmed.iter().any(|(_, g)| g.eq_ignore_ascii_case(genus)) {
+                harmed.push((limit, genus.clone()));
+            }
+        }
+        harmed.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+        let strictest = harmed.first().map(|(limit, _)| *limit).unwrap_or(DEFAULT_EC_LIMIT_US_CM);
+        if ec > strictest {
+            issues.push(WaterIssue::EcTooHigh {
+                ec_us_cm: ec,
+                limit_us_cm: strictest,
+                genera: harmed.into_iter().filter(|(limit, _)| *limit < DEFAULT_EC_LIMIT_US_CM).map(|(_, g)| g).collect(),
+            });
+        }
+    }
+    if let Some(ph) = reading.ph
+        && !(PH_SAFE_RANGE.0..=PH_SAFE_RANGE.1).contains(&ph) {
+        issues.push(WaterIssue::PhOutOfRange { ph });
+    }
+    issues
+}
+
+/// **What is it?**
+/// A warning about one water source, raised from its latest reading.
+///
+/// **Why does it exist?**
+/// It exists so a grower learns their tap water has drifted before Phragmipediums and Disas show burnt root tips.
+///
+/// **How should it be used?**
+/// Fetch with `server_fns::water_quality::get_water_quality_warnings` and show `issue.message()` next to the source.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WaterQualityWarning {
+    /// The source record ID.
+    pub source_id: String,
+    /// The source's name.
+    pub source_name: String,
+    /// When the reading that raised it was taken.
+    pub measured_at: DateTime<Utc>,
+    /// What is wrong.
+    pub issue: WaterIssue,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(ph: Option<f64>, ec: Option<f64>, tds: Option<f64>) -> WaterReading {
+        WaterReading { id: String::new(), measured_at: Utc::now(), ph, ec_us_cm: ec, tds_ppm: tds, note: None }
+    }
+
+    fn genera(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_ec_limits_follow_sensitive_genera() {
+        let tap = reading(Some(6.5), None, Some(120.0));
+        assert_eq!(tap.effective_ec(), Some(240.0));
+
+        // Only the genera actually over their limit are named, strictest first.
+        let issues = water_issues(&tap, &genera(&["Phalaenopsis", "Paphiopedilum", "Masdevallia", "Phragmipedium", "phragmipedium"]));
+        assert_eq!(issues, vec![WaterIssue::EcTooHigh {
+            ec_us_cm: 240.0,
+            limit_us_cm: 100.0,
+            genera: genera(&["Phragmipedium", "Masdevallia"]),
+        }]);
+        assert!(water_issues(&tap, &genera(&["Phalaenopsis"])).is_empty());
+
+        // Hard water trips the default limit even without sensitive plants.
+        let hard = reading(None, Some(800.0), None);
+        let issues = water_issues(&hard, &genera(&["Phalaenopsis"]));
+        assert_eq!(issues, vec![WaterIssue::EcTooHigh { ec_us_cm: 800.0, limit_us_cm: 500.0, genera: vec![] }]);
+    }
+
+    #[test]
+    fn test_ph_range_and_validation() {
+        assert_eq!(water_issues(&reading(Some(7.8), None, None), &[]), vec![WaterIssue::PhOutOfRange { ph: 7.8 }]);
+        assert!(water_issues(&reading(Some(6.0), None, None), &[]).is_empty());
+        assert!(reading(None, None, None).validate().is_err());
+        assert!(reading(Some(15.0), None, None).validate().is_err());
+        assert!(reading(Some(6.2), Some(35.0), None).validate().is_ok());
+        assert_eq!(reading(Some(6.2), Some(35.0), None).summary(), "pH 6.2 \u{00B7} 35 \u{00B5}S/cm");
+    }
+}
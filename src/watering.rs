@@ -6,7 +6,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::orchid::{ClimateReading, LightRequirement};
+use crate::orchid::{ClimateReading, LightRequirement, MediumFeedback};
 
 // ── Reference Conditions ────────────────────────────────────────────
 // What `water_frequency_days` assumes: standard indoor environment.
@@ -18,6 +18,15 @@ pub const REFERENCE_HUMIDITY_PCT: f64 = 55.0;
 /// Reference VPD calculated from 22°C / 55% RH ≈ 1.19 kPa
 pub const REFERENCE_VPD_KPA: f64 = 1.19;
 
+/// Hours of forecast rain counted toward the watering interval.
+pub const FORECAST_RAIN_HOURS: i64 = 24;
+/// Share of forecast rain counted as if it had already fallen; forecasts miss, and rain can pass a sheltered mount by.
+const FORECAST_RAIN_WEIGHT: f64 = 0.6;
+/// Forecast rain (mm, next 24 hours) at which a due watering can wait for it.
+pub const WAIT_FOR_RAIN_MM: f64 = 10.0;
+/// Forecast high (°C, next 48 hours) treated as a heat wave worth watering ahead of.
+pub const HEAT_WAVE_TEMP_C: f64 = 32.0;
+
 // ── Types ───────────────────────────────────────────────────────────
 
 /// Quality of the climate data used for adjustment.
@@ -44,6 +53,12 @@ pub struct ClimateSnapshot {
     pub avg_vpd_kpa: f64,
     /// Total precipitation in the last 48 hours (mm). None if indoor or no data.
     pub precipitation_48h_mm: Option<f64>,
+    /// Precipitation forecast for the next 24 hours (mm). None if indoor or the zone has no weather forecast.
+    #[serde(default)]
+    pub forecast_precipitation_24h_mm: Option<f64>,
+    /// Highest temperature forecast for the next 48 hours (°C). None if indoor or the zone has no weather forecast.
+    #[serde(default)]
+    pub forecast_max_temp_c: Option<f64>,
     /// Timestamp of the most recent reading included in this snapshot.
     pub newest_reading_at: DateTime<Utc>,
     /// Number of readings averaged into this snapshot.
@@ -52,6 +67,12 @@ pub struct ClimateSnapshot {
     pub quality: DataQuality,
     /// True if the zone represents an outdoor location.
     pub is_outdoor: bool,
+    /// Brightest illuminance in the window (lux), if the zone has a light sensor.
+    #[serde(default)]
+    pub peak_lux: Option<f64>,
+    /// Brightest PPFD in the window (µmol/m²/s), if the zone has a quantum sensor.
+    #[serde(default)]
+    pub peak_ppfd: Option<f64>,
 }
 
 /// Result of the climate-adjusted watering calculation.
@@ -84,6 +105,57 @@ pub struct FactorBreakdown {
     pub rain_factor: f64,
 }
 
+/// A forecast-based nudge to water later or sooner than the schedule says.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ForecastHint {
+    /// Enough rain is coming in the next day that watering can wait.
+    WaitForRain {
+        /// Forecast rain over the next 24 hours, mm.
+        mm: f64,
+    },
+    /// A heat wave is coming; water before it arrives.
+    WaterEarly {
+        /// Forecast high over the next 48 hours, °C.
+        max_temp_c: f64,
+    },
+}
+
+impl ForecastHint {
+    /// One-line advice, with temperatures in `unit` ("C" or "F").
+    pub fn message(&self, unit: &str) -> String {
+        match self {
+            ForecastHint::WaitForRain { mm } => format!("Consider waiting \u{2014} {:.0} mm of rain expected in the next 24h", mm),
+            ForecastHint::WaterEarly { max_temp_c } => {
+                let high = if unit == "F" {
+                    format!("{:.0}\u{00B0}F", max_temp_c * 9.0 / 5.0 + 32.0)
+                } else {
+                    format!("{:.0}\u{00B0}C", max_temp_c)
+                };
+                format!("Heat wave incoming ({} high) \u{2014} water early", high)
+            }
+        }
+    }
+
+    /// True when the hint argues for watering sooner rather than later.
+    pub fn is_urgent(&self) -> bool {
+        matches!(self, ForecastHint::WaterEarly { .. })
+    }
+}
+
+/// Forecast advice for an outdoor zone, if the coming weather should move watering.
+/// Rain wins over heat: a soaking storm cools and waters at once.
+pub fn forecast_hint(snapshot: &ClimateSnapshot) -> Option<ForecastHint> {
+    if !snapshot.is_outdoor {
+        return None;
+    }
+    if let Some(mm) = snapshot.forecast_precipitation_24h_mm.filter(|mm| *mm >= WAIT_FOR_RAIN_MM) {
+        return Some(ForecastHint::WaitForRain { mm });
+    }
+    snapshot.forecast_max_temp_c
+        .filter(|t| *t >= HEAT_WAVE_TEMP_C)
+        .map(|max_temp_c| ForecastHint::WaterEarly { max_temp_c })
+}
+
 // ── Factor Functions ────────────────────────────────────────────────
 
 /// VPD factor: primary driver of evaporative demand.
@@ -181,6 +253,15 @@ pub fn rain_factor(precipitation_48h_mm: Option<f64>, is_outdoor: bool) -> f64 {
     }
 }
 
+/// Rain that counts toward the rain factor: the last 48 hours plus a discounted share of the next 24.
+/// None when neither is known.
+pub fn expected_rain_mm(precipitation_48h_mm: Option<f64>, forecast_24h_mm: Option<f64>) -> Option<f64> {
+    match (precipitation_48h_mm, forecast_24h_mm) {
+        (None, None) => None,
+        (recent, forecast) => Some(recent.unwrap_or(0.0) + forecast.unwrap_or(0.0).max(0.0) * FORECAST_RAIN_WEIGHT),
+    }
+}
+
 // ── Main Algorithm ──────────────────────────────────────────────────
 
 /// Compute the climate-adjusted watering frequency.
@@ -222,7 +303,8 @@ pub fn climate_adjusted_frequency(
         Some(ppfd) => light_factor_par(ppfd),
         None => light_factor(light_req),
     };
-    let rf = rain_factor(snapshot.precipitation_48h_mm, snapshot.is_outdoor);
+    let rain = expected_rain_mm(snapshot.precipitation_48h_mm, snapshot.forecast_precipitation_24h_mm);
+    let rf = rain_factor(rain, snapshot.is_outdoor);
 
     let combined = base_days as f64 * vf * csf * mf * lf * rf;
     let max_days = base_days * 3;
@@ -254,6 +336,39 @@ pub fn calculate_vpd(temp_c: f64, humidity_pct: f64) -> f64 {
     saturation_pressure - actual_pressure
 }
 
+// ── Learning from Feedback ──────────────────────────────────────────
+
+/// Share of the base frequency one too-dry or still-wet rating moves it by.
+const FEEDBACK_STEP: f64 = 0.1;
+/// Days of slack between the interval a rating follows and the schedule before timing explains the rating.
+const FEEDBACK_SLACK_DAYS: i64 = 1;
+/// Longest base frequency learning can reach, matching the plant form's limit.
+const MAX_LEARNED_FREQUENCY_DAYS: u32 = 365;
+
+/// New base watering frequency after the grower rates the medium at a watering.
+///
+/// `interval_days` is how long the plant went since its previous watering (None if unknown) and
+/// `scheduled_days` what the schedule asked for then. "Too dry" shortens the base by about 10%,
+/// at least a day; "still wet" lengthens it the same way; "just right" leaves it. Ratings the
+/// timing already explains are ignored: dry after a late watering, wet after an early one.
+pub fn learn_water_frequency(
+    base_days: u32,
+    scheduled_days: u32,
+    interval_days: Option<i64>,
+    feedback: MediumFeedback,
+) -> u32 {
+    let step = ((base_days as f64 * FEEDBACK_STEP).round() as u32).max(1);
+    match feedback {
+        MediumFeedback::TooDry if interval_days.is_none_or(|i| i <= scheduled_days as i64 + FEEDBACK_SLACK_DAYS) => {
+            base_days.saturating_sub(step).max(1)
+        }
+        MediumFeedback::StillWet if interval_days.is_none_or(|i| i >= scheduled_days as i64 - FEEDBACK_SLACK_DAYS) => {
+            (base_days + step).min(MAX_LEARNED_FREQUENCY_DAYS)
+        }
+        _ => base_days,
+    }
+}
+
 // ── ClimateSnapshot Builder ─────────────────────────────────────────
 
 impl ClimateSnapshot {
@@ -303,18 +418,31 @@ impl ClimateSnapshot {
             None
         };
 
+        let peak = |value: fn(&ClimateReading) -> Option<f64>| {
+            readings.iter().filter_map(value).reduce(f64::max)
+        };
+
         Some(ClimateSnapshot {
             zone_name: zone_name.to_string(),
             avg_temp_c: avg_temp,
             avg_humidity_pct: avg_hum,
             avg_vpd_kpa: avg_vpd,
             precipitation_48h_mm: precip_sum,
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: newest,
             reading_count: readings.len(),
             quality,
             is_outdoor,
+            peak_lux: peak(|r| r.lux),
+            peak_ppfd: peak(|r| r.ppfd),
         })
     }
+
+    /// Peak light as PPFD: measured if available, otherwise estimated from lux.
+    pub fn peak_light_ppfd(&self) -> Option<f64> {
+        self.peak_ppfd.or_else(|| self.peak_lux.map(crate::light::lux_to_ppfd))
+    }
 }
 
 /// Determine data quality from the age of the newest reading.
@@ -528,10 +656,14 @@ mod tests {
             avg_humidity_pct: hum,
             avg_vpd_kpa: vpd,
             precipitation_48h_mm: None,
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: Utc::now(),
             reading_count: 10,
             quality: DataQuality::Fresh,
             is_outdoor: false,
+            peak_lux: None,
+            peak_ppfd: None,
         }
     }
 
@@ -648,6 +780,50 @@ mod tests {
         assert_eq!(est.adjusted_days, 7);
     }
 
+    #[test]
+    fn test_adjusted_forecast_rain_extends_outdoor() {
+        let mut snap = test_snapshot(22.0, 55.0, REFERENCE_VPD_KPA);
+        snap.is_outdoor = true;
+        snap.forecast_precipitation_24h_mm = Some(30.0);
+        let est = climate_adjusted_frequency(7, Some(&snap), None, &LightRequirement::Medium, None);
+        // 30mm forecast counts as 18mm fallen
+        assert!((est.factors.unwrap().rain_factor - 2.0).abs() < 0.01);
+        assert!(est.adjusted_days > 7);
+
+        snap.is_outdoor = false;
+        let est = climate_adjusted_frequency(7, Some(&snap), None, &LightRequirement::Medium, None);
+        assert_eq!(est.adjusted_days, 7);
+    }
+
+    #[test]
+    fn test_forecast_hint_prefers_rain_over_heat() {
+        let mut snap = test_snapshot(22.0, 55.0, REFERENCE_VPD_KPA);
+        snap.forecast_precipitation_24h_mm = Some(28.0);
+        snap.forecast_max_temp_c = Some(35.0);
+        assert_eq!(forecast_hint(&snap), None, "indoor zones get no forecast hints");
+
+        snap.is_outdoor = true;
+        let hint = forecast_hint(&snap).unwrap();
+        assert_eq!(hint, ForecastHint::WaitForRain { mm: 28.0 });
+        assert_eq!(hint.message("C"), "Consider waiting \u{2014} 28 mm of rain expected in the next 24h");
+
+        snap.forecast_precipitation_24h_mm = Some(2.0);
+        let hint = forecast_hint(&snap).unwrap();
+        assert!(hint.is_urgent());
+        assert_eq!(hint.message("F"), "Heat wave incoming (95\u{00B0}F high) \u{2014} water early");
+
+        snap.forecast_max_temp_c = Some(25.0);
+        assert_eq!(forecast_hint(&snap), None);
+    }
+
+    #[test]
+    fn test_expected_rain_combines_recent_and_forecast() {
+        assert_eq!(expected_rain_mm(None, None), None);
+        assert_eq!(expected_rain_mm(Some(4.0), None), Some(4.0));
+        assert!((expected_rain_mm(Some(4.0), Some(10.0)).unwrap() - 10.0).abs() < 1e-9);
+        assert!((expected_rain_mm(None, Some(-2.0)).unwrap()).abs() < 1e-9);
+    }
+
     #[test]
     fn test_adjusted_bark_medium_dries_faster() {
         let snap = test_snapshot(REFERENCE_TEMP_C, REFERENCE_HUMIDITY_PCT, REFERENCE_VPD_KPA);
@@ -769,7 +945,16 @@ mod tests {
             temperature: temp,
             humidity: hum,
             vpd,
+            dew_point: None,
             precipitation: precip,
+            uv_index: None,
+            solar_radiation: None,
+            wind_speed: None,
+            wind_gust: None,
+            lux: None,
+            ppfd: None,
+            co2_ppm: None,
+            substrate_moisture_pct: None,
             source: Some("test".into()),
             recorded_at: Utc::now() - chrono::Duration::hours(age_hours),
         }
@@ -806,6 +991,19 @@ mod tests {
         assert!((snap.avg_humidity_pct - 55.0).abs() < 0.01);
         assert!((snap.avg_vpd_kpa - 0.9).abs() < 0.01);
         assert_eq!(snap.reading_count, 3);
+        assert_eq!(snap.peak_light_ppfd(), None);
+    }
+
+    #[test]
+    fn test_snapshot_tracks_peak_light() {
+        let mut noon = make_reading(24.0, 55.0, None, None, 1);
+        noon.lux = Some(10_800.0);
+        let mut dusk = make_reading(22.0, 60.0, None, None, 2);
+        dusk.lux = Some(500.0);
+        let snap = ClimateSnapshot::from_readings("Z", &[noon, dusk], false).unwrap();
+        assert_eq!(snap.peak_lux, Some(10_800.0));
+        assert_eq!(snap.peak_ppfd, None);
+        assert!((snap.peak_light_ppfd().unwrap() - 200.0).abs() < 1e-9);
     }
 
     #[test]
@@ -857,10 +1055,14 @@ mod tests {
             avg_humidity_pct: 75.0,
             avg_vpd_kpa: 0.94,
             precipitation_48h_mm: None,
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: Utc::now(),
             reading_count: 48,
             quality: DataQuality::Fresh,
             is_outdoor: false,
+            peak_lux: None,
+            peak_ppfd: None,
         };
         let est = climate_adjusted_frequency(
             7,
@@ -887,10 +1089,14 @@ mod tests {
             avg_humidity_pct: 30.0,
             avg_vpd_kpa: 1.64,
             precipitation_48h_mm: None,
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: Utc::now(),
             reading_count: 48,
             quality: DataQuality::Fresh,
             is_outdoor: false,
+            peak_lux: None,
+            peak_ppfd: None,
         };
         let est = climate_adjusted_frequency(
             7,
@@ -917,10 +1123,14 @@ mod tests {
             avg_humidity_pct: 85.0,
             avg_vpd_kpa: 0.31,
             precipitation_48h_mm: Some(20.0),
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: Utc::now(),
             reading_count: 48,
             quality: DataQuality::Fresh,
             is_outdoor: true,
+            peak_lux: None,
+            peak_ppfd: None,
         };
         let est = climate_adjusted_frequency(
             7,
@@ -1011,10 +1221,14 @@ mod tests {
             avg_humidity_pct: 55.0,
             avg_vpd_kpa: REFERENCE_VPD_KPA,
             precipitation_48h_mm: None,
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: Utc::now(),
             reading_count: 10,
             quality: DataQuality::Fresh,
             is_outdoor: false,
+            peak_lux: None,
+            peak_ppfd: None,
         };
         // Without PAR: uses enum Low (factor 1.15)
         let est_enum = climate_adjusted_frequency(
@@ -1040,10 +1254,14 @@ mod tests {
             avg_humidity_pct: 55.0,
             avg_vpd_kpa: REFERENCE_VPD_KPA,
             precipitation_48h_mm: None,
+            forecast_precipitation_24h_mm: None,
+            forecast_max_temp_c: None,
             newest_reading_at: Utc::now(),
             reading_count: 10,
             quality: DataQuality::Fresh,
             is_outdoor: false,
+            peak_lux: None,
+            peak_ppfd: None,
         };
         // None PAR with Medium should behave identically to the explicit enum path
         let est_none = climate_adjusted_frequency(
@@ -1059,4 +1277,23 @@ mod tests {
             est_none.adjusted_days, est_par.adjusted_days,
         );
     }
+
+    // ── learn_water_frequency tests ─────────────────────────────────
+
+    #[test]
+    fn test_learn_water_frequency_nudges_on_schedule() {
+        assert_eq!(learn_water_frequency(7, 7, Some(7), MediumFeedback::TooDry), 6);
+        assert_eq!(learn_water_frequency(7, 7, Some(7), MediumFeedback::StillWet), 8);
+        assert_eq!(learn_water_frequency(7, 7, Some(7), MediumFeedback::JustRight), 7);
+        assert_eq!(learn_water_frequency(30, 30, None, MediumFeedback::StillWet), 33);
+        assert_eq!(learn_water_frequency(1, 1, Some(1), MediumFeedback::TooDry), 1);
+    }
+
+    #[test]
+    fn test_learn_water_frequency_ignores_ratings_timing_explains() {
+        // Dry after watering five days late says nothing about the schedule
+        assert_eq!(learn_water_frequency(7, 7, Some(12), MediumFeedback::TooDry), 7);
+        // Wet after watering three days early, likewise
+        assert_eq!(learn_water_frequency(7, 7, Some(4), MediumFeedback::StillWet), 7);
+    }
 }
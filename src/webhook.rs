@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+/// Longest webhook URL accepted, in bytes.
+pub const MAX_WEBHOOK_URL_LEN: usize = 500;
+/// Longest message Discord accepts, in characters.
+const DISCORD_MAX_CONTENT: usize = 2000;
+
+/// The chat service a webhook posts to, which decides the shape of the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookKind {
+    /// A Discord channel webhook (`https://discord.com/api/webhooks/...`).
+    Discord,
+    /// A Slack incoming webhook (`https://hooks.slack.com/services/...`).
+    Slack,
+}
+
+impl WebhookKind {
+    /// Works out the service from a webhook URL. Only HTTPS URLs on Discord's or Slack's own webhook
+    /// paths are accepted, so the server never posts to arbitrary addresses.
+    pub fn detect(url: &str) -> Result<Self, String> {
+        let url = url.trim();
+        if url.len() > MAX_WEBHOOK_URL_LEN {
+            return Err(format!("Webhook URL must be at most {} characters", MAX_WEBHOOK_URL_LEN));
+        }
+        let rest = url.strip_prefix("https://")
+            .ok_or_else(|| "Webhook URL must start with https://".to_string())?;
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let host = host.to_ascii_lowercase();
+        match host.as_str() {
+            "discord.com" | "discordapp.com" | "ptb.discord.com" | "canary.discord.com"
+                if path.starts_with("/api/webhooks/") => Ok(WebhookKind::Discord),
+            "hooks.slack.com" if path.starts_with("/services/") => Ok(WebhookKind::Slack),
+            _ => Err("Paste a Discord or Slack webhook URL".into()),
+        }
+    }
+
+    /// Display label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            WebhookKind::Discord => "Discord",
+            WebhookKind::Slack => "Slack",
+        }
+    }
+
+    /// The JSON body posting `title` and `body` as one message. Discord mentions are switched off so
+    /// an alert can never ping `@everyone`; Slack's control characters are escaped.
+    pub fn payload(&self, title: &str, body: &str) -> serde_json::Value {
+        match self {
+            WebhookKind::Discord => {
+                let content: String = format!("**{}**\n{}", title, body).chars().take(DISCORD_MAX_CONTENT).collect();
+                serde_json::json!({ "content": content, "allowed_mentions": { "parse": [] } })
+            }
+            WebhookKind::Slack => {
+                let escape = |s: &str| s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+                serde_json::json!({ "text": format!("*{}*\n{}", escape(title), escape(body)) })
+            }
+        }
+    }
+}
+
+/// The alert webhook as shown in settings: the service and a masked URL, never the secret part.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebhookStatus {
+    /// Which service it posts to.
+    pub kind: WebhookKind,
+    /// The URL with its token hidden, e.g. "hooks.slack.com/…/x7Qz".
+    pub masked_url: String,
+}
+
+impl WebhookStatus {
+    /// The status for a stored URL, or None if it isn't a webhook URL any more.
+    pub fn for_url(url: &str) -> Option<Self> {
+        let kind = WebhookKind::detect(url).ok()?;
+        let rest = url.trim().trim_start_matches("https://");
+        let host = rest.split('/').next().unwrap_or_default();
+        let tail: String = rest.chars().rev().take(4).collect::<Vec<_>>().into_iter().rev().collect();
+        Some(Self { kind, masked_url: format!("{}/\u{2026}/{}", host, tail) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_accepts_only_service_webhooks() {
+        assert_eq!(WebhookKind::detect(" https://discord.com/api/webhooks/123/abc "), Ok(WebhookKind::Discord));
+        assert_eq!(WebhookKind::detect("https://hooks.slack.com/services/T0/B0/xyz"), Ok(WebhookKind::Slack));
+        assert!(WebhookKind::detect("http://discord.com/api/webhooks/123/abc").is_err());
+        assert!(WebhookKind::detect("https://discord.com.evil.test/api/webhooks/1/a").is_err());
+        assert!(WebhookKind::detect("https://user@hooks.slack.com/services/T0").is_err());
+        assert!(WebhookKind::detect("https://hooks.slack.com/other").is_err());
+
+        let status = WebhookStatus::for_url("https://hooks.slack.com/services/T0/B0/x7Qz").unwrap();
+        assert_eq!(status.masked_url, "hooks.slack.com/\u{2026}/x7Qz");
+    }
+
+    #[test]
+    fn test_payloads() {
+        assert_eq!(
+            WebhookKind::Discord.payload("Warning", "@everyone Tent humidity 38%"),
+            serde_json::json!({ "content": "**Warning**\n@everyone Tent humidity 38%", "allowed_mentions": { "parse": [] } }),
+        );
+        assert_eq!(
+            WebhookKind::Slack.payload("Warning", "Tent <40% & dropping"),
+            serde_json::json!({ "text": "*Warning*\nTent &lt;40% &amp; dropping" }),
+        );
+    }
+}
@@ -16,6 +16,13 @@ fn test_add_log_entry_response_serde_roundtrip() {
             note: "First flower!".into(),
             image_filename: Some("user1/photo.jpg".into()),
             event_type: Some("Flowering".into()),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
         },
         is_first_bloom: true,
     };
@@ -42,6 +49,13 @@ fn test_add_log_entry_response_not_first_bloom() {
             note: "Watered".into(),
             image_filename: None,
             event_type: Some("Watered".into()),
+            climate: None,
+            watering_method: None,
+            water_volume_ml: None,
+            skip_reason: None,
+            medium_feedback: None,
+            measurement: None,
+            root_assessment: None,
         },
         is_first_bloom: false,
     };
@@ -127,6 +141,22 @@ fn test_orchid_first_bloom_at_roundtrip() {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     };
 
     let json = serde_json::to_string(&orchid).unwrap();
@@ -202,6 +232,22 @@ fn test_orchid_care_tracking_roundtrip() {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     };
 
     let json = serde_json::to_string(&orchid).unwrap();
@@ -259,6 +305,22 @@ fn test_orchid_fertilize_helpers() {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     };
 
     assert_eq!(orchid.days_since_fertilized(), Some(5));
@@ -303,6 +365,22 @@ fn test_orchid_repot_helper() {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     };
 
     assert_eq!(orchid.days_since_repotted(), Some(90));
@@ -350,6 +428,22 @@ fn test_orchid_no_fert_schedule_returns_none() {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     };
 
     assert_eq!(orchid.days_since_fertilized(), None);
@@ -370,7 +464,7 @@ fn test_fertilized_event_type_exists() {
 
 #[test]
 fn test_event_types_count() {
-    assert_eq!(EVENT_TYPES.len(), 8, "Expected exactly 8 event types");
+    assert_eq!(EVENT_TYPES.len(), 12, "Expected exactly 12 event types");
 }
 
 #[test]
@@ -38,6 +38,22 @@ fn test_orchid_json_serialization() {
         active_water_multiplier: None,
         active_fertilizer_multiplier: None,
         par_ppfd: None,
+        overdue_grace_days: None,
+        default_watering_method: None,
+        water_deferred_until: None,
+        reservoir_frequency_days: None,
+        last_topped_up_at: None,
+        acquired_at: None,
+        acquisition_price: None,
+        acquisition_source: None,
+        vendor_id: None,
+        grex_name: None,
+        seed_parent: None,
+        pollen_parent: None,
+        archived_at: None,
+        archive_reason: None,
+        cover_image: None,
+        updated_at: None,
     };
 
     // Serialize